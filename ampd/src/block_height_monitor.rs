@@ -130,6 +130,7 @@ mod tests {
 
     type BlockResultsResponse = tendermint_rpc::endpoint::block_results::Response;
     type BlockResponse = tendermint_rpc::endpoint::block::Response;
+    type TxSearchResponse = tendermint_rpc::endpoint::tx_search::Response;
     type Error = tendermint_rpc::Error;
 
     use error_stack::Result;
@@ -140,6 +141,7 @@ mod tests {
         impl tm_client::TmClient for TmClientClonable {
             async fn latest_block(&self) -> Result<BlockResponse, Error>;
             async fn block_results(&self, block_height: Height) -> Result<BlockResultsResponse, Error>;
+            async fn tx_search(&self, query: tendermint_rpc::query::Query, page: u32, per_page: u8) -> Result<TxSearchResponse, Error>;
         }
 
         impl Clone for TmClientClonable {
@@ -7,7 +7,7 @@ use futures::{StreamExt, TryFutureExt};
 use thiserror::Error;
 use tokio::sync::{mpsc, Mutex};
 use tokio_stream::wrappers::ReceiverStream;
-use tracing::{debug, error, trace};
+use tracing::{debug, error, trace, warn};
 
 use super::cosmos;
 use crate::asyncutil::future::{with_retry, RetryPolicy};
@@ -104,6 +104,40 @@ where
     }
 }
 
+/// Logs the execution result of confirmed txs until the confirmer stops
+pub async fn log_tx_responses(
+    mut tx_response_receiver: mpsc::Receiver<TxResponse>,
+) -> Result<(), Error> {
+    while let Some(tx_res) = tx_response_receiver.recv().await {
+        let tx_hash = tx_res.response.txhash;
+
+        match tx_res.status {
+            TxStatus::Success => {
+                tx_res.response.logs.iter().for_each(|log| {
+                    let msg_index = log.msg_index;
+
+                    log.events
+                        .iter()
+                        .enumerate()
+                        .for_each(|(event_index, event)| {
+                            debug!(tx_hash, msg_index, event_index, "tx event {:?}", event);
+                        });
+                });
+            }
+            TxStatus::Failure => {
+                warn!(
+                    tx_hash,
+                    log = tx_res.response.raw_log,
+                    error_code = tx_res.response.code,
+                    "tx failed"
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
 async fn confirm_tx_with_retry(
     client: Arc<Mutex<impl cosmos::CosmosClient>>,
     tx_hash: String,
@@ -4,8 +4,9 @@ use std::ops::Mul;
 use std::time::Duration;
 
 use async_trait::async_trait;
-use axelar_wasm_std::FnExt;
+use axelar_wasm_std::{nonempty, FnExt};
 use cosmrs::proto::cosmos::auth::v1beta1::{BaseAccount, QueryAccountRequest};
+use cosmrs::proto::cosmos::authz::v1beta1::MsgExec;
 use cosmrs::proto::cosmos::bank::v1beta1::QueryBalanceRequest;
 use cosmrs::proto::cosmos::base::abci::v1beta1::TxResponse;
 use cosmrs::proto::cosmos::tx::v1beta1::{BroadcastMode, BroadcastTxRequest, SimulateRequest};
@@ -75,6 +76,17 @@ pub struct Config {
     pub queue_cap: usize,
     #[serde(with = "humantime_serde")]
     pub broadcast_interval: Duration,
+    /// The account on whose behalf transactions are broadcast. If set, the broadcaster key only acts as an authz grantee
+    /// and fees are paid from the granter's fee allowance (see `ampd rotate-broadcaster-key`)
+    pub authz_granter: Option<TMAddress>,
+    /// Number of messages of each handler per round when broadcast batches are shared between the handlers and
+    /// the gRPC clients, see `grpc.clients` for the weights of the clients
+    #[serde(default = "default_handler_weight")]
+    pub handler_weight: nonempty::Usize,
+}
+
+fn default_handler_weight() -> nonempty::Usize {
+    1usize.try_into().expect("weight must not be zero")
 }
 
 impl Default for Config {
@@ -88,6 +100,8 @@ impl Default for Config {
             batch_gas_limit: 1000000,
             queue_cap: 1000,
             broadcast_interval: Duration::from_secs(5),
+            authz_granter: None,
+            handler_weight: default_handler_weight(),
         }
     }
 }
@@ -123,13 +137,18 @@ where
     pub async fn validate_fee_denomination(mut self) -> Result<BasicBroadcaster<T, S>, Error> {
         let denom: Denom = self.config.gas_price.denom.clone().into();
         let address: TMAddress = self.derive_address()?;
+        // with an authz granter, fees are deducted from the granter's fee allowance
+        let fee_payer = self.config.authz_granter.clone().unwrap_or(address.clone());
 
         ensure!(
-            self.balance(address.clone(), denom.clone())
+            self.balance(fee_payer.clone(), denom.clone())
                 .await?
                 .then(extract_non_zero_amount)
                 .is_some(),
-            Error::NoTokensOfFeeDenom { denom, address }
+            Error::NoTokensOfFeeDenom {
+                denom,
+                address: fee_payer
+            }
         );
 
         Ok(BasicBroadcaster {
@@ -202,10 +221,14 @@ where
     S: Multisig + Send + Sync,
 {
     fn sender_address(&self) -> TMAddress {
-        self.address.clone()
+        self.config
+            .authz_granter
+            .clone()
+            .unwrap_or(self.address.clone())
     }
 
     async fn broadcast(&mut self, msgs: Vec<Any>) -> Result<TxResponse, Error> {
+        let msgs = self.authorize(msgs)?;
         let (acc_number, acc_sequence) = self.acc_number_and_sequence().await?;
         let fee = self.estimate_fee(msgs.clone(), acc_sequence).await?;
         let tx = Tx::builder()
@@ -268,6 +291,7 @@ where
     }
 
     async fn estimate_fee(&mut self, msgs: Vec<Any>) -> Result<Fee, Error> {
+        let msgs = self.authorize(msgs)?;
         let (_, acc_sequence) = self.acc_number_and_sequence().await?;
 
         self.estimate_fee(msgs, acc_sequence).await
@@ -279,6 +303,18 @@ where
     T: cosmos::CosmosClient + Send,
     S: Multisig + Send + Sync,
 {
+    /// Wraps the messages into an authz `MsgExec` if the broadcaster acts on behalf of a granter
+    fn authorize(&self, msgs: Vec<Any>) -> Result<Vec<Any>, Error> {
+        match self.config.authz_granter {
+            Some(_) => Ok(vec![Any::from_msg(&MsgExec {
+                grantee: self.address.to_string(),
+                msgs,
+            })
+            .change_context(Error::TxBuilding)?]),
+            None => Ok(msgs),
+        }
+    }
+
     async fn acc_number_and_sequence(&mut self) -> Result<(u64, u64), Error> {
         let request = QueryAccountRequest {
             address: self.address.to_string(),
@@ -322,14 +358,21 @@ where
         self.estimate_gas(sim_tx).await.map(|gas| {
             let gas_adj = gas as f64 * self.config.gas_adjustment;
 
-            Ok(Fee::from_amount_and_gas(
-                Coin {
-                    amount: cast(gas_adj.mul(self.config.gas_price.amount).ceil())
-                        .ok_or(Error::FeeEstimation)?,
-                    denom: self.config.gas_price.denom.clone().into(),
-                },
-                cast::<f64, u64>(gas_adj).ok_or(Error::FeeEstimation)?,
-            ))
+            Ok(Fee {
+                granter: self
+                    .config
+                    .authz_granter
+                    .as_ref()
+                    .map(|granter| granter.as_ref().clone()),
+                ..Fee::from_amount_and_gas(
+                    Coin {
+                        amount: cast(gas_adj.mul(self.config.gas_price.amount).ceil())
+                            .ok_or(Error::FeeEstimation)?,
+                        denom: self.config.gas_price.denom.clone().into(),
+                    },
+                    cast::<f64, u64>(gas_adj).ok_or(Error::FeeEstimation)?,
+                )
+            })
         })?
     }
 
@@ -361,6 +404,7 @@ fn decode_base_account(account: Any) -> Result<BaseAccount, Error> {
 mod tests {
     use cosmrs::bank::MsgSend;
     use cosmrs::proto::cosmos::auth::v1beta1::{BaseAccount, QueryAccountResponse};
+    use cosmrs::proto::cosmos::authz::v1beta1::MsgExec;
     use cosmrs::proto::cosmos::bank::v1beta1::QueryBalanceResponse;
     use cosmrs::proto::cosmos::base::abci::v1beta1::{GasInfo, TxResponse};
     use cosmrs::proto::cosmos::tx::v1beta1::{
@@ -368,7 +412,7 @@ mod tests {
     };
     use cosmrs::proto::Any;
     use cosmrs::tx::Msg;
-    use cosmrs::{AccountId, Coin, Denom};
+    use cosmrs::{AccountId, Coin, Denom, Tx};
     use k256::ecdsa::SigningKey;
     use rand::rngs::OsRng;
     use report::ErrorExt;
@@ -560,6 +604,81 @@ mod tests {
         assert_eq!(broadcaster.acc_sequence, Some(1));
     }
 
+    #[test]
+    async fn broadcast_with_authz_granter_executes_msgs_on_behalf_of_granter() {
+        let key_id = "key_uid".to_string();
+        let priv_key = k256::ecdsa::SigningKey::random(&mut OsRng);
+        let pub_key = PublicKey::new_secp256k1(priv_key.verifying_key().to_sec1_bytes()).unwrap();
+        let grantee: TMAddress = CosmosPublicKey::try_from(pub_key)
+            .unwrap()
+            .account_id(PREFIX)
+            .unwrap()
+            .into();
+        let granter = TMAddress::random(PREFIX);
+
+        let signer = init_mock_signer(&key_id, &priv_key);
+        let mut client = setup_account_mock(MockCosmosClient::new(), &priv_key);
+        let fee_payer = granter.clone();
+        client.expect_balance().returning(move |request| {
+            assert_eq!(request.address, fee_payer.to_string());
+
+            Ok(QueryBalanceResponse {
+                balance: Some(
+                    Coin {
+                        amount: 1,
+                        denom: request.denom.parse().unwrap(),
+                    }
+                    .into(),
+                ),
+            })
+        });
+        client.expect_simulate().returning(|_| {
+            Ok(SimulateResponse {
+                gas_info: Some(GasInfo {
+                    gas_wanted: 1000,
+                    gas_used: 500,
+                }),
+                result: None,
+            })
+        });
+        let expected_granter = granter.clone();
+        let expected_grantee = grantee.clone();
+        client.expect_broadcast_tx().returning(move |request| {
+            let tx = Tx::from_bytes(&request.tx_bytes).unwrap();
+            assert_eq!(
+                tx.auth_info.fee.granter,
+                Some(expected_granter.as_ref().clone())
+            );
+            assert_eq!(tx.body.messages.len(), 1);
+
+            let msg_exec: MsgExec = tx.body.messages[0].to_msg().unwrap();
+            assert_eq!(msg_exec.grantee, expected_grantee.to_string());
+            assert_eq!(msg_exec.msgs, vec![dummy_msg()]);
+
+            Ok(BroadcastTxResponse {
+                tx_response: Some(TxResponse::default()),
+            })
+        });
+
+        let mut broadcaster = UnvalidatedBasicBroadcaster::builder()
+            .client(client)
+            .signer(signer)
+            .address_prefix(PREFIX.to_string())
+            .pub_key((key_id, CosmosPublicKey::try_from(pub_key).unwrap()))
+            .config(Config {
+                authz_granter: Some(granter.clone()),
+                ..Config::default()
+            })
+            .build()
+            .validate_fee_denomination()
+            .await
+            .unwrap();
+
+        assert_eq!(broadcaster.sender_address(), granter);
+        assert!(broadcaster.broadcast(vec![dummy_msg()]).await.is_ok());
+        assert_eq!(broadcaster.acc_sequence, Some(1));
+    }
+
     #[test]
     async fn broadcast_confirmed_in_mem_acc_sequence_mismatch_with_on_chain() {
         let key_id = "key_uid".to_string();
@@ -1,6 +1,7 @@
 use std::future::Future;
 use std::sync::Arc;
 
+use cosmrs::proto::cosmos::authz::v1beta1::MsgExec;
 use cosmrs::proto::cosmos::base::abci::v1beta1::TxResponse;
 use cosmrs::tx::Fee;
 use cosmrs::{tendermint, Any, Gas};
@@ -32,6 +33,11 @@ use crate::{cosmos, PREFIX};
 /// reset to the on-chain value after failures, ensuring the next transaction always
 /// uses the correct sequence number.
 ///
+/// # Key Rotation
+///
+/// All clones share the broadcasting account, so after [Broadcaster::rotate_key]
+/// every clone estimates gas for and broadcasts with the new key.
+///
 /// # Transaction Flow
 ///
 /// 1. Retrieves account info on initialization
@@ -57,10 +63,63 @@ where
 {
     client: T,
     chain_id: tendermint::chain::Id,
-    pub pub_key: CosmosPublicKey,
-    pub address: TMAddress,
-    acc_number: u64,
-    acc_sequence: Arc<RwLock<u64>>,
+    account: Arc<RwLock<Account>>,
+}
+
+/// The account that signs the broadcast transactions
+#[derive(Debug)]
+struct Account {
+    pub_key: CosmosPublicKey,
+    address: TMAddress,
+    number: u64,
+    sequence: u64,
+    /// if set, the account only acts as an authz grantee and fees are paid from the granter's fee allowance
+    authz_granter: Option<TMAddress>,
+}
+
+impl Account {
+    async fn query<T>(
+        client: &mut T,
+        pub_key: CosmosPublicKey,
+        authz_granter: Option<TMAddress>,
+    ) -> Result<Self>
+    where
+        T: cosmos::CosmosClient,
+    {
+        let address = pub_key
+            .account_id(PREFIX)
+            .change_context(Error::InvalidPubKey)?
+            .into();
+        let account = cosmos::account(client, &address)
+            .await
+            .change_context(Error::AccountQuery)?;
+
+        Ok(Self {
+            pub_key,
+            address,
+            number: account.account_number,
+            sequence: account.sequence,
+            authz_granter,
+        })
+    }
+
+    fn sender_address(&self) -> TMAddress {
+        self.authz_granter
+            .clone()
+            .unwrap_or_else(|| self.address.clone())
+    }
+
+    /// Wraps the messages into an authz `MsgExec` if the account acts on behalf of a granter
+    fn authorize(&self, msgs: Vec<Any>) -> Vec<Any> {
+        match self.authz_granter {
+            Some(_) => vec![Any::from_msg(&MsgExec {
+                grantee: self.address.to_string(),
+                msgs,
+            })
+            .expect("failed to serialize proto message for authz exec")],
+            None => msgs,
+        }
+    }
 }
 
 impl<T> Broadcaster<T>
@@ -93,24 +152,49 @@ where
         chain_id: tendermint::chain::Id,
         pub_key: CosmosPublicKey,
     ) -> Result<Self> {
-        let address = pub_key
-            .account_id(PREFIX)
-            .change_context(Error::InvalidPubKey)?
-            .into();
-        let account = cosmos::account(&mut client, &address)
-            .await
-            .change_context(Error::AccountQuery)?;
+        let account = Account::query(&mut client, pub_key, None).await?;
 
         Ok(Self {
             client,
             chain_id,
-            pub_key,
-            address,
-            acc_number: account.account_number,
-            acc_sequence: Arc::new(RwLock::new(account.sequence)),
+            account: Arc::new(RwLock::new(account)),
         })
     }
 
+    /// Broadcasts on behalf of the given authz granter, i.e. messages are wrapped into an authz `MsgExec`
+    /// and fees are paid from the granter's fee allowance
+    pub async fn with_authz_granter(self, authz_granter: Option<TMAddress>) -> Self {
+        self.account.write().await.authz_granter = authz_granter;
+        self
+    }
+
+    /// The public key that transactions are currently signed with
+    pub async fn pub_key(&self) -> CosmosPublicKey {
+        self.account.read().await.pub_key
+    }
+
+    /// The address on whose behalf messages are broadcast, i.e. the authz granter if set or the account itself
+    pub async fn sender_address(&self) -> TMAddress {
+        self.account.read().await.sender_address()
+    }
+
+    /// Switches all clones of the broadcaster over to a new key. Broadcasts that are in progress finish with the old key
+    ///
+    /// # Errors
+    ///
+    /// * `Error::InvalidPubKey` - If the public key cannot be converted to a valid Cosmos account address
+    /// * `Error::QueryAccount` - If querying the account information from the blockchain fails
+    pub async fn rotate_key(
+        &mut self,
+        pub_key: CosmosPublicKey,
+        authz_granter: Option<TMAddress>,
+    ) -> Result<()> {
+        let account = Account::query(&mut self.client, pub_key, authz_granter).await?;
+        *self.account.write().await = account;
+
+        Ok(())
+    }
+
     /// Estimates the gas required for a transaction containing the given messages.
     ///
     /// This performs a simulated execution of the transaction without actually
@@ -128,11 +212,16 @@ where
     ///
     /// * `Error::EstimateGas` - If the gas estimation fails
     pub async fn estimate_gas(&mut self, msgs: Vec<Any>) -> Result<Gas> {
-        let acc_sequence = self.acc_sequence.read().await;
+        let account = self.account.read().await;
 
-        cosmos::estimate_gas(&mut self.client, msgs, self.pub_key, *acc_sequence)
-            .await
-            .change_context(Error::EstimateGas)
+        cosmos::estimate_gas(
+            &mut self.client,
+            account.authorize(msgs),
+            account.pub_key,
+            account.sequence,
+        )
+        .await
+        .change_context(Error::EstimateGas)
     }
 
     /// Broadcasts a transaction to the Cosmos blockchain.
@@ -161,7 +250,7 @@ where
     ///
     /// # Thread Safety
     ///
-    /// This method acquires a write lock on the account sequence, ensuring that
+    /// This method acquires a write lock on the account, ensuring that
     /// concurrent broadcasts use distinct sequence numbers.
     pub async fn broadcast<F, Fut, Err>(
         &mut self,
@@ -174,22 +263,31 @@ where
         Fut: Future<Output = error_stack::Result<Vec<u8>, Err>>,
         Err: Context,
     {
-        let mut acc_sequence = self.acc_sequence.write().await;
+        let mut account = self.account.write().await;
+        // with an authz granter, fees are deducted from the granter's fee allowance
+        let fee = Fee {
+            granter: account
+                .authz_granter
+                .as_ref()
+                .map(|granter| granter.as_ref().clone()),
+            ..fee
+        };
 
         let tx = Tx::builder()
-            .msgs(msgs)
-            .pub_key(self.pub_key)
-            .acc_sequence(*acc_sequence)
+            .msgs(account.authorize(msgs))
+            .pub_key(account.pub_key)
+            .acc_sequence(account.sequence)
             .fee(fee)
             .build()
-            .sign_with(&self.chain_id, self.acc_number, sign_fn)
+            .sign_with(&self.chain_id, account.number, sign_fn)
             .await
             .change_context(Error::SignTx)?;
 
         match cosmos::broadcast(&mut self.client, tx).await {
             Ok(tx_response) => {
                 // increment sequence number on successful broadcast
-                *acc_sequence = acc_sequence
+                account.sequence = account
+                    .sequence
                     .checked_add(1)
                     .expect("account sequence must not overflow");
 
@@ -197,7 +295,7 @@ where
             }
             Err(err) => {
                 // reset sequence number on failed broadcast
-                reset_sequence(&mut self.client, &self.address, acc_sequence).await?;
+                reset_sequence(&mut self.client, account).await?;
 
                 Err(err).change_context(Error::BroadcastTx)
             }
@@ -205,18 +303,14 @@ where
     }
 }
 
-async fn reset_sequence<T>(
-    client: &mut T,
-    address: &TMAddress,
-    mut acc_sequence: RwLockWriteGuard<'_, u64>,
-) -> Result<()>
+async fn reset_sequence<T>(client: &mut T, mut account: RwLockWriteGuard<'_, Account>) -> Result<()>
 where
     T: cosmos::CosmosClient,
 {
-    let account = cosmos::account(client, address)
+    let on_chain = cosmos::account(client, &account.address)
         .await
         .change_context(Error::AccountQuery)?;
-    *acc_sequence = account.sequence;
+    account.sequence = on_chain.sequence;
 
     Ok(())
 }
@@ -287,9 +381,10 @@ mod tests {
 
         assert!(result.is_ok());
         let broadcaster = result.unwrap();
-        assert_eq!(broadcaster.address, address);
-        assert_eq!(broadcaster.acc_number, account_number);
-        assert_eq!(*broadcaster.acc_sequence.read().await, sequence);
+        let account = broadcaster.account.read().await;
+        assert_eq!(account.address, address);
+        assert_eq!(account.number, account_number);
+        assert_eq!(account.sequence, sequence);
     }
 
     #[tokio::test]
@@ -368,7 +463,7 @@ mod tests {
 
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), gas_used);
-        assert_eq!(*broadcaster.acc_sequence.read().await, sequence);
+        assert_eq!(broadcaster.account.read().await.sequence, sequence);
     }
 
     #[tokio::test]
@@ -428,7 +523,7 @@ mod tests {
         assert!(result.is_ok());
         let tx_response = result.unwrap();
         assert_eq!(tx_response.txhash, tx_hash);
-        assert_eq!(*broadcaster.acc_sequence.read().await, sequence + 1);
+        assert_eq!(broadcaster.account.read().await.sequence, sequence + 1);
     }
 
     #[tokio::test]
@@ -464,7 +559,7 @@ mod tests {
         let result = broadcaster.estimate_gas(vec![dummy_msg()]).await;
 
         assert_err_contains!(result, Error, Error::EstimateGas);
-        assert_eq!(*broadcaster.acc_sequence.read().await, sequence);
+        assert_eq!(broadcaster.account.read().await.sequence, sequence);
     }
 
     #[tokio::test]
@@ -503,7 +598,7 @@ mod tests {
         let result = broadcaster.broadcast(vec![dummy_msg()], fee, sign_fn).await;
 
         assert_err_contains!(result, Error, Error::SignTx);
-        assert_eq!(*broadcaster.acc_sequence.read().await, sequence);
+        assert_eq!(broadcaster.account.read().await.sequence, sequence);
     }
 
     #[tokio::test]
@@ -563,7 +658,7 @@ mod tests {
         let result = broadcaster.broadcast(vec![dummy_msg()], fee, sign_fn).await;
 
         assert_err_contains!(result, Error, Error::BroadcastTx);
-        assert_eq!(*broadcaster.acc_sequence.read().await, sequence);
+        assert_eq!(broadcaster.account.read().await.sequence, sequence);
     }
 
     #[tokio::test]
@@ -632,12 +727,18 @@ mod tests {
             .await;
         assert!(result.is_ok());
         assert_eq!(result.unwrap().txhash, "tx1");
-        assert_eq!(*broadcaster.acc_sequence.read().await, initial_sequence + 1);
+        assert_eq!(
+            broadcaster.account.read().await.sequence,
+            initial_sequence + 1
+        );
 
         let result = broadcaster.broadcast(vec![dummy_msg()], fee, sign_fn).await;
         assert!(result.is_ok());
         assert_eq!(result.unwrap().txhash, "tx2");
-        assert_eq!(*broadcaster.acc_sequence.read().await, initial_sequence + 2);
+        assert_eq!(
+            broadcaster.account.read().await.sequence,
+            initial_sequence + 2
+        );
     }
 
     #[tokio::test(flavor = "multi_thread")]
@@ -784,8 +885,101 @@ mod tests {
         }
 
         assert_eq!(
-            *broadcaster.acc_sequence.read().await,
+            broadcaster.account.read().await.sequence,
             initial_sequence + broadcast_count
         );
     }
+
+    #[tokio::test]
+    async fn broadcast_with_authz_granter_should_execute_msgs_on_behalf_of_granter() {
+        let pub_key = random_cosmos_public_key();
+        let address: TMAddress = pub_key.account_id(PREFIX).unwrap().into();
+        let granter = TMAddress::random(PREFIX);
+        let chain_id: tendermint::chain::Id = "test-chain-id".parse().unwrap();
+        let fee = Fee::from_amount_and_gas(Coin::new(3750u128, "uaxl").unwrap(), 150000u64);
+
+        let base_account = BaseAccount {
+            address: address.to_string(),
+            pub_key: None,
+            account_number: 42,
+            sequence: 10,
+        };
+
+        let mut mock_client = cosmos::MockCosmosClient::new();
+        mock_client.expect_account().return_once(move |_| {
+            Ok(QueryAccountResponse {
+                account: Some(Any::from_msg(&base_account).unwrap()),
+            })
+        });
+        let expected_granter = granter.clone();
+        mock_client
+            .expect_broadcast_tx()
+            .once()
+            .return_once(move |req| {
+                let tx = cosmrs::Tx::from_bytes(&req.tx_bytes).unwrap();
+                assert_eq!(
+                    tx.auth_info.fee.granter,
+                    Some(expected_granter.as_ref().clone())
+                );
+                assert_eq!(tx.body.messages.len(), 1);
+
+                let msg_exec: MsgExec = tx.body.messages[0].to_msg().unwrap();
+                assert_eq!(msg_exec.grantee, address.to_string());
+                assert_eq!(msg_exec.msgs, vec![dummy_msg()]);
+
+                Ok(BroadcastTxResponse {
+                    tx_response: Some(TxResponse::default()),
+                })
+            });
+
+        let mut broadcaster = Broadcaster::new(mock_client, chain_id, pub_key)
+            .await
+            .unwrap()
+            .with_authz_granter(Some(granter.clone()))
+            .await;
+        assert_eq!(broadcaster.sender_address().await, granter);
+
+        let sign_fn = |_: Vec<u8>| async { Ok::<Vec<u8>, Report<cosmos::Error>>(vec![0u8; 64]) };
+        assert!(broadcaster
+            .broadcast(vec![dummy_msg()], fee, sign_fn)
+            .await
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn rotate_key_should_switch_all_clones_to_new_account() {
+        let old_pub_key = random_cosmos_public_key();
+        let new_pub_key = random_cosmos_public_key();
+        let new_address: TMAddress = new_pub_key.account_id(PREFIX).unwrap().into();
+        let granter = TMAddress::random(PREFIX);
+        let chain_id: tendermint::chain::Id = "test-chain-id".parse().unwrap();
+
+        let mut mock_client = cosmos::MockCosmosClient::new();
+        mock_client.expect_account().times(2).returning(|req| {
+            Ok(QueryAccountResponse {
+                account: Some(
+                    Any::from_msg(&BaseAccount {
+                        address: req.address,
+                        pub_key: None,
+                        account_number: 42,
+                        sequence: 10,
+                    })
+                    .unwrap(),
+                ),
+            })
+        });
+
+        let broadcaster = Broadcaster::new(mock_client, chain_id, old_pub_key)
+            .await
+            .unwrap();
+        let mut clone = broadcaster.clone();
+        clone
+            .rotate_key(new_pub_key, Some(granter.clone()))
+            .await
+            .unwrap();
+
+        assert_eq!(broadcaster.pub_key().await, new_pub_key);
+        assert_eq!(broadcaster.sender_address().await, granter);
+        assert_eq!(broadcaster.account.read().await.address, new_address);
+    }
 }
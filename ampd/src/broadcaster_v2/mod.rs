@@ -9,13 +9,15 @@ use k256::sha2::{Digest, Sha256};
 use num_traits::cast;
 use report::{LoggableError, ResultCompatExt};
 use thiserror::Error;
-use tokio::sync::oneshot;
+use tokio::select;
+use tokio::sync::{mpsc, oneshot};
 use tokio_stream::StreamExt;
-use tracing::{error, info};
+use tracing::{error, info, warn};
 use typed_builder::TypedBuilder;
 use valuable::Valuable;
 
 use crate::broadcaster::dec_coin::DecCoin;
+use crate::types::{CosmosPublicKey, TMAddress};
 use crate::{cosmos, tofnd};
 
 mod broadcaster;
@@ -24,8 +26,12 @@ mod proto;
 
 pub use broadcaster::Broadcaster;
 #[cfg(test)]
+pub use msg_queue::MockBroadcasterClient;
+#[cfg(test)]
 pub use msg_queue::QueueMsg;
-pub use msg_queue::{MsgQueue, MsgQueueClient};
+pub use msg_queue::{BroadcasterClient, GasEstimate, MsgQueue, MsgQueueClient, Producer};
+#[cfg(test)]
+pub use proto::axelar::auxiliary::v1beta1::BatchRequest;
 
 type Result<T> = error_stack::Result<T, Error>;
 
@@ -55,6 +61,14 @@ pub enum Error {
     },
 }
 
+/// Key that the broadcaster task switches over to, e.g. after `ampd rotate-broadcaster-key`
+#[derive(Debug, Clone)]
+pub struct NewKey {
+    pub key_id: String,
+    pub pub_key: CosmosPublicKey,
+    pub authz_granter: Option<TMAddress>,
+}
+
 /// A task that processes queued messages and broadcasts them to a Cosmos blockchain
 ///
 /// `BroadcasterTask` continuously polls a message queue for batches of messages,
@@ -83,6 +97,12 @@ where
     key_id: String,
     gas_adjustment: f64,
     gas_price: DecCoin,
+    /// not set if the key never changes while running
+    #[builder(default, setter(strip_option))]
+    key_rotations: Option<mpsc::Receiver<NewKey>>,
+    /// receives the hashes of broadcast txs, so their execution on chain can be confirmed
+    #[builder(default, setter(strip_option))]
+    tx_hashes: Option<mpsc::Sender<String>>,
 }
 
 impl<T, Q, S> BroadcasterTask<T, Q, S>
@@ -100,6 +120,8 @@ where
     /// 4. Notifies submitters of the transaction result via callbacks
     /// 5. Proceeds to the next batch
     ///
+    /// Key rotations are applied between batches, so a batch is always signed with a single key.
+    ///
     /// The task runs until the message queue is closed/exhausted, at which point
     /// it terminates successfully. Errors during broadcasting are logged and
     /// communicated back to submitters but don't halt the task.
@@ -109,31 +131,72 @@ where
     /// A Result indicating whether the task completed successfully.
     /// Note that individual transaction failures don't cause the task to return an error.
     pub async fn run(mut self) -> Result<()> {
-        while let Some(msgs) = self.msg_queue.next().await {
-            let tx_hash = self
-                .broadcast(msgs.as_ref().iter().map(|msg| msg.msg.clone()))
-                .await
-                .inspect(|res| {
-                    info!(
-                        tx_hash = res.txhash,
-                        msg_count = msgs.as_ref().len(),
-                        "successfully broadcasted tx"
-                    );
-                })
-                .inspect_err(|err| {
-                    error!(
-                        err = LoggableError::from(err).as_value(),
-                        "failed to broadcast tx",
-                    );
-                })
-                .map(|res| res.txhash);
-
-            handle_tx_res(tx_hash, msgs);
+        loop {
+            select! {
+                msgs = self.msg_queue.next() => match msgs {
+                    Some(msgs) => self.broadcast_batch(msgs).await,
+                    None => break,
+                },
+                Some(new_key) = next_key(&mut self.key_rotations) => self.rotate_key(new_key).await,
+            }
         }
 
         Ok(())
     }
 
+    async fn broadcast_batch(&mut self, msgs: nonempty::Vec<msg_queue::QueueMsg>) {
+        let tx_hash = self
+            .broadcast(msgs.as_ref().iter().map(|msg| msg.msg.clone()))
+            .await
+            .inspect(|res| {
+                info!(
+                    tx_hash = res.txhash,
+                    msg_count = msgs.as_ref().len(),
+                    "successfully broadcasted tx"
+                );
+            })
+            .inspect_err(|err| {
+                error!(
+                    err = LoggableError::from(err).as_value(),
+                    "failed to broadcast tx",
+                );
+            })
+            .map(|res| res.txhash);
+
+        if let (Ok(tx_hash), Some(tx_hashes)) = (&tx_hash, &self.tx_hashes) {
+            if tx_hashes.send(tx_hash.clone()).await.is_err() {
+                warn!(tx_hash, "tx confirmation stopped, tx is not confirmed");
+            }
+        }
+
+        handle_tx_res(tx_hash, msgs);
+    }
+
+    async fn rotate_key(&mut self, new_key: NewKey) {
+        let old_sender = self.broadcaster.sender_address().await;
+
+        match self
+            .broadcaster
+            .rotate_key(new_key.pub_key, new_key.authz_granter)
+            .await
+        {
+            Ok(()) => {
+                self.key_id = new_key.key_id;
+                info!(
+                    old_sender = old_sender.to_string(),
+                    new_sender = self.broadcaster.sender_address().await.to_string(),
+                    "switched over to the new broadcaster key"
+                );
+            }
+            Err(err) => {
+                error!(
+                    err = LoggableError::from(&err).as_value(),
+                    "failed to switch over to the new broadcaster key, keeping the current key",
+                );
+            }
+        }
+    }
+
     async fn estimate_fee(&mut self, batch_req: Any) -> Result<Fee> {
         let gas = self
             .broadcaster
@@ -154,12 +217,12 @@ where
 
     async fn broadcast(&mut self, msgs: impl IntoIterator<Item = Any>) -> Result<TxResponse> {
         let batch_req = Any::from_msg(&proto::axelar::auxiliary::v1beta1::BatchRequest {
-            sender: self.broadcaster.address.as_ref().to_bytes(),
+            sender: self.broadcaster.sender_address().await.as_ref().to_bytes(),
             messages: msgs.into_iter().collect(),
         })
         .expect("failed to serialize proto message for batch request");
         let fee = self.estimate_fee(batch_req.clone()).await?;
-        let pub_key = self.broadcaster.pub_key;
+        let pub_key = self.broadcaster.pub_key().await;
 
         self.broadcaster
             .broadcast(vec![batch_req], fee, |sign_doc| {
@@ -183,6 +246,13 @@ where
     }
 }
 
+async fn next_key(key_rotations: &mut Option<mpsc::Receiver<NewKey>>) -> Option<NewKey> {
+    match key_rotations {
+        Some(key_rotations) => key_rotations.recv().await,
+        None => futures::future::pending().await,
+    }
+}
+
 fn handle_tx_res(tx_hash: Result<String>, msgs: nonempty::Vec<msg_queue::QueueMsg>) {
     Vec::from(msgs)
         .into_iter()
@@ -209,12 +279,12 @@ mod tests {
     use error_stack::report;
     use mockall::Sequence;
     use prost::Message;
-    use tokio::sync::oneshot;
+    use tokio::sync::{mpsc, oneshot};
     use tokio_stream::iter;
 
     use crate::broadcaster::dec_coin::DecCoin;
     use crate::broadcaster_v2::msg_queue::QueueMsg;
-    use crate::broadcaster_v2::{broadcaster, BroadcasterTask, Error};
+    use crate::broadcaster_v2::{broadcaster, BroadcasterTask, Error, NewKey, Producer};
     use crate::tofnd::error::Error as TofndError;
     use crate::tofnd::grpc::MockMultisig;
     use crate::types::{random_cosmos_public_key, TMAddress};
@@ -261,6 +331,7 @@ mod tests {
                 let msg = QueueMsg {
                     msg: dummy_msg(),
                     gas: 50000,
+                    producer: Producer::default(),
                     tx_res_callback: tx,
                 };
 
@@ -350,6 +421,7 @@ mod tests {
         let queue_msgs = vec![QueueMsg {
             msg: dummy_msg(),
             gas: 50000,
+            producer: Producer::default(),
             tx_res_callback: tx,
         }]
         .try_into()
@@ -431,6 +503,7 @@ mod tests {
         let queue_msgs = vec![QueueMsg {
             msg: dummy_msg(),
             gas: 50000,
+            producer: Producer::default(),
             tx_res_callback: tx,
         }]
         .try_into()
@@ -499,6 +572,7 @@ mod tests {
         let batch_1 = vec![QueueMsg {
             msg: dummy_msg(),
             gas: 50000,
+            producer: Producer::default(),
             tx_res_callback: tx_1,
         }]
         .try_into()
@@ -506,6 +580,7 @@ mod tests {
         let batch_2 = vec![QueueMsg {
             msg: dummy_msg(),
             gas: 50000,
+            producer: Producer::default(),
             tx_res_callback: tx_2,
         }]
         .try_into()
@@ -621,6 +696,7 @@ mod tests {
         let batch_1 = vec![QueueMsg {
             msg: dummy_msg(),
             gas: 50000,
+            producer: Producer::default(),
             tx_res_callback: tx_1,
         }]
         .try_into()
@@ -628,6 +704,7 @@ mod tests {
         let batch_2 = vec![QueueMsg {
             msg: dummy_msg(),
             gas: 50000,
+            producer: Producer::default(),
             tx_res_callback: tx_2,
         }]
         .try_into()
@@ -748,6 +825,7 @@ mod tests {
         let queue_msgs = vec![QueueMsg {
             msg: dummy_msg(),
             gas: 50000,
+            producer: Producer::default(),
             tx_res_callback: tx,
         }]
         .try_into()
@@ -829,4 +907,116 @@ mod tests {
         assert_eq!(tx_hash, "tx_hash_success");
         assert_eq!(idx, 0);
     }
+
+    #[tokio::test]
+    async fn broadcaster_task_should_sign_with_rotated_key_and_confirm_tx() {
+        let old_pub_key = random_cosmos_public_key();
+        let new_pub_key = random_cosmos_public_key();
+        let chain_id: tendermint::chain::Id = "test-chain-id".parse().unwrap();
+
+        let (tx, rx) = oneshot::channel();
+        let queue_msgs = vec![QueueMsg {
+            msg: dummy_msg(),
+            gas: 50000,
+            producer: Producer::default(),
+            tx_res_callback: tx,
+        }];
+        let (batch_sender, batch_receiver) = mpsc::channel(1);
+        let msg_queue = tokio_stream::wrappers::ReceiverStream::new(batch_receiver);
+
+        let mut mock_signer = MockMultisig::new();
+        mock_signer
+            .expect_sign()
+            .once()
+            .withf(|key_id, _, _, _| key_id == "new-key")
+            .returning(|_, _, _, _| Ok(vec![0u8; 64]));
+
+        let (rotated_sender, rotated_receiver) = oneshot::channel();
+        let mut seq = Sequence::new();
+        let mut mock_client = cosmos::MockCosmosClient::new();
+        mock_client
+            .expect_account()
+            .once()
+            .in_sequence(&mut seq)
+            .returning(|req| {
+                Ok(QueryAccountResponse {
+                    account: Some(
+                        Any::from_msg(&create_base_account(&req.address.parse().unwrap())).unwrap(),
+                    ),
+                })
+            });
+        mock_client
+            .expect_account()
+            .once()
+            .in_sequence(&mut seq)
+            .return_once(move |req| {
+                rotated_sender.send(()).unwrap();
+                Ok(QueryAccountResponse {
+                    account: Some(
+                        Any::from_msg(&create_base_account(&req.address.parse().unwrap())).unwrap(),
+                    ),
+                })
+            });
+        mock_client
+            .expect_simulate()
+            .once()
+            .in_sequence(&mut seq)
+            .return_once(|_| {
+                Ok(SimulateResponse {
+                    gas_info: Some(GasInfo {
+                        gas_wanted: 0,
+                        gas_used: 100000,
+                    }),
+                    result: None,
+                })
+            });
+        mock_client
+            .expect_broadcast_tx()
+            .once()
+            .in_sequence(&mut seq)
+            .return_once(|_| {
+                Ok(BroadcastTxResponse {
+                    tx_response: Some(TxResponse {
+                        txhash: "tx_hash_success".to_string(),
+                        ..Default::default()
+                    }),
+                })
+            });
+
+        let broadcaster = broadcaster::Broadcaster::new(mock_client, chain_id, old_pub_key)
+            .await
+            .unwrap();
+        let (key_sender, key_receiver) = mpsc::channel(1);
+        let (tx_hash_sender, mut tx_hash_receiver) = mpsc::channel(1);
+        let broadcaster_task = BroadcasterTask::builder()
+            .broadcaster(broadcaster)
+            .msg_queue(msg_queue)
+            .signer(mock_signer)
+            .key_id("old-key".to_string())
+            .gas_adjustment(1.5)
+            .gas_price(DecCoin::new(0.025, "uaxl").unwrap())
+            .key_rotations(key_receiver)
+            .tx_hashes(tx_hash_sender)
+            .build();
+        let handle = tokio::spawn(broadcaster_task.run());
+
+        key_sender
+            .send(NewKey {
+                key_id: "new-key".to_string(),
+                pub_key: new_pub_key,
+                authz_granter: None,
+            })
+            .await
+            .unwrap();
+        rotated_receiver.await.unwrap();
+        batch_sender
+            .send(queue_msgs.try_into().unwrap())
+            .await
+            .unwrap();
+        drop(batch_sender);
+
+        assert!(handle.await.unwrap().is_ok());
+        assert_eq!(rx.await.unwrap().unwrap().0, "tx_hash_success");
+        assert_eq!(tx_hash_receiver.recv().await.unwrap(), "tx_hash_success");
+    }
 }
@@ -1,11 +1,14 @@
 use core::pin::Pin;
 use core::task::{Context, Poll};
+use std::collections::VecDeque;
 use std::future::Future;
 
+use async_trait::async_trait;
 use axelar_wasm_std::nonempty;
 use cosmrs::{Any, Gas};
 use error_stack::{report, Report, ResultExt};
 use futures::{FutureExt, Stream, TryFutureExt};
+use mockall::automock;
 use pin_project_lite::pin_project;
 use report::{ErrorExt, LoggableError};
 use serde_json::json;
@@ -23,14 +26,71 @@ use crate::cosmos;
 /// Represents a message in the queue ready for broadcasting
 ///
 /// This struct contains a Cosmos message, its estimated gas cost,
-/// and a callback channel for receiving the transaction result.
+/// the producer that enqueued it, and a callback channel for receiving the transaction result.
 #[derive(Debug)]
 pub struct QueueMsg {
     pub msg: Any,
     pub gas: Gas,
+    pub producer: Producer,
     pub tx_res_callback: oneshot::Sender<Result<(String, u64)>>,
 }
 
+/// Source of queued messages, e.g. a connected gRPC client
+///
+/// Batches are filled in weighted round-robin between the producers with queued messages,
+/// taking up to `weight` messages of a producer per round. A producer that enqueues many
+/// messages therefore can't take up the whole gas cap of a batch while others are waiting.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Producer {
+    name: String,
+    weight: nonempty::Usize,
+}
+
+impl Producer {
+    pub fn new(name: impl Into<String>, weight: nonempty::Usize) -> Self {
+        Self {
+            name: name.into(),
+            weight,
+        }
+    }
+}
+
+impl Default for Producer {
+    fn default() -> Self {
+        Self::new(
+            "default",
+            1usize.try_into().expect("weight must not be zero"),
+        )
+    }
+}
+
+/// Broadcasts the messages of the event handlers
+#[automock]
+#[async_trait]
+pub trait BroadcasterClient {
+    /// Returns once the message is queued, i.e. its gas has been estimated successfully
+    async fn broadcast(&self, msg: Any) -> Result<()>;
+}
+
+#[async_trait]
+impl<T> BroadcasterClient for MsgQueueClient<T>
+where
+    T: cosmos::CosmosClient + Clone + Send + Sync,
+{
+    async fn broadcast(&self, msg: Any) -> Result<()> {
+        self.clone().enqueue_and_forget(msg).await
+    }
+}
+
+/// Simulated gas cost of a batch of messages
+#[derive(Debug, PartialEq)]
+pub struct GasEstimate {
+    /// Gas used by each message when it is simulated on its own, in the order of the given messages
+    pub per_msg: Vec<Gas>,
+    /// Gas used by all messages when they are simulated together in a single transaction
+    pub total: Gas,
+}
+
 /// Client interface for submitting messages to the message queue
 ///
 /// `MsgQueueClient` provides methods to enqueue Cosmos messages
@@ -65,6 +125,21 @@ where
 {
     tx: mpsc::Sender<QueueMsg>,
     broadcaster: broadcaster::Broadcaster<T>,
+    producer: Producer,
+}
+
+impl<T> MsgQueueClient<T>
+where
+    T: cosmos::CosmosClient + Clone,
+{
+    /// Returns a client that enqueues messages on behalf of the given producer
+    pub fn with_producer(&self, producer: Producer) -> Self {
+        Self {
+            tx: self.tx.clone(),
+            broadcaster: self.broadcaster.clone(),
+            producer,
+        }
+    }
 }
 
 impl<T> MsgQueueClient<T>
@@ -138,6 +213,40 @@ where
         Ok(())
     }
 
+    /// Estimates the gas required for the given messages without enqueueing them
+    ///
+    /// Each message is simulated on its own, and then all messages are simulated
+    /// together in a single transaction. Callers can use the estimate to size their
+    /// batches so they stay below the gas cap of the queue.
+    ///
+    /// # Arguments
+    ///
+    /// * `msgs` - The Cosmos messages to estimate the gas for
+    ///
+    /// # Returns
+    ///
+    /// The gas estimate of each message and of all messages combined
+    ///
+    /// # Errors
+    ///
+    /// * `Error::EstimateGas` - If gas estimation fails for any message or for the batch
+    pub async fn estimate_gas(&mut self, msgs: nonempty::Vec<Any>) -> Result<GasEstimate> {
+        let msgs = Vec::from(msgs);
+
+        let mut per_msg = Vec::with_capacity(msgs.len());
+        for msg in msgs.iter() {
+            per_msg.push(
+                self.broadcaster
+                    .estimate_gas(vec![msg.clone()])
+                    .await
+                    .attach_printable_lazy(|| json!({ "msg": msg }))?,
+            );
+        }
+        let total = self.broadcaster.estimate_gas(msgs).await?;
+
+        Ok(GasEstimate { per_msg, total })
+    }
+
     /// Internal method that handles message enqueueing
     ///
     /// This method:
@@ -168,6 +277,7 @@ where
         let msg = QueueMsg {
             msg,
             gas,
+            producer: self.producer.clone(),
             tx_res_callback: tx,
         };
 
@@ -192,13 +302,15 @@ pin_project! {
     ///
     /// This provides efficient batching while ensuring timely processing.
     /// The Stream implementation yields non-empty vectors of queued messages
-    /// that are ready for broadcasting.
+    /// that are ready for broadcasting. Each batch is shared between the
+    /// producers of the queued messages, see [Producer].
     pub struct MsgQueue {
         #[pin]
         stream: Fuse<ReceiverStream<QueueMsg>>,
         #[pin]
         deadline: time::Sleep,
         queue: Queue,
+        msg_cap: usize,
         duration: time::Duration,
     }
 }
@@ -215,7 +327,7 @@ impl MsgQueue {
     /// # Arguments
     ///
     /// * `broadcaster` - The broadcaster instance used for gas estimation and tx sending
-    /// * `msg_cap` - Capacity of the internal message channel
+    /// * `msg_cap` - Capacity of the internal message channel and maximum number of messages waiting to be batched
     /// * `gas_cap` - Maximum gas allowed per transaction batch
     /// * `duration` - Maximum time to wait before releasing queued messages
     ///
@@ -242,9 +354,14 @@ impl MsgQueue {
                 stream: ReceiverStream::new(rx).fuse(),
                 deadline: time::sleep(duration),
                 queue: Queue::new(gas_cap),
+                msg_cap,
                 duration,
             }),
-            MsgQueueClient { broadcaster, tx },
+            MsgQueueClient {
+                broadcaster,
+                tx,
+                producer: Producer::default(),
+            },
         )
     }
 }
@@ -255,17 +372,18 @@ impl Stream for MsgQueue {
 
     /// Polls the message queue and yields batched messages when ready
     ///
-    /// This implementation handles three cases:
-    /// 1. New message received: Add to queue, possibly triggering a batch release
-    /// 2. Stream closed: Drain the queue and then terminate
-    /// 3. Timeout elapsed: Release all queued messages
+    /// All messages that are already waiting in the channel are moved into the queue first,
+    /// so the next batch can be shared between all producers with queued messages. Then:
+    /// 1. Queued gas reaches the gas cap: Release a batch
+    /// 2. Stream closed: Drain the queue batch by batch and then terminate
+    /// 3. Timeout elapsed: Release a batch
     ///
     /// The poll logic ensures that messages are efficiently batched while
     /// maintaining a maximum delay for any queued message.
     fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
         let mut me = self.as_mut().project();
 
-        loop {
+        while me.queue.len() < *me.msg_cap {
             match me.stream.as_mut().poll_next(cx) {
                 Poll::Ready(Some(msg)) => {
                     // reset the deadline timer when the first message is added to an empty queue
@@ -273,28 +391,28 @@ impl Stream for MsgQueue {
                         me.deadline.set(time::sleep(*me.duration));
                     }
 
-                    // try to add the message to the queue
-                    // if the queue returns Some, it means we have a batch ready to send
-                    if let Some(msgs) = me.queue.push_or(msg, handle_queue_error) {
-                        return Poll::Ready(Some(msgs));
-                    }
+                    me.queue.push_or(msg, handle_queue_error);
                 }
                 Poll::Ready(None) => {
                     // input stream is closed, drain any remaining messages and terminate
-                    return Poll::Ready(me.queue.pop_all());
-                }
-                Poll::Pending => {
-                    // if we have no messages queued, we can't produce anything yet
-                    if me.queue.is_empty() {
-                        return Poll::Pending;
-                    }
-
-                    // check if the deadline has elapsed
-                    // if so, flush the queue regardless of how full it is
-                    return me.deadline.poll(cx).map(|_| me.queue.pop_all());
+                    return Poll::Ready(me.queue.pop_batch());
                 }
+                Poll::Pending => break,
             }
         }
+
+        if me.queue.is_full() {
+            return Poll::Ready(me.queue.pop_batch());
+        }
+
+        // if we have no messages queued, we can't produce anything yet
+        if me.queue.is_empty() {
+            return Poll::Pending;
+        }
+
+        // check if the deadline has elapsed
+        // if so, release a batch regardless of how full the queue is
+        me.deadline.poll(cx).map(|_| me.queue.pop_batch())
     }
 }
 
@@ -312,8 +430,14 @@ fn handle_queue_error(msg: QueueMsg, err: Error) {
     let _ = tx_res_callback.send(Err(err));
 }
 
+/// Queued messages of a single producer, in the order they were enqueued
+struct Lane {
+    producer: Producer,
+    msgs: VecDeque<QueueMsg>,
+}
+
 struct Queue {
-    msgs: Vec<QueueMsg>,
+    lanes: VecDeque<Lane>,
     gas_cost: Gas,
     gas_cap: Gas,
 }
@@ -321,13 +445,13 @@ struct Queue {
 impl Queue {
     pub fn new(gas_cap: Gas) -> Self {
         Queue {
-            msgs: vec![],
+            lanes: VecDeque::new(),
             gas_cost: Gas::default(),
             gas_cap,
         }
     }
 
-    pub fn push_or<F>(&mut self, msg: QueueMsg, handle_error: F) -> Option<nonempty::Vec<QueueMsg>>
+    pub fn push_or<F>(&mut self, msg: QueueMsg, handle_error: F)
     where
         F: FnOnce(QueueMsg, Error),
     {
@@ -339,48 +463,92 @@ impl Queue {
             };
             handle_error(msg, err);
 
-            return None;
+            return;
         }
 
+        self.gas_cost = self.gas_cost.saturating_add(msg.gas);
+
         match self
-            .gas_cost
-            .checked_add(msg.gas)
-            .filter(|gas_cost| gas_cost <= &self.gas_cap)
+            .lanes
+            .iter_mut()
+            .find(|lane| lane.producer.name == msg.producer.name)
         {
-            // if gas cost > gas cap or gas cost overflows, pop all and then push
-            // the new message
-            None => {
-                let results = self.pop_all();
-
-                self.gas_cost = msg.gas;
-                self.msgs.push(msg);
-
-                results
+            Some(lane) => {
+                // the latest message determines the weight of its producer
+                lane.producer = msg.producer.clone();
+                lane.msgs.push_back(msg);
             }
-            // if gas cost = gas cap, pop all including the new message
-            Some(gas_cost) if gas_cost == self.gas_cap => {
-                let mut results = self.pop_all().map(Vec::from).unwrap_or_default();
-                results.push(msg);
+            None => self.lanes.push_back(Lane {
+                producer: msg.producer.clone(),
+                msgs: VecDeque::from([msg]),
+            }),
+        }
+    }
 
-                Some(results.try_into().expect("must not be empty"))
+    /// Pops the next batch of messages that fits into the gas cap
+    ///
+    /// The batch is filled in rounds. In each round, every producer contributes up to its weight in messages,
+    /// until the next message of the producer doesn't fit anymore. The producer that starts the rounds changes
+    /// with every batch, so no producer is always served first.
+    pub fn pop_batch(&mut self) -> Option<nonempty::Vec<QueueMsg>> {
+        let first_producer = self.lanes.front().map(|lane| lane.producer.name.clone());
+        let mut batch = vec![];
+        let mut batch_gas_cost = Gas::default();
+        let mut is_lane_full = vec![false; self.lanes.len()];
+
+        while is_lane_full.iter().any(|is_full| !is_full) {
+            for (lane, is_full) in self
+                .lanes
+                .iter_mut()
+                .zip(is_lane_full.iter_mut())
+                .filter(|(_, is_full)| !**is_full)
+            {
+                for _ in 0..usize::from(lane.producer.weight) {
+                    let Some(gas_cost) = lane
+                        .msgs
+                        .front()
+                        .and_then(|msg| batch_gas_cost.checked_add(msg.gas))
+                        .filter(|gas_cost| gas_cost <= &self.gas_cap)
+                    else {
+                        *is_full = true;
+                        break;
+                    };
+
+                    batch_gas_cost = gas_cost;
+                    batch.extend(lane.msgs.pop_front());
+                }
             }
-            // if gas cost < gas cap, only push the new message
-            Some(gas_cost) => {
-                self.gas_cost = gas_cost;
-                self.msgs.push(msg);
+        }
 
-                None
-            }
+        self.lanes.retain(|lane| !lane.msgs.is_empty());
+        // the producer that started this batch is served last in the next one
+        if self.lanes.front().map(|lane| &lane.producer.name) == first_producer.as_ref()
+            && !self.lanes.is_empty()
+        {
+            self.lanes.rotate_left(1);
         }
+        self.gas_cost = self
+            .lanes
+            .iter()
+            .flat_map(|lane| lane.msgs.iter())
+            .fold(Gas::default(), |gas_cost, msg| {
+                gas_cost.saturating_add(msg.gas)
+            });
+
+        batch.try_into().ok()
     }
 
-    pub fn pop_all(&mut self) -> Option<nonempty::Vec<QueueMsg>> {
-        self.gas_cost = 0;
-        std::mem::take(&mut self.msgs).try_into().ok()
+    /// Returns true if the queued messages need at least as much gas as a batch can take
+    pub fn is_full(&self) -> bool {
+        self.gas_cost >= self.gas_cap
     }
 
     pub fn is_empty(&self) -> bool {
-        self.msgs.is_empty()
+        self.lanes.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.lanes.iter().map(|lane| lane.msgs.len()).sum()
     }
 }
 
@@ -454,6 +622,67 @@ mod tests {
         assert!(msg_queue.next().await.is_none());
     }
 
+    #[tokio::test]
+    async fn broadcaster_client_should_enqueue_msg_of_its_producer() {
+        let gas_cap = 1000u64;
+        let base_account = BaseAccount {
+            address: TMAddress::random(PREFIX).to_string(),
+            pub_key: None,
+            account_number: 42,
+            sequence: 10,
+        };
+
+        let mut cosmos_client = cosmos::MockCosmosClient::new();
+        cosmos_client.expect_account().return_once(move |_| {
+            Ok(QueryAccountResponse {
+                account: Some(Any::from_msg(&base_account).unwrap()),
+            })
+        });
+        // the client is cloned once for the producer and once more for the broadcast
+        cosmos_client.expect_clone().return_once(move || {
+            let mut producer_client = cosmos::MockCosmosClient::new();
+            producer_client.expect_clone().return_once(move || {
+                let mut broadcast_client = cosmos::MockCosmosClient::new();
+                broadcast_client.expect_simulate().return_once(move |_| {
+                    Ok(SimulateResponse {
+                        gas_info: Some(GasInfo {
+                            gas_wanted: gas_cap,
+                            gas_used: gas_cap,
+                        }),
+                        result: None,
+                    })
+                });
+                broadcast_client
+            });
+            producer_client
+        });
+        let broadcaster = broadcaster::Broadcaster::new(
+            cosmos_client,
+            "chain-id".parse().unwrap(),
+            random_cosmos_public_key(),
+        )
+        .await
+        .unwrap();
+
+        let (mut msg_queue, msg_queue_client) = MsgQueue::new_msg_queue_and_client(
+            broadcaster,
+            10,
+            gas_cap,
+            time::Duration::from_secs(1),
+        );
+        let producer = Producer::new("handler", 2usize.try_into().unwrap());
+
+        msg_queue_client
+            .with_producer(producer.clone())
+            .broadcast(dummy_msg())
+            .await
+            .unwrap();
+        let actual = msg_queue.next().await.unwrap();
+
+        assert_eq!(actual.as_ref().len(), 1);
+        assert_eq!(actual.as_ref()[0].producer, producer);
+    }
+
     #[tokio::test]
     async fn msg_queue_client_enqueue() {
         let gas_cap = 1000u64;
@@ -926,6 +1155,184 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn msg_queue_client_estimate_gas() {
+        let base_account = BaseAccount {
+            address: TMAddress::random(PREFIX).to_string(),
+            pub_key: None,
+            account_number: 42,
+            sequence: 10,
+        };
+
+        let mut cosmos_client = cosmos::MockCosmosClient::new();
+        cosmos_client.expect_account().return_once(move |_| {
+            Ok(QueryAccountResponse {
+                account: Some(Any::from_msg(&base_account).unwrap()),
+            })
+        });
+        let mut simulated_gas = vec![100u64, 200u64, 250u64].into_iter();
+        cosmos_client
+            .expect_simulate()
+            .times(3)
+            .returning(move |_| {
+                let gas = simulated_gas.next().unwrap();
+
+                Ok(SimulateResponse {
+                    gas_info: Some(GasInfo {
+                        gas_wanted: gas,
+                        gas_used: gas,
+                    }),
+                    result: None,
+                })
+            });
+        let broadcaster = broadcaster::Broadcaster::new(
+            cosmos_client,
+            "chain-id".parse().unwrap(),
+            random_cosmos_public_key(),
+        )
+        .await
+        .unwrap();
+
+        let (mut msg_queue, mut msg_queue_client) =
+            MsgQueue::new_msg_queue_and_client(broadcaster, 10, 1000, time::Duration::from_secs(1));
+
+        let actual = msg_queue_client
+            .estimate_gas(vec![dummy_msg(), dummy_msg()].try_into().unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            actual,
+            GasEstimate {
+                per_msg: vec![100, 200],
+                total: 250,
+            }
+        );
+
+        // estimating gas must not enqueue anything
+        drop(msg_queue_client);
+        assert!(msg_queue.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn msg_queue_client_estimate_gas_error_handling() {
+        let base_account = BaseAccount {
+            address: TMAddress::random(PREFIX).to_string(),
+            pub_key: None,
+            account_number: 42,
+            sequence: 10,
+        };
+
+        let mut cosmos_client = cosmos::MockCosmosClient::new();
+        cosmos_client.expect_account().return_once(move |_| {
+            Ok(QueryAccountResponse {
+                account: Some(Any::from_msg(&base_account).unwrap()),
+            })
+        });
+        cosmos_client.expect_simulate().return_once(|_| {
+            Ok(SimulateResponse {
+                gas_info: None,
+                result: None,
+            })
+        });
+        let broadcaster = broadcaster::Broadcaster::new(
+            cosmos_client,
+            "chain-id".parse().unwrap(),
+            random_cosmos_public_key(),
+        )
+        .await
+        .unwrap();
+
+        let (_, mut msg_queue_client) =
+            MsgQueue::new_msg_queue_and_client(broadcaster, 10, 1000, time::Duration::from_secs(1));
+
+        let result = msg_queue_client
+            .estimate_gas(vec![dummy_msg()].try_into().unwrap())
+            .await;
+
+        assert_err_contains!(result, Error, Error::EstimateGas);
+    }
+
+    #[test]
+    fn queue_should_share_batches_between_producers() {
+        let chatty = Producer::new("chatty", 1usize.try_into().unwrap());
+        let quiet = Producer::new("quiet", 1usize.try_into().unwrap());
+
+        let mut queue = Queue::new(1000);
+        for _ in 0..10 {
+            queue.push_or(queue_msg(&chatty, 100), |_, _| panic!("msg dropped"));
+        }
+        for _ in 0..2 {
+            queue.push_or(queue_msg(&quiet, 100), |_, _| panic!("msg dropped"));
+        }
+        assert!(queue.is_full());
+
+        let batch = Vec::from(queue.pop_batch().unwrap());
+        assert_eq!(batch.len(), 10);
+        assert_eq!(batch.iter().filter(|msg| msg.producer == quiet).count(), 2);
+
+        assert!(!queue.is_full());
+        assert_eq!(queue.len(), 2);
+        let batch = Vec::from(queue.pop_batch().unwrap());
+        assert!(batch.iter().all(|msg| msg.producer == chatty));
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn queue_should_take_up_to_weight_msgs_of_a_producer_per_round() {
+        let external = Producer::new("external", 1usize.try_into().unwrap());
+        let internal = Producer::new("internal", 3usize.try_into().unwrap());
+
+        let mut queue = Queue::new(800);
+        for _ in 0..6 {
+            queue.push_or(queue_msg(&external, 100), |_, _| panic!("msg dropped"));
+            queue.push_or(queue_msg(&internal, 100), |_, _| panic!("msg dropped"));
+        }
+
+        let producers: Vec<_> = Vec::from(queue.pop_batch().unwrap())
+            .into_iter()
+            .map(|msg| msg.producer.name)
+            .collect();
+        assert_eq!(
+            producers,
+            vec![
+                "external", "internal", "internal", "internal", "external", "internal", "internal",
+                "internal",
+            ]
+        );
+    }
+
+    #[test]
+    fn queue_should_change_first_producer_with_every_batch() {
+        let first = Producer::new("first", 1usize.try_into().unwrap());
+        let second = Producer::new("second", 1usize.try_into().unwrap());
+
+        let mut queue = Queue::new(100);
+        for _ in 0..2 {
+            queue.push_or(queue_msg(&first, 100), |_, _| panic!("msg dropped"));
+            queue.push_or(queue_msg(&second, 100), |_, _| panic!("msg dropped"));
+        }
+
+        let producers: Vec<_> = std::iter::from_fn(|| queue.pop_batch())
+            .map(|batch| Vec::from(batch).pop().unwrap().producer)
+            .collect();
+        assert_eq!(
+            producers,
+            vec![first.clone(), second.clone(), first, second]
+        );
+    }
+
+    fn queue_msg(producer: &Producer, gas: Gas) -> QueueMsg {
+        let (tx_res_callback, _) = oneshot::channel();
+
+        QueueMsg {
+            msg: dummy_msg(),
+            gas,
+            producer: producer.clone(),
+            tx_res_callback,
+        }
+    }
+
     fn dummy_msg() -> Any {
         Any::from_msg(&MsgSend {
             from_address: TMAddress::random(PREFIX).to_string(),
@@ -1,8 +1,11 @@
 use error_stack::Report;
 
 use crate::config::Config;
-use crate::Error;
+use crate::{ConfigLoader, Error};
 
-pub async fn run(config: Config) -> Result<Option<String>, Report<Error>> {
-    crate::run(config).await.map(|_| None)
+pub async fn run(
+    config: Config,
+    config_loader: ConfigLoader,
+) -> Result<Option<String>, Report<Error>> {
+    crate::run(config, config_loader).await.map(|_| None)
 }
@@ -0,0 +1,671 @@
+use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
+
+use axelar_wasm_std::msg_id::HexTxHashAndEventIndex;
+use axelar_wasm_std::voting::Vote;
+use cosmrs::cosmwasm::MsgExecuteContract;
+use cosmrs::tx::Msg;
+use cosmrs::Any;
+use error_stack::{report, Result, ResultExt};
+use ethers_core::types::TransactionReceipt;
+use ethers_providers::Http;
+use events::Event;
+use itertools::Itertools;
+use report::ResultCompatExt;
+use tendermint_rpc::query::Query;
+use tendermint_rpc::HttpClient;
+use tokio::sync::watch;
+use valuable::Valuable;
+
+use crate::commands::{verifier_account, verifier_pub_key};
+use crate::config::{Config, ReadOnlyConfig};
+use crate::event_processor::EventHandler;
+use crate::evm::json_rpc::EthereumClient;
+use crate::handlers::config::Config as HandlerConfig;
+use crate::handlers::vote_cache::VoteCache;
+use crate::json_rpc::replay::{Recorder, Recording};
+use crate::tm_client::TmClient;
+use crate::types::{EVMAddress, TMAddress};
+use crate::url::Url;
+use crate::{handlers, json_rpc, Error, DEFAULT_RPC_TIMEOUT};
+
+/// Types of the events that start a poll on the voting verifier
+const POLL_STARTED_EVENTS: [&str; 3] = [
+    "messages_poll_started",
+    "verifier_set_poll_started",
+    "messages_not_executed_poll_started",
+];
+
+#[derive(clap::Args, Debug, Valuable)]
+pub struct Args {
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(clap::Subcommand, Debug, Valuable)]
+pub enum Command {
+    /// Replay a single poll locally as the configured verifier: fetch the poll from the voting verifier, run the
+    /// verification of the configured handler and print the verdict for every item of the poll together with the
+    /// evidence it is based on. Only polls of EVM and Sui handlers can be replayed. Nothing is broadcast
+    VerifyPoll {
+        /// Id of the poll to replay
+        #[arg(long)]
+        poll_id: u64,
+        /// Address of the voting verifier contract that started the poll
+        #[arg(long)]
+        contract: String,
+        /// Save the responses of the chain's RPC endpoint to this file, so the poll can be replayed in handler tests
+        /// without network access
+        #[arg(long)]
+        record: Option<PathBuf>,
+    },
+}
+
+pub async fn run(config: Config, args: Args) -> Result<Option<String>, Error> {
+    match args.command {
+        Command::VerifyPoll {
+            poll_id,
+            contract,
+            record,
+        } => {
+            let contract = contract
+                .parse::<TMAddress>()
+                .change_context(Error::InvalidInput)
+                .attach_printable("invalid contract address")?;
+
+            let recording = Recording::default();
+            let report = verify_poll(config, poll_id, contract, &recording).await?;
+
+            if let Some(path) = record {
+                recording
+                    .cassette()
+                    .save(&path)
+                    .change_context(Error::InvalidInput)
+                    .attach_printable_lazy(|| {
+                        format!("failed to save RPC responses to {}", path.display())
+                    })?;
+            }
+
+            Ok(Some(report.to_string()))
+        }
+    }
+}
+
+async fn verify_poll(
+    config: Config,
+    poll_id: u64,
+    contract: TMAddress,
+    recording: &Recording,
+) -> Result<PollReport, Error> {
+    let event = poll_started_event(&config, poll_id, &contract).await?;
+
+    // the handlers only verify polls the verifier participates in
+    let verifier = configured_verifier(&config).await?;
+    if !participants(&event)?.contains(&verifier) {
+        return Err(report!(Error::InvalidInput).attach_printable(format!(
+            "verifier {} does not participate in poll {} of contract {}",
+            verifier, poll_id, contract
+        )));
+    }
+
+    for handler_config in config
+        .handlers
+        .into_iter()
+        .filter(|handler_config| handler_config.contracts().contains(&contract))
+    {
+        if let Some((handler, votes, evidence)) =
+            replay(handler_config, verifier.clone(), &event, recording).await?
+        {
+            return Ok(PollReport {
+                poll_id,
+                handler,
+                items: poll_items(&event)?,
+                votes,
+                evidence,
+            });
+        }
+    }
+
+    Err(report!(Error::InvalidInput).attach_printable(format!(
+        "no handler is configured to verify poll {} of contract {}",
+        poll_id, contract
+    )))
+}
+
+/// Returns the verifier that ampd votes as, i.e. the configured verifier in read-only mode
+/// or the account of the broadcaster key otherwise
+async fn configured_verifier(config: &Config) -> Result<TMAddress, Error> {
+    match &config.read_only {
+        Some(ReadOnlyConfig { verifier }) => Ok(verifier.clone()),
+        None => verifier_pub_key(config)
+            .await
+            .and_then(|pub_key| verifier_account(config, pub_key))
+            .map(Into::into),
+    }
+}
+
+async fn poll_started_event(
+    config: &Config,
+    poll_id: u64,
+    contract: &TMAddress,
+) -> Result<Event, Error> {
+    let tm_client = HttpClient::new(config.tm_jsonrpc.as_str())
+        .change_context(Error::Connection)
+        .attach_printable(config.tm_jsonrpc.clone())?;
+
+    for event_type in POLL_STARTED_EVENTS {
+        let event_type = format!("wasm-{}", event_type);
+        // poll ids are emitted as json strings
+        let query = Query::eq(
+            format!("{}.poll_id", event_type),
+            format!("\"{}\"", poll_id),
+        )
+        .and_eq(
+            format!("{}._contract_address", event_type),
+            contract.to_string(),
+        );
+
+        let txs = tm_client
+            .tx_search(query, 1, 1)
+            .await
+            .change_context(Error::Connection)
+            .attach_printable(config.tm_jsonrpc.clone())?
+            .txs;
+
+        let event = txs
+            .into_iter()
+            .flat_map(|tx| tx.tx_result.events)
+            .filter(|event| event.kind == event_type)
+            .map(Event::try_from)
+            .filter_map(std::result::Result::ok)
+            .find(|event| {
+                event.is_from_contract(contract.as_ref())
+                    && attribute::<String>(event, "poll_id").ok() == Some(poll_id.to_string())
+            });
+
+        if let Some(event) = event {
+            return Ok(event);
+        }
+    }
+
+    Err(report!(Error::InvalidInput).attach_printable(format!(
+        "poll {} of contract {} not found",
+        poll_id, contract
+    )))
+}
+
+/// Runs the given handler on the poll started event. Returns the name of the handler, its votes and the evidence
+/// the votes are based on, or None if the handler does not verify polls
+async fn replay(
+    handler_config: HandlerConfig,
+    verifier: TMAddress,
+    event: &Event,
+    recording: &Recording,
+) -> Result<Option<(String, Vec<Vote>, Vec<String>)>, Error> {
+    let (_, latest_block_height) = watch::channel(0);
+    let handler_type = handler_config.handler_type();
+
+    let (handler, msgs, evidence) = match handler_config {
+        HandlerConfig::EvmMsgVerifier {
+            cosmwasm_contract,
+            chain,
+            rpc_timeout,
+            ..
+        } => {
+            let msgs = handle(
+                handlers::evm_verify_msg::Handler::new(
+                    verifier,
+                    cosmwasm_contract,
+                    chain.name.clone(),
+                    chain.finalization,
+                    json_rpc_client(&chain.rpc_url, rpc_timeout, recording)?,
+                    latest_block_height,
+                    VoteCache::default(),
+                ),
+                event,
+            )
+            .await?;
+            let evidence = evm_evidence(
+                &json_rpc_client(&chain.rpc_url, rpc_timeout, recording)?,
+                event,
+            )
+            .await?;
+
+            (format!("{}-msg-verifier", chain.name), msgs, evidence)
+        }
+        HandlerConfig::EvmVerifierSetVerifier {
+            cosmwasm_contract,
+            chain,
+            rpc_timeout,
+        } => {
+            let msgs = handle(
+                handlers::evm_verify_verifier_set::Handler::new(
+                    verifier,
+                    cosmwasm_contract,
+                    chain.name.clone(),
+                    chain.finalization,
+                    json_rpc_client(&chain.rpc_url, rpc_timeout, recording)?,
+                    latest_block_height,
+                ),
+                event,
+            )
+            .await?;
+            let evidence = evm_evidence(
+                &json_rpc_client(&chain.rpc_url, rpc_timeout, recording)?,
+                event,
+            )
+            .await?;
+
+            (
+                format!("{}-verifier-set-verifier", chain.name),
+                msgs,
+                evidence,
+            )
+        }
+        HandlerConfig::SuiMsgVerifier {
+            cosmwasm_contract,
+            rpc_url,
+            rpc_timeout,
+        } => (
+            "sui-msg-verifier".to_string(),
+            handle(
+                handlers::sui_verify_msg::Handler::new(
+                    verifier,
+                    cosmwasm_contract,
+                    json_rpc_client(&rpc_url, rpc_timeout, recording)?,
+                    latest_block_height,
+                ),
+                event,
+            )
+            .await?,
+            recorded_evidence(recording),
+        ),
+        HandlerConfig::SuiVerifierSetVerifier {
+            cosmwasm_contract,
+            rpc_url,
+            rpc_timeout,
+        } => (
+            "sui-verifier-set-verifier".to_string(),
+            handle(
+                handlers::sui_verify_verifier_set::Handler::new(
+                    verifier,
+                    cosmwasm_contract,
+                    json_rpc_client(&rpc_url, rpc_timeout, recording)?,
+                    latest_block_height,
+                ),
+                event,
+            )
+            .await?,
+            recorded_evidence(recording),
+        ),
+        HandlerConfig::XRPLMsgVerifier { .. }
+        | HandlerConfig::MvxMsgVerifier { .. }
+        | HandlerConfig::MvxVerifierSetVerifier { .. }
+        | HandlerConfig::StellarMsgVerifier { .. }
+        | HandlerConfig::StellarVerifierSetVerifier { .. }
+        | HandlerConfig::StarknetMsgVerifier { .. }
+        | HandlerConfig::StarknetVerifierSetVerifier { .. }
+        | HandlerConfig::SolanaMsgVerifier { .. }
+        | HandlerConfig::SolanaVerifierSetVerifier { .. } => {
+            return Err(report!(Error::InvalidInput).attach_printable(format!(
+                "polls of {} handlers can't be replayed, because their RPC responses are not collected as evidence",
+                handler_type
+            )))
+        }
+        HandlerConfig::MultisigSigner { .. } | HandlerConfig::XRPLMultisigSigner { .. } => {
+            return Ok(None)
+        }
+    };
+
+    // handlers ignore events of other chains or poll types without producing a vote
+    match votes(msgs)? {
+        Some(votes) => Ok(Some((handler, votes, evidence))),
+        None => Ok(None),
+    }
+}
+
+async fn handle<H>(handler: H, event: &Event) -> Result<Vec<Any>, Error>
+where
+    H: EventHandler,
+{
+    handler
+        .handle(event)
+        .await
+        .change_context(Error::EventProcessor)
+}
+
+/// Extracts the votes from the vote message produced by a handler
+fn votes(msgs: Vec<Any>) -> Result<Option<Vec<Vote>>, Error> {
+    let Some(msg) = msgs.into_iter().next() else {
+        return Ok(None);
+    };
+
+    let msg = MsgExecuteContract::from_any(&msg).change_context(Error::InvalidInput)?;
+    match serde_json::from_slice(&msg.msg).change_context(Error::InvalidInput)? {
+        voting_verifier::msg::ExecuteMsg::Vote { votes, .. } => Ok(Some(votes)),
+        _ => Err(report!(Error::InvalidInput).attach_printable("handler did not produce a vote")),
+    }
+}
+
+/// Fetches the receipts of all transactions referenced by the poll together with the logs the gateway emitted in them
+async fn evm_evidence<C>(rpc_client: &C, event: &Event) -> Result<Vec<String>, Error>
+where
+    C: EthereumClient,
+{
+    let gateway: EVMAddress = attribute(event, "source_gateway_address")?;
+
+    let tx_hashes = poll_items(event)?
+        .iter()
+        .filter_map(|item| item.parse::<HexTxHashAndEventIndex>().ok())
+        .map(|msg_id| msg_id.tx_hash.into())
+        .unique()
+        .collect::<Vec<_>>();
+
+    let mut evidence = vec![];
+    for tx_hash in tx_hashes {
+        let receipt = rpc_client
+            .transaction_receipt(tx_hash)
+            .await
+            .change_context(Error::Connection)?;
+
+        evidence.push(match receipt {
+            Some(receipt) => describe_receipt(&receipt, &gateway),
+            None => format!("tx {:?}: receipt not found", tx_hash),
+        });
+    }
+
+    Ok(evidence)
+}
+
+/// Describes the JSON-RPC requests the handler sent to verify the poll together with the responses it got
+fn recorded_evidence(recording: &Recording) -> Vec<String> {
+    recording
+        .cassette()
+        .interactions
+        .iter()
+        .map(|interaction| {
+            format!(
+                "{} {}: {}",
+                interaction.method,
+                interaction.params,
+                serde_json::to_value(&interaction.outcome).unwrap_or_default()
+            )
+        })
+        .collect()
+}
+
+fn describe_receipt(receipt: &TransactionReceipt, gateway: &EVMAddress) -> String {
+    let logs = receipt
+        .logs
+        .iter()
+        .filter(|log| &log.address == gateway)
+        .map(|log| {
+            format!(
+                "\n    log {} with topics [{}]",
+                log.log_index.unwrap_or_default(),
+                log.topics
+                    .iter()
+                    .map(|topic| format!("{:?}", topic))
+                    .join(", ")
+            )
+        })
+        .join("");
+
+    format!(
+        "tx {:?}: block {}, status {}, {} of {} logs emitted by gateway {:?}{}",
+        receipt.transaction_hash,
+        receipt.block_number.unwrap_or_default(),
+        receipt.status.unwrap_or_default(),
+        receipt
+            .logs
+            .iter()
+            .filter(|log| &log.address == gateway)
+            .count(),
+        receipt.logs.len(),
+        gateway,
+        logs
+    )
+}
+
+fn participants(event: &Event) -> Result<Vec<TMAddress>, Error> {
+    attribute(event, "participants")
+}
+
+/// Returns the ids of the items that are voted on in the poll, in the order of the votes
+fn poll_items(event: &Event) -> Result<Vec<String>, Error> {
+    let message_id = |item: &serde_json::Value| {
+        item.get("message_id")
+            .and_then(serde_json::Value::as_str)
+            .map(ToString::to_string)
+            .unwrap_or_else(|| item.to_string())
+    };
+
+    if let Ok(messages) = attribute::<Vec<serde_json::Value>>(event, "messages") {
+        return Ok(messages.iter().map(message_id).collect());
+    }
+
+    attribute::<serde_json::Value>(event, "verifier_set").map(|item| vec![message_id(&item)])
+}
+
+fn attribute<T>(event: &Event, key: &str) -> Result<T, Error>
+where
+    T: serde::de::DeserializeOwned,
+{
+    match event {
+        Event::Abci { attributes, .. } => attributes
+            .get(key)
+            .cloned()
+            .ok_or(report!(Error::InvalidInput))
+            .and_then(|value| serde_json::from_value(value).change_context(Error::InvalidInput))
+            .attach_printable_lazy(|| format!("invalid attribute {} of poll started event", key)),
+        _ => Err(report!(Error::InvalidInput)),
+    }
+}
+
+fn http_client(rpc_timeout: Option<std::time::Duration>) -> Result<reqwest::Client, Error> {
+    reqwest::ClientBuilder::new()
+        .connect_timeout(rpc_timeout.unwrap_or(DEFAULT_RPC_TIMEOUT))
+        .timeout(rpc_timeout.unwrap_or(DEFAULT_RPC_TIMEOUT))
+        .build()
+        .change_context(Error::Connection)
+}
+
+/// Returns a JSON-RPC client whose responses are recorded, so they can be saved as a replay fixture
+fn json_rpc_client(
+    rpc_url: &Url,
+    rpc_timeout: Option<std::time::Duration>,
+    recording: &Recording,
+) -> Result<json_rpc::Client<Recorder<Http>>, Error> {
+    Ok(json_rpc::Client::new(recording.record(
+        Http::new_with_client(rpc_url, http_client(rpc_timeout)?),
+    )))
+}
+
+struct PollReport {
+    poll_id: u64,
+    handler: String,
+    items: Vec<String>,
+    votes: Vec<Vote>,
+    evidence: Vec<String>,
+}
+
+impl Display for PollReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "poll {} verified by handler {} (nothing was broadcast):",
+            self.poll_id, self.handler
+        )?;
+        for (item, vote) in self.items.iter().zip(&self.votes) {
+            writeln!(f, "{}: {}", item, vote.as_ref())?;
+        }
+
+        if self.evidence.is_empty() {
+            write!(f, "no evidence is collected for this handler")
+        } else {
+            write!(f, "evidence:")?;
+            for evidence in &self.evidence {
+                write!(f, "\n  {}", evidence)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmrs::cosmwasm::MsgExecuteContract;
+    use cosmrs::tx::Msg;
+    use ethers_core::types::{Log, TransactionReceipt, H256};
+    use events::Event;
+    use voting_verifier::events::{
+        PollMetadata, PollStarted, TxEventConfirmation, VerifierSetConfirmation,
+    };
+
+    use super::{describe_receipt, participants, poll_items, votes, PollReport};
+    use crate::handlers::tests::{
+        into_structured_event, participants as random_participants, voting_threshold,
+    };
+    use crate::types::{EVMAddress, TMAddress};
+    use crate::PREFIX;
+
+    fn metadata(participants: Vec<TMAddress>) -> PollMetadata {
+        PollMetadata {
+            poll_id: "100".parse().unwrap(),
+            source_chain: "ethereum".parse().unwrap(),
+            source_gateway_address: "0x4f4495243837681061c4743b74eedf548d5686a5"
+                .parse()
+                .unwrap(),
+            confirmation_height: 15,
+            expires_at: 100,
+            participants: participants
+                .into_iter()
+                .map(|addr| cosmwasm_std::Addr::unchecked(addr.to_string()))
+                .collect(),
+            voting_threshold: voting_threshold(),
+            observed_at: None,
+        }
+    }
+
+    #[allow(deprecated)] // TODO: The below event uses the deprecated tx_id and event_index fields. Remove this attribute when those fields are removed
+    fn messages_poll_started(participants: Vec<TMAddress>) -> Event {
+        let messages = ["0xff-0", "0xff-1"]
+            .into_iter()
+            .map(|msg_id| TxEventConfirmation {
+                tx_id: "0xff".parse().unwrap(),
+                event_index: 0,
+                message_id: msg_id.parse().unwrap(),
+                source_address: "0x1234".parse().unwrap(),
+                destination_chain: "avalanche".parse().unwrap(),
+                destination_address: "0x5678".parse().unwrap(),
+                payload_hash: [0; 32],
+            })
+            .collect();
+
+        into_structured_event(
+            PollStarted::Messages {
+                messages,
+                metadata: metadata(participants),
+            },
+            &TMAddress::random(PREFIX),
+        )
+    }
+
+    #[test]
+    fn poll_items_should_be_the_message_ids() {
+        let participants_ = random_participants(3, None);
+        let event = messages_poll_started(participants_.clone());
+
+        assert_eq!(poll_items(&event).unwrap(), vec!["0xff-0", "0xff-1"]);
+        assert_eq!(participants(&event).unwrap(), participants_);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn poll_items_should_be_the_verifier_set_message_id() {
+        let event = into_structured_event(
+            PollStarted::VerifierSet {
+                verifier_set: VerifierSetConfirmation {
+                    tx_id: "0xff".parse().unwrap(),
+                    event_index: 0,
+                    message_id: "0xff-3".parse().unwrap(),
+                    verifier_set: multisig::test::common::build_verifier_set(
+                        multisig::key::KeyType::Ecdsa,
+                        &multisig::test::common::ecdsa_test_data::signers(),
+                    ),
+                },
+                metadata: metadata(random_participants(3, None)),
+            },
+            &TMAddress::random(PREFIX),
+        );
+
+        assert_eq!(poll_items(&event).unwrap(), vec!["0xff-3"]);
+    }
+
+    #[test]
+    fn votes_should_be_extracted_from_vote_msg() {
+        let vote_msg = MsgExecuteContract {
+            sender: TMAddress::random(PREFIX).as_ref().clone(),
+            contract: TMAddress::random(PREFIX).as_ref().clone(),
+            msg: serde_json::to_vec(&voting_verifier::msg::ExecuteMsg::Vote {
+                poll_id: "100".parse().unwrap(),
+                votes: vec![
+                    axelar_wasm_std::voting::Vote::SucceededOnChain,
+                    axelar_wasm_std::voting::Vote::NotFound,
+                ],
+            })
+            .unwrap(),
+            funds: vec![],
+        }
+        .into_any()
+        .unwrap();
+
+        assert_eq!(votes(vec![]).unwrap(), None);
+        assert_eq!(
+            votes(vec![vote_msg]).unwrap(),
+            Some(vec![
+                axelar_wasm_std::voting::Vote::SucceededOnChain,
+                axelar_wasm_std::voting::Vote::NotFound,
+            ])
+        );
+    }
+
+    #[test]
+    fn report_should_list_verdicts_and_evidence() {
+        let gateway: EVMAddress = "0x4f4495243837681061c4743b74eedf548d5686a5"
+            .parse()
+            .unwrap();
+        let receipt = TransactionReceipt {
+            transaction_hash: H256::repeat_byte(0xff),
+            block_number: Some(10.into()),
+            status: Some(1.into()),
+            logs: vec![
+                Log {
+                    address: gateway,
+                    topics: vec![H256::repeat_byte(1)],
+                    log_index: Some(0.into()),
+                    ..Default::default()
+                },
+                Log {
+                    address: EVMAddress::random(),
+                    log_index: Some(1.into()),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+
+        let report = PollReport {
+            poll_id: 100,
+            handler: "ethereum-msg-verifier".to_string(),
+            items: vec!["0xff-0".to_string(), "0xff-1".to_string()],
+            votes: vec![
+                axelar_wasm_std::voting::Vote::SucceededOnChain,
+                axelar_wasm_std::voting::Vote::NotFound,
+            ],
+            evidence: vec![describe_receipt(&receipt, &gateway)],
+        };
+
+        goldie::assert!(report.to_string());
+    }
+}
@@ -1,29 +1,38 @@
 use clap::Subcommand;
 use cosmrs::proto::cosmos::base::abci::v1beta1::TxResponse;
+use cosmrs::proto::cosmwasm::wasm::v1::query_client::QueryClient;
 use cosmrs::proto::Any;
 use cosmrs::AccountId;
 use error_stack::{report, FutureExt, Result, ResultExt};
 use futures::TryFutureExt;
+use report::ResultCompatExt;
 use serde::{Deserialize, Serialize};
+use tonic::transport::Channel;
 use valuable::Valuable;
 
 use crate::asyncutil::future::RetryPolicy;
 use crate::broadcaster::confirm_tx::TxConfirmer;
 use crate::broadcaster::Broadcaster;
 use crate::config::{Config as AmpdConfig, Config};
-use crate::tofnd::grpc::{Multisig, MultisigClient};
+use crate::signer::Signer;
+use crate::tofnd::grpc::Multisig;
 use crate::types::{CosmosPublicKey, TMAddress};
 use crate::{broadcaster, cosmos, tofnd, Error, PREFIX};
 
 pub mod bond_verifier;
 pub mod claim_stake;
 pub mod daemon;
+pub mod debug;
 pub mod deregister_chain_support;
+pub mod onboard;
 pub mod register_chain_support;
 pub mod register_public_key;
+pub mod rewards;
+pub mod rotate_broadcaster_key;
 pub mod send_tokens;
 pub mod set_rewards_proxy;
 pub mod unbond_verifier;
+pub mod validate_config;
 pub mod verifier_address;
 
 #[derive(Debug, Subcommand, Valuable)]
@@ -42,12 +51,23 @@ pub enum SubCommand {
     DeregisterChainSupport(deregister_chain_support::Args),
     /// Register public key to the multisig contract
     RegisterPublicKey(register_public_key::Args),
+    /// Walk through bonding, registering chain support, registering public keys and setting the rewards proxy in a
+    /// single transaction, then check the verifier's end state in the contracts
+    Onboard(onboard::Args),
     /// Query the verifier address
     VerifierAddress,
     /// Send tokens from the verifier account to a specified address
     SendTokens(send_tokens::Args),
     /// Set a proxy address to receive rewards, instead of receiving rewards at the verifier address
     SetRewardsProxy(set_rewards_proxy::Args),
+    /// Inspect the verifier's standing in the rewards contract
+    Rewards(rewards::Args),
+    /// Rotate the broadcaster key by authorizing a new tofnd key to broadcast on behalf of the verifier
+    RotateBroadcasterKey(rotate_broadcaster_key::Args),
+    /// Inspect the ampd config
+    Config(validate_config::Args),
+    /// Debug the verification of polls without broadcasting any votes
+    Debug(debug::Args),
 }
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
@@ -76,22 +96,61 @@ impl Default for RewardsConfig {
     }
 }
 
-async fn verifier_pub_key(config: tofnd::Config) -> Result<CosmosPublicKey, Error> {
-    let pub_key = MultisigClient::new(config.party_uid, config.url.as_str(), config.timeout)
-        .await
-        .change_context(Error::Connection)
-        .attach_printable(config.url.clone())?
-        .keygen(&config.key_uid, tofnd::Algorithm::Ecdsa)
+async fn verifier_pub_key(config: &AmpdConfig) -> Result<CosmosPublicKey, Error> {
+    broadcaster_pub_key(config, &config.tofnd_config.key_uid).await
+}
+
+/// Returns the public key of a broadcaster key, which is held by tofnd unless it is configured to be held by the HSM
+async fn broadcaster_pub_key(config: &AmpdConfig, key_uid: &str) -> Result<CosmosPublicKey, Error> {
+    let pub_key = signer(config)
+        .await?
+        .keygen(key_uid, tofnd::Algorithm::Ecdsa)
         .await
         .change_context(Error::Tofnd)?;
 
     CosmosPublicKey::try_from(pub_key).change_context(Error::Tofnd)
 }
 
+async fn signer(config: &AmpdConfig) -> Result<Signer, Error> {
+    Signer::connect(&config.tofnd_config, config.pkcs11.as_ref())
+        .await
+        .change_context(Error::Connection)
+}
+
+/// Returns the account of the verifier. After a broadcaster key rotation this is the authz granter,
+/// otherwise it is the account of the given public key
+fn verifier_account(config: &AmpdConfig, pub_key: CosmosPublicKey) -> Result<AccountId, Error> {
+    match &config.broadcast.authz_granter {
+        Some(granter) => Ok(granter.as_ref().clone()),
+        None => pub_key.account_id(PREFIX).change_context(Error::Tofnd),
+    }
+}
+
+pub(crate) async fn wasm_query_client(config: &AmpdConfig) -> Result<QueryClient<Channel>, Error> {
+    let channel = tonic::transport::Endpoint::from_shared(config.tm_grpc.to_string())
+        .change_context(Error::Connection)?
+        .timeout(config.tm_grpc_timeout)
+        .connect_timeout(config.tm_grpc_timeout)
+        .connect()
+        .await
+        .change_context(Error::Connection)
+        .attach_printable(config.tm_grpc.clone())?;
+
+    Ok(QueryClient::new(channel))
+}
+
 async fn broadcast_tx(
     config: AmpdConfig,
     tx: Any,
     pub_key: CosmosPublicKey,
+) -> Result<TxResponse, Error> {
+    broadcast_msgs(config, vec![tx], pub_key).await
+}
+
+async fn broadcast_msgs(
+    config: AmpdConfig,
+    msgs: Vec<Any>,
+    pub_key: CosmosPublicKey,
 ) -> Result<TxResponse, Error> {
     let (confirmation_sender, mut confirmation_receiver) = tokio::sync::mpsc::channel(1);
     let (hash_to_confirm_sender, hash_to_confirm_receiver) = tokio::sync::mpsc::channel(1);
@@ -99,7 +158,7 @@ async fn broadcast_tx(
     let (mut broadcaster, confirmer) = instantiate_broadcaster(config, pub_key).await?;
 
     broadcaster
-        .broadcast(vec![tx])
+        .broadcast(msgs)
         .change_context(Error::Broadcaster)
         .and_then(|response| {
             hash_to_confirm_sender
@@ -127,6 +186,7 @@ async fn instantiate_broadcaster(
     config: Config,
     pub_key: CosmosPublicKey,
 ) -> Result<(impl Broadcaster, TxConfirmer<cosmos::CosmosGrpcClient>), Error> {
+    let signer = signer(&config).await?;
     let AmpdConfig {
         tm_grpc,
         tm_grpc_timeout,
@@ -138,14 +198,6 @@ async fn instantiate_broadcaster(
         .await
         .change_context(Error::Connection)
         .attach_printable(tm_grpc.clone())?;
-    let multisig_client = MultisigClient::new(
-        tofnd_config.party_uid,
-        tofnd_config.url.as_str(),
-        tofnd_config.timeout,
-    )
-    .await
-    .change_context(Error::Connection)
-    .attach_printable(tofnd_config.url)?;
 
     let confirmer = TxConfirmer::new(
         cosmos_client.clone(),
@@ -157,7 +209,7 @@ async fn instantiate_broadcaster(
 
     let basic_broadcaster = broadcaster::UnvalidatedBasicBroadcaster::builder()
         .client(cosmos_client)
-        .signer(multisig_client)
+        .signer(signer)
         .pub_key((tofnd_config.key_uid, pub_key))
         .config(broadcast)
         .address_prefix(PREFIX.to_string())
@@ -0,0 +1,769 @@
+use std::fmt::{Display, Formatter};
+use std::io::{self, BufRead, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use ::config::{Config as Builder, File, FileFormat};
+use axelar_wasm_std::nonempty;
+use clap::ValueEnum;
+use cosmrs::cosmwasm::MsgExecuteContract;
+use cosmrs::proto::cosmwasm::wasm::v1::query_client::QueryClient;
+use cosmrs::proto::cosmwasm::wasm::v1::QuerySmartContractStateRequest;
+use cosmrs::proto::Any;
+use cosmrs::tx::Msg;
+use cosmrs::{AccountId, Coin};
+use cosmwasm_std::Addr;
+use error_stack::{report, Result, ResultExt};
+use itertools::Itertools;
+use multisig::key::PublicKey;
+use multisig::msg::QueryMsg as MultisigQueryMsg;
+use report::ResultCompatExt;
+use rewards::msg::{ExecuteMsg as RewardsExecuteMsg, QueryMsg as RewardsQueryMsg};
+use router_api::{Address, ChainName};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use service_registry_api::msg::{
+    ExecuteMsg as ServiceRegistryExecuteMsg, QueryMsg as ServiceRegistryQueryMsg, VerifierDetails,
+};
+use service_registry_api::{AuthorizationState, BondingState};
+use tonic::transport::Channel;
+use tracing::info;
+use valuable::Valuable;
+
+use crate::commands::register_public_key::{multisig_address, register_public_key_msg, KeyType};
+use crate::commands::validate_config::{Check, Status};
+use crate::commands::{broadcast_msgs, verifier_account, verifier_pub_key, wasm_query_client};
+use crate::config::Config;
+use crate::types::TMAddress;
+use crate::Error;
+
+#[derive(clap::Args, Debug, Valuable)]
+pub struct Args {
+    /// Path of a YAML file that declares the onboarding steps. If not set, the steps are prompted for interactively
+    #[arg(long)]
+    pub plan: Option<PathBuf>,
+    /// Broadcast the onboarding transaction without asking for confirmation
+    #[arg(long)]
+    pub yes: bool,
+}
+
+/// Declares the state the verifier should end up in. Steps that are already done are skipped, so the same plan can be
+/// applied again after a partial failure
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct Plan {
+    pub service_name: nonempty::String,
+    /// Stake to bond, only bonded if the verifier isn't bonded yet
+    pub bond: Option<Bond>,
+    #[serde(default)]
+    pub chains: Vec<ChainName>,
+    /// Types of the multisig keys to generate in tofnd and register with the multisig contract
+    #[serde(default)]
+    pub public_keys: Vec<KeyType>,
+    /// Address that receives the verifier's rewards instead of the verifier address
+    pub rewards_proxy: Option<Address>,
+    /// Address of the coordinator contract, only used to check the verifier's chain support
+    pub coordinator: Option<TMAddress>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct Bond {
+    pub amount: u128,
+    pub denom: String,
+}
+
+/// What the verifier has already set up according to the contracts
+#[derive(Debug, Default)]
+struct VerifierState {
+    bonded: bool,
+    supported_chains: Vec<ChainName>,
+    public_keys: Vec<KeyType>,
+    rewards_proxy: Option<Addr>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Step {
+    Bond(Bond),
+    RegisterChainSupport(Vec<ChainName>),
+    RegisterPublicKey(KeyType),
+    SetRewardsProxy(Address),
+}
+
+impl Display for Step {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Step::Bond(bond) => write!(f, "bond {}{}", bond.amount, bond.denom),
+            Step::RegisterChainSupport(chains) => {
+                write!(f, "register support for {}", chains.iter().join(", "))
+            }
+            Step::RegisterPublicKey(key_type) => {
+                write!(f, "register {:?} public key", key_type)
+            }
+            Step::SetRewardsProxy(proxy) => write!(f, "set rewards proxy to {}", proxy),
+        }
+    }
+}
+
+/// Contracts the verifier is onboarded to
+struct Contracts {
+    service_registry: TMAddress,
+    rewards: TMAddress,
+    multisig: Option<TMAddress>,
+    coordinator: Option<TMAddress>,
+}
+
+/// Subset of the coordinator's verifier info that is checked. ampd doesn't depend on the coordinator contract
+#[derive(Deserialize)]
+struct CoordinatorVerifierInfo {
+    supported_chains: Vec<ChainName>,
+}
+
+struct OnboardingReport {
+    tx_hash: Option<String>,
+    checks: Vec<Check>,
+}
+
+impl OnboardingReport {
+    fn has_failures(&self) -> bool {
+        self.checks.iter().any(|check| check.status == Status::Fail)
+    }
+}
+
+impl Display for OnboardingReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "onboarding report:")?;
+        match &self.tx_hash {
+            Some(tx_hash) => writeln!(f, "broadcast onboarding transaction, tx hash: {}", tx_hash)?,
+            None => writeln!(f, "all steps were done already, nothing was broadcast")?,
+        }
+        for check in &self.checks {
+            writeln!(f, "{}", check)?;
+        }
+
+        let failures = self
+            .checks
+            .iter()
+            .filter(|check| check.status == Status::Fail)
+            .count();
+        write!(f, "{} of {} checks failed", failures, self.checks.len())
+    }
+}
+
+pub async fn run(config: Config, args: Args) -> Result<Option<String>, Error> {
+    let plan = match &args.plan {
+        Some(path) => load_plan(path)?,
+        None => prompt_plan()?,
+    };
+
+    let pub_key = verifier_pub_key(&config).await?;
+    let verifier = verifier_account(&config, pub_key)?;
+    let contracts = Contracts {
+        service_registry: config.service_registry.cosmwasm_contract.clone(),
+        rewards: config.rewards.cosmwasm_contract.clone(),
+        multisig: multisig_address(&config).ok(),
+        coordinator: plan.coordinator.clone(),
+    };
+    let mut client = wasm_query_client(&config).await?;
+
+    let state = query_state(&mut client, &contracts, &plan, &verifier).await?;
+    let steps = pending_steps(&plan, &state);
+
+    let tx_hash = if steps.is_empty() {
+        None
+    } else {
+        if !args.yes && !confirm(&steps)? {
+            return Ok(Some(
+                "onboarding aborted, nothing was broadcast".to_string(),
+            ));
+        }
+
+        let mut msgs = vec![];
+        for step in steps {
+            info!(step = step.to_string(), "preparing onboarding step");
+            msgs.push(step_msg(&config, &contracts, &plan, verifier.clone(), step).await?);
+        }
+
+        Some(broadcast_msgs(config, msgs, pub_key).await?.txhash)
+    };
+
+    let report = OnboardingReport {
+        tx_hash,
+        checks: validate(&mut client, &contracts, &plan, &verifier).await,
+    };
+
+    if report.has_failures() {
+        Err(report!(Error::Onboarding).attach_printable(report.to_string()))
+    } else {
+        Ok(Some(report.to_string()))
+    }
+}
+
+fn load_plan(path: &Path) -> Result<Plan, Error> {
+    Builder::builder()
+        .add_source(File::from(path).format(FileFormat::Yaml))
+        .build()
+        .and_then(|plan| plan.try_deserialize::<Plan>())
+        .change_context(Error::InvalidInput)
+        .attach_printable(format!("invalid onboarding plan {}", path.display()))
+}
+
+fn prompt_plan() -> Result<Plan, Error> {
+    let service_name =
+        nonempty::String::try_from(prompt("service name")?).change_context(Error::InvalidInput)?;
+
+    let bond = match prompt("amount to bond, leave empty if the verifier is bonded already")? {
+        amount if amount.is_empty() => None,
+        amount => Some(Bond {
+            amount: amount.parse().change_context(Error::InvalidInput)?,
+            denom: prompt("denom of the bond")?,
+        }),
+    };
+
+    let chains = prompt("chains to support, separated by spaces")?
+        .split_whitespace()
+        .map(ChainName::from_str)
+        .try_collect()
+        .change_context(Error::InvalidInput)?;
+
+    let public_keys =
+        prompt("types of the multisig keys to register (ecdsa, ed25519), separated by spaces")?
+            .split_whitespace()
+            .map(|key_type| {
+                KeyType::from_str(key_type, true)
+                    .map_err(|err| report!(Error::InvalidInput).attach_printable(err))
+            })
+            .try_collect()?;
+
+    let rewards_proxy = optional(prompt(
+        "address to receive rewards, leave empty to receive them at the verifier address",
+    )?)
+    .map(|proxy| proxy.parse::<Address>())
+    .transpose()
+    .change_context(Error::InvalidInput)?;
+
+    let coordinator = optional(prompt(
+        "address of the coordinator contract to check, leave empty to skip the check",
+    )?)
+    .map(|coordinator| coordinator.parse::<TMAddress>())
+    .transpose()
+    .change_context(Error::InvalidInput)?;
+
+    Ok(Plan {
+        service_name,
+        bond,
+        chains,
+        public_keys,
+        rewards_proxy,
+        coordinator,
+    })
+}
+
+fn confirm(steps: &[Step]) -> Result<bool, Error> {
+    println!("the following steps are broadcast in a single transaction:");
+    for step in steps {
+        println!("- {}", step);
+    }
+
+    Ok(prompt("continue? [y/N]")?.eq_ignore_ascii_case("y"))
+}
+
+fn prompt(question: &str) -> Result<String, Error> {
+    print!("{}: ", question);
+    io::stdout().flush().change_context(Error::InvalidInput)?;
+
+    let mut answer = String::new();
+    io::stdin()
+        .lock()
+        .read_line(&mut answer)
+        .change_context(Error::InvalidInput)?;
+
+    Ok(answer.trim().to_string())
+}
+
+fn optional(answer: String) -> Option<String> {
+    Some(answer).filter(|answer| !answer.is_empty())
+}
+
+/// Queries what the verifier has already set up. Unknown verifiers and keys count as not set up, so the
+/// corresponding step is attempted again. Any other failure aborts onboarding, because the state is unknown
+async fn query_state(
+    client: &mut QueryClient<Channel>,
+    contracts: &Contracts,
+    plan: &Plan,
+    verifier: &AccountId,
+) -> Result<VerifierState, Error> {
+    let details = query_verifier_details(client, contracts, plan, verifier).await?;
+
+    let mut public_keys = vec![];
+    for key_type in &plan.public_keys {
+        if query_public_key(client, contracts, verifier, *key_type)
+            .await?
+            .is_some()
+        {
+            public_keys.push(*key_type);
+        }
+    }
+
+    Ok(VerifierState {
+        bonded: details.as_ref().is_some_and(|details| {
+            matches!(details.verifier.bonding_state, BondingState::Bonded { .. })
+        }),
+        supported_chains: details
+            .map(|details| details.supported_chains)
+            .unwrap_or_default(),
+        public_keys,
+        rewards_proxy: query_rewards_proxy(client, contracts, verifier).await?,
+    })
+}
+
+fn pending_steps(plan: &Plan, state: &VerifierState) -> Vec<Step> {
+    let mut steps = vec![];
+
+    if let Some(bond) = plan.bond.as_ref().filter(|_| !state.bonded) {
+        steps.push(Step::Bond(bond.clone()));
+    }
+
+    let chains: Vec<_> = plan
+        .chains
+        .iter()
+        .filter(|chain| !state.supported_chains.contains(*chain))
+        .unique()
+        .cloned()
+        .collect();
+    if !chains.is_empty() {
+        steps.push(Step::RegisterChainSupport(chains));
+    }
+
+    steps.extend(
+        plan.public_keys
+            .iter()
+            .filter(|key_type| !state.public_keys.contains(*key_type))
+            .unique()
+            .map(|key_type| Step::RegisterPublicKey(*key_type)),
+    );
+
+    if let Some(proxy) = &plan.rewards_proxy {
+        if state.rewards_proxy.as_ref().map(Addr::as_str) != Some(proxy.as_str()) {
+            steps.push(Step::SetRewardsProxy(proxy.clone()));
+        }
+    }
+
+    steps
+}
+
+async fn step_msg(
+    config: &Config,
+    contracts: &Contracts,
+    plan: &Plan,
+    sender: AccountId,
+    step: Step,
+) -> Result<Any, Error> {
+    let (contract, msg, funds) = match step {
+        Step::Bond(bond) => (
+            &contracts.service_registry,
+            serde_json::to_vec(&ServiceRegistryExecuteMsg::BondVerifier {
+                service_name: plan.service_name.to_string(),
+            }),
+            vec![Coin::new(bond.amount, bond.denom.as_str()).change_context(Error::InvalidInput)?],
+        ),
+        Step::RegisterChainSupport(chains) => (
+            &contracts.service_registry,
+            serde_json::to_vec(&ServiceRegistryExecuteMsg::RegisterChainSupport {
+                service_name: plan.service_name.to_string(),
+                chains,
+            }),
+            vec![],
+        ),
+        Step::RegisterPublicKey(key_type) => {
+            return register_public_key_msg(config, sender, key_type).await
+        }
+        Step::SetRewardsProxy(proxy_address) => (
+            &contracts.rewards,
+            serde_json::to_vec(&RewardsExecuteMsg::SetVerifierProxy { proxy_address }),
+            vec![],
+        ),
+    };
+
+    Ok(MsgExecuteContract {
+        sender,
+        contract: contract.as_ref().clone(),
+        msg: msg.expect("onboarding msg should serialize"),
+        funds,
+    }
+    .into_any()
+    .expect("failed to serialize proto message"))
+}
+
+/// Checks the end state of the verifier against the plan
+async fn validate(
+    client: &mut QueryClient<Channel>,
+    contracts: &Contracts,
+    plan: &Plan,
+    verifier: &AccountId,
+) -> Vec<Check> {
+    let mut checks = vec![];
+
+    match query_verifier_details(client, contracts, plan, verifier).await {
+        Ok(Some(details)) => {
+            checks.push(match details.verifier.bonding_state {
+                BondingState::Bonded { amount } => {
+                    Check::pass("bond", format!("verifier is bonded with {}", amount))
+                }
+                state => Check::fail(
+                    "bond",
+                    format!(
+                        "verifier is not bonded but {:?}. set bond in the plan to bond the verifier",
+                        state
+                    ),
+                ),
+            });
+
+            checks.push(match details.verifier.authorization_state {
+                AuthorizationState::Authorized => {
+                    Check::pass("authorization", "verifier is authorized")
+                }
+                state => Check::skip(
+                    "authorization",
+                    format!(
+                        "verifier is {:?}. authorization is done by governance or the network operators",
+                        state
+                    ),
+                ),
+            });
+
+            checks.push(check_chains(
+                "chain support in the service registry",
+                &plan.chains,
+                &details.supported_chains,
+            ));
+        }
+        Ok(None) => checks.push(Check::fail(
+            "service registry",
+            format!(
+                "verifier not found. check that the verifier is bonded to service {}",
+                plan.service_name
+            ),
+        )),
+        Err(err) => checks.push(Check::fail(
+            "service registry",
+            format!(
+                "{err:#}. check that the verifier is bonded to service {}",
+                plan.service_name
+            ),
+        )),
+    }
+
+    for key_type in plan.public_keys.iter().unique() {
+        checks.push(Check::from_result(
+            format!("{:?} public key", key_type),
+            query_public_key(client, contracts, verifier, *key_type)
+                .await
+                .and_then(|public_key| {
+                    public_key
+                        .map(|public_key| format!("registered {:?}", public_key))
+                        .ok_or(report!(Error::InvalidInput))
+                        .attach_printable("public key not found")
+                }),
+            "check that a multisig handler is configured and the key is registered with the multisig contract",
+        ));
+    }
+
+    if let Some(proxy) = &plan.rewards_proxy {
+        checks.push(
+            match query_rewards_proxy(client, contracts, verifier).await {
+                Ok(Some(actual)) if actual.as_str() == proxy.as_str() => {
+                    Check::pass("rewards proxy", format!("rewards are sent to {}", actual))
+                }
+                Ok(actual) => Check::fail(
+                    "rewards proxy",
+                    format!("expected rewards proxy {} but found {:?}", proxy, actual),
+                ),
+                Err(err) => Check::fail("rewards proxy", format!("{err:#}")),
+            },
+        );
+    }
+
+    match &contracts.coordinator {
+        Some(coordinator) => {
+            let info: Result<CoordinatorVerifierInfo, Error> = query(
+                client,
+                coordinator,
+                &serde_json::json!({
+                    "verifier_info": {
+                        "service_name": plan.service_name.to_string(),
+                        "verifier": verifier.to_string(),
+                    }
+                }),
+            )
+            .await;
+
+            checks.push(match info {
+                Ok(info) => check_chains(
+                    "chain support in the coordinator",
+                    &plan.chains,
+                    &info.supported_chains,
+                ),
+                Err(err) => Check::fail(
+                    "coordinator",
+                    format!("{err:#}. check that the coordinator address is correct"),
+                ),
+            });
+        }
+        None => checks.push(Check::skip(
+            "coordinator",
+            "no coordinator address provided in the plan",
+        )),
+    }
+
+    checks
+}
+
+fn check_chains(item: &str, expected: &[ChainName], supported: &[ChainName]) -> Check {
+    let missing = expected
+        .iter()
+        .filter(|chain| !supported.contains(*chain))
+        .join(", ");
+
+    if missing.is_empty() {
+        Check::pass(
+            item,
+            format!("supported chains: {}", supported.iter().join(", ")),
+        )
+    } else {
+        Check::fail(item, format!("chains {} are not supported", missing))
+    }
+}
+
+async fn query_verifier_details(
+    client: &mut QueryClient<Channel>,
+    contracts: &Contracts,
+    plan: &Plan,
+    verifier: &AccountId,
+) -> Result<Option<VerifierDetails>, Error> {
+    query_optional(
+        client,
+        &contracts.service_registry,
+        &ServiceRegistryQueryMsg::Verifier {
+            service_name: plan.service_name.to_string(),
+            verifier: verifier.to_string(),
+        },
+    )
+    .await
+}
+
+async fn query_public_key(
+    client: &mut QueryClient<Channel>,
+    contracts: &Contracts,
+    verifier: &AccountId,
+    key_type: KeyType,
+) -> Result<Option<PublicKey>, Error> {
+    let multisig = contracts
+        .multisig
+        .as_ref()
+        .ok_or(report!(Error::InvalidInput))
+        .attach_printable("no multisig contract found in config")?;
+
+    query_optional(
+        client,
+        multisig,
+        &MultisigQueryMsg::PublicKey {
+            verifier_address: verifier.to_string(),
+            key_type: key_type.into(),
+        },
+    )
+    .await
+}
+
+async fn query_rewards_proxy(
+    client: &mut QueryClient<Channel>,
+    contracts: &Contracts,
+    verifier: &AccountId,
+) -> Result<Option<Addr>, Error> {
+    let verifier = verifier
+        .to_string()
+        .parse::<Address>()
+        .change_context(Error::InvalidInput)?;
+
+    query(
+        client,
+        &contracts.rewards,
+        &RewardsQueryMsg::VerifierProxy { verifier },
+    )
+    .await
+}
+
+async fn query<T: DeserializeOwned>(
+    client: &mut QueryClient<Channel>,
+    contract: &TMAddress,
+    msg: &impl Serialize,
+) -> Result<T, Error> {
+    query_optional(client, contract, msg)
+        .await?
+        .ok_or(report!(Error::InvalidInput))
+        .attach_printable(format!("contract {} returned not found", contract))
+}
+
+/// Returns None if the contract reports that the queried entry doesn't exist
+async fn query_optional<T: DeserializeOwned>(
+    client: &mut QueryClient<Channel>,
+    contract: &TMAddress,
+    msg: &impl Serialize,
+) -> Result<Option<T>, Error> {
+    let res = client
+        .smart_contract_state(QuerySmartContractStateRequest {
+            address: contract.to_string(),
+            query_data: serde_json::to_vec(msg).expect("onboarding query should serialize"),
+        })
+        .await;
+
+    let data = match res {
+        Ok(res) => res.into_inner().data,
+        Err(status) if is_not_found(&status) => return Ok(None),
+        Err(status) => {
+            return Err(report!(status))
+                .change_context(Error::Connection)
+                .attach_printable(format!("failed to query contract {}", contract))
+        }
+    };
+
+    serde_json::from_slice(&data)
+        .map(Some)
+        .change_context(Error::InvalidInput)
+        .attach_printable(format!("unexpected response from contract {}", contract))
+}
+
+/// The wasm module returns contract errors with code Unknown. Contracts fail queries for unknown entries with a
+/// not found error, e.g. for verifiers that never bonded or keys that were never registered
+fn is_not_found(status: &tonic::Status) -> bool {
+    status.code() == tonic::Code::Unknown && status.message().contains("not found")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PREFIX;
+
+    fn plan() -> Plan {
+        Plan {
+            service_name: "amplifier".try_into().unwrap(),
+            bond: Some(Bond {
+                amount: 1000000,
+                denom: "uaxl".to_string(),
+            }),
+            chains: vec!["ethereum".parse().unwrap(), "avalanche".parse().unwrap()],
+            public_keys: vec![KeyType::Ecdsa],
+            rewards_proxy: Some("axelar1proxy".parse().unwrap()),
+            coordinator: None,
+        }
+    }
+
+    #[test]
+    fn plan_should_be_parsed_from_yaml() {
+        let coordinator = TMAddress::random(PREFIX);
+        let yaml = format!(
+            r#"
+service_name: amplifier
+bond:
+  amount: 1000000
+  denom: uaxl
+chains:
+  - ethereum
+  - avalanche
+public_keys:
+  - ecdsa
+rewards_proxy: axelar1proxy
+coordinator: {}
+"#,
+            coordinator
+        );
+
+        let parsed = Builder::builder()
+            .add_source(File::from_str(&yaml, FileFormat::Yaml))
+            .build()
+            .unwrap()
+            .try_deserialize::<Plan>()
+            .unwrap();
+
+        assert_eq!(
+            parsed,
+            Plan {
+                coordinator: Some(coordinator),
+                ..plan()
+            }
+        );
+    }
+
+    #[test]
+    fn plan_should_only_require_service_name() {
+        let parsed = Builder::builder()
+            .add_source(File::from_str("service_name: amplifier", FileFormat::Yaml))
+            .build()
+            .unwrap()
+            .try_deserialize::<Plan>()
+            .unwrap();
+
+        assert_eq!(
+            parsed,
+            Plan {
+                service_name: "amplifier".try_into().unwrap(),
+                bond: None,
+                chains: vec![],
+                public_keys: vec![],
+                rewards_proxy: None,
+                coordinator: None,
+            }
+        );
+    }
+
+    #[test]
+    fn pending_steps_should_include_all_steps_for_new_verifier() {
+        let plan = plan();
+
+        assert_eq!(
+            pending_steps(&plan, &VerifierState::default()),
+            vec![
+                Step::Bond(plan.bond.clone().unwrap()),
+                Step::RegisterChainSupport(plan.chains.clone()),
+                Step::RegisterPublicKey(KeyType::Ecdsa),
+                Step::SetRewardsProxy(plan.rewards_proxy.clone().unwrap()),
+            ]
+        );
+    }
+
+    #[test]
+    fn pending_steps_should_skip_steps_that_are_done() {
+        let plan = plan();
+        let state = VerifierState {
+            bonded: true,
+            supported_chains: vec!["ethereum".parse().unwrap()],
+            public_keys: vec![KeyType::Ecdsa],
+            rewards_proxy: Some(Addr::unchecked("axelar1proxy")),
+        };
+
+        assert_eq!(
+            pending_steps(&plan, &state),
+            vec![Step::RegisterChainSupport(vec!["avalanche"
+                .parse()
+                .unwrap()])]
+        );
+
+        let state = VerifierState {
+            supported_chains: plan.chains.clone(),
+            ..state
+        };
+        assert_eq!(pending_steps(&plan, &state), vec![]);
+    }
+
+    #[test]
+    fn only_missing_entries_should_count_as_not_found() {
+        assert!(is_not_found(&tonic::Status::unknown(
+            "query wasm contract failed: verifier not found"
+        )));
+        assert!(!is_not_found(&tonic::Status::unknown(
+            "query wasm contract failed: invalid address"
+        )));
+        assert!(!is_not_found(&tonic::Status::unavailable(
+            "connection refused"
+        )));
+        assert!(!is_not_found(&tonic::Status::not_found("unknown service")));
+    }
+}
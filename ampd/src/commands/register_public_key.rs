@@ -1,24 +1,26 @@
-use std::convert::{TryFrom, TryInto};
+use std::convert::TryFrom;
 
 use cosmrs::cosmwasm::MsgExecuteContract;
+use cosmrs::proto::Any;
 use cosmrs::tx::Msg;
+use cosmrs::AccountId;
 use error_stack::{Result, ResultExt};
-use multisig::key::PublicKey;
+use multisig::key::{registration_challenge, PublicKey};
 use multisig::msg::ExecuteMsg;
-use report::ResultCompatExt;
-use sha3::{Digest, Keccak256};
+use serde::Deserialize;
 use tracing::info;
 use valuable::Valuable;
 
-use crate::commands::{broadcast_tx, verifier_pub_key};
+use crate::commands::{broadcast_tx, signer, verifier_account, verifier_pub_key};
 use crate::config::Config;
-use crate::tofnd::grpc::{Multisig, MultisigClient};
+use crate::tofnd::grpc::Multisig;
 use crate::tofnd::{self};
 use crate::types::TMAddress;
-use crate::{handlers, Error, PREFIX};
+use crate::{handlers, Error};
 
-#[derive(clap::ValueEnum, Clone, Debug, Valuable, Copy)]
-enum KeyType {
+#[derive(clap::ValueEnum, Clone, Debug, Deserialize, Valuable, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum KeyType {
     Ecdsa,
     Ed25519,
 }
@@ -47,70 +49,71 @@ pub struct Args {
 }
 
 pub async fn run(config: Config, args: Args) -> Result<Option<String>, Error> {
-    let pub_key = verifier_pub_key(config.tofnd_config.clone()).await?;
-
-    let multisig_address = multisig_address(&config)?;
-
-    let tofnd_config = config.tofnd_config.clone();
-
-    let multisig_client = MultisigClient::new(
-        tofnd_config.party_uid,
-        tofnd_config.url.as_str(),
-        tofnd_config.timeout,
-    )
-    .await
-    .change_context(Error::Connection)
-    .attach_printable(tofnd_config.url)?;
-    let multisig_key = multisig_client
-        .keygen(&multisig_address.to_string(), args.key_type.into())
+    let pub_key = verifier_pub_key(&config).await?;
+    let sender = verifier_account(&config, pub_key)?;
+
+    let tx = register_public_key_msg(&config, sender, args.key_type).await?;
+
+    let tx_hash = broadcast_tx(config, tx, pub_key).await?.txhash;
+
+    Ok(Some(format!(
+        "successfully broadcast register public key transaction, tx hash: {}",
+        tx_hash
+    )))
+}
+
+/// Generates the multisig key of the given type in tofnd and builds the message that registers it with the multisig
+/// contract, including the proof of possession
+pub(crate) async fn register_public_key_msg(
+    config: &Config,
+    sender: AccountId,
+    key_type: KeyType,
+) -> Result<Any, Error> {
+    let multisig_address = multisig_address(config)?;
+
+    let signer = signer(config).await?;
+    let multisig_key = signer
+        .keygen(&multisig_address.to_string(), key_type.into())
         .await
         .change_context(Error::Tofnd)?;
 
     info!(key_id = multisig_address.to_string(), "keygen successful");
 
-    let sender = pub_key.account_id(PREFIX).change_context(Error::Tofnd)?;
-
-    let address_hash: [u8; 32] = Keccak256::digest(sender.as_ref().as_bytes())
-        .as_slice()
-        .try_into()
-        .expect("wrong length");
+    let challenge = registration_challenge(
+        sender.as_ref(),
+        config.broadcast.chain_id.as_str(),
+        key_type.into(),
+    );
 
-    let signed_sender_address = multisig_client
+    let proof_of_possession = signer
         .sign(
             &multisig_address.to_string(),
-            address_hash.into(),
+            challenge.into(),
             multisig_key,
-            args.key_type.into(),
+            key_type.into(),
         )
         .await
         .change_context(Error::Tofnd)?
         .into();
 
     let msg = serde_json::to_vec(&ExecuteMsg::RegisterPublicKey {
-        public_key: PublicKey::try_from((args.key_type.into(), multisig_key.to_bytes().into()))
+        public_key: PublicKey::try_from((key_type.into(), multisig_key.to_bytes().into()))
             .change_context(Error::Tofnd)?,
-        signed_sender_address,
+        proof_of_possession,
     })
     .expect("register public key msg should serialize");
 
-    let tx = MsgExecuteContract {
+    Ok(MsgExecuteContract {
         sender,
         contract: multisig_address.as_ref().clone(),
         msg,
         funds: vec![],
     }
     .into_any()
-    .expect("failed to serialize proto message");
-
-    let tx_hash = broadcast_tx(config, tx, pub_key).await?.txhash;
-
-    Ok(Some(format!(
-        "successfully broadcast register public key transaction, tx hash: {}",
-        tx_hash
-    )))
+    .expect("failed to serialize proto message"))
 }
 
-fn multisig_address(config: &Config) -> Result<TMAddress, Error> {
+pub(crate) fn multisig_address(config: &Config) -> Result<TMAddress, Error> {
     config
         .handlers
         .iter()
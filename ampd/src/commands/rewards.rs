@@ -0,0 +1,598 @@
+use std::fmt::{Display, Formatter};
+
+use cosmrs::proto::cosmwasm::wasm::v1::query_client::QueryClient;
+use cosmrs::proto::cosmwasm::wasm::v1::QuerySmartContractStateRequest;
+use cosmwasm_std::{Addr, Fraction, Uint128};
+use error_stack::{report, Report, Result, ResultExt};
+use itertools::Itertools;
+use rewards::msg::{Participation, PoolId, QueryMsg, RewardsAsset, RewardsPool};
+use router_api::ChainName;
+use serde::de::DeserializeOwned;
+use tonic::transport::Channel;
+use valuable::Valuable;
+
+use crate::commands::{verifier_account, verifier_pub_key, wasm_query_client};
+use crate::config::Config;
+use crate::handlers::config::{Chain, Config as HandlerConfig};
+use crate::types::TMAddress;
+use crate::Error;
+
+/// Number of epochs that must pass before the rewards of an epoch can be distributed, as enforced by the rewards contract
+const EPOCH_PAYOUT_DELAY: u64 = 2;
+/// Number of epochs the rewards contract distributes at once if no epoch count is specified
+const DEFAULT_EPOCHS_TO_PROCESS: u64 = 10;
+
+#[derive(clap::Args, Debug, Valuable)]
+pub struct Args {
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(clap::Subcommand, Debug, Valuable)]
+pub enum Command {
+    /// Show the verifier's standing in the rewards pools of a chain: the current epoch, the participation in it compared
+    /// to the threshold, the pool balance and an estimate of the next payout
+    Status {
+        /// Name of the chain whose rewards pools are inspected
+        #[arg(long)]
+        chain: ChainName,
+        /// Address of a voting verifier or multisig contract whose rewards pool is inspected. Can be repeated.
+        /// If not set, the contracts of the handlers configured for the chain are used
+        #[arg(long = "contract")]
+        contracts: Vec<String>,
+    },
+}
+
+pub async fn run(config: Config, args: Args) -> Result<Option<String>, Error> {
+    match args.command {
+        Command::Status { chain, contracts } => {
+            let contracts = match contracts.as_slice() {
+                [] => pool_contracts(&config, &chain),
+                contracts => contracts
+                    .iter()
+                    .map(|contract| contract.parse::<TMAddress>().map(PoolContract::Configured))
+                    .try_collect()
+                    .change_context(Error::InvalidInput)
+                    .attach_printable("invalid contract address")?,
+            };
+
+            if contracts.is_empty() {
+                return Err(report!(Error::InvalidInput).attach_printable(format!(
+                    "no handler is configured for chain {}, specify the pool contracts with --contract",
+                    chain
+                )));
+            }
+
+            let verifier = verifier_pub_key(&config)
+                .await
+                .and_then(|pub_key| verifier_account(&config, pub_key))?;
+
+            let report = status(&config, verifier.into(), chain.clone(), contracts).await?;
+            if report.pools.is_empty() {
+                return Err(report!(Error::InvalidInput).attach_printable(format!(
+                    "no rewards pool found for the handlers of chain {}, specify the pool contracts with --contract",
+                    chain
+                )));
+            }
+
+            Ok(Some(report.to_string()))
+        }
+    }
+}
+
+async fn status(
+    config: &Config,
+    verifier: TMAddress,
+    chain: ChainName,
+    contracts: Vec<PoolContract>,
+) -> Result<StatusReport, Error> {
+    let mut client = wasm_query_client(config).await?;
+    let rewards_contract = &config.rewards.cosmwasm_contract;
+    let verifier_addr = Addr::unchecked(verifier.to_string());
+
+    let mut pools = vec![];
+    for contract in contracts {
+        let (contract, is_candidate) = match contract {
+            PoolContract::Configured(contract) => (contract, false),
+            PoolContract::Candidate(contract) => (contract, true),
+        };
+        let pool_id = PoolId {
+            chain_name: chain.clone(),
+            contract: contract.to_string(),
+        };
+
+        let pool: RewardsPool = match query(
+            &mut client,
+            rewards_contract,
+            &QueryMsg::RewardsPool {
+                pool_id: pool_id.clone(),
+            },
+        )
+        .await
+        {
+            Ok(pool) => pool,
+            // the contract of a candidate most likely belongs to another chain
+            Err(_) if is_candidate => continue,
+            Err(err) => {
+                return Err(err.attach_printable(format!("no rewards pool found for {}", contract)))
+            }
+        };
+
+        let current_participation = participation(
+            &mut client,
+            rewards_contract,
+            &pool_id,
+            pool.current_epoch_num.u64(),
+        )
+        .await?;
+
+        let mut payout_participation = vec![];
+        for epoch_num in payout_epochs(&pool) {
+            payout_participation
+                .extend(participation(&mut client, rewards_contract, &pool_id, epoch_num).await?);
+        }
+
+        pools.push(PoolStatus::new(
+            contract,
+            &verifier_addr,
+            pool,
+            current_participation,
+            payout_participation,
+        ));
+    }
+
+    Ok(StatusReport {
+        verifier,
+        chain,
+        pools,
+    })
+}
+
+async fn participation(
+    client: &mut QueryClient<Channel>,
+    rewards_contract: &TMAddress,
+    pool_id: &PoolId,
+    epoch_num: u64,
+) -> Result<Option<Participation>, Error> {
+    query(
+        client,
+        rewards_contract,
+        &QueryMsg::VerifierParticipation {
+            pool_id: pool_id.clone(),
+            epoch_num: Some(epoch_num),
+        },
+    )
+    .await
+}
+
+async fn query<T: DeserializeOwned>(
+    client: &mut QueryClient<Channel>,
+    contract: &TMAddress,
+    msg: &QueryMsg,
+) -> Result<T, Error> {
+    let data = client
+        .smart_contract_state(QuerySmartContractStateRequest {
+            address: contract.to_string(),
+            query_data: serde_json::to_vec(msg).expect("rewards query should serialize"),
+        })
+        .await
+        .map_err(Report::from)
+        .change_context(Error::Connection)
+        .attach_printable(format!("failed to query rewards contract {}", contract))?
+        .into_inner()
+        .data;
+
+    serde_json::from_slice(&data)
+        .change_context(Error::InvalidInput)
+        .attach_printable("unexpected response from the rewards contract")
+}
+
+/// Returns the epochs the next call to `DistributeRewards` pays out, if it doesn't specify an epoch count
+fn payout_epochs(pool: &RewardsPool) -> Vec<u64> {
+    let from = pool
+        .last_distribution_epoch
+        .map_or(0, |epoch| epoch.u64().saturating_add(1));
+    let Some(to) = pool.current_epoch_num.u64().checked_sub(EPOCH_PAYOUT_DELAY) else {
+        return vec![];
+    };
+
+    (from..=to.min(from.saturating_add(DEFAULT_EPOCHS_TO_PROCESS - 1))).collect()
+}
+
+/// Contract whose rewards pool on the inspected chain is reported
+#[derive(Debug, PartialEq)]
+enum PoolContract {
+    /// The contract is known to belong to the chain, so its pool must exist
+    Configured(TMAddress),
+    /// The handler of the contract is not configured with a chain name, so it only has a pool if it belongs to the chain
+    Candidate(TMAddress),
+}
+
+/// Returns the contracts of all handlers that are configured for the given chain, followed by the contracts of the
+/// handlers that are not configured with a chain name
+fn pool_contracts(config: &Config, chain: &ChainName) -> Vec<PoolContract> {
+    let is_sui = chain.as_ref() == crate::SUI_CHAIN_NAME;
+
+    let configured = config
+        .handlers
+        .iter()
+        .filter_map(|handler| match handler {
+            HandlerConfig::EvmMsgVerifier {
+                chain: Chain { name, .. },
+                cosmwasm_contract,
+                ..
+            }
+            | HandlerConfig::EvmVerifierSetVerifier {
+                chain: Chain { name, .. },
+                cosmwasm_contract,
+                ..
+            }
+            | HandlerConfig::MultisigSigner {
+                chain_name: name,
+                cosmwasm_contract,
+            }
+            | HandlerConfig::XRPLMsgVerifier {
+                chain_name: name,
+                cosmwasm_contract,
+                ..
+            }
+            | HandlerConfig::SolanaMsgVerifier {
+                chain_name: name,
+                cosmwasm_contract,
+                ..
+            }
+            | HandlerConfig::SolanaVerifierSetVerifier {
+                chain_name: name,
+                cosmwasm_contract,
+                ..
+            } if name == chain => Some(cosmwasm_contract.clone()),
+            HandlerConfig::SuiMsgVerifier {
+                cosmwasm_contract, ..
+            }
+            | HandlerConfig::SuiVerifierSetVerifier {
+                cosmwasm_contract, ..
+            } if is_sui => Some(cosmwasm_contract.clone()),
+            _ => None,
+        })
+        .unique_by(|contract| contract.to_string())
+        .collect::<Vec<_>>();
+
+    let candidates = config
+        .handlers
+        .iter()
+        .filter_map(|handler| match handler {
+            HandlerConfig::MvxMsgVerifier {
+                cosmwasm_contract, ..
+            }
+            | HandlerConfig::MvxVerifierSetVerifier {
+                cosmwasm_contract, ..
+            }
+            | HandlerConfig::StellarMsgVerifier {
+                cosmwasm_contract, ..
+            }
+            | HandlerConfig::StellarVerifierSetVerifier {
+                cosmwasm_contract, ..
+            }
+            | HandlerConfig::StarknetMsgVerifier {
+                cosmwasm_contract, ..
+            }
+            | HandlerConfig::StarknetVerifierSetVerifier {
+                cosmwasm_contract, ..
+            } => Some(cosmwasm_contract.clone()),
+            _ => None,
+        })
+        .filter(|contract| !configured.contains(contract))
+        .unique_by(|contract| contract.to_string())
+        .collect::<Vec<_>>();
+
+    configured
+        .into_iter()
+        .map(PoolContract::Configured)
+        .chain(candidates.into_iter().map(PoolContract::Candidate))
+        .collect()
+}
+
+#[derive(Debug)]
+struct PoolStatus {
+    contract: TMAddress,
+    rewards_asset: RewardsAsset,
+    balance: Uint128,
+    free_balance: Uint128,
+    current_epoch: u64,
+    last_distribution_epoch: Option<u64>,
+    participated: u64,
+    event_count: u64,
+    required: u64,
+    estimated_payout: Uint128,
+    payout_epochs: Vec<u64>,
+}
+
+impl PoolStatus {
+    fn new(
+        contract: TMAddress,
+        verifier: &Addr,
+        pool: RewardsPool,
+        current_participation: Option<Participation>,
+        payout_participation: Vec<Participation>,
+    ) -> Self {
+        let (participated, event_count) = current_participation
+            .map(|participation| {
+                (
+                    participation
+                        .participation
+                        .get(verifier)
+                        .copied()
+                        .unwrap_or_default(),
+                    participation.event_count,
+                )
+            })
+            .unwrap_or_default();
+
+        let estimated_payout = payout_participation
+            .iter()
+            .filter_map(|participation| participation.rewards_by_verifier.get(verifier))
+            .fold(Uint128::zero(), |total, rewards| {
+                total.saturating_add(*rewards)
+            });
+
+        Self {
+            contract,
+            required: required_participation(&pool, event_count),
+            payout_epochs: payout_epochs(&pool),
+            rewards_asset: pool.rewards_asset,
+            balance: pool.balance,
+            free_balance: pool.free_balance,
+            current_epoch: pool.current_epoch_num.u64(),
+            last_distribution_epoch: pool.last_distribution_epoch.map(|epoch| epoch.u64()),
+            participated,
+            event_count,
+            estimated_payout,
+        }
+    }
+}
+
+/// Returns the smallest participation count that meets the pool's participation threshold
+fn required_participation(pool: &RewardsPool, event_count: u64) -> u64 {
+    let numerator = u128::from(pool.participation_threshold.numerator().u64());
+    let denominator = u128::from(pool.participation_threshold.denominator().u64());
+
+    u128::from(event_count)
+        .saturating_mul(numerator)
+        .div_ceil(denominator)
+        .try_into()
+        .unwrap_or(u64::MAX)
+}
+
+impl Display for PoolStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let asset = match &self.rewards_asset {
+            RewardsAsset::Native => "native".to_string(),
+            RewardsAsset::Cw20 { contract } => format!("cw20 {}", contract),
+        };
+        let last_distribution = self
+            .last_distribution_epoch
+            .map_or("none".to_string(), |epoch| epoch.to_string());
+        let threshold = if self.event_count > 0 && self.participated >= self.required {
+            "met"
+        } else {
+            "not met"
+        };
+
+        writeln!(f, "pool {}:", self.contract)?;
+        writeln!(
+            f,
+            "  current epoch: {} (last distributed epoch: {})",
+            self.current_epoch, last_distribution
+        )?;
+        writeln!(
+            f,
+            "  participation: {} of {} events, {} required ({})",
+            self.participated, self.event_count, self.required, threshold
+        )?;
+        writeln!(
+            f,
+            "  pool balance: {} {} ({} free)",
+            self.balance, asset, self.free_balance
+        )?;
+        match (self.payout_epochs.first(), self.payout_epochs.last()) {
+            (Some(first), Some(last)) => write!(
+                f,
+                "  estimated next payout: {} for epochs {} to {}",
+                self.estimated_payout, first, last
+            ),
+            _ => write!(f, "  estimated next payout: no epochs to distribute yet"),
+        }
+    }
+}
+
+struct StatusReport {
+    verifier: TMAddress,
+    chain: ChainName,
+    pools: Vec<PoolStatus>,
+}
+
+impl Display for StatusReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "rewards status of verifier {} for chain {}:",
+            self.verifier, self.chain
+        )?;
+        for pool in &self.pools {
+            write!(f, "\n{}", pool)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use axelar_wasm_std::Threshold;
+    use cosmwasm_std::Uint64;
+    use rewards::msg::{Epoch, Params};
+
+    use super::*;
+    use crate::PREFIX;
+
+    fn pool(current_epoch_num: u64, last_distribution_epoch: Option<u64>) -> RewardsPool {
+        RewardsPool {
+            rewards_asset: RewardsAsset::Native,
+            balance: Uint128::new(10000),
+            reserved_balance: Uint128::new(1000),
+            free_balance: Uint128::new(9000),
+            epoch_duration: Uint64::new(100),
+            rewards_per_epoch: Uint128::new(300),
+            participation_threshold: Threshold::try_from((2, 3)).unwrap(),
+            remainder_policy: None,
+            carried_remainder: Uint128::zero(),
+            current_epoch_num: Uint64::new(current_epoch_num),
+            last_distribution_epoch: last_distribution_epoch.map(Uint64::new),
+        }
+    }
+
+    fn participation(
+        epoch_num: u64,
+        event_count: u64,
+        participation: Vec<(&Addr, u64)>,
+        rewards: Vec<(&Addr, u128)>,
+    ) -> Participation {
+        Participation {
+            event_count,
+            participation: participation
+                .into_iter()
+                .map(|(verifier, count)| (verifier.clone(), count))
+                .collect(),
+            rewards_by_verifier: rewards
+                .into_iter()
+                .map(|(verifier, amount)| (verifier.clone(), Uint128::new(amount)))
+                .collect::<HashMap<_, _>>(),
+            epoch: Epoch {
+                epoch_num,
+                block_height_started: epoch_num * 100,
+            },
+            params: Params {
+                epoch_duration: 100u64.try_into().unwrap(),
+                rewards_per_epoch: 300u128.try_into().unwrap(),
+                participation_threshold: Threshold::try_from((2, 3)).unwrap(),
+                distribution_cooldown: None,
+                remainder_policy: None,
+                epoch_time: None,
+            },
+        }
+    }
+
+    #[test]
+    fn payout_epochs_should_follow_the_rewards_contract() {
+        assert_eq!(payout_epochs(&pool(1, None)), Vec::<u64>::new());
+        assert_eq!(payout_epochs(&pool(4, None)), vec![0, 1, 2]);
+        assert_eq!(payout_epochs(&pool(12, Some(9))), vec![10]);
+        assert_eq!(payout_epochs(&pool(11, Some(9))), Vec::<u64>::new());
+        assert_eq!(
+            payout_epochs(&pool(50, Some(9))),
+            (10..=19).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn required_participation_should_round_up() {
+        assert_eq!(required_participation(&pool(0, None), 0), 0);
+        assert_eq!(required_participation(&pool(0, None), 10), 7);
+        assert_eq!(required_participation(&pool(0, None), 30), 20);
+    }
+
+    #[test]
+    fn status_report_should_show_participation_and_estimated_payout() {
+        let verifier = TMAddress::random(PREFIX);
+        let verifier_addr = Addr::unchecked(verifier.to_string());
+        let other = Addr::unchecked("other");
+
+        let report = StatusReport {
+            verifier: verifier.clone(),
+            chain: "ethereum".parse().unwrap(),
+            pools: vec![
+                PoolStatus::new(
+                    "axelar178lzht9kp8zc555ngcnsarkfhxxxjh70md650vnaqqey9vt8cynq7z9a0r"
+                        .parse()
+                        .unwrap(),
+                    &verifier_addr,
+                    pool(12, Some(9)),
+                    Some(participation(
+                        12,
+                        30,
+                        vec![(&verifier_addr, 25), (&other, 10)],
+                        vec![],
+                    )),
+                    vec![participation(
+                        10,
+                        10,
+                        vec![(&verifier_addr, 9), (&other, 9)],
+                        vec![(&verifier_addr, 150), (&other, 150)],
+                    )],
+                ),
+                PoolStatus::new(
+                    "axelar10yjnqzs0nxzpesqmzfv006q9fwgu833xme5kuu60tuan72n77j8qgdfpsm"
+                        .parse()
+                        .unwrap(),
+                    &verifier_addr,
+                    pool(1, None),
+                    None,
+                    vec![],
+                ),
+            ],
+        };
+
+        goldie::assert!(report
+            .to_string()
+            .replace(&verifier.to_string(), "<verifier>"));
+    }
+
+    #[test]
+    fn pool_contracts_should_include_handlers_without_chain_name() {
+        let ethereum = TMAddress::random(PREFIX);
+        let sui = TMAddress::random(PREFIX);
+        let stellar = TMAddress::random(PREFIX);
+
+        let config = Config {
+            handlers: vec![
+                HandlerConfig::EvmMsgVerifier {
+                    cosmwasm_contract: ethereum.clone(),
+                    chain: Chain {
+                        name: "ethereum".parse().unwrap(),
+                        rpc_url: "http://localhost:8545".parse().unwrap(),
+                        finalization: Default::default(),
+                    },
+                    rpc_timeout: None,
+                    cross_check_rpc_urls: vec![],
+                },
+                HandlerConfig::SuiMsgVerifier {
+                    cosmwasm_contract: sui.clone(),
+                    rpc_url: "http://localhost:9000".parse().unwrap(),
+                    rpc_timeout: None,
+                },
+                HandlerConfig::StellarMsgVerifier {
+                    cosmwasm_contract: stellar.clone(),
+                    rpc_url: "http://localhost:8000".parse().unwrap(),
+                },
+            ],
+            ..Config::default()
+        };
+
+        assert_eq!(
+            pool_contracts(&config, &"ethereum".parse().unwrap()),
+            vec![
+                PoolContract::Configured(ethereum),
+                PoolContract::Candidate(stellar.clone())
+            ]
+        );
+        assert_eq!(
+            pool_contracts(&config, &"sui".parse().unwrap()),
+            vec![
+                PoolContract::Configured(sui),
+                PoolContract::Candidate(stellar)
+            ]
+        );
+    }
+}
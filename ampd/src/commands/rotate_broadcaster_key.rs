@@ -0,0 +1,231 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use axelar_wasm_std::nonempty;
+use cosmrs::proto::cosmos::authz::v1beta1::{GenericAuthorization, Grant, MsgGrant};
+use cosmrs::proto::cosmos::base::v1beta1::Coin;
+use cosmrs::proto::cosmos::feegrant::v1beta1::{BasicAllowance, MsgGrantAllowance};
+use cosmrs::{AccountId, Any};
+use error_stack::{bail, ensure, report, Result};
+use report::ResultCompatExt;
+use tendermint_proto::google::protobuf::Timestamp;
+use tracing::info;
+use valuable::Valuable;
+
+use crate::commands::{broadcast_msgs, broadcaster_pub_key, verifier_pub_key};
+use crate::config::Config;
+use crate::{Error, PREFIX};
+
+/// Message types the new broadcaster key is authorized to execute on behalf of the verifier
+const AUTHORIZED_MSG_TYPES: [&str; 2] = [
+    "/axelar.auxiliary.v1beta1.BatchRequest",
+    "/cosmwasm.wasm.v1.MsgExecuteContract",
+];
+
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+#[derive(clap::Args, Debug, Valuable)]
+pub struct Args {
+    /// The tofnd key uid of the new broadcaster key. The key is generated if it doesn't exist in tofnd yet
+    pub new_key_uid: nonempty::String,
+    /// The maximum amount of the gas price denomination the new key can spend on fees from the verifier's funds
+    #[arg(long)]
+    pub fee_spend_limit: u128,
+    /// The authorization and fee allowance of the new key expire after this many days, so a leaked key can't be used indefinitely
+    #[arg(long, default_value_t = 365)]
+    pub expires_in_days: u64,
+}
+
+/// Phases of a broadcaster key rotation. Each phase is only entered once the previous one has succeeded
+#[derive(Debug, Valuable)]
+enum Phase {
+    /// Generate the new key in tofnd, or load it if it has been generated or imported before
+    GenerateKey { key_uid: String },
+    /// Authorize the new key's account to broadcast on behalf of the verifier and to pay fees with the verifier's funds
+    GrantAuthorization { granter: String, grantee: String },
+    /// The daemon can be switched over to the new key
+    Completed { granter: String, grantee: String },
+}
+
+impl Phase {
+    fn enter(self) -> Self {
+        info!(
+            phase = self.as_value(),
+            "entering broadcaster key rotation phase"
+        );
+        self
+    }
+}
+
+pub async fn run(config: Config, args: Args) -> Result<Option<String>, Error> {
+    if config.broadcast.authz_granter.is_some() {
+        bail!(report!(Error::InvalidInput)
+            .attach_printable("the broadcaster key has already been rotated, rotate it with the verifier's original key instead"));
+    }
+
+    ensure!(
+        args.fee_spend_limit > 0,
+        report!(Error::InvalidInput).attach_printable("the fee spend limit must be positive")
+    );
+
+    let granter_pub_key = verifier_pub_key(&config).await?;
+    let granter = granter_pub_key
+        .account_id(PREFIX)
+        .change_context(Error::Tofnd)?;
+
+    Phase::GenerateKey {
+        key_uid: args.new_key_uid.to_string(),
+    }
+    .enter();
+
+    let grantee = broadcaster_pub_key(&config, &args.new_key_uid.to_string())
+        .await?
+        .account_id(PREFIX)
+        .change_context(Error::Tofnd)?;
+    ensure!(grantee != granter, Error::InvalidInput);
+
+    Phase::GrantAuthorization {
+        granter: granter.to_string(),
+        grantee: grantee.to_string(),
+    }
+    .enter();
+
+    let fee_spend_limit = Coin {
+        denom: config.broadcast.gas_price.denom.to_string(),
+        amount: args.fee_spend_limit.to_string(),
+    };
+    let expiration = expiration(args.expires_in_days)?;
+
+    let tx_hash = broadcast_msgs(
+        config,
+        grant_msgs(&granter, &grantee, fee_spend_limit, expiration),
+        granter_pub_key,
+    )
+    .await?
+    .txhash;
+    info!(tx_hash, "authorization granted to the new broadcaster key");
+
+    Phase::Completed {
+        granter: granter.to_string(),
+        grantee: grantee.to_string(),
+    }
+    .enter();
+
+    Ok(Some(format!(
+        "successfully broadcast broadcaster key rotation transaction, tx hash: {}. \
+        To switch over, set tofnd_config.key_uid = '{}' and broadcast.authz_granter = '{}' in the config and send SIGHUP to the running daemon. \
+        Messages that are already queued are still broadcast with the old key",
+        tx_hash, args.new_key_uid, granter
+    )))
+}
+
+fn expiration(expires_in_days: u64) -> Result<Timestamp, Error> {
+    let expires_in = expires_in_days
+        .checked_mul(SECONDS_PER_DAY)
+        .map(Duration::from_secs)
+        .ok_or(Error::InvalidInput)?;
+    let expires_at = SystemTime::now()
+        .checked_add(expires_in)
+        .and_then(|expires_at| expires_at.duration_since(UNIX_EPOCH).ok())
+        .ok_or(Error::InvalidInput)?;
+
+    Ok(Timestamp {
+        seconds: i64::try_from(expires_at.as_secs()).map_err(|_| Error::InvalidInput)?,
+        nanos: 0,
+    })
+}
+
+fn grant_msgs(
+    granter: &AccountId,
+    grantee: &AccountId,
+    fee_spend_limit: Coin,
+    expiration: Timestamp,
+) -> Vec<Any> {
+    AUTHORIZED_MSG_TYPES
+        .into_iter()
+        .map(|msg_type| MsgGrant {
+            granter: granter.to_string(),
+            grantee: grantee.to_string(),
+            grant: Some(Grant {
+                authorization: Some(
+                    Any::from_msg(&GenericAuthorization {
+                        msg: msg_type.to_string(),
+                    })
+                    .expect("failed to serialize proto message"),
+                ),
+                expiration: Some(expiration.clone()),
+            }),
+        })
+        .map(|msg| Any::from_msg(&msg).expect("failed to serialize proto message"))
+        .chain(std::iter::once(
+            Any::from_msg(&MsgGrantAllowance {
+                granter: granter.to_string(),
+                grantee: grantee.to_string(),
+                allowance: Some(
+                    Any::from_msg(&BasicAllowance {
+                        spend_limit: vec![fee_spend_limit],
+                        expiration: Some(expiration),
+                    })
+                    .expect("failed to serialize proto message"),
+                ),
+            })
+            .expect("failed to serialize proto message"),
+        ))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmrs::proto::cosmos::authz::v1beta1::{GenericAuthorization, MsgGrant};
+    use cosmrs::proto::cosmos::base::v1beta1::Coin;
+    use cosmrs::proto::cosmos::feegrant::v1beta1::{BasicAllowance, MsgGrantAllowance};
+    use tendermint_proto::google::protobuf::Timestamp;
+
+    use super::{grant_msgs, AUTHORIZED_MSG_TYPES};
+    use crate::types::TMAddress;
+    use crate::PREFIX;
+
+    #[test]
+    fn grant_msgs_should_authorize_grantee_and_grant_fee_allowance() {
+        let granter = TMAddress::random(PREFIX);
+        let grantee = TMAddress::random(PREFIX);
+
+        let fee_spend_limit = Coin {
+            denom: "uaxl".to_string(),
+            amount: "1000000".to_string(),
+        };
+        let expiration = Timestamp {
+            seconds: 1_800_000_000,
+            nanos: 0,
+        };
+
+        let msgs = grant_msgs(
+            granter.as_ref(),
+            grantee.as_ref(),
+            fee_spend_limit.clone(),
+            expiration.clone(),
+        );
+        assert_eq!(msgs.len(), AUTHORIZED_MSG_TYPES.len() + 1);
+
+        let (allowance, grants) = msgs.split_last().unwrap();
+        for (msg, msg_type) in grants.iter().zip(AUTHORIZED_MSG_TYPES) {
+            let grant: MsgGrant = msg.to_msg().unwrap();
+            assert_eq!(grant.granter, granter.to_string());
+            assert_eq!(grant.grantee, grantee.to_string());
+
+            let grant = grant.grant.unwrap();
+            assert_eq!(grant.expiration, Some(expiration.clone()));
+
+            let authorization: GenericAuthorization =
+                grant.authorization.unwrap().to_msg().unwrap();
+            assert_eq!(authorization.msg, msg_type);
+        }
+
+        let allowance: MsgGrantAllowance = allowance.to_msg().unwrap();
+        assert_eq!(allowance.granter, granter.to_string());
+        assert_eq!(allowance.grantee, grantee.to_string());
+
+        let allowance: BasicAllowance = allowance.allowance.unwrap().to_msg().unwrap();
+        assert_eq!(allowance.spend_limit, vec![fee_spend_limit]);
+        assert_eq!(allowance.expiration, Some(expiration));
+    }
+}
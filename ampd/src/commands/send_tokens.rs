@@ -6,9 +6,9 @@ use error_stack::Result;
 use report::ResultCompatExt;
 use valuable::Valuable;
 
-use crate::commands::{broadcast_tx, verifier_pub_key};
+use crate::commands::{broadcast_tx, verifier_account, verifier_pub_key};
 use crate::config::Config;
-use crate::{Error, PREFIX};
+use crate::Error;
 
 #[derive(clap::Args, Debug, Valuable)]
 pub struct Args {
@@ -19,14 +19,14 @@ pub struct Args {
 
 pub async fn run(config: Config, args: Args) -> Result<Option<String>, Error> {
     let coin = Coin::new(args.amount, args.denom.as_str()).change_context(Error::InvalidInput)?;
-    let pub_key = verifier_pub_key(config.tofnd_config.clone()).await?;
+    let pub_key = verifier_pub_key(&config).await?;
 
     let tx = MsgSend {
         to_address: args
             .to_address
             .parse::<AccountId>()
             .change_context(Error::InvalidInput)?,
-        from_address: pub_key.account_id(PREFIX).change_context(Error::Tofnd)?,
+        from_address: verifier_account(&config, pub_key)?,
         amount: vec![coin],
     }
     .into_any()
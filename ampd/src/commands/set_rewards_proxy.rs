@@ -1,14 +1,13 @@
 use cosmrs::cosmwasm::MsgExecuteContract;
 use cosmrs::tx::Msg;
 use error_stack::Result;
-use report::ResultCompatExt;
 use rewards::msg::ExecuteMsg;
 use router_api::Address;
 use valuable::Valuable;
 
-use crate::commands::{broadcast_tx, verifier_pub_key};
+use crate::commands::{broadcast_tx, verifier_account, verifier_pub_key};
 use crate::config::Config;
-use crate::{Error, PREFIX};
+use crate::Error;
 
 #[derive(clap::Args, Debug, Valuable)]
 pub struct Args {
@@ -16,7 +15,7 @@ pub struct Args {
 }
 
 pub async fn run(config: Config, args: Args) -> Result<Option<String>, Error> {
-    let pub_key = verifier_pub_key(config.tofnd_config.clone()).await?;
+    let pub_key = verifier_pub_key(&config).await?;
 
     let msg = serde_json::to_vec(&ExecuteMsg::SetVerifierProxy {
         proxy_address: args.proxy_address,
@@ -24,7 +23,7 @@ pub async fn run(config: Config, args: Args) -> Result<Option<String>, Error> {
     .expect("register chain support msg should serialize");
 
     let tx = MsgExecuteContract {
-        sender: pub_key.account_id(PREFIX).change_context(Error::Tofnd)?,
+        sender: verifier_account(&config, pub_key)?,
         contract: config.rewards.cosmwasm_contract.as_ref().clone(),
         msg,
         funds: vec![],
@@ -0,0 +1,559 @@
+use std::fmt::{Display, Formatter};
+
+use cosmrs::proto::cosmos::bank::v1beta1::QueryBalanceRequest;
+use cosmrs::proto::cosmwasm::wasm::v1::query_client::QueryClient;
+use cosmrs::proto::cosmwasm::wasm::v1::{QueryContractInfoRequest, QuerySmartContractStateRequest};
+use error_stack::{report, Report, Result, ResultExt};
+use itertools::Itertools;
+use report::ResultCompatExt;
+use router_api::ChainName;
+use tendermint_rpc::{Client, HttpClient};
+use tonic::transport::Channel;
+use valuable::Valuable;
+
+use crate::commands::{verifier_account, verifier_pub_key, wasm_query_client};
+use crate::config::Config;
+use crate::contract_compatibility::{check_version, contracts, query_contract_version};
+use crate::cosmos::{CosmosClient, CosmosGrpcClient};
+use crate::handlers::config::{Chain, Config as HandlerConfig};
+use crate::types::TMAddress;
+use crate::Error;
+
+#[derive(clap::Args, Debug, Valuable)]
+pub struct Args {
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(clap::Subcommand, Debug, Valuable)]
+pub enum Command {
+    /// Check the config against the configured endpoints and contracts and print a pass/fail report for every item.
+    /// Exits with a non-zero code if any check fails
+    Validate {
+        /// Address of the router contract. If set, the chains of all handlers are checked to be registered with the router.
+        /// Defaults to the router of the contracts config
+        #[arg(long)]
+        router: Option<String>,
+    },
+}
+
+#[derive(Debug, PartialEq)]
+pub(crate) enum Status {
+    Pass,
+    Fail,
+    Skip,
+}
+
+#[derive(Debug)]
+pub(crate) struct Check {
+    pub(crate) item: String,
+    pub(crate) status: Status,
+    pub(crate) details: String,
+}
+
+impl Check {
+    pub(crate) fn pass(item: impl Into<String>, details: impl Into<String>) -> Self {
+        Self {
+            item: item.into(),
+            status: Status::Pass,
+            details: details.into(),
+        }
+    }
+
+    pub(crate) fn fail(item: impl Into<String>, details: impl Into<String>) -> Self {
+        Self {
+            item: item.into(),
+            status: Status::Fail,
+            details: details.into(),
+        }
+    }
+
+    pub(crate) fn skip(item: impl Into<String>, details: impl Into<String>) -> Self {
+        Self {
+            item: item.into(),
+            status: Status::Skip,
+            details: details.into(),
+        }
+    }
+
+    pub(crate) fn from_result(
+        item: impl Into<String>,
+        result: Result<String, Error>,
+        hint: &str,
+    ) -> Self {
+        match result {
+            Ok(details) => Self::pass(item, details),
+            Err(err) => Self::fail(item, format!("{err:#}. {hint}")),
+        }
+    }
+}
+
+impl Display for Check {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let status = match self.status {
+            Status::Pass => "PASS",
+            Status::Fail => "FAIL",
+            Status::Skip => "SKIP",
+        };
+
+        write!(f, "[{}] {}: {}", status, self.item, self.details)
+    }
+}
+
+struct ValidationReport(Vec<Check>);
+
+impl ValidationReport {
+    fn has_failures(&self) -> bool {
+        self.0.iter().any(|check| check.status == Status::Fail)
+    }
+}
+
+impl Display for ValidationReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "config validation report:")?;
+        for check in &self.0 {
+            writeln!(f, "{}", check)?;
+        }
+
+        let failures = self
+            .0
+            .iter()
+            .filter(|check| check.status == Status::Fail)
+            .count();
+        write!(f, "{} of {} checks failed", failures, self.0.len())
+    }
+}
+
+pub async fn run(config: Config, args: Args) -> Result<Option<String>, Error> {
+    match args.command {
+        Command::Validate { router } => {
+            let router = router
+                .map(|router| router.parse::<TMAddress>())
+                .transpose()
+                .change_context(Error::InvalidInput)
+                .attach_printable("invalid router address")?
+                .or_else(|| config.contracts.router.clone());
+
+            let report = validate(&config, router).await;
+
+            if report.has_failures() {
+                Err(report!(Error::ConfigValidation).attach_printable(report.to_string()))
+            } else {
+                Ok(Some(report.to_string()))
+            }
+        }
+    }
+}
+
+async fn validate(config: &Config, router: Option<TMAddress>) -> ValidationReport {
+    let mut checks = vec![Check::from_result(
+        "tendermint rpc",
+        check_tm_rpc(config).await,
+        "check that tm_jsonrpc points to a reachable node",
+    )];
+
+    let grpc = connect_grpc(config).await;
+    checks.push(match &grpc {
+        Ok(_) => Check::pass("cosmos grpc", format!("connected to {}", config.tm_grpc)),
+        Err(err) => Check::fail(
+            "cosmos grpc",
+            format!("{err:#}. check that tm_grpc points to a reachable node with gRPC enabled"),
+        ),
+    });
+
+    let verifier = verifier_pub_key(config)
+        .await
+        .and_then(|pub_key| verifier_account(config, pub_key));
+    checks.push(match &verifier {
+        Ok(verifier) => Check::pass(
+            "tofnd",
+            format!(
+                "key '{}' is available, verifier address is {}",
+                config.tofnd_config.key_uid, verifier
+            ),
+        ),
+        Err(err) => Check::fail(
+            "tofnd",
+            format!(
+                "{err:#}. check that tofnd is running at {} and tofnd_config.key_uid is correct",
+                config.tofnd_config.url
+            ),
+        ),
+    });
+
+    let Ok((mut cosmos_client, mut wasm_client)) = grpc else {
+        checks.push(Check::skip(
+            "balance, contracts and chains",
+            "cannot be checked without a cosmos grpc connection",
+        ));
+        return ValidationReport(checks);
+    };
+
+    checks.push(match verifier {
+        Ok(verifier) => {
+            let fee_payer = config
+                .broadcast
+                .authz_granter
+                .clone()
+                .unwrap_or(verifier.into());
+            Check::from_result(
+                format!("balance of {}", fee_payer),
+                check_balance(config, &mut cosmos_client, &fee_payer).await,
+                "fund the account with the denom of broadcast.gas_price to pay for transaction fees",
+            )
+        }
+        Err(_) => Check::skip("balance", "cannot be checked without the verifier address"),
+    });
+
+    for (item, address, expected_name) in contracts(config) {
+        checks.push(Check::from_result(
+            format!("{} contract {}", item, address),
+            check_contract(&mut wasm_client, &address, expected_name).await,
+            "check that the address points to the right contract on the connected chain",
+        ));
+    }
+
+    match router {
+        Some(router) => {
+            for chain_name in chain_names(config) {
+                checks.push(Check::from_result(
+                    format!("chain {}", chain_name),
+                    check_chain_registered(&mut wasm_client, &router, &chain_name).await,
+                    "check that the chain name matches the name the chain is registered with in the router",
+                ));
+            }
+        }
+        None => checks.push(Check::skip(
+            "chain registration",
+            "no router address provided (--router)",
+        )),
+    }
+
+    match &config.contracts.coordinator {
+        Some(coordinator) => {
+            for (chain_name, voting_verifier) in chain_voting_verifiers(config) {
+                checks.push(Check::from_result(
+                    format!("voting verifier of chain {}", chain_name),
+                    check_chain_voting_verifier(
+                        &mut wasm_client,
+                        coordinator,
+                        &chain_name,
+                        &voting_verifier,
+                    )
+                    .await,
+                    "check that the handler's cosmwasm_contract is the voting verifier of the chain",
+                ));
+            }
+        }
+        None => checks.push(Check::skip(
+            "chain contracts",
+            "no coordinator configured (contracts.coordinator)",
+        )),
+    }
+
+    ValidationReport(checks)
+}
+
+async fn check_tm_rpc(config: &Config) -> Result<String, Error> {
+    let client = HttpClient::new(config.tm_jsonrpc.as_str())
+        .change_context(Error::Connection)
+        .attach_printable(config.tm_jsonrpc.clone())?;
+
+    let block = Client::latest_block(&client)
+        .await
+        .change_context(Error::Connection)
+        .attach_printable(config.tm_jsonrpc.clone())?;
+
+    Ok(format!(
+        "connected to {}, latest block height is {}",
+        config.tm_jsonrpc, block.block.header.height
+    ))
+}
+
+async fn connect_grpc(config: &Config) -> Result<(CosmosGrpcClient, QueryClient<Channel>), Error> {
+    let cosmos_client = CosmosGrpcClient::new(config.tm_grpc.as_str(), config.tm_grpc_timeout)
+        .await
+        .change_context(Error::Connection)
+        .attach_printable(config.tm_grpc.clone())?;
+
+    Ok((cosmos_client, wasm_query_client(config).await?))
+}
+
+async fn check_balance(
+    config: &Config,
+    client: &mut CosmosGrpcClient,
+    address: &TMAddress,
+) -> Result<String, Error> {
+    let denom = config.broadcast.gas_price.denom.to_string();
+
+    let balance = client
+        .balance(QueryBalanceRequest {
+            address: address.to_string(),
+            denom: denom.clone(),
+        })
+        .await
+        .change_context(Error::Connection)?
+        .balance
+        .map(|coin| coin.amount)
+        .unwrap_or_default();
+
+    match balance.as_str() {
+        "" | "0" => Err(report!(Error::InvalidInput)
+            .attach_printable(format!("account has no funds of denom {}", denom))),
+        amount => Ok(format!("{} {}", amount, denom)),
+    }
+}
+
+async fn check_contract(
+    client: &mut QueryClient<Channel>,
+    address: &TMAddress,
+    expected_name: Option<&str>,
+) -> Result<String, Error> {
+    let info = client
+        .contract_info(QueryContractInfoRequest {
+            address: address.to_string(),
+        })
+        .await
+        .change_context(Error::Connection)
+        .attach_printable("contract not found")?
+        .into_inner();
+
+    let version = query_contract_version(client, address)
+        .await
+        .change_context(Error::InvalidInput)?;
+
+    if let Some(expected_name) = expected_name {
+        check_version(address, expected_name, &version).change_context(Error::InvalidInput)?;
+    }
+
+    Ok(format!(
+        "{} {} (code id {})",
+        version.contract,
+        version.version,
+        info.contract_info
+            .map(|info| info.code_id)
+            .unwrap_or_default()
+    ))
+}
+
+async fn check_chain_registered(
+    client: &mut QueryClient<Channel>,
+    router: &TMAddress,
+    chain_name: &ChainName,
+) -> Result<String, Error> {
+    let query = serde_json::to_vec(&router_api::msg::QueryMsg::ChainInfo(chain_name.clone()))
+        .expect("router query should serialize");
+
+    client
+        .smart_contract_state(QuerySmartContractStateRequest {
+            address: router.to_string(),
+            query_data: query,
+        })
+        .await
+        .map_err(Report::from)
+        .change_context(Error::InvalidInput)
+        .attach_printable(format!(
+            "chain {} is not registered with the router",
+            chain_name
+        ))?;
+
+    Ok(format!("registered with router {}", router))
+}
+
+async fn check_chain_voting_verifier(
+    client: &mut QueryClient<Channel>,
+    coordinator: &TMAddress,
+    chain_name: &ChainName,
+    voting_verifier: &TMAddress,
+) -> Result<String, Error> {
+    let query = serde_json::json!({ "chain_contracts_info": { "chain_name": chain_name } })
+        .to_string()
+        .into_bytes();
+
+    let res = client
+        .smart_contract_state(QuerySmartContractStateRequest {
+            address: coordinator.to_string(),
+            query_data: query,
+        })
+        .await
+        .map_err(Report::from)
+        .change_context(Error::InvalidInput)
+        .attach_printable(format!(
+            "chain {} is not registered with the coordinator",
+            chain_name
+        ))?
+        .into_inner()
+        .data;
+
+    let registered = serde_json::from_slice::<serde_json::Value>(&res)
+        .change_context(Error::InvalidInput)?
+        .get("verifier_address")
+        .and_then(|address| address.as_str().map(ToString::to_string))
+        .ok_or_else(|| {
+            report!(Error::InvalidInput).attach_printable(format!(
+                "coordinator {} returned no voting verifier for chain {}",
+                coordinator, chain_name
+            ))
+        })?;
+
+    if registered != voting_verifier.to_string() {
+        return Err(report!(Error::InvalidInput).attach_printable(format!(
+            "coordinator {} has voting verifier {} registered for chain {}",
+            coordinator, registered, chain_name
+        )));
+    }
+
+    Ok(format!("registered with coordinator {}", coordinator))
+}
+
+/// Returns the names of all chains that handlers are configured with
+fn chain_names(config: &Config) -> Vec<ChainName> {
+    config
+        .handlers
+        .iter()
+        .filter_map(|handler| match handler {
+            HandlerConfig::EvmMsgVerifier {
+                chain: Chain { name, .. },
+                ..
+            }
+            | HandlerConfig::EvmVerifierSetVerifier {
+                chain: Chain { name, .. },
+                ..
+            } => Some(name.clone()),
+            HandlerConfig::MultisigSigner { chain_name, .. }
+            | HandlerConfig::XRPLMsgVerifier { chain_name, .. }
+            | HandlerConfig::SolanaMsgVerifier { chain_name, .. }
+            | HandlerConfig::SolanaVerifierSetVerifier { chain_name, .. } => {
+                Some(chain_name.clone())
+            }
+            _ => None,
+        })
+        .unique()
+        .collect()
+}
+
+/// Returns the voting verifier that the handlers of each chain are configured with
+fn chain_voting_verifiers(config: &Config) -> Vec<(ChainName, TMAddress)> {
+    config
+        .handlers
+        .iter()
+        .filter_map(|handler| match handler {
+            HandlerConfig::EvmMsgVerifier {
+                chain: Chain { name, .. },
+                cosmwasm_contract,
+                ..
+            }
+            | HandlerConfig::EvmVerifierSetVerifier {
+                chain: Chain { name, .. },
+                cosmwasm_contract,
+                ..
+            } => Some((name.clone(), cosmwasm_contract.clone())),
+            HandlerConfig::XRPLMsgVerifier {
+                chain_name,
+                cosmwasm_contract,
+                ..
+            }
+            | HandlerConfig::SolanaMsgVerifier {
+                chain_name,
+                cosmwasm_contract,
+                ..
+            }
+            | HandlerConfig::SolanaVerifierSetVerifier {
+                chain_name,
+                cosmwasm_contract,
+                ..
+            } => Some((chain_name.clone(), cosmwasm_contract.clone())),
+            _ => None,
+        })
+        .unique_by(|(chain_name, voting_verifier)| {
+            (chain_name.clone(), voting_verifier.to_string())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use router_api::ChainName;
+
+    use super::{chain_names, chain_voting_verifiers, Check, ValidationReport};
+    use crate::commands::{RewardsConfig, ServiceRegistryConfig};
+    use crate::config::Config;
+    use crate::contract_compatibility::contracts;
+    use crate::handlers::config::Config as HandlerConfig;
+    use crate::types::TMAddress;
+    use crate::PREFIX;
+
+    #[test]
+    fn report_should_only_fail_on_failed_checks() {
+        let report = ValidationReport(vec![
+            Check::pass("a", "ok"),
+            Check::skip("b", "not configured"),
+        ]);
+        assert!(!report.has_failures());
+
+        let report = ValidationReport(vec![
+            Check::pass("a", "ok"),
+            Check::fail("b", "unreachable"),
+        ]);
+        assert!(report.has_failures());
+        assert_eq!(
+            report.to_string(),
+            "config validation report:\n[PASS] a: ok\n[FAIL] b: unreachable\n1 of 2 checks failed"
+        );
+    }
+
+    #[test]
+    fn contracts_and_chains_should_be_collected_from_handlers() {
+        let multisig = TMAddress::random(PREFIX);
+        let voting_verifier = TMAddress::random(PREFIX);
+
+        let config = Config {
+            handlers: vec![
+                HandlerConfig::MultisigSigner {
+                    cosmwasm_contract: multisig.clone(),
+                    chain_name: "ethereum".parse().unwrap(),
+                },
+                HandlerConfig::MultisigSigner {
+                    cosmwasm_contract: multisig.clone(),
+                    chain_name: "polygon".parse().unwrap(),
+                },
+                HandlerConfig::SolanaMsgVerifier {
+                    chain_name: "solana".parse().unwrap(),
+                    cosmwasm_contract: voting_verifier.clone(),
+                    rpc_url: "http://localhost:8899".parse().unwrap(),
+                    rpc_timeout: None,
+                },
+            ],
+            service_registry: ServiceRegistryConfig {
+                cosmwasm_contract: TMAddress::random(PREFIX),
+            },
+            rewards: RewardsConfig {
+                cosmwasm_contract: TMAddress::random(PREFIX),
+            },
+            ..Config::default()
+        };
+
+        let contracts = contracts(&config);
+        assert_eq!(contracts.len(), 4);
+        assert!(contracts
+            .iter()
+            .any(|(_, address, name)| address == &multisig && *name == Some("multisig")));
+        assert!(contracts
+            .iter()
+            .any(|(_, address, name)| address == &voting_verifier
+                && *name == Some("voting-verifier")));
+
+        assert_eq!(
+            chain_names(&config),
+            vec![
+                "ethereum".parse::<ChainName>().unwrap(),
+                "polygon".parse().unwrap(),
+                "solana".parse().unwrap()
+            ]
+        );
+        assert_eq!(
+            chain_voting_verifiers(&config),
+            vec![("solana".parse().unwrap(), voting_verifier)]
+        );
+    }
+}
@@ -1,14 +1,25 @@
+use std::fs;
 use std::net::{Ipv4Addr, SocketAddrV4};
+use std::path::{Path, PathBuf};
 use std::time::Duration;
 
+use ::config::{
+    Config as Builder, ConfigError, Environment, File, FileFormat, Map, Source, Value, ValueKind,
+};
+use error_stack::{ensure, report, Result, ResultExt};
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tracing::info;
 
 use crate::commands::{RewardsConfig, ServiceRegistryConfig};
 use crate::handlers::config::deserialize_handler_configs;
 use crate::handlers::{self};
 use crate::tofnd::Config as TofndConfig;
+use crate::types::TMAddress;
 use crate::url::Url;
-use crate::{broadcaster, event_processor, grpc};
+use crate::{
+    broadcaster, contract_compatibility, event_archive, event_processor, event_sub, grpc, signer,
+};
 
 #[derive(Deserialize, Serialize, Debug, PartialEq)]
 #[serde(default)]
@@ -26,6 +37,35 @@ pub struct Config {
     pub rewards: RewardsConfig,
     #[serde(deserialize_with = "grpc::deserialize_config")]
     pub grpc: grpc::Config,
+    pub signed_sessions: handlers::signed_sessions::Config,
+    pub vote_cache: handlers::vote_cache::Config,
+    pub rpc_divergence: handlers::rpc_divergence::Config,
+    pub chain_lag: handlers::chain_lag::Configs,
+    pub circuit_breaker: handlers::circuit_breaker::Configs,
+    pub event_buffer: event_sub::buffer::Config,
+    pub block_processing: event_sub::pipeline::Config,
+    pub event_archive: event_archive::Config,
+    pub contract_compatibility: contract_compatibility::Config,
+    pub contracts: ContractsConfig,
+    /// If set, the configured keys are held by a PKCS#11 token instead of tofnd
+    pub pkcs11: Option<signer::pkcs11::Config>,
+    /// If set, ampd runs without a broadcaster account, e.g. for analytics deployments
+    pub read_only: Option<ReadOnlyConfig>,
+}
+
+/// Amplifier contracts that are served to handler clients through the gRPC server, in addition to the service registry
+/// and rewards contracts. The contracts of a chain are looked up in the coordinator
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone, Default)]
+pub struct ContractsConfig {
+    pub router: Option<TMAddress>,
+    pub multisig: Option<TMAddress>,
+    pub coordinator: Option<TMAddress>,
+}
+
+/// Handlers verify as the given verifier, but nothing is ever broadcast, so no broadcaster key is needed
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+pub struct ReadOnlyConfig {
+    pub verifier: TMAddress,
 }
 
 impl Default for Config {
@@ -42,12 +82,239 @@ impl Default for Config {
             rewards: RewardsConfig::default(),
             health_check_bind_addr: SocketAddrV4::new(Ipv4Addr::UNSPECIFIED, 3000),
             grpc: grpc::Config::default(),
+            signed_sessions: handlers::signed_sessions::Config::default(),
+            vote_cache: handlers::vote_cache::Config::default(),
+            rpc_divergence: handlers::rpc_divergence::Config::default(),
+            chain_lag: handlers::chain_lag::Configs::default(),
+            circuit_breaker: handlers::circuit_breaker::Configs::default(),
+            event_buffer: event_sub::buffer::Config::default(),
+            block_processing: event_sub::pipeline::Config::default(),
+            event_archive: event_archive::Config::default(),
+            contract_compatibility: contract_compatibility::Config::default(),
+            contracts: ContractsConfig::default(),
+            pkcs11: None,
+            read_only: None,
         }
     }
 }
 
+/// Suffix of the config files that hold local overrides, which are layered on top of the base and the network profile
+const LOCAL_OVERRIDES: &str = "local";
+
+#[derive(Error, Debug, PartialEq)]
+pub enum Error {
+    #[error("invalid profile name {0}, only alphanumeric characters, '-' and '_' are allowed")]
+    InvalidProfile(String),
+    #[error("no config file found for profile {0}")]
+    ProfileNotFound(String),
+    #[error("failed to read config file {0}")]
+    ReadFile(String),
+    #[error("failed to parse config file {0}")]
+    ParseFile(String),
+    #[error("environment variable {variable} referenced in config file {file} is not set")]
+    MissingEnvVar { file: String, variable: String },
+    #[error("unterminated environment variable reference in config file {0}")]
+    UnterminatedEnvVar(String),
+    #[error("failed to parse config")]
+    Parse,
+}
+
+/// Loads the config from layered config files. For every given path, the base file is followed by the file of the
+/// network profile (e.g. `config.mainnet.toml` next to `config.toml`) and the file with local overrides
+/// (e.g. `config.local.toml`). Later layers override earlier ones, and environment variables prefixed with `AMPD_`
+/// override all files. References to environment variables in the string values of the files (`${NAME}`) are replaced by
+/// their values, `$$` escapes a literal `$`. Missing profiles and environment variables are errors, so a broken config is
+/// caught at startup
+pub fn load(config_paths: &[PathBuf], profile: Option<&str>) -> Result<Config, Error> {
+    let files = layered_config_files(config_paths, profile)?
+        .into_iter()
+        .map(|path| load_file(&path, &|name: &str| std::env::var(name).ok()))
+        .collect::<Result<Vec<InterpolatedFile>, Error>>()?;
+
+    Builder::builder()
+        .add_source(files)
+        .add_source(Environment::with_prefix(clap::crate_name!()))
+        .build()
+        .and_then(|config| config.try_deserialize::<Config>())
+        .change_context(Error::Parse)
+}
+
+/// Returns the existing config files in the order in which they are layered: all base files, then all files of the profile,
+/// then all files with local overrides
+fn layered_config_files(
+    config_paths: &[PathBuf],
+    profile: Option<&str>,
+) -> Result<Vec<PathBuf>, Error> {
+    if let Some(profile) = profile {
+        ensure!(
+            !profile.is_empty()
+                && profile != LOCAL_OVERRIDES
+                && profile
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'),
+            Error::InvalidProfile(profile.to_string())
+        );
+    }
+
+    let base_paths: Vec<PathBuf> = config_paths.iter().map(expand_home_dir).collect();
+    let existing = |suffix: Option<&str>| -> Vec<PathBuf> {
+        base_paths
+            .iter()
+            .map(|path| match suffix {
+                Some(suffix) => with_suffix(path, suffix),
+                None => path.clone(),
+            })
+            .filter_map(|path| fs::canonicalize(path).ok())
+            .inspect(|path| info!("found config file {}", path.to_string_lossy()))
+            .collect()
+    };
+
+    let base_files = existing(None);
+    let profile_files = match profile {
+        Some(profile) => {
+            let files = existing(Some(profile));
+            ensure!(
+                !files.is_empty(),
+                Error::ProfileNotFound(profile.to_string())
+            );
+            files
+        }
+        None => vec![],
+    };
+    let local_files = existing(Some(LOCAL_OVERRIDES));
+
+    let files = [base_files, profile_files, local_files].concat();
+    if files.is_empty() {
+        info!("found no config files to load");
+    }
+
+    Ok(files)
+}
+
+/// Inserts the suffix between the file stem and the extension, e.g. `config.toml` becomes `config.mainnet.toml`
+fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    let file_name = match path.extension() {
+        Some(extension) => format!("{}.{}.{}", stem, suffix, extension.to_string_lossy()),
+        None => format!("{}.{}", stem, suffix),
+    };
+
+    path.with_file_name(file_name)
+}
+
+fn file_format(path: &Path) -> FileFormat {
+    match path.extension().and_then(|extension| extension.to_str()) {
+        Some("json") => FileFormat::Json,
+        Some("yaml") | Some("yml") => FileFormat::Yaml,
+        _ => FileFormat::Toml,
+    }
+}
+
+/// Config file whose string values had their environment variable references replaced
+#[derive(Clone, Debug)]
+struct InterpolatedFile(Map<String, Value>);
+
+impl Source for InterpolatedFile {
+    fn clone_into_box(&self) -> Box<dyn Source + Send + Sync> {
+        Box::new(self.clone())
+    }
+
+    fn collect(&self) -> std::result::Result<Map<String, Value>, ConfigError> {
+        Ok(self.0.clone())
+    }
+}
+
+/// Parses the file before interpolating, so references in comments are ignored and substituted values never need to be
+/// escaped for the file format
+fn load_file(
+    path: &Path,
+    lookup: &impl Fn(&str) -> Option<String>,
+) -> Result<InterpolatedFile, Error> {
+    let file = path.to_string_lossy().to_string();
+    let content = fs::read_to_string(path).change_context(Error::ReadFile(file.clone()))?;
+
+    let mut values = File::from_str(&content, file_format(path))
+        .collect()
+        .change_context(Error::ParseFile(file.clone()))?;
+    for value in values.values_mut() {
+        interpolate_value(value, &file, lookup)?;
+    }
+
+    Ok(InterpolatedFile(values))
+}
+
+fn interpolate_value(
+    value: &mut Value,
+    file: &str,
+    lookup: &impl Fn(&str) -> Option<String>,
+) -> Result<(), Error> {
+    match &mut value.kind {
+        ValueKind::String(content) => *content = interpolate_env_vars(content, file, lookup)?,
+        ValueKind::Table(table) => {
+            for value in table.values_mut() {
+                interpolate_value(value, file, lookup)?;
+            }
+        }
+        ValueKind::Array(array) => {
+            for value in array.iter_mut() {
+                interpolate_value(value, file, lookup)?;
+            }
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+fn interpolate_env_vars(
+    content: &str,
+    file: &str,
+    lookup: &impl Fn(&str) -> Option<String>,
+) -> Result<String, Error> {
+    let mut interpolated = String::with_capacity(content.len());
+    let mut rest = content;
+
+    while let Some(start) = rest.find('$') {
+        interpolated.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        if let Some(escaped) = rest.strip_prefix("$$") {
+            interpolated.push('$');
+            rest = escaped;
+        } else if let Some(reference) = rest.strip_prefix("${") {
+            let end = reference
+                .find('}')
+                .ok_or(report!(Error::UnterminatedEnvVar(file.to_string())))?;
+            let variable = &reference[..end];
+            let value = lookup(variable).ok_or(report!(Error::MissingEnvVar {
+                file: file.to_string(),
+                variable: variable.to_string(),
+            }))?;
+
+            interpolated.push_str(&value);
+            rest = &reference[end.saturating_add(1)..];
+        } else {
+            interpolated.push('$');
+            rest = &rest[1..];
+        }
+    }
+    interpolated.push_str(rest);
+
+    Ok(interpolated)
+}
+
+pub fn expand_home_dir(path: impl AsRef<Path>) -> PathBuf {
+    let path = path.as_ref();
+    let Ok(home_subfolder) = path.strip_prefix("~") else {
+        return path.to_path_buf();
+    };
+
+    dirs::home_dir().map_or(path.to_path_buf(), |home| home.join(home_subfolder))
+}
+
 #[cfg(test)]
 mod tests {
+    use std::collections::HashMap;
     use std::fs;
     use std::fs::File;
     use std::io::Write;
@@ -58,8 +325,9 @@ mod tests {
     use cosmrs::AccountId;
     use router_api::ChainName;
 
-    use super::Config;
+    use super::{interpolate_env_vars, load, load_file, Config, Error};
     use crate::evm::finalizer::Finalization;
+    use crate::handlers;
     use crate::handlers::config::{Chain, Config as HandlerConfig};
     use crate::types::TMAddress;
     use crate::url::Url;
@@ -175,6 +443,51 @@ mod tests {
         assert!(cfg.is_err());
     }
 
+    #[test]
+    fn deserialize_grpc_clients() {
+        let token_sha256 = "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08";
+        let config_str = format!(
+            "
+            [grpc]
+            ip_addr = '0.0.0.0'
+            port = 9091
+            concurrency_limit = 2048
+            concurrency_limit_per_connection = 256
+
+            [[grpc.clients]]
+            name = 'relayer'
+            token_sha256 = '{token_sha256}'
+            weight = 3
+            ",
+        );
+        let cfg: Config = toml::from_str(config_str.as_str()).unwrap();
+
+        assert_eq!(cfg.grpc.clients.len(), 1);
+        assert_eq!(cfg.grpc.clients[0].name, "relayer");
+        assert_eq!(hex::encode(cfg.grpc.clients[0].token_sha256), token_sha256);
+        assert_eq!(usize::from(cfg.grpc.clients[0].weight), 3);
+
+        let config_str = format!(
+            "
+            [grpc]
+            ip_addr = '0.0.0.0'
+            port = 9091
+            concurrency_limit = 2048
+            concurrency_limit_per_connection = 256
+
+            [[grpc.clients]]
+            name = 'relayer'
+            token_sha256 = '{token_sha256}'
+
+            [[grpc.clients]]
+            name = 'other'
+            token_sha256 = '{token_sha256}'
+            ",
+        );
+        let cfg: Result<Config, _> = toml::from_str(config_str.as_str());
+        assert!(cfg.is_err());
+    }
+
     #[test]
     fn deserialize_handlers() {
         let config_str = format!(
@@ -376,6 +689,149 @@ mod tests {
         assert_eq!(cfg.tm_grpc.as_str(), expected_url);
     }
 
+    fn config_dir(files: &[(&str, &str)]) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("ampd-{}", rand::random::<u64>()));
+        fs::create_dir_all(&dir).unwrap();
+
+        for (name, content) in files {
+            fs::write(dir.join(name), content).unwrap();
+        }
+
+        dir
+    }
+
+    #[test]
+    fn load_should_layer_profile_and_local_overrides_on_base_config() {
+        let dir = config_dir(&[
+            (
+                "config.toml",
+                "tm_jsonrpc = 'http://base:26657'\ntm_grpc = 'tcp://base:9090'\nhealth_check_bind_addr = '0.0.0.0:3001'\n",
+            ),
+            (
+                "config.mainnet.toml",
+                "tm_jsonrpc = 'http://mainnet:26657'\nhealth_check_bind_addr = '0.0.0.0:3002'\n",
+            ),
+            (
+                "config.testnet.toml",
+                "tm_jsonrpc = 'http://testnet:26657'\n",
+            ),
+            ("config.local.toml", "tm_grpc = 'tcp://local:9090'\n"),
+        ]);
+        let paths = vec![dir.join("config.toml")];
+
+        let cfg = load(&paths, Some("mainnet")).unwrap();
+        assert_eq!(cfg.tm_jsonrpc.as_str(), "http://mainnet:26657/");
+        assert_eq!(cfg.tm_grpc.as_str(), "tcp://local:9090");
+        assert_eq!(cfg.health_check_bind_addr.port(), 3002);
+
+        let cfg = load(&paths, None).unwrap();
+        assert_eq!(cfg.tm_jsonrpc.as_str(), "http://base:26657/");
+        assert_eq!(cfg.tm_grpc.as_str(), "tcp://local:9090");
+        assert_eq!(cfg.health_check_bind_addr.port(), 3001);
+    }
+
+    #[test]
+    fn load_should_fail_for_missing_or_invalid_profile() {
+        let dir = config_dir(&[("config.toml", "")]);
+        let paths = vec![dir.join("config.toml")];
+
+        for (profile, expected) in [
+            ("devnet", Error::ProfileNotFound("devnet".to_string())),
+            (
+                "../mainnet",
+                Error::InvalidProfile("../mainnet".to_string()),
+            ),
+            ("local", Error::InvalidProfile("local".to_string())),
+        ] {
+            assert_eq!(
+                load(&paths, Some(profile)).unwrap_err().current_context(),
+                &expected
+            );
+        }
+    }
+
+    #[test]
+    fn load_should_interpolate_env_vars() {
+        let variable = format!("TEST_TM_JSONRPC_{}", rand::random::<u64>());
+        let dir = config_dir(&[(
+            "config.toml",
+            &format!("tm_jsonrpc = '${{{}}}'\n", variable),
+        )]);
+        let paths = vec![dir.join("config.toml")];
+
+        assert!(matches!(
+            load(&paths, None).unwrap_err().current_context(),
+            Error::MissingEnvVar { .. }
+        ));
+
+        std::env::set_var(&variable, "http://node:26657");
+        assert_eq!(
+            load(&paths, None).unwrap().tm_jsonrpc.as_str(),
+            "http://node:26657/"
+        );
+    }
+
+    #[test]
+    fn interpolate_env_vars_should_replace_references_and_unescape() {
+        let lookup = |name: &str| (name == "KEY").then(|| "value".to_string());
+
+        assert_eq!(
+            interpolate_env_vars(
+                "a = '${KEY}'\nb = '$$${KEY}$$'\nc = '$1'",
+                "config.toml",
+                &lookup
+            )
+            .unwrap(),
+            "a = 'value'\nb = '$value$'\nc = '$1'"
+        );
+        assert_eq!(
+            interpolate_env_vars("a = '${OTHER}'", "config.toml", &lookup)
+                .unwrap_err()
+                .current_context(),
+            &Error::MissingEnvVar {
+                file: "config.toml".to_string(),
+                variable: "OTHER".to_string()
+            }
+        );
+        assert_eq!(
+            interpolate_env_vars("a = '${KEY'", "config.toml", &lookup)
+                .unwrap_err()
+                .current_context(),
+            &Error::UnterminatedEnvVar("config.toml".to_string())
+        );
+    }
+
+    #[test]
+    fn load_file_should_only_interpolate_values() {
+        let dir = config_dir(&[(
+            "config.toml",
+            "# set ${UNSET} to override\n[table]\nkey = '${KEY}'\nlist = ['${KEY}', 1]\n",
+        )]);
+        let value = r#"it's "quoted" \ and 'escaped'"#;
+        let lookup = |name: &str| (name == "KEY").then(|| value.to_string());
+
+        let mut table = load_file(&dir.join("config.toml"), &lookup)
+            .unwrap()
+            .0
+            .remove("table")
+            .unwrap()
+            .into_table()
+            .unwrap();
+
+        assert_eq!(table.remove("key").unwrap().into_string().unwrap(), value);
+        assert_eq!(
+            table
+                .remove("list")
+                .unwrap()
+                .into_array()
+                .unwrap()
+                .remove(0)
+                .into_string()
+                .unwrap(),
+            value
+        );
+    }
+
     #[test]
     fn fail_deserialization() {
         assert!(toml::from_str::<Config>("tm_jsonrpc = 'some other string'").is_err());
@@ -408,6 +864,40 @@ mod tests {
         assert_eq!(cfg.tofnd_config.key_uid.as_str(), key_uid);
     }
 
+    #[test]
+    fn deserialize_circuit_breaker_and_chain_lag_config_per_chain() {
+        let config_str = "
+            [circuit_breaker.ethereum]
+            max_failures = 5
+            max_latency = '2s'
+
+            [circuit_breaker.polygon]
+            max_failures = 10
+
+            [chain_lag.ethereum]
+            max_lag = 10
+            ";
+
+        let cfg: Config = toml::from_str(config_str).unwrap();
+
+        let ethereum = &cfg.circuit_breaker[&ChainName::from_str("ethereum").unwrap()];
+        assert_eq!(ethereum.max_failures, Some(5));
+        assert_eq!(ethereum.max_latency, Some(Duration::from_secs(2)));
+        assert_eq!(ethereum.window, 20);
+
+        let polygon = &cfg.circuit_breaker[&ChainName::from_str("polygon").unwrap()];
+        assert_eq!(polygon.max_failures, Some(10));
+        assert_eq!(polygon.max_latency, None);
+
+        assert_eq!(
+            cfg.chain_lag,
+            HashMap::from([(
+                ChainName::from_str("ethereum").unwrap(),
+                handlers::chain_lag::Config { max_lag: Some(10) }
+            )])
+        );
+    }
+
     #[test]
     fn can_serialize_deserialize_config() {
         let cfg = config_template();
@@ -449,6 +939,7 @@ mod tests {
                     cosmwasm_contract: TMAddress::from(
                         AccountId::new("axelar", &[0u8; 32]).unwrap(),
                     ),
+                    cross_check_rpc_urls: vec![],
                 },
                 HandlerConfig::EvmVerifierSetVerifier {
                     cosmwasm_contract: TMAddress::from(
@@ -0,0 +1,327 @@
+use cosmrs::proto::cosmwasm::wasm::v1::query_client::QueryClient;
+use cosmrs::proto::cosmwasm::wasm::v1::QueryRawContractStateRequest;
+use error_stack::{report, Report, Result, ResultExt};
+use report::LoggableError;
+use semver::{Version, VersionReq};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tonic::transport::Channel;
+use tracing::{info, warn};
+use valuable::Valuable;
+
+use crate::config::Config as AmpdConfig;
+use crate::handlers::config::Config as HandlerConfig;
+use crate::types::TMAddress;
+
+/// Storage key under which cw2 stores the contract name and version
+pub const CW2_CONTRACT_INFO_KEY: &[u8] = b"contract_info";
+
+/// Contract versions this ampd version works with, by cw2 contract name
+const SUPPORTED_VERSIONS: [(&str, &str); 5] = [
+    ("voting-verifier", "^1.1.0"),
+    ("multisig", "^2.0.0"),
+    ("rewards", "^1.2.0"),
+    ("service-registry", "^1.1.0"),
+    ("router", "^1.2.0"),
+];
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Mode {
+    /// ampd doesn't start if any contract is unsupported
+    #[default]
+    Refuse,
+    /// unsupported contracts are logged, ampd starts anyway
+    Warn,
+    /// contracts are not checked
+    Disabled,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+#[serde(default)]
+pub struct Config {
+    pub mode: Mode,
+    /// Address of the router contract, which is only checked if set because ampd doesn't interact with it otherwise
+    pub router: Option<TMAddress>,
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum Error {
+    #[error("failed to query the cw2 version of contract {0}")]
+    Query(TMAddress),
+    #[error("contract {0} has no cw2 version")]
+    MissingVersion(TMAddress),
+    #[error("expected contract {expected} at {address} but found {found}")]
+    UnexpectedContract {
+        address: TMAddress,
+        expected: String,
+        found: String,
+    },
+    #[error(
+        "{contract} {version} at {address} is not supported, supported versions are {supported}"
+    )]
+    UnsupportedVersion {
+        address: TMAddress,
+        contract: String,
+        version: String,
+        supported: String,
+    },
+    #[error("contracts are not compatible with this ampd version")]
+    Incompatible,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct ContractVersion {
+    pub contract: String,
+    pub version: String,
+}
+
+/// Checks the cw2 versions of all contracts referenced by the config against the versions this ampd version supports.
+/// Depending on the configured mode, unsupported contracts are an error or only logged
+pub async fn check(config: &AmpdConfig, client: &mut QueryClient<Channel>) -> Result<(), Error> {
+    let mode = config.contract_compatibility.mode;
+    if mode == Mode::Disabled {
+        return Ok(());
+    }
+
+    let mut failures = vec![];
+    for (item, address, expected_name) in contracts(config) {
+        let Some(expected_name) = expected_name else {
+            continue;
+        };
+
+        match query_contract_version(client, &address)
+            .await
+            .and_then(|version| check_version(&address, expected_name, &version))
+        {
+            Ok(version) => info!(
+                contract = item,
+                address = address.to_string(),
+                version = version.to_string(),
+                "contract is compatible"
+            ),
+            Err(err) => failures.push(err),
+        }
+    }
+
+    match (mode, failures.into_iter().reduce(extend)) {
+        (_, None) => Ok(()),
+        (Mode::Warn, Some(err)) => {
+            warn!(
+                err = LoggableError::from(&err).as_value(),
+                "contracts are not compatible with this ampd version, starting anyway"
+            );
+            Ok(())
+        }
+        (_, Some(err)) => Err(err.change_context(Error::Incompatible)),
+    }
+}
+
+fn extend(mut acc: Report<Error>, err: Report<Error>) -> Report<Error> {
+    acc.extend_one(err);
+    acc
+}
+
+pub async fn query_contract_version(
+    client: &mut QueryClient<Channel>,
+    address: &TMAddress,
+) -> Result<ContractVersion, Error> {
+    let raw_version = client
+        .raw_contract_state(QueryRawContractStateRequest {
+            address: address.to_string(),
+            query_data: CW2_CONTRACT_INFO_KEY.to_vec(),
+        })
+        .await
+        .map_err(Report::from)
+        .change_context(Error::Query(address.clone()))?
+        .into_inner()
+        .data;
+
+    serde_json::from_slice(&raw_version).change_context(Error::MissingVersion(address.clone()))
+}
+
+/// Returns the version of the contract if it is the expected contract in a supported version
+pub fn check_version(
+    address: &TMAddress,
+    expected_name: &str,
+    version: &ContractVersion,
+) -> Result<Version, Error> {
+    if version.contract != expected_name {
+        return Err(report!(Error::UnexpectedContract {
+            address: address.clone(),
+            expected: expected_name.to_string(),
+            found: format!("{} {}", version.contract, version.version),
+        }));
+    }
+
+    let supported = SUPPORTED_VERSIONS
+        .iter()
+        .find(|(name, _)| *name == expected_name)
+        .map(|(_, supported)| {
+            VersionReq::parse(supported).expect("supported versions should be valid")
+        });
+    let unsupported = || Error::UnsupportedVersion {
+        address: address.clone(),
+        contract: version.contract.clone(),
+        version: version.version.clone(),
+        supported: supported
+            .as_ref()
+            .map_or("unknown".to_string(), VersionReq::to_string),
+    };
+
+    let parsed = Version::parse(&version.version).change_context_lazy(unsupported)?;
+    match &supported {
+        Some(supported) if !supported.matches(&parsed) => Err(report!(unsupported())),
+        _ => Ok(parsed),
+    }
+}
+
+/// Returns all contracts referenced by the config together with the expected cw2 contract name, if known
+pub fn contracts(config: &AmpdConfig) -> Vec<(&'static str, TMAddress, Option<&'static str>)> {
+    let mut contracts = vec![
+        (
+            "service registry",
+            config.service_registry.cosmwasm_contract.clone(),
+            Some("service-registry"),
+        ),
+        (
+            "rewards",
+            config.rewards.cosmwasm_contract.clone(),
+            Some("rewards"),
+        ),
+    ];
+
+    if let Some(router) = &config.contract_compatibility.router {
+        contracts.push(("router", router.clone(), Some("router")));
+    }
+
+    for handler in &config.handlers {
+        match handler {
+            HandlerConfig::MultisigSigner {
+                cosmwasm_contract, ..
+            } => contracts.push(("multisig", cosmwasm_contract.clone(), Some("multisig"))),
+            HandlerConfig::XRPLMultisigSigner {
+                multisig_prover_contract,
+                multisig_contract,
+            } => {
+                contracts.push((
+                    "xrpl multisig prover",
+                    multisig_prover_contract.clone(),
+                    None,
+                ));
+                contracts.push(("multisig", multisig_contract.clone(), Some("multisig")));
+            }
+            HandlerConfig::EvmMsgVerifier {
+                cosmwasm_contract, ..
+            }
+            | HandlerConfig::EvmVerifierSetVerifier {
+                cosmwasm_contract, ..
+            }
+            | HandlerConfig::SuiMsgVerifier {
+                cosmwasm_contract, ..
+            }
+            | HandlerConfig::SuiVerifierSetVerifier {
+                cosmwasm_contract, ..
+            }
+            | HandlerConfig::XRPLMsgVerifier {
+                cosmwasm_contract, ..
+            }
+            | HandlerConfig::MvxMsgVerifier {
+                cosmwasm_contract, ..
+            }
+            | HandlerConfig::MvxVerifierSetVerifier {
+                cosmwasm_contract, ..
+            }
+            | HandlerConfig::StellarMsgVerifier {
+                cosmwasm_contract, ..
+            }
+            | HandlerConfig::StellarVerifierSetVerifier {
+                cosmwasm_contract, ..
+            }
+            | HandlerConfig::StarknetMsgVerifier {
+                cosmwasm_contract, ..
+            }
+            | HandlerConfig::StarknetVerifierSetVerifier {
+                cosmwasm_contract, ..
+            }
+            | HandlerConfig::SolanaMsgVerifier {
+                cosmwasm_contract, ..
+            }
+            | HandlerConfig::SolanaVerifierSetVerifier {
+                cosmwasm_contract, ..
+            } => contracts.push((
+                "voting verifier",
+                cosmwasm_contract.clone(),
+                Some("voting-verifier"),
+            )),
+        }
+    }
+
+    contracts.sort_by(|(_, a, _), (_, b, _)| a.to_string().cmp(&b.to_string()));
+    contracts.dedup_by(|(_, a, _), (_, b, _)| a == b);
+    contracts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PREFIX;
+
+    fn version(contract: &str, version: &str) -> ContractVersion {
+        ContractVersion {
+            contract: contract.to_string(),
+            version: version.to_string(),
+        }
+    }
+
+    #[test]
+    fn supported_versions_should_be_valid() {
+        for (_, supported) in SUPPORTED_VERSIONS {
+            assert!(VersionReq::parse(supported).is_ok());
+        }
+    }
+
+    #[test]
+    fn check_version_should_accept_supported_versions() {
+        let address = TMAddress::random(PREFIX);
+
+        assert_eq!(
+            check_version(&address, "multisig", &version("multisig", "2.0.0")).unwrap(),
+            Version::new(2, 0, 0)
+        );
+        assert_eq!(
+            check_version(&address, "multisig", &version("multisig", "2.3.1")).unwrap(),
+            Version::new(2, 3, 1)
+        );
+    }
+
+    #[test]
+    fn check_version_should_reject_unsupported_versions() {
+        let address = TMAddress::random(PREFIX);
+
+        for unsupported in ["1.1.0", "3.0.0", "not a version"] {
+            assert!(matches!(
+                check_version(&address, "multisig", &version("multisig", unsupported))
+                    .unwrap_err()
+                    .current_context(),
+                Error::UnsupportedVersion { .. }
+            ));
+        }
+    }
+
+    #[test]
+    fn check_version_should_reject_unexpected_contracts() {
+        let address = TMAddress::random(PREFIX);
+
+        assert_eq!(
+            check_version(&address, "multisig", &version("rewards", "1.2.0"))
+                .unwrap_err()
+                .current_context(),
+            &Error::UnexpectedContract {
+                address,
+                expected: "multisig".to_string(),
+                found: "rewards 1.2.0".to_string(),
+            }
+        );
+    }
+}
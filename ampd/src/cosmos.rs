@@ -13,6 +13,10 @@ use cosmrs::proto::cosmos::tx::v1beta1::{
     BroadcastMode, BroadcastTxRequest, BroadcastTxResponse, GetTxRequest, GetTxResponse,
     SimulateRequest, SimulateResponse, TxRaw,
 };
+use cosmrs::proto::cosmwasm::wasm::v1::query_client::QueryClient as WasmQueryClient;
+use cosmrs::proto::cosmwasm::wasm::v1::{
+    QuerySmartContractStateRequest, QuerySmartContractStateResponse,
+};
 use cosmrs::tx::MessageExt;
 use cosmrs::{Any, Gas};
 use error_stack::{report, ResultExt};
@@ -28,6 +32,11 @@ use crate::types::{CosmosPublicKey, TMAddress};
 
 type Result<T> = error_stack::Result<T, Error>;
 
+/// Maximum size in bytes of a smart query response, so a single query can't make ampd hold arbitrarily large contract
+/// state (4 MiB, the default message size limit of gRPC clients). Larger responses are rejected by the decoder as soon as
+/// their length is known, before they are buffered in full
+pub const MAX_QUERY_RESPONSE_SIZE: usize = 4_194_304;
+
 #[derive(Error, Debug)]
 pub enum Error {
     #[error("failed to connect to the grpc endpoint")]
@@ -64,6 +73,8 @@ mock! {
         async fn account(&mut self, address: QueryAccountRequest) -> Result<QueryAccountResponse>;
 
         async fn balance(&mut self, request: QueryBalanceRequest) -> Result<QueryBalanceResponse>;
+
+        async fn smart_contract_state(&mut self, request: QuerySmartContractStateRequest) -> Result<QuerySmartContractStateResponse>;
     }
 }
 
@@ -76,6 +87,11 @@ pub trait CosmosClient {
     async fn account(&mut self, address: QueryAccountRequest) -> Result<QueryAccountResponse>;
 
     async fn balance(&mut self, request: QueryBalanceRequest) -> Result<QueryBalanceResponse>;
+
+    async fn smart_contract_state(
+        &mut self,
+        request: QuerySmartContractStateRequest,
+    ) -> Result<QuerySmartContractStateResponse>;
 }
 
 /// CosmosGrpcClient implements the CosmosClient trait to interact with Cosmos blockchain nodes via gRPC.
@@ -86,7 +102,7 @@ pub trait CosmosClient {
 /// When `clone()` is called on a CosmosGrpcClient:
 ///
 /// - A new CosmosGrpcClient instance is created with cloned fields
-/// - The underlying gRPC clients (auth, bank, service) are cloned
+/// - The underlying gRPC clients (auth, bank, service, wasm) are cloned
 /// - The tonic::transport::Channel is cloned, but this doesn't create a new TCP connection
 ///   Instead, it creates a new reference to the same underlying connection pool
 ///
@@ -98,6 +114,7 @@ pub struct CosmosGrpcClient {
     auth: AuthQueryClient<Channel>,
     bank: BankQueryClient<Channel>,
     service: ServiceClient<Channel>,
+    wasm: WasmQueryClient<Channel>,
 }
 
 impl CosmosGrpcClient {
@@ -113,7 +130,8 @@ impl CosmosGrpcClient {
         Ok(Self {
             auth: AuthQueryClient::new(conn.clone()),
             bank: BankQueryClient::new(conn.clone()),
-            service: ServiceClient::new(conn),
+            service: ServiceClient::new(conn.clone()),
+            wasm: WasmQueryClient::new(conn).max_decoding_message_size(MAX_QUERY_RESPONSE_SIZE),
         })
     }
 }
@@ -159,6 +177,17 @@ impl CosmosClient for CosmosGrpcClient {
             .map(Response::into_inner)
             .map_err(ErrorExt::into_report)
     }
+
+    async fn smart_contract_state(
+        &mut self,
+        request: QuerySmartContractStateRequest,
+    ) -> Result<QuerySmartContractStateResponse> {
+        self.wasm
+            .smart_contract_state(request)
+            .await
+            .map(Response::into_inner)
+            .map_err(ErrorExt::into_report)
+    }
 }
 
 pub async fn estimate_gas<T>(
@@ -220,6 +249,24 @@ where
         .and_then(|res| res.tx_response.ok_or(report!(Error::TxResponseMissing)))
 }
 
+/// Runs the JSON encoded smart query against the contract and returns the JSON encoded result
+pub async fn contract_state<T>(
+    client: &mut T,
+    contract: &TMAddress,
+    query: Vec<u8>,
+) -> Result<Vec<u8>>
+where
+    T: CosmosClient,
+{
+    client
+        .smart_contract_state(QuerySmartContractStateRequest {
+            address: contract.to_string(),
+            query_data: query,
+        })
+        .await
+        .map(|res| res.data)
+}
+
 fn decode_base_account(account: Any) -> Result<BaseAccount> {
     BaseAccount::decode(&account.value[..]).change_context(Error::MalformedResponse)
 }
@@ -404,6 +451,30 @@ mod tests {
         assert_err_contains!(actual, Error, Error::TxResponseMissing);
     }
 
+    #[tokio::test]
+    async fn contract_state_success() {
+        let contract = TMAddress::random(PREFIX);
+        let query = br#"{"verifier_set":{}}"#.to_vec();
+        let data = br#"{"id":"1"}"#.to_vec();
+
+        let mut mock_client = MockCosmosClient::new();
+        let expected_address = contract.to_string();
+        let expected_query = query.clone();
+        let response_data = data.clone();
+        mock_client
+            .expect_smart_contract_state()
+            .withf(move |req| req.address == expected_address && req.query_data == expected_query)
+            .return_once(|_| {
+                Ok(QuerySmartContractStateResponse {
+                    data: response_data,
+                })
+            });
+
+        let actual = contract_state(&mut mock_client, &contract, query).await;
+
+        assert_eq!(actual.unwrap(), data);
+    }
+
     #[test]
     fn ensure_broadcast_tx_req_res_serialization_do_not_change() {
         let req = BroadcastTxRequest {
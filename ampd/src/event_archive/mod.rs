@@ -0,0 +1,531 @@
+use std::convert::Infallible;
+use std::io::Write;
+use std::num::NonZeroU64;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use cosmrs::Any;
+use error_stack::{report, Result, ResultExt};
+use events::Event;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use report::LoggableError;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tokio::fs;
+use tokio::io::AsyncWriteExt;
+use tokio::sync::Mutex;
+use tracing::{error, info};
+use valuable::Valuable;
+
+use crate::config::expand_home_dir;
+use crate::event_processor::EventHandler;
+use crate::types::TMAddress;
+
+pub mod s3;
+
+const FILE_PREFIX: &str = "events_";
+const FILE_EXTENSION: &str = ".ndjson.gz";
+const PARTIAL_EXTENSION: &str = "partial";
+const UPLOADED_EXTENSION: &str = "uploaded";
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("failed to write to event archive file {0}")]
+    Write(String),
+    #[error("failed to complete event archive file {0}")]
+    Complete(String),
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(default)]
+pub struct Config {
+    /// Directory the archive files are written to. The archive is disabled if not set
+    pub path: Option<PathBuf>,
+    /// Number of blocks covered by each archive file
+    pub blocks_per_file: NonZeroU64,
+    /// Events are archived if they match any of the filters. All events are archived if no filter is set
+    pub filters: Vec<Filter>,
+    /// If set, completed archive files are uploaded to S3-compatible object storage
+    pub upload: Option<s3::Config>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            path: None,
+            blocks_per_file: NonZeroU64::new(10000).expect("should not be zero"),
+            filters: vec![],
+            upload: None,
+        }
+    }
+}
+
+/// Matches events by type, emitting contract, or both
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct Filter {
+    pub event_type: Option<String>,
+    pub contract: Option<TMAddress>,
+}
+
+impl Filter {
+    fn matches(&self, event_type: &str, contract: Option<&TMAddress>) -> bool {
+        self.event_type
+            .as_ref()
+            .map_or(true, |filter| filter == event_type)
+            && self
+                .contract
+                .as_ref()
+                .map_or(true, |filter| Some(filter) == contract)
+    }
+}
+
+/// One line of an archive file
+#[derive(Serialize)]
+struct Record<'a> {
+    height: u64,
+    event_type: &'a str,
+    attributes: &'a serde_json::Map<String, serde_json::Value>,
+}
+
+struct State {
+    height: Option<u64>,
+    /// compressed records of the current block, appended to the archive file as one gzip member when the block ends
+    block: Option<GzEncoder<Vec<u8>>>,
+    /// first block height of the archive file that is currently written to
+    file_start: Option<u64>,
+}
+
+/// Writes events that match the configured filters to gzip compressed NDJSON files, one per range of `blocks_per_file` blocks.
+/// The file of the current range keeps a `.partial` extension. Once the range is over, the file is completed and
+/// never written to again, and uploaded to object storage if configured.
+/// Every block is appended as a separate gzip member, so a partial file can be resumed after a restart.
+#[derive(Clone)]
+pub struct EventArchive {
+    dir: PathBuf,
+    blocks_per_file: NonZeroU64,
+    filters: Vec<Filter>,
+    uploader: Option<s3::Client>,
+    state: Arc<Mutex<State>>,
+}
+
+impl EventArchive {
+    /// Returns None if the archive is disabled
+    pub fn new(config: Config) -> Option<Self> {
+        let dir = expand_home_dir(config.path?);
+
+        Some(Self {
+            dir,
+            blocks_per_file: config.blocks_per_file,
+            filters: config.filters,
+            uploader: config.upload.map(s3::Client::new),
+            state: Arc::new(Mutex::new(State {
+                height: None,
+                block: None,
+                file_start: None,
+            })),
+        })
+    }
+
+    async fn handle(&self, event: &Event) -> Result<(), Error> {
+        let mut state = self.state.lock().await;
+
+        match event {
+            Event::BlockBegin(height) => {
+                state.height = Some(height.value());
+                state.block = None;
+            }
+            Event::BlockEnd(height) => self.end_block(&mut state, height.value()).await?,
+            Event::Abci {
+                event_type,
+                attributes,
+            } => {
+                let Some(height) = state.height else {
+                    return Ok(());
+                };
+
+                if !self.matches(event_type, event.contract_address().map(Into::into)) {
+                    return Ok(());
+                }
+
+                let mut line = serde_json::to_vec(&Record {
+                    height,
+                    event_type,
+                    attributes,
+                })
+                .expect("event records should be serializable");
+                line.push(b'\n');
+
+                state
+                    .block
+                    .get_or_insert_with(|| GzEncoder::new(vec![], Compression::default()))
+                    .write_all(&line)
+                    .change_context(Error::Write(height.to_string()))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn matches(&self, event_type: &str, contract: Option<TMAddress>) -> bool {
+        self.filters.is_empty()
+            || self
+                .filters
+                .iter()
+                .any(|filter| filter.matches(event_type, contract.as_ref()))
+    }
+
+    async fn end_block(&self, state: &mut State, height: u64) -> Result<(), Error> {
+        let file_start = height
+            .checked_div(self.blocks_per_file.get())
+            .and_then(|range| range.checked_mul(self.blocks_per_file.get()))
+            .expect("blocks per file should not be zero");
+
+        if state.file_start != Some(file_start) {
+            self.complete_files_except(&self.partial_path(file_start))
+                .await?;
+            state.file_start = Some(file_start);
+        }
+
+        let Some(block) = state.block.take() else {
+            return Ok(());
+        };
+
+        let path = self.partial_path(file_start);
+        let error = || Error::Write(path.to_string_lossy().to_string());
+
+        let content = block.finish().change_context_lazy(error)?;
+        fs::create_dir_all(&self.dir)
+            .await
+            .change_context_lazy(error)?;
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .await
+            .change_context_lazy(error)?;
+        file.write_all(&content).await.change_context_lazy(error)?;
+        file.flush().await.change_context_lazy(error)
+    }
+
+    /// Completes all partial files except the given one. This includes files of earlier runs that were interrupted.
+    /// Completed files that have not been uploaded yet are uploaded again, so failed uploads are retried
+    async fn complete_files_except(&self, current: &Path) -> Result<(), Error> {
+        let error = || Error::Complete(self.dir.to_string_lossy().to_string());
+
+        let mut entries = match fs::read_dir(&self.dir).await {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+            Err(err) => return Err(err).change_context_lazy(error),
+        };
+
+        while let Some(entry) = entries.next_entry().await.change_context_lazy(error)? {
+            let path = entry.path();
+            if path == current {
+                continue;
+            }
+
+            if path.extension().is_some_and(|ext| ext == PARTIAL_EXTENSION) {
+                let completed = path.with_extension("");
+                fs::rename(&path, &completed)
+                    .await
+                    .change_context_lazy(|| Error::Complete(path.to_string_lossy().to_string()))?;
+                info!(
+                    file = completed.to_string_lossy().to_string(),
+                    "completed event archive file"
+                );
+
+                self.upload(completed);
+            } else if is_completed_file(&path)
+                && !fs::try_exists(uploaded_marker(&path))
+                    .await
+                    .change_context_lazy(error)?
+            {
+                self.upload(path);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn upload(&self, path: PathBuf) {
+        let Some(uploader) = self.uploader.clone() else {
+            return;
+        };
+
+        tokio::spawn(async move {
+            let name = path
+                .file_name()
+                .expect("archive files should have a name")
+                .to_string_lossy()
+                .to_string();
+
+            let result = match fs::read(&path).await {
+                Ok(content) => uploader.put(&name, content).await,
+                Err(err) => Err(err).change_context(s3::Error::Upload(name.clone())),
+            };
+
+            match result {
+                Ok(()) => {
+                    info!(file = name, "uploaded event archive file");
+
+                    if let Err(err) = fs::write(uploaded_marker(&path), []).await {
+                        error!(
+                            err = LoggableError::from(&report!(err)).as_value(),
+                            "failed to mark event archive file as uploaded, it is uploaded again"
+                        );
+                    }
+                }
+                Err(err) => error!(
+                    err = LoggableError::from(&err).as_value(),
+                    "failed to upload event archive file, it is kept locally and uploaded again once the next file is completed"
+                ),
+            }
+        });
+    }
+
+    fn partial_path(&self, file_start: u64) -> PathBuf {
+        let file_end = file_start.saturating_add(self.blocks_per_file.get().saturating_sub(1));
+
+        self.dir.join(format!(
+            "{}{:012}-{:012}{}.{}",
+            FILE_PREFIX, file_start, file_end, FILE_EXTENSION, PARTIAL_EXTENSION
+        ))
+    }
+}
+
+fn is_completed_file(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| name.starts_with(FILE_PREFIX) && name.ends_with(FILE_EXTENSION))
+}
+
+/// Empty file next to an archive file that marks it as uploaded
+fn uploaded_marker(path: &Path) -> PathBuf {
+    let mut marker = path.as_os_str().to_owned();
+    marker.push(".");
+    marker.push(UPLOADED_EXTENSION);
+    PathBuf::from(marker)
+}
+
+/// Feeds the event stream into the [EventArchive]. It never broadcasts any messages
+pub struct Handler {
+    archive: EventArchive,
+}
+
+impl Handler {
+    pub fn new(archive: EventArchive) -> Self {
+        Self { archive }
+    }
+}
+
+#[async_trait]
+impl EventHandler for Handler {
+    type Err = Infallible;
+
+    async fn handle(&self, event: &Event) -> Result<Vec<Any>, Infallible> {
+        // archiving is best effort and must not hold up the event stream
+        if let Err(err) = self.archive.handle(event).await {
+            error!(
+                err = LoggableError::from(&err).as_value(),
+                "failed to archive event"
+            );
+        }
+
+        Ok(vec![])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs as std_fs;
+    use std::io::Read;
+
+    use cosmrs::AccountId;
+    use flate2::read::MultiGzDecoder;
+    use tendermint::block;
+
+    use super::*;
+    use crate::PREFIX;
+
+    fn archive(dir: &Path, filters: Vec<Filter>) -> EventArchive {
+        EventArchive::new(Config {
+            path: Some(dir.to_path_buf()),
+            blocks_per_file: NonZeroU64::new(10).unwrap(),
+            filters,
+            upload: None,
+        })
+        .unwrap()
+    }
+
+    fn contract(seed: u8) -> TMAddress {
+        TMAddress::from(AccountId::new(PREFIX, &[seed; 32]).unwrap())
+    }
+
+    fn abci_event(event_type: &str, contract: &TMAddress) -> Event {
+        Event::Abci {
+            event_type: event_type.to_string(),
+            attributes: serde_json::json!({ "_contract_address": contract.to_string() })
+                .as_object()
+                .unwrap()
+                .clone(),
+        }
+    }
+
+    async fn block(archive: &EventArchive, height: u32, events: &[Event]) {
+        archive
+            .handle(&Event::BlockBegin(block::Height::from(height)))
+            .await
+            .unwrap();
+        for event in events {
+            archive.handle(event).await.unwrap();
+        }
+        archive
+            .handle(&Event::BlockEnd(block::Height::from(height)))
+            .await
+            .unwrap();
+    }
+
+    fn records(path: &Path) -> Vec<serde_json::Value> {
+        let mut content = String::new();
+        MultiGzDecoder::new(std_fs::File::open(path).unwrap())
+            .read_to_string(&mut content)
+            .unwrap();
+
+        content
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect()
+    }
+
+    fn temp_dir() -> PathBuf {
+        std::env::temp_dir().join(format!("ampd-event-archive-{}", rand::random::<u64>()))
+    }
+
+    #[tokio::test]
+    async fn files_should_be_completed_when_their_range_is_over() {
+        let dir = temp_dir();
+        let archive = archive(&dir, vec![]);
+        let contract = contract(1);
+
+        block(&archive, 8, &[abci_event("wasm-a", &contract)]).await;
+        block(&archive, 9, &[abci_event("wasm-b", &contract)]).await;
+
+        let partial = dir.join("events_000000000000-000000000009.ndjson.gz.partial");
+        assert_eq!(records(&partial).len(), 2);
+
+        block(&archive, 10, &[abci_event("wasm-c", &contract)]).await;
+
+        assert!(!partial.exists());
+        let completed = records(&dir.join("events_000000000000-000000000009.ndjson.gz"));
+        assert_eq!(completed.len(), 2);
+        assert_eq!(completed[0]["height"], 8);
+        assert_eq!(completed[0]["event_type"], "wasm-a");
+        assert_eq!(completed[1]["height"], 9);
+        assert_eq!(completed[1]["event_type"], "wasm-b");
+        assert_eq!(
+            records(&dir.join("events_000000000010-000000000019.ndjson.gz.partial")).len(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn partial_files_should_be_resumed_and_stale_ones_completed_after_restart() {
+        let dir = temp_dir();
+        let contract = contract(1);
+
+        let first_run = archive(&dir, vec![]);
+        block(&first_run, 5, &[abci_event("wasm-a", &contract)]).await;
+        block(&first_run, 15, &[abci_event("wasm-b", &contract)]).await;
+
+        let stale = dir.join("events_000000000000-000000000009.ndjson.gz.partial");
+        std_fs::copy(
+            dir.join("events_000000000000-000000000009.ndjson.gz"),
+            &stale,
+        )
+        .unwrap();
+        std_fs::remove_file(dir.join("events_000000000000-000000000009.ndjson.gz")).unwrap();
+
+        let second_run = archive(&dir, vec![]);
+        block(&second_run, 16, &[abci_event("wasm-c", &contract)]).await;
+
+        assert!(!stale.exists());
+        assert_eq!(
+            records(&dir.join("events_000000000000-000000000009.ndjson.gz")).len(),
+            1
+        );
+        assert_eq!(
+            records(&dir.join("events_000000000010-000000000019.ndjson.gz.partial")).len(),
+            2
+        );
+    }
+
+    #[tokio::test]
+    async fn only_events_matching_a_filter_should_be_archived() {
+        let dir = temp_dir();
+        let archived = contract(1);
+        let other = contract(2);
+        let archive = archive(
+            &dir,
+            vec![
+                Filter {
+                    event_type: Some("wasm-a".to_string()),
+                    contract: None,
+                },
+                Filter {
+                    event_type: Some("wasm-b".to_string()),
+                    contract: Some(archived.clone()),
+                },
+            ],
+        );
+
+        block(
+            &archive,
+            1,
+            &[
+                abci_event("wasm-a", &other),
+                abci_event("wasm-b", &other),
+                abci_event("wasm-b", &archived),
+                abci_event("wasm-c", &archived),
+            ],
+        )
+        .await;
+
+        let records = records(&dir.join("events_000000000000-000000000009.ndjson.gz.partial"));
+        let archived_events: Vec<_> = records
+            .iter()
+            .map(|record| {
+                (
+                    record["event_type"].as_str().unwrap(),
+                    record["attributes"]["_contract_address"].as_str().unwrap(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            archived_events,
+            vec![
+                ("wasm-a", other.to_string().as_str()),
+                ("wasm-b", archived.to_string().as_str()),
+            ]
+        );
+    }
+
+    #[test]
+    fn only_completed_files_should_be_uploaded() {
+        let file = Path::new("/archive/events_000000000000-000000000009.ndjson.gz");
+
+        assert!(is_completed_file(file));
+        assert!(!is_completed_file(&file.with_extension("gz.partial")));
+        assert!(!is_completed_file(&uploaded_marker(file)));
+        assert_eq!(
+            uploaded_marker(file),
+            Path::new("/archive/events_000000000000-000000000009.ndjson.gz.uploaded")
+        );
+    }
+
+    #[test]
+    fn archive_should_be_disabled_without_path() {
+        assert!(EventArchive::new(Config::default()).is_none());
+    }
+}
@@ -17,13 +17,20 @@ use valuable::Valuable;
 
 use crate::asyncutil::future::{self, RetryPolicy};
 use crate::asyncutil::task::TaskError;
-use crate::queue::queued_broadcaster::BroadcasterClient;
+use crate::broadcaster_v2::BroadcasterClient;
+use crate::poll_watchdog::{MissReason, PollWatchdog};
 
 #[async_trait]
 pub trait EventHandler {
     type Err: Context;
 
     async fn handle(&self, event: &Event) -> Result<Vec<Any>, Self::Err>;
+
+    /// Called once all messages the handler returned for the event have been broadcast, so handlers can record
+    /// that the event has been acted upon. It is not called for events the handler returned no messages for
+    async fn on_broadcast(&self, _event: &Event) -> Result<(), Self::Err> {
+        Ok(())
+    }
 }
 
 #[derive(Error, Debug)]
@@ -42,6 +49,13 @@ pub struct Config {
     #[serde(with = "humantime_serde")]
     pub stream_timeout: Duration,
     pub stream_buffer_size: usize,
+    /// If set, the events of contracts other than the ones of the configured handlers are dropped before they reach the handlers
+    #[serde(default)]
+    pub filter_by_contracts: bool,
+    /// If set, only the transactions that execute the contracts of the configured handlers are retrieved from the node.
+    /// Implies `filter_by_contracts` and requires the node to index transactions
+    #[serde(default)]
+    pub filter_pushdown: bool,
 }
 
 impl Default for Config {
@@ -51,17 +65,21 @@ impl Default for Config {
             retry_max_attempts: 3,
             stream_timeout: Duration::from_secs(15),
             stream_buffer_size: 100000,
+            filter_by_contracts: false,
+            filter_pushdown: false,
         }
     }
 }
 
 /// Let the `handler` consume events from the `event_stream`. The token is checked for cancellation
 /// at the end of each consumed block or when the `event_stream` times out. If the token is cancelled or the
-/// `event_stream` is closed, the function returns
+/// `event_stream` is closed, the function returns. Events that could not be handled or broadcast are
+/// reported to the `poll_watchdog`
 pub async fn consume_events<H, B, S, E>(
     handler_label: String,
     handler: H,
     broadcaster: B,
+    poll_watchdog: PollWatchdog,
     event_stream: S,
     event_processor_config: Config,
     token: CancellationToken,
@@ -83,6 +101,7 @@ where
             handle_event(
                 &handler,
                 &broadcaster,
+                &poll_watchdog,
                 event,
                 RetryPolicy::RepeatConstant {
                     sleep: event_processor_config.retry_delay,
@@ -109,6 +128,7 @@ where
 async fn handle_event<H, B>(
     handler: &H,
     broadcaster: &B,
+    poll_watchdog: &PollWatchdog,
     event: &Event,
     retry_policy: RetryPolicy,
 ) -> Result<(), Error>
@@ -119,16 +139,29 @@ where
     // if handlers run into errors we log them and then move on to the next event
     match future::with_retry(|| handler.handle(event), retry_policy).await {
         Ok(msgs) => {
+            let mut all_broadcast = !msgs.is_empty();
             for msg in msgs {
                 if let Err(err) = broadcaster.broadcast(msg.clone()).await {
+                    all_broadcast = false;
+                    poll_watchdog.record_failure(event, MissReason::BroadcastFailure);
                     warn!(
                         err = LoggableError::from(&err).as_value(),
                         "failed to broadcast message {:?} for event {}", msg, event
                     )
                 }
             }
+
+            if all_broadcast {
+                let _ = handler.on_broadcast(event).await.inspect_err(|err| {
+                    warn!(
+                        err = LoggableError::from(err).as_value(),
+                        "handler failed to process the broadcast for event {}", event,
+                    )
+                });
+            }
         }
         Err(err) => {
+            poll_watchdog.record_failure(event, MissReason::from_handler_error(&err));
             warn!(
                 err = LoggableError::from(&err).as_value(),
                 "handler failed to process event {}", event,
@@ -189,9 +222,16 @@ mod tests {
     use tokio::time::timeout;
     use tokio_util::sync::CancellationToken;
 
+    use crate::broadcaster_v2::{Error as BroadcasterError, MockBroadcasterClient};
     use crate::event_processor;
     use crate::event_processor::{consume_events, Config, Error, EventHandler};
-    use crate::queue::queued_broadcaster::{Error as BroadcasterError, MockBroadcasterClient};
+    use crate::poll_watchdog::PollWatchdog;
+    use crate::types::TMAddress;
+    use crate::PREFIX;
+
+    fn poll_watchdog() -> PollWatchdog {
+        PollWatchdog::new(TMAddress::random(PREFIX), [])
+    }
 
     pub fn setup_event_config(
         retry_delay_value: Duration,
@@ -202,6 +242,8 @@ mod tests {
             retry_max_attempts: 3,
             stream_timeout: stream_timeout_value,
             stream_buffer_size: 100000,
+            filter_by_contracts: false,
+            filter_pushdown: false,
         }
     }
 
@@ -228,6 +270,7 @@ mod tests {
                 "handler".to_string(),
                 handler,
                 broadcaster,
+                poll_watchdog(),
                 stream::iter(events),
                 event_config,
                 CancellationToken::new(),
@@ -258,6 +301,7 @@ mod tests {
                 "handler".to_string(),
                 handler,
                 broadcaster,
+                poll_watchdog(),
                 stream::iter(events),
                 event_config,
                 CancellationToken::new(),
@@ -289,6 +333,7 @@ mod tests {
                 "handler".to_string(),
                 handler,
                 broadcaster,
+                poll_watchdog(),
                 stream::iter(events),
                 event_config,
                 CancellationToken::new(),
@@ -324,6 +369,7 @@ mod tests {
                 "handler".to_string(),
                 handler,
                 broadcaster,
+                poll_watchdog(),
                 stream::iter(events),
                 event_config,
                 CancellationToken::new(),
@@ -351,7 +397,7 @@ mod tests {
         broadcaster
             .expect_broadcast()
             .times(2)
-            .returning(|_| Err(report!(BroadcasterError::EstimateFee)));
+            .returning(|_| Err(report!(BroadcasterError::EstimateGas)));
 
         let result_with_timeout = timeout(
             Duration::from_secs(3),
@@ -359,6 +405,7 @@ mod tests {
                 "handler".to_string(),
                 handler,
                 broadcaster,
+                poll_watchdog(),
                 stream::iter(events),
                 event_config,
                 CancellationToken::new(),
@@ -394,6 +441,7 @@ mod tests {
                 "handler".to_string(),
                 handler,
                 broadcaster,
+                poll_watchdog(),
                 stream::iter(events),
                 event_config,
                 token,
@@ -421,6 +469,7 @@ mod tests {
                 "handler".to_string(),
                 handler,
                 broadcaster,
+                poll_watchdog(),
                 stream::pending::<Result<Event, Error>>(), // never returns any items so it can time out
                 event_config,
                 token,
@@ -0,0 +1,304 @@
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use error_stack::{report, Result, ResultExt};
+use serde::{Deserialize, Serialize};
+use tendermint::block;
+use thiserror::Error;
+
+use crate::config::expand_home_dir;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("failed to load the event buffer from {0}")]
+    Load(String),
+    #[error("failed to persist the event buffer to {0}")]
+    Persist(String),
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(default)]
+pub struct Config {
+    /// Number of recently published blocks that are remembered. Missed blocks within this window are replayed
+    pub capacity: usize,
+    /// If set, the buffer is persisted to this file, so blocks that were missed while ampd was down are replayed on restart
+    pub path: Option<PathBuf>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            capacity: 1000,
+            path: None,
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize, Serialize, PartialEq)]
+struct State {
+    published: BTreeSet<u64>,
+    missed: BTreeSet<u64>,
+}
+
+/// Ring buffer of the heights of the most recently published blocks. It bridges gaps in the event stream:
+/// blocks whose events could not be retrieved are replayed once the node is reachable again, and blocks whose events
+/// were already published are never published a second time. Replayed blocks are published after the block that
+/// triggered the replay, so consumers must not rely on blocks arriving in order.
+pub struct EventBuffer {
+    capacity: usize,
+    path: Option<PathBuf>,
+    state: State,
+    /// heights that were scheduled, but have neither been published nor missed yet
+    in_flight: BTreeSet<u64>,
+    resumed: bool,
+}
+
+/// State of the buffer that still has to be written to its file
+pub struct Snapshot {
+    path: PathBuf,
+    content: Vec<u8>,
+}
+
+impl Snapshot {
+    pub async fn persist(self) -> Result<(), Error> {
+        // file IO blocks, so it must not run on the async runtime
+        let error = Error::Persist(self.path.to_string_lossy().to_string());
+        tokio::task::spawn_blocking(move || persist(&self.path, &self.content))
+            .await
+            .unwrap_or_else(|err| Err(report!(err).change_context(error)))
+    }
+}
+
+impl EventBuffer {
+    pub async fn load(config: Config) -> Result<Self, Error> {
+        let path = config.path.map(expand_home_dir);
+
+        let state = match path.clone() {
+            Some(path) => {
+                // file IO blocks, so it must not run on the async runtime
+                let error = Error::Load(path.to_string_lossy().to_string());
+                tokio::task::spawn_blocking(move || load(&path))
+                    .await
+                    .unwrap_or_else(|err| Err(report!(err).change_context(error)))?
+            }
+            None => State::default(),
+        };
+
+        Ok(Self {
+            capacity: config.capacity,
+            path,
+            state,
+            in_flight: BTreeSet::new(),
+            resumed: false,
+        })
+    }
+
+    /// Returns the heights to process for a newly streamed block: the previously missed blocks followed by the new
+    /// block itself, leaving out all blocks that were already published or are still being processed.
+    /// Missed blocks stay in the (persisted) buffer until they are published.
+    /// The first block after loading the buffer also schedules the blocks between the last published block and itself.
+    pub fn schedule(&mut self, height: block::Height) -> Vec<block::Height> {
+        if !self.resumed {
+            self.resumed = true;
+            self.schedule_restart_gap(height.value());
+        }
+
+        let mut heights: Vec<u64> = self
+            .state
+            .missed
+            .iter()
+            .copied()
+            .filter(|missed| *missed != height.value() && !self.in_flight.contains(missed))
+            .collect();
+        heights.push(height.value());
+        heights.retain(|height| !self.state.published.contains(height));
+
+        self.in_flight.extend(heights.iter().copied());
+
+        heights
+            .into_iter()
+            .filter_map(|height| block::Height::try_from(height).ok())
+            .collect()
+    }
+
+    /// Returns the snapshot to persist if the buffer is persistent
+    pub fn mark_published(&mut self, height: block::Height) -> Option<Snapshot> {
+        self.state.published.insert(height.value());
+        self.state.missed.remove(&height.value());
+        self.in_flight.remove(&height.value());
+
+        while self.state.published.len() > self.capacity {
+            self.state.published.pop_first();
+        }
+
+        self.snapshot()
+    }
+
+    /// Remembers the block to be replayed with the next scheduled block, unless it is already outside the buffer's window.
+    /// Returns the snapshot to persist if the buffer is persistent and changed
+    pub fn mark_missed(&mut self, height: block::Height) -> Option<Snapshot> {
+        let height = height.value();
+        self.in_flight.remove(&height);
+
+        if self.state.published.contains(&height) || !self.is_within_window(height) {
+            return None;
+        }
+
+        self.state.missed.insert(height);
+        self.snapshot()
+    }
+
+    fn schedule_restart_gap(&mut self, height: u64) {
+        let Some(last_published) = self.state.published.last().copied() else {
+            return;
+        };
+
+        let from = last_published
+            .saturating_add(1)
+            .max(height.saturating_sub(self.capacity as u64));
+
+        self.state.missed.extend(from..height);
+    }
+
+    fn is_within_window(&self, height: u64) -> bool {
+        match self.state.published.last() {
+            Some(last_published) => height >= last_published.saturating_sub(self.capacity as u64),
+            None => true,
+        }
+    }
+
+    fn snapshot(&self) -> Option<Snapshot> {
+        self.path.clone().map(|path| Snapshot {
+            path,
+            content: serde_json::to_vec(&self.state).expect("event buffer should serialize"),
+        })
+    }
+}
+
+fn load(path: &Path) -> Result<State, Error> {
+    let error = || Error::Load(path.to_string_lossy().to_string());
+
+    match fs::read(path) {
+        Ok(content) => serde_json::from_slice(&content).change_context_lazy(error),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(State::default()),
+        Err(err) => Err(err).change_context_lazy(error),
+    }
+}
+
+fn persist(path: &Path, content: &[u8]) -> Result<(), Error> {
+    let error = || Error::Persist(path.to_string_lossy().to_string());
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).change_context_lazy(error)?;
+    }
+
+    // write to a temporary file first so a crash never leaves a truncated file behind
+    let tmp_path = path.with_extension("tmp");
+    fs::write(&tmp_path, content).change_context_lazy(error)?;
+    fs::rename(&tmp_path, path).change_context_lazy(error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn height(height: u64) -> block::Height {
+        block::Height::try_from(height).unwrap()
+    }
+
+    fn heights(heights: impl IntoIterator<Item = u64>) -> Vec<block::Height> {
+        heights.into_iter().map(height).collect()
+    }
+
+    async fn persist(snapshot: Option<Snapshot>) {
+        snapshot.unwrap().persist().await.unwrap();
+    }
+
+    fn persistent_config(capacity: usize) -> Config {
+        Config {
+            capacity,
+            path: Some(
+                std::env::temp_dir()
+                    .join(format!("ampd-{}", rand::random::<u64>()))
+                    .join("event_buffer.json"),
+            ),
+        }
+    }
+
+    #[tokio::test]
+    async fn missed_blocks_should_be_replayed_with_the_next_block() {
+        let mut buffer = EventBuffer::load(Config::default()).await.unwrap();
+
+        assert_eq!(buffer.schedule(height(10)), heights([10]));
+        buffer.mark_missed(height(10));
+
+        assert_eq!(buffer.schedule(height(11)), heights([10, 11]));
+        buffer.mark_published(height(11));
+        buffer.mark_missed(height(10));
+
+        assert_eq!(buffer.schedule(height(12)), heights([10, 12]));
+        buffer.mark_published(height(10));
+        buffer.mark_published(height(12));
+
+        assert_eq!(buffer.schedule(height(13)), heights([13]));
+    }
+
+    #[tokio::test]
+    async fn published_blocks_should_not_be_scheduled_again() {
+        let mut buffer = EventBuffer::load(Config::default()).await.unwrap();
+
+        buffer.schedule(height(10));
+        buffer.mark_published(height(10));
+        buffer.mark_missed(height(10));
+
+        assert_eq!(buffer.schedule(height(10)), vec![]);
+    }
+
+    #[tokio::test]
+    async fn buffer_should_only_remember_capacity_many_blocks() {
+        let mut buffer = EventBuffer::load(Config {
+            capacity: 3,
+            path: None,
+        })
+        .await
+        .unwrap();
+
+        for h in 1..=10 {
+            buffer.schedule(height(h));
+            buffer.mark_published(height(h));
+        }
+
+        assert_eq!(buffer.state.published, BTreeSet::from_iter([8, 9, 10]));
+
+        // blocks outside of the window are not replayed anymore
+        buffer.mark_missed(height(2));
+        assert_eq!(buffer.schedule(height(11)), heights([11]));
+    }
+
+    #[tokio::test]
+    async fn blocks_missed_while_down_should_be_replayed_after_restart() {
+        let config = persistent_config(5);
+
+        let mut buffer = EventBuffer::load(config.clone()).await.unwrap();
+        buffer.schedule(height(10));
+        persist(buffer.mark_missed(height(10))).await;
+        buffer.schedule(height(11));
+        persist(buffer.mark_published(height(11))).await;
+
+        let mut buffer = EventBuffer::load(config).await.unwrap();
+        assert_eq!(buffer.schedule(height(14)), heights([10, 12, 13, 14]));
+    }
+
+    #[tokio::test]
+    async fn restart_gap_should_be_limited_to_capacity() {
+        let config = persistent_config(5);
+
+        let mut buffer = EventBuffer::load(config.clone()).await.unwrap();
+        buffer.schedule(height(10));
+        persist(buffer.mark_published(height(10))).await;
+
+        let mut buffer = EventBuffer::load(config).await.unwrap();
+        assert_eq!(buffer.schedule(height(100)), heights(95..=100));
+    }
+}
@@ -1,3 +1,4 @@
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
 use error_stack::{Report, Result};
@@ -9,6 +10,7 @@ use tendermint::block;
 use thiserror::Error;
 use tokio::select;
 use tokio::sync::broadcast::{self, Sender};
+use tokio::sync::watch;
 use tokio_stream::wrappers::errors::BroadcastStreamRecvError;
 use tokio_stream::wrappers::BroadcastStream;
 use tokio_stream::Stream;
@@ -18,19 +20,12 @@ use valuable::Valuable;
 
 use crate::asyncutil::future::RetryPolicy;
 use crate::tm_client::TmClient;
+use crate::types::TMAddress;
 
+pub mod buffer;
+pub mod pipeline;
 pub mod stream;
 
-// The maximum number of blocks to process concurrently.
-// - A value of 1 ensures sequential processing, preventing the event sub
-//   from downloading events from multiple blocks simultaneously. This minimizes
-//   memory usage but may slow down event processing.
-// - Higher values enable parallel block processing, improving throughput but
-//   increasing memory usage and potential resource contention.
-// - Setting this too high may cause excessive memory consumption, while setting
-//   it too low may lead to slower processing and underutilization of downstream
-//   consumers.
-const BLOCK_PROCESSING_BUFFER: usize = 10;
 // Interval to poll for new blocks
 const POLL_INTERVAL: Duration = Duration::from_secs(5);
 // Retry policy for block processing and event retrival
@@ -45,6 +40,8 @@ pub enum Error {
     LatestBlockQuery,
     #[error("failed querying the block results for block {block}")]
     BlockResultsQuery { block: block::Height },
+    #[error("failed searching the transactions of block {block}")]
+    TxSearch { block: block::Height },
     #[error("failed decoding event in block {block}")]
     EventDecoding { block: block::Height },
     #[error("failed receiving event from broadcast stream")]
@@ -59,10 +56,25 @@ pub trait EventSub {
 #[derive(Clone)]
 pub struct EventSubscriber {
     tx: Sender<std::result::Result<Event, Error>>,
+    contracts: Option<watch::Receiver<Vec<TMAddress>>>,
+}
+
+impl EventSubscriber {
+    /// Drops the events of all contracts but the given ones from the subscriptions of the returned subscriber.
+    /// Events that are not emitted by a contract, including begin and end block events, are still streamed.
+    /// Updates to the contracts apply to all events streamed after the update
+    pub fn filter_by_contracts(&self, contracts: watch::Receiver<Vec<TMAddress>>) -> Self {
+        Self {
+            tx: self.tx.clone(),
+            contracts: Some(contracts),
+        }
+    }
 }
 
 impl EventSub for EventSubscriber {
     fn subscribe(&self) -> impl Stream<Item = Result<Event, Error>> + 'static {
+        let contracts = self.contracts.clone();
+
         BroadcastStream::new(self.tx.subscribe())
             .map(|event| match event {
                 Ok(Ok(event)) => Ok(event),
@@ -70,12 +82,27 @@ impl EventSub for EventSubscriber {
                 Err(err) => Err(err.into()),
             })
             .map_err(Report::from)
+            .filter(move |event| {
+                future::ready(match (event, &contracts) {
+                    (Ok(event), Some(contracts)) => is_from_any(event, &contracts.borrow()),
+                    _ => true,
+                })
+            })
     }
 }
 
+fn is_from_any(event: &Event, contracts: &[TMAddress]) -> bool {
+    event.contract_address().map_or(true, |contract| {
+        contracts.contains(&TMAddress::from(contract))
+    })
+}
+
 pub struct EventPublisher<T: TmClient + Sync> {
     tm_client: T,
     poll_interval: Duration,
+    buffer: Option<Arc<Mutex<buffer::EventBuffer>>>,
+    block_processing: pipeline::Config,
+    contracts: Option<watch::Receiver<Vec<TMAddress>>>,
     tx: Sender<std::result::Result<Event, Error>>,
 }
 
@@ -85,19 +112,65 @@ impl<T: TmClient + Sync> EventPublisher<T> {
         let publisher = EventPublisher {
             tm_client: client,
             poll_interval: POLL_INTERVAL,
+            buffer: None,
+            block_processing: pipeline::Config::default(),
+            contracts: None,
             tx: tx.clone(),
         };
-        let subscriber = EventSubscriber { tx };
+        let subscriber = EventSubscriber {
+            tx,
+            contracts: None,
+        };
 
         (publisher, subscriber)
     }
 
+    /// Replay blocks whose events could not be retrieved and never publish the events of a block twice,
+    /// see [buffer::EventBuffer]
+    pub fn with_event_buffer(mut self, buffer: buffer::EventBuffer) -> Self {
+        self.buffer = Some(Arc::new(Mutex::new(buffer)));
+        self
+    }
+
+    /// Retrieve the events of multiple blocks concurrently within the bounds of the config.
+    /// Events are still published in the order of the blocks, see [pipeline::ordered]
+    pub fn with_block_processing(mut self, config: pipeline::Config) -> Self {
+        self.block_processing = config;
+        self
+    }
+
+    /// Only retrieve the events of transactions that execute one of the given contracts, so the node filters them
+    /// instead of returning the results of the whole block. Requires the node to index transactions. The ABCI events
+    /// emitted at the begin and end of blocks are not retrieved. If a search fails, all events of the block are retrieved
+    /// instead and the events of other contracts are dropped before they are published
+    pub fn filter_pushdown(mut self, contracts: watch::Receiver<Vec<TMAddress>>) -> Self {
+        self.contracts = Some(contracts);
+        self
+    }
+
     pub async fn run(self, token: CancellationToken) -> Result<(), Error> {
         let block_stream = stream::blocks(&self.tm_client, self.poll_interval, token.child_token())
             .await?
-            .filter(|_| future::ready(self.has_subscriber())); // skip processing blocks when no subscriber exists
-        let mut event_stream =
-            stream::events(&self.tm_client, block_stream, BLOCK_PROCESSING_RETRY_POLICY);
+            .filter(|_| future::ready(self.has_subscriber())) // skip processing blocks when no subscriber exists
+            .flat_map(|block_height| {
+                let block_heights = match (block_height, &self.buffer) {
+                    (Ok(block_height), Some(buffer)) => lock(buffer)
+                        .schedule(block_height)
+                        .into_iter()
+                        .map(Ok)
+                        .collect(),
+                    (block_height, _) => vec![block_height],
+                };
+
+                futures::stream::iter(block_heights)
+            });
+        let mut event_stream = stream::events(
+            &self.tm_client,
+            block_stream,
+            self.contracts.as_ref(),
+            BLOCK_PROCESSING_RETRY_POLICY,
+            self.block_processing,
+        );
 
         loop {
             select! {
@@ -109,6 +182,8 @@ impl<T: TmClient + Sync> EventPublisher<T> {
                             })
                             .map_err(|err| err.current_context().clone());
 
+                        self.update_buffer(&event).await;
+
                         let _ = self.tx.send(event)
                             .map_err(Report::new)
                             .inspect_err(|err| {
@@ -133,6 +208,38 @@ impl<T: TmClient + Sync> EventPublisher<T> {
     fn has_subscriber(&self) -> bool {
         self.tx.receiver_count() > 0
     }
+
+    async fn update_buffer(&self, event: &std::result::Result<Event, Error>) {
+        let Some(buffer) = &self.buffer else {
+            return;
+        };
+
+        // the lock must be released before the snapshot is persisted
+        let snapshot = match event {
+            Ok(Event::BlockEnd(block)) => lock(buffer).mark_published(*block),
+            Err(Error::BlockResultsQuery { block })
+            | Err(Error::TxSearch { block })
+            | Err(Error::EventDecoding { block }) => lock(buffer).mark_missed(*block),
+            _ => None,
+        };
+
+        let Some(snapshot) = snapshot else {
+            return;
+        };
+
+        let _ = snapshot.persist().await.inspect_err(|err| {
+            error!(
+                err = LoggableError::from(err).as_value(),
+                "failed to update the event buffer"
+            );
+        });
+    }
+}
+
+fn lock(buffer: &Mutex<buffer::EventBuffer>) -> std::sync::MutexGuard<'_, buffer::EventBuffer> {
+    buffer
+        .lock()
+        .expect("event buffer lock should not be poisoned")
 }
 
 #[cfg(test)]
@@ -147,10 +254,13 @@ mod tests {
     use futures::stream::StreamExt;
     use random_string::generate;
     use tendermint::{abci, block};
+    use tokio::sync::watch;
     use tokio_util::sync::CancellationToken;
 
     use crate::event_sub::{Error, EventPublisher, EventSub};
     use crate::tm_client::{self, MockTmClient};
+    use crate::types::TMAddress;
+    use crate::PREFIX;
 
     #[tokio::test(flavor = "multi_thread")]
     async fn should_skip_processing_blocks_when_no_subscriber_exists() {
@@ -270,6 +380,73 @@ mod tests {
         handle.await.unwrap().unwrap();
     }
 
+    #[tokio::test]
+    async fn filtered_subscriber_should_only_drop_events_of_other_contracts() {
+        let watched_contract = TMAddress::random(PREFIX);
+        let (event_publisher, subscriber) = EventPublisher::new(MockTmClient::new(), 100);
+        let (contracts, contracts_receiver) = watch::channel(vec![watched_contract.clone()]);
+        let mut filtered_stream = subscriber
+            .filter_by_contracts(contracts_receiver)
+            .subscribe();
+        let mut unfiltered_stream = subscriber.subscribe();
+
+        let watched_contract_event = contract_event(&watched_contract);
+        let other_contract = TMAddress::random(PREFIX);
+        let other_contract_event = contract_event(&other_contract);
+        let non_contract_event = Event::Abci {
+            event_type: "transfer".to_string(),
+            attributes: serde_json::Map::new(),
+        };
+        let events = vec![
+            Event::BlockBegin(1u32.into()),
+            other_contract_event.clone(),
+            watched_contract_event.clone(),
+            non_contract_event.clone(),
+            Event::BlockEnd(1u32.into()),
+        ];
+        for event in events.clone() {
+            event_publisher.tx.send(Ok(event)).unwrap();
+        }
+
+        for event in [
+            Event::BlockBegin(1u32.into()),
+            watched_contract_event,
+            non_contract_event,
+            Event::BlockEnd(1u32.into()),
+        ] {
+            assert_eq!(filtered_stream.next().await.unwrap().unwrap(), event);
+        }
+
+        contracts.send_replace(vec![other_contract]);
+        event_publisher
+            .tx
+            .send(Ok(other_contract_event.clone()))
+            .unwrap();
+        drop(event_publisher);
+        drop(subscriber);
+
+        assert_eq!(
+            filtered_stream.next().await.unwrap().unwrap(),
+            other_contract_event
+        );
+        assert!(filtered_stream.next().await.is_none());
+
+        for event in events.into_iter().chain([other_contract_event]) {
+            assert_eq!(unfiltered_stream.next().await.unwrap().unwrap(), event);
+        }
+        assert!(unfiltered_stream.next().await.is_none());
+    }
+
+    fn contract_event(contract: &TMAddress) -> Event {
+        Event::Abci {
+            event_type: "wasm-event".to_string(),
+            attributes: serde_json::Map::from_iter([(
+                "_contract_address".to_string(),
+                serde_json::Value::String(contract.to_string()),
+            )]),
+        }
+    }
+
     pub fn random_event() -> abci::Event {
         let charset = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
 
@@ -0,0 +1,215 @@
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::task::Poll;
+
+use futures::stream::FuturesUnordered;
+use futures::{stream, FutureExt, Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+
+/// Bounds for processing blocks concurrently. Catching up after downtime is mostly spent waiting for the node,
+/// so retrieving multiple blocks at the same time speeds it up considerably, at the cost of memory for the events
+/// of blocks that have been retrieved, but can't be published before all earlier blocks are.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(default)]
+pub struct Config {
+    /// Maximum number of blocks whose events are retrieved at the same time. A value of 1 processes blocks sequentially
+    pub concurrency: usize,
+    /// Maximum number of events of already retrieved blocks that wait for earlier blocks to be published.
+    /// Once reached, no further blocks are retrieved until the waiting events are published
+    pub max_buffered_events: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            concurrency: 10,
+            max_buffered_events: 100000,
+        }
+    }
+}
+
+/// Processes the items of the stream concurrently within the bounds of the config, and streams the results
+/// in the order of the items, regardless of the order in which their processing finishes
+pub fn ordered<'a, S, F, Fut, T, E>(
+    items: S,
+    mut process: F,
+    config: Config,
+) -> impl Stream<Item = Result<Vec<T>, E>> + 'a
+where
+    S: Stream + 'a,
+    F: FnMut(S::Item) -> Fut + 'a,
+    Fut: Future<Output = Result<Vec<T>, E>> + 'a,
+    T: 'a,
+    E: 'a,
+{
+    let concurrency = config.concurrency.max(1);
+
+    let mut items = Box::pin(items);
+    let mut items_done = false;
+    let mut in_flight = FuturesUnordered::new();
+    // results that finished before the results of earlier items, keyed by the index of their item
+    let mut finished = BTreeMap::new();
+    let mut buffered_events = 0usize;
+    let mut next_to_start = 0u64;
+    let mut next_to_stream = 0u64;
+
+    stream::poll_fn(move |cx| loop {
+        if let Some(result) = finished.remove(&next_to_stream) {
+            next_to_stream = next_to_stream.saturating_add(1);
+            buffered_events = buffered_events.saturating_sub(event_count(&result));
+
+            return Poll::Ready(Some(result));
+        }
+
+        // the next item to stream is either in flight or not started yet, and it can always be started
+        // if nothing is in flight, so waiting for it can't deadlock even if the bounds are reached
+        while !items_done
+            && in_flight.len() < concurrency
+            && buffered_events < config.max_buffered_events
+        {
+            match items.as_mut().poll_next(cx) {
+                Poll::Ready(Some(item)) => {
+                    let index = next_to_start;
+                    next_to_start = next_to_start.saturating_add(1);
+
+                    in_flight.push(process(item).map(move |result| (index, result)));
+                }
+                Poll::Ready(None) => items_done = true,
+                Poll::Pending => break,
+            }
+        }
+
+        match in_flight.poll_next_unpin(cx) {
+            Poll::Ready(Some((index, result))) => {
+                buffered_events = buffered_events.saturating_add(event_count(&result));
+                finished.insert(index, result);
+            }
+            Poll::Ready(None) if items_done => return Poll::Ready(None),
+            _ => return Poll::Pending,
+        }
+    })
+}
+
+fn event_count<T, E>(result: &Result<Vec<T>, E>) -> usize {
+    result.as_ref().map_or(0, Vec::len)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use futures::{stream, StreamExt};
+
+    use super::*;
+
+    #[tokio::test(start_paused = true)]
+    async fn results_should_be_streamed_in_order() {
+        let config = Config {
+            concurrency: 5,
+            max_buffered_events: 100,
+        };
+
+        // later items finish first
+        let results: Vec<Result<Vec<u64>, ()>> = ordered(
+            stream::iter(0..10u64),
+            |item| async move {
+                tokio::time::sleep(Duration::from_secs(10u64.saturating_sub(item))).await;
+                Ok(vec![item])
+            },
+            config,
+        )
+        .collect()
+        .await;
+
+        assert_eq!(
+            results,
+            (0..10).map(|item| Ok(vec![item])).collect::<Vec<_>>()
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn items_should_not_be_started_beyond_concurrency() {
+        let config = Config {
+            concurrency: 3,
+            max_buffered_events: 100,
+        };
+        let running = Arc::new(AtomicUsize::new(0));
+        let max_running = Arc::new(AtomicUsize::new(0));
+
+        let results: Vec<Result<Vec<u64>, ()>> = ordered(
+            stream::iter(0..10u64),
+            |item| {
+                let running = running.clone();
+                let max_running = max_running.clone();
+
+                async move {
+                    let now_running = running.fetch_add(1, Ordering::SeqCst).saturating_add(1);
+                    max_running.fetch_max(now_running, Ordering::SeqCst);
+                    tokio::time::sleep(Duration::from_secs(1)).await;
+                    running.fetch_sub(1, Ordering::SeqCst);
+
+                    Ok(vec![item])
+                }
+            },
+            config,
+        )
+        .collect()
+        .await;
+
+        assert_eq!(results.len(), 10);
+        assert_eq!(max_running.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn items_should_not_be_started_while_too_many_events_are_buffered() {
+        let config = Config {
+            concurrency: 3,
+            max_buffered_events: 1,
+        };
+        let started = Arc::new(AtomicUsize::new(0));
+
+        let mut results = Box::pin(ordered(
+            stream::iter(0..10u64),
+            |item| {
+                started.fetch_add(1, Ordering::SeqCst);
+
+                async move {
+                    // the first item is slow, so the results of the following items have to wait for it
+                    if item == 0 {
+                        tokio::time::sleep(Duration::from_secs(10)).await;
+                    }
+
+                    Ok::<_, ()>(vec![item; 3])
+                }
+            },
+            config,
+        ));
+
+        assert_eq!(results.next().await, Some(Ok(vec![0; 3])));
+        assert_eq!(started.load(Ordering::SeqCst), 3);
+
+        assert_eq!(results.count().await, 9);
+        assert_eq!(started.load(Ordering::SeqCst), 10);
+    }
+
+    #[tokio::test]
+    async fn errors_should_be_streamed_in_order() {
+        let results: Vec<Result<Vec<u64>, u64>> = ordered(
+            stream::iter(0..4u64),
+            |item| async move {
+                if item == 1 || item == 3 {
+                    Err(item)
+                } else {
+                    Ok(vec![item])
+                }
+            },
+            Config::default(),
+        )
+        .collect()
+        .await;
+
+        assert_eq!(results, vec![Ok(vec![0]), Err(1), Ok(vec![2]), Err(3)]);
+    }
+}
@@ -1,19 +1,31 @@
+use std::collections::BTreeMap;
 use std::iter;
 use std::time::Duration;
 
 use error_stack::ResultExt;
 use events::Event;
-use futures::{stream, FutureExt, Stream, StreamExt};
-use tendermint::block;
+use futures::{future, stream, FutureExt, Stream, StreamExt};
+use report::LoggableError;
+use tendermint::{abci, block};
+use tendermint_rpc::endpoint::tx;
+use tendermint_rpc::query::Query;
+use tokio::sync::watch;
 use tokio::time::{interval, Interval};
 use tokio_util::sync::CancellationToken;
+use tracing::warn;
+use valuable::Valuable;
 
+use super::pipeline;
 use crate::asyncutil::future::{with_retry, RetryPolicy};
 use crate::tm_client::TmClient;
+use crate::types::TMAddress;
 
 type Error = super::Error;
 type Result<T> = error_stack::Result<T, Error>;
 
+// Maximum number of transactions retrieved per tx search request
+const TX_SEARCH_PAGE_SIZE: u8 = 100;
+
 pub async fn blocks<T>(
     tm_client: &T,
     poll_interval: Duration,
@@ -29,29 +41,37 @@ where
         .map(Box::pin)
 }
 
+/// Streams the events of each block.
+/// Multiple blocks are processed concurrently, but their events are streamed in block order.
+/// If `contracts` is set, only the transactions that execute one of the contracts are retrieved, see [contract_tx_events]
 pub fn events<'a, T, S>(
     tm_client: &'a T,
     block_stream: S,
+    contracts: Option<&'a watch::Receiver<Vec<TMAddress>>>,
     retry_policy: RetryPolicy,
+    block_processing: pipeline::Config,
 ) -> impl Stream<Item = Result<Event>> + 'a
 where
     T: TmClient,
     S: Stream<Item = Result<block::Height>> + 'a,
 {
-    block_stream
-        .map(move |block_height| process_block(tm_client, block_height, retry_policy))
-        .buffered(super::BLOCK_PROCESSING_BUFFER)
-        .flat_map(|result| {
-            result.map_or_else(
-                |err| stream::iter(vec![Err(err)]),
-                |events| stream::iter(events.into_iter().map(Ok).collect::<Vec<_>>()),
-            )
-        })
+    pipeline::ordered(
+        block_stream,
+        move |block_height| process_block(tm_client, block_height, contracts, retry_policy),
+        block_processing,
+    )
+    .flat_map(|result| {
+        result.map_or_else(
+            |err| stream::iter(vec![Err(err)]),
+            |events| stream::iter(events.into_iter().map(Ok).collect::<Vec<_>>()),
+        )
+    })
 }
 
 async fn process_block<T>(
     tm_client: &T,
     block_height: Result<block::Height>,
+    contracts: Option<&watch::Receiver<Vec<TMAddress>>>,
     retry_policy: RetryPolicy,
 ) -> Result<Vec<Event>>
 where
@@ -59,13 +79,69 @@ where
 {
     match block_height {
         Ok(block_height) => {
-            with_retry(|| block_events(tm_client, block_height), retry_policy).await
+            with_retry(
+                || block_events(tm_client, block_height, contracts),
+                retry_policy,
+            )
+            .await
         }
         Err(err) => Err(err),
     }
 }
 
-async fn block_events<T>(tm_client: &T, block_height: block::Height) -> Result<Vec<Event>>
+async fn block_events<T>(
+    tm_client: &T,
+    block_height: block::Height,
+    contracts: Option<&watch::Receiver<Vec<TMAddress>>>,
+) -> Result<Vec<Event>>
+where
+    T: TmClient,
+{
+    let contracts = contracts.map(|contracts| contracts.borrow().clone());
+
+    let events = match &contracts {
+        Some(contracts) => match contract_tx_events(tm_client, block_height, contracts).await {
+            Ok(events) => events,
+            // the events of other contracts are dropped after decoding, so the whole block can be retrieved instead
+            Err(err) => {
+                warn!(
+                    err = LoggableError::from(&err).as_value(),
+                    block = block_height.value(),
+                    "failed to search the transactions of the filtered contracts, retrieving all events of the block"
+                );
+
+                all_events(tm_client, block_height).await?
+            }
+        },
+        None => all_events(tm_client, block_height).await?,
+    };
+
+    let batch = events::EventBatch::decode(events).change_context(Error::EventDecoding {
+        block: block_height,
+    })?;
+    // attribute values are only parsed for the events that are published
+    let events = batch
+        .events()
+        .filter(|event| {
+            contracts
+                .as_ref()
+                .map_or(true, |contracts| is_from_any(event, contracts))
+        })
+        .map(|event| event.to_event());
+
+    Ok(iter::once(Event::BlockBegin(block_height))
+        .chain(events)
+        .chain(iter::once(Event::BlockEnd(block_height)))
+        .collect())
+}
+
+fn is_from_any(event: &events::EventView, contracts: &[TMAddress]) -> bool {
+    event.contract_address().map_or(true, |contract| {
+        contracts.contains(&TMAddress::from(contract))
+    })
+}
+
+async fn all_events<T>(tm_client: &T, block_height: block::Height) -> Result<Vec<abci::Event>>
 where
     T: TmClient,
 {
@@ -85,20 +161,70 @@ where
         .flat_map(|tx| tx.events);
     let end_block_events = block_results.end_block_events.into_iter().flatten();
 
-    let events = begin_block_events
+    Ok(begin_block_events
         .chain(tx_events)
         .chain(end_block_events)
-        .map(|event| {
-            Event::try_from(event).change_context(Error::EventDecoding {
+        .collect())
+}
+
+/// Returns the events of the transactions in the block that execute one of the given contracts, in block order.
+/// The node only returns the matching transactions, so neither the events of other transactions nor the begin and end
+/// block events are retrieved. Each contract needs a search of its own, as the node's query language has no disjunction
+async fn contract_tx_events<T>(
+    tm_client: &T,
+    block_height: block::Height,
+    contracts: &[TMAddress],
+) -> Result<Vec<abci::Event>>
+where
+    T: TmClient,
+{
+    // the execute event is emitted for every execution of a contract, including sub-messages, while the wasm event is
+    // only emitted if the contract adds attributes to its response
+    let searches = contracts.iter().map(|contract| {
+        let query = Query::eq("tx.height", block_height.value())
+            .and_eq("execute._contract_address", contract.to_string());
+
+        search_txs(tm_client, query, block_height)
+    });
+
+    // a transaction can execute multiple contracts, so it is keyed by its index in the block to deduplicate and order it
+    let txs: BTreeMap<_, _> = future::try_join_all(searches)
+        .await?
+        .into_iter()
+        .flatten()
+        .map(|tx| (tx.index, tx.tx_result.events))
+        .collect();
+
+    Ok(txs.into_values().flatten().collect())
+}
+
+async fn search_txs<T>(
+    tm_client: &T,
+    query: Query,
+    block_height: block::Height,
+) -> Result<Vec<tx::Response>>
+where
+    T: TmClient,
+{
+    let mut txs = vec![];
+    for page in 1.. {
+        let response = tm_client
+            .tx_search(query.clone(), page, TX_SEARCH_PAGE_SIZE)
+            .await
+            .change_context(Error::TxSearch {
                 block: block_height,
-            })
-        })
-        .collect::<Result<Vec<_>>>()?;
+            })?;
 
-    Ok(iter::once(Event::BlockBegin(block_height))
-        .chain(events)
-        .chain(iter::once(Event::BlockEnd(block_height)))
-        .collect())
+        let is_last_page = response.txs.is_empty()
+            || txs.len().saturating_add(response.txs.len()) >= response.total_count as usize;
+        txs.extend(response.txs);
+
+        if is_last_page {
+            break;
+        }
+    }
+
+    Ok(txs)
 }
 
 #[derive(Copy, Clone)]
@@ -177,13 +303,44 @@ async fn latest_block_height<T: TmClient>(tm_client: &T) -> Result<block::Height
 #[cfg(test)]
 mod tests {
     use axelar_wasm_std::assert_err_contains;
+    use base64::engine::general_purpose::STANDARD;
+    use base64::Engine;
     use error_stack::report;
-    use futures::StreamExt;
-    use tendermint::abci;
+    use futures::{StreamExt, TryStreamExt};
 
     use super::super::tests::{block_results_response, random_event};
     use super::*;
     use crate::tm_client::{self, MockTmClient};
+    use crate::PREFIX;
+
+    fn tx_response(index: u32, event: abci::Event) -> tx::Response {
+        tx::Response {
+            hash: Default::default(),
+            height: 1u32.into(),
+            index,
+            tx_result: abci::types::ExecTxResult {
+                events: vec![event],
+                ..Default::default()
+            },
+            tx: vec![],
+            proof: None,
+        }
+    }
+
+    fn abci_contract_event(contract: &TMAddress) -> abci::Event {
+        abci::Event::new(
+            "wasm",
+            vec![abci::EventAttribute {
+                key: STANDARD.encode("_contract_address"),
+                value: STANDARD.encode(contract.to_string()),
+                index: false,
+            }],
+        )
+    }
+
+    fn tx_search_response(txs: Vec<tx::Response>, total_count: u32) -> tm_client::TxSearchResponse {
+        tm_client::TxSearchResponse { txs, total_count }
+    }
 
     #[tokio::test]
     async fn event_stream_should_stream_error_if_block_stream_streams_error() {
@@ -198,7 +355,13 @@ mod tests {
             Err(report!(Error::LatestBlockQuery)),
             Err(report!(Error::LatestBlockQuery)),
         ]);
-        let mut stream = events(&tm_client, block_stream, retry_policy);
+        let mut stream = events(
+            &tm_client,
+            block_stream,
+            None,
+            retry_policy,
+            pipeline::Config::default(),
+        );
 
         assert_err_contains!(stream.next().await.unwrap(), Error, Error::LatestBlockQuery);
         assert_err_contains!(stream.next().await.unwrap(), Error, Error::LatestBlockQuery);
@@ -279,7 +442,13 @@ mod tests {
             max_attempts: 3,
         };
         let block_stream = stream::iter(vec![Ok(1u32.into()), Ok(2u32.into())]);
-        let mut stream = events(&tm_client, block_stream, retry_policy);
+        let mut stream = events(
+            &tm_client,
+            block_stream,
+            None,
+            retry_policy,
+            pipeline::Config::default(),
+        );
 
         assert!(matches!(
             stream.next().await.unwrap(),
@@ -340,7 +509,13 @@ mod tests {
             max_attempts: 3,
         };
         let block_stream = stream::iter(vec![Ok(1u32.into()), Ok(2u32.into())]);
-        let stream = events(&tm_client, block_stream, retry_policy);
+        let stream = events(
+            &tm_client,
+            block_stream,
+            None,
+            retry_policy,
+            pipeline::Config::default(),
+        );
 
         let events: Vec<_> = stream.collect().await;
 
@@ -364,6 +539,119 @@ mod tests {
         ))
     }
 
+    #[tokio::test]
+    async fn event_stream_should_only_retrieve_txs_of_filtered_contracts() {
+        let (_, contracts) =
+            watch::channel(vec![TMAddress::random(PREFIX), TMAddress::random(PREFIX)]);
+        let shared_tx_event = random_event();
+        let first_page_event = random_event();
+        let second_page_event = random_event();
+
+        let mut tm_client = MockTmClient::new();
+        tm_client.expect_block_results().never();
+
+        let first_contract = contracts.borrow()[0].to_string();
+        let (first_tx, second_tx, third_tx) = (
+            tx_response(2, shared_tx_event.clone()),
+            tx_response(0, first_page_event.clone()),
+            tx_response(1, second_page_event.clone()),
+        );
+        tm_client
+            .expect_tx_search()
+            .times(3)
+            .returning(move |query, page, _| {
+                assert!(query.to_string().contains("tx.height = 1"));
+
+                // the first contract's transactions are spread over two pages
+                if query.to_string().contains(&first_contract) {
+                    match page {
+                        1 => Ok(tx_search_response(
+                            vec![second_tx.clone(), first_tx.clone()],
+                            3,
+                        )),
+                        2 => Ok(tx_search_response(vec![third_tx.clone()], 3)),
+                        _ => unreachable!(),
+                    }
+                } else {
+                    Ok(tx_search_response(vec![first_tx.clone()], 1))
+                }
+            });
+
+        let retry_policy = RetryPolicy::RepeatConstant {
+            sleep: Duration::from_millis(100),
+            max_attempts: 3,
+        };
+        let block_stream = stream::iter(vec![Ok(1u32.into())]);
+        let stream = events(
+            &tm_client,
+            block_stream,
+            Some(&contracts),
+            retry_policy,
+            pipeline::Config::default(),
+        );
+
+        let events: Vec<_> = stream.try_collect().await.unwrap();
+        let expected_events: Vec<_> = [first_page_event, second_page_event, shared_tx_event]
+            .into_iter()
+            .map(|event| Event::try_from(event).unwrap())
+            .collect();
+
+        assert_eq!(events.len(), 5);
+        assert_eq!(events.first(), Some(&Event::BlockBegin(1u32.into())));
+        assert_eq!(&events[1..4], expected_events.as_slice());
+        assert_eq!(events.last(), Some(&Event::BlockEnd(1u32.into())));
+    }
+
+    #[tokio::test]
+    async fn event_stream_should_fall_back_to_block_results_if_tx_search_fails() {
+        let contract = TMAddress::random(PREFIX);
+        let (_, contracts) = watch::channel(vec![contract.clone()]);
+        let contract_event = abci_contract_event(&contract);
+        let other_contract_event = abci_contract_event(&TMAddress::random(PREFIX));
+
+        let mut tm_client = MockTmClient::new();
+        tm_client.expect_tx_search().once().returning(|_, _, _| {
+            Err(report!(tendermint_rpc::Error::server(
+                "tx indexing is disabled".to_string()
+            )))
+        });
+        tm_client
+            .expect_block_results()
+            .once()
+            .returning(move |height| {
+                Ok(block_results_response(
+                    height,
+                    vec![random_event()],
+                    vec![random_event()],
+                    vec![contract_event.clone(), other_contract_event.clone()],
+                ))
+            });
+
+        let retry_policy = RetryPolicy::RepeatConstant {
+            sleep: Duration::from_millis(100),
+            max_attempts: 3,
+        };
+        let block_stream = stream::iter(vec![Ok(1u32.into())]);
+        let stream = events(
+            &tm_client,
+            block_stream,
+            Some(&contracts),
+            retry_policy,
+            pipeline::Config::default(),
+        );
+
+        let events: Vec<_> = stream.try_collect().await.unwrap();
+
+        // the event of the other contract is dropped
+        assert_eq!(events.len(), 5);
+        assert!(events[1].contract_address().is_none());
+        assert_eq!(
+            events[2].contract_address().map(TMAddress::from),
+            Some(contract)
+        );
+        assert!(events[3].contract_address().is_none());
+    }
+
     #[tokio::test]
     async fn block_stream_should_return_error_immediately_if_latest_block_height_query_fails() {
         let interval = std::time::Duration::from_millis(100);
@@ -0,0 +1,163 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use axelar_wasm_std::nonempty;
+use k256::sha2::{Digest, Sha256};
+use serde::{Deserialize, Serialize};
+use tonic::service::Interceptor;
+use tonic::{Request, Status};
+
+use crate::broadcaster_v2::Producer;
+
+const AUTHORIZATION: &str = "authorization";
+const BEARER: &str = "Bearer ";
+const ANONYMOUS: &str = "anonymous";
+
+/// A client that authenticates with a bearer token. Only the SHA-256 hash of the token is configured,
+/// so the config doesn't hold the token itself
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct ClientConfig {
+    pub name: String,
+    #[serde(with = "hex")]
+    pub token_sha256: [u8; 32],
+    /// number of messages of the client per round when broadcast batches are shared with other clients and the handlers
+    #[serde(default = "default_weight")]
+    pub weight: nonempty::Usize,
+}
+
+fn default_weight() -> nonempty::Usize {
+    1usize.try_into().expect("weight must not be zero")
+}
+
+/// Authenticates the clients of the gRPC server by their bearer token. Each client broadcasts as its own
+/// producer, so it can't get a larger share of the broadcast batches by opening more connections.
+/// Without configured clients, authentication is disabled and all requests share a single producer
+#[derive(Clone, Debug)]
+pub struct Auth {
+    clients: Arc<HashMap<[u8; 32], Producer>>,
+}
+
+impl Auth {
+    pub fn new(clients: &[ClientConfig]) -> Self {
+        Self {
+            clients: Arc::new(
+                clients
+                    .iter()
+                    .map(|client| {
+                        (
+                            client.token_sha256,
+                            Producer::new(client.name.clone(), client.weight),
+                        )
+                    })
+                    .collect(),
+            ),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        !self.clients.is_empty()
+    }
+
+    fn authenticate<T>(&self, req: &Request<T>) -> Result<Producer, Status> {
+        if !self.is_enabled() {
+            return Ok(anonymous());
+        }
+
+        let token = req
+            .metadata()
+            .get(AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix(BEARER))
+            .ok_or_else(|| Status::unauthenticated("missing bearer token"))?;
+        let token_sha256: [u8; 32] = Sha256::digest(token).into();
+
+        self.clients
+            .get(&token_sha256)
+            .cloned()
+            .ok_or_else(|| Status::unauthenticated("invalid bearer token"))
+    }
+}
+
+impl Interceptor for Auth {
+    fn call(&mut self, mut req: Request<()>) -> Result<Request<()>, Status> {
+        let producer = self.authenticate(&req)?;
+        req.extensions_mut().insert(producer);
+
+        Ok(req)
+    }
+}
+
+/// The producer of an authenticated request
+pub fn producer<T>(req: &Request<T>) -> Producer {
+    req.extensions()
+        .get::<Producer>()
+        .cloned()
+        .unwrap_or_else(anonymous)
+}
+
+fn anonymous() -> Producer {
+    Producer::new(ANONYMOUS, default_weight())
+}
+
+#[cfg(test)]
+mod tests {
+    use tonic::Code;
+
+    use super::*;
+
+    fn client(name: &str, token: &str, weight: usize) -> ClientConfig {
+        ClientConfig {
+            name: name.to_string(),
+            token_sha256: Sha256::digest(token).into(),
+            weight: weight.try_into().unwrap(),
+        }
+    }
+
+    fn request_with_token(token: &str) -> Request<()> {
+        let mut req = Request::new(());
+        req.metadata_mut().insert(
+            AUTHORIZATION,
+            format!("{}{}", BEARER, token).parse().unwrap(),
+        );
+        req
+    }
+
+    #[test]
+    fn requests_should_share_anonymous_producer_if_auth_is_disabled() {
+        let mut auth = Auth::new(&[]);
+
+        let first = auth.call(Request::new(())).unwrap();
+        let second = auth.call(request_with_token("token")).unwrap();
+
+        assert!(!auth.is_enabled());
+        assert_eq!(producer(&first), anonymous());
+        assert_eq!(producer(&second), anonymous());
+    }
+
+    #[test]
+    fn authenticated_requests_should_be_produced_by_their_client() {
+        let mut auth = Auth::new(&[client("relayer", "secret", 3), client("other", "other", 1)]);
+
+        let req = auth.call(request_with_token("secret")).unwrap();
+
+        assert!(auth.is_enabled());
+        assert_eq!(
+            producer(&req),
+            Producer::new("relayer", 3usize.try_into().unwrap())
+        );
+    }
+
+    #[test]
+    fn requests_without_valid_token_should_be_rejected_if_auth_is_enabled() {
+        let mut auth = Auth::new(&[client("relayer", "secret", 1)]);
+
+        assert_eq!(
+            auth.call(Request::new(())).unwrap_err().code(),
+            Code::Unauthenticated
+        );
+        assert_eq!(
+            auth.call(request_with_token("wrong")).unwrap_err().code(),
+            Code::Unauthenticated
+        );
+    }
+}
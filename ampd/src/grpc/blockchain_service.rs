@@ -1,27 +1,40 @@
 use std::pin::Pin;
 
 use ampd_proto::blockchain_service_server::BlockchainService;
+use ampd_proto::broadcast::broadcast_service_server::BroadcastService;
+use ampd_proto::broadcast::{
+    broadcast_stream_response, BroadcastError, BroadcastReceipt, BroadcastStreamRequest,
+    BroadcastStreamResponse, EstimateGasRequest, EstimateGasResponse,
+};
 use ampd_proto::{
     AddressRequest, AddressResponse, BroadcastRequest, BroadcastResponse, ContractsRequest,
     ContractsResponse, QueryRequest, QueryResponse, SubscribeRequest, SubscribeResponse,
 };
 use async_trait::async_trait;
-use futures::{Stream, TryFutureExt, TryStreamExt};
+use futures::future::{self, FutureExt};
+use futures::{Future, Stream, TryFutureExt, TryStreamExt};
 use tokio_stream::StreamExt;
-use tonic::{Request, Response, Status};
+use tonic::{Request, Response, Status, Streaming};
 use typed_builder::TypedBuilder;
 
-use super::{error, reqs};
+use super::{auth, contracts, error, reqs};
 use crate::{broadcaster_v2, cosmos, event_sub};
 
-#[derive(TypedBuilder)]
+/// Maximum number of messages of a single broadcast stream that can wait for their receipts at the same time
+const BROADCAST_STREAM_BUFFER_SIZE: usize = 100;
+
+/// Serves both the blockchain service and the broadcast RPCs that are not part of it yet, so they share the broadcaster
+#[derive(TypedBuilder, Clone)]
 pub struct Service<E, C>
 where
     E: event_sub::EventSub,
     C: cosmos::CosmosClient,
 {
     event_sub: E,
-    msg_queue_client: broadcaster_v2::MsgQueueClient<C>,
+    cosmos_client: C,
+    contracts: contracts::Contracts,
+    /// not set in read-only mode, so broadcasts are rejected
+    msg_queue_client: Option<broadcaster_v2::MsgQueueClient<C>>,
 }
 
 #[async_trait]
@@ -58,12 +71,17 @@ where
         &self,
         req: Request<BroadcastRequest>,
     ) -> Result<Response<BroadcastResponse>, Status> {
+        let msg_queue_client = self
+            .msg_queue_client
+            .as_ref()
+            .ok_or_else(error::broadcast_disabled)?;
+        let producer = auth::producer(&req);
         let msg = reqs::validate_broadcast(req)
             .inspect_err(error::log("invalid broadcast request"))
             .map_err(error::ErrorExt::into_status)?;
 
-        self.msg_queue_client
-            .clone()
+        msg_queue_client
+            .with_producer(producer)
             .enqueue(msg)
             .and_then(|rx| rx)
             .await
@@ -73,8 +91,17 @@ where
             .map_err(error::ErrorExt::into_status)
     }
 
-    async fn query(&self, _req: Request<QueryRequest>) -> Result<Response<QueryResponse>, Status> {
-        todo!("implement query method")
+    async fn query(&self, req: Request<QueryRequest>) -> Result<Response<QueryResponse>, Status> {
+        let (contract, query) = reqs::validate_query(req)
+            .inspect_err(error::log("invalid query request"))
+            .map_err(error::ErrorExt::into_status)?;
+
+        let result = cosmos::contract_state(&mut self.cosmos_client.clone(), &contract, query)
+            .await
+            .inspect_err(error::log("contract query error"))
+            .map_err(error::ErrorExt::into_status)?;
+
+        Ok(Response::new(QueryResponse { result }))
     }
 
     async fn address(
@@ -86,12 +113,147 @@ where
 
     async fn contracts(
         &self,
-        _req: Request<ContractsRequest>,
+        req: Request<ContractsRequest>,
     ) -> Result<Response<ContractsResponse>, Status> {
-        todo!("implement contracts method")
+        let chain = reqs::validate_contracts(req)
+            .inspect_err(error::log("invalid contracts request"))
+            .map_err(error::ErrorExt::into_status)?;
+
+        self.contracts
+            .resolve(&mut self.cosmos_client.clone(), chain)
+            .await
+            .map(Response::new)
+            .inspect_err(error::log("contracts lookup error"))
+            .map_err(error::ErrorExt::into_status)
+    }
+}
+
+#[async_trait]
+impl<E, C> BroadcastService for Service<E, C>
+where
+    E: event_sub::EventSub + Send + Sync + 'static,
+    C: cosmos::CosmosClient + Clone + Send + Sync + 'static,
+{
+    type BroadcastStreamStream =
+        Pin<Box<dyn Stream<Item = Result<BroadcastStreamResponse, Status>> + Send>>;
+
+    async fn broadcast_stream(
+        &self,
+        req: Request<Streaming<BroadcastStreamRequest>>,
+    ) -> Result<Response<Self::BroadcastStreamStream>, Status> {
+        let msg_queue_client = self
+            .msg_queue_client
+            .as_ref()
+            .ok_or_else(error::broadcast_disabled)?
+            .with_producer(auth::producer(&req));
+
+        Ok(Response::new(Box::pin(broadcast_receipts(
+            msg_queue_client,
+            req.into_inner(),
+        ))))
+    }
+
+    async fn estimate_gas(
+        &self,
+        req: Request<EstimateGasRequest>,
+    ) -> Result<Response<EstimateGasResponse>, Status> {
+        let msg_queue_client = self
+            .msg_queue_client
+            .as_ref()
+            .ok_or_else(error::broadcast_disabled)?;
+        let msgs = reqs::validate_estimate_gas(req)
+            .inspect_err(error::log("invalid estimate gas request"))
+            .map_err(error::ErrorExt::into_status)?;
+
+        msg_queue_client
+            .clone()
+            .estimate_gas(msgs)
+            .await
+            .map(|estimate| EstimateGasResponse {
+                gas_per_msg: estimate.per_msg,
+                total_gas: estimate.total,
+            })
+            .map(Response::new)
+            .inspect_err(error::log("gas estimation error"))
+            .map_err(error::ErrorExt::into_status)
     }
 }
 
+/// Enqueues the messages of the request stream in the order they were submitted and yields a receipt for each message
+/// once its transaction is confirmed, in the same order. A message that fails only fails its own receipt, while an error
+/// of the request stream itself ends the stream
+fn broadcast_receipts<C, S>(
+    msg_queue_client: broadcaster_v2::MsgQueueClient<C>,
+    reqs: S,
+) -> impl Stream<Item = Result<BroadcastStreamResponse, Status>> + Send + 'static
+where
+    C: cosmos::CosmosClient + Clone + Send + Sync + 'static,
+    S: Stream<Item = Result<BroadcastStreamRequest, Status>> + Send + 'static,
+{
+    let pending_receipts = futures::StreamExt::then(
+        futures::StreamExt::enumerate(reqs),
+        move |(sequence, req)| {
+            let mut msg_queue_client = msg_queue_client.clone();
+
+            async move {
+                let req = match req {
+                    Ok(req) => req,
+                    Err(status) => return future::ready(Err(status)).left_future(),
+                };
+                let receipt = enqueue_stream_msg(&mut msg_queue_client, req).await;
+
+                async move {
+                    let result = match receipt {
+                        Ok(receipt) => receipt.await,
+                        Err(status) => Err(status),
+                    };
+
+                    Ok(BroadcastStreamResponse {
+                        sequence: sequence as u64,
+                        result: Some(match result {
+                            Ok(receipt) => broadcast_stream_response::Result::Receipt(receipt),
+                            Err(status) => {
+                                broadcast_stream_response::Result::Error(BroadcastError {
+                                    code: status.code().into(),
+                                    message: status.message().to_string(),
+                                })
+                            }
+                        }),
+                    })
+                }
+                .right_future()
+            }
+        },
+    );
+
+    futures::StreamExt::buffered(pending_receipts, BROADCAST_STREAM_BUFFER_SIZE)
+}
+
+/// Enqueues the message of the request and returns the future of its receipt, so the next message can be enqueued while
+/// the receipt is pending
+async fn enqueue_stream_msg<C>(
+    msg_queue_client: &mut broadcaster_v2::MsgQueueClient<C>,
+    req: BroadcastStreamRequest,
+) -> Result<impl Future<Output = Result<BroadcastReceipt, Status>> + Send, Status>
+where
+    C: cosmos::CosmosClient,
+{
+    let msg = reqs::validate_broadcast_stream(req)
+        .inspect_err(error::log("invalid broadcast request"))
+        .map_err(error::ErrorExt::into_status)?;
+
+    let receipt = msg_queue_client
+        .enqueue(msg)
+        .await
+        .inspect_err(error::log("message broadcast error"))
+        .map_err(error::ErrorExt::into_status)?;
+
+    Ok(receipt
+        .map_ok(|(tx_hash, index)| BroadcastReceipt { tx_hash, index })
+        .inspect_err(error::log("message broadcast error"))
+        .map_err(error::ErrorExt::into_status))
+}
+
 #[cfg(test)]
 mod tests {
     use std::sync::Arc;
@@ -101,6 +263,9 @@ mod tests {
     use cosmrs::proto::cosmos::auth::v1beta1::{BaseAccount, QueryAccountResponse};
     use cosmrs::proto::cosmos::base::abci::v1beta1::GasInfo;
     use cosmrs::proto::cosmos::tx::v1beta1::SimulateResponse;
+    use cosmrs::proto::cosmwasm::wasm::v1::{
+        QuerySmartContractStateRequest, QuerySmartContractStateResponse,
+    };
     use cosmrs::{Any, Gas};
     use error_stack::report;
     use events::{self, Event};
@@ -154,7 +319,9 @@ mod tests {
         );
         let service = Service::builder()
             .event_sub(mock_event_sub)
-            .msg_queue_client(msg_queue_client)
+            .cosmos_client(MockCosmosClient::new())
+            .contracts(contracts(None))
+            .msg_queue_client(Some(msg_queue_client))
             .build();
 
         (service, msg_queue)
@@ -470,6 +637,19 @@ mod tests {
         assert!(res.is_err_and(|status| status.code() == Code::InvalidArgument));
     }
 
+    #[tokio::test]
+    async fn broadcast_should_be_rejected_in_read_only_mode() {
+        let service = Service::<MockEventSub, MockCosmosClient>::builder()
+            .event_sub(MockEventSub::new())
+            .cosmos_client(MockCosmosClient::new())
+            .contracts(contracts(None))
+            .msg_queue_client(None)
+            .build();
+
+        let res = service.broadcast(broadcast_req(Some(dummy_msg()))).await;
+        assert!(res.is_err_and(|status| status.code() == Code::FailedPrecondition));
+    }
+
     #[tokio::test]
     async fn broadcast_should_return_error_if_enqueue_failed() {
         let mut mock_cosmos_client = MockCosmosClient::new();
@@ -577,6 +757,311 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn broadcast_stream_should_yield_receipts_in_submission_order() {
+        let tx_hash = "0x7cedbb3799cd99636045c84c5c55aef8a138f107ac8ba53a08cad1070ba4385b";
+        let msg_count = 4;
+
+        let (service, mut msg_queue) = setup(
+            MockEventSub::new(),
+            cosmos_client_with_gas(GAS_CAP / msg_count as u64),
+        )
+        .await;
+
+        // the invalid request in the middle of the stream must not end the stream
+        let reqs = vec![
+            Some(dummy_msg()),
+            Some(dummy_msg()),
+            None,
+            Some(dummy_msg()),
+            Some(dummy_msg()),
+        ]
+        .into_iter()
+        .map(|msg| Ok(BroadcastStreamRequest { msg }));
+        let receipts = broadcast_receipts(
+            service.msg_queue_client.clone().unwrap(),
+            stream::iter(reqs),
+        );
+        let handle = tokio::spawn(receipts.collect::<Vec<_>>());
+
+        let msgs: Vec<_> = msg_queue.next().await.unwrap().into();
+        assert_eq!(msgs.len(), msg_count);
+        // confirm the messages in reverse order, the receipts must still be in submission order
+        for (i, msg) in msgs.into_iter().enumerate().rev() {
+            msg.tx_res_callback
+                .send(Ok((tx_hash.to_string(), i as u64)))
+                .unwrap();
+        }
+
+        let receipts: Vec<_> = handle
+            .await
+            .unwrap()
+            .into_iter()
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(
+            receipts
+                .iter()
+                .map(|receipt| receipt.sequence)
+                .collect::<Vec<_>>(),
+            vec![0, 1, 2, 3, 4]
+        );
+        assert!(matches!(
+            receipts[2].result,
+            Some(broadcast_stream_response::Result::Error(BroadcastError { code, .. }))
+                if code == i32::from(Code::InvalidArgument)
+        ));
+        let indices: Vec<_> = receipts
+            .into_iter()
+            .filter_map(|receipt| match receipt.result {
+                Some(broadcast_stream_response::Result::Receipt(receipt)) => {
+                    assert_eq!(receipt.tx_hash, tx_hash);
+                    Some(receipt.index)
+                }
+                _ => None,
+            })
+            .collect();
+        assert_eq!(indices, vec![0, 1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn broadcast_stream_should_end_on_request_stream_error() {
+        let (service, _) = setup(MockEventSub::new(), cosmos_client_with_gas(GAS_CAP)).await;
+
+        let reqs = vec![Err(Status::cancelled("client went away"))];
+        let receipts: Vec<_> = broadcast_receipts(
+            service.msg_queue_client.clone().unwrap(),
+            stream::iter(reqs),
+        )
+        .collect()
+        .await;
+
+        assert_eq!(receipts.len(), 1);
+        assert!(receipts[0]
+            .as_ref()
+            .is_err_and(|status| status.code() == Code::Cancelled));
+    }
+
+    #[tokio::test]
+    async fn estimate_gas_should_return_error_if_req_is_invalid() {
+        let (service, _) = setup(MockEventSub::new(), MockCosmosClient::new()).await;
+        let res = service.estimate_gas(estimate_gas_req(vec![])).await;
+        assert!(res.is_err_and(|status| status.code() == Code::InvalidArgument));
+    }
+
+    #[tokio::test]
+    async fn estimate_gas_should_be_rejected_in_read_only_mode() {
+        let service = Service::<MockEventSub, MockCosmosClient>::builder()
+            .event_sub(MockEventSub::new())
+            .cosmos_client(MockCosmosClient::new())
+            .contracts(contracts(None))
+            .msg_queue_client(None)
+            .build();
+
+        let res = service
+            .estimate_gas(estimate_gas_req(vec![dummy_msg()]))
+            .await;
+        assert!(res.is_err_and(|status| status.code() == Code::FailedPrecondition));
+    }
+
+    #[tokio::test]
+    async fn estimate_gas_should_return_error_if_simulation_failed() {
+        let mut mock_cosmos_client = MockCosmosClient::new();
+        mock_cosmos_client.expect_clone().return_once(|| {
+            let mut mock_cosmos_client = MockCosmosClient::new();
+            mock_cosmos_client
+                .expect_simulate()
+                .return_once(|_| Err(Status::internal("simulate error").into_report()));
+
+            mock_cosmos_client
+        });
+
+        let (service, _) = setup(MockEventSub::new(), mock_cosmos_client).await;
+        let res = service
+            .estimate_gas(estimate_gas_req(vec![dummy_msg()]))
+            .await;
+        assert!(res.is_err_and(|status| status.code() == Code::InvalidArgument));
+    }
+
+    #[tokio::test]
+    async fn estimate_gas_should_return_gas_per_msg_and_total_without_enqueueing() {
+        let mut mock_cosmos_client = MockCosmosClient::new();
+        mock_cosmos_client.expect_clone().return_once(|| {
+            let mut mock_cosmos_client = MockCosmosClient::new();
+            let mut simulated_gas = vec![100u64, 200u64, 250u64].into_iter();
+            mock_cosmos_client
+                .expect_simulate()
+                .times(3)
+                .returning(move |_| {
+                    let gas = simulated_gas.next().unwrap();
+
+                    Ok(SimulateResponse {
+                        gas_info: Some(GasInfo {
+                            gas_wanted: gas,
+                            gas_used: gas,
+                        }),
+                        result: None,
+                    })
+                });
+
+            mock_cosmos_client
+        });
+
+        let (service, mut msg_queue) = setup(MockEventSub::new(), mock_cosmos_client).await;
+        let res = service
+            .estimate_gas(estimate_gas_req(vec![dummy_msg(), dummy_msg()]))
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(
+            res,
+            EstimateGasResponse {
+                gas_per_msg: vec![100, 200],
+                total_gas: 250,
+            }
+        );
+
+        // estimating gas must not enqueue anything
+        drop(service);
+        assert!(msg_queue.next().await.is_none());
+    }
+
+    fn query_service(
+        query_result: impl FnOnce(QuerySmartContractStateRequest) -> error_stack::Result<Vec<u8>, cosmos::Error>
+            + Send
+            + 'static,
+    ) -> Service<MockEventSub, MockCosmosClient> {
+        let mut mock_cosmos_client = MockCosmosClient::new();
+        mock_cosmos_client.expect_clone().return_once(move || {
+            let mut mock_cosmos_client = MockCosmosClient::new();
+            mock_cosmos_client
+                .expect_smart_contract_state()
+                .return_once(move |req| {
+                    query_result(req).map(|data| QuerySmartContractStateResponse { data })
+                });
+
+            mock_cosmos_client
+        });
+
+        // queries don't need a broadcaster, so they also work in read-only mode
+        Service::builder()
+            .event_sub(MockEventSub::new())
+            .cosmos_client(mock_cosmos_client)
+            .contracts(contracts(None))
+            .msg_queue_client(None)
+            .build()
+    }
+
+    #[tokio::test]
+    async fn query_should_return_contract_query_result() {
+        let contract = TMAddress::random(PREFIX);
+        let expected_contract = contract.to_string();
+
+        let service = query_service(move |req| {
+            assert_eq!(req.address, expected_contract);
+            assert_eq!(req.query_data, br#"{"verifier_set":{}}"#.to_vec());
+
+            Ok(br#"{"id":"1"}"#.to_vec())
+        });
+
+        let res = service
+            .query(query_req(&contract.to_string(), br#"{"verifier_set":{}}"#))
+            .await
+            .unwrap();
+        assert_eq!(res.into_inner().result, br#"{"id":"1"}"#.to_vec());
+    }
+
+    #[tokio::test]
+    async fn query_should_return_error_if_req_is_invalid() {
+        let service = query_service(|_| panic!("invalid queries must not reach the node"));
+
+        let res = service.query(query_req("invalid_contract", b"{}")).await;
+        assert!(res.is_err_and(|status| status.code() == Code::InvalidArgument));
+
+        let res = service
+            .query(query_req(
+                &TMAddress::random(PREFIX).to_string(),
+                b"not json",
+            ))
+            .await;
+        assert!(res.is_err_and(|status| status.code() == Code::InvalidArgument));
+    }
+
+    #[tokio::test]
+    async fn query_should_map_node_errors_to_status() {
+        for (node_status, expected_code) in [
+            (
+                Status::unknown("query wasm contract failed"),
+                Code::InvalidArgument,
+            ),
+            (Status::not_found("no such contract"), Code::NotFound),
+            (Status::unavailable("node is down"), Code::Unavailable),
+            (Status::internal("node failed"), Code::Internal),
+            // responses beyond the decoding limit are rejected by the client before they are received in full
+            (
+                Status::out_of_range("decoded message length too large"),
+                Code::ResourceExhausted,
+            ),
+        ] {
+            let service = query_service(move |_| Err(node_status.into_report()));
+
+            let res = service
+                .query(query_req(&TMAddress::random(PREFIX).to_string(), b"{}"))
+                .await;
+            assert!(res.is_err_and(|status| status.code() == expected_code));
+        }
+    }
+
+    fn contracts(coordinator: Option<TMAddress>) -> contracts::Contracts {
+        contracts::Contracts {
+            router: Some(TMAddress::random(PREFIX)),
+            multisig: Some(TMAddress::random(PREFIX)),
+            service_registry: TMAddress::random(PREFIX),
+            rewards: TMAddress::random(PREFIX),
+            coordinator,
+        }
+    }
+
+    #[tokio::test]
+    async fn contracts_should_return_error_if_chain_cannot_be_looked_up() {
+        let service = Service::builder()
+            .event_sub(MockEventSub::new())
+            .cosmos_client(MockCosmosClient::new())
+            .contracts(contracts(None))
+            .msg_queue_client(None)
+            .build();
+
+        let res = service.contracts(contracts_req("invalid:chain")).await;
+        assert!(res.is_err_and(|status| status.code() == Code::InvalidArgument));
+
+        let mut mock_cosmos_client = MockCosmosClient::new();
+        mock_cosmos_client
+            .expect_clone()
+            .return_once(MockCosmosClient::new);
+        let service = Service::builder()
+            .event_sub(MockEventSub::new())
+            .cosmos_client(mock_cosmos_client)
+            .contracts(contracts(None))
+            .msg_queue_client(None)
+            .build();
+
+        let res = service.contracts(contracts_req("ethereum")).await;
+        assert!(res.is_err_and(|status| status.code() == Code::FailedPrecondition));
+    }
+
+    fn contracts_req(chain: &str) -> Request<ContractsRequest> {
+        Request::new(ContractsRequest {
+            chain: chain.to_string(),
+        })
+    }
+
+    fn query_req(contract: &str, query: &[u8]) -> Request<QueryRequest> {
+        Request::new(QueryRequest {
+            contract: contract.to_string(),
+            query: query.to_vec(),
+        })
+    }
+
     fn subscribe_req(
         filters: Vec<ampd_proto::EventFilter>,
         include_block_begin_end: bool,
@@ -587,6 +1072,28 @@ mod tests {
         })
     }
 
+    fn cosmos_client_with_gas(gas: Gas) -> MockCosmosClient {
+        let mut mock_cosmos_client = MockCosmosClient::new();
+        mock_cosmos_client
+            .expect_clone()
+            .returning(move || cosmos_client_with_gas(gas));
+        mock_cosmos_client.expect_simulate().returning(move |_| {
+            Ok(SimulateResponse {
+                gas_info: Some(GasInfo {
+                    gas_wanted: gas,
+                    gas_used: gas,
+                }),
+                result: None,
+            })
+        });
+
+        mock_cosmos_client
+    }
+
+    fn estimate_gas_req(msgs: Vec<Any>) -> Request<EstimateGasRequest> {
+        Request::new(EstimateGasRequest { msgs })
+    }
+
     fn broadcast_req(msg: Option<Any>) -> Request<BroadcastRequest> {
         Request::new(BroadcastRequest { msg })
     }
@@ -0,0 +1,270 @@
+use ampd_proto::ContractsResponse;
+use error_stack::{report, Result, ResultExt};
+use router_api::ChainName;
+use serde::Deserialize;
+use serde_json::json;
+use thiserror::Error;
+use tonic::Code;
+
+use crate::cosmos;
+use crate::types::TMAddress;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("no coordinator configured to look up the contracts of chain {0}")]
+    CoordinatorMissing(ChainName),
+    #[error("no contracts registered for chain {0} in the coordinator")]
+    ChainNotRegistered(ChainName),
+    #[error("failed to query the coordinator for the contracts of chain {0}")]
+    CoordinatorQuery(ChainName),
+    #[error("failed to decode the contracts of chain {0} returned by the coordinator")]
+    MalformedResponse(ChainName),
+}
+
+/// Amplifier contracts that are returned to handler clients. Contracts that don't depend on the chain are configured,
+/// while the contracts of a chain are looked up in the coordinator
+#[derive(Debug, Clone, PartialEq)]
+pub struct Contracts {
+    pub router: Option<TMAddress>,
+    pub multisig: Option<TMAddress>,
+    pub service_registry: TMAddress,
+    pub rewards: TMAddress,
+    pub coordinator: Option<TMAddress>,
+}
+
+/// Subset of the coordinator's chain contracts response. ampd doesn't depend on the coordinator contract, so only the
+/// fields that are returned to clients are decoded
+#[derive(Deserialize, Debug)]
+struct ChainContracts {
+    verifier_address: String,
+    gateway_address: String,
+    prover_address: String,
+}
+
+impl Contracts {
+    /// Returns the configured contracts, together with the contracts of the chain if one is given
+    pub async fn resolve<C>(
+        &self,
+        client: &mut C,
+        chain: Option<ChainName>,
+    ) -> Result<ContractsResponse, Error>
+    where
+        C: cosmos::CosmosClient,
+    {
+        let chain_contracts = match chain {
+            Some(chain) => Some(self.chain_contracts(client, chain).await?),
+            None => None,
+        };
+
+        Ok(ContractsResponse {
+            router: to_string(self.router.as_ref()),
+            multisig: to_string(self.multisig.as_ref()),
+            service_registry: self.service_registry.to_string(),
+            rewards: self.rewards.to_string(),
+            voting_verifier: chain_contracts
+                .as_ref()
+                .map(|contracts| contracts.verifier_address.clone())
+                .unwrap_or_default(),
+            gateway: chain_contracts
+                .as_ref()
+                .map(|contracts| contracts.gateway_address.clone())
+                .unwrap_or_default(),
+            multisig_prover: chain_contracts
+                .map(|contracts| contracts.prover_address)
+                .unwrap_or_default(),
+        })
+    }
+
+    async fn chain_contracts<C>(
+        &self,
+        client: &mut C,
+        chain: ChainName,
+    ) -> Result<ChainContracts, Error>
+    where
+        C: cosmos::CosmosClient,
+    {
+        let coordinator = self
+            .coordinator
+            .as_ref()
+            .ok_or_else(|| report!(Error::CoordinatorMissing(chain.clone())))?;
+        let query = json!({ "chain_contracts_info": { "chain_name": chain } }).to_string();
+
+        let res = cosmos::contract_state(client, coordinator, query.into_bytes())
+            .await
+            .map_err(|err| {
+                let context = if is_chain_not_registered(err.current_context(), &chain) {
+                    Error::ChainNotRegistered(chain.clone())
+                } else {
+                    Error::CoordinatorQuery(chain.clone())
+                };
+
+                err.change_context(context)
+            })?;
+
+        serde_json::from_slice(&res).change_context(Error::MalformedResponse(chain))
+    }
+}
+
+/// The node fails every contract query that returns an error with the same status code, so only the error message of
+/// the coordinator tells an unregistered chain apart from other failures of the contract or the query
+fn is_chain_not_registered(err: &cosmos::Error, chain: &ChainName) -> bool {
+    match err {
+        cosmos::Error::GrpcRequest(status) => {
+            matches!(status.code(), Code::InvalidArgument | Code::Unknown)
+                && status
+                    .message()
+                    .contains(&format!("chain {} is not registered", chain))
+        }
+        _ => false,
+    }
+}
+
+fn to_string(contract: Option<&TMAddress>) -> String {
+    contract.map(ToString::to_string).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use cosmrs::proto::cosmwasm::wasm::v1::QuerySmartContractStateResponse;
+    use tonic::Status;
+
+    use super::*;
+    use crate::cosmos::MockCosmosClient;
+    use crate::PREFIX;
+
+    fn contracts(coordinator: Option<TMAddress>) -> Contracts {
+        Contracts {
+            router: Some(TMAddress::random(PREFIX)),
+            multisig: None,
+            service_registry: TMAddress::random(PREFIX),
+            rewards: TMAddress::random(PREFIX),
+            coordinator,
+        }
+    }
+
+    #[tokio::test]
+    async fn resolve_without_chain_should_return_configured_contracts() {
+        let contracts = contracts(None);
+
+        let res = contracts
+            .resolve(&mut MockCosmosClient::new(), None)
+            .await
+            .unwrap();
+        assert_eq!(res.router, contracts.router.unwrap().to_string());
+        assert_eq!(res.multisig, "");
+        assert_eq!(res.service_registry, contracts.service_registry.to_string());
+        assert_eq!(res.rewards, contracts.rewards.to_string());
+        assert_eq!(res.voting_verifier, "");
+        assert_eq!(res.gateway, "");
+        assert_eq!(res.multisig_prover, "");
+    }
+
+    #[tokio::test]
+    async fn resolve_with_chain_should_look_up_chain_contracts_in_coordinator() {
+        let coordinator = TMAddress::random(PREFIX);
+        let expected_coordinator = coordinator.to_string();
+        let contracts = contracts(Some(coordinator));
+
+        let mut client = MockCosmosClient::new();
+        client
+            .expect_smart_contract_state()
+            .withf(move |req| {
+                req.address == expected_coordinator
+                    && req.query_data
+                        == br#"{"chain_contracts_info":{"chain_name":"ethereum"}}"#.to_vec()
+            })
+            .return_once(|_| {
+                Ok(QuerySmartContractStateResponse {
+                    data: br#"{"chain_name":"ethereum","prover_address":"prover","gateway_address":"gateway","verifier_address":"verifier"}"#.to_vec(),
+                })
+            });
+
+        let res = contracts
+            .resolve(&mut client, Some(ChainName::from_str("Ethereum").unwrap()))
+            .await
+            .unwrap();
+        assert_eq!(res.voting_verifier, "verifier");
+        assert_eq!(res.gateway, "gateway");
+        assert_eq!(res.multisig_prover, "prover");
+        assert_eq!(res.service_registry, contracts.service_registry.to_string());
+    }
+
+    #[tokio::test]
+    async fn resolve_with_chain_should_fail_without_coordinator() {
+        let res = contracts(None)
+            .resolve(
+                &mut MockCosmosClient::new(),
+                Some(ChainName::from_str("ethereum").unwrap()),
+            )
+            .await;
+
+        assert!(matches!(
+            res.unwrap_err().current_context(),
+            Error::CoordinatorMissing(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn resolve_with_chain_should_classify_coordinator_errors() {
+        let chain = ChainName::from_str("ethereum").unwrap();
+
+        let mut client = MockCosmosClient::new();
+        client.expect_smart_contract_state().return_once(|_| {
+            Err(report!(cosmos::Error::GrpcRequest(Status::unknown(
+                "coordinator failed to retreive chain contracts info: chain ethereum is not registered: query wasm contract failed"
+            ))))
+        });
+        let res = contracts(Some(TMAddress::random(PREFIX)))
+            .resolve(&mut client, Some(chain.clone()))
+            .await;
+        assert!(matches!(
+            res.unwrap_err().current_context(),
+            Error::ChainNotRegistered(_)
+        ));
+
+        // other contract failures must not be mistaken for an unregistered chain
+        let mut client = MockCosmosClient::new();
+        client.expect_smart_contract_state().return_once(|_| {
+            Err(report!(cosmos::Error::GrpcRequest(Status::unknown(
+                "Error parsing into type coordinator::msg::QueryMsg: unknown variant: query wasm contract failed"
+            ))))
+        });
+        let res = contracts(Some(TMAddress::random(PREFIX)))
+            .resolve(&mut client, Some(chain.clone()))
+            .await;
+        assert!(matches!(
+            res.unwrap_err().current_context(),
+            Error::CoordinatorQuery(_)
+        ));
+
+        let mut client = MockCosmosClient::new();
+        client.expect_smart_contract_state().return_once(|_| {
+            Err(report!(cosmos::Error::GrpcRequest(Status::unavailable(
+                "node unavailable"
+            ))))
+        });
+        let res = contracts(Some(TMAddress::random(PREFIX)))
+            .resolve(&mut client, Some(chain.clone()))
+            .await;
+        assert!(matches!(
+            res.unwrap_err().current_context(),
+            Error::CoordinatorQuery(_)
+        ));
+
+        let mut client = MockCosmosClient::new();
+        client.expect_smart_contract_state().return_once(|_| {
+            Ok(QuerySmartContractStateResponse {
+                data: br#"{"unexpected":true}"#.to_vec(),
+            })
+        });
+        let res = contracts(Some(TMAddress::random(PREFIX)))
+            .resolve(&mut client, Some(chain))
+            .await;
+        assert!(matches!(
+            res.unwrap_err().current_context(),
+            Error::MalformedResponse(_)
+        ));
+    }
+}
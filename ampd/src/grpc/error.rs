@@ -1,11 +1,15 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
 use error_stack::Report;
 use report::LoggableError;
-use tonic::Status;
+use tonic::{Code, Status};
+use tonic_types::{ErrorDetails, StatusExt};
 use tracing::error;
 use valuable::Valuable;
 
-use super::reqs;
-use crate::{broadcaster_v2, event_sub};
+use super::{contracts, reqs};
+use crate::{broadcaster_v2, cosmos, event_sub};
 
 pub fn log<Err>(msg: &str) -> impl Fn(&Report<Err>) + '_ {
     move |err| {
@@ -59,34 +63,279 @@ impl From<Status> for Error {
     }
 }
 
+/// Domain of the google.rpc.ErrorInfo detail attached to every status returned by ampd
+pub const ERROR_DOMAIN: &str = "ampd";
+/// Metadata key of the google.rpc.ErrorInfo detail that names the ampd subsystem the error originated in
+pub const SUBSYSTEM_KEY: &str = "subsystem";
+
+/// Delay that clients are asked to wait before retrying a request that failed because of a temporarily unavailable dependency
+const RETRY_DELAY: Duration = Duration::from_secs(1);
+
+/// Builds a status with google.rpc error details, so clients can decide on retries and point users at the offending field
+/// without parsing the message
+struct StatusBuilder {
+    code: Code,
+    message: String,
+    details: ErrorDetails,
+}
+
+impl StatusBuilder {
+    fn new(code: Code, message: impl Into<String>, subsystem: &str, reason: &str) -> Self {
+        Self {
+            code,
+            message: message.into(),
+            details: ErrorDetails::with_error_info(
+                reason,
+                ERROR_DOMAIN,
+                HashMap::from([(SUBSYSTEM_KEY.to_string(), subsystem.to_string())]),
+            ),
+        }
+    }
+
+    fn retry_after(mut self, delay: Duration) -> Self {
+        self.details.set_retry_info(Some(delay));
+        self
+    }
+
+    fn bad_field(mut self, field: &str, description: impl Into<String>) -> Self {
+        self.details.add_bad_request_violation(field, description);
+        self
+    }
+}
+
+/// Returned for broadcasts while ampd runs in read-only mode, as it has no broadcaster account to broadcast with
+pub fn broadcast_disabled() -> Status {
+    StatusBuilder::new(
+        Code::FailedPrecondition,
+        "broadcasting is disabled in read-only mode",
+        "broadcaster",
+        "READ_ONLY",
+    )
+    .into_status()
+}
+
+/// Returned for queries with a result that exceeds the response size limit, as the client can't receive it anyway
+fn query_response_too_large(limit: usize) -> StatusBuilder {
+    StatusBuilder::new(
+        Code::ResourceExhausted,
+        format!("query response exceeds the limit of {} bytes", limit),
+        "query",
+        "RESPONSE_TOO_LARGE",
+    )
+    .bad_field(
+        "query",
+        "query must select less data, e.g. with a smaller page size",
+    )
+}
+
+impl From<StatusBuilder> for Error {
+    fn from(builder: StatusBuilder) -> Self {
+        Status::with_error_details(builder.code, builder.message, builder.details).into()
+    }
+}
+
 impl From<&reqs::Error> for Error {
     fn from(err: &reqs::Error) -> Self {
+        const SUBSYSTEM: &str = "request_validation";
+
         match err {
-            reqs::Error::EmptyFilter => Status::invalid_argument("empty filter provided"),
-            reqs::Error::InvalidContractAddress(contract) => Status::invalid_argument(format!(
-                "invalid contract address {} provided in filters",
-                contract
-            )),
-            reqs::Error::EmptyBroadcastMsg => {
-                Status::invalid_argument("empty broadcast message provided")
-            }
+            reqs::Error::EmptyFilter => StatusBuilder::new(
+                Code::InvalidArgument,
+                "empty filter provided",
+                SUBSYSTEM,
+                "EMPTY_FILTER",
+            )
+            .bad_field("filters", "filter must set an event type or a contract"),
+            reqs::Error::InvalidContractAddress(contract) => StatusBuilder::new(
+                Code::InvalidArgument,
+                format!("invalid contract address {} provided in filters", contract),
+                SUBSYSTEM,
+                "INVALID_CONTRACT_ADDRESS",
+            )
+            .bad_field(
+                "filters.contract",
+                format!("{} is not a valid axelar address", contract),
+            ),
+            reqs::Error::EmptyBroadcastMsg => StatusBuilder::new(
+                Code::InvalidArgument,
+                "empty broadcast message provided",
+                SUBSYSTEM,
+                "EMPTY_BROADCAST_MSG",
+            )
+            .bad_field("msg", "message to broadcast must be set"),
+            reqs::Error::EmptyEstimateGasMsgs => StatusBuilder::new(
+                Code::InvalidArgument,
+                "no messages provided to estimate the gas of",
+                SUBSYSTEM,
+                "EMPTY_ESTIMATE_GAS_MSGS",
+            )
+            .bad_field("msgs", "at least one message must be set"),
+            reqs::Error::InvalidQueryContract(contract) => StatusBuilder::new(
+                Code::InvalidArgument,
+                format!("invalid contract address {} provided to query", contract),
+                SUBSYSTEM,
+                "INVALID_CONTRACT_ADDRESS",
+            )
+            .bad_field(
+                "contract",
+                format!("{} is not a valid axelar address", contract),
+            ),
+            reqs::Error::InvalidQuery => StatusBuilder::new(
+                Code::InvalidArgument,
+                "invalid query provided",
+                SUBSYSTEM,
+                "INVALID_QUERY",
+            )
+            .bad_field("query", "query must be a JSON encoded smart query"),
+            reqs::Error::InvalidChain(chain) => StatusBuilder::new(
+                Code::InvalidArgument,
+                format!("invalid chain {} provided", chain),
+                SUBSYSTEM,
+                "INVALID_CHAIN",
+            )
+            .bad_field("chain", format!("{} is not a valid chain name", chain)),
         }
         .into()
     }
 }
 
-impl From<&event_sub::Error> for Error {
-    fn from(err: &event_sub::Error) -> Self {
+impl From<&cosmos::Error> for Error {
+    fn from(err: &cosmos::Error) -> Self {
+        const SUBSYSTEM: &str = "query";
+
         match err {
-            event_sub::Error::LatestBlockQuery | event_sub::Error::BlockResultsQuery { .. } => {
-                Status::unavailable("blockchain service is temporarily unavailable")
+            // the node fails the query if the contract rejects it, e.g. because it doesn't match the contract's query msg
+            cosmos::Error::GrpcRequest(status)
+                if matches!(status.code(), Code::InvalidArgument | Code::Unknown) =>
+            {
+                StatusBuilder::new(
+                    Code::InvalidArgument,
+                    "contract query failed",
+                    SUBSYSTEM,
+                    "QUERY_FAILED",
+                )
+                .bad_field("query", status.message())
+            }
+            // raised by the client's decoder for responses beyond cosmos::MAX_QUERY_RESPONSE_SIZE
+            cosmos::Error::GrpcRequest(status) if status.code() == Code::OutOfRange => {
+                query_response_too_large(cosmos::MAX_QUERY_RESPONSE_SIZE)
             }
-            event_sub::Error::EventDecoding { .. } => {
-                Status::internal("server encountered an error processing blockchain events")
+            cosmos::Error::GrpcRequest(status) if status.code() == Code::NotFound => {
+                StatusBuilder::new(
+                    Code::NotFound,
+                    "contract not found",
+                    SUBSYSTEM,
+                    "CONTRACT_NOT_FOUND",
+                )
+                .bad_field("contract", status.message())
             }
-            event_sub::Error::BroadcastStreamRecv(_) => {
-                Status::data_loss("events have been missed due to client lag")
+            cosmos::Error::GrpcConnection(_) => StatusBuilder::new(
+                Code::Unavailable,
+                "blockchain service is temporarily unavailable",
+                SUBSYSTEM,
+                "BLOCKCHAIN_UNAVAILABLE",
+            )
+            .retry_after(RETRY_DELAY),
+            cosmos::Error::GrpcRequest(status)
+                if matches!(
+                    status.code(),
+                    Code::Unavailable
+                        | Code::DeadlineExceeded
+                        | Code::ResourceExhausted
+                        | Code::Aborted
+                ) =>
+            {
+                StatusBuilder::new(
+                    Code::Unavailable,
+                    "blockchain service is temporarily unavailable",
+                    SUBSYSTEM,
+                    "BLOCKCHAIN_UNAVAILABLE",
+                )
+                .retry_after(RETRY_DELAY)
             }
+            cosmos::Error::GrpcRequest(_)
+            | cosmos::Error::GasInfoMissing
+            | cosmos::Error::AccountMissing
+            | cosmos::Error::TxResponseMissing
+            | cosmos::Error::MalformedResponse
+            | cosmos::Error::TxBuilding => StatusBuilder::new(
+                Code::Internal,
+                "server encountered an error processing request",
+                SUBSYSTEM,
+                "INTERNAL",
+            ),
+        }
+        .into()
+    }
+}
+
+impl From<&contracts::Error> for Error {
+    fn from(err: &contracts::Error) -> Self {
+        const SUBSYSTEM: &str = "contracts";
+
+        match err {
+            contracts::Error::CoordinatorMissing(_) => StatusBuilder::new(
+                Code::FailedPrecondition,
+                "contracts of a chain can't be looked up without a coordinator configured",
+                SUBSYSTEM,
+                "COORDINATOR_NOT_CONFIGURED",
+            ),
+            contracts::Error::ChainNotRegistered(chain) => StatusBuilder::new(
+                Code::NotFound,
+                format!("no contracts registered for chain {}", chain),
+                SUBSYSTEM,
+                "CHAIN_NOT_FOUND",
+            )
+            .bad_field(
+                "chain",
+                format!("{} is not registered in the coordinator", chain),
+            ),
+            contracts::Error::CoordinatorQuery(_) => StatusBuilder::new(
+                Code::Unavailable,
+                "blockchain service is temporarily unavailable",
+                SUBSYSTEM,
+                "BLOCKCHAIN_UNAVAILABLE",
+            )
+            .retry_after(RETRY_DELAY),
+            contracts::Error::MalformedResponse(_) => StatusBuilder::new(
+                Code::Internal,
+                "server encountered an error processing request",
+                SUBSYSTEM,
+                "INTERNAL",
+            ),
+        }
+        .into()
+    }
+}
+
+impl From<&event_sub::Error> for Error {
+    fn from(err: &event_sub::Error) -> Self {
+        const SUBSYSTEM: &str = "event_subscription";
+
+        match err {
+            event_sub::Error::LatestBlockQuery
+            | event_sub::Error::BlockResultsQuery { .. }
+            | event_sub::Error::TxSearch { .. } => StatusBuilder::new(
+                Code::Unavailable,
+                "blockchain service is temporarily unavailable",
+                SUBSYSTEM,
+                "BLOCKCHAIN_UNAVAILABLE",
+            )
+            .retry_after(RETRY_DELAY),
+            event_sub::Error::EventDecoding { .. } => StatusBuilder::new(
+                Code::Internal,
+                "server encountered an error processing blockchain events",
+                SUBSYSTEM,
+                "EVENT_DECODING_FAILED",
+            ),
+            // the client can resubscribe right away, but has to catch up on the missed events itself
+            event_sub::Error::BroadcastStreamRecv(_) => StatusBuilder::new(
+                Code::DataLoss,
+                "events have been missed due to client lag",
+                SUBSYSTEM,
+                "CLIENT_LAGGED",
+            )
+            .retry_after(Duration::ZERO),
         }
         .into()
     }
@@ -94,22 +343,54 @@ impl From<&event_sub::Error> for Error {
 
 impl From<&broadcaster_v2::Error> for Error {
     fn from(err: &broadcaster_v2::Error) -> Self {
+        const SUBSYSTEM: &str = "broadcaster";
+
         match err {
-            broadcaster_v2::Error::EstimateGas | broadcaster_v2::Error::GasExceedsGasCap { .. } => {
-                Status::invalid_argument("failed to estimate gas or gas exceeds gas cap")
-            }
+            broadcaster_v2::Error::EstimateGas => StatusBuilder::new(
+                Code::InvalidArgument,
+                "failed to estimate gas or gas exceeds gas cap",
+                SUBSYSTEM,
+                "GAS_ESTIMATION_FAILED",
+            )
+            .bad_field("msg", "gas estimation of the message failed"),
+            broadcaster_v2::Error::GasExceedsGasCap { gas, gas_cap, .. } => StatusBuilder::new(
+                Code::InvalidArgument,
+                "failed to estimate gas or gas exceeds gas cap",
+                SUBSYSTEM,
+                "GAS_EXCEEDS_GAS_CAP",
+            )
+            .bad_field(
+                "msg",
+                format!(
+                    "message requires {} gas, exceeding the gas cap {}",
+                    gas, gas_cap
+                ),
+            ),
             broadcaster_v2::Error::AccountQuery | broadcaster_v2::Error::BroadcastTx => {
-                Status::unavailable("blockchain service is temporarily unavailable")
-            }
-            broadcaster_v2::Error::SignTx => {
-                Status::unavailable("signing service is temporarily unavailable")
+                StatusBuilder::new(
+                    Code::Unavailable,
+                    "blockchain service is temporarily unavailable",
+                    SUBSYSTEM,
+                    "BLOCKCHAIN_UNAVAILABLE",
+                )
+                .retry_after(RETRY_DELAY)
             }
+            broadcaster_v2::Error::SignTx => StatusBuilder::new(
+                Code::Unavailable,
+                "signing service is temporarily unavailable",
+                SUBSYSTEM,
+                "SIGNER_UNAVAILABLE",
+            )
+            .retry_after(RETRY_DELAY),
             broadcaster_v2::Error::EnqueueMsg
             | broadcaster_v2::Error::FeeAdjustment
             | broadcaster_v2::Error::InvalidPubKey
-            | broadcaster_v2::Error::ReceiveTxResult(_) => {
-                Status::internal("server encountered an error processing request")
-            }
+            | broadcaster_v2::Error::ReceiveTxResult(_) => StatusBuilder::new(
+                Code::Internal,
+                "server encountered an error processing request",
+                SUBSYSTEM,
+                "INTERNAL",
+            ),
         }
         .into()
     }
@@ -121,7 +402,6 @@ mod tests {
     use tendermint::block::Height;
     use tokio::sync::oneshot;
     use tokio_stream::wrappers::errors::BroadcastStreamRecvError;
-    use tonic::Code;
 
     use super::*;
 
@@ -169,6 +449,153 @@ mod tests {
         );
     }
 
+    #[test]
+    fn invalid_request_status_should_name_offending_field() {
+        let details = reqs::Error::InvalidContractAddress("invalid_contract_address".to_string())
+            .into_status()
+            .get_error_details();
+
+        let error_info = details.error_info().unwrap();
+        assert_eq!(error_info.reason, "INVALID_CONTRACT_ADDRESS");
+        assert_eq!(error_info.domain, ERROR_DOMAIN);
+        assert_eq!(error_info.metadata[SUBSYSTEM_KEY], "request_validation");
+
+        let violations = details.bad_request().unwrap().field_violations;
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].field, "filters.contract");
+        assert!(details.retry_info().is_none());
+    }
+
+    #[test]
+    fn unavailable_status_should_be_retryable() {
+        let details = broadcaster_v2::Error::SignTx
+            .into_status()
+            .get_error_details();
+
+        assert_eq!(details.retry_info().unwrap().retry_delay, Some(RETRY_DELAY));
+        assert_eq!(details.error_info().unwrap().reason, "SIGNER_UNAVAILABLE");
+        assert_eq!(
+            details.error_info().unwrap().metadata[SUBSYSTEM_KEY],
+            "broadcaster"
+        );
+        assert!(details.bad_request().is_none());
+    }
+
+    #[test]
+    fn internal_status_should_not_be_retryable() {
+        let details = (&report!(event_sub::Error::EventDecoding {
+            block: Height::default()
+        }))
+            .into_status()
+            .get_error_details();
+
+        assert!(details.retry_info().is_none());
+        assert_eq!(
+            details.error_info().unwrap().metadata[SUBSYSTEM_KEY],
+            "event_subscription"
+        );
+    }
+
+    #[test]
+    fn cosmos_errors_to_status() {
+        assert_eq!(
+            cosmos::Error::GrpcRequest(Status::unknown("query wasm contract failed"))
+                .into_status()
+                .code(),
+            Code::InvalidArgument
+        );
+        assert_eq!(
+            cosmos::Error::GrpcRequest(Status::invalid_argument("invalid query data"))
+                .into_status()
+                .code(),
+            Code::InvalidArgument
+        );
+        assert_eq!(
+            cosmos::Error::GrpcRequest(Status::not_found("no such contract"))
+                .into_status()
+                .code(),
+            Code::NotFound
+        );
+
+        let status = cosmos::Error::GrpcRequest(Status::unavailable("node is down")).into_status();
+        assert_eq!(status.code(), Code::Unavailable);
+        assert_eq!(
+            status.get_error_details().retry_info().unwrap().retry_delay,
+            Some(RETRY_DELAY)
+        );
+
+        assert_eq!(
+            cosmos::Error::GrpcRequest(Status::permission_denied("denied"))
+                .into_status()
+                .code(),
+            Code::Internal
+        );
+        assert_eq!(
+            cosmos::Error::MalformedResponse.into_status().code(),
+            Code::Internal
+        );
+    }
+
+    #[test]
+    fn contracts_errors_to_status() {
+        let chain: router_api::ChainName = "ethereum".parse().unwrap();
+
+        assert_eq!(
+            contracts::Error::CoordinatorMissing(chain.clone())
+                .into_status()
+                .code(),
+            Code::FailedPrecondition
+        );
+
+        let status = contracts::Error::ChainNotRegistered(chain.clone()).into_status();
+        assert_eq!(status.code(), Code::NotFound);
+        assert_eq!(
+            status
+                .get_error_details()
+                .bad_request()
+                .unwrap()
+                .field_violations[0]
+                .field,
+            "chain"
+        );
+
+        let status = contracts::Error::CoordinatorQuery(chain.clone()).into_status();
+        assert_eq!(status.code(), Code::Unavailable);
+        assert_eq!(
+            status.get_error_details().retry_info().unwrap().retry_delay,
+            Some(RETRY_DELAY)
+        );
+
+        assert_eq!(
+            contracts::Error::MalformedResponse(chain)
+                .into_status()
+                .code(),
+            Code::Internal
+        );
+        assert_eq!(
+            reqs::Error::InvalidChain("invalid:chain".to_string())
+                .into_status()
+                .code(),
+            Code::InvalidArgument
+        );
+    }
+
+    #[test]
+    fn oversized_query_response_status_should_name_query() {
+        let status = cosmos::Error::GrpcRequest(Status::out_of_range(
+            "Error, decoded message length too large",
+        ))
+        .into_status();
+        assert_eq!(status.code(), Code::ResourceExhausted);
+
+        let details = status.get_error_details();
+        assert_eq!(details.error_info().unwrap().reason, "RESPONSE_TOO_LARGE");
+        assert_eq!(
+            details.bad_request().unwrap().field_violations[0].field,
+            "query"
+        );
+    }
+
     #[tokio::test]
     async fn broadcaster_v2_errors_to_status() {
         assert_eq!(
@@ -0,0 +1,86 @@
+use ampd_proto::info::info_service_server::InfoService;
+use ampd_proto::info::{ServiceInfoRequest, ServiceInfoResponse};
+use async_trait::async_trait;
+use tokio::sync::watch;
+use tonic::{Request, Response, Status};
+
+pub struct Service {
+    handlers: watch::Receiver<Vec<String>>,
+    auth_enabled: bool,
+}
+
+impl Service {
+    pub fn new(handlers: watch::Receiver<Vec<String>>, auth_enabled: bool) -> Self {
+        Self {
+            handlers,
+            auth_enabled,
+        }
+    }
+}
+
+#[async_trait]
+impl InfoService for Service {
+    async fn service_info(
+        &self,
+        _req: Request<ServiceInfoRequest>,
+    ) -> Result<Response<ServiceInfoResponse>, Status> {
+        Ok(Response::new(ServiceInfoResponse {
+            ampd_version: env!("CARGO_PKG_VERSION").to_string(),
+            proto_versions: ampd_proto::PROTO_VERSIONS
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+            handlers: self.handlers.borrow().clone(),
+            auth_enabled: self.auth_enabled,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn service_info_should_report_version_and_current_handlers() {
+        let (handlers_sender, handlers) = watch::channel(vec!["MultisigSigner".to_string()]);
+        let service = Service::new(handlers, false);
+
+        let info = service
+            .service_info(Request::new(ServiceInfoRequest {}))
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(info.ampd_version, env!("CARGO_PKG_VERSION"));
+        assert_eq!(
+            info.proto_versions,
+            vec!["ampd.v1", "ampd.info.v1", "ampd.broadcast.v1"]
+        );
+        assert_eq!(info.handlers, vec!["MultisigSigner"]);
+        assert!(!info.auth_enabled);
+
+        handlers_sender.send_replace(vec![
+            "MultisigSigner".to_string(),
+            "EvmMsgVerifier".to_string(),
+        ]);
+
+        let info = service
+            .service_info(Request::new(ServiceInfoRequest {}))
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(info.handlers, vec!["MultisigSigner", "EvmMsgVerifier"]);
+    }
+
+    #[tokio::test]
+    async fn service_info_should_report_whether_auth_is_enabled() {
+        let (_, handlers) = watch::channel(vec![]);
+        let service = Service::new(handlers, true);
+
+        let info = service
+            .service_info(Request::new(ServiceInfoRequest {}))
+            .await
+            .unwrap()
+            .into_inner();
+        assert!(info.auth_enabled);
+    }
+}
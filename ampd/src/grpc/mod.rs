@@ -1,13 +1,17 @@
 use std::net::{IpAddr, SocketAddr};
 
 use ampd_proto::blockchain_service_server::BlockchainServiceServer;
+use ampd_proto::broadcast::broadcast_service_server::BroadcastServiceServer;
 use ampd_proto::crypto_service_server::CryptoServiceServer;
+use ampd_proto::info::info_service_server::InfoServiceServer;
 use axelar_wasm_std::nonempty;
 use error_stack::Result;
+use itertools::Itertools;
 use report::{ErrorExt, LoggableError};
 use serde::de::{self, Deserializer};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
+use tokio::sync::watch;
 use tokio_util::sync::CancellationToken;
 use tonic::transport;
 use tower::limit::ConcurrencyLimitLayer;
@@ -18,15 +22,23 @@ use valuable::Valuable;
 
 use crate::{broadcaster_v2, cosmos, event_sub};
 
+mod auth;
 mod blockchain_service;
+mod contracts;
 mod crypto_service;
 mod error;
+mod info_service;
 mod reqs;
 
+pub use auth::ClientConfig;
+pub use contracts::Contracts;
+
 #[derive(Error, Debug)]
 pub enum Error {
     #[error("failed to start the gRPC server")]
     Transport(#[from] transport::Error),
+    #[error("failed to build the gRPC reflection service")]
+    Reflection(#[from] tonic_reflection::server::Error),
 }
 
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
@@ -35,6 +47,9 @@ pub struct Config {
     pub port: u16,
     pub concurrency_limit: nonempty::Usize,
     pub concurrency_limit_per_connection: nonempty::Usize,
+    /// clients that must authenticate with their bearer token. If empty, authentication is disabled
+    #[serde(default)]
+    pub clients: Vec<ClientConfig>,
 }
 
 impl Default for Config {
@@ -48,6 +63,7 @@ impl Default for Config {
             concurrency_limit_per_connection: 32
                 .try_into()
                 .expect("default concurrency limit per connection must be valid"),
+            clients: vec![],
         }
     }
 }
@@ -64,6 +80,24 @@ where
         ));
     }
 
+    if !config
+        .clients
+        .iter()
+        .map(|client| &client.name)
+        .all_unique()
+    {
+        return Err(de::Error::custom("gRPC client names must be unique"));
+    }
+
+    if !config
+        .clients
+        .iter()
+        .map(|client| client.token_sha256)
+        .all_unique()
+    {
+        return Err(de::Error::custom("gRPC client tokens must be unique"));
+    }
+
     Ok(config)
 }
 
@@ -71,7 +105,12 @@ where
 pub struct Server {
     config: Config,
     event_sub: event_sub::EventSubscriber,
-    msg_queue_client: broadcaster_v2::MsgQueueClient<cosmos::CosmosGrpcClient>,
+    cosmos_client: cosmos::CosmosGrpcClient,
+    contracts: Contracts,
+    /// not set in read-only mode
+    msg_queue_client: Option<broadcaster_v2::MsgQueueClient<cosmos::CosmosGrpcClient>>,
+    /// types of the currently enabled handlers, updated when the handlers are reloaded
+    handlers: watch::Receiver<Vec<String>>,
 }
 
 impl Server {
@@ -96,19 +135,45 @@ impl Server {
             // This logs at ERROR level with status code and error details
             // Example: ERROR grpc_request{method="..."}: failed to process request latency=5ms status=INVALID_ARGUMENT code=3 message="empty broadcast message"
             .on_failure(trace::DefaultOnFailure::new().level(tracing::Level::ERROR));
+        // lets clients such as grpcurl discover the services without access to the proto files
+        let reflection_service = tonic_reflection::server::Builder::configure()
+            .register_encoded_file_descriptor_set(ampd_proto::FILE_DESCRIPTOR_SET)
+            .build_v1()
+            .map_err(ErrorExt::<Error>::into_report)?;
+
+        let auth = auth::Auth::new(&self.config.clients);
+        info!(enabled = auth.is_enabled(), "gRPC client authentication");
+
+        let blockchain_service = blockchain_service::Service::builder()
+            .event_sub(self.event_sub)
+            .cosmos_client(self.cosmos_client)
+            .contracts(self.contracts)
+            .msg_queue_client(self.msg_queue_client)
+            .build();
+
         let router = transport::Server::builder()
             .layer(trace_layer)
             .layer(ConcurrencyLimitLayer::new(
                 self.config.concurrency_limit.into(),
             ))
             .concurrency_limit_per_connection(self.config.concurrency_limit_per_connection.into())
-            .add_service(BlockchainServiceServer::new(
-                blockchain_service::Service::builder()
-                    .event_sub(self.event_sub)
-                    .msg_queue_client(self.msg_queue_client)
-                    .build(),
+            .add_service(BlockchainServiceServer::with_interceptor(
+                blockchain_service.clone(),
+                auth.clone(),
+            ))
+            .add_service(BroadcastServiceServer::with_interceptor(
+                blockchain_service,
+                auth.clone(),
+            ))
+            .add_service(CryptoServiceServer::with_interceptor(
+                crypto_service::Service::new(),
+                auth.clone(),
+            ))
+            .add_service(InfoServiceServer::with_interceptor(
+                info_service::Service::new(self.handlers, auth.is_enabled()),
+                auth,
             ))
-            .add_service(CryptoServiceServer::new(crypto_service::Service::new()));
+            .add_service(reflection_service);
 
         info!(%addr, "gRPC server started");
 
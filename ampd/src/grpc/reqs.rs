@@ -1,8 +1,12 @@
-use ampd_proto::{BroadcastRequest, SubscribeRequest};
+use std::str::FromStr;
+
+use ampd_proto::broadcast::{BroadcastStreamRequest, EstimateGasRequest};
+use ampd_proto::{BroadcastRequest, ContractsRequest, QueryRequest, SubscribeRequest};
 use axelar_wasm_std::nonempty;
 use cosmrs::Any;
 use error_stack::{ensure, report, Report, Result};
 use report::ResultCompatExt;
+use router_api::ChainName;
 use thiserror::Error;
 use tonic::Request;
 
@@ -24,6 +28,45 @@ pub fn validate_broadcast(req: Request<BroadcastRequest>) -> Result<Any, Error>
         .ok_or(report!(Error::EmptyBroadcastMsg))
 }
 
+pub fn validate_broadcast_stream(req: BroadcastStreamRequest) -> Result<Any, Error> {
+    req.msg.ok_or(report!(Error::EmptyBroadcastMsg))
+}
+
+pub fn validate_estimate_gas(
+    req: Request<EstimateGasRequest>,
+) -> Result<nonempty::Vec<Any>, Error> {
+    nonempty::Vec::try_from(req.into_inner().msgs).map_err(|_| report!(Error::EmptyEstimateGasMsgs))
+}
+
+/// Returns the contract to query and the query, which must be a JSON encoded smart query
+pub fn validate_query(req: Request<QueryRequest>) -> Result<(TMAddress, Vec<u8>), Error> {
+    let QueryRequest { contract, query } = req.into_inner();
+
+    let contract: TMAddress = contract
+        .parse()
+        .change_context(Error::InvalidQueryContract(contract.clone()))?;
+    ensure!(
+        contract.as_ref().prefix() == PREFIX,
+        Error::InvalidQueryContract(contract.to_string())
+    );
+    serde_json::from_slice::<serde_json::Value>(&query).change_context(Error::InvalidQuery)?;
+
+    Ok((contract, query))
+}
+
+/// Returns the chain to look up the contracts of, if one is requested
+pub fn validate_contracts(req: Request<ContractsRequest>) -> Result<Option<ChainName>, Error> {
+    let ContractsRequest { chain } = req.into_inner();
+
+    if chain.is_empty() {
+        return Ok(None);
+    }
+
+    ChainName::from_str(&chain)
+        .change_context(Error::InvalidChain(chain.clone()))
+        .map(Some)
+}
+
 #[derive(Error, Debug)]
 pub enum Error {
     #[error("empty filter")]
@@ -32,6 +75,14 @@ pub enum Error {
     InvalidContractAddress(String),
     #[error("empty broadcast message")]
     EmptyBroadcastMsg,
+    #[error("no messages to estimate the gas of")]
+    EmptyEstimateGasMsgs,
+    #[error("invalid contract address {0} to query")]
+    InvalidQueryContract(String),
+    #[error("query is not valid JSON")]
+    InvalidQuery,
+    #[error("invalid chain name {0}")]
+    InvalidChain(String),
 }
 
 #[derive(Debug)]
@@ -399,4 +450,107 @@ mod tests {
         let req = Request::new(BroadcastRequest { msg: None });
         assert_err_contains!(validate_broadcast(req), Error, Error::EmptyBroadcastMsg);
     }
+
+    #[test]
+    fn validate_broadcast_stream_should_require_msg() {
+        let msg = Any {
+            type_url: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+            value: vec![1, 2, 3],
+        };
+        let req = BroadcastStreamRequest {
+            msg: Some(msg.clone()),
+        };
+        assert_eq!(validate_broadcast_stream(req).unwrap(), msg);
+
+        let req = BroadcastStreamRequest { msg: None };
+        assert_err_contains!(
+            validate_broadcast_stream(req),
+            Error,
+            Error::EmptyBroadcastMsg
+        );
+    }
+
+    #[test]
+    fn validate_estimate_gas_should_require_msgs() {
+        let msg = Any {
+            type_url: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+            value: vec![1, 2, 3],
+        };
+        let req = Request::new(EstimateGasRequest {
+            msgs: vec![msg.clone(), msg.clone()],
+        });
+        assert_eq!(
+            Vec::from(validate_estimate_gas(req).unwrap()),
+            vec![msg.clone(), msg]
+        );
+
+        let req = Request::new(EstimateGasRequest { msgs: vec![] });
+        assert_err_contains!(
+            validate_estimate_gas(req),
+            Error,
+            Error::EmptyEstimateGasMsgs
+        );
+    }
+
+    #[test]
+    fn validate_query_should_return_contract_and_query() {
+        let contract = TMAddress::random(PREFIX);
+        let req = Request::new(QueryRequest {
+            contract: contract.to_string(),
+            query: br#"{"verifier_set":{}}"#.to_vec(),
+        });
+
+        let (actual_contract, query) = validate_query(req).unwrap();
+        assert_eq!(actual_contract, contract);
+        assert_eq!(query, br#"{"verifier_set":{}}"#.to_vec());
+    }
+
+    #[test]
+    fn validate_query_should_fail_for_invalid_contract_or_query() {
+        for contract in [
+            "".to_string(),
+            "invalid_address".to_string(),
+            TMAddress::random("wrong").to_string(),
+        ] {
+            let req = Request::new(QueryRequest {
+                contract,
+                query: br#"{"verifier_set":{}}"#.to_vec(),
+            });
+            assert_err_contains!(validate_query(req), Error, Error::InvalidQueryContract(_));
+        }
+
+        for query in [vec![], b"{not json".to_vec()] {
+            let req = Request::new(QueryRequest {
+                contract: TMAddress::random(PREFIX).to_string(),
+                query,
+            });
+            assert_err_contains!(validate_query(req), Error, Error::InvalidQuery);
+        }
+    }
+
+    #[test]
+    fn validate_contracts_should_return_requested_chain() {
+        let req = Request::new(ContractsRequest {
+            chain: "".to_string(),
+        });
+        assert_eq!(validate_contracts(req).unwrap(), None);
+
+        let req = Request::new(ContractsRequest {
+            chain: "Ethereum".to_string(),
+        });
+        assert_eq!(
+            validate_contracts(req).unwrap(),
+            Some(ChainName::from_str("ethereum").unwrap())
+        );
+    }
+
+    #[test]
+    fn validate_contracts_should_fail_for_invalid_chain() {
+        for chain in ["invalid:chain", "chain_name_that_is_too_long"] {
+            let req = Request::new(ContractsRequest {
+                chain: chain.to_string(),
+            });
+            assert_err_contains!(validate_contracts(req), Error, Error::InvalidChain(_));
+        }
+    }
 }
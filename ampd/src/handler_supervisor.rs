@@ -0,0 +1,344 @@
+use async_trait::async_trait;
+use axelar_wasm_std::error::extend_err;
+use error_stack::{Result, ResultExt};
+use report::LoggableError;
+use tokio::select;
+use tokio::sync::mpsc;
+use tokio::task::JoinSet;
+use tokio_util::sync::CancellationToken;
+use tracing::{error, info, warn};
+use valuable::Valuable;
+
+use crate::asyncutil::task::CancellableTask;
+use crate::event_processor;
+use crate::handlers::config::Config;
+use crate::Error;
+
+pub type HandlerTask = CancellableTask<Result<(), event_processor::Error>>;
+
+#[async_trait]
+pub trait HandlerFactory {
+    async fn create(&self, config: Config) -> Result<HandlerTask, Error>;
+}
+
+struct RunningHandler {
+    id: usize,
+    config: Config,
+    token: CancellationToken,
+}
+
+/// Runs the event handlers and replaces them whenever a new handler config is received. Handlers whose config did not change
+/// keep running, handlers that were removed from the config are cancelled, and handlers that were added are started.
+/// Like a task group, the supervisor shuts down all handlers if one of them stops on its own.
+pub struct HandlerSupervisor<F>
+where
+    F: HandlerFactory,
+{
+    factory: F,
+    running: Vec<RunningHandler>,
+    tasks: JoinSet<(usize, Result<(), event_processor::Error>)>,
+    next_id: usize,
+}
+
+impl<F> HandlerSupervisor<F>
+where
+    F: HandlerFactory,
+{
+    pub fn new(factory: F) -> Self {
+        Self {
+            factory,
+            running: vec![],
+            tasks: JoinSet::new(),
+            next_id: 0,
+        }
+    }
+
+    /// Starts the handlers of the initial config and then applies every handler config received from `reloads`.
+    /// Failing to create one of the initial handlers is an error, while failing to create a reloaded handler is only logged,
+    /// so a bad reload does not take down the handlers that are already running.
+    pub async fn run(
+        mut self,
+        initial: Vec<Config>,
+        mut reloads: mpsc::Receiver<Vec<Config>>,
+        token: CancellationToken,
+    ) -> Result<(), Error> {
+        for config in initial {
+            let task = self.factory.create(config.clone()).await?;
+            self.start(config, task, &token);
+        }
+
+        let result = loop {
+            select! {
+                Some(configs) = reloads.recv() => self.reload(configs, &token).await,
+                Some(result) = self.tasks.join_next() => match result {
+                    Ok((id, result)) if self.running.iter().any(|handler| handler.id == id) => {
+                        break result.change_context(Error::EventProcessor);
+                    }
+                    // the handler was removed by a reload, so stopping is expected
+                    Ok((_, result)) => {
+                        let _ = result.inspect_err(|err| {
+                            warn!(err = LoggableError::from(err).as_value(), "removed handler stopped with an error")
+                        });
+                    }
+                    Err(err) => break Err(err).change_context(Error::EventProcessor),
+                },
+                _ = token.cancelled() => break Ok(()),
+            }
+        };
+
+        self.shutdown(result).await
+    }
+
+    async fn reload(&mut self, configs: Vec<Config>, token: &CancellationToken) {
+        let (kept, removed): (Vec<_>, Vec<_>) = std::mem::take(&mut self.running)
+            .into_iter()
+            .partition(|handler| configs.contains(&handler.config));
+        self.running = kept;
+
+        for handler in &removed {
+            info!(
+                contracts = ?handler.config.contracts(),
+                "stopping removed handler"
+            );
+            handler.token.cancel();
+        }
+
+        let mut started = 0usize;
+        for config in configs {
+            if self.running.iter().any(|handler| handler.config == config) {
+                continue;
+            }
+
+            match self.factory.create(config.clone()).await {
+                Ok(task) => {
+                    info!(contracts = ?config.contracts(), "starting added handler");
+                    self.start(config, task, token);
+                    started = started.saturating_add(1);
+                }
+                Err(err) => {
+                    error!(
+                        err = LoggableError::from(&err).as_value(),
+                        contracts = ?config.contracts(),
+                        "failed to start added handler"
+                    );
+                }
+            }
+        }
+
+        info!(
+            started,
+            stopped = removed.len(),
+            running = self.running.len(),
+            "reloaded handlers"
+        );
+    }
+
+    fn start(&mut self, config: Config, task: HandlerTask, token: &CancellationToken) {
+        let id = self.next_id;
+        self.next_id = self.next_id.saturating_add(1);
+
+        let token = token.child_token();
+        let task_token = token.clone();
+        self.tasks
+            .spawn(async move { (id, task.run(task_token).await) });
+        self.running.push(RunningHandler { id, config, token });
+    }
+
+    async fn shutdown(mut self, result: Result<(), Error>) -> Result<(), Error> {
+        for handler in &self.running {
+            handler.token.cancel();
+        }
+
+        let mut final_result = result;
+        while let Some(result) = self.tasks.join_next().await {
+            final_result = match result.change_context(Error::EventProcessor) {
+                Ok((_, Ok(()))) => final_result,
+                Ok((_, Err(err))) => {
+                    extend_err(final_result, err.change_context(Error::EventProcessor))
+                }
+                Err(err) => extend_err(final_result, err),
+            };
+        }
+
+        final_result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use async_trait::async_trait;
+    use error_stack::{report, Result};
+    use tokio::sync::mpsc;
+    use tokio_util::sync::CancellationToken;
+
+    use super::{HandlerFactory, HandlerSupervisor, HandlerTask};
+    use crate::asyncutil::task::CancellableTask;
+    use crate::event_processor;
+    use crate::handlers::config::Config;
+    use crate::types::TMAddress;
+    use crate::{Error, PREFIX};
+
+    #[derive(Debug, PartialEq, Eq, Hash)]
+    enum Lifecycle {
+        Started(TMAddress),
+        Stopped(TMAddress),
+    }
+
+    /// Creates handlers that report when they start and stop. Handlers of the chain "failing" cannot be created,
+    /// and handlers of the chain "exiting" stop on their own right after starting
+    struct TestFactory {
+        lifecycle: mpsc::UnboundedSender<Lifecycle>,
+    }
+
+    #[async_trait]
+    impl HandlerFactory for TestFactory {
+        async fn create(&self, config: Config) -> Result<HandlerTask, Error> {
+            let Config::MultisigSigner {
+                cosmwasm_contract,
+                chain_name,
+            } = config
+            else {
+                unreachable!()
+            };
+
+            if chain_name.as_ref() == "failing" {
+                return Err(report!(Error::Connection));
+            }
+
+            let lifecycle = self.lifecycle.clone();
+            let exiting = chain_name.as_ref() == "exiting";
+            Ok(CancellableTask::create(move |token| async move {
+                lifecycle
+                    .send(Lifecycle::Started(cosmwasm_contract.clone()))
+                    .unwrap();
+                if exiting {
+                    return Err(report!(event_processor::Error::EventStream));
+                }
+
+                token.cancelled().await;
+                lifecycle
+                    .send(Lifecycle::Stopped(cosmwasm_contract))
+                    .unwrap();
+                Ok(())
+            }))
+        }
+    }
+
+    fn handler_config(chain_name: &str) -> (Config, TMAddress) {
+        let contract = TMAddress::random(PREFIX);
+        let config = Config::MultisigSigner {
+            cosmwasm_contract: contract.clone(),
+            chain_name: chain_name.parse().unwrap(),
+        };
+
+        (config, contract)
+    }
+
+    async fn next_lifecycle_events(
+        lifecycle: &mut mpsc::UnboundedReceiver<Lifecycle>,
+        count: usize,
+    ) -> HashSet<Lifecycle> {
+        let mut events = HashSet::new();
+        for _ in 0..count {
+            events.insert(lifecycle.recv().await.unwrap());
+        }
+        events
+    }
+
+    #[tokio::test]
+    async fn reload_should_only_restart_changed_handlers() {
+        let (lifecycle_sender, mut lifecycle) = mpsc::unbounded_channel();
+        let (reload_sender, reloads) = mpsc::channel(1);
+        let token = CancellationToken::new();
+
+        let (kept, kept_contract) = handler_config("kept");
+        let (removed, removed_contract) = handler_config("removed");
+        let (added, added_contract) = handler_config("added");
+        let (failing, _) = handler_config("failing");
+
+        let supervisor = HandlerSupervisor::new(TestFactory {
+            lifecycle: lifecycle_sender,
+        });
+        let handle =
+            tokio::spawn(supervisor.run(vec![kept.clone(), removed], reloads, token.clone()));
+
+        assert_eq!(
+            next_lifecycle_events(&mut lifecycle, 2).await,
+            HashSet::from([
+                Lifecycle::Started(kept_contract.clone()),
+                Lifecycle::Started(removed_contract.clone()),
+            ])
+        );
+
+        // the handler that fails to be created is skipped and does not affect the other handlers
+        reload_sender
+            .send(vec![kept, failing, added])
+            .await
+            .unwrap();
+        assert_eq!(
+            next_lifecycle_events(&mut lifecycle, 2).await,
+            HashSet::from([
+                Lifecycle::Stopped(removed_contract),
+                Lifecycle::Started(added_contract.clone()),
+            ])
+        );
+
+        token.cancel();
+        assert!(handle.await.unwrap().is_ok());
+        assert_eq!(
+            next_lifecycle_events(&mut lifecycle, 2).await,
+            HashSet::from([
+                Lifecycle::Stopped(kept_contract),
+                Lifecycle::Stopped(added_contract),
+            ])
+        );
+        assert!(lifecycle.recv().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn failing_initial_handler_should_stop_supervisor() {
+        let (lifecycle_sender, _lifecycle) = mpsc::unbounded_channel();
+        let (_reload_sender, reloads) = mpsc::channel(1);
+
+        let supervisor = HandlerSupervisor::new(TestFactory {
+            lifecycle: lifecycle_sender,
+        });
+        let result = supervisor
+            .run(
+                vec![handler_config("failing").0],
+                reloads,
+                CancellationToken::new(),
+            )
+            .await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn handler_stopping_on_its_own_should_stop_all_handlers() {
+        let (lifecycle_sender, mut lifecycle) = mpsc::unbounded_channel();
+        let (_reload_sender, reloads) = mpsc::channel(1);
+
+        let (running, running_contract) = handler_config("running");
+        let (exiting, exiting_contract) = handler_config("exiting");
+
+        let supervisor = HandlerSupervisor::new(TestFactory {
+            lifecycle: lifecycle_sender,
+        });
+        let result = supervisor
+            .run(vec![running, exiting], reloads, CancellationToken::new())
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(
+            next_lifecycle_events(&mut lifecycle, 3).await,
+            HashSet::from([
+                Lifecycle::Started(running_contract.clone()),
+                Lifecycle::Started(exiting_contract),
+                Lifecycle::Stopped(running_contract),
+            ])
+        );
+    }
+}
@@ -0,0 +1,413 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use async_trait::async_trait;
+use axelar_wasm_std::voting::PollId;
+use cosmrs::Any;
+use error_stack::{Result, ResultExt};
+use ethers_providers::JsonRpcClient;
+use events::Event;
+use report::LoggableError;
+use router_api::ChainName;
+use serde::{Deserialize, Serialize};
+use solana_client::nonblocking::rpc_client::RpcClient;
+use thiserror::Error;
+use tracing::{error, warn};
+use valuable::Valuable;
+use voting_verifier::msg::ObservedBlock;
+
+use crate::event_processor::EventHandler;
+use crate::evm::json_rpc::EthereumClient;
+use crate::json_rpc;
+use crate::types::TMAddress;
+
+/// Chain lag config of each chain. Voting is never paused for chains without a config
+pub type Configs = HashMap<ChainName, Config>;
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+#[serde(default)]
+pub struct Config {
+    /// Maximum number of blocks that the latest height reported by a handler's RPC endpoint may be behind the
+    /// source chain height a poll was observed at. Handlers don't vote in polls beyond it. Disabled if not set
+    pub max_lag: Option<u64>,
+}
+
+#[derive(Error, Debug)]
+#[error("failed to get the latest height of the source chain")]
+pub struct Error;
+
+/// Source of the latest height of a chain, in the unit that polls of the chain are observed at
+#[async_trait]
+pub trait LatestHeight {
+    async fn latest_height(&self) -> Result<u64, Error>;
+}
+
+/// Latest block of an EVM chain
+pub struct EvmBlock<C>(pub C);
+
+#[async_trait]
+impl<C> LatestHeight for EvmBlock<C>
+where
+    C: EthereumClient + Send + Sync,
+{
+    async fn latest_height(&self) -> Result<u64, Error> {
+        self.0
+            .block_number()
+            .await
+            .change_context(Error)
+            .map(|height| height.as_u64())
+    }
+}
+
+/// Latest checkpoint of Sui
+pub struct SuiCheckpoint<P: JsonRpcClient>(pub json_rpc::Client<P>);
+
+#[async_trait]
+impl<P> LatestHeight for SuiCheckpoint<P>
+where
+    P: JsonRpcClient + Send + Sync + 'static,
+{
+    async fn latest_height(&self) -> Result<u64, Error> {
+        // sequence numbers are returned as strings
+        let checkpoint: String = self
+            .0
+            .request("sui_getLatestCheckpointSequenceNumber", ())
+            .await
+            .change_context(Error)?;
+
+        checkpoint.parse().change_context(Error)
+    }
+}
+
+/// Latest slot of Solana
+#[async_trait]
+impl LatestHeight for RpcClient {
+    async fn latest_height(&self) -> Result<u64, Error> {
+        self.get_slot().await.change_context(Error)
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub struct Metrics {
+    /// number of blocks the RPC endpoint was behind the most recent poll that referenced a source chain height, per chain
+    pub lag: HashMap<ChainName, u64>,
+    /// number of polls the verifier did not vote in because the RPC endpoint was lagging, per chain
+    pub paused_polls: HashMap<ChainName, u64>,
+}
+
+/// Compares the latest source chain height known to a handler's RPC endpoint with the height that a poll was observed at.
+/// An endpoint that lags behind doesn't know about recent transactions yet, so the verifier would vote NotFound
+/// for messages that exist. Instead, it pauses voting for the chain and alerts while the lag exceeds the chain's threshold
+#[derive(Clone, Default)]
+pub struct ChainLag {
+    configs: Arc<Configs>,
+    metrics: Arc<Mutex<Metrics>>,
+}
+
+impl ChainLag {
+    pub fn new(configs: Configs) -> Self {
+        Self {
+            configs: Arc::new(configs),
+            metrics: Arc::new(Mutex::new(Metrics::default())),
+        }
+    }
+
+    /// Records the lag of the RPC endpoint and returns true if the verifier should not vote in the poll
+    pub fn should_pause(
+        &self,
+        chain: &ChainName,
+        poll_id: &str,
+        rpc_height: u64,
+        observed_height: u64,
+    ) -> bool {
+        let lag = observed_height.saturating_sub(rpc_height);
+        let pause = self
+            .configs
+            .get(chain)
+            .and_then(|config| config.max_lag)
+            .is_some_and(|max_lag| lag > max_lag);
+
+        let mut metrics = self.lock();
+        metrics.lag.insert(chain.clone(), lag);
+
+        if pause {
+            error!(
+                chain = chain.to_string(),
+                poll_id,
+                rpc_height,
+                observed_height,
+                lag,
+                "RPC endpoint is lagging behind the source chain, not voting in poll"
+            );
+
+            let count = metrics.paused_polls.entry(chain.clone()).or_default();
+            *count = count.saturating_add(1);
+        }
+
+        pause
+    }
+
+    pub fn metrics(&self) -> Metrics {
+        self.lock().clone()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Metrics> {
+        self.metrics
+            .lock()
+            .expect("chain lag metrics lock should not be poisoned")
+    }
+}
+
+#[derive(Deserialize)]
+struct PollStarted {
+    poll_id: PollId,
+    source_chain: ChainName,
+    participants: Vec<TMAddress>,
+    #[serde(default)]
+    observed_at: Option<ObservedBlock>,
+}
+
+/// Wraps a voting handler, so the verifier doesn't vote in polls that were observed at a source chain height the
+/// handler's RPC endpoint hasn't reached yet. If the latest height can't be retrieved, the check is skipped
+pub struct Handler<H, L> {
+    inner: H,
+    verifier: TMAddress,
+    latest_height: L,
+    chain_lag: ChainLag,
+}
+
+impl<H, L> Handler<H, L> {
+    pub fn new(inner: H, verifier: TMAddress, latest_height: L, chain_lag: ChainLag) -> Self {
+        Self {
+            inner,
+            verifier,
+            latest_height,
+            chain_lag,
+        }
+    }
+
+    fn observed_poll(&self, event: &Event) -> Option<(PollStarted, ObservedBlock)> {
+        let Event::Abci {
+            event_type,
+            attributes,
+        } = event
+        else {
+            return None;
+        };
+
+        if !(event_type.starts_with("wasm-") && event_type.ends_with("_poll_started")) {
+            return None;
+        }
+
+        let mut poll_started: PollStarted =
+            serde_json::from_value(attributes.clone().into()).ok()?;
+        let observed_at = poll_started.observed_at.take()?;
+
+        poll_started
+            .participants
+            .contains(&self.verifier)
+            .then_some((poll_started, observed_at))
+    }
+}
+
+#[async_trait]
+impl<H, L> EventHandler for Handler<H, L>
+where
+    H: EventHandler + Send + Sync,
+    L: LatestHeight + Send + Sync,
+{
+    type Err = H::Err;
+
+    async fn handle(&self, event: &Event) -> Result<Vec<Any>, Self::Err> {
+        if let Some((poll_started, observed_at)) = self.observed_poll(event) {
+            match self.latest_height.latest_height().await {
+                Ok(rpc_height)
+                    if self.chain_lag.should_pause(
+                        &poll_started.source_chain,
+                        &poll_started.poll_id.to_string(),
+                        rpc_height,
+                        observed_at.height,
+                    ) =>
+                {
+                    return Ok(vec![]);
+                }
+                Ok(_) => {}
+                Err(err) => warn!(
+                    err = LoggableError::from(&err).as_value(),
+                    poll_id = poll_started.poll_id.to_string(),
+                    "failed to get the latest height of the source chain, skipping the RPC lag check"
+                ),
+            }
+        }
+
+        self.inner.handle(event).await
+    }
+
+    async fn on_broadcast(&self, event: &Event) -> Result<(), Self::Err> {
+        self.inner.on_broadcast(event).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    use error_stack::report;
+
+    use super::*;
+    use crate::PREFIX;
+
+    /// Votes in every poll
+    #[derive(Default)]
+    struct VotingHandler {
+        votes: AtomicU64,
+    }
+
+    #[async_trait]
+    impl EventHandler for VotingHandler {
+        type Err = std::convert::Infallible;
+
+        async fn handle(&self, _event: &Event) -> Result<Vec<Any>, Self::Err> {
+            self.votes.fetch_add(1, Ordering::SeqCst);
+            Ok(vec![Any::default()])
+        }
+    }
+
+    struct FixedHeight(Option<u64>);
+
+    #[async_trait]
+    impl LatestHeight for FixedHeight {
+        async fn latest_height(&self) -> Result<u64, Error> {
+            self.0.ok_or_else(|| report!(Error))
+        }
+    }
+
+    fn poll_started_event(verifier: &TMAddress, observed_at: Option<u64>) -> Event {
+        let mut attributes = serde_json::json!({
+            "_contract_address": TMAddress::random(PREFIX).to_string(),
+            "poll_id": "1",
+            "source_chain": "ethereum",
+            "participants": [verifier.to_string()],
+        })
+        .as_object()
+        .unwrap()
+        .clone();
+
+        if let Some(height) = observed_at {
+            attributes.insert(
+                "observed_at".to_string(),
+                serde_json::json!({ "height": height }),
+            );
+        }
+
+        Event::Abci {
+            event_type: "wasm-messages_poll_started".to_string(),
+            attributes,
+        }
+    }
+
+    fn handler(rpc_height: Option<u64>) -> (Handler<VotingHandler, FixedHeight>, TMAddress) {
+        let verifier = TMAddress::random(PREFIX);
+        let chain_lag = ChainLag::new(HashMap::from([(
+            "ethereum".parse().unwrap(),
+            Config { max_lag: Some(10) },
+        )]));
+
+        (
+            Handler::new(
+                VotingHandler::default(),
+                verifier.clone(),
+                FixedHeight(rpc_height),
+                chain_lag,
+            ),
+            verifier,
+        )
+    }
+
+    #[test]
+    fn should_pause_only_beyond_max_lag() {
+        let chain: ChainName = "ethereum".parse().unwrap();
+        let chain_lag = ChainLag::new(HashMap::from([(
+            chain.clone(),
+            Config { max_lag: Some(10) },
+        )]));
+
+        assert!(!chain_lag.should_pause(&chain, "1", 100, 90));
+        assert_eq!(chain_lag.metrics().lag, HashMap::from([(chain.clone(), 0)]));
+
+        assert!(!chain_lag.should_pause(&chain, "2", 100, 110));
+        assert_eq!(
+            chain_lag.metrics().lag,
+            HashMap::from([(chain.clone(), 10)])
+        );
+
+        assert!(chain_lag.should_pause(&chain, "3", 100, 111));
+        assert_eq!(
+            chain_lag.metrics(),
+            Metrics {
+                lag: HashMap::from([(chain.clone(), 11)]),
+                paused_polls: HashMap::from([(chain, 1)]),
+            }
+        );
+    }
+
+    #[test]
+    fn should_never_pause_chains_without_max_lag() {
+        let chain: ChainName = "ethereum".parse().unwrap();
+        let chain_lag = ChainLag::new(HashMap::from([(
+            "sui".parse().unwrap(),
+            Config { max_lag: Some(10) },
+        )]));
+
+        assert!(!chain_lag.should_pause(&chain, "1", 0, u64::MAX));
+        assert_eq!(chain_lag.metrics().lag, HashMap::from([(chain, u64::MAX)]));
+        assert!(chain_lag.metrics().paused_polls.is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_not_vote_while_rpc_is_lagging_behind_observed_height() {
+        let (in_sync, verifier) = handler(Some(40));
+        assert_eq!(
+            in_sync
+                .handle(&poll_started_event(&verifier, Some(50)))
+                .await
+                .unwrap()
+                .len(),
+            1
+        );
+
+        let (lagging, verifier) = handler(Some(39));
+        assert!(lagging
+            .handle(&poll_started_event(&verifier, Some(50)))
+            .await
+            .unwrap()
+            .is_empty());
+        assert_eq!(lagging.chain_lag.metrics().paused_polls.len(), 1);
+
+        // polls without an observed height are not checked
+        assert_eq!(
+            lagging
+                .handle(&poll_started_event(&verifier, None))
+                .await
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn should_skip_the_check_if_the_latest_height_is_unavailable() {
+        let (handler, verifier) = handler(None);
+
+        assert_eq!(
+            handler
+                .handle(&poll_started_event(&verifier, Some(50)))
+                .await
+                .unwrap()
+                .len(),
+            1
+        );
+        assert_eq!(handler.inner.votes.load(Ordering::SeqCst), 1);
+        assert!(handler.chain_lag.metrics().lag.is_empty());
+    }
+}
@@ -0,0 +1,433 @@
+use std::collections::{HashMap, VecDeque};
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use ethers_providers::{JsonRpcClient, ProviderError};
+use router_api::ChainName;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use tracing::{error, info};
+
+/// Circuit breaker config of each chain. The circuit breaker is disabled for chains without a config
+pub type Configs = HashMap<ChainName, Config>;
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(default)]
+pub struct Config {
+    /// Number of failed calls among the most recent `window` calls to the chain's RPC endpoint that opens the circuit.
+    /// Disabled if not set
+    pub max_failures: Option<usize>,
+    /// Number of most recent calls that failures are counted in
+    pub window: usize,
+    /// Calls that take longer than this count as failed, even if they succeed eventually. No limit if not set
+    #[serde(with = "humantime_serde")]
+    pub max_latency: Option<Duration>,
+    /// How long the circuit stays open before a single probe call is let through
+    #[serde(with = "humantime_serde")]
+    pub open_duration: Duration,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            max_failures: None,
+            window: 20,
+            max_latency: None,
+            open_duration: Duration::from_secs(30),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CircuitState {
+    /// calls go through
+    #[default]
+    Closed,
+    /// calls are rejected
+    Open,
+    /// a single probe call goes through to decide whether to close the circuit again
+    HalfOpen,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub struct Metrics {
+    /// current state of the circuit, per chain
+    pub state: HashMap<ChainName, CircuitState>,
+    /// number of times the circuit was opened, per chain
+    pub trips: HashMap<ChainName, u64>,
+    /// number of calls that were rejected because the circuit was open, per chain
+    pub rejected_calls: HashMap<ChainName, u64>,
+}
+
+#[derive(Debug, Default)]
+struct Circuit {
+    state: CircuitState,
+    /// when the circuit was opened, or when the probe call was let through if it is half open
+    since: Option<Instant>,
+    /// outcomes of the most recent calls while closed, true if the call failed
+    outcomes: VecDeque<bool>,
+}
+
+#[derive(Debug, Default)]
+struct Inner {
+    circuits: HashMap<ChainName, Circuit>,
+    metrics: Metrics,
+}
+
+/// Keeps track of failing and slow calls to the RPC endpoint of each chain. Once too many of the recent calls failed,
+/// the circuit opens and calls to the endpoint are rejected right away instead of piling up requests that time out.
+/// After `open_duration`, a single probe call decides whether the circuit closes again
+#[derive(Clone, Debug, Default)]
+pub struct CircuitBreaker {
+    configs: Arc<Configs>,
+    inner: Arc<Mutex<Inner>>,
+}
+
+impl CircuitBreaker {
+    pub fn new(configs: Configs) -> Self {
+        Self {
+            configs: Arc::new(configs),
+            inner: Arc::new(Mutex::new(Inner::default())),
+        }
+    }
+
+    /// Wraps the JSON-RPC transport of the chain's RPC client, so every call to the endpoint goes through the chain's circuit
+    pub fn guard<P>(&self, chain: ChainName, provider: P) -> Guarded<P>
+    where
+        P: JsonRpcClient,
+    {
+        Guarded {
+            provider,
+            chain,
+            circuit_breaker: self.clone(),
+        }
+    }
+
+    /// Returns the config of the chain if its circuit breaker is enabled
+    fn config(&self, chain: &ChainName) -> Option<(&Config, usize)> {
+        self.configs.get(chain).and_then(|config| {
+            config
+                .max_failures
+                .map(|max_failures| (config, max_failures))
+        })
+    }
+
+    /// Returns true if the RPC endpoint of the chain may be called
+    pub fn allow(&self, chain: &ChainName) -> bool {
+        let Some((config, _)) = self.config(chain) else {
+            return true;
+        };
+
+        let mut inner = self.lock();
+        let circuit = inner.circuits.entry(chain.clone()).or_default();
+
+        let allowed = match circuit.state {
+            CircuitState::Closed => true,
+            // a probe call that never reported back must not keep the circuit half open forever
+            CircuitState::Open | CircuitState::HalfOpen
+                if circuit
+                    .since
+                    .is_some_and(|since| since.elapsed() >= config.open_duration) =>
+            {
+                if circuit.state == CircuitState::Open {
+                    info!(
+                        chain = chain.to_string(),
+                        "letting a probe call through to the RPC endpoint"
+                    );
+                }
+
+                circuit.state = CircuitState::HalfOpen;
+                circuit.since = Some(Instant::now());
+                true
+            }
+            CircuitState::Open | CircuitState::HalfOpen => false,
+        };
+
+        let state = circuit.state;
+        inner.metrics.state.insert(chain.clone(), state);
+        if !allowed {
+            let count = inner
+                .metrics
+                .rejected_calls
+                .entry(chain.clone())
+                .or_default();
+            *count = count.saturating_add(1);
+        }
+
+        allowed
+    }
+
+    /// Records the outcome of a call to the RPC endpoint of the chain
+    pub fn record(&self, chain: &ChainName, succeeded: bool, latency: Duration) {
+        let Some((config, max_failures)) = self.config(chain) else {
+            return;
+        };
+
+        let failed = !succeeded
+            || config
+                .max_latency
+                .is_some_and(|max_latency| latency > max_latency);
+
+        let mut inner = self.lock();
+        let circuit = inner.circuits.entry(chain.clone()).or_default();
+
+        let tripped = match circuit.state {
+            CircuitState::Closed => {
+                circuit.outcomes.push_back(failed);
+                if circuit.outcomes.len() > config.window {
+                    circuit.outcomes.pop_front();
+                }
+
+                circuit.outcomes.iter().filter(|failed| **failed).count() >= max_failures
+            }
+            CircuitState::HalfOpen if failed => true,
+            CircuitState::HalfOpen => {
+                info!(
+                    chain = chain.to_string(),
+                    "probe call to the RPC endpoint succeeded, closing the circuit"
+                );
+
+                circuit.state = CircuitState::Closed;
+                circuit.since = None;
+                circuit.outcomes.clear();
+                false
+            }
+            // calls that were already in flight when the circuit opened don't change anything
+            CircuitState::Open => false,
+        };
+
+        if tripped {
+            error!(
+                chain = chain.to_string(),
+                latency_ms = latency.as_millis(),
+                "calls to the RPC endpoint are failing, opening the circuit"
+            );
+
+            circuit.state = CircuitState::Open;
+            circuit.since = Some(Instant::now());
+            circuit.outcomes.clear();
+        }
+
+        let state = circuit.state;
+        inner.metrics.state.insert(chain.clone(), state);
+        if tripped {
+            let count = inner.metrics.trips.entry(chain.clone()).or_default();
+            *count = count.saturating_add(1);
+        }
+    }
+
+    pub fn metrics(&self) -> Metrics {
+        self.lock().metrics.clone()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Inner> {
+        self.inner
+            .lock()
+            .expect("circuit breaker lock should not be poisoned")
+    }
+}
+
+/// Forwards requests to the wrapped provider while the circuit of the chain is closed, and records their outcome
+#[derive(Debug)]
+pub struct Guarded<P> {
+    provider: P,
+    chain: ChainName,
+    circuit_breaker: CircuitBreaker,
+}
+
+#[async_trait]
+impl<P> JsonRpcClient for Guarded<P>
+where
+    P: JsonRpcClient,
+{
+    type Error = ProviderError;
+
+    async fn request<T, R>(&self, method: &str, params: T) -> std::result::Result<R, Self::Error>
+    where
+        T: Debug + Serialize + Send + Sync,
+        R: DeserializeOwned + Send,
+    {
+        if !self.circuit_breaker.allow(&self.chain) {
+            return Err(ProviderError::CustomError(format!(
+                "circuit of the RPC endpoint of chain {} is open",
+                self.chain
+            )));
+        }
+
+        let started_at = Instant::now();
+        let result = self.provider.request(method, params).await;
+        self.circuit_breaker
+            .record(&self.chain, result.is_ok(), started_at.elapsed());
+
+        result.map_err(Into::into)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::Value;
+
+    use super::*;
+    use crate::json_rpc::replay::{Cassette, Interaction, Outcome, Replay};
+
+    const FAST: Duration = Duration::from_millis(10);
+
+    fn circuit_breaker(open_duration: Duration) -> CircuitBreaker {
+        CircuitBreaker::new(HashMap::from([(
+            "ethereum".parse().unwrap(),
+            Config {
+                max_failures: Some(2),
+                window: 3,
+                max_latency: Some(Duration::from_secs(1)),
+                open_duration,
+            },
+        )]))
+    }
+
+    #[test]
+    fn should_open_after_max_failures_within_window() {
+        let chain: ChainName = "ethereum".parse().unwrap();
+        let circuit_breaker = circuit_breaker(Duration::from_secs(3600));
+
+        circuit_breaker.record(&chain, false, FAST);
+        circuit_breaker.record(&chain, true, FAST);
+        circuit_breaker.record(&chain, true, FAST);
+        // the first failure has dropped out of the window
+        circuit_breaker.record(&chain, false, FAST);
+        assert!(circuit_breaker.allow(&chain));
+
+        // slow calls count as failed
+        circuit_breaker.record(&chain, true, Duration::from_secs(2));
+        assert!(!circuit_breaker.allow(&chain));
+        assert!(!circuit_breaker.allow(&chain));
+
+        assert_eq!(
+            circuit_breaker.metrics(),
+            Metrics {
+                state: HashMap::from([(chain.clone(), CircuitState::Open)]),
+                trips: HashMap::from([(chain.clone(), 1)]),
+                rejected_calls: HashMap::from([(chain, 2)]),
+            }
+        );
+    }
+
+    #[test]
+    fn should_close_after_successful_probe() {
+        let chain: ChainName = "ethereum".parse().unwrap();
+        let circuit_breaker = circuit_breaker(Duration::ZERO);
+
+        circuit_breaker.record(&chain, false, FAST);
+        circuit_breaker.record(&chain, false, FAST);
+        assert_eq!(
+            circuit_breaker.metrics().state,
+            HashMap::from([(chain.clone(), CircuitState::Open)])
+        );
+
+        assert!(circuit_breaker.allow(&chain));
+        assert_eq!(
+            circuit_breaker.metrics().state,
+            HashMap::from([(chain.clone(), CircuitState::HalfOpen)])
+        );
+
+        circuit_breaker.record(&chain, true, FAST);
+        assert_eq!(
+            circuit_breaker.metrics().state,
+            HashMap::from([(chain.clone(), CircuitState::Closed)])
+        );
+
+        // failures from before the circuit opened are forgotten
+        circuit_breaker.record(&chain, false, FAST);
+        assert!(circuit_breaker.allow(&chain));
+        assert_eq!(circuit_breaker.metrics().trips, HashMap::from([(chain, 1)]));
+    }
+
+    #[test]
+    fn should_reopen_after_failed_probe() {
+        let chain: ChainName = "ethereum".parse().unwrap();
+        let circuit_breaker = circuit_breaker(Duration::ZERO);
+
+        circuit_breaker.record(&chain, false, FAST);
+        circuit_breaker.record(&chain, false, FAST);
+        assert!(circuit_breaker.allow(&chain));
+
+        circuit_breaker.record(&chain, false, FAST);
+        assert_eq!(
+            circuit_breaker.metrics().state,
+            HashMap::from([(chain.clone(), CircuitState::Open)])
+        );
+        assert_eq!(circuit_breaker.metrics().trips, HashMap::from([(chain, 2)]));
+    }
+
+    #[test]
+    fn should_never_open_without_max_failures() {
+        let chain: ChainName = "ethereum".parse().unwrap();
+        let circuit_breaker =
+            CircuitBreaker::new(HashMap::from([(chain.clone(), Config::default())]));
+
+        for _ in 0..100 {
+            circuit_breaker.record(&chain, false, FAST);
+        }
+
+        assert!(circuit_breaker.allow(&chain));
+        assert_eq!(circuit_breaker.metrics(), Metrics::default());
+    }
+
+    #[test]
+    fn circuits_should_be_configured_per_chain() {
+        let ethereum: ChainName = "ethereum".parse().unwrap();
+        let polygon: ChainName = "polygon".parse().unwrap();
+        let circuit_breaker = circuit_breaker(Duration::from_secs(3600));
+
+        for _ in 0..2 {
+            circuit_breaker.record(&ethereum, false, FAST);
+            circuit_breaker.record(&polygon, false, FAST);
+        }
+
+        assert!(!circuit_breaker.allow(&ethereum));
+        assert!(circuit_breaker.allow(&polygon));
+        assert_eq!(
+            circuit_breaker.metrics().trips,
+            HashMap::from([(ethereum, 1)])
+        );
+    }
+
+    #[tokio::test]
+    async fn guarded_provider_should_reject_calls_while_circuit_is_open() {
+        let chain: ChainName = "ethereum".parse().unwrap();
+        let circuit_breaker = circuit_breaker(Duration::from_secs(3600));
+        let provider = circuit_breaker.guard(
+            chain.clone(),
+            Replay::new(Cassette {
+                interactions: vec![Interaction {
+                    method: "eth_blockNumber".to_string(),
+                    params: Value::Null,
+                    outcome: Outcome::Result("0x1".into()),
+                }],
+            }),
+        );
+
+        let block_number: String = provider.request("eth_blockNumber", ()).await.unwrap();
+        assert_eq!(block_number, "0x1");
+
+        // requests that were not recorded fail
+        for _ in 0..2 {
+            assert!(provider
+                .request::<_, Value>("eth_chainId", ())
+                .await
+                .is_err());
+        }
+
+        // the recorded request is rejected without reaching the provider now
+        assert!(provider
+            .request::<_, String>("eth_blockNumber", ())
+            .await
+            .is_err());
+
+        let metrics = circuit_breaker.metrics();
+        assert_eq!(metrics.state[&chain], CircuitState::Open);
+        assert_eq!(metrics.rejected_calls[&chain], 1);
+    }
+}
@@ -27,6 +27,9 @@ pub enum Config {
         #[serde(flatten, with = "chain")]
         chain: Chain,
         rpc_timeout: Option<Duration>,
+        /// Further endpoints of the same chain. Messages are only voted on as usual if all endpoints agree on them
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        cross_check_rpc_urls: Vec<Url>,
     },
     EvmVerifierSetVerifier {
         cosmwasm_contract: TMAddress,
@@ -96,6 +99,81 @@ pub enum Config {
     },
 }
 
+impl Config {
+    /// Returns the handler type as it is set in the config file
+    pub fn handler_type(&self) -> &'static str {
+        match self {
+            Config::EvmMsgVerifier { .. } => "EvmMsgVerifier",
+            Config::EvmVerifierSetVerifier { .. } => "EvmVerifierSetVerifier",
+            Config::MultisigSigner { .. } => "MultisigSigner",
+            Config::SuiMsgVerifier { .. } => "SuiMsgVerifier",
+            Config::SuiVerifierSetVerifier { .. } => "SuiVerifierSetVerifier",
+            Config::XRPLMsgVerifier { .. } => "XRPLMsgVerifier",
+            Config::XRPLMultisigSigner { .. } => "XRPLMultisigSigner",
+            Config::MvxMsgVerifier { .. } => "MvxMsgVerifier",
+            Config::MvxVerifierSetVerifier { .. } => "MvxVerifierSetVerifier",
+            Config::StellarMsgVerifier { .. } => "StellarMsgVerifier",
+            Config::StellarVerifierSetVerifier { .. } => "StellarVerifierSetVerifier",
+            Config::StarknetMsgVerifier { .. } => "StarknetMsgVerifier",
+            Config::StarknetVerifierSetVerifier { .. } => "StarknetVerifierSetVerifier",
+            Config::SolanaMsgVerifier { .. } => "SolanaMsgVerifier",
+            Config::SolanaVerifierSetVerifier { .. } => "SolanaVerifierSetVerifier",
+        }
+    }
+
+    /// Returns the addresses of the contracts whose events the handler consumes
+    pub fn contracts(&self) -> Vec<TMAddress> {
+        match self {
+            Config::XRPLMultisigSigner {
+                multisig_prover_contract,
+                multisig_contract,
+            } => vec![multisig_prover_contract.clone(), multisig_contract.clone()],
+            Config::EvmMsgVerifier {
+                cosmwasm_contract, ..
+            }
+            | Config::EvmVerifierSetVerifier {
+                cosmwasm_contract, ..
+            }
+            | Config::MultisigSigner {
+                cosmwasm_contract, ..
+            }
+            | Config::SuiMsgVerifier {
+                cosmwasm_contract, ..
+            }
+            | Config::SuiVerifierSetVerifier {
+                cosmwasm_contract, ..
+            }
+            | Config::XRPLMsgVerifier {
+                cosmwasm_contract, ..
+            }
+            | Config::MvxMsgVerifier {
+                cosmwasm_contract, ..
+            }
+            | Config::MvxVerifierSetVerifier {
+                cosmwasm_contract, ..
+            }
+            | Config::StellarMsgVerifier {
+                cosmwasm_contract, ..
+            }
+            | Config::StellarVerifierSetVerifier {
+                cosmwasm_contract, ..
+            }
+            | Config::StarknetMsgVerifier {
+                cosmwasm_contract, ..
+            }
+            | Config::StarknetVerifierSetVerifier {
+                cosmwasm_contract, ..
+            }
+            | Config::SolanaMsgVerifier {
+                cosmwasm_contract, ..
+            }
+            | Config::SolanaVerifierSetVerifier {
+                cosmwasm_contract, ..
+            } => vec![cosmwasm_contract.clone()],
+        }
+    }
+}
+
 fn validate_starknet_msg_verifier_config<'de, D>(configs: &[Config]) -> Result<(), D::Error>
 where
     D: Deserializer<'de>,
@@ -11,11 +11,12 @@ use error_stack::ResultExt;
 use ethers_core::types::{TransactionReceipt, U64};
 use events::Error::EventTypeMismatch;
 use events_derive::try_from;
-use futures::future::join_all;
+use futures::future::{join_all, try_join_all};
+use report::LoggableError;
 use router_api::ChainName;
 use serde::Deserialize;
 use tokio::sync::watch::Receiver;
-use tracing::{info, info_span};
+use tracing::{info, info_span, warn};
 use valuable::Valuable;
 use voting_verifier::msg::ExecuteMsg;
 
@@ -26,6 +27,8 @@ use crate::evm::json_rpc::EthereumClient;
 use crate::evm::verifier::verify_message;
 use crate::handlers::errors::Error;
 use crate::handlers::errors::Error::DeserializeEvent;
+use crate::handlers::rpc_divergence::{Countermeasure, Evidence, RpcDivergence};
+use crate::handlers::vote_cache::{self, VoteCache};
 use crate::types::{EVMAddress, Hash, TMAddress};
 
 type Result<T> = error_stack::Result<T, Error>;
@@ -61,6 +64,10 @@ where
     finalizer_type: Finalization,
     rpc_client: C,
     latest_block_height: Receiver<u64>,
+    vote_cache: VoteCache,
+    rpc_endpoint: String,
+    cross_check_clients: Vec<(String, C)>,
+    rpc_divergence: RpcDivergence,
 }
 
 impl<C> Handler<C>
@@ -74,6 +81,7 @@ where
         finalizer_type: Finalization,
         rpc_client: C,
         latest_block_height: Receiver<u64>,
+        vote_cache: VoteCache,
     ) -> Self {
         Self {
             verifier,
@@ -82,31 +90,56 @@ where
             finalizer_type,
             rpc_client,
             latest_block_height,
+            vote_cache,
+            rpc_endpoint: String::default(),
+            cross_check_clients: vec![],
+            rpc_divergence: RpcDivergence::default(),
         }
     }
 
+    /// Verifies every message with each of the cross check clients as well. Messages are only voted on
+    /// as usual if all endpoints agree, otherwise the countermeasure of `rpc_divergence` is applied.
+    /// Cross check endpoints that fail are left out. If an endpoint hasn't finalized the block of a transaction
+    /// that another endpoint returned yet, the verifier abstains on the message without recording a divergence.
+    /// Endpoints are identified by the given labels in the recorded evidence
+    pub fn with_cross_checks(
+        mut self,
+        rpc_endpoint: String,
+        cross_check_clients: Vec<(String, C)>,
+        rpc_divergence: RpcDivergence,
+    ) -> Self {
+        self.rpc_endpoint = rpc_endpoint;
+        self.cross_check_clients = cross_check_clients;
+        self.rpc_divergence = rpc_divergence;
+        self
+    }
+
+    /// Returns the receipts of the finalized transactions, together with the latest finalized block height of the endpoint
     async fn finalized_tx_receipts<T>(
         &self,
+        rpc_client: &C,
         tx_hashes: T,
         confirmation_height: u64,
-    ) -> Result<HashMap<Hash, TransactionReceipt>>
+    ) -> Result<(U64, HashMap<Hash, TransactionReceipt>)>
     where
         T: IntoIterator<Item = Hash>,
     {
         let latest_finalized_block_height =
-            finalizer::pick(&self.finalizer_type, &self.rpc_client, confirmation_height)
+            finalizer::pick(&self.finalizer_type, rpc_client, confirmation_height)
                 .latest_finalized_block_height()
                 .await
                 .change_context(Error::Finalizer)?;
 
-        Ok(join_all(
+        // a failed request must not be mistaken for a missing transaction, e.g. if the circuit of the endpoint opened
+        let tx_receipts = try_join_all(
             tx_hashes
                 .into_iter()
-                .map(|tx_hash| self.rpc_client.transaction_receipt(tx_hash)),
+                .map(|tx_hash| rpc_client.transaction_receipt(tx_hash)),
         )
         .await
+        .change_context(Error::TxReceipts)?
         .into_iter()
-        .filter_map(std::result::Result::unwrap_or_default)
+        .flatten()
         .filter_map(|tx_receipt| {
             if tx_receipt
                 .block_number
@@ -118,8 +151,68 @@ where
                 None
             }
         })
-        .collect())
+        .collect();
+
+        Ok((latest_finalized_block_height, tx_receipts))
+    }
+
+    /// Failing cross check endpoints are left out, so they can't prevent the verifier from voting
+    async fn cross_check_tx_receipts(
+        &self,
+        tx_hashes: &HashSet<Hash>,
+        confirmation_height: u64,
+    ) -> Vec<(String, U64, HashMap<Hash, TransactionReceipt>)> {
+        join_all(
+            self.cross_check_clients
+                .iter()
+                .map(|(endpoint, client)| async move {
+                    match self
+                        .finalized_tx_receipts(
+                            client,
+                            tx_hashes.iter().copied(),
+                            confirmation_height,
+                        )
+                        .await
+                    {
+                        Ok((finalized_height, tx_receipts)) => {
+                            Some((endpoint.clone(), finalized_height, tx_receipts))
+                        }
+                        Err(err) => {
+                            warn!(
+                        err = LoggableError::from(&err).as_value(),
+                        endpoint,
+                        "failed to cross check messages with RPC endpoint, verifying without it"
+                    );
+                            None
+                        }
+                    }
+                }),
+        )
+        .await
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+
+    /// The vote on a message depends on the gateway that emitted it and all of its fields
+    fn vote_cache_key(
+        &self,
+        source_gateway_address: &EVMAddress,
+        msg: &Message,
+    ) -> vote_cache::Key {
+        vote_cache::Key::new(
+            &self.chain,
+            &msg.message_id,
+            [
+                source_gateway_address.as_bytes(),
+                msg.destination_address.as_bytes(),
+                msg.destination_chain.as_ref().as_bytes(),
+                msg.source_address.as_bytes(),
+                msg.payload_hash.as_bytes(),
+            ],
+        )
     }
+
     fn vote_msg(&self, poll_id: PollId, votes: Vec<Vote>) -> MsgExecuteContract {
         MsgExecuteContract {
             sender: self.verifier.as_ref().clone(),
@@ -172,13 +265,36 @@ where
             return Ok(vec![]);
         }
 
+        let cache_keys: Vec<_> = messages
+            .iter()
+            .map(|msg| self.vote_cache_key(&source_gateway_address, msg))
+            .collect();
+        let cached_votes: Vec<_> = cache_keys
+            .iter()
+            .map(|key| self.vote_cache.get(key))
+            .collect();
+
         let tx_hashes: HashSet<Hash> = messages
             .iter()
-            .map(|msg| msg.message_id.tx_hash.into())
+            .zip(&cached_votes)
+            .filter(|(_, cached_vote)| cached_vote.is_none())
+            .map(|(msg, _)| msg.message_id.tx_hash.into())
             .collect();
-        let finalized_tx_receipts = self
-            .finalized_tx_receipts(tx_hashes, confirmation_height)
-            .await?;
+        let ((finalized_height, finalized_tx_receipts), cross_check_tx_receipts) =
+            if tx_hashes.is_empty() {
+                ((U64::zero(), HashMap::new()), vec![])
+            } else {
+                let cross_check_tx_receipts = self
+                    .cross_check_tx_receipts(&tx_hashes, confirmation_height)
+                    .await;
+
+                (
+                    self.finalized_tx_receipts(&self.rpc_client, tx_hashes, confirmation_height)
+                        .await?,
+                    cross_check_tx_receipts,
+                )
+            };
+        let mut divergences = vec![];
 
         let poll_id_str: String = poll_id.into();
         let source_chain_str: String = source_chain.into();
@@ -197,12 +313,72 @@ where
 
             let votes: Vec<_> = messages
                 .iter()
-                .map(|msg| {
-                    finalized_tx_receipts
-                        .get(&msg.message_id.tx_hash.into())
-                        .map_or(Vote::NotFound, |tx_receipt| {
-                            verify_message(&source_gateway_address, tx_receipt, msg)
-                        })
+                .zip(cache_keys)
+                .zip(cached_votes)
+                .map(|((msg, cache_key), cached_vote)| {
+                    cached_vote.unwrap_or_else(|| {
+                        let tx_hash: Hash = msg.message_id.tx_hash.into();
+                        let block_number = |tx_receipts: &HashMap<Hash, TransactionReceipt>| {
+                            tx_receipts
+                                .get(&tx_hash)
+                                .and_then(|tx_receipt| tx_receipt.block_number)
+                        };
+                        let verify = |tx_receipts: &HashMap<Hash, TransactionReceipt>| {
+                            tx_receipts.get(&tx_hash).map_or(
+                                Vote::NotFound,
+                                |tx_receipt| {
+                                    verify_message(&source_gateway_address, tx_receipt, msg)
+                                },
+                            )
+                        };
+
+                        let vote = verify(&finalized_tx_receipts);
+                        // an endpoint that hasn't finalized the block of the transaction yet can neither confirm
+                        // nor refute the other endpoints, so this isn't a divergence. The message can't be
+                        // cross checked though, so the verifier abstains instead of trusting a single endpoint
+                        let lagging_endpoint = cross_check_tx_receipts.iter().find(
+                            |(_, cross_check_height, tx_receipts)| {
+                                block_number(&finalized_tx_receipts)
+                                    .is_some_and(|block| block > *cross_check_height)
+                                    || block_number(tx_receipts)
+                                        .is_some_and(|block| block > finalized_height)
+                            },
+                        );
+                        if let Some((endpoint, _, _)) = lagging_endpoint {
+                            warn!(
+                                endpoint,
+                                message_id = msg.message_id.to_string(),
+                                "RPC endpoints have finalized different heights, abstaining on message"
+                            );
+                            return Vote::Abstain;
+                        }
+
+                        let cross_check_votes: Vec<_> = cross_check_tx_receipts
+                            .iter()
+                            .map(|(endpoint, _, tx_receipts)| (endpoint.clone(), verify(tx_receipts)))
+                            .collect();
+
+                        // divergent results are never cached, so the message is verified again in the next poll
+                        if cross_check_votes.iter().any(|(_, other)| *other != vote) {
+                            divergences.push(Evidence::new(
+                                &self.chain,
+                                &poll_id_str,
+                                &msg.message_id,
+                                std::iter::once((self.rpc_endpoint.clone(), vote))
+                                    .chain(cross_check_votes),
+                                self.rpc_divergence.countermeasure(),
+                            ));
+
+                            return match self.rpc_divergence.countermeasure() {
+                                Countermeasure::VoteAbstain => Vote::Abstain,
+                                _ => Vote::NotFound,
+                            };
+                        }
+
+                        self.vote_cache.insert(cache_key, vote.clone());
+
+                        vote
+                    })
                 })
                 .collect();
             info!(
@@ -213,6 +389,17 @@ where
             votes
         });
 
+        let diverged = !divergences.is_empty();
+        self.rpc_divergence.record(divergences).await;
+
+        if diverged && self.rpc_divergence.countermeasure() == Countermeasure::Abstain {
+            warn!(
+                poll_id = poll_id_str,
+                "abstaining from poll because RPC endpoints returned divergent results"
+            );
+            return Ok(vec![]);
+        }
+
         Ok(vec![self
             .vote_msg(poll_id, votes)
             .into_any()
@@ -226,9 +413,12 @@ mod tests {
     use std::str::FromStr;
 
     use axelar_wasm_std::msg_id::HexTxHashAndEventIndex;
+    use axelar_wasm_std::voting::Vote;
+    use cosmrs::cosmwasm::MsgExecuteContract;
+    use cosmrs::tx::Msg;
     use cosmwasm_std;
     use error_stack::{Report, Result};
-    use ethers_core::types::{H160, H256};
+    use ethers_core::types::{Block, TransactionReceipt, H160, H256, U64};
     use ethers_providers::ProviderError;
     use events::Error::{DeserializationFailed, EventTypeMismatch};
     use events::Event;
@@ -236,12 +426,17 @@ mod tests {
     use tokio::sync::watch;
     use tokio::test as async_test;
     use voting_verifier::events::{PollMetadata, PollStarted, TxEventConfirmation};
+    use voting_verifier::msg::ExecuteMsg;
 
     use super::PollStartedEvent;
     use crate::event_processor::EventHandler;
     use crate::evm::finalizer::Finalization;
     use crate::evm::json_rpc::MockEthereumClient;
-    use crate::handlers::tests::{into_structured_event, participants};
+    use crate::handlers::rpc_divergence::{self, Countermeasure, RpcDivergence};
+    use crate::handlers::tests::{into_structured_event, participants, voting_threshold};
+    use crate::handlers::vote_cache::VoteCache;
+    use crate::json_rpc;
+    use crate::json_rpc::replay::Replay;
     use crate::types::TMAddress;
     use crate::PREFIX;
 
@@ -264,6 +459,8 @@ mod tests {
                     .into_iter()
                     .map(|addr| cosmwasm_std::Addr::unchecked(addr.to_string()))
                     .collect(),
+                voting_threshold: voting_threshold(),
+                observed_at: None,
             },
             #[allow(deprecated)] // TODO: The below events use the deprecated tx_id and event_index fields. Remove this attribute when those fields are removed
             messages: vec![
@@ -380,6 +577,7 @@ mod tests {
             Finalization::RPCFinalizedBlock,
             rpc_client,
             rx,
+            VoteCache::default(),
         );
 
         // poll is not expired yet, should hit rpc error
@@ -390,4 +588,302 @@ mod tests {
         // poll is expired, should not hit rpc error now
         assert_eq!(handler.handle(&event).await.unwrap(), vec![]);
     }
+
+    #[async_test]
+    async fn should_vote_with_cached_results_without_querying_the_chain() {
+        let mut rpc_client = MockEthereumClient::new();
+        // mock the rpc client as erroring. If all votes are cached, we won't hit this
+        rpc_client.expect_finalized_block().returning(|| {
+            Err(Report::from(ProviderError::CustomError(
+                "failed to get finalized block".to_string(),
+            )))
+        });
+
+        let voting_verifier_contract = TMAddress::random(PREFIX);
+        let verifier = TMAddress::random(PREFIX);
+        let event: Event = into_structured_event(
+            poll_started_event(participants(5, Some(verifier.clone())), 100),
+            &voting_verifier_contract,
+        );
+        let (_tx, rx) = watch::channel(99);
+
+        let handler = super::Handler::new(
+            verifier,
+            voting_verifier_contract,
+            ChainName::from_str("ethereum").unwrap(),
+            Finalization::RPCFinalizedBlock,
+            rpc_client,
+            rx,
+            VoteCache::default(),
+        );
+
+        let PollStartedEvent {
+            source_gateway_address,
+            messages,
+            ..
+        } = (&event).try_into().unwrap();
+        for msg in messages.iter().skip(1) {
+            handler.vote_cache.insert(
+                handler.vote_cache_key(&source_gateway_address, msg),
+                Vote::SucceededOnChain,
+            );
+        }
+
+        // the first message is not cached yet, so the chain has to be queried
+        assert!(handler.handle(&event).await.is_err());
+
+        handler.vote_cache.insert(
+            handler.vote_cache_key(&source_gateway_address, &messages[0]),
+            Vote::FailedOnChain,
+        );
+
+        let msgs = handler.handle(&event).await.unwrap();
+        assert_eq!(msgs.len(), 1);
+        assert_eq!(handler.vote_cache.metrics().hits, 5);
+    }
+
+    /// Returns a client that only knows finalized transactions that failed, or no transactions at all
+    fn rpc_client_with_failed_txs(has_txs: bool) -> MockEthereumClient {
+        let mut rpc_client = MockEthereumClient::new();
+        rpc_client.expect_finalized_block().returning(|| {
+            Ok(Block {
+                number: Some(U64::from(20)),
+                ..Block::default()
+            })
+        });
+        rpc_client
+            .expect_transaction_receipt()
+            .returning(move |tx_hash| {
+                Ok(has_txs.then(|| TransactionReceipt {
+                    transaction_hash: tx_hash,
+                    block_number: Some(U64::from(10)),
+                    status: Some(U64::zero()),
+                    ..TransactionReceipt::default()
+                }))
+            });
+
+        rpc_client
+    }
+
+    fn votes(msgs: Vec<cosmrs::Any>) -> Vec<Vote> {
+        let msg = MsgExecuteContract::from_any(msgs.first().unwrap()).unwrap();
+        match serde_json::from_slice(&msg.msg).unwrap() {
+            ExecuteMsg::Vote { votes, .. } => votes,
+            _ => panic!("unexpected msg"),
+        }
+    }
+
+    #[async_test]
+    async fn should_apply_countermeasure_when_rpc_endpoints_diverge() {
+        let voting_verifier_contract = TMAddress::random(PREFIX);
+        let verifier = TMAddress::random(PREFIX);
+        let event: Event = into_structured_event(
+            poll_started_event(participants(5, Some(verifier.clone())), 100),
+            &voting_verifier_contract,
+        );
+
+        let handler = |cross_check_has_txs: bool, countermeasure: Countermeasure| {
+            let (_tx, rx) = watch::channel(99);
+
+            super::Handler::new(
+                verifier.clone(),
+                voting_verifier_contract.clone(),
+                ChainName::from_str("ethereum").unwrap(),
+                Finalization::RPCFinalizedBlock,
+                rpc_client_with_failed_txs(true),
+                rx,
+                VoteCache::default(),
+            )
+            .with_cross_checks(
+                "http://primary".to_string(),
+                vec![(
+                    "http://cross-check".to_string(),
+                    rpc_client_with_failed_txs(cross_check_has_txs),
+                )],
+                RpcDivergence::new(rpc_divergence::Config {
+                    countermeasure,
+                    path: std::env::temp_dir()
+                        .join(format!("ampd-{}", rand::random::<u64>()))
+                        .join("rpc_divergence.jsonl"),
+                }),
+            )
+        };
+
+        let agreeing = handler(true, Countermeasure::VoteNotFound);
+        assert_eq!(
+            votes(agreeing.handle(&event).await.unwrap()),
+            vec![Vote::FailedOnChain; 3]
+        );
+        assert!(agreeing
+            .rpc_divergence
+            .metrics()
+            .divergent_messages
+            .is_empty());
+
+        let vote_not_found = handler(false, Countermeasure::VoteNotFound);
+        assert_eq!(
+            votes(vote_not_found.handle(&event).await.unwrap()),
+            vec![Vote::NotFound; 3]
+        );
+        assert_eq!(
+            vote_not_found.rpc_divergence.metrics().divergent_messages
+                [&ChainName::from_str("ethereum").unwrap()],
+            3
+        );
+        assert_eq!(vote_not_found.vote_cache.metrics().cached, 0);
+
+        let vote_abstain = handler(false, Countermeasure::VoteAbstain);
+        assert_eq!(
+            votes(vote_abstain.handle(&event).await.unwrap()),
+            vec![Vote::Abstain; 3]
+        );
+        assert_eq!(vote_abstain.vote_cache.metrics().cached, 0);
+
+        let abstain = handler(false, Countermeasure::Abstain);
+        assert_eq!(abstain.handle(&event).await.unwrap(), vec![]);
+        assert_eq!(abstain.rpc_divergence.metrics().abstained_polls, 1);
+    }
+
+    #[async_test]
+    async fn should_not_detect_divergence_with_failing_or_lagging_cross_check_endpoints() {
+        let voting_verifier_contract = TMAddress::random(PREFIX);
+        let verifier = TMAddress::random(PREFIX);
+        let event: Event = into_structured_event(
+            poll_started_event(participants(5, Some(verifier.clone())), 100),
+            &voting_verifier_contract,
+        );
+
+        let handler = |cross_check_client: MockEthereumClient| {
+            let (_tx, rx) = watch::channel(99);
+
+            super::Handler::new(
+                verifier.clone(),
+                voting_verifier_contract.clone(),
+                ChainName::from_str("ethereum").unwrap(),
+                Finalization::RPCFinalizedBlock,
+                rpc_client_with_failed_txs(true),
+                rx,
+                VoteCache::default(),
+            )
+            .with_cross_checks(
+                "http://primary".to_string(),
+                vec![("http://cross-check".to_string(), cross_check_client)],
+                RpcDivergence::default(),
+            )
+        };
+
+        let mut failing_client = MockEthereumClient::new();
+        failing_client.expect_finalized_block().returning(|| {
+            Err(Report::from(ProviderError::CustomError(
+                "failed".to_string(),
+            )))
+        });
+
+        let failing = handler(failing_client);
+        assert_eq!(
+            votes(failing.handle(&event).await.unwrap()),
+            vec![Vote::FailedOnChain; 3]
+        );
+        assert!(failing
+            .rpc_divergence
+            .metrics()
+            .divergent_messages
+            .is_empty());
+
+        // the transactions are in block 10, which the lagging endpoint hasn't finalized yet
+        let mut lagging_client = MockEthereumClient::new();
+        lagging_client.expect_finalized_block().returning(|| {
+            Ok(Block {
+                number: Some(U64::from(5)),
+                ..Block::default()
+            })
+        });
+        lagging_client
+            .expect_transaction_receipt()
+            .returning(|_| Ok(None));
+
+        let lagging = handler(lagging_client);
+        assert_eq!(
+            votes(lagging.handle(&event).await.unwrap()),
+            vec![Vote::Abstain; 3]
+        );
+        assert!(lagging
+            .rpc_divergence
+            .metrics()
+            .divergent_messages
+            .is_empty());
+        assert_eq!(lagging.vote_cache.metrics().cached, 0);
+    }
+
+    #[allow(deprecated)]
+    // TODO: The below event uses the deprecated tx_id and event_index fields. Remove this attribute when those fields are removed
+    #[async_test]
+    async fn should_vote_on_replayed_rpc_responses() {
+        let rpc_client = json_rpc::Client::new(Replay::load(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/handlers/testdata/replay/evm_verify_msg_should_vote_on_replayed_rpc_responses.json"
+        )));
+
+        let voting_verifier_contract = TMAddress::random(PREFIX);
+        let verifier = TMAddress::random(PREFIX);
+        let PollStarted::Messages { metadata, .. } =
+            poll_started_event(participants(5, Some(verifier.clone())), 100)
+        else {
+            panic!("expected messages poll");
+        };
+        // succeeded, failed, missing and not yet finalized transactions
+        let messages = [
+            "0x9ffd8bdb27ed760f2258a06a78dcdf30653b360bf563f37616bb05e25ea714d8",
+            "0x59bf6d73636c758dfb96243a00e073bd279f9798d26a00972f72c15aacde3b92",
+            "0x29b766bc2e0cdc15a579876a0bd2e59e28ba491cc790b43a011c3cbc2cddcc29",
+            "0xf2ad435013a0524fbafed54bc2829413cb0450ac072aaa193a5038294390a8fb",
+        ]
+        .into_iter()
+        .map(|tx_hash| {
+            let msg_id = HexTxHashAndEventIndex::new(H256::from_str(tx_hash).unwrap(), 0u64);
+            TxEventConfirmation {
+                tx_id: msg_id.tx_hash_as_hex(),
+                event_index: 0,
+                message_id: msg_id.to_string().parse().unwrap(),
+                source_address: "0x87cc5004b64e803248413178d9c38e627aa73ae6"
+                    .parse()
+                    .unwrap(),
+                destination_chain: "avalanche".parse().unwrap(),
+                destination_address: "0x5678aBe5a0d1Ebd1f9a4B7E8d19b8c0f6E3D2a10"
+                    .parse()
+                    .unwrap(),
+                payload_hash: H256::from_str(
+                    "0xe2fe759bf7330fee8ce5e2935aaae3bfb90c74e19375ed2f76860c3b768d6e95",
+                )
+                .unwrap()
+                .to_fixed_bytes(),
+            }
+        })
+        .collect();
+        let event: Event = into_structured_event(
+            PollStarted::Messages { metadata, messages },
+            &voting_verifier_contract,
+        );
+        let (_tx, rx) = watch::channel(99);
+
+        let handler = super::Handler::new(
+            verifier,
+            voting_verifier_contract,
+            ChainName::from_str("ethereum").unwrap(),
+            Finalization::RPCFinalizedBlock,
+            rpc_client,
+            rx,
+            VoteCache::default(),
+        );
+
+        assert_eq!(
+            votes(handler.handle(&event).await.unwrap()),
+            vec![
+                Vote::SucceededOnChain,
+                Vote::FailedOnChain,
+                Vote::NotFound,
+                Vote::NotFound
+            ]
+        );
+    }
 }
@@ -215,7 +215,7 @@ mod tests {
     use crate::evm::finalizer::Finalization;
     use crate::evm::json_rpc::MockEthereumClient;
     use crate::handlers::evm_verify_verifier_set::PollStartedEvent;
-    use crate::handlers::tests::{into_structured_event, participants};
+    use crate::handlers::tests::{into_structured_event, participants, voting_threshold};
     use crate::types::TMAddress;
     use crate::PREFIX;
 
@@ -290,6 +290,8 @@ mod tests {
                     .into_iter()
                     .map(|addr| cosmwasm_std::Addr::unchecked(addr.to_string()))
                     .collect(),
+                voting_threshold: voting_threshold(),
+                observed_at: None,
             },
         }
     }
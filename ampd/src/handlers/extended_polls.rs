@@ -0,0 +1,430 @@
+use std::collections::HashMap;
+use std::sync::{Mutex, MutexGuard};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use async_trait::async_trait;
+use axelar_wasm_std::time::Timestamp;
+use axelar_wasm_std::voting::PollId;
+use cosmrs::Any;
+use error_stack::Result;
+use events::Event;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use tokio::sync::watch::Receiver;
+use tracing::info;
+
+use crate::event_processor::EventHandler;
+use crate::poll_watchdog::POLL_EXPIRY_EXTENDED_EVENT;
+use crate::types::TMAddress;
+
+const SOURCE_CHAIN_HALTED_EVENT: &str = "wasm-source_chain_halted";
+
+#[derive(Deserialize)]
+struct PollStarted {
+    poll_id: PollId,
+    expires_at: u64,
+    /// polls with a wall-clock expiry also expire at this time, whichever comes first
+    #[serde(default)]
+    expires_at_time: Option<Timestamp>,
+    participants: Vec<TMAddress>,
+}
+
+#[derive(Deserialize)]
+struct PollExpiryExtended {
+    poll_id: PollId,
+    expires_at: u64,
+}
+
+#[derive(Deserialize)]
+struct SourceChainHalted {
+    started_at: u64,
+    ends_at: u64,
+}
+
+struct ParkedPoll {
+    event: Event,
+    expires_at: u64,
+    /// the poll can't be extended anymore once this block has ended
+    extendable_until: u64,
+}
+
+/// Wraps a voting handler, so the verifier votes in polls whose expiry is extended after they started.
+/// Polls that have expired by block height or by their wall-clock expiry when their start event is handled are not
+/// passed on to the voting handler. Such polls are parked, and their start event is handled again with the new expiry
+/// once the voting verifier extends it. Extending a poll removes its wall-clock expiry
+pub struct Handler<H> {
+    inner: H,
+    verifier: TMAddress,
+    latest_block_height: Receiver<u64>,
+    // poll ids are keyed by their string representation, because PollId is not hashable
+    parked: Mutex<HashMap<(TMAddress, String), ParkedPoll>>,
+}
+
+impl<H> Handler<H> {
+    pub fn new(inner: H, verifier: TMAddress, latest_block_height: Receiver<u64>) -> Self {
+        Self {
+            inner,
+            verifier,
+            latest_block_height,
+            parked: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns true if the poll has expired and is parked
+    fn park(&self, event: &Event) -> bool {
+        let Some((contract, poll_started)) = parse::<PollStarted>(event) else {
+            return false;
+        };
+
+        let expired = *self.latest_block_height.borrow() >= poll_started.expires_at
+            || poll_started
+                .expires_at_time
+                .is_some_and(|expires_at_time| expires_at_time.is_reached(now()));
+        if !poll_started.participants.contains(&self.verifier) || !expired {
+            return false;
+        }
+
+        self.lock().insert(
+            (contract, poll_started.poll_id.into()),
+            ParkedPoll {
+                event: event.clone(),
+                expires_at: poll_started.expires_at,
+                extendable_until: poll_started.expires_at,
+            },
+        );
+        true
+    }
+
+    /// Polls that are in progress when the source chain halts can be extended by at most the length of the halt.
+    /// The extension itself can be applied later in pages, so parked polls are kept until then
+    fn keep_for_halt(&self, event: &Event) {
+        let Some((contract, halted)) = parse::<SourceChainHalted>(event) else {
+            return;
+        };
+
+        let halt_blocks = halted.ends_at.saturating_sub(halted.started_at);
+        self.lock()
+            .iter_mut()
+            .filter(|((parked_contract, _), poll)| {
+                *parked_contract == contract && poll.expires_at > halted.started_at
+            })
+            .for_each(|(_, poll)| {
+                poll.extendable_until = poll
+                    .extendable_until
+                    .max(poll.expires_at.saturating_add(halt_blocks));
+            });
+    }
+
+    /// Returns the start event of the extended poll with the new expiry if the verifier can vote in it now
+    fn extend(&self, event: &Event) -> Option<Event> {
+        let (contract, extended) = parse::<PollExpiryExtended>(event)?;
+        let key = (contract, extended.poll_id.into());
+
+        let mut parked = self.lock();
+        let poll = parked.get_mut(&key)?;
+        if let Event::Abci { attributes, .. } = &mut poll.event {
+            attributes.insert("expires_at".to_string(), extended.expires_at.into());
+            attributes.remove("expires_at_time");
+        }
+        poll.expires_at = extended.expires_at;
+        poll.extendable_until = poll.extendable_until.max(extended.expires_at);
+
+        if *self.latest_block_height.borrow() >= extended.expires_at {
+            return None;
+        }
+
+        info!(poll_id = key.1, "voting in poll with extended expiry");
+        parked.remove(&key).map(|poll| poll.event)
+    }
+
+    fn drop_unextendable(&self, height: u64) {
+        self.lock().retain(|_, poll| poll.extendable_until > height);
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<(TMAddress, String), ParkedPoll>> {
+        self.parked
+            .lock()
+            .expect("parked polls lock should not be poisoned")
+    }
+}
+
+#[async_trait]
+impl<H> EventHandler for Handler<H>
+where
+    H: EventHandler + Send + Sync,
+{
+    type Err = H::Err;
+
+    async fn handle(&self, event: &Event) -> Result<Vec<Any>, Self::Err> {
+        match event {
+            Event::BlockEnd(height) => self.drop_unextendable(height.value()),
+            Event::Abci { event_type, .. } if event_type == SOURCE_CHAIN_HALTED_EVENT => {
+                self.keep_for_halt(event)
+            }
+            Event::Abci { event_type, .. } if event_type == POLL_EXPIRY_EXTENDED_EVENT => {
+                if let Some(poll_started) = self.extend(event) {
+                    return self.inner.handle(&poll_started).await;
+                }
+            }
+            Event::Abci { event_type, .. }
+                if event_type.starts_with("wasm-") && event_type.ends_with("_poll_started") =>
+            {
+                if self.park(event) {
+                    return Ok(vec![]);
+                }
+            }
+            _ => {}
+        }
+
+        self.inner.handle(event).await
+    }
+
+    async fn on_broadcast(&self, event: &Event) -> Result<(), Self::Err> {
+        self.inner.on_broadcast(event).await
+    }
+}
+
+fn now() -> Timestamp {
+    Timestamp::from_seconds(
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |since_epoch| since_epoch.as_secs()),
+    )
+}
+
+fn parse<T: DeserializeOwned>(event: &Event) -> Option<(TMAddress, T)> {
+    let Event::Abci { attributes, .. } = event else {
+        return None;
+    };
+
+    let contract = event.contract_address()?.into();
+    serde_json::from_value(attributes.clone().into())
+        .ok()
+        .map(|parsed| (contract, parsed))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::Arc;
+
+    use tokio::sync::watch;
+
+    use super::*;
+    use crate::PREFIX;
+
+    /// Votes in every poll that has not expired yet and returns the poll id in the message
+    struct VotingHandler {
+        latest_block_height: Receiver<u64>,
+        votes: Arc<AtomicU64>,
+    }
+
+    #[async_trait]
+    impl EventHandler for VotingHandler {
+        type Err = std::convert::Infallible;
+
+        async fn handle(&self, event: &Event) -> Result<Vec<Any>, Self::Err> {
+            let Some((_, poll_started)) = parse::<PollStarted>(event) else {
+                return Ok(vec![]);
+            };
+
+            if *self.latest_block_height.borrow() >= poll_started.expires_at {
+                return Ok(vec![]);
+            }
+
+            self.votes.fetch_add(1, Ordering::SeqCst);
+            Ok(vec![Any {
+                type_url: "vote".to_string(),
+                value: String::from(poll_started.poll_id).into_bytes(),
+            }])
+        }
+    }
+
+    fn abci_event(event_type: &str, contract: &TMAddress, attributes: serde_json::Value) -> Event {
+        let mut attributes = attributes.as_object().unwrap().clone();
+        attributes.insert("_contract_address".to_string(), contract.to_string().into());
+
+        Event::Abci {
+            event_type: event_type.to_string(),
+            attributes,
+        }
+    }
+
+    fn poll_started_event(contract: &TMAddress, poll_id: u64, verifier: &TMAddress) -> Event {
+        abci_event(
+            "wasm-messages_poll_started",
+            contract,
+            serde_json::json!({
+                "poll_id": poll_id.to_string(),
+                "expires_at": 100,
+                "participants": [verifier.to_string()],
+            }),
+        )
+    }
+
+    fn expiry_extended_event(contract: &TMAddress, poll_id: u64, expires_at: u64) -> Event {
+        abci_event(
+            POLL_EXPIRY_EXTENDED_EVENT,
+            contract,
+            serde_json::json!({
+                "poll_id": poll_id.to_string(),
+                "expires_at": expires_at,
+            }),
+        )
+    }
+
+    fn setup(latest_block_height: u64) -> (Handler<VotingHandler>, TMAddress, Arc<AtomicU64>) {
+        let verifier = TMAddress::random(PREFIX);
+        let (_, rx) = watch::channel(latest_block_height);
+        let votes = Arc::new(AtomicU64::new(0));
+
+        let handler = Handler::new(
+            VotingHandler {
+                latest_block_height: rx.clone(),
+                votes: votes.clone(),
+            },
+            verifier.clone(),
+            rx,
+        );
+
+        (handler, verifier, votes)
+    }
+
+    #[tokio::test]
+    async fn expired_poll_should_be_voted_in_once_its_expiry_is_extended() {
+        let (handler, verifier, votes) = setup(150);
+        let contract = TMAddress::random(PREFIX);
+
+        assert!(handler
+            .handle(&poll_started_event(&contract, 1, &verifier))
+            .await
+            .unwrap()
+            .is_empty());
+
+        // still expired with the new expiry
+        assert!(handler
+            .handle(&expiry_extended_event(&contract, 1, 120))
+            .await
+            .unwrap()
+            .is_empty());
+
+        let msgs = handler
+            .handle(&expiry_extended_event(&contract, 1, 200))
+            .await
+            .unwrap();
+        assert_eq!(msgs.len(), 1);
+        assert_eq!(votes.load(Ordering::SeqCst), 1);
+
+        // the poll is only voted in once
+        assert!(handler
+            .handle(&expiry_extended_event(&contract, 1, 300))
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn poll_past_its_wall_clock_expiry_should_only_be_voted_in_once_extended() {
+        let (handler, verifier, votes) = setup(50);
+        let contract = TMAddress::random(PREFIX);
+
+        let with_expiry_time = |poll_id, expires_at_time: &str| {
+            let mut event = poll_started_event(&contract, poll_id, &verifier);
+            if let Event::Abci { attributes, .. } = &mut event {
+                attributes.insert("expires_at_time".to_string(), expires_at_time.into());
+            }
+            event
+        };
+
+        assert!(handler
+            .handle(&with_expiry_time(1, "1000000000"))
+            .await
+            .unwrap()
+            .is_empty());
+        assert_eq!(votes.load(Ordering::SeqCst), 0);
+
+        // the wall-clock expiry has not been reached yet
+        assert_eq!(
+            handler
+                .handle(&with_expiry_time(2, &u64::MAX.to_string()))
+                .await
+                .unwrap()
+                .len(),
+            1
+        );
+
+        let msgs = handler
+            .handle(&expiry_extended_event(&contract, 1, 200))
+            .await
+            .unwrap();
+        assert_eq!(msgs.len(), 1);
+        assert_eq!(votes.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn expiry_extensions_of_other_contracts_or_polls_should_be_ignored() {
+        let (handler, verifier, votes) = setup(150);
+        let contract = TMAddress::random(PREFIX);
+
+        handler
+            .handle(&poll_started_event(&contract, 1, &verifier))
+            .await
+            .unwrap();
+
+        assert!(handler
+            .handle(&expiry_extended_event(&TMAddress::random(PREFIX), 1, 200))
+            .await
+            .unwrap()
+            .is_empty());
+        assert!(handler
+            .handle(&expiry_extended_event(&contract, 2, 200))
+            .await
+            .unwrap()
+            .is_empty());
+        assert_eq!(votes.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn parked_polls_should_be_dropped_once_they_cannot_be_extended() {
+        let (handler, verifier, votes) = setup(150);
+        let contract = TMAddress::random(PREFIX);
+
+        handler
+            .handle(&poll_started_event(&contract, 1, &verifier))
+            .await
+            .unwrap();
+        handler
+            .handle(&poll_started_event(&contract, 2, &verifier))
+            .await
+            .unwrap();
+        handler
+            .handle(&abci_event(
+                SOURCE_CHAIN_HALTED_EVENT,
+                &contract,
+                serde_json::json!({
+                    "source_chain": "\"ethereum\"",
+                    "started_at": 90,
+                    "ends_at": 140,
+                }),
+            ))
+            .await
+            .unwrap();
+        handler
+            .handle(&Event::BlockEnd(100u32.into()))
+            .await
+            .unwrap();
+        assert_eq!(handler.lock().len(), 2);
+
+        handler
+            .handle(&Event::BlockEnd(150u32.into()))
+            .await
+            .unwrap();
+        assert!(handler.lock().is_empty());
+        assert!(handler
+            .handle(&expiry_extended_event(&contract, 1, 200))
+            .await
+            .unwrap()
+            .is_empty());
+        assert_eq!(votes.load(Ordering::SeqCst), 0);
+    }
+}
@@ -0,0 +1,271 @@
+use std::iter;
+
+use async_trait::async_trait;
+use axelar_wasm_std::voting::{PollId, Vote};
+use cosmrs::cosmwasm::MsgExecuteContract;
+use cosmrs::tx::Msg;
+use cosmrs::Any;
+use error_stack::Result;
+use events::Event;
+use report::LoggableError;
+use serde::Deserialize;
+use tokio::sync::watch::Receiver;
+use tracing::warn;
+use valuable::Valuable;
+use voting_verifier::msg::ExecuteMsg;
+
+use crate::asyncutil::future::{self, RetryPolicy};
+use crate::event_processor::EventHandler;
+use crate::types::TMAddress;
+
+#[derive(Deserialize)]
+struct PollStarted {
+    poll_id: PollId,
+    expires_at: u64,
+    participants: Vec<TMAddress>,
+    /// not set for verifier set polls, which have a single vote
+    #[serde(default)]
+    messages: Option<Vec<serde_json::Value>>,
+}
+
+/// Wraps a voting handler, so the verifier votes Abstain on every message of a poll if the handler can't gather the
+/// evidence to vote, e.g. because its RPC endpoint keeps failing. The handler is retried before the verifier abstains.
+/// Abstaining doesn't influence the outcome of the poll, but the verifier is still only rewarded for messages that
+/// didn't reach consensus
+pub struct Handler<H> {
+    inner: H,
+    verifier: TMAddress,
+    latest_block_height: Receiver<u64>,
+    retry_policy: RetryPolicy,
+}
+
+impl<H> Handler<H> {
+    pub fn new(
+        inner: H,
+        verifier: TMAddress,
+        latest_block_height: Receiver<u64>,
+        retry_policy: RetryPolicy,
+    ) -> Self {
+        Self {
+            inner,
+            verifier,
+            latest_block_height,
+            retry_policy,
+        }
+    }
+
+    /// Returns the contract and the poll if the verifier is supposed to vote in it
+    fn poll_to_vote_in(&self, event: &Event) -> Option<(TMAddress, PollStarted)> {
+        let Event::Abci {
+            event_type,
+            attributes,
+        } = event
+        else {
+            return None;
+        };
+
+        if !(event_type.starts_with("wasm-") && event_type.ends_with("_poll_started")) {
+            return None;
+        }
+
+        let contract = event.contract_address()?.into();
+        let poll_started: PollStarted = serde_json::from_value(attributes.clone().into()).ok()?;
+
+        (poll_started.participants.contains(&self.verifier)
+            && *self.latest_block_height.borrow() < poll_started.expires_at)
+            .then_some((contract, poll_started))
+    }
+
+    fn abstain_msg(&self, contract: TMAddress, poll_started: PollStarted) -> Any {
+        let vote_count = poll_started.messages.map_or(1, |messages| messages.len());
+
+        MsgExecuteContract {
+            sender: self.verifier.as_ref().clone(),
+            contract: contract.as_ref().clone(),
+            msg: serde_json::to_vec(&ExecuteMsg::Vote {
+                poll_id: poll_started.poll_id,
+                votes: iter::repeat(Vote::Abstain).take(vote_count).collect(),
+            })
+            .expect("vote msg should serialize"),
+            funds: vec![],
+        }
+        .into_any()
+        .expect("vote msg should serialize")
+    }
+}
+
+#[async_trait]
+impl<H> EventHandler for Handler<H>
+where
+    H: EventHandler + Send + Sync,
+{
+    type Err = H::Err;
+
+    async fn handle(&self, event: &Event) -> Result<Vec<Any>, Self::Err> {
+        let Some((contract, poll_started)) = self.poll_to_vote_in(event) else {
+            return self.inner.handle(event).await;
+        };
+
+        match future::with_retry(|| self.inner.handle(event), self.retry_policy).await {
+            Ok(msgs) => Ok(msgs),
+            Err(err) => {
+                warn!(
+                    err = LoggableError::from(&err).as_value(),
+                    poll_id = poll_started.poll_id.to_string(),
+                    "abstaining in poll because the evidence could not be gathered"
+                );
+
+                Ok(vec![self.abstain_msg(contract, poll_started)])
+            }
+        }
+    }
+
+    async fn on_broadcast(&self, event: &Event) -> Result<(), Self::Err> {
+        self.inner.on_broadcast(event).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::time::Duration;
+
+    use error_stack::report;
+    use thiserror::Error;
+    use tokio::sync::watch;
+
+    use super::*;
+    use crate::PREFIX;
+
+    #[derive(Error, Debug)]
+    #[error("failed to get transaction receipts")]
+    struct RpcError;
+
+    /// Fails to handle every event
+    #[derive(Default)]
+    struct FailingHandler {
+        calls: AtomicU64,
+    }
+
+    #[async_trait]
+    impl EventHandler for FailingHandler {
+        type Err = RpcError;
+
+        async fn handle(&self, _event: &Event) -> Result<Vec<Any>, Self::Err> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            Err(report!(RpcError))
+        }
+    }
+
+    fn poll_started_event(
+        event_type: &str,
+        contract: &TMAddress,
+        verifier: &TMAddress,
+        attributes: serde_json::Value,
+    ) -> Event {
+        let mut attributes = attributes.as_object().unwrap().clone();
+        attributes.insert("_contract_address".to_string(), contract.to_string().into());
+        attributes.insert("poll_id".to_string(), "1".into());
+        attributes.insert("expires_at".to_string(), 100.into());
+        attributes.insert(
+            "participants".to_string(),
+            serde_json::json!([verifier.to_string()]),
+        );
+
+        Event::Abci {
+            event_type: event_type.to_string(),
+            attributes,
+        }
+    }
+
+    fn setup(latest_block_height: u64) -> (Handler<FailingHandler>, TMAddress) {
+        let verifier = TMAddress::random(PREFIX);
+        let (_, rx) = watch::channel(latest_block_height);
+
+        let handler = Handler::new(
+            FailingHandler::default(),
+            verifier.clone(),
+            rx,
+            RetryPolicy::RepeatConstant {
+                sleep: Duration::from_millis(1),
+                max_attempts: 3,
+            },
+        );
+
+        (handler, verifier)
+    }
+
+    fn votes(msg: &Any) -> Vec<Vote> {
+        let msg = MsgExecuteContract::from_any(msg).unwrap();
+        match serde_json::from_slice(&msg.msg).unwrap() {
+            ExecuteMsg::Vote { votes, .. } => votes,
+            _ => panic!("expected vote msg"),
+        }
+    }
+
+    #[tokio::test]
+    async fn should_abstain_on_every_message_after_retrying_the_failing_handler() {
+        let (handler, verifier) = setup(50);
+        let contract = TMAddress::random(PREFIX);
+
+        let msgs = handler
+            .handle(&poll_started_event(
+                "wasm-messages_poll_started",
+                &contract,
+                &verifier,
+                serde_json::json!({ "messages": [{}, {}] }),
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(msgs.len(), 1);
+        assert_eq!(votes(&msgs[0]), vec![Vote::Abstain; 2]);
+        assert_eq!(handler.inner.calls.load(Ordering::SeqCst), 3);
+
+        let msgs = handler
+            .handle(&poll_started_event(
+                "wasm-verifier_set_poll_started",
+                &contract,
+                &verifier,
+                serde_json::json!({ "verifier_set": {} }),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(votes(&msgs[0]), vec![Vote::Abstain]);
+    }
+
+    #[tokio::test]
+    async fn should_not_abstain_in_polls_the_verifier_cannot_vote_in() {
+        let contract = TMAddress::random(PREFIX);
+        let messages = serde_json::json!({ "messages": [{}] });
+
+        let (handler, _) = setup(50);
+        assert!(handler
+            .handle(&poll_started_event(
+                "wasm-messages_poll_started",
+                &contract,
+                &TMAddress::random(PREFIX),
+                messages.clone(),
+            ))
+            .await
+            .is_err());
+
+        let (handler, verifier) = setup(100);
+        assert!(handler
+            .handle(&poll_started_event(
+                "wasm-messages_poll_started",
+                &contract,
+                &verifier,
+                messages,
+            ))
+            .await
+            .is_err());
+
+        // other events are passed through without retries
+        assert!(handler
+            .handle(&Event::BlockEnd(100u32.into()))
+            .await
+            .is_err());
+        assert_eq!(handler.inner.calls.load(Ordering::SeqCst), 2);
+    }
+}
@@ -1,10 +1,16 @@
+pub mod chain_lag;
+pub mod circuit_breaker;
 pub mod config;
 mod errors;
 pub mod evm_verify_msg;
 pub mod evm_verify_verifier_set;
+pub mod extended_polls;
+pub mod inconclusive_evidence;
 pub mod multisig;
 pub mod mvx_verify_msg;
 pub mod mvx_verify_verifier_set;
+pub mod rpc_divergence;
+pub mod signed_sessions;
 pub mod solana_verify_msg;
 pub mod solana_verify_verifier_set;
 pub mod starknet_verify_msg;
@@ -13,13 +19,15 @@ pub(crate) mod stellar_verify_msg;
 pub(crate) mod stellar_verify_verifier_set;
 pub mod sui_verify_msg;
 pub mod sui_verify_verifier_set;
+pub mod vote_cache;
 pub mod xrpl_multisig;
 pub mod xrpl_verify_msg;
 
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
     use std::convert::TryInto;
 
+    use axelar_wasm_std::{MajorityThreshold, Threshold};
     use base64::engine::general_purpose::STANDARD;
     use base64::Engine;
     use cosmrs::AccountId;
@@ -52,6 +60,13 @@ mod tests {
         .expect("should convert to ABCI event")
     }
 
+    pub fn voting_threshold() -> MajorityThreshold {
+        Threshold::try_from((2u64, 3u64))
+            .unwrap()
+            .try_into()
+            .unwrap()
+    }
+
     pub fn participants(n: u8, verifier: Option<TMAddress>) -> Vec<TMAddress> {
         (0..n)
             .map(|i| {
@@ -9,16 +9,19 @@ use cosmwasm_std::{HexBinary, Uint64};
 use error_stack::ResultExt;
 use events_derive;
 use events_derive::try_from;
-use hex::encode;
+use hex::{encode, FromHex};
 use multisig::msg::ExecuteMsg;
+use report::LoggableError;
 use router_api::ChainName;
 use serde::de::Error as DeserializeError;
 use serde::{Deserialize, Deserializer};
 use tokio::sync::watch::Receiver;
-use tracing::info;
+use tracing::{info, warn};
+use valuable::Valuable;
 
 use crate::event_processor::EventHandler;
 use crate::handlers::errors::Error::{self, DeserializeEvent};
+use crate::handlers::signed_sessions::SignedSessions;
 use crate::tofnd::grpc::Multisig;
 use crate::tofnd::{self, MessageDigest};
 use crate::types::{PublicKey, TMAddress};
@@ -35,6 +38,73 @@ struct SigningStartedEvent {
     chain: ChainName,
 }
 
+#[derive(Debug, Deserialize)]
+#[try_from("wasm-batch_signing_started")]
+struct BatchSigningStartedEvent {
+    session_id: u64,
+    #[serde(deserialize_with = "deserialize_public_keys")]
+    pub_keys: HashMap<TMAddress, PublicKey>,
+    #[serde(deserialize_with = "deserialize_msgs")]
+    msgs: Vec<MessageDigest>,
+    expires_at: u64,
+    chain: ChainName,
+}
+
+#[derive(Debug, Deserialize)]
+#[try_from("wasm-signing_aborted")]
+struct SigningAbortedEvent {
+    session_id: u64,
+    reason: String,
+    expires_at: u64,
+    chain: ChainName,
+}
+
+/// Signing request of either a single message or a batch of messages
+struct SigningRequest {
+    session_id: u64,
+    pub_keys: HashMap<TMAddress, PublicKey>,
+    msgs: Vec<MessageDigest>,
+    expires_at: u64,
+    chain: ChainName,
+    is_batch: bool,
+}
+
+impl From<SigningStartedEvent> for SigningRequest {
+    fn from(event: SigningStartedEvent) -> Self {
+        Self {
+            session_id: event.session_id,
+            pub_keys: event.pub_keys,
+            msgs: vec![event.msg],
+            expires_at: event.expires_at,
+            chain: event.chain,
+            is_batch: false,
+        }
+    }
+}
+
+impl From<BatchSigningStartedEvent> for SigningRequest {
+    fn from(event: BatchSigningStartedEvent) -> Self {
+        Self {
+            session_id: event.session_id,
+            pub_keys: event.pub_keys,
+            msgs: event.msgs,
+            expires_at: event.expires_at,
+            chain: event.chain,
+            is_batch: true,
+        }
+    }
+}
+
+fn deserialize_msgs<'de, D>(deserializer: D) -> Result<Vec<MessageDigest>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Vec::<String>::deserialize(deserializer)?
+        .into_iter()
+        .map(|msg| MessageDigest::from_hex(msg).map_err(D::Error::custom))
+        .collect()
+}
+
 fn deserialize_public_keys<'de, D>(
     deserializer: D,
 ) -> Result<HashMap<TMAddress, PublicKey>, D::Error>
@@ -56,6 +126,7 @@ pub struct Handler<S> {
     chain: ChainName,
     signer: S,
     latest_block_height: Receiver<u64>,
+    signed_sessions: SignedSessions,
 }
 
 impl<S> Handler<S>
@@ -68,6 +139,7 @@ where
         chain: ChainName,
         signer: S,
         latest_block_height: Receiver<u64>,
+        signed_sessions: SignedSessions,
     ) -> Self {
         Self {
             verifier,
@@ -75,7 +147,41 @@ where
             chain,
             signer,
             latest_block_height,
+            signed_sessions,
+        }
+    }
+
+    /// Aborted sessions are tracked like signed ones, so they are skipped if their signing request is handled again,
+    /// e.g. when blocks are replayed after a restart
+    async fn cancel_session(&self, event: SigningAbortedEvent) {
+        let SigningAbortedEvent {
+            session_id,
+            reason,
+            expires_at,
+            chain,
+        } = event;
+
+        if !chain.eq(&self.chain) {
+            return;
         }
+
+        info!(
+            session_id = session_id.to_string(),
+            reason = reason.as_str(),
+            "signing session was aborted"
+        );
+
+        let latest_block_height = *self.latest_block_height.borrow();
+        let _ = self
+            .signed_sessions
+            .record(&self.multisig, session_id, expires_at, latest_block_height)
+            .await
+            .inspect_err(|err| {
+                warn!(
+                    err = LoggableError::from(err).as_value(),
+                    "failed to persist aborted session"
+                )
+            });
     }
 
     fn submit_signature_msg(
@@ -94,6 +200,53 @@ where
             funds: vec![],
         }
     }
+
+    fn submit_signatures_msg(
+        &self,
+        session_id: impl Into<Uint64>,
+        signatures: Vec<HexBinary>,
+    ) -> MsgExecuteContract {
+        MsgExecuteContract {
+            sender: self.verifier.as_ref().clone(),
+            contract: self.multisig.as_ref().clone(),
+            msg: serde_json::to_vec(&ExecuteMsg::SubmitSignatures {
+                session_id: session_id.into(),
+                signatures: signatures
+                    .try_into()
+                    .expect("batch signing session should not be empty"),
+            })
+            .expect("submit signatures msg should serialize"),
+            funds: vec![],
+        }
+    }
+}
+
+fn is_type_mismatch(report: &error_stack::Report<events::Error>) -> bool {
+    matches!(
+        report.current_context(),
+        events::Error::EventTypeMismatch(_)
+    )
+}
+
+fn signing_request(event: &events::Event) -> error_stack::Result<Option<SigningRequest>, Error> {
+    match event.try_into() as error_stack::Result<SigningStartedEvent, _> {
+        Err(report) if is_type_mismatch(&report) => {}
+        result => return Ok(Some(result.change_context(DeserializeEvent)?.into())),
+    }
+
+    match event.try_into() as error_stack::Result<BatchSigningStartedEvent, _> {
+        Err(report) if is_type_mismatch(&report) => Ok(None),
+        result => Ok(Some(result.change_context(DeserializeEvent)?.into())),
+    }
+}
+
+fn signing_aborted(
+    event: &events::Event,
+) -> error_stack::Result<Option<SigningAbortedEvent>, Error> {
+    match event.try_into() as error_stack::Result<SigningAbortedEvent, _> {
+        Err(report) if is_type_mismatch(&report) => Ok(None),
+        result => Ok(Some(result.change_context(DeserializeEvent)?)),
+    }
 }
 
 #[async_trait]
@@ -108,22 +261,21 @@ where
             return Ok(vec![]);
         }
 
-        let SigningStartedEvent {
+        if let Some(aborted) = signing_aborted(event)? {
+            self.cancel_session(aborted).await;
+            return Ok(vec![]);
+        }
+
+        let SigningRequest {
             session_id,
             pub_keys,
-            msg,
+            msgs,
             expires_at,
             chain,
-        } = match event.try_into() as error_stack::Result<_, _> {
-            Err(report)
-                if matches!(
-                    report.current_context(),
-                    events::Error::EventTypeMismatch(_)
-                ) =>
-            {
-                return Ok(vec![]);
-            }
-            result => result.change_context(DeserializeEvent)?,
+            is_batch,
+        } = match signing_request(event)? {
+            Some(request) => request,
+            None => return Ok(vec![]),
         };
 
         if !chain.eq(&self.chain) {
@@ -137,7 +289,7 @@ where
 
         info!(
             session_id = session_id,
-            msg = encode(&msg),
+            msgs = msgs.iter().map(encode).collect::<Vec<_>>().join(","),
             "get signing request",
         );
 
@@ -150,6 +302,17 @@ where
             return Ok(vec![]);
         }
 
+        if self
+            .signed_sessions
+            .is_duplicate(&self.multisig, session_id)
+        {
+            info!(
+                session_id = session_id.to_string(),
+                "skipping signing session that was already signed"
+            );
+            return Ok(vec![]);
+        }
+
         match pub_keys.get(&self.verifier) {
             Some(pub_key) => {
                 let key_type = match pub_key {
@@ -157,21 +320,25 @@ where
                     PublicKey::Ed25519(_) => tofnd::Algorithm::Ed25519,
                 };
 
-                let signature = self
-                    .signer
-                    .sign(
-                        self.multisig.to_string().as_str(),
-                        msg.clone(),
-                        *pub_key,
-                        key_type,
-                    )
-                    .await
-                    .change_context(Error::Sign)?;
-
-                info!(signature = encode(&signature), "ready to submit signature");
-
-                Ok(vec![self
-                    .submit_signature_msg(session_id, signature)
+                let mut signatures = Vec::with_capacity(msgs.len());
+                for msg in msgs {
+                    let signature = self
+                        .signer
+                        .sign(self.multisig.to_string().as_str(), msg, *pub_key, key_type)
+                        .await
+                        .change_context(Error::Sign)?;
+
+                    info!(signature = encode(&signature), "ready to submit signature");
+                    signatures.push(HexBinary::from(signature));
+                }
+
+                let msg = if is_batch {
+                    self.submit_signatures_msg(session_id, signatures)
+                } else {
+                    self.submit_signature_msg(session_id, signatures.remove(0))
+                };
+
+                Ok(vec![msg
                     .into_any()
                     .expect("submit signature msg should serialize")])
             }
@@ -182,6 +349,37 @@ where
             }
         }
     }
+
+    /// The session only counts as signed once the signatures have been broadcast, so a failed broadcast
+    /// doesn't prevent signing the session again
+    async fn on_broadcast(&self, event: &events::Event) -> error_stack::Result<(), Error> {
+        if !event.is_from_contract(self.multisig.as_ref()) {
+            return Ok(());
+        }
+
+        let Some(SigningRequest {
+            session_id,
+            expires_at,
+            ..
+        }) = signing_request(event)?
+        else {
+            return Ok(());
+        };
+
+        let latest_block_height = *self.latest_block_height.borrow();
+        let _ = self
+            .signed_sessions
+            .record(&self.multisig, session_id, expires_at, latest_block_height)
+            .await
+            .inspect_err(|err| {
+                warn!(
+                    err = LoggableError::from(err).as_value(),
+                    "failed to persist signed session"
+                )
+            });
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -254,6 +452,63 @@ mod test {
         .unwrap()
     }
 
+    fn batch_signing_started_event(batch_size: usize) -> events::Event {
+        let pub_keys = (0..10)
+            .map(|_| (TMAddress::random(PREFIX).to_string(), rand_public_key()))
+            .collect::<HashMap<String, multisig::key::PublicKey>>();
+
+        let batch_started = Event::BatchSigningStarted {
+            session_id: Uint64::one(),
+            verifier_set_id: "verifier_set_id".to_string(),
+            pub_keys,
+            msgs: (0..batch_size)
+                .map(|_| MsgToSign::unchecked(rand_message()))
+                .collect(),
+            chain_name: "Ethereum".parse().unwrap(),
+            expires_at: 100u64,
+        };
+
+        let mut event: cosmwasm_std::Event = batch_started.into();
+        event.ty = format!("wasm-{}", event.ty);
+        event = event.add_attribute("_contract_address", MULTISIG_ADDRESS);
+
+        events::Event::try_from(abci::Event::new(
+            event.ty,
+            event
+                .attributes
+                .into_iter()
+                .map(|cosmwasm_std::Attribute { key, value }| {
+                    (STANDARD.encode(key), STANDARD.encode(value))
+                }),
+        ))
+        .unwrap()
+    }
+
+    fn signing_aborted_event() -> events::Event {
+        let aborted = Event::SigningAborted {
+            session_id: Uint64::one(),
+            aborted_at: 50u64,
+            reason: "payload is obsolete".to_string(),
+            chain_name: "Ethereum".parse().unwrap(),
+            expires_at: 100u64,
+        };
+
+        let mut event: cosmwasm_std::Event = aborted.into();
+        event.ty = format!("wasm-{}", event.ty);
+        event = event.add_attribute("_contract_address", MULTISIG_ADDRESS);
+
+        events::Event::try_from(abci::Event::new(
+            event.ty,
+            event
+                .attributes
+                .into_iter()
+                .map(|cosmwasm_std::Attribute { key, value }| {
+                    (STANDARD.encode(key), STANDARD.encode(value))
+                }),
+        ))
+        .unwrap()
+    }
+
     // this returns an event that is named SigningStarted, but some expected fields are missing
     fn signing_started_event_with_missing_fields(contract_address: &str) -> events::Event {
         let pub_keys = (0..10)
@@ -300,7 +555,14 @@ mod test {
 
         let (_, rx) = watch::channel(latest_block_height);
 
-        Handler::new(verifier, multisig, chain, signer, rx)
+        Handler::new(
+            verifier,
+            multisig,
+            chain,
+            signer,
+            rx,
+            SignedSessions::default(),
+        )
     }
 
     #[test]
@@ -503,4 +765,112 @@ mod test {
 
         assert_eq!(handler.handle(&event).await.unwrap(), vec![]);
     }
+
+    #[tokio::test]
+    async fn should_sign_session_only_once() {
+        let mut client = MockMultisig::default();
+        client
+            .expect_sign()
+            .times(1)
+            .returning(|_, _, _, _| Ok(vec![0u8; 64]));
+
+        let event = signing_started_event();
+        let signing_started: SigningStartedEvent = ((&event).try_into() as Result<_, _>).unwrap();
+        let verifier = signing_started.pub_keys.keys().next().unwrap().clone();
+        let handler = handler(
+            verifier,
+            TMAddress::from(MULTISIG_ADDRESS.parse::<AccountId>().unwrap()),
+            "Ethereum".parse().unwrap(),
+            client,
+            99u64,
+        );
+
+        assert_eq!(handler.handle(&event).await.unwrap().len(), 1);
+        handler.on_broadcast(&event).await.unwrap();
+        assert_eq!(handler.handle(&event).await.unwrap(), vec![]);
+        assert_eq!(handler.signed_sessions.metrics().duplicates_skipped, 1);
+    }
+
+    #[tokio::test]
+    async fn should_sign_session_again_if_signatures_were_not_broadcast() {
+        let mut client = MockMultisig::default();
+        client
+            .expect_sign()
+            .times(2)
+            .returning(|_, _, _, _| Ok(vec![0u8; 64]));
+
+        let event = signing_started_event();
+        let signing_started: SigningStartedEvent = ((&event).try_into() as Result<_, _>).unwrap();
+        let verifier = signing_started.pub_keys.keys().next().unwrap().clone();
+        let handler = handler(
+            verifier,
+            TMAddress::from(MULTISIG_ADDRESS.parse::<AccountId>().unwrap()),
+            "Ethereum".parse().unwrap(),
+            client,
+            99u64,
+        );
+
+        assert_eq!(handler.handle(&event).await.unwrap().len(), 1);
+        assert_eq!(handler.handle(&event).await.unwrap().len(), 1);
+        assert_eq!(handler.signed_sessions.metrics().duplicates_skipped, 0);
+    }
+
+    #[tokio::test]
+    async fn should_submit_one_signature_per_message_of_batch_session() {
+        let mut client = MockMultisig::default();
+        client
+            .expect_sign()
+            .times(3)
+            .returning(|_, _, _, _| Ok(vec![0u8; 64]));
+
+        let event = batch_signing_started_event(3);
+        let batch_started: BatchSigningStartedEvent =
+            ((&event).try_into() as Result<_, _>).unwrap();
+        let verifier = batch_started.pub_keys.keys().next().unwrap().clone();
+        let handler = handler(
+            verifier,
+            TMAddress::from(MULTISIG_ADDRESS.parse::<AccountId>().unwrap()),
+            "Ethereum".parse().unwrap(),
+            client,
+            99u64,
+        );
+
+        let msgs = handler.handle(&event).await.unwrap();
+        assert_eq!(msgs.len(), 1);
+
+        let msg = MsgExecuteContract::from_any(msgs.first().unwrap()).unwrap();
+        match serde_json::from_slice(&msg.msg).unwrap() {
+            ExecuteMsg::SubmitSignatures {
+                session_id,
+                signatures,
+            } => {
+                assert_eq!(session_id, Uint64::one());
+                assert_eq!(signatures.as_ref().len(), 3);
+            }
+            _ => panic!("unexpected msg"),
+        }
+    }
+
+    #[tokio::test]
+    async fn should_not_sign_aborted_session() {
+        let mut client = MockMultisig::default();
+        client.expect_sign().never();
+
+        let event = signing_started_event();
+        let signing_started: SigningStartedEvent = ((&event).try_into() as Result<_, _>).unwrap();
+        let verifier = signing_started.pub_keys.keys().next().unwrap().clone();
+        let handler = handler(
+            verifier,
+            TMAddress::from(MULTISIG_ADDRESS.parse::<AccountId>().unwrap()),
+            "Ethereum".parse().unwrap(),
+            client,
+            99u64,
+        );
+
+        assert_eq!(
+            handler.handle(&signing_aborted_event()).await.unwrap(),
+            vec![]
+        );
+        assert_eq!(handler.handle(&event).await.unwrap(), vec![]);
+    }
 }
@@ -184,7 +184,7 @@ mod tests {
 
     use super::PollStartedEvent;
     use crate::event_processor::EventHandler;
-    use crate::handlers::tests::{into_structured_event, participants};
+    use crate::handlers::tests::{into_structured_event, participants, voting_threshold};
     use crate::mvx::proxy::MockMvxProxy;
     use crate::types::TMAddress;
     use crate::PREFIX;
@@ -338,6 +338,8 @@ mod tests {
                     .into_iter()
                     .map(|addr| cosmwasm_std::Addr::unchecked(addr.to_string()))
                     .collect(),
+                voting_threshold: voting_threshold(),
+                observed_at: None,
             },
             #[allow(deprecated)] // TODO: The below event uses the deprecated tx_id and event_index fields. Remove this attribute when those fields are removed
             messages: vec![TxEventConfirmation {
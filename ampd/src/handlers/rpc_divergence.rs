@@ -0,0 +1,287 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use axelar_wasm_std::voting::Vote;
+use error_stack::{report, Result, ResultExt};
+use report::LoggableError;
+use router_api::ChainName;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tracing::{error, warn};
+use valuable::Valuable;
+
+use crate::config::expand_home_dir;
+use crate::url::Url;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("failed to persist RPC divergence evidence to {0}")]
+    Persist(String),
+}
+
+/// What the verifier does when the RPC endpoints of a handler disagree on the verification of a message
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Countermeasure {
+    /// Vote NotFound for the messages the endpoints disagree on, and vote normally for all other messages of the poll
+    #[default]
+    VoteNotFound,
+    /// Vote Abstain for the messages the endpoints disagree on, so the verifier participates without influencing the outcome
+    VoteAbstain,
+    /// Don't vote in the poll at all
+    Abstain,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(default)]
+pub struct Config {
+    pub countermeasure: Countermeasure,
+    /// File that divergent responses are appended to, one JSON object per line
+    pub path: PathBuf,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            countermeasure: Countermeasure::default(),
+            path: PathBuf::from("~/.ampd/rpc_divergence.jsonl"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub struct Metrics {
+    /// number of messages the RPC endpoints disagreed on, per source chain
+    pub divergent_messages: HashMap<ChainName, u64>,
+    /// number of polls the verifier did not vote in because of divergent responses
+    pub abstained_polls: u64,
+}
+
+/// The votes that the responses of each RPC endpoint would have resulted in for a single message
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct Evidence {
+    pub chain: ChainName,
+    pub poll_id: String,
+    pub message_id: String,
+    /// keyed by the origin of the endpoint, i.e. without path and query, which often contain API keys
+    pub votes: BTreeMap<String, Vote>,
+    pub countermeasure: Countermeasure,
+    /// unix timestamp in seconds
+    pub detected_at: u64,
+}
+
+impl Evidence {
+    pub fn new(
+        chain: &ChainName,
+        poll_id: impl ToString,
+        message_id: impl ToString,
+        votes: impl IntoIterator<Item = (String, Vote)>,
+        countermeasure: Countermeasure,
+    ) -> Self {
+        Self {
+            chain: chain.clone(),
+            poll_id: poll_id.to_string(),
+            message_id: message_id.to_string(),
+            votes: votes.into_iter().collect(),
+            countermeasure,
+            detected_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |elapsed| elapsed.as_secs()),
+        }
+    }
+}
+
+/// Identifies an RPC endpoint in logs and evidence without leaking credentials that are part of its URL
+pub fn endpoint(url: &Url) -> String {
+    url.origin().ascii_serialization()
+}
+
+/// Compares the verification results of multiple RPC endpoints of the same chain. A compromised or faulty endpoint
+/// could otherwise trick the verifier into voting that a message exists on the source chain when it doesn't.
+/// Every disagreement raises an alert, is counted in the metrics and is persisted as evidence,
+/// and the configured countermeasure decides how the verifier votes.
+#[derive(Clone, Default)]
+pub struct RpcDivergence {
+    countermeasure: Countermeasure,
+    path: Option<PathBuf>,
+    metrics: Arc<Mutex<Metrics>>,
+}
+
+impl RpcDivergence {
+    pub fn new(config: Config) -> Self {
+        Self {
+            countermeasure: config.countermeasure,
+            path: Some(expand_home_dir(config.path)),
+            metrics: Arc::new(Mutex::new(Metrics::default())),
+        }
+    }
+
+    pub fn countermeasure(&self) -> Countermeasure {
+        self.countermeasure
+    }
+
+    /// Alerts about and records the divergent responses of a poll. Failing to persist the evidence
+    /// is logged, but does not prevent the countermeasure from being applied
+    pub async fn record(&self, evidence: Vec<Evidence>) {
+        if evidence.is_empty() {
+            return;
+        }
+
+        let mut metrics = self.lock();
+        for item in &evidence {
+            error!(
+                chain = item.chain.to_string(),
+                poll_id = item.poll_id,
+                message_id = item.message_id,
+                votes = ?item.votes,
+                countermeasure = ?item.countermeasure,
+                "RPC endpoints returned divergent results for message"
+            );
+
+            let count = metrics
+                .divergent_messages
+                .entry(item.chain.clone())
+                .or_default();
+            *count = count.saturating_add(1);
+        }
+
+        if self.countermeasure == Countermeasure::Abstain {
+            metrics.abstained_polls = metrics.abstained_polls.saturating_add(1);
+        }
+        drop(metrics);
+
+        let Some(path) = self.path.clone() else {
+            return;
+        };
+
+        // file IO blocks, so it must not run on the async runtime
+        let error = Error::Persist(path.to_string_lossy().to_string());
+        let result = tokio::task::spawn_blocking(move || persist(&path, &evidence))
+            .await
+            .unwrap_or_else(|err| Err(report!(err).change_context(error)));
+
+        if let Err(err) = result {
+            warn!(
+                err = LoggableError::from(&err).as_value(),
+                "failed to persist RPC divergence evidence"
+            );
+        }
+    }
+
+    pub fn metrics(&self) -> Metrics {
+        self.lock().clone()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Metrics> {
+        self.metrics
+            .lock()
+            .expect("rpc divergence metrics lock should not be poisoned")
+    }
+}
+
+fn persist(path: &Path, evidence: &[Evidence]) -> Result<(), Error> {
+    let error = || Error::Persist(path.to_string_lossy().to_string());
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).change_context_lazy(error)?;
+    }
+
+    let mut lines = Vec::new();
+    for item in evidence {
+        serde_json::to_writer(&mut lines, item).expect("evidence should serialize");
+        lines.push(b'\n');
+    }
+
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .and_then(|mut file| file.write_all(&lines))
+        .change_context_lazy(error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(countermeasure: Countermeasure) -> Config {
+        Config {
+            countermeasure,
+            path: std::env::temp_dir()
+                .join(format!("ampd-{}", rand::random::<u64>()))
+                .join("rpc_divergence.jsonl"),
+        }
+    }
+
+    fn evidence(chain: &ChainName, message_id: &str) -> Evidence {
+        Evidence::new(
+            chain,
+            1u64,
+            message_id,
+            [
+                ("https://a.example".to_string(), Vote::SucceededOnChain),
+                ("https://b.example".to_string(), Vote::NotFound),
+            ],
+            Countermeasure::VoteNotFound,
+        )
+    }
+
+    #[tokio::test]
+    async fn evidence_should_be_appended_to_the_file() {
+        let config = config(Countermeasure::VoteNotFound);
+        let chain: ChainName = "ethereum".parse().unwrap();
+
+        let rpc_divergence = RpcDivergence::new(config.clone());
+        rpc_divergence.record(vec![evidence(&chain, "0x1-0")]).await;
+        rpc_divergence
+            .record(vec![evidence(&chain, "0x2-0"), evidence(&chain, "0x3-0")])
+            .await;
+
+        let recorded: Vec<Evidence> = fs::read_to_string(&config.path)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(
+            recorded
+                .iter()
+                .map(|evidence| evidence.message_id.as_str())
+                .collect::<Vec<_>>(),
+            vec!["0x1-0", "0x2-0", "0x3-0"]
+        );
+
+        assert_eq!(
+            rpc_divergence.metrics(),
+            Metrics {
+                divergent_messages: HashMap::from([(chain, 3)]),
+                abstained_polls: 0,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn abstained_polls_should_be_counted() {
+        let chain: ChainName = "ethereum".parse().unwrap();
+
+        let rpc_divergence = RpcDivergence::new(config(Countermeasure::Abstain));
+        rpc_divergence.record(vec![]).await;
+        rpc_divergence
+            .record(vec![evidence(&chain, "0x1-0"), evidence(&chain, "0x2-0")])
+            .await;
+
+        assert_eq!(rpc_divergence.metrics().abstained_polls, 1);
+    }
+
+    #[test]
+    fn endpoint_should_not_contain_path_or_query() {
+        let url: Url = "https://eth-mainnet.example.com/v2/secret-key?token=secret"
+            .parse()
+            .unwrap();
+
+        assert_eq!(endpoint(&url), "https://eth-mainnet.example.com");
+    }
+}
@@ -0,0 +1,258 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use error_stack::{Result, ResultExt};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tokio::sync::Mutex as AsyncMutex;
+
+use crate::config::expand_home_dir;
+use crate::types::TMAddress;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("failed to load signed sessions from {0}")]
+    Load(String),
+    #[error("failed to persist signed sessions to {0}")]
+    Persist(String),
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(default)]
+pub struct Config {
+    pub path: PathBuf,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            path: PathBuf::from("~/.ampd/signed_sessions.json"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub struct Metrics {
+    /// number of signed sessions that have not expired yet
+    pub tracked: usize,
+    /// number of signing requests that were skipped because the session had already been signed
+    pub duplicates_skipped: u64,
+    /// number of signed sessions that were dropped after expiring
+    pub expired: u64,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct SignedSession {
+    multisig: TMAddress,
+    session_id: u64,
+    expires_at: u64,
+}
+
+#[derive(Default)]
+struct State {
+    sessions: HashMap<(TMAddress, u64), u64>,
+    metrics: Metrics,
+}
+
+/// Keeps track of the multisig signing sessions this verifier has already signed.
+/// Sessions are persisted to disk, so restarts and replayed events never result in a second
+/// signature submission for the same session. A session is forgotten once it expires.
+#[derive(Clone, Default)]
+pub struct SignedSessions {
+    path: Option<PathBuf>,
+    state: Arc<Mutex<State>>,
+    persisting: Arc<AsyncMutex<()>>,
+}
+
+impl SignedSessions {
+    pub fn load(config: Config) -> Result<Self, Error> {
+        let path = expand_home_dir(config.path);
+
+        let sessions: Vec<SignedSession> = match fs::read(&path) {
+            Ok(content) => serde_json::from_slice(&content)
+                .change_context_lazy(|| Error::Load(path.to_string_lossy().to_string()))?,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => vec![],
+            Err(err) => {
+                return Err(err).change_context(Error::Load(path.to_string_lossy().to_string()))
+            }
+        };
+
+        let sessions: HashMap<_, _> = sessions
+            .into_iter()
+            .map(|session| ((session.multisig, session.session_id), session.expires_at))
+            .collect();
+
+        Ok(Self {
+            path: Some(path),
+            state: Arc::new(Mutex::new(State {
+                metrics: Metrics {
+                    tracked: sessions.len(),
+                    ..Metrics::default()
+                },
+                sessions,
+            })),
+            persisting: Arc::default(),
+        })
+    }
+
+    /// Returns true if the session has already been signed, and counts it as a skipped duplicate
+    pub fn is_duplicate(&self, multisig: &TMAddress, session_id: u64) -> bool {
+        let mut state = self.lock();
+
+        let is_duplicate = state.sessions.contains_key(&(multisig.clone(), session_id));
+        if is_duplicate {
+            state.metrics.duplicates_skipped = state.metrics.duplicates_skipped.saturating_add(1);
+        }
+
+        is_duplicate
+    }
+
+    /// Records the session as signed and drops all sessions that expired at the given block height.
+    /// The session is kept in memory even if it cannot be persisted.
+    pub async fn record(
+        &self,
+        multisig: &TMAddress,
+        session_id: u64,
+        expires_at: u64,
+        latest_block_height: u64,
+    ) -> Result<(), Error> {
+        // records are persisted one at a time, so a newer snapshot is never overwritten by an older one
+        let _persisting = self.persisting.lock().await;
+
+        let sessions = self.update(multisig, session_id, expires_at, latest_block_height);
+
+        match &self.path {
+            Some(path) => persist(path, sessions).await,
+            None => Ok(()),
+        }
+    }
+
+    pub fn metrics(&self) -> Metrics {
+        self.lock().metrics
+    }
+
+    /// Returns a snapshot of the sessions to persist
+    fn update(
+        &self,
+        multisig: &TMAddress,
+        session_id: u64,
+        expires_at: u64,
+        latest_block_height: u64,
+    ) -> Vec<SignedSession> {
+        let mut state = self.lock();
+
+        let tracked = state.sessions.len();
+        state
+            .sessions
+            .retain(|_, expires_at| *expires_at > latest_block_height);
+        let expired = tracked.saturating_sub(state.sessions.len());
+
+        state
+            .sessions
+            .insert((multisig.clone(), session_id), expires_at);
+
+        state.metrics.tracked = state.sessions.len();
+        state.metrics.expired = state
+            .metrics
+            .expired
+            .saturating_add(u64::try_from(expired).unwrap_or(u64::MAX));
+
+        state
+            .sessions
+            .iter()
+            .map(|((multisig, session_id), expires_at)| SignedSession {
+                multisig: multisig.clone(),
+                session_id: *session_id,
+                expires_at: *expires_at,
+            })
+            .collect()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.state
+            .lock()
+            .expect("signed sessions lock should not be poisoned")
+    }
+}
+
+async fn persist(path: &Path, sessions: Vec<SignedSession>) -> Result<(), Error> {
+    let error = || Error::Persist(path.to_string_lossy().to_string());
+
+    if let Some(dir) = path.parent() {
+        tokio::fs::create_dir_all(dir)
+            .await
+            .change_context_lazy(error)?;
+    }
+
+    // write to a temporary file first so a crash never leaves a truncated file behind
+    let tmp_path = path.with_extension("tmp");
+    tokio::fs::write(
+        &tmp_path,
+        serde_json::to_vec(&sessions).expect("signed sessions should serialize"),
+    )
+    .await
+    .change_context_lazy(error)?;
+    tokio::fs::rename(&tmp_path, path)
+        .await
+        .change_context_lazy(error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PREFIX;
+
+    fn config() -> Config {
+        Config {
+            path: std::env::temp_dir()
+                .join(format!("ampd-{}", rand::random::<u64>()))
+                .join("signed_sessions.json"),
+        }
+    }
+
+    #[tokio::test]
+    async fn signed_sessions_should_survive_reload() {
+        let config = config();
+        let multisig = TMAddress::random(PREFIX);
+
+        let signed_sessions = SignedSessions::load(config.clone()).unwrap();
+        assert!(!signed_sessions.is_duplicate(&multisig, 1));
+        signed_sessions.record(&multisig, 1, 100, 10).await.unwrap();
+
+        let signed_sessions = SignedSessions::load(config.clone()).unwrap();
+        assert!(signed_sessions.is_duplicate(&multisig, 1));
+        assert!(!signed_sessions.is_duplicate(&multisig, 2));
+        assert!(!signed_sessions.is_duplicate(&TMAddress::random(PREFIX), 1));
+
+        assert_eq!(
+            signed_sessions.metrics(),
+            Metrics {
+                tracked: 1,
+                duplicates_skipped: 1,
+                expired: 0,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn expired_sessions_should_be_dropped() {
+        let config = config();
+        let multisig = TMAddress::random(PREFIX);
+
+        let signed_sessions = SignedSessions::load(config.clone()).unwrap();
+        signed_sessions.record(&multisig, 1, 100, 10).await.unwrap();
+        signed_sessions.record(&multisig, 2, 200, 10).await.unwrap();
+        signed_sessions
+            .record(&multisig, 3, 300, 100)
+            .await
+            .unwrap();
+
+        let signed_sessions = SignedSessions::load(config.clone()).unwrap();
+        assert!(!signed_sessions.is_duplicate(&multisig, 1));
+        assert!(signed_sessions.is_duplicate(&multisig, 2));
+        assert!(signed_sessions.is_duplicate(&multisig, 3));
+        assert_eq!(signed_sessions.metrics().tracked, 2);
+    }
+}
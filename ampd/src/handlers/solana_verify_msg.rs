@@ -185,7 +185,7 @@ mod test {
     use voting_verifier::events::{PollMetadata, PollStarted, TxEventConfirmation};
 
     use super::*;
-    use crate::handlers::tests::into_structured_event;
+    use crate::handlers::tests::{into_structured_event, voting_threshold};
     use crate::types::TMAddress;
     use crate::PREFIX;
 
@@ -372,6 +372,8 @@ mod test {
                     .into_iter()
                     .map(|addr| cosmwasm_std::Addr::unchecked(addr.to_string()))
                     .collect(),
+                voting_threshold: voting_threshold(),
+                observed_at: None,
             },
             #[allow(deprecated)]
             messages: vec![
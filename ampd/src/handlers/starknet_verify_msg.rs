@@ -164,6 +164,7 @@ mod tests {
     use voting_verifier::events::{PollMetadata, PollStarted, TxEventConfirmation};
 
     use super::*;
+    use crate::handlers::tests::voting_threshold;
     use crate::starknet::json_rpc::MockStarknetClient;
     use crate::types::starknet::events::contract_call::ContractCallEvent;
     use crate::PREFIX;
@@ -428,6 +429,8 @@ mod tests {
                     .into_iter()
                     .map(|addr| cosmwasm_std::Addr::unchecked(addr.to_string()))
                     .collect(),
+                voting_threshold: voting_threshold(),
+                observed_at: None,
             },
             messages: vec![
                 #[allow(deprecated)] // TODO: Use message_id, on deprecating tx_id and event_index
@@ -495,6 +498,8 @@ mod tests {
                     .into_iter()
                     .map(|addr| cosmwasm_std::Addr::unchecked(addr.to_string()))
                     .collect(),
+                voting_threshold: voting_threshold(),
+                observed_at: None,
             },
             messages: vec![
                 #[allow(deprecated)] // TODO: Use message_id, on deprecating tx_id and event_index
@@ -562,6 +567,8 @@ mod tests {
                     .into_iter()
                     .map(|addr| cosmwasm_std::Addr::unchecked(addr.to_string()))
                     .collect(),
+                voting_threshold: voting_threshold(),
+                observed_at: None,
             },
             messages: vec![
                 #[allow(deprecated)] // TODO: Use message_id, on deprecating tx_id and event_index
@@ -180,6 +180,7 @@ mod tests {
 
     use crate::event_processor::EventHandler;
     use crate::handlers::starknet_verify_verifier_set::PollStartedEvent;
+    use crate::handlers::tests::voting_threshold;
     use crate::starknet::json_rpc::MockStarknetClient;
     use crate::types::TMAddress;
     use crate::PREFIX;
@@ -264,6 +265,8 @@ mod tests {
                     .into_iter()
                     .map(|addr| cosmwasm_std::Addr::unchecked(addr.to_string()))
                     .collect(),
+                voting_threshold: voting_threshold(),
+                observed_at: None,
             },
         }
     }
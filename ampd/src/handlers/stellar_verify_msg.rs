@@ -185,7 +185,7 @@ mod tests {
 
     use super::PollStartedEvent;
     use crate::event_processor::EventHandler;
-    use crate::handlers::tests::{into_structured_event, participants};
+    use crate::handlers::tests::{into_structured_event, participants, voting_threshold};
     use crate::stellar::rpc_client::Client;
     use crate::types::TMAddress;
     use crate::PREFIX;
@@ -314,6 +314,8 @@ mod tests {
                     .into_iter()
                     .map(|addr| cosmwasm_std::Addr::unchecked(addr.to_string()))
                     .collect(),
+                voting_threshold: voting_threshold(),
+                observed_at: None,
             },
             messages: (0..2)
                 .map(|i| {
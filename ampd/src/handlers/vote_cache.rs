@@ -0,0 +1,259 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+use axelar_wasm_std::voting::Vote;
+use router_api::ChainName;
+use serde::{Deserialize, Serialize};
+use sha3::{Digest, Keccak256};
+
+/// Config of the vote cache, which only the EVM message verifiers use
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(default)]
+pub struct Config {
+    /// How long a verification result is reused for
+    #[serde(with = "humantime_serde")]
+    pub ttl: Duration,
+    /// Maximum number of cached verification results. The oldest results are dropped first
+    pub capacity: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            ttl: Duration::from_secs(600),
+            capacity: 10000,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub struct Metrics {
+    /// number of verification results that are currently cached
+    pub cached: usize,
+    /// number of message verifications that were answered from the cache
+    pub hits: u64,
+    /// number of message verifications that required querying the chain
+    pub misses: u64,
+    /// number of verification results that were dropped because they expired or the cache was full
+    pub evicted: u64,
+}
+
+/// Identifies a message by its chain, its id and everything its verification depends on
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Key {
+    chain: ChainName,
+    message_id: String,
+    content_hash: [u8; 32],
+}
+
+impl Key {
+    pub fn new<'a>(
+        chain: &ChainName,
+        message_id: impl ToString,
+        content: impl IntoIterator<Item = &'a [u8]>,
+    ) -> Self {
+        let mut hasher = Keccak256::new();
+        // length-prefix every field so that different contents can never produce the same hash input
+        for field in content {
+            hasher.update((field.len() as u64).to_be_bytes());
+            hasher.update(field);
+        }
+
+        Self {
+            chain: chain.clone(),
+            message_id: message_id.to_string(),
+            content_hash: hasher.finalize().into(),
+        }
+    }
+}
+
+struct State {
+    votes: HashMap<Key, (Vote, Instant)>,
+    /// keys in insertion order, which is also the order in which they expire
+    order: VecDeque<Key>,
+    metrics: Metrics,
+}
+
+/// Short-lived cache of message verification results. When the same message shows up in several polls,
+/// e.g. because its verification is retried, the result of the first verification is reused instead of
+/// querying the chain again. Only conclusive votes are cached: a message that was not found might just
+/// not be finalized yet, so it is always verified again. The cache is shared by the EVM message verifiers of all chains,
+/// other voting handlers don't use it.
+#[derive(Clone)]
+pub struct VoteCache {
+    ttl: Duration,
+    capacity: usize,
+    state: Arc<Mutex<State>>,
+}
+
+impl Default for VoteCache {
+    fn default() -> Self {
+        Self::new(Config::default())
+    }
+}
+
+impl VoteCache {
+    pub fn new(config: Config) -> Self {
+        Self {
+            ttl: config.ttl,
+            capacity: config.capacity,
+            state: Arc::new(Mutex::new(State {
+                votes: HashMap::new(),
+                order: VecDeque::new(),
+                metrics: Metrics::default(),
+            })),
+        }
+    }
+
+    /// Returns the cached vote for the message, and counts the lookup as a hit or miss
+    pub fn get(&self, key: &Key) -> Option<Vote> {
+        let mut state = self.lock();
+
+        let vote = state
+            .votes
+            .get(key)
+            .filter(|(_, cached_at)| cached_at.elapsed() < self.ttl)
+            .map(|(vote, _)| vote.clone());
+
+        match vote {
+            Some(_) => state.metrics.hits = state.metrics.hits.saturating_add(1),
+            None => state.metrics.misses = state.metrics.misses.saturating_add(1),
+        }
+
+        vote
+    }
+
+    pub fn insert(&self, key: Key, vote: Vote) {
+        if matches!(vote, Vote::NotFound | Vote::Abstain) || self.capacity == 0 {
+            return;
+        }
+
+        let mut state = self.lock();
+        self.evict_expired(&mut state);
+
+        if state.votes.contains_key(&key) {
+            return;
+        }
+
+        while state.votes.len() >= self.capacity {
+            Self::evict_oldest(&mut state);
+        }
+
+        state.votes.insert(key.clone(), (vote, Instant::now()));
+        state.order.push_back(key);
+        state.metrics.cached = state.votes.len();
+    }
+
+    pub fn metrics(&self) -> Metrics {
+        self.lock().metrics
+    }
+
+    fn evict_expired(&self, state: &mut State) {
+        while state
+            .order
+            .front()
+            .and_then(|key| state.votes.get(key))
+            .is_some_and(|(_, cached_at)| cached_at.elapsed() >= self.ttl)
+        {
+            Self::evict_oldest(state);
+        }
+
+        state.metrics.cached = state.votes.len();
+    }
+
+    fn evict_oldest(state: &mut State) {
+        if let Some(key) = state.order.pop_front() {
+            state.votes.remove(&key);
+            state.metrics.evicted = state.metrics.evicted.saturating_add(1);
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.state
+            .lock()
+            .expect("vote cache lock should not be poisoned")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(message_id: &str, content: &[u8]) -> Key {
+        Key::new(&"ethereum".parse().unwrap(), message_id, [content])
+    }
+
+    #[test]
+    fn cached_vote_should_be_returned_until_it_expires() {
+        let cache = VoteCache::new(Config {
+            ttl: Duration::from_millis(50),
+            capacity: 10,
+        });
+
+        assert_eq!(cache.get(&key("0x1-0", b"content")), None);
+
+        cache.insert(key("0x1-0", b"content"), Vote::SucceededOnChain);
+        assert_eq!(
+            cache.get(&key("0x1-0", b"content")),
+            Some(Vote::SucceededOnChain)
+        );
+
+        std::thread::sleep(Duration::from_millis(60));
+        assert_eq!(cache.get(&key("0x1-0", b"content")), None);
+
+        assert_eq!(
+            cache.metrics(),
+            Metrics {
+                cached: 1,
+                hits: 1,
+                misses: 2,
+                evicted: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn vote_should_only_be_reused_for_the_same_content() {
+        let cache = VoteCache::default();
+
+        cache.insert(key("0x1-0", b"content"), Vote::FailedOnChain);
+
+        assert_eq!(cache.get(&key("0x1-0", b"other content")), None);
+        assert_eq!(cache.get(&key("0x1-1", b"content")), None);
+        assert_ne!(
+            Key::new(&"ethereum".parse().unwrap(), "0x1-0", [&b"ab"[..], b"c"]),
+            Key::new(&"ethereum".parse().unwrap(), "0x1-0", [&b"a"[..], b"bc"])
+        );
+    }
+
+    #[test]
+    fn not_found_vote_should_not_be_cached() {
+        let cache = VoteCache::default();
+
+        cache.insert(key("0x1-0", b"content"), Vote::NotFound);
+
+        assert_eq!(cache.get(&key("0x1-0", b"content")), None);
+        assert_eq!(cache.metrics().cached, 0);
+    }
+
+    #[test]
+    fn oldest_votes_should_be_evicted_when_cache_is_full() {
+        let cache = VoteCache::new(Config {
+            ttl: Duration::from_secs(600),
+            capacity: 2,
+        });
+
+        cache.insert(key("0x1-0", b"content"), Vote::SucceededOnChain);
+        cache.insert(key("0x2-0", b"content"), Vote::SucceededOnChain);
+        cache.insert(key("0x3-0", b"content"), Vote::FailedOnChain);
+
+        assert_eq!(cache.get(&key("0x1-0", b"content")), None);
+        assert_eq!(
+            cache.get(&key("0x3-0", b"content")),
+            Some(Vote::FailedOnChain)
+        );
+        assert_eq!(cache.metrics().cached, 2);
+        assert_eq!(cache.metrics().evicted, 1);
+    }
+}
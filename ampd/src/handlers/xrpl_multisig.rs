@@ -12,14 +12,17 @@ use events_derive;
 use events_derive::try_from;
 use hex::encode;
 use multisig::msg::ExecuteMsg;
+use report::LoggableError;
 use serde::de::Error as DeserializeError;
 use serde::{Deserialize, Deserializer};
 use tokio::sync::watch::Receiver;
-use tracing::info;
+use tracing::{info, warn};
+use valuable::Valuable;
 use xrpl_types::types::XRPLAccountId;
 
 use crate::event_processor::EventHandler;
 use crate::handlers::errors::Error::{self, DeserializeEvent};
+use crate::handlers::signed_sessions::SignedSessions;
 use crate::tofnd::grpc::Multisig;
 use crate::tofnd::{Algorithm, MessageDigest};
 use crate::types::{PublicKey, TMAddress};
@@ -55,6 +58,7 @@ pub struct Handler<S> {
     multisig: TMAddress,
     signer: S,
     latest_block_height: Receiver<u64>,
+    signed_sessions: SignedSessions,
 }
 
 impl<S> Handler<S>
@@ -67,6 +71,7 @@ where
         multisig_prover: TMAddress,
         signer: S,
         latest_block_height: Receiver<u64>,
+        signed_sessions: SignedSessions,
     ) -> Self {
         Self {
             verifier,
@@ -74,6 +79,7 @@ where
             multisig_prover,
             signer,
             latest_block_height,
+            signed_sessions,
         }
     }
 
@@ -134,6 +140,17 @@ where
             return Ok(vec![]);
         }
 
+        if self
+            .signed_sessions
+            .is_duplicate(&self.multisig, session_id)
+        {
+            info!(
+                session_id = session_id.to_string(),
+                "skipping signing session that was already signed"
+            );
+            return Ok(vec![]);
+        }
+
         match pub_keys.get(&self.verifier) {
             Some(&pub_key) => {
                 let pub_key_hex = HexBinary::from(pub_key.to_bytes());
@@ -174,6 +191,39 @@ where
             }
         }
     }
+
+    /// The session only counts as signed once the signature has been broadcast, so a failed broadcast
+    /// doesn't prevent signing the session again
+    async fn on_broadcast(&self, event: &events::Event) -> error_stack::Result<(), Error> {
+        if !event.is_from_contract(self.multisig_prover.as_ref()) {
+            return Ok(());
+        }
+
+        let XRPLSigningStartedEvent {
+            session_id,
+            expires_at,
+            ..
+        } = match event.try_into() as error_stack::Result<_, _> {
+            Err(report) if matches!(report.current_context(), EventTypeMismatch(_)) => {
+                return Ok(());
+            }
+            result => result.change_context(DeserializeEvent)?,
+        };
+
+        let latest_block_height = *self.latest_block_height.borrow();
+        let _ = self
+            .signed_sessions
+            .record(&self.multisig, session_id, expires_at, latest_block_height)
+            .await
+            .inspect_err(|err| {
+                warn!(
+                    err = LoggableError::from(err).as_value(),
+                    "failed to persist signed session"
+                )
+            });
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -291,7 +341,14 @@ mod test {
 
         let (_, rx) = watch::channel(latest_block_height);
 
-        Handler::new(verifier, multisig, multisig_prover, signer, rx)
+        Handler::new(
+            verifier,
+            multisig,
+            multisig_prover,
+            signer,
+            rx,
+            SignedSessions::default(),
+        )
     }
 
     #[test]
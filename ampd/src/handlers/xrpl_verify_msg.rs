@@ -48,6 +48,7 @@ where
 {
     verifier: TMAddress,
     voting_verifier_contract: TMAddress,
+    chain: ChainName,
     rpc_client: C,
     latest_block_height: Receiver<u64>,
 }
@@ -59,12 +60,14 @@ where
     pub fn new(
         verifier: TMAddress,
         voting_verifier_contract: TMAddress,
+        chain: ChainName,
         rpc_client: C,
         latest_block_height: Receiver<u64>,
     ) -> Self {
         Self {
             verifier,
             voting_verifier_contract,
+            chain,
             rpc_client,
             latest_block_height,
         }
@@ -132,6 +135,10 @@ where
             event => event.change_context(Error::DeserializeEvent)?,
         };
 
+        if self.chain != source_chain {
+            return Ok(vec![]);
+        }
+
         if !participants.contains(&self.verifier) {
             return Ok(vec![]);
         }
@@ -185,3 +192,227 @@ where
             .expect("vote msg should serialize")])
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use axelar_wasm_std::msg_id::HexTxHash;
+    use axelar_wasm_std::voting::{PollId, Vote};
+    use cosmrs::cosmwasm::MsgExecuteContract;
+    use cosmrs::tx::Msg;
+    use cosmwasm_std::{Event, Uint64};
+    use tokio::sync::watch;
+    use tokio::test as async_test;
+    use voting_verifier::msg::ExecuteMsg;
+    use xrpl_http_client::{
+        Memo, Meta, Transaction, TransactionCommon, TransactionResult, TxResponse,
+    };
+    use xrpl_types::msg::{XRPLMessage, XRPLProverMessage};
+
+    use super::Handler;
+    use crate::event_processor::EventHandler;
+    use crate::handlers::tests::{into_structured_event, participants};
+    use crate::types::TMAddress;
+    use crate::xrpl::json_rpc::MockXRPLClient;
+    use crate::PREFIX;
+
+    const GATEWAY_ACCOUNT: &str = "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh";
+
+    fn prover_message() -> XRPLProverMessage {
+        XRPLProverMessage {
+            tx_id: HexTxHash::new([1; 32]),
+            unsigned_tx_hash: HexTxHash::new([2; 32]),
+        }
+    }
+
+    fn poll_started_event(
+        source_chain: &str,
+        participants: Vec<TMAddress>,
+        expires_at: u64,
+    ) -> Event {
+        Event::new("messages_poll_started")
+            .add_attribute(
+                "poll_id",
+                serde_json::to_string(&PollId::from(Uint64::one())).unwrap(),
+            )
+            .add_attribute("source_chain", source_chain)
+            .add_attribute("source_gateway_address", GATEWAY_ACCOUNT)
+            .add_attribute("confirmation_height", "1")
+            .add_attribute("expires_at", expires_at.to_string())
+            .add_attribute(
+                "participants",
+                serde_json::to_string(&participants).unwrap(),
+            )
+            .add_attribute(
+                "messages",
+                serde_json::to_string(&vec![XRPLMessage::ProverMessage(prover_message())]).unwrap(),
+            )
+    }
+
+    /// A multisig-signed `SignerListSet` transaction of the gateway account, as submitted by the XRPL prover
+    fn signer_list_set_tx(validated: bool) -> TxResponse {
+        let message = prover_message();
+        let memo = |memo_type: &str, memo_data: String| Memo {
+            memo_type: Some(hex::encode(memo_type)),
+            memo_data: Some(hex::encode(memo_data)),
+            memo_format: None,
+        };
+
+        TxResponse {
+            tx: Transaction::SignerListSet(TransactionCommon {
+                account: GATEWAY_ACCOUNT.to_string(),
+                memos: Some(vec![
+                    memo("type", "proof".to_string()),
+                    memo(
+                        "unsigned_tx_hash",
+                        message
+                            .unsigned_tx_hash
+                            .tx_hash_as_hex_no_prefix()
+                            .to_string(),
+                    ),
+                ]),
+                hash: Some(message.tx_id.tx_hash_as_hex_no_prefix().to_uppercase()),
+                validated: Some(validated),
+                meta: Some(Meta {
+                    affected_nodes: vec![],
+                    transaction_index: 0,
+                    transaction_result: TransactionResult::tesSUCCESS,
+                    delivered_amount: None,
+                }),
+                ..TransactionCommon::default()
+            }),
+        }
+    }
+
+    fn handler(
+        verifier: TMAddress,
+        voting_verifier: TMAddress,
+        rpc_client: MockXRPLClient,
+        latest_block_height: u64,
+    ) -> Handler<MockXRPLClient> {
+        let (_, rx) = watch::channel(latest_block_height);
+        Handler::new(
+            verifier,
+            voting_verifier,
+            "xrpl".parse().unwrap(),
+            rpc_client,
+            rx,
+        )
+    }
+
+    fn votes(msgs: Vec<cosmrs::Any>) -> Vec<Vote> {
+        assert_eq!(msgs.len(), 1);
+        let msg = MsgExecuteContract::from_any(&msgs[0]).unwrap();
+        match serde_json::from_slice(&msg.msg).unwrap() {
+            ExecuteMsg::Vote { votes, .. } => votes,
+            _ => panic!("expected vote msg"),
+        }
+    }
+
+    #[async_test]
+    async fn should_vote_correctly_for_validated_signer_list_set() {
+        let mut rpc_client = MockXRPLClient::new();
+        rpc_client
+            .expect_tx()
+            .returning(|_| Ok(Some(signer_list_set_tx(true))));
+
+        let voting_verifier = TMAddress::random(PREFIX);
+        let verifier = TMAddress::random(PREFIX);
+        let event = into_structured_event(
+            poll_started_event("xrpl", participants(5, Some(verifier.clone())), 100),
+            &voting_verifier,
+        );
+
+        let handler = handler(verifier, voting_verifier, rpc_client, 0);
+        let msgs = handler.handle(&event).await.unwrap();
+
+        assert_eq!(votes(msgs), vec![Vote::SucceededOnChain]);
+    }
+
+    #[async_test]
+    async fn should_vote_not_found_if_tx_is_not_in_validated_ledger() {
+        let mut rpc_client = MockXRPLClient::new();
+        rpc_client
+            .expect_tx()
+            .returning(|_| Ok(Some(signer_list_set_tx(false))));
+
+        let voting_verifier = TMAddress::random(PREFIX);
+        let verifier = TMAddress::random(PREFIX);
+        let event = into_structured_event(
+            poll_started_event("xrpl", participants(5, Some(verifier.clone())), 100),
+            &voting_verifier,
+        );
+
+        let handler = handler(verifier, voting_verifier, rpc_client, 0);
+        let msgs = handler.handle(&event).await.unwrap();
+
+        assert_eq!(votes(msgs), vec![Vote::NotFound]);
+    }
+
+    #[async_test]
+    async fn should_vote_not_found_if_tx_does_not_exist() {
+        let mut rpc_client = MockXRPLClient::new();
+        rpc_client.expect_tx().returning(|_| Ok(None));
+
+        let voting_verifier = TMAddress::random(PREFIX);
+        let verifier = TMAddress::random(PREFIX);
+        let event = into_structured_event(
+            poll_started_event("xrpl", participants(5, Some(verifier.clone())), 100),
+            &voting_verifier,
+        );
+
+        let handler = handler(verifier, voting_verifier, rpc_client, 0);
+        let msgs = handler.handle(&event).await.unwrap();
+
+        assert_eq!(votes(msgs), vec![Vote::NotFound]);
+    }
+
+    #[async_test]
+    async fn should_skip_poll_of_other_chain() {
+        let mut rpc_client = MockXRPLClient::new();
+        rpc_client.expect_tx().never();
+
+        let voting_verifier = TMAddress::random(PREFIX);
+        let verifier = TMAddress::random(PREFIX);
+        let event = into_structured_event(
+            poll_started_event("xrpl-evm", participants(5, Some(verifier.clone())), 100),
+            &voting_verifier,
+        );
+
+        let handler = handler(verifier, voting_verifier, rpc_client, 0);
+
+        assert_eq!(handler.handle(&event).await.unwrap(), vec![]);
+    }
+
+    #[async_test]
+    async fn should_skip_if_verifier_is_not_a_participant() {
+        let mut rpc_client = MockXRPLClient::new();
+        rpc_client.expect_tx().never();
+
+        let voting_verifier = TMAddress::random(PREFIX);
+        let event = into_structured_event(
+            poll_started_event("xrpl", participants(5, None), 100),
+            &voting_verifier,
+        );
+
+        let handler = handler(TMAddress::random(PREFIX), voting_verifier, rpc_client, 0);
+
+        assert_eq!(handler.handle(&event).await.unwrap(), vec![]);
+    }
+
+    #[async_test]
+    async fn should_skip_expired_poll() {
+        let mut rpc_client = MockXRPLClient::new();
+        rpc_client.expect_tx().never();
+
+        let voting_verifier = TMAddress::random(PREFIX);
+        let verifier = TMAddress::random(PREFIX);
+        let event = into_structured_event(
+            poll_started_event("xrpl", participants(5, Some(verifier.clone())), 100),
+            &voting_verifier,
+        );
+
+        let handler = handler(verifier, voting_verifier, rpc_client, 100);
+
+        assert_eq!(handler.handle(&event).await.unwrap(), vec![]);
+    }
+}
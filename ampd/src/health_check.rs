@@ -1,5 +1,6 @@
 use std::net::SocketAddrV4;
 
+use axum::extract::State;
 use axum::http::StatusCode;
 use axum::routing::get;
 use axum::{Json, Router};
@@ -9,6 +10,14 @@ use thiserror::Error;
 use tokio_util::sync::CancellationToken;
 use tracing::info;
 
+use crate::handlers::chain_lag::{self, ChainLag};
+use crate::handlers::circuit_breaker::{self, CircuitBreaker};
+use crate::handlers::rpc_divergence::{self, RpcDivergence};
+use crate::handlers::signed_sessions::{self, SignedSessions};
+use crate::handlers::vote_cache::{self, VoteCache};
+use crate::poll_watchdog::{self, PollWatchdog};
+use crate::signer::{self, Signer};
+
 #[derive(Error, Debug)]
 pub enum Error {
     #[error("failed to start the health check server")]
@@ -19,11 +28,43 @@ pub enum Error {
 
 pub struct Server {
     bind_address: SocketAddrV4,
+    metrics_sources: MetricsSources,
+}
+
+#[derive(Clone)]
+struct MetricsSources {
+    signed_sessions: SignedSessions,
+    vote_cache: VoteCache,
+    rpc_divergence: RpcDivergence,
+    chain_lag: ChainLag,
+    circuit_breaker: CircuitBreaker,
+    poll_watchdog: PollWatchdog,
+    signer: Signer,
 }
 
 impl Server {
-    pub fn new(bind_address: SocketAddrV4) -> Self {
-        Self { bind_address }
+    pub fn new(
+        bind_address: SocketAddrV4,
+        signed_sessions: SignedSessions,
+        vote_cache: VoteCache,
+        rpc_divergence: RpcDivergence,
+        chain_lag: ChainLag,
+        circuit_breaker: CircuitBreaker,
+        poll_watchdog: PollWatchdog,
+        signer: Signer,
+    ) -> Self {
+        Self {
+            bind_address,
+            metrics_sources: MetricsSources {
+                signed_sessions,
+                vote_cache,
+                rpc_divergence,
+                chain_lag,
+                circuit_breaker,
+                poll_watchdog,
+                signer,
+            },
+        }
     }
 
     pub async fn run(self, cancel: CancellationToken) -> Result<(), Error> {
@@ -36,7 +77,10 @@ impl Server {
             "starting health check server"
         );
 
-        let app = Router::new().route("/status", get(status));
+        let app = Router::new()
+            .route("/status", get(status))
+            .route("/metrics", get(metrics))
+            .with_state(self.metrics_sources);
         axum::serve(listener, app)
             .with_graceful_shutdown(async move {
                 cancel.cancelled().await;
@@ -47,9 +91,13 @@ impl Server {
     }
 }
 
-// basic handler that responds with a static string
-async fn status() -> (StatusCode, Json<Status>) {
-    (StatusCode::OK, Json(Status { ok: true }))
+// the daemon is only healthy if it is able to sign
+async fn status(State(sources): State<MetricsSources>) -> (StatusCode, Json<Status>) {
+    if sources.signer.is_healthy().await {
+        (StatusCode::OK, Json(Status { ok: true }))
+    } else {
+        (StatusCode::SERVICE_UNAVAILABLE, Json(Status { ok: false }))
+    }
 }
 
 #[derive(Serialize, Deserialize)]
@@ -57,6 +105,32 @@ struct Status {
     ok: bool,
 }
 
+async fn metrics(State(sources): State<MetricsSources>) -> (StatusCode, Json<Metrics>) {
+    (
+        StatusCode::OK,
+        Json(Metrics {
+            signed_sessions: sources.signed_sessions.metrics(),
+            vote_cache: sources.vote_cache.metrics(),
+            rpc_divergence: sources.rpc_divergence.metrics(),
+            chain_lag: sources.chain_lag.metrics(),
+            circuit_breaker: sources.circuit_breaker.metrics(),
+            poll_watchdog: sources.poll_watchdog.metrics(),
+            signer: sources.signer.metrics(),
+        }),
+    )
+}
+
+#[derive(Serialize, Deserialize)]
+struct Metrics {
+    signed_sessions: signed_sessions::Metrics,
+    vote_cache: vote_cache::Metrics,
+    rpc_divergence: rpc_divergence::Metrics,
+    chain_lag: chain_lag::Metrics,
+    circuit_breaker: circuit_breaker::Metrics,
+    poll_watchdog: poll_watchdog::Metrics,
+    signer: signer::Metrics,
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -66,12 +140,27 @@ mod tests {
     use tokio::test as async_test;
 
     use super::*;
+    use crate::signer::MockBackend;
+    use crate::types::TMAddress;
+    use crate::PREFIX;
 
     #[async_test]
     async fn server_lifecycle() {
         let bind_address = test_bind_addr();
 
-        let server = Server::new(bind_address);
+        let mut tofnd = MockBackend::new();
+        tofnd.expect_health_check().returning(|| Ok(()));
+
+        let server = Server::new(
+            bind_address,
+            SignedSessions::default(),
+            VoteCache::default(),
+            RpcDivergence::default(),
+            ChainLag::default(),
+            CircuitBreaker::default(),
+            PollWatchdog::new(TMAddress::random(PREFIX), []),
+            Signer::new(tofnd),
+        );
 
         let cancel = CancellationToken::new();
 
@@ -87,6 +176,21 @@ mod tests {
         let status = response.json::<Status>().await.unwrap();
         assert!(status.ok);
 
+        let response = reqwest::get(format!("http://{}/metrics", bind_address))
+            .await
+            .unwrap();
+        assert_eq!(reqwest::StatusCode::OK, response.status());
+
+        let metrics = response.json::<Metrics>().await.unwrap();
+        assert_eq!(metrics.signed_sessions, signed_sessions::Metrics::default());
+        assert_eq!(metrics.vote_cache, vote_cache::Metrics::default());
+        assert_eq!(metrics.rpc_divergence, rpc_divergence::Metrics::default());
+        assert_eq!(metrics.chain_lag, chain_lag::Metrics::default());
+        assert_eq!(metrics.circuit_breaker, circuit_breaker::Metrics::default());
+        assert_eq!(metrics.poll_watchdog, poll_watchdog::Metrics::default());
+        assert!(metrics.signer.tofnd.healthy);
+        assert_eq!(metrics.signer.pkcs11, None);
+
         cancel.cancel();
 
         tokio::time::sleep(Duration::from_millis(100)).await;
@@ -97,6 +201,41 @@ mod tests {
         };
     }
 
+    #[async_test]
+    async fn status_should_be_unavailable_if_signer_is_unhealthy() {
+        let bind_address = test_bind_addr();
+
+        let mut tofnd = MockBackend::new();
+        tofnd.expect_health_check().returning(|| {
+            Err(error_stack::report!(
+                crate::tofnd::error::Error::KeyPresenceFailed
+            ))
+        });
+
+        let server = Server::new(
+            bind_address,
+            SignedSessions::default(),
+            VoteCache::default(),
+            RpcDivergence::default(),
+            ChainLag::default(),
+            CircuitBreaker::default(),
+            PollWatchdog::new(TMAddress::random(PREFIX), []),
+            Signer::new(tofnd),
+        );
+
+        let cancel = CancellationToken::new();
+        tokio::spawn(server.run(cancel.clone()));
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        let response = reqwest::get(format!("http://{}/status", bind_address))
+            .await
+            .unwrap();
+        assert_eq!(reqwest::StatusCode::SERVICE_UNAVAILABLE, response.status());
+        assert!(!response.json::<Status>().await.unwrap().ok);
+
+        cancel.cancel();
+    }
+
     fn test_bind_addr() -> SocketAddrV4 {
         let listener = TcpListener::bind("127.0.0.1:0").unwrap();
 
@@ -1,12 +1,12 @@
+pub mod replay;
+
 use std::fmt::Debug;
 
 use error_stack::Report;
-use ethers_providers::{Http, JsonRpcClient, ProviderError};
+use ethers_providers::{JsonRpcClient, ProviderError};
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 
-use crate::url::Url;
-
 type Result<T> = error_stack::Result<T, ProviderError>;
 
 pub struct Client<P>
@@ -36,9 +36,3 @@ where
             .map_err(Report::from)
     }
 }
-
-impl Client<Http> {
-    pub fn new_http(url: &Url, client: reqwest::Client) -> Self {
-        Client::new(Http::new_with_client(url, client))
-    }
-}
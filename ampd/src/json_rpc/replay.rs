@@ -0,0 +1,253 @@
+use std::fmt::Debug;
+use std::fs::File;
+use std::io;
+use std::path::Path;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use async_trait::async_trait;
+#[cfg(test)]
+use ethers_providers::HttpClientError;
+use ethers_providers::{JsonRpcClient, JsonRpcError, ProviderError, RpcError};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Result(Value),
+    Error(JsonRpcError),
+}
+
+/// A single JSON-RPC request and the response of the endpoint
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct Interaction {
+    pub method: String,
+    pub params: Value,
+    #[serde(flatten)]
+    pub outcome: Outcome,
+}
+
+/// JSON-RPC interactions recorded from a live endpoint, so they can be replayed without network access
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct Cassette {
+    pub interactions: Vec<Interaction>,
+}
+
+impl Cassette {
+    #[cfg(test)]
+    pub fn load(path: impl AsRef<Path>) -> Self {
+        let file = File::open(path).expect("cassette should exist");
+        serde_json::from_reader(file).expect("cassette should be valid")
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let file = File::create(path)?;
+        serde_json::to_writer_pretty(file, self).map_err(io::Error::from)
+    }
+
+    /// Returns the first recorded response to the request
+    pub fn outcome(&self, method: &str, params: &Value) -> Option<&Outcome> {
+        self.interactions
+            .iter()
+            .find(|interaction| interaction.method == method && interaction.params == *params)
+            .map(|interaction| &interaction.outcome)
+    }
+
+    /// Records the response to the request. Repeated requests are only recorded once,
+    /// because handlers commonly send the same request more than once
+    fn record(&mut self, method: &str, params: Value, outcome: Outcome) {
+        if self.outcome(method, &params).is_none() {
+            self.interactions.push(Interaction {
+                method: method.to_string(),
+                params,
+                outcome,
+            });
+        }
+    }
+}
+
+/// Collects the interactions of all recorders created from it into a single cassette
+#[derive(Clone, Debug, Default)]
+pub struct Recording(Arc<Mutex<Cassette>>);
+
+impl Recording {
+    pub fn record<P>(&self, provider: P) -> Recorder<P>
+    where
+        P: JsonRpcClient,
+    {
+        Recorder {
+            provider,
+            recording: self.clone(),
+        }
+    }
+
+    pub fn cassette(&self) -> Cassette {
+        self.lock().clone()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Cassette> {
+        self.0
+            .lock()
+            .expect("recording lock should not be poisoned")
+    }
+}
+
+/// Forwards requests to the wrapped provider and records every response, including JSON-RPC errors
+#[derive(Debug)]
+pub struct Recorder<P> {
+    provider: P,
+    recording: Recording,
+}
+
+#[async_trait]
+impl<P> JsonRpcClient for Recorder<P>
+where
+    P: JsonRpcClient,
+{
+    type Error = ProviderError;
+
+    async fn request<T, R>(&self, method: &str, params: T) -> std::result::Result<R, Self::Error>
+    where
+        T: Debug + Serialize + Send + Sync,
+        R: DeserializeOwned + Send,
+    {
+        let params_value = serde_json::to_value(&params).map_err(ProviderError::SerdeJson)?;
+
+        match self.provider.request::<T, Value>(method, params).await {
+            Ok(result) => {
+                self.recording
+                    .lock()
+                    .record(method, params_value, Outcome::Result(result.clone()));
+                serde_json::from_value(result).map_err(ProviderError::SerdeJson)
+            }
+            Err(err) => {
+                if let Some(err) = err.as_error_response() {
+                    self.recording
+                        .lock()
+                        .record(method, params_value, Outcome::Error(err.clone()));
+                }
+                Err(err.into())
+            }
+        }
+    }
+}
+
+/// Answers requests with the responses recorded in a cassette. Requests that were not recorded fail
+#[cfg(test)]
+#[derive(Clone, Debug)]
+pub struct Replay {
+    cassette: Arc<Cassette>,
+}
+
+#[cfg(test)]
+impl Replay {
+    pub fn new(cassette: Cassette) -> Self {
+        Self {
+            cassette: Arc::new(cassette),
+        }
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Self {
+        Self::new(Cassette::load(path))
+    }
+}
+
+#[cfg(test)]
+#[async_trait]
+impl JsonRpcClient for Replay {
+    type Error = ProviderError;
+
+    async fn request<T, R>(&self, method: &str, params: T) -> std::result::Result<R, Self::Error>
+    where
+        T: Debug + Serialize + Send + Sync,
+        R: DeserializeOwned + Send,
+    {
+        let params = serde_json::to_value(&params).map_err(ProviderError::SerdeJson)?;
+
+        match self.cassette.outcome(method, &params) {
+            Some(Outcome::Result(result)) => {
+                serde_json::from_value(result.clone()).map_err(ProviderError::SerdeJson)
+            }
+            Some(Outcome::Error(err)) => Err(HttpClientError::JsonRpcError(err.clone()).into()),
+            None => Err(ProviderError::CustomError(format!(
+                "no recorded response for {} with params {}",
+                method, params
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ethers_core::types::U64;
+    use ethers_providers::{MockProvider, MockResponse};
+    use serde_json::json;
+
+    use super::*;
+    use crate::json_rpc::Client;
+
+    #[tokio::test]
+    async fn replay_should_return_recorded_responses() {
+        let provider = MockProvider::new();
+        provider.push_response(MockResponse::Error(JsonRpcError {
+            code: -32000,
+            message: "header not found".to_string(),
+            data: None,
+        }));
+        provider.push(U64::from(20)).unwrap();
+
+        let recording = Recording::default();
+        let client = Client::new(recording.record(provider));
+
+        let block_number: U64 = client.request("eth_blockNumber", ()).await.unwrap();
+        assert_eq!(block_number, U64::from(20));
+        assert!(client
+            .request::<_, Value>("eth_getBlockByNumber", ["0x15", "false"])
+            .await
+            .is_err());
+
+        let cassette = recording.cassette();
+        assert_eq!(cassette.interactions.len(), 2);
+
+        let client = Client::new(Replay::new(cassette));
+
+        let block_number: U64 = client.request("eth_blockNumber", ()).await.unwrap();
+        assert_eq!(block_number, U64::from(20));
+
+        let err = client
+            .request::<_, Value>("eth_getBlockByNumber", ["0x15", "false"])
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.current_context()
+                .as_error_response()
+                .map(|err| err.message.as_str()),
+            Some("header not found")
+        );
+    }
+
+    #[tokio::test]
+    async fn replay_should_fail_for_unrecorded_requests() {
+        let client = Client::new(Replay::new(Cassette {
+            interactions: vec![Interaction {
+                method: "eth_getTransactionReceipt".to_string(),
+                params: json!(["0x01"]),
+                outcome: Outcome::Result(Value::Null),
+            }],
+        }));
+
+        assert!(client
+            .request::<_, Value>("eth_getTransactionReceipt", ["0x01"])
+            .await
+            .is_ok());
+        assert!(client
+            .request::<_, Value>("eth_getTransactionReceipt", ["0x02"])
+            .await
+            .is_err());
+        assert!(client
+            .request::<_, Value>("eth_blockNumber", ())
+            .await
+            .is_err());
+    }
+}
@@ -1,32 +1,46 @@
 use std::pin::Pin;
 use std::time::Duration;
 
+use async_trait::async_trait;
 use asyncutil::task::{CancellableTask, TaskError, TaskGroup};
+use axelar_wasm_std::nonempty;
 use block_height_monitor::BlockHeightMonitor;
-use broadcaster::Broadcaster;
-use broadcaster_v2::MsgQueue;
+use broadcaster_v2::{BroadcasterClient, MsgQueue, MsgQueueClient, Producer};
 use cosmos::CosmosGrpcClient;
-use error_stack::{FutureExt, Result, ResultExt};
+use cosmrs::Any;
+use error_stack::{ensure, report, FutureExt, Result, ResultExt};
+use ethers_providers::{Http, JsonRpcClient};
+use event_archive::EventArchive;
 use event_processor::EventHandler;
 use event_sub::EventSub;
 use evm::finalizer::{pick, Finalization};
 use evm::json_rpc::EthereumClient;
+use handler_supervisor::{HandlerFactory, HandlerSupervisor, HandlerTask};
+use handlers::chain_lag::{ChainLag, EvmBlock, SuiCheckpoint};
+use handlers::circuit_breaker::CircuitBreaker;
+use handlers::rpc_divergence::{self, RpcDivergence};
+use handlers::signed_sessions::SignedSessions;
+use handlers::vote_cache::VoteCache;
+use itertools::Itertools;
 use multiversx_sdk::gateway::GatewayProxy;
-use queue::queued_broadcaster::QueuedBroadcaster;
+use poll_watchdog::PollWatchdog;
+use report::LoggableError;
 use router_api::ChainName;
+use signer::Signer;
 use solana_client::nonblocking::rpc_client::RpcClient;
 use solana_sdk::commitment_config::CommitmentConfig;
 use starknet_providers::jsonrpc::HttpTransport;
 use thiserror::Error;
-use tofnd::grpc::{Multisig, MultisigClient};
+use tofnd::grpc::Multisig;
+use tokio::select;
 use tokio::signal::unix::{signal, SignalKind};
-use tokio::sync::mpsc;
-use tokio::time::interval;
+use tokio::sync::{mpsc, watch};
 use tokio_util::sync::CancellationToken;
-use tracing::info;
+use tracing::{debug, error, info};
 use types::{CosmosPublicKey, TMAddress};
+use valuable::Valuable;
 
-use crate::config::Config;
+use crate::config::{Config, ContractsConfig, ReadOnlyConfig};
 
 mod asyncutil;
 mod block_height_monitor;
@@ -35,16 +49,22 @@ mod broadcaster;
 mod broadcaster_v2;
 pub mod commands;
 pub mod config;
+mod contract_compatibility;
 mod cosmos;
+mod event_archive;
 mod event_processor;
 mod event_sub;
 mod evm;
 mod grpc;
+mod handler_supervisor;
 mod handlers;
 mod health_check;
 mod json_rpc;
 mod mvx;
-mod queue;
+mod poll_watchdog;
+mod signer;
+#[cfg(test)]
+mod simulation;
 mod solana;
 mod starknet;
 mod stellar;
@@ -56,16 +76,27 @@ mod url;
 mod xrpl;
 
 use crate::asyncutil::future::RetryPolicy;
-use crate::broadcaster::confirm_tx::TxConfirmer;
+use crate::broadcaster::confirm_tx::{self, TxConfirmer};
+use crate::url::Url;
 
 const PREFIX: &str = "axelar";
 const DEFAULT_RPC_TIMEOUT: Duration = Duration::from_secs(3);
+/// The Sui handlers are not configured with a chain name, so their circuit and rewards pools are keyed by this one
+const SUI_CHAIN_NAME: &str = "sui";
 
-pub async fn run(cfg: Config) -> Result<(), Error> {
-    prepare_app(cfg).await?.run().await
+/// Loads the current config from its sources, so parts of it can be reloaded while the daemon is running
+pub type ConfigLoader = Box<dyn Fn() -> Result<Config, Error> + Send + Sync>;
+
+pub async fn run(cfg: Config, config_loader: ConfigLoader) -> Result<(), Error> {
+    prepare_app(cfg, config_loader).await?.run().await
 }
 
-async fn prepare_app(cfg: Config) -> Result<App<impl Broadcaster>, Error> {
+async fn prepare_app(cfg: Config, config_loader: ConfigLoader) -> Result<App, Error> {
+    let mut wasm_client = commands::wasm_query_client(&cfg).await?;
+    contract_compatibility::check(&cfg, &mut wasm_client)
+        .await
+        .change_context(Error::ContractCompatibility)?;
+
     let Config {
         tm_jsonrpc,
         tm_grpc,
@@ -74,81 +105,250 @@ async fn prepare_app(cfg: Config) -> Result<App<impl Broadcaster>, Error> {
         handlers,
         tofnd_config,
         event_processor,
-        service_registry: _service_registry,
-        rewards: _rewards,
+        service_registry,
+        rewards,
         health_check_bind_addr,
         grpc: grpc_config,
+        signed_sessions,
+        vote_cache,
+        rpc_divergence,
+        chain_lag,
+        circuit_breaker,
+        event_buffer,
+        block_processing,
+        event_archive,
+        contract_compatibility: _contract_compatibility,
+        contracts:
+            ContractsConfig {
+                router,
+                multisig,
+                coordinator,
+            },
+        pkcs11,
+        read_only,
     } = cfg;
 
     let tm_client = tendermint_rpc::HttpClient::new(tm_jsonrpc.to_string().as_str())
         .change_context(Error::Connection)
         .attach_printable(tm_jsonrpc.clone())?;
-    let multisig_client = MultisigClient::new(
-        tofnd_config.party_uid,
-        tofnd_config.url.as_str(),
-        tofnd_config.timeout,
-    )
-    .await
-    .change_context(Error::Connection)
-    .attach_printable(tofnd_config.url)?;
+    let signer = Signer::connect(&tofnd_config, pkcs11.as_ref())
+        .await
+        .change_context(Error::Connection)?;
     let block_height_monitor = BlockHeightMonitor::connect(tm_client.clone())
         .await
         .change_context(Error::Connection)
         .attach_printable(tm_jsonrpc)?;
-    let pub_key = multisig_client
-        .keygen(&tofnd_config.key_uid, tofnd::Algorithm::Ecdsa)
+    let event_buffer = event_sub::buffer::EventBuffer::load(event_buffer)
         .await
-        .change_context(Error::Tofnd)?;
-    let pub_key = CosmosPublicKey::try_from(pub_key).change_context(Error::Tofnd)?;
+        .change_context(Error::EventBuffer)?;
     let (event_publisher, event_subscriber) =
         event_sub::EventPublisher::new(tm_client.clone(), event_processor.stream_buffer_size);
+    let event_publisher = event_publisher.with_block_processing(block_processing);
+    let (contract_filter, contracts) =
+        if event_processor.filter_by_contracts || event_processor.filter_pushdown {
+            let (contract_filter, contracts) = watch::channel(handler_contracts(&handlers));
+            (Some(contract_filter), Some(contracts))
+        } else {
+            (None, None)
+        };
+    // gRPC subscribers choose their own filters, so only the handlers' subscriptions are filtered by contract.
+    // If the filter is pushed down to the node, the handlers get a publisher of their own, which owns the event buffer
+    let (event_publisher, handler_event_publisher, handler_event_subscriber) = match contracts {
+        Some(contracts) if event_processor.filter_pushdown => {
+            let (handler_event_publisher, handler_event_subscriber) =
+                event_sub::EventPublisher::new(
+                    tm_client.clone(),
+                    event_processor.stream_buffer_size,
+                );
+            let handler_event_publisher = handler_event_publisher
+                .with_event_buffer(event_buffer)
+                .with_block_processing(block_processing)
+                .filter_pushdown(contracts.clone());
+
+            (
+                event_publisher,
+                Some(handler_event_publisher),
+                handler_event_subscriber.filter_by_contracts(contracts),
+            )
+        }
+        Some(contracts) => (
+            event_publisher.with_event_buffer(event_buffer),
+            None,
+            event_subscriber.filter_by_contracts(contracts),
+        ),
+        None => (
+            event_publisher.with_event_buffer(event_buffer),
+            None,
+            event_subscriber.clone(),
+        ),
+    };
     let cosmos_client = cosmos::CosmosGrpcClient::new(tm_grpc.as_str(), tm_grpc_timeout)
         .await
         .change_context(Error::Connection)
         .attach_printable(tm_grpc.clone())?;
+    let handler_weight = broadcast.handler_weight;
+    let (verifier, broadcasting) = match read_only {
+        Some(ReadOnlyConfig { verifier }) => {
+            info!(%verifier, "running in read-only mode, nothing is broadcast");
+            (verifier, None)
+        }
+        None => {
+            let broadcasting =
+                prepare_broadcasting(&signer, cosmos_client.clone(), &tofnd_config, broadcast)
+                    .await?;
+            (broadcasting.verifier.clone(), Some(broadcasting))
+        }
+    };
+    let (handler_types, handler_types_receiver) = watch::channel(handler_types(&handlers));
+    let grpc_server = grpc::Server::builder()
+        .config(grpc_config)
+        .event_sub(event_subscriber.clone())
+        .cosmos_client(cosmos_client.clone())
+        .contracts(grpc::Contracts {
+            router,
+            multisig,
+            service_registry: service_registry.cosmwasm_contract,
+            rewards: rewards.cosmwasm_contract,
+            coordinator,
+        })
+        .msg_queue_client(
+            broadcasting
+                .as_ref()
+                .map(|broadcasting| broadcasting.msg_queue_client.clone()),
+        )
+        .handlers(handler_types_receiver)
+        .build();
+
+    let signed_sessions =
+        SignedSessions::load(signed_sessions).change_context(Error::SignedSessions)?;
+    let vote_cache = VoteCache::new(vote_cache);
+    let rpc_divergence = RpcDivergence::new(rpc_divergence);
+    let chain_lag = ChainLag::new(chain_lag);
+    let circuit_breaker = CircuitBreaker::new(circuit_breaker);
+    let poll_watchdog = PollWatchdog::new(
+        verifier.clone(),
+        handlers
+            .iter()
+            .flat_map(handlers::config::Config::contracts),
+    );
+
+    let health_check_server = health_check::Server::new(
+        health_check_bind_addr,
+        signed_sessions.clone(),
+        vote_cache.clone(),
+        rpc_divergence.clone(),
+        chain_lag.clone(),
+        circuit_breaker.clone(),
+        poll_watchdog.clone(),
+        signer.clone(),
+    );
+
+    let handler_factory = EventHandlerFactory {
+        verifier,
+        signer,
+        latest_block_height: block_height_monitor.latest_block_height(),
+        signed_sessions,
+        vote_cache,
+        rpc_divergence,
+        chain_lag,
+        circuit_breaker,
+        broadcaster: broadcasting
+            .as_ref()
+            .map(|broadcasting| broadcasting.msg_queue_client.clone()),
+        handler_weight,
+        poll_watchdog: poll_watchdog.clone(),
+        event_subscriber: handler_event_subscriber,
+        event_processor_config: event_processor,
+        evm_rpc: HttpEvmRpc,
+    };
+    let event_archive_handler = EventArchive::new(event_archive)
+        .map(|archive| handler_factory.task("event-archive", event_archive::Handler::new(archive)));
+    let key_rotation = broadcasting.as_ref().map(|broadcasting| KeyRotation {
+        signer: handler_factory.signer.clone(),
+        cosmos_client: cosmos_client.clone(),
+        key_uid: tofnd_config.key_uid.clone(),
+        verifier: broadcasting.verifier.clone(),
+        broadcast: broadcasting.broadcast.clone(),
+        rotations: broadcasting.key_rotations.clone(),
+    });
+    let handler_reloader = HandlerReloader {
+        config_loader,
+        contract_filter,
+        handler_types,
+        poll_watchdog,
+        key_rotation,
+    };
+
+    Ok(App::new(
+        event_publisher,
+        handler_event_publisher,
+        broadcasting,
+        block_height_monitor,
+        health_check_server,
+        grpc_server,
+        handler_factory,
+        handlers,
+        handler_reloader,
+        event_archive_handler,
+    ))
+}
+
+/// Everything ampd needs to broadcast with its broadcaster account, which read-only deployments don't have.
+/// The handlers and the gRPC clients share the batches of a single msg queue, each of them as its own producer
+struct Broadcasting {
+    verifier: TMAddress,
+    broadcast: broadcaster::Config,
+    tx_confirmer: TxConfirmer<CosmosGrpcClient>,
+    tx_hashes: mpsc::Receiver<String>,
+    broadcaster_task:
+        broadcaster_v2::BroadcasterTask<cosmos::CosmosGrpcClient, Pin<Box<MsgQueue>>, Signer>,
+    msg_queue_client: MsgQueueClient<cosmos::CosmosGrpcClient>,
+    key_rotations: mpsc::Sender<broadcaster_v2::NewKey>,
+}
+
+async fn prepare_broadcasting(
+    signer: &Signer,
+    cosmos_client: CosmosGrpcClient,
+    tofnd_config: &tofnd::Config,
+    broadcast: broadcaster::Config,
+) -> Result<Broadcasting, Error> {
+    let pub_key = broadcaster_pub_key(signer, &tofnd_config.key_uid).await?;
+    validate_fee_payer(
+        signer,
+        cosmos_client.clone(),
+        &tofnd_config.key_uid,
+        pub_key,
+        broadcast.clone(),
+    )
+    .await?;
+
     let broadcaster = broadcaster_v2::Broadcaster::new(
         cosmos_client.clone(),
         broadcast.chain_id.clone(),
         pub_key,
     )
     .await
-    .change_context(Error::Broadcaster)?;
+    .change_context(Error::Broadcaster)?
+    .with_authz_granter(broadcast.authz_granter.clone())
+    .await;
     let (msg_queue, msg_queue_client) = broadcaster_v2::MsgQueue::new_msg_queue_and_client(
         broadcaster.clone(),
         broadcast.queue_cap,
         broadcast.batch_gas_limit,
         broadcast.broadcast_interval,
     );
-    let grpc_server = grpc::Server::builder()
-        .config(grpc_config)
-        .event_sub(event_subscriber.clone())
-        .msg_queue_client(msg_queue_client)
-        .build();
+    let (key_rotations, key_rotation_receiver) = mpsc::channel(1);
+    let (tx_hash_sender, tx_hashes) = mpsc::channel(1000);
     let broadcaster_task = broadcaster_v2::BroadcasterTask::builder()
         .broadcaster(broadcaster)
         .msg_queue(msg_queue)
-        .signer(multisig_client.clone())
+        .signer(signer.clone())
         .key_id(tofnd_config.key_uid.clone())
         .gas_adjustment(broadcast.gas_adjustment)
         .gas_price(broadcast.gas_price.clone())
+        .key_rotations(key_rotation_receiver)
+        .tx_hashes(tx_hash_sender)
         .build();
-    let broadcaster = broadcaster::UnvalidatedBasicBroadcaster::builder()
-        .address_prefix(PREFIX.to_string())
-        .client(cosmos_client.clone())
-        .signer(multisig_client.clone())
-        .pub_key((tofnd_config.key_uid, pub_key))
-        .config(broadcast.clone())
-        .build()
-        .validate_fee_denomination()
-        .await
-        .change_context(Error::Broadcaster)?;
-
-    let broadcaster = QueuedBroadcaster::new(
-        broadcaster,
-        broadcast.batch_gas_limit,
-        broadcast.queue_cap,
-        interval(broadcast.broadcast_interval),
-    );
 
     let tx_confirmer = TxConfirmer::new(
         cosmos_client,
@@ -158,26 +358,71 @@ async fn prepare_app(cfg: Config) -> Result<App<impl Broadcaster>, Error> {
         },
     );
 
-    let health_check_server = health_check::Server::new(health_check_bind_addr);
-
-    let verifier: TMAddress = pub_key
-        .account_id(PREFIX)
-        .expect("failed to convert to account identifier")
-        .into();
-
-    App::new(
-        event_publisher,
-        event_subscriber,
-        broadcaster,
+    Ok(Broadcasting {
+        verifier: verifier_address(&pub_key, &broadcast),
+        broadcast,
         tx_confirmer,
-        multisig_client,
-        block_height_monitor,
-        health_check_server,
-        grpc_server,
+        tx_hashes,
         broadcaster_task,
-    )
-    .configure_handlers(verifier, handlers, event_processor)
-    .await
+        msg_queue_client,
+        key_rotations,
+    })
+}
+
+async fn broadcaster_pub_key(signer: &Signer, key_uid: &str) -> Result<CosmosPublicKey, Error> {
+    let pub_key = signer
+        .keygen(key_uid, tofnd::Algorithm::Ecdsa)
+        .await
+        .change_context(Error::Tofnd)?;
+
+    CosmosPublicKey::try_from(pub_key).change_context(Error::Tofnd)
+}
+
+/// Makes sure the fee payer, i.e. the authz granter or the broadcaster account itself, can pay the broadcast fees
+async fn validate_fee_payer(
+    signer: &Signer,
+    cosmos_client: CosmosGrpcClient,
+    key_uid: &str,
+    pub_key: CosmosPublicKey,
+    broadcast: broadcaster::Config,
+) -> Result<(), Error> {
+    broadcaster::UnvalidatedBasicBroadcaster::builder()
+        .address_prefix(PREFIX.to_string())
+        .client(cosmos_client)
+        .signer(signer.clone())
+        .pub_key((key_uid.to_string(), pub_key))
+        .config(broadcast)
+        .build()
+        .validate_fee_denomination()
+        .await
+        .change_context(Error::Broadcaster)
+        .map(|_| ())
+}
+
+/// When broadcasting as an authz grantee, the verifier is the granter account
+fn verifier_address(pub_key: &CosmosPublicKey, broadcast: &broadcaster::Config) -> TMAddress {
+    broadcast.authz_granter.clone().unwrap_or_else(|| {
+        pub_key
+            .account_id(PREFIX)
+            .expect("failed to convert to account identifier")
+            .into()
+    })
+}
+
+fn handler_contracts(handler_configs: &[handlers::config::Config]) -> Vec<TMAddress> {
+    handler_configs
+        .iter()
+        .flat_map(handlers::config::Config::contracts)
+        .unique()
+        .collect()
+}
+
+fn handler_types(handler_configs: &[handlers::config::Config]) -> Vec<String> {
+    handler_configs
+        .iter()
+        .map(|config| config.handler_type().to_string())
+        .unique()
+        .collect()
 }
 
 async fn check_finalizer<C>(
@@ -196,423 +441,700 @@ where
     Ok(())
 }
 
-struct App<T>
+/// Opens the JSON-RPC connections of the EVM handlers, so simulations can replay recorded responses instead
+trait EvmRpc {
+    type Provider: JsonRpcClient + Send + Sync + 'static;
+
+    fn provider(&self, url: &Url, rpc_timeout: Option<Duration>) -> Result<Self::Provider, Error>;
+}
+
+struct HttpEvmRpc;
+
+impl EvmRpc for HttpEvmRpc {
+    type Provider = Http;
+
+    fn provider(&self, url: &Url, rpc_timeout: Option<Duration>) -> Result<Http, Error> {
+        http_provider(url, rpc_timeout)
+    }
+}
+
+fn http_provider(url: &Url, rpc_timeout: Option<Duration>) -> Result<Http, Error> {
+    let http_client = reqwest::ClientBuilder::new()
+        .connect_timeout(rpc_timeout.unwrap_or(DEFAULT_RPC_TIMEOUT))
+        .timeout(rpc_timeout.unwrap_or(DEFAULT_RPC_TIMEOUT))
+        .build()
+        .change_context(Error::Connection)?;
+
+    Ok(Http::new_with_client(url, http_client))
+}
+
+/// Creates the event handler tasks from their configs. Each handler consumes its own subscription to the event stream
+struct EventHandlerFactory<S = Signer, C = CosmosGrpcClient, R = HttpEvmRpc>
 where
-    T: Broadcaster,
+    C: cosmos::CosmosClient,
 {
-    event_publisher: event_sub::EventPublisher<tendermint_rpc::HttpClient>,
+    verifier: TMAddress,
+    signer: S,
+    latest_block_height: watch::Receiver<u64>,
+    signed_sessions: SignedSessions,
+    vote_cache: VoteCache,
+    rpc_divergence: RpcDivergence,
+    chain_lag: ChainLag,
+    circuit_breaker: CircuitBreaker,
+    /// not set in read-only mode, see [ReadOnlyBroadcaster]
+    broadcaster: Option<MsgQueueClient<C>>,
+    handler_weight: nonempty::Usize,
+    poll_watchdog: PollWatchdog,
     event_subscriber: event_sub::EventSubscriber,
-    event_processor: TaskGroup<event_processor::Error>,
-    broadcaster: QueuedBroadcaster<T>,
-    tx_confirmer: TxConfirmer<CosmosGrpcClient>,
-    multisig_client: MultisigClient,
-    block_height_monitor: BlockHeightMonitor<tendermint_rpc::HttpClient>,
-    health_check_server: health_check::Server,
-    grpc_server: grpc::Server,
-    broadcaster_task: broadcaster_v2::BroadcasterTask<
-        cosmos::CosmosGrpcClient,
-        Pin<Box<MsgQueue>>,
-        MultisigClient,
-    >,
+    event_processor_config: event_processor::Config,
+    evm_rpc: R,
 }
 
-impl<T> App<T>
+impl<S, C, R> EventHandlerFactory<S, C, R>
 where
-    T: Broadcaster + Send + Sync + 'static,
+    C: cosmos::CosmosClient + Clone + Send + Sync + 'static,
 {
-    #[allow(clippy::too_many_arguments)]
-    fn new(
-        event_publisher: event_sub::EventPublisher<tendermint_rpc::HttpClient>,
-        event_subscriber: event_sub::EventSubscriber,
-        broadcaster: QueuedBroadcaster<T>,
-        tx_confirmer: TxConfirmer<CosmosGrpcClient>,
-        multisig_client: MultisigClient,
-        block_height_monitor: BlockHeightMonitor<tendermint_rpc::HttpClient>,
-        health_check_server: health_check::Server,
-        grpc_server: grpc::Server,
-        broadcaster_task: broadcaster_v2::BroadcasterTask<
-            cosmos::CosmosGrpcClient,
-            Pin<Box<MsgQueue>>,
-            MultisigClient,
-        >,
-    ) -> Self {
-        let event_processor = TaskGroup::new("event handler");
+    fn task<L, H>(&self, label: L, handler: H) -> HandlerTask
+    where
+        L: AsRef<str>,
+        H: EventHandler + Send + Sync + 'static,
+    {
+        let label = label.as_ref().to_string();
 
-        Self {
-            event_publisher,
-            event_subscriber,
-            event_processor,
-            broadcaster,
-            tx_confirmer,
-            multisig_client,
-            block_height_monitor,
-            health_check_server,
-            grpc_server,
-            broadcaster_task,
+        match &self.broadcaster {
+            // each handler is its own producer, so a busy handler can't hold back the messages of the others
+            Some(client) => {
+                let broadcaster =
+                    client.with_producer(Producer::new(label.clone(), self.handler_weight));
+                self.consume_events(label, handler, broadcaster)
+            }
+            None => self.consume_events(label, handler, ReadOnlyBroadcaster),
         }
     }
 
-    async fn configure_handlers(
-        mut self,
-        verifier: TMAddress,
-        handler_configs: Vec<handlers::config::Config>,
-        event_processor_config: event_processor::Config,
-    ) -> Result<App<T>, Error> {
-        for config in handler_configs {
-            let task = match config {
-                handlers::config::Config::EvmMsgVerifier {
-                    chain,
-                    cosmwasm_contract,
-                    rpc_timeout,
-                } => {
-                    let rpc_client = json_rpc::Client::new_http(
-                        &chain.rpc_url,
-                        reqwest::ClientBuilder::new()
-                            .connect_timeout(rpc_timeout.unwrap_or(DEFAULT_RPC_TIMEOUT))
-                            .timeout(rpc_timeout.unwrap_or(DEFAULT_RPC_TIMEOUT))
-                            .build()
-                            .change_context(Error::Connection)?,
+    fn consume_events<H, B>(&self, label: String, handler: H, broadcaster: B) -> HandlerTask
+    where
+        H: EventHandler + Send + Sync + 'static,
+        B: BroadcasterClient + Send + Sync + 'static,
+    {
+        let poll_watchdog = self.poll_watchdog.clone();
+        let sub = self.event_subscriber.subscribe();
+        let event_processor_config = self.event_processor_config.clone();
+
+        CancellableTask::create(move |token| {
+            event_processor::consume_events(
+                label,
+                handler,
+                broadcaster,
+                poll_watchdog,
+                sub,
+                event_processor_config,
+                token,
+            )
+        })
+    }
+
+    /// Signing handlers submit signatures that nobody else can, so in read-only mode they are replaced by a task that
+    /// never signs instead of signing and dropping the signatures
+    fn signing_task<L, H>(&self, label: L, handler: impl FnOnce() -> H) -> HandlerTask
+    where
+        L: AsRef<str>,
+        H: EventHandler + Send + Sync + 'static,
+    {
+        match self.broadcaster {
+            Some(_) => self.task(label, handler()),
+            None => {
+                info!(
+                    handler = label.as_ref(),
+                    "read-only mode, the signing handler is disabled"
+                );
+                CancellableTask::create(|token| async move {
+                    token.cancelled().await;
+                    Ok(())
+                })
+            }
+        }
+    }
+
+    /// Message verifiers of chains whose RPC endpoint reports the latest height don't vote in polls observed at a height
+    /// the endpoint hasn't reached yet
+    fn lag_checked<H, L>(
+        &self,
+        handler: H,
+        latest_height: L,
+    ) -> handlers::chain_lag::Handler<H, L> {
+        handlers::chain_lag::Handler::new(
+            handler,
+            self.verifier.clone(),
+            latest_height,
+            self.chain_lag.clone(),
+        )
+    }
+
+    /// Voting handlers skip polls that look expired when they are handled, so they are wrapped to still vote in
+    /// polls whose expiry gets extended later. They are also wrapped to abstain in polls they keep failing to verify
+    fn voting_task<L, H>(&self, label: L, handler: H) -> HandlerTask
+    where
+        L: AsRef<str>,
+        H: EventHandler + Send + Sync + 'static,
+    {
+        let handler = handlers::inconclusive_evidence::Handler::new(
+            handler,
+            self.verifier.clone(),
+            self.latest_block_height.clone(),
+            RetryPolicy::RepeatConstant {
+                sleep: self.event_processor_config.retry_delay,
+                max_attempts: self.event_processor_config.retry_max_attempts,
+            },
+        );
+
+        self.task(
+            label,
+            handlers::extended_polls::Handler::new(
+                handler,
+                self.verifier.clone(),
+                self.latest_block_height.clone(),
+            ),
+        )
+    }
+}
+
+#[async_trait]
+impl<S, C, R> HandlerFactory for EventHandlerFactory<S, C, R>
+where
+    S: Multisig + Clone + Send + Sync + 'static,
+    C: cosmos::CosmosClient + Clone + Send + Sync + 'static,
+    R: EvmRpc + Send + Sync,
+{
+    async fn create(&self, config: handlers::config::Config) -> Result<HandlerTask, Error> {
+        let task = match config {
+            handlers::config::Config::EvmMsgVerifier {
+                chain,
+                cosmwasm_contract,
+                rpc_timeout,
+                cross_check_rpc_urls,
+            } => {
+                let rpc_client = json_rpc::Client::new(self.circuit_breaker.guard(
+                    chain.name.clone(),
+                    self.evm_rpc.provider(&chain.rpc_url, rpc_timeout)?,
+                ));
+
+                check_finalizer(&chain.name, &chain.finalization, &rpc_client).await?;
+
+                let mut cross_check_clients = vec![];
+                for url in &cross_check_rpc_urls {
+                    // failing cross check endpoints must not open the circuit of the chain's main endpoint
+                    let client = json_rpc::Client::new(
+                        CircuitBreaker::default()
+                            .guard(chain.name.clone(), self.evm_rpc.provider(url, rpc_timeout)?),
                     );
+                    check_finalizer(&chain.name, &chain.finalization, &client).await?;
 
-                    check_finalizer(&chain.name, &chain.finalization, &rpc_client).await?;
+                    cross_check_clients.push((rpc_divergence::endpoint(url), client));
+                }
 
-                    self.create_handler_task(
-                        format!("{}-msg-verifier", chain.name),
+                let latest_block = EvmBlock(json_rpc::Client::new(self.circuit_breaker.guard(
+                    chain.name.clone(),
+                    self.evm_rpc.provider(&chain.rpc_url, rpc_timeout)?,
+                )));
+
+                self.voting_task(
+                    format!("{}-msg-verifier", chain.name),
+                    self.lag_checked(
                         handlers::evm_verify_msg::Handler::new(
-                            verifier.clone(),
+                            self.verifier.clone(),
                             cosmwasm_contract,
                             chain.name,
                             chain.finalization,
                             rpc_client,
-                            self.block_height_monitor.latest_block_height(),
+                            self.latest_block_height.clone(),
+                            self.vote_cache.clone(),
+                        )
+                        .with_cross_checks(
+                            rpc_divergence::endpoint(&chain.rpc_url),
+                            cross_check_clients,
+                            self.rpc_divergence.clone(),
                         ),
-                        event_processor_config.clone(),
-                    )
-                }
-                handlers::config::Config::EvmVerifierSetVerifier {
-                    chain,
-                    cosmwasm_contract,
-                    rpc_timeout,
-                } => {
-                    let rpc_client = json_rpc::Client::new_http(
-                        &chain.rpc_url,
-                        reqwest::ClientBuilder::new()
-                            .connect_timeout(rpc_timeout.unwrap_or(DEFAULT_RPC_TIMEOUT))
-                            .timeout(rpc_timeout.unwrap_or(DEFAULT_RPC_TIMEOUT))
-                            .build()
-                            .change_context(Error::Connection)?,
-                    );
+                        latest_block,
+                    ),
+                )
+            }
+            handlers::config::Config::EvmVerifierSetVerifier {
+                chain,
+                cosmwasm_contract,
+                rpc_timeout,
+            } => {
+                let rpc_client = json_rpc::Client::new(self.circuit_breaker.guard(
+                    chain.name.clone(),
+                    self.evm_rpc.provider(&chain.rpc_url, rpc_timeout)?,
+                ));
 
-                    check_finalizer(&chain.name, &chain.finalization, &rpc_client).await?;
+                check_finalizer(&chain.name, &chain.finalization, &rpc_client).await?;
 
-                    self.create_handler_task(
-                        format!("{}-verifier-set-verifier", chain.name),
-                        handlers::evm_verify_verifier_set::Handler::new(
-                            verifier.clone(),
-                            cosmwasm_contract,
-                            chain.name,
-                            chain.finalization,
-                            rpc_client,
-                            self.block_height_monitor.latest_block_height(),
-                        ),
-                        event_processor_config.clone(),
-                    )
-                }
-                handlers::config::Config::MultisigSigner {
-                    cosmwasm_contract,
-                    chain_name,
-                } => self.create_handler_task(
-                    "multisig-signer",
-                    handlers::multisig::Handler::new(
-                        verifier.clone(),
+                self.voting_task(
+                    format!("{}-verifier-set-verifier", chain.name),
+                    handlers::evm_verify_verifier_set::Handler::new(
+                        self.verifier.clone(),
                         cosmwasm_contract,
-                        chain_name,
-                        self.multisig_client.clone(),
-                        self.block_height_monitor.latest_block_height(),
+                        chain.name,
+                        chain.finalization,
+                        rpc_client,
+                        self.latest_block_height.clone(),
                     ),
-                    event_processor_config.clone(),
-                ),
-                handlers::config::Config::SuiMsgVerifier {
+                )
+            }
+            handlers::config::Config::MultisigSigner {
+                cosmwasm_contract,
+                chain_name,
+            } => self.signing_task("multisig-signer", || {
+                handlers::multisig::Handler::new(
+                    self.verifier.clone(),
                     cosmwasm_contract,
-                    rpc_url,
-                    rpc_timeout,
-                } => self.create_handler_task(
-                    "sui-msg-verifier",
+                    chain_name,
+                    self.signer.clone(),
+                    self.latest_block_height.clone(),
+                    self.signed_sessions.clone(),
+                )
+            }),
+            handlers::config::Config::SuiMsgVerifier {
+                cosmwasm_contract,
+                rpc_url,
+                rpc_timeout,
+            } => self.voting_task(
+                "sui-msg-verifier",
+                self.lag_checked(
                     handlers::sui_verify_msg::Handler::new(
-                        verifier.clone(),
+                        self.verifier.clone(),
                         cosmwasm_contract,
-                        json_rpc::Client::new_http(
-                            &rpc_url,
-                            reqwest::ClientBuilder::new()
-                                .connect_timeout(rpc_timeout.unwrap_or(DEFAULT_RPC_TIMEOUT))
-                                .timeout(rpc_timeout.unwrap_or(DEFAULT_RPC_TIMEOUT))
-                                .build()
-                                .change_context(Error::Connection)?,
-                        ),
-                        self.block_height_monitor.latest_block_height(),
+                        json_rpc::Client::new(self.circuit_breaker.guard(
+                            SUI_CHAIN_NAME.parse().expect("chain name should be valid"),
+                            http_provider(&rpc_url, rpc_timeout)?,
+                        )),
+                        self.latest_block_height.clone(),
                     ),
-                    event_processor_config.clone(),
+                    SuiCheckpoint(json_rpc::Client::new(self.circuit_breaker.guard(
+                        SUI_CHAIN_NAME.parse().expect("chain name should be valid"),
+                        http_provider(&rpc_url, rpc_timeout)?,
+                    ))),
                 ),
-                handlers::config::Config::XRPLMsgVerifier {
-                    cosmwasm_contract,
-                    chain_name,
-                    chain_rpc_url,
-                    rpc_timeout,
-                } => {
-                    let rpc_client = xrpl_http_client::Client::builder()
-                        .base_url(chain_rpc_url.as_str())
-                        .http_client(
-                            reqwest::ClientBuilder::new()
-                                .connect_timeout(rpc_timeout.unwrap_or(DEFAULT_RPC_TIMEOUT))
-                                .timeout(rpc_timeout.unwrap_or(DEFAULT_RPC_TIMEOUT))
-                                .build()
-                                .change_context(Error::Connection)?,
-                        )
-                        .build();
-
-                    self.create_handler_task(
-                        format!("{}-msg-verifier", chain_name),
-                        handlers::xrpl_verify_msg::Handler::new(
-                            verifier.clone(),
-                            cosmwasm_contract,
-                            rpc_client,
-                            self.block_height_monitor.latest_block_height(),
-                        ),
-                        event_processor_config.clone(),
+            ),
+            handlers::config::Config::XRPLMsgVerifier {
+                cosmwasm_contract,
+                chain_name,
+                chain_rpc_url,
+                rpc_timeout,
+            } => {
+                let rpc_client = xrpl_http_client::Client::builder()
+                    .base_url(chain_rpc_url.as_str())
+                    .http_client(
+                        reqwest::ClientBuilder::new()
+                            .connect_timeout(rpc_timeout.unwrap_or(DEFAULT_RPC_TIMEOUT))
+                            .timeout(rpc_timeout.unwrap_or(DEFAULT_RPC_TIMEOUT))
+                            .build()
+                            .change_context(Error::Connection)?,
                     )
-                }
-                handlers::config::Config::XRPLMultisigSigner {
+                    .build();
+
+                self.voting_task(
+                    format!("{}-msg-verifier", chain_name),
+                    handlers::xrpl_verify_msg::Handler::new(
+                        self.verifier.clone(),
+                        cosmwasm_contract,
+                        chain_name,
+                        rpc_client,
+                        self.latest_block_height.clone(),
+                    ),
+                )
+            }
+            handlers::config::Config::XRPLMultisigSigner {
+                multisig_contract,
+                multisig_prover_contract,
+            } => self.signing_task("xrpl-multisig-signer", || {
+                handlers::xrpl_multisig::Handler::new(
+                    self.verifier.clone(),
                     multisig_contract,
                     multisig_prover_contract,
-                } => self.create_handler_task(
-                    "xrpl-multisig-signer",
-                    handlers::xrpl_multisig::Handler::new(
-                        verifier.clone(),
-                        multisig_contract,
-                        multisig_prover_contract,
-                        self.multisig_client.clone(),
-                        self.block_height_monitor.latest_block_height(),
-                    ),
-                    event_processor_config.clone(),
-                ),
-                handlers::config::Config::SuiVerifierSetVerifier {
+                    self.signer.clone(),
+                    self.latest_block_height.clone(),
+                    self.signed_sessions.clone(),
+                )
+            }),
+            handlers::config::Config::SuiVerifierSetVerifier {
+                cosmwasm_contract,
+                rpc_url,
+                rpc_timeout,
+            } => self.voting_task(
+                "sui-verifier-set-verifier",
+                handlers::sui_verify_verifier_set::Handler::new(
+                    self.verifier.clone(),
                     cosmwasm_contract,
-                    rpc_url,
-                    rpc_timeout,
-                } => self.create_handler_task(
-                    "sui-verifier-set-verifier",
-                    handlers::sui_verify_verifier_set::Handler::new(
-                        verifier.clone(),
-                        cosmwasm_contract,
-                        json_rpc::Client::new_http(
-                            &rpc_url,
-                            reqwest::ClientBuilder::new()
-                                .connect_timeout(rpc_timeout.unwrap_or(DEFAULT_RPC_TIMEOUT))
-                                .timeout(rpc_timeout.unwrap_or(DEFAULT_RPC_TIMEOUT))
-                                .build()
-                                .change_context(Error::Connection)?,
-                        ),
-                        self.block_height_monitor.latest_block_height(),
-                    ),
-                    event_processor_config.clone(),
+                    json_rpc::Client::new(self.circuit_breaker.guard(
+                        SUI_CHAIN_NAME.parse().expect("chain name should be valid"),
+                        http_provider(&rpc_url, rpc_timeout)?,
+                    )),
+                    self.latest_block_height.clone(),
                 ),
-                handlers::config::Config::MvxMsgVerifier {
+            ),
+            handlers::config::Config::MvxMsgVerifier {
+                cosmwasm_contract,
+                proxy_url,
+            } => self.voting_task(
+                "mvx-msg-verifier",
+                handlers::mvx_verify_msg::Handler::new(
+                    self.verifier.clone(),
                     cosmwasm_contract,
-                    proxy_url,
-                } => self.create_handler_task(
-                    "mvx-msg-verifier",
-                    handlers::mvx_verify_msg::Handler::new(
-                        verifier.clone(),
-                        cosmwasm_contract,
-                        GatewayProxy::new(proxy_url.to_string().trim_end_matches('/').into()),
-                        self.block_height_monitor.latest_block_height(),
-                    ),
-                    event_processor_config.clone(),
+                    GatewayProxy::new(proxy_url.to_string().trim_end_matches('/').into()),
+                    self.latest_block_height.clone(),
                 ),
-                handlers::config::Config::MvxVerifierSetVerifier {
+            ),
+            handlers::config::Config::MvxVerifierSetVerifier {
+                cosmwasm_contract,
+                proxy_url,
+            } => self.voting_task(
+                "mvx-worker-set-verifier",
+                handlers::mvx_verify_verifier_set::Handler::new(
+                    self.verifier.clone(),
                     cosmwasm_contract,
-                    proxy_url,
-                } => self.create_handler_task(
-                    "mvx-worker-set-verifier",
-                    handlers::mvx_verify_verifier_set::Handler::new(
-                        verifier.clone(),
-                        cosmwasm_contract,
-                        GatewayProxy::new(proxy_url.to_string().trim_end_matches('/').into()),
-                        self.block_height_monitor.latest_block_height(),
-                    ),
-                    event_processor_config.clone(),
+                    GatewayProxy::new(proxy_url.to_string().trim_end_matches('/').into()),
+                    self.latest_block_height.clone(),
                 ),
-                handlers::config::Config::StellarMsgVerifier {
+            ),
+            handlers::config::Config::StellarMsgVerifier {
+                cosmwasm_contract,
+                rpc_url,
+            } => self.voting_task(
+                "stellar-msg-verifier",
+                handlers::stellar_verify_msg::Handler::new(
+                    self.verifier.clone(),
                     cosmwasm_contract,
-                    rpc_url,
-                } => self.create_handler_task(
-                    "stellar-msg-verifier",
-                    handlers::stellar_verify_msg::Handler::new(
-                        verifier.clone(),
-                        cosmwasm_contract,
-                        stellar::rpc_client::Client::new(
-                            rpc_url.to_string().trim_end_matches('/').into(),
-                        )
-                        .change_context(Error::Connection)?,
-                        self.block_height_monitor.latest_block_height(),
-                    ),
-                    event_processor_config.clone(),
+                    stellar::rpc_client::Client::new(
+                        rpc_url.to_string().trim_end_matches('/').into(),
+                    )
+                    .change_context(Error::Connection)?,
+                    self.latest_block_height.clone(),
                 ),
-                handlers::config::Config::StellarVerifierSetVerifier {
+            ),
+            handlers::config::Config::StellarVerifierSetVerifier {
+                cosmwasm_contract,
+                rpc_url,
+            } => self.voting_task(
+                "stellar-verifier-set-verifier",
+                handlers::stellar_verify_verifier_set::Handler::new(
+                    self.verifier.clone(),
                     cosmwasm_contract,
-                    rpc_url,
-                } => self.create_handler_task(
-                    "stellar-verifier-set-verifier",
-                    handlers::stellar_verify_verifier_set::Handler::new(
-                        verifier.clone(),
-                        cosmwasm_contract,
-                        stellar::rpc_client::Client::new(
-                            rpc_url.to_string().trim_end_matches('/').into(),
-                        )
-                        .change_context(Error::Connection)?,
-                        self.block_height_monitor.latest_block_height(),
-                    ),
-                    event_processor_config.clone(),
+                    stellar::rpc_client::Client::new(
+                        rpc_url.to_string().trim_end_matches('/').into(),
+                    )
+                    .change_context(Error::Connection)?,
+                    self.latest_block_height.clone(),
                 ),
-                handlers::config::Config::StarknetMsgVerifier {
-                    cosmwasm_contract,
-                    rpc_url,
-                } => self.create_handler_task(
-                    "starknet-msg-verifier",
+            ),
+            handlers::config::Config::StarknetMsgVerifier {
+                cosmwasm_contract,
+                rpc_url,
+            } => self.voting_task(
+                "starknet-msg-verifier",
+                self.lag_checked(
                     handlers::starknet_verify_msg::Handler::new(
-                        verifier.clone(),
+                        self.verifier.clone(),
                         cosmwasm_contract,
                         starknet::json_rpc::Client::new_with_transport(HttpTransport::new(
                             &rpc_url,
                         ))
                         .change_context(Error::Connection)?,
-                        self.block_height_monitor.latest_block_height(),
+                        self.latest_block_height.clone(),
                     ),
-                    event_processor_config.clone(),
+                    starknet::json_rpc::Client::new_with_transport(HttpTransport::new(&rpc_url))
+                        .change_context(Error::Connection)?,
                 ),
-                handlers::config::Config::StarknetVerifierSetVerifier {
+            ),
+            handlers::config::Config::StarknetVerifierSetVerifier {
+                cosmwasm_contract,
+                rpc_url,
+            } => self.voting_task(
+                "starknet-verifier-set-verifier",
+                handlers::starknet_verify_verifier_set::Handler::new(
+                    self.verifier.clone(),
                     cosmwasm_contract,
-                    rpc_url,
-                } => self.create_handler_task(
-                    "starknet-verifier-set-verifier",
-                    handlers::starknet_verify_verifier_set::Handler::new(
-                        verifier.clone(),
-                        cosmwasm_contract,
-                        starknet::json_rpc::Client::new_with_transport(HttpTransport::new(
-                            &rpc_url,
-                        ))
+                    starknet::json_rpc::Client::new_with_transport(HttpTransport::new(&rpc_url))
                         .change_context(Error::Connection)?,
-                        self.block_height_monitor.latest_block_height(),
-                    ),
-                    event_processor_config.clone(),
+                    self.latest_block_height.clone(),
                 ),
-                handlers::config::Config::SolanaMsgVerifier {
-                    chain_name,
-                    cosmwasm_contract,
-                    rpc_url,
-                    rpc_timeout,
-                } => self.create_handler_task(
-                    "solana-msg-verifier",
+            ),
+            handlers::config::Config::SolanaMsgVerifier {
+                chain_name,
+                cosmwasm_contract,
+                rpc_url,
+                rpc_timeout,
+            } => self.voting_task(
+                "solana-msg-verifier",
+                self.lag_checked(
                     handlers::solana_verify_msg::Handler::new(
                         chain_name,
-                        verifier.clone(),
+                        self.verifier.clone(),
                         cosmwasm_contract,
                         RpcClient::new_with_timeout_and_commitment(
                             rpc_url.to_string(),
                             rpc_timeout.unwrap_or(DEFAULT_RPC_TIMEOUT),
                             CommitmentConfig::finalized(),
                         ),
-                        self.block_height_monitor.latest_block_height(),
+                        self.latest_block_height.clone(),
+                    ),
+                    RpcClient::new_with_timeout_and_commitment(
+                        rpc_url.to_string(),
+                        rpc_timeout.unwrap_or(DEFAULT_RPC_TIMEOUT),
+                        CommitmentConfig::finalized(),
                     ),
-                    event_processor_config.clone(),
                 ),
-                handlers::config::Config::SolanaVerifierSetVerifier {
+            ),
+            handlers::config::Config::SolanaVerifierSetVerifier {
+                chain_name,
+                cosmwasm_contract,
+                rpc_url,
+                rpc_timeout,
+            } => self.voting_task(
+                "solana-verifier-set-verifier",
+                handlers::solana_verify_verifier_set::Handler::new(
                     chain_name,
+                    self.verifier.clone(),
                     cosmwasm_contract,
-                    rpc_url,
-                    rpc_timeout,
-                } => self.create_handler_task(
-                    "solana-verifier-set-verifier",
-                    handlers::solana_verify_verifier_set::Handler::new(
-                        chain_name,
-                        verifier.clone(),
-                        cosmwasm_contract,
-                        RpcClient::new_with_timeout_and_commitment(
-                            rpc_url.to_string(),
-                            rpc_timeout.unwrap_or(DEFAULT_RPC_TIMEOUT),
-                            CommitmentConfig::finalized(),
-                        ),
-                        self.block_height_monitor.latest_block_height(),
-                    )
-                    .await,
-                    event_processor_config.clone(),
-                ),
-            };
-            self.event_processor = self.event_processor.add_task(task);
+                    RpcClient::new_with_timeout_and_commitment(
+                        rpc_url.to_string(),
+                        rpc_timeout.unwrap_or(DEFAULT_RPC_TIMEOUT),
+                        CommitmentConfig::finalized(),
+                    ),
+                    self.latest_block_height.clone(),
+                )
+                .await,
+            ),
+        };
+
+        Ok(task)
+    }
+}
+
+/// Read-only deployments have no broadcaster account, so the votes of their handlers are dropped. Signing handlers
+/// don't run at all in read-only mode, see [EventHandlerFactory::signing_task]
+struct ReadOnlyBroadcaster;
+
+#[async_trait]
+impl BroadcasterClient for ReadOnlyBroadcaster {
+    async fn broadcast(&self, msg: Any) -> Result<(), broadcaster_v2::Error> {
+        debug!(type_url = msg.type_url, "read-only mode, dropping message");
+        Ok(())
+    }
+}
+
+/// Reloads the handler configs on SIGHUP, so chain support can be added or removed without restarting the daemon.
+/// The broadcaster key can be switched over as well. All other config sections are only read on startup
+struct HandlerReloader {
+    config_loader: ConfigLoader,
+    contract_filter: Option<watch::Sender<Vec<TMAddress>>>,
+    handler_types: watch::Sender<Vec<String>>,
+    poll_watchdog: PollWatchdog,
+    key_rotation: Option<KeyRotation>,
+}
+
+/// Switches the handlers' broadcaster over to the key in `tofnd_config.key_uid` when it changes,
+/// e.g. after `ampd rotate-broadcaster-key` granted the new key authorization to broadcast for the verifier
+struct KeyRotation {
+    signer: Signer,
+    cosmos_client: CosmosGrpcClient,
+    key_uid: String,
+    verifier: TMAddress,
+    broadcast: broadcaster::Config,
+    rotations: mpsc::Sender<broadcaster_v2::NewKey>,
+}
+
+impl KeyRotation {
+    async fn apply(&mut self, config: &Config) -> Result<(), Error> {
+        let key_uid = &config.tofnd_config.key_uid;
+        let broadcast = config.broadcast.clone();
+        if *key_uid == self.key_uid && broadcast.authz_granter == self.broadcast.authz_granter {
+            return Ok(());
         }
 
-        Ok(self)
+        let pub_key = broadcaster_pub_key(&self.signer, key_uid).await?;
+        // the handlers keep acting as the same verifier, so the new key must broadcast on its behalf
+        ensure!(
+            verifier_address(&pub_key, &broadcast) == self.verifier,
+            report!(Error::InvalidInput).attach_printable(format!(
+                "key {} does not broadcast on behalf of verifier {}, set broadcast.authz_granter to the verifier",
+                key_uid, self.verifier
+            ))
+        );
+
+        validate_fee_payer(
+            &self.signer,
+            self.cosmos_client.clone(),
+            key_uid,
+            pub_key,
+            broadcast.clone(),
+        )
+        .await?;
+        self.rotations
+            .send(broadcaster_v2::NewKey {
+                key_id: key_uid.clone(),
+                pub_key,
+                authz_granter: broadcast.authz_granter.clone(),
+            })
+            .await
+            .change_context(Error::Broadcaster)?;
+
+        info!(key_uid, "broadcaster key rotated");
+        self.key_uid.clone_from(key_uid);
+        self.broadcast = broadcast;
+
+        Ok(())
     }
+}
 
-    fn create_handler_task<L, H>(
-        &mut self,
-        label: L,
-        handler: H,
-        event_processor_config: event_processor::Config,
-    ) -> CancellableTask<Result<(), event_processor::Error>>
-    where
-        L: AsRef<str>,
-        H: EventHandler + Send + Sync + 'static,
-    {
-        let label = label.as_ref().to_string();
-        let broadcaster = self.broadcaster.client();
-        let sub = self.event_subscriber.subscribe();
+impl HandlerReloader {
+    async fn run(
+        mut self,
+        reloads: mpsc::Sender<Vec<handlers::config::Config>>,
+        token: CancellationToken,
+    ) -> Result<(), Error> {
+        let mut sighup = signal(SignalKind::hangup())
+            .change_context(Error::ReloadHandlers)
+            .attach_printable("failed to capture SIGHUP")?;
 
-        CancellableTask::create(move |token| {
-            event_processor::consume_events(
-                label,
-                handler,
-                broadcaster,
-                sub,
-                event_processor_config,
-                token,
-            )
-        })
+        loop {
+            select! {
+                _ = sighup.recv() => {},
+                _ = token.cancelled() => return Ok(()),
+            }
+
+            info!("SIGHUP received, reloading handler config");
+
+            // a config that cannot be loaded must not stop the running handlers, so only a valid config is applied
+            let config = match (self.config_loader)() {
+                Ok(config) => config,
+                Err(err) => {
+                    error!(
+                        err = LoggableError::from(&err).as_value(),
+                        "failed to reload config, keeping the current handlers"
+                    );
+                    continue;
+                }
+            };
+
+            if let Some(key_rotation) = &mut self.key_rotation {
+                if let Err(err) = key_rotation.apply(&config).await {
+                    error!(
+                        err = LoggableError::from(&err).as_value(),
+                        "failed to rotate the broadcaster key, keeping the current key"
+                    );
+                }
+            }
+
+            let handler_configs = config.handlers;
+            let contracts = handler_contracts(&handler_configs);
+            if let Some(contract_filter) = &self.contract_filter {
+                contract_filter.send_replace(contracts.clone());
+            }
+            self.poll_watchdog.set_contracts(contracts);
+            self.handler_types
+                .send_replace(handler_types(&handler_configs));
+
+            reloads
+                .send(handler_configs)
+                .await
+                .change_context(Error::ReloadHandlers)?;
+        }
+    }
+}
+
+struct App {
+    event_publisher: event_sub::EventPublisher<tendermint_rpc::HttpClient>,
+    handler_event_publisher: Option<event_sub::EventPublisher<tendermint_rpc::HttpClient>>,
+    handler_supervisor: HandlerSupervisor<EventHandlerFactory>,
+    handler_configs: Vec<handlers::config::Config>,
+    handler_reloader: HandlerReloader,
+    poll_watchdog_handler: HandlerTask,
+    event_archive_handler: Option<HandlerTask>,
+    broadcasting: Option<Broadcasting>,
+    block_height_monitor: BlockHeightMonitor<tendermint_rpc::HttpClient>,
+    health_check_server: health_check::Server,
+    grpc_server: grpc::Server,
+}
+
+impl App {
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        event_publisher: event_sub::EventPublisher<tendermint_rpc::HttpClient>,
+        handler_event_publisher: Option<event_sub::EventPublisher<tendermint_rpc::HttpClient>>,
+        broadcasting: Option<Broadcasting>,
+        block_height_monitor: BlockHeightMonitor<tendermint_rpc::HttpClient>,
+        health_check_server: health_check::Server,
+        grpc_server: grpc::Server,
+        handler_factory: EventHandlerFactory,
+        handler_configs: Vec<handlers::config::Config>,
+        handler_reloader: HandlerReloader,
+        event_archive_handler: Option<HandlerTask>,
+    ) -> Self {
+        let poll_watchdog_handler = handler_factory.task(
+            "poll-watchdog",
+            poll_watchdog::Handler::new(handler_factory.poll_watchdog.clone()),
+        );
+
+        Self {
+            event_publisher,
+            handler_event_publisher,
+            handler_supervisor: HandlerSupervisor::new(handler_factory),
+            handler_configs,
+            handler_reloader,
+            poll_watchdog_handler,
+            event_archive_handler,
+            broadcasting,
+            block_height_monitor,
+            health_check_server,
+            grpc_server,
+        }
     }
 
     fn create_broadcaster_task(
-        broadcaster: QueuedBroadcaster<T>,
-        confirmer: TxConfirmer<CosmosGrpcClient>,
+        Broadcasting {
+            tx_confirmer,
+            tx_hashes,
+            broadcaster_task,
+            ..
+        }: Broadcasting,
     ) -> TaskGroup<Error> {
-        let (tx_hash_sender, tx_hash_receiver) = mpsc::channel(1000);
         let (tx_response_sender, tx_response_receiver) = mpsc::channel(1000);
 
         TaskGroup::new("broadcaster")
             .add_task(CancellableTask::create(|_| {
-                confirmer
-                    .run(tx_hash_receiver, tx_response_sender)
+                tx_confirmer
+                    .run(tx_hashes, tx_response_sender)
                     .change_context(Error::TxConfirmation)
             }))
             .add_task(CancellableTask::create(|_| {
-                broadcaster
-                    .run(tx_hash_sender, tx_response_receiver)
-                    .change_context(Error::Broadcaster)
+                confirm_tx::log_tx_responses(tx_response_receiver)
+                    .change_context(Error::TxConfirmation)
+            }))
+            .add_task(CancellableTask::create(|_| {
+                broadcaster_task.run().change_context(Error::Broadcaster)
             }))
     }
 
     async fn run(self) -> Result<(), Error> {
         let Self {
             event_publisher,
-            event_processor,
-            broadcaster,
-            tx_confirmer,
+            handler_event_publisher,
+            handler_supervisor,
+            handler_configs,
+            handler_reloader,
+            poll_watchdog_handler,
+            event_archive_handler,
+            broadcasting,
             block_height_monitor,
             health_check_server,
             grpc_server,
-            broadcaster_task,
-            ..
         } = self;
 
+        let (reload_sender, reload_receiver) = mpsc::channel(1);
+
         let main_token = CancellationToken::new();
         let exit_token = main_token.clone();
         tokio::spawn(async move {
@@ -629,7 +1151,7 @@ where
             exit_token.cancel();
         });
 
-        TaskGroup::new("ampd")
+        let tasks = TaskGroup::new("ampd")
             .add_task(CancellableTask::create(|token| {
                 block_height_monitor
                     .run(token)
@@ -646,21 +1168,49 @@ where
                     .change_context(Error::HealthCheck)
             }))
             .add_task(CancellableTask::create(|token| {
-                event_processor
+                handler_supervisor.run(handler_configs, reload_receiver, token)
+            }))
+            .add_task(CancellableTask::create(|token| {
+                poll_watchdog_handler
                     .run(token)
                     .change_context(Error::EventProcessor)
             }))
             .add_task(CancellableTask::create(|token| {
-                App::create_broadcaster_task(broadcaster, tx_confirmer).run(token)
+                handler_reloader.run(reload_sender, token)
             }))
             .add_task(CancellableTask::create(|token| {
                 grpc_server.run(token).change_context(Error::GrpcServer)
-            }))
-            .add_task(CancellableTask::create(|_| {
-                broadcaster_task.run().change_context(Error::Broadcaster)
-            }))
-            .run(main_token)
-            .await
+            }));
+
+        // read-only deployments have nothing to broadcast, so the broadcaster tasks are only added if broadcasting is enabled
+        let tasks = match broadcasting {
+            Some(broadcasting) => tasks.add_task(CancellableTask::create(|token| {
+                App::create_broadcaster_task(broadcasting).run(token)
+            })),
+            None => tasks,
+        };
+
+        // every task of the group is expected to run until shutdown, so the archive is only added if it is enabled
+        let tasks = match event_archive_handler {
+            Some(event_archive_handler) => tasks.add_task(CancellableTask::create(|token| {
+                event_archive_handler
+                    .run(token)
+                    .change_context(Error::EventProcessor)
+            })),
+            None => tasks,
+        };
+
+        // only set if the handlers' contract filter is pushed down to the node
+        let tasks = match handler_event_publisher {
+            Some(handler_event_publisher) => tasks.add_task(CancellableTask::create(|token| {
+                handler_event_publisher
+                    .run(token)
+                    .change_context(Error::EventPublisher)
+            })),
+            None => tasks,
+        };
+
+        tasks.run(main_token).await
     }
 }
 
@@ -694,4 +1244,16 @@ pub enum Error {
     HealthCheck,
     #[error("gRPC server failed")]
     GrpcServer,
+    #[error("config validation failed")]
+    ConfigValidation,
+    #[error("failed to load signed signing sessions")]
+    SignedSessions,
+    #[error("failed to load the event buffer")]
+    EventBuffer,
+    #[error("failed to reload handlers")]
+    ReloadHandlers,
+    #[error("contract compatibility check failed")]
+    ContractCompatibility,
+    #[error("verifier onboarding is incomplete")]
+    Onboarding,
 }
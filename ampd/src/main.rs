@@ -1,20 +1,16 @@
 use std::fmt::Debug;
-use std::fs::canonicalize;
-use std::path::{Path, PathBuf};
+use std::path::PathBuf;
 use std::process::ExitCode;
 
-use ::config::{Config as cfg, Environment, File, FileFormat, FileSourceFile};
 use ampd::commands::{
-    bond_verifier, claim_stake, daemon, deregister_chain_support, register_chain_support,
-    register_public_key, send_tokens, set_rewards_proxy, unbond_verifier, verifier_address,
-    SubCommand,
+    bond_verifier, claim_stake, daemon, debug, deregister_chain_support, onboard,
+    register_chain_support, register_public_key, rewards, rotate_broadcaster_key, send_tokens,
+    set_rewards_proxy, unbond_verifier, validate_config, verifier_address, SubCommand,
 };
-use ampd::config::Config;
 use ampd::Error;
 use axelar_wasm_std::FnExt;
 use clap::{arg, command, Parser, ValueEnum};
-use config::ConfigError;
-use error_stack::{Report, ResultExt};
+use error_stack::ResultExt;
 use report::LoggableError;
 use tracing::{error, info};
 use tracing_core::LevelFilter;
@@ -28,6 +24,11 @@ struct Args {
     #[arg(short, long, default_values_os_t = vec![std::path::PathBuf::from("~/.ampd/config.toml"), std::path::PathBuf::from("config.toml")])]
     pub config: Vec<PathBuf>,
 
+    /// Set the network profile, e.g. mainnet. The profile's config files (e.g. config.mainnet.toml) are layered on top of
+    /// the config files, followed by the files with local overrides (e.g. config.local.toml)
+    #[arg(short, long)]
+    pub profile: Option<String>,
+
     /// Set the output style of the logs
     #[arg(short, long, value_enum, default_value_t = Output::Text)]
     pub output: Output,
@@ -47,13 +48,25 @@ async fn main() -> ExitCode {
     let args: Args = Args::parse();
     set_up_logger(&args.output);
 
-    let cfg = init_config(&args.config);
+    let cfg = match ampd::config::load(&args.config, args.profile.as_deref())
+        .change_context(Error::LoadConfig)
+    {
+        Ok(cfg) => cfg,
+        Err(report) => return exit_with_error(report, &args.output),
+    };
 
     let result = match args.cmd {
         Some(SubCommand::Daemon) | None => {
             info!(args = args.as_value(), "starting daemon");
 
-            daemon::run(cfg).await.then(|result| {
+            let config_paths = args.config.clone();
+            let profile = args.profile.clone();
+            let config_loader = Box::new(move || {
+                ampd::config::load(&config_paths, profile.as_deref())
+                    .change_context(Error::LoadConfig)
+            });
+
+            daemon::run(cfg, config_loader).await.then(|result| {
                 info!("shutting down");
                 result
             })
@@ -66,11 +79,18 @@ async fn main() -> ExitCode {
             deregister_chain_support::run(cfg, args).await
         }
         Some(SubCommand::RegisterPublicKey(args)) => register_public_key::run(cfg, args).await,
-        Some(SubCommand::VerifierAddress) => verifier_address::run(cfg.tofnd_config).await,
+        Some(SubCommand::Onboard(args)) => onboard::run(cfg, args).await,
+        Some(SubCommand::VerifierAddress) => verifier_address::run(cfg).await,
         Some(SubCommand::UnbondVerifier(args)) => unbond_verifier::run(cfg, args).await,
         Some(SubCommand::ClaimStake(args)) => claim_stake::run(cfg, args).await,
         Some(SubCommand::SendTokens(args)) => send_tokens::run(cfg, args).await,
         Some(SubCommand::SetRewardsProxy(args)) => set_rewards_proxy::run(cfg, args).await,
+        Some(SubCommand::Rewards(args)) => rewards::run(cfg, args).await,
+        Some(SubCommand::RotateBroadcasterKey(args)) => {
+            rotate_broadcaster_key::run(cfg, args).await
+        }
+        Some(SubCommand::Config(args)) => validate_config::run(cfg, args).await,
+        Some(SubCommand::Debug(args)) => debug::run(cfg, args).await,
     };
 
     match result {
@@ -80,17 +100,19 @@ async fn main() -> ExitCode {
             }
             ExitCode::SUCCESS
         }
-        Err(report) => {
-            error!(err = LoggableError::from(&report).as_value(), "{report:#}");
+        Err(report) => exit_with_error(report, &args.output),
+    }
+}
 
-            // print detailed error report as the last output if in text mode
-            if matches!(args.output, Output::Text) {
-                eprintln!("{report:?}");
-            }
+fn exit_with_error(report: error_stack::Report<Error>, output: &Output) -> ExitCode {
+    error!(err = LoggableError::from(&report).as_value(), "{report:#}");
 
-            ExitCode::FAILURE
-        }
+    // print detailed error report as the last output if in text mode
+    if matches!(output, Output::Text) {
+        eprintln!("{report:?}");
     }
+
+    ExitCode::FAILURE
 }
 
 fn set_up_logger(output: &Output) {
@@ -118,49 +140,3 @@ fn set_up_logger(output: &Output) {
         }
     };
 }
-
-fn init_config(config_paths: &[PathBuf]) -> Config {
-    let files = find_config_files(config_paths);
-
-    parse_config(files)
-        .change_context(Error::LoadConfig)
-        .inspect_err(|report| error!(err = LoggableError::from(report).as_value(), "{report}"))
-        .unwrap_or_default()
-}
-
-fn find_config_files(config: &[PathBuf]) -> Vec<File<FileSourceFile, FileFormat>> {
-    let files = config
-        .iter()
-        .map(expand_home_dir)
-        .map(canonicalize)
-        .filter_map(Result::ok)
-        .inspect(|path| info!("found config file {}", path.to_string_lossy()))
-        .map(File::from)
-        .collect::<Vec<_>>();
-
-    if files.is_empty() {
-        info!("found no config files to load");
-    }
-
-    files
-}
-
-fn parse_config(
-    files: Vec<File<FileSourceFile, FileFormat>>,
-) -> error_stack::Result<Config, ConfigError> {
-    cfg::builder()
-        .add_source(files)
-        .add_source(Environment::with_prefix(clap::crate_name!()))
-        .build()?
-        .try_deserialize::<Config>()
-        .map_err(Report::from)
-}
-
-fn expand_home_dir(path: impl AsRef<Path>) -> PathBuf {
-    let path = path.as_ref();
-    let Ok(home_subfolder) = path.strip_prefix("~") else {
-        return path.to_path_buf();
-    };
-
-    dirs::home_dir().map_or(path.to_path_buf(), |home| home.join(home_subfolder))
-}
@@ -0,0 +1,501 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::convert::Infallible;
+use std::io;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use async_trait::async_trait;
+use axelar_wasm_std::voting::PollId;
+use cosmrs::Any;
+use error_stack::{Context, Frame, Report, Result};
+use ethers_providers::ProviderError;
+use events::Event;
+use router_api::ChainName;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use tokio::time::error::Elapsed;
+use tracing::warn;
+
+use crate::event_processor::EventHandler;
+use crate::types::TMAddress;
+
+const VOTED_EVENT: &str = "wasm-voted";
+const POLL_CANCELLED_EVENT: &str = "wasm-poll_cancelled";
+pub const POLL_EXPIRY_EXTENDED_EVENT: &str = "wasm-poll_expiry_extended";
+
+/// Why the verifier did not vote in a poll, as far as ampd can tell
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum MissReason {
+    HandlerError,
+    RpcTimeout,
+    BroadcastFailure,
+    Unknown,
+}
+
+impl MissReason {
+    /// Classifies a handler error by the types of the errors in its chain. A timeout of one of the clients that handlers
+    /// query their RPC endpoints with makes it an RPC timeout, any other error a handler error
+    pub fn from_handler_error<C: Context>(err: &Report<C>) -> Self {
+        if err.frames().any(is_timeout) {
+            MissReason::RpcTimeout
+        } else {
+            MissReason::HandlerError
+        }
+    }
+}
+
+fn is_timeout(frame: &Frame) -> bool {
+    if let Some(err) = frame.downcast_ref::<ProviderError>() {
+        return matches!(err, ProviderError::HTTPError(err) if err.is_timeout());
+    }
+
+    frame
+        .downcast_ref::<reqwest::Error>()
+        .is_some_and(reqwest::Error::is_timeout)
+        || frame
+            .downcast_ref::<tonic::Status>()
+            .is_some_and(|status| status.code() == tonic::Code::DeadlineExceeded)
+        || frame
+            .downcast_ref::<io::Error>()
+            .is_some_and(|err| err.kind() == io::ErrorKind::TimedOut)
+        || frame.is::<Elapsed>()
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub struct Metrics {
+    /// number of polls this verifier participates in that have not expired yet
+    pub tracked: usize,
+    /// number of polls that expired without a vote from this verifier, per source chain
+    pub missed: HashMap<ChainName, u64>,
+    /// number of polls that expired without a vote from this verifier, per reason
+    pub missed_by_reason: BTreeMap<MissReason, u64>,
+}
+
+#[derive(Deserialize)]
+struct PollStarted {
+    poll_id: PollId,
+    source_chain: ChainName,
+    expires_at: u64,
+    participants: Vec<TMAddress>,
+}
+
+#[derive(Deserialize)]
+struct Voted {
+    poll_id: PollId,
+    voter: TMAddress,
+}
+
+#[derive(Deserialize)]
+struct PollCancelled {
+    poll_id: PollId,
+}
+
+#[derive(Deserialize)]
+struct PollExpiryExtended {
+    poll_id: PollId,
+    expires_at: u64,
+}
+
+struct TrackedPoll {
+    source_chain: ChainName,
+    expires_at: u64,
+    voted: bool,
+    reason: Option<MissReason>,
+}
+
+struct State {
+    verifier: TMAddress,
+    contracts: HashSet<TMAddress>,
+    // poll ids are keyed by their string representation, because PollId is not hashable
+    polls: HashMap<(TMAddress, String), TrackedPoll>,
+    metrics: Metrics,
+}
+
+/// Keeps track of the polls of the configured voting verifier contracts this verifier participates in.
+/// If a poll expires before this verifier voted, it is reported as missed, together with the reason
+/// of the last failure that was recorded for it.
+#[derive(Clone)]
+pub struct PollWatchdog {
+    state: Arc<Mutex<State>>,
+}
+
+impl PollWatchdog {
+    pub fn new(verifier: TMAddress, contracts: impl IntoIterator<Item = TMAddress>) -> Self {
+        Self {
+            state: Arc::new(Mutex::new(State {
+                verifier,
+                contracts: contracts.into_iter().collect(),
+                polls: HashMap::new(),
+                metrics: Metrics::default(),
+            })),
+        }
+    }
+
+    /// Replaces the watched contracts. Polls of contracts that are no longer watched are dropped without being reported as missed
+    pub fn set_contracts(&self, contracts: impl IntoIterator<Item = TMAddress>) {
+        let mut state = self.lock();
+
+        state.contracts = contracts.into_iter().collect();
+        let State {
+            contracts, polls, ..
+        } = &mut *state;
+        polls.retain(|(contract, _), _| contracts.contains(contract));
+    }
+
+    /// Records why the verifier could not vote on the poll started by the given event.
+    /// Events that do not start a poll of a watched contract are ignored
+    pub fn record_failure(&self, event: &Event, reason: MissReason) {
+        let mut state = self.lock();
+
+        if let Some((contract, poll_started)) = state.parse_poll_started(event) {
+            state.track(contract, poll_started).reason = Some(reason);
+        }
+    }
+
+    pub fn metrics(&self) -> Metrics {
+        self.lock().metrics.clone()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.state
+            .lock()
+            .expect("poll watchdog lock should not be poisoned")
+    }
+}
+
+impl State {
+    fn handle(&mut self, event: &Event) {
+        match event {
+            Event::BlockEnd(height) => self.report_expired(height.value()),
+            Event::Abci { event_type, .. } if event_type == VOTED_EVENT => {
+                if let Some((contract, voted)) = self.parse::<Voted>(event) {
+                    if voted.voter == self.verifier {
+                        if let Some(poll) = self.polls.get_mut(&(contract, voted.poll_id.into())) {
+                            poll.voted = true;
+                        }
+                    }
+                }
+            }
+            Event::Abci { event_type, .. } if event_type == POLL_CANCELLED_EVENT => {
+                if let Some((contract, cancelled)) = self.parse::<PollCancelled>(event) {
+                    self.polls.remove(&(contract, cancelled.poll_id.into()));
+                }
+            }
+            Event::Abci { event_type, .. } if event_type == POLL_EXPIRY_EXTENDED_EVENT => {
+                if let Some((contract, extended)) = self.parse::<PollExpiryExtended>(event) {
+                    if let Some(poll) = self.polls.get_mut(&(contract, extended.poll_id.into())) {
+                        poll.expires_at = extended.expires_at;
+                    }
+                }
+            }
+            _ => {
+                if let Some((contract, poll_started)) = self.parse_poll_started(event) {
+                    self.track(contract, poll_started);
+                }
+            }
+        }
+
+        self.metrics.tracked = self.polls.len();
+    }
+
+    // votes are only accepted while the block height is below expires_at, so once the block before
+    // that has ended the poll cannot receive any more votes
+    fn report_expired(&mut self, height: u64) {
+        let expired: Vec<_> = self
+            .polls
+            .iter()
+            .filter(|(_, poll)| poll.expires_at <= height.saturating_add(1))
+            .map(|(key, _)| key.clone())
+            .collect();
+
+        for (contract, poll_id) in expired {
+            let Some(poll) = self.polls.remove(&(contract.clone(), poll_id.clone())) else {
+                continue;
+            };
+
+            if poll.voted {
+                continue;
+            }
+
+            let reason = poll.reason.unwrap_or(MissReason::Unknown);
+            warn!(
+                contract = contract.to_string(),
+                poll_id,
+                source_chain = poll.source_chain.to_string(),
+                expires_at = poll.expires_at,
+                reason = ?reason,
+                "poll expired without a vote from this verifier"
+            );
+
+            let missed = self.metrics.missed.entry(poll.source_chain).or_default();
+            *missed = missed.saturating_add(1);
+            let missed = self.metrics.missed_by_reason.entry(reason).or_default();
+            *missed = missed.saturating_add(1);
+        }
+    }
+
+    fn track(&mut self, contract: TMAddress, poll_started: PollStarted) -> &mut TrackedPoll {
+        self.polls
+            .entry((contract, poll_started.poll_id.into()))
+            .or_insert(TrackedPoll {
+                source_chain: poll_started.source_chain,
+                expires_at: poll_started.expires_at,
+                voted: false,
+                reason: None,
+            })
+    }
+
+    fn parse_poll_started(&self, event: &Event) -> Option<(TMAddress, PollStarted)> {
+        match event {
+            Event::Abci { event_type, .. }
+                if event_type.starts_with("wasm-") && event_type.ends_with("_poll_started") =>
+            {
+                self.parse::<PollStarted>(event)
+                    .filter(|(_, poll_started)| poll_started.participants.contains(&self.verifier))
+            }
+            _ => None,
+        }
+    }
+
+    fn parse<T: DeserializeOwned>(&self, event: &Event) -> Option<(TMAddress, T)> {
+        let Event::Abci { attributes, .. } = event else {
+            return None;
+        };
+
+        let contract: TMAddress = event.contract_address()?.into();
+        if !self.contracts.contains(&contract) {
+            return None;
+        }
+
+        serde_json::from_value(attributes.clone().into())
+            .ok()
+            .map(|parsed| (contract, parsed))
+    }
+}
+
+/// Feeds the event stream into the [PollWatchdog]. It never broadcasts any messages
+pub struct Handler {
+    watchdog: PollWatchdog,
+}
+
+impl Handler {
+    pub fn new(watchdog: PollWatchdog) -> Self {
+        Self { watchdog }
+    }
+}
+
+#[async_trait]
+impl EventHandler for Handler {
+    type Err = Infallible;
+
+    async fn handle(&self, event: &Event) -> Result<Vec<Any>, Infallible> {
+        self.watchdog.lock().handle(event);
+
+        Ok(vec![])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use error_stack::report;
+    use events::Event;
+
+    use super::*;
+    use crate::PREFIX;
+
+    fn poll_started_event(
+        contract: &TMAddress,
+        poll_id: u64,
+        participants: &[&TMAddress],
+    ) -> Event {
+        Event::Abci {
+            event_type: "wasm-messages_poll_started".to_string(),
+            attributes: serde_json::json!({
+                "_contract_address": contract.to_string(),
+                "poll_id": poll_id.to_string(),
+                "source_chain": "ethereum",
+                "source_gateway_address": "0x4f4495243837681061c4743b74b3eedf548d56a5",
+                "confirmation_height": 15,
+                "expires_at": 100,
+                "participants": participants.iter().map(|p| p.to_string()).collect::<Vec<_>>(),
+                "messages": [],
+            })
+            .as_object()
+            .unwrap()
+            .clone(),
+        }
+    }
+
+    fn voted_event(contract: &TMAddress, poll_id: u64, voter: &TMAddress) -> Event {
+        Event::Abci {
+            event_type: VOTED_EVENT.to_string(),
+            attributes: serde_json::json!({
+                "_contract_address": contract.to_string(),
+                "poll_id": poll_id.to_string(),
+                "voter": voter.to_string(),
+                "votes": ["succeeded_on_chain"],
+            })
+            .as_object()
+            .unwrap()
+            .clone(),
+        }
+    }
+
+    async fn handle_all(handler: &Handler, events: Vec<Event>) {
+        for event in events {
+            assert!(handler.handle(&event).await.unwrap().is_empty());
+        }
+    }
+
+    #[tokio::test]
+    async fn unvoted_expired_polls_should_be_reported_as_missed() {
+        let verifier = TMAddress::random(PREFIX);
+        let other_verifier = TMAddress::random(PREFIX);
+        let contract = TMAddress::random(PREFIX);
+
+        let watchdog = PollWatchdog::new(verifier.clone(), [contract.clone()]);
+        let handler = Handler::new(watchdog.clone());
+
+        handle_all(
+            &handler,
+            vec![
+                poll_started_event(&contract, 1, &[&verifier, &other_verifier]),
+                poll_started_event(&contract, 2, &[&verifier, &other_verifier]),
+                poll_started_event(&contract, 3, &[&verifier]),
+                poll_started_event(&contract, 4, &[&other_verifier]),
+                poll_started_event(&TMAddress::random(PREFIX), 5, &[&verifier]),
+                voted_event(&contract, 1, &verifier),
+                voted_event(&contract, 2, &other_verifier),
+            ],
+        )
+        .await;
+        watchdog.record_failure(
+            &poll_started_event(&contract, 3, &[&verifier]),
+            MissReason::BroadcastFailure,
+        );
+
+        assert_eq!(watchdog.metrics().tracked, 3);
+
+        handle_all(&handler, vec![Event::BlockEnd(98u32.into())]).await;
+        assert_eq!(watchdog.metrics().tracked, 3);
+
+        handle_all(&handler, vec![Event::BlockEnd(99u32.into())]).await;
+        assert_eq!(
+            watchdog.metrics(),
+            Metrics {
+                tracked: 0,
+                missed: HashMap::from([("ethereum".parse().unwrap(), 2)]),
+                missed_by_reason: BTreeMap::from([
+                    (MissReason::BroadcastFailure, 1),
+                    (MissReason::Unknown, 1)
+                ]),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn cancelled_polls_should_not_be_reported_as_missed() {
+        let verifier = TMAddress::random(PREFIX);
+        let contract = TMAddress::random(PREFIX);
+
+        let watchdog = PollWatchdog::new(verifier.clone(), [contract.clone()]);
+        let handler = Handler::new(watchdog.clone());
+
+        handle_all(
+            &handler,
+            vec![
+                poll_started_event(&contract, 1, &[&verifier]),
+                Event::Abci {
+                    event_type: POLL_CANCELLED_EVENT.to_string(),
+                    attributes: serde_json::json!({
+                        "_contract_address": contract.to_string(),
+                        "poll_id": "1",
+                        "source_chain": "ethereum",
+                        "reason": "stuck",
+                    })
+                    .as_object()
+                    .unwrap()
+                    .clone(),
+                },
+                Event::BlockEnd(100u32.into()),
+            ],
+        )
+        .await;
+
+        assert_eq!(watchdog.metrics(), Metrics::default());
+    }
+
+    #[tokio::test]
+    async fn polls_with_extended_expiry_should_be_reported_at_the_new_expiry() {
+        let verifier = TMAddress::random(PREFIX);
+        let contract = TMAddress::random(PREFIX);
+
+        let watchdog = PollWatchdog::new(verifier.clone(), [contract.clone()]);
+        let handler = Handler::new(watchdog.clone());
+
+        handle_all(
+            &handler,
+            vec![
+                poll_started_event(&contract, 1, &[&verifier]),
+                Event::Abci {
+                    event_type: POLL_EXPIRY_EXTENDED_EVENT.to_string(),
+                    attributes: serde_json::json!({
+                        "_contract_address": contract.to_string(),
+                        "poll_id": "1",
+                        "expires_at": 150,
+                    })
+                    .as_object()
+                    .unwrap()
+                    .clone(),
+                },
+                Event::BlockEnd(100u32.into()),
+            ],
+        )
+        .await;
+        assert_eq!(watchdog.metrics().tracked, 1);
+
+        handle_all(&handler, vec![Event::BlockEnd(149u32.into())]).await;
+        assert_eq!(watchdog.metrics().tracked, 0);
+        assert_eq!(
+            watchdog.metrics().missed,
+            HashMap::from([("ethereum".parse().unwrap(), 1)])
+        );
+    }
+
+    #[derive(thiserror::Error, Debug)]
+    enum Error {
+        #[error("failed to get the latest finalized block")]
+        Finalizer,
+        #[error("failed to deserialize the event")]
+        DeserializeEvent,
+        #[error("invalid poll timeout")]
+        InvalidTimeout,
+    }
+
+    #[test]
+    fn handler_errors_caused_by_timeouts_should_be_classified_as_rpc_timeouts() {
+        let err = report!(std::io::Error::new(
+            std::io::ErrorKind::TimedOut,
+            "operation timed out"
+        ))
+        .change_context(Error::Finalizer);
+        assert_eq!(MissReason::from_handler_error(&err), MissReason::RpcTimeout);
+
+        let err = report!(tonic::Status::deadline_exceeded("request expired"))
+            .change_context(Error::Finalizer);
+        assert_eq!(MissReason::from_handler_error(&err), MissReason::RpcTimeout);
+
+        let err = report!(Error::DeserializeEvent);
+        assert_eq!(
+            MissReason::from_handler_error(&err),
+            MissReason::HandlerError
+        );
+
+        // errors are classified by type, not by their message
+        let err = report!(Error::InvalidTimeout);
+        assert_eq!(
+            MissReason::from_handler_error(&err),
+            MissReason::HandlerError
+        );
+    }
+}
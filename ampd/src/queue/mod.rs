@@ -1,3 +0,0 @@
-mod msg_queue;
-mod proto;
-pub mod queued_broadcaster;
@@ -0,0 +1,393 @@
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use error_stack::ResultExt;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::tofnd::error::Error as SigningError;
+use crate::tofnd::grpc::{Multisig, MultisigClient};
+use crate::tofnd::{self, Algorithm, MessageDigest, Signature};
+use crate::types::PublicKey;
+
+pub mod pkcs11;
+
+type Result<T> = error_stack::Result<T, SigningError>;
+
+/// Health check results are reused for this long, so frequent status probes don't query the backends every time
+const HEALTH_CHECK_CACHE_TTL: Duration = Duration::from_secs(30);
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("failed to connect to tofnd")]
+    Tofnd,
+    #[error("failed to open a session with the PKCS#11 token")]
+    Pkcs11,
+    #[error("key {0} is not available on the PKCS#11 token")]
+    MissingKey(String),
+}
+
+/// A signing backend that holds keys and signs with them
+#[async_trait]
+pub trait Backend: Multisig + Send + Sync {
+    /// Fails if the backend is not able to sign
+    async fn health_check(&self) -> Result<()>;
+}
+
+/// tofnd together with the key it is expected to hold, so its availability can be checked
+#[derive(Clone)]
+pub struct Tofnd {
+    client: MultisigClient,
+    key_uid: String,
+}
+
+impl Tofnd {
+    pub async fn connect(config: &tofnd::Config) -> error_stack::Result<Self, Error> {
+        let client = MultisigClient::new(
+            config.party_uid.clone(),
+            config.url.as_str(),
+            config.timeout,
+        )
+        .await
+        .change_context(Error::Tofnd)
+        .attach_printable(config.url.clone())?;
+
+        Ok(Self {
+            client,
+            key_uid: config.key_uid.clone(),
+        })
+    }
+}
+
+#[async_trait]
+impl Multisig for Tofnd {
+    async fn keygen(&self, key_uid: &str, algorithm: Algorithm) -> Result<PublicKey> {
+        self.client.keygen(key_uid, algorithm).await
+    }
+
+    async fn sign(
+        &self,
+        key_uid: &str,
+        data: MessageDigest,
+        pub_key: PublicKey,
+        algorithm: Algorithm,
+    ) -> Result<Signature> {
+        self.client.sign(key_uid, data, pub_key, algorithm).await
+    }
+}
+
+#[async_trait]
+impl Backend for Tofnd {
+    async fn health_check(&self) -> Result<()> {
+        // the key might be held by the HSM instead, so tofnd only needs to be able to answer
+        self.client
+            .key_presence(&self.key_uid, Algorithm::Ecdsa)
+            .await
+            .map(|_| ())
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub struct Metrics {
+    pub tofnd: BackendMetrics,
+    /// only set if a PKCS#11 token is configured
+    pub pkcs11: Option<BackendMetrics>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub struct BackendMetrics {
+    /// whether the last request or health check succeeded
+    pub healthy: bool,
+    /// number of keygen and sign requests
+    pub requests: u64,
+    /// number of keygen and sign requests that failed
+    pub failures: u64,
+    /// latency of the last request in milliseconds
+    pub last_latency_ms: u64,
+    /// highest latency of any request in milliseconds
+    pub max_latency_ms: u64,
+    /// sum of the latencies of all requests in milliseconds, to calculate the average latency
+    pub total_latency_ms: u64,
+}
+
+impl BackendMetrics {
+    fn record_request(&mut self, latency: Duration, succeeded: bool) {
+        let latency_ms = u64::try_from(latency.as_millis()).unwrap_or(u64::MAX);
+
+        self.healthy = succeeded;
+        self.requests = self.requests.saturating_add(1);
+        if !succeeded {
+            self.failures = self.failures.saturating_add(1);
+        }
+        self.last_latency_ms = latency_ms;
+        self.max_latency_ms = self.max_latency_ms.max(latency_ms);
+        self.total_latency_ms = self.total_latency_ms.saturating_add(latency_ms);
+    }
+}
+
+#[derive(Clone, Copy)]
+enum BackendKind {
+    Tofnd,
+    Pkcs11,
+}
+
+/// Routes keygen and sign requests to the backend that holds the key, and keeps track of the
+/// health and latency of each backend. Keys are held by tofnd unless they are configured to be held by a PKCS#11 token.
+#[derive(Clone)]
+pub struct Signer {
+    tofnd: Arc<dyn Backend>,
+    pkcs11: Option<(Arc<dyn Backend>, HashSet<String>)>,
+    metrics: Arc<Mutex<Metrics>>,
+    last_health_check: Arc<tokio::sync::Mutex<Option<(Instant, bool)>>>,
+}
+
+impl Signer {
+    pub fn new(tofnd: impl Backend + 'static) -> Self {
+        Self {
+            tofnd: Arc::new(tofnd),
+            pkcs11: None,
+            metrics: Arc::new(Mutex::new(Metrics::default())),
+            last_health_check: Arc::new(tokio::sync::Mutex::new(None)),
+        }
+    }
+
+    pub fn with_pkcs11(
+        mut self,
+        backend: impl Backend + 'static,
+        key_uids: impl IntoIterator<Item = String>,
+    ) -> Self {
+        self.pkcs11 = Some((Arc::new(backend), key_uids.into_iter().collect()));
+        self.lock().pkcs11 = Some(BackendMetrics::default());
+        self
+    }
+
+    pub async fn connect(
+        tofnd_config: &tofnd::Config,
+        pkcs11_config: Option<&pkcs11::Config>,
+    ) -> error_stack::Result<Self, Error> {
+        let signer = Self::new(Tofnd::connect(tofnd_config).await?);
+
+        match pkcs11_config {
+            Some(config) => {
+                let client = pkcs11::Pkcs11Client::connect(config)?;
+                let key_uids: Vec<_> = client.key_uids().cloned().collect();
+
+                Ok(signer.with_pkcs11(client, key_uids))
+            }
+            None => Ok(signer),
+        }
+    }
+
+    /// Checks every backend, so the result is reflected in the metrics
+    pub async fn health_check(&self) -> Result<()> {
+        let tofnd = self.tofnd.health_check().await;
+        self.lock().tofnd.healthy = tofnd.is_ok();
+
+        if let Some((backend, _)) = &self.pkcs11 {
+            let pkcs11 = backend.health_check().await;
+            if let Some(metrics) = self.lock().pkcs11.as_mut() {
+                metrics.healthy = pkcs11.is_ok();
+            }
+
+            tofnd.and(pkcs11)
+        } else {
+            tofnd
+        }
+    }
+
+    /// Returns the result of the last health check, unless it is older than [HEALTH_CHECK_CACHE_TTL].
+    /// Concurrent calls wait for the same check instead of querying the backends in parallel
+    pub async fn is_healthy(&self) -> bool {
+        let mut last_health_check = self.last_health_check.lock().await;
+
+        match *last_health_check {
+            Some((checked_at, healthy)) if checked_at.elapsed() < HEALTH_CHECK_CACHE_TTL => healthy,
+            _ => {
+                let healthy = self.health_check().await.is_ok();
+                *last_health_check = Some((Instant::now(), healthy));
+
+                healthy
+            }
+        }
+    }
+
+    pub fn metrics(&self) -> Metrics {
+        self.lock().clone()
+    }
+
+    fn backend(&self, key_uid: &str) -> (BackendKind, &Arc<dyn Backend>) {
+        match &self.pkcs11 {
+            Some((backend, key_uids)) if key_uids.contains(key_uid) => {
+                (BackendKind::Pkcs11, backend)
+            }
+            _ => (BackendKind::Tofnd, &self.tofnd),
+        }
+    }
+
+    fn record_request(&self, kind: BackendKind, latency: Duration, succeeded: bool) {
+        let mut metrics = self.lock();
+
+        let backend_metrics = match kind {
+            BackendKind::Tofnd => Some(&mut metrics.tofnd),
+            BackendKind::Pkcs11 => metrics.pkcs11.as_mut(),
+        };
+
+        if let Some(backend_metrics) = backend_metrics {
+            backend_metrics.record_request(latency, succeeded);
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Metrics> {
+        self.metrics
+            .lock()
+            .expect("signer metrics lock should not be poisoned")
+    }
+}
+
+#[async_trait]
+impl Multisig for Signer {
+    async fn keygen(&self, key_uid: &str, algorithm: Algorithm) -> Result<PublicKey> {
+        let (kind, backend) = self.backend(key_uid);
+
+        let start = Instant::now();
+        let result = backend.keygen(key_uid, algorithm).await;
+        self.record_request(kind, start.elapsed(), result.is_ok());
+
+        result
+    }
+
+    async fn sign(
+        &self,
+        key_uid: &str,
+        data: MessageDigest,
+        pub_key: PublicKey,
+        algorithm: Algorithm,
+    ) -> Result<Signature> {
+        let (kind, backend) = self.backend(key_uid);
+
+        let start = Instant::now();
+        let result = backend.sign(key_uid, data, pub_key, algorithm).await;
+        self.record_request(kind, start.elapsed(), result.is_ok());
+
+        result
+    }
+}
+
+#[cfg(test)]
+mockall::mock! {
+    pub Backend {}
+
+    #[async_trait]
+    impl Multisig for Backend {
+        async fn keygen(&self, key_uid: &str, algorithm: Algorithm) -> Result<PublicKey>;
+        async fn sign(
+            &self,
+            key_uid: &str,
+            data: MessageDigest,
+            pub_key: PublicKey,
+            algorithm: Algorithm,
+        ) -> Result<Signature>;
+    }
+
+    #[async_trait]
+    impl Backend for Backend {
+        async fn health_check(&self) -> Result<()>;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use error_stack::report;
+    use rand::rngs::OsRng;
+
+    use super::*;
+
+    fn pub_key() -> PublicKey {
+        PublicKey::Secp256k1(*k256::ecdsa::SigningKey::random(&mut OsRng).verifying_key())
+    }
+
+    #[tokio::test]
+    async fn requests_should_be_routed_to_the_backend_that_holds_the_key() {
+        let hsm_key = pub_key();
+        let tofnd_key = pub_key();
+
+        let mut tofnd = MockBackend::new();
+        tofnd
+            .expect_keygen()
+            .withf(|key_uid, _| key_uid == "multisig")
+            .times(1)
+            .returning(move |_, _| Ok(tofnd_key));
+
+        let mut pkcs11 = MockBackend::new();
+        pkcs11
+            .expect_keygen()
+            .withf(|key_uid, _| key_uid == "axelar")
+            .times(1)
+            .returning(move |_, _| Ok(hsm_key));
+        pkcs11
+            .expect_sign()
+            .times(1)
+            .returning(|_, _, _, _| Err(report!(SigningError::SignFailed)));
+
+        let signer = Signer::new(tofnd).with_pkcs11(pkcs11, ["axelar".to_string()]);
+
+        assert_eq!(
+            signer.keygen("axelar", Algorithm::Ecdsa).await.unwrap(),
+            hsm_key
+        );
+        assert_eq!(
+            signer.keygen("multisig", Algorithm::Ecdsa).await.unwrap(),
+            tofnd_key
+        );
+        assert!(signer
+            .sign("axelar", [0; 32].into(), hsm_key, Algorithm::Ecdsa)
+            .await
+            .is_err());
+
+        let metrics = signer.metrics();
+        assert_eq!(metrics.tofnd.requests, 1);
+        assert_eq!(metrics.tofnd.failures, 0);
+        assert!(metrics.tofnd.healthy);
+
+        let pkcs11_metrics = metrics.pkcs11.unwrap();
+        assert_eq!(pkcs11_metrics.requests, 2);
+        assert_eq!(pkcs11_metrics.failures, 1);
+        assert!(!pkcs11_metrics.healthy);
+    }
+
+    #[tokio::test]
+    async fn health_check_should_fail_if_any_backend_is_unhealthy() {
+        let mut tofnd = MockBackend::new();
+        tofnd.expect_health_check().returning(|| Ok(()));
+
+        let signer = Signer::new(tofnd);
+        assert!(signer.health_check().await.is_ok());
+        assert!(signer.metrics().tofnd.healthy);
+        assert_eq!(signer.metrics().pkcs11, None);
+
+        let mut pkcs11 = MockBackend::new();
+        pkcs11
+            .expect_health_check()
+            .returning(|| Err(report!(SigningError::KeyPresenceFailed)));
+
+        let signer = signer.with_pkcs11(pkcs11, []);
+        assert!(signer.health_check().await.is_err());
+        assert!(signer.metrics().tofnd.healthy);
+        assert!(!signer.metrics().pkcs11.unwrap().healthy);
+    }
+
+    #[tokio::test]
+    async fn health_check_result_should_be_cached() {
+        let mut tofnd = MockBackend::new();
+        tofnd
+            .expect_health_check()
+            .times(1)
+            .returning(|| Err(report!(SigningError::KeyPresenceFailed)));
+
+        let signer = Signer::new(tofnd);
+        assert!(!signer.is_healthy().await);
+        assert!(!signer.is_healthy().await);
+    }
+}
@@ -0,0 +1,339 @@
+use std::collections::HashMap;
+use std::env;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use cryptoki::context::{CInitializeArgs, Pkcs11};
+use cryptoki::mechanism::Mechanism;
+use cryptoki::object::{Attribute, AttributeType, KeyType, ObjectClass, ObjectHandle};
+use cryptoki::session::{Session, UserType};
+use cryptoki::slot::Slot;
+use cryptoki::types::AuthPin;
+use der::asn1::OctetString;
+use der::Decode;
+use error_stack::{report, ResultExt};
+use serde::{Deserialize, Serialize};
+
+use super::{Backend, Error};
+use crate::tofnd::error::Error as SigningError;
+use crate::tofnd::grpc::Multisig;
+use crate::tofnd::{Algorithm, MessageDigest, Signature};
+use crate::types::PublicKey;
+
+type Result<T> = error_stack::Result<T, SigningError>;
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct Config {
+    /// Path to the PKCS#11 library provided by the HSM vendor
+    pub module_path: PathBuf,
+    /// Label of the token that holds the keys
+    pub token_label: String,
+    /// Environment variable that holds the user PIN of the token, so the PIN is never written to the config file
+    #[serde(default = "default_pin_env")]
+    pub pin_env: String,
+    /// Keys that are held by the HSM. All other keys are held by tofnd
+    pub keys: Vec<KeyConfig>,
+}
+
+fn default_pin_env() -> String {
+    "AMPD_PKCS11_PIN".to_string()
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct KeyConfig {
+    /// Key uid that ampd uses for the key, e.g. tofnd_config.key_uid for the broadcaster key
+    pub key_uid: String,
+    /// Label of the key pair on the token
+    pub label: String,
+}
+
+/// Signs with keys that never leave the HSM. Keys cannot be generated by ampd, they must be
+/// provisioned on the token beforehand, so keygen only loads the public key of an existing key pair.
+/// The session is reopened whenever the token invalidated it, e.g. after the HSM was restarted
+#[derive(Clone)]
+pub struct Pkcs11Client {
+    context: Pkcs11,
+    slot: Slot,
+    pin: Arc<AuthPin>,
+    session: Arc<Mutex<Session>>,
+    labels: HashMap<String, String>,
+}
+
+impl Pkcs11Client {
+    pub fn connect(config: &Config) -> error_stack::Result<Self, Error> {
+        let context = Pkcs11::new(&config.module_path)
+            .change_context(Error::Pkcs11)
+            .attach_printable_lazy(|| config.module_path.display().to_string())?;
+        context
+            .initialize(CInitializeArgs::OsThreads)
+            .change_context(Error::Pkcs11)?;
+
+        let slot = context
+            .get_slots_with_token()
+            .change_context(Error::Pkcs11)?
+            .into_iter()
+            .find(|slot| {
+                context
+                    .get_token_info(*slot)
+                    .is_ok_and(|info| info.label() == config.token_label)
+            })
+            .ok_or(report!(Error::Pkcs11))
+            .attach_printable_lazy(|| format!("token {} not found", config.token_label))?;
+
+        let pin = env::var(&config.pin_env)
+            .map(AuthPin::new)
+            .change_context(Error::Pkcs11)
+            .attach_printable_lazy(|| format!("PIN variable {} is not set", config.pin_env))?;
+        let session = open_session(&context, slot, &pin)?;
+
+        for key in &config.keys {
+            find_key(&session, ObjectClass::PRIVATE_KEY, &key.label)
+                .change_context(Error::MissingKey(key.key_uid.clone()))
+                .attach_printable_lazy(|| format!("{{ label = {} }}", key.label))?;
+        }
+
+        Ok(Self {
+            context,
+            slot,
+            pin: Arc::new(pin),
+            session: Arc::new(Mutex::new(session)),
+            labels: config
+                .keys
+                .iter()
+                .map(|key| (key.key_uid.clone(), key.label.clone()))
+                .collect(),
+        })
+    }
+
+    pub fn key_uids(&self) -> impl Iterator<Item = &String> {
+        self.labels.keys()
+    }
+
+    fn label(&self, key_uid: &str) -> Result<String> {
+        self.labels
+            .get(key_uid)
+            .cloned()
+            .ok_or(report!(SigningError::KeygenFailed))
+            .attach_printable_lazy(|| format!("key {} is not held by the HSM", key_uid))
+    }
+
+    /// PKCS#11 calls block, so they are run on the blocking thread pool
+    async fn with_session<T, F>(&self, error: SigningError, f: F) -> Result<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&Session) -> Result<T> + Send + 'static,
+    {
+        let (context, slot, pin, session) = (
+            self.context.clone(),
+            self.slot,
+            self.pin.clone(),
+            self.session.clone(),
+        );
+
+        let result = tokio::task::spawn_blocking(move || -> error_stack::Result<_, Error> {
+            let mut session = session
+                .lock()
+                .expect("pkcs11 session lock should not be poisoned");
+
+            if session.get_session_info().is_err() {
+                *session = open_session(&context, slot, &pin)?;
+            }
+
+            Ok(f(&session))
+        })
+        .await;
+
+        match result {
+            Ok(Ok(result)) => result,
+            Ok(Err(err)) => Err(err.change_context(error)),
+            Err(err) => Err(report!(err).change_context(error)),
+        }
+    }
+}
+
+#[async_trait]
+impl Multisig for Pkcs11Client {
+    async fn keygen(&self, key_uid: &str, algorithm: Algorithm) -> Result<PublicKey> {
+        let label = self.label(key_uid)?;
+
+        self.with_session(SigningError::KeygenFailed, move |session| {
+            let handle = find_key(session, ObjectClass::PUBLIC_KEY, &label)?;
+            let attributes = session
+                .get_attributes(handle, &[AttributeType::KeyType, AttributeType::EcPoint])
+                .change_context(SigningError::KeygenFailed)?;
+
+            let (mut key_type, mut ec_point) = (None, None);
+            for attribute in attributes {
+                match attribute {
+                    Attribute::KeyType(value) => key_type = Some(value),
+                    Attribute::EcPoint(value) => ec_point = Some(value),
+                    _ => {}
+                }
+            }
+
+            let point = ec_point
+                .map(decode_ec_point)
+                .ok_or(report!(SigningError::ParsingFailed))
+                .attach_printable("public key has no EC point")?;
+
+            match (algorithm, key_type) {
+                (Algorithm::Ecdsa, Some(KeyType::EC)) => PublicKey::new_secp256k1(&point),
+                (Algorithm::Ed25519, Some(KeyType::EC_EDWARDS)) => PublicKey::new_ed25519(&point),
+                (_, key_type) => {
+                    return Err(report!(SigningError::KeygenFailed)).attach_printable(format!(
+                        "key {} has type {:?}, expected {:?}",
+                        label, key_type, algorithm
+                    ))
+                }
+            }
+            .change_context(SigningError::ParsingFailed)
+            .attach_printable(format!("{{ invalid_value = {:?} }}", point))
+        })
+        .await
+    }
+
+    async fn sign(
+        &self,
+        key_uid: &str,
+        data: MessageDigest,
+        _pub_key: PublicKey,
+        algorithm: Algorithm,
+    ) -> Result<Signature> {
+        let label = self
+            .label(key_uid)
+            .change_context(SigningError::SignFailed)?;
+
+        self.with_session(SigningError::SignFailed, move |session| {
+            let handle = find_key(session, ObjectClass::PRIVATE_KEY, &label)
+                .change_context(SigningError::SignFailed)?;
+
+            match algorithm {
+                Algorithm::Ecdsa => {
+                    let signature = session
+                        .sign(&Mechanism::Ecdsa, handle, data.as_ref())
+                        .change_context(SigningError::SignFailed)?;
+
+                    // unlike tofnd, HSMs don't normalize signatures, but high-S signatures are rejected on chain
+                    k256::ecdsa::Signature::from_slice(&signature)
+                        .map(|sig| sig.normalize_s().unwrap_or(sig).to_vec())
+                        .change_context(SigningError::ParsingFailed)
+                }
+                Algorithm::Ed25519 => {
+                    let signature = session
+                        .sign(&Mechanism::Eddsa, handle, data.as_ref())
+                        .change_context(SigningError::SignFailed)?;
+
+                    ed25519_dalek::Signature::from_slice(&signature)
+                        .map(|sig| sig.to_vec())
+                        .change_context(SigningError::ParsingFailed)
+                }
+            }
+        })
+        .await
+    }
+}
+
+#[async_trait]
+impl Backend for Pkcs11Client {
+    async fn health_check(&self) -> Result<()> {
+        let context = self.context.clone();
+        let slot = self.slot;
+
+        self.with_session(SigningError::KeyPresenceFailed, move |session| {
+            context
+                .get_token_info(slot)
+                .change_context(SigningError::KeyPresenceFailed)?;
+            session
+                .get_session_info()
+                .change_context(SigningError::KeyPresenceFailed)?;
+
+            Ok(())
+        })
+        .await
+    }
+}
+
+fn open_session(
+    context: &Pkcs11,
+    slot: Slot,
+    pin: &AuthPin,
+) -> error_stack::Result<Session, Error> {
+    let session = context
+        .open_ro_session(slot)
+        .change_context(Error::Pkcs11)?;
+    session
+        .login(UserType::User, Some(pin))
+        .change_context(Error::Pkcs11)?;
+
+    Ok(session)
+}
+
+fn find_key(session: &Session, class: ObjectClass, label: &str) -> Result<ObjectHandle> {
+    session
+        .find_objects(&[
+            Attribute::Class(class),
+            Attribute::Label(label.as_bytes().to_vec()),
+        ])
+        .change_context(SigningError::KeygenFailed)?
+        .into_iter()
+        .next()
+        .ok_or(report!(SigningError::KeygenFailed))
+        .attach_printable_lazy(|| format!("no {} with label {} found", class, label))
+}
+
+/// CKA_EC_POINT is a DER encoded octet string, but some HSMs return the raw point instead.
+/// Raw points are told apart by their length, because a raw point can also be valid DER by chance
+fn decode_ec_point(value: Vec<u8>) -> Vec<u8> {
+    const RAW_POINT_LENGTHS: [usize; 3] = [32, 33, 65];
+
+    if RAW_POINT_LENGTHS.contains(&value.len()) {
+        return value;
+    }
+
+    OctetString::from_der(&value)
+        .map(|point| point.as_bytes().to_vec())
+        .unwrap_or(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use der::Encode;
+    use rand::rngs::OsRng;
+
+    use super::*;
+
+    #[test]
+    fn ec_point_should_be_decoded_from_der_and_raw_bytes() {
+        let signing_key = k256::ecdsa::SigningKey::random(&mut OsRng);
+        let compressed = signing_key.verifying_key().to_sec1_bytes().to_vec();
+        let uncompressed = signing_key
+            .verifying_key()
+            .to_encoded_point(false)
+            .as_bytes()
+            .to_vec();
+        let ed25519 = ed25519_dalek::SigningKey::generate(&mut OsRng)
+            .verifying_key()
+            .to_bytes()
+            .to_vec();
+
+        for point in [compressed, uncompressed, ed25519] {
+            let encoded = OctetString::new(point.clone()).unwrap().to_der().unwrap();
+
+            assert_eq!(decode_ec_point(encoded), point);
+            assert_eq!(decode_ec_point(point.clone()), point);
+        }
+    }
+
+    #[test]
+    fn pin_should_be_read_from_the_default_variable_if_not_configured() {
+        let config: Config = serde_json::from_value(serde_json::json!({
+            "module_path": "/usr/lib/softhsm/libsofthsm2.so",
+            "token_label": "ampd",
+            "keys": [],
+        }))
+        .unwrap();
+
+        assert_eq!(config.pin_env, "AMPD_PKCS11_PIN");
+    }
+}
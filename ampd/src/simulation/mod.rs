@@ -0,0 +1,586 @@
+//! Deterministic simulation of ampd for integration tests. A scenario file scripts the blocks of the axelar chain
+//! and their contract events, the responses of external chain RPC endpoints and the signatures of tofnd.
+//! The handlers are created by the same factory as in production, and their messages go through the msg queue
+//! and the broadcaster down to the cosmos client, which records the broadcast txs instead of sending them.
+//! Time only advances through tokio's paused test clock, so the broadcast messages of a scenario are always the same.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use cosmrs::cosmwasm::MsgExecuteContract;
+use cosmrs::proto::cosmos::auth::v1beta1::{
+    BaseAccount, QueryAccountRequest, QueryAccountResponse,
+};
+use cosmrs::proto::cosmos::bank::v1beta1::{QueryBalanceRequest, QueryBalanceResponse};
+use cosmrs::proto::cosmos::base::abci::v1beta1::{GasInfo, TxResponse};
+use cosmrs::proto::cosmos::tx::v1beta1::{
+    BroadcastTxRequest, BroadcastTxResponse, GetTxRequest, GetTxResponse, SimulateRequest,
+    SimulateResponse,
+};
+use cosmrs::proto::cosmwasm::wasm::v1::{
+    QuerySmartContractStateRequest, QuerySmartContractStateResponse,
+};
+use cosmrs::tx::Msg;
+use cosmrs::Any;
+use cosmwasm_std::HexBinary;
+use error_stack::{report, Report, Result};
+use events::Event;
+use futures::StreamExt;
+use k256::ecdsa::SigningKey;
+use k256::sha2::{Digest, Sha256};
+use serde::Deserialize;
+use serde_json::Value;
+use tendermint::{abci, block};
+use tokio::time::Instant;
+use tokio_util::sync::CancellationToken;
+
+use crate::block_height_monitor::BlockHeightMonitor;
+use crate::broadcaster;
+use crate::broadcaster_v2::{self, BatchRequest, BroadcasterTask, MsgQueue};
+use crate::cosmos::{self, CosmosClient};
+use crate::event_processor;
+use crate::event_sub::{EventPublisher, EventSub};
+use crate::handler_supervisor::HandlerFactory;
+use crate::handlers;
+use crate::handlers::chain_lag::ChainLag;
+use crate::handlers::circuit_breaker::CircuitBreaker;
+use crate::handlers::rpc_divergence::RpcDivergence;
+use crate::handlers::signed_sessions::SignedSessions;
+use crate::handlers::vote_cache::VoteCache;
+use crate::json_rpc::replay::{Cassette, Replay};
+use crate::poll_watchdog::PollWatchdog;
+use crate::tm_client::{self, TmClient};
+use crate::tofnd::error::Error as TofndError;
+use crate::tofnd::grpc::Multisig;
+use crate::tofnd::{self, MessageDigest, Signature};
+use crate::types::{CosmosPublicKey, PublicKey, TMAddress};
+use crate::url::Url;
+use crate::{EventHandlerFactory, EvmRpc};
+
+const SCENARIOS_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/simulation/scenarios");
+const BROADCASTER_KEY_UID: &str = "broadcaster";
+// gas that the simulated axelar node reports for every simulated tx
+const SIMULATED_GAS: u64 = 100000;
+
+/// Scripted environment of ampd and the messages it is expected to broadcast
+#[derive(Debug, Deserialize)]
+pub struct Scenario {
+    pub verifier: TMAddress,
+    /// Time between two blocks of the axelar chain
+    #[serde(with = "humantime_serde")]
+    pub block_time: Duration,
+    /// Handlers in the format of the ampd config. The RPC urls of EVM handlers are ignored, their responses are taken
+    /// from `evm_rpc` instead. All other handlers connect to their configured endpoints
+    pub handlers: Vec<handlers::config::Config>,
+    /// The chain starts at the height of the first block and stops at the last one. Heights in between without
+    /// a scripted block are empty blocks
+    pub blocks: Vec<Block>,
+    #[serde(default)]
+    pub evm_rpc: Cassette,
+    /// Signatures of tofnd by hex encoded message digest
+    #[serde(default)]
+    pub signatures: HashMap<String, HexBinary>,
+    pub expected_broadcasts: Vec<Broadcast>,
+}
+
+impl Scenario {
+    pub fn load(name: &str) -> Self {
+        let file = std::fs::File::open(format!("{}/{}", SCENARIOS_DIR, name))
+            .expect("scenario should exist");
+        serde_json::from_reader(file).expect("scenario should be valid")
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Block {
+    pub height: u64,
+    #[serde(default)]
+    pub events: Vec<ContractEvent>,
+}
+
+/// Event as it is emitted by a contract. Attribute values are JSON encoded like the attributes of the contracts
+#[derive(Debug, Deserialize)]
+pub struct ContractEvent {
+    #[serde(rename = "type")]
+    pub event_type: String,
+    pub contract: TMAddress,
+    pub attributes: serde_json::Map<String, Value>,
+}
+
+impl From<&ContractEvent> for abci::Event {
+    fn from(event: &ContractEvent) -> Self {
+        let contract_address = ("_contract_address".to_string(), event.contract.to_string());
+        let attributes = event
+            .attributes
+            .iter()
+            .map(|(key, value)| {
+                (
+                    key.clone(),
+                    serde_json::to_string(value).expect("attribute value should serialize"),
+                )
+            })
+            .chain(std::iter::once(contract_address))
+            .map(|(key, value)| abci::EventAttribute {
+                key: STANDARD.encode(key),
+                value: STANDARD.encode(value),
+                index: false,
+            })
+            .collect::<Vec<_>>();
+
+        abci::Event::new(format!("wasm-{}", event.event_type), attributes)
+    }
+}
+
+/// Contract execution that was broadcast by ampd
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct Broadcast {
+    pub contract: TMAddress,
+    pub msg: Value,
+}
+
+/// Axelar chain that produces the scripted blocks in the interval of the block time,
+/// measured from the moment it was created
+#[derive(Clone)]
+struct ScriptedChain {
+    events: Arc<BTreeMap<u64, Vec<abci::Event>>>,
+    first_height: u64,
+    last_height: u64,
+    block_time: Duration,
+    started_at: Instant,
+    block_template: Arc<tendermint::Block>,
+}
+
+impl ScriptedChain {
+    fn new(scenario: &Scenario) -> Self {
+        let events: BTreeMap<_, _> = scenario
+            .blocks
+            .iter()
+            .map(|block| (block.height, block.events.iter().map(Into::into).collect()))
+            .collect();
+        let first_height = *events.keys().next().expect("scenario should have blocks");
+        let last_height = *events
+            .keys()
+            .next_back()
+            .expect("scenario should have blocks");
+
+        Self {
+            events: Arc::new(events),
+            first_height,
+            last_height,
+            block_time: scenario.block_time,
+            started_at: Instant::now(),
+            block_template: Arc::new(
+                serde_json::from_str(include_str!("../tests/axelar_block.json"))
+                    .expect("block template should be valid"),
+            ),
+        }
+    }
+
+    fn latest_height(&self) -> u64 {
+        let produced_blocks = self
+            .started_at
+            .elapsed()
+            .as_nanos()
+            .checked_div(self.block_time.as_nanos())
+            .unwrap_or(u128::MAX);
+
+        u64::try_from(produced_blocks)
+            .unwrap_or(u64::MAX)
+            .saturating_add(self.first_height)
+            .min(self.last_height)
+    }
+}
+
+#[async_trait]
+impl TmClient for ScriptedChain {
+    async fn latest_block(&self) -> Result<tm_client::BlockResponse, tm_client::Error> {
+        let mut block = (*self.block_template).clone();
+        block.header.height = self
+            .latest_height()
+            .try_into()
+            .expect("block height should be valid");
+
+        Ok(tm_client::BlockResponse {
+            block_id: Default::default(),
+            block,
+        })
+    }
+
+    async fn block_results(
+        &self,
+        height: block::Height,
+    ) -> Result<tm_client::BlockResultsResponse, tm_client::Error> {
+        if height.value() > self.latest_height() {
+            return Err(report!(tm_client::Error::server(format!(
+                "block {} was not produced yet",
+                height
+            ))));
+        }
+
+        let txs_events = self
+            .events
+            .get(&height.value())
+            .cloned()
+            .unwrap_or_default();
+
+        Ok(tm_client::BlockResultsResponse {
+            height,
+            begin_block_events: Some(vec![]),
+            end_block_events: Some(vec![]),
+            consensus_param_updates: None,
+            txs_results: Some(
+                txs_events
+                    .into_iter()
+                    .map(|event| abci::types::ExecTxResult {
+                        events: vec![event],
+                        ..Default::default()
+                    })
+                    .collect(),
+            ),
+            validator_updates: vec![],
+            app_hash: Default::default(),
+            finalize_block_events: vec![],
+        })
+    }
+
+    async fn tx_search(
+        &self,
+        _query: tendermint_rpc::query::Query,
+        _page: u32,
+        _per_page: u8,
+    ) -> Result<tm_client::TxSearchResponse, tm_client::Error> {
+        Err(report!(tm_client::Error::server(
+            "transaction search is not part of the simulation".to_string()
+        )))
+    }
+}
+
+/// Tofnd that signs the txs of the broadcaster, but otherwise only signs the message digests of the scenario
+#[derive(Clone)]
+struct ScriptedSigner {
+    broadcaster_key: SigningKey,
+    signatures: Arc<HashMap<String, HexBinary>>,
+}
+
+impl ScriptedSigner {
+    fn new(scenario: &Scenario) -> Self {
+        Self {
+            broadcaster_key: SigningKey::from_slice(&[1; 32])
+                .expect("broadcaster key should be valid"),
+            signatures: Arc::new(scenario.signatures.clone()),
+        }
+    }
+}
+
+#[async_trait]
+impl Multisig for ScriptedSigner {
+    async fn keygen(
+        &self,
+        key_uid: &str,
+        _algorithm: tofnd::Algorithm,
+    ) -> Result<PublicKey, TofndError> {
+        if key_uid != BROADCASTER_KEY_UID {
+            return Err(report!(TofndError::KeygenFailed));
+        }
+
+        Ok(CosmosPublicKey::from(self.broadcaster_key.verifying_key()).into())
+    }
+
+    async fn sign(
+        &self,
+        key_uid: &str,
+        data: MessageDigest,
+        _pub_key: PublicKey,
+        _algorithm: tofnd::Algorithm,
+    ) -> Result<Signature, TofndError> {
+        if key_uid == BROADCASTER_KEY_UID {
+            return self
+                .broadcaster_key
+                .sign_prehash_recoverable(<Vec<u8>>::from(data).as_slice())
+                .map(|(signature, _)| signature.to_vec())
+                .map_err(|_| report!(TofndError::SignFailed));
+        }
+
+        self.signatures
+            .get(&hex::encode(data))
+            .map(HexBinary::to_vec)
+            .ok_or_else(|| report!(TofndError::SignFailed))
+    }
+}
+
+/// Answers the requests of every EVM handler with the recorded responses of the scenario
+struct ReplayedEvmRpc(Replay);
+
+impl EvmRpc for ReplayedEvmRpc {
+    type Provider = Replay;
+
+    fn provider(&self, _url: &Url, _rpc_timeout: Option<Duration>) -> Result<Replay, crate::Error> {
+        Ok(self.0.clone())
+    }
+}
+
+/// Axelar node that accepts all txs and records the contract executions in them instead of executing them
+#[derive(Clone, Default)]
+struct RecordingCosmosClient {
+    broadcasts: Arc<Mutex<Vec<Broadcast>>>,
+}
+
+impl RecordingCosmosClient {
+    fn broadcasts(&self) -> Vec<Broadcast> {
+        self.broadcasts
+            .lock()
+            .expect("broadcasts lock should not be poisoned")
+            .clone()
+    }
+}
+
+#[async_trait]
+impl CosmosClient for RecordingCosmosClient {
+    async fn broadcast_tx(
+        &mut self,
+        req: BroadcastTxRequest,
+    ) -> Result<BroadcastTxResponse, cosmos::Error> {
+        let tx = cosmrs::Tx::from_bytes(&req.tx_bytes).expect("tx should be valid");
+        let executions = tx
+            .body
+            .messages
+            .iter()
+            .map(|msg| msg.to_msg::<BatchRequest>().expect("tx should batch msgs"))
+            .flat_map(|batch| batch.messages)
+            .map(|msg| {
+                let msg =
+                    MsgExecuteContract::from_any(&msg).expect("msg should execute a contract");
+
+                Broadcast {
+                    contract: msg.contract.into(),
+                    msg: serde_json::from_slice(&msg.msg).expect("contract msg should be JSON"),
+                }
+            });
+
+        self.broadcasts
+            .lock()
+            .expect("broadcasts lock should not be poisoned")
+            .extend(executions);
+
+        Ok(BroadcastTxResponse {
+            tx_response: Some(TxResponse {
+                txhash: hex::encode(Sha256::digest(&req.tx_bytes)),
+                ..Default::default()
+            }),
+        })
+    }
+
+    async fn simulate(&mut self, _req: SimulateRequest) -> Result<SimulateResponse, cosmos::Error> {
+        Ok(SimulateResponse {
+            gas_info: Some(GasInfo {
+                gas_wanted: 0,
+                gas_used: SIMULATED_GAS,
+            }),
+            result: None,
+        })
+    }
+
+    async fn tx(&mut self, _req: GetTxRequest) -> Result<GetTxResponse, cosmos::Error> {
+        Err(not_simulated("tx query"))
+    }
+
+    async fn account(
+        &mut self,
+        request: QueryAccountRequest,
+    ) -> Result<QueryAccountResponse, cosmos::Error> {
+        let account = BaseAccount {
+            address: request.address,
+            pub_key: None,
+            account_number: 0,
+            sequence: 0,
+        };
+
+        Ok(QueryAccountResponse {
+            account: Some(Any::from_msg(&account).expect("account should serialize")),
+        })
+    }
+
+    async fn balance(
+        &mut self,
+        _request: QueryBalanceRequest,
+    ) -> Result<QueryBalanceResponse, cosmos::Error> {
+        Err(not_simulated("balance query"))
+    }
+
+    async fn smart_contract_state(
+        &mut self,
+        _request: QuerySmartContractStateRequest,
+    ) -> Result<QuerySmartContractStateResponse, cosmos::Error> {
+        Err(not_simulated("contract query"))
+    }
+}
+
+fn not_simulated(request: &str) -> Report<cosmos::Error> {
+    report!(cosmos::Error::GrpcRequest(tonic::Status::unimplemented(
+        format!("{} is not part of the simulation", request)
+    )))
+}
+
+/// Runs the handlers of the scenario until all of its blocks are processed and returns the broadcast messages in order
+pub async fn run(scenario: &Scenario) -> Vec<Broadcast> {
+    let token = CancellationToken::new();
+    let chain = ScriptedChain::new(scenario);
+    let cosmos_client = RecordingCosmosClient::default();
+    let signer = ScriptedSigner::new(scenario);
+    let broadcast = broadcaster::Config::default();
+
+    let block_height_monitor = BlockHeightMonitor::connect(chain.clone())
+        .await
+        .expect("block height monitor should connect");
+    let (event_publisher, event_subscriber) = EventPublisher::new(chain.clone(), 100000);
+
+    let pub_key = signer
+        .keygen(BROADCASTER_KEY_UID, tofnd::Algorithm::Ecdsa)
+        .await
+        .expect("broadcaster key should exist");
+    let broadcaster = broadcaster_v2::Broadcaster::new(
+        cosmos_client.clone(),
+        broadcast.chain_id.clone(),
+        CosmosPublicKey::try_from(pub_key).expect("broadcaster key should be valid"),
+    )
+    .await
+    .expect("broadcaster account should exist");
+    let (msg_queue, msg_queue_client) = MsgQueue::new_msg_queue_and_client(
+        broadcaster.clone(),
+        broadcast.queue_cap,
+        broadcast.batch_gas_limit,
+        broadcast.broadcast_interval,
+    );
+    let broadcaster_task = BroadcasterTask::builder()
+        .broadcaster(broadcaster)
+        .msg_queue(msg_queue)
+        .signer(signer.clone())
+        .key_id(BROADCASTER_KEY_UID.to_string())
+        .gas_adjustment(broadcast.gas_adjustment)
+        .gas_price(broadcast.gas_price.clone())
+        .build();
+
+    // subscribe before any block is published, so no handler misses an event
+    let mut block_ends = event_subscriber.subscribe();
+    let handler_factory = EventHandlerFactory {
+        verifier: scenario.verifier.clone(),
+        signer,
+        latest_block_height: block_height_monitor.latest_block_height(),
+        signed_sessions: SignedSessions::default(),
+        vote_cache: VoteCache::default(),
+        rpc_divergence: RpcDivergence::default(),
+        chain_lag: ChainLag::default(),
+        circuit_breaker: CircuitBreaker::default(),
+        broadcaster: Some(msg_queue_client),
+        handler_weight: broadcast.handler_weight,
+        poll_watchdog: PollWatchdog::new(
+            scenario.verifier.clone(),
+            scenario
+                .handlers
+                .iter()
+                .flat_map(handlers::config::Config::contracts),
+        ),
+        event_subscriber,
+        event_processor_config: event_processor::Config::default(),
+        evm_rpc: ReplayedEvmRpc(Replay::new(scenario.evm_rpc.clone())),
+    };
+
+    let mut handler_tasks = vec![];
+    for config in scenario.handlers.iter().cloned() {
+        let task = handler_factory
+            .create(config)
+            .await
+            .expect("handler should be created");
+        handler_tasks.push(tokio::spawn(task.run(token.clone())));
+    }
+    // afterwards the handlers hold the only msg queue clients, so the broadcaster stops once they are done
+    drop(handler_factory);
+
+    let broadcaster_task = tokio::spawn(broadcaster_task.run());
+    let block_height_monitor = tokio::spawn(block_height_monitor.run(token.child_token()));
+    let event_publisher = tokio::spawn(event_publisher.run(token.child_token()));
+
+    while let Some(event) = block_ends.next().await {
+        if matches!(event, Ok(Event::BlockEnd(height)) if height.value() == chain.last_height) {
+            break;
+        }
+    }
+
+    // the handlers stop at the end of the next block or when their event stream times out
+    token.cancel();
+    for task in handler_tasks {
+        task.await
+            .expect("handler should not panic")
+            .expect("handler should consume all events");
+    }
+    broadcaster_task
+        .await
+        .expect("broadcaster should not panic")
+        .expect("broadcaster should broadcast all queued msgs");
+    event_publisher
+        .await
+        .expect("event publisher should not panic")
+        .expect("event publisher should stop");
+    block_height_monitor
+        .await
+        .expect("block height monitor should not panic")
+        .expect("block height monitor should stop");
+
+    cosmos_client.broadcasts()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use itertools::Itertools;
+    use serde_json::Value;
+
+    use super::{run, Broadcast, Scenario};
+
+    async fn assert_scenario(name: &str) {
+        let scenario = Scenario::load(name);
+
+        assert_eq!(
+            by_contract(run(&scenario).await),
+            by_contract(scenario.expected_broadcasts)
+        );
+    }
+
+    // handlers run concurrently, so only the order of the broadcasts of each handler is deterministic
+    fn by_contract(broadcasts: Vec<Broadcast>) -> HashMap<String, Vec<Value>> {
+        broadcasts
+            .into_iter()
+            .map(|broadcast| (broadcast.contract.to_string(), broadcast.msg))
+            .into_group_map()
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn evm_poll_votes() {
+        assert_scenario("evm_poll_votes.json").await;
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn evm_skipped_polls() {
+        assert_scenario("evm_skipped_polls.json").await;
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn multisig_signing_sessions() {
+        assert_scenario("multisig_signing_sessions.json").await;
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn multisig_skipped_sessions() {
+        assert_scenario("multisig_skipped_sessions.json").await;
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn voting_and_signing_in_the_same_blocks() {
+        assert_scenario("voting_and_signing.json").await;
+    }
+}
@@ -204,6 +204,7 @@ mod tests {
             },
             threshold: 700_u128.into(),
             created_at: 1,
+            min_signers: None,
         };
         let verifier_set_hash = [
             172, 102, 223, 34, 98, 37, 150, 236, 159, 55, 30, 83, 126, 25, 217, 16, 52, 190, 185,
@@ -3,12 +3,14 @@
 
 use async_trait::async_trait;
 use axelar_wasm_std::msg_id::FieldElementAndEventIndex;
+use error_stack::ResultExt;
 use mockall::automock;
 use starknet_core::types::{ExecutionResult, TransactionReceipt};
 use starknet_providers::jsonrpc::JsonRpcTransport;
 use starknet_providers::{JsonRpcClient, Provider, ProviderError};
 use thiserror::Error;
 
+use crate::handlers::chain_lag::{self, LatestHeight};
 use crate::types::starknet::events::contract_call::{ContractCallError, ContractCallEvent};
 use crate::types::starknet::events::signers_rotated::SignersRotatedEvent;
 
@@ -49,6 +51,20 @@ where
     }
 }
 
+/// Latest block of Starknet
+#[async_trait]
+impl<T> LatestHeight for Client<T>
+where
+    T: JsonRpcTransport + Send + Sync + 'static,
+{
+    async fn latest_height(&self) -> error_stack::Result<u64, chain_lag::Error> {
+        self.client
+            .block_number()
+            .await
+            .change_context(chain_lag::Error)
+    }
+}
+
 /// A trait for fetching a ContractCall event, by a given tx_hash
 /// and parsing parsing it into
 /// `crate::starknet::events::contract_call::ContractCallEvent`
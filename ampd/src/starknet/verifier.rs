@@ -288,6 +288,7 @@ mod tests {
             signers: btree_signers,
             threshold: Uint128::one(),
             created_at: 1,
+            min_signers: None,
         }
     }
 
@@ -294,6 +294,7 @@ mod test {
                 .collect(),
             threshold: Uint128::new(2u128),
             created_at: rand::random(),
+            min_signers: None,
         };
 
         assert_eq!(
@@ -401,6 +402,7 @@ mod test {
                     .collect(),
                 threshold,
                 created_at,
+                min_signers: None,
             },
         };
 
@@ -467,6 +467,7 @@ mod tests {
                     .collect(),
                 threshold,
                 created_at,
+                min_signers: None,
             },
         };
 
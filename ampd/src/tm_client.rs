@@ -4,12 +4,14 @@ use async_trait::async_trait;
 use error_stack::{Report, Result};
 use mockall::automock;
 use tendermint::block::Height;
-use tendermint_rpc::{Client, HttpClient};
+use tendermint_rpc::query::Query;
+use tendermint_rpc::{Client, HttpClient, Order};
 
 use crate::asyncutil::future::{self, RetryPolicy};
 
 pub type BlockResultsResponse = tendermint_rpc::endpoint::block_results::Response;
 pub type BlockResponse = tendermint_rpc::endpoint::block::Response;
+pub type TxSearchResponse = tendermint_rpc::endpoint::tx_search::Response;
 pub type Error = tendermint_rpc::Error;
 
 #[automock]
@@ -17,6 +19,12 @@ pub type Error = tendermint_rpc::Error;
 pub trait TmClient {
     async fn latest_block(&self) -> Result<BlockResponse, Error>;
     async fn block_results(&self, block_height: Height) -> Result<BlockResultsResponse, Error>;
+    async fn tx_search(
+        &self,
+        query: Query,
+        page: u32,
+        per_page: u8,
+    ) -> Result<TxSearchResponse, Error>;
 }
 
 #[async_trait]
@@ -44,4 +52,21 @@ impl TmClient for HttpClient {
         .await
         .map_err(Report::from)
     }
+
+    async fn tx_search(
+        &self,
+        query: Query,
+        page: u32,
+        per_page: u8,
+    ) -> Result<TxSearchResponse, Error> {
+        future::with_retry(
+            || Client::tx_search(self, query.clone(), false, page, per_page, Order::Ascending),
+            RetryPolicy::RepeatConstant {
+                sleep: Duration::from_secs(1),
+                max_attempts: 15,
+            },
+        )
+        .await
+        .map_err(Report::from)
+    }
 }
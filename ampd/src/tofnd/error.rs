@@ -10,6 +10,8 @@ pub enum Error {
     KeygenFailed,
     #[error("sign failed")]
     SignFailed,
+    #[error("key presence check failed")]
+    KeyPresenceFailed,
     #[error(transparent)]
     FromHex(#[from] hex::FromHexError),
     #[error("parsing failed")]
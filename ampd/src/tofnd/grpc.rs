@@ -8,9 +8,10 @@ use tonic::transport::Channel;
 use tonic::Status;
 
 use super::error::{Error, TofndError};
+use super::proto::key_presence_response::Response as KeyPresence;
 use super::proto::keygen_response::KeygenResponse;
 use super::proto::sign_response::SignResponse;
-use super::proto::{multisig_client, Algorithm, KeygenRequest, SignRequest};
+use super::proto::{multisig_client, Algorithm, KeyPresenceRequest, KeygenRequest, SignRequest};
 use super::{MessageDigest, Signature};
 use crate::types::PublicKey;
 
@@ -50,6 +51,33 @@ impl MultisigClient {
             client: multisig_client::MultisigClient::new(conn),
         })
     }
+
+    /// Returns whether tofnd holds the key. Fails if tofnd cannot be reached or cannot check for the key
+    pub async fn key_presence(&self, key_uid: &str, algorithm: Algorithm) -> Result<bool> {
+        let request = KeyPresenceRequest {
+            key_uid: key_uid.to_string(),
+            pub_key: vec![],
+            algorithm: algorithm.into(),
+        };
+
+        let response = self
+            .client
+            .clone()
+            .key_presence(request)
+            .await
+            .map_err(ErrorExt::into_report)?
+            .into_inner()
+            .response();
+
+        match response {
+            KeyPresence::Present => Ok(true),
+            KeyPresence::Absent => Ok(false),
+            KeyPresence::Unspecified | KeyPresence::Fail => {
+                Err(TofndError::ExecutionFailed(format!("{:?}", response)))
+                    .change_context(Error::KeyPresenceFailed)
+            }
+        }
+    }
 }
 
 #[async_trait]
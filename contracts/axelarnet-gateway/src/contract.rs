@@ -29,6 +29,8 @@ pub enum Error {
     RouteMessages,
     #[error("failed to execute a cross-chain execution payload")]
     Execute,
+    #[error("failed to recall messages")]
+    RecallMessages,
     #[error("failed to query routable messages")]
     QueryRoutableMessage,
     #[error("failed to query executable messages")]
@@ -57,11 +59,11 @@ pub fn instantiate(
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response<nexus::execute::Message>, ContractError> {
-    match msg.ensure_permissions(deps.storage, &info.sender, match_nexus)? {
+    match msg.ensure_permissions(deps.storage, &info.sender, match_nexus, match_router)? {
         ExecuteMsg::CallContract {
             destination_chain,
             destination_address,
@@ -82,11 +84,18 @@ pub fn execute(
                 .change_context(Error::RouteMessages)
         }
         ExecuteMsg::Execute { cc_id, payload } => {
-            execute::execute(deps, cc_id, payload).change_context(Error::Execute)
+            execute::execute(deps, env.block.height, cc_id, payload).change_context(Error::Execute)
         }
         ExecuteMsg::RouteMessagesFromNexus(msgs) => {
             Ok(execute::route_messages_from_nexus(deps.storage, msgs)?)
         }
+        ExecuteMsg::RecallMessages(cc_ids) => {
+            execute::recall_messages(deps.storage, cc_ids).change_context(Error::RecallMessages)
+        }
+        ExecuteMsg::RouteMessagesWithExpiry(msgs) => {
+            execute::route_messages_with_expiry(deps.storage, deps.querier, info.sender, msgs)
+                .change_context(Error::RouteMessages)
+        }
     }?
     .then(Ok)
 }
@@ -110,3 +119,7 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> Result<Binary, ContractErr
 fn match_nexus(storage: &dyn Storage, _: &ExecuteMsg) -> Result<Addr, Report<Error>> {
     Ok(state::load_config(storage).nexus)
 }
+
+fn match_router(storage: &dyn Storage, _: &ExecuteMsg) -> Result<Addr, Report<Error>> {
+    Ok(state::load_config(storage).router)
+}
@@ -15,6 +15,7 @@ use sha3::{Digest, Keccak256};
 
 use crate::clients::external;
 use crate::events::AxelarnetGatewayEvent;
+use crate::msg::MessageWithExpiry;
 use crate::state::Config;
 use crate::{state, AxelarExecutableMsg};
 
@@ -28,6 +29,14 @@ pub enum Error {
     MessageMismatch(CrossChainId),
     #[error("failed to mark message with ID {0} as executed")]
     MarkExecuted(CrossChainId),
+    #[error("failed to mark message with ID {0} as recalled")]
+    MarkRecalled(CrossChainId),
+    #[error("failed to save the expiry of message with ID {0}")]
+    SaveExpiry(CrossChainId),
+    #[error("failed to check the expiry of message with ID {0}")]
+    Expiry(CrossChainId),
+    #[error("message with ID {0} has expired")]
+    MessageExpired(CrossChainId),
     #[error("expected destination chain {expected}, got {actual}")]
     InvalidDestination {
         expected: ChainName,
@@ -160,11 +169,37 @@ pub fn route_messages(
         })
 }
 
+pub fn route_messages_with_expiry(
+    storage: &mut dyn Storage,
+    querier: QuerierWrapper,
+    sender: Addr,
+    msgs: Vec<MessageWithExpiry>,
+) -> Result<Response<nexus::execute::Message>> {
+    for msg in msgs.iter() {
+        state::save_expiry_height(storage, &msg.message.cc_id, msg.expires_at)
+            .change_context(Error::SaveExpiry(msg.message.cc_id.clone()))?;
+    }
+
+    route_messages(
+        storage,
+        querier,
+        sender,
+        msgs.into_iter().map(|msg| msg.message).collect(),
+    )
+}
+
 pub fn execute(
     deps: DepsMut,
+    block_height: u64,
     cc_id: CrossChainId,
     payload: HexBinary,
 ) -> Result<Response<nexus::execute::Message>> {
+    ensure!(
+        !state::is_expired(deps.storage, &cc_id, block_height)
+            .change_context(Error::Expiry(cc_id.clone()))?,
+        Error::MessageExpired(cc_id)
+    );
+
     let payload_hash: [u8; 32] = Keccak256::digest(payload.as_slice()).into();
     let msg = state::mark_as_executed(
         deps.storage,
@@ -184,12 +219,33 @@ pub fn execute(
             msg.destination_address.to_string(),
         ))?;
 
+    // the router tracks latencies of the messages it routed until their execution is recorded
+    let router = Router::new(state::load_config(deps.storage).router);
+
     Response::new()
         .add_message(external::Client::new(deps.querier, &destination).execute(executable_msg))
+        .add_messages(router.record_executions(vec![msg.cc_id.clone()]))
         .add_event(AxelarnetGatewayEvent::MessageExecuted { msg })
         .then(Ok)
 }
 
+pub fn recall_messages(
+    storage: &mut dyn Storage,
+    cc_ids: Vec<CrossChainId>,
+) -> Result<Response<nexus::execute::Message>> {
+    let mut events = vec![];
+
+    for cc_id in cc_ids {
+        if let Some(msg) = state::mark_as_recalled(storage, &cc_id)
+            .change_context(Error::MarkRecalled(cc_id.clone()))?
+        {
+            events.push(AxelarnetGatewayEvent::MessageRecalled { msg });
+        }
+    }
+
+    Ok(Response::new().add_events(events))
+}
+
 pub fn route_messages_from_nexus(
     storage: &dyn Storage,
     msgs: Vec<nexus::execute::Message>,
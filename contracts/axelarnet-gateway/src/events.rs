@@ -13,6 +13,9 @@ pub enum AxelarnetGatewayEvent {
     MessageExecuted {
         msg: Message,
     },
+    MessageRecalled {
+        msg: Message,
+    },
 }
 
 impl From<AxelarnetGatewayEvent> for Event {
@@ -26,6 +29,9 @@ impl From<AxelarnetGatewayEvent> for Event {
             AxelarnetGatewayEvent::MessageExecuted { msg } => {
                 make_message_event("message_executed", msg)
             }
+            AxelarnetGatewayEvent::MessageRecalled { msg } => {
+                make_message_event("message_recalled", msg)
+            }
         }
     }
 }
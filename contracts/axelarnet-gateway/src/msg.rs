@@ -4,6 +4,9 @@ use cosmwasm_std::HexBinary;
 use msgs_derive::EnsurePermissions;
 use router_api::{Address, ChainName, CrossChainId, Message};
 
+// the router sends the same message to every gateway
+pub use gateway_api::msg::MessageWithExpiry;
+
 pub use crate::contract::MigrateMsg;
 
 #[cw_serde]
@@ -12,6 +15,8 @@ pub enum ExecutableMessage {
     Approved(Message),
     /// An approved message that has been executed.
     Executed(Message),
+    /// An approved message that has been recalled by the router and can't be executed anymore.
+    Recalled(Message),
 }
 
 #[cw_serde]
@@ -54,6 +59,16 @@ pub enum ExecuteMsg {
     /// Forward the given nexus messages to the next step of the routing layer.
     #[permission(Specific(nexus))]
     RouteMessagesFromNexus(Vec<nexus::execute::Message>),
+
+    /// Refuses the execution of the given approved messages, because they were recalled after they had been routed.
+    /// Can only be called by the router.
+    #[permission(Specific(router))]
+    RecallMessages(Vec<CrossChainId>),
+
+    /// Like RouteMessages for messages coming from the router, but each message carries the block height at which it expires.
+    /// Expired messages can't be executed anymore. Can only be called by the router.
+    #[permission(Specific(router))]
+    RouteMessagesWithExpiry(Vec<MessageWithExpiry>),
 }
 
 #[cw_serde]
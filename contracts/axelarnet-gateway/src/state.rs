@@ -10,6 +10,8 @@ use crate::msg;
 const CONFIG: Item<Config> = Item::new("config");
 const ROUTABLE_MESSAGES: Map<&CrossChainId, Message> = Map::new("routable_messages");
 const EXECUTABLE_MESSAGES: Map<&CrossChainId, ExecutableMessage> = Map::new("executable_messages");
+/// Block heights from which on approved messages can no longer be executed, for messages that were routed with an expiry
+const EXPIRY_HEIGHTS: Map<&CrossChainId, u64> = Map::new("expiry_heights");
 
 #[derive(thiserror::Error, Debug, PartialEq, IntoContractError)]
 pub enum Error {
@@ -25,6 +27,10 @@ pub enum Error {
     MessageNotApproved(CrossChainId),
     #[error("message with ID {0} already executed")]
     MessageAlreadyExecuted(CrossChainId),
+    #[error("message with ID {0} has been recalled")]
+    MessageRecalled(CrossChainId),
+    #[error("message with ID {0} has expired")]
+    MessageExpired(CrossChainId),
     #[error("sent message with ID {0} already exists")]
     MessageAlreadyExists(CrossChainId),
     #[error("payload hash doesn't match message")]
@@ -44,12 +50,16 @@ pub enum ExecutableMessage {
     Approved(Message),
     /// An approved message that has been executed.
     Executed(Message),
+    /// An approved message that has been recalled by the router and can't be executed anymore.
+    Recalled(Message),
 }
 
 impl ExecutableMessage {
     pub fn msg(&self) -> &Message {
         match self {
-            ExecutableMessage::Approved(msg) | ExecutableMessage::Executed(msg) => msg,
+            ExecutableMessage::Approved(msg)
+            | ExecutableMessage::Executed(msg)
+            | ExecutableMessage::Recalled(msg) => msg,
         }
     }
 }
@@ -59,6 +69,7 @@ impl From<ExecutableMessage> for msg::ExecutableMessage {
         match value {
             ExecutableMessage::Approved(msg) => msg::ExecutableMessage::Approved(msg),
             ExecutableMessage::Executed(msg) => msg::ExecutableMessage::Executed(msg),
+            ExecutableMessage::Recalled(msg) => msg::ExecutableMessage::Recalled(msg),
         }
     }
 }
@@ -110,6 +121,35 @@ pub fn save_executable_msg(
     }
 }
 
+/// Saves the expiry of a message that is about to be approved. The first expiry is kept if the message is routed again,
+/// and executed or recalled messages don't need one anymore.
+pub fn save_expiry_height(
+    storage: &mut dyn Storage,
+    cc_id: &CrossChainId,
+    expires_at: u64,
+) -> Result<(), Error> {
+    if EXPIRY_HEIGHTS.has(storage, cc_id)
+        || matches!(
+            may_load_executable_msg(storage, cc_id)?,
+            Some(ExecutableMessage::Executed(_) | ExecutableMessage::Recalled(_))
+        )
+    {
+        return Ok(());
+    }
+
+    Ok(EXPIRY_HEIGHTS.save(storage, cc_id, &expires_at)?)
+}
+
+pub fn is_expired(
+    storage: &dyn Storage,
+    cc_id: &CrossChainId,
+    block_height: u64,
+) -> Result<bool, Error> {
+    Ok(EXPIRY_HEIGHTS
+        .may_load(storage, cc_id)?
+        .is_some_and(|expires_at| block_height >= expires_at))
+}
+
 pub fn may_load_executable_msg(
     storage: &dyn Storage,
     cc_id: &CrossChainId,
@@ -135,10 +175,28 @@ pub fn mark_as_executed(
     let msg = match may_load_executable_msg(storage, cc_id)? {
         None => Err(Error::MessageNotApproved(cc_id.clone())),
         Some(ExecutableMessage::Executed(_)) => Err(Error::MessageAlreadyExecuted(cc_id.clone())),
+        Some(ExecutableMessage::Recalled(_)) => Err(Error::MessageRecalled(cc_id.clone())),
         Some(ExecutableMessage::Approved(msg)) => validate(&msg)?.then(|_| Ok(msg)),
     }?;
 
     EXECUTABLE_MESSAGES.save(storage, cc_id, &ExecutableMessage::Executed(msg.clone()))?;
+    EXPIRY_HEIGHTS.remove(storage, cc_id);
 
     Ok(msg)
 }
+
+/// Update the status of a message to recalled if it is in approved status. Returns the message if its status changed.
+/// Executed messages can't be recalled anymore, and messages that are unknown or already recalled are ignored.
+pub fn mark_as_recalled(
+    storage: &mut dyn Storage,
+    cc_id: &CrossChainId,
+) -> Result<Option<Message>, Error> {
+    match may_load_executable_msg(storage, cc_id)? {
+        Some(ExecutableMessage::Approved(msg)) => {
+            EXECUTABLE_MESSAGES.save(storage, cc_id, &ExecutableMessage::Recalled(msg.clone()))?;
+            EXPIRY_HEIGHTS.remove(storage, cc_id);
+            Ok(Some(msg))
+        }
+        _ => Ok(None),
+    }
+}
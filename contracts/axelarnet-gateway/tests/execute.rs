@@ -4,8 +4,8 @@ use axelar_wasm_std::assert_err_contains;
 use axelar_wasm_std::response::inspect_response_msg;
 use axelarnet_gateway::contract::ExecuteError;
 use axelarnet_gateway::StateError;
-use cosmwasm_std::testing::{message_info, mock_dependencies};
-use cosmwasm_std::HexBinary;
+use cosmwasm_std::testing::{message_info, mock_dependencies, mock_env, MockApi};
+use cosmwasm_std::{to_json_binary, CosmosMsg, HexBinary, WasmMsg};
 use rand::RngCore;
 use router_api::msg::ExecuteMsg as RouterExecuteMsg;
 use router_api::{CrossChainId, Message};
@@ -54,6 +54,88 @@ fn execute_approved_message_when_already_executed_fails() {
     );
 }
 
+#[test]
+fn execute_approved_message_when_recalled_fails() {
+    let mut deps = mock_axelar_dependencies();
+    deps.querier = deps
+        .querier
+        .with_custom_handler(reply_with_is_chain_registered(false));
+
+    let payload: HexBinary = vec![1, 2, 3].into();
+    let msg = messages::dummy_from_router(&payload);
+    let cc_id = msg.cc_id.clone();
+
+    utils::instantiate_contract(deps.as_default_mut()).unwrap();
+    utils::route_from_router(deps.as_default_mut(), vec![msg]).unwrap();
+    let response = assert_ok!(utils::recall_messages(
+        deps.as_default_mut(),
+        vec![cc_id.clone()]
+    ));
+    assert_eq!(response.events.len(), 1);
+
+    assert_err_contains!(
+        utils::execute_payload(deps.as_default_mut(), cc_id, payload),
+        StateError,
+        StateError::MessageRecalled(..)
+    );
+}
+
+#[test]
+fn execute_approved_message_when_expired_fails() {
+    let mut deps = mock_axelar_dependencies();
+    deps.querier = deps
+        .querier
+        .with_custom_handler(reply_with_is_chain_registered(false));
+
+    let payload: HexBinary = vec![1, 2, 3].into();
+    let msg = messages::dummy_from_router(&payload);
+    let cc_id = msg.cc_id.clone();
+
+    utils::instantiate_contract(deps.as_default_mut()).unwrap();
+    assert_ok!(utils::route_from_router_with_expiry(
+        deps.as_default_mut(),
+        vec![msg.clone()],
+        mock_env().block.height
+    ));
+    // routing the message again doesn't extend its expiry
+    assert_ok!(utils::route_from_router_with_expiry(
+        deps.as_default_mut(),
+        vec![msg],
+        u64::MAX
+    ));
+
+    assert_err_contains!(
+        utils::execute_payload(deps.as_default_mut(), cc_id, payload),
+        ExecuteError,
+        ExecuteError::MessageExpired(..)
+    );
+}
+
+#[test]
+fn execute_approved_message_before_expiry_succeeds() {
+    let mut deps = mock_axelar_dependencies();
+    deps.querier = deps
+        .querier
+        .with_custom_handler(reply_with_is_chain_registered(false));
+
+    let payload: HexBinary = vec![1, 2, 3].into();
+    let msg = messages::dummy_from_router(&payload);
+    let cc_id = msg.cc_id.clone();
+
+    utils::instantiate_contract(deps.as_default_mut()).unwrap();
+    assert_ok!(utils::route_from_router_with_expiry(
+        deps.as_default_mut(),
+        vec![msg],
+        u64::MAX
+    ));
+
+    assert_ok!(utils::execute_payload(
+        deps.as_default_mut(),
+        cc_id,
+        payload
+    ));
+}
+
 #[test]
 fn execute_approved_message_when_payload_mismatch_fails() {
     let mut deps = mock_axelar_dependencies();
@@ -90,11 +172,23 @@ fn execute_approved_message_once_returns_correct_message() {
     utils::instantiate_contract(deps.as_default_mut()).unwrap();
     utils::route_from_router(deps.as_default_mut(), vec![msg]).unwrap();
 
-    let response = assert_ok!(utils::execute_payload(
+    let mut response = assert_ok!(utils::execute_payload(
         deps.as_default_mut(),
-        cc_id,
+        cc_id.clone(),
         payload
     ));
+
+    // the execution is recorded at the router after the destination contract is called
+    let record_execution = response.messages.pop().unwrap().msg;
+    assert_eq!(
+        record_execution,
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: MockApi::default().addr_make(params::ROUTER).to_string(),
+            msg: to_json_binary(&RouterExecuteMsg::RecordExecutions(vec![cc_id])).unwrap(),
+            funds: vec![],
+        })
+    );
+
     let msg: utils::ExecuteMsg = assert_ok!(inspect_response_msg(response));
     goldie::assert_json!(msg)
 }
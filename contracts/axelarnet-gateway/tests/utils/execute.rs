@@ -1,6 +1,6 @@
 use axelar_core_std::nexus;
 use axelar_wasm_std::error::ContractError;
-use axelarnet_gateway::msg::ExecuteMsg as GatewayExecuteMsg;
+use axelarnet_gateway::msg::{ExecuteMsg as GatewayExecuteMsg, MessageWithExpiry};
 use axelarnet_gateway::{contract, AxelarExecutableMsg};
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::testing::{message_info, mock_env, MockApi};
@@ -46,6 +46,38 @@ pub fn route_from_router(
     )
 }
 
+pub fn route_from_router_with_expiry(
+    deps: DepsMut,
+    msgs: Vec<Message>,
+    expires_at: u64,
+) -> Result<Response<nexus::execute::Message>, ContractError> {
+    contract::execute(
+        deps,
+        mock_env(),
+        message_info(&MockApi::default().addr_make(params::ROUTER), &[]),
+        GatewayExecuteMsg::RouteMessagesWithExpiry(
+            msgs.into_iter()
+                .map(|message| MessageWithExpiry {
+                    message,
+                    expires_at,
+                })
+                .collect(),
+        ),
+    )
+}
+
+pub fn recall_messages(
+    deps: DepsMut,
+    cc_ids: Vec<CrossChainId>,
+) -> Result<Response<nexus::execute::Message>, ContractError> {
+    contract::execute(
+        deps,
+        mock_env(),
+        message_info(&MockApi::default().addr_make(params::ROUTER), &[]),
+        GatewayExecuteMsg::RecallMessages(cc_ids),
+    )
+}
+
 pub fn execute_payload(
     deps: DepsMut,
     cc_id: CrossChainId,
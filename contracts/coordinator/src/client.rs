@@ -4,7 +4,9 @@ use cosmwasm_std::{Addr, CosmosMsg};
 use error_stack::{Result, ResultExt};
 use router_api::ChainName;
 
-use crate::msg::{ChainContractsKey, ChainContractsResponse, ExecuteMsg, QueryMsg};
+use crate::msg::{
+    ChainContractsKey, ChainContractsResponse, ExecuteMsg, QueryMsg, VerifierAuthorizationPreview,
+};
 
 #[derive(thiserror::Error, Debug, PartialEq)]
 pub enum Error {
@@ -22,6 +24,9 @@ pub enum Error {
 
     #[error("failed to query ChainContractsInfo by verifier {0}")]
     VerifierNotRegistered(Addr),
+
+    #[error("coordinator failed to preview the authorization of verifiers for chain {0}")]
+    VerifierAuthorizationPreview(ChainName),
 }
 
 impl<'a> From<client::ContractClient<'a, ExecuteMsg, QueryMsg>> for Client<'a> {
@@ -66,6 +71,19 @@ impl Client<'_> {
             .execute(&ExecuteMsg::SetActiveVerifiers { verifiers })
     }
 
+    pub fn authorize_verifiers(
+        &self,
+        service_name: String,
+        chain_name: ChainName,
+        verifiers: Vec<String>,
+    ) -> CosmosMsg {
+        self.client.execute(&ExecuteMsg::AuthorizeVerifiers {
+            service_name,
+            chain_name,
+            verifiers,
+        })
+    }
+
     pub fn ready_to_unbond(&self, verifier_address: String) -> Result<bool, Error> {
         let msg = QueryMsg::ReadyToUnbond {
             verifier_address: verifier_address.clone(),
@@ -76,6 +94,23 @@ impl Client<'_> {
             .change_context(Error::ReadyToUnbond(verifier_address))
     }
 
+    pub fn verifier_authorization_preview(
+        &self,
+        service_name: String,
+        chain_name: ChainName,
+        verifiers: Vec<String>,
+    ) -> Result<VerifierAuthorizationPreview, Error> {
+        let msg = QueryMsg::VerifierAuthorizationPreview {
+            service_name,
+            chain_name: chain_name.clone(),
+            verifiers,
+        };
+
+        self.client
+            .query(&msg)
+            .change_context(Error::VerifierAuthorizationPreview(chain_name))
+    }
+
     pub fn chain_contracts(
         &self,
         chain_contracts_key: ChainContractsKey,
@@ -109,7 +144,10 @@ mod test {
     use cosmwasm_std::{from_json, to_json_binary, Addr, QuerierWrapper, SystemError, WasmQuery};
 
     use crate::client::Client;
-    use crate::msg::{ChainContractsKey, ChainContractsResponse, QueryMsg};
+    use crate::msg::{
+        AuthorizationAction, ChainContractsKey, ChainContractsResponse, QueryMsg,
+        VerifierAuthorizationAction, VerifierAuthorizationPreview,
+    };
 
     #[test]
     fn query_ready_to_unbond_returns_error_when_query_fails() {
@@ -133,6 +171,36 @@ mod test {
         goldie::assert_json!(res.unwrap());
     }
 
+    #[test]
+    fn query_verifier_authorization_preview_returns_error_when_query_fails() {
+        let (querier, addr) = setup_queries_to_fail();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+        let res = client.verifier_authorization_preview(
+            "validators".to_string(),
+            router_api::ChainName::from_str("axelar").unwrap(),
+            vec![MockApi::default().addr_make("verifier").to_string()],
+        );
+
+        assert!(res.is_err());
+        goldie::assert!(res.unwrap_err().to_string());
+    }
+
+    #[test]
+    fn query_verifier_authorization_preview_returns_correct_result() {
+        let (querier, addr) = setup_queries_to_succeed();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+        let res = client.verifier_authorization_preview(
+            "validators".to_string(),
+            router_api::ChainName::from_str("axelar").unwrap(),
+            vec![MockApi::default().addr_make("verifier").to_string()],
+        );
+
+        assert!(res.is_ok());
+        goldie::assert_json!(res.unwrap());
+    }
+
     #[test]
     fn query_chain_contracts_returns_correct_result() {
         let (querier, addr) = setup_queries_to_succeed();
@@ -249,6 +317,23 @@ mod test {
                         service_name: _,
                         verifier: _,
                     } => Ok(to_json_binary(&true).into()).into(),
+                    QueryMsg::VerifierAuthorizationPreview {
+                        service_name,
+                        chain_name,
+                        verifiers,
+                    } => Ok(to_json_binary(&VerifierAuthorizationPreview {
+                        service_name,
+                        chain_name,
+                        actions: verifiers
+                            .into_iter()
+                            .map(|verifier| VerifierAuthorizationAction {
+                                verifier: Addr::unchecked(verifier),
+                                action: AuthorizationAction::Authorize,
+                            })
+                            .collect(),
+                    })
+                    .into())
+                    .into(),
                     QueryMsg::ChainContractsInfo(_) => {
                         Ok(to_json_binary(&ChainContractsResponse {
                             chain_name: router_api::ChainName::from_str("axelar").unwrap(),
@@ -64,7 +64,7 @@ pub fn instantiate(
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
@@ -111,6 +111,36 @@ pub fn execute(
                 .try_collect()?;
             execute::set_active_verifier_set(deps, info, verifiers)
         }
+        ExecuteMsg::AuthorizeVerifiers {
+            service_name,
+            chain_name,
+            verifiers,
+        } => {
+            let verifiers = verifiers
+                .iter()
+                .map(|v| validate_cosmwasm_address(deps.api, v))
+                .try_collect()?;
+            execute::authorize_verifiers(deps, service_name, chain_name, verifiers)
+        }
+        ExecuteMsg::InstantiateChainContracts {
+            chain_name,
+            salt,
+            params,
+        } => {
+            let admin = params
+                .admin
+                .as_ref()
+                .map(|admin| validate_cosmwasm_address(deps.api, admin))
+                .transpose()?;
+            execute::instantiate_chain_contracts(deps, env, chain_name, salt.into(), *params, admin)
+        }
+        ExecuteMsg::ScheduleMaintenanceWindow { chain_name, window } => {
+            execute::schedule_maintenance_window(deps, env, chain_name, window)
+        }
+        ExecuteMsg::CancelMaintenanceWindow {
+            chain_name,
+            start_height,
+        } => execute::cancel_maintenance_window(deps, env, chain_name, start_height),
     }
     .change_context(Error::Execute)?
     .then(Ok)
@@ -131,7 +161,7 @@ fn find_prover_address(
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> Result<Binary, ContractError> {
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary, ContractError> {
     match msg {
         QueryMsg::ReadyToUnbond {
             verifier_address: worker_address,
@@ -158,18 +188,58 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> Result<Binary, ContractErr
         QueryMsg::ChainContractsInfo(chain_contracts_key) => Ok(to_json_binary(
             &query::get_chain_contracts_info(deps, chain_contracts_key)?,
         )?),
+        QueryMsg::VerifierAuthorizationPreview {
+            service_name,
+            chain_name,
+            verifiers,
+        } => {
+            let verifiers = verifiers
+                .iter()
+                .map(|v| validate_cosmwasm_address(deps.api, v))
+                .try_collect()?;
+
+            Ok(to_json_binary(&query::verifier_authorization_preview(
+                deps,
+                service_name,
+                chain_name,
+                verifiers,
+            )?)?)
+        }
+        QueryMsg::PredictChainContractAddresses {
+            chain_name,
+            salt,
+            code_ids,
+        } => Ok(to_json_binary(&query::predict_chain_contract_addresses(
+            deps,
+            &env,
+            chain_name,
+            &salt.into(),
+            &code_ids,
+        )?)?),
+        QueryMsg::DeploymentSalt(chain_name) => {
+            Ok(to_json_binary(&query::deployment_salt(deps, chain_name)?)?)
+        }
+        QueryMsg::MaintenanceWindows(chain_name) => Ok(to_json_binary(
+            &query::maintenance_windows(deps, &env, chain_name)?,
+        )?),
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use axelar_wasm_std::maintenance::MaintenanceWindow;
     use axelar_wasm_std::permission_control::Permission;
-    use cosmwasm_std::{Addr, StdResult};
+    use axelar_wasm_std::{bounded, nonempty};
+    use cosmwasm_std::testing::{message_info, mock_dependencies, mock_env};
+    use cosmwasm_std::{from_json, wasm_execute, Addr, Empty, HexBinary, StdResult, WasmQuery};
     use cw_multi_test::{no_init, App, ContractWrapper, Executor};
     use router_api::ChainName;
 
     use super::*;
-    use crate::msg::ChainContractsKey;
+    use crate::msg::{
+        ChainContractsKey, ChainContractsResponse, ContractDeploymentInfo, DeploymentParams,
+        DutiesQueryMsg, MaintenanceExecuteMsg, MAX_SALT_LENGTH,
+    };
     use crate::state::{load_prover_by_chain, ChainContractsRecord};
 
     struct TestSetup {
@@ -339,6 +409,240 @@ mod tests {
         goldie::assert_json!(record_response_by_verifier.unwrap());
     }
 
+    fn deployment_info(app: &mut App, label: &str) -> ContractDeploymentInfo {
+        let code = ContractWrapper::new(
+            |_, _, _, _: Empty| -> StdResult<Response> { Ok(Response::new()) },
+            |_, _, _, _: Empty| -> StdResult<Response> { Ok(Response::new()) },
+            |_, _, _: Empty| -> StdResult<Binary> { Ok(Binary::default()) },
+        );
+
+        ContractDeploymentInfo {
+            code_id: app.store_code(Box::new(code)),
+            label: label.to_string(),
+            msg: to_json_binary(&Empty {}).unwrap(),
+        }
+    }
+
+    #[test]
+    fn instantiate_chain_contracts_deploys_to_predicted_addresses() {
+        let mut test_setup = setup();
+        let chain_name: ChainName = "Avalanche".parse().unwrap();
+        let salt: bounded::Bytes<MAX_SALT_LENGTH> =
+            HexBinary::from_hex("abcd").unwrap().try_into().unwrap();
+
+        let params = DeploymentParams {
+            gateway: deployment_info(&mut test_setup.app, "gateway"),
+            voting_verifier: deployment_info(&mut test_setup.app, "voting_verifier"),
+            prover: deployment_info(&mut test_setup.app, "prover"),
+            admin: Some(test_setup.admin_addr.to_string()),
+        };
+
+        let predicted: ChainContractsResponse = test_setup
+            .app
+            .wrap()
+            .query_wasm_smart(
+                test_setup.coordinator_addr.clone(),
+                &QueryMsg::PredictChainContractAddresses {
+                    chain_name: chain_name.clone(),
+                    salt: salt.clone(),
+                    code_ids: params.code_ids(),
+                },
+            )
+            .unwrap();
+
+        let msg = ExecuteMsg::InstantiateChainContracts {
+            chain_name: chain_name.clone(),
+            salt: salt.clone(),
+            params: Box::new(params),
+        };
+        assert!(test_setup
+            .app
+            .execute_contract(
+                test_setup.admin_addr.clone(),
+                test_setup.coordinator_addr.clone(),
+                &msg,
+                &[]
+            )
+            .is_ok());
+
+        let deployed: ChainContractsResponse = test_setup
+            .app
+            .wrap()
+            .query_wasm_smart(
+                test_setup.coordinator_addr.clone(),
+                &QueryMsg::ChainContractsInfo(ChainContractsKey::ChainName(chain_name.clone())),
+            )
+            .unwrap();
+        assert_eq!(deployed, predicted);
+
+        for address in [
+            &deployed.gateway_address,
+            &deployed.verifier_address,
+            &deployed.prover_address,
+        ] {
+            let info = test_setup.app.wrap().query_wasm_contract_info(address);
+            assert!(info.is_ok(), "{:?}", info);
+        }
+
+        let stored_salt: Option<nonempty::HexBinary> = test_setup
+            .app
+            .wrap()
+            .query_wasm_smart(
+                test_setup.coordinator_addr.clone(),
+                &QueryMsg::DeploymentSalt(chain_name),
+            )
+            .unwrap();
+        assert_eq!(stored_salt, Some(salt.into()));
+
+        assert!(test_setup
+            .app
+            .execute_contract(
+                test_setup.admin_addr.clone(),
+                test_setup.coordinator_addr.clone(),
+                &msg,
+                &[]
+            )
+            .is_err());
+    }
+
+    #[test]
+    #[allow(clippy::arithmetic_side_effects)]
+    fn maintenance_windows_are_scheduled_and_cancelled() {
+        let mut deps = mock_dependencies();
+        let api = deps.api;
+        let governance = api.addr_make("governance");
+        let prover = api.addr_make("eth_prover");
+        let chain_name: ChainName = "Ethereum".parse().unwrap();
+
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&governance, &[]),
+            InstantiateMsg {
+                governance_address: governance.to_string(),
+                service_registry: api.addr_make("service_registry").to_string(),
+                router_address: api.addr_make("router").to_string(),
+                multisig_address: api.addr_make("multisig").to_string(),
+            },
+        )
+        .unwrap();
+
+        let schedule =
+            |deps: DepsMut, env: Env, chain_name: &ChainName, window: MaintenanceWindow| {
+                execute(
+                    deps,
+                    env,
+                    message_info(&governance, &[]),
+                    ExecuteMsg::ScheduleMaintenanceWindow {
+                        chain_name: chain_name.clone(),
+                        window,
+                    },
+                )
+            };
+        let query_windows = |deps: Deps, env: Env| {
+            from_json::<Vec<MaintenanceWindow>>(
+                query(deps, env, QueryMsg::MaintenanceWindows(chain_name.clone())).unwrap(),
+            )
+            .unwrap()
+        };
+
+        let height = mock_env().block.height;
+        let window = MaintenanceWindow::new(height + 10, height + 20).unwrap();
+
+        // the chain's contracts must be registered to receive the windows
+        assert!(schedule(deps.as_mut(), mock_env(), &chain_name, window).is_err());
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&governance, &[]),
+            ExecuteMsg::RegisterChain {
+                chain_name: chain_name.clone(),
+                prover_address: prover.to_string(),
+                gateway_address: api.addr_make("eth_gateway").to_string(),
+                voting_verifier_address: api.addr_make("eth_voting_verifier").to_string(),
+            },
+        )
+        .unwrap();
+
+        assert!(execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make("random"), &[]),
+            ExecuteMsg::ScheduleMaintenanceWindow {
+                chain_name: chain_name.clone(),
+                window,
+            },
+        )
+        .is_err());
+
+        let res = schedule(deps.as_mut(), mock_env(), &chain_name, window).unwrap();
+        assert_eq!(res.messages.len(), 3);
+        assert_eq!(
+            res.messages[1].msg,
+            wasm_execute(
+                &prover,
+                &MaintenanceExecuteMsg::SetMaintenanceWindows {
+                    windows: vec![window]
+                },
+                vec![]
+            )
+            .unwrap()
+            .into()
+        );
+        assert_eq!(res.events[0].ty, "maintenance_window_scheduled");
+
+        // windows must not overlap or start in the past
+        let overlapping = MaintenanceWindow::new(height + 15, height + 25).unwrap();
+        assert!(schedule(deps.as_mut(), mock_env(), &chain_name, overlapping).is_err());
+        let in_past = MaintenanceWindow::new(height - 1, height + 5).unwrap();
+        assert!(schedule(deps.as_mut(), mock_env(), &chain_name, in_past).is_err());
+
+        let later = MaintenanceWindow::new(height + 30, height + 40).unwrap();
+        schedule(deps.as_mut(), mock_env(), &chain_name, later).unwrap();
+        assert_eq!(
+            query_windows(deps.as_ref(), mock_env()),
+            vec![window, later]
+        );
+
+        // ended windows are no longer returned, and are dropped once the chain's windows change
+        let mut env = mock_env();
+        env.block.height = height + 35;
+        assert_eq!(query_windows(deps.as_ref(), env.clone()), vec![later]);
+
+        let next = MaintenanceWindow::new(height + 50, height + 60).unwrap();
+        let res = schedule(deps.as_mut(), env.clone(), &chain_name, next).unwrap();
+        assert_eq!(
+            res.messages[1].msg,
+            wasm_execute(
+                &prover,
+                &MaintenanceExecuteMsg::SetMaintenanceWindows {
+                    windows: vec![later, next]
+                },
+                vec![]
+            )
+            .unwrap()
+            .into()
+        );
+
+        let cancel = |deps: DepsMut, start_height: u64| {
+            execute(
+                deps,
+                env.clone(),
+                message_info(&governance, &[]),
+                ExecuteMsg::CancelMaintenanceWindow {
+                    chain_name: chain_name.clone(),
+                    start_height,
+                },
+            )
+        };
+        assert!(cancel(deps.as_mut(), window.start_height()).is_err());
+        let res = cancel(deps.as_mut(), later.start_height()).unwrap();
+        assert_eq!(res.events[0].ty, "maintenance_window_cancelled");
+        assert_eq!(query_windows(deps.as_ref(), env.clone()), vec![next]);
+        assert!(cancel(deps.as_mut(), later.start_height()).is_err());
+    }
+
     #[test]
     fn migrate_sets_contract_version() {
         let mut test_setup = setup();
@@ -370,4 +674,74 @@ mod tests {
         assert_eq!(contract_version.contract, CONTRACT_NAME);
         assert_eq!(contract_version.version, CONTRACT_VERSION);
     }
+
+    #[test]
+    fn verifier_should_only_be_ready_to_unbond_without_open_duties() {
+        let mut deps = mock_dependencies();
+        let api = deps.api;
+        let governance = api.addr_make("governance");
+        let verifier = api.addr_make("verifier");
+
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&governance, &[]),
+            InstantiateMsg {
+                governance_address: governance.to_string(),
+                service_registry: api.addr_make("service_registry").to_string(),
+                router_address: api.addr_make("router").to_string(),
+                multisig_address: api.addr_make("multisig").to_string(),
+            },
+        )
+        .unwrap();
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&governance, &[]),
+            ExecuteMsg::RegisterChain {
+                chain_name: "Ethereum".parse().unwrap(),
+                prover_address: api.addr_make("eth_prover").to_string(),
+                gateway_address: api.addr_make("eth_gateway").to_string(),
+                voting_verifier_address: api.addr_make("eth_voting_verifier").to_string(),
+            },
+        )
+        .unwrap();
+
+        for (open_signing_sessions, open_polls) in [(false, false), (true, false), (false, true)] {
+            let multisig = api.addr_make("multisig");
+            let voting_verifier = api.addr_make("eth_voting_verifier");
+            let expected_polls_query = DutiesQueryMsg::HasOpenPolls {
+                verifier_address: verifier.to_string(),
+            };
+            deps.querier.update_wasm(move |query| match query {
+                WasmQuery::Smart { contract_addr, .. } if contract_addr == multisig.as_str() => {
+                    Ok(to_json_binary(&open_signing_sessions).into()).into()
+                }
+                WasmQuery::Smart { contract_addr, msg }
+                    if contract_addr == voting_verifier.as_str() =>
+                {
+                    assert_eq!(
+                        from_json::<DutiesQueryMsg>(msg).unwrap(),
+                        expected_polls_query
+                    );
+                    Ok(to_json_binary(&open_polls).into()).into()
+                }
+                _ => panic!("unexpected query: {:?}", query),
+            });
+
+            let ready_to_unbond: bool = from_json(
+                query(
+                    deps.as_ref(),
+                    mock_env(),
+                    QueryMsg::ReadyToUnbond {
+                        verifier_address: verifier.to_string(),
+                    },
+                )
+                .unwrap(),
+            )
+            .unwrap();
+            assert_eq!(ready_to_unbond, !open_signing_sessions && !open_polls);
+        }
+    }
 }
@@ -1,10 +1,27 @@
 use std::collections::HashSet;
 
-use cosmwasm_std::{Addr, DepsMut, MessageInfo, Response};
-use error_stack::{Result, ResultExt};
+use axelar_wasm_std::maintenance::MaintenanceWindow;
+use axelar_wasm_std::nonempty;
+use cosmwasm_std::{
+    wasm_execute, Addr, CosmosMsg, DepsMut, Env, MessageInfo, Response, Storage, WasmMsg,
+};
+use error_stack::{ensure, report, Result, ResultExt};
+use router_api::client::Router;
 use router_api::ChainName;
 
-use crate::state::{save_chain_contracts, save_prover_for_chain, update_verifier_set_for_prover};
+use crate::contract::query::{
+    contract_salt, predict_chain_contract_addresses, verifier_authorization_preview, GATEWAY,
+    PROVER, VOTING_VERIFIER,
+};
+use crate::events::Event;
+use crate::msg::{
+    AuthorizationAction, ContractDeploymentInfo, DeploymentParams, MaintenanceExecuteMsg,
+};
+use crate::state::{
+    contracts_by_chain, load_config, load_maintenance_windows, save_chain_contracts,
+    save_deployment_salt, save_maintenance_windows, save_prover_for_chain,
+    update_verifier_set_for_prover, ChainContractsRecord,
+};
 
 #[derive(thiserror::Error, Debug, PartialEq)]
 pub enum Error {
@@ -16,6 +33,27 @@ pub enum Error {
 
     #[error("prover {0} is not registered")]
     ProverNotRegistered(Addr),
+
+    #[error("failed to authorize verifiers for chain {0}")]
+    VerifierAuthorizationFailed(ChainName),
+
+    #[error("failed to instantiate the contracts of chain {0}")]
+    ChainContractsInstantiationFailed(ChainName),
+
+    #[error("maintenance window of chain {0} must not start before the current block")]
+    MaintenanceWindowInPast(ChainName),
+
+    #[error("maintenance window overlaps with a scheduled maintenance window of chain {0}")]
+    MaintenanceWindowOverlaps(ChainName),
+
+    #[error("no maintenance window of chain {chain_name} starts at height {start_height}")]
+    MaintenanceWindowNotFound {
+        chain_name: ChainName,
+        start_height: u64,
+    },
+
+    #[error("failed to update the maintenance windows of chain {0}")]
+    MaintenanceWindowsUpdateFailed(ChainName),
 }
 
 pub fn register_prover(
@@ -55,3 +93,181 @@ pub fn set_active_verifier_set(
         .change_context(Error::VerifierSetActivationFailed)?;
     Ok(Response::new())
 }
+
+pub fn authorize_verifiers(
+    deps: DepsMut,
+    service_name: String,
+    chain_name: ChainName,
+    verifiers: Vec<Addr>,
+) -> Result<Response, Error> {
+    let preview = verifier_authorization_preview(
+        deps.as_ref(),
+        service_name.clone(),
+        chain_name.clone(),
+        verifiers,
+    )
+    .change_context(Error::VerifierAuthorizationFailed(chain_name))?;
+
+    let to_authorize = preview
+        .actions
+        .into_iter()
+        .filter(|verifier_action| verifier_action.action == AuthorizationAction::Authorize)
+        .map(|verifier_action| verifier_action.verifier.to_string())
+        .collect();
+
+    let config = load_config(deps.storage);
+
+    let service_registry: service_registry_api::Client =
+        client::ContractClient::new(deps.querier, &config.service_registry).into();
+
+    Ok(Response::new()
+        .add_messages(service_registry.authorize_verifiers(to_authorize, service_name)))
+}
+
+pub fn instantiate_chain_contracts(
+    deps: DepsMut,
+    env: Env,
+    chain_name: ChainName,
+    salt: nonempty::HexBinary,
+    params: DeploymentParams,
+    admin: Option<Addr>,
+) -> Result<Response, Error> {
+    let error = || Error::ChainContractsInstantiationFailed(chain_name.clone());
+
+    save_deployment_salt(deps.storage, chain_name.clone(), &salt).change_context_lazy(error)?;
+
+    let addresses = predict_chain_contract_addresses(
+        deps.as_ref(),
+        &env,
+        chain_name.clone(),
+        &salt,
+        &params.code_ids(),
+    )
+    .change_context_lazy(error)?;
+
+    save_chain_contracts(
+        deps.storage,
+        chain_name.clone(),
+        addresses.prover_address,
+        addresses.gateway_address,
+        addresses.verifier_address,
+    )
+    .change_context_lazy(error)?;
+
+    let instantiate = |contract: &str, info: ContractDeploymentInfo| WasmMsg::Instantiate2 {
+        admin: admin.as_ref().map(Addr::to_string),
+        code_id: info.code_id,
+        label: info.label,
+        msg: info.msg,
+        funds: vec![],
+        salt: contract_salt(contract, &chain_name, &salt),
+    };
+
+    Ok(Response::new().add_messages([
+        instantiate(VOTING_VERIFIER, params.voting_verifier),
+        instantiate(GATEWAY, params.gateway),
+        instantiate(PROVER, params.prover),
+    ]))
+}
+
+pub fn schedule_maintenance_window(
+    deps: DepsMut,
+    env: Env,
+    chain_name: ChainName,
+    window: MaintenanceWindow,
+) -> Result<Response, Error> {
+    let error = || Error::MaintenanceWindowsUpdateFailed(chain_name.clone());
+
+    ensure!(
+        window.start_height() >= env.block.height,
+        Error::MaintenanceWindowInPast(chain_name.clone())
+    );
+
+    let contracts = contracts_by_chain(deps.storage, chain_name.clone())
+        .change_context(Error::ChainNotRegistered(chain_name.clone()))?;
+
+    let mut windows = unended_maintenance_windows(deps.storage, &env, chain_name.clone())?;
+    ensure!(
+        !windows.iter().any(|scheduled| scheduled.overlaps(&window)),
+        Error::MaintenanceWindowOverlaps(chain_name.clone())
+    );
+
+    windows.push(window);
+    save_maintenance_windows(deps.storage, chain_name.clone(), windows)
+        .change_context_lazy(error)?;
+
+    Ok(Response::new()
+        .add_messages(set_maintenance_windows_msgs(deps.storage, &contracts)?)
+        .add_event(Event::MaintenanceWindowScheduled { chain_name, window }))
+}
+
+pub fn cancel_maintenance_window(
+    deps: DepsMut,
+    env: Env,
+    chain_name: ChainName,
+    start_height: u64,
+) -> Result<Response, Error> {
+    let error = || Error::MaintenanceWindowsUpdateFailed(chain_name.clone());
+
+    let contracts = contracts_by_chain(deps.storage, chain_name.clone())
+        .change_context(Error::ChainNotRegistered(chain_name.clone()))?;
+
+    let mut windows = unended_maintenance_windows(deps.storage, &env, chain_name.clone())?;
+    let index = windows
+        .iter()
+        .position(|scheduled| scheduled.start_height() == start_height)
+        .ok_or_else(|| {
+            report!(Error::MaintenanceWindowNotFound {
+                chain_name: chain_name.clone(),
+                start_height,
+            })
+        })?;
+    let cancelled = windows.remove(index);
+    save_maintenance_windows(deps.storage, chain_name.clone(), windows)
+        .change_context_lazy(error)?;
+
+    Ok(Response::new()
+        .add_messages(set_maintenance_windows_msgs(deps.storage, &contracts)?)
+        .add_event(Event::MaintenanceWindowCancelled {
+            chain_name,
+            window: cancelled,
+        }))
+}
+
+fn unended_maintenance_windows(
+    storage: &dyn Storage,
+    env: &Env,
+    chain_name: ChainName,
+) -> Result<Vec<MaintenanceWindow>, Error> {
+    Ok(load_maintenance_windows(storage, chain_name.clone())
+        .change_context(Error::MaintenanceWindowsUpdateFailed(chain_name))?
+        .into_iter()
+        .filter(|window| !window.has_ended(env.block.height))
+        .collect())
+}
+
+/// The router, prover and voting verifier of the chain each get the full list of the chain's windows
+fn set_maintenance_windows_msgs(
+    storage: &dyn Storage,
+    contracts: &ChainContractsRecord,
+) -> Result<Vec<CosmosMsg>, Error> {
+    let error = || Error::MaintenanceWindowsUpdateFailed(contracts.chain_name.clone());
+
+    let windows = load_maintenance_windows(storage, contracts.chain_name.clone())
+        .change_context_lazy(error)?;
+
+    let router: Router = Router::new(load_config(storage).router);
+    let msg = MaintenanceExecuteMsg::SetMaintenanceWindows {
+        windows: windows.clone(),
+    };
+
+    Ok(vec![
+        router.set_maintenance_windows(contracts.chain_name.clone(), windows),
+        wasm_execute(&contracts.prover_address, &msg, vec![])
+            .change_context_lazy(error)?
+            .into(),
+        wasm_execute(&contracts.verifier_address, &msg, vec![])
+            .change_context_lazy(error)?
+            .into(),
+    ])
+}
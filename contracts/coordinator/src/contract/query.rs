@@ -1,14 +1,23 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
-use cosmwasm_std::{Addr, Deps, Order, StdError};
+use axelar_wasm_std::maintenance::MaintenanceWindow;
+use axelar_wasm_std::nonempty;
+use cosmwasm_std::{instantiate2_address, Addr, Binary, Deps, Env, Order, StdError};
 use error_stack::{Result, ResultExt};
 use itertools::Itertools;
-use service_registry_api::msg::VerifierDetails;
+use router_api::ChainName;
+use service_registry_api::msg::{VerifierDetails, MAX_VERIFIERS_QUERY_LIMIT};
+use service_registry_api::AuthorizationState;
+use sha3::{Digest, Keccak256};
 
-use crate::msg::{ChainContractsKey, ChainContractsResponse, VerifierInfo};
+use crate::msg::{
+    AuthorizationAction, ChainContractsKey, ChainContractsResponse, ContractCodeIds,
+    DutiesQueryMsg, VerifierAuthorizationAction, VerifierAuthorizationPreview, VerifierInfo,
+};
 use crate::state::{
-    contracts_by_chain, contracts_by_gateway, contracts_by_prover, contracts_by_verifier,
-    load_config, VERIFIER_PROVER_INDEXED_MAP,
+    all_voting_verifiers, contracts_by_chain, contracts_by_gateway, contracts_by_prover,
+    contracts_by_verifier, load_config, load_maintenance_windows, may_load_deployment_salt,
+    VERIFIER_PROVER_INDEXED_MAP,
 };
 
 #[derive(thiserror::Error, Debug, PartialEq)]
@@ -26,10 +35,62 @@ pub enum Error {
 
     #[error("failed to get provers for verifier {0}")]
     FailedToGetProversForVerifier(Addr),
+
+    #[error("coordinator failed to preview verifier authorization. service_name: {service_name}, chain_name: {chain_name}")]
+    VerifierAuthorizationPreview {
+        service_name: String,
+        chain_name: ChainName,
+    },
+
+    #[error("coordinator failed to predict the contract addresses of chain {0}")]
+    AddressPrediction(ChainName),
+
+    #[error("coordinator failed to retrieve the deployment salt of chain {0}")]
+    DeploymentSalt(ChainName),
+
+    #[error("coordinator failed to retrieve the maintenance windows of chain {0}")]
+    MaintenanceWindows(ChainName),
+
+    #[error("coordinator failed to check the open duties of verifier {0}")]
+    OpenDuties(Addr),
 }
 
+pub const GATEWAY: &str = "gateway";
+pub const VOTING_VERIFIER: &str = "voting_verifier";
+pub const PROVER: &str = "prover";
+
+/// A verifier is ready to unbond once it is no longer part of any verifier set and has neither polls to vote in nor
+/// signing sessions to sign anymore
 pub fn check_verifier_ready_to_unbond(deps: Deps, verifier_address: Addr) -> Result<bool, Error> {
-    Ok(!is_verifier_in_any_verifier_set(deps, &verifier_address))
+    if is_verifier_in_any_verifier_set(deps, &verifier_address) {
+        return Ok(false);
+    }
+
+    let error = || Error::OpenDuties(verifier_address.clone());
+
+    let multisig: multisig::Client =
+        client::ContractClient::new(deps.querier, &load_config(deps.storage).multisig).into();
+    if multisig
+        .has_open_signing_sessions(verifier_address.to_string())
+        .change_context_lazy(error)?
+    {
+        return Ok(false);
+    }
+
+    let msg = DutiesQueryMsg::HasOpenPolls {
+        verifier_address: verifier_address.to_string(),
+    };
+    for voting_verifier in all_voting_verifiers(deps.storage).change_context_lazy(error)? {
+        let has_open_polls: bool = deps
+            .querier
+            .query_wasm_smart(&voting_verifier, &msg)
+            .change_context_lazy(error)?;
+        if has_open_polls {
+            return Ok(false);
+        }
+    }
+
+    Ok(true)
 }
 
 pub fn verifier_details_with_provers(
@@ -107,3 +168,130 @@ pub fn get_chain_contracts_info(
     .change_context(Error::ChainContractsInfo)
     .map(ChainContractsResponse::from)
 }
+
+pub fn verifier_authorization_preview(
+    deps: Deps,
+    service_name: String,
+    chain_name: ChainName,
+    verifiers: Vec<Addr>,
+) -> Result<VerifierAuthorizationPreview, Error> {
+    let error = || Error::VerifierAuthorizationPreview {
+        service_name: service_name.clone(),
+        chain_name: chain_name.clone(),
+    };
+
+    contracts_by_chain(deps.storage, chain_name.clone()).change_context_lazy(error)?;
+
+    let config = load_config(deps.storage);
+
+    let service_registry: service_registry_api::Client =
+        client::ContractClient::new(deps.querier, &config.service_registry).into();
+
+    let verifiers: Vec<_> = verifiers.into_iter().unique().collect();
+
+    // the service registry only looks up a limited number of verifiers per query
+    let mut authorization_states = HashMap::new();
+    for chunk in verifiers.chunks(MAX_VERIFIERS_QUERY_LIMIT) {
+        authorization_states.extend(
+            service_registry
+                .verifiers(
+                    service_name.clone(),
+                    chunk.iter().map(Addr::to_string).collect(),
+                )
+                .change_context_lazy(error)?
+                .into_iter()
+                .map(|verifier| (verifier.address, verifier.authorization_state)),
+        );
+    }
+
+    let actions = verifiers
+        .into_iter()
+        .map(|verifier| {
+            let action = match authorization_states.get(&verifier) {
+                Some(AuthorizationState::Authorized) => AuthorizationAction::AlreadyAuthorized,
+                Some(AuthorizationState::Jailed) => AuthorizationAction::BlockedByJail,
+                Some(AuthorizationState::NotAuthorized) | None => AuthorizationAction::Authorize,
+            };
+
+            VerifierAuthorizationAction { verifier, action }
+        })
+        .collect();
+
+    Ok(VerifierAuthorizationPreview {
+        service_name,
+        chain_name,
+        actions,
+    })
+}
+
+pub fn predict_chain_contract_addresses(
+    deps: Deps,
+    env: &Env,
+    chain_name: ChainName,
+    salt: &nonempty::HexBinary,
+    code_ids: &ContractCodeIds,
+) -> Result<ChainContractsResponse, Error> {
+    let error = || Error::AddressPrediction(chain_name.clone());
+
+    let creator = deps
+        .api
+        .addr_canonicalize(env.contract.address.as_str())
+        .change_context_lazy(error)?;
+
+    let predict = |contract: &str, code_id: u64| -> Result<Addr, Error> {
+        let checksum = deps
+            .querier
+            .query_wasm_code_info(code_id)
+            .change_context_lazy(error)?
+            .checksum;
+        let address = instantiate2_address(
+            checksum.as_slice(),
+            &creator,
+            &contract_salt(contract, &chain_name, salt),
+        )
+        .change_context_lazy(error)?;
+
+        deps.api.addr_humanize(&address).change_context_lazy(error)
+    };
+
+    Ok(ChainContractsResponse {
+        prover_address: predict(PROVER, code_ids.prover)?,
+        gateway_address: predict(GATEWAY, code_ids.gateway)?,
+        verifier_address: predict(VOTING_VERIFIER, code_ids.voting_verifier)?,
+        chain_name,
+    })
+}
+
+/// Derives the instantiate2 salt of one of the chain's contracts, so a single salt can be used for all of them
+/// and the same salt results in different addresses for different chains
+pub fn contract_salt(contract: &str, chain_name: &ChainName, salt: &nonempty::HexBinary) -> Binary {
+    // neither the contract nor the chain name contain a null byte, so the preimage is unambiguous
+    let mut hasher = Keccak256::new();
+    hasher.update(contract.as_bytes());
+    hasher.update([0u8]);
+    hasher.update(chain_name.as_ref().as_bytes());
+    hasher.update([0u8]);
+    hasher.update(salt.as_slice());
+
+    Binary::from(hasher.finalize().as_slice())
+}
+
+pub fn deployment_salt(
+    deps: Deps,
+    chain_name: ChainName,
+) -> Result<Option<nonempty::HexBinary>, Error> {
+    may_load_deployment_salt(deps.storage, chain_name.clone())
+        .change_context(Error::DeploymentSalt(chain_name))
+}
+
+pub fn maintenance_windows(
+    deps: Deps,
+    env: &Env,
+    chain_name: ChainName,
+) -> Result<Vec<MaintenanceWindow>, Error> {
+    Ok(load_maintenance_windows(deps.storage, chain_name.clone())
+        .change_context(Error::MaintenanceWindows(chain_name))?
+        .into_iter()
+        .filter(|window| !window.has_ended(env.block.height))
+        .collect())
+}
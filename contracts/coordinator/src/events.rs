@@ -0,0 +1,31 @@
+use axelar_wasm_std::maintenance::MaintenanceWindow;
+use router_api::ChainName;
+
+pub enum Event {
+    MaintenanceWindowScheduled {
+        chain_name: ChainName,
+        window: MaintenanceWindow,
+    },
+    MaintenanceWindowCancelled {
+        chain_name: ChainName,
+        window: MaintenanceWindow,
+    },
+}
+
+impl From<Event> for cosmwasm_std::Event {
+    fn from(other: Event) -> Self {
+        let (event_type, chain_name, window) = match other {
+            Event::MaintenanceWindowScheduled { chain_name, window } => {
+                ("maintenance_window_scheduled", chain_name, window)
+            }
+            Event::MaintenanceWindowCancelled { chain_name, window } => {
+                ("maintenance_window_cancelled", chain_name, window)
+            }
+        };
+
+        cosmwasm_std::Event::new(event_type)
+            .add_attribute("chain_name", chain_name)
+            .add_attribute("start_height", window.start_height().to_string())
+            .add_attribute("end_height", window.end_height().to_string())
+    }
+}
@@ -2,5 +2,6 @@ mod client;
 pub use client::Client;
 
 pub mod contract;
+mod events;
 pub mod msg;
 mod state;
@@ -1,14 +1,18 @@
 use std::collections::HashSet;
 
-use axelar_wasm_std::nonempty;
+use axelar_wasm_std::maintenance::MaintenanceWindow;
+use axelar_wasm_std::{bounded, nonempty};
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::Addr;
+use cosmwasm_std::{Addr, Binary};
 use msgs_derive::EnsurePermissions;
 use router_api::ChainName;
 use service_registry_api::Verifier;
 
 pub use crate::contract::MigrateMsg;
 
+/// Maximum length in bytes of the salt chain contracts are instantiated with
+pub const MAX_SALT_LENGTH: usize = 64;
+
 type ProverAddress = Addr;
 type GatewayAddress = Addr;
 type VerifierAddress = Addr;
@@ -41,6 +45,88 @@ pub enum ExecuteMsg {
     },
     #[permission(Specific(prover))]
     SetActiveVerifiers { verifiers: HashSet<String> },
+    /// Authorizes a cohort of verifiers for the given service with a single service registry call.
+    /// Verifiers that are already authorized or jailed are left untouched,
+    /// see `QueryMsg::VerifierAuthorizationPreview` for the actions that will be applied.
+    #[permission(Governance)]
+    AuthorizeVerifiers {
+        service_name: String,
+        chain_name: ChainName,
+        verifiers: Vec<String>,
+    },
+    /// Deterministically instantiates the gateway, voting verifier and prover of a chain with instantiate2
+    /// and registers them for the chain. The addresses only depend on the code ids, the chain name and the salt,
+    /// see `QueryMsg::PredictChainContractAddresses`. The contracts of a chain can only be instantiated once.
+    #[permission(Governance)]
+    InstantiateChainContracts {
+        chain_name: ChainName,
+        salt: bounded::Bytes<MAX_SALT_LENGTH>,
+        params: Box<DeploymentParams>,
+    },
+    /// Schedules a maintenance window for a registered chain. While the window is active, the router doesn't route
+    /// messages to the chain, the chain's prover doesn't construct proofs and the chain's voting verifier extends the
+    /// expiry of its polls. The window must not start in the past or overlap with another window of the chain.
+    /// The emitted event contains the start and end height of the window, so no further events mark its start or end.
+    #[permission(Governance)]
+    ScheduleMaintenanceWindow {
+        chain_name: ChainName,
+        window: MaintenanceWindow,
+    },
+    /// Cancels the maintenance window of the chain that starts at the given height. Cancelling an active window ends it.
+    /// Ended windows can't be cancelled
+    #[permission(Governance)]
+    CancelMaintenanceWindow {
+        chain_name: ChainName,
+        start_height: u64,
+    },
+}
+
+/// Message the coordinator sends to the prover and the voting verifier of a chain whenever the chain's maintenance
+/// windows change. Both contracts accept it as one of their execute messages
+#[cw_serde]
+pub enum MaintenanceExecuteMsg {
+    SetMaintenanceWindows { windows: Vec<MaintenanceWindow> },
+}
+
+/// Query the coordinator sends to the voting verifier of each chain to find out if a verifier that wants to unbond still
+/// has polls to vote in. The voting verifier accepts it as one of its query messages
+#[cw_serde]
+pub enum DutiesQueryMsg {
+    HasOpenPolls { verifier_address: String },
+}
+
+#[cw_serde]
+pub struct ContractDeploymentInfo {
+    pub code_id: u64,
+    pub label: String,
+    /// Instantiate message of the contract. It can reference the predicted addresses of the chain's other contracts
+    pub msg: Binary,
+}
+
+#[cw_serde]
+pub struct DeploymentParams {
+    pub gateway: ContractDeploymentInfo,
+    pub voting_verifier: ContractDeploymentInfo,
+    pub prover: ContractDeploymentInfo,
+    /// Admin of the instantiated contracts, who is able to migrate them
+    pub admin: Option<String>,
+}
+
+impl DeploymentParams {
+    pub fn code_ids(&self) -> ContractCodeIds {
+        ContractCodeIds {
+            gateway: self.gateway.code_id,
+            voting_verifier: self.voting_verifier.code_id,
+            prover: self.prover.code_id,
+        }
+    }
+}
+
+#[cw_serde]
+pub struct ContractCodeIds {
+    pub gateway: u64,
+    pub voting_verifier: u64,
+    pub prover: u64,
 }
 
 #[cw_serde]
@@ -57,6 +143,30 @@ pub enum QueryMsg {
 
     #[returns(ChainContractsResponse)]
     ChainContractsInfo(ChainContractsKey),
+
+    /// Returns the action `ExecuteMsg::AuthorizeVerifiers` would apply to each of the given verifiers
+    #[returns(VerifierAuthorizationPreview)]
+    VerifierAuthorizationPreview {
+        service_name: String,
+        chain_name: ChainName,
+        verifiers: Vec<String>,
+    },
+
+    /// Returns the addresses that `ExecuteMsg::InstantiateChainContracts` would instantiate the chain's contracts at
+    #[returns(ChainContractsResponse)]
+    PredictChainContractAddresses {
+        chain_name: ChainName,
+        salt: bounded::Bytes<MAX_SALT_LENGTH>,
+        code_ids: ContractCodeIds,
+    },
+
+    /// Returns the salt the chain's contracts were instantiated with, if they were instantiated by the coordinator
+    #[returns(Option<nonempty::HexBinary>)]
+    DeploymentSalt(ChainName),
+
+    /// Returns the active and upcoming maintenance windows of the chain, ordered by start height
+    #[returns(Vec<MaintenanceWindow>)]
+    MaintenanceWindows(ChainName),
 }
 
 #[cw_serde]
@@ -82,3 +192,23 @@ pub struct ChainContractsResponse {
     pub gateway_address: GatewayAddress,
     pub verifier_address: VerifierAddress,
 }
+
+#[cw_serde]
+pub enum AuthorizationAction {
+    Authorize,
+    AlreadyAuthorized,
+    BlockedByJail,
+}
+
+#[cw_serde]
+pub struct VerifierAuthorizationAction {
+    pub verifier: VerifierAddress,
+    pub action: AuthorizationAction,
+}
+
+#[cw_serde]
+pub struct VerifierAuthorizationPreview {
+    pub service_name: String,
+    pub chain_name: ChainName,
+    pub actions: Vec<VerifierAuthorizationAction>,
+}
@@ -1,8 +1,12 @@
 use std::collections::HashSet;
 
+use axelar_wasm_std::maintenance::MaintenanceWindow;
+use axelar_wasm_std::nonempty;
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Order, Storage};
-use cw_storage_plus::{index_list, Index, IndexList, IndexedMap, Item, MultiIndex, UniqueIndex};
+use cw_storage_plus::{
+    index_list, Index, IndexList, IndexedMap, Item, Map, MultiIndex, UniqueIndex,
+};
 use error_stack::{report, Result, ResultExt};
 use router_api::ChainName;
 
@@ -26,6 +30,9 @@ pub enum Error {
     #[error("verifier {0} is not registered")]
     VerifierNotRegistered(Addr),
 
+    #[error("contracts of chain {0} have already been instantiated")]
+    ChainAlreadyInstantiated(ChainName),
+
     #[error("failed to parse state data")]
     StateParseFailed,
 
@@ -170,6 +177,14 @@ pub fn contracts_by_verifier(
         .1)
 }
 
+pub fn all_voting_verifiers(storage: &dyn Storage) -> Result<Vec<VerifierAddress>, Error> {
+    CHAIN_CONTRACTS_MAP
+        .range(storage, None, None, Order::Ascending)
+        .map(|record| record.map(|(_, record)| record.verifier_address))
+        .collect::<std::result::Result<_, _>>()
+        .change_context(Error::StateParseFailed)
+}
+
 // Legacy prover storage - maintained for backward compatibility
 #[index_list(ProverAddress)]
 struct ChainProverIndexes<'a> {
@@ -219,6 +234,62 @@ pub fn save_prover_for_chain(
     Ok(())
 }
 
+/// Salts that the contracts of each chain were instantiated with by the coordinator
+const DEPLOYMENT_SALTS: Map<ChainName, nonempty::HexBinary> = Map::new("deployment_salts");
+
+pub fn save_deployment_salt(
+    storage: &mut dyn Storage,
+    chain: ChainName,
+    salt: &nonempty::HexBinary,
+) -> Result<(), Error> {
+    if DEPLOYMENT_SALTS.has(storage, chain.clone()) {
+        return Err(report!(Error::ChainAlreadyInstantiated(chain)));
+    }
+
+    DEPLOYMENT_SALTS
+        .save(storage, chain, salt)
+        .change_context(Error::StateSaveFailed)
+}
+
+pub fn may_load_deployment_salt(
+    storage: &dyn Storage,
+    chain: ChainName,
+) -> Result<Option<nonempty::HexBinary>, Error> {
+    DEPLOYMENT_SALTS
+        .may_load(storage, chain)
+        .change_context(Error::StateParseFailed)
+}
+
+/// Scheduled maintenance windows of each chain, ordered by start height. Ended windows are removed whenever the
+/// windows of the chain change
+const MAINTENANCE_WINDOWS: Map<ChainName, Vec<MaintenanceWindow>> = Map::new("maintenance_windows");
+
+pub fn load_maintenance_windows(
+    storage: &dyn Storage,
+    chain: ChainName,
+) -> Result<Vec<MaintenanceWindow>, Error> {
+    Ok(MAINTENANCE_WINDOWS
+        .may_load(storage, chain)
+        .change_context(Error::StateParseFailed)?
+        .unwrap_or_default())
+}
+
+pub fn save_maintenance_windows(
+    storage: &mut dyn Storage,
+    chain: ChainName,
+    mut windows: Vec<MaintenanceWindow>,
+) -> Result<(), Error> {
+    if windows.is_empty() {
+        MAINTENANCE_WINDOWS.remove(storage, chain);
+        return Ok(());
+    }
+
+    windows.sort();
+    MAINTENANCE_WINDOWS
+        .save(storage, chain, &windows)
+        .change_context(Error::StateSaveFailed)
+}
+
 #[index_list(VerifierProverRecord)]
 pub struct VerifierSetIndex<'a> {
     pub by_verifier:
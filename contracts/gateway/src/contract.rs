@@ -1,11 +1,12 @@
 use std::fmt::Debug;
 
-use axelar_wasm_std::{address, FnExt};
+use axelar_wasm_std::{address, permission_control, FnExt};
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
-use cosmwasm_std::{Binary, Deps, DepsMut, Env, MessageInfo, Response};
-use error_stack::ResultExt;
+use cosmwasm_std::{Addr, Binary, Deps, DepsMut, Env, HexBinary, MessageInfo, Response, Storage};
+use error_stack::{Report, ResultExt};
 use router_api::client::Router;
+use router_api::{ChainName, CrossChainId};
 
 use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
 use crate::state;
@@ -34,10 +35,52 @@ pub enum Error {
     RouteIncomingMessages,
     #[error("failed to query outgoing messages")]
     OutgoingMessages,
+    #[error("failed to query proof batches")]
+    ProofBatches,
     #[error("failed to save outgoing message")]
     SaveOutgoingMessage,
     #[error("failed to execute gateway command")]
     Execute,
+    #[error("payload verification is not enabled for this gateway")]
+    PayloadVerificationDisabled,
+    #[error("payload of message {cc_id} does not match the payload hash, expected {expected}, got {actual}")]
+    PayloadHashMismatch {
+        cc_id: CrossChainId,
+        expected: HexBinary,
+        actual: HexBinary,
+    },
+    #[error("failed to validate messages from source chain {0}")]
+    MessageValidation(ChainName),
+    #[error("message {cc_id} is invalid: {reason}")]
+    InvalidMessage { cc_id: CrossChainId, reason: String },
+    #[error("failed to save message validator")]
+    SaveMessageValidator,
+    #[error("failed to query message validator")]
+    MessageValidator,
+    #[error("failed to query routed messages")]
+    RoutedMessages,
+    #[error("failed to recall messages")]
+    RecallMessages,
+    #[error("relayer {0} is not on the allowlist")]
+    RelayerNotAllowlisted(Addr),
+    #[error("failed to record relayer submission")]
+    RelayerSubmission,
+    #[error("failed to update relayer allowlist")]
+    UpdateRelayerAllowlist,
+    #[error("failed to update batching window")]
+    UpdateBatchingWindow,
+    #[error("failed to query relayer allowlist")]
+    RelayerAllowlist,
+    #[error("failed to record submitted messages")]
+    RecordSubmissions,
+    #[error("failed to query duplicate submissions")]
+    DuplicateSubmissions,
+    #[error("failed to record message executions")]
+    RecordExecutions,
+    #[error("failed to prune messages")]
+    PruneMessages,
+    #[error("failed to query prunable messages")]
+    PrunableMessages,
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -49,53 +92,231 @@ pub fn instantiate(
 ) -> Result<Response, axelar_wasm_std::error::ContractError> {
     cw2::set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
+    let governance = address::validate_cosmwasm_address(deps.api, &msg.governance_address)?;
+    permission_control::set_governance(deps.storage, &governance)?;
+
     let router = address::validate_cosmwasm_address(deps.api, &msg.router_address)?;
     let verifier = address::validate_cosmwasm_address(deps.api, &msg.verifier_address)?;
 
-    state::save_config(deps.storage, &Config { verifier, router })?;
+    state::save_config(
+        deps.storage,
+        &Config {
+            verifier,
+            router,
+            payload_hash_algorithm: msg.payload_hash_algorithm,
+            batching_window: msg.batching_window,
+            relayer_allowlist_enabled: false,
+            message_retention: msg.message_retention,
+        },
+    )?;
     Ok(Response::new())
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
     deps: DepsMut,
-    _: Env,
+    env: Env,
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, axelar_wasm_std::error::ContractError> {
     let config = state::load_config(deps.storage).change_context(Error::Execute)?;
     let verifier = client::ContractClient::new(deps.querier, &config.verifier).into();
 
-    match msg.ensure_permissions(deps.storage, &info.sender)? {
+    match msg.ensure_permissions(deps.storage, &info.sender, match_router)? {
         ExecuteMsg::VerifyMessages(msgs) => {
-            execute::verify_messages(&verifier, msgs).change_context(Error::VerifyMessages)
+            execute::record_relayer_submission(
+                deps.storage,
+                config.relayer_allowlist_enabled,
+                &info.sender,
+                msgs.len(),
+            )?;
+            execute::validate_incoming_messages(deps.storage, deps.querier, &msgs)?;
+            execute::verify_messages(deps.storage, &verifier, env.block.height, msgs, None)
+                .change_context(Error::VerifyMessages)
+        }
+        ExecuteMsg::VerifyMessagesObservedAt {
+            messages,
+            observed_at,
+        } => {
+            execute::record_relayer_submission(
+                deps.storage,
+                config.relayer_allowlist_enabled,
+                &info.sender,
+                messages.len(),
+            )?;
+            execute::validate_incoming_messages(deps.storage, deps.querier, &messages)?;
+            execute::verify_messages(
+                deps.storage,
+                &verifier,
+                env.block.height,
+                messages,
+                Some(observed_at),
+            )
+            .change_context(Error::VerifyMessages)
         }
         ExecuteMsg::RouteMessages(msgs) => {
             let router = Router::new(config.router);
 
             if info.sender == router.address {
-                execute::route_outgoing_messages(deps.storage, msgs)
-                    .change_context(Error::RouteOutgoingMessages)
+                execute::route_outgoing_messages(
+                    deps.storage,
+                    config.batching_window,
+                    env.block.height,
+                    msgs,
+                )
+                .change_context(Error::RouteOutgoingMessages)
             } else {
+                execute::record_relayer_submission(
+                    deps.storage,
+                    config.relayer_allowlist_enabled,
+                    &info.sender,
+                    msgs.len(),
+                )?;
+                execute::validate_incoming_messages(deps.storage, deps.querier, &msgs)?;
                 execute::route_incoming_messages(&verifier, &router, msgs)
                     .change_context(Error::RouteIncomingMessages)
             }
         }
+        ExecuteMsg::RouteMessagesWithTtl { messages, ttl } => {
+            let router = Router::new(config.router);
+
+            execute::record_relayer_submission(
+                deps.storage,
+                config.relayer_allowlist_enabled,
+                &info.sender,
+                messages.len(),
+            )?;
+            execute::validate_incoming_messages(deps.storage, deps.querier, &messages)?;
+            execute::route_incoming_messages_with_ttl(&verifier, &router, messages, ttl)
+                .change_context(Error::RouteIncomingMessages)
+        }
+        ExecuteMsg::RouteMessagesWithPayload(msgs) => {
+            let router = Router::new(config.router);
+
+            execute::record_relayer_submission(
+                deps.storage,
+                config.relayer_allowlist_enabled,
+                &info.sender,
+                msgs.len(),
+            )?;
+            execute::validate_incoming_messages(
+                deps.storage,
+                deps.querier,
+                msgs.iter().map(|msg| &msg.message),
+            )?;
+            execute::route_incoming_messages_with_payload(
+                &verifier,
+                &router,
+                config.payload_hash_algorithm,
+                msgs,
+            )
+        }
+        ExecuteMsg::RegisterMessageValidator {
+            source_chain,
+            validator,
+        } => {
+            let validator = address::validate_cosmwasm_address(deps.api, &validator)?;
+            execute::register_message_validator(deps.storage, source_chain, validator)
+        }
+        ExecuteMsg::DeregisterMessageValidator { source_chain } => {
+            execute::deregister_message_validator(deps.storage, source_chain)
+        }
+        ExecuteMsg::RecallMessages(cc_ids) => execute::recall_messages(deps.storage, cc_ids),
+        ExecuteMsg::RouteMessagesWithExpiry(msgs) => execute::route_outgoing_messages_with_expiry(
+            deps.storage,
+            config.batching_window,
+            env.block.height,
+            msgs,
+        )
+        .change_context(Error::RouteOutgoingMessages),
+        ExecuteMsg::EnableRelayerAllowlist => {
+            execute::update_relayer_allowlist_enabled(deps.storage, true)
+        }
+        ExecuteMsg::DisableRelayerAllowlist => {
+            execute::update_relayer_allowlist_enabled(deps.storage, false)
+        }
+        ExecuteMsg::AllowRelayers(relayers) => {
+            let relayers = relayers
+                .into_iter()
+                .map(|relayer| address::validate_cosmwasm_address(deps.api, &relayer))
+                .collect::<Result<Vec<_>, _>>()?;
+            execute::allow_relayers(deps.storage, relayers)
+        }
+        ExecuteMsg::DisallowRelayers(relayers) => {
+            let relayers = relayers
+                .into_iter()
+                .map(|relayer| address::validate_cosmwasm_address(deps.api, &relayer))
+                .collect::<Result<Vec<_>, _>>()?;
+            execute::disallow_relayers(deps.storage, relayers)
+        }
+        ExecuteMsg::RecordExecutions(cc_ids) => {
+            execute::ensure_allowlisted_relayer(deps.storage, &info.sender)?;
+            execute::record_executions(
+                deps.storage,
+                &Router::new(config.router),
+                env.block.height,
+                cc_ids,
+            )
+        }
+        ExecuteMsg::PruneMessages { limit } => {
+            execute::ensure_allowlisted_relayer(deps.storage, &info.sender)?;
+            execute::prune_messages(
+                deps.storage,
+                config.message_retention,
+                env.block.height,
+                limit,
+            )
+        }
+        ExecuteMsg::UpdateBatchingWindow { batching_window } => {
+            execute::update_batching_window(deps.storage, batching_window)
+        }
     }?
     .then(Ok)
 }
 
+fn match_router(storage: &dyn Storage, _: &ExecuteMsg) -> Result<Addr, Report<state::Error>> {
+    Ok(state::load_config(storage)?.router)
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(
     deps: Deps,
-    _: Env,
+    env: Env,
     msg: QueryMsg,
 ) -> Result<Binary, axelar_wasm_std::error::ContractError> {
     match msg {
         QueryMsg::OutgoingMessages(message_ids) => {
-            query::outgoing_messages(deps.storage, message_ids.iter())
+            query::outgoing_messages(deps.storage, env.block.height, message_ids.iter())
                 .change_context(Error::OutgoingMessages)
         }
+        QueryMsg::ProofBatches {
+            start_height,
+            limit,
+        } => query::proof_batches(deps.storage, start_height, limit)
+            .change_context(Error::ProofBatches),
+        QueryMsg::MessageValidator { source_chain } => {
+            query::message_validator(deps.storage, &source_chain)
+                .change_context(Error::MessageValidator)
+        }
+        QueryMsg::RoutedMessages { start_after, limit } => {
+            query::routed_messages(deps.storage, start_after, limit)
+                .change_context(Error::RoutedMessages)
+        }
+        QueryMsg::RelayerAllowlist { start_after, limit } => {
+            let start_after = start_after
+                .map(|relayer| address::validate_cosmwasm_address(deps.api, &relayer))
+                .transpose()?;
+            query::relayer_allowlist(deps.storage, start_after, limit)
+                .change_context(Error::RelayerAllowlist)
+        }
+        QueryMsg::DuplicateSubmissions { start_after, limit } => {
+            query::duplicate_submissions(deps.storage, env.block.height, start_after, limit)
+                .change_context(Error::DuplicateSubmissions)
+        }
+        QueryMsg::PrunableMessages { limit } => {
+            query::prunable_messages(deps.storage, env.block.height, limit)
+                .change_context(Error::PrunableMessages)
+        }
     }?
     .then(Ok)
 }
@@ -104,9 +325,8 @@ pub fn query(
 mod test {
     use assert_ok::assert_ok;
     use cosmwasm_std::testing::{message_info, mock_dependencies, mock_env};
-    use cosmwasm_std::Empty;
 
-    use crate::contract::{instantiate, migrate, CONTRACT_NAME, CONTRACT_VERSION};
+    use crate::contract::{instantiate, migrate, MigrateMsg, CONTRACT_NAME, CONTRACT_VERSION};
     use crate::msg::InstantiateMsg;
 
     #[test]
@@ -116,8 +336,12 @@ mod test {
         let env = mock_env();
         let info = message_info(&api.addr_make("sender"), &[]);
         let instantiate_msg = InstantiateMsg {
+            governance_address: api.addr_make("governance").to_string(),
             verifier_address: api.addr_make("verifier").to_string(),
             router_address: api.addr_make("router").to_string(),
+            payload_hash_algorithm: None,
+            batching_window: None,
+            message_retention: None,
         };
 
         assert_ok!(instantiate(
@@ -127,7 +351,15 @@ mod test {
             instantiate_msg
         ));
 
-        migrate(deps.as_mut(), mock_env(), Empty {}).unwrap();
+        migrate(
+            deps.as_mut(),
+            mock_env(),
+            MigrateMsg {
+                governance_address: api.addr_make("governance").to_string(),
+                message_retention: None,
+            },
+        )
+        .unwrap();
 
         let contract_version = cw2::get_contract_version(deps.as_mut().storage).unwrap();
         assert_eq!(contract_version.contract, CONTRACT_NAME);
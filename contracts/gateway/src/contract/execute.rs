@@ -1,60 +1,581 @@
-use axelar_wasm_std::{FnExt, VerificationStatus};
-use cosmwasm_std::{CosmosMsg, Event, Response, Storage};
-use error_stack::{Result, ResultExt};
+use axelar_wasm_std::{nonempty, FnExt, VerificationStatus};
+use cosmwasm_std::{Addr, CosmosMsg, Empty, Event, QuerierWrapper, Response, Storage};
+use error_stack::{report, Result, ResultExt};
+use gateway_api::msg::{InvalidMessage, MessageValidatorQueryMsg, MessageWithExpiry};
 use itertools::Itertools;
 use router_api::client::Router;
-use router_api::Message;
-use voting_verifier::msg::MessageStatus;
+use router_api::{ChainName, CrossChainId, Message};
+use voting_verifier::msg::{MessageStatus, ObservedBlock};
 
 use crate::contract::Error;
 use crate::events::GatewayEvent;
+use crate::msg::{MessageWithPayload, PayloadHashAlgorithm, Relayer};
 use crate::state;
+use crate::state::SubmissionStats;
 
 pub fn verify_messages(
+    storage: &mut dyn Storage,
     verifier: &voting_verifier::Client,
+    block_height: u64,
+    msgs: Vec<Message>,
+    observed_at: Option<ObservedBlock>,
+) -> Result<Response, Error> {
+    let msgs_by_status = msgs_by_status(verifier, msgs)?;
+    let summaries = record_submissions(storage, block_height, &msgs_by_status)?;
+
+    verify(verifier, msgs_by_status, observed_at)
+        .then(|(msgs, events)| {
+            Response::new()
+                .add_messages(msgs)
+                .add_events(events)
+                .add_events(summaries)
+        })
+        .then(Ok)
+}
+
+pub fn route_incoming_messages(
+    verifier: &voting_verifier::Client,
+    router: &Router,
     msgs: Vec<Message>,
 ) -> Result<Response, Error> {
     apply(verifier, msgs, |msgs_by_status| {
-        verify(verifier, msgs_by_status)
+        route(router, msgs_by_status, None)
     })
 }
 
-pub fn route_incoming_messages(
+pub fn route_incoming_messages_with_ttl(
     verifier: &voting_verifier::Client,
     router: &Router,
     msgs: Vec<Message>,
+    ttl: nonempty::Uint64,
 ) -> Result<Response, Error> {
     apply(verifier, msgs, |msgs_by_status| {
-        route(router, msgs_by_status)
+        route(router, msgs_by_status, Some(ttl))
     })
 }
 
+pub fn route_incoming_messages_with_payload(
+    verifier: &voting_verifier::Client,
+    router: &Router,
+    payload_hash_algorithm: Option<PayloadHashAlgorithm>,
+    msgs: Vec<MessageWithPayload>,
+) -> Result<Response, Error> {
+    let payload_hash_algorithm =
+        payload_hash_algorithm.ok_or(report!(Error::PayloadVerificationDisabled))?;
+
+    for msg in msgs.iter() {
+        verify_payload(payload_hash_algorithm, msg)?;
+    }
+
+    let (msgs, payload_events): (Vec<_>, Vec<_>) = msgs
+        .into_iter()
+        .map(|MessageWithPayload { message, payload }| {
+            (
+                message.clone(),
+                Event::from(GatewayEvent::PayloadVerified {
+                    msg: message,
+                    payload,
+                }),
+            )
+        })
+        .unzip();
+
+    route_incoming_messages(verifier, router, msgs)
+        .change_context(Error::RouteIncomingMessages)
+        .map(|response| response.add_events(payload_events))
+}
+
+fn verify_payload(
+    payload_hash_algorithm: PayloadHashAlgorithm,
+    msg: &MessageWithPayload,
+) -> Result<(), Error> {
+    let payload_hash = payload_hash_algorithm.digest(msg.payload.as_slice());
+
+    if payload_hash != msg.message.payload_hash {
+        return Err(report!(Error::PayloadHashMismatch {
+            cc_id: msg.message.cc_id.clone(),
+            expected: msg.message.payload_hash.into(),
+            actual: payload_hash.into(),
+        }));
+    }
+
+    Ok(())
+}
+
+/// Rejects the messages if any of them violates the rules of the validator registered for its source chain.
+/// Messages from source chains without a registered validator are always accepted.
+pub fn validate_incoming_messages<'a>(
+    storage: &dyn Storage,
+    querier: QuerierWrapper,
+    msgs: impl IntoIterator<Item = &'a Message>,
+) -> Result<(), Error> {
+    let msgs: Vec<_> = msgs
+        .into_iter()
+        .filter_map(|msg| {
+            ChainName::try_from(msg.cc_id.source_chain.as_ref())
+                .ok()
+                .map(|source_chain| (source_chain, msg))
+        })
+        .collect();
+
+    // validate chain by chain in order of first appearance, so the result is deterministic
+    for source_chain in msgs.iter().map(|(source_chain, _)| source_chain).unique() {
+        let Some(validator) = state::may_load_message_validator(storage, source_chain)
+            .change_context_lazy(|| Error::MessageValidation(source_chain.clone()))?
+        else {
+            continue;
+        };
+
+        let chain_msgs = msgs
+            .iter()
+            .filter(|(chain, _)| chain == source_chain)
+            .map(|(_, msg)| (*msg).clone())
+            .collect();
+
+        let invalid: Vec<InvalidMessage> =
+            client::ContractClient::<Empty, MessageValidatorQueryMsg>::new(querier, &validator)
+                .query(&MessageValidatorQueryMsg::InvalidMessages(chain_msgs))
+                .change_context_lazy(|| Error::MessageValidation(source_chain.clone()))?;
+
+        if let Some(InvalidMessage { cc_id, reason }) = invalid.into_iter().next() {
+            return Err(report!(Error::InvalidMessage { cc_id, reason }));
+        }
+    }
+
+    Ok(())
+}
+
+pub fn register_message_validator(
+    storage: &mut dyn Storage,
+    source_chain: ChainName,
+    validator: Addr,
+) -> Result<Response, Error> {
+    state::save_message_validator(storage, &source_chain, &validator)
+        .change_context(Error::SaveMessageValidator)?;
+
+    Ok(
+        Response::new().add_event(GatewayEvent::MessageValidatorRegistered {
+            source_chain,
+            validator,
+        }),
+    )
+}
+
+pub fn deregister_message_validator(
+    storage: &mut dyn Storage,
+    source_chain: ChainName,
+) -> Result<Response, Error> {
+    state::remove_message_validator(storage, &source_chain)
+        .change_context(Error::SaveMessageValidator)?;
+
+    Ok(Response::new().add_event(GatewayEvent::MessageValidatorDeregistered { source_chain }))
+}
+
 // because the messages came from the router, we can assume they are already verified
 pub fn route_outgoing_messages(
     store: &mut dyn Storage,
+    batching_window: Option<nonempty::Uint64>,
+    block_height: u64,
     verified: Vec<Message>,
 ) -> Result<Response, Error> {
-    let msgs = check_for_duplicates(verified)?;
+    let msgs = check_for_duplicates(verified)?
+        .into_iter()
+        .map(|msg| {
+            state::is_recalled(store, &msg.cc_id)
+                .change_context(Error::SaveOutgoingMessage)
+                .map(|recalled| (!recalled).then_some(msg))
+        })
+        // recalled messages must not be relayed, even if the router routes them again
+        .filter_map_ok(|msg| msg)
+        .collect::<Result<Vec<_>, _>>()?;
+    let window_start = batching_window.map(|window| batching_window_start(block_height, window));
 
     for msg in msgs.iter() {
-        state::save_outgoing_message(store, &msg.cc_id, msg)
+        let is_new = state::save_outgoing_message(store, &msg.cc_id, msg)
             .change_context(Error::SaveOutgoingMessage)?;
+
+        if is_new {
+            state::save_routing_height(store, &msg.cc_id, block_height)
+                .change_context(Error::SaveOutgoingMessage)?;
+        }
+
+        // re-routed messages stay in the batch of the window in which they were first routed
+        if let (true, Some(window_start)) = (is_new, window_start) {
+            state::add_to_proof_batch(store, window_start, msg)
+                .change_context(Error::SaveOutgoingMessage)?;
+        }
     }
 
     Ok(Response::new().add_events(msgs.into_iter().map(|msg| GatewayEvent::Routing { msg })))
 }
 
+pub fn route_outgoing_messages_with_expiry(
+    store: &mut dyn Storage,
+    batching_window: Option<nonempty::Uint64>,
+    block_height: u64,
+    msgs: Vec<MessageWithExpiry>,
+) -> Result<Response, Error> {
+    for msg in msgs.iter() {
+        // pruned messages are not stored again, so neither is their expiry
+        if state::is_pruned(store, &msg.message.cc_id).change_context(Error::SaveOutgoingMessage)? {
+            continue;
+        }
+
+        state::save_expiry_height(store, &msg.message.cc_id, msg.expires_at)
+            .change_context(Error::SaveOutgoingMessage)?;
+    }
+
+    route_outgoing_messages(
+        store,
+        batching_window,
+        block_height,
+        msgs.into_iter().map(|msg| msg.message).collect(),
+    )
+}
+
+pub fn recall_messages(
+    storage: &mut dyn Storage,
+    cc_ids: Vec<CrossChainId>,
+) -> Result<Response, Error> {
+    let mut events = vec![];
+
+    for cc_id in cc_ids {
+        if state::is_recalled(storage, &cc_id).change_context(Error::RecallMessages)? {
+            continue;
+        }
+
+        state::save_recalled_message(storage, &cc_id).change_context(Error::RecallMessages)?;
+        events.push(GatewayEvent::MessageRecalled { cc_id });
+    }
+
+    Ok(Response::new().add_events(events))
+}
+
+/// Counts the submission for the sender if it is an allowlisted relayer.
+/// While the allowlist is enabled, submissions of all other senders are rejected
+pub fn record_relayer_submission(
+    storage: &mut dyn Storage,
+    allowlist_enabled: bool,
+    sender: &Addr,
+    msg_count: usize,
+) -> Result<(), Error> {
+    let relayer =
+        state::may_load_relayer(storage, sender).change_context(Error::RelayerSubmission)?;
+
+    match relayer {
+        Some(relayer) => state::save_relayer(
+            storage,
+            &Relayer {
+                submissions: relayer.submissions.saturating_add(1),
+                messages: relayer.messages.saturating_add(msg_count as u64),
+                ..relayer
+            },
+        )
+        .change_context(Error::RelayerSubmission),
+        None if allowlist_enabled => Err(report!(Error::RelayerNotAllowlisted(sender.clone()))),
+        None => Ok(()),
+    }
+}
+
+pub fn update_batching_window(
+    storage: &mut dyn Storage,
+    batching_window: Option<nonempty::Uint64>,
+) -> Result<Response, Error> {
+    let config = state::load_config(storage).change_context(Error::UpdateBatchingWindow)?;
+    state::save_config(
+        storage,
+        &state::Config {
+            batching_window,
+            ..config
+        },
+    )
+    .change_context(Error::UpdateBatchingWindow)?;
+
+    Ok(Response::new().add_event(GatewayEvent::BatchingWindowUpdated { batching_window }))
+}
+
+pub fn update_relayer_allowlist_enabled(
+    storage: &mut dyn Storage,
+    enabled: bool,
+) -> Result<Response, Error> {
+    let config = state::load_config(storage).change_context(Error::UpdateRelayerAllowlist)?;
+    state::save_config(
+        storage,
+        &state::Config {
+            relayer_allowlist_enabled: enabled,
+            ..config
+        },
+    )
+    .change_context(Error::UpdateRelayerAllowlist)?;
+
+    let event = if enabled {
+        GatewayEvent::RelayerAllowlistEnabled
+    } else {
+        GatewayEvent::RelayerAllowlistDisabled
+    };
+
+    Ok(Response::new().add_event(event))
+}
+
+pub fn allow_relayers(storage: &mut dyn Storage, relayers: Vec<Addr>) -> Result<Response, Error> {
+    let mut events = vec![];
+
+    for relayer in relayers {
+        if state::may_load_relayer(storage, &relayer)
+            .change_context(Error::UpdateRelayerAllowlist)?
+            .is_some()
+        {
+            continue;
+        }
+
+        state::save_relayer(
+            storage,
+            &Relayer {
+                address: relayer.clone(),
+                submissions: 0,
+                messages: 0,
+            },
+        )
+        .change_context(Error::UpdateRelayerAllowlist)?;
+        events.push(GatewayEvent::RelayerAllowed { relayer });
+    }
+
+    Ok(Response::new().add_events(events))
+}
+
+pub fn disallow_relayers(
+    storage: &mut dyn Storage,
+    relayers: Vec<Addr>,
+) -> Result<Response, Error> {
+    let mut events = vec![];
+
+    for relayer in relayers {
+        if state::may_load_relayer(storage, &relayer)
+            .change_context(Error::UpdateRelayerAllowlist)?
+            .is_none()
+        {
+            continue;
+        }
+
+        state::remove_relayer(storage, &relayer);
+        events.push(GatewayEvent::RelayerDisallowed { relayer });
+    }
+
+    Ok(Response::new().add_events(events))
+}
+
+/// Rejects senders that are not on the relayer allowlist, regardless of whether the allowlist is enforced for submissions
+pub fn ensure_allowlisted_relayer(storage: &dyn Storage, sender: &Addr) -> Result<(), Error> {
+    state::may_load_relayer(storage, sender)
+        .change_context(Error::RelayerAllowlist)?
+        .ok_or_else(|| report!(Error::RelayerNotAllowlisted(sender.clone())))
+        .map(|_| ())
+}
+
+/// Newly recorded executions are forwarded to the router, so it can track latencies and drop its record of the messages
+pub fn record_executions(
+    storage: &mut dyn Storage,
+    router: &Router,
+    block_height: u64,
+    cc_ids: Vec<CrossChainId>,
+) -> Result<Response, Error> {
+    let mut recorded = vec![];
+    let mut events = vec![];
+
+    for cc_id in cc_ids {
+        match state::load_outgoing_message(storage, &cc_id) {
+            Ok(_) => (),
+            Err(state::Error::MessageNotFound(_) | state::Error::MessageRecalled(_)) => continue,
+            Err(err) => return Err(report!(err).change_context(Error::RecordExecutions)),
+        }
+
+        if state::save_execution_height(storage, &cc_id, block_height)
+            .change_context(Error::RecordExecutions)?
+        {
+            recorded.push(cc_id.clone());
+            events.push(GatewayEvent::MessageExecuted { cc_id });
+        }
+    }
+
+    Ok(Response::new()
+        .add_messages(router.record_executions(recorded))
+        .add_events(events))
+}
+
+pub fn prune_messages(
+    storage: &mut dyn Storage,
+    message_retention: Option<nonempty::Uint64>,
+    block_height: u64,
+    limit: u32,
+) -> Result<Response, Error> {
+    let limit =
+        usize::try_from(limit.min(state::MAX_PRUNE_LIMIT)).expect("limit should fit into usize");
+    let retention_cutoff = state::retention_cutoff(block_height, message_retention);
+
+    let prunable = state::load_prunable_messages(storage, block_height, retention_cutoff, limit)
+        .change_context(Error::PruneMessages)?;
+
+    let mut deleted_keys = 0u64;
+    for msg in prunable.iter() {
+        let removed_keys =
+            state::remove_outgoing_message(storage, msg).change_context(Error::PruneMessages)?;
+        deleted_keys = deleted_keys.saturating_add(removed_keys);
+    }
+
+    let proof_batches = match retention_cutoff {
+        Some(cutoff) => state::remove_proof_batches(storage, cutoff, limit)
+            .change_context(Error::PruneMessages)?,
+        None => 0,
+    };
+    deleted_keys = deleted_keys.saturating_add(proof_batches);
+
+    let routing_heights = match block_height.checked_sub(state::ROUTING_HEIGHT_RETENTION) {
+        Some(routed_until) => state::remove_routing_heights(storage, routed_until, limit)
+            .change_context(Error::PruneMessages)?,
+        None => 0,
+    };
+    deleted_keys = deleted_keys.saturating_add(routing_heights);
+
+    let duplicate_submissions = state::remove_duplicate_submissions(
+        storage,
+        state::summary_period_start(block_height),
+        limit,
+    )
+    .change_context(Error::PruneMessages)?;
+    deleted_keys = deleted_keys.saturating_add(duplicate_submissions);
+
+    Ok(Response::new().add_event(GatewayEvent::MessagesPruned {
+        messages: u64::try_from(prunable.len()).expect("number of messages should fit into u64"),
+        proof_batches,
+        deleted_keys,
+    }))
+}
+
+/// Counts the messages submitted for verification per source chain, and the duplicate submissions of messages that are
+/// already being verified or whose verification is final. Returns summary events for the source chains whose previous
+/// summary period is over
+fn record_submissions(
+    storage: &mut dyn Storage,
+    block_height: u64,
+    msgs_by_status: &[(VerificationStatus, Vec<Message>)],
+) -> Result<Vec<GatewayEvent>, Error> {
+    let submissions: Vec<_> = msgs_by_status
+        .iter()
+        .flat_map(|(status, msgs)| {
+            msgs.iter()
+                .map(|msg| (&msg.cc_id, is_duplicate_submission(*status)))
+        })
+        .collect();
+
+    let period_start = state::summary_period_start(block_height);
+
+    // a message that is submitted several times in the same call is only written once
+    for (cc_id, count) in submissions
+        .iter()
+        .filter(|(_, is_duplicate)| *is_duplicate)
+        .map(|(cc_id, _)| *cc_id)
+        .counts()
+    {
+        state::record_duplicate_submissions(
+            storage,
+            period_start,
+            cc_id,
+            u64::try_from(count).unwrap_or(u64::MAX),
+        )
+        .change_context(Error::RecordSubmissions)?;
+    }
+
+    let mut events = vec![];
+
+    // summarize chain by chain in order of first appearance, so the events are deterministic
+    for source_chain in submissions
+        .iter()
+        .map(|(cc_id, _)| &cc_id.source_chain)
+        .unique()
+    {
+        let chain_submissions = submissions
+            .iter()
+            .filter(|(cc_id, _)| &cc_id.source_chain == source_chain);
+        let messages = u64::try_from(chain_submissions.clone().count()).unwrap_or(u64::MAX);
+        let duplicates = u64::try_from(
+            chain_submissions
+                .filter(|(_, is_duplicate)| *is_duplicate)
+                .count(),
+        )
+        .unwrap_or(u64::MAX);
+
+        let stats = match state::may_load_submission_stats(storage, source_chain)
+            .change_context(Error::RecordSubmissions)?
+        {
+            Some(stats) if stats.period_start == period_start => stats,
+            previous => {
+                if let Some(previous) = previous {
+                    events.push(GatewayEvent::DuplicateSubmissionsSummary {
+                        source_chain: source_chain.clone(),
+                        stats: previous,
+                    });
+                }
+
+                SubmissionStats {
+                    period_start,
+                    messages: 0,
+                    duplicates: 0,
+                }
+            }
+        };
+
+        state::save_submission_stats(
+            storage,
+            source_chain,
+            &SubmissionStats {
+                messages: stats.messages.saturating_add(messages),
+                duplicates: stats.duplicates.saturating_add(duplicates),
+                ..stats
+            },
+        )
+        .change_context(Error::RecordSubmissions)?;
+    }
+
+    Ok(events)
+}
+
+/// Submissions of messages that are not verifiable don't trigger a new verification
+fn is_duplicate_submission(status: VerificationStatus) -> bool {
+    !matches!(
+        status,
+        VerificationStatus::Unknown
+            | VerificationStatus::NotFoundOnSourceChain
+            | VerificationStatus::FailedToVerify
+    )
+}
+
+fn batching_window_start(block_height: u64, batching_window: nonempty::Uint64) -> u64 {
+    let offset = block_height
+        .checked_rem(batching_window.into())
+        .expect("batching window must be non-zero");
+
+    block_height.saturating_sub(offset)
+}
+
 fn apply(
     verifier: &voting_verifier::Client,
     msgs: Vec<Message>,
-    action: impl Fn(Vec<(VerificationStatus, Vec<Message>)>) -> (Option<CosmosMsg>, Vec<Event>),
+    action: impl FnOnce(Vec<(VerificationStatus, Vec<Message>)>) -> (Option<CosmosMsg>, Vec<Event>),
 ) -> Result<Response, Error> {
+    msgs_by_status(verifier, msgs)?
+        .then(action)
+        .then(|(msgs, events)| Response::new().add_messages(msgs).add_events(events))
+        .then(Ok)
+}
+
+fn msgs_by_status(
+    verifier: &voting_verifier::Client,
+    msgs: Vec<Message>,
+) -> Result<Vec<(VerificationStatus, Vec<Message>)>, Error> {
     check_for_duplicates(msgs)?
         .then(|msgs| verifier.messages_status(msgs))
         .change_context(Error::MessageStatus)?
         .then(group_by_status)
-        .then(action)
-        .then(|(msgs, events)| Response::new().add_messages(msgs).add_events(events))
         .then(Ok)
 }
 
@@ -89,6 +610,7 @@ fn group_by_status(
 fn verify(
     verifier: &voting_verifier::Client,
     msgs_by_status: Vec<(VerificationStatus, Vec<Message>)>,
+    observed_at: Option<ObservedBlock>,
 ) -> (Option<CosmosMsg>, Vec<Event>) {
     msgs_by_status
         .into_iter()
@@ -99,12 +621,20 @@ fn verify(
             )
         })
         .then(flat_unzip)
-        .then(|(msgs, events)| (verifier.verify_messages(msgs), events))
+        .then(|(msgs, events)| {
+            let msg = match observed_at {
+                Some(observed_at) => verifier.verify_messages_observed_at(msgs, observed_at),
+                None => verifier.verify_messages(msgs),
+            };
+
+            (msg, events)
+        })
 }
 
 fn route(
     router: &Router,
     msgs_by_status: Vec<(VerificationStatus, Vec<Message>)>,
+    ttl: Option<nonempty::Uint64>,
 ) -> (Option<CosmosMsg>, Vec<Event>) {
     msgs_by_status
         .into_iter()
@@ -115,7 +645,10 @@ fn route(
             )
         })
         .then(flat_unzip)
-        .then(|(msgs, events)| (router.route(msgs), events))
+        .then(|(msgs, events)| match ttl {
+            Some(ttl) => (router.route_with_ttl(msgs, ttl), events),
+            None => (router.route(msgs), events),
+        })
 }
 
 // not all messages are verifiable, so it's better to only take a reference and allocate a vector on demand
@@ -198,13 +731,13 @@ mod tests {
 
         let mut deps = mock_dependencies();
 
-        let response = route_outgoing_messages(deps.as_mut().storage, vec![msg.clone()]);
+        let response = route_outgoing_messages(deps.as_mut().storage, None, 0, vec![msg.clone()]);
         assert!(response.is_ok());
 
         // re-route with different payload
         msg.payload_hash = [2; 32];
 
-        let response = route_outgoing_messages(deps.as_mut().storage, vec![msg]);
+        let response = route_outgoing_messages(deps.as_mut().storage, None, 0, vec![msg]);
         assert!(response.is_err_and(|err| err_contains!(
             err,
             state::Error,
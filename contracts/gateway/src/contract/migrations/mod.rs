@@ -1,16 +1,34 @@
-use axelar_wasm_std::migrate_from_version;
+use axelar_wasm_std::{address, migrate_from_version, nonempty, permission_control};
+use cosmwasm_schema::cw_serde;
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
-use cosmwasm_std::{DepsMut, Empty, Env, Response};
+use cosmwasm_std::{DepsMut, Env, Response};
 
-pub type MigrateMsg = Empty;
+use crate::state;
+
+#[cw_serde]
+pub struct MigrateMsg {
+    /// Governance can register validators for the messages of individual source chains
+    pub governance_address: String,
+    /// Number of blocks for which outgoing messages are kept after their execution was recorded. If not set, only expired
+    /// messages can be pruned.
+    #[serde(default)]
+    pub message_retention: Option<nonempty::Uint64>,
+}
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 #[migrate_from_version("1.1")]
 pub fn migrate(
     deps: DepsMut,
     _env: Env,
-    _msg: MigrateMsg,
+    msg: MigrateMsg,
 ) -> Result<Response, axelar_wasm_std::error::ContractError> {
+    let governance = address::validate_cosmwasm_address(deps.api, &msg.governance_address)?;
+    permission_control::set_governance(deps.storage, &governance)?;
+
+    let mut config = state::load_config(deps.storage)?;
+    config.message_retention = msg.message_retention;
+    state::save_config(deps.storage, &config)?;
+
     Ok(Response::default())
 }
@@ -1,25 +1,147 @@
 use axelar_wasm_std::error::extend_err;
-use cosmwasm_std::{to_json_binary, Binary, Storage};
+use cosmwasm_std::{to_json_binary, Addr, Binary, Storage};
 use error_stack::Result;
-use router_api::{CrossChainId, Message};
+use router_api::{ChainName, CrossChainId, Message};
 
+use crate::msg::RelayerAllowlist;
 use crate::state;
 
+const DEFAULT_PROOF_BATCHES_LIMIT: u32 = 50;
+const DEFAULT_ROUTED_MESSAGES_LIMIT: u32 = 100;
+const DEFAULT_RELAYERS_LIMIT: u32 = 100;
+const MAX_DUPLICATE_SUBMISSIONS_LIMIT: u32 = 100;
+
 pub fn outgoing_messages<'a>(
     storage: &dyn Storage,
+    block_height: u64,
     cross_chain_ids: impl Iterator<Item = &'a CrossChainId>,
 ) -> Result<Binary, state::Error> {
     let msgs = cross_chain_ids
-        .map(|id| state::load_outgoing_message(storage, id))
-        .fold(Ok(vec![]), accumulate_errs)?;
+        .map(|id| load_approvable_outgoing_message(storage, block_height, id))
+        .fold(Ok(vec![]), accumulate_errs)?
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+
+    Ok(to_json_binary(&msgs).map_err(state::Error::from)?)
+}
+
+// expired and recalled messages must not be approved, so they are left out instead of being handed out to construct proofs
+fn load_approvable_outgoing_message(
+    storage: &dyn Storage,
+    block_height: u64,
+    cc_id: &CrossChainId,
+) -> std::result::Result<Option<Message>, state::Error> {
+    if state::is_expired(storage, cc_id, block_height)? {
+        return Ok(None);
+    }
+
+    match state::load_outgoing_message(storage, cc_id) {
+        Ok(msg) => Ok(Some(msg)),
+        Err(state::Error::MessageRecalled(_)) => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+pub fn proof_batches(
+    storage: &dyn Storage,
+    start_height: Option<u64>,
+    limit: Option<u32>,
+) -> Result<Binary, state::Error> {
+    let limit = limit.unwrap_or(DEFAULT_PROOF_BATCHES_LIMIT);
+    let batches = state::load_proof_batches(
+        storage,
+        start_height.unwrap_or_default(),
+        usize::try_from(limit).expect("limit should fit into usize"),
+    )?;
+
+    Ok(to_json_binary(&batches).map_err(state::Error::from)?)
+}
+
+pub fn routed_messages(
+    storage: &dyn Storage,
+    start_after: Option<CrossChainId>,
+    limit: Option<u32>,
+) -> Result<Binary, state::Error> {
+    let limit = limit.unwrap_or(DEFAULT_ROUTED_MESSAGES_LIMIT);
+    let msgs = state::load_routed_messages(
+        storage,
+        start_after.as_ref(),
+        usize::try_from(limit).expect("limit should fit into usize"),
+    )?;
 
     Ok(to_json_binary(&msgs).map_err(state::Error::from)?)
 }
 
-fn accumulate_errs(
-    acc: Result<Vec<Message>, state::Error>,
-    msg: std::result::Result<Message, state::Error>,
-) -> Result<Vec<Message>, state::Error> {
+pub fn relayer_allowlist(
+    storage: &dyn Storage,
+    start_after: Option<Addr>,
+    limit: Option<u32>,
+) -> Result<Binary, state::Error> {
+    let limit = limit.unwrap_or(DEFAULT_RELAYERS_LIMIT);
+    let allowlist = RelayerAllowlist {
+        enabled: state::load_config(storage)?.relayer_allowlist_enabled,
+        relayers: state::load_relayers(
+            storage,
+            start_after.as_ref(),
+            usize::try_from(limit).expect("limit should fit into usize"),
+        )?,
+    };
+
+    Ok(to_json_binary(&allowlist).map_err(state::Error::from)?)
+}
+
+pub fn duplicate_submissions(
+    storage: &dyn Storage,
+    block_height: u64,
+    start_after: Option<CrossChainId>,
+    limit: Option<u32>,
+) -> Result<Binary, state::Error> {
+    let limit = limit
+        .unwrap_or(MAX_DUPLICATE_SUBMISSIONS_LIMIT)
+        .min(MAX_DUPLICATE_SUBMISSIONS_LIMIT);
+    let duplicates = state::load_duplicate_submissions(
+        storage,
+        state::summary_period_start(block_height),
+        start_after.as_ref(),
+        usize::try_from(limit).expect("limit should fit into usize"),
+    )?;
+
+    Ok(to_json_binary(&duplicates).map_err(state::Error::from)?)
+}
+
+pub fn prunable_messages(
+    storage: &dyn Storage,
+    block_height: u64,
+    limit: Option<u32>,
+) -> Result<Binary, state::Error> {
+    let limit = limit
+        .unwrap_or(state::MAX_PRUNE_LIMIT)
+        .min(state::MAX_PRUNE_LIMIT);
+    let message_retention = state::load_config(storage)?.message_retention;
+    let prunable = state::load_prunable_messages(
+        storage,
+        block_height,
+        state::retention_cutoff(block_height, message_retention),
+        usize::try_from(limit).expect("limit should fit into usize"),
+    )?;
+
+    Ok(to_json_binary(&prunable).map_err(state::Error::from)?)
+}
+
+pub fn message_validator(
+    storage: &dyn Storage,
+    source_chain: &ChainName,
+) -> Result<Binary, state::Error> {
+    let validator = state::may_load_message_validator(storage, source_chain)?;
+
+    Ok(to_json_binary(&validator).map_err(state::Error::from)?)
+}
+
+fn accumulate_errs<T>(
+    acc: Result<Vec<T>, state::Error>,
+    msg: std::result::Result<T, state::Error>,
+) -> Result<Vec<T>, state::Error> {
     match (acc, msg) {
         (Ok(mut msgs), Ok(msg)) => {
             msgs.push(msg);
@@ -50,7 +172,7 @@ mod test {
 
         let ids = messages.iter().map(|msg| &msg.cc_id);
 
-        let res = super::outgoing_messages(&deps.storage, ids).unwrap();
+        let res = super::outgoing_messages(&deps.storage, 0, ids).unwrap();
         let actual_messages: Vec<Message> = from_json(res).unwrap();
         assert_eq!(actual_messages, messages);
     }
@@ -62,7 +184,7 @@ mod test {
         let messages = generate_messages();
         let ids = messages.iter().map(|msg| &msg.cc_id);
 
-        let res = super::outgoing_messages(&deps.storage, ids);
+        let res = super::outgoing_messages(&deps.storage, 0, ids);
 
         assert!(res.is_err());
         assert_eq!(res.unwrap_err().current_frames().len(), messages.len());
@@ -79,7 +201,7 @@ mod test {
 
         let ids = messages.iter().map(|msg| &msg.cc_id);
 
-        let res = super::outgoing_messages(&deps.storage, ids);
+        let res = super::outgoing_messages(&deps.storage, 0, ids);
 
         assert!(res.is_err());
         assert_eq!(res.unwrap_err().current_frames().len(), messages.len() - 1);
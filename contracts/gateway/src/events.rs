@@ -1,12 +1,62 @@
-use cosmwasm_std::{Attribute, Event};
-use router_api::Message;
+use axelar_wasm_std::nonempty;
+use cosmwasm_std::{Addr, Attribute, Event, HexBinary};
+use router_api::{ChainName, ChainNameRaw, CrossChainId, Message};
+
+use crate::state::SubmissionStats;
 
 pub enum GatewayEvent {
-    Verifying { msg: Message },
-    AlreadyVerified { msg: Message },
-    AlreadyRejected { msg: Message },
-    Routing { msg: Message },
-    UnfitForRouting { msg: Message },
+    Verifying {
+        msg: Message,
+    },
+    AlreadyVerified {
+        msg: Message,
+    },
+    AlreadyRejected {
+        msg: Message,
+    },
+    Routing {
+        msg: Message,
+    },
+    UnfitForRouting {
+        msg: Message,
+    },
+    PayloadVerified {
+        msg: Message,
+        payload: HexBinary,
+    },
+    MessageValidatorRegistered {
+        source_chain: ChainName,
+        validator: Addr,
+    },
+    MessageValidatorDeregistered {
+        source_chain: ChainName,
+    },
+    MessageRecalled {
+        cc_id: CrossChainId,
+    },
+    RelayerAllowlistEnabled,
+    RelayerAllowlistDisabled,
+    BatchingWindowUpdated {
+        batching_window: Option<nonempty::Uint64>,
+    },
+    RelayerAllowed {
+        relayer: Addr,
+    },
+    RelayerDisallowed {
+        relayer: Addr,
+    },
+    DuplicateSubmissionsSummary {
+        source_chain: ChainNameRaw,
+        stats: SubmissionStats,
+    },
+    MessagesPruned {
+        messages: u64,
+        proof_batches: u64,
+        deleted_keys: u64,
+    },
+    MessageExecuted {
+        cc_id: CrossChainId,
+    },
 }
 
 fn make_message_event(event_name: &str, msg: Message) -> Event {
@@ -23,6 +73,60 @@ impl From<GatewayEvent> for Event {
             GatewayEvent::AlreadyRejected { msg } => make_message_event("already_rejected", msg),
             GatewayEvent::Routing { msg } => make_message_event("routing", msg),
             GatewayEvent::UnfitForRouting { msg } => make_message_event("unfit_for_routing", msg),
+            GatewayEvent::PayloadVerified { msg, payload } => {
+                make_message_event("payload_verified", msg)
+                    .add_attribute("payload", payload.to_string())
+            }
+            GatewayEvent::MessageValidatorRegistered {
+                source_chain,
+                validator,
+            } => Event::new("message_validator_registered")
+                .add_attribute("source_chain", source_chain)
+                .add_attribute("validator", validator),
+            GatewayEvent::MessageValidatorDeregistered { source_chain } => {
+                Event::new("message_validator_deregistered")
+                    .add_attribute("source_chain", source_chain)
+            }
+            GatewayEvent::MessageRecalled { cc_id } => Event::new("message_recalled")
+                .add_attribute("source_chain", cc_id.source_chain)
+                .add_attribute("message_id", cc_id.message_id),
+            GatewayEvent::RelayerAllowlistEnabled => Event::new("relayer_allowlist_enabled"),
+            GatewayEvent::RelayerAllowlistDisabled => Event::new("relayer_allowlist_disabled"),
+            GatewayEvent::BatchingWindowUpdated { batching_window } => {
+                let event = Event::new("batching_window_updated");
+
+                match batching_window {
+                    Some(batching_window) => {
+                        event.add_attribute("batching_window", batching_window.to_string())
+                    }
+                    None => event,
+                }
+            }
+            GatewayEvent::RelayerAllowed { relayer } => {
+                Event::new("relayer_allowed").add_attribute("relayer", relayer)
+            }
+            GatewayEvent::RelayerDisallowed { relayer } => {
+                Event::new("relayer_disallowed").add_attribute("relayer", relayer)
+            }
+            GatewayEvent::DuplicateSubmissionsSummary {
+                source_chain,
+                stats,
+            } => Event::new("duplicate_submissions_summary")
+                .add_attribute("source_chain", source_chain.to_string())
+                .add_attribute("period_start", stats.period_start.to_string())
+                .add_attribute("messages", stats.messages.to_string())
+                .add_attribute("duplicates", stats.duplicates.to_string()),
+            GatewayEvent::MessagesPruned {
+                messages,
+                proof_batches,
+                deleted_keys,
+            } => Event::new("messages_pruned")
+                .add_attribute("messages", messages.to_string())
+                .add_attribute("proof_batches", proof_batches.to_string())
+                .add_attribute("deleted_keys", deleted_keys.to_string()),
+            GatewayEvent::MessageExecuted { cc_id } => Event::new("message_executed")
+                .add_attribute("source_chain", cc_id.source_chain)
+                .add_attribute("message_id", cc_id.message_id),
         }
     }
 }
@@ -1,13 +1,47 @@
+use axelar_wasm_std::nonempty;
 use cosmwasm_schema::cw_serde;
+use sha3::Digest;
 // these messages are extracted into a separate package to avoid circular dependencies
-pub use gateway_api::msg::{ExecuteMsg, QueryMsg};
+pub use gateway_api::msg::{
+    DuplicateSubmissions, ExecuteMsg, MessageWithExpiry, MessageWithPayload, ProofBatch,
+    PrunableMessage, QueryMsg, Relayer, RelayerAllowlist, RoutedMessage,
+};
 
 pub use crate::contract::MigrateMsg;
 
 #[cw_serde]
 pub struct InstantiateMsg {
+    /// Address of the governance account, which can register validators for the messages of individual source chains.
+    pub governance_address: String,
     /// Address of the verifier contract on axelar associated with the source chain. E.g., the voting verifier contract.
     pub verifier_address: String,
     /// Address of the router contract on axelar.
     pub router_address: String,
+    /// Hash function the source chain uses to commit to message payloads. If set, messages can be routed together
+    /// with their full payload, which is checked against the payload hash. If not set, payload verification is disabled.
+    pub payload_hash_algorithm: Option<PayloadHashAlgorithm>,
+    /// Number of blocks over which outgoing messages are grouped into suggested proof batches.
+    /// If not set, outgoing messages are not batched.
+    #[serde(default)]
+    pub batching_window: Option<nonempty::Uint64>,
+    /// Number of blocks for which outgoing messages are kept after their execution on the destination chain was recorded.
+    /// Older executed messages can be pruned with PruneMessages. If not set, only expired messages can be pruned.
+    #[serde(default)]
+    pub message_retention: Option<nonempty::Uint64>,
+}
+
+#[cw_serde]
+#[derive(Copy)]
+pub enum PayloadHashAlgorithm {
+    Keccak256,
+    Sha256,
+}
+
+impl PayloadHashAlgorithm {
+    pub fn digest(&self, payload: &[u8]) -> [u8; 32] {
+        match self {
+            PayloadHashAlgorithm::Keccak256 => sha3::Keccak256::digest(payload).into(),
+            PayloadHashAlgorithm::Sha256 => sha2::Sha256::digest(payload).into(),
+        }
+    }
 }
@@ -1,17 +1,86 @@
-use axelar_wasm_std::IntoContractError;
+use std::collections::HashSet;
+
+use axelar_wasm_std::hash::Hash;
+use axelar_wasm_std::{nonempty, IntoContractError};
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, StdError, Storage};
-use cw_storage_plus::{Item, Map};
-use router_api::{CrossChainId, Message};
+use cosmwasm_std::{Addr, Order, StdError, Storage};
+use cw_storage_plus::{Bound, Item, Map, PrefixBound};
+use router_api::{ChainName, ChainNameRaw, CrossChainId, Message};
+
+use crate::msg::{
+    DuplicateSubmissions, PayloadHashAlgorithm, ProofBatch, PrunableMessage, Relayer, RoutedMessage,
+};
 
 #[cw_serde]
 pub struct Config {
     pub verifier: Addr,
     pub router: Addr,
+    pub payload_hash_algorithm: Option<PayloadHashAlgorithm>,
+    #[serde(default)]
+    pub batching_window: Option<nonempty::Uint64>,
+    /// If true, only allowlisted relayers can submit incoming messages
+    #[serde(default)]
+    pub relayer_allowlist_enabled: bool,
+    /// Number of blocks for which outgoing messages are kept after their execution was recorded. If None, only expired
+    /// messages are pruned
+    #[serde(default)]
+    pub message_retention: Option<nonempty::Uint64>,
 }
 
+/// Maximum number of outgoing messages and proof batches that are pruned in a single call
+pub const MAX_PRUNE_LIMIT: u32 = 100;
+/// Maximum number of messages in a proof batch. Once a batch is full, further messages of the same window and
+/// destination chain go into the next batch
+pub const MAX_PROOF_BATCH_SIZE: usize = 100;
+/// Number of blocks for which the routing height of an outgoing message is kept. Older routing heights are deleted when
+/// messages are pruned, so the list of routed messages only covers recent messages, whether or not they are ever pruned
+pub const ROUTING_HEIGHT_RETENTION: u64 = 1_000_000;
+/// Number of blocks over which duplicate submissions are counted and summarized per source chain
+pub const SUBMISSION_SUMMARY_PERIOD: u64 = 1000;
+
 const CONFIG: Item<Config> = Item::new("config");
 const OUTGOING_MESSAGES: Map<&CrossChainId, Message> = Map::new("outgoing_messages");
+/// Maps the start of a batching window, a destination chain and the index of a batch within that window to the IDs of
+/// the outgoing messages routed during that window
+const PROOF_BATCHES: Map<(u64, (&ChainName, u32)), Vec<CrossChainId>> = Map::new("proof_batches");
+/// Maps a source chain to the contract that validates incoming messages against the chain's rules
+const MESSAGE_VALIDATORS: Map<&ChainName, Addr> = Map::new("message_validators");
+/// Outgoing messages that were recalled by the router and must not be relayed
+const RECALLED_MESSAGES: Map<&CrossChainId, ()> = Map::new("recalled_messages");
+/// Block height at which an outgoing message was first routed to the gateway. Messages routed before this was tracked have no entry
+const ROUTING_HEIGHTS: Map<&CrossChainId, u64> = Map::new("routing_heights");
+/// Outgoing messages ordered by the block height at which they were first routed, so the oldest routing heights can be pruned
+const MESSAGES_BY_ROUTING_HEIGHT: Map<(u64, &CrossChainId), ()> =
+    Map::new("messages_by_routing_height");
+/// Block height from which on an outgoing message that was routed with a TTL can no longer be approved
+const EXPIRY_HEIGHTS: Map<&CrossChainId, u64> = Map::new("expiry_heights");
+/// Outgoing messages ordered by the block height at which they expire, so expired ones can be pruned
+const MESSAGES_BY_EXPIRY_HEIGHT: Map<(u64, &CrossChainId), ()> =
+    Map::new("messages_by_expiry_height");
+/// Block height at which the execution of an outgoing message on the destination chain was recorded
+const EXECUTION_HEIGHTS: Map<&CrossChainId, u64> = Map::new("execution_heights");
+/// Executed outgoing messages ordered by the block height at which their execution was recorded, so the oldest ones can be pruned first
+const MESSAGES_BY_EXECUTION_HEIGHT: Map<(u64, &CrossChainId), ()> =
+    Map::new("messages_by_execution_height");
+/// Hashes of pruned outgoing messages, so routing them again stays idempotent and mismatching messages are still refused
+const PRUNED_MESSAGES: Map<&CrossChainId, Hash> = Map::new("pruned_messages");
+/// Allowlisted relayers and their submission counters
+const RELAYERS: Map<&Addr, Relayer> = Map::new("relayers");
+/// Number of times an incoming message was submitted for verification while it was already being verified or its verification was final,
+/// keyed by the start of the summary period. Counts of past periods are deleted when messages are pruned
+const DUPLICATE_SUBMISSIONS: Map<(u64, &CrossChainId), u64> = Map::new("duplicate_submissions");
+/// Incoming messages submitted for verification per source chain during the current summary period
+const SUBMISSION_STATS: Map<&ChainNameRaw, SubmissionStats> = Map::new("submission_stats");
+
+#[cw_serde]
+pub struct SubmissionStats {
+    /// Block height at which the summary period begins
+    pub period_start: u64,
+    /// Number of messages submitted for verification during the period
+    pub messages: u64,
+    /// Number of those messages that were duplicate submissions
+    pub duplicates: u64,
+}
 
 #[derive(thiserror::Error, Debug, IntoContractError)]
 pub enum Error {
@@ -23,6 +92,10 @@ pub enum Error {
     MessageMismatch(CrossChainId),
     #[error("message with ID {0} not found")]
     MessageNotFound(CrossChainId),
+    #[error("no message validator registered for source chain {0}")]
+    MessageValidatorNotFound(ChainName),
+    #[error("message with ID {0} has been recalled")]
+    MessageRecalled(CrossChainId),
 }
 
 pub fn load_config(storage: &dyn Storage) -> Result<Config, Error> {
@@ -40,30 +113,563 @@ pub fn load_outgoing_message(
     storage: &dyn Storage,
     cc_id: &CrossChainId,
 ) -> Result<Message, Error> {
+    if is_recalled(storage, cc_id)? {
+        return Err(Error::MessageRecalled(cc_id.clone()));
+    }
+
     OUTGOING_MESSAGES
         .may_load(storage, cc_id)
         .map_err(Error::from)?
         .ok_or_else(|| Error::MessageNotFound(cc_id.clone()))
 }
 
+/// Returns true if the message was not stored before
 pub fn save_outgoing_message(
     storage: &mut dyn Storage,
     cc_id: &CrossChainId,
     msg: &Message,
+) -> Result<bool, Error> {
+    let existing = match OUTGOING_MESSAGES
+        .may_load(storage, cc_id)
+        .map_err(Error::from)?
+    {
+        Some(existing) => Some(existing.hash()),
+        None => PRUNED_MESSAGES
+            .may_load(storage, cc_id)
+            .map_err(Error::from)?,
+    };
+
+    match existing {
+        Some(existing) if msg.hash() != existing => Err(Error::MessageMismatch(msg.cc_id.clone())),
+        Some(_) => Ok(false), // new message is identical, no need to store it
+        None => {
+            OUTGOING_MESSAGES
+                .save(storage, cc_id, msg)
+                .map_err(Error::from)?;
+            Ok(true)
+        }
+    }
+}
+
+pub fn is_pruned(storage: &dyn Storage, cc_id: &CrossChainId) -> Result<bool, Error> {
+    Ok(PRUNED_MESSAGES
+        .may_load(storage, cc_id)
+        .map_err(Error::from)?
+        .is_some())
+}
+
+pub fn is_recalled(storage: &dyn Storage, cc_id: &CrossChainId) -> Result<bool, Error> {
+    Ok(RECALLED_MESSAGES
+        .may_load(storage, cc_id)
+        .map_err(Error::from)?
+        .is_some())
+}
+
+pub fn save_recalled_message(storage: &mut dyn Storage, cc_id: &CrossChainId) -> Result<(), Error> {
+    RECALLED_MESSAGES
+        .save(storage, cc_id, &())
+        .map_err(Error::from)
+}
+
+/// The expiry of a message is set when it is first routed with one, later expiry heights are ignored
+pub fn save_expiry_height(
+    storage: &mut dyn Storage,
+    cc_id: &CrossChainId,
+    expires_at: u64,
 ) -> Result<(), Error> {
-    let existing = OUTGOING_MESSAGES
+    if EXPIRY_HEIGHTS
+        .may_load(storage, cc_id)
+        .map_err(Error::from)?
+        .is_none()
+    {
+        EXPIRY_HEIGHTS
+            .save(storage, cc_id, &expires_at)
+            .map_err(Error::from)?;
+        MESSAGES_BY_EXPIRY_HEIGHT
+            .save(storage, (expires_at, cc_id), &())
+            .map_err(Error::from)?;
+    }
+
+    Ok(())
+}
+
+pub fn is_expired(
+    storage: &dyn Storage,
+    cc_id: &CrossChainId,
+    block_height: u64,
+) -> Result<bool, Error> {
+    Ok(EXPIRY_HEIGHTS
         .may_load(storage, cc_id)
+        .map_err(Error::from)?
+        .is_some_and(|expires_at| block_height >= expires_at))
+}
+
+pub fn save_routing_height(
+    storage: &mut dyn Storage,
+    cc_id: &CrossChainId,
+    block_height: u64,
+) -> Result<(), Error> {
+    ROUTING_HEIGHTS
+        .save(storage, cc_id, &block_height)
         .map_err(Error::from)?;
+    MESSAGES_BY_ROUTING_HEIGHT
+        .save(storage, (block_height, cc_id), &())
+        .map_err(Error::from)
+}
 
-    match existing {
-        Some(existing) if msg.hash() != existing.hash() => {
-            Err(Error::MessageMismatch(msg.cc_id.clone()))
+/// Deletes up to `limit` routing heights of messages that were routed at or before `routed_until`, oldest first.
+/// The messages themselves are kept. Returns the number of deleted keys
+pub fn remove_routing_heights(
+    storage: &mut dyn Storage,
+    routed_until: u64,
+    limit: usize,
+) -> Result<u64, Error> {
+    let keys = MESSAGES_BY_ROUTING_HEIGHT
+        .prefix_range(
+            storage,
+            None,
+            Some(PrefixBound::inclusive(routed_until)),
+            Order::Ascending,
+        )
+        .take(limit)
+        .map(|entry| entry.map(|(key, _)| key))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    for (routed_at, cc_id) in keys.iter() {
+        ROUTING_HEIGHTS.remove(storage, cc_id);
+        MESSAGES_BY_ROUTING_HEIGHT.remove(storage, (*routed_at, cc_id));
+    }
+
+    Ok(u64::try_from(keys.len())
+        .expect("number of routing heights should fit into u64")
+        .saturating_mul(2))
+}
+
+/// Returns true if the execution of the message was not recorded before
+pub fn save_execution_height(
+    storage: &mut dyn Storage,
+    cc_id: &CrossChainId,
+    block_height: u64,
+) -> Result<bool, Error> {
+    if EXECUTION_HEIGHTS
+        .may_load(storage, cc_id)
+        .map_err(Error::from)?
+        .is_some()
+    {
+        return Ok(false);
+    }
+
+    EXECUTION_HEIGHTS
+        .save(storage, cc_id, &block_height)
+        .map_err(Error::from)?;
+    MESSAGES_BY_EXECUTION_HEIGHT
+        .save(storage, (block_height, cc_id), &())
+        .map_err(Error::from)?;
+
+    Ok(true)
+}
+
+/// Returns the last block height at which the execution of a message must have been recorded to be beyond the retention window,
+/// or None if nothing is
+pub fn retention_cutoff(
+    block_height: u64,
+    message_retention: Option<nonempty::Uint64>,
+) -> Option<u64> {
+    message_retention.and_then(|retention| block_height.checked_sub(retention.into()))
+}
+
+/// Returns up to `limit` outgoing messages that can be pruned at the given block height. Expired messages come first,
+/// ordered by expiry, followed by the messages whose execution was recorded at or before `executed_until`, ordered by
+/// execution height. Messages that neither expired nor were executed are never pruned
+pub fn load_prunable_messages(
+    storage: &dyn Storage,
+    block_height: u64,
+    executed_until: Option<u64>,
+    limit: usize,
+) -> Result<Vec<PrunableMessage>, Error> {
+    let mut prunable: Vec<PrunableMessage> = MESSAGES_BY_EXPIRY_HEIGHT
+        .prefix_range(
+            storage,
+            None,
+            Some(PrefixBound::inclusive(block_height)),
+            Order::Ascending,
+        )
+        .take(limit)
+        .map(|entry| -> Result<PrunableMessage, Error> {
+            let ((expires_at, cc_id), _) = entry?;
+
+            Ok(PrunableMessage {
+                routed_at: ROUTING_HEIGHTS.may_load(storage, &cc_id)?,
+                expires_at: Some(expires_at),
+                executed_at: EXECUTION_HEIGHTS.may_load(storage, &cc_id)?,
+                cc_id,
+            })
+        })
+        .collect::<Result<_, _>>()?;
+
+    if let Some(executed_until) = executed_until {
+        let remaining = limit.saturating_sub(prunable.len());
+        let expired: HashSet<_> = prunable.iter().map(|msg| msg.cc_id.clone()).collect();
+
+        let executed = MESSAGES_BY_EXECUTION_HEIGHT
+            .prefix_range(
+                storage,
+                None,
+                Some(PrefixBound::inclusive(executed_until)),
+                Order::Ascending,
+            )
+            .map(|entry| -> Result<Option<PrunableMessage>, Error> {
+                let ((executed_at, cc_id), _) = entry?;
+
+                if expired.contains(&cc_id) {
+                    return Ok(None);
+                }
+
+                Ok(Some(PrunableMessage {
+                    routed_at: ROUTING_HEIGHTS.may_load(storage, &cc_id)?,
+                    expires_at: EXPIRY_HEIGHTS.may_load(storage, &cc_id)?,
+                    executed_at: Some(executed_at),
+                    cc_id,
+                }))
+            })
+            .filter_map(Result::transpose)
+            .take(remaining)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        prunable.extend(executed);
+    }
+
+    Ok(prunable)
+}
+
+/// Replaces the message with the hash of its content and deletes everything recorded about its routing, expiry and
+/// execution. Returns the number of deleted keys, net of the added hash. Recalled messages stay marked as recalled,
+/// so they are still refused if they are routed again
+pub fn remove_outgoing_message(
+    storage: &mut dyn Storage,
+    msg: &PrunableMessage,
+) -> Result<u64, Error> {
+    let mut removed_keys = 0u64;
+
+    if let Some(stored) = OUTGOING_MESSAGES
+        .may_load(storage, &msg.cc_id)
+        .map_err(Error::from)?
+    {
+        OUTGOING_MESSAGES.remove(storage, &msg.cc_id);
+        PRUNED_MESSAGES
+            .save(storage, &msg.cc_id, &stored.hash())
+            .map_err(Error::from)?;
+    }
+
+    if let Some(routed_at) = msg.routed_at {
+        ROUTING_HEIGHTS.remove(storage, &msg.cc_id);
+        MESSAGES_BY_ROUTING_HEIGHT.remove(storage, (routed_at, &msg.cc_id));
+        removed_keys = removed_keys.saturating_add(2);
+    }
+
+    if let Some(expires_at) = msg.expires_at {
+        EXPIRY_HEIGHTS.remove(storage, &msg.cc_id);
+        MESSAGES_BY_EXPIRY_HEIGHT.remove(storage, (expires_at, &msg.cc_id));
+        removed_keys = removed_keys.saturating_add(2);
+    }
+
+    if let Some(executed_at) = msg.executed_at {
+        EXECUTION_HEIGHTS.remove(storage, &msg.cc_id);
+        MESSAGES_BY_EXECUTION_HEIGHT.remove(storage, (executed_at, &msg.cc_id));
+        removed_keys = removed_keys.saturating_add(2);
+    }
+
+    Ok(removed_keys)
+}
+
+/// Returns up to `limit` outgoing messages with a known routing height, ordered by ID and starting after `start_after`.
+/// Recalled messages are skipped. Routing heights are only kept for [ROUTING_HEIGHT_RETENTION] blocks, which bounds the
+/// number of messages that can be listed
+pub fn load_routed_messages(
+    storage: &dyn Storage,
+    start_after: Option<&CrossChainId>,
+    limit: usize,
+) -> Result<Vec<RoutedMessage>, Error> {
+    ROUTING_HEIGHTS
+        .range(
+            storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .map(|entry| -> Result<Option<RoutedMessage>, Error> {
+            let (cc_id, routed_at) = entry?;
+
+            if is_recalled(storage, &cc_id)? {
+                return Ok(None);
+            }
+
+            Ok(Some(RoutedMessage {
+                message: load_outgoing_message(storage, &cc_id)?,
+                routed_at,
+            }))
+        })
+        .filter_map(Result::transpose)
+        .take(limit)
+        .collect()
+}
+
+pub fn add_to_proof_batch(
+    storage: &mut dyn Storage,
+    window_start: u64,
+    msg: &Message,
+) -> Result<(), Error> {
+    let chain = &msg.destination_chain;
+    let latest_batch = PROOF_BATCHES
+        .prefix(window_start)
+        .range(
+            storage,
+            Some(Bound::inclusive((chain, 0))),
+            Some(Bound::inclusive((chain, u32::MAX))),
+            Order::Descending,
+        )
+        .next()
+        .transpose()?;
+
+    let (index, mut message_ids) = match latest_batch {
+        Some(((_, index), message_ids)) if message_ids.len() < MAX_PROOF_BATCH_SIZE => {
+            (index, message_ids)
+        }
+        Some(((_, index), _)) => (
+            index
+                .checked_add(1)
+                .expect("number of proof batches per window should fit into u32"),
+            vec![],
+        ),
+        None => (0, vec![]),
+    };
+
+    message_ids.push(msg.cc_id.clone());
+    PROOF_BATCHES
+        .save(storage, (window_start, (chain, index)), &message_ids)
+        .map_err(Error::from)
+}
+
+pub fn load_proof_batches(
+    storage: &dyn Storage,
+    start_height: u64,
+    limit: usize,
+) -> Result<Vec<ProofBatch>, Error> {
+    PROOF_BATCHES
+        .prefix_range(
+            storage,
+            Some(PrefixBound::inclusive(start_height)),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .map(|entry| -> Result<ProofBatch, Error> {
+            let ((window_start, (destination_chain, index)), message_ids) = entry?;
+
+            Ok(ProofBatch {
+                destination_chain,
+                window_start,
+                index,
+                message_ids: without_recalled(storage, message_ids)?,
+            })
+        })
+        .collect()
+}
+
+/// Deletes up to `limit` proof batches with a window that starts at or before `window_start_until`, oldest first,
+/// and returns the number of deleted batches
+pub fn remove_proof_batches(
+    storage: &mut dyn Storage,
+    window_start_until: u64,
+    limit: usize,
+) -> Result<u64, Error> {
+    let keys = PROOF_BATCHES
+        .prefix_range(
+            storage,
+            None,
+            Some(PrefixBound::inclusive(window_start_until)),
+            Order::Ascending,
+        )
+        .take(limit)
+        .map(|entry| entry.map(|(key, _)| key))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    for (window_start, (destination_chain, index)) in keys.iter() {
+        PROOF_BATCHES.remove(storage, (*window_start, (destination_chain, *index)));
+    }
+
+    Ok(u64::try_from(keys.len()).expect("number of proof batches should fit into u64"))
+}
+
+fn without_recalled(
+    storage: &dyn Storage,
+    message_ids: Vec<CrossChainId>,
+) -> Result<Vec<CrossChainId>, Error> {
+    let mut relayable = vec![];
+
+    for cc_id in message_ids {
+        if !is_recalled(storage, &cc_id)? {
+            relayable.push(cc_id);
         }
-        Some(_) => Ok(()), // new message is identical, no need to store it
-        None => Ok(OUTGOING_MESSAGES
-            .save(storage, cc_id, msg)
-            .map_err(Error::from)?),
     }
+
+    Ok(relayable)
+}
+
+pub fn may_load_message_validator(
+    storage: &dyn Storage,
+    source_chain: &ChainName,
+) -> Result<Option<Addr>, Error> {
+    MESSAGE_VALIDATORS
+        .may_load(storage, source_chain)
+        .map_err(Error::from)
+}
+
+pub fn save_message_validator(
+    storage: &mut dyn Storage,
+    source_chain: &ChainName,
+    validator: &Addr,
+) -> Result<(), Error> {
+    MESSAGE_VALIDATORS
+        .save(storage, source_chain, validator)
+        .map_err(Error::from)
+}
+
+pub fn remove_message_validator(
+    storage: &mut dyn Storage,
+    source_chain: &ChainName,
+) -> Result<(), Error> {
+    may_load_message_validator(storage, source_chain)?
+        .ok_or_else(|| Error::MessageValidatorNotFound(source_chain.clone()))?;
+
+    MESSAGE_VALIDATORS.remove(storage, source_chain);
+    Ok(())
+}
+
+pub fn may_load_relayer(storage: &dyn Storage, relayer: &Addr) -> Result<Option<Relayer>, Error> {
+    RELAYERS.may_load(storage, relayer).map_err(Error::from)
+}
+
+pub fn save_relayer(storage: &mut dyn Storage, relayer: &Relayer) -> Result<(), Error> {
+    RELAYERS
+        .save(storage, &relayer.address, relayer)
+        .map_err(Error::from)
+}
+
+pub fn remove_relayer(storage: &mut dyn Storage, relayer: &Addr) {
+    RELAYERS.remove(storage, relayer)
+}
+
+pub fn load_relayers(
+    storage: &dyn Storage,
+    start_after: Option<&Addr>,
+    limit: usize,
+) -> Result<Vec<Relayer>, Error> {
+    RELAYERS
+        .range(
+            storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .map(|entry| entry.map(|(_, relayer)| relayer).map_err(Error::from))
+        .collect()
+}
+
+pub fn summary_period_start(block_height: u64) -> u64 {
+    let offset = block_height
+        .checked_rem(SUBMISSION_SUMMARY_PERIOD)
+        .expect("summary period must be non-zero");
+
+    block_height.saturating_sub(offset)
+}
+
+pub fn record_duplicate_submissions(
+    storage: &mut dyn Storage,
+    period_start: u64,
+    cc_id: &CrossChainId,
+    count: u64,
+) -> Result<(), Error> {
+    DUPLICATE_SUBMISSIONS
+        .update(
+            storage,
+            (period_start, cc_id),
+            |previous| -> Result<_, StdError> {
+                Ok(previous.unwrap_or_default().saturating_add(count))
+            },
+        )
+        .map_err(Error::from)?;
+
+    Ok(())
+}
+
+/// Returns up to `limit` incoming messages that were submitted for verification more than once during the summary
+/// period, together with the number of duplicate submissions, ordered by ID and starting after `start_after`
+pub fn load_duplicate_submissions(
+    storage: &dyn Storage,
+    period_start: u64,
+    start_after: Option<&CrossChainId>,
+    limit: usize,
+) -> Result<Vec<DuplicateSubmissions>, Error> {
+    DUPLICATE_SUBMISSIONS
+        .prefix(period_start)
+        .range(
+            storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .map(|entry| {
+            entry
+                .map(|(cc_id, count)| DuplicateSubmissions { cc_id, count })
+                .map_err(Error::from)
+        })
+        .collect()
+}
+
+/// Deletes up to `limit` duplicate submission counts of summary periods that started before `period_start`
+pub fn remove_duplicate_submissions(
+    storage: &mut dyn Storage,
+    period_start: u64,
+    limit: usize,
+) -> Result<u64, Error> {
+    let keys = DUPLICATE_SUBMISSIONS
+        .prefix_range(
+            storage,
+            None,
+            Some(PrefixBound::exclusive(period_start)),
+            Order::Ascending,
+        )
+        .take(limit)
+        .map(|entry| entry.map(|(key, _)| key))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    for (period_start, cc_id) in keys.iter() {
+        DUPLICATE_SUBMISSIONS.remove(storage, (*period_start, cc_id));
+    }
+
+    Ok(u64::try_from(keys.len()).expect("number of duplicate submissions should fit into u64"))
+}
+
+pub fn may_load_submission_stats(
+    storage: &dyn Storage,
+    source_chain: &ChainNameRaw,
+) -> Result<Option<SubmissionStats>, Error> {
+    SUBMISSION_STATS
+        .may_load(storage, source_chain)
+        .map_err(Error::from)
+}
+
+pub fn save_submission_stats(
+    storage: &mut dyn Storage,
+    source_chain: &ChainNameRaw,
+    stats: &SubmissionStats,
+) -> Result<(), Error> {
+    SUBMISSION_STATS
+        .save(storage, source_chain, stats)
+        .map_err(Error::from)
 }
 
 #[cfg(test)]
@@ -3,7 +3,7 @@ use std::fmt::Debug;
 use std::iter;
 
 use axelar_wasm_std::error::ContractError;
-use axelar_wasm_std::{err_contains, VerificationStatus};
+use axelar_wasm_std::{err_contains, nonempty, VerificationStatus};
 use cosmwasm_std::testing::{
     message_info, mock_dependencies, mock_env, MockApi, MockQuerier, MockStorage,
 };
@@ -11,17 +11,25 @@ use cosmwasm_std::testing::{
 use cosmwasm_std::{
     from_json, to_json_binary, ContractResult, OwnedDeps, QuerierResult, WasmQuery,
 };
+use cosmwasm_std::{Addr, CosmosMsg, Deps, HexBinary, WasmMsg};
 use gateway::contract::*;
-use gateway::msg::InstantiateMsg;
-use gateway_api::msg::{ExecuteMsg, QueryMsg};
+use gateway::msg::{InstantiateMsg, PayloadHashAlgorithm};
+use gateway_api::msg::{
+    DuplicateSubmissions, ExecuteMsg, InvalidMessage, MessageValidatorQueryMsg, MessageWithExpiry,
+    MessageWithPayload, ProofBatch, PrunableMessage, QueryMsg, Relayer, RelayerAllowlist,
+    RoutedMessage,
+};
 use itertools::Itertools;
 use rand::{thread_rng, Rng};
 use router_api::{CrossChainId, Message};
 use serde::Serialize;
-use voting_verifier::msg::MessageStatus;
+use voting_verifier::msg::{MessageStatus, ObservedBlock};
 
 const ROUTER: &str = "router";
 const VERIFIER: &str = "verifier";
+const GOVERNANCE: &str = "governance";
+const VALIDATOR: &str = "validator";
+const RELAYER: &str = "relayer";
 
 #[test]
 fn instantiate_works() {
@@ -35,8 +43,12 @@ fn instantiate_works() {
         mock_env(),
         message_info(&api.addr_make("sender"), &[]),
         InstantiateMsg {
+            governance_address: api.addr_make(GOVERNANCE).into_string(),
             verifier_address: verifier_address.into_string(),
             router_address: router_address.into_string(),
+            payload_hash_algorithm: None,
+            batching_window: None,
+            message_retention: None,
         },
     );
 
@@ -75,6 +87,54 @@ fn successful_verify() {
     goldie::assert_json!(responses);
 }
 
+#[test]
+fn verify_with_observed_block_forwards_it_to_the_verifier() {
+    let mut deps = instantiate_contract();
+    let api = deps.api;
+
+    let msgs = generate_msgs("observed", 3);
+    let status_by_msg = msgs
+        .iter()
+        .map(|msg| (msg.clone(), VerificationStatus::Unknown))
+        .collect();
+    update_query_handler(
+        &mut deps.querier,
+        correctly_working_verifier_handler(status_by_msg),
+    );
+
+    let observed_at = ObservedBlock {
+        height: 100,
+        hash: Some("blockHash".try_into().unwrap()),
+    };
+
+    let response = execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&api.addr_make("sender"), &[]),
+        ExecuteMsg::VerifyMessagesObservedAt {
+            messages: msgs.clone(),
+            observed_at: observed_at.clone(),
+        },
+    )
+    .unwrap();
+
+    assert_eq!(response.messages.len(), 1);
+    assert_eq!(
+        response.messages[0].msg,
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: api.addr_make(VERIFIER).to_string(),
+            msg: to_json_binary(
+                &voting_verifier::msg::ExecuteMsg::VerifyMessagesObservedAt {
+                    messages: msgs,
+                    observed_at,
+                }
+            )
+            .unwrap(),
+            funds: vec![],
+        })
+    );
+}
+
 #[test]
 fn successful_route_incoming() {
     let (test_cases, handler) = test_cases_for_correct_verifier();
@@ -159,6 +219,856 @@ fn successful_route_outgoing() {
     goldie::assert_json!(responses);
 }
 
+#[test]
+fn route_outgoing_groups_messages_into_proof_batches() {
+    let mut deps = instantiate_contract_with_config(None, Some(10u64.try_into().unwrap()), None);
+    let router = deps.api.addr_make(ROUTER);
+
+    let msgs = generate_msgs("batched", 4);
+    let mut env = mock_env();
+
+    for (height, msgs) in [(20, &msgs[..2]), (29, &msgs[2..3]), (30, &msgs[3..])] {
+        env.block.height = height;
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&router, &[]),
+            ExecuteMsg::RouteMessages(msgs.to_vec()),
+        )
+        .unwrap();
+    }
+
+    // re-routing a message does not move it into a later batch
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&router, &[]),
+        ExecuteMsg::RouteMessages(msgs[..1].to_vec()),
+    )
+    .unwrap();
+
+    let batch = |window_start: u64, msgs: &[Message]| ProofBatch {
+        destination_chain: msgs[0].destination_chain.clone(),
+        window_start,
+        index: 0,
+        message_ids: msgs.iter().map(|msg| msg.cc_id.clone()).collect(),
+    };
+
+    let res = query(
+        deps.as_ref(),
+        env.clone(),
+        QueryMsg::ProofBatches {
+            start_height: None,
+            limit: None,
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        from_json::<Vec<ProofBatch>>(res).unwrap(),
+        vec![batch(20, &msgs[..3]), batch(30, &msgs[3..])]
+    );
+
+    let res = query(
+        deps.as_ref(),
+        env,
+        QueryMsg::ProofBatches {
+            start_height: Some(21),
+            limit: Some(1),
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        from_json::<Vec<ProofBatch>>(res).unwrap(),
+        vec![batch(30, &msgs[3..])]
+    );
+}
+
+#[test]
+fn full_proof_batches_are_continued_in_the_next_batch() {
+    let mut deps = instantiate_contract_with_config(None, Some(10u64.try_into().unwrap()), None);
+    let router = deps.api.addr_make(ROUTER);
+
+    // batches are capped at 100 messages
+    let msgs = generate_msgs("batched", 101);
+    let mut env = mock_env();
+    env.block.height = 20;
+
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&router, &[]),
+        ExecuteMsg::RouteMessages(msgs.clone()),
+    )
+    .unwrap();
+
+    let res = query(
+        deps.as_ref(),
+        env,
+        QueryMsg::ProofBatches {
+            start_height: None,
+            limit: None,
+        },
+    )
+    .unwrap();
+    let batches = from_json::<Vec<ProofBatch>>(res).unwrap();
+
+    assert_eq!(
+        batches
+            .iter()
+            .map(|batch| (batch.window_start, batch.index, batch.message_ids.len()))
+            .collect::<Vec<_>>(),
+        vec![(20, 0, 100), (20, 1, 1)]
+    );
+    assert_eq!(batches[1].message_ids, vec![msgs[100].cc_id.clone()]);
+}
+
+#[test]
+fn governance_can_update_batching_window() {
+    let mut deps = instantiate_contract();
+    let router = deps.api.addr_make(ROUTER);
+    let mut env = mock_env();
+    env.block.height = 25;
+
+    let update = |batching_window: Option<u64>| ExecuteMsg::UpdateBatchingWindow {
+        batching_window: batching_window.map(|window| window.try_into().unwrap()),
+    };
+
+    assert!(execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&router, &[]),
+        update(Some(10)),
+    )
+    .is_err());
+
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&deps.api.addr_make(GOVERNANCE), &[]),
+        update(Some(10)),
+    )
+    .unwrap();
+    assert_eq!(res.events[0].ty, "batching_window_updated");
+
+    let msgs = generate_msgs("batched", 2);
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&router, &[]),
+        ExecuteMsg::RouteMessages(msgs[..1].to_vec()),
+    )
+    .unwrap();
+
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&deps.api.addr_make(GOVERNANCE), &[]),
+        update(None),
+    )
+    .unwrap();
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&router, &[]),
+        ExecuteMsg::RouteMessages(msgs[1..].to_vec()),
+    )
+    .unwrap();
+
+    let res = query(
+        deps.as_ref(),
+        env,
+        QueryMsg::ProofBatches {
+            start_height: None,
+            limit: None,
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        from_json::<Vec<ProofBatch>>(res).unwrap(),
+        vec![ProofBatch {
+            destination_chain: msgs[0].destination_chain.clone(),
+            window_start: 20,
+            index: 0,
+            message_ids: vec![msgs[0].cc_id.clone()],
+        }]
+    );
+}
+
+#[test]
+fn route_outgoing_without_batching_window_creates_no_proof_batches() {
+    let mut deps = instantiate_contract();
+    let router = deps.api.addr_make(ROUTER);
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&router, &[]),
+        ExecuteMsg::RouteMessages(generate_msgs("unbatched", 2)),
+    )
+    .unwrap();
+
+    let res = query(
+        deps.as_ref(),
+        mock_env(),
+        QueryMsg::ProofBatches {
+            start_height: None,
+            limit: None,
+        },
+    )
+    .unwrap();
+    assert!(from_json::<Vec<ProofBatch>>(res).unwrap().is_empty());
+}
+
+#[test]
+fn routed_messages_are_listed_with_the_height_they_were_first_routed_at() {
+    let mut deps = instantiate_contract();
+    let router = deps.api.addr_make(ROUTER);
+
+    // message IDs only differ in their last digit, so the messages are already ordered by ID
+    let msgs = generate_msgs("routed", 4);
+
+    let mut env = mock_env();
+    env.block.height = 100;
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&router, &[]),
+        ExecuteMsg::RouteMessages(msgs[..2].to_vec()),
+    )
+    .unwrap();
+
+    // re-routed messages keep the height at which they were first routed
+    env.block.height = 200;
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&router, &[]),
+        ExecuteMsg::RouteMessages(msgs.clone()),
+    )
+    .unwrap();
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&router, &[]),
+        ExecuteMsg::RecallMessages(vec![msgs[2].cc_id.clone()]),
+    )
+    .unwrap();
+
+    let routed_messages = |start_after: Option<CrossChainId>, limit: Option<u32>| {
+        from_json::<Vec<RoutedMessage>>(
+            query(
+                deps.as_ref(),
+                env.clone(),
+                QueryMsg::RoutedMessages { start_after, limit },
+            )
+            .unwrap(),
+        )
+        .unwrap()
+    };
+    let routed_message = |msg: &Message, routed_at: u64| RoutedMessage {
+        message: msg.clone(),
+        routed_at,
+    };
+
+    assert_eq!(
+        routed_messages(None, None),
+        vec![
+            routed_message(&msgs[0], 100),
+            routed_message(&msgs[1], 100),
+            routed_message(&msgs[3], 200),
+        ]
+    );
+    assert_eq!(
+        routed_messages(None, Some(1)),
+        vec![routed_message(&msgs[0], 100)]
+    );
+    assert_eq!(
+        routed_messages(Some(msgs[1].cc_id.clone()), Some(1)),
+        vec![routed_message(&msgs[3], 200)]
+    );
+}
+
+#[test]
+fn routing_heights_are_pruned_after_the_retention_window() {
+    let mut deps = instantiate_contract();
+    let router = deps.api.addr_make(ROUTER);
+    let relayer = deps.api.addr_make(RELAYER);
+
+    let msgs = generate_msgs("routed", 3);
+
+    let mut env = mock_env();
+    env.block.height = 100;
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&router, &[]),
+        ExecuteMsg::RouteMessages(msgs[..2].to_vec()),
+    )
+    .unwrap();
+    env.block.height = 200;
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&router, &[]),
+        ExecuteMsg::RouteMessages(msgs[2..].to_vec()),
+    )
+    .unwrap();
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&deps.api.addr_make(GOVERNANCE), &[]),
+        ExecuteMsg::AllowRelayers(vec![relayer.to_string()]),
+    )
+    .unwrap();
+
+    // routing heights are kept for 1,000,000 blocks, so only the messages routed at height 100 are affected
+    env.block.height = 1_000_100;
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&relayer, &[]),
+        ExecuteMsg::PruneMessages { limit: 10 },
+    )
+    .unwrap();
+    let deleted_keys = res
+        .events
+        .iter()
+        .find(|event| event.ty == "messages_pruned")
+        .and_then(|event| {
+            event
+                .attributes
+                .iter()
+                .find(|attribute| attribute.key == "deleted_keys")
+        })
+        .map(|attribute| attribute.value.clone());
+    assert_eq!(deleted_keys, Some("4".to_string()));
+
+    assert_eq!(
+        from_json::<Vec<RoutedMessage>>(
+            query(
+                deps.as_ref(),
+                env.clone(),
+                QueryMsg::RoutedMessages {
+                    start_after: None,
+                    limit: None
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap(),
+        vec![RoutedMessage {
+            message: msgs[2].clone(),
+            routed_at: 200,
+        }]
+    );
+    // the messages themselves are kept
+    assert_eq!(
+        query(
+            deps.as_ref(),
+            env,
+            QueryMsg::OutgoingMessages(msgs.iter().map(|msg| msg.cc_id.clone()).collect())
+        )
+        .unwrap(),
+        to_json_binary(&msgs).unwrap()
+    );
+}
+
+#[test]
+fn recalled_outgoing_messages_are_refused() {
+    let mut deps = instantiate_contract_with_config(None, Some(10u64.try_into().unwrap()), None);
+    let router = deps.api.addr_make(ROUTER);
+
+    let msgs = generate_msgs("recalled", 3);
+    let cc_ids: Vec<_> = msgs.iter().map(|msg| msg.cc_id.clone()).collect();
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&router, &[]),
+        ExecuteMsg::RouteMessages(msgs[..2].to_vec()),
+    )
+    .unwrap();
+
+    // only the router can recall messages
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&deps.api.addr_make(GOVERNANCE), &[]),
+        ExecuteMsg::RecallMessages(cc_ids[..1].to_vec()),
+    )
+    .unwrap_err();
+    assert!(err_contains!(
+        err.report,
+        axelar_wasm_std::permission_control::Error,
+        axelar_wasm_std::permission_control::Error::PermissionDenied { .. }
+    ));
+
+    // messages can be recalled before they reach the gateway, e.g. if they are still in flight
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&router, &[]),
+        ExecuteMsg::RecallMessages(vec![cc_ids[0].clone(), cc_ids[2].clone()]),
+    )
+    .unwrap();
+    assert_eq!(res.events.len(), 2);
+
+    // recalled messages are left out, so proofs can still be constructed for the remaining messages
+    assert_eq!(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::OutgoingMessages(cc_ids[..2].to_vec())
+        )
+        .unwrap(),
+        to_json_binary(&msgs[1..2]).unwrap()
+    );
+
+    // recalled messages are ignored if they are routed again
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&router, &[]),
+        ExecuteMsg::RouteMessages(msgs.clone()),
+    )
+    .unwrap();
+    assert_eq!(res.events.len(), 1);
+    assert_eq!(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::OutgoingMessages(cc_ids[2..].to_vec())
+        )
+        .unwrap(),
+        to_json_binary(&Vec::<Message>::new()).unwrap()
+    );
+
+    let res = query(
+        deps.as_ref(),
+        mock_env(),
+        QueryMsg::ProofBatches {
+            start_height: None,
+            limit: None,
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        from_json::<Vec<ProofBatch>>(res).unwrap()[0].message_ids,
+        cc_ids[1..2].to_vec()
+    );
+}
+
+#[test]
+fn expired_outgoing_messages_are_left_out() {
+    let mut deps = instantiate_contract();
+    let router = deps.api.addr_make(ROUTER);
+
+    let msgs = generate_msgs("expiring", 2);
+    let cc_ids: Vec<_> = msgs.iter().map(|msg| msg.cc_id.clone()).collect();
+    let expires_at = mock_env().block.height + 10;
+
+    // only the router can route messages with an expiry
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&deps.api.addr_make(GOVERNANCE), &[]),
+        ExecuteMsg::RouteMessagesWithExpiry(vec![MessageWithExpiry {
+            message: msgs[0].clone(),
+            expires_at,
+        }]),
+    )
+    .unwrap_err();
+    assert!(err_contains!(
+        err.report,
+        axelar_wasm_std::permission_control::Error,
+        axelar_wasm_std::permission_control::Error::PermissionDenied { .. }
+    ));
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&router, &[]),
+        ExecuteMsg::RouteMessagesWithExpiry(vec![MessageWithExpiry {
+            message: msgs[0].clone(),
+            expires_at,
+        }]),
+    )
+    .unwrap();
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&router, &[]),
+        ExecuteMsg::RouteMessages(msgs[1..].to_vec()),
+    )
+    .unwrap();
+
+    let mut env = mock_env();
+    env.block.height = expires_at - 1;
+    assert_eq!(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::OutgoingMessages(cc_ids.clone())
+        )
+        .unwrap(),
+        to_json_binary(&msgs).unwrap()
+    );
+
+    // a later expiry of a re-routed message doesn't extend the first one
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&router, &[]),
+        ExecuteMsg::RouteMessagesWithExpiry(vec![MessageWithExpiry {
+            message: msgs[0].clone(),
+            expires_at: expires_at + 100,
+        }]),
+    )
+    .unwrap();
+
+    // expired messages don't fail the whole batch
+    env.block.height = expires_at;
+    assert_eq!(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::OutgoingMessages(cc_ids[..1].to_vec())
+        )
+        .unwrap(),
+        to_json_binary(&Vec::<Message>::new()).unwrap()
+    );
+    assert_eq!(
+        query(deps.as_ref(), env, QueryMsg::OutgoingMessages(cc_ids)).unwrap(),
+        to_json_binary(&msgs[1..]).unwrap()
+    );
+}
+
+#[test]
+fn expired_messages_and_executed_messages_beyond_the_retention_window_are_pruned() {
+    let mut deps = instantiate_contract_with_config(
+        None,
+        Some(10u64.try_into().unwrap()),
+        Some(100u64.try_into().unwrap()),
+    );
+    let router = deps.api.addr_make(ROUTER);
+    let relayer = deps.api.addr_make(RELAYER);
+    let anyone = deps.api.addr_make("anyone");
+
+    // message IDs only differ in their last digit, so the messages are already ordered by ID
+    let msgs = generate_msgs("pruned", 4);
+    let cc_ids: Vec<_> = msgs.iter().map(|msg| msg.cc_id.clone()).collect();
+
+    let mut env = mock_env();
+    env.block.height = 100;
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&router, &[]),
+        ExecuteMsg::RouteMessages(msgs[..2].to_vec()),
+    )
+    .unwrap();
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&router, &[]),
+        ExecuteMsg::RouteMessagesWithExpiry(vec![MessageWithExpiry {
+            message: msgs[2].clone(),
+            expires_at: 150,
+        }]),
+    )
+    .unwrap();
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&deps.api.addr_make(GOVERNANCE), &[]),
+        ExecuteMsg::AllowRelayers(vec![relayer.to_string()]),
+    )
+    .unwrap();
+
+    // only allowlisted relayers can record executions
+    assert!(execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&anyone, &[]),
+        ExecuteMsg::RecordExecutions(cc_ids[..2].to_vec()),
+    )
+    .is_err_and(|err| err_contains!(err.report, Error, Error::RelayerNotAllowlisted(_))));
+
+    env.block.height = 120;
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&relayer, &[]),
+        ExecuteMsg::RecordExecutions(vec![
+            cc_ids[0].clone(),
+            cc_ids[1].clone(),
+            CrossChainId::new("unknown", "message").unwrap(),
+        ]),
+    )
+    .unwrap();
+    assert_eq!(res.events.len(), 2);
+    assert!(res
+        .events
+        .iter()
+        .all(|event| event.ty == "message_executed"));
+    assert_eq!(
+        res.messages
+            .into_iter()
+            .map(|msg| msg.msg)
+            .collect::<Vec<_>>(),
+        vec![CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: router.to_string(),
+            msg: cosmwasm_std::to_json_binary(&router_api::msg::ExecuteMsg::RecordExecutions(
+                cc_ids[..2].to_vec()
+            ))
+            .unwrap(),
+            funds: vec![],
+        })]
+    );
+
+    // executions are only recorded once
+    env.block.height = 130;
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&relayer, &[]),
+        ExecuteMsg::RecordExecutions(cc_ids[..2].to_vec()),
+    )
+    .unwrap();
+    assert!(res.events.is_empty());
+    assert!(res.messages.is_empty());
+
+    env.block.height = 150;
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&router, &[]),
+        ExecuteMsg::RouteMessages(msgs[3..].to_vec()),
+    )
+    .unwrap();
+
+    let prunable_messages = |deps: Deps, height: u64| {
+        let mut env = mock_env();
+        env.block.height = height;
+
+        from_json::<Vec<PrunableMessage>>(
+            query(deps, env, QueryMsg::PrunableMessages { limit: None }).unwrap(),
+        )
+        .unwrap()
+    };
+    let prunable_message =
+        |i: usize, expires_at: Option<u64>, executed_at: Option<u64>| PrunableMessage {
+            cc_id: cc_ids[i].clone(),
+            routed_at: Some(100),
+            expires_at,
+            executed_at,
+        };
+
+    assert_eq!(prunable_messages(deps.as_ref(), 149), vec![]);
+    assert_eq!(
+        prunable_messages(deps.as_ref(), 150),
+        vec![prunable_message(2, Some(150), None)]
+    );
+    // the message that was never executed is never pruned
+    assert_eq!(
+        prunable_messages(deps.as_ref(), 1_000_000),
+        vec![
+            prunable_message(2, Some(150), None),
+            prunable_message(0, None, Some(120)),
+            prunable_message(1, None, Some(120)),
+        ]
+    );
+
+    // only allowlisted relayers can prune messages
+    env.block.height = 220;
+    assert!(execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&anyone, &[]),
+        ExecuteMsg::PruneMessages { limit: 2 },
+    )
+    .is_err_and(|err| err_contains!(err.report, Error, Error::RelayerNotAllowlisted(_))));
+
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&relayer, &[]),
+        ExecuteMsg::PruneMessages { limit: 2 },
+    )
+    .unwrap();
+    let event = res
+        .events
+        .iter()
+        .find(|event| event.ty == "messages_pruned")
+        .unwrap();
+    let attribute = |key: &str| {
+        event
+            .attributes
+            .iter()
+            .find(|attribute| attribute.key == key)
+            .map(|attribute| attribute.value.clone())
+            .unwrap()
+    };
+    assert_eq!(attribute("messages"), "2");
+    assert_eq!(attribute("proof_batches"), "1");
+    // the routing heights of both messages, the expiry of the expired message, the execution of the executed message,
+    // and the batch. The messages themselves are replaced by their hashes
+    assert_eq!(attribute("deleted_keys"), "9");
+
+    assert!(query(
+        deps.as_ref(),
+        env.clone(),
+        QueryMsg::OutgoingMessages(vec![cc_ids[0].clone()])
+    )
+    .is_err());
+    assert_eq!(
+        prunable_messages(deps.as_ref(), 220),
+        vec![prunable_message(1, None, Some(120))]
+    );
+
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&relayer, &[]),
+        ExecuteMsg::PruneMessages { limit: 10 },
+    )
+    .unwrap();
+    assert_eq!(prunable_messages(deps.as_ref(), 1_000_000), vec![]);
+
+    // routing pruned messages again has no effect, not even on their expiry
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&router, &[]),
+        ExecuteMsg::RouteMessages(msgs[..2].to_vec()),
+    )
+    .unwrap();
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&router, &[]),
+        ExecuteMsg::RouteMessagesWithExpiry(vec![MessageWithExpiry {
+            message: msgs[2].clone(),
+            expires_at: 300,
+        }]),
+    )
+    .unwrap();
+    assert!(query(
+        deps.as_ref(),
+        env.clone(),
+        QueryMsg::OutgoingMessages(cc_ids[..3].to_vec())
+    )
+    .is_err());
+    assert_eq!(prunable_messages(deps.as_ref(), 1_000_000), vec![]);
+
+    // but pruned messages that mismatch the original are still refused
+    let mut mismatched = msgs[0].clone();
+    mismatched.payload_hash = [0; 32];
+    assert!(execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&router, &[]),
+        ExecuteMsg::RouteMessages(vec![mismatched]),
+    )
+    .is_err());
+
+    let res = query(
+        deps.as_ref(),
+        env.clone(),
+        QueryMsg::ProofBatches {
+            start_height: None,
+            limit: None,
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        from_json::<Vec<ProofBatch>>(res).unwrap(),
+        vec![ProofBatch {
+            destination_chain: msgs[3].destination_chain.clone(),
+            window_start: 150,
+            index: 0,
+            message_ids: cc_ids[3..].to_vec(),
+        }]
+    );
+    assert_eq!(
+        query(
+            deps.as_ref(),
+            env,
+            QueryMsg::OutgoingMessages(cc_ids[3..].to_vec())
+        )
+        .unwrap(),
+        to_json_binary(&msgs[3..]).unwrap()
+    );
+}
+
+#[test]
+fn without_message_retention_only_expired_messages_are_pruned() {
+    let mut deps = instantiate_contract();
+    let router = deps.api.addr_make(ROUTER);
+    let relayer = deps.api.addr_make(RELAYER);
+
+    let msgs = generate_msgs("expiring", 2);
+    let expires_at = mock_env().block.height + 10;
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&router, &[]),
+        ExecuteMsg::RouteMessagesWithExpiry(vec![MessageWithExpiry {
+            message: msgs[0].clone(),
+            expires_at,
+        }]),
+    )
+    .unwrap();
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&router, &[]),
+        ExecuteMsg::RouteMessages(msgs[1..].to_vec()),
+    )
+    .unwrap();
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&deps.api.addr_make(GOVERNANCE), &[]),
+        ExecuteMsg::AllowRelayers(vec![relayer.to_string()]),
+    )
+    .unwrap();
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&relayer, &[]),
+        ExecuteMsg::RecordExecutions(vec![msgs[1].cc_id.clone()]),
+    )
+    .unwrap();
+
+    let mut env = mock_env();
+    env.block.height = expires_at + 1_000_000;
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&relayer, &[]),
+        ExecuteMsg::PruneMessages { limit: 10 },
+    )
+    .unwrap();
+
+    assert!(query(
+        deps.as_ref(),
+        env.clone(),
+        QueryMsg::OutgoingMessages(vec![msgs[0].cc_id.clone()])
+    )
+    .is_err());
+    assert_eq!(
+        query(
+            deps.as_ref(),
+            env,
+            QueryMsg::OutgoingMessages(msgs[1..].iter().map(|msg| msg.cc_id.clone()).collect())
+        )
+        .unwrap(),
+        to_json_binary(&msgs[1..]).unwrap()
+    );
+}
+
 #[test]
 fn verify_with_faulty_verifier_fails() {
     // if the mock querier is not overwritten, it will return an error
@@ -280,6 +1190,546 @@ fn reject_reroute_outgoing_message_with_different_contents() {
     )));
 }
 
+#[test]
+fn route_with_payload_succeeds_for_matching_payloads() {
+    for algorithm in [
+        PayloadHashAlgorithm::Keccak256,
+        PayloadHashAlgorithm::Sha256,
+    ] {
+        let msgs = generate_msgs_with_payload(algorithm, 10);
+        let handler = correctly_working_verifier_handler(map_status_by_msg(HashMap::from([(
+            VerificationStatus::SucceededOnSourceChain,
+            msgs.iter().map(|msg| msg.message.clone()).collect(),
+        )])));
+
+        let mut deps = instantiate_contract_with_payload_hash_algorithm(Some(algorithm));
+        let api = deps.api;
+        update_query_handler(&mut deps.querier, handler);
+
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make("sender"), &[]),
+            ExecuteMsg::RouteMessagesWithPayload(msgs.clone()),
+        )
+        .unwrap();
+
+        assert_eq!(response.messages.len(), 1);
+        let payload_events = response
+            .events
+            .iter()
+            .filter(|event| event.ty == "payload_verified")
+            .collect::<Vec<_>>();
+        assert_eq!(payload_events.len(), msgs.len());
+        for (event, msg) in payload_events.into_iter().zip(msgs) {
+            assert!(event
+                .attributes
+                .iter()
+                .any(|attr| attr.key == "payload" && attr.value == msg.payload.to_string()));
+        }
+    }
+}
+
+#[test]
+fn route_with_payload_rejects_mismatching_payload() {
+    let mut msgs = generate_msgs_with_payload(PayloadHashAlgorithm::Keccak256, 10);
+    msgs[5].payload = HexBinary::from(b"tampered payload".to_vec());
+    let mismatched_id = msgs[5].message.cc_id.clone();
+
+    // the verifier is not mocked, so the payloads must be rejected before it is queried
+    let mut deps =
+        instantiate_contract_with_payload_hash_algorithm(Some(PayloadHashAlgorithm::Keccak256));
+    let api = deps.api;
+
+    let response = execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&api.addr_make("sender"), &[]),
+        ExecuteMsg::RouteMessagesWithPayload(msgs),
+    );
+    assert!(response.is_err_and(|err| err_contains!(
+        err.report,
+        Error,
+        Error::PayloadHashMismatch { cc_id, .. } if *cc_id == mismatched_id
+    )));
+}
+
+#[test]
+fn route_with_payload_rejects_payload_hashed_with_other_algorithm() {
+    let msgs = generate_msgs_with_payload(PayloadHashAlgorithm::Sha256, 10);
+
+    let mut deps =
+        instantiate_contract_with_payload_hash_algorithm(Some(PayloadHashAlgorithm::Keccak256));
+    let api = deps.api;
+
+    let response = execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&api.addr_make("sender"), &[]),
+        ExecuteMsg::RouteMessagesWithPayload(msgs),
+    );
+    assert!(response.is_err_and(|err| err_contains!(
+        err.report,
+        Error,
+        Error::PayloadHashMismatch { .. }
+    )));
+}
+
+#[test]
+fn route_with_payload_fails_when_payload_verification_is_disabled() {
+    let msgs = generate_msgs_with_payload(PayloadHashAlgorithm::Keccak256, 10);
+
+    let mut deps = instantiate_contract();
+    let api = deps.api;
+
+    let response = execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&api.addr_make("sender"), &[]),
+        ExecuteMsg::RouteMessagesWithPayload(msgs),
+    );
+    assert!(response.is_err_and(|err| err_contains!(
+        err.report,
+        Error,
+        Error::PayloadVerificationDisabled
+    )));
+}
+
+#[test]
+fn route_with_ttl_forwards_verified_messages_with_their_ttl() {
+    let msgs = generate_msgs("ttl", 4);
+    let handler = correctly_working_verifier_handler(map_status_by_msg(HashMap::from([
+        (
+            VerificationStatus::SucceededOnSourceChain,
+            msgs[..3].to_vec(),
+        ),
+        (VerificationStatus::Unknown, msgs[3..].to_vec()),
+    ])));
+
+    let mut deps = instantiate_contract();
+    let api = deps.api;
+    update_query_handler(&mut deps.querier, handler);
+
+    let ttl: nonempty::Uint64 = 100u64.try_into().unwrap();
+    let response = execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&api.addr_make("sender"), &[]),
+        ExecuteMsg::RouteMessagesWithTtl {
+            messages: msgs.clone(),
+            ttl,
+        },
+    )
+    .unwrap();
+
+    assert_eq!(response.messages.len(), 1);
+    assert_eq!(
+        response.messages[0].msg,
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: api.addr_make(ROUTER).to_string(),
+            msg: to_json_binary(&router_api::msg::ExecuteMsg::RouteMessagesWithTtl {
+                messages: msgs[..3].to_vec(),
+                ttl,
+            })
+            .unwrap(),
+            funds: vec![],
+        })
+    );
+}
+
+#[test]
+fn message_validator_rejects_invalid_incoming_messages() {
+    let msgs = generate_msgs("validated", 3);
+    let invalid_cc_id = msgs[1].cc_id.clone();
+
+    let mut deps = instantiate_contract();
+    let api = deps.api;
+
+    let register_validator = ExecuteMsg::RegisterMessageValidator {
+        source_chain: "mock-chain".parse().unwrap(),
+        validator: api.addr_make(VALIDATOR).to_string(),
+    };
+    assert!(execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&api.addr_make("sender"), &[]),
+        register_validator.clone(),
+    )
+    .is_err());
+    let response = execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&api.addr_make(GOVERNANCE), &[]),
+        register_validator,
+    )
+    .unwrap();
+    assert_eq!(response.events[0].ty, "message_validator_registered");
+
+    let validator = api.addr_make(VALIDATOR).to_string();
+    let status_by_msg = msgs
+        .iter()
+        .map(|msg| (msg.clone(), VerificationStatus::SucceededOnSourceChain))
+        .collect();
+    let verifier = correctly_working_verifier_handler(status_by_msg);
+    let invalid = invalid_cc_id.clone();
+    deps.querier.update_wasm(move |query| match query {
+        WasmQuery::Smart { contract_addr, msg } if *contract_addr == validator => {
+            let MessageValidatorQueryMsg::InvalidMessages(msgs) = from_json(msg).unwrap();
+            let invalid_messages: Vec<_> = msgs
+                .into_iter()
+                .filter(|msg| msg.cc_id == invalid)
+                .map(|msg| InvalidMessage {
+                    cc_id: msg.cc_id,
+                    reason: "not an ITS message".to_string(),
+                })
+                .collect();
+            QuerierResult::Ok(ContractResult::Ok(
+                to_json_binary(&invalid_messages).unwrap(),
+            ))
+        }
+        WasmQuery::Smart { msg, .. } => QuerierResult::Ok(ContractResult::Ok(
+            to_json_binary(&verifier(from_json(msg).unwrap()).unwrap()).unwrap(),
+        )),
+        _ => unimplemented!("unsupported query"),
+    });
+
+    let response = execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&api.addr_make("sender"), &[]),
+        ExecuteMsg::RouteMessages(msgs.clone()),
+    );
+    assert!(response.is_err_and(|err| err_contains!(
+        err.report,
+        Error,
+        Error::InvalidMessage { cc_id, .. } if *cc_id == invalid_cc_id
+    )));
+
+    let valid_msgs: Vec<_> = msgs
+        .iter()
+        .filter(|msg| msg.cc_id != invalid_cc_id)
+        .cloned()
+        .collect();
+    assert!(execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&api.addr_make("sender"), &[]),
+        ExecuteMsg::VerifyMessages(valid_msgs),
+    )
+    .is_ok());
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&api.addr_make(GOVERNANCE), &[]),
+        ExecuteMsg::DeregisterMessageValidator {
+            source_chain: "mock-chain".parse().unwrap(),
+        },
+    )
+    .unwrap();
+    let validator: Option<Addr> = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::MessageValidator {
+                source_chain: "mock-chain".parse().unwrap(),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(validator, None);
+
+    assert!(execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&api.addr_make("sender"), &[]),
+        ExecuteMsg::RouteMessages(msgs),
+    )
+    .is_ok());
+}
+
+#[test]
+fn relayer_allowlist_restricts_incoming_submissions_to_allowlisted_relayers() {
+    let msgs = generate_msgs("allowlisted", 3);
+
+    let mut deps = instantiate_contract();
+    let api = deps.api;
+    let status_by_msg = msgs
+        .iter()
+        .map(|msg| (msg.clone(), VerificationStatus::SucceededOnSourceChain))
+        .collect();
+    update_query_handler(
+        &mut deps.querier,
+        correctly_working_verifier_handler(status_by_msg),
+    );
+
+    let governance = message_info(&api.addr_make(GOVERNANCE), &[]);
+    let relayer = message_info(&api.addr_make(RELAYER), &[]);
+    let other = message_info(&api.addr_make("sender"), &[]);
+
+    assert!(execute(
+        deps.as_mut(),
+        mock_env(),
+        other.clone(),
+        ExecuteMsg::EnableRelayerAllowlist,
+    )
+    .is_err());
+
+    let response = execute(
+        deps.as_mut(),
+        mock_env(),
+        governance.clone(),
+        ExecuteMsg::AllowRelayers(vec![api.addr_make(RELAYER).to_string()]),
+    )
+    .unwrap();
+    assert_eq!(response.events[0].ty, "relayer_allowed");
+
+    // submissions of allowlisted relayers are counted even while the allowlist is disabled
+    assert!(execute(
+        deps.as_mut(),
+        mock_env(),
+        other.clone(),
+        ExecuteMsg::VerifyMessages(msgs.clone()),
+    )
+    .is_ok());
+    assert!(execute(
+        deps.as_mut(),
+        mock_env(),
+        relayer.clone(),
+        ExecuteMsg::VerifyMessages(msgs.clone()),
+    )
+    .is_ok());
+
+    let response = execute(
+        deps.as_mut(),
+        mock_env(),
+        governance.clone(),
+        ExecuteMsg::EnableRelayerAllowlist,
+    )
+    .unwrap();
+    assert_eq!(response.events[0].ty, "relayer_allowlist_enabled");
+
+    for msg in [
+        ExecuteMsg::VerifyMessages(msgs.clone()),
+        ExecuteMsg::RouteMessages(msgs.clone()),
+    ] {
+        let response = execute(deps.as_mut(), mock_env(), other.clone(), msg);
+        assert!(response.is_err_and(|err| err_contains!(
+            err.report,
+            Error,
+            Error::RelayerNotAllowlisted(..)
+        )));
+    }
+    assert!(execute(
+        deps.as_mut(),
+        mock_env(),
+        relayer.clone(),
+        ExecuteMsg::RouteMessages(msgs[..2].to_vec()),
+    )
+    .is_ok());
+
+    let allowlist: RelayerAllowlist = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::RelayerAllowlist {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        allowlist,
+        RelayerAllowlist {
+            enabled: true,
+            relayers: vec![Relayer {
+                address: api.addr_make(RELAYER),
+                submissions: 2,
+                messages: 5,
+            }],
+        }
+    );
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        governance.clone(),
+        ExecuteMsg::DisallowRelayers(vec![api.addr_make(RELAYER).to_string()]),
+    )
+    .unwrap();
+    assert!(execute(
+        deps.as_mut(),
+        mock_env(),
+        relayer,
+        ExecuteMsg::VerifyMessages(msgs.clone()),
+    )
+    .is_err());
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        governance,
+        ExecuteMsg::DisableRelayerAllowlist,
+    )
+    .unwrap();
+    assert!(execute(
+        deps.as_mut(),
+        mock_env(),
+        other,
+        ExecuteMsg::VerifyMessages(msgs),
+    )
+    .is_ok());
+}
+
+#[test]
+fn duplicate_submissions_are_counted_and_summarized_per_source_chain() {
+    let in_progress = generate_msgs(VerificationStatus::InProgress, 2);
+    let unknown = generate_msgs(VerificationStatus::Unknown, 2);
+    let msgs = in_progress
+        .iter()
+        .chain(&unknown)
+        .cloned()
+        .collect::<Vec<_>>();
+
+    let mut deps = instantiate_contract();
+    let api = deps.api;
+    let status_by_msg = in_progress
+        .iter()
+        .map(|msg| (msg.clone(), VerificationStatus::InProgress))
+        .chain(
+            unknown
+                .iter()
+                .map(|msg| (msg.clone(), VerificationStatus::Unknown)),
+        )
+        .collect();
+    update_query_handler(
+        &mut deps.querier,
+        correctly_working_verifier_handler(status_by_msg),
+    );
+
+    let sender = message_info(&api.addr_make("sender"), &[]);
+    let mut env = mock_env();
+    env.block.height = 12000;
+
+    for _ in 0..2 {
+        let response = execute(
+            deps.as_mut(),
+            env.clone(),
+            sender.clone(),
+            ExecuteMsg::VerifyMessages(msgs.clone()),
+        )
+        .unwrap();
+        assert!(response
+            .events
+            .iter()
+            .all(|event| event.ty != "duplicate_submissions_summary"));
+    }
+
+    let duplicates: Vec<DuplicateSubmissions> = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::DuplicateSubmissions {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        duplicates,
+        in_progress
+            .iter()
+            .map(|msg| DuplicateSubmissions {
+                cc_id: msg.cc_id.clone(),
+                count: 2,
+            })
+            .collect::<Vec<_>>()
+    );
+
+    let duplicates: Vec<DuplicateSubmissions> = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::DuplicateSubmissions {
+                start_after: Some(in_progress[0].cc_id.clone()),
+                limit: Some(1),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(duplicates.len(), 1);
+    assert_eq!(duplicates[0].cc_id, in_progress[1].cc_id);
+
+    // the first submission in a later period summarizes the previous one
+    env.block.height = 13500;
+    let response = execute(
+        deps.as_mut(),
+        env.clone(),
+        sender.clone(),
+        ExecuteMsg::VerifyMessages(msgs),
+    )
+    .unwrap();
+    let summary = response
+        .events
+        .iter()
+        .find(|event| event.ty == "duplicate_submissions_summary")
+        .unwrap();
+    assert_eq!(
+        summary
+            .attributes
+            .iter()
+            .map(|attr| (attr.key.as_str(), attr.value.as_str()))
+            .collect::<Vec<_>>(),
+        vec![
+            ("source_chain", "mock-chain"),
+            ("period_start", "12000"),
+            ("messages", "8"),
+            ("duplicates", "4"),
+        ]
+    );
+
+    // only the current period is queried, past periods are pruned
+    let duplicates: Vec<DuplicateSubmissions> = from_json(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::DuplicateSubmissions {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert!(duplicates.iter().all(|duplicate| duplicate.count == 1));
+
+    let response = execute(
+        deps.as_mut(),
+        env,
+        sender,
+        ExecuteMsg::PruneMessages { limit: 10 },
+    )
+    .unwrap();
+    let deleted_keys = response
+        .events
+        .iter()
+        .find(|event| event.ty == "messages_pruned")
+        .and_then(|event| {
+            event
+                .attributes
+                .iter()
+                .find(|attribute| attribute.key == "deleted_keys")
+        })
+        .unwrap();
+    assert_eq!(deleted_keys.value, "2");
+}
+
 fn test_cases_for_correct_verifier() -> (
     Vec<Vec<Message>>,
     impl Fn(voting_verifier::msg::QueryMsg) -> Result<Vec<MessageStatus>, ContractError> + Clone,
@@ -353,6 +1803,21 @@ fn generate_msgs(namespace: impl Debug, count: u8) -> Vec<Message> {
         .collect()
 }
 
+fn generate_msgs_with_payload(
+    algorithm: PayloadHashAlgorithm,
+    count: u8,
+) -> Vec<MessageWithPayload> {
+    generate_msgs("with payload", count)
+        .into_iter()
+        .map(|mut message| {
+            let payload = HexBinary::from(format!("payload of {}", message.cc_id).into_bytes());
+            message.payload_hash = algorithm.digest(payload.as_slice());
+
+            MessageWithPayload { message, payload }
+        })
+        .collect()
+}
+
 #[allow(clippy::arithmetic_side_effects)]
 fn all_statuses() -> Vec<VerificationStatus> {
     let statuses = vec![
@@ -432,6 +1897,20 @@ fn update_query_handler<U: Serialize>(
 }
 
 fn instantiate_contract() -> OwnedDeps<MockStorage, MockApi, MockQuerier> {
+    instantiate_contract_with_payload_hash_algorithm(None)
+}
+
+fn instantiate_contract_with_payload_hash_algorithm(
+    payload_hash_algorithm: Option<PayloadHashAlgorithm>,
+) -> OwnedDeps<MockStorage, MockApi, MockQuerier> {
+    instantiate_contract_with_config(payload_hash_algorithm, None, None)
+}
+
+fn instantiate_contract_with_config(
+    payload_hash_algorithm: Option<PayloadHashAlgorithm>,
+    batching_window: Option<nonempty::Uint64>,
+    message_retention: Option<nonempty::Uint64>,
+) -> OwnedDeps<MockStorage, MockApi, MockQuerier> {
     let mut deps = mock_dependencies();
     let api = deps.api;
     let verifier_address = deps.api.addr_make(VERIFIER);
@@ -442,8 +1921,12 @@ fn instantiate_contract() -> OwnedDeps<MockStorage, MockApi, MockQuerier> {
         mock_env(),
         message_info(&api.addr_make("sender"), &[]),
         InstantiateMsg {
+            governance_address: api.addr_make(GOVERNANCE).into_string(),
             verifier_address: verifier_address.into_string(),
             router_address: router_address.into_string(),
+            payload_hash_algorithm,
+            batching_window,
+            message_retention,
         }
         .clone(),
     );
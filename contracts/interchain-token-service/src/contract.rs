@@ -57,6 +57,16 @@ pub enum Error {
     QueryContractStatus,
     #[error("failed to query chain configs")]
     QueryAllChainConfigs,
+    #[error("failed to set token manager types")]
+    SetTokenManagerTypes,
+    #[error("failed to query token manager types")]
+    QueryTokenManagerTypes,
+    #[error("failed to query the linked token manager type")]
+    QueryLinkedTokenManagerType,
+    #[error("failed to correct token decimals")]
+    CorrectTokenDecimals,
+    #[error("failed to query the decimal scaling of a token")]
+    QueryDecimalScaling,
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -144,6 +154,17 @@ pub fn execute(
         ExecuteMsg::EnableExecution => {
             execute::enable_execution(deps).change_context(Error::EnableExecution)
         }
+        ExecuteMsg::SetTokenManagerTypes {
+            chain,
+            token_manager_types,
+        } => execute::set_token_manager_types(deps, chain, token_manager_types)
+            .change_context(Error::SetTokenManagerTypes),
+        ExecuteMsg::CorrectTokenDecimals {
+            chain,
+            token_id,
+            decimals,
+        } => execute::correct_token_decimals(deps, chain, token_id, decimals)
+            .change_context(Error::CorrectTokenDecimals),
     }?
     .then(Ok)
 }
@@ -180,6 +201,16 @@ pub fn query(deps: Deps, _: Env, msg: QueryMsg) -> Result<Binary, ContractError>
         QueryMsg::IsEnabled => {
             query::is_contract_enabled(deps).change_context(Error::QueryContractStatus)
         }
+        QueryMsg::TokenManagerTypes { chain } => {
+            query::token_manager_types(deps, chain).change_context(Error::QueryTokenManagerTypes)
+        }
+        QueryMsg::LinkedTokenManagerType { chain, token_id } => {
+            query::linked_token_manager_type(deps, chain, token_id)
+                .change_context(Error::QueryLinkedTokenManagerType)
+        }
+        QueryMsg::DecimalScaling { token_id } => {
+            query::decimal_scaling(deps, token_id).change_context(Error::QueryDecimalScaling)
+        }
     }?
     .then(Ok)
 }
@@ -65,6 +65,63 @@ pub fn apply_scaling_factor_to_amount(
     Ok(transfer)
 }
 
+/// Adds the transfer to the totals that were transferred out of the source chain and into the destination chain.
+/// If the destination chain has fewer decimals, the truncated remainder is recorded as rounding loss on the source chain.
+/// Transfers between chains with the same decimals are not scaled and always reconcile, so they are not recorded
+pub fn record_transfer_totals(
+    storage: &mut dyn Storage,
+    source_chain: &ChainNameRaw,
+    destination_chain: &ChainNameRaw,
+    source_amount: nonempty::Uint256,
+    transfer: &InterchainTransfer,
+) -> Result<(), Error> {
+    let source_decimals =
+        try_load_token_instance(storage, source_chain.clone(), transfer.token_id)?.decimals;
+    let destination_decimals =
+        try_load_token_instance(storage, destination_chain.clone(), transfer.token_id)?.decimals;
+
+    if source_decimals == destination_decimals {
+        return Ok(());
+    }
+
+    let rounding_loss = if source_decimals > destination_decimals {
+        Uint256::from_u128(10)
+            .checked_pow(source_decimals.abs_diff(destination_decimals).into())
+            .ok()
+            .and_then(|scaling_factor| source_amount.checked_rem(scaling_factor).ok())
+            .unwrap_or(*source_amount)
+    } else {
+        Uint256::zero()
+    };
+
+    let mut source_totals =
+        state::load_transfer_totals(storage, source_chain.clone(), transfer.token_id)
+            .change_context(Error::State)?;
+    source_totals.transferred_out = source_totals.transferred_out.saturating_add(*source_amount);
+    source_totals.rounding_loss = source_totals.rounding_loss.saturating_add(rounding_loss);
+    state::save_transfer_totals(
+        storage,
+        source_chain.clone(),
+        transfer.token_id,
+        &source_totals,
+    )
+    .change_context(Error::State)?;
+
+    let mut destination_totals =
+        state::load_transfer_totals(storage, destination_chain.clone(), transfer.token_id)
+            .change_context(Error::State)?;
+    destination_totals.transferred_in = destination_totals
+        .transferred_in
+        .saturating_add(*transfer.amount);
+    state::save_transfer_totals(
+        storage,
+        destination_chain.clone(),
+        transfer.token_id,
+        &destination_totals,
+    )
+    .change_context(Error::State)
+}
+
 pub fn deploy_token_to_source_chain(
     storage: &mut dyn Storage,
     chain: &ChainNameRaw,
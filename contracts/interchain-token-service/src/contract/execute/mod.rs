@@ -2,6 +2,7 @@ use axelar_wasm_std::{killswitch, nonempty, FnExt, IntoContractError};
 use cosmwasm_std::{DepsMut, HexBinary, QuerierWrapper, Response, Storage, Uint256};
 use error_stack::{bail, ensure, report, Result, ResultExt};
 use interceptors::{deploy_token_to_destination_chain, deploy_token_to_source_chain};
+use itertools::Itertools;
 use router_api::{Address, ChainName, ChainNameRaw, CrossChainId};
 
 use crate::events::Event;
@@ -94,6 +95,15 @@ pub enum Error {
     FailedToQueryAxelarnetGateway,
     #[error("supply modification overflowed. existing supply {0:?}")]
     ModifySupplyOverflow(TokenSupply),
+    #[error("token manager type {0} is registered more than once")]
+    DuplicateTokenManagerTypeId(Uint256),
+    #[error("token manager type name {0} is registered more than once")]
+    DuplicateTokenManagerTypeName(nonempty::String),
+    #[error("token manager type {token_manager_type} not registered for chain {chain}")]
+    TokenManagerTypeNotRegistered {
+        token_manager_type: Uint256,
+        chain: ChainNameRaw,
+    },
 }
 
 /// Executes an incoming ITS message.
@@ -235,6 +245,12 @@ fn apply_to_link_token(
         source_token.decimals,
     )?;
 
+    ensure_token_manager_type_registered(
+        storage,
+        &destination_chain,
+        link_token.token_manager_type,
+    )?;
+
     let destination_decimals = state::may_load_custom_token(
         storage,
         destination_chain.clone(),
@@ -254,6 +270,14 @@ fn apply_to_link_token(
         TokenDeploymentType::CustomMinter,
     )?;
 
+    state::save_linked_token_manager_type(
+        storage,
+        destination_chain,
+        link_token.token_id,
+        link_token.token_manager_type,
+    )
+    .change_context(Error::State)?;
+
     Ok(link_token)
 }
 
@@ -263,7 +287,11 @@ fn apply_to_transfer(
     destination_chain: ChainNameRaw,
     transfer: InterchainTransfer,
 ) -> Result<InterchainTransfer, Error> {
+    ensure_linked_token_manager_type_registered(storage, &source_chain, transfer.token_id)?;
+    ensure_linked_token_manager_type_registered(storage, &destination_chain, transfer.token_id)?;
+
     interceptors::subtract_supply_amount(storage, &source_chain, &transfer)?;
+    let source_amount = transfer.amount;
     let transfer = interceptors::apply_scaling_factor_to_amount(
         storage,
         &source_chain,
@@ -271,6 +299,13 @@ fn apply_to_transfer(
         transfer,
     )?;
     interceptors::add_supply_amount(storage, &destination_chain, &transfer)?;
+    interceptors::record_transfer_totals(
+        storage,
+        &source_chain,
+        &destination_chain,
+        source_amount,
+        &transfer,
+    )?;
 
     Ok(transfer)
 }
@@ -341,6 +376,46 @@ fn ensure_chain_is_registered(storage: &dyn Storage, chain: ChainNameRaw) -> Res
     Ok(())
 }
 
+/// Ensures that the token manager type is supported by the chain. Chains without registered
+/// token manager types accept any type.
+fn ensure_token_manager_type_registered(
+    storage: &dyn Storage,
+    chain: &ChainNameRaw,
+    token_manager_type: Uint256,
+) -> Result<(), Error> {
+    let token_manager_types =
+        state::load_token_manager_types(storage, chain).change_context(Error::State)?;
+
+    ensure!(
+        token_manager_types.is_empty()
+            || token_manager_types
+                .iter()
+                .any(|registered| registered.id == token_manager_type),
+        Error::TokenManagerTypeNotRegistered {
+            token_manager_type,
+            chain: chain.to_owned(),
+        }
+    );
+
+    Ok(())
+}
+
+/// Ensures that a token linked to the chain still uses a token manager type supported by the chain.
+fn ensure_linked_token_manager_type_registered(
+    storage: &dyn Storage,
+    chain: &ChainNameRaw,
+    token_id: TokenId,
+) -> Result<(), Error> {
+    match state::may_load_linked_token_manager_type(storage, chain.clone(), token_id)
+        .change_context(Error::State)?
+    {
+        Some(token_manager_type) => {
+            ensure_token_manager_type_registered(storage, chain, token_manager_type)
+        }
+        None => Ok(()),
+    }
+}
+
 fn send_to_destination(
     storage: &dyn Storage,
     querier: QuerierWrapper,
@@ -391,6 +466,41 @@ pub fn enable_execution(deps: DepsMut) -> Result<Response, Error> {
     killswitch::disengage(deps.storage, Event::ExecutionEnabled).change_context(Error::State)
 }
 
+pub fn set_token_manager_types(
+    deps: DepsMut,
+    chain: ChainNameRaw,
+    token_manager_types: Vec<msg::TokenManagerType>,
+) -> Result<Response, Error> {
+    ensure_chain_is_registered(deps.storage, chain.clone())?;
+
+    if let Some(duplicate) = token_manager_types
+        .iter()
+        .map(|t| t.id)
+        .sorted()
+        .tuple_windows()
+        .find_map(|(a, b)| (a == b).then_some(a))
+    {
+        bail!(Error::DuplicateTokenManagerTypeId(duplicate));
+    }
+
+    if let Some(duplicate) = token_manager_types
+        .iter()
+        .map(|t| t.name.clone())
+        .duplicates()
+        .next()
+    {
+        bail!(Error::DuplicateTokenManagerTypeName(duplicate));
+    }
+
+    state::save_token_manager_types(deps.storage, &chain, &token_manager_types)
+        .change_context(Error::State)?;
+
+    Ok(Response::new().add_event(Event::TokenManagerTypesSet {
+        chain,
+        token_manager_types,
+    }))
+}
+
 pub fn register_chains(deps: DepsMut, chains: Vec<msg::ChainConfig>) -> Result<Response, Error> {
     chains
         .into_iter()
@@ -427,6 +537,41 @@ fn update_chain(storage: &mut dyn Storage, config: msg::ChainConfig) -> Result<(
     Ok(())
 }
 
+/// Overwrites the decimals registered for a token on a chain, e.g. when they were registered incorrectly.
+/// The tracked supply on the chain is converted to the corrected decimals, truncating if they are fewer.
+/// Subsequent transfers are scaled with the corrected decimals, amounts that were already transferred are not adjusted
+pub fn correct_token_decimals(
+    deps: DepsMut,
+    chain: ChainNameRaw,
+    token_id: TokenId,
+    decimals: u8,
+) -> Result<Response, Error> {
+    let mut token_instance = state::may_load_token_instance(deps.storage, chain.clone(), token_id)
+        .change_context(Error::State)?
+        .ok_or(Error::TokenNotDeployed {
+            token_id,
+            chain: chain.clone(),
+        })?;
+
+    let previous_decimals = token_instance.decimals;
+    token_instance.supply = token_instance
+        .supply
+        .clone()
+        .checked_rescale(previous_decimals, decimals)
+        .change_context(Error::ModifySupplyOverflow(token_instance.supply.clone()))?;
+    token_instance.decimals = decimals;
+
+    state::save_token_instance(deps.storage, chain.clone(), token_id, &token_instance)
+        .change_context(Error::State)?;
+
+    Ok(Response::new().add_event(Event::TokenDecimalsCorrected {
+        token_id,
+        chain,
+        previous_decimals,
+        decimals,
+    }))
+}
+
 pub fn modify_supply(
     deps: DepsMut,
     chain: ChainNameRaw,
@@ -548,7 +693,8 @@ mod tests {
     use super::{apply_to_hub, register_p2p_token_instance};
     use crate::contract::execute::{
         apply_to_transfer, disable_execution, enable_execution, execute_message, freeze_chain,
-        modify_supply, register_chain, register_chains, unfreeze_chain, update_chains, Error,
+        modify_supply, register_chain, register_chains, set_token_manager_types, unfreeze_chain,
+        update_chains, Error,
     };
     use crate::msg::TruncationConfig;
     use crate::state::{self, Config};
@@ -1813,6 +1959,145 @@ mod tests {
         );
     }
 
+    #[test]
+    fn should_only_link_tokens_with_registered_token_manager_types() {
+        let mut deps = mock_dependencies();
+        init(&mut deps);
+
+        let lock_unlock_fee = Uint256::from_u128(3);
+        assert_ok!(set_token_manager_types(
+            deps.as_mut(),
+            ethereum(),
+            vec![token_manager_type("lock_unlock_fee", lock_unlock_fee)]
+        ));
+
+        register_custom_token(&mut deps, solana(), 6, its_address());
+        register_custom_token(&mut deps, ethereum(), 6, its_address());
+
+        assert_err_contains!(
+            link_custom_token_with_type(
+                &mut deps,
+                token_id(),
+                solana(),
+                ethereum(),
+                its_address(),
+                Uint256::zero()
+            ),
+            Error,
+            Error::TokenManagerTypeNotRegistered { token_manager_type, chain }
+                if *token_manager_type == Uint256::zero() && *chain == ethereum()
+        );
+
+        assert_ok!(link_custom_token_with_type(
+            &mut deps,
+            token_id(),
+            solana(),
+            ethereum(),
+            its_address(),
+            lock_unlock_fee
+        ));
+        assert_eq!(
+            assert_ok!(state::may_load_linked_token_manager_type(
+                deps.as_mut().storage,
+                ethereum(),
+                token_id()
+            )),
+            Some(lock_unlock_fee)
+        );
+    }
+
+    #[test]
+    fn should_not_transfer_linked_tokens_when_token_manager_type_is_deregistered() {
+        let mut deps = mock_dependencies();
+        init(&mut deps);
+
+        assert_ok!(set_token_manager_types(
+            deps.as_mut(),
+            ethereum(),
+            vec![token_manager_type(
+                "native_interchain_token",
+                Uint256::zero()
+            )]
+        ));
+        register_and_link_custom_tokens(
+            &mut deps,
+            token_id(),
+            solana(),
+            ethereum(),
+            6,
+            6,
+            its_address(),
+        );
+
+        let amount = Uint256::one().try_into().unwrap();
+        assert_ok!(transfer_token(
+            deps.as_mut(),
+            solana(),
+            ethereum(),
+            token_id(),
+            amount
+        ));
+
+        assert_ok!(set_token_manager_types(
+            deps.as_mut(),
+            ethereum(),
+            vec![token_manager_type("gateway_native", Uint256::from_u128(5))]
+        ));
+        assert_err_contains!(
+            transfer_token(deps.as_mut(), ethereum(), solana(), token_id(), amount),
+            Error,
+            Error::TokenManagerTypeNotRegistered { chain, .. } if *chain == ethereum()
+        );
+
+        assert_ok!(set_token_manager_types(deps.as_mut(), ethereum(), vec![]));
+        assert_ok!(transfer_token(
+            deps.as_mut(),
+            ethereum(),
+            solana(),
+            token_id(),
+            amount
+        ));
+    }
+
+    #[test]
+    fn set_token_manager_types_should_reject_invalid_types() {
+        let mut deps = mock_dependencies();
+        init(&mut deps);
+
+        let unknown_chain = ChainNameRaw::try_from("unknown").unwrap();
+        assert_err_contains!(
+            set_token_manager_types(deps.as_mut(), unknown_chain.clone(), vec![]),
+            Error,
+            Error::ChainNotRegistered(chain) if *chain == unknown_chain
+        );
+
+        assert_err_contains!(
+            set_token_manager_types(
+                deps.as_mut(),
+                ethereum(),
+                vec![
+                    token_manager_type("lock_unlock", Uint256::from_u128(2)),
+                    token_manager_type("lock_unlock_fee", Uint256::from_u128(2)),
+                ]
+            ),
+            Error,
+            Error::DuplicateTokenManagerTypeId(id) if *id == Uint256::from_u128(2)
+        );
+
+        assert_err_contains!(
+            set_token_manager_types(
+                deps.as_mut(),
+                ethereum(),
+                vec![
+                    token_manager_type("lock_unlock", Uint256::from_u128(2)),
+                    token_manager_type("lock_unlock", Uint256::from_u128(3)),
+                ]
+            ),
+            Error,
+            Error::DuplicateTokenManagerTypeName(..)
+        );
+    }
+
     // Below are various helper functions to assist with writing tests
 
     fn its_address() -> nonempty::HexBinary {
@@ -1839,6 +2124,13 @@ mod tests {
         ETHEREUM.try_into().unwrap()
     }
 
+    fn token_manager_type(name: &str, id: Uint256) -> msg::TokenManagerType {
+        msg::TokenManagerType {
+            name: name.try_into().unwrap(),
+            id,
+        }
+    }
+
     fn cc_id(source_chain: ChainNameRaw) -> CrossChainId {
         CrossChainId {
             source_chain,
@@ -1984,11 +2276,30 @@ mod tests {
         destination_chain: ChainNameRaw,
         token_address: nonempty::HexBinary,
     ) {
+        let res = assert_ok!(link_custom_token_with_type(
+            deps,
+            token_id,
+            source_chain,
+            destination_chain,
+            token_address,
+            Uint256::zero(),
+        ));
+        assert_eq!(res.messages.len(), 1);
+    }
+
+    fn link_custom_token_with_type(
+        deps: &mut OwnedDeps<MemoryStorage, MockApi, MockQuerier>,
+        token_id: TokenId,
+        source_chain: ChainNameRaw,
+        destination_chain: ChainNameRaw,
+        token_address: nonempty::HexBinary,
+        token_manager_type: Uint256,
+    ) -> Result<Response, Error> {
         let msg = HubMessage::SendToHub {
             destination_chain: destination_chain.clone(),
             message: LinkToken {
                 token_id,
-                token_manager_type: Uint256::zero(),
+                token_manager_type,
                 source_token_address: token_address.clone(),
                 destination_token_address: token_address.clone(),
                 params: None,
@@ -1996,7 +2307,7 @@ mod tests {
             .into(),
         };
 
-        let res = assert_ok!(execute_message(
+        execute_message(
             deps.as_mut(),
             CrossChainId {
                 source_chain: source_chain.clone(),
@@ -2007,8 +2318,7 @@ mod tests {
             },
             ITS_ADDRESS.to_string().try_into().unwrap(),
             msg.clone().abi_encode(),
-        ));
-        assert_eq!(res.messages.len(), 1);
+        )
     }
 
     fn register_and_link_custom_tokens(
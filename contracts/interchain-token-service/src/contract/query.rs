@@ -1,5 +1,5 @@
 use axelar_wasm_std::{killswitch, IntoContractError};
-use cosmwasm_std::{to_json_binary, Binary, Deps};
+use cosmwasm_std::{to_json_binary, Binary, Deps, Uint256};
 use error_stack::{Result, ResultExt};
 use itertools::Itertools;
 use router_api::ChainNameRaw;
@@ -86,3 +86,95 @@ pub fn is_contract_enabled(deps: Deps) -> Result<Binary, Error> {
     to_json_binary(&killswitch::is_contract_active(deps.storage))
         .change_context(Error::JsonSerialization)
 }
+
+pub fn token_manager_types(deps: Deps, chain: ChainNameRaw) -> Result<Binary, Error> {
+    let token_manager_types =
+        state::load_token_manager_types(deps.storage, &chain).change_context(Error::State)?;
+    to_json_binary(&token_manager_types).change_context(Error::JsonSerialization)
+}
+
+pub fn linked_token_manager_type(
+    deps: Deps,
+    chain: ChainNameRaw,
+    token_id: TokenId,
+) -> Result<Binary, Error> {
+    let token_manager_type =
+        state::may_load_linked_token_manager_type(deps.storage, chain, token_id)
+            .change_context(Error::State)?;
+    to_json_binary(&token_manager_type).change_context(Error::JsonSerialization)
+}
+
+pub fn decimal_scaling(deps: Deps, token_id: TokenId) -> Result<Binary, Error> {
+    to_json_binary(&decimal_scaling_audit(deps, token_id)?).change_context(Error::JsonSerialization)
+}
+
+fn decimal_scaling_audit(
+    deps: Deps,
+    token_id: TokenId,
+) -> Result<Option<msg::DecimalScalingAudit>, Error> {
+    let Some(state::TokenConfig { origin_chain }) =
+        state::may_load_token_config(deps.storage, &token_id).change_context(Error::State)?
+    else {
+        return Ok(None);
+    };
+
+    let Some(origin_token) =
+        state::may_load_token_instance(deps.storage, origin_chain.clone(), token_id)
+            .change_context(Error::State)?
+    else {
+        return Ok(None);
+    };
+
+    let chains: Vec<_> = state::load_token_instances(deps.storage, token_id)
+        .change_context(Error::State)?
+        .into_iter()
+        .map(|(chain, token)| {
+            let totals = state::load_transfer_totals(deps.storage, chain.clone(), token_id)
+                .change_context(Error::State)?;
+
+            Ok(msg::ChainDecimalScaling {
+                chain,
+                decimals: token.decimals,
+                scaling_exponent: i16::from(token.decimals)
+                    .saturating_sub(i16::from(origin_token.decimals)),
+                transferred_out: totals.transferred_out,
+                transferred_in: totals.transferred_in,
+                rounding_loss: totals.rounding_loss,
+            })
+        })
+        .try_collect()?;
+
+    Ok(Some(msg::DecimalScalingAudit {
+        token_id,
+        origin_chain,
+        reconciled: reconcile(&chains).unwrap_or(false),
+        chains,
+    }))
+}
+
+/// Compares the total amount transferred out with the total amount transferred in plus rounding loss,
+/// after scaling all amounts to the highest number of decimals. Returns None if the scaled amounts overflow
+fn reconcile(chains: &[msg::ChainDecimalScaling]) -> Option<bool> {
+    let max_decimals = chains.iter().map(|chain| chain.decimals).max()?;
+
+    let (sent, received) = chains.iter().try_fold(
+        (Uint256::zero(), Uint256::zero()),
+        |(sent, received), chain| {
+            let scaling_factor = Uint256::from_u128(10)
+                .checked_pow(max_decimals.saturating_sub(chain.decimals).into())
+                .ok()?;
+            let scale = |amount: Uint256| amount.checked_mul(scaling_factor).ok();
+
+            Some((
+                sent.checked_add(scale(chain.transferred_out)?).ok()?,
+                received
+                    .checked_add(scale(chain.transferred_in)?)
+                    .ok()?
+                    .checked_add(scale(chain.rounding_loss)?)
+                    .ok()?,
+            ))
+        },
+    )?;
+
+    Some(sent == received)
+}
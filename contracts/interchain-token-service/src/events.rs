@@ -1,7 +1,7 @@
 use axelar_wasm_std::{nonempty, IntoEvent};
 use router_api::{Address, ChainNameRaw, CrossChainId};
 
-use crate::msg::SupplyModifier;
+use crate::msg::{SupplyModifier, TokenManagerType};
 use crate::primitives::Message;
 use crate::TokenId;
 
@@ -31,6 +31,16 @@ pub enum Event {
         chain: ChainNameRaw,
         supply_modifier: SupplyModifier,
     },
+    TokenManagerTypesSet {
+        chain: ChainNameRaw,
+        token_manager_types: Vec<TokenManagerType>,
+    },
+    TokenDecimalsCorrected {
+        token_id: TokenId,
+        chain: ChainNameRaw,
+        previous_decimals: u8,
+        decimals: u8,
+    },
 }
 
 #[cfg(test)]
@@ -3,6 +3,7 @@ use std::collections::HashMap;
 use axelar_wasm_std::nonempty;
 use axelarnet_gateway::AxelarExecutableMsg;
 use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::Uint256;
 use msgs_derive::EnsurePermissions;
 use router_api::{Address, ChainNameRaw};
 
@@ -81,6 +82,33 @@ pub enum ExecuteMsg {
 
     #[permission(Elevated)]
     EnableExecution,
+
+    /// Set the token manager types that are supported by a chain's ITS contract, replacing any
+    /// previously registered types. Once a chain has registered types, tokens can only be linked
+    /// to that chain with one of them, and transfers of linked tokens require their type to remain
+    /// registered. An empty list removes the restriction.
+    #[permission(Governance)]
+    SetTokenManagerTypes {
+        chain: ChainNameRaw,
+        token_manager_types: Vec<TokenManagerType>,
+    },
+
+    /// Correct the decimals registered for a token on a chain. Only transfers after the correction
+    /// are scaled with the new decimals, so the decimal scaling audit of the token reports any
+    /// amounts that were scaled incorrectly before.
+    #[permission(Governance)]
+    CorrectTokenDecimals {
+        chain: ChainNameRaw,
+        token_id: TokenId,
+        decimals: u8,
+    },
+}
+
+/// A token manager type supported by a chain's ITS contract, e.g. lock/unlock-fee or gateway-native
+#[cw_serde]
+pub struct TokenManagerType {
+    pub name: nonempty::String,
+    pub id: Uint256,
 }
 
 #[cw_serde]
@@ -122,6 +150,31 @@ pub struct ChainConfigResponse {
     pub frozen: bool,
 }
 
+/// Decimal scaling applied to a token on a single chain, and the amounts transferred through the hub.
+/// All amounts are denominated in the token's decimals on this chain
+#[cw_serde]
+pub struct ChainDecimalScaling {
+    pub chain: ChainNameRaw,
+    pub decimals: u8,
+    /// Amounts are scaled by 10^scaling_exponent relative to the origin chain
+    pub scaling_exponent: i16,
+    pub transferred_out: Uint256,
+    pub transferred_in: Uint256,
+    /// Amount transferred out that was truncated when scaling to chains with fewer decimals
+    pub rounding_loss: Uint256,
+}
+
+#[cw_serde]
+pub struct DecimalScalingAudit {
+    pub token_id: TokenId,
+    pub origin_chain: ChainNameRaw,
+    pub chains: Vec<ChainDecimalScaling>,
+    /// True if, at the highest precision among all chains, everything transferred out was either
+    /// transferred in or lost to rounding. A mismatch means amounts drifted, e.g. because the
+    /// decimals of a chain were corrected after transfers had already been scaled
+    pub reconciled: bool,
+}
+
 #[cw_serde]
 #[derive(QueryResponses)]
 pub enum QueryMsg {
@@ -159,4 +212,20 @@ pub enum QueryMsg {
     /// Query the state of contract (enabled/disabled)
     #[returns(bool)]
     IsEnabled,
+
+    /// Query the token manager types registered for a chain
+    #[returns(Vec<TokenManagerType>)]
+    TokenManagerTypes { chain: ChainNameRaw },
+
+    /// Query the token manager type a token was linked with on a specific chain
+    #[returns(Option<Uint256>)]
+    LinkedTokenManagerType {
+        chain: ChainNameRaw,
+        token_id: TokenId,
+    },
+
+    /// Query the decimal scaling of a token on each chain it is deployed to, and reconcile the
+    /// amounts transferred between them
+    #[returns(Option<DecimalScalingAudit>)]
+    DecimalScaling { token_id: TokenId },
 }
@@ -89,6 +89,31 @@ impl TokenSupply {
         }
         .then(Ok)
     }
+
+    /// Converts the supply from one number of decimals to another, truncating if the new number of decimals is smaller
+    pub fn checked_rescale(
+        self,
+        from_decimals: u8,
+        to_decimals: u8,
+    ) -> Result<Self, OverflowError> {
+        let scaling_factor =
+            Uint256::from_u128(10).checked_pow(from_decimals.abs_diff(to_decimals).into());
+
+        match self {
+            TokenSupply::Untracked => TokenSupply::Untracked,
+            TokenSupply::Tracked(supply) if to_decimals >= from_decimals => {
+                TokenSupply::Tracked(supply.checked_mul(scaling_factor?)?)
+            }
+            // a scaling factor that overflows is larger than any supply
+            TokenSupply::Tracked(supply) => TokenSupply::Tracked(
+                scaling_factor
+                    .ok()
+                    .and_then(|scaling_factor| supply.checked_div(scaling_factor).ok())
+                    .unwrap_or_default(),
+            ),
+        }
+        .then(Ok)
+    }
 }
 
 /// Information about a token on a specific chain.
@@ -129,6 +154,17 @@ pub struct TokenConfig {
     pub origin_chain: ChainNameRaw,
 }
 
+/// Amounts of a token that were transferred from and to a chain through the hub with decimal scaling, in the token's units
+/// on that chain
+#[cw_serde]
+#[derive(Default)]
+pub struct TransferTotals {
+    pub transferred_out: Uint256,
+    pub transferred_in: Uint256,
+    /// Part of the amount transferred out that was truncated when scaling it to destination chains with fewer decimals
+    pub rounding_loss: Uint256,
+}
+
 type TokenAddress = nonempty::HexBinary;
 
 #[cw_serde]
@@ -144,6 +180,11 @@ const TOKEN_INSTANCE: Map<&(ChainNameRaw, TokenId), TokenInstance> = Map::new("t
 const TOKEN_CONFIGS: Map<&TokenId, TokenConfig> = Map::new("token_configs");
 const CUSTOM_TOKEN_METADATA: Map<&(ChainNameRaw, TokenAddress), CustomTokenMetadata> =
     Map::new("custom_tokens");
+const TOKEN_MANAGER_TYPES: Map<&ChainNameRaw, Vec<msg::TokenManagerType>> =
+    Map::new("token_manager_types");
+const LINKED_TOKEN_MANAGER_TYPES: Map<&(ChainNameRaw, TokenId), Uint256> =
+    Map::new("linked_token_manager_types");
+const TRANSFER_TOTALS: Map<&(ChainNameRaw, TokenId), TransferTotals> = Map::new("transfer_totals");
 
 pub fn load_config(storage: &dyn Storage) -> Config {
     CONFIG
@@ -325,6 +366,93 @@ pub fn may_load_custom_token(
         .change_context(Error::Storage)
 }
 
+pub fn load_token_manager_types(
+    storage: &dyn Storage,
+    chain: &ChainNameRaw,
+) -> Result<Vec<msg::TokenManagerType>, Error> {
+    TOKEN_MANAGER_TYPES
+        .may_load(storage, chain)
+        .map(Option::unwrap_or_default)
+        .change_context(Error::Storage)
+}
+
+pub fn save_token_manager_types(
+    storage: &mut dyn Storage,
+    chain: &ChainNameRaw,
+    token_manager_types: &[msg::TokenManagerType],
+) -> Result<(), Error> {
+    if token_manager_types.is_empty() {
+        TOKEN_MANAGER_TYPES.remove(storage, chain);
+        return Ok(());
+    }
+
+    TOKEN_MANAGER_TYPES
+        .save(storage, chain, &token_manager_types.to_vec())
+        .change_context(Error::Storage)
+}
+
+pub fn may_load_linked_token_manager_type(
+    storage: &dyn Storage,
+    chain: ChainNameRaw,
+    token_id: TokenId,
+) -> Result<Option<Uint256>, Error> {
+    LINKED_TOKEN_MANAGER_TYPES
+        .may_load(storage, &(chain, token_id))
+        .change_context(Error::Storage)
+}
+
+pub fn save_linked_token_manager_type(
+    storage: &mut dyn Storage,
+    chain: ChainNameRaw,
+    token_id: TokenId,
+    token_manager_type: Uint256,
+) -> Result<(), Error> {
+    LINKED_TOKEN_MANAGER_TYPES
+        .save(storage, &(chain, token_id), &token_manager_type)
+        .change_context(Error::Storage)
+}
+
+/// Loads the instances of the token on all registered chains, ordered by chain name
+pub fn load_token_instances(
+    storage: &dyn Storage,
+    token_id: TokenId,
+) -> Result<Vec<(ChainNameRaw, TokenInstance)>, Error> {
+    CHAIN_CONFIGS
+        .keys(storage, None, None, Order::Ascending)
+        .map(|chain| chain.change_context(Error::Storage))
+        .filter_map(|chain| {
+            chain
+                .and_then(|chain| {
+                    may_load_token_instance(storage, chain.clone(), token_id)
+                        .map(|instance| instance.map(|instance| (chain, instance)))
+                })
+                .transpose()
+        })
+        .collect()
+}
+
+pub fn load_transfer_totals(
+    storage: &dyn Storage,
+    chain: ChainNameRaw,
+    token_id: TokenId,
+) -> Result<TransferTotals, Error> {
+    TRANSFER_TOTALS
+        .may_load(storage, &(chain, token_id))
+        .change_context(Error::Storage)
+        .map(Option::unwrap_or_default)
+}
+
+pub fn save_transfer_totals(
+    storage: &mut dyn Storage,
+    chain: ChainNameRaw,
+    token_id: TokenId,
+    totals: &TransferTotals,
+) -> Result<(), Error> {
+    TRANSFER_TOTALS
+        .save(storage, &(chain, token_id), totals)
+        .change_context(Error::Storage)
+}
+
 #[cfg(test)]
 mod tests {
     use assert_ok::assert_ok;
@@ -413,4 +541,31 @@ mod tests {
                 .collect::<HashMap<_, _>>()
         );
     }
+
+    #[test]
+    fn token_supply_rescale_converts_decimals() {
+        let supply = TokenSupply::Tracked(Uint256::from_u128(1_000_000_000_015));
+
+        assert_eq!(
+            assert_ok!(supply.clone().checked_rescale(12, 14)),
+            TokenSupply::Tracked(Uint256::from_u128(100_000_000_001_500))
+        );
+        assert_eq!(
+            assert_ok!(supply.clone().checked_rescale(12, 10)),
+            TokenSupply::Tracked(Uint256::from_u128(10_000_000_000))
+        );
+        assert_eq!(
+            assert_ok!(supply.clone().checked_rescale(12, 12)),
+            supply.clone()
+        );
+        assert_eq!(
+            assert_ok!(supply.clone().checked_rescale(u8::MAX, 0)),
+            TokenSupply::Tracked(Uint256::zero())
+        );
+        assert!(supply.checked_rescale(0, u8::MAX).is_err());
+        assert_eq!(
+            assert_ok!(TokenSupply::Untracked.checked_rescale(18, 6)),
+            TokenSupply::Untracked
+        );
+    }
 }
@@ -722,6 +722,151 @@ fn execute_message_interchain_transfer_should_scale_correctly_in_3_chain_cycle_w
     goldie::assert_json!(response_to_destination);
 }
 
+#[test]
+fn decimal_scaling_audit_should_reconcile_transfers_until_decimals_are_corrected() {
+    let TestMessage {
+        source_its_contract,
+        ..
+    } = TestMessage::dummy();
+    let configs = vec![
+        (
+            "ethereum".parse().unwrap(),
+            source_its_contract.clone(),
+            256,
+            u8::MAX,
+        ),
+        (
+            "stellar".parse().unwrap(),
+            source_its_contract.clone(),
+            128,
+            12,
+        ),
+    ];
+    let (ethereum, stellar): (ChainNameRaw, ChainNameRaw) =
+        (configs[0].0.clone(), configs[1].0.clone());
+
+    let (mut deps, TestMessage { router_message, .. }) =
+        utils::setup_multiple_chains(configs.clone());
+    let token_id = TokenId::new([1; 32]);
+    assert_eq!(
+        assert_ok!(utils::query_decimal_scaling(deps.as_ref(), token_id)),
+        None
+    );
+
+    let deploy_token = DeployInterchainToken {
+        token_id,
+        name: "Test".try_into().unwrap(),
+        symbol: "TST".try_into().unwrap(),
+        decimals: 18,
+        minter: None,
+    };
+    assert_ok!(utils::execute_hub_message(
+        deps.as_mut(),
+        CrossChainId::new(ethereum.clone(), router_message.cc_id.message_id.clone()).unwrap(),
+        source_its_contract.clone(),
+        HubMessage::SendToHub {
+            destination_chain: stellar.clone(),
+            message: deploy_token.into(),
+        },
+    ));
+
+    let transfer = InterchainTransfer {
+        token_id,
+        source_address: HexBinary::from([1; 32]).try_into().unwrap(),
+        destination_address: HexBinary::from([2; 32]).try_into().unwrap(),
+        amount: Uint256::from_u128(1000000000010000001u128)
+            .try_into()
+            .unwrap(),
+        data: None,
+    };
+    assert_ok!(utils::execute_hub_message(
+        deps.as_mut(),
+        CrossChainId::new(ethereum.clone(), router_message.cc_id.message_id.clone()).unwrap(),
+        source_its_contract.clone(),
+        HubMessage::SendToHub {
+            destination_chain: stellar.clone(),
+            message: transfer.into(),
+        },
+    ));
+
+    let audit = assert_ok!(utils::query_decimal_scaling(deps.as_ref(), token_id)).unwrap();
+    assert_eq!(
+        audit,
+        msg::DecimalScalingAudit {
+            token_id,
+            origin_chain: ethereum.clone(),
+            chains: vec![
+                msg::ChainDecimalScaling {
+                    chain: ethereum.clone(),
+                    decimals: 18,
+                    scaling_exponent: 0,
+                    transferred_out: Uint256::from_u128(1000000000010000001u128),
+                    transferred_in: Uint256::zero(),
+                    rounding_loss: Uint256::one(),
+                },
+                msg::ChainDecimalScaling {
+                    chain: stellar.clone(),
+                    decimals: 12,
+                    scaling_exponent: -6,
+                    transferred_out: Uint256::zero(),
+                    transferred_in: Uint256::from_u128(1000000000010u128),
+                    rounding_loss: Uint256::zero(),
+                },
+            ],
+            reconciled: true,
+        }
+    );
+
+    let operator = deps.api.addr_make(params::OPERATOR);
+    let result = contract::execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&operator, &[]),
+        ExecuteMsg::CorrectTokenDecimals {
+            chain: stellar.clone(),
+            token_id,
+            decimals: 10,
+        },
+    );
+    assert_err_contains!(
+        result,
+        permission_control::Error,
+        permission_control::Error::PermissionDenied { .. }
+    );
+
+    let response = assert_ok!(utils::correct_token_decimals(
+        deps.as_mut(),
+        stellar.clone(),
+        token_id,
+        10
+    ));
+    assert_eq!(
+        response.events,
+        vec![cosmwasm_std::Event::from(Event::TokenDecimalsCorrected {
+            token_id,
+            chain: stellar.clone(),
+            previous_decimals: 12,
+            decimals: 10,
+        })]
+    );
+
+    let audit = assert_ok!(utils::query_decimal_scaling(deps.as_ref(), token_id)).unwrap();
+    assert_eq!(audit.chains[1].scaling_exponent, -8);
+    assert!(!audit.reconciled);
+
+    // the tracked supply is converted to the corrected decimals
+    let token_instance = assert_ok!(utils::query_token_instance(
+        deps.as_ref(),
+        stellar.clone(),
+        token_id
+    ))
+    .unwrap();
+    assert_eq!(
+        token_instance.supply,
+        TokenSupply::Tracked(Uint256::from_u128(10000000000u128))
+    );
+}
+
 #[test]
 fn execute_message_deploy_interchain_token_should_translate_decimals_when_max_uints_are_different()
 {
@@ -1194,6 +1339,57 @@ fn enable_execution_when_not_admin_fails() {
     );
 }
 
+#[test]
+fn governance_can_set_token_manager_types() {
+    let mut deps = mock_dependencies();
+    let api = deps.api;
+
+    utils::instantiate_contract(deps.as_mut()).unwrap();
+
+    let chain: ChainNameRaw = "ethereum".parse().unwrap();
+    assert_ok!(utils::register_chain(
+        deps.as_mut(),
+        chain.clone(),
+        "0x1234567890123456789012345678901234567890"
+            .parse()
+            .unwrap(),
+        256.try_into().unwrap(),
+        18u8
+    ));
+
+    let token_manager_types = vec![msg::TokenManagerType {
+        name: "gateway_native".try_into().unwrap(),
+        id: Uint256::from_u128(5),
+    }];
+    let msg = ExecuteMsg::SetTokenManagerTypes {
+        chain: chain.clone(),
+        token_manager_types: token_manager_types.clone(),
+    };
+
+    assert_err_contains!(
+        contract::execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(params::ADMIN), &[]),
+            msg.clone(),
+        ),
+        permission_control::Error,
+        permission_control::Error::PermissionDenied { .. }
+    );
+
+    let res = assert_ok!(contract::execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&api.addr_make(params::GOVERNANCE), &[]),
+        msg,
+    ));
+    assert_eq!(res.events.len(), 1);
+    assert_eq!(
+        assert_ok!(utils::query_token_manager_types(deps.as_ref(), chain)),
+        token_manager_types
+    );
+}
+
 #[test]
 fn admin_or_governance_can_enable_execution() {
     let mut deps = mock_dependencies();
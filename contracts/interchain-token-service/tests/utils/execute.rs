@@ -206,6 +206,24 @@ pub fn modify_supply(
     )
 }
 
+pub fn correct_token_decimals(
+    deps: DepsMut,
+    chain: ChainNameRaw,
+    token_id: TokenId,
+    decimals: u8,
+) -> Result<Response, ContractError> {
+    contract::execute(
+        deps,
+        mock_env(),
+        message_info(&MockApi::default().addr_make(params::GOVERNANCE), &[]),
+        ExecuteMsg::CorrectTokenDecimals {
+            chain,
+            token_id,
+            decimals,
+        },
+    )
+}
+
 pub fn setup_multiple_chains(
     configs: Vec<(ChainNameRaw, Address, u32, u8)>,
 ) -> (
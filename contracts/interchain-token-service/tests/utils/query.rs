@@ -4,7 +4,10 @@ use axelar_wasm_std::error::ContractError;
 use cosmwasm_std::testing::mock_env;
 use cosmwasm_std::{from_json, Deps};
 use interchain_token_service::contract::query;
-use interchain_token_service::msg::{ChainConfigResponse, ChainFilter, QueryMsg, TruncationConfig};
+use interchain_token_service::msg::{
+    ChainConfigResponse, ChainFilter, DecimalScalingAudit, QueryMsg, TokenManagerType,
+    TruncationConfig,
+};
 use interchain_token_service::shared::NumBits;
 use interchain_token_service::{TokenConfig, TokenId, TokenInstance};
 use router_api::{Address, ChainNameRaw};
@@ -93,3 +96,19 @@ pub fn assert_configs_equal(actual: &[ChainConfigResponse], expected: &[ChainCon
         assert_eq!(a, e, "Config mismatch for chain {}", e.chain);
     }
 }
+
+pub fn query_token_manager_types(
+    deps: Deps,
+    chain: ChainNameRaw,
+) -> Result<Vec<TokenManagerType>, ContractError> {
+    let bin = query(deps, mock_env(), QueryMsg::TokenManagerTypes { chain })?;
+    Ok(from_json(bin)?)
+}
+
+pub fn query_decimal_scaling(
+    deps: Deps,
+    token_id: TokenId,
+) -> Result<Option<DecimalScalingAudit>, ContractError> {
+    let bin = query(deps, mock_env(), QueryMsg::DecimalScaling { token_id })?;
+    Ok(from_json(bin)?)
+}
@@ -1,12 +1,14 @@
 use axelar_wasm_std::{address, permission_control};
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
-use cosmwasm_std::{to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response};
+use cosmwasm_std::{
+    to_json_binary, Addr, Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response, Storage,
+};
 use error_stack::ResultExt;
 
 use crate::error::ContractError;
 use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
-use crate::state::{Config, CONFIG};
+use crate::state::{self, Config, CONFIG};
 
 mod execute;
 mod migrations;
@@ -17,13 +19,29 @@ pub use migrations::{migrate, MigrateMsg};
 
 pub const START_MULTISIG_REPLY_ID: u64 = 1;
 
+/// Submessages dispatched by this contract that expect a reply
+enum ReplyId {
+    StartMultisig,
+}
+
+impl TryFrom<u64> for ReplyId {
+    type Error = ();
+
+    fn try_from(id: u64) -> Result<Self, Self::Error> {
+        match id {
+            START_MULTISIG_REPLY_ID => Ok(ReplyId::StartMultisig),
+            _ => Err(()),
+        }
+    }
+}
+
 const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     _info: MessageInfo,
     msg: InstantiateMsg,
 ) -> Result<Response, axelar_wasm_std::error::ContractError> {
@@ -47,10 +65,13 @@ pub fn instantiate(
         verifier_set_diff_threshold: msg.verifier_set_diff_threshold,
         encoder: msg.encoder,
         key_type: msg.key_type,
-        domain_separator: msg.domain_separator,
+        proof_validity: msg.proof_validity,
+        min_signers: msg.min_signers,
     };
     CONFIG.save(deps.storage, &config)?;
 
+    state::save_domain_separator(deps.storage, msg.domain_separator, env.block.height)?;
+
     permission_control::set_admin(
         deps.storage,
         &address::validate_cosmwasm_address(deps.api, &msg.admin_address)?,
@@ -70,8 +91,16 @@ pub fn execute(
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, axelar_wasm_std::error::ContractError> {
-    match msg.ensure_permissions(deps.storage, &info.sender)? {
-        ExecuteMsg::ConstructProof(message_ids) => Ok(execute::construct_proof(deps, message_ids)?),
+    match msg.ensure_permissions(deps.storage, &info.sender, match_coordinator)? {
+        ExecuteMsg::ConstructProof(message_ids) => {
+            Ok(execute::construct_proof(deps, env, message_ids, None)?)
+        }
+        ExecuteMsg::ConstructProofWithHints { message_ids, hints } => Ok(execute::construct_proof(
+            deps,
+            env,
+            message_ids,
+            Some(hints),
+        )?),
         ExecuteMsg::UpdateVerifierSet {} => Ok(execute::update_verifier_set(deps, env)?),
         ExecuteMsg::ConfirmVerifierSet {} => Ok(execute::confirm_verifier_set(deps, info.sender)?),
         ExecuteMsg::UpdateSigningThreshold {
@@ -80,21 +109,53 @@ pub fn execute(
             deps,
             new_signing_threshold,
         )?),
+        ExecuteMsg::UpdateMinSigners { min_signers } => {
+            Ok(execute::update_min_signers(deps, min_signers)?)
+        }
         ExecuteMsg::UpdateAdmin { new_admin_address } => {
             Ok(execute::update_admin(deps, new_admin_address)?)
         }
+        ExecuteMsg::UpdateProofSignatures {
+            multisig_session_id,
+        } => Ok(execute::update_proof_signatures(
+            deps,
+            env,
+            multisig_session_id,
+        )?),
+        ExecuteMsg::ResignProof {
+            multisig_session_id,
+        } => Ok(execute::resign_proof(deps, env, multisig_session_id)?),
+        ExecuteMsg::AbortProof {
+            multisig_session_id,
+            reason,
+        } => Ok(execute::abort_proof(deps, multisig_session_id, reason)?),
+        ExecuteMsg::RotateDomainSeparator { domain_separator } => Ok(
+            execute::rotate_domain_separator(deps, env, domain_separator)?,
+        ),
+        ExecuteMsg::SetMaintenanceWindows { windows } => {
+            Ok(execute::set_maintenance_windows(deps, windows)?)
+        }
     }
 }
 
+fn match_coordinator(
+    storage: &dyn Storage,
+    _: &ExecuteMsg,
+) -> error_stack::Result<Addr, ContractError> {
+    Ok(CONFIG
+        .load(storage)
+        .map_err(ContractError::from)?
+        .coordinator)
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn reply(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     reply: Reply,
 ) -> Result<Response, axelar_wasm_std::error::ContractError> {
-    match reply.id {
-        START_MULTISIG_REPLY_ID => reply::start_multisig_reply(deps, reply),
-        _ => unreachable!("unknown reply ID"),
+    match axelar_wasm_std::reply::route(&reply)? {
+        ReplyId::StartMultisig => reply::start_multisig_reply(deps, env, reply),
     }
     .map_err(axelar_wasm_std::error::ContractError::from)
 }
@@ -102,15 +163,29 @@ pub fn reply(
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(
     deps: Deps,
-    _env: Env,
+    env: Env,
     msg: QueryMsg,
 ) -> Result<Binary, axelar_wasm_std::error::ContractError> {
     match msg {
         QueryMsg::Proof {
             multisig_session_id,
-        } => to_json_binary(&query::proof(deps, multisig_session_id)?),
+        } => to_json_binary(&query::proof(deps, env, multisig_session_id)?),
+        QueryMsg::BestAvailableProof {
+            multisig_session_id,
+        } => to_json_binary(&query::best_available_proof(
+            deps,
+            env,
+            multisig_session_id,
+        )?),
+        QueryMsg::ProofSignatures {
+            multisig_session_id,
+        } => to_json_binary(&query::proof_signatures(deps, multisig_session_id)?),
         QueryMsg::CurrentVerifierSet {} => to_json_binary(&query::current_verifier_set(deps)?),
         QueryMsg::NextVerifierSet {} => to_json_binary(&query::next_verifier_set(deps)?),
+        QueryMsg::DomainSeparators {} => to_json_binary(&query::domain_separators(deps)?),
+        QueryMsg::UnsignedMessages { start_after, limit } => {
+            to_json_binary(&query::unsigned_messages(deps, env, start_after, limit)?)
+        }
     }
     .change_context(ContractError::SerializeResponse)
     .map_err(axelar_wasm_std::error::ContractError::from)
@@ -118,13 +193,16 @@ pub fn query(
 
 #[cfg(test)]
 mod tests {
+    use axelar_wasm_std::maintenance::MaintenanceWindow;
     use axelar_wasm_std::permission_control::Permission;
+    use axelar_wasm_std::time::Timestamp;
     use axelar_wasm_std::{permission_control, MajorityThreshold, Threshold, VerificationStatus};
     use cosmwasm_std::testing::{
         message_info, mock_dependencies, mock_env, MockApi, MockQuerier, MockStorage,
     };
     use cosmwasm_std::{
-        from_json, Addr, Empty, Fraction, OwnedDeps, SubMsgResponse, SubMsgResult, Uint128, Uint64,
+        from_json, Addr, CosmosMsg, Empty, Fraction, OwnedDeps, StdResult, SubMsgResponse,
+        SubMsgResult, Uint128, Uint64, WasmMsg,
     };
     use multisig::msg::Signer;
     use multisig::verifier_set::VerifierSet;
@@ -134,7 +212,10 @@ mod tests {
 
     use super::*;
     use crate::contract::execute::should_update_verifier_set;
-    use crate::msg::{ProofResponse, ProofStatus, VerifierSetResponse};
+    use crate::msg::{
+        DeliveryHints, DomainSeparatorsResponse, ProofResponse, ProofSignatures, ProofStatus,
+        UnsignedMessage, UnsignedMessagesResponse, VerifierSetResponse,
+    };
     use crate::test::test_data::{self, TestOperator};
     use crate::test::test_utils::{
         mock_querier_handler, ADMIN, COORDINATOR_ADDRESS, GATEWAY_ADDRESS, GOVERNANCE,
@@ -172,6 +253,8 @@ mod tests {
                 encoder: Encoder::Abi,
                 key_type: multisig::key::KeyType::Ecdsa,
                 domain_separator: [0; 32],
+                proof_validity: None,
+                min_signers: None,
             },
         )
         .unwrap();
@@ -210,6 +293,15 @@ mod tests {
         execute(deps, mock_env(), message_info(&sender, &[]), msg)
     }
 
+    fn execute_update_min_signers(
+        deps: DepsMut,
+        sender: Addr,
+        min_signers: Option<u16>,
+    ) -> Result<Response, axelar_wasm_std::error::ContractError> {
+        let msg = ExecuteMsg::UpdateMinSigners { min_signers };
+        execute(deps, mock_env(), message_info(&sender, &[]), msg)
+    }
+
     fn execute_update_admin(
         deps: DepsMut,
         sender: Addr,
@@ -242,7 +334,15 @@ mod tests {
     fn reply_construct_proof(
         deps: DepsMut,
     ) -> Result<Response, axelar_wasm_std::error::ContractError> {
-        let session_id = to_json_binary(&MULTISIG_SESSION_ID).unwrap();
+        reply_start_multisig(deps, mock_env(), MULTISIG_SESSION_ID)
+    }
+
+    fn reply_start_multisig(
+        deps: DepsMut,
+        env: Env,
+        multisig_session_id: Uint64,
+    ) -> Result<Response, axelar_wasm_std::error::ContractError> {
+        let session_id = to_json_binary(&multisig_session_id).unwrap();
 
         #[allow(deprecated)]
         // TODO: use `msg_responses` instead when the cosmwasm vm is updated to 2.x.x
@@ -259,7 +359,7 @@ mod tests {
 
         reply(
             deps,
-            mock_env(),
+            env,
             Reply {
                 id: START_MULTISIG_REPLY_ID,
                 result: SubMsgResult::Ok(response),
@@ -325,7 +425,11 @@ mod tests {
         .try_into()
         .unwrap();
         let service_name = "service_name";
-        for encoding in [Encoder::Abi, Encoder::Bcs] {
+        for encoding in [
+            Encoder::Abi,
+            Encoder::Bcs,
+            Encoder::LegacyAbi { chain_id: 1 },
+        ] {
             let mut deps = mock_dependencies();
             let info = message_info(&instantiator, &[]);
             let env = mock_env();
@@ -345,6 +449,8 @@ mod tests {
                 encoder: encoding,
                 key_type: multisig::key::KeyType::Ecdsa,
                 domain_separator: [0; 32],
+                proof_validity: None,
+                min_signers: None,
             };
 
             let res = instantiate(deps.as_mut(), env, info, msg);
@@ -396,6 +502,7 @@ mod tests {
                 .collect(),
             threshold: quorum,
             created_at: nonce,
+            min_signers: None,
         }
     }
 
@@ -420,6 +527,73 @@ mod tests {
         assert_eq!(verifier_set, expected_verifier_set.into());
     }
 
+    #[test]
+    fn update_verifier_set_should_apply_min_signers() {
+        let mut deps = setup_test_case();
+        let num_operators = u16::try_from(test_data::operators().len()).unwrap();
+
+        CONFIG
+            .update(deps.as_mut().storage, |mut config| -> StdResult<_> {
+                config.min_signers = Some(num_operators.saturating_add(1));
+                Ok(config)
+            })
+            .unwrap();
+        assert!(execute_update_verifier_set(deps.as_mut()).is_err());
+
+        CONFIG
+            .update(deps.as_mut().storage, |mut config| -> StdResult<_> {
+                config.min_signers = Some(num_operators);
+                Ok(config)
+            })
+            .unwrap();
+        execute_update_verifier_set(deps.as_mut()).unwrap();
+
+        let verifier_set = query_verifier_set(deps.as_ref()).unwrap().unwrap();
+        let expected_verifier_set =
+            test_operators_to_verifier_set(test_data::operators(), mock_env().block.height)
+                .with_min_signers(Some(num_operators));
+
+        assert_eq!(verifier_set, expected_verifier_set.into());
+    }
+
+    #[test]
+    fn governance_should_be_able_to_update_min_signers() {
+        let mut deps = setup_test_case();
+        let api = deps.api;
+        let num_operators = u16::try_from(test_data::operators().len()).unwrap();
+
+        execute_update_verifier_set(deps.as_mut()).unwrap();
+
+        assert!(execute_update_min_signers(
+            deps.as_mut(),
+            api.addr_make("random"),
+            Some(num_operators)
+        )
+        .is_err());
+        execute_update_min_signers(
+            deps.as_mut(),
+            api.addr_make(GOVERNANCE),
+            Some(num_operators),
+        )
+        .unwrap();
+
+        // the verifier set in use doesn't change until the update is confirmed
+        let verifier_set = query_verifier_set(deps.as_ref())
+            .unwrap()
+            .unwrap()
+            .verifier_set;
+        assert_eq!(verifier_set.min_signers, None);
+
+        execute_update_verifier_set(deps.as_mut()).unwrap();
+        confirm_verifier_set(deps.as_mut(), api.addr_make(GOVERNANCE)).unwrap();
+
+        let verifier_set = query_verifier_set(deps.as_ref())
+            .unwrap()
+            .unwrap()
+            .verifier_set;
+        assert_eq!(verifier_set.min_signers, Some(num_operators));
+    }
+
     #[test]
     fn test_update_verifier_set_from_non_admin_or_governance_should_fail() {
         let mut deps = setup_test_case();
@@ -642,6 +816,68 @@ mod tests {
         );
     }
 
+    #[test]
+    fn verifier_set_rotations_should_emit_diff_events() {
+        let mut deps = setup_test_case();
+        let api = deps.api;
+
+        let attribute = |res: &Response, key: &str| -> serde_json::Value {
+            let event = res
+                .events
+                .iter()
+                .find(|event| event.ty == "verifier_set_rotated")
+                .unwrap();
+            let value = &event
+                .attributes
+                .iter()
+                .find(|attribute| attribute.key == key)
+                .unwrap()
+                .value;
+            serde_json::from_str(value).unwrap()
+        };
+
+        let res = execute_update_verifier_set(deps.as_mut()).unwrap();
+        let initial_verifier_set = query_verifier_set(deps.as_ref())
+            .unwrap()
+            .unwrap()
+            .verifier_set;
+        assert_eq!(
+            attribute(&res, "previous_verifier_set_id"),
+            serde_json::Value::Null
+        );
+        assert_eq!(
+            attribute(&res, "added_signers").as_array().unwrap().len(),
+            initial_verifier_set.signers.len()
+        );
+
+        let mut new_verifier_set = test_data::operators();
+        let removed = new_verifier_set.pop().unwrap();
+        deps.querier.update_wasm(mock_querier_handler(
+            new_verifier_set,
+            VerificationStatus::SucceededOnSourceChain,
+        ));
+        let res = execute_update_verifier_set(deps.as_mut()).unwrap();
+        assert!(!res
+            .events
+            .iter()
+            .any(|event| event.ty == "verifier_set_rotated"));
+
+        let res = confirm_verifier_set(deps.as_mut(), api.addr_make("relayer")).unwrap();
+        assert_eq!(
+            attribute(&res, "previous_verifier_set_id"),
+            serde_json::Value::from(initial_verifier_set.id())
+        );
+        assert_eq!(
+            attribute(&res, "removed_signers"),
+            serde_json::json!([{
+                "signer": removed.address,
+                "weight": removed.weight,
+            }])
+        );
+        assert_eq!(attribute(&res, "added_signers"), serde_json::json!([]));
+        assert_eq!(attribute(&res, "weight_changes"), serde_json::json!([]));
+    }
+
     #[test]
     fn confirm_verifier_no_update_in_progress_should_fail() {
         let mut deps = setup_test_case();
@@ -682,6 +918,64 @@ mod tests {
         assert!(event.is_some());
     }
 
+    #[test]
+    fn proof_events_should_include_delivery_hints() {
+        let mut deps = setup_test_case();
+        execute_update_verifier_set(deps.as_mut()).unwrap();
+
+        let message_ids = test_data::messages()
+            .into_iter()
+            .map(|msg| msg.cc_id)
+            .collect::<Vec<CrossChainId>>();
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&MockApi::default().addr_make(RELAYER), &[]),
+            ExecuteMsg::ConstructProofWithHints {
+                message_ids,
+                hints: DeliveryHints {
+                    priority: Some(5),
+                    execute_by: Some(Timestamp::from_seconds(1000)),
+                },
+            },
+        )
+        .unwrap();
+        let res = reply_construct_proof(deps.as_mut()).unwrap();
+
+        let attribute = |res: &Response, ty: &str, key: &str| {
+            res.events
+                .iter()
+                .find(|event| event.ty == ty)
+                .and_then(|event| event.attributes.iter().find(|attr| attr.key == key))
+                .map(|attr| attr.value.clone())
+                .unwrap()
+        };
+        assert_eq!(attribute(&res, "proof_under_construction", "priority"), "5");
+        assert_eq!(
+            attribute(&res, "proof_under_construction", "execute_by"),
+            "\"1000000000000\""
+        );
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&MockApi::default().addr_make(RELAYER), &[]),
+            ExecuteMsg::UpdateProofSignatures {
+                multisig_session_id: MULTISIG_SESSION_ID,
+            },
+        )
+        .unwrap();
+        assert_eq!(attribute(&res, "proof_signatures_updated", "priority"), "5");
+
+        // hints are not carried over to proofs constructed without them
+        execute_construct_proof(deps.as_mut(), None).unwrap();
+        let res = reply_start_multisig(deps.as_mut(), mock_env(), Uint64::new(2)).unwrap();
+        assert_eq!(
+            attribute(&res, "proof_under_construction", "priority"),
+            "null"
+        );
+    }
+
     #[test]
     fn test_query_proof() {
         let mut deps = setup_test_case();
@@ -701,6 +995,427 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_query_best_available_proof() {
+        let mut deps = setup_test_case();
+        execute_update_verifier_set(deps.as_mut()).unwrap();
+        execute_construct_proof(deps.as_mut(), None).unwrap();
+        reply_construct_proof(deps.as_mut()).unwrap(); // simulate reply from multisig
+
+        let res: ProofResponse = from_json(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::BestAvailableProof {
+                    multisig_session_id: MULTISIG_SESSION_ID,
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(res.multisig_session_id, MULTISIG_SESSION_ID);
+        assert_eq!(res.message_ids.len(), 1);
+        match res.status {
+            ProofStatus::Completed { execute_data } => {
+                // the mocked session collected exactly the quorum, so all signatures are included
+                assert_eq!(execute_data, test_data::approve_messages_calldata());
+            }
+            _ => panic!("Expected proof status to be completed"),
+        }
+    }
+
+    #[test]
+    fn update_proof_signatures_should_record_signature_accumulation() {
+        let mut deps = setup_test_case();
+        execute_update_verifier_set(deps.as_mut()).unwrap();
+        execute_construct_proof(deps.as_mut(), None).unwrap();
+        reply_construct_proof(deps.as_mut()).unwrap(); // simulate reply from multisig
+
+        let update_proof_signatures = |deps: DepsMut| {
+            execute(
+                deps,
+                mock_env(),
+                message_info(&MockApi::default().addr_make(RELAYER), &[]),
+                ExecuteMsg::UpdateProofSignatures {
+                    multisig_session_id: MULTISIG_SESSION_ID,
+                },
+            )
+        };
+        let query_proof_signatures = |deps: Deps| -> Option<ProofSignatures> {
+            from_json(
+                query(
+                    deps,
+                    mock_env(),
+                    QueryMsg::ProofSignatures {
+                        multisig_session_id: MULTISIG_SESSION_ID,
+                    },
+                )
+                .unwrap(),
+            )
+            .unwrap()
+        };
+
+        assert_eq!(query_proof_signatures(deps.as_ref()), None);
+
+        let res = update_proof_signatures(deps.as_mut()).unwrap();
+        assert!(res
+            .events
+            .iter()
+            .any(|event| event.ty == "proof_signatures_updated"));
+        assert_eq!(
+            query_proof_signatures(deps.as_ref()),
+            Some(ProofSignatures {
+                signature_count: 3,
+                signed_weight: Uint128::from(3u128),
+                updated_at: mock_env().block.height,
+                min_signers: None,
+            })
+        );
+
+        // no new signatures arrived, so nothing changes
+        let res = update_proof_signatures(deps.as_mut()).unwrap();
+        assert!(res.events.is_empty());
+    }
+
+    #[test]
+    fn update_proof_signatures_should_fail_for_unknown_session() {
+        let mut deps = setup_test_case();
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&MockApi::default().addr_make(RELAYER), &[]),
+            ExecuteMsg::UpdateProofSignatures {
+                multisig_session_id: MULTISIG_SESSION_ID,
+            },
+        );
+
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            axelar_wasm_std::error::ContractError::from(ContractError::UnknownMultisigSession {
+                multisig_session_id: MULTISIG_SESSION_ID
+            })
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn abort_proof_should_abort_multisig_session() {
+        let mut deps = setup_test_case();
+        execute_update_verifier_set(deps.as_mut()).unwrap();
+        execute_construct_proof(deps.as_mut(), None).unwrap();
+        reply_construct_proof(deps.as_mut()).unwrap();
+
+        let abort_proof = |deps: DepsMut, sender: &str, multisig_session_id: Uint64| {
+            execute(
+                deps,
+                mock_env(),
+                message_info(&MockApi::default().addr_make(sender), &[]),
+                ExecuteMsg::AbortProof {
+                    multisig_session_id,
+                    reason: "payload is obsolete".try_into().unwrap(),
+                },
+            )
+        };
+
+        assert!(abort_proof(deps.as_mut(), RELAYER, MULTISIG_SESSION_ID).is_err());
+
+        let unknown_session_id = Uint64::from(2u64);
+        let res = abort_proof(deps.as_mut(), ADMIN, unknown_session_id);
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            axelar_wasm_std::error::ContractError::from(ContractError::UnknownMultisigSession {
+                multisig_session_id: unknown_session_id
+            })
+            .to_string()
+        );
+
+        let res = abort_proof(deps.as_mut(), ADMIN, MULTISIG_SESSION_ID).unwrap();
+        assert_eq!(res.messages.len(), 1);
+        assert_eq!(
+            res.messages[0].msg,
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: MockApi::default().addr_make(MULTISIG_ADDRESS).to_string(),
+                msg: to_json_binary(&multisig::msg::ExecuteMsg::AbortSigningSession {
+                    session_id: MULTISIG_SESSION_ID,
+                    reason: "payload is obsolete".try_into().unwrap(),
+                })
+                .unwrap(),
+                funds: vec![],
+            })
+        );
+    }
+
+    #[test]
+    fn stale_proof_should_be_resignable() {
+        let mut deps = setup_test_case();
+        CONFIG
+            .update(deps.as_mut().storage, |mut config| -> StdResult<_> {
+                config.proof_validity = Some(10);
+                Ok(config)
+            })
+            .unwrap();
+        execute_update_verifier_set(deps.as_mut()).unwrap();
+        execute_construct_proof(deps.as_mut(), None).unwrap();
+        reply_construct_proof(deps.as_mut()).unwrap();
+
+        let mut env = mock_env();
+        let resign_proof = |deps: DepsMut, env: Env| {
+            execute(
+                deps,
+                env,
+                message_info(&MockApi::default().addr_make(RELAYER), &[]),
+                ExecuteMsg::ResignProof {
+                    multisig_session_id: MULTISIG_SESSION_ID,
+                },
+            )
+        };
+
+        let res = resign_proof(deps.as_mut(), env.clone());
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            axelar_wasm_std::error::ContractError::from(ContractError::ProofNotStale {
+                multisig_session_id: MULTISIG_SESSION_ID
+            })
+            .to_string()
+        );
+
+        env.block.height += 10;
+        let proof: ProofResponse = from_json(
+            query(
+                deps.as_ref(),
+                env.clone(),
+                QueryMsg::Proof {
+                    multisig_session_id: MULTISIG_SESSION_ID,
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            proof.status,
+            ProofStatus::Stale {
+                superseded_by: None
+            }
+        );
+
+        let res = resign_proof(deps.as_mut(), env.clone()).unwrap();
+        assert_eq!(res.messages.len(), 1);
+        assert_eq!(res.messages[0].id, START_MULTISIG_REPLY_ID);
+
+        let new_session_id = Uint64::new(2);
+        let res = reply_start_multisig(deps.as_mut(), env.clone(), new_session_id).unwrap();
+        let event = res
+            .events
+            .iter()
+            .find(|event| event.ty == "proof_resigned")
+            .unwrap();
+        assert!(event
+            .attributes
+            .iter()
+            .any(|attribute| attribute.key == "previous_multisig_session_id"
+                && attribute.value.contains(&MULTISIG_SESSION_ID.to_string())));
+
+        let proof: ProofResponse = from_json(
+            query(
+                deps.as_ref(),
+                env.clone(),
+                QueryMsg::Proof {
+                    multisig_session_id: MULTISIG_SESSION_ID,
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            proof.status,
+            ProofStatus::Stale {
+                superseded_by: Some(new_session_id)
+            }
+        );
+
+        let new_proof: ProofResponse = from_json(
+            query(
+                deps.as_ref(),
+                env.clone(),
+                QueryMsg::Proof {
+                    multisig_session_id: new_session_id,
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(new_proof.payload, proof.payload);
+        assert!(matches!(new_proof.status, ProofStatus::Completed { .. }));
+
+        let res = resign_proof(deps.as_mut(), env);
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            axelar_wasm_std::error::ContractError::from(ContractError::ProofAlreadyResigned {
+                multisig_session_id: MULTISIG_SESSION_ID,
+                superseded_by: new_session_id,
+            })
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn proof_without_validity_window_should_not_be_resignable() {
+        let mut deps = setup_test_case();
+        execute_update_verifier_set(deps.as_mut()).unwrap();
+        execute_construct_proof(deps.as_mut(), None).unwrap();
+        reply_construct_proof(deps.as_mut()).unwrap();
+
+        let mut env = mock_env();
+        env.block.height = u64::MAX;
+
+        let res = execute(
+            deps.as_mut(),
+            env,
+            message_info(&MockApi::default().addr_make(RELAYER), &[]),
+            ExecuteMsg::ResignProof {
+                multisig_session_id: MULTISIG_SESSION_ID,
+            },
+        );
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            axelar_wasm_std::error::ContractError::from(ContractError::ProofNotStale {
+                multisig_session_id: MULTISIG_SESSION_ID
+            })
+            .to_string()
+        );
+    }
+
+    #[test]
+    #[allow(clippy::arithmetic_side_effects)]
+    fn construct_proof_should_fail_while_chain_is_under_maintenance() {
+        let mut deps = setup_test_case();
+        let api = deps.api;
+        execute_update_verifier_set(deps.as_mut()).unwrap();
+
+        // the coordinator sends its own message type, which must deserialize into the prover's
+        let height = mock_env().block.height;
+        let msg: ExecuteMsg = from_json(
+            to_json_binary(
+                &coordinator::msg::MaintenanceExecuteMsg::SetMaintenanceWindows {
+                    windows: vec![MaintenanceWindow::new(height, height + 10).unwrap()],
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert!(execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(GOVERNANCE), &[]),
+            msg.clone(),
+        )
+        .is_err());
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(COORDINATOR_ADDRESS), &[]),
+            msg,
+        )
+        .unwrap();
+
+        let res = execute_construct_proof(deps.as_mut(), None);
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            axelar_wasm_std::error::ContractError::from(ContractError::ChainUnderMaintenance(
+                "ganache-0".parse().unwrap()
+            ))
+            .to_string()
+        );
+
+        let mut env = mock_env();
+        env.block.height = height + 10;
+        assert!(execute(
+            deps.as_mut(),
+            env,
+            message_info(&api.addr_make(RELAYER), &[]),
+            ExecuteMsg::ConstructProof(
+                test_data::messages()
+                    .into_iter()
+                    .map(|msg| msg.cc_id)
+                    .collect()
+            ),
+        )
+        .is_ok());
+    }
+
+    #[test]
+    #[allow(clippy::arithmetic_side_effects)]
+    fn resign_proof_should_fail_while_chain_is_under_maintenance() {
+        let mut deps = setup_test_case();
+        let api = deps.api;
+        CONFIG
+            .update(deps.as_mut().storage, |mut config| -> StdResult<_> {
+                config.proof_validity = Some(10);
+                Ok(config)
+            })
+            .unwrap();
+        execute_update_verifier_set(deps.as_mut()).unwrap();
+        execute_construct_proof(deps.as_mut(), None).unwrap();
+        reply_construct_proof(deps.as_mut()).unwrap();
+
+        let height = mock_env().block.height;
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(COORDINATOR_ADDRESS), &[]),
+            ExecuteMsg::SetMaintenanceWindows {
+                windows: vec![MaintenanceWindow::new(height + 10, height + 20).unwrap()],
+            },
+        )
+        .unwrap();
+
+        let resign_proof = |deps: DepsMut, height: u64| {
+            let mut env = mock_env();
+            env.block.height = height;
+            execute(
+                deps,
+                env,
+                message_info(&api.addr_make(RELAYER), &[]),
+                ExecuteMsg::ResignProof {
+                    multisig_session_id: MULTISIG_SESSION_ID,
+                },
+            )
+        };
+
+        // the proof is stale, but the chain is under maintenance
+        let res = resign_proof(deps.as_mut(), height + 10);
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            axelar_wasm_std::error::ContractError::from(ContractError::ChainUnderMaintenance(
+                "ganache-0".parse().unwrap()
+            ))
+            .to_string()
+        );
+
+        assert!(resign_proof(deps.as_mut(), height + 20).is_ok());
+    }
+
+    #[test]
+    fn construct_proof_should_fail_if_gateway_leaves_out_all_messages() {
+        let mut deps = setup_test_case();
+        execute_update_verifier_set(deps.as_mut()).unwrap();
+
+        // the gateway only returns messages that can still be approved, e.g. unexpired ones
+        let res = execute_construct_proof(
+            deps.as_mut(),
+            Some(vec![
+                CrossChainId::new("ganache-1", "expired-message").unwrap()
+            ]),
+        );
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            axelar_wasm_std::error::ContractError::from(ContractError::NoMessagesToProve)
+                .to_string()
+        );
+    }
+
     #[test]
     fn test_construct_proof_no_verifier_set() {
         let mut deps = setup_test_case();
@@ -934,4 +1649,153 @@ mod tests {
             Permission::NoPrivilege.into()
         );
     }
+
+    fn execute_rotate_domain_separator(
+        deps: DepsMut,
+        sender: Addr,
+        domain_separator: [u8; 32],
+    ) -> Result<Response, axelar_wasm_std::error::ContractError> {
+        let msg = ExecuteMsg::RotateDomainSeparator { domain_separator };
+        execute(deps, mock_env(), message_info(&sender, &[]), msg)
+    }
+
+    fn query_domain_separators(deps: Deps) -> DomainSeparatorsResponse {
+        from_json(query(deps, mock_env(), QueryMsg::DomainSeparators {}).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn non_governance_should_not_be_able_to_rotate_domain_separator() {
+        let mut deps = setup_test_case();
+        let api = deps.api;
+
+        let res = execute_rotate_domain_separator(deps.as_mut(), api.addr_make(ADMIN), [1; 32]);
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn rotating_to_the_active_domain_separator_should_fail() {
+        let mut deps = setup_test_case();
+        let api = deps.api;
+
+        let res =
+            execute_rotate_domain_separator(deps.as_mut(), api.addr_make(GOVERNANCE), [0; 32]);
+        assert!(res
+            .unwrap_err()
+            .to_string()
+            .contains(&ContractError::DomainSeparatorUnchanged.to_string()));
+    }
+
+    #[test]
+    fn rotated_domain_separator_should_be_queryable_with_its_history() {
+        let mut deps = setup_test_case();
+        let api = deps.api;
+
+        let initial = query_domain_separators(deps.as_ref());
+        assert_eq!(initial.current.version, 0);
+        assert_eq!(initial.current.domain_separator, [0; 32]);
+        assert_eq!(initial.history, vec![initial.current.clone()]);
+
+        let res =
+            execute_rotate_domain_separator(deps.as_mut(), api.addr_make(GOVERNANCE), [1; 32])
+                .unwrap();
+        assert!(res
+            .events
+            .iter()
+            .any(|event| event.ty == "domain_separator_rotated"));
+
+        let rotated = query_domain_separators(deps.as_ref());
+        assert_eq!(rotated.current.version, 1);
+        assert_eq!(rotated.current.domain_separator, [1; 32]);
+        assert_eq!(
+            rotated.history,
+            vec![initial.current, rotated.current.clone()]
+        );
+    }
+
+    #[test]
+    fn in_flight_sessions_should_keep_their_domain_separator_after_rotation() {
+        let mut deps = setup_test_case();
+        let api = deps.api;
+
+        execute_update_verifier_set(deps.as_mut()).unwrap();
+        execute_construct_proof(deps.as_mut(), None).unwrap();
+        reply_construct_proof(deps.as_mut()).unwrap();
+
+        execute_rotate_domain_separator(deps.as_mut(), api.addr_make(GOVERNANCE), [1; 32]).unwrap();
+
+        let execute_data = |res: ProofResponse| match res.status {
+            ProofStatus::Completed { execute_data } => execute_data,
+            _ => panic!("Expected proof status to be completed"),
+        };
+
+        // the session started before the rotation still uses the initial domain separator
+        assert_eq!(
+            execute_data(query_proof(deps.as_ref(), None).unwrap()),
+            test_data::approve_messages_calldata()
+        );
+
+        // sessions started after the rotation use the new domain separator
+        let new_session_id = Uint64::new(2);
+        execute_construct_proof(deps.as_mut(), None).unwrap();
+        reply_start_multisig(deps.as_mut(), mock_env(), new_session_id).unwrap();
+
+        let session_domain_separator =
+            |session_id: Uint64| state::session_domain_separator(&deps.storage, session_id.u64());
+        assert_eq!(
+            session_domain_separator(MULTISIG_SESSION_ID)
+                .unwrap()
+                .domain_separator,
+            [0; 32]
+        );
+        assert_eq!(
+            session_domain_separator(new_session_id)
+                .unwrap()
+                .domain_separator,
+            [1; 32]
+        );
+    }
+
+    #[test]
+    fn unsigned_messages_should_exclude_messages_in_signing_sessions() {
+        let mut deps = setup_test_case();
+        let mut env = mock_env();
+        env.block.height = 11;
+
+        let query_unsigned_messages = |deps: Deps, env: Env| -> UnsignedMessagesResponse {
+            from_json(
+                query(
+                    deps,
+                    env,
+                    QueryMsg::UnsignedMessages {
+                        start_after: None,
+                        limit: Some(1),
+                    },
+                )
+                .unwrap(),
+            )
+            .unwrap()
+        };
+
+        let res = query_unsigned_messages(deps.as_ref(), env.clone());
+        let message = test_data::messages().remove(0);
+        assert_eq!(
+            res,
+            UnsignedMessagesResponse {
+                messages: vec![UnsignedMessage {
+                    message: message.clone(),
+                    routed_at: 1,
+                    age: 10,
+                }],
+                next_start_after: Some(message.cc_id.clone()),
+            }
+        );
+
+        execute_update_verifier_set(deps.as_mut()).unwrap();
+        execute_construct_proof(deps.as_mut(), None).unwrap();
+        reply_construct_proof(deps.as_mut()).unwrap();
+
+        let res = query_unsigned_messages(deps.as_ref(), env);
+        assert!(res.messages.is_empty());
+        assert_eq!(res.next_start_after, Some(message.cc_id));
+    }
 }
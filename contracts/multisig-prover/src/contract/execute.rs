@@ -1,31 +1,41 @@
 use std::collections::{BTreeMap, HashSet};
 
+use axelar_wasm_std::hash::Hash;
+use axelar_wasm_std::maintenance::{self, MaintenanceWindow};
 use axelar_wasm_std::permission_control::Permission;
 use axelar_wasm_std::snapshot::{Participant, Snapshot};
 use axelar_wasm_std::{
     address, nonempty, permission_control, FnExt, MajorityThreshold, VerificationStatus,
 };
-use cosmwasm_std::{wasm_execute, Addr, DepsMut, Env, QuerierWrapper, Response, Storage, SubMsg};
+use cosmwasm_std::{
+    wasm_execute, Addr, DepsMut, Env, HexBinary, QuerierWrapper, Response, Storage, SubMsg, Uint64,
+};
 use error_stack::{report, Result, ResultExt};
 use itertools::Itertools;
 use multisig::msg::Signer;
+use multisig::types::MultisigState;
 use multisig::verifier_set::VerifierSet;
 use router_api::{ChainName, CrossChainId, Message};
-use service_registry_api::WeightedVerifier;
 
 use crate::contract::START_MULTISIG_REPLY_ID;
 use crate::encoding::EncoderExt;
 use crate::error::ContractError;
+use crate::events::Event;
 use crate::state::{
-    Config, CONFIG, CURRENT_VERIFIER_SET, NEXT_VERIFIER_SET, PAYLOAD, REPLY_TRACKER,
+    self, Config, DeliveryHints, ProofSignatures, CONFIG, CURRENT_VERIFIER_SET, HINTS_TRACKER,
+    MAINTENANCE_WINDOWS, MULTISIG_SESSION_PAYLOAD, NEXT_VERIFIER_SET, PAYLOAD, PROOF_EXPIRY,
+    PROOF_HINTS, PROOF_SIGNATURES, REPLY_TRACKER, RESIGN_TRACKER, SUPERSEDED_PROOFS,
 };
 use crate::Payload;
 
 pub fn construct_proof(
     deps: DepsMut,
+    env: Env,
     message_ids: Vec<CrossChainId>,
+    hints: Option<DeliveryHints>,
 ) -> error_stack::Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage).map_err(ContractError::from)?;
+    ensure_not_under_maintenance(deps.storage, &env, &config)?;
 
     let messages = messages(
         deps.querier,
@@ -54,19 +64,23 @@ pub fn construct_proof(
         }
     };
 
-    // keep track of the payload id to use during submessage reply
+    // keep track of the payload id and the delivery hints to use during submessage reply
     REPLY_TRACKER
         .save(deps.storage, &payload_id)
         .map_err(ContractError::from)?;
+    save_hints_tracker(deps.storage, hints)?;
 
     let verifier_set = CURRENT_VERIFIER_SET
         .may_load(deps.storage)
         .map_err(ContractError::from)?
         .ok_or(ContractError::NoVerifierSet)?;
 
-    let digest = config
-        .encoder
-        .digest(&config.domain_separator, &verifier_set, &payload)?;
+    let domain_separator = state::current_domain_separator(deps.storage)?;
+
+    let digest =
+        config
+            .encoder
+            .digest(&domain_separator.domain_separator, &verifier_set, &payload)?;
 
     let start_sig_msg = multisig::msg::ExecuteMsg::StartSigningSession {
         verifier_set_id: verifier_set.id(),
@@ -81,6 +95,104 @@ pub fn construct_proof(
     Ok(Response::new().add_submessage(SubMsg::reply_on_success(wasm_msg, START_MULTISIG_REPLY_ID)))
 }
 
+pub fn resign_proof(
+    deps: DepsMut,
+    env: Env,
+    multisig_session_id: Uint64,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage).map_err(ContractError::from)?;
+    ensure_not_under_maintenance(deps.storage, &env, &config)?;
+
+    let payload_id = MULTISIG_SESSION_PAYLOAD
+        .may_load(deps.storage, multisig_session_id.u64())
+        .change_context(ContractError::StorageError)?
+        .ok_or(ContractError::UnknownMultisigSession {
+            multisig_session_id,
+        })?;
+
+    let is_stale = PROOF_EXPIRY
+        .may_load(deps.storage, multisig_session_id.u64())
+        .change_context(ContractError::StorageError)?
+        .is_some_and(|expires_at| env.block.height >= expires_at);
+    if !is_stale {
+        return Err(report!(ContractError::ProofNotStale {
+            multisig_session_id
+        }));
+    }
+
+    if let Some(superseded_by) = SUPERSEDED_PROOFS
+        .may_load(deps.storage, multisig_session_id.u64())
+        .change_context(ContractError::StorageError)?
+    {
+        return Err(report!(ContractError::ProofAlreadyResigned {
+            multisig_session_id,
+            superseded_by: superseded_by.into(),
+        }));
+    }
+
+    let payload = PAYLOAD
+        .load(deps.storage, &payload_id)
+        .change_context(ContractError::StorageError)?;
+
+    // keep track of the payload id and the stale session to use during submessage reply
+    REPLY_TRACKER
+        .save(deps.storage, &payload_id)
+        .change_context(ContractError::StorageError)?;
+    RESIGN_TRACKER
+        .save(deps.storage, &multisig_session_id.u64())
+        .change_context(ContractError::StorageError)?;
+    // the new session keeps the delivery hints of the stale one
+    let hints = PROOF_HINTS
+        .may_load(deps.storage, multisig_session_id.u64())
+        .change_context(ContractError::StorageError)?;
+    save_hints_tracker(deps.storage, hints)?;
+
+    let verifier_set = CURRENT_VERIFIER_SET
+        .may_load(deps.storage)
+        .change_context(ContractError::StorageError)?
+        .ok_or(ContractError::NoVerifierSet)?;
+
+    let domain_separator = state::current_domain_separator(deps.storage)?;
+
+    let digest =
+        config
+            .encoder
+            .digest(&domain_separator.domain_separator, &verifier_set, &payload)?;
+
+    let start_sig_msg = multisig::msg::ExecuteMsg::StartSigningSession {
+        verifier_set_id: verifier_set.id(),
+        msg: digest.into(),
+        chain_name: config.chain_name,
+        sig_verifier: None,
+    };
+
+    let wasm_msg =
+        wasm_execute(config.multisig, &start_sig_msg, vec![]).map_err(ContractError::from)?;
+
+    Ok(Response::new().add_submessage(SubMsg::reply_on_success(wasm_msg, START_MULTISIG_REPLY_ID)))
+}
+
+pub fn abort_proof(
+    deps: DepsMut,
+    multisig_session_id: Uint64,
+    reason: nonempty::String,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage).map_err(ContractError::from)?;
+
+    // only sessions started by this prover can be aborted by it
+    MULTISIG_SESSION_PAYLOAD
+        .may_load(deps.storage, multisig_session_id.u64())
+        .change_context(ContractError::StorageError)?
+        .ok_or(ContractError::UnknownMultisigSession {
+            multisig_session_id,
+        })?;
+
+    let multisig: multisig::Client =
+        client::ContractClient::new(deps.querier, &config.multisig).into();
+
+    Ok(Response::new().add_message(multisig.abort_signing_session(multisig_session_id, reason)))
+}
+
 fn messages(
     querier: QuerierWrapper,
     message_ids: Vec<CrossChainId>,
@@ -95,12 +207,16 @@ fn messages(
         .outgoing_messages(message_ids)
         .change_context(ContractError::FailedToGetMessages)?;
 
-    assert_eq!(
-        messages.len(),
-        length,
-        "violated invariant: returned gateway messages count mismatch"
+    // the gateway leaves out messages that can no longer be approved, so only the remaining ones are proven
+    assert!(
+        messages.len() <= length,
+        "violated invariant: gateway returned more messages than requested"
     );
 
+    if messages.is_empty() {
+        return Err(ContractError::NoMessagesToProve.into());
+    }
+
     if let Some(wrong_destination) = messages
         .iter()
         .find(|msg| msg.destination_chain != chain_name)
@@ -123,8 +239,8 @@ fn make_verifier_set(
     let service_registry: service_registry_api::Client =
         client::ContractClient::new(deps.querier, &config.service_registry).into();
 
-    let verifiers: Vec<WeightedVerifier> = service_registry
-        .active_verifiers(config.service_name.clone(), config.chain_name.to_owned())
+    let participants = service_registry
+        .active_participants(config.service_name.clone(), config.chain_name.to_owned())
         .change_context(ContractError::FailedToBuildVerifierSet)?;
 
     let min_num_verifiers = service_registry
@@ -135,17 +251,19 @@ fn make_verifier_set(
     let multisig: multisig::Client =
         client::ContractClient::new(deps.querier, &config.multisig).into();
 
-    let participants_with_pubkeys = verifiers
+    let participants_with_pubkeys = participants
         .into_iter()
-        .filter_map(|verifier| {
-            match multisig.public_key(verifier.verifier_info.address.to_string(), config.key_type) {
-                Ok(pub_key) => Some((Participant::from(verifier), pub_key)),
+        .filter_map(|participant| {
+            match multisig.public_key(participant.address.to_string(), config.key_type) {
+                Ok(pub_key) => Some((participant, pub_key)),
                 Err(_) => None,
             }
         })
         .collect::<Vec<_>>();
 
-    if participants_with_pubkeys.len() < min_num_verifiers as usize {
+    if participants_with_pubkeys.len() < min_num_verifiers as usize
+        || participants_with_pubkeys.len() < config.min_signers.map_or(0, usize::from)
+    {
         return Err(ContractError::NotEnoughVerifiers.into());
     }
 
@@ -164,7 +282,8 @@ fn make_verifier_set(
         participants_with_pubkeys,
         snapshot.quorum.into(),
         env.block.height,
-    ))
+    )
+    .with_min_signers(config.min_signers))
 }
 
 fn next_verifier_set(
@@ -239,6 +358,11 @@ pub fn update_verifier_set(
                 .map_err(ContractError::from)?;
 
             Ok(Response::new()
+                .add_event(Event::verifier_set_rotated(
+                    config.chain_name.clone(),
+                    None,
+                    &new_verifier_set,
+                ))
                 .add_message(multisig.register_verifier_set(new_verifier_set.clone()))
                 .add_message(
                     coordinator.set_active_verifiers(
@@ -265,10 +389,13 @@ pub fn update_verifier_set(
                 .save(deps.storage, &payload_id)
                 .map_err(ContractError::from)?;
 
-            let digest =
-                config
-                    .encoder
-                    .digest(&config.domain_separator, &cur_verifier_set, &payload)?;
+            let domain_separator = state::current_domain_separator(deps.storage)?;
+
+            let digest = config.encoder.digest(
+                &domain_separator.domain_separator,
+                &cur_verifier_set,
+                &payload,
+            )?;
 
             let verifier_union_set = all_active_verifiers(deps.storage)?;
 
@@ -321,6 +448,9 @@ pub fn confirm_verifier_set(deps: DepsMut, sender: Addr) -> Result<Response, Con
         ensure_verifier_set_verification(&verifier_set, &config, &deps)?;
     }
 
+    let previous_verifier_set = CURRENT_VERIFIER_SET
+        .may_load(deps.storage)
+        .change_context(ContractError::StorageError)?;
     CURRENT_VERIFIER_SET
         .save(deps.storage, &verifier_set)
         .change_context(ContractError::StorageError)?;
@@ -335,6 +465,11 @@ pub fn confirm_verifier_set(deps: DepsMut, sender: Addr) -> Result<Response, Con
         client::ContractClient::new(deps.querier, &config.multisig).into();
 
     Ok(Response::new()
+        .add_event(Event::verifier_set_rotated(
+            config.chain_name.clone(),
+            previous_verifier_set.as_ref(),
+            &verifier_set,
+        ))
         .add_message(multisig.register_verifier_set(verifier_set))
         .add_message(
             coordinator
@@ -369,6 +504,7 @@ pub fn should_update_verifier_set(
     max_diff: usize,
 ) -> bool {
     new_verifiers.threshold != cur_verifiers.threshold
+        || new_verifiers.min_signers != cur_verifiers.min_signers
         || signers_symetric_difference_count(&new_verifiers.signers, &cur_verifiers.signers)
             > max_diff
 }
@@ -410,6 +546,22 @@ pub fn update_signing_threshold(
     Ok(Response::new())
 }
 
+pub fn update_min_signers(
+    deps: DepsMut,
+    min_signers: Option<u16>,
+) -> Result<Response, ContractError> {
+    CONFIG
+        .update(
+            deps.storage,
+            |mut config| -> std::result::Result<Config, ContractError> {
+                config.min_signers = min_signers;
+                Ok(config)
+            },
+        )
+        .change_context(ContractError::StorageError)?;
+    Ok(Response::new())
+}
+
 pub fn update_admin(deps: DepsMut, new_admin_address: String) -> Result<Response, ContractError> {
     let new_admin = address::validate_cosmwasm_address(deps.api, &new_admin_address)
         .change_context(ContractError::FailedToUpdateAdmin)?;
@@ -418,6 +570,135 @@ pub fn update_admin(deps: DepsMut, new_admin_address: String) -> Result<Response
     Ok(Response::new())
 }
 
+pub fn update_proof_signatures(
+    deps: DepsMut,
+    env: Env,
+    multisig_session_id: Uint64,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage).map_err(ContractError::from)?;
+
+    MULTISIG_SESSION_PAYLOAD
+        .may_load(deps.storage, multisig_session_id.u64())
+        .change_context(ContractError::StorageError)?
+        .ok_or(ContractError::UnknownMultisigSession {
+            multisig_session_id,
+        })?;
+
+    let multisig: multisig::Client =
+        client::ContractClient::new(deps.querier, &config.multisig).into();
+    let session = multisig
+        .multisig(multisig_session_id)
+        .change_context(ContractError::FailedToQueryMultisigSession)?;
+
+    if !matches!(session.state, MultisigState::Completed { .. }) {
+        return Err(report!(ContractError::ProofNotCompleted {
+            multisig_session_id
+        }));
+    }
+
+    let signature_count = session.signatures.len() as u64;
+    let previous = PROOF_SIGNATURES
+        .may_load(deps.storage, multisig_session_id.u64())
+        .change_context(ContractError::StorageError)?;
+
+    if previous.is_some_and(|previous| previous.signature_count >= signature_count) {
+        return Ok(Response::new());
+    }
+
+    let proof_signatures = ProofSignatures {
+        signature_count,
+        signed_weight: session.signed_weight(),
+        updated_at: env.block.height,
+        min_signers: session.verifier_set.min_signers,
+    };
+    PROOF_SIGNATURES
+        .save(deps.storage, multisig_session_id.u64(), &proof_signatures)
+        .change_context(ContractError::StorageError)?;
+
+    let hints = PROOF_HINTS
+        .may_load(deps.storage, multisig_session_id.u64())
+        .change_context(ContractError::StorageError)?
+        .unwrap_or_default();
+
+    Ok(Response::new().add_event(Event::ProofSignaturesUpdated {
+        destination_chain: config.chain_name,
+        multisig_session_id,
+        signature_count: proof_signatures.signature_count,
+        signed_weight: proof_signatures.signed_weight,
+        priority: hints.priority,
+        execute_by: hints.execute_by,
+    }))
+}
+
+fn save_hints_tracker(
+    storage: &mut dyn Storage,
+    hints: Option<DeliveryHints>,
+) -> Result<(), ContractError> {
+    match hints {
+        Some(hints) => HINTS_TRACKER
+            .save(storage, &hints)
+            .change_context(ContractError::StorageError),
+        None => {
+            HINTS_TRACKER.remove(storage);
+            Ok(())
+        }
+    }
+}
+
+/// Activates a new domain separator for all signing sessions started from now on.
+/// Sessions that were started before keep the domain separator that was active at their start.
+pub fn rotate_domain_separator(
+    deps: DepsMut,
+    env: Env,
+    domain_separator: Hash,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage).map_err(ContractError::from)?;
+
+    let previous = state::current_domain_separator(deps.storage)?;
+    if previous.domain_separator == domain_separator {
+        return Err(report!(ContractError::DomainSeparatorUnchanged));
+    }
+
+    let current = state::save_domain_separator(deps.storage, domain_separator, env.block.height)?;
+
+    Ok(Response::new().add_event(Event::DomainSeparatorRotated {
+        destination_chain: config.chain_name,
+        previous_version: previous.version,
+        version: current.version,
+        domain_separator: HexBinary::from(current.domain_separator),
+    }))
+}
+
+/// No proofs are signed while a maintenance window of the chain is active
+fn ensure_not_under_maintenance(
+    storage: &dyn Storage,
+    env: &Env,
+    config: &Config,
+) -> Result<(), ContractError> {
+    let windows = MAINTENANCE_WINDOWS
+        .may_load(storage)
+        .map_err(ContractError::from)?
+        .unwrap_or_default();
+    if maintenance::active_window(&windows, env.block.height).is_some() {
+        return Err(report!(ContractError::ChainUnderMaintenance(
+            config.chain_name.clone()
+        )));
+    }
+
+    Ok(())
+}
+
+pub fn set_maintenance_windows(
+    deps: DepsMut,
+    windows: Vec<MaintenanceWindow>,
+) -> Result<Response, ContractError> {
+    MAINTENANCE_WINDOWS
+        .save(deps.storage, &windows)
+        .map_err(ContractError::from)?;
+
+    Ok(Response::new())
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::BTreeMap;
@@ -559,7 +840,8 @@ mod tests {
             verifier_set_diff_threshold: 0,
             encoder: Encoder::Abi,
             key_type: multisig::key::KeyType::Ecdsa,
-            domain_separator: [0; 32],
+            proof_validity: None,
+            min_signers: None,
         }
     }
 }
@@ -1,16 +1,126 @@
-use axelar_wasm_std::migrate_from_version;
+use axelar_wasm_std::hash::Hash;
+use axelar_wasm_std::{migrate_from_version, MajorityThreshold};
+use cosmwasm_schema::cw_serde;
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
-use cosmwasm_std::{DepsMut, Empty, Env, Response};
+use cosmwasm_std::{Addr, DepsMut, Empty, Env, Order, Response};
+use cw_storage_plus::Item;
+use multisig::key::KeyType;
+use multisig_prover_api::encoding::Encoder;
+use router_api::ChainName;
+
+use crate::state::{self, Config, CONFIG, DOMAIN_SEPARATORS};
 
 pub type MigrateMsg = Empty;
 
+#[cw_serde]
+pub struct OldConfig {
+    pub gateway: Addr,
+    pub multisig: Addr,
+    pub coordinator: Addr,
+    pub service_registry: Addr,
+    pub voting_verifier: Addr,
+    pub signing_threshold: MajorityThreshold,
+    pub service_name: String,
+    pub chain_name: ChainName,
+    pub verifier_set_diff_threshold: u32,
+    pub encoder: Encoder,
+    pub key_type: KeyType,
+    pub domain_separator: Hash,
+    #[serde(default)]
+    pub proof_validity: Option<u64>,
+}
+pub const OLD_CONFIG: Item<OldConfig> = Item::new("config");
+
+/// The domain separator moves from the config into its versioned history, where it becomes the initial version.
+/// Sessions started before the migration are not tracked and therefore keep using it.
 #[cfg_attr(not(feature = "library"), entry_point)]
 #[migrate_from_version("1.1")]
 pub fn migrate(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     _msg: MigrateMsg,
 ) -> Result<Response, axelar_wasm_std::error::ContractError> {
+    let already_migrated = DOMAIN_SEPARATORS
+        .keys(deps.storage, None, None, Order::Ascending)
+        .next()
+        .is_some();
+    if already_migrated {
+        return Ok(Response::default());
+    }
+
+    let old_config = OLD_CONFIG.load(deps.storage)?;
+
+    CONFIG.save(
+        deps.storage,
+        &Config {
+            gateway: old_config.gateway,
+            multisig: old_config.multisig,
+            coordinator: old_config.coordinator,
+            service_registry: old_config.service_registry,
+            voting_verifier: old_config.voting_verifier,
+            signing_threshold: old_config.signing_threshold,
+            service_name: old_config.service_name,
+            chain_name: old_config.chain_name,
+            verifier_set_diff_threshold: old_config.verifier_set_diff_threshold,
+            encoder: old_config.encoder,
+            key_type: old_config.key_type,
+            proof_validity: old_config.proof_validity,
+            min_signers: None,
+        },
+    )?;
+    state::save_domain_separator(deps.storage, old_config.domain_separator, env.block.height)?;
+
     Ok(Response::default())
 }
+
+#[cfg(test)]
+mod tests {
+    use axelar_wasm_std::Threshold;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, MockApi};
+
+    use super::*;
+
+    #[test]
+    fn migrate_moves_domain_separator_into_history() {
+        let mut deps = mock_dependencies();
+        let api = MockApi::default();
+
+        cw2::set_contract_version(deps.as_mut().storage, "multisig-prover", "1.1.0").unwrap();
+        OLD_CONFIG
+            .save(
+                deps.as_mut().storage,
+                &OldConfig {
+                    gateway: api.addr_make("gateway"),
+                    multisig: api.addr_make("multisig"),
+                    coordinator: api.addr_make("coordinator"),
+                    service_registry: api.addr_make("service_registry"),
+                    voting_verifier: api.addr_make("voting_verifier"),
+                    signing_threshold: Threshold::try_from((2, 3)).unwrap().try_into().unwrap(),
+                    service_name: "validators".to_string(),
+                    chain_name: "ethereum".parse().unwrap(),
+                    verifier_set_diff_threshold: 0,
+                    encoder: Encoder::Abi,
+                    key_type: KeyType::Ecdsa,
+                    domain_separator: [1; 32],
+                    proof_validity: Some(100),
+                },
+            )
+            .unwrap();
+
+        migrate(deps.as_mut(), mock_env(), Empty {}).unwrap();
+
+        let config = CONFIG.load(deps.as_ref().storage).unwrap();
+        assert_eq!(config.proof_validity, Some(100));
+
+        let domain_separator = state::current_domain_separator(deps.as_ref().storage).unwrap();
+        assert_eq!(domain_separator.version, 0);
+        assert_eq!(domain_separator.domain_separator, [1; 32]);
+
+        // sessions started before the migration fall back to the initial domain separator
+        assert_eq!(
+            state::session_domain_separator(deps.as_ref().storage, 1).unwrap(),
+            domain_separator
+        );
+    }
+}
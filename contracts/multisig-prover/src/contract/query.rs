@@ -1,22 +1,79 @@
-use cosmwasm_std::{to_json_binary, Deps, QueryRequest, StdResult, Uint64, WasmQuery};
-use error_stack::Result;
+use cosmwasm_std::{to_json_binary, Deps, Env, Order, QueryRequest, StdResult, Uint64, WasmQuery};
+use error_stack::{Result, ResultExt};
+use multisig::msg::SignerWithSig;
 use multisig::multisig::Multisig;
 use multisig::types::MultisigState;
+use router_api::CrossChainId;
 
 use crate::encoding::EncoderExt;
 use crate::error::ContractError;
-use crate::msg::{ProofResponse, ProofStatus, VerifierSetResponse};
+use crate::msg::{
+    DomainSeparatorsResponse, ProofResponse, ProofStatus, UnsignedMessage,
+    UnsignedMessagesResponse, VerifierSetResponse,
+};
 use crate::state::{
-    CONFIG, CURRENT_VERIFIER_SET, MULTISIG_SESSION_PAYLOAD, NEXT_VERIFIER_SET, PAYLOAD,
+    self, DomainSeparator, ProofSignatures, CONFIG, CURRENT_VERIFIER_SET, DOMAIN_SEPARATORS,
+    MESSAGE_SESSIONS, MULTISIG_SESSION_PAYLOAD, NEXT_VERIFIER_SET, PAYLOAD, PROOF_EXPIRY,
+    PROOF_SIGNATURES, SUPERSEDED_PROOFS,
 };
 
-pub fn proof(deps: Deps, multisig_session_id: Uint64) -> Result<ProofResponse, ContractError> {
+const DEFAULT_UNSIGNED_MESSAGES_LIMIT: u32 = 100;
+
+pub fn proof(
+    deps: Deps,
+    env: Env,
+    multisig_session_id: Uint64,
+) -> Result<ProofResponse, ContractError> {
+    proof_with_signatures(
+        deps,
+        env,
+        multisig_session_id,
+        Multisig::optimize_signatures,
+    )
+}
+
+pub fn best_available_proof(
+    deps: Deps,
+    env: Env,
+    multisig_session_id: Uint64,
+) -> Result<ProofResponse, ContractError> {
+    proof_with_signatures(deps, env, multisig_session_id, Multisig::all_signatures)
+}
+
+fn proof_with_signatures(
+    deps: Deps,
+    env: Env,
+    multisig_session_id: Uint64,
+    select_signatures: impl Fn(&Multisig) -> Vec<SignerWithSig>,
+) -> Result<ProofResponse, ContractError> {
     let config = CONFIG.load(deps.storage).map_err(ContractError::from)?;
 
     let payload_id = MULTISIG_SESSION_PAYLOAD
         .load(deps.storage, multisig_session_id.u64())
         .map_err(ContractError::from)?;
 
+    let payload = PAYLOAD
+        .load(deps.storage, &payload_id)
+        .map_err(ContractError::from)?;
+
+    let is_stale = PROOF_EXPIRY
+        .may_load(deps.storage, multisig_session_id.u64())
+        .map_err(ContractError::from)?
+        .is_some_and(|expires_at| env.block.height >= expires_at);
+    if is_stale {
+        let superseded_by = SUPERSEDED_PROOFS
+            .may_load(deps.storage, multisig_session_id.u64())
+            .map_err(ContractError::from)?
+            .map(Uint64::from);
+
+        return Ok(ProofResponse {
+            multisig_session_id,
+            message_ids: payload.message_ids().unwrap_or_default(),
+            payload,
+            status: ProofStatus::Stale { superseded_by },
+        });
+    }
+
     let query_msg = multisig::msg::QueryMsg::Multisig {
         session_id: multisig_session_id,
     };
@@ -29,21 +86,21 @@ pub fn proof(deps: Deps, multisig_session_id: Uint64) -> Result<ProofResponse, C
         }))
         .map_err(ContractError::from)?;
 
-    let payload = PAYLOAD
-        .load(deps.storage, &payload_id)
-        .map_err(ContractError::from)?;
-
     let status = match multisig.state {
         MultisigState::Pending => ProofStatus::Pending,
         MultisigState::Completed { .. } => {
+            let domain_separator =
+                state::session_domain_separator(deps.storage, multisig_session_id.u64())?;
+
             let execute_data = config.encoder.execute_data(
-                &config.domain_separator,
+                &domain_separator.domain_separator,
                 &multisig.verifier_set,
-                multisig.optimize_signatures(),
+                select_signatures(&multisig),
                 &payload,
             )?;
             ProofStatus::Completed { execute_data }
         }
+        MultisigState::Aborted { reason, .. } => ProofStatus::Aborted { reason },
     };
 
     Ok(ProofResponse {
@@ -54,6 +111,13 @@ pub fn proof(deps: Deps, multisig_session_id: Uint64) -> Result<ProofResponse, C
     })
 }
 
+pub fn proof_signatures(
+    deps: Deps,
+    multisig_session_id: Uint64,
+) -> StdResult<Option<ProofSignatures>> {
+    PROOF_SIGNATURES.may_load(deps.storage, multisig_session_id.u64())
+}
+
 pub fn current_verifier_set(deps: Deps) -> StdResult<Option<VerifierSetResponse>> {
     CURRENT_VERIFIER_SET
         .may_load(deps.storage)
@@ -66,6 +130,57 @@ pub fn next_verifier_set(deps: Deps) -> StdResult<Option<VerifierSetResponse>> {
         .map(|op| op.map(|set| set.into()))
 }
 
+pub fn domain_separators(deps: Deps) -> Result<DomainSeparatorsResponse, ContractError> {
+    let current = state::current_domain_separator(deps.storage)?;
+
+    let history = DOMAIN_SEPARATORS
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, domain_separator)| domain_separator))
+        .collect::<StdResult<Vec<DomainSeparator>>>()
+        .map_err(ContractError::from)?;
+
+    Ok(DomainSeparatorsResponse { current, history })
+}
+
+pub fn unsigned_messages(
+    deps: Deps,
+    env: Env,
+    start_after: Option<CrossChainId>,
+    limit: Option<u32>,
+) -> Result<UnsignedMessagesResponse, ContractError> {
+    let limit = limit.unwrap_or(DEFAULT_UNSIGNED_MESSAGES_LIMIT);
+    let config = CONFIG.load(deps.storage).map_err(ContractError::from)?;
+
+    let gateway: gateway_api::Client =
+        client::ContractClient::new(deps.querier, &config.gateway).into();
+    let routed_messages = gateway
+        .routed_messages(start_after, Some(limit))
+        .change_context(ContractError::FailedToGetMessages)?;
+
+    // the gateway pages are filtered here, so the next page must start after the last scanned message, not the last returned one
+    let next_start_after = match routed_messages.last() {
+        Some(last) if routed_messages.len() >= usize::try_from(limit).unwrap_or(usize::MAX) => {
+            Some(last.message.cc_id.clone())
+        }
+        _ => None,
+    };
+
+    let messages = routed_messages
+        .into_iter()
+        .filter(|routed| !MESSAGE_SESSIONS.has(deps.storage, &routed.message.cc_id))
+        .map(|routed| UnsignedMessage {
+            age: env.block.height.saturating_sub(routed.routed_at),
+            routed_at: routed.routed_at,
+            message: routed.message,
+        })
+        .collect();
+
+    Ok(UnsignedMessagesResponse {
+        messages,
+        next_start_after,
+    })
+}
+
 #[cfg(test)]
 mod test {
     use cosmwasm_std::testing::mock_dependencies;
@@ -1,49 +1,115 @@
-use cosmwasm_std::{from_json, DepsMut, Reply, Response, Uint64};
-use cw_utils::{parse_execute_response_data, MsgExecuteContractResponse, ParseReplyError};
+use cosmwasm_std::{DepsMut, Env, Reply, Response, Uint64};
+use error_stack::{Result, ResultExt};
 
 use crate::error::ContractError;
 use crate::events::Event;
-use crate::state::{CONFIG, MULTISIG_SESSION_PAYLOAD, PAYLOAD, REPLY_TRACKER};
-
-pub fn start_multisig_reply(deps: DepsMut, reply: Reply) -> Result<Response, ContractError> {
-    let config = CONFIG.load(deps.storage)?;
-    #[allow(deprecated)]
-    // TODO: use `msg_responses` instead when the cosmwasm vm is updated to 2.x.x
-    let data = reply
-        .result
-        .into_result()
-        .map_err(ParseReplyError::SubMsgFailure)?
-        .data
-        .ok_or_else(|| ParseReplyError::ParseFailure("missing reply data".to_owned()))?;
-
-    match parse_execute_response_data(data.as_slice()) {
-        Ok(MsgExecuteContractResponse { data: Some(data) }) => {
-            let payload_id = REPLY_TRACKER.load(deps.storage)?;
-
-            let multisig_session_id: Uint64 =
-                from_json(data).map_err(|_| ContractError::InvalidContractReply {
-                    reason: "invalid multisig session ID".to_string(),
-                })?;
-
-            MULTISIG_SESSION_PAYLOAD.save(deps.storage, multisig_session_id.u64(), &payload_id)?;
-
-            let msg_ids = PAYLOAD
-                .load(deps.storage, &payload_id)?
-                .message_ids()
-                .unwrap_or_default();
-
-            Ok(Response::new().add_event(Event::ProofUnderConstruction {
-                destination_chain: config.chain_name,
-                msg_ids,
-                payload_id,
-                multisig_session_id,
-            }))
-        }
-        Ok(MsgExecuteContractResponse { data: None }) => Err(ContractError::InvalidContractReply {
-            reason: "no data".to_string(),
-        }),
-        Err(_) => {
-            unreachable!("violated invariant: replied failed submessage with ReplyOn::Success")
-        }
+use crate::state::{
+    self, CONFIG, HINTS_TRACKER, MULTISIG_SESSION_PAYLOAD, PAYLOAD, PROOF_EXPIRY, PROOF_HINTS,
+    REPLY_TRACKER, RESIGN_TRACKER, SESSION_DOMAIN_SEPARATOR, SUPERSEDED_PROOFS,
+};
+
+pub fn start_multisig_reply(
+    deps: DepsMut,
+    env: Env,
+    reply: Reply,
+) -> Result<Response, ContractError> {
+    let config = CONFIG
+        .load(deps.storage)
+        .change_context(ContractError::StorageError)?;
+
+    let multisig_session_id: Uint64 = axelar_wasm_std::reply::parse_execute_data(reply)
+        .change_context(ContractError::InvalidContractReply {
+            reason: "invalid multisig session ID".to_string(),
+        })?;
+
+    let payload_id = REPLY_TRACKER
+        .load(deps.storage)
+        .change_context(ContractError::StorageError)?;
+
+    MULTISIG_SESSION_PAYLOAD
+        .save(deps.storage, multisig_session_id.u64(), &payload_id)
+        .change_context(ContractError::StorageError)?;
+
+    // the session was started in the same transaction, so it signs with the currently active domain separator
+    let domain_separator = state::current_domain_separator(deps.storage)?;
+    SESSION_DOMAIN_SEPARATOR
+        .save(
+            deps.storage,
+            multisig_session_id.u64(),
+            &domain_separator.version,
+        )
+        .change_context(ContractError::StorageError)?;
+
+    let msg_ids = PAYLOAD
+        .load(deps.storage, &payload_id)
+        .change_context(ContractError::StorageError)?
+        .message_ids()
+        .unwrap_or_default();
+
+    for msg_id in &msg_ids {
+        state::save_message_session(
+            deps.storage,
+            msg_id,
+            multisig_session_id.u64(),
+            env.block.height,
+        )?;
+    }
+    state::prune_message_sessions(
+        deps.storage,
+        env.block.height,
+        state::MESSAGE_SESSION_PRUNE_LIMIT,
+    )?;
+
+    if let Some(proof_validity) = config.proof_validity {
+        PROOF_EXPIRY
+            .save(
+                deps.storage,
+                multisig_session_id.u64(),
+                &env.block.height.saturating_add(proof_validity),
+            )
+            .change_context(ContractError::StorageError)?;
+    }
+
+    let hints = HINTS_TRACKER
+        .may_load(deps.storage)
+        .change_context(ContractError::StorageError)?;
+    if let Some(hints) = &hints {
+        HINTS_TRACKER.remove(deps.storage);
+        PROOF_HINTS
+            .save(deps.storage, multisig_session_id.u64(), hints)
+            .change_context(ContractError::StorageError)?;
+    }
+    let hints = hints.unwrap_or_default();
+
+    let mut response = Response::new().add_event(Event::ProofUnderConstruction {
+        destination_chain: config.chain_name.clone(),
+        msg_ids,
+        payload_id: payload_id.clone(),
+        multisig_session_id,
+        priority: hints.priority,
+        execute_by: hints.execute_by,
+    });
+
+    if let Some(previous_multisig_session_id) = RESIGN_TRACKER
+        .may_load(deps.storage)
+        .change_context(ContractError::StorageError)?
+    {
+        RESIGN_TRACKER.remove(deps.storage);
+        SUPERSEDED_PROOFS
+            .save(
+                deps.storage,
+                previous_multisig_session_id,
+                &multisig_session_id.u64(),
+            )
+            .change_context(ContractError::StorageError)?;
+
+        response = response.add_event(Event::ProofResigned {
+            destination_chain: config.chain_name,
+            payload_id,
+            previous_multisig_session_id: previous_multisig_session_id.into(),
+            multisig_session_id,
+        });
     }
+
+    Ok(response)
 }
@@ -120,7 +120,7 @@ pub fn encode_execute_data(
 }
 
 // Convert non-recoverable ECDSA signatures to recoverable ones.
-fn to_recoverable<M>(msg: M, signers: Vec<SignerWithSig>) -> Vec<SignerWithSig>
+pub(super) fn to_recoverable<M>(msg: M, signers: Vec<SignerWithSig>) -> Vec<SignerWithSig>
 where
     M: AsRef<[u8]>,
 {
@@ -165,6 +165,7 @@ mod tests {
             .collect(),
             threshold: 2u128.into(),
             created_at: 2024,
+            min_signers: None,
         };
         let payload = Payload::VerifierSet(VerifierSet {
             signers: vec![
@@ -212,6 +213,7 @@ mod tests {
             .collect(),
             threshold: 2u128.into(),
             created_at: 2025,
+            min_signers: None,
         });
 
         goldie::assert!(hex::encode(
@@ -267,6 +269,7 @@ mod tests {
             .collect(),
             threshold: 2u128.into(),
             created_at: 2024,
+            min_signers: None,
         };
         let payload = Payload::Messages(vec![
             Message {
@@ -0,0 +1,360 @@
+use std::str::FromStr;
+
+use axelar_wasm_std::hash::Hash;
+use axelar_wasm_std::msg_id::HexTxHashAndEventIndex;
+use cosmwasm_std::HexBinary;
+use error_stack::{Result, ResultExt};
+use ethers_core::abi::{encode as abi_encode, short_signature, ParamType, Token};
+use ethers_core::types::U256;
+use evm_gateway::{Message, Proof, WeightedSigners};
+use multisig::msg::SignerWithSig;
+use multisig::verifier_set::VerifierSet;
+use sha3::{Digest, Keccak256};
+
+use crate::encoding::abi::to_recoverable;
+use crate::error::ContractError;
+use crate::Payload;
+
+const PREFIX: &str = "\x19Ethereum Signed Message:\n32";
+const APPROVE_CONTRACT_CALL: &str = "approveContractCall";
+const TRANSFER_OPERATORSHIP: &str = "transferOperatorship";
+
+/// A command of the legacy Axelar gateway, which executes a batch of commands in a single transaction
+struct Command {
+    id: Hash,
+    name: &'static str,
+    params: Vec<u8>,
+}
+
+/// The legacy gateway doesn't bind the signed data to the signers, so the digest only covers the encoded commands
+pub fn payload_digest(chain_id: u64, payload: &Payload) -> Result<Hash, ContractError> {
+    let data_hash = Keccak256::digest(encode_data(chain_id, payload)?);
+
+    // Prefix for standard EVM signed data https://eips.ethereum.org/EIPS/eip-191
+    let unsigned = [PREFIX.as_bytes(), data_hash.as_slice()].concat();
+
+    Ok(Keccak256::digest(unsigned).into())
+}
+
+/// Encodes the payload as abi.encode(uint256 chainId, bytes32[] commandIds, string[] commands, bytes[] params)
+pub fn encode_data(chain_id: u64, payload: &Payload) -> Result<Vec<u8>, ContractError> {
+    let commands = match payload {
+        Payload::Messages(messages) => messages
+            .iter()
+            .map(approve_contract_call)
+            .collect::<Result<Vec<_>, _>>()?,
+        Payload::VerifierSet(verifier_set) => vec![transfer_operatorship(verifier_set)?],
+    };
+
+    Ok(abi_encode(&[
+        Token::Uint(U256::from(chain_id)),
+        Token::Array(
+            commands
+                .iter()
+                .map(|command| Token::FixedBytes(command.id.to_vec()))
+                .collect(),
+        ),
+        Token::Array(
+            commands
+                .iter()
+                .map(|command| Token::String(command.name.to_string()))
+                .collect(),
+        ),
+        Token::Array(
+            commands
+                .into_iter()
+                .map(|command| Token::Bytes(command.params))
+                .collect(),
+        ),
+    ]))
+}
+
+/// Encodes the call to execute(bytes input) of the legacy gateway, with input = abi.encode(bytes data, bytes proof)
+pub fn encode_execute_data(
+    chain_id: u64,
+    verifier_set: &VerifierSet,
+    signers: Vec<SignerWithSig>,
+    payload: &Payload,
+) -> Result<HexBinary, ContractError> {
+    let signers = to_recoverable(payload_digest(chain_id, payload)?, signers);
+
+    let proof = Proof::new(verifier_set, signers).change_context(ContractError::Proof)?;
+
+    // abi.encode(address[] operators, uint256[] weights, uint256 threshold, bytes[] signatures)
+    let proof = abi_encode(&[
+        operators(&proof.signers),
+        weights(&proof.signers),
+        Token::Uint(U256::from(proof.signers.threshold)),
+        Token::Array(
+            proof
+                .signatures
+                .into_iter()
+                .map(|signature| Token::Bytes(signature.to_vec()))
+                .collect(),
+        ),
+    ]);
+
+    let input = abi_encode(&[
+        Token::Bytes(encode_data(chain_id, payload)?),
+        Token::Bytes(proof),
+    ]);
+
+    Ok(short_signature("execute", &[ParamType::Bytes])
+        .into_iter()
+        .chain(abi_encode(&[Token::Bytes(input)]))
+        .collect::<Vec<_>>()
+        .into())
+}
+
+/// Message ids that don't consist of a transaction hash and event index are approved with an empty source transaction.
+/// The legacy gateway only includes the source transaction in its events, the command id identifies the message
+fn approve_contract_call(message: &router_api::Message) -> Result<Command, ContractError> {
+    let source_chain = message.cc_id.source_chain.to_string();
+    let message_id = message.cc_id.message_id.to_string();
+
+    let (source_tx_hash, source_event_index) = HexTxHashAndEventIndex::from_str(&message_id)
+        .map(|id| (id.tx_hash, id.event_index))
+        .unwrap_or_default();
+
+    let message = Message::try_from(message).change_context(ContractError::InvalidMessage)?;
+
+    let params = abi_encode(&[
+        Token::String(message.source_chain),
+        Token::String(message.source_address),
+        Token::Address(message.contract_address),
+        Token::FixedBytes(message.payload_hash.to_vec()),
+        Token::FixedBytes(source_tx_hash.to_vec()),
+        Token::Uint(U256::from(source_event_index)),
+    ]);
+
+    Ok(Command {
+        // same command id as the amplifier gateway assigns to the message
+        id: Keccak256::digest(format!("{}_{}", source_chain, message_id)).into(),
+        name: APPROVE_CONTRACT_CALL,
+        params,
+    })
+}
+
+fn transfer_operatorship(verifier_set: &VerifierSet) -> Result<Command, ContractError> {
+    let signers = WeightedSigners::try_from(verifier_set)
+        .change_context(ContractError::InvalidVerifierSet)?;
+
+    // abi.encode(address[] newOperators, uint256[] newWeights, uint256 newThreshold)
+    let params = abi_encode(&[
+        operators(&signers),
+        weights(&signers),
+        Token::Uint(U256::from(signers.threshold)),
+    ]);
+
+    Ok(Command {
+        // the signers hash includes the nonce of the verifier set, so rotating back to the same signers gets a new id
+        id: signers.hash(),
+        name: TRANSFER_OPERATORSHIP,
+        params,
+    })
+}
+
+fn operators(signers: &WeightedSigners) -> Token {
+    Token::Array(
+        signers
+            .signers
+            .iter()
+            .map(|signer| Token::Address(signer.signer))
+            .collect(),
+    )
+}
+
+fn weights(signers: &WeightedSigners) -> Token {
+    Token::Array(
+        signers
+            .signers
+            .iter()
+            .map(|signer| Token::Uint(U256::from(signer.weight)))
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_ok::assert_ok;
+    use cosmwasm_std::HexBinary;
+    use ethers_core::abi::{decode, short_signature, ParamType, Token};
+    use ethers_core::types::U256;
+    use ethers_core::utils::hash_message;
+    use evm_gateway::{evm_address, WeightedSigners};
+    use itertools::Itertools;
+    use multisig::key::{KeyTyped, Signature};
+    use sha3::{Digest, Keccak256};
+
+    use crate::encoding::legacy_abi::{encode_data, encode_execute_data, payload_digest};
+    use crate::test::test_data::{curr_verifier_set, messages, new_verifier_set};
+    use crate::Payload;
+
+    const CHAIN_ID: u64 = 1;
+
+    #[test]
+    fn legacy_payload_digest_should_be_eip191_hash_of_data() {
+        let payload = Payload::Messages(messages());
+
+        let data = assert_ok!(encode_data(CHAIN_ID, &payload));
+        let digest = assert_ok!(payload_digest(CHAIN_ID, &payload));
+
+        assert_eq!(
+            digest.as_slice(),
+            hash_message(Keccak256::digest(data)).as_bytes()
+        );
+    }
+
+    #[test]
+    fn legacy_approve_messages_data() {
+        let message = messages().remove(0);
+
+        let data = assert_ok!(encode_data(
+            CHAIN_ID,
+            &Payload::Messages(vec![message.clone()])
+        ));
+        let tokens = decode(
+            &[
+                ParamType::Uint(256),
+                ParamType::Array(Box::new(ParamType::FixedBytes(32))),
+                ParamType::Array(Box::new(ParamType::String)),
+                ParamType::Array(Box::new(ParamType::Bytes)),
+            ],
+            &data,
+        )
+        .unwrap();
+
+        let command_id = Keccak256::digest(format!(
+            "{}_{}",
+            message.cc_id.source_chain, message.cc_id.message_id
+        ));
+        assert_eq!(tokens[0], Token::Uint(U256::from(CHAIN_ID)));
+        assert_eq!(
+            tokens[1],
+            Token::Array(vec![Token::FixedBytes(command_id.to_vec())])
+        );
+        assert_eq!(
+            tokens[2],
+            Token::Array(vec![Token::String("approveContractCall".to_string())])
+        );
+
+        let params = match &tokens[3] {
+            Token::Array(params) => match params.as_slice() {
+                [Token::Bytes(params)] => params.clone(),
+                _ => panic!("expected a single command"),
+            },
+            _ => panic!("expected an array of params"),
+        };
+        let params = decode(
+            &[
+                ParamType::String,
+                ParamType::String,
+                ParamType::Address,
+                ParamType::FixedBytes(32),
+                ParamType::FixedBytes(32),
+                ParamType::Uint(256),
+            ],
+            &params,
+        )
+        .unwrap();
+
+        assert_eq!(
+            params,
+            vec![
+                Token::String("ganache-1".to_string()),
+                Token::String("0x52444f1835Adc02086c37Cb226561605e2E1699b".to_string()),
+                Token::Address(
+                    "0xA4f10f76B86E01B98daF66A3d02a65e14adb0767"
+                        .parse()
+                        .unwrap()
+                ),
+                Token::FixedBytes(message.payload_hash.to_vec()),
+                Token::FixedBytes(
+                    HexBinary::from_hex(
+                        "ff822c88807859ff226b58e24f24974a70f04b9442501ae38fd665b3c68f3834"
+                    )
+                    .unwrap()
+                    .to_vec()
+                ),
+                Token::Uint(U256::zero()),
+            ]
+        );
+    }
+
+    #[test]
+    fn legacy_transfer_operatorship_execute_data() {
+        let verifier_set = curr_verifier_set();
+        let payload = Payload::VerifierSet(new_verifier_set());
+
+        // signatures are only encoded, not verified, so any recoverable signature of the right length will do
+        let signers_with_sigs = verifier_set
+            .signers
+            .values()
+            .take(3)
+            .map(|signer| {
+                signer.with_sig(
+                    Signature::try_from((
+                        signer.pub_key.key_type(),
+                        HexBinary::from(vec![1u8; 65]),
+                    ))
+                    .unwrap(),
+                )
+            })
+            .collect();
+
+        let execute_data = assert_ok!(encode_execute_data(
+            CHAIN_ID,
+            &verifier_set,
+            signers_with_sigs,
+            &payload
+        ));
+
+        let (selector, input) = execute_data.split_at(4);
+        assert_eq!(selector, short_signature("execute", &[ParamType::Bytes]));
+
+        let input = decode(&[ParamType::Bytes], input)
+            .unwrap()
+            .remove(0)
+            .into_bytes()
+            .unwrap();
+        let mut input = decode(&[ParamType::Bytes, ParamType::Bytes], &input).unwrap();
+        let proof = input.remove(1).into_bytes().unwrap();
+        let data = input.remove(0).into_bytes().unwrap();
+
+        assert_eq!(data, assert_ok!(encode_data(CHAIN_ID, &payload)));
+
+        let proof = decode(
+            &[
+                ParamType::Array(Box::new(ParamType::Address)),
+                ParamType::Array(Box::new(ParamType::Uint(256))),
+                ParamType::Uint(256),
+                ParamType::Array(Box::new(ParamType::Bytes)),
+            ],
+            &proof,
+        )
+        .unwrap();
+
+        let signers = WeightedSigners::try_from(&verifier_set).unwrap();
+        let operators = verifier_set
+            .signers
+            .values()
+            .map(|signer| evm_address(&signer.pub_key).unwrap())
+            .sorted()
+            .map(Token::Address)
+            .collect::<Vec<_>>();
+
+        assert_eq!(proof[0], Token::Array(operators));
+        assert_eq!(
+            proof[1],
+            Token::Array(
+                signers
+                    .signers
+                    .iter()
+                    .map(|signer| Token::Uint(U256::from(signer.weight)))
+                    .collect()
+            )
+        );
+        assert_eq!(proof[2], Token::Uint(U256::from(signers.threshold)));
+        assert_eq!(proof[3], Token::Array(vec![Token::Bytes(vec![1u8; 65]); 3]));
+    }
+}
@@ -358,6 +358,7 @@ mod tests {
                 .collect(),
             threshold: threshold.into(),
             created_at,
+            min_signers: None,
         }
     }
 
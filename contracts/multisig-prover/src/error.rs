@@ -1,5 +1,5 @@
 use axelar_wasm_std::{nonempty, IntoContractError};
-use cosmwasm_std::StdError;
+use cosmwasm_std::{StdError, Uint64};
 use cw_utils::ParseReplyError;
 use router_api::ChainName;
 use thiserror::Error;
@@ -85,6 +85,9 @@ pub enum ContractError {
     #[error("failed to get outgoing messages from gateway")]
     FailedToGetMessages,
 
+    #[error("none of the requested messages can be proven anymore")]
+    NoMessagesToProve,
+
     #[error("failed to build verifier set")]
     FailedToBuildVerifierSet,
 
@@ -97,6 +100,33 @@ pub enum ContractError {
     #[error("failed to create wasm execute msg")]
     FailedToCreateWasmExecuteMsg,
 
+    #[error("multisig session {multisig_session_id} was not started by this prover")]
+    UnknownMultisigSession { multisig_session_id: Uint64 },
+
+    #[error("failed to query multisig session")]
+    FailedToQueryMultisigSession,
+
+    #[error("proof for multisig session {multisig_session_id} is not completed yet")]
+    ProofNotCompleted { multisig_session_id: Uint64 },
+
+    #[error("proof for multisig session {multisig_session_id} is not stale")]
+    ProofNotStale { multisig_session_id: Uint64 },
+
+    #[error("proof for multisig session {multisig_session_id} was already re-signed in session {superseded_by}")]
+    ProofAlreadyResigned {
+        multisig_session_id: Uint64,
+        superseded_by: Uint64,
+    },
+
+    #[error("no domain separator stored")]
+    NoDomainSeparator,
+
+    #[error("domain separator is already active")]
+    DomainSeparatorUnchanged,
+
+    #[error("chain {0} is under maintenance")]
+    ChainUnderMaintenance(ChainName),
+
     // Generic error to wrap cw_storage_plus errors
     // This should only be used for things that shouldn't happen, such as encountering
     // an error when loading data that should always load successfully.
@@ -1,5 +1,7 @@
+use axelar_wasm_std::time::Timestamp;
 use axelar_wasm_std::IntoEvent;
-use cosmwasm_std::Uint64;
+use cosmwasm_std::{HexBinary, Uint128, Uint64};
+use multisig::verifier_set::{SignerWeight, VerifierSet, WeightChange};
 use router_api::{ChainName, CrossChainId};
 
 use crate::payload::PayloadId;
@@ -11,9 +13,63 @@ pub enum Event {
         payload_id: PayloadId,
         multisig_session_id: Uint64,
         msg_ids: Vec<CrossChainId>,
+        priority: Option<u32>,
+        execute_by: Option<Timestamp>,
+    },
+    ProofSignaturesUpdated {
+        destination_chain: ChainName,
+        multisig_session_id: Uint64,
+        signature_count: u64,
+        signed_weight: Uint128,
+        priority: Option<u32>,
+        execute_by: Option<Timestamp>,
+    },
+    ProofResigned {
+        destination_chain: ChainName,
+        payload_id: PayloadId,
+        previous_multisig_session_id: Uint64,
+        multisig_session_id: Uint64,
+    },
+    DomainSeparatorRotated {
+        destination_chain: ChainName,
+        previous_version: u64,
+        version: u64,
+        domain_separator: HexBinary,
+    },
+    /// Emitted when a verifier set becomes the current verifier set, with the changes compared to the previous one
+    VerifierSetRotated {
+        destination_chain: ChainName,
+        previous_verifier_set_id: Option<String>,
+        verifier_set_id: String,
+        previous_threshold: Option<Uint128>,
+        threshold: Uint128,
+        added_signers: Vec<SignerWeight>,
+        removed_signers: Vec<SignerWeight>,
+        weight_changes: Vec<WeightChange>,
     },
 }
 
+impl Event {
+    pub fn verifier_set_rotated(
+        destination_chain: ChainName,
+        previous: Option<&VerifierSet>,
+        verifier_set: &VerifierSet,
+    ) -> Self {
+        let diff = verifier_set.diff(previous);
+
+        Event::VerifierSetRotated {
+            destination_chain,
+            previous_verifier_set_id: previous.map(VerifierSet::id),
+            verifier_set_id: verifier_set.id(),
+            previous_threshold: previous.map(|previous| previous.threshold),
+            threshold: verifier_set.threshold,
+            added_signers: diff.added,
+            removed_signers: diff.removed,
+            weight_changes: diff.weight_changes,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use router_api::Message;
@@ -45,6 +101,76 @@ mod tests {
             payload_id: payload.id(),
             multisig_session_id: Uint64::new(2),
             msg_ids: payload.message_ids().unwrap(),
+            priority: Some(10),
+            execute_by: Some(Timestamp::from_seconds(1700000000)),
+        };
+        let event = cosmwasm_std::Event::from(event);
+
+        goldie::assert_json!(event);
+    }
+
+    #[test]
+    fn proof_signatures_updated_is_serializable() {
+        let event = Event::ProofSignaturesUpdated {
+            destination_chain: "avalanche".parse().unwrap(),
+            multisig_session_id: Uint64::new(2),
+            signature_count: 5,
+            signed_weight: Uint128::new(12),
+            priority: None,
+            execute_by: None,
+        };
+        let event = cosmwasm_std::Event::from(event);
+
+        goldie::assert_json!(event);
+    }
+
+    #[test]
+    fn proof_resigned_is_serializable() {
+        let event = Event::ProofResigned {
+            destination_chain: "avalanche".parse().unwrap(),
+            payload_id: vec![1; 32].into(),
+            previous_multisig_session_id: Uint64::new(2),
+            multisig_session_id: Uint64::new(3),
+        };
+        let event = cosmwasm_std::Event::from(event);
+
+        goldie::assert_json!(event);
+    }
+
+    #[test]
+    fn domain_separator_rotated_is_serializable() {
+        let event = Event::DomainSeparatorRotated {
+            destination_chain: "avalanche".parse().unwrap(),
+            previous_version: 0,
+            version: 1,
+            domain_separator: HexBinary::from([1; 32]),
+        };
+        let event = cosmwasm_std::Event::from(event);
+
+        goldie::assert_json!(event);
+    }
+
+    #[test]
+    fn verifier_set_rotated_is_serializable() {
+        let event = Event::VerifierSetRotated {
+            destination_chain: "avalanche".parse().unwrap(),
+            previous_verifier_set_id: Some("previous-id".to_string()),
+            verifier_set_id: "id".to_string(),
+            previous_threshold: Some(Uint128::new(2)),
+            threshold: Uint128::new(3),
+            added_signers: vec![SignerWeight {
+                signer: cosmwasm_std::Addr::unchecked("added"),
+                weight: Uint128::one(),
+            }],
+            removed_signers: vec![SignerWeight {
+                signer: cosmwasm_std::Addr::unchecked("removed"),
+                weight: Uint128::one(),
+            }],
+            weight_changes: vec![WeightChange {
+                signer: cosmwasm_std::Addr::unchecked("changed"),
+                previous_weight: Uint128::one(),
+                weight: Uint128::new(2),
+            }],
         };
         let event = cosmwasm_std::Event::from(event);
 
@@ -1,20 +1,30 @@
-use axelar_wasm_std::MajorityThreshold;
+use axelar_wasm_std::hash::Hash;
+use axelar_wasm_std::maintenance::MaintenanceWindow;
+use axelar_wasm_std::{nonempty, MajorityThreshold};
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::{HexBinary, Uint64};
 use msgs_derive::EnsurePermissions;
 pub use multisig_prover_api::msg::InstantiateMsg;
-use router_api::CrossChainId;
+use router_api::{CrossChainId, Message};
 
 pub use crate::contract::MigrateMsg;
+pub use crate::state::{DeliveryHints, DomainSeparator, ProofSignatures};
 use crate::Payload;
 
 #[cw_serde]
 #[derive(EnsurePermissions)]
 pub enum ExecuteMsg {
     // Start building a proof that includes specified messages
-    // Queries the gateway for actual message contents
+    // Queries the gateway for actual message contents, messages the gateway leaves out because they expired are skipped
     #[permission(Any)]
     ConstructProof(Vec<CrossChainId>),
+    // Same as ConstructProof, but attaches delivery hints that are emitted with the proof events,
+    // so relayers can order deliveries by urgency
+    #[permission(Any)]
+    ConstructProofWithHints {
+        message_ids: Vec<CrossChainId>,
+        hints: DeliveryHints,
+    },
     #[permission(Elevated)]
     UpdateVerifierSet,
 
@@ -26,8 +36,45 @@ pub enum ExecuteMsg {
     UpdateSigningThreshold {
         new_signing_threshold: MajorityThreshold,
     },
+    // Updates the minimum number of distinct signers required to complete a signing session. The verifier set currently in use
+    // does not change. The verifier set must be updated and confirmed for the change to take effect.
+    #[permission(Governance)]
+    UpdateMinSigners { min_signers: Option<u16> },
     #[permission(Governance)]
     UpdateAdmin { new_admin_address: String },
+
+    /// Records the signatures collected for a completed proof. Signers can keep submitting signatures
+    /// after the signing threshold is met, until the multisig session expires.
+    #[permission(Any)]
+    UpdateProofSignatures { multisig_session_id: Uint64 },
+
+    // Starts a new signing session for the payload of a stale proof, i.e. a proof whose validity window has passed.
+    // The new session signs the same payload with the current domain separator and supersedes the stale one.
+    #[permission(Any)]
+    ResignProof { multisig_session_id: Uint64 },
+
+    // Aborts the pending multisig session of a proof, e.g. because its payload became obsolete. Verifiers stop signing,
+    // and the proof is reported as aborted. The messages can be proven again with ConstructProof.
+    #[permission(Elevated)]
+    AbortProof {
+        multisig_session_id: Uint64,
+        reason: nonempty::String,
+    },
+
+    // Activates a new domain separator, e.g. after the destination gateway rotated its own. Signing sessions that are
+    // already in flight keep using the domain separator that was active when they were started.
+    // Value must be a String in hex format without `0x`.
+    #[permission(Governance)]
+    RotateDomainSeparator {
+        #[serde(with = "axelar_wasm_std::hex")]
+        #[schemars(with = "String")]
+        domain_separator: Hash,
+    },
+
+    // Replaces the scheduled maintenance windows of the chain. Called by the coordinator whenever a window is
+    // scheduled or cancelled. No proofs are constructed while one of the windows is active.
+    #[permission(Specific(coordinator))]
+    SetMaintenanceWindows { windows: Vec<MaintenanceWindow> },
 }
 
 #[cw_serde]
@@ -36,6 +83,15 @@ pub enum QueryMsg {
     #[returns(ProofResponse)]
     Proof { multisig_session_id: Uint64 },
 
+    /// Returns a proof that includes every signature collected so far, not just the minimum needed to meet the threshold.
+    /// Useful for destination chains that refund gas for extra signatures or require a higher on-chain threshold.
+    #[returns(ProofResponse)]
+    BestAvailableProof { multisig_session_id: Uint64 },
+
+    /// Returns the signature accumulation recorded by the last `UpdateProofSignatures` call for the given session.
+    #[returns(Option<ProofSignatures>)]
+    ProofSignatures { multisig_session_id: Uint64 },
+
     /// Returns a `VerifierSetResponse` with the current verifier set id and the verifier set itself.
     #[returns(Option<VerifierSetResponse>)]
     CurrentVerifierSet,
@@ -43,12 +99,29 @@ pub enum QueryMsg {
     /// Returns a `VerifierSetResponse` with the next verifier set id and the verifier set itself.
     #[returns(Option<VerifierSetResponse>)]
     NextVerifierSet,
+
+    /// Returns the currently active domain separator and all domain separators used so far, ordered by version.
+    #[returns(DomainSeparatorsResponse)]
+    DomainSeparators,
+
+    /// Returns the messages routed to this chain that have not been included in any signing session yet, in the order
+    /// of their ids, together with the number of blocks since they were routed. Pages are scanned on the gateway,
+    /// so a page can contain fewer than `limit` messages. Continue with `next_start_after` until it is `None`.
+    #[returns(UnsignedMessagesResponse)]
+    UnsignedMessages {
+        start_after: Option<CrossChainId>,
+        limit: Option<u32>,
+    },
 }
 
 #[cw_serde]
 pub enum ProofStatus {
     Pending,
     Completed { execute_data: HexBinary }, // encoded data and proof sent to destination gateway
+    // validity window of the proof has passed, the payload needs to be re-signed with ResignProof
+    Stale { superseded_by: Option<Uint64> },
+    // the multisig session was aborted before it was completed, the payload needs to be re-signed
+    Aborted { reason: String },
 }
 
 #[cw_serde]
@@ -59,6 +132,27 @@ pub struct ProofResponse {
     pub status: ProofStatus,
 }
 
+#[cw_serde]
+pub struct DomainSeparatorsResponse {
+    pub current: DomainSeparator,
+    pub history: Vec<DomainSeparator>,
+}
+
+#[cw_serde]
+pub struct UnsignedMessage {
+    pub message: Message,
+    /// Block height at which the message was first routed to this chain
+    pub routed_at: u64,
+    /// Number of blocks since the message was routed
+    pub age: u64,
+}
+
+#[cw_serde]
+pub struct UnsignedMessagesResponse {
+    pub messages: Vec<UnsignedMessage>,
+    pub next_start_after: Option<CrossChainId>,
+}
+
 #[cw_serde]
 pub struct VerifierSetResponse {
     pub id: String,
@@ -1,13 +1,17 @@
 use axelar_wasm_std::hash::Hash;
+use axelar_wasm_std::maintenance::MaintenanceWindow;
+use axelar_wasm_std::time::Timestamp;
 use axelar_wasm_std::MajorityThreshold;
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::Addr;
-use cw_storage_plus::{Item, Map};
+use cosmwasm_std::{Addr, Order, Storage, Uint128};
+use cw_storage_plus::{Item, Map, PrefixBound};
+use error_stack::{report, Result, ResultExt};
 use multisig::key::KeyType;
 use multisig::verifier_set::VerifierSet;
 use multisig_prover_api::encoding::Encoder;
-use router_api::ChainName;
+use router_api::{ChainName, CrossChainId};
 
+use crate::error::ContractError;
 use crate::payload::{Payload, PayloadId};
 
 #[cw_serde]
@@ -23,14 +27,227 @@ pub struct Config {
     pub verifier_set_diff_threshold: u32,
     pub encoder: Encoder,
     pub key_type: KeyType,
-    pub domain_separator: Hash,
+    #[serde(default)]
+    pub proof_validity: Option<u64>,
+    #[serde(default)]
+    pub min_signers: Option<u16>,
 }
 
 pub const CONFIG: Item<Config> = Item::new("config");
 pub const PAYLOAD: Map<&PayloadId, Payload> = Map::new("payload");
 pub const MULTISIG_SESSION_PAYLOAD: Map<u64, PayloadId> = Map::new("multisig_session_payload");
 
+/// Signatures collected for a proof after its multisig session completed.
+/// Signers can keep submitting signatures until the session expires, so this is updated on demand.
+#[cw_serde]
+pub struct ProofSignatures {
+    pub signature_count: u64,
+    pub signed_weight: Uint128,
+    pub updated_at: u64,
+    /// Minimum number of distinct signers of the session's verifier set
+    #[serde(default)]
+    pub min_signers: Option<u16>,
+}
+
+pub const PROOF_SIGNATURES: Map<u64, ProofSignatures> = Map::new("proof_signatures");
+
+/// Optional hints attached by the caller of `ConstructProofWithHints`. They are emitted with the proof events,
+/// so relayers can order deliveries by urgency. The prover doesn't enforce them in any way.
+#[cw_serde]
+#[derive(Default)]
+pub struct DeliveryHints {
+    /// Relative urgency of the batch, higher values are more urgent
+    pub priority: Option<u32>,
+    /// Time by which the proof should be executed on the destination chain
+    pub execute_by: Option<Timestamp>,
+}
+
+/// Delivery hints of each multisig session. Sessions started without hints have no entry
+pub const PROOF_HINTS: Map<u64, DeliveryHints> = Map::new("proof_hints");
+/// Keeps track of the delivery hints of the session being started to use during submessage reply
+pub const HINTS_TRACKER: Item<DeliveryHints> = Item::new("hints_tracker");
+
 pub const REPLY_TRACKER: Item<PayloadId> = Item::new("reply_tracker");
 
+/// Block height at which the proof of a multisig session becomes stale
+pub const PROOF_EXPIRY: Map<u64, u64> = Map::new("proof_expiry");
+/// Links a stale multisig session to the session that re-signed its payload
+pub const SUPERSEDED_PROOFS: Map<u64, u64> = Map::new("superseded_proofs");
+/// Keeps track of the stale multisig session being re-signed to use during submessage reply
+pub const RESIGN_TRACKER: Item<u64> = Item::new("resign_tracker");
+/// Most recent multisig session that includes a message. Messages signed before this was tracked have no entry
+pub const MESSAGE_SESSIONS: Map<&CrossChainId, u64> = Map::new("message_sessions");
+/// Messages ordered by the block height at which they were included in a multisig session, with the ID of that session,
+/// so the oldest entries of MESSAGE_SESSIONS can be pruned
+const MESSAGE_SESSIONS_BY_HEIGHT: Map<(u64, &CrossChainId), u64> =
+    Map::new("message_sessions_by_height");
+/// Number of blocks for which the session of a message is kept. The gateway only lists messages that were routed within
+/// the same number of blocks, so older entries can't affect the unsigned messages anymore
+pub const MESSAGE_SESSION_RETENTION: u64 = 1_000_000;
+/// Maximum number of outdated message sessions that are pruned whenever a new multisig session is started
+pub const MESSAGE_SESSION_PRUNE_LIMIT: usize = 100;
+
+/// Maintenance windows of the chain as scheduled by the coordinator
+pub const MAINTENANCE_WINDOWS: Item<Vec<MaintenanceWindow>> = Item::new("maintenance_windows");
+
 pub const CURRENT_VERIFIER_SET: Item<VerifierSet> = Item::new("current_verifier_set");
 pub const NEXT_VERIFIER_SET: Item<VerifierSet> = Item::new("next_verifier_set");
+
+/// A domain separator of the destination gateway, together with the height it was activated at
+#[cw_serde]
+pub struct DomainSeparator {
+    pub version: u64,
+    #[serde(with = "axelar_wasm_std::hex")]
+    #[schemars(with = "String")]
+    pub domain_separator: Hash,
+    pub activated_at: u64,
+}
+
+/// All domain separators the contract has used, by version. The one with the highest version is currently active
+pub const DOMAIN_SEPARATORS: Map<u64, DomainSeparator> = Map::new("domain_separators");
+/// Version of the domain separator that was active when a multisig session was started.
+/// Sessions started before domain separators could be rotated are not tracked and use the initial version
+pub const SESSION_DOMAIN_SEPARATOR: Map<u64, u64> = Map::new("session_domain_separator");
+
+pub fn current_domain_separator(storage: &dyn Storage) -> Result<DomainSeparator, ContractError> {
+    DOMAIN_SEPARATORS
+        .range(storage, None, None, Order::Descending)
+        .next()
+        .transpose()
+        .change_context(ContractError::StorageError)?
+        .map(|(_, domain_separator)| domain_separator)
+        .ok_or(report!(ContractError::NoDomainSeparator))
+}
+
+pub fn session_domain_separator(
+    storage: &dyn Storage,
+    multisig_session_id: u64,
+) -> Result<DomainSeparator, ContractError> {
+    let version = SESSION_DOMAIN_SEPARATOR
+        .may_load(storage, multisig_session_id)
+        .change_context(ContractError::StorageError)?
+        .unwrap_or_default();
+
+    DOMAIN_SEPARATORS
+        .may_load(storage, version)
+        .change_context(ContractError::StorageError)?
+        .ok_or(report!(ContractError::NoDomainSeparator))
+}
+
+/// Activates the given domain separator under the next version and returns it
+pub fn save_domain_separator(
+    storage: &mut dyn Storage,
+    domain_separator: Hash,
+    activated_at: u64,
+) -> Result<DomainSeparator, ContractError> {
+    let version = DOMAIN_SEPARATORS
+        .keys(storage, None, None, Order::Descending)
+        .next()
+        .transpose()
+        .change_context(ContractError::StorageError)?
+        .map_or(0, |latest| latest.saturating_add(1));
+
+    let domain_separator = DomainSeparator {
+        version,
+        domain_separator,
+        activated_at,
+    };
+    DOMAIN_SEPARATORS
+        .save(storage, version, &domain_separator)
+        .change_context(ContractError::StorageError)?;
+
+    Ok(domain_separator)
+}
+
+/// Records that the message was included in the given multisig session at the given block height
+pub fn save_message_session(
+    storage: &mut dyn Storage,
+    cc_id: &CrossChainId,
+    multisig_session_id: u64,
+    block_height: u64,
+) -> Result<(), ContractError> {
+    MESSAGE_SESSIONS
+        .save(storage, cc_id, &multisig_session_id)
+        .change_context(ContractError::StorageError)?;
+    MESSAGE_SESSIONS_BY_HEIGHT
+        .save(storage, (block_height, cc_id), &multisig_session_id)
+        .change_context(ContractError::StorageError)
+}
+
+/// Deletes up to `limit` message sessions that were recorded more than [MESSAGE_SESSION_RETENTION] blocks ago, oldest first.
+/// Messages that were signed again later keep their most recent session. Returns the number of pruned entries
+pub fn prune_message_sessions(
+    storage: &mut dyn Storage,
+    block_height: u64,
+    limit: usize,
+) -> Result<usize, ContractError> {
+    let Some(recorded_until) = block_height.checked_sub(MESSAGE_SESSION_RETENTION) else {
+        return Ok(0);
+    };
+
+    let outdated = MESSAGE_SESSIONS_BY_HEIGHT
+        .prefix_range(
+            storage,
+            None,
+            Some(PrefixBound::inclusive(recorded_until)),
+            Order::Ascending,
+        )
+        .take(limit)
+        .collect::<std::result::Result<Vec<_>, _>>()
+        .change_context(ContractError::StorageError)?;
+
+    for ((recorded_at, cc_id), multisig_session_id) in outdated.iter() {
+        MESSAGE_SESSIONS_BY_HEIGHT.remove(storage, (*recorded_at, cc_id));
+
+        if MESSAGE_SESSIONS
+            .may_load(storage, cc_id)
+            .change_context(ContractError::StorageError)?
+            == Some(*multisig_session_id)
+        {
+            MESSAGE_SESSIONS.remove(storage, cc_id);
+        }
+    }
+
+    Ok(outdated.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::mock_dependencies;
+
+    use super::*;
+
+    #[test]
+    fn prune_message_sessions_should_keep_sessions_of_messages_that_were_signed_again() {
+        let mut deps = mock_dependencies();
+        let resigned = &CrossChainId::new("ganache-1", "0x01-0").unwrap();
+        let signed_once = &CrossChainId::new("ganache-1", "0x02-0").unwrap();
+
+        save_message_session(deps.as_mut().storage, resigned, 1, 10).unwrap();
+        save_message_session(deps.as_mut().storage, signed_once, 1, 10).unwrap();
+        save_message_session(deps.as_mut().storage, resigned, 2, 500).unwrap();
+
+        assert_eq!(
+            prune_message_sessions(deps.as_mut().storage, MESSAGE_SESSION_RETENTION + 9, 10)
+                .unwrap(),
+            0
+        );
+        assert_eq!(
+            prune_message_sessions(deps.as_mut().storage, MESSAGE_SESSION_RETENTION + 10, 10)
+                .unwrap(),
+            2
+        );
+        assert_eq!(
+            MESSAGE_SESSIONS.may_load(&deps.storage, resigned).unwrap(),
+            Some(2)
+        );
+        assert!(!MESSAGE_SESSIONS.has(&deps.storage, signed_once));
+
+        assert_eq!(
+            prune_message_sessions(deps.as_mut().storage, MESSAGE_SESSION_RETENTION + 500, 10)
+                .unwrap(),
+            1
+        );
+        assert!(!MESSAGE_SESSIONS.has(&deps.storage, resigned));
+    }
+}
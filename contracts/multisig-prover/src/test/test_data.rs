@@ -72,6 +72,7 @@ pub fn new_verifier_set() -> VerifierSet {
         signers: btree_signers,
         threshold: Uint128::from(30u128),
         created_at: 1,
+        min_signers: None,
     }
 }
 
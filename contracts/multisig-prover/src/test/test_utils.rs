@@ -6,6 +6,7 @@ use multisig::multisig::Multisig;
 use multisig::types::MultisigState;
 use multisig::verifier_set::VerifierSet;
 use service_registry::VERIFIER_WEIGHT;
+use service_registry_api::msg::ActiveVerifiersPageResponse;
 use service_registry_api::{AuthorizationState, BondingState, Verifier, WeightedVerifier};
 
 use super::test_data::{self, TestOperator};
@@ -24,10 +25,10 @@ pub fn mock_querier_handler(
     verifier_set_status: VerificationStatus,
 ) -> impl Fn(&WasmQuery) -> QuerierResult {
     move |wq: &WasmQuery| match wq {
-        WasmQuery::Smart { contract_addr, .. }
+        WasmQuery::Smart { contract_addr, msg }
             if contract_addr == MockApi::default().addr_make(GATEWAY_ADDRESS).as_str() =>
         {
-            gateway_mock_querier_handler()
+            gateway_mock_querier_handler(from_json(msg).unwrap())
         }
         WasmQuery::Smart { contract_addr, msg }
             if contract_addr == MockApi::default().addr_make(MULTISIG_ADDRESS).as_str() =>
@@ -54,8 +55,27 @@ pub fn mock_querier_handler(
     }
 }
 
-fn gateway_mock_querier_handler() -> QuerierResult {
-    Ok(to_json_binary(&test_data::messages()).into()).into()
+fn gateway_mock_querier_handler(msg: gateway_api::msg::QueryMsg) -> QuerierResult {
+    let result = match msg {
+        gateway_api::msg::QueryMsg::OutgoingMessages(cc_ids) => to_json_binary(
+            &test_data::messages()
+                .into_iter()
+                .filter(|msg| cc_ids.contains(&msg.cc_id))
+                .collect::<Vec<_>>(),
+        ),
+        gateway_api::msg::QueryMsg::RoutedMessages { .. } => to_json_binary(
+            &test_data::messages()
+                .into_iter()
+                .map(|message| gateway_api::msg::RoutedMessage {
+                    message,
+                    routed_at: 1,
+                })
+                .collect::<Vec<_>>(),
+        ),
+        _ => panic!("unexpected query: {:?}", msg),
+    };
+
+    Ok(result.into()).into()
 }
 
 fn multisig_mock_querier_handler(
@@ -115,6 +135,7 @@ fn mock_multisig(operators: Vec<TestOperator>) -> Multisig {
         signers,
         threshold: quorum,
         created_at: 1,
+        min_signers: None,
     };
 
     Multisig {
@@ -141,28 +162,29 @@ fn service_registry_mock_querier_handler(
                 bond_denom: "uaxl".to_string(),
                 unbonding_period_days: 1,
                 description: "verifiers".to_string(),
+                exit_handover_blocks: 0,
             })
         }
-        service_registry_api::msg::QueryMsg::ActiveVerifiers {
-            service_name: _,
-            chain_name: _,
-        } => to_json_binary(
-            &operators
-                .clone()
-                .into_iter()
-                .map(|op| WeightedVerifier {
-                    verifier_info: Verifier {
-                        address: op.address,
-                        bonding_state: BondingState::Bonded {
-                            amount: op.weight.try_into().unwrap(),
+        service_registry_api::msg::QueryMsg::ActiveVerifiersPage { .. } => {
+            to_json_binary(&ActiveVerifiersPageResponse {
+                verifiers: operators
+                    .clone()
+                    .into_iter()
+                    .map(|op| WeightedVerifier {
+                        verifier_info: Verifier {
+                            address: op.address,
+                            bonding_state: BondingState::Bonded {
+                                amount: op.weight.try_into().unwrap(),
+                            },
+                            authorization_state: AuthorizationState::Authorized,
+                            service_name: SERVICE_NAME.to_string(),
                         },
-                        authorization_state: AuthorizationState::Authorized,
-                        service_name: SERVICE_NAME.to_string(),
-                    },
-                    weight: VERIFIER_WEIGHT,
-                })
-                .collect::<Vec<WeightedVerifier>>(),
-        ),
+                        weight: VERIFIER_WEIGHT,
+                    })
+                    .collect(),
+                next_start_after: None,
+            })
+        }
         _ => panic!("unexpected query: {:?}", msg),
     };
     Ok(result.into()).into()
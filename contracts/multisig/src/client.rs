@@ -1,12 +1,13 @@
 use std::collections::HashMap;
 
+use axelar_wasm_std::{bounded, nonempty};
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{CosmosMsg, HexBinary, Uint64};
 use error_stack::{Result, ResultExt};
 use router_api::ChainName;
 
 use crate::key::{KeyType, PublicKey};
-use crate::msg::{ExecuteMsg, QueryMsg};
+use crate::msg::{ExecuteMsg, QueryMsg, MAX_REASON_LENGTH};
 use crate::multisig::Multisig;
 use crate::verifier_set::VerifierSet;
 
@@ -16,6 +17,9 @@ pub enum Error {
     #[error("failed to query multisig contract for multisig session. session_id: {0}")]
     MultisigSession(Uint64),
 
+    #[error("failed to query multisig contract for batch multisig session. session_id: {0}")]
+    BatchMultisigSession(Uint64),
+
     #[error("failed to query multisig contract for verifier set: verifier_set_id: {0}")]
     VerifierSet(String),
 
@@ -30,6 +34,9 @@ pub enum Error {
         contract_address: String,
         chain_name: ChainName,
     },
+
+    #[error("failed to query multisig contract for open signing sessions. verifier_address: {0}")]
+    HasOpenSigningSessions(String),
 }
 
 impl<'a> From<client::ContractClient<'a, ExecuteMsg, QueryMsg>> for Client<'a> {
@@ -42,6 +49,7 @@ impl From<QueryMsg> for Error {
     fn from(value: QueryMsg) -> Self {
         match value {
             QueryMsg::Multisig { session_id } => Error::MultisigSession(session_id),
+            QueryMsg::BatchMultisig { session_id } => Error::BatchMultisigSession(session_id),
             QueryMsg::VerifierSet { verifier_set_id } => Error::VerifierSet(verifier_set_id),
             QueryMsg::PublicKey {
                 verifier_address,
@@ -57,6 +65,9 @@ impl From<QueryMsg> for Error {
                 contract_address,
                 chain_name,
             },
+            QueryMsg::HasOpenSigningSessions { verifier_address } => {
+                Error::HasOpenSigningSessions(verifier_address)
+            }
         }
     }
 }
@@ -81,6 +92,30 @@ impl Client<'_> {
         })
     }
 
+    pub fn start_batch_signing_session(
+        &self,
+        verifier_set_id: String,
+        msgs: nonempty::Vec<HexBinary>,
+        chain_name: ChainName,
+        sig_verifier: Option<String>,
+    ) -> CosmosMsg {
+        self.client.execute(&ExecuteMsg::StartBatchSigningSession {
+            verifier_set_id,
+            msgs,
+            chain_name,
+            sig_verifier,
+        })
+    }
+
+    pub fn abort_signing_session(
+        &self,
+        session_id: Uint64,
+        reason: bounded::String<MAX_REASON_LENGTH>,
+    ) -> CosmosMsg {
+        self.client
+            .execute(&ExecuteMsg::AbortSigningSession { session_id, reason })
+    }
+
     pub fn submit_signature(&self, session_id: Uint64, signature: HexBinary) -> CosmosMsg {
         self.client.execute(&ExecuteMsg::SubmitSignature {
             session_id,
@@ -88,6 +123,17 @@ impl Client<'_> {
         })
     }
 
+    pub fn submit_signatures(
+        &self,
+        session_id: Uint64,
+        signatures: nonempty::Vec<HexBinary>,
+    ) -> CosmosMsg {
+        self.client.execute(&ExecuteMsg::SubmitSignatures {
+            session_id,
+            signatures,
+        })
+    }
+
     pub fn register_verifier_set(&self, verifier_set: VerifierSet) -> CosmosMsg {
         self.client
             .execute(&ExecuteMsg::RegisterVerifierSet { verifier_set })
@@ -96,11 +142,11 @@ impl Client<'_> {
     pub fn register_public_key(
         &self,
         public_key: PublicKey,
-        signed_sender_address: HexBinary,
+        proof_of_possession: HexBinary,
     ) -> CosmosMsg {
         self.client.execute(&ExecuteMsg::RegisterPublicKey {
             public_key,
-            signed_sender_address,
+            proof_of_possession,
         })
     }
 
@@ -127,6 +173,11 @@ impl Client<'_> {
         self.client.query(&msg).change_context_lazy(|| msg.into())
     }
 
+    pub fn batch_multisig(&self, session_id: Uint64) -> Result<Vec<Multisig>, Error> {
+        let msg = QueryMsg::BatchMultisig { session_id };
+        self.client.query(&msg).change_context_lazy(|| msg.into())
+    }
+
     pub fn verifier_set(&self, verifier_set_id: String) -> Result<VerifierSet, Error> {
         let msg = QueryMsg::VerifierSet { verifier_set_id };
         self.client.query(&msg).change_context_lazy(|| msg.into())
@@ -155,6 +206,11 @@ impl Client<'_> {
         };
         self.client.query(&msg).change_context_lazy(|| msg.into())
     }
+
+    pub fn has_open_signing_sessions(&self, verifier_address: String) -> Result<bool, Error> {
+        let msg = QueryMsg::HasOpenSigningSessions { verifier_address };
+        self.client.query(&msg).change_context_lazy(|| msg.into())
+    }
 }
 
 #[cfg(test)]
@@ -196,6 +252,30 @@ mod test {
         goldie::assert_json!(res.unwrap());
     }
 
+    #[test]
+    fn query_batch_multisig_session_returns_error_when_query_errors() {
+        let (querier, addr) = setup_queries_to_fail();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+
+        let session_id: Uint64 = 1u64.into();
+        let res = client.batch_multisig(session_id);
+        assert!(res.is_err());
+        goldie::assert!(res.unwrap_err().to_string());
+    }
+
+    #[test]
+    fn query_batch_multisig_session_returns_sessions() {
+        let (querier, addr) = setup_queries_to_succeed();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+
+        let session_id: Uint64 = 1u64.into();
+        let res = client.batch_multisig(session_id);
+        assert!(res.is_ok());
+        assert_eq!(res.unwrap(), vec![multisig(), multisig()]);
+    }
+
     #[test]
     fn query_verifier_set_returns_error_when_query_errors() {
         let (querier, addr) = setup_queries_to_fail();
@@ -272,6 +352,30 @@ mod test {
         goldie::assert_json!(res.unwrap());
     }
 
+    #[test]
+    fn query_has_open_signing_sessions_returns_error_when_query_errors() {
+        let (querier, addr) = setup_queries_to_fail();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+
+        let verifier_address = MockApi::default().addr_make("verifier").to_string();
+        let res = client.has_open_signing_sessions(verifier_address);
+        assert!(res.is_err());
+        goldie::assert!(res.unwrap_err().to_string());
+    }
+
+    #[test]
+    fn query_has_open_signing_sessions_returns_open_signing_sessions() {
+        let (querier, addr) = setup_queries_to_succeed();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+
+        let verifier_address = MockApi::default().addr_make("verifier").to_string();
+        let res = client.has_open_signing_sessions(verifier_address);
+        assert!(res.is_ok());
+        goldie::assert_json!(res.unwrap());
+    }
+
     fn setup_queries_to_fail() -> (MockQuerier, Addr) {
         let addr = "multisig";
 
@@ -299,26 +403,12 @@ mod test {
             {
                 let msg = from_json::<QueryMsg>(msg).unwrap();
                 match msg {
-                    QueryMsg::Multisig { session_id: _ } => Ok(to_json_binary(&Multisig {
-                        state: MultisigState::Completed { completed_at: 1 },
-                        verifier_set: build_verifier_set(
-                            crate::key::KeyType::Ecdsa,
-                            &ecdsa_test_data::signers(),
-                        ),
-
-                        signatures: ecdsa_test_data::signers()
-                            .into_iter()
-                            .map(|signer| {
-                                (
-                                    signer.address.to_string(),
-                                    Signature::try_from((KeyType::Ecdsa, signer.signature))
-                                        .unwrap(),
-                                )
-                            })
-                            .collect(),
-                    })
-                    .into())
-                    .into(),
+                    QueryMsg::Multisig { session_id: _ } => {
+                        Ok(to_json_binary(&multisig()).into()).into()
+                    }
+                    QueryMsg::BatchMultisig { session_id: _ } => {
+                        Ok(to_json_binary(&vec![multisig(), multisig()]).into()).into()
+                    }
                     QueryMsg::VerifierSet { verifier_set_id: _ } => Ok(to_json_binary(
                         &build_verifier_set(KeyType::Ecdsa, &ecdsa_test_data::signers()),
                     )
@@ -336,6 +426,9 @@ mod test {
                         contract_address: _,
                         chain_name: _,
                     } => Ok(to_json_binary(&true).into()).into(),
+                    QueryMsg::HasOpenSigningSessions {
+                        verifier_address: _,
+                    } => Ok(to_json_binary(&false).into()).into(),
                 }
             }
             _ => panic!("unexpected query: {:?}", msg),
@@ -343,4 +436,20 @@ mod test {
 
         (querier, MockApi::default().addr_make(addr))
     }
+
+    fn multisig() -> Multisig {
+        Multisig {
+            state: MultisigState::Completed { completed_at: 1 },
+            verifier_set: build_verifier_set(KeyType::Ecdsa, &ecdsa_test_data::signers()),
+            signatures: ecdsa_test_data::signers()
+                .into_iter()
+                .map(|signer| {
+                    (
+                        signer.address.to_string(),
+                        Signature::try_from((KeyType::Ecdsa, signer.signature)).unwrap(),
+                    )
+                })
+                .collect(),
+        }
+    }
 }
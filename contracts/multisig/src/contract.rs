@@ -48,6 +48,10 @@ pub fn instantiate(
     let config = Config {
         rewards_contract: address::validate_cosmwasm_address(deps.api, &msg.rewards_address)?,
         block_expiry: msg.block_expiry,
+        coordinator: msg
+            .coordinator_address
+            .map(|addr| address::validate_cosmwasm_address(deps.api, &addr))
+            .transpose()?,
     };
     CONFIG.save(deps.storage, &config)?;
 
@@ -67,6 +71,7 @@ pub fn execute(
         deps.storage,
         &info.sender,
         can_start_signing_session(&info.sender),
+        match_coordinator,
     )? {
         ExecuteMsg::StartSigningSession {
             verifier_set_id,
@@ -80,6 +85,7 @@ pub fn execute(
             execute::start_signing_session(
                 deps,
                 env,
+                info,
                 verifier_set_id,
                 msg.try_into()
                     .map_err(axelar_wasm_std::error::ContractError::from)?,
@@ -87,17 +93,52 @@ pub fn execute(
                 sig_verifier,
             )
         }
+        ExecuteMsg::StartBatchSigningSession {
+            verifier_set_id,
+            msgs,
+            chain_name,
+            sig_verifier,
+        } => {
+            let sig_verifier = sig_verifier
+                .map(|addr| address::validate_cosmwasm_address(deps.api, &addr))
+                .transpose()?;
+            let msgs: Vec<MsgToSign> = Vec::from(msgs)
+                .into_iter()
+                .map(MsgToSign::try_from)
+                .try_collect()
+                .map_err(axelar_wasm_std::error::ContractError::from)?;
+            execute::start_batch_signing_session(
+                deps,
+                env,
+                info,
+                verifier_set_id,
+                msgs.try_into()
+                    .expect("violated invariant: batch must not be empty"),
+                chain_name,
+                sig_verifier,
+            )
+        }
+        ExecuteMsg::AbortSigningSession { session_id, reason } => {
+            execute::abort_signing_session(deps, env, info, session_id, reason.into())
+        }
         ExecuteMsg::SubmitSignature {
             session_id,
             signature,
         } => execute::submit_signature(deps, env, info, session_id, signature),
+        ExecuteMsg::SubmitSignatures {
+            session_id,
+            signatures,
+        } => execute::submit_signatures(deps, env, info, session_id, signatures),
         ExecuteMsg::RegisterVerifierSet { verifier_set } => {
             execute::register_verifier_set(deps, verifier_set)
         }
         ExecuteMsg::RegisterPublicKey {
             public_key,
-            signed_sender_address,
-        } => execute::register_pub_key(deps, info, public_key, signed_sender_address),
+            proof_of_possession,
+        } => execute::register_pub_key(deps, env, info, public_key, proof_of_possession),
+        ExecuteMsg::RegisterPublicKeys { registrations } => {
+            execute::register_pub_keys(deps, env, registrations.into())
+        }
         ExecuteMsg::AuthorizeCallers { contracts } => {
             let contracts = validate_contract_addresses(&deps, contracts)?;
             execute::authorize_callers(deps, contracts)
@@ -106,6 +147,9 @@ pub fn execute(
             let contracts = validate_contract_addresses(&deps, contracts)?;
             execute::unauthorize_callers(deps, contracts)
         }
+        ExecuteMsg::PruneBatchSessions { entry_limit } => {
+            execute::prune_batch_sessions(deps, env, entry_limit)
+        }
         ExecuteMsg::DisableSigning => execute::disable_signing(deps),
         ExecuteMsg::EnableSigning => execute::enable_signing(deps),
     }?
@@ -132,7 +176,8 @@ fn can_start_signing_session(
 ) -> impl FnOnce(&dyn Storage, &ExecuteMsg) -> error_stack::Result<Addr, permission_control::Error> + '_
 {
     |storage, msg| match msg {
-        ExecuteMsg::StartSigningSession { chain_name, .. } => {
+        ExecuteMsg::StartSigningSession { chain_name, .. }
+        | ExecuteMsg::StartBatchSigningSession { chain_name, .. } => {
             execute::require_authorized_caller(storage, sender, chain_name)
                 .change_context(permission_control::Error::Unauthorized)
         }
@@ -140,14 +185,28 @@ fn can_start_signing_session(
     }
 }
 
+fn match_coordinator(
+    storage: &dyn Storage,
+    _: &ExecuteMsg,
+) -> error_stack::Result<Addr, permission_control::Error> {
+    CONFIG
+        .load(storage)
+        .change_context(permission_control::Error::Unauthorized)?
+        .coordinator
+        .ok_or_else(|| report!(permission_control::Error::Unauthorized))
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(
     deps: Deps,
-    _env: Env,
+    env: Env,
     msg: QueryMsg,
 ) -> Result<Binary, axelar_wasm_std::error::ContractError> {
     match msg {
         QueryMsg::Multisig { session_id } => to_json_binary(&query::multisig(deps, session_id)?)?,
+        QueryMsg::BatchMultisig { session_id } => {
+            to_json_binary(&query::batch_multisig(deps, session_id)?)?
+        }
         QueryMsg::VerifierSet { verifier_set_id } => {
             to_json_binary(&query::verifier_set(deps, verifier_set_id)?)?
         }
@@ -167,6 +226,13 @@ pub fn query(
             address::validate_cosmwasm_address(deps.api, &contract_address)?,
             chain_name,
         )?)?,
+        QueryMsg::HasOpenSigningSessions { verifier_address } => {
+            to_json_binary(&query::has_open_signing_sessions(
+                deps,
+                env.block.height,
+                address::validate_cosmwasm_address(deps.api, &verifier_address)?,
+            )?)?
+        }
     }
     .then(Ok)
 }
@@ -180,14 +246,18 @@ mod tests {
     use cosmwasm_std::testing::{
         message_info, mock_dependencies, mock_env, MockApi, MockQuerier, MockStorage,
     };
-    use cosmwasm_std::{from_json, Addr, Empty, OwnedDeps, WasmMsg};
+    use cosmwasm_std::{from_json, Addr, CosmosMsg, Empty, OwnedDeps, WasmMsg};
     use k256::elliptic_curve::rand_core;
     use permission_control::Permission;
     use router_api::ChainName;
     use serde_json::from_str;
 
     use super::*;
-    use crate::key::{KeyType, PublicKey, Signature};
+    use k256::ecdsa::signature::hazmat::PrehashSigner;
+    use sha3::{Digest, Keccak256};
+
+    use crate::key::{registration_challenge, KeyType, PublicKey, Signature};
+    use crate::msg::{PublicKeyRegistration, PublicKeyRegistrationResult};
     use crate::multisig::Multisig;
     use crate::state::load_session_signatures;
     use crate::test::common::{build_verifier_set, ecdsa_test_data, ed25519_test_data, TestSigner};
@@ -199,6 +269,7 @@ mod tests {
     const REWARDS_CONTRACT: &str = "rewards";
     const GOVERNANCE: &str = "governance";
     const ADMIN: &str = "admin";
+    const COORDINATOR: &str = "coordinator";
 
     const SIGNATURE_BLOCK_EXPIRY: u64 = 100;
 
@@ -217,6 +288,7 @@ mod tests {
             admin_address: admin.into_string(),
             rewards_address: rewards.into_string(),
             block_expiry: SIGNATURE_BLOCK_EXPIRY.try_into().unwrap(),
+            coordinator_address: Some(api.addr_make(COORDINATOR).into_string()),
         };
 
         instantiate(deps, env, info, msg)
@@ -288,19 +360,80 @@ mod tests {
         execute(deps, env, message_info(&signer.address, &[]), msg)
     }
 
+    fn do_abort_signing_session(
+        deps: DepsMut,
+        sender: Addr,
+        session_id: Uint64,
+    ) -> Result<Response, axelar_wasm_std::error::ContractError> {
+        let msg = ExecuteMsg::AbortSigningSession {
+            session_id,
+            reason: "payload is obsolete".try_into().unwrap(),
+        };
+        execute(deps, mock_env(), message_info(&sender, &[]), msg)
+    }
+
+    fn do_start_batch_signing_session(
+        deps: DepsMut,
+        sender: Addr,
+        verifier_set_id: &str,
+        chain_name: ChainName,
+        batch_size: usize,
+    ) -> Result<Response, axelar_wasm_std::error::ContractError> {
+        let info = message_info(&sender, &[]);
+        let env = mock_env();
+
+        // the test signers only have signatures for a single message, so the batch repeats it
+        let msg = ExecuteMsg::StartBatchSigningSession {
+            verifier_set_id: verifier_set_id.to_string(),
+            msgs: vec![ecdsa_test_data::message(); batch_size]
+                .try_into()
+                .unwrap(),
+            chain_name,
+            sig_verifier: None,
+        };
+        execute(deps, env, info, msg)
+    }
+
+    fn do_sign_batch(
+        deps: DepsMut,
+        env: Env,
+        session_id: Uint64,
+        signer: &TestSigner,
+        batch_size: usize,
+    ) -> Result<Response, axelar_wasm_std::error::ContractError> {
+        let msg = ExecuteMsg::SubmitSignatures {
+            session_id,
+            signatures: vec![signer.signature.clone(); batch_size]
+                .try_into()
+                .unwrap(),
+        };
+        execute(deps, env, message_info(&signer.address, &[]), msg)
+    }
+
     fn do_register_key(
         deps: DepsMut,
         verifier: Addr,
         public_key: PublicKey,
-        signed_sender_address: HexBinary,
+        proof_of_possession: HexBinary,
     ) -> Result<Response, axelar_wasm_std::error::ContractError> {
         let msg = ExecuteMsg::RegisterPublicKey {
             public_key,
-            signed_sender_address,
+            proof_of_possession,
         };
         execute(deps, mock_env(), message_info(&verifier, &[]), msg)
     }
 
+    fn do_register_keys(
+        deps: DepsMut,
+        sender: Addr,
+        registrations: Vec<PublicKeyRegistration>,
+    ) -> Result<Response, axelar_wasm_std::error::ContractError> {
+        let msg = ExecuteMsg::RegisterPublicKeys {
+            registrations: registrations.try_into().unwrap(),
+        };
+        execute(deps, mock_env(), message_info(&sender, &[]), msg)
+    }
+
     fn do_authorize_callers(
         deps: DepsMut,
         contracts: Vec<(Addr, ChainName)>,
@@ -721,6 +854,276 @@ mod tests {
         }
     }
 
+    #[test]
+    fn submit_signatures_completes_each_message_of_batch_session() {
+        let (mut deps, ecdsa_subkey, ed25519_subkey) = setup();
+        let api = deps.api;
+        let chain_name: ChainName = "mock-chain".parse().unwrap();
+        do_authorize_callers(
+            deps.as_mut(),
+            vec![(api.addr_make(PROVER), chain_name.clone())],
+        )
+        .unwrap();
+
+        let batch_size = 3;
+
+        for (key_type, subkey, signers, session_id) in
+            signature_test_data(&ecdsa_subkey, &ed25519_subkey)
+        {
+            let res = do_start_batch_signing_session(
+                deps.as_mut(),
+                api.addr_make(PROVER),
+                subkey,
+                chain_name.clone(),
+                batch_size,
+            )
+            .unwrap();
+
+            let event = res.events.first().unwrap();
+            assert_eq!(event.ty, "batch_signing_started".to_string());
+            assert_eq!(
+                event_attribute(event, "session_id").unwrap(),
+                session_id.to_string()
+            );
+
+            let signer = signers.first().unwrap().to_owned();
+            let res =
+                do_sign_batch(deps.as_mut(), mock_env(), session_id, &signer, batch_size).unwrap();
+            assert_eq!(res.events.len(), 1);
+            assert_eq!(res.events[0].ty, "batch_signatures_submitted".to_string());
+
+            let env = mock_env();
+            let expected_completed_at = env.block.height;
+
+            let signer = signers.get(1).unwrap().to_owned();
+            let res = do_sign_batch(deps.as_mut(), env, session_id, &signer, batch_size).unwrap();
+
+            let completed_messages = res
+                .events
+                .iter()
+                .filter(|event| event.ty == "batch_message_signing_completed")
+                .map(|event| event_attribute(event, "index").unwrap())
+                .collect::<Vec<_>>();
+            assert_eq!(completed_messages, vec!["0", "1", "2"]);
+            assert_eq!(res.events.last().unwrap().ty, "signing_completed");
+
+            let session = SIGNING_SESSIONS
+                .load(deps.as_ref().storage, session_id.into())
+                .unwrap();
+            assert_eq!(
+                session.state,
+                MultisigState::Completed {
+                    completed_at: expected_completed_at
+                }
+            );
+
+            let res = query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::BatchMultisig { session_id },
+            )
+            .unwrap();
+            let multisigs: Vec<Multisig> = from_json(res).unwrap();
+
+            assert_eq!(multisigs.len(), batch_size);
+            for multisig in multisigs {
+                assert_eq!(
+                    multisig.state,
+                    MultisigState::Completed {
+                        completed_at: expected_completed_at
+                    }
+                );
+                assert_eq!(multisig.signatures.len(), 2);
+                assert_eq!(
+                    multisig.signatures.get(signer.address.as_str()).unwrap(),
+                    &Signature::try_from((key_type, signer.signature.clone())).unwrap()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn submit_signatures_fails_if_signature_count_does_not_match_batch() {
+        let (mut deps, ecdsa_subkey, _) = setup();
+        let api = deps.api;
+        let chain_name: ChainName = "mock-chain".parse().unwrap();
+        do_authorize_callers(
+            deps.as_mut(),
+            vec![(api.addr_make(PROVER), chain_name.clone())],
+        )
+        .unwrap();
+        do_start_batch_signing_session(
+            deps.as_mut(),
+            api.addr_make(PROVER),
+            &ecdsa_subkey,
+            chain_name.clone(),
+            2,
+        )
+        .unwrap();
+
+        let session_id = Uint64::one();
+        let signer = ecdsa_test_data::signers().first().unwrap().to_owned();
+
+        let res = do_sign_batch(deps.as_mut(), mock_env(), session_id, &signer, 3);
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            axelar_wasm_std::error::ContractError::from(ContractError::SignatureCountMismatch {
+                session_id,
+                expected: 2,
+                actual: 3,
+            })
+            .to_string()
+        );
+
+        let res = do_sign(deps.as_mut(), mock_env(), session_id, &signer);
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            axelar_wasm_std::error::ContractError::from(ContractError::SignatureCountMismatch {
+                session_id,
+                expected: 2,
+                actual: 1,
+            })
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn multisig_query_fails_for_batch_session() {
+        let (mut deps, ecdsa_subkey, _) = setup();
+        let api = deps.api;
+        let chain_name: ChainName = "mock-chain".parse().unwrap();
+        do_authorize_callers(
+            deps.as_mut(),
+            vec![(api.addr_make(PROVER), chain_name.clone())],
+        )
+        .unwrap();
+        do_start_batch_signing_session(
+            deps.as_mut(),
+            api.addr_make(PROVER),
+            &ecdsa_subkey,
+            chain_name,
+            2,
+        )
+        .unwrap();
+
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Multisig {
+                session_id: Uint64::one(),
+            },
+        );
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn prune_batch_sessions_removes_expired_sessions() {
+        let (mut deps, ecdsa_subkey, _) = setup();
+        let api = deps.api;
+        let chain_name: ChainName = "mock-chain".parse().unwrap();
+        do_authorize_callers(
+            deps.as_mut(),
+            vec![(api.addr_make(PROVER), chain_name.clone())],
+        )
+        .unwrap();
+
+        let batch_size = 3;
+        do_start_batch_signing_session(
+            deps.as_mut(),
+            api.addr_make(PROVER),
+            &ecdsa_subkey,
+            chain_name,
+            batch_size,
+        )
+        .unwrap();
+
+        let session_id = Uint64::one();
+        for signer in ecdsa_test_data::signers().iter().take(2) {
+            do_sign_batch(deps.as_mut(), mock_env(), session_id, signer, batch_size).unwrap();
+        }
+
+        let prune = |deps: DepsMut, height: u64, entry_limit: Option<u32>| {
+            let mut env = mock_env();
+            env.block.height = height;
+            let res = execute(
+                deps,
+                env,
+                message_info(&api.addr_make("anyone"), &[]),
+                ExecuteMsg::PruneBatchSessions { entry_limit },
+            )
+            .unwrap();
+            let event = res.events.first().unwrap().clone();
+            (
+                event_attribute(&event, "session_ids").unwrap().to_string(),
+                event_attribute(&event, "signatures_pruned")
+                    .unwrap()
+                    .to_string(),
+            )
+        };
+
+        let expires_at = mock_env().block.height + SIGNATURE_BLOCK_EXPIRY;
+        let retained_until = expires_at + execute::BATCH_SESSION_RETENTION;
+
+        assert_eq!(
+            prune(deps.as_mut(), retained_until, None),
+            ("[]".to_string(), "0".to_string())
+        );
+
+        assert_eq!(
+            prune(deps.as_mut(), retained_until + 1, Some(4)),
+            ("[]".to_string(), "4".to_string())
+        );
+        assert!(query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::BatchMultisig { session_id }
+        )
+        .is_ok());
+
+        assert_eq!(
+            prune(deps.as_mut(), retained_until + 1, None),
+            ("[\"1\"]".to_string(), "2".to_string())
+        );
+        assert!(query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::BatchMultisig { session_id }
+        )
+        .is_err());
+        assert!(SIGNING_SESSIONS.has(deps.as_ref().storage, session_id.u64()));
+    }
+
+    #[test]
+    fn submit_signatures_fails_for_single_message_session() {
+        let (mut deps, ecdsa_subkey, _) = setup();
+        let api = deps.api;
+        let chain_name: ChainName = "mock-chain".parse().unwrap();
+        do_authorize_callers(
+            deps.as_mut(),
+            vec![(api.addr_make(PROVER), chain_name.clone())],
+        )
+        .unwrap();
+        do_start_signing_session(
+            deps.as_mut(),
+            api.addr_make(PROVER),
+            &ecdsa_subkey,
+            chain_name.clone(),
+        )
+        .unwrap();
+
+        let session_id = Uint64::one();
+        let signer = ecdsa_test_data::signers().first().unwrap().to_owned();
+        let res = do_sign_batch(deps.as_mut(), mock_env(), session_id, &signer, 1);
+
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            axelar_wasm_std::error::ContractError::from(ContractError::NotABatchSession {
+                session_id
+            })
+            .to_string()
+        );
+    }
+
     #[test]
     fn submit_signature_before_expiry() {
         let (mut deps, ecdsa_subkey, ed25519_subkey) = setup();
@@ -823,6 +1226,74 @@ mod tests {
         }
     }
 
+    #[test]
+    fn has_open_signing_sessions_until_verifier_signed_or_session_expired() {
+        let (mut deps, ecdsa_subkey, _) = setup();
+        let api = deps.api;
+        let chain_name: ChainName = "mock-chain".parse().unwrap();
+        do_authorize_callers(
+            deps.as_mut(),
+            vec![(api.addr_make(PROVER), chain_name.clone())],
+        )
+        .unwrap();
+
+        let has_open_signing_sessions = |deps: Deps, env: Env, verifier: &Addr| -> bool {
+            from_json(
+                query(
+                    deps,
+                    env,
+                    QueryMsg::HasOpenSigningSessions {
+                        verifier_address: verifier.to_string(),
+                    },
+                )
+                .unwrap(),
+            )
+            .unwrap()
+        };
+
+        do_start_signing_session(
+            deps.as_mut(),
+            api.addr_make(PROVER),
+            &ecdsa_subkey,
+            chain_name,
+        )
+        .unwrap();
+
+        let signers = ecdsa_test_data::signers();
+        let signer = signers.first().unwrap();
+        let other_signer = signers.get(1).unwrap();
+        assert!(has_open_signing_sessions(
+            deps.as_ref(),
+            mock_env(),
+            &signer.address
+        ));
+        assert!(!has_open_signing_sessions(
+            deps.as_ref(),
+            mock_env(),
+            &api.addr_make("not_a_signer")
+        ));
+
+        do_sign(deps.as_mut(), mock_env(), Uint64::one(), signer).unwrap();
+        assert!(!has_open_signing_sessions(
+            deps.as_ref(),
+            mock_env(),
+            &signer.address
+        ));
+        assert!(has_open_signing_sessions(
+            deps.as_ref(),
+            mock_env(),
+            &other_signer.address
+        ));
+
+        let mut env = mock_env();
+        env.block.height += SIGNATURE_BLOCK_EXPIRY + 1;
+        assert!(!has_open_signing_sessions(
+            deps.as_ref(),
+            env,
+            &other_signer.address
+        ));
+    }
+
     #[test]
     fn submit_signature_wrong_session_id() {
         let (mut deps, ecdsa_subkey, _) = setup();
@@ -854,6 +1325,159 @@ mod tests {
         );
     }
 
+    #[test]
+    fn abort_signing_session_rejects_late_signatures() {
+        let (mut deps, ecdsa_subkey, _) = setup();
+        let api = deps.api;
+        let chain_name: ChainName = "mock-chain".parse().unwrap();
+        do_authorize_callers(
+            deps.as_mut(),
+            vec![(api.addr_make(PROVER), chain_name.clone())],
+        )
+        .unwrap();
+        do_start_signing_session(
+            deps.as_mut(),
+            api.addr_make(PROVER),
+            &ecdsa_subkey,
+            chain_name.clone(),
+        )
+        .unwrap();
+
+        let session_id = Uint64::one();
+        let signers = ecdsa_test_data::signers();
+        do_sign(deps.as_mut(), mock_env(), session_id, &signers[0]).unwrap();
+
+        let res =
+            do_abort_signing_session(deps.as_mut(), api.addr_make(PROVER), session_id).unwrap();
+
+        // the signers that can no longer sign are credited for the session
+        let unsigned = &signers[1..];
+        assert_eq!(res.messages.len(), unsigned.len());
+        for signer in unsigned {
+            let expected_rewards_msg: CosmosMsg = WasmMsg::Execute {
+                contract_addr: api.addr_make(REWARDS_CONTRACT).to_string(),
+                msg: to_json_binary(&rewards::msg::ExecuteMsg::RecordParticipation {
+                    chain_name: chain_name.clone(),
+                    event_id: session_id.to_string().try_into().unwrap(),
+                    verifier_address: signer.address.clone().into(),
+                })
+                .unwrap(),
+                funds: vec![],
+            }
+            .into();
+            assert!(res.messages.iter().any(|m| m.msg == expected_rewards_msg));
+        }
+
+        let event = res
+            .events
+            .iter()
+            .find(|event| event.ty == "signing_aborted")
+            .unwrap();
+        assert_eq!(
+            event_attribute(event, "session_id").unwrap(),
+            session_id.to_string()
+        );
+        assert_eq!(
+            event_attribute(event, "reason").unwrap(),
+            "payload is obsolete"
+        );
+        assert_eq!(
+            event_attribute(event, "chain").unwrap(),
+            chain_name.to_string()
+        );
+
+        let session = SIGNING_SESSIONS
+            .load(deps.as_ref().storage, session_id.u64())
+            .unwrap();
+        assert_eq!(
+            session.state,
+            MultisigState::Aborted {
+                aborted_at: mock_env().block.height,
+                reason: "payload is obsolete".to_string(),
+            }
+        );
+
+        let res = do_sign(deps.as_mut(), mock_env(), session_id, &signers[1]);
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            axelar_wasm_std::error::ContractError::from(ContractError::SigningSessionAborted {
+                session_id
+            })
+            .to_string()
+        );
+
+        let res = do_abort_signing_session(deps.as_mut(), api.addr_make(PROVER), session_id);
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            axelar_wasm_std::error::ContractError::from(ContractError::SigningSessionNotPending {
+                session_id
+            })
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn abort_signing_session_fails_if_sender_did_not_start_it() {
+        let (mut deps, ecdsa_subkey, _) = setup();
+        let api = deps.api;
+        let chain_name: ChainName = "mock-chain".parse().unwrap();
+        do_authorize_callers(
+            deps.as_mut(),
+            vec![
+                (api.addr_make(PROVER), chain_name.clone()),
+                (api.addr_make("other prover"), chain_name.clone()),
+            ],
+        )
+        .unwrap();
+        do_start_signing_session(
+            deps.as_mut(),
+            api.addr_make(PROVER),
+            &ecdsa_subkey,
+            chain_name.clone(),
+        )
+        .unwrap();
+
+        let res =
+            do_abort_signing_session(deps.as_mut(), api.addr_make("other prover"), Uint64::one());
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            axelar_wasm_std::error::ContractError::from(ContractError::Unauthorized).to_string()
+        );
+    }
+
+    #[test]
+    fn abort_signing_session_fails_if_session_is_completed() {
+        let (mut deps, ecdsa_subkey, _) = setup();
+        let api = deps.api;
+        let chain_name: ChainName = "mock-chain".parse().unwrap();
+        do_authorize_callers(
+            deps.as_mut(),
+            vec![(api.addr_make(PROVER), chain_name.clone())],
+        )
+        .unwrap();
+        do_start_signing_session(
+            deps.as_mut(),
+            api.addr_make(PROVER),
+            &ecdsa_subkey,
+            chain_name.clone(),
+        )
+        .unwrap();
+
+        let session_id = Uint64::one();
+        for signer in ecdsa_test_data::signers().iter().take(2) {
+            do_sign(deps.as_mut(), mock_env(), session_id, signer).unwrap();
+        }
+
+        let res = do_abort_signing_session(deps.as_mut(), api.addr_make(PROVER), session_id);
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            axelar_wasm_std::error::ContractError::from(ContractError::SigningSessionNotPending {
+                session_id
+            })
+            .to_string()
+        );
+    }
+
     #[test]
     fn query_signing_session() {
         let (mut deps, ecdsa_subkey, ed25519_subkey) = setup();
@@ -927,17 +1551,17 @@ mod tests {
                 (
                     signer.address.clone(),
                     signer.pub_key.clone(),
-                    signer.signed_address.clone(),
+                    signer.proof_of_possession.clone(),
                 )
             })
             .collect::<Vec<(Addr, HexBinary, HexBinary)>>();
 
-        for (addr, pub_key, signed_address) in &ecdsa_pub_keys {
+        for (addr, pub_key, proof_of_possession) in &ecdsa_pub_keys {
             do_register_key(
                 deps.as_mut(),
                 addr.clone(),
                 PublicKey::Ecdsa(pub_key.clone()),
-                signed_address.clone(),
+                proof_of_possession.clone(),
             )
             .unwrap();
         }
@@ -950,17 +1574,17 @@ mod tests {
                 (
                     signer.address.clone(),
                     signer.pub_key.clone(),
-                    signer.signed_address.clone(),
+                    signer.proof_of_possession.clone(),
                 )
             })
             .collect::<Vec<(Addr, HexBinary, HexBinary)>>();
 
-        for (addr, pub_key, signed_address) in &ed25519_pub_keys {
+        for (addr, pub_key, proof_of_possession) in &ed25519_pub_keys {
             do_register_key(
                 deps.as_mut(),
                 addr.clone(),
                 PublicKey::Ed25519(pub_key.clone()),
-                signed_address.clone(),
+                proof_of_possession.clone(),
             )
             .unwrap();
         }
@@ -998,7 +1622,7 @@ mod tests {
                 deps.as_mut(),
                 signer.address.clone(),
                 PublicKey::Ecdsa(signer.pub_key.clone()),
-                signer.signed_address.clone(),
+                signer.proof_of_possession.clone(),
             )
             .unwrap();
         });
@@ -1013,7 +1637,7 @@ mod tests {
             deps.as_mut(),
             new_signer.address.clone(),
             PublicKey::Ecdsa(new_signer.pub_key.clone()),
-            new_signer.signed_address.clone(),
+            new_signer.proof_of_possession.clone(),
         )
         .unwrap();
 
@@ -1042,7 +1666,7 @@ mod tests {
             deps.as_mut(),
             new_signer.address.clone(),
             PublicKey::Ed25519(new_signer.pub_key.clone()),
-            new_signer.signed_address.clone(),
+            new_signer.proof_of_possession.clone(),
         )
         .unwrap();
 
@@ -1095,7 +1719,7 @@ mod tests {
             deps.as_mut(),
             signer1.address.clone(),
             PublicKey::Ecdsa(signer1.pub_key.clone()),
-            signer2.signed_address.clone(),
+            signer2.proof_of_possession.clone(),
         );
         assert_eq!(
             res.unwrap_err().to_string(),
@@ -1114,7 +1738,7 @@ mod tests {
             deps.as_mut(),
             signer1.address.clone(),
             PublicKey::Ed25519(signer1.pub_key.clone()),
-            signer2.signed_address.clone(),
+            signer2.proof_of_possession.clone(),
         );
         assert_eq!(
             res.unwrap_err().to_string(),
@@ -1125,6 +1749,58 @@ mod tests {
         );
     }
 
+    #[test]
+    fn should_fail_register_key_if_challenge_is_not_bound_to_sender_chain_and_key_type() {
+        let mut deps = mock_dependencies();
+        do_instantiate(deps.as_mut()).unwrap();
+
+        let signing_key = k256::ecdsa::SigningKey::random(&mut rand_core::OsRng);
+        let signer = ecdsa_test_data::new(
+            MockApi::default().addr_make("verifier"),
+            signing_key.clone(),
+        );
+        let chain_id = mock_env().block.chain_id;
+
+        let invalid_challenges = [
+            // legacy proof over the sender address only
+            Keccak256::digest(signer.address.as_bytes()).into(),
+            registration_challenge(signer.address.as_str(), "other-chain-id", KeyType::Ecdsa),
+            registration_challenge(signer.address.as_str(), &chain_id, KeyType::Ed25519),
+            registration_challenge(
+                MockApi::default().addr_make("other").as_str(),
+                &chain_id,
+                KeyType::Ecdsa,
+            ),
+        ];
+
+        for challenge in invalid_challenges {
+            let proof_of_possession: k256::ecdsa::Signature =
+                signing_key.sign_prehash(challenge.as_slice()).unwrap();
+
+            let res = do_register_key(
+                deps.as_mut(),
+                signer.address.clone(),
+                PublicKey::Ecdsa(signer.pub_key.clone()),
+                proof_of_possession.to_bytes().to_vec().into(),
+            );
+            assert_eq!(
+                res.unwrap_err().to_string(),
+                axelar_wasm_std::error::ContractError::from(
+                    ContractError::InvalidPublicKeyRegistrationSignature
+                )
+                .to_string()
+            );
+        }
+
+        assert!(do_register_key(
+            deps.as_mut(),
+            signer.address.clone(),
+            PublicKey::Ecdsa(signer.pub_key.clone()),
+            signer.proof_of_possession.clone(),
+        )
+        .is_ok());
+    }
+
     #[test]
     fn should_fail_duplicate_public_key_registration() {
         let mut deps = mock_dependencies();
@@ -1137,7 +1813,7 @@ mod tests {
             deps.as_mut(),
             signer.address.clone(),
             PublicKey::Ecdsa(signer.pub_key.clone()),
-            signer.signed_address.clone(),
+            signer.proof_of_possession.clone(),
         )
         .unwrap();
 
@@ -1145,7 +1821,7 @@ mod tests {
             deps.as_mut(),
             signer.address.clone(),
             PublicKey::Ecdsa(signer.pub_key.clone()),
-            signer.signed_address.clone(),
+            signer.proof_of_possession.clone(),
         );
 
         assert_eq!(
@@ -1155,6 +1831,98 @@ mod tests {
         );
     }
 
+    #[test]
+    fn register_keys_on_behalf_of_verifiers() {
+        let mut deps = mock_dependencies();
+        do_instantiate(deps.as_mut()).unwrap();
+
+        let signers = ecdsa_test_data::signers();
+        let mut registrations: Vec<_> = signers
+            .iter()
+            .map(|signer| PublicKeyRegistration {
+                verifier_address: signer.address.to_string(),
+                public_key: PublicKey::Ecdsa(signer.pub_key.clone()),
+                proof_of_possession: signer.proof_of_possession.clone(),
+            })
+            .collect();
+        // the proof of possession of another verifier is rejected
+        let unproven_verifier = MockApi::default().addr_make("unproven");
+        let unproven_key = ecdsa_test_data::new(
+            unproven_verifier.clone(),
+            k256::ecdsa::SigningKey::random(&mut rand_core::OsRng),
+        );
+        registrations.push(PublicKeyRegistration {
+            verifier_address: unproven_verifier.to_string(),
+            public_key: PublicKey::Ecdsa(unproven_key.pub_key.clone()),
+            proof_of_possession: signers[0].proof_of_possession.clone(),
+        });
+        // a key that is already part of the batch is rejected
+        registrations.push(registrations[0].clone());
+
+        let res = do_register_keys(
+            deps.as_mut(),
+            MockApi::default().addr_make(COORDINATOR),
+            registrations.clone(),
+        )
+        .unwrap();
+
+        let results: Vec<PublicKeyRegistrationResult> = from_json(res.data.unwrap()).unwrap();
+        assert_eq!(results.len(), registrations.len());
+        assert!(results[..signers.len()]
+            .iter()
+            .all(|result| result.error.is_none()));
+        assert_eq!(
+            results[signers.len()],
+            PublicKeyRegistrationResult {
+                verifier_address: unproven_verifier.to_string(),
+                error: Some(ContractError::InvalidPublicKeyRegistrationSignature.to_string()),
+            }
+        );
+        assert_eq!(
+            results[signers.len() + 1].error,
+            Some(ContractError::DuplicatePublicKey.to_string())
+        );
+        assert_eq!(res.events.len(), signers.len());
+
+        for signer in &signers {
+            let res =
+                query_registered_public_key(deps.as_ref(), signer.address.clone(), KeyType::Ecdsa)
+                    .unwrap();
+            assert_eq!(
+                from_json::<PublicKey>(res).unwrap(),
+                PublicKey::Ecdsa(signer.pub_key.clone())
+            );
+        }
+        assert!(
+            query_registered_public_key(deps.as_ref(), unproven_verifier, KeyType::Ecdsa).is_err()
+        );
+    }
+
+    #[test]
+    fn register_keys_on_behalf_of_verifiers_is_restricted_to_governance_and_coordinator() {
+        let api = MockApi::default();
+        let signer = ecdsa_test_data::signers().pop().unwrap();
+        let registrations = vec![PublicKeyRegistration {
+            verifier_address: signer.address.to_string(),
+            public_key: PublicKey::Ecdsa(signer.pub_key.clone()),
+            proof_of_possession: signer.proof_of_possession.clone(),
+        }];
+
+        for sender in [api.addr_make(ADMIN), signer.address.clone()] {
+            let mut deps = mock_dependencies();
+            do_instantiate(deps.as_mut()).unwrap();
+
+            assert!(do_register_keys(deps.as_mut(), sender, registrations.clone()).is_err());
+        }
+
+        for sender in [api.addr_make(GOVERNANCE), api.addr_make(COORDINATOR)] {
+            let mut deps = mock_dependencies();
+            do_instantiate(deps.as_mut()).unwrap();
+
+            assert!(do_register_keys(deps.as_mut(), sender, registrations.clone()).is_ok());
+        }
+    }
+
     #[test]
     fn authorize_and_unauthorize_callers() {
         let (mut deps, ecdsa_subkey, ed25519_subkey) = setup();
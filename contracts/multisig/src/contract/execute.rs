@@ -1,35 +1,120 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
+use axelar_wasm_std::nonempty;
 use cosmwasm_std::{ensure, OverflowError, OverflowOperation, Storage, WasmMsg};
+use error_stack::bail;
 use router_api::ChainName;
-use sha3::{Digest, Keccak256};
 use signature_verifier_api::client::SignatureVerifier;
 
 use super::*;
-use crate::key::{KeyTyped, PublicKey, Signature};
-use crate::signing::{validate_session_signature, SigningSession};
-use crate::state::{load_session_signatures, save_pub_key, save_signature, AUTHORIZED_CALLERS};
+use crate::key::{registration_challenge, KeyTyped, PublicKey, Signature};
+use crate::msg::{PublicKeyRegistration, PublicKeyRegistrationResult};
+use crate::signing::{
+    recalculate_state, validate_session_signature, validate_signature, SigningSession,
+};
+use crate::state::{
+    self, load_batch_signatures, load_batch_state, load_session_signatures, save_batch_signature,
+    save_pub_key, save_signature, AUTHORIZED_CALLERS, BATCH_STATES, SESSION_BATCHES,
+};
 use crate::verifier_set::VerifierSet;
 
+const DEFAULT_ENTRIES_TO_PRUNE: u32 = 100;
+/// Number of blocks a batch signing session is kept after it expired
+pub const BATCH_SESSION_RETENTION: u64 = 100_000;
+
 pub fn start_signing_session(
     deps: DepsMut,
     env: Env,
+    info: MessageInfo,
     verifier_set_id: String,
     msg: MsgToSign,
     chain_name: ChainName,
     sig_verifier: Option<Addr>,
 ) -> error_stack::Result<Response, ContractError> {
+    let (signing_session, verifier_set) = create_signing_session(
+        deps.storage,
+        &env,
+        verifier_set_id,
+        msg,
+        chain_name,
+        sig_verifier,
+        info.sender,
+    )?;
+
+    let event = Event::SigningStarted {
+        session_id: signing_session.id,
+        verifier_set_id: signing_session.verifier_set_id,
+        pub_keys: verifier_set.pub_keys(),
+        msg: signing_session.msg,
+        chain_name: signing_session.chain_name,
+        expires_at: signing_session.expires_at,
+    };
+
+    Ok(Response::new()
+        .set_data(to_json_binary(&signing_session.id).map_err(ContractError::from)?)
+        .add_event(event))
+}
+
+pub fn start_batch_signing_session(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    verifier_set_id: String,
+    msgs: nonempty::Vec<MsgToSign>,
+    chain_name: ChainName,
+    sig_verifier: Option<Addr>,
+) -> error_stack::Result<Response, ContractError> {
+    let msgs: Vec<MsgToSign> = msgs.into();
+
+    // the session's own message is the first one of the batch, the whole batch is stored separately
+    let (signing_session, verifier_set) = create_signing_session(
+        deps.storage,
+        &env,
+        verifier_set_id,
+        msgs[0].clone(),
+        chain_name,
+        sig_verifier,
+        info.sender,
+    )?;
+
+    SESSION_BATCHES
+        .save(deps.storage, signing_session.id.u64(), &msgs)
+        .map_err(ContractError::from)?;
+
+    let event = Event::BatchSigningStarted {
+        session_id: signing_session.id,
+        verifier_set_id: signing_session.verifier_set_id,
+        pub_keys: verifier_set.pub_keys(),
+        msgs,
+        chain_name: signing_session.chain_name,
+        expires_at: signing_session.expires_at,
+    };
+
+    Ok(Response::new()
+        .set_data(to_json_binary(&signing_session.id).map_err(ContractError::from)?)
+        .add_event(event))
+}
+
+fn create_signing_session(
+    storage: &mut dyn Storage,
+    env: &Env,
+    verifier_set_id: String,
+    msg: MsgToSign,
+    chain_name: ChainName,
+    sig_verifier: Option<Addr>,
+    initiator: Addr,
+) -> error_stack::Result<(SigningSession, VerifierSet), ContractError> {
     ensure!(
-        killswitch::is_contract_active(deps.storage),
+        killswitch::is_contract_active(storage),
         ContractError::SigningDisabled
     );
 
-    let config = CONFIG.load(deps.storage).map_err(ContractError::from)?;
+    let config = CONFIG.load(storage).map_err(ContractError::from)?;
 
-    let verifier_set = verifier_set(deps.storage, &verifier_set_id)?;
+    let verifier_set = verifier_set(storage, &verifier_set_id)?;
 
     let session_id = SIGNING_SESSION_COUNTER.update(
-        deps.storage,
+        storage,
         |mut counter| -> Result<Uint64, ContractError> {
             counter = counter
                 .checked_add(Uint64::one())
@@ -47,29 +132,109 @@ pub fn start_signing_session(
 
     let signing_session = SigningSession::new(
         session_id,
-        verifier_set_id.clone(),
-        chain_name.clone(),
-        msg.clone(),
+        verifier_set_id,
+        chain_name,
+        msg,
         expires_at,
         sig_verifier,
+        initiator,
     );
 
     SIGNING_SESSIONS
-        .save(deps.storage, session_id.into(), &signing_session)
+        .save(storage, session_id.into(), &signing_session)
         .map_err(ContractError::from)?;
 
-    let event = Event::SigningStarted {
-        session_id,
-        verifier_set_id,
-        pub_keys: verifier_set.pub_keys(),
-        msg,
-        chain_name,
-        expires_at,
+    Ok((signing_session, verifier_set))
+}
+
+pub fn abort_signing_session(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    session_id: Uint64,
+    reason: nonempty::String,
+) -> error_stack::Result<Response, ContractError> {
+    let mut session = SIGNING_SESSIONS
+        .load(deps.storage, session_id.into())
+        .map_err(|_| ContractError::SigningSessionNotFound { session_id })?;
+
+    ensure!(
+        session.state == MultisigState::Pending,
+        ContractError::SigningSessionNotPending { session_id }
+    );
+
+    // sessions started before the initiator was recorded can be aborted by any caller authorized for their chain
+    match &session.initiator {
+        Some(initiator) => ensure!(*initiator == info.sender, ContractError::Unauthorized),
+        None => {
+            require_authorized_caller(deps.storage, &info.sender, &session.chain_name)
+                .map_err(|_| ContractError::Unauthorized)?;
+        }
+    }
+
+    let reason: String = reason.into();
+    session.state = MultisigState::Aborted {
+        aborted_at: env.block.height,
+        reason: reason.clone(),
     };
+    SIGNING_SESSIONS
+        .save(deps.storage, session.id.u64(), &session)
+        .map_err(ContractError::from)?;
+
+    // participants that can no longer sign are credited for the session, so the abort doesn't count against them
+    let config = CONFIG.load(deps.storage).map_err(ContractError::from)?;
+    let release_msgs = unsigned_participants(deps.storage, &session)?
+        .iter()
+        .map(|participant| {
+            record_participation_msg(&session, participant, config.rewards_contract.to_string())
+        })
+        .collect::<error_stack::Result<Vec<_>, _>>()?;
 
     Ok(Response::new()
-        .set_data(to_json_binary(&session_id).map_err(ContractError::from)?)
-        .add_event(event))
+        .add_messages(release_msgs)
+        .add_event(Event::SigningAborted {
+            session_id,
+            aborted_at: env.block.height,
+            reason,
+            chain_name: session.chain_name,
+            expires_at: session.expires_at,
+        }))
+}
+
+/// Returns the participants of the session that haven't signed it. If nobody has signed, the session hasn't been
+/// recorded as a rewards event yet, so there is no participation to release and no participant is returned
+fn unsigned_participants(
+    storage: &dyn Storage,
+    session: &SigningSession,
+) -> error_stack::Result<Vec<Addr>, ContractError> {
+    let session_id = session.id.u64();
+
+    // batch signatures are submitted for all messages at once, so the signers of the first message signed the batch
+    let signed: HashSet<String> = match SESSION_BATCHES
+        .may_load(storage, session_id)
+        .map_err(ContractError::from)?
+    {
+        Some(_) => load_batch_signatures(storage, session_id, 0),
+        None => load_session_signatures(storage, session_id),
+    }
+    .map_err(ContractError::from)?
+    .into_keys()
+    .collect();
+
+    if signed.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let verifier_set = VERIFIER_SETS
+        .load(storage, &session.verifier_set_id)
+        .map_err(ContractError::from)?;
+
+    Ok(verifier_set
+        .signers
+        .values()
+        .filter(|signer| !signed.contains(signer.address.as_str()))
+        .map(|signer| signer.address.clone())
+        .collect())
 }
 
 pub fn submit_signature(
@@ -88,17 +253,23 @@ pub fn submit_signature(
     let mut session = SIGNING_SESSIONS
         .load(deps.storage, session_id.into())
         .map_err(|_| ContractError::SigningSessionNotFound { session_id })?;
+
+    if let Some(msgs) = SESSION_BATCHES
+        .may_load(deps.storage, session_id.u64())
+        .map_err(ContractError::from)?
+    {
+        bail!(ContractError::SignatureCountMismatch {
+            session_id,
+            expected: msgs.len(),
+            actual: 1,
+        });
+    }
+
     let verifier_set = VERIFIER_SETS
         .load(deps.storage, &session.verifier_set_id)
         .map_err(ContractError::from)?;
 
-    let pub_key = match verifier_set.signers.get(&info.sender.to_string()) {
-        Some(signer) => Ok(&signer.pub_key),
-        None => Err(ContractError::NotAParticipant {
-            session_id,
-            signer: info.sender.to_string(),
-        }),
-    }?;
+    let pub_key = participant_pub_key(&verifier_set, session_id, &info.sender)?;
 
     let signature: Signature = (pub_key.key_type(), signature).try_into()?;
 
@@ -142,10 +313,175 @@ pub fn submit_signature(
     })
 }
 
+pub fn submit_signatures(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    session_id: Uint64,
+    signatures: nonempty::Vec<HexBinary>,
+) -> error_stack::Result<Response, ContractError> {
+    ensure!(
+        killswitch::is_contract_active(deps.storage),
+        ContractError::SigningDisabled
+    );
+
+    let config = CONFIG.load(deps.storage).map_err(ContractError::from)?;
+    let mut session = SIGNING_SESSIONS
+        .load(deps.storage, session_id.into())
+        .map_err(|_| ContractError::SigningSessionNotFound { session_id })?;
+    let msgs = SESSION_BATCHES
+        .may_load(deps.storage, session_id.u64())
+        .map_err(ContractError::from)?
+        .ok_or(ContractError::NotABatchSession { session_id })?;
+
+    let signatures: Vec<HexBinary> = signatures.into();
+    ensure!(
+        signatures.len() == msgs.len(),
+        ContractError::SignatureCountMismatch {
+            session_id,
+            expected: msgs.len(),
+            actual: signatures.len(),
+        }
+    );
+
+    let verifier_set = VERIFIER_SETS
+        .load(deps.storage, &session.verifier_set_id)
+        .map_err(ContractError::from)?;
+
+    let pub_key = participant_pub_key(&verifier_set, session_id, &info.sender)?;
+
+    let mut sig_verifier_msgs = vec![];
+    let mut saved_signatures = vec![];
+    let mut completed_events = vec![];
+
+    for ((index, msg), signature) in (0u32..).zip(msgs.iter()).zip(signatures) {
+        let signature: Signature = (pub_key.key_type(), signature).try_into()?;
+
+        let sig_verifier = session
+            .sig_verifier
+            .clone()
+            .map(|address| SignatureVerifier::new(address, deps.querier));
+
+        sig_verifier_msgs.extend(validate_signature(
+            &session,
+            msg,
+            &info.sender,
+            &signature,
+            pub_key,
+            env.block.height,
+            sig_verifier,
+        )?);
+        saved_signatures.push(save_batch_signature(
+            deps.storage,
+            session_id,
+            index,
+            signature,
+            &info.sender,
+        )?);
+
+        let batch_signatures = load_batch_signatures(deps.storage, session_id.u64(), index)
+            .map_err(ContractError::from)?;
+        let old_state =
+            load_batch_state(deps.storage, session_id.u64(), index).map_err(ContractError::from)?;
+        let state = recalculate_state(
+            old_state.clone(),
+            &batch_signatures,
+            &verifier_set,
+            env.block.height,
+        );
+
+        if let (MultisigState::Pending, MultisigState::Completed { completed_at }) =
+            (&old_state, &state)
+        {
+            BATCH_STATES
+                .save(deps.storage, (session_id.u64(), index), &state)
+                .map_err(ContractError::from)?;
+            completed_events.push(Event::BatchMessageSigningCompleted {
+                session_id,
+                index,
+                completed_at: *completed_at,
+                chain_name: session.chain_name.clone(),
+            });
+        }
+    }
+
+    let old_state = session.state.clone();
+
+    // the session as a whole is completed once every message of the batch is
+    if session.state == MultisigState::Pending
+        && batch_completed(deps.storage, session_id.u64(), msgs.len())?
+    {
+        session.state = MultisigState::Completed {
+            completed_at: env.block.height,
+        };
+    }
+
+    SIGNING_SESSIONS
+        .save(deps.storage, session.id.u64(), &session)
+        .map_err(ContractError::from)?;
+
+    let state_changed = old_state != session.state;
+
+    let response = Response::new()
+        .add_message(record_participation_msg(
+            &session,
+            &info.sender,
+            config.rewards_contract.into_string(),
+        )?)
+        .add_messages(sig_verifier_msgs)
+        .add_event(Event::BatchSignaturesSubmitted {
+            session_id,
+            participant: info.sender,
+            signatures: saved_signatures,
+        })
+        .add_events(completed_events);
+
+    Ok(with_completed_event(response, session, state_changed))
+}
+
+fn batch_completed(
+    storage: &dyn Storage,
+    session_id: u64,
+    batch_size: usize,
+) -> Result<bool, ContractError> {
+    for index in (0u32..).take(batch_size) {
+        if load_batch_state(storage, session_id, index)? == MultisigState::Pending {
+            return Ok(false);
+        }
+    }
+
+    Ok(true)
+}
+
+fn participant_pub_key<'a>(
+    verifier_set: &'a VerifierSet,
+    session_id: Uint64,
+    signer: &Addr,
+) -> Result<&'a PublicKey, ContractError> {
+    match verifier_set.signers.get(&signer.to_string()) {
+        Some(signer) => Ok(&signer.pub_key),
+        None => Err(ContractError::NotAParticipant {
+            session_id,
+            signer: signer.to_string(),
+        }),
+    }
+}
+
 pub fn register_verifier_set(
     deps: DepsMut,
     verifier_set: VerifierSet,
 ) -> error_stack::Result<Response, ContractError> {
+    // sessions of a verifier set that can never reach its minimum signer count could never complete
+    if let Some(min_signers) = verifier_set.min_signers {
+        ensure!(
+            usize::from(min_signers) <= verifier_set.signers.len(),
+            ContractError::InvalidMinSigners {
+                min_signers,
+                num_signers: verifier_set.signers.len(),
+            }
+        );
+    }
+
     let verifier_set_id = verifier_set.id();
     VERIFIER_SETS
         .save(deps.storage, &verifier_set_id, &verifier_set)
@@ -156,27 +492,96 @@ pub fn register_verifier_set(
 
 pub fn register_pub_key(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     public_key: PublicKey,
-    signed_sender_address: HexBinary,
+    proof_of_possession: HexBinary,
+) -> error_stack::Result<Response, ContractError> {
+    let event = verify_and_save_pub_key(
+        deps.storage,
+        env.block.chain_id.as_str(),
+        info.sender,
+        public_key,
+        proof_of_possession,
+    )?;
+
+    Ok(Response::new().add_event(event))
+}
+
+pub fn register_pub_keys(
+    mut deps: DepsMut,
+    env: Env,
+    registrations: Vec<PublicKeyRegistration>,
 ) -> error_stack::Result<Response, ContractError> {
-    let signed_sender_address: Signature =
-        (public_key.key_type(), signed_sender_address).try_into()?;
+    let (results, events): (Vec<_>, Vec<_>) = registrations
+        .into_iter()
+        .map(|registration| {
+            let verifier_address = registration.verifier_address.clone();
+
+            match register_pub_key_on_behalf(deps.branch(), &env, registration) {
+                Ok(event) => (
+                    PublicKeyRegistrationResult {
+                        verifier_address,
+                        error: None,
+                    },
+                    Some(event),
+                ),
+                Err(err) => (
+                    PublicKeyRegistrationResult {
+                        verifier_address,
+                        error: Some(err.to_string()),
+                    },
+                    None,
+                ),
+            }
+        })
+        .unzip();
+
+    Ok(Response::new()
+        .set_data(to_json_binary(&results).map_err(ContractError::from)?)
+        .add_events(events.into_iter().flatten()))
+}
+
+fn register_pub_key_on_behalf(
+    deps: DepsMut,
+    env: &Env,
+    registration: PublicKeyRegistration,
+) -> Result<Event, ContractError> {
+    let verifier = address::validate_cosmwasm_address(deps.api, &registration.verifier_address)
+        .map_err(|_| ContractError::InvalidVerifierAddress(registration.verifier_address))?;
+
+    verify_and_save_pub_key(
+        deps.storage,
+        env.block.chain_id.as_str(),
+        verifier,
+        registration.public_key,
+        registration.proof_of_possession,
+    )
+}
+
+fn verify_and_save_pub_key(
+    storage: &mut dyn Storage,
+    chain_id: &str,
+    verifier: Addr,
+    public_key: PublicKey,
+    proof_of_possession: HexBinary,
+) -> Result<Event, ContractError> {
+    let proof_of_possession: Signature = (public_key.key_type(), proof_of_possession).try_into()?;
 
-    let address_hash = Keccak256::digest(info.sender.as_bytes());
+    let challenge = registration_challenge(verifier.as_str(), chain_id, public_key.key_type());
 
     // to prevent anyone from registering a public key that belongs to someone else,
-    // we require the sender to sign their own address using the private key
-    signed_sender_address
-        .verify(address_hash.as_slice(), &public_key)
+    // we require the verifier to sign the registration challenge using the private key
+    proof_of_possession
+        .verify(challenge.as_slice(), &public_key)
         .map_err(|_| ContractError::InvalidPublicKeyRegistrationSignature)?;
 
-    save_pub_key(deps.storage, info.sender.clone(), public_key.clone())?;
+    save_pub_key(storage, verifier.clone(), public_key.clone())?;
 
-    Ok(Response::new().add_event(Event::PublicKeyRegistered {
-        verifier: info.sender,
+    Ok(Event::PublicKeyRegistered {
+        verifier,
         public_key,
-    }))
+    })
 }
 
 pub fn require_authorized_caller(
@@ -232,6 +637,49 @@ pub fn unauthorize_callers(
     )
 }
 
+/// Removes the signatures and message states of batch signing sessions that expired more than
+/// `BATCH_SESSION_RETENTION` blocks ago, oldest first. At most `entry_limit` entries are removed.
+pub fn prune_batch_sessions(
+    deps: DepsMut,
+    env: Env,
+    entry_limit: Option<u32>,
+) -> error_stack::Result<Response, ContractError> {
+    let prune_before = env.block.height.saturating_sub(BATCH_SESSION_RETENTION);
+
+    let mut remaining = entry_limit.unwrap_or(DEFAULT_ENTRIES_TO_PRUNE) as usize;
+    let mut session_ids = vec![];
+    let mut signatures_pruned = 0u64;
+    while remaining > 0 {
+        let Some(session_id) =
+            state::load_oldest_batch_session_expired_before(deps.storage, prune_before)
+                .map_err(ContractError::from)?
+        else {
+            break;
+        };
+
+        let signatures = state::load_batch_signature_keys(deps.storage, session_id, remaining)
+            .map_err(ContractError::from)?;
+        remaining = remaining.saturating_sub(signatures.len());
+        signatures_pruned = signatures_pruned.saturating_add(signatures.len() as u64);
+        for (index, signer) in signatures {
+            state::remove_batch_signature(deps.storage, session_id, index, &signer);
+        }
+
+        if remaining == 0 {
+            break;
+        }
+
+        state::remove_batch_session(deps.storage, session_id).map_err(ContractError::from)?;
+        remaining = remaining.saturating_sub(1);
+        session_ids.push(Uint64::from(session_id));
+    }
+
+    Ok(Response::new().add_event(Event::BatchSessionsPruned {
+        session_ids,
+        signatures_pruned,
+    }))
+}
+
 pub fn enable_signing(deps: DepsMut) -> error_stack::Result<Response, ContractError> {
     Ok(killswitch::disengage(deps.storage, Event::SigningEnabled).map_err(ContractError::from)?)
 }
@@ -247,7 +695,25 @@ fn signing_response(
     signature: Signature,
     rewards_contract: String,
 ) -> error_stack::Result<Response, ContractError> {
-    let rewards_msg = WasmMsg::Execute {
+    let rewards_msg = record_participation_msg(&session, &signer, rewards_contract)?;
+
+    let event = Event::SignatureSubmitted {
+        session_id: session.id,
+        participant: signer,
+        signature,
+    };
+
+    let response = Response::new().add_message(rewards_msg).add_event(event);
+
+    Ok(with_completed_event(response, session, state_changed))
+}
+
+fn record_participation_msg(
+    session: &SigningSession,
+    signer: &Addr,
+    rewards_contract: String,
+) -> error_stack::Result<WasmMsg, ContractError> {
+    Ok(WasmMsg::Execute {
         contract_addr: rewards_contract,
         msg: to_json_binary(&rewards::msg::ExecuteMsg::RecordParticipation {
             chain_name: session.chain_name.clone(),
@@ -260,26 +726,23 @@ fn signing_response(
         })
         .map_err(ContractError::from)?,
         funds: vec![],
-    };
-
-    let event = Event::SignatureSubmitted {
-        session_id: session.id,
-        participant: signer,
-        signature,
-    };
-
-    let mut response = Response::new().add_message(rewards_msg).add_event(event);
+    })
+}
 
-    if let MultisigState::Completed { completed_at } = session.state {
-        if state_changed {
-            // only send event if state changed
-            response = response.add_event(Event::SigningCompleted {
+fn with_completed_event(
+    response: Response,
+    session: SigningSession,
+    state_changed: bool,
+) -> Response {
+    match session.state {
+        // only send event if state changed
+        MultisigState::Completed { completed_at } if state_changed => {
+            response.add_event(Event::SigningCompleted {
                 session_id: session.id,
                 completed_at,
                 chain_name: session.chain_name,
             })
         }
+        _ => response,
     }
-
-    Ok(response)
 }
@@ -1,16 +1,42 @@
-use axelar_wasm_std::migrate_from_version;
+use axelar_wasm_std::{address, migrate_from_version};
+use cosmwasm_schema::cw_serde;
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
-use cosmwasm_std::{DepsMut, Empty, Env, Response};
+use cosmwasm_std::{DepsMut, Env, Response};
 
-pub type MigrateMsg = Empty;
+use crate::state::{Config, CONFIG};
 
+#[cw_serde]
+pub struct MigrateMsg {
+    /// The coordinator address (or governance) is allowed to register public keys for multiple verifiers at once
+    #[serde(default)]
+    pub coordinator_address: Option<String>,
+}
+
+/// Public keys registered before the registration challenge was introduced were proven with a
+/// signature over the verifier address only. They are kept as is: the new proof of possession is
+/// only enforced when a key is registered or rotated, so no storage migration is required.
+/// The migration only sets the coordinator that is allowed to register public keys on behalf of verifiers.
 #[cfg_attr(not(feature = "library"), entry_point)]
 #[migrate_from_version("1.2")]
 pub fn migrate(
     deps: DepsMut,
     _env: Env,
-    _msg: MigrateMsg,
+    msg: MigrateMsg,
 ) -> Result<Response, axelar_wasm_std::error::ContractError> {
+    let coordinator = msg
+        .coordinator_address
+        .map(|addr| address::validate_cosmwasm_address(deps.api, &addr))
+        .transpose()?;
+
+    let config = CONFIG.load(deps.storage)?;
+    CONFIG.save(
+        deps.storage,
+        &Config {
+            coordinator,
+            ..config
+        },
+    )?;
+
     Ok(Response::default())
 }
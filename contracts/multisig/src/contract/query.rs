@@ -1,13 +1,23 @@
+use cosmwasm_std::{Order, StdError};
 use router_api::ChainName;
 
 use super::*;
 use crate::key::{KeyType, PublicKey};
 use crate::multisig::Multisig;
-use crate::state::{load_pub_key, load_session_signatures, AUTHORIZED_CALLERS};
+use crate::state::{
+    load_batch_signatures, load_batch_state, load_pub_key, load_session_signatures,
+    AUTHORIZED_CALLERS, BATCH_SIGNATURES, SESSION_BATCHES, SIGNATURES,
+};
 use crate::verifier_set::VerifierSet;
 
 pub fn multisig(deps: Deps, session_id: Uint64) -> StdResult<Multisig> {
     let session = SIGNING_SESSIONS.load(deps.storage, session_id.into())?;
+    if SESSION_BATCHES.has(deps.storage, session.id.u64()) {
+        return Err(StdError::generic_err(format!(
+            "signing session {} is a batch signing session, query BatchMultisig instead",
+            session.id
+        )));
+    }
 
     let verifier_set = VERIFIER_SETS.load(deps.storage, &session.verifier_set_id)?;
     let signatures = load_session_signatures(deps.storage, session.id.u64())?;
@@ -19,6 +29,24 @@ pub fn multisig(deps: Deps, session_id: Uint64) -> StdResult<Multisig> {
     })
 }
 
+pub fn batch_multisig(deps: Deps, session_id: Uint64) -> StdResult<Vec<Multisig>> {
+    let session = SIGNING_SESSIONS.load(deps.storage, session_id.into())?;
+    let msgs = SESSION_BATCHES.load(deps.storage, session.id.u64())?;
+
+    let verifier_set = VERIFIER_SETS.load(deps.storage, &session.verifier_set_id)?;
+
+    (0u32..)
+        .take(msgs.len())
+        .map(|index| {
+            Ok(Multisig {
+                state: load_batch_state(deps.storage, session.id.u64(), index)?,
+                verifier_set: verifier_set.clone(),
+                signatures: load_batch_signatures(deps.storage, session.id.u64(), index)?,
+            })
+        })
+        .collect()
+}
+
 pub fn verifier_set(deps: Deps, verifier_set_id: String) -> StdResult<VerifierSet> {
     VERIFIER_SETS.load(deps.storage, &verifier_set_id)
 }
@@ -32,3 +60,36 @@ pub fn caller_authorized(deps: Deps, address: Addr, chain_name: ChainName) -> St
     let is_authorized = AUTHORIZED_CALLERS.may_load(deps.storage, &address)? == Some(chain_name);
     Ok(is_authorized)
 }
+
+/// Sessions expire in the order they were started, so the sessions are checked from the most recent one backwards
+/// until the first expired one
+pub fn has_open_signing_sessions(deps: Deps, block_height: u64, verifier: Addr) -> StdResult<bool> {
+    for session in SIGNING_SESSIONS.range(deps.storage, None, None, Order::Descending) {
+        let (session_id, session) = session?;
+
+        if session.expires_at < block_height {
+            break;
+        }
+
+        if session.state != MultisigState::Pending {
+            continue;
+        }
+
+        let verifier_set = VERIFIER_SETS.load(deps.storage, &session.verifier_set_id)?;
+        if !verifier_set.signers.contains_key(verifier.as_str()) {
+            continue;
+        }
+
+        // batch signatures are submitted for all messages at once, so the signers of the first message signed the batch
+        let signed = if SESSION_BATCHES.has(deps.storage, session_id) {
+            BATCH_SIGNATURES.has(deps.storage, (session_id, 0, verifier.as_str()))
+        } else {
+            SIGNATURES.has(deps.storage, (session_id, verifier.as_str()))
+        };
+        if !signed {
+            return Ok(true);
+        }
+    }
+
+    Ok(false)
+}
@@ -36,6 +36,9 @@ pub enum ContractError {
     #[error("signed sender address could not be verified using submitted public key")]
     InvalidPublicKeyRegistrationSignature,
 
+    #[error("invalid verifier address {0}")]
+    InvalidVerifierAddress(String),
+
     #[error("invalid public key")]
     InvalidPublicKey,
 
@@ -51,9 +54,25 @@ pub enum ContractError {
     #[error("signing session {session_id:?} is already closed")]
     SigningSessionClosed { session_id: Uint64 },
 
+    #[error("signing session {session_id:?} was aborted")]
+    SigningSessionAborted { session_id: Uint64 },
+
+    #[error("signing session {session_id:?} is not pending")]
+    SigningSessionNotPending { session_id: Uint64 },
+
     #[error("signing session {session_id:?} not found")]
     SigningSessionNotFound { session_id: Uint64 },
 
+    #[error("signing session {session_id:?} is not a batch signing session")]
+    NotABatchSession { session_id: Uint64 },
+
+    #[error("signing session {session_id:?} expects {expected} signatures, got {actual}")]
+    SignatureCountMismatch {
+        session_id: Uint64,
+        expected: usize,
+        actual: usize,
+    },
+
     #[error("number of participants does not match number of public keys")]
     PublicKeysMismatchParticipants,
 
@@ -71,4 +90,10 @@ pub enum ContractError {
 
     #[error("specified chain name is incorrect. expected: {expected}")]
     WrongChainName { expected: ChainName },
+
+    #[error("verifier set requires {min_signers} signers, but only has {num_signers}")]
+    InvalidMinSigners {
+        min_signers: u16,
+        num_signers: usize,
+    },
 }
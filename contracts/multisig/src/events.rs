@@ -1,8 +1,8 @@
 use std::collections::HashMap;
 
+use axelar_wasm_std::canonical_json::to_string;
 use cosmwasm_std::{Addr, HexBinary, Uint64};
 use router_api::ChainName;
-use serde_json::to_string;
 
 use crate::key::{PublicKey, Signature};
 use crate::types::MsgToSign;
@@ -17,18 +17,48 @@ pub enum Event {
         chain_name: ChainName,
         expires_at: u64,
     },
+    // Emitted when a new batch signing session is open
+    BatchSigningStarted {
+        session_id: Uint64,
+        verifier_set_id: String,
+        pub_keys: HashMap<String, PublicKey>,
+        msgs: Vec<MsgToSign>,
+        chain_name: ChainName,
+        expires_at: u64,
+    },
     // Emitted when a participant submits a signature
     SignatureSubmitted {
         session_id: Uint64,
         participant: Addr,
         signature: Signature,
     },
+    // Emitted when a participant submits the signatures of a batch signing session
+    BatchSignaturesSubmitted {
+        session_id: Uint64,
+        participant: Addr,
+        signatures: Vec<Signature>,
+    },
+    // Emitted when a message of a batch signing session was completed
+    BatchMessageSigningCompleted {
+        session_id: Uint64,
+        index: u32,
+        completed_at: u64,
+        chain_name: ChainName,
+    },
     // Emitted when a signing session was completed
     SigningCompleted {
         session_id: Uint64,
         completed_at: u64,
         chain_name: ChainName,
     },
+    // Emitted when a signing session was aborted by the contract that started it
+    SigningAborted {
+        session_id: Uint64,
+        aborted_at: u64,
+        reason: String,
+        chain_name: ChainName,
+        expires_at: u64,
+    },
     PublicKeyRegistered {
         verifier: Addr,
         public_key: PublicKey,
@@ -41,6 +71,10 @@ pub enum Event {
         contract_address: Addr,
         chain_name: ChainName,
     },
+    BatchSessionsPruned {
+        session_ids: Vec<Uint64>,
+        signatures_pruned: u64,
+    },
     SigningEnabled,
     SigningDisabled,
 }
@@ -66,6 +100,33 @@ impl From<Event> for cosmwasm_std::Event {
                 .add_attribute("msg", HexBinary::from(msg).to_hex())
                 .add_attribute("chain", chain)
                 .add_attribute("expires_at", expires_at.to_string()),
+            Event::BatchSigningStarted {
+                session_id,
+                verifier_set_id,
+                pub_keys,
+                msgs,
+                chain_name: chain,
+                expires_at,
+            } => cosmwasm_std::Event::new("batch_signing_started")
+                .add_attribute("session_id", session_id)
+                .add_attribute("verifier_set_id", verifier_set_id)
+                .add_attribute(
+                    "pub_keys",
+                    to_string(&pub_keys)
+                        .expect("violated invariant: pub_keys are not serializable"),
+                )
+                .add_attribute(
+                    "msgs",
+                    to_string(
+                        &msgs
+                            .into_iter()
+                            .map(|msg| HexBinary::from(msg).to_hex())
+                            .collect::<Vec<_>>(),
+                    )
+                    .expect("violated invariant: msgs are not serializable"),
+                )
+                .add_attribute("chain", chain)
+                .add_attribute("expires_at", expires_at.to_string()),
             Event::SignatureSubmitted {
                 session_id,
                 participant,
@@ -74,6 +135,33 @@ impl From<Event> for cosmwasm_std::Event {
                 .add_attribute("session_id", session_id)
                 .add_attribute("participant", participant)
                 .add_attribute("signature", HexBinary::from(signature.as_ref()).to_hex()),
+            Event::BatchSignaturesSubmitted {
+                session_id,
+                participant,
+                signatures,
+            } => cosmwasm_std::Event::new("batch_signatures_submitted")
+                .add_attribute("session_id", session_id)
+                .add_attribute("participant", participant)
+                .add_attribute(
+                    "signatures",
+                    to_string(
+                        &signatures
+                            .iter()
+                            .map(|signature| HexBinary::from(signature.as_ref()).to_hex())
+                            .collect::<Vec<_>>(),
+                    )
+                    .expect("violated invariant: signatures are not serializable"),
+                ),
+            Event::BatchMessageSigningCompleted {
+                session_id,
+                index,
+                completed_at,
+                chain_name,
+            } => cosmwasm_std::Event::new("batch_message_signing_completed")
+                .add_attribute("session_id", session_id)
+                .add_attribute("index", index.to_string())
+                .add_attribute("completed_at", completed_at.to_string())
+                .add_attribute("chain", chain_name),
             Event::SigningCompleted {
                 session_id,
                 completed_at,
@@ -82,6 +170,18 @@ impl From<Event> for cosmwasm_std::Event {
                 .add_attribute("session_id", session_id)
                 .add_attribute("completed_at", completed_at.to_string())
                 .add_attribute("chain", chain_name),
+            Event::SigningAborted {
+                session_id,
+                aborted_at,
+                reason,
+                chain_name,
+                expires_at,
+            } => cosmwasm_std::Event::new("signing_aborted")
+                .add_attribute("session_id", session_id)
+                .add_attribute("aborted_at", aborted_at.to_string())
+                .add_attribute("reason", reason)
+                .add_attribute("chain", chain_name)
+                .add_attribute("expires_at", expires_at.to_string()),
             Event::PublicKeyRegistered {
                 verifier,
                 public_key,
@@ -106,8 +206,56 @@ impl From<Event> for cosmwasm_std::Event {
             } => cosmwasm_std::Event::new("caller_unauthorized")
                 .add_attribute("contract_address", contract_address)
                 .add_attribute("chain_name", chain_name),
+            Event::BatchSessionsPruned {
+                session_ids,
+                signatures_pruned,
+            } => cosmwasm_std::Event::new("batch_sessions_pruned")
+                .add_attribute(
+                    "session_ids",
+                    to_string(&session_ids)
+                        .expect("violated invariant: session ids are not serializable"),
+                )
+                .add_attribute("signatures_pruned", signatures_pruned.to_string()),
             Event::SigningEnabled => cosmwasm_std::Event::new("signing_enabled"),
             Event::SigningDisabled => cosmwasm_std::Event::new("signing_disabled"),
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use cosmwasm_std::testing::MockApi;
+
+    use super::*;
+    use crate::test::common::{ecdsa_test_data, ed25519_test_data};
+
+    #[test]
+    fn signing_started_event_is_deterministic() {
+        let pub_keys: HashMap<_, _> = (0..10)
+            .map(|i| {
+                let pub_key = if i < 5 {
+                    PublicKey::Ecdsa(ecdsa_test_data::pub_key())
+                } else {
+                    PublicKey::Ed25519(ed25519_test_data::pub_key())
+                };
+                (
+                    MockApi::default()
+                        .addr_make(&format!("verifier{}", i))
+                        .to_string(),
+                    pub_key,
+                )
+            })
+            .collect();
+
+        let event = cosmwasm_std::Event::from(Event::SigningStarted {
+            session_id: Uint64::one(),
+            verifier_set_id: "verifier_set_id".to_string(),
+            pub_keys,
+            msg: MsgToSign::unchecked(ecdsa_test_data::message()),
+            chain_name: "ethereum".parse().unwrap(),
+            expires_at: 100,
+        });
+
+        goldie::assert_json!(event);
+    }
+}
@@ -7,6 +7,7 @@ use enum_display_derive::Display;
 use error_stack::{Report, ResultExt};
 use serde::de::Error;
 use serde::{Deserialize, Deserializer};
+use sha3::{Digest, Keccak256};
 
 use crate::ed25519::{ed25519_verify, ED25519_SIGNATURE_LEN};
 use crate::secp256k1::ecdsa_verify;
@@ -21,6 +22,21 @@ pub enum KeyType {
     Ed25519,
 }
 
+/// Returns the challenge a verifier must sign with its private key to prove possession when registering a public key.
+/// The challenge binds the key to the verifier address, the chain the multisig contract runs on and the key type,
+/// so a registration signature can neither be reused by another address nor replayed on another chain.
+/// Each field is prefixed by its length, so the encoding is unambiguous.
+pub fn registration_challenge(verifier: &str, chain_id: &str, key_type: KeyType) -> [u8; 32] {
+    let mut hasher = Keccak256::new();
+
+    for field in [verifier, chain_id, &key_type.to_string()] {
+        hasher.update((field.len() as u64).to_be_bytes());
+        hasher.update(field.as_bytes());
+    }
+
+    hasher.finalize().into()
+}
+
 #[cw_serde]
 #[derive(PartialOrd, Ord, Eq)]
 pub enum Signature {
@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 
-use axelar_wasm_std::nonempty;
+use axelar_wasm_std::snapshot::WeightedParticipant;
+use axelar_wasm_std::{bounded, nonempty};
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::{Addr, HexBinary, Uint128, Uint64};
 use msgs_derive::EnsurePermissions;
@@ -11,6 +12,9 @@ use crate::key::{KeyType, PublicKey, Signature};
 use crate::multisig::Multisig;
 use crate::verifier_set::VerifierSet;
 
+/// Maximum length in bytes of the reason a signing session is aborted for
+pub const MAX_REASON_LENGTH: usize = 256;
+
 #[cw_serde]
 pub struct InstantiateMsg {
     /// the governance address is allowed to modify the authorized caller list for this contract
@@ -20,6 +24,9 @@ pub struct InstantiateMsg {
     pub rewards_address: String,
     /// number of blocks after which a signing session expires
     pub block_expiry: nonempty::Uint64,
+    /// The coordinator address (or governance) is allowed to register public keys for multiple verifiers at once
+    #[serde(default)]
+    pub coordinator_address: Option<String>,
 }
 
 #[cw_serde]
@@ -39,19 +46,52 @@ pub enum ExecuteMsg {
         /// [signature_verifier_api::msg]
         sig_verifier: Option<String>,
     },
+    /// Starts a single signing session for a batch of messages, e.g. all proofs a prover needs at once.
+    /// Signers submit one signature per message with SubmitSignatures, and each message completes separately.
+    /// Can only be called by an authorized contract.
+    #[permission(Specific(authorized))]
+    StartBatchSigningSession {
+        verifier_set_id: String,
+        msgs: nonempty::Vec<HexBinary>,
+        chain_name: ChainName,
+        /// See StartSigningSession
+        sig_verifier: Option<String>,
+    },
+    /// Aborts a pending signing session, e.g. because its payload became obsolete. Signers are no longer expected to sign,
+    /// and signatures submitted after the abort are rejected. Can only be called by the contract that started the session.
+    #[permission(Any)]
+    AbortSigningSession {
+        session_id: Uint64,
+        reason: bounded::String<MAX_REASON_LENGTH>,
+    },
     #[permission(Any)]
     SubmitSignature {
         session_id: Uint64,
         signature: HexBinary,
     },
+    /// Submits the signatures for a batch signing session, one for each message in the same order as the messages
+    #[permission(Any)]
+    SubmitSignatures {
+        session_id: Uint64,
+        signatures: nonempty::Vec<HexBinary>,
+    },
     #[permission(Any)]
     RegisterVerifierSet { verifier_set: VerifierSet },
     #[permission(Any)]
     RegisterPublicKey {
         public_key: PublicKey,
         /// To prevent anyone from registering a public key that belongs to someone else, we require the sender
-        /// to sign their own address using the private key
-        signed_sender_address: HexBinary,
+        /// to sign the registration challenge (see `key::registration_challenge`) using the private key.
+        /// The challenge covers the sender address, the chain id and the key type.
+        proof_of_possession: HexBinary,
+    },
+    /// Registers public keys on behalf of multiple verifiers at once, e.g. to onboard a cohort of new verifiers.
+    /// Each proof of possession must sign the registration challenge of the respective verifier address.
+    /// Invalid registrations don't fail the whole batch, the response data contains the outcome of each registration
+    /// as a `Vec<PublicKeyRegistrationResult>` in the same order as the input.
+    #[permission(Governance, Specific(coordinator))]
+    RegisterPublicKeys {
+        registrations: nonempty::Vec<PublicKeyRegistration>,
     },
     /// Authorizes a set of contracts to call StartSigningSession.
     #[permission(Governance)]
@@ -63,6 +103,10 @@ pub enum ExecuteMsg {
     UnauthorizeCallers {
         contracts: HashMap<String, ChainName>,
     },
+    /// Removes the signatures and message states of batch signing sessions that expired long ago, oldest first.
+    /// At most `entry_limit` entries (default 100) are removed per call, so it may need to be called repeatedly.
+    #[permission(Any)]
+    PruneBatchSessions { entry_limit: Option<u32> },
 
     /// Emergency command to stop all amplifier signing
     #[permission(Elevated)]
@@ -79,6 +123,10 @@ pub enum QueryMsg {
     #[returns(Multisig)]
     Multisig { session_id: Uint64 },
 
+    /// Returns the multisig of each message of a batch signing session, in the same order as the messages
+    #[returns(Vec<Multisig>)]
+    BatchMultisig { session_id: Uint64 },
+
     #[returns(VerifierSet)]
     VerifierSet { verifier_set_id: String },
 
@@ -93,6 +141,25 @@ pub enum QueryMsg {
         contract_address: String,
         chain_name: ChainName,
     },
+
+    /// Returns true if the verifier is a signer of a pending signing session that hasn't expired and that it hasn't signed yet
+    #[returns(bool)]
+    HasOpenSigningSessions { verifier_address: String },
+}
+
+#[cw_serde]
+pub struct PublicKeyRegistration {
+    pub verifier_address: String,
+    pub public_key: PublicKey,
+    /// Signature over the registration challenge (see `key::registration_challenge`) of `verifier_address`
+    pub proof_of_possession: HexBinary,
+}
+
+#[cw_serde]
+pub struct PublicKeyRegistrationResult {
+    pub verifier_address: String,
+    /// The reason the registration failed, or `None` if the public key was registered
+    pub error: Option<String>,
 }
 
 #[cw_serde]
@@ -103,6 +170,16 @@ pub struct Signer {
     pub pub_key: PublicKey,
 }
 
+impl WeightedParticipant for Signer {
+    fn address(&self) -> &Addr {
+        &self.address
+    }
+
+    fn weight(&self) -> Uint128 {
+        self.weight
+    }
+}
+
 impl Signer {
     pub fn with_sig(&self, signature: Signature) -> SignerWithSig {
         SignerWithSig {
@@ -117,3 +194,13 @@ pub struct SignerWithSig {
     pub signer: Signer,
     pub signature: Signature,
 }
+
+impl WeightedParticipant for SignerWithSig {
+    fn address(&self) -> &Addr {
+        &self.signer.address
+    }
+
+    fn weight(&self) -> Uint128 {
+        self.signer.weight
+    }
+}
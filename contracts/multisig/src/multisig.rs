@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 
+use axelar_wasm_std::snapshot;
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::Uint128;
 use itertools::Itertools;
@@ -17,26 +18,36 @@ pub struct Multisig {
 }
 
 impl Multisig {
-    /// Returns the minimum amount of signers with signatures to satisfy the quorum, sorted by weight
+    /// Returns the minimum amount of signers with signatures to satisfy the quorum and the minimum signer count
+    /// of the verifier set, sorted by weight
     pub fn optimize_signatures(&self) -> Vec<SignerWithSig> {
+        let all_signatures = self.all_signatures();
+        let quorum_signers =
+            snapshot::heaviest_until_quorum(&all_signatures, self.verifier_set.threshold).len();
+        let min_signers = self.verifier_set.min_signers.map_or(0, usize::from);
+
+        all_signatures
+            .into_iter()
+            .take(quorum_signers.max(min_signers))
+            .collect()
+    }
+
+    /// Returns all signers with submitted signatures, including those beyond the quorum, sorted by weight
+    pub fn all_signatures(&self) -> Vec<SignerWithSig> {
         self.signatures
             .iter()
             .sorted_by(|(addr_a, _), (addr_b, _)| {
                 self.signer(addr_b).weight.cmp(&self.signer(addr_a).weight)
             })
-            .scan(Uint128::zero(), |acc, (addr, signature)| {
-                let signer = self.signer(addr);
-
-                if *acc < self.verifier_set.threshold {
-                    *acc = acc.saturating_add(signer.weight);
-                    Some(signer.with_sig(signature.clone()))
-                } else {
-                    None
-                }
-            })
+            .map(|(addr, signature)| self.signer(addr).with_sig(signature.clone()))
             .collect()
     }
 
+    /// Returns the cumulative weight of all signers with submitted signatures
+    pub fn signed_weight(&self) -> Uint128 {
+        snapshot::total_weight(self.signatures.keys().map(|addr| self.signer(addr)))
+    }
+
     fn signer(&self, address: &str) -> &Signer {
         self.verifier_set
             .signers
@@ -95,6 +106,7 @@ mod test {
                 .collect(),
             threshold,
             created_at: 1,
+            min_signers: None,
         };
 
         let multisig = Multisig {
@@ -106,6 +118,96 @@ mod test {
         assert_eq!(multisig.optimize_signatures(), expected_optimized_signers);
     }
 
+    #[test]
+    fn all_signatures_includes_signers_beyond_quorum() {
+        let api = MockApi::default();
+        let signers = vec![
+            signer(api.addr_make("signer0"), 1),
+            signer(api.addr_make("signer1"), 3),
+            signer(api.addr_make("signer2"), 5),
+            signer(api.addr_make("signer3"), 7),
+        ];
+
+        let sig = Signature::Ecdsa(HexBinary::from([0; 64]).try_into().unwrap());
+
+        // signer 0, 2, 3 submitted signatures, signer 3 alone satisfies the quorum
+        let sigs = vec![
+            (api.addr_make("signer0").to_string(), sig.clone()),
+            (api.addr_make("signer2").to_string(), sig.clone()),
+            (api.addr_make("signer3").to_string(), sig.clone()),
+        ];
+
+        let verifier_set = VerifierSet {
+            signers: signers
+                .iter()
+                .map(|s| (s.address.to_string(), s.clone()))
+                .collect(),
+            threshold: Uint128::from(7u64),
+            created_at: 1,
+            min_signers: None,
+        };
+
+        let multisig = Multisig {
+            state: MultisigState::Completed { completed_at: 1 },
+            verifier_set,
+            signatures: sigs.into_iter().collect(),
+        };
+
+        assert_eq!(
+            multisig.optimize_signatures(),
+            vec![signers[3].with_sig(sig.clone())]
+        );
+        assert_eq!(
+            multisig.all_signatures(),
+            vec![
+                signers[3].with_sig(sig.clone()),
+                signers[2].with_sig(sig.clone()),
+                signers[0].with_sig(sig.clone()),
+            ]
+        );
+        assert_eq!(multisig.signed_weight(), Uint128::from(13u64));
+    }
+
+    #[test]
+    fn optimize_signatures_includes_min_signers() {
+        let api = MockApi::default();
+        let signers = vec![
+            signer(api.addr_make("signer0"), 1),
+            signer(api.addr_make("signer1"), 3),
+            signer(api.addr_make("signer2"), 7),
+        ];
+
+        let sig = Signature::Ecdsa(HexBinary::from([0; 64]).try_into().unwrap());
+        let sigs = signers
+            .iter()
+            .map(|signer| (signer.address.to_string(), sig.clone()));
+
+        // signer 2 alone satisfies the quorum, but two signers are required
+        let verifier_set = VerifierSet {
+            signers: signers
+                .iter()
+                .map(|s| (s.address.to_string(), s.clone()))
+                .collect(),
+            threshold: Uint128::from(7u64),
+            created_at: 1,
+            min_signers: Some(2),
+        };
+
+        let multisig = Multisig {
+            state: MultisigState::Completed { completed_at: 1 },
+            verifier_set,
+            signatures: sigs.collect(),
+        };
+
+        assert_eq!(
+            multisig.optimize_signatures(),
+            vec![
+                signers[2].with_sig(sig.clone()),
+                signers[1].with_sig(sig.clone()),
+            ]
+        );
+    }
+
     fn signer(address: Addr, weight: u64) -> Signer {
         Signer {
             address,
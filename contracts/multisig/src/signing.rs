@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 
+use axelar_wasm_std::snapshot;
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, CosmosMsg, HexBinary, Uint128, Uint64};
 use error_stack::{bail, ResultExt};
@@ -20,6 +21,9 @@ pub struct SigningSession {
     pub state: MultisigState,
     pub expires_at: u64,
     pub sig_verifier: Option<Addr>,
+    /// Contract that started the session. Not known for sessions started before it was recorded
+    #[serde(default)]
+    pub initiator: Option<Addr>,
 }
 
 impl SigningSession {
@@ -30,6 +34,7 @@ impl SigningSession {
         msg: MsgToSign,
         expires_at: u64,
         sig_verifier: Option<Addr>,
+        initiator: Addr,
     ) -> Self {
         Self {
             id: session_id,
@@ -39,6 +44,7 @@ impl SigningSession {
             state: MultisigState::Pending,
             expires_at,
             sig_verifier,
+            initiator: Some(initiator),
         }
     }
 
@@ -48,13 +54,28 @@ impl SigningSession {
         verifier_set: &VerifierSet,
         block_height: u64,
     ) {
-        let weight = signers_weight(signatures, verifier_set);
+        self.state = recalculate_state(self.state.clone(), signatures, verifier_set, block_height);
+    }
+}
 
-        if self.state == MultisigState::Pending && weight >= verifier_set.threshold {
-            self.state = MultisigState::Completed {
-                completed_at: block_height,
-            };
+/// Completes a pending session, or a message of a batch session, once the signatures meet the quorum
+pub fn recalculate_state(
+    state: MultisigState,
+    signatures: &HashMap<String, Signature>,
+    verifier_set: &VerifierSet,
+    block_height: u64,
+) -> MultisigState {
+    let weight = signers_weight(signatures, verifier_set);
+
+    if state == MultisigState::Pending
+        && snapshot::is_quorum_met(weight, verifier_set.threshold)
+        && verifier_set.is_min_signers_met(signatures.len())
+    {
+        MultisigState::Completed {
+            completed_at: block_height,
         }
+    } else {
+        state
     }
 }
 
@@ -66,6 +87,33 @@ pub fn validate_session_signature(
     block_height: u64,
     sig_verifier: Option<SignatureVerifier>,
 ) -> error_stack::Result<Option<CosmosMsg>, ContractError> {
+    validate_signature(
+        session,
+        &session.msg,
+        signer,
+        signature,
+        pub_key,
+        block_height,
+        sig_verifier,
+    )
+}
+
+/// Validates a signature over one of the messages of the session
+pub fn validate_signature(
+    session: &SigningSession,
+    msg: &MsgToSign,
+    signer: &Addr,
+    signature: &Signature,
+    pub_key: &PublicKey,
+    block_height: u64,
+    sig_verifier: Option<SignatureVerifier>,
+) -> error_stack::Result<Option<CosmosMsg>, ContractError> {
+    if let MultisigState::Aborted { .. } = session.state {
+        bail!(ContractError::SigningSessionAborted {
+            session_id: session.id,
+        });
+    }
+
     if session.expires_at < block_height {
         bail!(ContractError::SigningSessionClosed {
             session_id: session.id,
@@ -76,18 +124,18 @@ pub fn validate_session_signature(
         Some(sig_verifier) => Ok(Some(call_sig_verifier(
             sig_verifier,
             signature.as_ref().into(),
-            session.msg.as_ref().into(),
+            msg.as_ref().into(),
             pub_key.as_ref().into(),
             signer.to_string(),
             session.id,
         ))),
         None => {
-            signature.verify(&session.msg, pub_key).change_context(
-                ContractError::InvalidSignature {
+            signature
+                .verify(msg, pub_key)
+                .change_context(ContractError::InvalidSignature {
                     session_id: session.id,
                     signer: signer.into(),
-                },
-            )?;
+                })?;
 
             Ok(None)
         }
@@ -106,16 +154,12 @@ fn call_sig_verifier(
 }
 
 fn signers_weight(signatures: &HashMap<String, Signature>, verifier_set: &VerifierSet) -> Uint128 {
-    signatures
-        .keys()
-        .map(|addr| -> Uint128 {
-            verifier_set
-                .signers
-                .get(addr)
-                .expect("violated invariant: signature submitted by non-participant")
-                .weight
-        })
-        .sum()
+    snapshot::total_weight(signatures.keys().map(|addr| {
+        verifier_set
+            .signers
+            .get(addr)
+            .expect("violated invariant: signature submitted by non-participant")
+    }))
 }
 
 #[cfg(test)]
@@ -152,6 +196,7 @@ mod tests {
             message.clone(),
             expires_at,
             None,
+            MockApi::default().addr_make("prover"),
         );
 
         let signatures: HashMap<String, Signature> = signers
@@ -188,6 +233,7 @@ mod tests {
             message.clone(),
             expires_at,
             None,
+            MockApi::default().addr_make("prover"),
         );
 
         let signatures: HashMap<String, Signature> = signers
@@ -229,6 +275,31 @@ mod tests {
         }
     }
 
+    #[test]
+    fn session_should_not_complete_below_min_signers() {
+        for config in [ecdsa_setup(), ed25519_setup()] {
+            let mut session = config.session;
+            let num_signers = u16::try_from(config.verifier_set.signers.len()).unwrap();
+            let verifier_set = config
+                .verifier_set
+                .with_min_signers(Some(num_signers.saturating_add(1)));
+            let block_height = 12345;
+
+            // the weight of all signers meets the threshold, but not enough distinct signers signed
+            session.recalculate_session_state(&config.signatures, &verifier_set, block_height);
+            assert_eq!(session.state, MultisigState::Pending);
+
+            let verifier_set = verifier_set.with_min_signers(Some(num_signers));
+            session.recalculate_session_state(&config.signatures, &verifier_set, block_height);
+            assert_eq!(
+                session.state,
+                MultisigState::Completed {
+                    completed_at: block_height
+                }
+            );
+        }
+    }
+
     #[test]
     fn success_validation() {
         for config in [ecdsa_setup(), ed25519_setup()] {
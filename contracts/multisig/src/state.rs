@@ -9,6 +9,7 @@ use router_api::ChainName;
 
 use crate::key::{KeyType, KeyTyped, PublicKey, Signature};
 use crate::signing::SigningSession;
+use crate::types::{MsgToSign, MultisigState};
 use crate::verifier_set::VerifierSet;
 use crate::ContractError;
 
@@ -16,6 +17,8 @@ use crate::ContractError;
 pub struct Config {
     pub rewards_contract: Addr,
     pub block_expiry: nonempty::Uint64, // number of blocks after which a signing session expires
+    /// The coordinator is allowed to register public keys on behalf of verifiers, in addition to governance
+    pub coordinator: Option<Addr>,
 }
 
 type VerifierSetId = str;
@@ -30,6 +33,13 @@ pub const VERIFIER_SETS: Map<&VerifierSetId, VerifierSet> = Map::new("verifier_s
 /// Signatures by session id and signer address
 pub const SIGNATURES: Map<(u64, &str), Signature> = Map::new("signatures");
 
+/// Messages of batch signing sessions by session id
+pub const SESSION_BATCHES: Map<u64, Vec<MsgToSign>> = Map::new("session_batches");
+/// Batch signatures by session id, message index and signer address
+pub const BATCH_SIGNATURES: Map<(u64, u32, &str), Signature> = Map::new("batch_signatures");
+/// State of each message of a batch signing session by session id and message index
+pub const BATCH_STATES: Map<(u64, u32), MultisigState> = Map::new("batch_states");
+
 pub fn load_session_signatures(
     store: &dyn Storage,
     session_id: u64,
@@ -61,6 +71,93 @@ pub fn save_signature(
     )
 }
 
+pub fn load_batch_signatures(
+    store: &dyn Storage,
+    session_id: u64,
+    index: u32,
+) -> StdResult<HashMap<String, Signature>> {
+    BATCH_SIGNATURES
+        .prefix((session_id, index))
+        .range(store, None, None, Order::Ascending)
+        .collect()
+}
+
+pub fn save_batch_signature(
+    store: &mut dyn Storage,
+    session_id: Uint64,
+    index: u32,
+    signature: Signature,
+    signer: &Addr,
+) -> Result<Signature, ContractError> {
+    BATCH_SIGNATURES.update(
+        store,
+        (session_id.u64(), index, signer.as_ref()),
+        |sig| -> Result<Signature, ContractError> {
+            match sig {
+                Some(_) => Err(ContractError::DuplicateSignature {
+                    session_id,
+                    signer: signer.into(),
+                }),
+                None => Ok(signature),
+            }
+        },
+    )
+}
+
+pub fn load_batch_signature_keys(
+    store: &dyn Storage,
+    session_id: u64,
+    limit: usize,
+) -> StdResult<Vec<(u32, String)>> {
+    BATCH_SIGNATURES
+        .sub_prefix(session_id)
+        .keys(store, None, None, Order::Ascending)
+        .take(limit)
+        .collect()
+}
+
+pub fn remove_batch_signature(store: &mut dyn Storage, session_id: u64, index: u32, signer: &str) {
+    BATCH_SIGNATURES.remove(store, (session_id, index, signer))
+}
+
+/// Returns the oldest batch signing session if it expired before the given block height
+pub fn load_oldest_batch_session_expired_before(
+    store: &dyn Storage,
+    height: u64,
+) -> StdResult<Option<u64>> {
+    let Some(session_id) = SESSION_BATCHES
+        .keys(store, None, None, Order::Ascending)
+        .next()
+        .transpose()?
+    else {
+        return Ok(None);
+    };
+
+    let session = SIGNING_SESSIONS.load(store, session_id)?;
+    Ok((session.expires_at < height).then_some(session_id))
+}
+
+/// Removes the messages and message states of a batch signing session. Its signatures must be removed beforehand.
+pub fn remove_batch_session(store: &mut dyn Storage, session_id: u64) -> StdResult<()> {
+    let msgs = SESSION_BATCHES.load(store, session_id)?;
+    for index in (0u32..).take(msgs.len()) {
+        BATCH_STATES.remove(store, (session_id, index));
+    }
+    SESSION_BATCHES.remove(store, session_id);
+
+    Ok(())
+}
+
+pub fn load_batch_state(
+    store: &dyn Storage,
+    session_id: u64,
+    index: u32,
+) -> StdResult<MultisigState> {
+    Ok(BATCH_STATES
+        .may_load(store, (session_id, index))?
+        .unwrap_or(MultisigState::Pending))
+}
+
 pub fn verifier_set(
     store: &dyn Storage,
     verifier_set_id: &str,
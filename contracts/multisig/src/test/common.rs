@@ -2,7 +2,7 @@ use axelar_wasm_std::Participant;
 use cosmwasm_std::{Addr, HexBinary, Uint128};
 use k256::ecdsa::signature::hazmat::PrehashSigner;
 
-use crate::key::{KeyType, PublicKey};
+use crate::key::{registration_challenge, KeyType, PublicKey};
 use crate::verifier_set::VerifierSet;
 
 #[derive(Clone, Debug)]
@@ -10,27 +10,31 @@ pub struct TestSigner {
     pub address: Addr,
     pub pub_key: HexBinary,
     pub signature: HexBinary,
-    pub signed_address: HexBinary,
+    pub proof_of_possession: HexBinary,
 }
 
 pub mod ecdsa_test_data {
-    use cosmwasm_std::testing::MockApi;
+    use cosmwasm_std::testing::{mock_env, MockApi};
     use k256::ecdsa::{Signature, SigningKey};
-    use sha3::{Digest, Keccak256};
 
     use super::*;
 
     pub fn new(address: Addr, signing_key: SigningKey) -> TestSigner {
-        let address_hash = Keccak256::digest(address.as_bytes());
+        let challenge = registration_challenge(
+            address.as_str(),
+            mock_env().block.chain_id.as_str(),
+            KeyType::Ecdsa,
+        );
         let verifying_key = signing_key.verifying_key();
         let signature: Signature = signing_key.sign_prehash(message().as_slice()).unwrap();
-        let signed_address: Signature = signing_key.sign_prehash(address_hash.as_slice()).unwrap();
+        let proof_of_possession: Signature =
+            signing_key.sign_prehash(challenge.as_slice()).unwrap();
 
         TestSigner {
             address,
             pub_key: verifying_key.to_sec1_bytes().to_vec().into(),
             signature: signature.to_bytes().to_vec().into(),
-            signed_address: signed_address.to_bytes().to_vec().into(),
+            proof_of_possession: proof_of_possession.to_bytes().to_vec().into(),
         }
     }
 
@@ -73,23 +77,26 @@ pub mod ecdsa_test_data {
 }
 
 pub mod ed25519_test_data {
-    use cosmwasm_std::testing::MockApi;
+    use cosmwasm_std::testing::{mock_env, MockApi};
     use k256::ecdsa::signature::SignerMut;
-    use sha3::{Digest, Keccak256};
 
     use super::*;
 
     pub fn new(address: Addr, mut signing_key: ed25519_dalek::SigningKey) -> TestSigner {
-        let address_hash = Keccak256::digest(address.as_bytes());
+        let challenge = registration_challenge(
+            address.as_str(),
+            mock_env().block.chain_id.as_str(),
+            KeyType::Ed25519,
+        );
         let verifying_key = signing_key.verifying_key();
         let signature = signing_key.sign(message().as_slice());
-        let signed_address = signing_key.sign(address_hash.as_slice());
+        let proof_of_possession = signing_key.sign(challenge.as_slice());
 
         TestSigner {
             address,
             pub_key: verifying_key.to_bytes().to_vec().into(),
             signature: signature.to_bytes().to_vec().into(),
-            signed_address: signed_address.to_bytes().to_vec().into(),
+            proof_of_possession: proof_of_possession.to_bytes().to_vec().into(),
         }
     }
 
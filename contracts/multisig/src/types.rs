@@ -31,6 +31,10 @@ pub enum MultisigState {
     Completed {
         completed_at: u64, // block at which the session was completed
     },
+    Aborted {
+        aborted_at: u64, // block at which the session was aborted
+        reason: String,
+    },
 }
 
 const MESSAGE_HASH_LEN: usize = 32;
@@ -17,6 +17,9 @@ pub struct VerifierSet {
     // for hash uniqueness. The same exact verifier set could be in use at two different times,
     // and we need to be able to distinguish between the two
     pub created_at: u64,
+    /// Minimum number of distinct signers required to complete a signing session, in addition to the weight threshold
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_signers: Option<u16>,
 }
 
 impl VerifierSet {
@@ -43,9 +46,48 @@ impl VerifierSet {
             signers,
             threshold,
             created_at: block_height,
+            min_signers: None,
         }
     }
 
+    /// Returns the signers that were added, removed, or changed their weight compared to the previous verifier set.
+    /// If there is no previous verifier set, all signers count as added
+    pub fn diff(&self, previous: Option<&VerifierSet>) -> VerifierSetDiff {
+        let previous_signers = previous.map(|previous| &previous.signers);
+        let was_signer = |address: &String| {
+            previous_signers.and_then(|previous_signers| previous_signers.get(address))
+        };
+
+        let mut diff = VerifierSetDiff::default();
+        for (address, signer) in &self.signers {
+            match was_signer(address) {
+                None => diff.added.push(signer.into()),
+                Some(previous_signer) if previous_signer.weight != signer.weight => {
+                    diff.weight_changes.push(WeightChange {
+                        signer: signer.address.clone(),
+                        previous_weight: previous_signer.weight,
+                        weight: signer.weight,
+                    })
+                }
+                Some(_) => {}
+            }
+        }
+
+        diff.removed = previous_signers
+            .into_iter()
+            .flatten()
+            .filter(|(address, _)| !self.signers.contains_key(*address))
+            .map(|(_, signer)| signer.into())
+            .collect();
+
+        diff
+    }
+
+    pub fn with_min_signers(mut self, min_signers: Option<u16>) -> Self {
+        self.min_signers = min_signers;
+        self
+    }
+
     pub fn hash(&self) -> Hash {
         let mut hasher = Keccak256::new();
 
@@ -61,6 +103,11 @@ impl VerifierSet {
         hasher.update(self.threshold.to_be_bytes());
         hasher.update(self.created_at.to_be_bytes());
 
+        // only hashed if set, so the ids of verifier sets without a minimum stay the same
+        if let Some(min_signers) = self.min_signers {
+            hasher.update(min_signers.to_be_bytes());
+        }
+
         hasher.finalize().into()
     }
 
@@ -78,10 +125,50 @@ impl VerifierSet {
     pub fn includes(&self, signer: &Addr) -> bool {
         self.signers.contains_key(signer.as_str())
     }
+
+    /// Returns true if the given number of distinct signers is enough to complete a signing session
+    pub fn is_min_signers_met(&self, num_signers: usize) -> bool {
+        self.min_signers
+            .map_or(true, |min_signers| num_signers >= usize::from(min_signers))
+    }
+}
+
+/// Changes of the signers of a verifier set compared to a previous verifier set, each ordered by signer address
+#[cw_serde]
+#[derive(Default)]
+pub struct VerifierSetDiff {
+    pub added: Vec<SignerWeight>,
+    pub removed: Vec<SignerWeight>,
+    pub weight_changes: Vec<WeightChange>,
+}
+
+#[cw_serde]
+pub struct SignerWeight {
+    pub signer: Addr,
+    pub weight: Uint128,
+}
+
+impl From<&Signer> for SignerWeight {
+    fn from(signer: &Signer) -> Self {
+        SignerWeight {
+            signer: signer.address.clone(),
+            weight: signer.weight,
+        }
+    }
+}
+
+#[cw_serde]
+pub struct WeightChange {
+    pub signer: Addr,
+    pub previous_weight: Uint128,
+    pub weight: Uint128,
 }
 
 #[cfg(test)]
 mod tests {
+    use cosmwasm_std::Uint128;
+
+    use super::{SignerWeight, VerifierSetDiff, WeightChange};
     use crate::key::KeyType;
     use crate::test::common::{build_verifier_set, ecdsa_test_data};
 
@@ -102,4 +189,46 @@ mod tests {
 
         goldie::assert_json!(verifier_set.id());
     }
+
+    #[test]
+    fn diff_should_list_added_and_removed_signers_and_weight_changes() {
+        let signers = ecdsa_test_data::signers();
+        let previous = build_verifier_set(KeyType::Ecdsa, &signers[..2]);
+
+        let mut verifier_set = build_verifier_set(KeyType::Ecdsa, &signers[1..]);
+        let changed = verifier_set
+            .signers
+            .get_mut(signers[1].address.as_str())
+            .unwrap();
+        changed.weight = Uint128::new(5);
+
+        let signer_weight = |index: usize| SignerWeight {
+            signer: signers[index].address.clone(),
+            weight: Uint128::one(),
+        };
+        assert_eq!(
+            verifier_set.diff(Some(&previous)),
+            VerifierSetDiff {
+                added: vec![signer_weight(2)],
+                removed: vec![signer_weight(0)],
+                weight_changes: vec![WeightChange {
+                    signer: signers[1].address.clone(),
+                    previous_weight: Uint128::one(),
+                    weight: Uint128::new(5),
+                }],
+            }
+        );
+
+        assert_eq!(
+            verifier_set.diff(Some(&verifier_set)),
+            VerifierSetDiff::default()
+        );
+
+        let initial = previous.diff(None);
+        assert_eq!(initial.added.len(), 2);
+        assert!(initial.added.contains(&signer_weight(0)));
+        assert!(initial.added.contains(&signer_weight(1)));
+        assert!(initial.removed.is_empty());
+        assert!(initial.weight_changes.is_empty());
+    }
 }
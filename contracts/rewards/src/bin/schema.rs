@@ -1,10 +1,11 @@
 use cosmwasm_schema::write_api;
-use rewards::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
+use rewards::msg::{ExecuteMsg, InstantiateMsg, QueryMsg, SudoMsg};
 
 fn main() {
     write_api! {
         instantiate: InstantiateMsg,
         execute: ExecuteMsg,
         query: QueryMsg,
+        sudo: SudoMsg,
     }
 }
@@ -0,0 +1,96 @@
+use std::ops::Deref;
+
+use router_api::Address;
+
+use crate::msg::{ExecuteMsg, QueryClient, QueryMsg};
+use crate::PoolId;
+
+#[derive(thiserror::Error, Debug, PartialEq)]
+pub enum Error {
+    #[error("failed to query rewards contract for rewards pool {0:?}")]
+    RewardsPool(PoolId),
+    #[error("failed to query rewards contract for participation in pool {pool_id:?} and epoch {epoch_num:?}")]
+    VerifierParticipation {
+        pool_id: PoolId,
+        epoch_num: Option<u64>,
+    },
+    #[error("failed to query rewards contract for proxy of verifier {0}")]
+    VerifierProxy(Address),
+    #[error("failed to query rewards contract for verifiers of proxy {0}")]
+    ProxyVerifiers(Address),
+    #[error("failed to query rewards contract for rewards opt-out of verifier {verifier} in pool {pool_id:?}")]
+    RewardsOptOut { pool_id: PoolId, verifier: String },
+    #[error("failed to query rewards contract for pending governance transfer")]
+    PendingGovernanceTransfer,
+    #[error("failed to query rewards contract for distribution cooldown of pool {0:?}")]
+    DistributionCooldown(PoolId),
+    #[error("failed to query rewards contract for epoch schedule of pool {0:?}")]
+    EpochSchedule(PoolId),
+    #[error("failed to query rewards contract for distributor of pool {0:?}")]
+    PoolDistributor(PoolId),
+    #[error("failed to query rewards contract for payout hook of pool {0:?}")]
+    PoolPayoutHook(PoolId),
+    #[error("failed to query rewards contract for effects of a params update of pool {0:?}")]
+    PoolParamsUpdateEffects(PoolId),
+    #[error("failed to query rewards contract for participation proof of verifier {verifier} in pool {pool_id:?} and epoch {epoch_num}")]
+    ParticipationProof {
+        pool_id: PoolId,
+        verifier: String,
+        epoch_num: u64,
+    },
+}
+
+impl From<QueryMsg> for Error {
+    fn from(value: QueryMsg) -> Self {
+        match value {
+            QueryMsg::RewardsPool { pool_id } => Error::RewardsPool(pool_id),
+            QueryMsg::VerifierParticipation { pool_id, epoch_num } => {
+                Error::VerifierParticipation { pool_id, epoch_num }
+            }
+            QueryMsg::VerifierProxy { verifier } => Error::VerifierProxy(verifier),
+            QueryMsg::ProxyVerifiers { proxy_address } => Error::ProxyVerifiers(proxy_address),
+            QueryMsg::RewardsOptOut { pool_id, verifier } => {
+                Error::RewardsOptOut { pool_id, verifier }
+            }
+            QueryMsg::PendingGovernanceTransfer => Error::PendingGovernanceTransfer,
+            QueryMsg::DistributionCooldown { pool_id } => Error::DistributionCooldown(pool_id),
+            QueryMsg::EpochSchedule { pool_id } => Error::EpochSchedule(pool_id),
+            QueryMsg::PoolDistributor { pool_id } => Error::PoolDistributor(pool_id),
+            QueryMsg::PoolPayoutHook { pool_id } => Error::PoolPayoutHook(pool_id),
+            QueryMsg::PoolParamsUpdateEffects { pool_id, .. } => {
+                Error::PoolParamsUpdateEffects(pool_id)
+            }
+            QueryMsg::ParticipationProof {
+                pool_id,
+                verifier,
+                epoch_num,
+                ..
+            } => Error::ParticipationProof {
+                pool_id,
+                verifier,
+                epoch_num,
+            },
+        }
+    }
+}
+
+impl<'a> From<client::ContractClient<'a, ExecuteMsg, QueryMsg>> for Client<'a> {
+    fn from(client: client::ContractClient<'a, ExecuteMsg, QueryMsg>) -> Self {
+        Client {
+            queries: QueryClient::new(client.querier(), client.address),
+        }
+    }
+}
+
+/// Queries are available through the generated [QueryClient]
+pub struct Client<'a> {
+    queries: QueryClient<'a>,
+}
+
+impl<'a> Deref for Client<'a> {
+    type Target = QueryClient<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.queries
+    }
+}
@@ -2,15 +2,21 @@ use axelar_wasm_std::{address, nonempty, permission_control};
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    to_json_binary, BankMsg, Binary, Coin, Deps, DepsMut, Env, MessageInfo, Response,
+    from_json, to_json_binary, wasm_execute, Addr, Api, BankMsg, Binary, Coin, CosmosMsg, Deps,
+    DepsMut, Env, MessageInfo, Reply, Response, Storage, SubMsg, Uint128, WasmMsg,
 };
-use error_stack::ResultExt;
+use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg};
+use error_stack::{report, ResultExt};
 use itertools::Itertools;
 
 use crate::error::ContractError;
 use crate::events;
-use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
-use crate::state::{self, Config, PoolId, CONFIG};
+use crate::msg::{
+    ExecuteMsg, InstantiateMsg, NewPool, PayoutHookMsg, QueryMsg, ReceiveMsg, SudoMsg,
+};
+use crate::state::{
+    self, AttestationTarget, Config, Epoch, ParamsSnapshot, PoolId, RewardsAsset, CONFIG,
+};
 
 mod execute;
 mod migrations;
@@ -18,6 +24,29 @@ mod query;
 
 pub use migrations::{migrate, MigrateMsg};
 
+/// Reply ids below this one are the indices of the pools of the most recent `DistributeRewardsMulti` call
+const SUBMIT_ATTESTATIONS_REPLY_ID: u64 = u64::MAX;
+/// Reply ids from this one up to the attestation reply id are offset indices of the most recent payouts to a payout hook
+const HOOKED_PAYOUT_REPLY_ID_OFFSET: u64 = 1 << 32;
+
+enum ReplyId {
+    SubmitAttestations,
+    MultiDistribution(u64),
+    HookedPayout(u64),
+}
+
+impl From<u64> for ReplyId {
+    fn from(id: u64) -> Self {
+        match id {
+            SUBMIT_ATTESTATIONS_REPLY_ID => ReplyId::SubmitAttestations,
+            id if id >= HOOKED_PAYOUT_REPLY_ID_OFFSET => {
+                ReplyId::HookedPayout(id.saturating_sub(HOOKED_PAYOUT_REPLY_ID_OFFSET))
+            }
+            index => ReplyId::MultiDistribution(index),
+        }
+    }
+}
+
 const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
@@ -33,10 +62,19 @@ pub fn instantiate(
     let governance = address::validate_cosmwasm_address(deps.api, &msg.governance_address)?;
     permission_control::set_governance(deps.storage, &governance)?;
 
+    let attestation_target = msg
+        .attestation_target
+        .map(|target| AttestationTarget::try_from_msg_attestation_target(deps.api, target))
+        .transpose()?;
+
     CONFIG.save(
         deps.storage,
         &Config {
             rewards_denom: msg.rewards_denom,
+            epoch_retention: msg.epoch_retention,
+            track_event_attribution: msg.track_event_attribution,
+            reserve_epoch_rewards: msg.reserve_epoch_rewards,
+            attestation_target,
         },
     )?;
 
@@ -50,7 +88,7 @@ pub fn execute(
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, axelar_wasm_std::error::ContractError> {
-    match msg.ensure_permissions(deps.storage, &info.sender)? {
+    match msg.ensure_permissions(deps.storage, &info.sender, pending_governance)? {
         ExecuteMsg::RecordParticipation {
             chain_name,
             event_id,
@@ -61,21 +99,23 @@ pub fn execute(
                 chain_name,
                 contract: info.sender,
             };
-            execute::record_participation(
+            execute::end_epoch_after_epoch_time(deps.storage, &pool_id, &env.block)?;
+            let under_reserved = execute::record_participation(
                 deps.storage,
-                event_id,
+                event_id.into(),
                 verifier_address,
                 pool_id,
                 env.block.height,
             )?;
 
-            Ok(Response::new())
+            Ok(Response::new().add_events(under_reserved))
         }
         ExecuteMsg::AddRewards { pool_id } => {
+            let rewards_denom = state::load_config(deps.storage)?.rewards_denom;
             let amount = info
                 .funds
                 .iter()
-                .find(|coin| coin.denom == state::load_config(deps.storage).rewards_denom)
+                .find(|coin| coin.denom == rewards_denom)
                 .filter(|_| info.funds.len() == 1) // filter here to make sure expected denom is the only one attached to this message, and other funds aren't silently swallowed
                 .ok_or(ContractError::WrongDenom)?
                 .amount;
@@ -84,6 +124,20 @@ pub fn execute(
                 deps.storage,
                 PoolId::try_from_msg_pool_id(deps.api, pool_id)?,
                 nonempty::Uint128::try_from(amount).change_context(ContractError::ZeroRewards)?,
+                &RewardsAsset::Native,
+            )?;
+
+            Ok(Response::new())
+        }
+        ExecuteMsg::Receive(Cw20ReceiveMsg { amount, msg, .. }) => {
+            let ReceiveMsg::AddRewards { pool_id } =
+                from_json(&msg).change_context(ContractError::InvalidReceiveMsg)?;
+
+            execute::add_rewards(
+                deps.storage,
+                PoolId::try_from_msg_pool_id(deps.api, pool_id)?,
+                nonempty::Uint128::try_from(amount).change_context(ContractError::ZeroRewards)?,
+                &RewardsAsset::Cw20(info.sender),
             )?;
 
             Ok(Response::new())
@@ -92,53 +146,210 @@ pub fn execute(
             pool_id,
             epoch_count,
         } => {
+            let pool_id = PoolId::try_from_msg_pool_id(deps.api, pool_id)?;
+            execute::ensure_can_distribute(
+                deps.storage,
+                &pool_id,
+                &info.sender,
+                &env.contract.address,
+            )?;
+
+            execute::end_epoch_after_epoch_time(deps.storage, &pool_id, &env.block)?;
             let rewards_distribution = execute::distribute_rewards(
                 deps.storage,
-                PoolId::try_from_msg_pool_id(deps.api, pool_id)?,
+                pool_id.clone(),
                 env.block.height,
                 epoch_count,
             )?;
 
-            let msgs = rewards_distribution
-                .rewards
-                .clone()
-                .into_iter()
-                .sorted()
-                .map(|(verifier, amount)| BankMsg::Send {
-                    to_address: verifier
-                        .proxy_address
-                        .unwrap_or(verifier.verifier_address)
-                        .into(),
-                    amount: vec![Coin {
-                        denom: state::load_config(deps.storage).rewards_denom.clone(),
-                        amount,
-                    }],
-                });
+            let config = state::load_config(deps.storage)?;
+            let attestation_msg = match config.attestation_target {
+                Some(target) => {
+                    let service_registry: service_registry_api::Client =
+                        client::ContractClient::new(deps.querier, &target.service_registry).into();
+                    let summaries = execute::participation_summaries(
+                        deps.storage,
+                        &pool_id,
+                        &rewards_distribution.epochs_processed,
+                        active_verifiers(&service_registry, &target.service_name, &pool_id),
+                    )?;
+
+                    // a failed submission must not revert the distribution, the reply reports it instead
+                    service_registry
+                        .submit_attestations(target.service_name, summaries)
+                        .map(|msg| SubMsg::reply_on_error(msg, SUBMIT_ATTESTATIONS_REPLY_ID))
+                }
+                None => None,
+            };
+
+            let asset = state::load_rewards_pool(deps.storage, pool_id.clone())?.asset;
+            let rewards_denom = config.rewards_denom;
+            let payout_hook = state::may_load_pool_payout_hook(deps.storage, pool_id.clone())?;
+
+            let mut payouts = rewards_distribution.rewards.clone();
+            for (verifier, owed) in state::take_owed_payouts(deps.storage, &pool_id)? {
+                let amount = payouts
+                    .entry(state::load_verifier(deps.storage, &verifier)?)
+                    .or_default();
+                *amount = amount.checked_add(owed).map_err(ContractError::from)?;
+            }
+            let payouts = payouts.into_iter().sorted().collect::<Vec<_>>();
+
+            let msgs = match &payout_hook {
+                // a reverting hook must not block the distribution, the reply keeps the failed payout owed instead
+                Some(payout_hook) => {
+                    state::save_hooked_payouts(
+                        deps.storage,
+                        &payouts
+                            .iter()
+                            .map(|(verifier, amount)| state::HookedPayout {
+                                pool_id: pool_id.clone(),
+                                verifier: verifier.verifier_address.clone(),
+                                amount: *amount,
+                            })
+                            .collect::<Vec<_>>(),
+                    )?;
+
+                    payouts
+                        .into_iter()
+                        .zip(HOOKED_PAYOUT_REPLY_ID_OFFSET..)
+                        .map(|((verifier, amount), reply_id)| {
+                            hooked_payout_msg(
+                                &asset,
+                                &rewards_denom,
+                                payout_hook,
+                                PayoutHookMsg::Payout {
+                                    pool_id: pool_id.clone().into(),
+                                    beneficiary: verifier
+                                        .proxy_address
+                                        .unwrap_or(verifier.verifier_address.clone()),
+                                    verifier: verifier.verifier_address,
+                                },
+                                amount,
+                            )
+                            .map(|msg| SubMsg::reply_on_error(msg, reply_id))
+                        })
+                        .collect::<Result<Vec<_>, _>>()?
+                }
+                None => payouts
+                    .into_iter()
+                    .map(|(verifier, amount)| {
+                        payout_msg(
+                            &asset,
+                            &rewards_denom,
+                            verifier.proxy_address.unwrap_or(verifier.verifier_address),
+                            amount,
+                        )
+                        .map(SubMsg::new)
+                    })
+                    .collect::<Result<Vec<_>, _>>()?,
+            };
+            let burn_msg = (!rewards_distribution.burned.is_zero())
+                .then(|| burn_msg(&asset, &rewards_denom, rewards_distribution.burned))
+                .transpose()?;
 
             Ok(Response::new()
-                .add_messages(msgs)
-                .add_event(events::Event::from(rewards_distribution)))
+                .add_submessages(msgs)
+                .add_messages(burn_msg)
+                .add_submessages(attestation_msg)
+                .add_events(Vec::<cosmwasm_std::Event>::from(rewards_distribution)))
         }
-        ExecuteMsg::UpdatePoolParams { params, pool_id } => {
+        ExecuteMsg::DistributeRewardsMulti {
+            pool_ids,
+            epoch_count,
+        } => {
+            let validated_pool_ids = pool_ids
+                .iter()
+                .cloned()
+                .map(|pool_id| PoolId::try_from_msg_pool_id(deps.api, pool_id))
+                .collect::<Result<Vec<_>, _>>()?;
+            // the distributions are executed by the contract itself, so the permission of the sender is checked up front
+            for pool_id in &validated_pool_ids {
+                execute::ensure_can_distribute(
+                    deps.storage,
+                    pool_id,
+                    &info.sender,
+                    &env.contract.address,
+                )?;
+            }
+            state::save_multi_distribution_pools(deps.storage, &validated_pool_ids)?;
+
+            // the index of the pool is used as reply id, so a failed distribution can be attributed to its pool
+            let msgs = pool_ids
+                .into_iter()
+                .zip(0u64..)
+                .map(|(pool_id, index)| {
+                    wasm_execute(
+                        env.contract.address.clone(),
+                        &ExecuteMsg::DistributeRewards {
+                            pool_id,
+                            epoch_count,
+                        },
+                        vec![],
+                    )
+                    .map(|msg| SubMsg::reply_on_error(msg, index))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            Ok(Response::new().add_submessages(msgs))
+        }
+        ExecuteMsg::PruneEpochs { pool_id, limit } => {
+            let pruned = execute::prune_epochs(
+                deps.storage,
+                PoolId::try_from_msg_pool_id(deps.api, pool_id)?,
+                limit,
+            )?;
+
+            Ok(Response::new().add_event(events::Event::from(pruned)))
+        }
+        ExecuteMsg::UpdatePoolParams {
+            params,
+            pool_id,
+            align_epochs_with,
+        } => {
             execute::update_pool_params(
                 deps.storage,
                 &PoolId::try_from_msg_pool_id(deps.api, pool_id)?,
                 params,
                 env.block.height,
+                align_epochs_with
+                    .map(|pool_id| PoolId::try_from_msg_pool_id(deps.api, pool_id))
+                    .transpose()?,
             )?;
 
             Ok(Response::new())
         }
-        ExecuteMsg::CreatePool { params, pool_id } => {
-            execute::create_pool(
+        ExecuteMsg::CreatePool {
+            params,
+            pool_id,
+            rewards_asset,
+            align_epochs_with,
+        } => {
+            ensure_not_funded(&info)?;
+
+            create_pool(
+                deps.api,
                 deps.storage,
-                params,
                 env.block.height,
-                PoolId::try_from_msg_pool_id(deps.api, pool_id)?,
+                NewPool {
+                    params,
+                    pool_id,
+                    rewards_asset,
+                    align_epochs_with,
+                },
             )?;
 
             Ok(Response::new())
         }
+        ExecuteMsg::CreatePools(pools) => {
+            ensure_not_funded(&info)?;
+
+            for pool in pools {
+                create_pool(deps.api, deps.storage, env.block.height, pool)?;
+            }
+
+            Ok(Response::new())
+        }
         ExecuteMsg::SetVerifierProxy { proxy_address } => {
             execute::set_verifier_proxy(
                 deps.storage,
@@ -149,11 +360,348 @@ pub fn execute(
             Ok(Response::new())
         }
         ExecuteMsg::RemoveVerifierProxy {} => {
-            execute::remove_verifier_proxy(deps.storage, &info.sender);
+            execute::remove_verifier_proxy(deps.storage, &info.sender)?;
 
             Ok(Response::new())
         }
+        ExecuteMsg::OptOutOfRewards { pool_id } => {
+            let pool_id = PoolId::try_from_msg_pool_id(deps.api, pool_id)?;
+            execute::opt_out_of_rewards(deps.storage, pool_id.clone(), info.sender.clone())?;
+
+            Ok(Response::new().add_event(events::Event::RewardsOptedOut {
+                pool_id,
+                verifier: info.sender,
+            }))
+        }
+        ExecuteMsg::OptIntoRewards { pool_id } => {
+            let pool_id = PoolId::try_from_msg_pool_id(deps.api, pool_id)?;
+            execute::opt_into_rewards(deps.storage, pool_id.clone(), info.sender.clone())?;
+
+            Ok(Response::new().add_event(events::Event::RewardsOptedIn {
+                pool_id,
+                verifier: info.sender,
+            }))
+        }
+        ExecuteMsg::SetPoolDistributor {
+            pool_id,
+            distributor,
+        } => {
+            let pool_id = PoolId::try_from_msg_pool_id(deps.api, pool_id)?;
+            let distributor = distributor
+                .map(|distributor| address::validate_cosmwasm_address(deps.api, &distributor))
+                .transpose()?;
+            let previous_distributor =
+                execute::set_pool_distributor(deps.storage, pool_id.clone(), distributor.clone())?;
+
+            Ok(
+                Response::new().add_event(events::Event::PoolDistributorUpdated {
+                    pool_id,
+                    previous_distributor,
+                    distributor,
+                }),
+            )
+        }
+        ExecuteMsg::SetPoolPayoutHook {
+            pool_id,
+            payout_hook,
+        } => {
+            let pool_id = PoolId::try_from_msg_pool_id(deps.api, pool_id)?;
+            let payout_hook = payout_hook
+                .map(|payout_hook| address::validate_cosmwasm_address(deps.api, &payout_hook))
+                .transpose()?;
+            let previous_payout_hook =
+                execute::set_pool_payout_hook(deps.storage, pool_id.clone(), payout_hook.clone())?;
+
+            Ok(
+                Response::new().add_event(events::Event::PoolPayoutHookUpdated {
+                    pool_id,
+                    previous_payout_hook,
+                    payout_hook,
+                }),
+            )
+        }
+        ExecuteMsg::ProposeGovernanceTransfer {
+            new_governance,
+            expiry_blocks,
+        } => {
+            let transfer = execute::propose_governance_transfer(
+                deps.storage,
+                info.sender,
+                address::validate_cosmwasm_address(deps.api, &new_governance)?,
+                expiry_blocks,
+                env.block.height,
+            )?;
+
+            Ok(
+                Response::new().add_event(events::Event::GovernanceTransferProposed {
+                    current_governance: transfer.current_governance,
+                    new_governance: transfer.new_governance,
+                    expires_at: transfer.expires_at,
+                }),
+            )
+        }
+        ExecuteMsg::AcceptGovernanceTransfer {} => {
+            let transfer = execute::accept_governance_transfer(deps.storage, env.block.height)?;
+
+            Ok(
+                Response::new().add_event(events::Event::GovernanceTransferAccepted {
+                    previous_governance: transfer.current_governance,
+                    new_governance: transfer.new_governance,
+                }),
+            )
+        }
+    }
+}
+
+/// Pays out rewards in the pool's asset, either as a bank transfer of the native rewards denom or as a CW20 transfer
+fn payout_msg(
+    asset: &RewardsAsset,
+    rewards_denom: &str,
+    recipient: Addr,
+    amount: Uint128,
+) -> Result<CosmosMsg, ContractError> {
+    match asset {
+        RewardsAsset::Native => Ok(BankMsg::Send {
+            to_address: recipient.into(),
+            amount: vec![Coin {
+                denom: rewards_denom.to_string(),
+                amount,
+            }],
+        }
+        .into()),
+        RewardsAsset::Cw20(contract) => Ok(WasmMsg::Execute {
+            contract_addr: contract.to_string(),
+            msg: to_json_binary(&Cw20ExecuteMsg::Transfer {
+                recipient: recipient.into(),
+                amount,
+            })?,
+            funds: vec![],
+        }
+        .into()),
+    }
+}
+
+/// Pays out rewards to the payout hook of the pool, either as a bank transfer attached to the hook message
+/// or as a CW20 `Send` that carries the hook message
+fn hooked_payout_msg(
+    asset: &RewardsAsset,
+    rewards_denom: &str,
+    payout_hook: &Addr,
+    hook_msg: PayoutHookMsg,
+    amount: Uint128,
+) -> Result<CosmosMsg, ContractError> {
+    match asset {
+        RewardsAsset::Native => Ok(WasmMsg::Execute {
+            contract_addr: payout_hook.to_string(),
+            msg: to_json_binary(&hook_msg)?,
+            funds: vec![Coin {
+                denom: rewards_denom.to_string(),
+                amount,
+            }],
+        }
+        .into()),
+        RewardsAsset::Cw20(contract) => Ok(WasmMsg::Execute {
+            contract_addr: contract.to_string(),
+            msg: to_json_binary(&Cw20ExecuteMsg::Send {
+                contract: payout_hook.to_string(),
+                amount,
+                msg: to_json_binary(&hook_msg)?,
+            })?,
+            funds: vec![],
+        }
+        .into()),
+    }
+}
+
+fn burn_msg(
+    asset: &RewardsAsset,
+    rewards_denom: &str,
+    amount: Uint128,
+) -> Result<CosmosMsg, ContractError> {
+    match asset {
+        RewardsAsset::Native => Ok(BankMsg::Burn {
+            amount: vec![Coin {
+                denom: rewards_denom.to_string(),
+                amount,
+            }],
+        }
+        .into()),
+        RewardsAsset::Cw20(contract) => Ok(WasmMsg::Execute {
+            contract_addr: contract.to_string(),
+            msg: to_json_binary(&Cw20ExecuteMsg::Burn { amount })?,
+            funds: vec![],
+        }
+        .into()),
+    }
+}
+
+/// Pools are funded with `AddRewards` only, so funds attached to their creation would be lost
+fn ensure_not_funded(info: &MessageInfo) -> Result<(), ContractError> {
+    if info.funds.is_empty() {
+        Ok(())
+    } else {
+        Err(ContractError::PoolCreationNotPayable)
+    }
+}
+
+fn create_pool(
+    api: &dyn Api,
+    storage: &mut dyn Storage,
+    block_height: u64,
+    NewPool {
+        params,
+        pool_id,
+        rewards_asset,
+        align_epochs_with,
+    }: NewPool,
+) -> Result<(), axelar_wasm_std::error::ContractError> {
+    execute::create_pool(
+        storage,
+        params,
+        block_height,
+        PoolId::try_from_msg_pool_id(api, pool_id)?,
+        RewardsAsset::try_from_msg_rewards_asset(api, rewards_asset)?,
+        align_epochs_with
+            .map(|pool_id| PoolId::try_from_msg_pool_id(api, pool_id))
+            .transpose()?,
+    )?;
+
+    Ok(())
+}
+
+fn pending_governance(
+    storage: &dyn Storage,
+    _: &ExecuteMsg,
+) -> error_stack::Result<Addr, ContractError> {
+    state::may_load_governance_transfer(storage)?
+        .map(|transfer| transfer.new_governance)
+        .ok_or(report!(ContractError::NoPendingGovernanceTransfer))
+}
+
+/// Handles the distributions of `DistributeRewardsMulti`, the attestation submissions and the payouts to payout hooks
+/// that failed. Their state changes are reverted, and the failure is reported in an event instead of failing the whole
+/// transaction. The rewards of a failed payout stay owed to the verifier
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(
+    deps: DepsMut,
+    _env: Env,
+    reply: Reply,
+) -> Result<Response, axelar_wasm_std::error::ContractError> {
+    match axelar_wasm_std::reply::route(&reply)? {
+        ReplyId::SubmitAttestations => {
+            let reason = axelar_wasm_std::reply::parse_failure(reply)?;
+
+            Ok(Response::new().add_event(events::Event::AttestationSubmissionFailed { reason }))
+        }
+        ReplyId::MultiDistribution(index) => {
+            let pool_id = state::load_multi_distribution_pool(deps.storage, index)?;
+            let reason = axelar_wasm_std::reply::parse_failure(reply)?;
+
+            Ok(Response::new()
+                .add_event(events::Event::RewardsDistributionFailed { pool_id, reason }))
+        }
+        ReplyId::HookedPayout(index) => {
+            let payout = state::load_hooked_payout(deps.storage, index)?;
+            let reason = axelar_wasm_std::reply::parse_failure(reply)?;
+            let owed = state::add_owed_payout(deps.storage, payout.clone())?;
+
+            Ok(
+                Response::new().add_event(events::Event::HookedPayoutFailed {
+                    pool_id: payout.pool_id,
+                    verifier: payout.verifier,
+                    amount: payout.amount,
+                    owed,
+                    reason,
+                }),
+            )
+        }
+    }
+}
+
+/// Loads the verifiers that are active for the pool's chain, so verifiers without any participation are attested as well.
+/// Attestations must not block the distribution, so if the service registry can't be queried, only the verifiers that
+/// participated are attested
+fn active_verifiers(
+    service_registry: &service_registry_api::Client,
+    service_name: &str,
+    pool_id: &PoolId,
+) -> Vec<Addr> {
+    let mut verifiers: Vec<Addr> = vec![];
+    let mut start_after = None;
+
+    loop {
+        let Ok(page) = service_registry.active_verifiers_page(
+            service_name.to_string(),
+            pool_id.chain_name.clone(),
+            start_after,
+            None,
+        ) else {
+            return vec![];
+        };
+
+        verifiers.extend(
+            page.verifiers
+                .into_iter()
+                .map(|verifier| verifier.verifier_info.address),
+        );
+
+        match page.next_start_after {
+            Some(next_start_after) => start_after = Some(next_start_after),
+            None => return verifiers,
+        }
+    }
+}
+
+/// Recovers from corrupted state. Only the chain can call this entry point, so it is not subject to permission control
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn sudo(
+    deps: DepsMut,
+    _env: Env,
+    msg: SudoMsg,
+) -> Result<Response, axelar_wasm_std::error::ContractError> {
+    match msg {
+        SudoMsg::RestoreConfig {
+            rewards_denom,
+            epoch_retention,
+            track_event_attribution,
+            reserve_epoch_rewards,
+            attestation_target,
+        } => {
+            let attestation_target = attestation_target
+                .map(|target| AttestationTarget::try_from_msg_attestation_target(deps.api, target))
+                .transpose()?;
+
+            state::save_config(
+                deps.storage,
+                &Config {
+                    rewards_denom,
+                    epoch_retention,
+                    track_event_attribution,
+                    reserve_epoch_rewards,
+                    attestation_target,
+                },
+            )?;
+        }
+        SudoMsg::RestorePoolParams {
+            pool_id,
+            params,
+            created_at,
+        } => {
+            state::restore_pool_params(
+                deps.storage,
+                &PoolId::try_from_msg_pool_id(deps.api, pool_id)?,
+                &ParamsSnapshot {
+                    params,
+                    created_at: Epoch {
+                        epoch_num: created_at.epoch_num,
+                        block_height_started: created_at.block_height_started,
+                    },
+                },
+            )?;
+        }
     }
+
+    Ok(Response::new())
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -191,18 +739,116 @@ pub fn query(
                 .change_context(ContractError::SerializeResponse)
                 .map_err(axelar_wasm_std::error::ContractError::from)
         }
+        QueryMsg::ProxyVerifiers { proxy_address } => {
+            let verifiers = state::load_proxy_verifiers(
+                deps.storage,
+                &deps.api.addr_validate(&proxy_address)?,
+            )?;
+            to_json_binary(&verifiers)
+                .change_context(ContractError::SerializeResponse)
+                .map_err(axelar_wasm_std::error::ContractError::from)
+        }
+        QueryMsg::RewardsOptOut { pool_id, verifier } => {
+            let opted_out = state::is_opted_out_of_rewards(
+                deps.storage,
+                PoolId::try_from_msg_pool_id(deps.api, pool_id)?,
+                address::validate_cosmwasm_address(deps.api, &verifier)?,
+            );
+            to_json_binary(&opted_out)
+                .change_context(ContractError::SerializeResponse)
+                .map_err(axelar_wasm_std::error::ContractError::from)
+        }
+        QueryMsg::PendingGovernanceTransfer => {
+            let transfer = query::pending_governance_transfer(deps.storage, env.block.height)?;
+            to_json_binary(&transfer)
+                .change_context(ContractError::SerializeResponse)
+                .map_err(axelar_wasm_std::error::ContractError::from)
+        }
+        QueryMsg::DistributionCooldown { pool_id } => {
+            let cooldown = query::distribution_cooldown(
+                deps.storage,
+                PoolId::try_from_msg_pool_id(deps.api, pool_id)?,
+                env.block.height,
+            )?;
+            to_json_binary(&cooldown)
+                .change_context(ContractError::SerializeResponse)
+                .map_err(axelar_wasm_std::error::ContractError::from)
+        }
+        QueryMsg::PoolDistributor { pool_id } => {
+            let distributor = state::may_load_pool_distributor(
+                deps.storage,
+                PoolId::try_from_msg_pool_id(deps.api, pool_id)?,
+            )?;
+            to_json_binary(&distributor)
+                .change_context(ContractError::SerializeResponse)
+                .map_err(axelar_wasm_std::error::ContractError::from)
+        }
+        QueryMsg::PoolPayoutHook { pool_id } => {
+            let payout_hook = state::may_load_pool_payout_hook(
+                deps.storage,
+                PoolId::try_from_msg_pool_id(deps.api, pool_id)?,
+            )?;
+            to_json_binary(&payout_hook)
+                .change_context(ContractError::SerializeResponse)
+                .map_err(axelar_wasm_std::error::ContractError::from)
+        }
+        QueryMsg::EpochSchedule { pool_id } => {
+            let schedule = query::epoch_schedule(
+                deps.storage,
+                PoolId::try_from_msg_pool_id(deps.api, pool_id)?,
+                env.block.height,
+            )?;
+            to_json_binary(&schedule)
+                .change_context(ContractError::SerializeResponse)
+                .map_err(axelar_wasm_std::error::ContractError::from)
+        }
+        QueryMsg::PoolParamsUpdateEffects { pool_id, params } => {
+            let effects = query::pool_params_update_effects(
+                deps.storage,
+                PoolId::try_from_msg_pool_id(deps.api, pool_id)?,
+                params,
+                env.block.height,
+            )?;
+            to_json_binary(&effects)
+                .change_context(ContractError::SerializeResponse)
+                .map_err(axelar_wasm_std::error::ContractError::from)
+        }
+        QueryMsg::ParticipationProof {
+            pool_id,
+            verifier,
+            epoch_num,
+            start_after,
+            limit,
+        } => {
+            let proof = query::participation_proof(
+                deps.storage,
+                PoolId::try_from_msg_pool_id(deps.api, pool_id)?,
+                address::validate_cosmwasm_address(deps.api, &verifier)?,
+                epoch_num,
+                start_after,
+                limit,
+            )?;
+            to_json_binary(&proof)
+                .change_context(ContractError::SerializeResponse)
+                .map_err(axelar_wasm_std::error::ContractError::from)
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use cosmwasm_std::testing::MockApi;
-    use cosmwasm_std::{coins, Addr, BlockInfo, Uint128};
-    use cw_multi_test::{App, ContractWrapper, Executor};
+    use assert_ok::assert_ok;
+    use cosmwasm_std::testing::{message_info, mock_dependencies, mock_env, MockApi};
+    use cosmwasm_std::{coins, Addr, BlockInfo, Empty, Uint128};
+    use cw_multi_test::{App, AppResponse, ContractWrapper, Executor};
     use router_api::ChainName;
+    use service_registry_api::ParticipationSummary;
 
     use super::*;
-    use crate::msg::{ExecuteMsg, InstantiateMsg, Params, PoolId, QueryMsg, RewardsPool};
+    use crate::msg::{
+        AttestationTarget, ExecuteMsg, GovernanceTransfer, InstantiateMsg, NewPool, Params, PoolId,
+        QueryMsg, RewardsAsset, RewardsPool,
+    };
 
     /// Tests that the contract entry points (instantiate, query and execute) work as expected.
     /// Instantiates the contract and calls each of the 4 ExecuteMsg variants.
@@ -230,6 +876,9 @@ mod tests {
             epoch_duration: 10u64.try_into().unwrap(),
             rewards_per_epoch: Uint128::from(100u128).try_into().unwrap(),
             participation_threshold: (1, 2).try_into().unwrap(),
+            distribution_cooldown: None,
+            remainder_policy: None,
+            epoch_time: None,
         };
         let contract_address = app
             .instantiate_contract(
@@ -238,6 +887,10 @@ mod tests {
                 &InstantiateMsg {
                     governance_address: governance_address.to_string(),
                     rewards_denom: AXL_DENOMINATION.to_string(),
+                    epoch_retention: 10,
+                    track_event_attribution: false,
+                    reserve_epoch_rewards: false,
+                    attestation_target: None,
                 },
                 &[],
                 "Contract",
@@ -256,6 +909,8 @@ mod tests {
             &ExecuteMsg::CreatePool {
                 params: initial_params.clone(),
                 pool_id: pool_id.clone(),
+                rewards_asset: RewardsAsset::Native,
+                align_epochs_with: None,
             },
             &[],
         );
@@ -282,6 +937,7 @@ mod tests {
             &ExecuteMsg::UpdatePoolParams {
                 params: updated_params.clone(),
                 pool_id: pool_id.clone(),
+                align_epochs_with: None,
             },
             &[],
         );
@@ -320,11 +976,16 @@ mod tests {
             res,
             RewardsPool {
                 balance: rewards.into(),
+                reserved_balance: Uint128::zero(),
+                free_balance: rewards.into(),
                 epoch_duration: updated_params.epoch_duration.into(),
                 rewards_per_epoch: updated_params.rewards_per_epoch.into(),
                 participation_threshold: updated_params.participation_threshold,
+                remainder_policy: None,
+                carried_remainder: Uint128::zero(),
                 current_epoch_num: 0u64.into(),
-                last_distribution_epoch: None
+                last_distribution_epoch: None,
+                rewards_asset: RewardsAsset::Native,
             }
         );
 
@@ -381,6 +1042,9 @@ mod tests {
             epoch_duration: 10u64.try_into().unwrap(),
             rewards_per_epoch: Uint128::from(100u128).try_into().unwrap(),
             participation_threshold: (1, 2).try_into().unwrap(),
+            distribution_cooldown: None,
+            remainder_policy: None,
+            epoch_time: None,
         };
         let contract_address = app
             .instantiate_contract(
@@ -389,6 +1053,10 @@ mod tests {
                 &InstantiateMsg {
                     governance_address: governance_address.to_string(),
                     rewards_denom: AXL_DENOMINATION.to_string(),
+                    epoch_retention: 10,
+                    track_event_attribution: false,
+                    reserve_epoch_rewards: false,
+                    attestation_target: None,
                 },
                 &[],
                 "Contract",
@@ -407,6 +1075,8 @@ mod tests {
             &ExecuteMsg::CreatePool {
                 params: params.clone(),
                 pool_id: pool_id.clone(),
+                rewards_asset: RewardsAsset::Native,
+                align_epochs_with: None,
             },
             &[],
         )
@@ -436,6 +1106,18 @@ mod tests {
             .unwrap();
         assert_eq!(res, Some(proxy.clone()));
 
+        // query the verifiers of the proxy
+        let res: Vec<Addr> = app
+            .wrap()
+            .query_wasm_smart(
+                contract_address.clone(),
+                &QueryMsg::ProxyVerifiers {
+                    proxy_address: proxy.to_string().parse().unwrap(),
+                },
+            )
+            .unwrap();
+        assert_eq!(res, vec![verifier.clone()]);
+
         let rewards = 200;
         app.execute_contract(
             user.clone(),
@@ -570,6 +1252,9 @@ mod tests {
             epoch_duration: 10u64.try_into().unwrap(),
             rewards_per_epoch: Uint128::from(100u128).try_into().unwrap(),
             participation_threshold: (1, 2).try_into().unwrap(),
+            distribution_cooldown: None,
+            remainder_policy: None,
+            epoch_time: None,
         };
         let contract_address = app
             .instantiate_contract(
@@ -578,6 +1263,10 @@ mod tests {
                 &InstantiateMsg {
                     governance_address: governance_address.to_string(),
                     rewards_denom: AXL_DENOMINATION.to_string(),
+                    epoch_retention: 10,
+                    track_event_attribution: false,
+                    reserve_epoch_rewards: false,
+                    attestation_target: None,
                 },
                 &[],
                 "Contract",
@@ -596,6 +1285,8 @@ mod tests {
             &ExecuteMsg::CreatePool {
                 params: initial_params.clone(),
                 pool_id: pool_id.clone(),
+                rewards_asset: RewardsAsset::Native,
+                align_epochs_with: None,
             },
             &[],
         );
@@ -629,6 +1320,7 @@ mod tests {
             &ExecuteMsg::UpdatePoolParams {
                 params: updated_params.clone(),
                 pool_id: pool_id.clone(),
+                align_epochs_with: None,
             },
             &[],
         );
@@ -648,11 +1340,16 @@ mod tests {
             res,
             RewardsPool {
                 balance: Uint128::zero(),
+                reserved_balance: Uint128::zero(),
+                free_balance: Uint128::zero(),
                 epoch_duration: updated_params.epoch_duration.into(),
                 rewards_per_epoch: updated_params.rewards_per_epoch.into(),
                 participation_threshold: updated_params.participation_threshold,
+                remainder_policy: None,
+                carried_remainder: Uint128::zero(),
                 current_epoch_num: 0u64.into(),
-                last_distribution_epoch: None
+                last_distribution_epoch: None,
+                rewards_asset: RewardsAsset::Native,
             }
         );
 
@@ -724,6 +1421,9 @@ mod tests {
             epoch_duration: 10u64.try_into().unwrap(),
             rewards_per_epoch: Uint128::from(100u128).try_into().unwrap(),
             participation_threshold: (1, 2).try_into().unwrap(),
+            distribution_cooldown: None,
+            remainder_policy: None,
+            epoch_time: None,
         };
         let contract_address = app
             .instantiate_contract(
@@ -732,6 +1432,10 @@ mod tests {
                 &InstantiateMsg {
                     governance_address: governance_address.to_string(),
                     rewards_denom: AXL_DENOMINATION.to_string(),
+                    epoch_retention: 10,
+                    track_event_attribution: false,
+                    reserve_epoch_rewards: false,
+                    attestation_target: None,
                 },
                 &[],
                 "Contract",
@@ -750,6 +1454,8 @@ mod tests {
             &ExecuteMsg::CreatePool {
                 params: initial_params.clone(),
                 pool_id: pool_id.clone(),
+                rewards_asset: RewardsAsset::Native,
+                align_epochs_with: None,
             },
             &[],
         );
@@ -772,6 +1478,7 @@ mod tests {
             &ExecuteMsg::UpdatePoolParams {
                 params: updated_params.clone(),
                 pool_id: pool_id.clone(),
+                align_epochs_with: None,
             },
             &[],
         );
@@ -791,11 +1498,16 @@ mod tests {
             res,
             RewardsPool {
                 balance: Uint128::zero(),
+                reserved_balance: Uint128::zero(),
+                free_balance: Uint128::zero(),
                 epoch_duration: updated_params.epoch_duration.into(),
                 rewards_per_epoch: updated_params.rewards_per_epoch.into(),
                 participation_threshold: updated_params.participation_threshold,
+                remainder_policy: None,
+                carried_remainder: Uint128::zero(),
                 current_epoch_num: 0u64.into(),
-                last_distribution_epoch: None
+                last_distribution_epoch: None,
+                rewards_asset: RewardsAsset::Native,
             }
         );
 
@@ -885,6 +1597,9 @@ mod tests {
             epoch_duration: 10u64.try_into().unwrap(),
             rewards_per_epoch: Uint128::from(100u128).try_into().unwrap(),
             participation_threshold: (1, 2).try_into().unwrap(),
+            distribution_cooldown: None,
+            remainder_policy: None,
+            epoch_time: None,
         };
         let contract_address = app
             .instantiate_contract(
@@ -893,6 +1608,10 @@ mod tests {
                 &InstantiateMsg {
                     governance_address: governance_address.to_string(),
                     rewards_denom: AXL_DENOMINATION.to_string(),
+                    epoch_retention: 10,
+                    track_event_attribution: false,
+                    reserve_epoch_rewards: false,
+                    attestation_target: None,
                 },
                 &[],
                 "Contract",
@@ -911,6 +1630,8 @@ mod tests {
             &ExecuteMsg::CreatePool {
                 params: initial_params.clone(),
                 pool_id: pool_id.clone(),
+                rewards_asset: RewardsAsset::Native,
+                align_epochs_with: None,
             },
             &[],
         );
@@ -955,6 +1676,7 @@ mod tests {
             &ExecuteMsg::UpdatePoolParams {
                 params: updated_params.clone(),
                 pool_id: pool_id.clone(),
+                align_epochs_with: None,
             },
             &[],
         );
@@ -974,11 +1696,16 @@ mod tests {
             res,
             RewardsPool {
                 balance: Uint128::zero(),
+                reserved_balance: Uint128::zero(),
+                free_balance: Uint128::zero(),
                 epoch_duration: updated_params.epoch_duration.into(),
                 rewards_per_epoch: updated_params.rewards_per_epoch.into(),
                 participation_threshold: updated_params.participation_threshold,
+                remainder_policy: None,
+                carried_remainder: Uint128::zero(),
                 current_epoch_num: 1u64.into(),
-                last_distribution_epoch: None
+                last_distribution_epoch: None,
+                rewards_asset: RewardsAsset::Native,
             }
         );
 
@@ -1029,4 +1756,1514 @@ mod tests {
             Uint128::from(updated_params.rewards_per_epoch)
         );
     }
+
+    fn setup_governance_transfer_test() -> (App, Addr, Addr) {
+        let mut app = App::default();
+        let code = ContractWrapper::new(execute, instantiate, query);
+        let code_id = app.store_code(Box::new(code));
+
+        let governance_address = MockApi::default().addr_make("governance");
+        let contract_address = app
+            .instantiate_contract(
+                code_id,
+                MockApi::default().addr_make("router"),
+                &InstantiateMsg {
+                    governance_address: governance_address.to_string(),
+                    rewards_denom: "uaxl".to_string(),
+                    epoch_retention: 10,
+                    track_event_attribution: false,
+                    reserve_epoch_rewards: false,
+                    attestation_target: None,
+                },
+                &[],
+                "Contract",
+                None,
+            )
+            .unwrap();
+
+        (app, contract_address, governance_address)
+    }
+
+    fn create_pool_msg() -> ExecuteMsg {
+        ExecuteMsg::CreatePool {
+            params: Params {
+                epoch_duration: 10u64.try_into().unwrap(),
+                rewards_per_epoch: Uint128::from(100u128).try_into().unwrap(),
+                participation_threshold: (1, 2).try_into().unwrap(),
+                distribution_cooldown: None,
+                remainder_policy: None,
+                epoch_time: None,
+            },
+            pool_id: PoolId {
+                chain_name: "mock-chain".parse().unwrap(),
+                contract: MockApi::default().addr_make("pool_contract").to_string(),
+            },
+            rewards_asset: RewardsAsset::Native,
+            align_epochs_with: None,
+        }
+    }
+
+    const CW20_BALANCES: cw_storage_plus::Map<&Addr, Uint128> =
+        cw_storage_plus::Map::new("balances");
+
+    /// Minimal CW20 token that only supports transfers, enough to observe rewards paid out by the contract
+    fn cw20_execute(
+        deps: DepsMut,
+        _env: Env,
+        _info: MessageInfo,
+        msg: Cw20ExecuteMsg,
+    ) -> Result<Response, cosmwasm_std::StdError> {
+        match msg {
+            Cw20ExecuteMsg::Transfer { recipient, amount } => {
+                CW20_BALANCES.update(deps.storage, &Addr::unchecked(recipient), |balance| {
+                    balance
+                        .unwrap_or_default()
+                        .checked_add(amount)
+                        .map_err(cosmwasm_std::StdError::from)
+                })?;
+                Ok(Response::new())
+            }
+            _ => unimplemented!(),
+        }
+    }
+
+    fn cw20_query(deps: Deps, _env: Env, address: Addr) -> Result<Binary, cosmwasm_std::StdError> {
+        to_json_binary(
+            &CW20_BALANCES
+                .may_load(deps.storage, &address)?
+                .unwrap_or_default(),
+        )
+    }
+
+    #[test]
+    fn cw20_rewards_flow() {
+        let chain_name: ChainName = "mock-chain".parse().unwrap();
+        let user = MockApi::default().addr_make("user");
+        let verifier = MockApi::default().addr_make("verifier");
+        let pool_contract = MockApi::default().addr_make("pool_contract");
+
+        let (mut app, contract_address, governance_address) = setup_governance_transfer_test();
+        app.init_modules(|router, _, storage| {
+            router
+                .bank
+                .init_balance(storage, &user, coins(1000, "uaxl"))
+                .unwrap()
+        });
+
+        let cw20_code = ContractWrapper::new(cw20_execute, instantiate, cw20_query);
+        let cw20_code_id = app.store_code(Box::new(cw20_code));
+        let token = app
+            .instantiate_contract(
+                cw20_code_id,
+                user.clone(),
+                &InstantiateMsg {
+                    governance_address: governance_address.to_string(),
+                    rewards_denom: "unused".to_string(),
+                    epoch_retention: 10,
+                    track_event_attribution: false,
+                    reserve_epoch_rewards: false,
+                    attestation_target: None,
+                },
+                &[],
+                "Token",
+                None,
+            )
+            .unwrap();
+        let other_token = MockApi::default().addr_make("other_token");
+
+        let params = Params {
+            epoch_duration: 10u64.try_into().unwrap(),
+            rewards_per_epoch: Uint128::from(100u128).try_into().unwrap(),
+            participation_threshold: (1, 2).try_into().unwrap(),
+            distribution_cooldown: None,
+            remainder_policy: None,
+            epoch_time: None,
+        };
+        let pool_id = PoolId {
+            chain_name: chain_name.clone(),
+            contract: pool_contract.to_string(),
+        };
+
+        assert!(app
+            .execute_contract(
+                governance_address,
+                contract_address.clone(),
+                &ExecuteMsg::CreatePool {
+                    params: params.clone(),
+                    pool_id: pool_id.clone(),
+                    rewards_asset: RewardsAsset::Cw20 {
+                        contract: token.to_string(),
+                    },
+                    align_epochs_with: None,
+                },
+                &[],
+            )
+            .is_ok());
+
+        let add_rewards = |amount: u128| {
+            ExecuteMsg::Receive(Cw20ReceiveMsg {
+                sender: user.to_string(),
+                amount: amount.into(),
+                msg: to_json_binary(&ReceiveMsg::AddRewards {
+                    pool_id: pool_id.clone(),
+                })
+                .unwrap(),
+            })
+        };
+
+        // native funds and other tokens must not be mixed into the pool
+        let err = app
+            .execute_contract(
+                user.clone(),
+                contract_address.clone(),
+                &ExecuteMsg::AddRewards {
+                    pool_id: pool_id.clone(),
+                },
+                &coins(100, "uaxl"),
+            )
+            .unwrap_err();
+        assert!(err
+            .root_cause()
+            .to_string()
+            .contains(&ContractError::WrongRewardsAsset.to_string()));
+
+        let err = app
+            .execute_contract(
+                other_token,
+                contract_address.clone(),
+                &add_rewards(100),
+                &[],
+            )
+            .unwrap_err();
+        assert!(err
+            .root_cause()
+            .to_string()
+            .contains(&ContractError::WrongRewardsAsset.to_string()));
+
+        assert!(app
+            .execute_contract(
+                token.clone(),
+                contract_address.clone(),
+                &add_rewards(200),
+                &[],
+            )
+            .is_ok());
+
+        assert!(app
+            .execute_contract(
+                pool_contract,
+                contract_address.clone(),
+                &ExecuteMsg::RecordParticipation {
+                    chain_name,
+                    event_id: "some event".try_into().unwrap(),
+                    verifier_address: verifier.to_string(),
+                },
+                &[],
+            )
+            .is_ok());
+
+        let height = app.block_info().height;
+        app.set_block(BlockInfo {
+            height: height + u64::from(params.epoch_duration) * 2,
+            ..app.block_info()
+        });
+
+        assert!(app
+            .execute_contract(
+                user,
+                contract_address.clone(),
+                &ExecuteMsg::DistributeRewards {
+                    pool_id: pool_id.clone(),
+                    epoch_count: None,
+                },
+                &[],
+            )
+            .is_ok());
+
+        let balance: Uint128 = app
+            .wrap()
+            .query_wasm_smart(token.clone(), &verifier)
+            .unwrap();
+        assert_eq!(balance, Uint128::from(params.rewards_per_epoch));
+
+        let pool: RewardsPool = app
+            .wrap()
+            .query_wasm_smart(contract_address, &QueryMsg::RewardsPool { pool_id })
+            .unwrap();
+        assert_eq!(pool.balance, Uint128::from(100u128));
+        assert_eq!(
+            pool.rewards_asset,
+            RewardsAsset::Cw20 {
+                contract: token.to_string()
+            }
+        );
+    }
+
+    const SUBMITTED_SUMMARIES: cw_storage_plus::Item<Vec<ParticipationSummary>> =
+        cw_storage_plus::Item::new("submitted_summaries");
+    const MOCK_SERVICE_REGISTRY_CONFIG: cw_storage_plus::Item<MockServiceRegistryConfig> =
+        cw_storage_plus::Item::new("mock_service_registry_config");
+
+    #[cosmwasm_schema::cw_serde]
+    struct MockServiceRegistryConfig {
+        active_verifiers: Vec<Addr>,
+        reject_attestations: bool,
+    }
+
+    /// Minimal service registry that returns the configured active verifiers and records submitted attestations
+    fn service_registry_execute(
+        deps: DepsMut,
+        _env: Env,
+        _info: MessageInfo,
+        msg: service_registry_api::msg::ExecuteMsg,
+    ) -> Result<Response, cosmwasm_std::StdError> {
+        match msg {
+            service_registry_api::msg::ExecuteMsg::SubmitAttestations { summaries, .. } => {
+                if MOCK_SERVICE_REGISTRY_CONFIG
+                    .load(deps.storage)?
+                    .reject_attestations
+                {
+                    return Err(cosmwasm_std::StdError::generic_err("attestations rejected"));
+                }
+
+                SUBMITTED_SUMMARIES.save(deps.storage, &summaries)?;
+                Ok(Response::new())
+            }
+            _ => unimplemented!(),
+        }
+    }
+
+    fn service_registry_instantiate(
+        deps: DepsMut,
+        _env: Env,
+        _info: MessageInfo,
+        msg: MockServiceRegistryConfig,
+    ) -> Result<Response, cosmwasm_std::StdError> {
+        MOCK_SERVICE_REGISTRY_CONFIG.save(deps.storage, &msg)?;
+        Ok(Response::new())
+    }
+
+    fn service_registry_query(
+        deps: Deps,
+        _env: Env,
+        msg: service_registry_api::msg::QueryMsg,
+    ) -> Result<Binary, cosmwasm_std::StdError> {
+        match msg {
+            service_registry_api::msg::QueryMsg::ActiveVerifiersPage {
+                service_name,
+                start_after,
+                limit,
+                ..
+            } => {
+                let verifiers: Vec<_> = MOCK_SERVICE_REGISTRY_CONFIG
+                    .load(deps.storage)?
+                    .active_verifiers
+                    .into_iter()
+                    .filter(|verifier| {
+                        start_after
+                            .as_ref()
+                            .map_or(true, |start_after| verifier.as_str() > start_after.as_str())
+                    })
+                    .take(limit.unwrap_or(100) as usize)
+                    .map(|address| service_registry_api::WeightedVerifier {
+                        verifier_info: service_registry_api::Verifier {
+                            address,
+                            bonding_state: service_registry_api::BondingState::Bonded {
+                                amount: Uint128::one().try_into().unwrap(),
+                            },
+                            authorization_state:
+                                service_registry_api::AuthorizationState::Authorized,
+                            service_name: service_name.clone(),
+                        },
+                        weight: Uint128::one().try_into().unwrap(),
+                    })
+                    .collect();
+                to_json_binary(&service_registry_api::msg::ActiveVerifiersPageResponse {
+                    next_start_after: verifiers
+                        .last()
+                        .filter(|_| verifiers.len() == limit.unwrap_or(100) as usize)
+                        .map(|verifier| verifier.verifier_info.address.to_string()),
+                    verifiers,
+                })
+            }
+            _ => unimplemented!(),
+        }
+    }
+
+    fn instantiate_with_attestation_target(
+        app: &mut App,
+        governance_address: &Addr,
+        service_registry_config: MockServiceRegistryConfig,
+    ) -> (Addr, Addr) {
+        let service_registry_code = ContractWrapper::new(
+            service_registry_execute,
+            service_registry_instantiate,
+            service_registry_query,
+        );
+        let service_registry_code_id = app.store_code(Box::new(service_registry_code));
+        let service_registry = app
+            .instantiate_contract(
+                service_registry_code_id,
+                governance_address.clone(),
+                &service_registry_config,
+                &[],
+                "ServiceRegistry",
+                None,
+            )
+            .unwrap();
+
+        let code = ContractWrapper::new(execute, instantiate, query).with_reply(reply);
+        let code_id = app.store_code(Box::new(code));
+        let contract_address = app
+            .instantiate_contract(
+                code_id,
+                MockApi::default().addr_make("router"),
+                &InstantiateMsg {
+                    governance_address: governance_address.to_string(),
+                    rewards_denom: "uaxl".to_string(),
+                    epoch_retention: 10,
+                    track_event_attribution: false,
+                    reserve_epoch_rewards: false,
+                    attestation_target: Some(AttestationTarget {
+                        service_registry: service_registry.to_string(),
+                        service_name: "validators".to_string(),
+                    }),
+                },
+                &[],
+                "Contract",
+                None,
+            )
+            .unwrap();
+
+        (service_registry, contract_address)
+    }
+
+    fn record_participation_and_distribute(
+        app: &mut App,
+        contract_address: &Addr,
+        pool_id: &PoolId,
+        participation: &[(&str, &Addr)],
+    ) -> AppResponse {
+        let user = MockApi::default().addr_make("user");
+        let pool_contract = Addr::unchecked(pool_id.contract.clone());
+
+        app.execute_contract(
+            user.clone(),
+            contract_address.clone(),
+            &ExecuteMsg::AddRewards {
+                pool_id: pool_id.clone(),
+            },
+            &coins(1000, "uaxl"),
+        )
+        .unwrap();
+
+        for (event_id, verifier) in participation {
+            app.execute_contract(
+                pool_contract.clone(),
+                contract_address.clone(),
+                &ExecuteMsg::RecordParticipation {
+                    chain_name: pool_id.chain_name.clone(),
+                    event_id: (*event_id).try_into().unwrap(),
+                    verifier_address: verifier.to_string(),
+                },
+                &[],
+            )
+            .unwrap();
+        }
+
+        app.set_block(BlockInfo {
+            height: app.block_info().height + 20,
+            ..app.block_info()
+        });
+
+        app.execute_contract(
+            user,
+            contract_address.clone(),
+            &ExecuteMsg::DistributeRewards {
+                pool_id: pool_id.clone(),
+                epoch_count: None,
+            },
+            &[],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn distribute_rewards_submits_participation_to_service_registry() {
+        let chain_name: ChainName = "mock-chain".parse().unwrap();
+        let user = MockApi::default().addr_make("user");
+        let verifier = MockApi::default().addr_make("verifier");
+        let other_verifier = MockApi::default().addr_make("other_verifier");
+        let idle_verifier = MockApi::default().addr_make("idle_verifier");
+        let governance_address = MockApi::default().addr_make("governance");
+
+        let mut app = App::new(|router, _, storage| {
+            router
+                .bank
+                .init_balance(storage, &user, coins(1000, "uaxl"))
+                .unwrap()
+        });
+        let (service_registry, contract_address) = instantiate_with_attestation_target(
+            &mut app,
+            &governance_address,
+            MockServiceRegistryConfig {
+                active_verifiers: vec![
+                    verifier.clone(),
+                    other_verifier.clone(),
+                    idle_verifier.clone(),
+                ],
+                reject_attestations: false,
+            },
+        );
+        app.execute_contract(
+            governance_address,
+            contract_address.clone(),
+            &create_pool_msg(),
+            &[],
+        )
+        .unwrap();
+
+        let pool_id = PoolId {
+            chain_name: chain_name.clone(),
+            contract: MockApi::default().addr_make("pool_contract").to_string(),
+        };
+        record_participation_and_distribute(
+            &mut app,
+            &contract_address,
+            &pool_id,
+            &[
+                ("event", &verifier),
+                ("event", &other_verifier),
+                ("other event", &verifier),
+            ],
+        );
+
+        let submitted = SUBMITTED_SUMMARIES
+            .query(&app.wrap(), service_registry)
+            .unwrap();
+        let mut expected = vec![
+            ParticipationSummary {
+                verifier: verifier.to_string(),
+                chain_name: chain_name.clone(),
+                events_participated: 2,
+                events_total: 2,
+            },
+            ParticipationSummary {
+                verifier: other_verifier.to_string(),
+                chain_name: chain_name.clone(),
+                events_participated: 1,
+                events_total: 2,
+            },
+            ParticipationSummary {
+                verifier: idle_verifier.to_string(),
+                chain_name,
+                events_participated: 0,
+                events_total: 2,
+            },
+        ];
+        expected.sort_by(|a, b| a.verifier.cmp(&b.verifier));
+        assert_eq!(submitted, expected);
+    }
+
+    #[test]
+    fn distribute_rewards_succeeds_when_attestation_submission_fails() {
+        let chain_name: ChainName = "mock-chain".parse().unwrap();
+        let user = MockApi::default().addr_make("user");
+        let verifier = MockApi::default().addr_make("verifier");
+        let governance_address = MockApi::default().addr_make("governance");
+
+        let mut app = App::new(|router, _, storage| {
+            router
+                .bank
+                .init_balance(storage, &user, coins(1000, "uaxl"))
+                .unwrap()
+        });
+        let (service_registry, contract_address) = instantiate_with_attestation_target(
+            &mut app,
+            &governance_address,
+            MockServiceRegistryConfig {
+                active_verifiers: vec![verifier.clone()],
+                reject_attestations: true,
+            },
+        );
+        app.execute_contract(
+            governance_address,
+            contract_address.clone(),
+            &create_pool_msg(),
+            &[],
+        )
+        .unwrap();
+
+        let pool_id = PoolId {
+            chain_name,
+            contract: MockApi::default().addr_make("pool_contract").to_string(),
+        };
+        let res = record_participation_and_distribute(
+            &mut app,
+            &contract_address,
+            &pool_id,
+            &[("event", &verifier)],
+        );
+
+        assert!(res
+            .events
+            .iter()
+            .any(|event| event.ty == "wasm-attestation_submission_failed"));
+        assert!(app.wrap().query_balance(&verifier, "uaxl").unwrap().amount > Uint128::zero());
+        assert!(SUBMITTED_SUMMARIES
+            .query(&app.wrap(), service_registry)
+            .is_err());
+    }
+
+    #[test]
+    fn distribute_rewards_multi_isolates_failed_pools() {
+        let chain_name: ChainName = "mock-chain".parse().unwrap();
+        let user = MockApi::default().addr_make("user");
+        let verifier = MockApi::default().addr_make("verifier");
+        let pool_contract = MockApi::default().addr_make("pool_contract");
+        let unknown_pool_contract = MockApi::default().addr_make("unknown_pool_contract");
+        let governance_address = MockApi::default().addr_make("governance");
+
+        let mut app = App::new(|router, _, storage| {
+            router
+                .bank
+                .init_balance(storage, &user, coins(1000, "uaxl"))
+                .unwrap()
+        });
+        let code = ContractWrapper::new(execute, instantiate, query).with_reply(reply);
+        let code_id = app.store_code(Box::new(code));
+        let contract_address = app
+            .instantiate_contract(
+                code_id,
+                MockApi::default().addr_make("router"),
+                &InstantiateMsg {
+                    governance_address: governance_address.to_string(),
+                    rewards_denom: "uaxl".to_string(),
+                    epoch_retention: 10,
+                    track_event_attribution: false,
+                    reserve_epoch_rewards: false,
+                    attestation_target: None,
+                },
+                &[],
+                "Contract",
+                None,
+            )
+            .unwrap();
+
+        let pool_id = PoolId {
+            chain_name: chain_name.clone(),
+            contract: pool_contract.to_string(),
+        };
+        let unknown_pool_id = PoolId {
+            chain_name: chain_name.clone(),
+            contract: unknown_pool_contract.to_string(),
+        };
+
+        app.execute_contract(
+            governance_address,
+            contract_address.clone(),
+            &create_pool_msg(),
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            user.clone(),
+            contract_address.clone(),
+            &ExecuteMsg::AddRewards {
+                pool_id: pool_id.clone(),
+            },
+            &coins(1000, "uaxl"),
+        )
+        .unwrap();
+        app.execute_contract(
+            pool_contract,
+            contract_address.clone(),
+            &ExecuteMsg::RecordParticipation {
+                chain_name,
+                event_id: "event".try_into().unwrap(),
+                verifier_address: verifier.to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+
+        app.set_block(BlockInfo {
+            height: app.block_info().height + 20,
+            ..app.block_info()
+        });
+
+        let res = app
+            .execute_contract(
+                user,
+                contract_address,
+                &ExecuteMsg::DistributeRewardsMulti {
+                    pool_ids: vec![unknown_pool_id, pool_id],
+                    epoch_count: None,
+                },
+                &[],
+            )
+            .unwrap();
+
+        let failed: Vec<_> = res
+            .events
+            .iter()
+            .filter(|event| event.ty == "wasm-rewards_distribution_failed")
+            .collect();
+        assert_eq!(failed.len(), 1);
+        assert!(failed[0]
+            .attributes
+            .iter()
+            .any(|attribute| attribute.key == "contract"
+                && attribute.value == unknown_pool_contract.as_str()));
+
+        let balance = app.wrap().query_balance(verifier, "uaxl").unwrap();
+        assert_eq!(balance.amount, Uint128::from(100u128));
+    }
+
+    #[test]
+    fn pool_distributor_gates_distribution() {
+        let chain_name: ChainName = "mock-chain".parse().unwrap();
+        let user = MockApi::default().addr_make("user");
+        let distributor = MockApi::default().addr_make("distributor");
+        let verifier = MockApi::default().addr_make("verifier");
+        let pool_contract = MockApi::default().addr_make("pool_contract");
+        let governance_address = MockApi::default().addr_make("governance");
+
+        let mut app = App::new(|router, _, storage| {
+            router
+                .bank
+                .init_balance(storage, &user, coins(1000, "uaxl"))
+                .unwrap()
+        });
+        let code = ContractWrapper::new(execute, instantiate, query).with_reply(reply);
+        let code_id = app.store_code(Box::new(code));
+        let contract_address = app
+            .instantiate_contract(
+                code_id,
+                MockApi::default().addr_make("router"),
+                &InstantiateMsg {
+                    governance_address: governance_address.to_string(),
+                    rewards_denom: "uaxl".to_string(),
+                    epoch_retention: 10,
+                    track_event_attribution: false,
+                    reserve_epoch_rewards: false,
+                    attestation_target: None,
+                },
+                &[],
+                "Contract",
+                None,
+            )
+            .unwrap();
+
+        let pool_id = PoolId {
+            chain_name: chain_name.clone(),
+            contract: pool_contract.to_string(),
+        };
+
+        app.execute_contract(
+            governance_address.clone(),
+            contract_address.clone(),
+            &create_pool_msg(),
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            user.clone(),
+            contract_address.clone(),
+            &ExecuteMsg::AddRewards {
+                pool_id: pool_id.clone(),
+            },
+            &coins(1000, "uaxl"),
+        )
+        .unwrap();
+        app.execute_contract(
+            pool_contract,
+            contract_address.clone(),
+            &ExecuteMsg::RecordParticipation {
+                chain_name,
+                event_id: "event".try_into().unwrap(),
+                verifier_address: verifier.to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+
+        assert!(app
+            .execute_contract(
+                user.clone(),
+                contract_address.clone(),
+                &ExecuteMsg::SetPoolDistributor {
+                    pool_id: pool_id.clone(),
+                    distributor: Some(distributor.to_string()),
+                },
+                &[],
+            )
+            .is_err());
+
+        let res = app
+            .execute_contract(
+                governance_address,
+                contract_address.clone(),
+                &ExecuteMsg::SetPoolDistributor {
+                    pool_id: pool_id.clone(),
+                    distributor: Some(distributor.to_string()),
+                },
+                &[],
+            )
+            .unwrap();
+        assert!(res
+            .events
+            .iter()
+            .any(|event| event.ty == "wasm-pool_distributor_updated"
+                && event
+                    .attributes
+                    .iter()
+                    .any(|attribute| attribute.key == "distributor"
+                        && attribute.value == distributor.as_str())
+                && event
+                    .attributes
+                    .iter()
+                    .all(|attribute| attribute.key != "previous_distributor")));
+
+        let queried: Option<Addr> = app
+            .wrap()
+            .query_wasm_smart(
+                contract_address.clone(),
+                &QueryMsg::PoolDistributor {
+                    pool_id: pool_id.clone(),
+                },
+            )
+            .unwrap();
+        assert_eq!(queried, Some(distributor.clone()));
+
+        app.set_block(BlockInfo {
+            height: app.block_info().height + 20,
+            ..app.block_info()
+        });
+
+        for msg in [
+            ExecuteMsg::DistributeRewards {
+                pool_id: pool_id.clone(),
+                epoch_count: None,
+            },
+            ExecuteMsg::DistributeRewardsMulti {
+                pool_ids: vec![pool_id.clone()],
+                epoch_count: None,
+            },
+        ] {
+            let err = app
+                .execute_contract(user.clone(), contract_address.clone(), &msg, &[])
+                .unwrap_err();
+            assert!(err
+                .root_cause()
+                .to_string()
+                .contains(&ContractError::NotDistributor(distributor.clone()).to_string()));
+        }
+
+        app.execute_contract(
+            distributor,
+            contract_address,
+            &ExecuteMsg::DistributeRewardsMulti {
+                pool_ids: vec![pool_id],
+                epoch_count: None,
+            },
+            &[],
+        )
+        .unwrap();
+
+        let balance = app.wrap().query_balance(verifier, "uaxl").unwrap();
+        assert_eq!(balance.amount, Uint128::from(100u128));
+    }
+
+    const PAYOUTS: cw_storage_plus::Map<&Addr, (Addr, Uint128)> =
+        cw_storage_plus::Map::new("payouts");
+
+    /// Minimal vesting contract that records the verifier and the amount of the rewards it receives for each beneficiary
+    fn vesting_execute(
+        deps: DepsMut,
+        _env: Env,
+        info: MessageInfo,
+        msg: PayoutHookMsg,
+    ) -> Result<Response, cosmwasm_std::StdError> {
+        let PayoutHookMsg::Payout {
+            verifier,
+            beneficiary,
+            ..
+        } = msg;
+        PAYOUTS.save(
+            deps.storage,
+            &beneficiary,
+            &(verifier, info.funds[0].amount),
+        )?;
+
+        Ok(Response::new())
+    }
+
+    fn vesting_query(
+        deps: Deps,
+        _env: Env,
+        beneficiary: Addr,
+    ) -> Result<Binary, cosmwasm_std::StdError> {
+        to_json_binary(&PAYOUTS.may_load(deps.storage, &beneficiary)?)
+    }
+
+    #[test]
+    #[allow(clippy::arithmetic_side_effects)]
+    fn payout_hook_receives_rewards_instead_of_verifiers() {
+        let chain_name: ChainName = "mock-chain".parse().unwrap();
+        let user = MockApi::default().addr_make("user");
+        let verifier = MockApi::default().addr_make("verifier");
+        let proxy = MockApi::default().addr_make("proxy");
+        let pool_contract = MockApi::default().addr_make("pool_contract");
+
+        let (mut app, contract_address, governance_address) = setup_governance_transfer_test();
+        app.init_modules(|router, _, storage| {
+            router
+                .bank
+                .init_balance(storage, &user, coins(1000, "uaxl"))
+                .unwrap()
+        });
+
+        let vesting_code = ContractWrapper::new(vesting_execute, instantiate, vesting_query);
+        let vesting_code_id = app.store_code(Box::new(vesting_code));
+        let vesting = app
+            .instantiate_contract(
+                vesting_code_id,
+                user.clone(),
+                &InstantiateMsg {
+                    governance_address: governance_address.to_string(),
+                    rewards_denom: "unused".to_string(),
+                    epoch_retention: 10,
+                    track_event_attribution: false,
+                    reserve_epoch_rewards: false,
+                    attestation_target: None,
+                },
+                &[],
+                "Vesting",
+                None,
+            )
+            .unwrap();
+
+        let pool_id = PoolId {
+            chain_name: chain_name.clone(),
+            contract: pool_contract.to_string(),
+        };
+
+        app.execute_contract(
+            governance_address.clone(),
+            contract_address.clone(),
+            &create_pool_msg(),
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            user.clone(),
+            contract_address.clone(),
+            &ExecuteMsg::AddRewards {
+                pool_id: pool_id.clone(),
+            },
+            &coins(1000, "uaxl"),
+        )
+        .unwrap();
+        app.execute_contract(
+            verifier.clone(),
+            contract_address.clone(),
+            &ExecuteMsg::SetVerifierProxy {
+                proxy_address: proxy.to_string().parse().unwrap(),
+            },
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            pool_contract,
+            contract_address.clone(),
+            &ExecuteMsg::RecordParticipation {
+                chain_name,
+                event_id: "event".try_into().unwrap(),
+                verifier_address: verifier.to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+
+        let set_payout_hook = ExecuteMsg::SetPoolPayoutHook {
+            pool_id: pool_id.clone(),
+            payout_hook: Some(vesting.to_string()),
+        };
+        assert!(app
+            .execute_contract(
+                user.clone(),
+                contract_address.clone(),
+                &set_payout_hook,
+                &[]
+            )
+            .is_err());
+
+        let res = app
+            .execute_contract(
+                governance_address,
+                contract_address.clone(),
+                &set_payout_hook,
+                &[],
+            )
+            .unwrap();
+        assert!(res
+            .events
+            .iter()
+            .any(|event| event.ty == "wasm-pool_payout_hook_updated"
+                && event
+                    .attributes
+                    .iter()
+                    .any(|attribute| attribute.key == "payout_hook"
+                        && attribute.value == vesting.as_str())));
+
+        let queried: Option<Addr> = app
+            .wrap()
+            .query_wasm_smart(
+                contract_address.clone(),
+                &QueryMsg::PoolPayoutHook {
+                    pool_id: pool_id.clone(),
+                },
+            )
+            .unwrap();
+        assert_eq!(queried, Some(vesting.clone()));
+
+        app.set_block(BlockInfo {
+            height: app.block_info().height + 20,
+            ..app.block_info()
+        });
+
+        app.execute_contract(
+            user,
+            contract_address,
+            &ExecuteMsg::DistributeRewards {
+                pool_id,
+                epoch_count: None,
+            },
+            &[],
+        )
+        .unwrap();
+
+        for address in [&verifier, &proxy] {
+            let balance = app.wrap().query_balance(address, "uaxl").unwrap();
+            assert_eq!(balance.amount, Uint128::zero());
+        }
+        let balance = app.wrap().query_balance(&vesting, "uaxl").unwrap();
+        assert_eq!(balance.amount, Uint128::from(100u128));
+
+        let payout: Option<(Addr, Uint128)> = app.wrap().query_wasm_smart(vesting, &proxy).unwrap();
+        assert_eq!(payout, Some((verifier, Uint128::from(100u128))));
+    }
+
+    fn reverting_hook_execute(
+        _deps: DepsMut,
+        _env: Env,
+        _info: MessageInfo,
+        _msg: PayoutHookMsg,
+    ) -> Result<Response, cosmwasm_std::StdError> {
+        Err(cosmwasm_std::StdError::generic_err("vesting is paused"))
+    }
+
+    #[test]
+    #[allow(clippy::arithmetic_side_effects)]
+    fn failed_hooked_payout_should_stay_owed_until_the_next_distribution() {
+        let chain_name: ChainName = "mock-chain".parse().unwrap();
+        let user = MockApi::default().addr_make("user");
+        let verifier = MockApi::default().addr_make("verifier");
+        let pool_contract = MockApi::default().addr_make("pool_contract");
+        let pool_id = PoolId {
+            chain_name: chain_name.clone(),
+            contract: pool_contract.to_string(),
+        };
+
+        let (mut app, contract_address, governance_address) = setup_governance_transfer_test();
+        app.init_modules(|router, _, storage| {
+            router
+                .bank
+                .init_balance(storage, &user, coins(1000, "uaxl"))
+                .unwrap()
+        });
+
+        let instantiate_msg = InstantiateMsg {
+            governance_address: governance_address.to_string(),
+            rewards_denom: "unused".to_string(),
+            epoch_retention: 10,
+            track_event_attribution: false,
+            reserve_epoch_rewards: false,
+            attestation_target: None,
+        };
+        let reverting_code_id = app.store_code(Box::new(ContractWrapper::new(
+            reverting_hook_execute,
+            instantiate,
+            vesting_query,
+        )));
+        let reverting_hook = app
+            .instantiate_contract(
+                reverting_code_id,
+                user.clone(),
+                &instantiate_msg,
+                &[],
+                "Reverting",
+                None,
+            )
+            .unwrap();
+        let vesting_code_id = app.store_code(Box::new(ContractWrapper::new(
+            vesting_execute,
+            instantiate,
+            vesting_query,
+        )));
+        let vesting = app
+            .instantiate_contract(
+                vesting_code_id,
+                user.clone(),
+                &instantiate_msg,
+                &[],
+                "Vesting",
+                None,
+            )
+            .unwrap();
+
+        app.execute_contract(
+            governance_address.clone(),
+            contract_address.clone(),
+            &create_pool_msg(),
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            user.clone(),
+            contract_address.clone(),
+            &ExecuteMsg::AddRewards {
+                pool_id: pool_id.clone(),
+            },
+            &coins(1000, "uaxl"),
+        )
+        .unwrap();
+
+        let record_participation_and_distribute = |app: &mut App, payout_hook: &Addr| {
+            app.execute_contract(
+                pool_contract.clone(),
+                contract_address.clone(),
+                &ExecuteMsg::RecordParticipation {
+                    chain_name: chain_name.clone(),
+                    event_id: format!("event-{}", app.block_info().height)
+                        .try_into()
+                        .unwrap(),
+                    verifier_address: verifier.to_string(),
+                },
+                &[],
+            )
+            .unwrap();
+            app.execute_contract(
+                governance_address.clone(),
+                contract_address.clone(),
+                &ExecuteMsg::SetPoolPayoutHook {
+                    pool_id: pool_id.clone(),
+                    payout_hook: Some(payout_hook.to_string()),
+                },
+                &[],
+            )
+            .unwrap();
+            app.set_block(BlockInfo {
+                height: app.block_info().height + 20,
+                ..app.block_info()
+            });
+
+            app.execute_contract(
+                user.clone(),
+                contract_address.clone(),
+                &ExecuteMsg::DistributeRewards {
+                    pool_id: pool_id.clone(),
+                    epoch_count: None,
+                },
+                &[],
+            )
+            .unwrap()
+        };
+
+        // the reverting hook doesn't fail the distribution, its payout stays with the contract instead
+        let res = record_participation_and_distribute(&mut app, &reverting_hook);
+        assert!(res
+            .events
+            .iter()
+            .any(|event| event.ty == "wasm-hooked_payout_failed"
+                && event
+                    .attributes
+                    .iter()
+                    .any(|attribute| attribute.key == "owed" && attribute.value == "100")));
+        let balance = app.wrap().query_balance(&contract_address, "uaxl").unwrap();
+        assert_eq!(balance.amount, Uint128::from(1000u128));
+
+        // the owed payout is added to the verifier's payout of the next distribution
+        record_participation_and_distribute(&mut app, &vesting);
+        let balance = app.wrap().query_balance(&vesting, "uaxl").unwrap();
+        assert_eq!(balance.amount, Uint128::from(200u128));
+
+        let payout: Option<(Addr, Uint128)> =
+            app.wrap().query_wasm_smart(vesting, &verifier).unwrap();
+        assert_eq!(payout, Some((verifier, Uint128::from(200u128))));
+    }
+
+    #[test]
+    fn cw20_payout_hook_receives_rewards_with_send() {
+        let payout_hook = MockApi::default().addr_make("vesting");
+        let token = MockApi::default().addr_make("token");
+        let hook_msg = PayoutHookMsg::Payout {
+            pool_id: PoolId {
+                chain_name: "mock-chain".parse().unwrap(),
+                contract: MockApi::default().addr_make("pool_contract").to_string(),
+            },
+            verifier: MockApi::default().addr_make("verifier"),
+            beneficiary: MockApi::default().addr_make("verifier"),
+        };
+
+        let msg = hooked_payout_msg(
+            &state::RewardsAsset::Cw20(token.clone()),
+            "uaxl",
+            &payout_hook,
+            hook_msg.clone(),
+            Uint128::from(100u128),
+        )
+        .unwrap();
+
+        assert_eq!(
+            msg,
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: token.to_string(),
+                msg: to_json_binary(&Cw20ExecuteMsg::Send {
+                    contract: payout_hook.to_string(),
+                    amount: Uint128::from(100u128),
+                    msg: to_json_binary(&hook_msg).unwrap(),
+                })
+                .unwrap(),
+                funds: vec![],
+            })
+        );
+    }
+
+    #[test]
+    fn governance_transfer_takes_effect_only_after_acceptance() {
+        let (mut app, contract_address, governance_address) = setup_governance_transfer_test();
+        let new_governance = MockApi::default().addr_make("new_governance");
+        let proposed_at = app.block_info().height;
+
+        let res = app
+            .execute_contract(
+                governance_address.clone(),
+                contract_address.clone(),
+                &ExecuteMsg::ProposeGovernanceTransfer {
+                    new_governance: new_governance.to_string(),
+                    expiry_blocks: 100u64.try_into().unwrap(),
+                },
+                &[],
+            )
+            .unwrap();
+        assert!(res
+            .events
+            .iter()
+            .any(|event| event.ty == "wasm-governance_transfer_proposed"));
+
+        let pending: Option<GovernanceTransfer> = app
+            .wrap()
+            .query_wasm_smart(
+                contract_address.clone(),
+                &QueryMsg::PendingGovernanceTransfer,
+            )
+            .unwrap();
+        assert_eq!(
+            pending,
+            Some(GovernanceTransfer {
+                current_governance: governance_address.clone(),
+                new_governance: new_governance.clone(),
+                proposed_at,
+                expires_at: proposed_at + 100,
+            })
+        );
+
+        // the proposal alone does not change governance
+        assert!(app
+            .execute_contract(
+                new_governance.clone(),
+                contract_address.clone(),
+                &create_pool_msg(),
+                &[],
+            )
+            .is_err());
+
+        // only the proposed address can accept
+        assert!(app
+            .execute_contract(
+                MockApi::default().addr_make("user"),
+                contract_address.clone(),
+                &ExecuteMsg::AcceptGovernanceTransfer {},
+                &[],
+            )
+            .is_err());
+
+        let res = app
+            .execute_contract(
+                new_governance.clone(),
+                contract_address.clone(),
+                &ExecuteMsg::AcceptGovernanceTransfer {},
+                &[],
+            )
+            .unwrap();
+        assert!(res
+            .events
+            .iter()
+            .any(|event| event.ty == "wasm-governance_transfer_accepted"));
+
+        let pending: Option<GovernanceTransfer> = app
+            .wrap()
+            .query_wasm_smart(
+                contract_address.clone(),
+                &QueryMsg::PendingGovernanceTransfer,
+            )
+            .unwrap();
+        assert_eq!(pending, None);
+
+        assert!(app
+            .execute_contract(
+                governance_address,
+                contract_address.clone(),
+                &create_pool_msg(),
+                &[],
+            )
+            .is_err());
+        assert!(app
+            .execute_contract(new_governance, contract_address, &create_pool_msg(), &[])
+            .is_ok());
+    }
+
+    #[test]
+    fn expired_governance_transfer_cannot_be_accepted() {
+        let (mut app, contract_address, governance_address) = setup_governance_transfer_test();
+        let new_governance = MockApi::default().addr_make("new_governance");
+        let expires_at = app.block_info().height + 10;
+
+        app.execute_contract(
+            governance_address.clone(),
+            contract_address.clone(),
+            &ExecuteMsg::ProposeGovernanceTransfer {
+                new_governance: new_governance.to_string(),
+                expiry_blocks: 10u64.try_into().unwrap(),
+            },
+            &[],
+        )
+        .unwrap();
+
+        app.set_block(BlockInfo {
+            height: expires_at,
+            ..app.block_info()
+        });
+
+        let pending: Option<GovernanceTransfer> = app
+            .wrap()
+            .query_wasm_smart(
+                contract_address.clone(),
+                &QueryMsg::PendingGovernanceTransfer,
+            )
+            .unwrap();
+        assert_eq!(pending, None);
+
+        let err = app
+            .execute_contract(
+                new_governance,
+                contract_address.clone(),
+                &ExecuteMsg::AcceptGovernanceTransfer {},
+                &[],
+            )
+            .unwrap_err();
+        assert!(err
+            .root_cause()
+            .to_string()
+            .contains(&ContractError::GovernanceTransferExpired(expires_at).to_string()));
+
+        assert!(app
+            .execute_contract(
+                governance_address,
+                contract_address,
+                &create_pool_msg(),
+                &[],
+            )
+            .is_ok());
+    }
+
+    #[test]
+    fn governance_transfer_to_current_governance_fails() {
+        let (mut app, contract_address, governance_address) = setup_governance_transfer_test();
+
+        assert!(app
+            .execute_contract(
+                governance_address.clone(),
+                contract_address,
+                &ExecuteMsg::ProposeGovernanceTransfer {
+                    new_governance: governance_address.to_string(),
+                    expiry_blocks: 10u64.try_into().unwrap(),
+                },
+                &[],
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn create_pools_creates_all_pools_or_none() {
+        let (mut app, contract_address, governance_address) = setup_governance_transfer_test();
+        app.init_modules(|router, _, storage| {
+            router
+                .bank
+                .init_balance(storage, &governance_address, coins(1000, "uaxl"))
+                .unwrap()
+        });
+
+        let ExecuteMsg::CreatePool { params, .. } = create_pool_msg() else {
+            panic!("expected a CreatePool message");
+        };
+        let new_pool = |chain_name: &str| NewPool {
+            params: params.clone(),
+            pool_id: PoolId {
+                chain_name: chain_name.parse().unwrap(),
+                contract: MockApi::default().addr_make("pool_contract").to_string(),
+            },
+            rewards_asset: RewardsAsset::Native,
+            align_epochs_with: None,
+        };
+        let pools = vec![new_pool("chain-1"), new_pool("chain-2")];
+
+        assert!(app
+            .execute_contract(
+                MockApi::default().addr_make("anyone"),
+                contract_address.clone(),
+                &ExecuteMsg::CreatePools(pools.clone()),
+                &[],
+            )
+            .is_err());
+
+        let err = app
+            .execute_contract(
+                governance_address.clone(),
+                contract_address.clone(),
+                &ExecuteMsg::CreatePools(pools.clone()),
+                &coins(100, "uaxl"),
+            )
+            .unwrap_err();
+        assert!(err
+            .root_cause()
+            .to_string()
+            .contains(&ContractError::PoolCreationNotPayable.to_string()));
+
+        // a pool that already exists fails the whole batch
+        let err = app
+            .execute_contract(
+                governance_address.clone(),
+                contract_address.clone(),
+                &ExecuteMsg::CreatePools(vec![pools[0].clone(), pools[0].clone()]),
+                &[],
+            )
+            .unwrap_err();
+        assert!(err
+            .root_cause()
+            .to_string()
+            .contains(&ContractError::RewardsPoolAlreadyExists.to_string()));
+
+        // funding a pool that wasn't created fails
+        let err = app
+            .execute_contract(
+                governance_address.clone(),
+                contract_address.clone(),
+                &ExecuteMsg::AddRewards {
+                    pool_id: pools[0].pool_id.clone(),
+                },
+                &coins(100, "uaxl"),
+            )
+            .unwrap_err();
+        assert!(err
+            .root_cause()
+            .to_string()
+            .contains(&ContractError::RewardsPoolNotFound.to_string()));
+
+        assert!(app
+            .execute_contract(
+                governance_address.clone(),
+                contract_address.clone(),
+                &ExecuteMsg::CreatePools(pools.clone()),
+                &[],
+            )
+            .is_ok());
+
+        for pool in pools {
+            assert!(app
+                .execute_contract(
+                    governance_address.clone(),
+                    contract_address.clone(),
+                    &ExecuteMsg::AddRewards {
+                        pool_id: pool.pool_id.clone(),
+                    },
+                    &coins(100, "uaxl"),
+                )
+                .is_ok());
+
+            let res: RewardsPool = app
+                .wrap()
+                .query_wasm_smart(
+                    contract_address.clone(),
+                    &QueryMsg::RewardsPool {
+                        pool_id: pool.pool_id,
+                    },
+                )
+                .unwrap();
+            assert_eq!(res.balance, Uint128::from(100u128));
+        }
+    }
+
+    #[test]
+    fn sudo_restores_missing_config() {
+        let api = MockApi::default();
+        let mut deps = mock_dependencies();
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make("instantiator"), &[]),
+            InstantiateMsg {
+                governance_address: api.addr_make("governance").to_string(),
+                rewards_denom: "uaxl".to_string(),
+                epoch_retention: 10,
+                track_event_attribution: true,
+                reserve_epoch_rewards: false,
+                attestation_target: None,
+            },
+        )
+        .unwrap();
+
+        CONFIG.remove(deps.as_mut().storage);
+
+        let query_msg = QueryMsg::ParticipationProof {
+            pool_id: PoolId {
+                chain_name: "mock-chain".parse().unwrap(),
+                contract: api.addr_make("pool_contract").to_string(),
+            },
+            verifier: api.addr_make("verifier").to_string(),
+            epoch_num: 0,
+            start_after: None,
+            limit: None,
+        };
+        assert!(query(deps.as_ref(), mock_env(), query_msg.clone())
+            .unwrap_err()
+            .to_string()
+            .contains(&ContractError::LoadConfig.to_string()));
+
+        assert_ok!(sudo(
+            deps.as_mut(),
+            mock_env(),
+            SudoMsg::RestoreConfig {
+                rewards_denom: "uaxl".to_string(),
+                epoch_retention: 10,
+                track_event_attribution: true,
+                reserve_epoch_rewards: false,
+                attestation_target: None,
+            },
+        ));
+
+        assert_ok!(query(deps.as_ref(), mock_env(), query_msg));
+    }
 }
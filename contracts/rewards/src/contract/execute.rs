@@ -1,41 +1,161 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 
-use axelar_wasm_std::{nonempty, FnExt};
-use cosmwasm_std::{Addr, OverflowError, OverflowOperation, Storage, Uint128};
+use axelar_wasm_std::permission_control::Permission;
+use axelar_wasm_std::time::Timestamp;
+use axelar_wasm_std::{nonempty, permission_control, FnExt};
+use cosmwasm_std::{Addr, BlockInfo, OverflowError, OverflowOperation, Storage, Uint128};
 use error_stack::{ensure, Report, Result};
 use itertools::Itertools;
+use service_registry_api::ParticipationSummary;
 
 use crate::error::ContractError;
-use crate::msg::Params;
+use crate::events;
+use crate::msg::{GovernanceTransfer, Params, RemainderPolicy};
 use crate::state::{
-    self, Epoch, EpochTally, Event, ParamsSnapshot, PoolId, RewardsDistribution, RewardsPool,
-    StorageState,
+    self, Epoch, EpochStartTime, EpochTally, Event, ParamsSnapshot, PoolId, PrunedEpochs,
+    RewardsAsset, RewardsDistribution, RewardsPool, StorageState,
 };
 
 const DEFAULT_EPOCHS_TO_PROCESS: u64 = 10;
 const EPOCH_PAYOUT_DELAY: u64 = 2;
+const DEFAULT_ENTRIES_TO_PRUNE: u32 = 100;
 
+/// Ends the current epoch of the pool at this block if it has lasted the pool's epoch time. The contract records the
+/// block time at which it first sees an epoch, and once the epoch time has passed since then, the next epoch starts at the
+/// current block, in the same way as after a params update that ends the current epoch. Pools without an epoch time are
+/// left unchanged
+pub fn end_epoch_after_epoch_time(
+    storage: &mut dyn Storage,
+    pool_id: &PoolId,
+    block: &BlockInfo,
+) -> Result<(), ContractError> {
+    let current_params = state::load_rewards_pool_params(storage, pool_id.clone())?;
+    let Some(epoch_time) = current_params.params.epoch_time else {
+        return Ok(());
+    };
+
+    let now = Timestamp::from(block.time);
+    let cur_epoch = Epoch::current(&current_params, block.height)?;
+
+    let next_start_time = match state::load_epoch_start_time(storage, pool_id.clone())? {
+        Some(start_time) if start_time.epoch_num == cur_epoch.epoch_num => {
+            if !start_time
+                .started_at
+                .saturating_add(epoch_time)
+                .is_reached(now)
+            {
+                return Ok(());
+            }
+
+            let next_epoch = cur_epoch.next_starting_at(block.height)?;
+            state::update_pool_params(
+                storage,
+                pool_id,
+                &ParamsSnapshot {
+                    params: current_params.params,
+                    created_at: next_epoch.clone(),
+                },
+            )?;
+
+            EpochStartTime {
+                epoch_num: next_epoch.epoch_num,
+                started_at: now,
+            }
+        }
+        _ => EpochStartTime {
+            epoch_num: cur_epoch.epoch_num,
+            started_at: now,
+        },
+    };
+
+    state::save_epoch_start_time(storage, pool_id.clone(), &next_start_time)
+}
+
+/// Records the verifier's participation in the event. This must never fail because of the pool's funding, as it's
+/// called from the end of polls and signing sessions. If the pool can't cover the rewards of a new epoch, the epoch is
+/// marked as under-reserved and an event is returned, and the missing rewards are settled at distribution
 pub fn record_participation(
     storage: &mut dyn Storage,
     event_id: nonempty::String,
     verifier: Addr,
     pool_id: PoolId,
     block_height: u64,
-) -> Result<(), ContractError> {
+) -> Result<Option<events::Event>, ContractError> {
     let current_params = state::load_rewards_pool_params(storage, pool_id.clone())?;
     let cur_epoch = Epoch::current(&current_params, block_height)?;
 
     let event = load_or_store_event(storage, event_id, pool_id.clone(), cur_epoch.epoch_num)?;
 
-    state::load_epoch_tally(storage, pool_id.clone(), event.epoch_num)?
-        .unwrap_or(EpochTally::new(pool_id, cur_epoch, current_params.params))
-        .record_participation(verifier)
-        .then(|mut tally| {
-            if matches!(event, StorageState::New(_)) {
-                tally.event_count = tally.event_count.saturating_add(1)
-            }
-            state::save_epoch_tally(storage, &tally)
-        })
+    let config = state::load_config(storage)?;
+    if config.track_event_attribution {
+        state::save_event_attribution(storage, &event, verifier.clone())?;
+    }
+
+    let (tally, under_reserved) =
+        match state::load_epoch_tally(storage, pool_id.clone(), event.epoch_num)? {
+            Some(tally) => (tally, None),
+            None if config.reserve_epoch_rewards => reserve_available_epoch_rewards(
+                storage,
+                EpochTally::new(pool_id, cur_epoch, current_params.params),
+            )?,
+            None => (
+                EpochTally::new(pool_id, cur_epoch, current_params.params),
+                None,
+            ),
+        };
+
+    tally.record_participation(verifier).then(|mut tally| {
+        if matches!(event, StorageState::New(_)) {
+            tally.event_count = tally.event_count.saturating_add(1)
+        }
+        state::save_epoch_tally(storage, &tally)
+    })?;
+
+    Ok(under_reserved)
+}
+
+/// Reserves as much of the rewards of the tally's epoch as the free balance of the pool allows. Returns the tally and
+/// an event if the epoch is under-reserved
+fn reserve_available_epoch_rewards(
+    storage: &mut dyn Storage,
+    mut tally: EpochTally,
+) -> Result<(EpochTally, Option<events::Event>), ContractError> {
+    let target = Uint128::from(tally.params.rewards_per_epoch);
+
+    let (pool, reserved) = state::load_rewards_pool(storage, tally.pool_id.clone())?
+        .reserve_available(tally.reserved, target);
+    state::save_rewards_pool(storage, &pool)?;
+
+    tally.reserved = reserved;
+    tally.under_reserved = reserved < target;
+
+    let event = tally
+        .under_reserved
+        .then(|| events::Event::EpochUnderReserved {
+            pool_id: tally.pool_id.clone(),
+            epoch_num: tally.epoch.epoch_num,
+            reserved,
+            rewards_per_epoch: target,
+        });
+
+    Ok((tally, event))
+}
+
+/// Reserves the rewards of the tally's epoch from the free balance of the pool, or adjusts an existing reservation
+/// to the tally's current rewards per epoch
+fn reserve_epoch_rewards(
+    storage: &mut dyn Storage,
+    mut tally: EpochTally,
+) -> Result<EpochTally, ContractError> {
+    let target = Uint128::from(tally.params.rewards_per_epoch);
+
+    state::load_rewards_pool(storage, tally.pool_id.clone())?
+        .update_reservation(tally.reserved, target)?
+        .then(|pool| state::save_rewards_pool(storage, &pool))?;
+
+    tally.reserved = target;
+    tally.under_reserved = false;
+    Ok(tally)
 }
 
 fn load_or_store_event(
@@ -62,6 +182,12 @@ pub fn distribute_rewards(
     cur_block_height: u64,
     epoch_process_limit: Option<u64>,
 ) -> Result<RewardsDistribution, ContractError> {
+    let earliest_allowed_height = state::earliest_distribution_height(storage, pool_id.clone())?;
+    ensure!(
+        cur_block_height >= earliest_allowed_height,
+        ContractError::DistributionCooldown(earliest_allowed_height)
+    );
+
     let epoch_process_limit = epoch_process_limit.unwrap_or(DEFAULT_EPOCHS_TO_PROCESS);
     let cur_epoch = state::current_epoch(storage, &pool_id, cur_block_height)?;
 
@@ -77,9 +203,18 @@ pub fn distribute_rewards(
         return Err(ContractError::NoRewardsToDistribute.into());
     }
 
-    let rewards = process_rewards_for_epochs(storage, pool_id.clone(), from, to)?;
-    state::save_rewards_watermark(storage, pool_id, to)?;
+    let (rewards, burned) = process_rewards_for_epochs(storage, pool_id.clone(), from, to)?;
+    state::save_rewards_watermark(storage, pool_id.clone(), to)?;
+
+    // the cooldown only starts once the pool has caught up, so a backlog of epochs can be distributed in consecutive batches
+    let can_distribute_more = to < cur_epoch.epoch_num.saturating_sub(EPOCH_PAYOUT_DELAY);
+    if !can_distribute_more {
+        state::save_last_distribution_height(storage, pool_id.clone(), cur_block_height)?;
+    }
+    let pool = state::load_rewards_pool(storage, pool_id.clone())?;
+
     Ok(RewardsDistribution {
+        pool_id,
         rewards: rewards
             .into_iter()
             .map(|(addr, amount)| {
@@ -88,33 +223,119 @@ pub fn distribute_rewards(
             .try_collect()?,
         epochs_processed: (from..=to).collect(),
         current_epoch: cur_epoch.clone(),
-        can_distribute_more: to < cur_epoch.epoch_num.saturating_sub(EPOCH_PAYOUT_DELAY),
+        can_distribute_more,
+        remaining_balance: pool.balance,
+        remainder_policy: pool.params.params.remainder_policy,
+        carried_remainder: pool.carried_remainder,
+        burned,
     })
 }
 
+/// Sums up the participation of each verifier over the given epochs of the pool. Verifiers are expected to participate in
+/// every event of an epoch. The given active verifiers are included even if they participated in none of the events
+pub fn participation_summaries(
+    storage: &dyn Storage,
+    pool_id: &PoolId,
+    epochs: &[u64],
+    active_verifiers: Vec<Addr>,
+) -> Result<Vec<ParticipationSummary>, ContractError> {
+    let tallies: Vec<EpochTally> = epochs
+        .iter()
+        .map(|epoch_num| state::load_epoch_tally(storage, pool_id.clone(), *epoch_num))
+        .filter_map_ok(|tally| tally)
+        .try_collect()?;
+
+    let events_total = tallies
+        .iter()
+        .fold(0u64, |total, tally| total.saturating_add(tally.event_count));
+
+    if events_total == 0 {
+        return Ok(vec![]);
+    }
+
+    let no_participation = active_verifiers
+        .into_iter()
+        .map(|verifier| (verifier.to_string(), 0u64))
+        .collect::<BTreeMap<_, _>>();
+
+    Ok(tallies
+        .iter()
+        .flat_map(|tally| tally.participation.iter())
+        .fold(no_participation, |mut participation, (verifier, count)| {
+            let entry: &mut u64 = participation.entry(verifier.clone()).or_default();
+            *entry = entry.saturating_add(*count);
+            participation
+        })
+        .into_iter()
+        .map(|(verifier, events_participated)| ParticipationSummary {
+            verifier,
+            chain_name: pool_id.chain_name.clone(),
+            events_participated,
+            events_total,
+        })
+        .collect())
+}
+
+/// Pays out the rewards of the given epochs from the pool. Returns the rewards of each verifier and the burned remainder
 fn process_rewards_for_epochs(
     storage: &mut dyn Storage,
     pool_id: PoolId,
     from: u64,
     to: u64,
-) -> Result<HashMap<Addr, Uint128>, ContractError> {
-    let rewards = cumulate_rewards(storage, &pool_id, from, to)?;
-    state::load_rewards_pool(storage, pool_id.clone())?
-        .sub_reward(rewards.values().sum())?
-        .then(|pool| state::save_rewards_pool(storage, &pool))?;
+) -> Result<(HashMap<Addr, Uint128>, Uint128), ContractError> {
+    let pool = state::load_rewards_pool(storage, pool_id.clone())?;
+    let tallies: Vec<EpochTally> = iterate_epoch_tallies(storage, &pool_id, from, to).collect();
+    let reserved: Uint128 = tallies.iter().map(|tally| tally.reserved).sum();
+
+    let opted_out = state::load_opted_out_verifiers(storage, pool_id.clone())?;
+
+    let (rewards, carried_remainder, burned) =
+        cumulate_rewards(&tallies, pool.carried_remainder, &opted_out)?;
+
+    // the previously carried remainder is released together with the epochs' reservations, and the new one is reserved instead
+    let released = reserved.saturating_add(pool.carried_remainder);
+    let mut pool = pool
+        .update_reservation(released, carried_remainder)?
+        .sub_reward(rewards.values().sum::<Uint128>().saturating_add(burned))?;
+    pool.carried_remainder = carried_remainder;
+    state::save_rewards_pool(storage, &pool)?;
 
-    Ok(rewards)
+    Ok((rewards, burned))
 }
 
+/// Sums up the rewards of the given tallies in epoch order, so a carried remainder is added to the next epoch with rewards.
+/// Opted out verifiers are skipped and their shares are handled like the remainder.
+/// Returns the rewards of each verifier, the remainder that is carried on and the remainder that is burned
 fn cumulate_rewards(
-    storage: &mut dyn Storage,
-    pool_id: &PoolId,
-    from: u64,
-    to: u64,
-) -> Result<HashMap<Addr, Uint128>, ContractError> {
-    iterate_epoch_tallies(storage, pool_id, from, to)
-        .map(|tally| tally.rewards_by_verifier())
-        .try_fold(HashMap::new(), merge_rewards)
+    tallies: &[EpochTally],
+    carried_remainder: Uint128,
+    opted_out: &HashSet<Addr>,
+) -> Result<(HashMap<Addr, Uint128>, Uint128, Uint128), ContractError> {
+    tallies.iter().try_fold(
+        (HashMap::new(), carried_remainder, Uint128::zero()),
+        |(rewards, carried_remainder, burned), tally| {
+            let policy = tally.params.remainder_policy;
+
+            // a remainder is only carried on as long as the policy is to carry it, otherwise it's released into the pool balance
+            let carried_in = match policy {
+                Some(RemainderPolicy::Carry) => carried_remainder,
+                _ => Uint128::zero(),
+            };
+            let (epoch_rewards, remainder) = tally.split_rewards(carried_in, opted_out);
+
+            let (carried_remainder, burned) = match policy {
+                Some(RemainderPolicy::Carry) => (remainder, burned),
+                Some(RemainderPolicy::Burn) => (Uint128::zero(), burned.saturating_add(remainder)),
+                Some(RemainderPolicy::RoundRobin) | None => (Uint128::zero(), burned),
+            };
+
+            Ok((
+                merge_rewards(rewards, epoch_rewards)?,
+                carried_remainder,
+                burned,
+            ))
+        },
+    )
 }
 
 fn iterate_epoch_tallies<'a>(
@@ -128,20 +349,109 @@ fn iterate_epoch_tallies<'a>(
     })
 }
 
+/// Removes events, event attributions and tallies of epochs that are older than the retention window below the rewards watermark, oldest first.
+/// At most `entry_limit` entries are removed. The events and attributions of an epoch are removed before its tally,
+/// so a tally is only removed once all of its events and attributions are gone.
+pub fn prune_epochs(
+    storage: &mut dyn Storage,
+    pool_id: PoolId,
+    entry_limit: Option<u32>,
+) -> Result<PrunedEpochs, ContractError> {
+    let mut pruned = PrunedEpochs {
+        pool_id: pool_id.clone(),
+        epochs: None,
+        tallies_pruned: 0,
+        events_pruned: 0,
+        attributions_pruned: 0,
+        can_prune_more: false,
+    };
+
+    let Some(watermark) = state::load_rewards_watermark(storage, pool_id.clone())? else {
+        return Ok(pruned);
+    };
+    let prune_before = watermark.saturating_sub(state::load_config(storage)?.epoch_retention);
+
+    let mut remaining = entry_limit.unwrap_or(DEFAULT_ENTRIES_TO_PRUNE) as usize;
+    while remaining > 0 {
+        let Some(tally) =
+            state::load_oldest_epoch_tally_before(storage, pool_id.clone(), prune_before)?
+        else {
+            break;
+        };
+        let epoch_num = tally.epoch.epoch_num;
+
+        let event_ids = state::load_event_ids(storage, pool_id.clone(), epoch_num, remaining)?;
+        remaining = remaining.saturating_sub(event_ids.len());
+        pruned.events_pruned = pruned.events_pruned.saturating_add(event_ids.len() as u64);
+        for event_id in event_ids {
+            state::remove_event(storage, event_id, pool_id.clone())?;
+        }
+
+        let attributions =
+            state::load_event_attributions(storage, pool_id.clone(), epoch_num, remaining)?;
+        remaining = remaining.saturating_sub(attributions.len());
+        pruned.attributions_pruned = pruned
+            .attributions_pruned
+            .saturating_add(attributions.len() as u64);
+        for (verifier, event_id) in attributions {
+            state::remove_event_attribution(
+                storage,
+                pool_id.clone(),
+                epoch_num,
+                verifier,
+                event_id,
+            );
+        }
+
+        pruned.epochs = Some(
+            pruned
+                .epochs
+                .map_or((epoch_num, epoch_num), |(from, _)| (from, epoch_num)),
+        );
+
+        if remaining == 0 {
+            break;
+        }
+
+        state::remove_epoch_tally(storage, pool_id.clone(), epoch_num);
+        remaining = remaining.saturating_sub(1);
+        pruned.tallies_pruned = pruned.tallies_pruned.saturating_add(1);
+    }
+
+    pruned.can_prune_more =
+        state::load_oldest_epoch_tally_before(storage, pool_id, prune_before)?.is_some();
+
+    Ok(pruned)
+}
+
 pub fn create_pool(
     storage: &mut dyn Storage,
     params: Params,
     block_height: u64,
     pool_id: PoolId,
+    asset: RewardsAsset,
+    align_epochs_with: Option<PoolId>,
 ) -> Result<(), ContractError> {
     ensure!(
         !state::pool_exists(storage, &pool_id)?,
         ContractError::RewardsPoolAlreadyExists
     );
 
-    let cur_epoch = Epoch {
-        epoch_num: 0,
-        block_height_started: block_height,
+    let cur_epoch = match align_epochs_with {
+        Some(anchor) => {
+            let cur_epoch = aligned_epoch(storage, &pool_id, &params, anchor, block_height)?;
+
+            // epochs before the shared current epoch never belonged to this pool, so they must not be distributed
+            if let Some(last_epoch) = cur_epoch.epoch_num.checked_sub(1) {
+                state::save_rewards_watermark(storage, pool_id.clone(), last_epoch)?;
+            }
+
+            cur_epoch
+        }
+        None => Epoch {
+            epoch_num: 0,
+            block_height_started: block_height,
+        },
     };
 
     let params_snapshot = ParamsSnapshot {
@@ -153,41 +463,57 @@ pub fn create_pool(
         id: pool_id.clone(),
         balance: Uint128::zero(),
         params: params_snapshot,
+        asset,
+        reserved: Uint128::zero(),
+        carried_remainder: Uint128::zero(),
     };
 
     state::save_rewards_pool(storage, &pool)
 }
 
+/// Returns the current epoch of the anchor pool, which a new pool with the given params can adopt as its own
+fn aligned_epoch(
+    storage: &dyn Storage,
+    pool_id: &PoolId,
+    params: &Params,
+    anchor: PoolId,
+    block_height: u64,
+) -> Result<Epoch, ContractError> {
+    ensure!(
+        anchor.chain_name == pool_id.chain_name,
+        ContractError::EpochAlignmentChainMismatch
+    );
+
+    let anchor_params = state::load_rewards_pool_params(storage, anchor)?;
+    ensure!(
+        anchor_params.params.epoch_duration == params.epoch_duration,
+        ContractError::EpochAlignmentDurationMismatch
+    );
+
+    Epoch::current(&anchor_params, block_height)
+}
+
 pub fn update_pool_params(
     storage: &mut dyn Storage,
     pool_id: &PoolId,
     new_params: Params,
     block_height: u64,
+    align_epochs_with: Option<PoolId>,
 ) -> Result<(), ContractError> {
     let cur_epoch = state::current_epoch(storage, pool_id, block_height)?;
+    let new_epoch = match align_epochs_with {
+        Some(anchor) => {
+            let new_epoch = aligned_epoch(storage, pool_id, &new_params, anchor, block_height)?;
+
+            // epoch numbers must never go back, otherwise participation would be recorded in already tallied epochs
+            ensure!(
+                new_epoch.epoch_num >= cur_epoch.epoch_num,
+                ContractError::EpochAlignmentEpochBehind
+            );
 
-    // If the param update reduces the epoch duration such that the current epoch immediately ends,
-    // start a new epoch at this block, incrementing the current epoch number by 1.
-    // This prevents us from jumping forward an arbitrary number of epochs, and maintains consistency for past events.
-    // (i.e. we are in epoch 0, which started at block 0 and epoch duration is 1000. At epoch 500, the params
-    // are updated to shorten the epoch duration to 100 blocks. We set the epoch number to 1, to prevent skipping
-    // epochs 1-4, and so all events prior to the start of epoch 1 have an epoch number of 0)
-    let should_end = cur_epoch
-        .block_height_started
-        .checked_add(u64::from(new_params.epoch_duration))
-        .ok_or_else(|| OverflowError::new(OverflowOperation::Add))
-        .map_err(ContractError::from)?
-        < block_height;
-    let new_epoch = if should_end {
-        Epoch {
-            block_height_started: block_height,
-            epoch_num: cur_epoch
-                .epoch_num
-                .checked_add(1)
-                .expect("epoch number should be strictly smaller than the current block height"),
+            new_epoch
         }
-    } else {
-        cur_epoch.clone()
+        None => cur_epoch.after_params_update(&new_params, block_height)?,
     };
 
     let new_params_snapshot = ParamsSnapshot {
@@ -200,6 +526,9 @@ pub fn update_pool_params(
     let cur_tally = state::load_epoch_tally(storage, pool_id.clone(), cur_epoch.epoch_num)?;
     if let Some(mut tally) = cur_tally {
         tally.params = new_params_snapshot.params;
+        if state::load_config(storage)?.reserve_epoch_rewards {
+            tally = reserve_epoch_rewards(storage, tally)?;
+        }
         state::save_epoch_tally(storage, &tally)?;
     }
 
@@ -210,8 +539,17 @@ pub fn add_rewards(
     storage: &mut dyn Storage,
     pool_id: PoolId,
     amount: nonempty::Uint128,
+    asset: &RewardsAsset,
 ) -> Result<(), ContractError> {
+    // pools are only created by governance, funding must never create one implicitly
+    ensure!(
+        state::pool_exists(storage, &pool_id)?,
+        ContractError::RewardsPoolNotFound
+    );
+
     let mut pool = state::load_rewards_pool(storage, pool_id)?;
+    ensure!(&pool.asset == asset, ContractError::WrongRewardsAsset);
+
     pool.balance = pool
         .balance
         .checked_add(Uint128::from(amount))
@@ -256,10 +594,155 @@ pub fn set_verifier_proxy(
     state::save_verifier_proxy(storage, proxy_address, verifier_addr)
 }
 
-pub fn remove_verifier_proxy(storage: &mut dyn Storage, verifier_addr: &Addr) {
+pub fn remove_verifier_proxy(
+    storage: &mut dyn Storage,
+    verifier_addr: &Addr,
+) -> Result<(), ContractError> {
     state::remove_verifier_proxy(storage, verifier_addr)
 }
 
+/// Replaces the distributor of the pool and returns the previous one
+pub fn set_pool_distributor(
+    storage: &mut dyn Storage,
+    pool_id: PoolId,
+    distributor: Option<Addr>,
+) -> Result<Option<Addr>, ContractError> {
+    ensure!(
+        state::pool_exists(storage, &pool_id)?,
+        ContractError::RewardsPoolNotFound
+    );
+
+    let previous_distributor = state::may_load_pool_distributor(storage, pool_id.clone())?;
+
+    match distributor {
+        Some(distributor) => state::save_pool_distributor(storage, pool_id, &distributor)?,
+        None => state::remove_pool_distributor(storage, pool_id),
+    }
+
+    Ok(previous_distributor)
+}
+
+/// Replaces the payout hook of the pool and returns the previous one
+pub fn set_pool_payout_hook(
+    storage: &mut dyn Storage,
+    pool_id: PoolId,
+    payout_hook: Option<Addr>,
+) -> Result<Option<Addr>, ContractError> {
+    ensure!(
+        state::pool_exists(storage, &pool_id)?,
+        ContractError::RewardsPoolNotFound
+    );
+
+    let previous_payout_hook = state::may_load_pool_payout_hook(storage, pool_id.clone())?;
+
+    match payout_hook {
+        Some(payout_hook) => state::save_pool_payout_hook(storage, pool_id, &payout_hook)?,
+        None => state::remove_pool_payout_hook(storage, pool_id),
+    }
+
+    Ok(previous_payout_hook)
+}
+
+/// Rewards of a pool with a distributor can only be distributed by the distributor and governance.
+/// The contract itself is allowed as well, because `DistributeRewardsMulti` distributes each pool in a submessage
+/// after checking the original sender
+pub fn ensure_can_distribute(
+    storage: &dyn Storage,
+    pool_id: &PoolId,
+    sender: &Addr,
+    contract: &Addr,
+) -> Result<(), ContractError> {
+    let Some(distributor) = state::may_load_pool_distributor(storage, pool_id.clone())? else {
+        return Ok(());
+    };
+
+    let is_governance = permission_control::sender_role(storage, sender)
+        .map_err(ContractError::from)?
+        .contains(Permission::Governance);
+
+    ensure!(
+        *sender == distributor || sender == contract || is_governance,
+        ContractError::NotDistributor(distributor)
+    );
+
+    Ok(())
+}
+
+pub fn opt_out_of_rewards(
+    storage: &mut dyn Storage,
+    pool_id: PoolId,
+    verifier_addr: Addr,
+) -> Result<(), ContractError> {
+    ensure!(
+        state::pool_exists(storage, &pool_id)?,
+        ContractError::RewardsPoolNotFound
+    );
+
+    state::save_rewards_opt_out(storage, pool_id, verifier_addr)
+}
+
+pub fn opt_into_rewards(
+    storage: &mut dyn Storage,
+    pool_id: PoolId,
+    verifier_addr: Addr,
+) -> Result<(), ContractError> {
+    ensure!(
+        state::pool_exists(storage, &pool_id)?,
+        ContractError::RewardsPoolNotFound
+    );
+
+    state::remove_rewards_opt_out(storage, pool_id, verifier_addr);
+
+    Ok(())
+}
+
+pub fn propose_governance_transfer(
+    storage: &mut dyn Storage,
+    current_governance: Addr,
+    new_governance: Addr,
+    expiry_blocks: nonempty::Uint64,
+    block_height: u64,
+) -> Result<GovernanceTransfer, ContractError> {
+    ensure!(
+        current_governance != new_governance,
+        ContractError::GovernanceUnchanged
+    );
+
+    let transfer = GovernanceTransfer {
+        current_governance,
+        new_governance,
+        proposed_at: block_height,
+        expires_at: block_height
+            .checked_add(u64::from(expiry_blocks))
+            .ok_or_else(|| OverflowError::new(OverflowOperation::Add))
+            .map_err(ContractError::from)?,
+    };
+
+    state::save_governance_transfer(storage, &transfer)?;
+
+    Ok(transfer)
+}
+
+/// The caller must have been checked to be the proposed governance before calling this function
+pub fn accept_governance_transfer(
+    storage: &mut dyn Storage,
+    block_height: u64,
+) -> Result<GovernanceTransfer, ContractError> {
+    let transfer = state::may_load_governance_transfer(storage)?
+        .ok_or(ContractError::NoPendingGovernanceTransfer)?;
+
+    ensure!(
+        block_height < transfer.expires_at,
+        ContractError::GovernanceTransferExpired(transfer.expires_at)
+    );
+
+    permission_control::set_governance(storage, &transfer.new_governance)
+        .map_err(ContractError::from)?;
+    state::remove_governance_transfer(storage);
+
+    Ok(transfer)
+}
+
 #[cfg(test)]
 mod test {
     use std::collections::HashMap;
@@ -388,6 +871,96 @@ mod test {
         }
     }
 
+    #[test]
+    fn epoch_should_end_after_epoch_time_before_epoch_duration_has_passed() {
+        let pool_id = PoolId {
+            chain_name: "mock-chain".parse().unwrap(),
+            contract: MockApi::default().addr_make("some contract"),
+        };
+        let mut mock_deps = setup(1, 250, 100, pool_id.clone());
+        let storage = mock_deps.as_mut().storage;
+
+        let params = state::load_rewards_pool_params(storage, pool_id.clone())
+            .unwrap()
+            .params;
+        state::update_pool_params(
+            storage,
+            &pool_id,
+            &ParamsSnapshot {
+                params: Params {
+                    epoch_time: Some(60.try_into().unwrap()),
+                    ..params
+                },
+                created_at: Epoch {
+                    epoch_num: 1,
+                    block_height_started: 250,
+                },
+            },
+        )
+        .unwrap();
+
+        // the start of the epoch is recorded when the contract first sees it
+        end_epoch_after_epoch_time(storage, &pool_id, &block_at(260, 1000)).unwrap();
+        end_epoch_after_epoch_time(storage, &pool_id, &block_at(270, 1059)).unwrap();
+        assert_eq!(
+            state::current_epoch(storage, &pool_id, 270).unwrap(),
+            Epoch {
+                epoch_num: 1,
+                block_height_started: 250,
+            }
+        );
+
+        end_epoch_after_epoch_time(storage, &pool_id, &block_at(280, 1060)).unwrap();
+        assert_eq!(
+            state::current_epoch(storage, &pool_id, 280).unwrap(),
+            Epoch {
+                epoch_num: 2,
+                block_height_started: 280,
+            }
+        );
+        assert_eq!(
+            state::load_epoch_start_time(storage, pool_id.clone()).unwrap(),
+            Some(EpochStartTime {
+                epoch_num: 2,
+                started_at: Timestamp::from_seconds(1060),
+            })
+        );
+
+        // the epoch still ends after the epoch duration if that comes first
+        assert_eq!(
+            state::current_epoch(storage, &pool_id, 380).unwrap(),
+            Epoch {
+                epoch_num: 3,
+                block_height_started: 380,
+            }
+        );
+    }
+
+    #[test]
+    fn epoch_without_epoch_time_should_only_end_after_epoch_duration() {
+        let pool_id = PoolId {
+            chain_name: "mock-chain".parse().unwrap(),
+            contract: MockApi::default().addr_make("some contract"),
+        };
+        let mut mock_deps = setup(1, 250, 100, pool_id.clone());
+        let storage = mock_deps.as_mut().storage;
+
+        end_epoch_after_epoch_time(storage, &pool_id, &block_at(260, 1000)).unwrap();
+        end_epoch_after_epoch_time(storage, &pool_id, &block_at(270, 1_000_000)).unwrap();
+
+        assert_eq!(
+            state::current_epoch(storage, &pool_id, 270).unwrap(),
+            Epoch {
+                epoch_num: 1,
+                block_height_started: 250,
+            }
+        );
+        assert_eq!(
+            state::load_epoch_start_time(storage, pool_id).unwrap(),
+            None
+        );
+    }
+
     /// Tests that multiple participation events for the same pool within a given epoch are recorded correctly
     #[test]
     fn record_participation_multiple_events() {
@@ -556,6 +1129,9 @@ mod test {
             participation_threshold: (1, 2).try_into().unwrap(),
             epoch_duration: 100u64.try_into().unwrap(),
             rewards_per_epoch: 100u128.try_into().unwrap(),
+            distribution_cooldown: None,
+            remainder_policy: None,
+            epoch_time: None,
         };
         let mut mock_deps = setup_multiple_pools_with_params(
             cur_epoch_num,
@@ -630,6 +1206,9 @@ mod test {
                 .unwrap(),
             participation_threshold: (Uint64::new(2), Uint64::new(3)).try_into().unwrap(),
             epoch_duration: epoch_duration.try_into().unwrap(), // keep this the same to not affect epoch computation
+            distribution_cooldown: None,
+            remainder_policy: None,
+            epoch_time: None,
         };
 
         // the epoch shouldn't change when the params are updated, since we are not changing the epoch duration
@@ -646,6 +1225,7 @@ mod test {
             &pool_id,
             new_params.clone(),
             cur_height,
+            None,
         )
         .unwrap();
         let stored = state::load_rewards_pool(mock_deps.as_ref().storage, pool_id.clone())
@@ -710,6 +1290,7 @@ mod test {
             &pool_id,
             new_params.clone(),
             cur_height,
+            None,
         )
         .unwrap();
 
@@ -780,6 +1361,7 @@ mod test {
             &pool_id,
             new_params.clone(),
             cur_height,
+            None,
         )
         .unwrap();
 
@@ -843,6 +1425,7 @@ mod test {
             &pool_id,
             new_params.clone(),
             cur_height,
+            None,
         )
         .unwrap();
 
@@ -889,6 +1472,7 @@ mod test {
             mock_deps.as_mut().storage,
             pool_id.clone(),
             initial_amount.try_into().unwrap(),
+            &RewardsAsset::Native,
         )
         .unwrap();
 
@@ -900,6 +1484,7 @@ mod test {
             mock_deps.as_mut().storage,
             pool_id.clone(),
             added_amount.try_into().unwrap(),
+            &RewardsAsset::Native,
         )
         .unwrap();
 
@@ -955,9 +1540,14 @@ mod test {
                     epoch_duration: epoch_duration.try_into().unwrap(),
                     rewards_per_epoch: rewards_per_epoch.try_into().unwrap(),
                     participation_threshold: participation_threshold.try_into().unwrap(),
+                    distribution_cooldown: None,
+                    remainder_policy: None,
+                    epoch_time: None,
                 },
                 block_height_started,
                 pool_id.clone(),
+                RewardsAsset::Native,
+                None,
             )
             .unwrap();
 
@@ -966,6 +1556,7 @@ mod test {
                     mock_deps.as_mut().storage,
                     pool_id.clone(),
                     cosmwasm_std::Uint128::from(*amount).try_into().unwrap(),
+                    &RewardsAsset::Native,
                 )
                 .unwrap();
             }
@@ -985,18 +1576,187 @@ mod test {
         }
     }
 
-    /// Tests that pools can have different reward amounts
+    /// Tests that a pool created with aligned epochs adopts the current epoch of the anchor pool
     #[test]
-    fn multiple_pools_different_rewards_amount() {
-        let cur_epoch_num = 1u64;
+    fn create_pool_aligned_with_existing_pool() {
+        let cur_epoch_num = 3u64;
         let block_height_started = 250u64;
         let epoch_duration = 100u64;
+        let chain_name: ChainName = "mock-chain".parse().unwrap();
+        let anchor = PoolId {
+            chain_name: chain_name.clone(),
+            contract: MockApi::default().addr_make("voting_verifier"),
+        };
+        let mut mock_deps = setup(
+            cur_epoch_num,
+            block_height_started,
+            epoch_duration,
+            anchor.clone(),
+        );
 
-        let mut simulated_participation = HashMap::new();
-        simulated_participation.insert(
-            MockApi::default().addr_make("verifier-1"),
-            (
-                PoolId {
+        let params = |epoch_duration: u64| Params {
+            epoch_duration: epoch_duration.try_into().unwrap(),
+            rewards_per_epoch: 100u128.try_into().unwrap(),
+            participation_threshold: (1, 2).try_into().unwrap(),
+            distribution_cooldown: None,
+            remainder_policy: None,
+            epoch_time: None,
+        };
+        let block_height = block_height_started + epoch_duration / 2;
+
+        let pool_id = PoolId {
+            chain_name: "other-chain".parse().unwrap(),
+            contract: MockApi::default().addr_make("multisig"),
+        };
+        assert_eq!(
+            create_pool(
+                mock_deps.as_mut().storage,
+                params(epoch_duration),
+                block_height,
+                pool_id,
+                RewardsAsset::Native,
+                Some(anchor.clone()),
+            )
+            .unwrap_err()
+            .current_context(),
+            &ContractError::EpochAlignmentChainMismatch
+        );
+
+        let pool_id = PoolId {
+            chain_name,
+            contract: MockApi::default().addr_make("multisig"),
+        };
+        assert_eq!(
+            create_pool(
+                mock_deps.as_mut().storage,
+                params(epoch_duration * 2),
+                block_height,
+                pool_id.clone(),
+                RewardsAsset::Native,
+                Some(anchor.clone()),
+            )
+            .unwrap_err()
+            .current_context(),
+            &ContractError::EpochAlignmentDurationMismatch
+        );
+
+        create_pool(
+            mock_deps.as_mut().storage,
+            params(epoch_duration),
+            block_height,
+            pool_id.clone(),
+            RewardsAsset::Native,
+            Some(anchor.clone()),
+        )
+        .unwrap();
+
+        for block_height in [block_height, block_height + epoch_duration * 5] {
+            assert_eq!(
+                state::current_epoch(mock_deps.as_mut().storage, &pool_id, block_height).unwrap(),
+                state::current_epoch(mock_deps.as_mut().storage, &anchor, block_height).unwrap()
+            );
+        }
+        assert_eq!(
+            state::load_rewards_watermark(mock_deps.as_ref().storage, pool_id).unwrap(),
+            Some(cur_epoch_num - 1)
+        );
+    }
+
+    /// Tests that a pool can re-anchor its epochs to another pool after the epoch duration of that pool was updated
+    #[test]
+    fn update_pool_params_realigns_epochs_with_existing_pool() {
+        let block_height_started = 250u64;
+        let chain_name: ChainName = "mock-chain".parse().unwrap();
+        let anchor = PoolId {
+            chain_name: chain_name.clone(),
+            contract: MockApi::default().addr_make("voting_verifier"),
+        };
+        let pool_id = PoolId {
+            chain_name,
+            contract: MockApi::default().addr_make("multisig"),
+        };
+        let mut mock_deps = setup(3, block_height_started, 100, anchor.clone());
+
+        let params = |epoch_duration: u64| Params {
+            epoch_duration: epoch_duration.try_into().unwrap(),
+            rewards_per_epoch: 100u128.try_into().unwrap(),
+            participation_threshold: (1, 2).try_into().unwrap(),
+            distribution_cooldown: None,
+            remainder_policy: None,
+            epoch_time: None,
+        };
+
+        create_pool(
+            mock_deps.as_mut().storage,
+            params(100),
+            300,
+            pool_id.clone(),
+            RewardsAsset::Native,
+            Some(anchor.clone()),
+        )
+        .unwrap();
+
+        // shortening the epochs of the anchor ends its current epoch, so the pools drift apart
+        update_pool_params(mock_deps.as_mut().storage, &anchor, params(50), 320, None).unwrap();
+        assert_ne!(
+            state::current_epoch(mock_deps.as_mut().storage, &pool_id, 330).unwrap(),
+            state::current_epoch(mock_deps.as_mut().storage, &anchor, 330).unwrap()
+        );
+
+        assert_eq!(
+            update_pool_params(
+                mock_deps.as_mut().storage,
+                &pool_id,
+                params(100),
+                330,
+                Some(anchor.clone()),
+            )
+            .unwrap_err()
+            .current_context(),
+            &ContractError::EpochAlignmentDurationMismatch
+        );
+        assert_eq!(
+            update_pool_params(
+                mock_deps.as_mut().storage,
+                &anchor,
+                params(100),
+                330,
+                Some(pool_id.clone()),
+            )
+            .unwrap_err()
+            .current_context(),
+            &ContractError::EpochAlignmentEpochBehind
+        );
+
+        update_pool_params(
+            mock_deps.as_mut().storage,
+            &pool_id,
+            params(50),
+            330,
+            Some(anchor.clone()),
+        )
+        .unwrap();
+
+        for block_height in [330, 330 + 50 * 7 + 10] {
+            assert_eq!(
+                state::current_epoch(mock_deps.as_mut().storage, &pool_id, block_height).unwrap(),
+                state::current_epoch(mock_deps.as_mut().storage, &anchor, block_height).unwrap()
+            );
+        }
+    }
+
+    /// Tests that pools can have different reward amounts
+    #[test]
+    fn multiple_pools_different_rewards_amount() {
+        let cur_epoch_num = 1u64;
+        let block_height_started = 250u64;
+        let epoch_duration = 100u64;
+
+        let mut simulated_participation = HashMap::new();
+        simulated_participation.insert(
+            MockApi::default().addr_make("verifier-1"),
+            (
+                PoolId {
                     chain_name: "mock-chain".parse().unwrap(),
                     contract: MockApi::default().addr_make("contract-1"),
                 },
@@ -1027,6 +1787,9 @@ mod test {
             participation_threshold: (1, 2).try_into().unwrap(),
             epoch_duration: 100u64.try_into().unwrap(),
             rewards_per_epoch: 100u128.try_into().unwrap(), // this is overwritten below
+            distribution_cooldown: None,
+            remainder_policy: None,
+            epoch_time: None,
         };
         let rewards_per_epoch = vec![50u128, 100u128, 200u128];
         let pool_params: Vec<(PoolId, Params)> = simulated_participation
@@ -1064,6 +1827,7 @@ mod test {
                 mock_deps.as_mut().storage,
                 pool_id.clone(),
                 Uint128::from(rewards_to_add).try_into().unwrap(),
+                &RewardsAsset::Native,
             );
 
             let distribution = distribute_rewards(
@@ -1114,6 +1878,9 @@ mod test {
             participation_threshold: (1, 2).try_into().unwrap(), // this is overwritten below
             epoch_duration: 100u64.try_into().unwrap(),
             rewards_per_epoch: 100u128.try_into().unwrap(),
+            distribution_cooldown: None,
+            remainder_policy: None,
+            epoch_time: None,
         };
         // the first pool has a 2/3 threshold, the second 3/4 threshold
         let participation_thresholds = vec![(2, 3), (3, 4)];
@@ -1148,7 +1915,12 @@ mod test {
 
         for (pool_id, params) in pool_params {
             let rewards_to_add = params.rewards_per_epoch;
-            let _ = add_rewards(mock_deps.as_mut().storage, pool_id.clone(), rewards_to_add);
+            let _ = add_rewards(
+                mock_deps.as_mut().storage,
+                pool_id.clone(),
+                rewards_to_add,
+                &RewardsAsset::Native,
+            );
 
             let distribution = distribute_rewards(
                 mock_deps.as_mut().storage,
@@ -1209,6 +1981,9 @@ mod test {
             participation_threshold: (1, 2).try_into().unwrap(),
             epoch_duration: 100u64.try_into().unwrap(), // this is overwritten below
             rewards_per_epoch: 100u128.try_into().unwrap(),
+            distribution_cooldown: None,
+            remainder_policy: None,
+            epoch_time: None,
         };
         // one pool has twice the epoch duration as the other
         let epoch_durations = vec![base_epoch_duration, base_epoch_duration * 2];
@@ -1243,7 +2018,13 @@ mod test {
 
         for (pool_id, params) in pool_params {
             let rewards_to_add = params.rewards_per_epoch;
-            add_rewards(mock_deps.as_mut().storage, pool_id.clone(), rewards_to_add).unwrap();
+            add_rewards(
+                mock_deps.as_mut().storage,
+                pool_id.clone(),
+                rewards_to_add,
+                &RewardsAsset::Native,
+            )
+            .unwrap();
 
             let distribution = distribute_rewards(
                 mock_deps.as_mut().storage,
@@ -1261,6 +2042,214 @@ mod test {
         }
     }
 
+    /// Tests that the remainder of uneven splits is carried on or burned, and is not paid out twice
+    #[test]
+    fn distribute_rewards_applies_remainder_policy() {
+        let epoch_duration = 100u64;
+        let pool_id = PoolId {
+            chain_name: "mock-chain".parse().unwrap(),
+            contract: MockApi::default().addr_make("pool_contract"),
+        };
+        let verifiers: Vec<_> = (1..=3)
+            .map(|i| MockApi::default().addr_make(&format!("verifier{}", i)))
+            .collect();
+
+        for (policy, expected_carried, expected_burned) in [
+            (RemainderPolicy::Carry, 2u128, 0u128),
+            (RemainderPolicy::Burn, 0u128, 2u128),
+        ] {
+            let params = Params {
+                epoch_duration: epoch_duration.try_into().unwrap(),
+                rewards_per_epoch: 100u128.try_into().unwrap(),
+                participation_threshold: (1, 2).try_into().unwrap(),
+                distribution_cooldown: None,
+                remainder_policy: Some(policy),
+                epoch_time: None,
+            };
+            let mut mock_deps =
+                setup_multiple_pools_with_params(0, 0, vec![(pool_id.clone(), params)]);
+
+            // all verifiers participate in epochs 0 and 1, so each epoch's rewards are split three ways
+            for epoch_num in 0..2u64 {
+                for verifier in &verifiers {
+                    record_participation(
+                        mock_deps.as_mut().storage,
+                        format!("event{}", epoch_num).try_into().unwrap(),
+                        verifier.clone(),
+                        pool_id.clone(),
+                        epoch_num * epoch_duration,
+                    )
+                    .unwrap();
+                }
+            }
+
+            add_rewards(
+                mock_deps.as_mut().storage,
+                pool_id.clone(),
+                300u128.try_into().unwrap(),
+                &RewardsAsset::Native,
+            )
+            .unwrap();
+
+            let distribution = distribute_rewards(
+                mock_deps.as_mut().storage,
+                pool_id.clone(),
+                3 * epoch_duration,
+                None,
+            )
+            .unwrap();
+
+            // epoch 0 splits 100 into 3 * 33, epoch 1 splits 100 plus any carried remainder of 1 into 3 * 33
+            assert_eq!(distribution.epochs_processed, vec![0, 1]);
+            assert!(distribution
+                .rewards
+                .values()
+                .all(|amount| *amount == Uint128::from(66u128)));
+            assert_eq!(distribution.remainder_policy, Some(policy));
+            assert_eq!(
+                distribution.carried_remainder,
+                Uint128::from(expected_carried)
+            );
+            assert_eq!(distribution.burned, Uint128::from(expected_burned));
+            assert_eq!(
+                distribution.remaining_balance,
+                Uint128::from(300u128 - 198 - expected_burned)
+            );
+
+            // the carried remainder is reserved, so it can't be paid out as the rewards of other epochs
+            let pool =
+                state::load_rewards_pool(mock_deps.as_ref().storage, pool_id.clone()).unwrap();
+            assert_eq!(pool.carried_remainder, Uint128::from(expected_carried));
+            assert_eq!(pool.reserved, Uint128::from(expected_carried));
+            assert_eq!(pool.free_balance(), Uint128::from(100u128));
+        }
+    }
+
+    /// Tests that opted out verifiers are skipped, their share is carried on according to the remainder policy,
+    /// and that they receive rewards again after opting back in
+    #[test]
+    fn distribute_rewards_skips_opted_out_verifiers() {
+        let epoch_duration = 100u64;
+        let pool_id = PoolId {
+            chain_name: "mock-chain".parse().unwrap(),
+            contract: MockApi::default().addr_make("pool_contract"),
+        };
+        let verifier1 = MockApi::default().addr_make("verifier1");
+        let verifier2 = MockApi::default().addr_make("verifier2");
+        let params = Params {
+            epoch_duration: epoch_duration.try_into().unwrap(),
+            rewards_per_epoch: 100u128.try_into().unwrap(),
+            participation_threshold: (1, 2).try_into().unwrap(),
+            distribution_cooldown: None,
+            remainder_policy: Some(RemainderPolicy::Carry),
+            epoch_time: None,
+        };
+        let mut mock_deps = setup_multiple_pools_with_params(0, 0, vec![(pool_id.clone(), params)]);
+
+        add_rewards(
+            mock_deps.as_mut().storage,
+            pool_id.clone(),
+            300u128.try_into().unwrap(),
+            &RewardsAsset::Native,
+        )
+        .unwrap();
+
+        let record_epoch = |storage: &mut dyn Storage, epoch_num: u64| {
+            for verifier in [&verifier1, &verifier2] {
+                record_participation(
+                    storage,
+                    format!("event{}", epoch_num).try_into().unwrap(),
+                    verifier.clone(),
+                    pool_id.clone(),
+                    epoch_num * epoch_duration,
+                )
+                .unwrap();
+            }
+        };
+
+        record_epoch(mock_deps.as_mut().storage, 0);
+        opt_out_of_rewards(
+            mock_deps.as_mut().storage,
+            pool_id.clone(),
+            verifier2.clone(),
+        )
+        .unwrap();
+
+        let distribution = distribute_rewards(
+            mock_deps.as_mut().storage,
+            pool_id.clone(),
+            3 * epoch_duration,
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            distribution.rewards,
+            HashMap::from([(
+                make_verifier_with_no_proxy(&verifier1),
+                Uint128::from(50u128)
+            )])
+        );
+        assert_eq!(distribution.carried_remainder, Uint128::from(50u128));
+
+        record_epoch(mock_deps.as_mut().storage, 2);
+        opt_into_rewards(
+            mock_deps.as_mut().storage,
+            pool_id.clone(),
+            verifier2.clone(),
+        )
+        .unwrap();
+
+        // the carried share of the opted out verifier is split amongst all rewarded verifiers of the next epoch
+        let distribution = distribute_rewards(
+            mock_deps.as_mut().storage,
+            pool_id.clone(),
+            5 * epoch_duration,
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            distribution.rewards,
+            HashMap::from([
+                (
+                    make_verifier_with_no_proxy(&verifier1),
+                    Uint128::from(75u128)
+                ),
+                (
+                    make_verifier_with_no_proxy(&verifier2),
+                    Uint128::from(75u128)
+                ),
+            ])
+        );
+        assert_eq!(distribution.carried_remainder, Uint128::zero());
+        assert_eq!(distribution.remaining_balance, Uint128::from(100u128));
+    }
+
+    #[test]
+    fn cannot_opt_out_of_rewards_of_unknown_pool() {
+        let pool_id = PoolId {
+            chain_name: "mock-chain".parse().unwrap(),
+            contract: MockApi::default().addr_make("pool_contract"),
+        };
+        let mut mock_deps = setup(0, 0, 100, pool_id);
+
+        let unknown_pool = PoolId {
+            chain_name: "mock-chain".parse().unwrap(),
+            contract: MockApi::default().addr_make("unknown_contract"),
+        };
+        let verifier = MockApi::default().addr_make("verifier");
+
+        let err = opt_out_of_rewards(
+            mock_deps.as_mut().storage,
+            unknown_pool.clone(),
+            verifier.clone(),
+        )
+        .unwrap_err();
+        assert_eq!(err.current_context(), &ContractError::RewardsPoolNotFound);
+
+        let err = opt_into_rewards(mock_deps.as_mut().storage, unknown_pool, verifier).unwrap_err();
+        assert_eq!(err.current_context(), &ContractError::RewardsPoolNotFound);
+    }
+
     /// Tests that rewards are distributed correctly based on participation
     #[test]
     fn successfully_distribute_rewards() {
@@ -1338,6 +2327,7 @@ mod test {
             mock_deps.as_mut().storage,
             pool_id.clone(),
             Uint128::from(rewards_added).try_into().unwrap(),
+            &RewardsAsset::Native,
         );
 
         let distribution = distribute_rewards(
@@ -1407,6 +2397,7 @@ mod test {
             mock_deps.as_mut().storage,
             pool_id.clone(),
             Uint128::from(rewards_added).try_into().unwrap(),
+            &RewardsAsset::Native,
         );
 
         // this puts us in epoch 9
@@ -1508,6 +2499,7 @@ mod test {
             mock_deps.as_mut().storage,
             pool_id.clone(),
             Uint128::from(rewards_added).try_into().unwrap(),
+            &RewardsAsset::Native,
         );
 
         // too early, still in the same epoch
@@ -1594,6 +2586,7 @@ mod test {
             mock_deps.as_mut().storage,
             pool_id.clone(),
             Uint128::from(rewards_added).try_into().unwrap(),
+            &RewardsAsset::Native,
         );
 
         let err = distribute_rewards(
@@ -1613,6 +2606,7 @@ mod test {
             mock_deps.as_mut().storage,
             pool_id.clone(),
             Uint128::from(rewards_added).try_into().unwrap(),
+            &RewardsAsset::Native,
         );
 
         let distribution = distribute_rewards(
@@ -1661,6 +2655,7 @@ mod test {
             mock_deps.as_mut().storage,
             pool_id.clone(),
             Uint128::from(rewards_added).try_into().unwrap(),
+            &RewardsAsset::Native,
         );
 
         let distribution = distribute_rewards(
@@ -1685,41 +2680,201 @@ mod test {
     }
 
     #[test]
-    fn cannot_record_participation_before_pool_is_created() {
-        let cur_epoch_num = 1u64;
-        let block_height_started = 250u64;
-        let mut mock_deps =
-            setup_multiple_pools_with_params(cur_epoch_num, block_height_started, vec![]);
+    fn distribute_rewards_during_cooldown_fails() {
+        let block_height_started = 0u64;
+        let epoch_duration = 1000u64;
+        let cooldown = 1500u64;
+        let pool_id = PoolId {
+            chain_name: "mock-chain".parse().unwrap(),
+            contract: MockApi::default().addr_make("pool_contract"),
+        };
 
-        assert!(record_participation(
+        let mut mock_deps = setup(0, block_height_started, epoch_duration, pool_id.clone());
+        let params = state::load_rewards_pool_params(mock_deps.as_ref().storage, pool_id.clone())
+            .unwrap()
+            .params;
+        update_pool_params(
             mock_deps.as_mut().storage,
-            "some-event".parse().unwrap(),
-            MockApi::default().addr_make("verifier"),
-            PoolId {
-                chain_name: "mock-chain".parse().unwrap(),
-                contract: MockApi::default().addr_make("contract")
+            &pool_id,
+            Params {
+                distribution_cooldown: Some(cooldown.try_into().unwrap()),
+                ..params
             },
-            block_height_started
+            block_height_started,
+            None,
         )
-        .is_err());
-    }
+        .unwrap();
 
-    #[test]
-    fn cannot_add_rewards_before_pool_is_created() {
-        let cur_epoch_num = 1u64;
-        let block_height_started = 250u64;
-        let mut mock_deps =
-            setup_multiple_pools_with_params(cur_epoch_num, block_height_started, vec![]);
-        assert!(add_rewards(
+        let verifier = MockApi::default().addr_make("verifier");
+        for epoch_num in 0..2 {
+            record_participation(
+                mock_deps.as_mut().storage,
+                format!("event-{}", epoch_num).try_into().unwrap(),
+                verifier.clone(),
+                pool_id.clone(),
+                block_height_started + epoch_duration * epoch_num,
+            )
+            .unwrap();
+        }
+        add_rewards(
             mock_deps.as_mut().storage,
-            PoolId {
-                chain_name: "mock-chain".parse().unwrap(),
-                contract: MockApi::default().addr_make("contract")
-            },
-            100u128.try_into().unwrap(),
+            pool_id.clone(),
+            Uint128::from(1000u128).try_into().unwrap(),
+            &RewardsAsset::Native,
         )
-        .is_err());
-    }
+        .unwrap();
+
+        let distribution_height = block_height_started + epoch_duration * 2;
+        let distribution = distribute_rewards(
+            mock_deps.as_mut().storage,
+            pool_id.clone(),
+            distribution_height,
+            None,
+        )
+        .unwrap();
+        assert_eq!(distribution.epochs_processed, vec![0]);
+
+        // epoch 1 becomes distributable before the cooldown has passed
+        let earliest_allowed_height = distribution_height + cooldown;
+        let err = distribute_rewards(
+            mock_deps.as_mut().storage,
+            pool_id.clone(),
+            earliest_allowed_height - 1,
+            None,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.current_context(),
+            &ContractError::DistributionCooldown(earliest_allowed_height)
+        );
+
+        let distribution = distribute_rewards(
+            mock_deps.as_mut().storage,
+            pool_id,
+            earliest_allowed_height,
+            None,
+        )
+        .unwrap();
+        assert_eq!(distribution.epochs_processed, vec![1]);
+    }
+
+    #[test]
+    fn distribute_rewards_in_batches_is_not_blocked_by_cooldown() {
+        let block_height_started = 0u64;
+        let epoch_duration = 1000u64;
+        let cooldown = 1500u64;
+        let pool_id = PoolId {
+            chain_name: "mock-chain".parse().unwrap(),
+            contract: MockApi::default().addr_make("pool_contract"),
+        };
+
+        let mut mock_deps = setup(0, block_height_started, epoch_duration, pool_id.clone());
+        let params = state::load_rewards_pool_params(mock_deps.as_ref().storage, pool_id.clone())
+            .unwrap()
+            .params;
+        update_pool_params(
+            mock_deps.as_mut().storage,
+            &pool_id,
+            Params {
+                distribution_cooldown: Some(cooldown.try_into().unwrap()),
+                ..params
+            },
+            block_height_started,
+        )
+        .unwrap();
+
+        let verifier = MockApi::default().addr_make("verifier");
+        for epoch_num in 0..4 {
+            record_participation(
+                mock_deps.as_mut().storage,
+                format!("event-{}", epoch_num).try_into().unwrap(),
+                verifier.clone(),
+                pool_id.clone(),
+                block_height_started + epoch_duration * epoch_num,
+            )
+            .unwrap();
+        }
+        add_rewards(
+            mock_deps.as_mut().storage,
+            pool_id.clone(),
+            Uint128::from(1000u128).try_into().unwrap(),
+        )
+        .unwrap();
+
+        // epochs 0 to 3 are distributable, but only two are processed per call
+        let distribution_height = block_height_started + epoch_duration * 5;
+        let distribution = distribute_rewards(
+            mock_deps.as_mut().storage,
+            pool_id.clone(),
+            distribution_height,
+            Some(2),
+        )
+        .unwrap();
+        assert_eq!(distribution.epochs_processed, vec![0, 1]);
+        assert!(distribution.can_distribute_more);
+
+        let distribution = distribute_rewards(
+            mock_deps.as_mut().storage,
+            pool_id.clone(),
+            distribution_height,
+            Some(2),
+        )
+        .unwrap();
+        assert_eq!(distribution.epochs_processed, vec![2, 3]);
+        assert!(!distribution.can_distribute_more);
+
+        // the pool has caught up, so the cooldown starts now
+        let err = distribute_rewards(
+            mock_deps.as_mut().storage,
+            pool_id,
+            distribution_height + epoch_duration,
+            None,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.current_context(),
+            &ContractError::DistributionCooldown(distribution_height + cooldown)
+        );
+    }
+
+    #[test]
+    fn cannot_record_participation_before_pool_is_created() {
+        let cur_epoch_num = 1u64;
+        let block_height_started = 250u64;
+        let mut mock_deps =
+            setup_multiple_pools_with_params(cur_epoch_num, block_height_started, vec![]);
+
+        assert!(record_participation(
+            mock_deps.as_mut().storage,
+            "some-event".parse().unwrap(),
+            MockApi::default().addr_make("verifier"),
+            PoolId {
+                chain_name: "mock-chain".parse().unwrap(),
+                contract: MockApi::default().addr_make("contract")
+            },
+            block_height_started
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn cannot_add_rewards_before_pool_is_created() {
+        let cur_epoch_num = 1u64;
+        let block_height_started = 250u64;
+        let mut mock_deps =
+            setup_multiple_pools_with_params(cur_epoch_num, block_height_started, vec![]);
+        let err = add_rewards(
+            mock_deps.as_mut().storage,
+            PoolId {
+                chain_name: "mock-chain".parse().unwrap(),
+                contract: MockApi::default().addr_make("contract"),
+            },
+            100u128.try_into().unwrap(),
+            &RewardsAsset::Native,
+        )
+        .unwrap_err();
+        assert_eq!(err.current_context(), &ContractError::RewardsPoolNotFound);
+    }
 
     #[test]
     fn cannot_distribute_rewards_before_pool_is_created() {
@@ -1783,6 +2938,7 @@ mod test {
             Uint128::from(rewards_per_epoch * epoch_count as u128)
                 .try_into()
                 .unwrap(),
+            &RewardsAsset::Native,
         )
         .unwrap();
 
@@ -1845,7 +3001,7 @@ mod test {
         assert!(distribution.can_distribute_more);
 
         // remove the proxy address and distribute the final epochs worth of rewards
-        remove_verifier_proxy(mock_deps.as_mut().storage, &verifier);
+        remove_verifier_proxy(mock_deps.as_mut().storage, &verifier).unwrap();
 
         let distribution = distribute_rewards(
             mock_deps.as_mut().storage,
@@ -1872,6 +3028,377 @@ mod test {
         assert!(!distribution.can_distribute_more);
     }
 
+    /// Tests that tallies and events are pruned oldest first, only below the retention window and within the entry limit
+    #[test]
+    fn prune_epochs_removes_epochs_older_than_retention_window() {
+        let epoch_duration = 100u64;
+        let pool_id = PoolId {
+            chain_name: "mock-chain".parse().unwrap(),
+            contract: MockApi::default().addr_make("pool_contract"),
+        };
+        let mut mock_deps = setup(0, 0, epoch_duration, pool_id.clone());
+        CONFIG
+            .save(
+                mock_deps.as_mut().storage,
+                &Config {
+                    rewards_denom: "AXL".to_string(),
+                    epoch_retention: 2,
+                    track_event_attribution: false,
+                    reserve_epoch_rewards: false,
+                    attestation_target: None,
+                },
+            )
+            .unwrap();
+
+        let verifier = MockApi::default().addr_make("verifier");
+        for epoch in 0..7u64 {
+            for event in 0..3 {
+                record_participation(
+                    mock_deps.as_mut().storage,
+                    format!("{}-{}", epoch, event).try_into().unwrap(),
+                    verifier.clone(),
+                    pool_id.clone(),
+                    epoch * epoch_duration,
+                )
+                .unwrap();
+            }
+        }
+        state::save_rewards_watermark(mock_deps.as_mut().storage, pool_id.clone(), 5).unwrap();
+
+        // epochs 0 to 2 are prunable, the limit only allows removing epoch 0 and one event of epoch 1
+        let pruned = prune_epochs(mock_deps.as_mut().storage, pool_id.clone(), Some(5)).unwrap();
+        assert_eq!(pruned.epochs, Some((0, 1)));
+        assert_eq!(pruned.tallies_pruned, 1);
+        assert_eq!(pruned.events_pruned, 4);
+        assert!(pruned.can_prune_more);
+
+        let pruned = prune_epochs(mock_deps.as_mut().storage, pool_id.clone(), None).unwrap();
+        assert_eq!(pruned.epochs, Some((1, 2)));
+        assert_eq!(pruned.tallies_pruned, 2);
+        assert_eq!(pruned.events_pruned, 5);
+        assert!(!pruned.can_prune_more);
+
+        for epoch in 0..7u64 {
+            let tally = state::load_epoch_tally(mock_deps.as_ref().storage, pool_id.clone(), epoch)
+                .unwrap();
+            let event = state::load_event(
+                mock_deps.as_ref().storage,
+                format!("{}-0", epoch),
+                pool_id.clone(),
+            )
+            .unwrap();
+
+            assert_eq!(tally.is_some(), epoch >= 3);
+            assert_eq!(event.is_some(), epoch >= 3);
+        }
+
+        let pruned = prune_epochs(mock_deps.as_mut().storage, pool_id, None).unwrap();
+        assert_eq!(pruned.epochs, None);
+        assert_eq!(pruned.tallies_pruned, 0);
+        assert_eq!(pruned.events_pruned, 0);
+    }
+
+    /// Tests that the events a verifier was credited for are only attributed if tracking is enabled, and are pruned with their epoch
+    #[test]
+    fn record_participation_with_event_attribution() {
+        let epoch_duration = 100u64;
+        let pool_id = PoolId {
+            chain_name: "mock-chain".parse().unwrap(),
+            contract: MockApi::default().addr_make("pool_contract"),
+        };
+        let mut mock_deps = setup(0, 0, epoch_duration, pool_id.clone());
+        let verifier = MockApi::default().addr_make("verifier");
+        let other_verifier = MockApi::default().addr_make("other_verifier");
+
+        record_participation(
+            mock_deps.as_mut().storage,
+            "untracked".try_into().unwrap(),
+            verifier.clone(),
+            pool_id.clone(),
+            0,
+        )
+        .unwrap();
+
+        CONFIG
+            .save(
+                mock_deps.as_mut().storage,
+                &Config {
+                    rewards_denom: "AXL".to_string(),
+                    epoch_retention: 0,
+                    track_event_attribution: true,
+                    reserve_epoch_rewards: false,
+                    attestation_target: None,
+                },
+            )
+            .unwrap();
+
+        for (event_id, verifier, height) in [
+            ("event-1", &verifier, 0),
+            ("event-2", &verifier, 1),
+            ("event-2", &other_verifier, 1),
+            ("event-3", &other_verifier, 2),
+            ("event-4", &verifier, epoch_duration),
+        ] {
+            record_participation(
+                mock_deps.as_mut().storage,
+                event_id.try_into().unwrap(),
+                verifier.clone(),
+                pool_id.clone(),
+                height,
+            )
+            .unwrap();
+        }
+
+        let attributed_event_ids = |storage: &dyn Storage, verifier: &Addr, epoch_num: u64| {
+            state::load_attributed_event_ids(
+                storage,
+                pool_id.clone(),
+                epoch_num,
+                verifier.clone(),
+                None,
+                usize::MAX,
+            )
+            .unwrap()
+        };
+
+        assert_eq!(
+            attributed_event_ids(mock_deps.as_ref().storage, &verifier, 0),
+            vec!["event-1".to_string(), "event-2".to_string()]
+        );
+        assert_eq!(
+            attributed_event_ids(mock_deps.as_ref().storage, &other_verifier, 0),
+            vec!["event-2".to_string(), "event-3".to_string()]
+        );
+        assert_eq!(
+            attributed_event_ids(mock_deps.as_ref().storage, &verifier, 1),
+            vec!["event-4".to_string()]
+        );
+
+        state::save_rewards_watermark(mock_deps.as_mut().storage, pool_id.clone(), 1).unwrap();
+        let pruned = prune_epochs(mock_deps.as_mut().storage, pool_id.clone(), None).unwrap();
+        assert_eq!(pruned.epochs, Some((0, 0)));
+        assert_eq!(pruned.events_pruned, 4);
+        assert_eq!(pruned.attributions_pruned, 4);
+
+        assert!(attributed_event_ids(mock_deps.as_ref().storage, &verifier, 0).is_empty());
+        assert!(attributed_event_ids(mock_deps.as_ref().storage, &other_verifier, 0).is_empty());
+        assert_eq!(
+            attributed_event_ids(mock_deps.as_ref().storage, &verifier, 1),
+            vec!["event-4".to_string()]
+        );
+    }
+
+    /// Tests that the rewards of an epoch are reserved when its first participation is recorded, and released when they are distributed
+    #[test]
+    fn record_participation_reserves_epoch_rewards() {
+        let epoch_duration = 100u64;
+        let pool_id = PoolId {
+            chain_name: "mock-chain".parse().unwrap(),
+            contract: MockApi::default().addr_make("pool_contract"),
+        };
+        let mut mock_deps = setup(0, 0, epoch_duration, pool_id.clone());
+        enable_epoch_rewards_reservation(mock_deps.as_mut().storage);
+        let verifier = MockApi::default().addr_make("verifier");
+
+        add_rewards(
+            mock_deps.as_mut().storage,
+            pool_id.clone(),
+            Uint128::from(150u128).try_into().unwrap(),
+            &RewardsAsset::Native,
+        )
+        .unwrap();
+
+        for (event_id, height) in [("event-1", 0), ("event-2", 1)] {
+            let under_reserved = record_participation(
+                mock_deps.as_mut().storage,
+                event_id.try_into().unwrap(),
+                verifier.clone(),
+                pool_id.clone(),
+                height,
+            )
+            .unwrap();
+            assert!(under_reserved.is_none());
+        }
+
+        let pool = state::load_rewards_pool(mock_deps.as_ref().storage, pool_id.clone()).unwrap();
+        assert_eq!(pool.reserved, Uint128::from(100u128));
+        assert_eq!(pool.free_balance(), Uint128::from(50u128));
+
+        // the free balance can't cover the next epoch, but participation is recorded nonetheless
+        let under_reserved = record_participation(
+            mock_deps.as_mut().storage,
+            "event-3".try_into().unwrap(),
+            verifier.clone(),
+            pool_id.clone(),
+            epoch_duration,
+        )
+        .unwrap();
+        assert!(matches!(
+            under_reserved,
+            Some(events::Event::EpochUnderReserved { epoch_num: 1, reserved, .. })
+                if reserved == Uint128::from(50u128)
+        ));
+
+        let tally = state::load_epoch_tally(mock_deps.as_ref().storage, pool_id.clone(), 1)
+            .unwrap()
+            .unwrap();
+        assert!(tally.under_reserved);
+        assert_eq!(tally.verifier_participation().len(), 1);
+
+        let pool = state::load_rewards_pool(mock_deps.as_ref().storage, pool_id.clone()).unwrap();
+        assert_eq!(pool.reserved, Uint128::from(150u128));
+        assert_eq!(pool.free_balance(), Uint128::zero());
+
+        // the missing rewards of the under-reserved epoch are settled from the pool balance at distribution
+        add_rewards(
+            mock_deps.as_mut().storage,
+            pool_id.clone(),
+            Uint128::from(50u128).try_into().unwrap(),
+            &RewardsAsset::Native,
+        )
+        .unwrap();
+
+        let distribution = distribute_rewards(
+            mock_deps.as_mut().storage,
+            pool_id.clone(),
+            epoch_duration * 3,
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            distribution
+                .rewards
+                .get(&make_verifier_with_no_proxy(&verifier)),
+            Some(&Uint128::from(200u128))
+        );
+
+        let pool = state::load_rewards_pool(mock_deps.as_ref().storage, pool_id).unwrap();
+        assert_eq!(pool.balance, Uint128::zero());
+        assert_eq!(pool.reserved, Uint128::zero());
+    }
+
+    /// Tests that participation is recorded for a pool without any funds
+    #[test]
+    fn record_participation_succeeds_for_unfunded_pool() {
+        let pool_id = PoolId {
+            chain_name: "mock-chain".parse().unwrap(),
+            contract: MockApi::default().addr_make("pool_contract"),
+        };
+        let mut mock_deps = setup(0, 0, 100, pool_id.clone());
+        enable_epoch_rewards_reservation(mock_deps.as_mut().storage);
+
+        let under_reserved = record_participation(
+            mock_deps.as_mut().storage,
+            "event".try_into().unwrap(),
+            MockApi::default().addr_make("verifier"),
+            pool_id.clone(),
+            0,
+        )
+        .unwrap();
+        assert!(matches!(
+            under_reserved,
+            Some(events::Event::EpochUnderReserved { reserved, .. }) if reserved.is_zero()
+        ));
+
+        let pool = state::load_rewards_pool(mock_deps.as_ref().storage, pool_id).unwrap();
+        assert_eq!(pool.reserved, Uint128::zero());
+    }
+
+    /// Tests that updating the pool params adjusts the reservation of the current epoch to the new rewards per epoch
+    #[test]
+    fn update_pool_params_adjusts_epoch_rewards_reservation() {
+        let pool_id = PoolId {
+            chain_name: "mock-chain".parse().unwrap(),
+            contract: MockApi::default().addr_make("pool_contract"),
+        };
+        let mut mock_deps = setup(0, 0, 100, pool_id.clone());
+        enable_epoch_rewards_reservation(mock_deps.as_mut().storage);
+
+        add_rewards(
+            mock_deps.as_mut().storage,
+            pool_id.clone(),
+            Uint128::from(150u128).try_into().unwrap(),
+            &RewardsAsset::Native,
+        )
+        .unwrap();
+        record_participation(
+            mock_deps.as_mut().storage,
+            "event".try_into().unwrap(),
+            MockApi::default().addr_make("verifier"),
+            pool_id.clone(),
+            0,
+        )
+        .unwrap();
+
+        let params_with_rewards = |rewards_per_epoch: u128| Params {
+            participation_threshold: (1, 2).try_into().unwrap(),
+            epoch_duration: 100u64.try_into().unwrap(),
+            rewards_per_epoch: Uint128::from(rewards_per_epoch).try_into().unwrap(),
+            distribution_cooldown: None,
+            remainder_policy: None,
+            epoch_time: None,
+        };
+
+        let err = update_pool_params(
+            mock_deps.as_mut().storage,
+            &pool_id,
+            params_with_rewards(200),
+            1,
+            None,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.current_context(),
+            &ContractError::FreeBalanceInsufficient
+        );
+
+        update_pool_params(
+            mock_deps.as_mut().storage,
+            &pool_id,
+            params_with_rewards(150),
+            1,
+            None,
+        )
+        .unwrap();
+
+        let pool = state::load_rewards_pool(mock_deps.as_ref().storage, pool_id.clone()).unwrap();
+        assert_eq!(pool.reserved, Uint128::from(150u128));
+        let tally = state::load_epoch_tally(mock_deps.as_ref().storage, pool_id, 0)
+            .unwrap()
+            .unwrap();
+        assert_eq!(tally.reserved, Uint128::from(150u128));
+    }
+
+    /// Tests that nothing is pruned before rewards have been distributed for the pool
+    #[test]
+    fn prune_epochs_without_distribution_does_nothing() {
+        let pool_id = PoolId {
+            chain_name: "mock-chain".parse().unwrap(),
+            contract: MockApi::default().addr_make("pool_contract"),
+        };
+        let mut mock_deps = setup(0, 0, 100, pool_id.clone());
+
+        for epoch in 0..20u64 {
+            record_participation(
+                mock_deps.as_mut().storage,
+                epoch.to_string().try_into().unwrap(),
+                MockApi::default().addr_make("verifier"),
+                pool_id.clone(),
+                epoch * 100,
+            )
+            .unwrap();
+        }
+
+        let pruned = prune_epochs(mock_deps.as_mut().storage, pool_id.clone(), None).unwrap();
+        assert_eq!(pruned.epochs, None);
+        assert!(!pruned.can_prune_more);
+        assert!(
+            state::load_epoch_tally(mock_deps.as_ref().storage, pool_id, 0)
+                .unwrap()
+                .is_some()
+        );
+    }
+
     fn make_verifier_with_no_proxy(addr: &Addr) -> Verifier {
         Verifier {
             verifier_address: addr.to_owned(),
@@ -1888,6 +3415,19 @@ mod test {
 
     type MockDeps = OwnedDeps<MockStorage, MockApi, MockQuerier>;
 
+    fn enable_epoch_rewards_reservation(storage: &mut dyn Storage) {
+        let config = state::load_config(storage).unwrap();
+        CONFIG
+            .save(
+                storage,
+                &Config {
+                    reserve_epoch_rewards: true,
+                    ..config
+                },
+            )
+            .unwrap();
+    }
+
     fn setup_multiple_pools_with_params(
         cur_epoch_num: u64,
         block_height_started: u64,
@@ -1912,6 +3452,9 @@ mod test {
                     id: pool_id,
                     params: params_snapshot,
                     balance: Uint128::zero(),
+                    asset: RewardsAsset::Native,
+                    reserved: Uint128::zero(),
+                    carried_remainder: Uint128::zero(),
                 },
             )
             .unwrap();
@@ -1919,6 +3462,10 @@ mod test {
 
         let config = Config {
             rewards_denom: "AXL".to_string(),
+            epoch_retention: 10,
+            track_event_attribution: false,
+            reserve_epoch_rewards: false,
+            attestation_target: None,
         };
 
         CONFIG.save(storage, &config).unwrap();
@@ -1926,6 +3473,14 @@ mod test {
         deps
     }
 
+    fn block_at(height: u64, seconds: u64) -> BlockInfo {
+        BlockInfo {
+            height,
+            time: cosmwasm_std::Timestamp::from_seconds(seconds),
+            chain_id: "mock-chain".to_string(),
+        }
+    }
+
     fn setup_with_params(
         cur_epoch_num: u64,
         block_height_started: u64,
@@ -1947,6 +3502,9 @@ mod test {
                 participation_threshold: participation_threshold.try_into().unwrap(),
                 epoch_duration: epoch_duration.try_into().unwrap(),
                 rewards_per_epoch,
+                distribution_cooldown: None,
+                remainder_policy: None,
+                epoch_time: None,
             },
             created_at: current_epoch.clone(),
         };
@@ -1959,12 +3517,19 @@ mod test {
                 id: pool_id,
                 params: params_snapshot,
                 balance: Uint128::zero(),
+                asset: RewardsAsset::Native,
+                reserved: Uint128::zero(),
+                carried_remainder: Uint128::zero(),
             },
         )
         .unwrap();
 
         let config = Config {
             rewards_denom: "AXL".to_string(),
+            epoch_retention: 10,
+            track_event_attribution: false,
+            reserve_epoch_rewards: false,
+            attestation_target: None,
         };
 
         CONFIG.save(storage, &config).unwrap();
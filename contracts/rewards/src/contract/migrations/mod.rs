@@ -1,16 +1,55 @@
 use axelar_wasm_std::migrate_from_version;
+use cosmwasm_schema::cw_serde;
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
-use cosmwasm_std::{DepsMut, Empty, Env, Response};
+use cosmwasm_std::{DepsMut, Env, Response};
+use cw_storage_plus::Item;
 
-pub type MigrateMsg = Empty;
+use crate::msg;
+use crate::state::{self, AttestationTarget, Config, CONFIG};
+
+#[cw_serde]
+pub struct OldConfig {
+    pub rewards_denom: String,
+}
+pub const OLD_CONFIG: Item<OldConfig> = Item::new("config");
+
+#[cw_serde]
+pub struct MigrateMsg {
+    pub epoch_retention: u64,
+    #[serde(default)]
+    pub track_event_attribution: bool,
+    #[serde(default)]
+    pub reserve_epoch_rewards: bool,
+    #[serde(default)]
+    pub attestation_target: Option<msg::AttestationTarget>,
+}
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 #[migrate_from_version("1.2")]
 pub fn migrate(
     deps: DepsMut,
     _env: Env,
-    _msg: MigrateMsg,
+    msg: MigrateMsg,
 ) -> Result<Response, axelar_wasm_std::error::ContractError> {
+    let old_config = OLD_CONFIG.load(deps.storage)?;
+    let attestation_target = msg
+        .attestation_target
+        .map(|target| AttestationTarget::try_from_msg_attestation_target(deps.api, target))
+        .transpose()?;
+
+    CONFIG.save(
+        deps.storage,
+        &Config {
+            rewards_denom: old_config.rewards_denom,
+            epoch_retention: msg.epoch_retention,
+            track_event_attribution: msg.track_event_attribution,
+            reserve_epoch_rewards: msg.reserve_epoch_rewards,
+            attestation_target,
+        },
+    )?;
+
+    state::index_proxy_verifiers(deps.storage)?;
+
     Ok(Response::default())
 }
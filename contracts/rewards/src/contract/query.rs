@@ -1,16 +1,20 @@
-use cosmwasm_std::{Storage, Uint64};
-use error_stack::Result;
+use cosmwasm_std::{Addr, Storage, Uint64};
+use error_stack::{ensure, Result};
+use itertools::Itertools;
 
 use crate::error::ContractError;
-use crate::msg;
+use crate::msg::{self, Params};
 use crate::state::{self, Epoch, PoolId};
 
+const DEFAULT_EVENT_IDS_LIMIT: u32 = 100;
+
 pub fn rewards_pool(
     storage: &dyn Storage,
     pool_id: PoolId,
     block_height: u64,
 ) -> Result<msg::RewardsPool, ContractError> {
     let pool = state::load_rewards_pool(storage, pool_id.clone())?;
+    let free_balance = pool.free_balance();
     let params_snapshot = pool.params;
     let cur_epoch = Epoch::current(&params_snapshot, block_height)?;
 
@@ -20,10 +24,15 @@ pub fn rewards_pool(
         state::load_rewards_watermark(storage, pool_id)?.map(Uint64::from);
 
     Ok(msg::RewardsPool {
+        rewards_asset: pool.asset.into(),
         balance: pool.balance,
+        reserved_balance: pool.reserved,
+        free_balance,
         epoch_duration: params.epoch_duration.into(),
         rewards_per_epoch: params.rewards_per_epoch.into(),
         participation_threshold: params.participation_threshold,
+        remainder_policy: params.remainder_policy,
+        carried_remainder: pool.carried_remainder,
         current_epoch_num: cur_epoch.epoch_num.into(),
         last_distribution_epoch,
     })
@@ -42,29 +51,158 @@ pub fn participation(
             Epoch::current(&current_params, block_height)?.epoch_num
         }
     };
-    let tally = state::load_epoch_tally(storage, pool_id, epoch_num)?;
+    let tally = state::load_epoch_tally(storage, pool_id.clone(), epoch_num)?;
+    let opted_out = state::load_opted_out_verifiers(storage, pool_id)?;
 
     match tally {
         None => Ok(None),
         Some(tally) => Ok(Some(msg::Participation {
             event_count: tally.event_count,
             participation: tally.verifier_participation(),
-            rewards_by_verifier: tally.rewards_by_verifier(),
+            rewards_by_verifier: tally.rewards_by_verifier(&opted_out),
             epoch: tally.epoch.into(),
             params: tally.params,
         })),
     }
 }
 
+pub fn pending_governance_transfer(
+    storage: &dyn Storage,
+    block_height: u64,
+) -> Result<Option<msg::GovernanceTransfer>, ContractError> {
+    Ok(state::may_load_governance_transfer(storage)?
+        .filter(|transfer| block_height < transfer.expires_at))
+}
+
+pub fn distribution_cooldown(
+    storage: &dyn Storage,
+    pool_id: PoolId,
+    block_height: u64,
+) -> Result<msg::DistributionCooldown, ContractError> {
+    let earliest_allowed_height = state::earliest_distribution_height(storage, pool_id.clone())?;
+
+    Ok(msg::DistributionCooldown {
+        last_distribution_height: state::load_last_distribution_height(storage, pool_id)?,
+        earliest_allowed_height,
+        remaining_blocks: earliest_allowed_height.saturating_sub(block_height),
+    })
+}
+
+pub fn epoch_schedule(
+    storage: &dyn Storage,
+    pool_id: PoolId,
+    block_height: u64,
+) -> Result<msg::EpochSchedule, ContractError> {
+    let params = state::load_rewards_pool_params(storage, pool_id.clone())?;
+    let cur_epoch = Epoch::current(&params, block_height)?;
+
+    let mut aligned_pools = vec![];
+    for pool in state::load_rewards_pools_of_chain(storage, pool_id.chain_name)? {
+        if pool.params.params.epoch_duration == params.params.epoch_duration
+            && Epoch::current(&pool.params, block_height)? == cur_epoch
+        {
+            aligned_pools.push(pool.id.into());
+        }
+    }
+
+    Ok(msg::EpochSchedule {
+        epoch_duration: params.params.epoch_duration.into(),
+        current_epoch: cur_epoch.into(),
+        aligned_pools,
+    })
+}
+
+pub fn pool_params_update_effects(
+    storage: &dyn Storage,
+    pool_id: PoolId,
+    new_params: Params,
+    block_height: u64,
+) -> Result<msg::PoolParamsUpdateEffects, ContractError> {
+    let pool = state::load_rewards_pool(storage, pool_id.clone())?;
+    let cur_epoch = Epoch::current(&pool.params, block_height)?;
+    let effective_epoch = cur_epoch.after_params_update(&new_params, block_height)?;
+
+    let mut projected_rewards = vec![];
+    let mut validation_failures = vec![];
+
+    // the new params also apply to the tally of the current epoch, so its participants are affected right away
+    if let Some(tally) = state::load_epoch_tally(storage, pool_id.clone(), cur_epoch.epoch_num)? {
+        if state::load_config(storage)?.reserve_epoch_rewards {
+            if let Err(err) =
+                pool.update_reservation(tally.reserved, new_params.rewards_per_epoch.into())
+            {
+                validation_failures.push(err.current_context().to_string());
+            }
+        }
+
+        let opted_out = state::load_opted_out_verifiers(storage, pool_id)?;
+        let current = tally.rewards_by_verifier(&opted_out);
+        let proposed = state::EpochTally {
+            params: new_params,
+            ..tally.clone()
+        }
+        .rewards_by_verifier(&opted_out);
+
+        projected_rewards = tally
+            .verifier_participation()
+            .into_keys()
+            .sorted()
+            .map(|verifier| msg::ProjectedRewards {
+                current: current.get(&verifier).copied().unwrap_or_default(),
+                proposed: proposed.get(&verifier).copied().unwrap_or_default(),
+                verifier,
+            })
+            .collect();
+    }
+
+    Ok(msg::PoolParamsUpdateEffects {
+        current_epoch: cur_epoch.into(),
+        effective_epoch: effective_epoch.into(),
+        projected_rewards,
+        validation_failures,
+    })
+}
+
+pub fn participation_proof(
+    storage: &dyn Storage,
+    pool_id: PoolId,
+    verifier: Addr,
+    epoch_num: u64,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> Result<msg::ParticipationProof, ContractError> {
+    ensure!(
+        state::load_config(storage)?.track_event_attribution,
+        ContractError::EventAttributionNotTracked
+    );
+
+    let event_ids = state::load_attributed_event_ids(
+        storage,
+        pool_id,
+        epoch_num,
+        verifier.clone(),
+        start_after,
+        limit.unwrap_or(DEFAULT_EVENT_IDS_LIMIT) as usize,
+    )?;
+
+    Ok(msg::ParticipationProof {
+        verifier,
+        epoch_num,
+        event_ids,
+    })
+}
+
 #[cfg(test)]
 mod tests {
+    use std::collections::HashSet;
+
     use cosmwasm_std::testing::{mock_dependencies, MockApi};
     use cosmwasm_std::{Uint128, Uint64};
     use msg::Participation;
 
     use super::*;
     use crate::msg::Params;
-    use crate::state::{EpochTally, ParamsSnapshot, RewardsPool};
+    use crate::state::{Config, EpochTally, Event, ParamsSnapshot, RewardsPool, CONFIG};
 
     fn setup(storage: &mut dyn Storage, initial_balance: Uint128) -> (ParamsSnapshot, PoolId) {
         let pool_id = PoolId {
@@ -81,6 +219,9 @@ mod tests {
             epoch_duration: Uint64::from(100u64).try_into().unwrap(),
             rewards_per_epoch: Uint128::from(1000u128).try_into().unwrap(),
             participation_threshold: (1, 2).try_into().unwrap(),
+            distribution_cooldown: None,
+            remainder_policy: None,
+            epoch_time: None,
         };
         let params_snapshot = ParamsSnapshot {
             params: params.clone(),
@@ -90,6 +231,9 @@ mod tests {
             id: pool_id.clone(),
             balance: initial_balance,
             params: params_snapshot.clone(),
+            asset: state::RewardsAsset::Native,
+            reserved: Uint128::zero(),
+            carried_remainder: Uint128::zero(),
         };
 
         state::save_rewards_pool(storage, &rewards_pool).unwrap();
@@ -111,14 +255,19 @@ mod tests {
             res,
             msg::RewardsPool {
                 balance,
+                reserved_balance: Uint128::zero(),
+                free_balance: balance,
                 epoch_duration: current_params.params.epoch_duration.into(),
                 rewards_per_epoch: current_params.params.rewards_per_epoch.into(),
                 participation_threshold: current_params.params.participation_threshold,
+                remainder_policy: None,
+                carried_remainder: Uint128::zero(),
                 current_epoch_num: Epoch::current(&current_params, block_height)
                     .unwrap()
                     .epoch_num
                     .into(),
                 last_distribution_epoch: None,
+                rewards_asset: msg::RewardsAsset::Native,
             }
         );
     }
@@ -145,14 +294,19 @@ mod tests {
             res,
             msg::RewardsPool {
                 balance,
+                reserved_balance: Uint128::zero(),
+                free_balance: balance,
                 epoch_duration: current_params.params.epoch_duration.into(),
                 rewards_per_epoch: current_params.params.rewards_per_epoch.into(),
                 participation_threshold: current_params.params.participation_threshold,
+                remainder_policy: None,
+                carried_remainder: Uint128::zero(),
                 current_epoch_num: Epoch::current(&current_params, block_height)
                     .unwrap()
                     .epoch_num
                     .into(),
                 last_distribution_epoch: Some(last_distribution_epoch.into()),
+                rewards_asset: msg::RewardsAsset::Native,
             }
         );
     }
@@ -171,6 +325,9 @@ mod tests {
             epoch_duration: Uint64::from(200u64).try_into().unwrap(),
             rewards_per_epoch: Uint128::from(2000u128).try_into().unwrap(),
             participation_threshold: (2, 3).try_into().unwrap(),
+            distribution_cooldown: None,
+            remainder_policy: None,
+            epoch_time: None,
         };
 
         state::save_epoch_tally(
@@ -189,14 +346,19 @@ mod tests {
             res,
             msg::RewardsPool {
                 balance,
+                reserved_balance: Uint128::zero(),
+                free_balance: balance,
                 epoch_duration: current_params.params.epoch_duration.into(),
                 rewards_per_epoch: current_params.params.rewards_per_epoch.into(),
                 participation_threshold: current_params.params.participation_threshold,
+                remainder_policy: None,
+                carried_remainder: Uint128::zero(),
                 current_epoch_num: Epoch::current(&current_params, cur_block_height)
                     .unwrap()
                     .epoch_num
                     .into(),
                 last_distribution_epoch: None,
+                rewards_asset: msg::RewardsAsset::Native,
             }
         );
     }
@@ -239,7 +401,7 @@ mod tests {
         let expected = Participation {
             event_count: tally.event_count,
             participation: tally.verifier_participation(),
-            rewards_by_verifier: tally.rewards_by_verifier(),
+            rewards_by_verifier: tally.rewards_by_verifier(&HashSet::new()),
             epoch: Epoch::current(&current_params.clone(), block_height)
                 .unwrap()
                 .into(),
@@ -274,4 +436,282 @@ mod tests {
             participation(deps.as_mut().storage, pool_id.clone(), None, block_height).unwrap();
         assert!(res.is_none());
     }
+
+    #[test]
+    fn should_get_distribution_cooldown() {
+        let mut deps = mock_dependencies();
+        let (mut params_snapshot, pool_id) = setup(deps.as_mut().storage, Uint128::zero());
+
+        let res = distribution_cooldown(deps.as_ref().storage, pool_id.clone(), 1000).unwrap();
+        assert_eq!(
+            res,
+            msg::DistributionCooldown {
+                last_distribution_height: None,
+                earliest_allowed_height: 0,
+                remaining_blocks: 0,
+            }
+        );
+
+        params_snapshot.params.distribution_cooldown = Some(500u64.try_into().unwrap());
+        state::update_pool_params(deps.as_mut().storage, &pool_id, &params_snapshot).unwrap();
+        state::save_last_distribution_height(deps.as_mut().storage, pool_id.clone(), 1000).unwrap();
+
+        let res = distribution_cooldown(deps.as_ref().storage, pool_id.clone(), 1200).unwrap();
+        assert_eq!(
+            res,
+            msg::DistributionCooldown {
+                last_distribution_height: Some(1000),
+                earliest_allowed_height: 1500,
+                remaining_blocks: 300,
+            }
+        );
+
+        let res = distribution_cooldown(deps.as_ref().storage, pool_id, 1600).unwrap();
+        assert_eq!(res.remaining_blocks, 0);
+    }
+
+    #[test]
+    fn should_get_epoch_schedule_with_aligned_pools() {
+        let mut deps = mock_dependencies();
+        let (params, pool_id) = setup(deps.as_mut().storage, Uint128::zero());
+
+        let pool_with_params = |contract: &str, params: ParamsSnapshot| RewardsPool {
+            id: PoolId {
+                chain_name: pool_id.chain_name.clone(),
+                contract: MockApi::default().addr_make(contract),
+            },
+            balance: Uint128::zero(),
+            params,
+            asset: state::RewardsAsset::Native,
+            reserved: Uint128::zero(),
+            carried_remainder: Uint128::zero(),
+        };
+
+        let aligned = pool_with_params(
+            "aligned",
+            ParamsSnapshot {
+                params: params.params.clone(),
+                created_at: Epoch {
+                    epoch_num: 2,
+                    block_height_started: 200,
+                },
+            },
+        );
+        let shifted = pool_with_params(
+            "shifted",
+            ParamsSnapshot {
+                params: params.params.clone(),
+                created_at: Epoch {
+                    epoch_num: 0,
+                    block_height_started: 150,
+                },
+            },
+        );
+        for pool in [&aligned, &shifted] {
+            state::save_rewards_pool(deps.as_mut().storage, pool).unwrap();
+        }
+
+        let block_height = 1050;
+        let schedule =
+            epoch_schedule(deps.as_ref().storage, pool_id.clone(), block_height).unwrap();
+
+        assert_eq!(schedule.epoch_duration, Uint64::from(100u64));
+        assert_eq!(
+            schedule.current_epoch,
+            msg::Epoch {
+                epoch_num: 10,
+                block_height_started: 1000,
+            }
+        );
+        assert_eq!(schedule.aligned_pools.len(), 2);
+        assert!(schedule.aligned_pools.contains(&pool_id.into()));
+        assert!(schedule.aligned_pools.contains(&aligned.id.into()));
+    }
+
+    #[test]
+    fn should_report_pool_params_update_effects_without_changing_state() {
+        let mut deps = mock_dependencies();
+        let (current_params, pool_id) = setup(deps.as_mut().storage, Uint128::from(1500u128));
+        CONFIG
+            .save(
+                deps.as_mut().storage,
+                &Config {
+                    rewards_denom: "AXL".to_string(),
+                    epoch_retention: 10,
+                    track_event_attribution: false,
+                    reserve_epoch_rewards: true,
+                    attestation_target: None,
+                },
+            )
+            .unwrap();
+
+        let api = MockApi::default();
+        let block_height = 1050;
+        let mut tally = EpochTally::new(
+            pool_id.clone(),
+            Epoch::current(&current_params, block_height).unwrap(),
+            current_params.params.clone(),
+        );
+        tally.reserved = Uint128::from(1000u128);
+        let mut pool = state::load_rewards_pool(deps.as_ref().storage, pool_id.clone()).unwrap();
+        pool.reserved = tally.reserved;
+        state::save_rewards_pool(deps.as_mut().storage, &pool).unwrap();
+        for _ in 0..3 {
+            tally = tally.record_participation(api.addr_make("verifier_1"));
+        }
+        tally = tally.record_participation(api.addr_make("verifier_2"));
+        tally.event_count = 3;
+        state::save_epoch_tally(deps.as_mut().storage, &tally).unwrap();
+
+        let new_params = |epoch_duration: u64, rewards_per_epoch: u128| Params {
+            epoch_duration: epoch_duration.try_into().unwrap(),
+            rewards_per_epoch: Uint128::from(rewards_per_epoch).try_into().unwrap(),
+            participation_threshold: (1, 4).try_into().unwrap(),
+            distribution_cooldown: None,
+            remainder_policy: None,
+            epoch_time: None,
+        };
+        let projected = |verifier: &str, current: u128, proposed: u128| msg::ProjectedRewards {
+            verifier: api.addr_make(verifier),
+            current: current.into(),
+            proposed: proposed.into(),
+        };
+        let mut expected_rewards = vec![
+            projected("verifier_1", 1000, 600),
+            projected("verifier_2", 0, 600),
+        ];
+        expected_rewards.sort_by(|a, b| a.verifier.cmp(&b.verifier));
+
+        let effects = pool_params_update_effects(
+            deps.as_ref().storage,
+            pool_id.clone(),
+            new_params(100, 1200),
+            block_height,
+        )
+        .unwrap();
+        assert_eq!(
+            effects,
+            msg::PoolParamsUpdateEffects {
+                current_epoch: msg::Epoch {
+                    epoch_num: 10,
+                    block_height_started: 1000,
+                },
+                effective_epoch: msg::Epoch {
+                    epoch_num: 10,
+                    block_height_started: 1000,
+                },
+                projected_rewards: expected_rewards,
+                validation_failures: vec![],
+            }
+        );
+
+        // a shorter epoch duration ends the current epoch, and the reservation can't cover the increased rewards
+        let effects = pool_params_update_effects(
+            deps.as_ref().storage,
+            pool_id.clone(),
+            new_params(10, 2000),
+            block_height,
+        )
+        .unwrap();
+        assert_eq!(
+            effects.effective_epoch,
+            msg::Epoch {
+                epoch_num: 11,
+                block_height_started: block_height,
+            }
+        );
+        assert_eq!(
+            effects.validation_failures,
+            vec![ContractError::FreeBalanceInsufficient.to_string()]
+        );
+
+        assert_eq!(
+            state::load_rewards_pool_params(deps.as_ref().storage, pool_id.clone()).unwrap(),
+            current_params
+        );
+        assert_eq!(
+            state::load_epoch_tally(deps.as_ref().storage, pool_id, 10).unwrap(),
+            Some(tally)
+        );
+    }
+
+    #[test]
+    fn should_get_participation_proof_only_if_event_attribution_is_tracked() {
+        let mut deps = mock_dependencies();
+        let (_, pool_id) = setup(deps.as_mut().storage, Uint128::zero());
+        let verifier = MockApi::default().addr_make("verifier");
+
+        let mut config = Config {
+            rewards_denom: "AXL".to_string(),
+            epoch_retention: 10,
+            track_event_attribution: false,
+            reserve_epoch_rewards: false,
+            attestation_target: None,
+        };
+        CONFIG.save(deps.as_mut().storage, &config).unwrap();
+
+        let res = participation_proof(
+            deps.as_ref().storage,
+            pool_id.clone(),
+            verifier.clone(),
+            0,
+            None,
+            None,
+        );
+        assert_eq!(
+            res.unwrap_err().current_context(),
+            &ContractError::EventAttributionNotTracked
+        );
+
+        config.track_event_attribution = true;
+        CONFIG.save(deps.as_mut().storage, &config).unwrap();
+
+        for event_id in ["event-c", "event-a", "event-b"] {
+            let event = Event::new(event_id.try_into().unwrap(), pool_id.clone(), 0);
+            state::save_event_attribution(deps.as_mut().storage, &event, verifier.clone()).unwrap();
+        }
+        let other_epoch_event = Event::new("event-d".try_into().unwrap(), pool_id.clone(), 1);
+        state::save_event_attribution(deps.as_mut().storage, &other_epoch_event, verifier.clone())
+            .unwrap();
+
+        let res = participation_proof(
+            deps.as_ref().storage,
+            pool_id.clone(),
+            verifier.clone(),
+            0,
+            None,
+            Some(2),
+        )
+        .unwrap();
+        assert_eq!(
+            res,
+            msg::ParticipationProof {
+                verifier: verifier.clone(),
+                epoch_num: 0,
+                event_ids: vec!["event-a".to_string(), "event-b".to_string()],
+            }
+        );
+
+        let res = participation_proof(
+            deps.as_ref().storage,
+            pool_id.clone(),
+            verifier,
+            0,
+            Some("event-b".to_string()),
+            None,
+        )
+        .unwrap();
+        assert_eq!(res.event_ids, vec!["event-c".to_string()]);
+
+        let res = participation_proof(
+            deps.as_ref().storage,
+            pool_id,
+            MockApi::default().addr_make("other_verifier"),
+            0,
+            None,
+            None,
+        )
+        .unwrap();
+        assert!(res.event_ids.is_empty());
+    }
 }
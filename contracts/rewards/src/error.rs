@@ -1,5 +1,5 @@
 use axelar_wasm_std::IntoContractError;
-use cosmwasm_std::{OverflowError, StdError};
+use cosmwasm_std::{Addr, OverflowError, StdError};
 use thiserror::Error;
 
 #[derive(Error, Debug, PartialEq, IntoContractError)]
@@ -10,6 +10,15 @@ pub enum ContractError {
     #[error("error saving params")]
     SaveParams,
 
+    #[error("error loading config")]
+    LoadConfig,
+
+    #[error("error saving config")]
+    SaveConfig,
+
+    #[error("epoch duration of the rewards pool is zero")]
+    ZeroEpochDuration,
+
     #[error("error saving epoch tally")]
     SaveEpochTally,
 
@@ -34,6 +43,18 @@ pub enum ContractError {
     #[error("error loading event")]
     LoadEvent,
 
+    #[error("error saving event attribution")]
+    SaveEventAttribution,
+
+    #[error("error loading event attribution")]
+    LoadEventAttribution,
+
+    #[error("event attribution is not tracked")]
+    EventAttributionNotTracked,
+
+    #[error("error removing event")]
+    RemoveEvent,
+
     #[error("error loading rewards pool")]
     LoadRewardsPool,
 
@@ -43,12 +64,33 @@ pub enum ContractError {
     #[error("rewards pool already exists")]
     RewardsPoolAlreadyExists,
 
+    #[error("funds cannot be attached to pool creation, pools are funded with AddRewards")]
+    PoolCreationNotPayable,
+
+    #[error("epochs can only be aligned with a pool of the same chain")]
+    EpochAlignmentChainMismatch,
+
+    #[error("epochs can only be aligned with a pool with the same epoch duration")]
+    EpochAlignmentDurationMismatch,
+
+    #[error("epochs can only be aligned with a pool whose current epoch number is not behind")]
+    EpochAlignmentEpochBehind,
+
     #[error("error loading rewards watermark")]
     LoadRewardsWatermark,
 
     #[error("error loading verifier proxy address")]
     LoadProxyAddress,
 
+    #[error("proxy address {0} is already set by another verifier")]
+    ProxyAddressAlreadySet(Addr),
+
+    #[error("error saving rewards opt-out")]
+    SaveRewardsOptOut,
+
+    #[error("error loading rewards opt-out")]
+    LoadRewardsOptOut,
+
     #[error("invalid event id")]
     InvalidEventId,
 
@@ -61,18 +103,96 @@ pub enum ContractError {
     #[error("rewards pool balance insufficient")]
     PoolBalanceInsufficient,
 
+    #[error("free balance of the rewards pool cannot cover the rewards of the epoch")]
+    FreeBalanceInsufficient,
+
     #[error("no rewards to distribute")]
     NoRewardsToDistribute,
 
+    #[error("rewards cannot be distributed before block height {0}")]
+    DistributionCooldown(u64),
+
+    #[error("error loading last distribution height")]
+    LoadDistributionHeight,
+
+    #[error("error saving last distribution height")]
+    SaveDistributionHeight,
+
+    #[error("error loading epoch start time")]
+    LoadEpochStartTime,
+
+    #[error("error saving epoch start time")]
+    SaveEpochStartTime,
+
     #[error("caller is not authorized")]
     Unauthorized,
 
     #[error("wrong denom for rewards")]
     WrongDenom,
 
+    #[error("rewards pool is not funded with this asset")]
+    WrongRewardsAsset,
+
+    #[error("invalid message attached to received CW20 tokens")]
+    InvalidReceiveMsg,
+
     #[error("rewards amount is zero")]
     ZeroRewards,
 
     #[error("failed to serialize the response")]
     SerializeResponse,
+
+    #[error("error saving governance transfer")]
+    SaveGovernanceTransfer,
+
+    #[error("error loading governance transfer")]
+    LoadGovernanceTransfer,
+
+    #[error("no pending governance transfer")]
+    NoPendingGovernanceTransfer,
+
+    #[error("governance transfer expired at block height {0}")]
+    GovernanceTransferExpired(u64),
+
+    #[error("new governance must differ from the current governance")]
+    GovernanceUnchanged,
+
+    #[error("error saving the pools of the multi-pool distribution")]
+    SaveMultiDistribution,
+
+    #[error("error loading the pools of the multi-pool distribution")]
+    LoadMultiDistribution,
+
+    #[error("no pool at index {0} of the multi-pool distribution")]
+    UnknownMultiDistributionPool(u64),
+
+    #[error("error saving pool distributor")]
+    SaveDistributor,
+
+    #[error("error loading pool distributor")]
+    LoadDistributor,
+
+    #[error("rewards of this pool can only be distributed by its distributor {0} or governance")]
+    NotDistributor(Addr),
+
+    #[error("error saving pool payout hook")]
+    SavePayoutHook,
+
+    #[error("error loading pool payout hook")]
+    LoadPayoutHook,
+
+    #[error("error saving the payouts to the payout hook")]
+    SaveHookedPayouts,
+
+    #[error("error loading the payouts to the payout hook")]
+    LoadHookedPayouts,
+
+    #[error("no payout to the payout hook at index {0}")]
+    UnknownHookedPayout(u64),
+
+    #[error("error saving owed payout")]
+    SaveOwedPayout,
+
+    #[error("error loading owed payouts")]
+    LoadOwedPayouts,
 }
@@ -1,7 +1,13 @@
+use axelar_wasm_std::IntoEvent;
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Uint128};
+use itertools::Itertools;
 
-use crate::state::{Epoch, RewardsDistribution};
+use crate::msg::RemainderPolicy;
+use crate::state::{Epoch, PoolId, PrunedEpochs, RewardsDistribution};
+
+/// Upper bound on the verifier payouts listed in a single `rewards_distributed` event, larger distributions are split into multiple events
+const MAX_REWARDS_PER_EVENT: usize = 100;
 
 #[cw_serde]
 pub struct VerifierDistribution {
@@ -9,58 +15,379 @@ pub struct VerifierDistribution {
     pub proxy_address: Option<Addr>,
     pub amount: Uint128,
 }
-pub enum Event {
+
+#[derive(IntoEvent)]
+pub enum DistributionEvent {
     RewardsDistributed {
-        rewards: Vec<VerifierDistribution>,
+        pool_id: PoolId,
         epochs_processed: Vec<u64>,
         current_epoch: Epoch,
+        /// Payouts of this chunk of the distribution, sorted by verifier
+        rewards: Vec<VerifierDistribution>,
+        /// Zero-based index of this chunk
+        chunk: u64,
+        chunk_count: u64,
+        remaining_balance: Uint128,
         can_distribute_more: bool,
+        remainder_policy: Option<RemainderPolicy>,
+        /// Remainder that is carried on to the next distributed epoch
+        carried_remainder: Uint128,
+        /// Remainder that was burned in this distribution
+        burned: Uint128,
     },
 }
 
-impl From<RewardsDistribution> for Event {
-    fn from(value: RewardsDistribution) -> Self {
-        Event::RewardsDistributed {
-            rewards: value
-                .rewards
-                .into_iter()
-                .map(|(v, amount)| VerifierDistribution {
-                    verifier_address: v.verifier_address,
-                    proxy_address: v.proxy_address,
-                    amount,
-                })
-                .collect(),
-            epochs_processed: value.epochs_processed,
-            current_epoch: value.current_epoch,
-            can_distribute_more: value.can_distribute_more,
+pub enum Event {
+    GovernanceTransferProposed {
+        current_governance: Addr,
+        new_governance: Addr,
+        expires_at: u64,
+    },
+    GovernanceTransferAccepted {
+        previous_governance: Addr,
+        new_governance: Addr,
+    },
+    EpochsPruned {
+        pool_id: PoolId,
+        epochs: Option<(u64, u64)>,
+        tallies_pruned: u64,
+        events_pruned: u64,
+        attributions_pruned: u64,
+        can_prune_more: bool,
+    },
+    RewardsOptedOut {
+        pool_id: PoolId,
+        verifier: Addr,
+    },
+    RewardsOptedIn {
+        pool_id: PoolId,
+        verifier: Addr,
+    },
+    RewardsDistributionFailed {
+        pool_id: PoolId,
+        reason: String,
+    },
+    AttestationSubmissionFailed {
+        reason: String,
+    },
+    HookedPayoutFailed {
+        pool_id: PoolId,
+        verifier: Addr,
+        amount: Uint128,
+        /// total rewards owed to the verifier, including the amount of this payout
+        owed: Uint128,
+        reason: String,
+    },
+    PoolDistributorUpdated {
+        pool_id: PoolId,
+        previous_distributor: Option<Addr>,
+        distributor: Option<Addr>,
+    },
+    PoolPayoutHookUpdated {
+        pool_id: PoolId,
+        previous_payout_hook: Option<Addr>,
+        payout_hook: Option<Addr>,
+    },
+    EpochUnderReserved {
+        pool_id: PoolId,
+        epoch_num: u64,
+        reserved: Uint128,
+        rewards_per_epoch: Uint128,
+    },
+}
+
+impl From<PrunedEpochs> for Event {
+    fn from(value: PrunedEpochs) -> Self {
+        Event::EpochsPruned {
+            pool_id: value.pool_id,
+            epochs: value.epochs,
+            tallies_pruned: value.tallies_pruned,
+            events_pruned: value.events_pruned,
+            attributions_pruned: value.attributions_pruned,
+            can_prune_more: value.can_prune_more,
         }
     }
 }
 
+impl From<RewardsDistribution> for Vec<cosmwasm_std::Event> {
+    fn from(value: RewardsDistribution) -> Self {
+        let rewards: Vec<VerifierDistribution> = value
+            .rewards
+            .into_iter()
+            .sorted()
+            .map(|(verifier, amount)| VerifierDistribution {
+                verifier_address: verifier.verifier_address,
+                proxy_address: verifier.proxy_address,
+                amount,
+            })
+            .collect();
+
+        // a distribution without payouts still emits a single event
+        let chunks: Vec<Vec<VerifierDistribution>> = if rewards.is_empty() {
+            vec![vec![]]
+        } else {
+            rewards
+                .chunks(MAX_REWARDS_PER_EVENT)
+                .map(<[VerifierDistribution]>::to_vec)
+                .collect()
+        };
+        let chunk_count = chunks.len() as u64;
+
+        chunks
+            .into_iter()
+            .zip(0u64..)
+            .map(|(rewards, chunk)| {
+                DistributionEvent::RewardsDistributed {
+                    pool_id: value.pool_id.clone(),
+                    epochs_processed: value.epochs_processed.clone(),
+                    current_epoch: value.current_epoch.clone(),
+                    rewards,
+                    chunk,
+                    chunk_count,
+                    remaining_balance: value.remaining_balance,
+                    can_distribute_more: value.can_distribute_more,
+                    remainder_policy: value.remainder_policy,
+                    carried_remainder: value.carried_remainder,
+                    burned: value.burned,
+                }
+                .into()
+            })
+            .collect()
+    }
+}
+
 impl From<Event> for cosmwasm_std::Event {
     fn from(other: Event) -> Self {
         match other {
-            Event::RewardsDistributed {
-                rewards,
-                epochs_processed,
-                current_epoch,
-                can_distribute_more: more_epochs_to_distribute,
-            } => cosmwasm_std::Event::new("rewards_distributed")
-                .add_attribute(
-                    "rewards",
-                    serde_json::to_string(&rewards).expect("failed to serialize rewards"),
-                )
-                .add_attribute(
-                    "epochs_processed",
-                    serde_json::to_string(&epochs_processed)
-                        .expect("failed to serialize epochs processed"),
-                )
-                .add_attribute(
-                    "current_epoch",
-                    serde_json::to_string(&current_epoch)
-                        .expect("failed to serialize current epoch"),
-                )
-                .add_attribute("can_distribute_more", more_epochs_to_distribute.to_string()),
+            Event::GovernanceTransferProposed {
+                current_governance,
+                new_governance,
+                expires_at,
+            } => cosmwasm_std::Event::new("governance_transfer_proposed")
+                .add_attribute("current_governance", current_governance)
+                .add_attribute("new_governance", new_governance)
+                .add_attribute("expires_at", expires_at.to_string()),
+            Event::GovernanceTransferAccepted {
+                previous_governance,
+                new_governance,
+            } => cosmwasm_std::Event::new("governance_transfer_accepted")
+                .add_attribute("previous_governance", previous_governance)
+                .add_attribute("new_governance", new_governance),
+            Event::EpochsPruned {
+                pool_id,
+                epochs,
+                tallies_pruned,
+                events_pruned,
+                attributions_pruned,
+                can_prune_more,
+            } => {
+                let event = cosmwasm_std::Event::new("epochs_pruned")
+                    .add_attribute("chain_name", pool_id.chain_name)
+                    .add_attribute("contract", pool_id.contract)
+                    .add_attribute("tallies_pruned", tallies_pruned.to_string())
+                    .add_attribute("events_pruned", events_pruned.to_string())
+                    .add_attribute("attributions_pruned", attributions_pruned.to_string())
+                    .add_attribute("can_prune_more", can_prune_more.to_string());
+
+                match epochs {
+                    Some((from_epoch, to_epoch)) => event
+                        .add_attribute("from_epoch", from_epoch.to_string())
+                        .add_attribute("to_epoch", to_epoch.to_string()),
+                    None => event,
+                }
+            }
+            Event::RewardsOptedOut { pool_id, verifier } => {
+                cosmwasm_std::Event::new("rewards_opted_out")
+                    .add_attribute("chain_name", pool_id.chain_name)
+                    .add_attribute("contract", pool_id.contract)
+                    .add_attribute("verifier", verifier)
+            }
+            Event::RewardsOptedIn { pool_id, verifier } => {
+                cosmwasm_std::Event::new("rewards_opted_in")
+                    .add_attribute("chain_name", pool_id.chain_name)
+                    .add_attribute("contract", pool_id.contract)
+                    .add_attribute("verifier", verifier)
+            }
+            Event::RewardsDistributionFailed { pool_id, reason } => {
+                cosmwasm_std::Event::new("rewards_distribution_failed")
+                    .add_attribute("chain_name", pool_id.chain_name)
+                    .add_attribute("contract", pool_id.contract)
+                    .add_attribute("reason", reason)
+            }
+            Event::AttestationSubmissionFailed { reason } => {
+                cosmwasm_std::Event::new("attestation_submission_failed")
+                    .add_attribute("reason", reason)
+            }
+            Event::HookedPayoutFailed {
+                pool_id,
+                verifier,
+                amount,
+                owed,
+                reason,
+            } => cosmwasm_std::Event::new("hooked_payout_failed")
+                .add_attribute("chain_name", pool_id.chain_name)
+                .add_attribute("contract", pool_id.contract)
+                .add_attribute("verifier", verifier)
+                .add_attribute("amount", amount)
+                .add_attribute("owed", owed)
+                .add_attribute("reason", reason),
+            Event::PoolDistributorUpdated {
+                pool_id,
+                previous_distributor,
+                distributor,
+            } => {
+                let event = cosmwasm_std::Event::new("pool_distributor_updated")
+                    .add_attribute("chain_name", pool_id.chain_name)
+                    .add_attribute("contract", pool_id.contract);
+
+                // absent attributes mean the pool had or has no distributor
+                let event = match previous_distributor {
+                    Some(previous_distributor) => {
+                        event.add_attribute("previous_distributor", previous_distributor)
+                    }
+                    None => event,
+                };
+
+                match distributor {
+                    Some(distributor) => event.add_attribute("distributor", distributor),
+                    None => event,
+                }
+            }
+            Event::PoolPayoutHookUpdated {
+                pool_id,
+                previous_payout_hook,
+                payout_hook,
+            } => {
+                let event = cosmwasm_std::Event::new("pool_payout_hook_updated")
+                    .add_attribute("chain_name", pool_id.chain_name)
+                    .add_attribute("contract", pool_id.contract);
+
+                // absent attributes mean the pool had or has no payout hook
+                let event = match previous_payout_hook {
+                    Some(previous_payout_hook) => {
+                        event.add_attribute("previous_payout_hook", previous_payout_hook)
+                    }
+                    None => event,
+                };
+
+                match payout_hook {
+                    Some(payout_hook) => event.add_attribute("payout_hook", payout_hook),
+                    None => event,
+                }
+            }
+            Event::EpochUnderReserved {
+                pool_id,
+                epoch_num,
+                reserved,
+                rewards_per_epoch,
+            } => cosmwasm_std::Event::new("epoch_under_reserved")
+                .add_attribute("chain_name", pool_id.chain_name)
+                .add_attribute("contract", pool_id.contract)
+                .add_attribute("epoch_num", epoch_num.to_string())
+                .add_attribute("reserved", reserved)
+                .add_attribute("rewards_per_epoch", rewards_per_epoch),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use cosmwasm_std::testing::MockApi;
+
+    use super::*;
+    use crate::state::Verifier;
+
+    fn distribution(verifier_count: usize) -> RewardsDistribution {
+        RewardsDistribution {
+            pool_id: PoolId {
+                chain_name: "mock-chain".parse().unwrap(),
+                contract: MockApi::default().addr_make("pool_contract"),
+            },
+            rewards: (0..verifier_count)
+                .map(|i| {
+                    (
+                        Verifier {
+                            verifier_address: MockApi::default()
+                                .addr_make(&format!("verifier{}", i)),
+                            proxy_address: None,
+                        },
+                        Uint128::from(100u128),
+                    )
+                })
+                .collect::<HashMap<_, _>>(),
+            epochs_processed: vec![1, 2],
+            current_epoch: Epoch {
+                epoch_num: 4,
+                block_height_started: 4000,
+            },
+            can_distribute_more: false,
+            remaining_balance: Uint128::from(500u128),
+            remainder_policy: Some(RemainderPolicy::Carry),
+            carried_remainder: Uint128::from(2u128),
+            burned: Uint128::zero(),
         }
     }
+
+    fn attribute<'a>(event: &'a cosmwasm_std::Event, key: &str) -> &'a str {
+        event
+            .attributes
+            .iter()
+            .find(|attr| attr.key == key)
+            .map(|attr| attr.value.as_str())
+            .unwrap()
+    }
+
+    #[test]
+    fn rewards_distributed_event_contains_payout_details() {
+        let events: Vec<cosmwasm_std::Event> = distribution(1).into();
+
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].ty, "rewards_distributed");
+        assert_eq!(attribute(&events[0], "epochs_processed"), "[1,2]");
+        assert_eq!(attribute(&events[0], "remaining_balance"), "\"500\"");
+        assert_eq!(attribute(&events[0], "chunk_count"), "1");
+        assert_eq!(attribute(&events[0], "remainder_policy"), "\"carry\"");
+        assert_eq!(attribute(&events[0], "carried_remainder"), "\"2\"");
+        assert_eq!(attribute(&events[0], "burned"), "\"0\"");
+        assert!(attribute(&events[0], "pool_id").contains("mock-chain"));
+
+        let rewards: Vec<VerifierDistribution> =
+            serde_json::from_str(attribute(&events[0], "rewards")).unwrap();
+        assert_eq!(rewards.len(), 1);
+        assert_eq!(rewards[0].amount, Uint128::from(100u128));
+    }
+
+    #[test]
+    fn large_distributions_are_split_into_chunks() {
+        let events: Vec<cosmwasm_std::Event> = distribution(250).into();
+
+        assert_eq!(events.len(), 3);
+
+        let rewards: Vec<VerifierDistribution> = events
+            .iter()
+            .enumerate()
+            .flat_map(|(i, event)| {
+                assert_eq!(attribute(event, "chunk"), i.to_string());
+                assert_eq!(attribute(event, "chunk_count"), "3");
+
+                serde_json::from_str::<Vec<VerifierDistribution>>(attribute(event, "rewards"))
+                    .unwrap()
+            })
+            .collect();
+
+        assert_eq!(rewards.len(), 250);
+        assert!(rewards
+            .windows(2)
+            .all(|pair| pair[0].verifier_address < pair[1].verifier_address));
+    }
+
+    #[test]
+    fn distribution_without_payouts_emits_a_single_event() {
+        let events: Vec<cosmwasm_std::Event> = distribution(0).into();
+
+        assert_eq!(events.len(), 1);
+        assert_eq!(attribute(&events[0], "rewards"), "[]");
+    }
 }
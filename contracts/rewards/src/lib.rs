@@ -1,3 +1,4 @@
+pub mod client;
 pub mod contract;
 pub mod error;
 pub mod events;
@@ -1,13 +1,17 @@
 use std::collections::HashMap;
 
-use axelar_wasm_std::{nonempty, Threshold};
+use axelar_wasm_std::{bounded, nonempty, time, QueryClient, Threshold};
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::{Addr, Uint128, Uint64};
+use cw20::Cw20ReceiveMsg;
 use msgs_derive::EnsurePermissions;
 use router_api::{Address, ChainName};
 
 pub use crate::contract::MigrateMsg;
 
+/// Maximum length in bytes of the event id of a participation
+pub const MAX_EVENT_ID_LENGTH: usize = 128;
+
 #[cw_serde]
 pub struct Epoch {
     pub epoch_num: u64,
@@ -24,6 +28,31 @@ pub struct PoolId {
 pub struct InstantiateMsg {
     pub governance_address: String,
     pub rewards_denom: String,
+    /// Number of epochs below the rewards watermark of a pool for which tallies and events are kept.
+    /// Older epochs can be pruned with `PruneEpochs`.
+    pub epoch_retention: u64,
+    /// If set, the ids of the events each verifier was credited for are stored in addition to the participation counts,
+    /// so verifiers can prove their participation with `ParticipationProof`. This increases the storage cost of recording participation.
+    #[serde(default)]
+    pub track_event_attribution: bool,
+    /// If set, the rewards of an epoch are reserved from the free balance of its pool as soon as the first participation in that epoch
+    /// is recorded, and distributions are paid from these reservations. If the free balance of the pool can't cover the rewards of a new
+    /// epoch, participation is still recorded: as much as is free is reserved, the epoch is marked as under-reserved and an
+    /// `EpochUnderReserved` event is emitted. The missing rewards are settled from the pool balance when the epoch is distributed,
+    /// so pools should still be topped up with `AddRewards` ahead of time.
+    #[serde(default)]
+    pub reserve_epoch_rewards: bool,
+    /// If set, the participation of each verifier in the distributed epochs is submitted to the service registry as performance
+    /// attestations whenever rewards are distributed. The rewards contract must be registered as an attester of the service.
+    #[serde(default)]
+    pub attestation_target: Option<AttestationTarget>,
+}
+
+/// Service registry service whose verifiers receive performance attestations
+#[cw_serde]
+pub struct AttestationTarget {
+    pub service_registry: String,
+    pub service_name: String,
 }
 
 #[cw_serde]
@@ -40,6 +69,58 @@ pub struct Params {
     /// and there are 100 events in a given epoch, verifiers must have participated in at least 90 events to receive rewards.
     /// Participation is reset at the beginning of each epoch, so participation in previous epochs does not affect rewards for future epochs.
     pub participation_threshold: Threshold,
+
+    /// Minimum number of blocks that must pass between two reward distributions for the pool. This prevents dust distributions
+    /// and excessive bank sends when `DistributeRewards` is called too frequently. The cooldown only starts once a distribution
+    /// has processed all distributable epochs, so a backlog can be distributed in consecutive batches. If not set, rewards can be
+    /// distributed at any time.
+    pub distribution_cooldown: Option<nonempty::Uint64>,
+
+    /// What happens to the part of an epoch's rewards that can't be split equally amongst the rewarded verifiers.
+    /// If not set, the remainder is not paid out and stays in the pool balance.
+    #[serde(default)]
+    pub remainder_policy: Option<RemainderPolicy>,
+
+    /// Maximum wall-clock time an epoch lasts. If set, an epoch ends after `epoch_duration` blocks or after this time, whichever
+    /// comes first, so epochs stay bounded in time on chains with irregular block times. Epochs ending early because of the time
+    /// are closed by the first participation or distribution after the time has passed.
+    #[serde(default)]
+    pub epoch_time: Option<time::Duration>,
+}
+
+/// Handling of the remainder when an epoch's rewards don't divide evenly by the number of rewarded verifiers
+#[cw_serde]
+#[derive(Copy)]
+pub enum RemainderPolicy {
+    /// Add the remainder to the rewards of the next distributed epoch. Carried remainders are reserved in the pool balance
+    Carry,
+    /// Pay one additional token each to the verifiers with the highest participation, ties broken by address
+    RoundRobin,
+    /// Burn the remainder when rewards are distributed
+    Burn,
+}
+
+/// A rewards pool to create with `CreatePools`, see `CreatePool` for the fields
+#[cw_serde]
+pub struct NewPool {
+    pub params: Params,
+    pub pool_id: PoolId,
+    #[serde(default)]
+    pub rewards_asset: RewardsAsset,
+    #[serde(default)]
+    pub align_epochs_with: Option<PoolId>,
+}
+
+/// The asset a rewards pool is funded with and pays out rewards in
+#[cw_serde]
+#[derive(Default)]
+pub enum RewardsAsset {
+    /// The native rewards denom the contract was instantiated with
+    #[default]
+    Native,
+    /// A CW20 token. The pool is funded by sending tokens to this contract via the token contract's `Send`,
+    /// and rewards are paid out with `Transfer` messages to the token contract
+    Cw20 { contract: String },
 }
 
 #[cw_serde]
@@ -57,12 +138,13 @@ pub enum ExecuteMsg {
     #[permission(Any)]
     RecordParticipation {
         chain_name: ChainName,
-        event_id: nonempty::String,
+        event_id: bounded::String<MAX_EVENT_ID_LENGTH>,
         verifier_address: String,
     },
 
     /// Distribute rewards up to epoch T - 2 (i.e. if we are currently in epoch 10, distribute all undistributed rewards for epochs 0-8) and send the required number of tokens to each verifier
-    /// This call will error if the pool does not yet exist.
+    /// This call will error if the pool does not yet exist, or if the pool's distribution cooldown has not passed since the last distribution that caught up with all distributable epochs.
+    /// If the pool has a distributor, only the distributor and governance can distribute its rewards.
     #[permission(Any)]
     DistributeRewards {
         pool_id: PoolId,
@@ -70,33 +152,169 @@ pub enum ExecuteMsg {
         epoch_count: Option<u64>,
     },
 
-    /// Add tokens to an existing rewards pool.
-    /// Any attached funds with a denom matching the rewards denom are added to the pool.
-    /// This call will error if the pool does not yet exist.
+    /// Distribute rewards for several pools in one transaction, the same way as `DistributeRewards`. Each pool is distributed in its own
+    /// submessage, so a pool whose distribution fails, e.g. because it does not exist or its cooldown has not passed, does not affect the
+    /// other pools. Failed distributions emit a `rewards_distribution_failed` event with the reason.
+    #[permission(Any)]
+    DistributeRewardsMulti {
+        pool_ids: Vec<PoolId>,
+        /// Maximum number of historical epochs for which to distribute rewards per pool. If not specified, distribute rewards for 10 epochs.
+        epoch_count: Option<u64>,
+    },
+
+    /// Add tokens to an existing rewards pool. Anyone can fund a pool, but only governance can create one, so funding
+    /// never creates a pool. Any attached funds with a denom matching the rewards denom are added to the pool.
+    /// This call will error with `RewardsPoolNotFound` if the pool does not yet exist, or if the pool is funded with a CW20 token.
     #[permission(Any)]
     AddRewards { pool_id: PoolId },
 
+    /// Add CW20 tokens to an existing rewards pool. Called by the CW20 token contract when tokens are sent to this contract,
+    /// with a `ReceiveMsg` as the attached message. This call will error if the pool does not yet exist, or if the pool is not
+    /// funded with the sending token.
+    #[permission(Any)]
+    Receive(Cw20ReceiveMsg),
+
+    /// Remove tallies and events of epochs that are older than the retention window below the pool's rewards watermark, starting with the oldest.
+    /// Such epochs have had rewards distributed already, so their participation is no longer needed.
+    /// This call will not remove anything if no rewards have been distributed for the pool yet.
+    #[permission(Any)]
+    PruneEpochs {
+        pool_id: PoolId,
+        /// Maximum number of tallies and events to remove. If not specified, remove up to 100 entries.
+        limit: Option<u32>,
+    },
+
     /// Overwrites the currently stored params for the specified pool. Callable only by governance.
     /// This call will error if the pool does not yet exist.
+    /// If `align_epochs_with` is set, the pool re-anchors its epochs to the current epoch of that pool of the same chain,
+    /// which must already have the new epoch duration and must not be behind in epoch numbers.
     #[permission(Governance)]
-    UpdatePoolParams { params: Params, pool_id: PoolId },
+    UpdatePoolParams {
+        params: Params,
+        pool_id: PoolId,
+        #[serde(default)]
+        align_epochs_with: Option<PoolId>,
+    },
 
     /// Creates a rewards pool with the specified pool ID and parameters. Callable only by governance.
+    /// The rewards asset of a pool cannot be changed after creation. If not specified, the pool uses the native rewards denom.
+    /// If `align_epochs_with` is set, the pool adopts the epoch numbers and boundaries of that existing pool of the same chain,
+    /// which must have the same epoch duration.
+    /// New pools are empty and must be funded with `AddRewards`, so this call will error if funds are attached.
     #[permission(Governance)]
-    CreatePool { params: Params, pool_id: PoolId },
+    CreatePool {
+        params: Params,
+        pool_id: PoolId,
+        #[serde(default)]
+        rewards_asset: RewardsAsset,
+        #[serde(default)]
+        align_epochs_with: Option<PoolId>,
+    },
+
+    /// Creates several rewards pools at once, e.g. when onboarding many chains, the same way as `CreatePool`. Pools are created
+    /// in the given order, so a pool can align its epochs with a pool created earlier in the same call. Callable only by governance.
+    /// If any of the pools cannot be created, no pool is created. This call will error if funds are attached.
+    #[permission(Governance)]
+    CreatePools(Vec<NewPool>),
 
     /// Sets a proxy address for verifier rewards. Any future rewards distributed to the sender will instead
-    /// be distributed to the proxy address.
+    /// be distributed to the proxy address. A proxy address can only be set by a single verifier.
     #[permission(Any)]
     SetVerifierProxy { proxy_address: Address },
 
     /// Removes any proxy address associated with the sender. Future verifier rewards will be distributed to the sender
     #[permission(Any)]
     RemoveVerifierProxy {},
+
+    /// Opts the sender out of the rewards of the given pool. Participation is still recorded, but distributions skip the sender,
+    /// and the sender's share is handled like the remainder according to the pool's remainder policy. Applies to all epochs
+    /// that are distributed while the opt-out is in place. This call will error if the pool does not exist.
+    #[permission(Any)]
+    OptOutOfRewards { pool_id: PoolId },
+
+    /// Reverts an opt-out of the sender from the rewards of the given pool. This call will error if the pool does not exist.
+    #[permission(Any)]
+    OptIntoRewards { pool_id: PoolId },
+
+    /// Sets the address that is allowed to distribute the rewards of the given pool with `DistributeRewards` and
+    /// `DistributeRewardsMulti`, in addition to governance. While a distributor is set, nobody else can distribute
+    /// the pool's rewards. Setting it to None makes distribution permissionless again. Callable only by governance.
+    /// This call will error if the pool does not exist.
+    #[permission(Governance)]
+    SetPoolDistributor {
+        pool_id: PoolId,
+        distributor: Option<String>,
+    },
+
+    /// Sets the contract that receives the rewards of the given pool instead of the verifiers, e.g. a vesting or escrow contract.
+    /// Rewards are sent to the hook with a `PayoutHookMsg` naming the verifier and its beneficiary, as the execute message of a
+    /// native transfer or as the message of a CW20 `Send`. Setting it to None pays rewards out directly again. Callable only by
+    /// governance. This call will error if the pool does not exist.
+    #[permission(Governance)]
+    SetPoolPayoutHook {
+        pool_id: PoolId,
+        payout_hook: Option<String>,
+    },
+
+    /// Proposes to transfer the governance role to `new_governance`. The transfer only takes effect once the proposed address
+    /// accepts it with `AcceptGovernanceTransfer`, which must happen within `expiry_blocks` blocks. A new proposal replaces
+    /// any pending one. Callable only by governance.
+    #[permission(Governance)]
+    ProposeGovernanceTransfer {
+        new_governance: String,
+        expiry_blocks: nonempty::Uint64,
+    },
+
+    /// Accepts the pending governance transfer, making the sender the new governance. Callable only by the proposed address,
+    /// and only before the proposal expires.
+    #[permission(Specific(pending_governance))]
+    AcceptGovernanceTransfer {},
 }
 
+/// Messages that can only be sent by the chain itself, e.g. through a governance proposal. They overwrite state without
+/// loading it first, so a deployment whose state can't be deserialized anymore can be recovered without a migration
 #[cw_serde]
-#[derive(QueryResponses)]
+pub enum SudoMsg {
+    /// Replaces the contract config
+    RestoreConfig {
+        rewards_denom: String,
+        epoch_retention: u64,
+        track_event_attribution: bool,
+        reserve_epoch_rewards: bool,
+        attestation_target: Option<AttestationTarget>,
+    },
+
+    /// Replaces the params of an existing rewards pool, together with the epoch in which they took effect.
+    /// Epochs are counted from `created_at` on, so it must not be later than the current epoch of the pool
+    RestorePoolParams {
+        pool_id: PoolId,
+        params: Params,
+        created_at: Epoch,
+    },
+}
+
+/// Message the rewards of a pool with a payout hook are sent to the hook contract with
+#[cw_serde]
+pub enum PayoutHookMsg {
+    /// The attached rewards were earned by `verifier` in the given pool and belong to `beneficiary`,
+    /// which is the verifier's proxy address if it set one
+    Payout {
+        pool_id: PoolId,
+        verifier: Addr,
+        beneficiary: Addr,
+    },
+}
+
+/// Message attached to CW20 tokens sent to this contract
+#[cw_serde]
+pub enum ReceiveMsg {
+    /// Add the received tokens to an existing rewards pool
+    AddRewards { pool_id: PoolId },
+}
+
+#[cw_serde]
+#[derive(QueryResponses, QueryClient)]
+#[query_client(error = crate::client::Error)]
 pub enum QueryMsg {
     /// Gets the rewards pool details for the given `pool_id``
     #[returns(RewardsPool)]
@@ -112,18 +330,131 @@ pub enum QueryMsg {
     /// Gets the proxy address associated with the verifier, if any
     #[returns(Option<Addr>)]
     VerifierProxy { verifier: Address },
+
+    /// Gets the verifiers that set the given proxy address. This is at most one verifier,
+    /// unless the proxy address was shared by several verifiers before that was prevented
+    #[returns(Vec<Addr>)]
+    ProxyVerifiers { proxy_address: Address },
+
+    /// Gets whether the verifier opted out of the rewards of the given pool
+    #[returns(bool)]
+    RewardsOptOut { pool_id: PoolId, verifier: String },
+
+    /// Gets the pending governance transfer, if any. Expired proposals are not returned
+    #[returns(Option<GovernanceTransfer>)]
+    PendingGovernanceTransfer,
+
+    /// Gets the block height from which on rewards can be distributed again for the given pool
+    #[returns(DistributionCooldown)]
+    DistributionCooldown { pool_id: PoolId },
+
+    /// Gets the address that is allowed to distribute the rewards of the given pool besides governance, if any
+    #[returns(Option<Addr>)]
+    PoolDistributor { pool_id: PoolId },
+
+    /// Gets the contract that receives the rewards of the given pool instead of the verifiers, if any
+    #[returns(Option<Addr>)]
+    PoolPayoutHook { pool_id: PoolId },
+
+    /// Gets the epoch schedule of the given pool, together with all pools of the same chain that share it
+    #[returns(EpochSchedule)]
+    EpochSchedule { pool_id: PoolId },
+
+    /// Reports the effects `UpdatePoolParams` would have if it was executed with the given params now, without changing any state.
+    /// This query will error if the pool does not exist.
+    #[returns(PoolParamsUpdateEffects)]
+    PoolParamsUpdateEffects { pool_id: PoolId, params: Params },
+
+    /// Gets the ids of the events the verifier was credited for in the given epoch and pool, in ascending order.
+    /// The list is paginated by:
+    /// - start_after: the event id to start after, which the next page of results should start.
+    /// - limit: limit the number of event ids returned, default is 100.
+    ///
+    /// This query will error if event attribution is not tracked by the contract.
+    #[returns(ParticipationProof)]
+    ParticipationProof {
+        pool_id: PoolId,
+        verifier: String,
+        epoch_num: u64,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
 }
 
 #[cw_serde]
 pub struct RewardsPool {
+    pub rewards_asset: RewardsAsset,
+    /// Total balance of the pool, including reserved rewards
     pub balance: Uint128,
+    /// Part of the balance that is reserved for the rewards of epochs that have not been distributed yet
+    pub reserved_balance: Uint128,
+    /// Part of the balance that is not reserved and can cover the rewards of future epochs
+    pub free_balance: Uint128,
     pub epoch_duration: Uint64,
     pub rewards_per_epoch: Uint128,
     pub participation_threshold: Threshold,
+    pub remainder_policy: Option<RemainderPolicy>,
+    /// Remainder of previous epochs that is added to the rewards of the next distributed epoch
+    pub carried_remainder: Uint128,
     pub current_epoch_num: Uint64,
     pub last_distribution_epoch: Option<Uint64>,
 }
 
+#[cw_serde]
+pub struct EpochSchedule {
+    pub epoch_duration: Uint64,
+    pub current_epoch: Epoch,
+    /// Pools of the same chain whose epochs have the same numbers and boundaries, including the queried pool
+    pub aligned_pools: Vec<PoolId>,
+}
+
+#[cw_serde]
+pub struct PoolParamsUpdateEffects {
+    pub current_epoch: Epoch,
+    /// Epoch in which the new params would take effect. This is a new epoch starting at the current block
+    /// if the new epoch duration ends the current epoch immediately, otherwise it is the current epoch
+    pub effective_epoch: Epoch,
+    /// Rewards of each participant of the current epoch with the current and with the new params, ordered by verifier address
+    pub projected_rewards: Vec<ProjectedRewards>,
+    /// Reasons why `UpdatePoolParams` would fail with the new params. The update would succeed if this is empty
+    pub validation_failures: Vec<String>,
+}
+
+#[cw_serde]
+pub struct ProjectedRewards {
+    pub verifier: Addr,
+    pub current: Uint128,
+    pub proposed: Uint128,
+}
+
+#[cw_serde]
+pub struct DistributionCooldown {
+    /// Block height of the most recent reward distribution that processed all distributable epochs, if any
+    pub last_distribution_height: Option<u64>,
+    /// Block height from which on rewards can be distributed again
+    pub earliest_allowed_height: u64,
+    /// Number of blocks until the cooldown has passed, 0 if rewards can be distributed now
+    pub remaining_blocks: u64,
+}
+
+#[cw_serde]
+pub struct GovernanceTransfer {
+    pub current_governance: Addr,
+    pub new_governance: Addr,
+    /// Block height at which the transfer was proposed
+    pub proposed_at: u64,
+    /// Block height from which on the transfer can no longer be accepted
+    pub expires_at: u64,
+}
+
+#[cw_serde]
+pub struct ParticipationProof {
+    pub verifier: Addr,
+    pub epoch_num: u64,
+    /// Ids of the events the verifier was credited for
+    pub event_ids: Vec<String>,
+}
+
 #[cw_serde]
 pub struct Participation {
     pub event_count: u64,
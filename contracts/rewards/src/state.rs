@@ -1,21 +1,27 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::ops::Deref;
 
-use axelar_wasm_std::{nonempty, Threshold};
+use axelar_wasm_std::{nonempty, time, Threshold};
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Api, StdResult, Storage, Uint128};
-use cw_storage_plus::{Item, Key, KeyDeserialize, Map, Prefixer, PrimaryKey};
-use error_stack::{Result, ResultExt};
+use cosmwasm_std::{
+    Addr, Api, Order, OverflowError, OverflowOperation, StdError, StdResult, Storage, Uint128,
+};
+use cw_storage_plus::{
+    Bound, Index, IndexList, IndexedMap, Item, Key, KeyDeserialize, Map, MultiIndex, Prefixer,
+    PrimaryKey,
+};
+use error_stack::{bail, report, Result, ResultExt};
+use itertools::Itertools;
 use router_api::ChainName;
 
 use crate::error::ContractError;
-use crate::msg::{self, Params};
+use crate::msg::{self, GovernanceTransfer, Params, RemainderPolicy};
 
 /// Maps a (pool id, epoch number) pair to a tally for that epoch and rewards pool
 const TALLIES: Map<TallyId, EpochTally> = Map::new("tallies");
 
-/// Maps an (event id, pool id) pair to an Event
-const EVENTS: Map<(String, PoolId), Event> = Map::new("events");
+const EVENTS_PKEY_NAMESPACE: &str = "events";
+const EVENTS_IDX_NAMESPACE: &str = "events_by_tally";
 
 /// Maps the id to the rewards pool for given chain and contract
 const POOLS: Map<PoolId, RewardsPool> = Map::new("pools");
@@ -24,13 +30,112 @@ const POOLS: Map<PoolId, RewardsPool> = Map::new("pools");
 /// have had rewards distributed already and all epochs after have not yet had rewards distributed for this pool
 const WATERMARKS: Map<PoolId, u64> = Map::new("rewards_watermarks");
 
+/// Maps a rewards pool to the block height at which its most recent distribution caught up with all distributable epochs
+const DISTRIBUTION_HEIGHTS: Map<PoolId, u64> = Map::new("distribution_heights");
+
+/// Maps a rewards pool with an epoch time to the block time at which the contract first saw its current epoch
+const EPOCH_START_TIMES: Map<PoolId, EpochStartTime> = Map::new("epoch_start_times");
+
+/// Maps a (tally id, verifier, event id) triple to nothing. Each entry records that the verifier was credited for the event
+/// in the tally's epoch. Only populated if event attribution is tracked
+const EVENT_ATTRIBUTIONS: Map<(TallyId, Addr, String), ()> = Map::new("event_attributions");
+
 pub const VERIFIER_PROXY_ADDRESSES: Map<Addr, Addr> = Map::new("verifier_proxy_addresses");
 
+/// Maps a (proxy, verifier) pair to nothing. Each entry records that the verifier set the proxy address for its rewards.
+/// A proxy can only be set by a single verifier, except for proxies that were shared before this was enforced
+const PROXY_VERIFIERS: Map<(Addr, Addr), ()> = Map::new("proxy_verifiers");
+
+/// Maps a (pool id, verifier) pair to nothing. Each entry records that the verifier opted out of the pool's rewards
+const OPTED_OUT_VERIFIERS: Map<(PoolId, Addr), ()> = Map::new("opted_out_verifiers");
+
+/// Maps a rewards pool to the only address besides governance that may distribute its rewards.
+/// Rewards of pools without an entry can be distributed by anyone
+const POOL_DISTRIBUTORS: Map<PoolId, Addr> = Map::new("pool_distributors");
+
+/// Maps a rewards pool to the contract its rewards are paid out to instead of the verifiers, e.g. for vesting.
+/// Rewards of pools without an entry are paid out directly
+const POOL_PAYOUT_HOOKS: Map<PoolId, Addr> = Map::new("pool_payout_hooks");
+
 pub const CONFIG: Item<Config> = Item::new("config");
 
+/// Governance transfer that has been proposed but not yet accepted by the new governance
+const PENDING_GOVERNANCE_TRANSFER: Item<GovernanceTransfer> =
+    Item::new("pending_governance_transfer");
+
+/// Pools of the most recent `DistributeRewardsMulti` call, in the order their distributions were dispatched.
+/// The reply of a failed distribution identifies its pool by the index in this list
+const MULTI_DISTRIBUTION_POOLS: Item<Vec<PoolId>> = Item::new("multi_distribution_pools");
+
+/// Payouts to the payout hook of the most recent distribution, in the order they were dispatched.
+/// The reply of a failed payout identifies it by the index in this list
+const HOOKED_PAYOUTS: Item<Vec<HookedPayout>> = Item::new("hooked_payouts");
+
+/// Rewards of payouts to a pool's payout hook that failed, by pool and verifier. They are still owed to the verifier
+/// and added to its payout in the next distribution of the pool
+const OWED_PAYOUTS: Map<(PoolId, Addr), Uint128> = Map::new("owed_payouts");
+
 #[cw_serde]
 pub struct Config {
     pub rewards_denom: String,
+    /// Number of epochs below the rewards watermark for which tallies and events are kept before they can be pruned
+    pub epoch_retention: u64,
+    /// If set, the ids of the events each verifier was credited for are stored in addition to the participation counts
+    #[serde(default)]
+    pub track_event_attribution: bool,
+    /// If set, the rewards of an epoch are reserved from the pool's free balance when the epoch's tally is created
+    #[serde(default)]
+    pub reserve_epoch_rewards: bool,
+    /// If set, verifier participation is submitted to the service registry as performance attestations on distribution
+    #[serde(default)]
+    pub attestation_target: Option<AttestationTarget>,
+}
+
+#[cw_serde]
+pub struct AttestationTarget {
+    pub service_registry: Addr,
+    pub service_name: String,
+}
+
+impl AttestationTarget {
+    pub fn try_from_msg_attestation_target(
+        api: &dyn Api,
+        target: msg::AttestationTarget,
+    ) -> Result<Self, axelar_wasm_std::address::Error> {
+        Ok(Self {
+            service_registry: axelar_wasm_std::address::validate_cosmwasm_address(
+                api,
+                &target.service_registry,
+            )?,
+            service_name: target.service_name,
+        })
+    }
+}
+
+/// A multi-index that indexes events by the tally (pool id and epoch number) they were counted in,
+/// so all events of an epoch can be found without knowing their ids
+pub struct EventsIndex<'a>(MultiIndex<'a, TallyId, Event, (String, PoolId)>);
+
+impl IndexList<Event> for EventsIndex<'_> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<Event>> + '_> {
+        let v: Vec<&dyn Index<Event>> = vec![&self.0];
+        Box::new(v.into_iter())
+    }
+}
+
+/// Maps an (event id, pool id) pair to an Event. Events recorded before the index was introduced are not part of the index
+fn events<'a>() -> IndexedMap<(String, PoolId), Event, EventsIndex<'a>> {
+    IndexedMap::new(
+        EVENTS_PKEY_NAMESPACE,
+        EventsIndex(MultiIndex::new(
+            |_pk: &[u8], event: &Event| TallyId {
+                pool_id: event.pool_id.clone(),
+                epoch_num: event.epoch_num,
+            },
+            EVENTS_PKEY_NAMESPACE,
+            EVENTS_IDX_NAMESPACE,
+        )),
+    )
 }
 
 #[cw_serde]
@@ -67,6 +172,15 @@ impl PoolId {
     }
 }
 
+impl From<PoolId> for msg::PoolId {
+    fn from(pool_id: PoolId) -> Self {
+        Self {
+            chain_name: pool_id.chain_name,
+            contract: pool_id.contract.into_string(),
+        }
+    }
+}
+
 impl PrimaryKey<'_> for PoolId {
     type Prefix = ChainName;
     type SubPrefix = ();
@@ -129,6 +243,12 @@ impl KeyDeserialize for TallyId {
     }
 }
 
+impl Prefixer<'_> for TallyId {
+    fn prefix(&self) -> Vec<Key> {
+        self.key()
+    }
+}
+
 #[cw_serde]
 pub struct EpochTally {
     pub pool_id: PoolId,
@@ -136,6 +256,13 @@ pub struct EpochTally {
     pub participation: HashMap<String, u64>, // maps a verifier address to participation count. Can't use Addr as key else deserialization will fail
     pub epoch: Epoch,
     pub params: Params,
+    /// Amount of the pool balance that is reserved for the rewards of this epoch
+    #[serde(default)]
+    pub reserved: Uint128,
+    /// Set if the pool's free balance couldn't cover the epoch's rewards when they were reserved. The missing part is
+    /// settled from the pool balance at distribution
+    #[serde(default)]
+    pub under_reserved: bool,
 }
 
 impl EpochTally {
@@ -146,6 +273,8 @@ impl EpochTally {
             participation: HashMap::new(),
             epoch,
             params,
+            reserved: Uint128::zero(),
+            under_reserved: false,
         }
     }
 
@@ -159,24 +288,82 @@ impl EpochTally {
         self
     }
 
-    pub fn rewards_by_verifier(&self) -> HashMap<Addr, Uint128> {
+    pub fn rewards_by_verifier(&self, opted_out: &HashSet<Addr>) -> HashMap<Addr, Uint128> {
+        self.split_rewards(Uint128::zero(), opted_out).0
+    }
+
+    /// Splits the rewards of the epoch plus the carried remainder of earlier epochs equally amongst the verifiers to reward.
+    /// Verifiers that opted out of the pool's rewards still count towards the split, but their shares are added to the remainder.
+    /// Returns the rewards of each verifier and the remainder that is left after applying the remainder policy
+    pub fn split_rewards(
+        &self,
+        carried_remainder: Uint128,
+        opted_out: &HashSet<Addr>,
+    ) -> (HashMap<Addr, Uint128>, Uint128) {
         let verifiers_to_reward = self.verifiers_to_reward();
-        let total_rewards: Uint128 = self.params.rewards_per_epoch.into();
+        let verifier_count = Uint128::from(verifiers_to_reward.len() as u128);
+        let total_rewards =
+            Uint128::from(self.params.rewards_per_epoch).saturating_add(carried_remainder);
 
         let rewards_per_verifier = total_rewards
-            .checked_div(Uint128::from(verifiers_to_reward.len() as u128))
+            .checked_div(verifier_count)
             .unwrap_or_default();
 
         // A bit of a weird case. The rewards per epoch is too low to accommodate the number of verifiers to be rewarded
         // This can't be checked when setting the rewards per epoch, as the number of verifiers to be rewarded is not known at that time.
+        // The epoch's rewards stay in the pool as before, only a carried remainder is carried on
         if rewards_per_verifier.is_zero() {
-            return HashMap::new();
+            return (HashMap::new(), carried_remainder);
         }
 
-        verifiers_to_reward
+        let remainder = total_rewards
+            .checked_rem(verifier_count)
+            .unwrap_or_default();
+
+        let (opted_out_verifiers, verifiers_to_pay): (Vec<_>, Vec<_>) = verifiers_to_reward
             .into_iter()
-            .map(|verifier| (verifier, rewards_per_verifier))
-            .collect()
+            .partition(|verifier| opted_out.contains(verifier));
+        let opted_out_rewards =
+            rewards_per_verifier.saturating_mul(Uint128::from(opted_out_verifiers.len() as u128));
+
+        let mut rewards: HashMap<Addr, Uint128> = verifiers_to_pay
+            .iter()
+            .map(|verifier| (verifier.clone(), rewards_per_verifier))
+            .collect();
+
+        match self.params.remainder_policy {
+            Some(RemainderPolicy::RoundRobin) => {
+                // the remainder is smaller than the number of verifiers, so every top participant gets at most one token
+                let top_participants = verifiers_to_pay
+                    .into_iter()
+                    .sorted_by(|a, b| {
+                        self.participation(b)
+                            .cmp(&self.participation(a))
+                            .then_with(|| a.cmp(b))
+                    })
+                    .take(usize::try_from(remainder.u128()).unwrap_or(usize::MAX))
+                    .collect::<Vec<_>>();
+                // opted out verifiers can leave fewer top participants than tokens in the remainder
+                let unpaid_remainder =
+                    remainder.saturating_sub(Uint128::from(top_participants.len() as u128));
+
+                for verifier in top_participants {
+                    rewards
+                        .entry(verifier)
+                        .and_modify(|reward| *reward = reward.saturating_add(Uint128::one()));
+                }
+
+                (rewards, unpaid_remainder.saturating_add(opted_out_rewards))
+            }
+            _ => (rewards, remainder.saturating_add(opted_out_rewards)),
+        }
+    }
+
+    fn participation(&self, verifier: &Addr) -> u64 {
+        self.participation
+            .get(verifier.as_str())
+            .copied()
+            .unwrap_or_default()
     }
 
     fn verifiers_to_reward(&self) -> Vec<Addr> {
@@ -248,20 +435,100 @@ impl Epoch {
             let epochs_elapsed = cur_block_height
                 .saturating_sub(last_updated_epoch.block_height_started)
                 .checked_div(epoch_duration)
-                .expect("invalid invariant: epoch duration is zero");
+                .ok_or(ContractError::ZeroEpochDuration)?;
             Ok(Epoch {
                 epoch_num: last_updated_epoch
                     .epoch_num
                     .checked_add(epochs_elapsed)
-                    .expect(
-                        "epoch number should be strictly smaller than the current block height",
-                    ),
+                    .ok_or_else(|| OverflowError::new(OverflowOperation::Add))
+                    .map_err(ContractError::from)?,
                 block_height_started: last_updated_epoch
                     .block_height_started
-                    .checked_add(epochs_elapsed.saturating_mul(epoch_duration)).expect("start of current epoch should be strictly smaller than the current block height"),
+                    .checked_add(epochs_elapsed.saturating_mul(epoch_duration))
+                    .ok_or_else(|| OverflowError::new(OverflowOperation::Add))
+                    .map_err(ContractError::from)?,
             })
         }
     }
+
+    /// Returns the epoch in which new params take effect if they are set at the given block height during this epoch.
+    /// If the new epoch duration ends this epoch immediately, a new epoch starts at this block, incrementing the epoch number by 1.
+    /// This prevents us from jumping forward an arbitrary number of epochs, and maintains consistency for past events.
+    /// (i.e. we are in epoch 0, which started at block 0 and epoch duration is 1000. At epoch 500, the params
+    /// are updated to shorten the epoch duration to 100 blocks. We set the epoch number to 1, to prevent skipping
+    /// epochs 1-4, and so all events prior to the start of epoch 1 have an epoch number of 0)
+    pub fn after_params_update(
+        &self,
+        new_params: &Params,
+        block_height: u64,
+    ) -> Result<Epoch, ContractError> {
+        let should_end = self
+            .block_height_started
+            .checked_add(u64::from(new_params.epoch_duration))
+            .ok_or_else(|| OverflowError::new(OverflowOperation::Add))
+            .map_err(ContractError::from)?
+            < block_height;
+
+        if should_end {
+            self.next_starting_at(block_height)
+        } else {
+            Ok(self.clone())
+        }
+    }
+
+    /// The epoch following this one, starting at the given block height
+    pub fn next_starting_at(&self, block_height: u64) -> Result<Epoch, ContractError> {
+        Ok(Epoch {
+            block_height_started: block_height,
+            epoch_num: self
+                .epoch_num
+                .checked_add(1)
+                .ok_or_else(|| OverflowError::new(OverflowOperation::Add))
+                .map_err(ContractError::from)?,
+        })
+    }
+}
+
+/// Block time at which the contract first saw an epoch of a pool. Epochs are block-based, so this is only tracked
+/// for pools whose epochs also end after a wall-clock time
+#[cw_serde]
+pub struct EpochStartTime {
+    pub epoch_num: u64,
+    pub started_at: time::Timestamp,
+}
+
+/// The asset a rewards pool is funded with and pays out rewards in. Balances of pools with different assets are never mixed
+#[cw_serde]
+#[derive(Default)]
+pub enum RewardsAsset {
+    #[default]
+    Native,
+    Cw20(Addr),
+}
+
+impl RewardsAsset {
+    pub fn try_from_msg_rewards_asset(
+        api: &dyn Api,
+        rewards_asset: msg::RewardsAsset,
+    ) -> Result<Self, axelar_wasm_std::address::Error> {
+        match rewards_asset {
+            msg::RewardsAsset::Native => Ok(Self::Native),
+            msg::RewardsAsset::Cw20 { contract } => Ok(Self::Cw20(
+                axelar_wasm_std::address::validate_cosmwasm_address(api, &contract)?,
+            )),
+        }
+    }
+}
+
+impl From<RewardsAsset> for msg::RewardsAsset {
+    fn from(rewards_asset: RewardsAsset) -> Self {
+        match rewards_asset {
+            RewardsAsset::Native => Self::Native,
+            RewardsAsset::Cw20(contract) => Self::Cw20 {
+                contract: contract.into(),
+            },
+        }
+    }
 }
 
 #[cw_serde]
@@ -269,17 +536,57 @@ pub struct RewardsPool {
     pub id: PoolId,
     pub balance: Uint128,
     pub params: ParamsSnapshot,
+    /// Pools created before CW20 rewards were supported are funded with the native rewards denom
+    #[serde(default)]
+    pub asset: RewardsAsset,
+    /// Part of the balance that is reserved for the rewards of undistributed epochs, including the carried remainder
+    #[serde(default)]
+    pub reserved: Uint128,
+    /// Remainder of distributed epochs that is added to the rewards of the next distributed epoch
+    #[serde(default)]
+    pub carried_remainder: Uint128,
 }
 
 impl RewardsPool {
+    /// Part of the balance that is not reserved for any epoch
+    pub fn free_balance(&self) -> Uint128 {
+        self.balance.saturating_sub(self.reserved)
+    }
+
+    /// Pays out rewards from the free balance. Reservations must be released before their rewards can be paid out
     pub fn sub_reward(mut self, reward: Uint128) -> Result<Self, ContractError> {
-        self.balance = self
-            .balance
-            .checked_sub(reward)
-            .map_err(|_| ContractError::PoolBalanceInsufficient)?;
+        if reward > self.free_balance() {
+            return Err(ContractError::PoolBalanceInsufficient.into());
+        }
+        self.balance = self.balance.saturating_sub(reward);
 
         Ok(self)
     }
+
+    /// Changes the reservation of an epoch from `current` to `target`, taking any increase from the free balance
+    pub fn update_reservation(
+        mut self,
+        current: Uint128,
+        target: Uint128,
+    ) -> Result<Self, ContractError> {
+        let released = self.reserved.saturating_sub(current);
+        if target > self.balance.saturating_sub(released) {
+            return Err(ContractError::FreeBalanceInsufficient.into());
+        }
+        self.reserved = released.saturating_add(target);
+
+        Ok(self)
+    }
+
+    /// Changes the reservation of an epoch from `current` to as much of `target` as the balance allows.
+    /// Returns the pool and the amount that is reserved for the epoch now
+    pub fn reserve_available(mut self, current: Uint128, target: Uint128) -> (Self, Uint128) {
+        let released = self.reserved.saturating_sub(current);
+        let reserved = target.min(self.balance.saturating_sub(released));
+        self.reserved = released.saturating_add(reserved);
+
+        (self, reserved)
+    }
 }
 
 #[cw_serde]
@@ -288,8 +595,16 @@ pub struct Verifier {
     pub verifier_address: Addr,
     pub proxy_address: Option<Addr>,
 }
+#[cw_serde]
+pub struct HookedPayout {
+    pub pool_id: PoolId,
+    pub verifier: Addr,
+    pub amount: Uint128,
+}
+
 #[cw_serde]
 pub struct RewardsDistribution {
+    pub pool_id: PoolId,
     /// Amount of rewards denom each verifier received
     pub rewards: HashMap<Verifier, Uint128>,
     /// List of epochs processed for this distribution
@@ -298,9 +613,38 @@ pub struct RewardsDistribution {
     pub current_epoch: Epoch,
     /// True if there are more rewards to distribute (later epochs that have not yet been distributed but are ready for distribution at the time of calling)
     pub can_distribute_more: bool,
+    /// Balance left in the pool after the distribution
+    pub remaining_balance: Uint128,
+    /// Remainder policy of the pool at the time of the distribution
+    pub remainder_policy: Option<RemainderPolicy>,
+    /// Remainder that is carried on to the next distributed epoch
+    pub carried_remainder: Uint128,
+    /// Remainder that was burned in this distribution
+    pub burned: Uint128,
+}
+
+#[cw_serde]
+pub struct PrunedEpochs {
+    pub pool_id: PoolId,
+    /// Oldest and newest epoch for which entries were removed, if any
+    pub epochs: Option<(u64, u64)>,
+    pub tallies_pruned: u64,
+    pub events_pruned: u64,
+    pub attributions_pruned: u64,
+    /// True if there are more epochs older than the retention window left to prune
+    pub can_prune_more: bool,
 }
-pub fn load_config(storage: &dyn Storage) -> Config {
-    CONFIG.load(storage).expect("couldn't load config")
+
+pub fn load_config(storage: &dyn Storage) -> Result<Config, ContractError> {
+    CONFIG
+        .load(storage)
+        .change_context(ContractError::LoadConfig)
+}
+
+pub fn save_config(storage: &mut dyn Storage, config: &Config) -> Result<(), ContractError> {
+    CONFIG
+        .save(storage, config)
+        .change_context(ContractError::SaveConfig)
 }
 
 pub fn load_rewards_watermark(
@@ -317,11 +661,108 @@ pub fn load_event(
     event_id: String,
     pool_id: PoolId,
 ) -> Result<Option<Event>, ContractError> {
-    EVENTS
+    events()
         .may_load(storage, (event_id, pool_id))
         .change_context(ContractError::LoadEvent)
 }
 
+/// Returns up to `limit` ids of the events that were counted in the given epoch
+pub fn load_event_ids(
+    storage: &dyn Storage,
+    pool_id: PoolId,
+    epoch_num: u64,
+    limit: usize,
+) -> Result<Vec<String>, ContractError> {
+    events()
+        .idx
+        .0
+        .prefix(TallyId { pool_id, epoch_num })
+        .keys(storage, None, None, Order::Ascending)
+        .take(limit)
+        .map(|key| key.map(|(event_id, _)| event_id))
+        .collect::<StdResult<_>>()
+        .change_context(ContractError::LoadEvent)
+}
+
+pub fn save_event_attribution(
+    storage: &mut dyn Storage,
+    event: &Event,
+    verifier: Addr,
+) -> Result<(), ContractError> {
+    let tally_id = TallyId {
+        pool_id: event.pool_id.clone(),
+        epoch_num: event.epoch_num,
+    };
+
+    EVENT_ATTRIBUTIONS
+        .save(
+            storage,
+            (tally_id, verifier, event.event_id.clone().into()),
+            &(),
+        )
+        .change_context(ContractError::SaveEventAttribution)
+}
+
+/// Returns up to `limit` ids of the events the verifier was credited for in the given epoch, starting after `start_after`
+pub fn load_attributed_event_ids(
+    storage: &dyn Storage,
+    pool_id: PoolId,
+    epoch_num: u64,
+    verifier: Addr,
+    start_after: Option<String>,
+    limit: usize,
+) -> Result<Vec<String>, ContractError> {
+    EVENT_ATTRIBUTIONS
+        .prefix((TallyId { pool_id, epoch_num }, verifier))
+        .keys(
+            storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .collect::<StdResult<_>>()
+        .change_context(ContractError::LoadEventAttribution)
+}
+
+/// Returns up to `limit` (verifier, event id) pairs of the attributions recorded in the given epoch
+pub fn load_event_attributions(
+    storage: &dyn Storage,
+    pool_id: PoolId,
+    epoch_num: u64,
+    limit: usize,
+) -> Result<Vec<(Addr, String)>, ContractError> {
+    EVENT_ATTRIBUTIONS
+        .sub_prefix(TallyId { pool_id, epoch_num })
+        .keys(storage, None, None, Order::Ascending)
+        .take(limit)
+        .collect::<StdResult<_>>()
+        .change_context(ContractError::LoadEventAttribution)
+}
+
+pub fn remove_event_attribution(
+    storage: &mut dyn Storage,
+    pool_id: PoolId,
+    epoch_num: u64,
+    verifier: Addr,
+    event_id: String,
+) {
+    EVENT_ATTRIBUTIONS.remove(
+        storage,
+        (TallyId { pool_id, epoch_num }, verifier, event_id),
+    )
+}
+
+pub fn remove_event(
+    storage: &mut dyn Storage,
+    event_id: String,
+    pool_id: PoolId,
+) -> Result<(), ContractError> {
+    events()
+        .remove(storage, (event_id, pool_id))
+        .change_context(ContractError::RemoveEvent)
+}
+
 pub fn load_epoch_tally(
     storage: &dyn Storage,
     pool_id: PoolId,
@@ -332,6 +773,32 @@ pub fn load_epoch_tally(
         .change_context(ContractError::LoadEpochTally)
 }
 
+/// Returns the tally of the oldest epoch of the pool that is before the given epoch, if any
+pub fn load_oldest_epoch_tally_before(
+    storage: &dyn Storage,
+    pool_id: PoolId,
+    epoch_num: u64,
+) -> Result<Option<EpochTally>, ContractError> {
+    TALLIES
+        .range(
+            storage,
+            Some(Bound::inclusive(TallyId {
+                pool_id: pool_id.clone(),
+                epoch_num: 0,
+            })),
+            Some(Bound::exclusive(TallyId { pool_id, epoch_num })),
+            Order::Ascending,
+        )
+        .next()
+        .transpose()
+        .map(|tally| tally.map(|(_, tally)| tally))
+        .change_context(ContractError::LoadEpochTally)
+}
+
+pub fn remove_epoch_tally(storage: &mut dyn Storage, pool_id: PoolId, epoch_num: u64) {
+    TALLIES.remove(storage, TallyId { pool_id, epoch_num })
+}
+
 pub fn may_load_rewards_pool(
     storage: &dyn Storage,
     pool_id: PoolId,
@@ -349,6 +816,18 @@ pub fn load_rewards_pool(
         .ok_or(ContractError::RewardsPoolNotFound.into())
 }
 
+pub fn load_rewards_pools_of_chain(
+    storage: &dyn Storage,
+    chain_name: ChainName,
+) -> Result<Vec<RewardsPool>, ContractError> {
+    POOLS
+        .prefix(chain_name)
+        .range(storage, None, None, Order::Ascending)
+        .map(|res| res.map(|(_, pool)| pool))
+        .collect::<StdResult<Vec<_>>>()
+        .change_context(ContractError::LoadRewardsPool)
+}
+
 pub fn load_rewards_pool_params(
     storage: &dyn Storage,
     pool_id: PoolId,
@@ -368,8 +847,66 @@ pub fn save_rewards_watermark(
         .change_context(ContractError::SaveRewardsWatermark)
 }
 
+pub fn load_last_distribution_height(
+    storage: &dyn Storage,
+    pool_id: PoolId,
+) -> Result<Option<u64>, ContractError> {
+    DISTRIBUTION_HEIGHTS
+        .may_load(storage, pool_id)
+        .change_context(ContractError::LoadDistributionHeight)
+}
+
+/// Returns the block height from which on rewards can be distributed again for the given pool
+pub fn earliest_distribution_height(
+    storage: &dyn Storage,
+    pool_id: PoolId,
+) -> Result<u64, ContractError> {
+    let params = load_rewards_pool_params(storage, pool_id.clone())?.params;
+
+    Ok(
+        match (
+            load_last_distribution_height(storage, pool_id)?,
+            params.distribution_cooldown,
+        ) {
+            (Some(last_distribution_height), Some(cooldown)) => {
+                last_distribution_height.saturating_add(cooldown.into())
+            }
+            _ => 0,
+        },
+    )
+}
+
+pub fn save_last_distribution_height(
+    storage: &mut dyn Storage,
+    pool_id: PoolId,
+    block_height: u64,
+) -> Result<(), ContractError> {
+    DISTRIBUTION_HEIGHTS
+        .save(storage, pool_id, &block_height)
+        .change_context(ContractError::SaveDistributionHeight)
+}
+
+pub fn load_epoch_start_time(
+    storage: &dyn Storage,
+    pool_id: PoolId,
+) -> Result<Option<EpochStartTime>, ContractError> {
+    EPOCH_START_TIMES
+        .may_load(storage, pool_id)
+        .change_context(ContractError::LoadEpochStartTime)
+}
+
+pub fn save_epoch_start_time(
+    storage: &mut dyn Storage,
+    pool_id: PoolId,
+    start_time: &EpochStartTime,
+) -> Result<(), ContractError> {
+    EPOCH_START_TIMES
+        .save(storage, pool_id, start_time)
+        .change_context(ContractError::SaveEpochStartTime)
+}
+
 pub fn save_event(storage: &mut dyn Storage, event: &Event) -> Result<(), ContractError> {
-    EVENTS
+    events()
         .save(
             storage,
             (event.event_id.clone().into(), event.pool_id.clone()),
@@ -413,11 +950,42 @@ pub fn update_pool_params(
                 id: pool_id.to_owned(),
                 balance: pool.balance,
                 params: updated_params.to_owned(),
+                asset: pool.asset,
+                reserved: pool.reserved,
+                carried_remainder: pool.carried_remainder,
             }),
         })
         .change_context(ContractError::UpdateRewardsPool)
 }
 
+/// Overwrites the params of a rewards pool without deserializing the stored params first,
+/// so a pool whose params are corrupted can still be recovered
+pub fn restore_pool_params(
+    storage: &mut dyn Storage,
+    pool_id: &PoolId,
+    params: &ParamsSnapshot,
+) -> Result<RewardsPool, ContractError> {
+    let mut pool: serde_json::Value = storage
+        .get(&POOLS.key(pool_id.clone()))
+        .ok_or(report!(ContractError::RewardsPoolNotFound))
+        .and_then(|pool| {
+            serde_json::from_slice(&pool).change_context(ContractError::LoadRewardsPool)
+        })?;
+
+    pool.as_object_mut()
+        .ok_or(report!(ContractError::LoadRewardsPool))?
+        .insert(
+            "params".to_string(),
+            serde_json::to_value(params).change_context(ContractError::UpdateRewardsPool)?,
+        );
+
+    let pool: RewardsPool =
+        serde_json::from_value(pool).change_context(ContractError::UpdateRewardsPool)?;
+    save_rewards_pool(storage, &pool)?;
+
+    Ok(pool)
+}
+
 pub fn pool_exists(storage: &mut dyn Storage, pool_id: &PoolId) -> Result<bool, ContractError> {
     POOLS
         .may_load(storage, pool_id.to_owned())
@@ -436,18 +1004,75 @@ pub fn current_epoch(
     )
 }
 
+/// Sets the proxy address of the verifier. Fails if the proxy address is already set by another verifier,
+/// so rewards sent to a proxy can always be attributed to a single verifier
 pub fn save_verifier_proxy(
     storage: &mut dyn Storage,
     proxy_address: &Addr,
     verifier_addr: &Addr,
 ) -> Result<(), ContractError> {
+    if load_proxy_verifiers(storage, proxy_address)?
+        .iter()
+        .any(|verifier| verifier != verifier_addr)
+    {
+        bail!(ContractError::ProxyAddressAlreadySet(
+            proxy_address.to_owned()
+        ));
+    }
+
+    remove_verifier_proxy(storage, verifier_addr)?;
+
     VERIFIER_PROXY_ADDRESSES
         .save(storage, verifier_addr.to_owned(), proxy_address)
+        .change_context(ContractError::SaveProxyAddress)?;
+    PROXY_VERIFIERS
+        .save(
+            storage,
+            (proxy_address.to_owned(), verifier_addr.to_owned()),
+            &(),
+        )
         .change_context(ContractError::SaveProxyAddress)
 }
 
-pub fn remove_verifier_proxy(storage: &mut dyn Storage, verifier_addr: &Addr) {
-    VERIFIER_PROXY_ADDRESSES.remove(storage, verifier_addr.to_owned())
+pub fn remove_verifier_proxy(
+    storage: &mut dyn Storage,
+    verifier_addr: &Addr,
+) -> Result<(), ContractError> {
+    if let Some(proxy_address) = may_load_verifier_proxy(storage, verifier_addr)? {
+        PROXY_VERIFIERS.remove(storage, (proxy_address, verifier_addr.to_owned()));
+    }
+
+    VERIFIER_PROXY_ADDRESSES.remove(storage, verifier_addr.to_owned());
+    Ok(())
+}
+
+/// Returns the verifiers that set the given proxy address. This is at most one verifier,
+/// unless the proxy was shared before that was enforced
+pub fn load_proxy_verifiers(
+    storage: &dyn Storage,
+    proxy_address: &Addr,
+) -> Result<Vec<Addr>, ContractError> {
+    PROXY_VERIFIERS
+        .prefix(proxy_address.to_owned())
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<_>>()
+        .change_context(ContractError::LoadProxyAddress)
+}
+
+/// Adds the proxy addresses that were set before the reverse lookup was maintained to it
+pub fn index_proxy_verifiers(storage: &mut dyn Storage) -> Result<(), ContractError> {
+    let proxies: Vec<(Addr, Addr)> = VERIFIER_PROXY_ADDRESSES
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<_>>()
+        .change_context(ContractError::LoadProxyAddress)?;
+
+    for (verifier_addr, proxy_address) in proxies {
+        PROXY_VERIFIERS
+            .save(storage, (proxy_address, verifier_addr), &())
+            .change_context(ContractError::SaveProxyAddress)?;
+    }
+
+    Ok(())
 }
 
 pub fn may_load_verifier_proxy(
@@ -459,6 +1084,191 @@ pub fn may_load_verifier_proxy(
         .change_context(ContractError::LoadProxyAddress)
 }
 
+pub fn save_rewards_opt_out(
+    storage: &mut dyn Storage,
+    pool_id: PoolId,
+    verifier_addr: Addr,
+) -> Result<(), ContractError> {
+    OPTED_OUT_VERIFIERS
+        .save(storage, (pool_id, verifier_addr), &())
+        .change_context(ContractError::SaveRewardsOptOut)
+}
+
+pub fn remove_rewards_opt_out(storage: &mut dyn Storage, pool_id: PoolId, verifier_addr: Addr) {
+    OPTED_OUT_VERIFIERS.remove(storage, (pool_id, verifier_addr))
+}
+
+pub fn is_opted_out_of_rewards(
+    storage: &dyn Storage,
+    pool_id: PoolId,
+    verifier_addr: Addr,
+) -> bool {
+    OPTED_OUT_VERIFIERS.has(storage, (pool_id, verifier_addr))
+}
+
+/// Returns all verifiers that opted out of the rewards of the given pool
+pub fn load_opted_out_verifiers(
+    storage: &dyn Storage,
+    pool_id: PoolId,
+) -> Result<HashSet<Addr>, ContractError> {
+    OPTED_OUT_VERIFIERS
+        .prefix(pool_id)
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<_>>()
+        .change_context(ContractError::LoadRewardsOptOut)
+}
+
+pub fn save_pool_distributor(
+    storage: &mut dyn Storage,
+    pool_id: PoolId,
+    distributor: &Addr,
+) -> Result<(), ContractError> {
+    POOL_DISTRIBUTORS
+        .save(storage, pool_id, distributor)
+        .change_context(ContractError::SaveDistributor)
+}
+
+pub fn remove_pool_distributor(storage: &mut dyn Storage, pool_id: PoolId) {
+    POOL_DISTRIBUTORS.remove(storage, pool_id)
+}
+
+pub fn may_load_pool_distributor(
+    storage: &dyn Storage,
+    pool_id: PoolId,
+) -> Result<Option<Addr>, ContractError> {
+    POOL_DISTRIBUTORS
+        .may_load(storage, pool_id)
+        .change_context(ContractError::LoadDistributor)
+}
+
+pub fn save_pool_payout_hook(
+    storage: &mut dyn Storage,
+    pool_id: PoolId,
+    payout_hook: &Addr,
+) -> Result<(), ContractError> {
+    POOL_PAYOUT_HOOKS
+        .save(storage, pool_id, payout_hook)
+        .change_context(ContractError::SavePayoutHook)
+}
+
+pub fn remove_pool_payout_hook(storage: &mut dyn Storage, pool_id: PoolId) {
+    POOL_PAYOUT_HOOKS.remove(storage, pool_id)
+}
+
+pub fn may_load_pool_payout_hook(
+    storage: &dyn Storage,
+    pool_id: PoolId,
+) -> Result<Option<Addr>, ContractError> {
+    POOL_PAYOUT_HOOKS
+        .may_load(storage, pool_id)
+        .change_context(ContractError::LoadPayoutHook)
+}
+
+pub fn save_governance_transfer(
+    storage: &mut dyn Storage,
+    transfer: &GovernanceTransfer,
+) -> Result<(), ContractError> {
+    PENDING_GOVERNANCE_TRANSFER
+        .save(storage, transfer)
+        .change_context(ContractError::SaveGovernanceTransfer)
+}
+
+pub fn may_load_governance_transfer(
+    storage: &dyn Storage,
+) -> Result<Option<GovernanceTransfer>, ContractError> {
+    PENDING_GOVERNANCE_TRANSFER
+        .may_load(storage)
+        .change_context(ContractError::LoadGovernanceTransfer)
+}
+
+pub fn remove_governance_transfer(storage: &mut dyn Storage) {
+    PENDING_GOVERNANCE_TRANSFER.remove(storage)
+}
+
+pub fn save_multi_distribution_pools(
+    storage: &mut dyn Storage,
+    pool_ids: &[PoolId],
+) -> Result<(), ContractError> {
+    MULTI_DISTRIBUTION_POOLS
+        .save(storage, &pool_ids.to_vec())
+        .change_context(ContractError::SaveMultiDistribution)
+}
+
+pub fn load_multi_distribution_pool(
+    storage: &dyn Storage,
+    index: u64,
+) -> Result<PoolId, ContractError> {
+    MULTI_DISTRIBUTION_POOLS
+        .may_load(storage)
+        .change_context(ContractError::LoadMultiDistribution)?
+        .and_then(|pool_ids| {
+            usize::try_from(index)
+                .ok()
+                .and_then(|index| pool_ids.into_iter().nth(index))
+        })
+        .ok_or(report!(ContractError::UnknownMultiDistributionPool(index)))
+}
+
+pub fn save_hooked_payouts(
+    storage: &mut dyn Storage,
+    payouts: &[HookedPayout],
+) -> Result<(), ContractError> {
+    HOOKED_PAYOUTS
+        .save(storage, &payouts.to_vec())
+        .change_context(ContractError::SaveHookedPayouts)
+}
+
+pub fn load_hooked_payout(
+    storage: &dyn Storage,
+    index: u64,
+) -> Result<HookedPayout, ContractError> {
+    HOOKED_PAYOUTS
+        .may_load(storage)
+        .change_context(ContractError::LoadHookedPayouts)?
+        .and_then(|payouts| {
+            usize::try_from(index)
+                .ok()
+                .and_then(|index| payouts.into_iter().nth(index))
+        })
+        .ok_or(report!(ContractError::UnknownHookedPayout(index)))
+}
+
+/// Adds the amount of a failed payout to the rewards still owed to the verifier
+pub fn add_owed_payout(
+    storage: &mut dyn Storage,
+    HookedPayout {
+        pool_id,
+        verifier,
+        amount,
+    }: HookedPayout,
+) -> Result<Uint128, ContractError> {
+    OWED_PAYOUTS
+        .update(storage, (pool_id, verifier), |owed| {
+            owed.unwrap_or_default()
+                .checked_add(amount)
+                .map_err(StdError::from)
+        })
+        .change_context(ContractError::SaveOwedPayout)
+}
+
+/// Loads the rewards owed to verifiers of the pool and removes them, as they are paid out with the current distribution
+pub fn take_owed_payouts(
+    storage: &mut dyn Storage,
+    pool_id: &PoolId,
+) -> Result<Vec<(Addr, Uint128)>, ContractError> {
+    let owed = OWED_PAYOUTS
+        .prefix(pool_id.clone())
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()
+        .change_context(ContractError::LoadOwedPayouts)?;
+
+    for (verifier, _) in &owed {
+        OWED_PAYOUTS.remove(storage, (pool_id.clone(), verifier.clone()));
+    }
+
+    Ok(owed)
+}
+
 pub fn load_verifier(
     storage: &dyn Storage,
     verifier_addr: &Addr,
@@ -536,6 +1346,9 @@ mod test {
                 epoch_duration: 100u64.try_into().unwrap(),
                 rewards_per_epoch: Uint128::new(1000).try_into().unwrap(),
                 participation_threshold: (1, 2).try_into().unwrap(),
+                distribution_cooldown: None,
+                remainder_policy: None,
+                epoch_time: None,
             },
             pool_id: PoolId {
                 chain_name: "mock-chain".parse().unwrap(),
@@ -551,6 +1364,8 @@ mod test {
                 epoch_num: 1u64,
                 block_height_started: 0u64,
             },
+            reserved: Uint128::zero(),
+            under_reserved: false,
         };
 
         let test_cases = vec![
@@ -584,11 +1399,86 @@ mod test {
         ];
 
         for test_case in test_cases {
-            let rewards = test_case.0.rewards_by_verifier();
+            let rewards = test_case.0.rewards_by_verifier(&HashSet::new());
             assert_eq!(rewards, test_case.1);
         }
     }
 
+    #[test]
+    fn split_rewards_applies_remainder_policy() {
+        let api = MockApi::default();
+        let tally = |remainder_policy| EpochTally {
+            params: Params {
+                epoch_duration: 100u64.try_into().unwrap(),
+                rewards_per_epoch: Uint128::new(1001).try_into().unwrap(),
+                participation_threshold: (1, 2).try_into().unwrap(),
+                distribution_cooldown: None,
+                remainder_policy,
+                epoch_time: None,
+            },
+            pool_id: PoolId {
+                chain_name: "mock-chain".parse().unwrap(),
+                contract: api.addr_make("pool_contract"),
+            },
+            event_count: 101u64,
+            participation: HashMap::from([
+                (api.addr_make("verifier1").to_string(), 51u64),
+                (api.addr_make("verifier2").to_string(), 50u64),
+                (api.addr_make("verifier3").to_string(), 75u64),
+            ]),
+            epoch: Epoch {
+                epoch_num: 1u64,
+                block_height_started: 0u64,
+            },
+            reserved: Uint128::zero(),
+            under_reserved: false,
+        };
+        let rewards = |verifier1: u128, verifier3: u128| {
+            HashMap::from([
+                (api.addr_make("verifier1"), Uint128::from(verifier1)),
+                (api.addr_make("verifier3"), Uint128::from(verifier3)),
+            ])
+        };
+
+        // the remainder is left over for the carry and burn policies, and without a policy
+        for policy in [
+            None,
+            Some(RemainderPolicy::Carry),
+            Some(RemainderPolicy::Burn),
+        ] {
+            assert_eq!(
+                tally(policy).split_rewards(Uint128::zero(), &HashSet::new()),
+                (rewards(500, 500), Uint128::one())
+            );
+        }
+
+        // a carried remainder is added to the epoch's rewards
+        assert_eq!(
+            tally(Some(RemainderPolicy::Carry)).split_rewards(Uint128::new(2), &HashSet::new()),
+            (rewards(501, 501), Uint128::one())
+        );
+
+        // the verifier with the highest participation receives the remainder
+        assert_eq!(
+            tally(Some(RemainderPolicy::RoundRobin))
+                .split_rewards(Uint128::zero(), &HashSet::new()),
+            (rewards(500, 501), Uint128::zero())
+        );
+
+        // the share of an opted out verifier is added to the remainder
+        let opted_out = HashSet::from([api.addr_make("verifier3")]);
+        let verifier1_rewards =
+            |amount: u128| HashMap::from([(api.addr_make("verifier1"), Uint128::from(amount))]);
+        assert_eq!(
+            tally(Some(RemainderPolicy::Carry)).split_rewards(Uint128::zero(), &opted_out),
+            (verifier1_rewards(500), Uint128::new(501))
+        );
+        assert_eq!(
+            tally(Some(RemainderPolicy::RoundRobin)).split_rewards(Uint128::zero(), &opted_out),
+            (verifier1_rewards(501), Uint128::new(500))
+        );
+    }
+
     #[test]
     fn sub_reward_from_pool() {
         let params = ParamsSnapshot {
@@ -596,6 +1486,9 @@ mod test {
                 participation_threshold: (Uint64::new(1), Uint64::new(2)).try_into().unwrap(),
                 epoch_duration: 100u64.try_into().unwrap(),
                 rewards_per_epoch: Uint128::from(1000u128).try_into().unwrap(),
+                distribution_cooldown: None,
+                remainder_policy: None,
+                epoch_time: None,
             },
             created_at: Epoch {
                 epoch_num: 1,
@@ -609,6 +1502,9 @@ mod test {
             },
             balance: Uint128::from(100u128),
             params,
+            asset: RewardsAsset::Native,
+            reserved: Uint128::zero(),
+            carried_remainder: Uint128::zero(),
         };
         let new_pool = pool.sub_reward(Uint128::from(50u128)).unwrap();
         assert_eq!(new_pool.balance, Uint128::from(50u128));
@@ -620,6 +1516,80 @@ mod test {
         ));
     }
 
+    #[test]
+    fn update_reservation_of_pool() {
+        let params = ParamsSnapshot {
+            params: Params {
+                participation_threshold: (Uint64::new(1), Uint64::new(2)).try_into().unwrap(),
+                epoch_duration: 100u64.try_into().unwrap(),
+                rewards_per_epoch: Uint128::from(1000u128).try_into().unwrap(),
+                distribution_cooldown: None,
+                remainder_policy: None,
+                epoch_time: None,
+            },
+            created_at: Epoch {
+                epoch_num: 1,
+                block_height_started: 1,
+            },
+        };
+        let pool = RewardsPool {
+            id: PoolId {
+                chain_name: "mock-chain".parse().unwrap(),
+                contract: MockApi::default().addr_make("pool_contract"),
+            },
+            balance: Uint128::from(100u128),
+            params,
+            asset: RewardsAsset::Native,
+            reserved: Uint128::zero(),
+            carried_remainder: Uint128::zero(),
+        };
+
+        let pool = pool
+            .update_reservation(Uint128::zero(), Uint128::from(80u128))
+            .unwrap();
+        assert_eq!(pool.reserved, Uint128::from(80u128));
+        assert_eq!(pool.free_balance(), Uint128::from(20u128));
+
+        // reserved rewards cannot be paid out before they are released
+        assert!(matches!(
+            pool.clone()
+                .sub_reward(Uint128::from(30u128))
+                .unwrap_err()
+                .current_context(),
+            ContractError::PoolBalanceInsufficient
+        ));
+        assert!(matches!(
+            pool.clone()
+                .update_reservation(Uint128::zero(), Uint128::from(30u128))
+                .unwrap_err()
+                .current_context(),
+            ContractError::FreeBalanceInsufficient
+        ));
+
+        let pool = pool
+            .update_reservation(Uint128::from(80u128), Uint128::from(100u128))
+            .unwrap()
+            .update_reservation(Uint128::from(100u128), Uint128::zero())
+            .unwrap()
+            .sub_reward(Uint128::from(100u128))
+            .unwrap();
+        assert_eq!(pool.balance, Uint128::zero());
+        assert_eq!(pool.reserved, Uint128::zero());
+
+        let pool = RewardsPool {
+            balance: Uint128::from(100u128),
+            ..pool
+        };
+        let (pool, reserved) = pool.reserve_available(Uint128::zero(), Uint128::from(80u128));
+        assert_eq!(reserved, Uint128::from(80u128));
+
+        // only the free balance is reserved if it can't cover the target
+        let (pool, reserved) = pool.reserve_available(Uint128::zero(), Uint128::from(30u128));
+        assert_eq!(reserved, Uint128::from(20u128));
+        assert_eq!(pool.reserved, Uint128::from(100u128));
+        assert_eq!(pool.free_balance(), Uint128::zero());
+    }
+
     #[test]
     fn save_and_load_rewards_watermark() {
         let mut mock_deps = mock_dependencies();
@@ -763,6 +1733,9 @@ mod test {
                 epoch_duration: 100u64.try_into().unwrap(),
                 rewards_per_epoch: rewards_rate,
                 participation_threshold: (1, 2).try_into().unwrap(),
+                distribution_cooldown: None,
+                remainder_policy: None,
+                epoch_time: None,
             },
         );
 
@@ -807,6 +1780,9 @@ mod test {
                 participation_threshold: (Uint64::new(1), Uint64::new(2)).try_into().unwrap(),
                 epoch_duration: 100u64.try_into().unwrap(),
                 rewards_per_epoch: Uint128::from(1000u128).try_into().unwrap(),
+                distribution_cooldown: None,
+                remainder_policy: None,
+                epoch_time: None,
             },
             created_at: Epoch {
                 epoch_num: 1,
@@ -823,6 +1799,9 @@ mod test {
             ),
             params,
             balance: Uint128::zero(),
+            asset: RewardsAsset::Native,
+            reserved: Uint128::zero(),
+            carried_remainder: Uint128::zero(),
         };
         let res = save_rewards_pool(mock_deps.as_mut().storage, &pool);
         assert!(res.is_ok());
@@ -832,4 +1811,190 @@ mod test {
         assert!(loaded.is_ok());
         assert_eq!(loaded.unwrap(), pool);
     }
+
+    #[test]
+    fn load_config_fails_without_config() {
+        let mock_deps = mock_dependencies();
+
+        assert_err_contains!(
+            load_config(mock_deps.as_ref().storage),
+            ContractError,
+            ContractError::LoadConfig
+        );
+    }
+
+    #[test]
+    fn current_epoch_fails_on_overflow() {
+        let params = ParamsSnapshot {
+            params: Params {
+                participation_threshold: (Uint64::new(1), Uint64::new(2)).try_into().unwrap(),
+                epoch_duration: 1u64.try_into().unwrap(),
+                rewards_per_epoch: Uint128::from(1000u128).try_into().unwrap(),
+                distribution_cooldown: None,
+                remainder_policy: None,
+                epoch_time: None,
+            },
+            created_at: Epoch {
+                epoch_num: u64::MAX,
+                block_height_started: 0,
+            },
+        };
+
+        assert!(Epoch::current(&params, 0).is_ok());
+        assert_err_contains!(
+            Epoch::current(&params, 1),
+            ContractError,
+            ContractError::Overflow(_)
+        );
+    }
+
+    #[test]
+    fn restore_corrupted_pool_params() {
+        let params = Params {
+            participation_threshold: (Uint64::new(1), Uint64::new(2)).try_into().unwrap(),
+            epoch_duration: 100u64.try_into().unwrap(),
+            rewards_per_epoch: Uint128::from(1000u128).try_into().unwrap(),
+            distribution_cooldown: None,
+            remainder_policy: None,
+            epoch_time: None,
+        };
+        let mut mock_deps = mock_dependencies();
+
+        let pool = RewardsPool {
+            id: PoolId::new(
+                "mock-chain".parse().unwrap(),
+                MockApi::default().addr_make("some contract"),
+            ),
+            params: ParamsSnapshot {
+                params: params.clone(),
+                created_at: Epoch {
+                    epoch_num: 1,
+                    block_height_started: 1,
+                },
+            },
+            balance: Uint128::new(500),
+            asset: RewardsAsset::Native,
+            reserved: Uint128::zero(),
+            carried_remainder: Uint128::zero(),
+        };
+        save_rewards_pool(mock_deps.as_mut().storage, &pool).unwrap();
+
+        // a zero epoch duration can't be deserialized
+        let key = POOLS.key(pool.id.clone());
+        let mut raw: serde_json::Value =
+            serde_json::from_slice(&mock_deps.storage.get(&key).unwrap()).unwrap();
+        raw["params"]["params"]["epoch_duration"] = "0".into();
+        mock_deps
+            .storage
+            .set(&key, &serde_json::to_vec(&raw).unwrap());
+        assert_err_contains!(
+            load_rewards_pool(mock_deps.as_ref().storage, pool.id.clone()),
+            ContractError,
+            ContractError::LoadRewardsPool
+        );
+
+        let restored_params = ParamsSnapshot {
+            params,
+            created_at: Epoch {
+                epoch_num: 5,
+                block_height_started: 500,
+            },
+        };
+        restore_pool_params(mock_deps.as_mut().storage, &pool.id, &restored_params).unwrap();
+
+        assert_eq!(
+            load_rewards_pool(mock_deps.as_ref().storage, pool.id.clone()).unwrap(),
+            RewardsPool {
+                params: restored_params,
+                ..pool
+            }
+        );
+    }
+
+    #[test]
+    fn proxy_verifiers_should_follow_verifier_proxies() {
+        let mut mock_deps = mock_dependencies();
+        let api = MockApi::default();
+        let verifier = api.addr_make("verifier");
+        let proxy = api.addr_make("proxy");
+        let new_proxy = api.addr_make("new proxy");
+
+        save_verifier_proxy(mock_deps.as_mut().storage, &proxy, &verifier).unwrap();
+        assert_eq!(
+            load_proxy_verifiers(mock_deps.as_ref().storage, &proxy).unwrap(),
+            vec![verifier.clone()]
+        );
+
+        save_verifier_proxy(mock_deps.as_mut().storage, &new_proxy, &verifier).unwrap();
+        assert!(load_proxy_verifiers(mock_deps.as_ref().storage, &proxy)
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            load_proxy_verifiers(mock_deps.as_ref().storage, &new_proxy).unwrap(),
+            vec![verifier.clone()]
+        );
+
+        remove_verifier_proxy(mock_deps.as_mut().storage, &verifier).unwrap();
+        assert!(load_proxy_verifiers(mock_deps.as_ref().storage, &new_proxy)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn save_verifier_proxy_fails_if_proxy_is_set_by_another_verifier() {
+        let mut mock_deps = mock_dependencies();
+        let api = MockApi::default();
+        let verifier = api.addr_make("verifier");
+        let other_verifier = api.addr_make("other verifier");
+        let proxy = api.addr_make("proxy");
+
+        save_verifier_proxy(mock_deps.as_mut().storage, &proxy, &verifier).unwrap();
+        // setting the same proxy again is fine
+        save_verifier_proxy(mock_deps.as_mut().storage, &proxy, &verifier).unwrap();
+
+        assert_err_contains!(
+            save_verifier_proxy(mock_deps.as_mut().storage, &proxy, &other_verifier),
+            ContractError,
+            ContractError::ProxyAddressAlreadySet(_)
+        );
+        assert_eq!(
+            may_load_verifier_proxy(mock_deps.as_ref().storage, &other_verifier).unwrap(),
+            None
+        );
+
+        remove_verifier_proxy(mock_deps.as_mut().storage, &verifier).unwrap();
+        save_verifier_proxy(mock_deps.as_mut().storage, &proxy, &other_verifier).unwrap();
+        assert_eq!(
+            load_proxy_verifiers(mock_deps.as_ref().storage, &proxy).unwrap(),
+            vec![other_verifier]
+        );
+    }
+
+    #[test]
+    fn index_proxy_verifiers_should_add_existing_proxies() {
+        let mut mock_deps = mock_dependencies();
+        let api = MockApi::default();
+        let verifier = api.addr_make("verifier");
+        let other_verifier = api.addr_make("other verifier");
+        let proxy = api.addr_make("proxy");
+
+        // proxies set before the reverse lookup existed, including a shared one
+        for verifier in [&verifier, &other_verifier] {
+            VERIFIER_PROXY_ADDRESSES
+                .save(mock_deps.as_mut().storage, verifier.clone(), &proxy)
+                .unwrap();
+        }
+        assert!(load_proxy_verifiers(mock_deps.as_ref().storage, &proxy)
+            .unwrap()
+            .is_empty());
+
+        index_proxy_verifiers(mock_deps.as_mut().storage).unwrap();
+
+        let mut expected = vec![verifier, other_verifier];
+        expected.sort();
+        assert_eq!(
+            load_proxy_verifiers(mock_deps.as_ref().storage, &proxy).unwrap(),
+            expected
+        );
+    }
 }
@@ -2,9 +2,11 @@ use axelar_wasm_std::{address, killswitch, permission_control, FnExt};
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    to_json_binary, Addr, Binary, Deps, DepsMut, Env, MessageInfo, Response, Storage,
+    to_json_binary, Addr, Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response, Storage,
 };
+use error_stack::{report, ResultExt};
 use router_api::error::Error;
+use router_api::Tap;
 
 use crate::events::RouterInstantiated;
 use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
@@ -14,9 +16,28 @@ use crate::state::{load_chain_by_gateway, load_config, Config};
 mod execute;
 mod migrations;
 mod query;
+mod reply;
 
 pub use migrations::{migrate, MigrateMsg};
 
+pub const TAP_REPLY_ID: u64 = 1;
+
+/// Submessages dispatched by this contract that expect a reply
+enum ReplyId {
+    Tap,
+}
+
+impl TryFrom<u64> for ReplyId {
+    type Error = ();
+
+    fn try_from(id: u64) -> Result<Self, Self::Error> {
+        match id {
+            TAP_REPLY_ID => Ok(ReplyId::Tap),
+            _ => Err(()),
+        }
+    }
+}
+
 pub const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
 pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
@@ -36,8 +57,14 @@ pub fn instantiate(
     permission_control::set_admin(deps.storage, &admin)?;
     permission_control::set_governance(deps.storage, &governance)?;
 
+    let coordinator = msg
+        .coordinator_address
+        .map(|addr| address::validate_cosmwasm_address(deps.api, &addr))
+        .transpose()?;
+
     let config = Config {
         axelarnet_gateway: axelarnet_gateway.clone(),
+        coordinator,
     };
 
     state::save_config(deps.storage, &config)?;
@@ -53,7 +80,7 @@ pub fn instantiate(
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, axelar_wasm_std::error::ContractError> {
@@ -61,11 +88,13 @@ pub fn execute(
         deps.storage,
         &info.sender,
         find_gateway_address(&info.sender),
+        match_coordinator,
     )? {
         ExecuteMsg::RegisterChain {
             chain,
             gateway_address,
             msg_id_format,
+            address_format,
         } => {
             let gateway_address = address::validate_cosmwasm_address(deps.api, &gateway_address)?;
             Ok(execute::register_chain(
@@ -74,8 +103,13 @@ pub fn execute(
                 chain,
                 gateway_address,
                 msg_id_format,
+                address_format,
             )?)
         }
+        ExecuteMsg::UpdateAddressFormat {
+            chain,
+            address_format,
+        } => execute::update_address_format(deps.storage, chain, address_format),
         ExecuteMsg::UpgradeGateway {
             chain,
             contract_address,
@@ -85,18 +119,91 @@ pub fn execute(
         }
         ExecuteMsg::FreezeChains { chains } => execute::freeze_chains(deps.storage, chains),
         ExecuteMsg::UnfreezeChains { chains } => execute::unfreeze_chains(deps.storage, chains),
-        ExecuteMsg::RouteMessages(msgs) => Ok(execute::route_messages(
+        ExecuteMsg::RouteMessages(msgs) => {
+            execute::record_routed_messages(deps.storage, env.block.height, &msgs, None)?;
+            execute::route_messages(
+                deps.storage,
+                deps.querier,
+                env.block.height,
+                info.sender,
+                msgs,
+            )
+        }
+        ExecuteMsg::RouteMessagesWithTtl { messages, ttl } => {
+            // the expiry is recorded first, so it is forwarded to the destination gateways
+            execute::record_routed_messages(deps.storage, env.block.height, &messages, Some(ttl))?;
+            execute::route_messages(
+                deps.storage,
+                deps.querier,
+                env.block.height,
+                info.sender,
+                messages,
+            )
+        }
+        ExecuteMsg::ExpireMessages(cc_ids) => Ok(execute::expire_messages(
             deps.storage,
-            deps.querier,
-            info.sender,
-            msgs,
+            env.block.height,
+            cc_ids,
         )?),
         ExecuteMsg::DisableRouting => execute::disable_routing(deps.storage),
         ExecuteMsg::EnableRouting => execute::enable_routing(deps.storage),
+        ExecuteMsg::RegisterTap {
+            source_chain,
+            destination_chain,
+            contract_address,
+            gas_limit,
+        } => {
+            let address = address::validate_cosmwasm_address(deps.api, &contract_address)?;
+            Ok(execute::register_tap(
+                deps.storage,
+                source_chain,
+                destination_chain,
+                Tap { address, gas_limit },
+            )?)
+        }
+        ExecuteMsg::DeregisterTap {
+            source_chain,
+            destination_chain,
+            contract_address,
+        } => {
+            let address = address::validate_cosmwasm_address(deps.api, &contract_address)?;
+            Ok(execute::deregister_tap(
+                deps.storage,
+                source_chain,
+                destination_chain,
+                address,
+            )?)
+        }
+        ExecuteMsg::RecordExecutions(cc_ids) => Ok(execute::record_executions(
+            deps.storage,
+            env.block.height,
+            info.sender,
+            cc_ids,
+        )?),
+        ExecuteMsg::RecallMessages(cc_ids) => Ok(execute::recall_messages(
+            deps.storage,
+            env.block.height,
+            cc_ids,
+        )?),
+        ExecuteMsg::SetMaintenanceWindows { chain, windows } => Ok(
+            execute::set_maintenance_windows(deps.storage, chain, windows)?,
+        ),
     }?
     .then(Ok)
 }
 
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(
+    _deps: DepsMut,
+    _env: Env,
+    reply: Reply,
+) -> Result<Response, axelar_wasm_std::error::ContractError> {
+    match axelar_wasm_std::reply::route(&reply)? {
+        ReplyId::Tap => reply::tap_failed_reply(reply),
+    }
+    .map_err(axelar_wasm_std::error::ContractError::from)
+}
+
 fn find_gateway_address(
     sender: &Addr,
 ) -> impl FnOnce(&dyn Storage, &ExecuteMsg) -> error_stack::Result<Addr, Error> + '_ {
@@ -113,10 +220,20 @@ fn find_gateway_address(
     }
 }
 
+fn match_coordinator(
+    storage: &dyn Storage,
+    _: &ExecuteMsg,
+) -> error_stack::Result<Addr, permission_control::Error> {
+    load_config(storage)
+        .change_context(permission_control::Error::Unauthorized)?
+        .coordinator
+        .ok_or_else(|| report!(permission_control::Error::Unauthorized))
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(
     deps: Deps,
-    _env: Env,
+    env: Env,
     msg: QueryMsg,
 ) -> Result<Binary, axelar_wasm_std::error::ContractError> {
     match msg {
@@ -125,6 +242,29 @@ pub fn query(
             to_json_binary(&query::chains(deps.storage, start_after, limit)?)
         }
         QueryMsg::IsEnabled => to_json_binary(&killswitch::is_contract_active(deps.storage)),
+        QueryMsg::Taps {
+            source_chain,
+            destination_chain,
+        } => to_json_binary(&query::taps(deps.storage, source_chain, destination_chain)?),
+        QueryMsg::Latency {
+            source_chain,
+            destination_chain,
+            window,
+        } => to_json_binary(&query::latency(
+            deps.storage,
+            env.block.height,
+            source_chain,
+            destination_chain,
+            window,
+        )?),
+        QueryMsg::RecalledMessages { start_after, limit } => {
+            to_json_binary(&query::recalled_messages(deps.storage, start_after, limit)?)
+        }
+        QueryMsg::MessageExpiry(cc_id) => to_json_binary(&query::message_expiry(
+            deps.storage,
+            env.block.height,
+            cc_id,
+        )?),
     }
     .map_err(axelar_wasm_std::error::ContractError::from)
 }
@@ -135,17 +275,24 @@ mod test {
     use std::str::FromStr;
 
     use axelar_core_std::nexus::test_utils::reply_with_is_chain_registered;
+    use axelar_wasm_std::address::AddressFormat;
     use axelar_wasm_std::err_contains;
     use axelar_wasm_std::error::ContractError;
+    use axelar_wasm_std::maintenance::MaintenanceWindow;
     use axelar_wasm_std::msg_id::HexTxHashAndEventIndex;
     use cosmwasm_std::testing::{
         message_info, mock_dependencies, mock_env, MockApi, MockQuerier, MockStorage,
     };
-    use cosmwasm_std::{from_json, Addr, CosmosMsg, Empty, OwnedDeps, WasmMsg};
+    use cosmwasm_std::{
+        from_json, Addr, CosmosMsg, Empty, Event, OwnedDeps, SubMsg, SubMsgResult, WasmMsg,
+    };
+    use gateway_api::msg::MessageWithExpiry;
     use permission_control::Permission;
     use router_api::error::Error;
+    use router_api::msg::TapExecuteMsg;
     use router_api::{
-        ChainEndpoint, ChainName, CrossChainId, GatewayDirection, Message, FIELD_DELIMITER,
+        ChainEndpoint, ChainName, CrossChainId, GatewayDirection, LatencyPercentiles, Message,
+        MessageExpiry, FIELD_DELIMITER,
     };
 
     use super::*;
@@ -155,6 +302,7 @@ mod test {
     const GOVERNANCE_ADDRESS: &str = "governance";
     const AXELARNET_GATEWAY_ADDRESS: &str = "axelarnet_gateway";
     const UNAUTHORIZED_ADDRESS: &str = "unauthorized";
+    const COORDINATOR_ADDRESS: &str = "coordinator";
 
     fn setup() -> OwnedDeps<MockStorage, MockApi, MockQuerier, Empty> {
         let mut deps = mock_dependencies();
@@ -171,6 +319,7 @@ mod test {
                 admin_address: api.addr_make(ADMIN_ADDRESS).to_string(),
                 governance_address: api.addr_make(GOVERNANCE_ADDRESS).to_string(),
                 axelarnet_gateway: api.addr_make(AXELARNET_GATEWAY_ADDRESS).to_string(),
+                coordinator_address: Some(api.addr_make(COORDINATOR_ADDRESS).to_string()),
             },
         )
         .unwrap();
@@ -199,6 +348,7 @@ mod test {
                 chain: chain.chain_name.clone(),
                 gateway_address: chain.gateway.to_string().try_into().unwrap(),
                 msg_id_format: axelar_wasm_std::msg_id::MessageIdFormat::HexTxHashAndEventIndex,
+                address_format: None,
             },
         )
         .unwrap();
@@ -438,6 +588,7 @@ mod test {
                 chain: chain.chain_name.clone(),
                 gateway_address: chain.gateway.to_string().try_into().unwrap(),
                 msg_id_format: axelar_wasm_std::msg_id::MessageIdFormat::HexTxHashAndEventIndex,
+                address_format: None,
             },
         )
         .unwrap_err();
@@ -457,6 +608,7 @@ mod test {
                 chain: chain.chain_name.clone(),
                 gateway_address: chain.gateway.to_string().try_into().unwrap(),
                 msg_id_format: axelar_wasm_std::msg_id::MessageIdFormat::HexTxHashAndEventIndex,
+                address_format: None,
             },
         )
         .unwrap_err();
@@ -476,6 +628,7 @@ mod test {
                 chain: chain.chain_name.clone(),
                 gateway_address: chain.gateway.to_string().try_into().unwrap(),
                 msg_id_format: axelar_wasm_std::msg_id::MessageIdFormat::HexTxHashAndEventIndex,
+                address_format: None,
             },
         );
         assert!(res.is_ok());
@@ -624,6 +777,77 @@ mod test {
         assert!(res.is_ok());
     }
 
+    #[test]
+    fn chain_info_should_include_registered_formats() {
+        let mut deps = setup();
+        let api = deps.api;
+        let eth = make_chain("ethereum");
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(GOVERNANCE_ADDRESS), &[]),
+            ExecuteMsg::RegisterChain {
+                chain: eth.chain_name.clone(),
+                gateway_address: eth.gateway.to_string().try_into().unwrap(),
+                msg_id_format: axelar_wasm_std::msg_id::MessageIdFormat::HexTxHashAndEventIndex,
+                address_format: Some(AddressFormat::Eip55),
+            },
+        )
+        .unwrap();
+
+        let chain_info = |deps: Deps| -> ChainEndpoint {
+            from_json(
+                query(
+                    deps,
+                    mock_env(),
+                    QueryMsg::ChainInfo(eth.chain_name.clone()),
+                )
+                .unwrap(),
+            )
+            .unwrap()
+        };
+        let endpoint = chain_info(deps.as_ref());
+        assert_eq!(endpoint.gateway.address, eth.gateway);
+        assert_eq!(
+            endpoint.msg_id_format,
+            axelar_wasm_std::msg_id::MessageIdFormat::HexTxHashAndEventIndex
+        );
+        assert_eq!(endpoint.address_format, Some(AddressFormat::Eip55));
+
+        let update_address_format = |deps: DepsMut, sender: &str, chain: ChainName| {
+            execute(
+                deps,
+                mock_env(),
+                message_info(&api.addr_make(sender), &[]),
+                ExecuteMsg::UpdateAddressFormat {
+                    chain,
+                    address_format: None,
+                },
+            )
+        };
+        assert!(
+            update_address_format(deps.as_mut(), ADMIN_ADDRESS, eth.chain_name.clone()).is_err()
+        );
+        assert!(update_address_format(
+            deps.as_mut(),
+            GOVERNANCE_ADDRESS,
+            "polygon".parse().unwrap()
+        )
+        .is_err());
+
+        let res = update_address_format(deps.as_mut(), GOVERNANCE_ADDRESS, eth.chain_name.clone())
+            .unwrap();
+        assert_eq!(
+            res.events,
+            vec![Event::from(events::AddressFormatUpdated {
+                chain: eth.chain_name.clone(),
+                address_format: None,
+            })]
+        );
+        assert_eq!(chain_info(deps.as_ref()).address_format, None);
+    }
+
     #[test]
     fn upgrade_gateway_outgoing() {
         let mut deps = setup();
@@ -755,6 +979,7 @@ mod test {
                     .try_into()
                     .unwrap(),
                 msg_id_format: axelar_wasm_std::msg_id::MessageIdFormat::HexTxHashAndEventIndex,
+                address_format: None,
             },
         )
         .unwrap_err();
@@ -773,6 +998,7 @@ mod test {
                     .try_into()
                     .unwrap(),
                 msg_id_format: axelar_wasm_std::msg_id::MessageIdFormat::HexTxHashAndEventIndex,
+                address_format: None,
             },
         )
         .unwrap_err();
@@ -808,6 +1034,7 @@ mod test {
                 chain: polygon.chain_name.clone(),
                 gateway_address: eth.gateway.to_string().try_into().unwrap(),
                 msg_id_format: axelar_wasm_std::msg_id::MessageIdFormat::HexTxHashAndEventIndex,
+                address_format: None,
             },
         )
         .unwrap_err();
@@ -1805,6 +2032,668 @@ mod test {
         .is_ok());
     }
 
+    fn register_tap(deps: DepsMut, source: &Chain, destination: &Chain, tap: &Addr) -> Response {
+        execute(
+            deps,
+            mock_env(),
+            message_info(&MockApi::default().addr_make(GOVERNANCE_ADDRESS), &[]),
+            ExecuteMsg::RegisterTap {
+                source_chain: source.chain_name.clone().into(),
+                destination_chain: destination.chain_name.clone(),
+                contract_address: tap.to_string().try_into().unwrap(),
+                gas_limit: 100_000,
+            },
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn taps_receive_copies_of_routed_messages() {
+        let mut deps = setup();
+        let api = deps.api;
+        let eth = make_chain("ethereum");
+        let polygon = make_chain("polygon");
+        let tap = api.addr_make("tap");
+
+        register_chain(deps.as_mut(), &eth);
+        register_chain(deps.as_mut(), &polygon);
+        register_tap(deps.as_mut(), &eth, &polygon, &tap);
+
+        let taps: Vec<Tap> = from_json(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::Taps {
+                    source_chain: eth.chain_name.clone().into(),
+                    destination_chain: polygon.chain_name.clone(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            taps,
+            vec![Tap {
+                address: tap.clone(),
+                gas_limit: 100_000,
+            }]
+        );
+
+        let messages = generate_messages(&eth, &polygon, &mut 0, 3);
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&eth.gateway, &[]),
+            ExecuteMsg::RouteMessages(messages.clone()),
+        )
+        .unwrap();
+
+        assert_eq!(res.messages.len(), 2);
+        assert_messages_in_cosmos_msg(
+            polygon.gateway.clone(),
+            messages.clone(),
+            &res.messages[0].msg,
+        );
+        assert_eq!(
+            res.messages[1],
+            SubMsg::reply_on_error(
+                WasmMsg::Execute {
+                    contract_addr: tap.to_string(),
+                    msg: to_json_binary(&TapExecuteMsg::ObserveMessages(messages)).unwrap(),
+                    funds: vec![],
+                },
+                TAP_REPLY_ID,
+            )
+            .with_gas_limit(100_000)
+        );
+
+        // messages in the other direction are not tapped
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&polygon.gateway, &[]),
+            ExecuteMsg::RouteMessages(generate_messages(&polygon, &eth, &mut 3, 1)),
+        )
+        .unwrap();
+        assert_eq!(res.messages.len(), 1);
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(GOVERNANCE_ADDRESS), &[]),
+            ExecuteMsg::DeregisterTap {
+                source_chain: eth.chain_name.clone().into(),
+                destination_chain: polygon.chain_name.clone(),
+                contract_address: tap.to_string().try_into().unwrap(),
+            },
+        )
+        .unwrap();
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&eth.gateway, &[]),
+            ExecuteMsg::RouteMessages(generate_messages(&eth, &polygon, &mut 4, 1)),
+        )
+        .unwrap();
+        assert_eq!(res.messages.len(), 1);
+    }
+
+    #[test]
+    fn failed_tap_does_not_block_routing() {
+        let mut deps = setup();
+
+        #[allow(deprecated)]
+        let res = reply(
+            deps.as_mut(),
+            mock_env(),
+            Reply {
+                id: TAP_REPLY_ID,
+                payload: Binary::default(),
+                gas_used: 0,
+                result: SubMsgResult::Err("tap out of gas".to_string()),
+            },
+        )
+        .unwrap();
+
+        assert!(res.messages.is_empty());
+        assert_eq!(
+            res.events,
+            vec![cosmwasm_std::Event::from(events::TapFailed {
+                reason: "tap out of gas".to_string(),
+            })]
+        );
+    }
+
+    #[test]
+    fn tap_registration_is_restricted() {
+        let mut deps = setup();
+        let api = deps.api;
+        let eth = make_chain("ethereum");
+        let polygon = make_chain("polygon");
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(UNAUTHORIZED_ADDRESS), &[]),
+            ExecuteMsg::RegisterTap {
+                source_chain: eth.chain_name.clone().into(),
+                destination_chain: polygon.chain_name.clone(),
+                contract_address: api.addr_make("tap").to_string().try_into().unwrap(),
+                gas_limit: 100_000,
+            },
+        )
+        .unwrap_err();
+        assert_contract_err_string_contains(
+            err,
+            permission_control::Error::PermissionDenied {
+                expected: Permission::Governance.into(),
+                actual: Permission::NoPrivilege.into(),
+            },
+        );
+
+        register_tap(deps.as_mut(), &eth, &polygon, &api.addr_make("tap"));
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(GOVERNANCE_ADDRESS), &[]),
+            ExecuteMsg::RegisterTap {
+                source_chain: eth.chain_name.clone().into(),
+                destination_chain: polygon.chain_name.clone(),
+                contract_address: api.addr_make("tap").to_string().try_into().unwrap(),
+                gas_limit: 100_000,
+            },
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("is already registered"));
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(GOVERNANCE_ADDRESS), &[]),
+            ExecuteMsg::RegisterTap {
+                source_chain: eth.chain_name.clone().into(),
+                destination_chain: polygon.chain_name.clone(),
+                contract_address: api
+                    .addr_make("expensive_tap")
+                    .to_string()
+                    .try_into()
+                    .unwrap(),
+                gas_limit: 1_000_001,
+            },
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("exceeds the maximum of 1000000"));
+
+        for i in 1..5 {
+            register_tap(
+                deps.as_mut(),
+                &eth,
+                &polygon,
+                &api.addr_make(&format!("tap{}", i)),
+            );
+        }
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(GOVERNANCE_ADDRESS), &[]),
+            ExecuteMsg::RegisterTap {
+                source_chain: eth.chain_name.clone().into(),
+                destination_chain: polygon.chain_name.clone(),
+                contract_address: api.addr_make("tap5").to_string().try_into().unwrap(),
+                gas_limit: 100_000,
+            },
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("maximum of 5 taps"));
+    }
+
+    fn env_at_height(height: u64) -> Env {
+        let mut env = mock_env();
+        env.block.height = height;
+        env
+    }
+
+    fn latency(
+        deps: Deps,
+        height: u64,
+        source: &Chain,
+        destination: &Chain,
+        window: u64,
+    ) -> Option<LatencyPercentiles> {
+        from_json(
+            query(
+                deps,
+                env_at_height(height),
+                QueryMsg::Latency {
+                    source_chain: source.chain_name.clone().into(),
+                    destination_chain: destination.chain_name.clone(),
+                    window,
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn latency_is_measured_from_routing_to_reported_execution() {
+        let mut deps = setup();
+        let eth = make_chain("ethereum");
+        let polygon = make_chain("polygon");
+
+        register_chain(deps.as_mut(), &eth);
+        register_chain(deps.as_mut(), &polygon);
+
+        let messages = generate_messages(&eth, &polygon, &mut 0, 4);
+        let cc_ids: Vec<_> = messages.iter().map(|msg| msg.cc_id.clone()).collect();
+
+        execute(
+            deps.as_mut(),
+            env_at_height(100),
+            message_info(&eth.gateway, &[]),
+            ExecuteMsg::RouteMessages(messages.clone()),
+        )
+        .unwrap();
+
+        // routing again does not reset the receipt
+        execute(
+            deps.as_mut(),
+            env_at_height(102),
+            message_info(&eth.gateway, &[]),
+            ExecuteMsg::RouteMessages(messages),
+        )
+        .unwrap();
+
+        assert_eq!(latency(deps.as_ref(), 100, &eth, &polygon, 100), None);
+
+        // only the destination gateway can report executions
+        let err = execute(
+            deps.as_mut(),
+            env_at_height(105),
+            message_info(&eth.gateway, &[]),
+            ExecuteMsg::RecordExecutions(cc_ids.clone()),
+        )
+        .unwrap_err();
+        assert!(err_contains!(
+            err.report,
+            Error,
+            Error::WrongDestinationGateway
+        ));
+
+        let res = execute(
+            deps.as_mut(),
+            env_at_height(105),
+            message_info(&polygon.gateway, &[]),
+            ExecuteMsg::RecordExecutions(cc_ids[..3].to_vec()),
+        )
+        .unwrap();
+        assert_eq!(res.events.len(), 3);
+
+        // executions that were already recorded are ignored
+        let res = execute(
+            deps.as_mut(),
+            env_at_height(110),
+            message_info(&polygon.gateway, &[]),
+            ExecuteMsg::RecordExecutions(cc_ids.clone()),
+        )
+        .unwrap();
+        assert_eq!(res.events.len(), 1);
+
+        assert_eq!(
+            latency(deps.as_ref(), 110, &eth, &polygon, 100),
+            Some(LatencyPercentiles {
+                sample_count: 4,
+                p50: 5,
+                p90: 10,
+                p99: 10,
+            })
+        );
+        assert_eq!(
+            latency(deps.as_ref(), 110, &eth, &polygon, 5),
+            Some(LatencyPercentiles {
+                sample_count: 1,
+                p50: 10,
+                p90: 10,
+                p99: 10,
+            })
+        );
+        assert_eq!(latency(deps.as_ref(), 110, &polygon, &eth, 100), None);
+    }
+
+    fn recalled_messages(deps: Deps) -> Vec<CrossChainId> {
+        from_json(
+            query(
+                deps,
+                mock_env(),
+                QueryMsg::RecalledMessages {
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn recalled_messages_are_refused_by_the_destination_gateway() {
+        let mut deps = setup();
+        let eth = make_chain("ethereum");
+        let polygon = make_chain("polygon");
+
+        register_chain(deps.as_mut(), &eth);
+        register_chain(deps.as_mut(), &polygon);
+
+        let messages = generate_messages(&eth, &polygon, &mut 0, 3);
+        let cc_ids: Vec<_> = messages.iter().map(|msg| msg.cc_id.clone()).collect();
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&eth.gateway, &[]),
+            ExecuteMsg::RouteMessages(messages.clone()),
+        )
+        .unwrap();
+
+        // only governance can recall messages
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&eth.gateway, &[]),
+            ExecuteMsg::RecallMessages(cc_ids[..2].to_vec()),
+        )
+        .unwrap_err();
+        assert!(err_contains!(
+            err.report,
+            permission_control::Error,
+            permission_control::Error::PermissionDenied { .. }
+        ));
+
+        // executed messages cannot be recalled anymore
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&polygon.gateway, &[]),
+            ExecuteMsg::RecordExecutions(vec![cc_ids[2].clone()]),
+        )
+        .unwrap();
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&MockApi::default().addr_make(GOVERNANCE_ADDRESS), &[]),
+            ExecuteMsg::RecallMessages(cc_ids.clone()),
+        )
+        .unwrap_err();
+        assert!(err_contains!(
+            err.report,
+            Error,
+            Error::MessageNotRecallable(_)
+        ));
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&MockApi::default().addr_make(GOVERNANCE_ADDRESS), &[]),
+            ExecuteMsg::RecallMessages(cc_ids[..2].to_vec()),
+        )
+        .unwrap();
+        assert_eq!(res.events.len(), 2);
+        assert_eq!(
+            res.messages,
+            vec![SubMsg::new(WasmMsg::Execute {
+                contract_addr: polygon.gateway.to_string(),
+                msg: to_json_binary(&gateway_api::msg::ExecuteMsg::RecallMessages(
+                    cc_ids[..2].to_vec()
+                ))
+                .unwrap(),
+                funds: vec![],
+            })]
+        );
+        assert_eq!(recalled_messages(deps.as_ref()), cc_ids[..2].to_vec());
+
+        // recalling again is a no-op
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&MockApi::default().addr_make(GOVERNANCE_ADDRESS), &[]),
+            ExecuteMsg::RecallMessages(cc_ids[..1].to_vec()),
+        )
+        .unwrap();
+        assert!(res.messages.is_empty());
+        assert!(res.events.is_empty());
+
+        // recalled messages are not routed again
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&eth.gateway, &[]),
+            ExecuteMsg::RouteMessages(messages.clone()),
+        )
+        .unwrap();
+        assert_eq!(
+            res.messages,
+            vec![SubMsg::new(WasmMsg::Execute {
+                contract_addr: polygon.gateway.to_string(),
+                msg: to_json_binary(&gateway_api::msg::ExecuteMsg::RouteMessages(
+                    messages[2..].to_vec()
+                ))
+                .unwrap(),
+                funds: vec![],
+            })]
+        );
+
+        // executed messages stay unrecallable after they are routed again
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&MockApi::default().addr_make(GOVERNANCE_ADDRESS), &[]),
+            ExecuteMsg::RecallMessages(vec![cc_ids[2].clone()]),
+        )
+        .unwrap_err();
+        assert!(err_contains!(
+            err.report,
+            Error,
+            Error::MessageNotRecallable(_)
+        ));
+    }
+
+    fn message_expiry(
+        deps: Deps,
+        block_height: u64,
+        cc_id: &CrossChainId,
+    ) -> Option<MessageExpiry> {
+        let mut env = mock_env();
+        env.block.height = block_height;
+
+        from_json(query(deps, env, QueryMsg::MessageExpiry(cc_id.clone())).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn messages_routed_with_ttl_expire() {
+        let mut deps = setup();
+        let eth = make_chain("ethereum");
+        let polygon = make_chain("polygon");
+
+        register_chain(deps.as_mut(), &eth);
+        register_chain(deps.as_mut(), &polygon);
+
+        let messages = generate_messages(&eth, &polygon, &mut 0, 2);
+        let cc_ids: Vec<_> = messages.iter().map(|msg| msg.cc_id.clone()).collect();
+
+        let mut env = mock_env();
+        env.block.height = 100;
+
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&eth.gateway, &[]),
+            ExecuteMsg::RouteMessagesWithTtl {
+                messages: messages[..1].to_vec(),
+                ttl: 10u64.try_into().unwrap(),
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            res.messages,
+            vec![SubMsg::new(WasmMsg::Execute {
+                contract_addr: polygon.gateway.to_string(),
+                msg: to_json_binary(&gateway_api::msg::ExecuteMsg::RouteMessagesWithExpiry(
+                    vec![MessageWithExpiry {
+                        message: messages[0].clone(),
+                        expires_at: 110,
+                    }]
+                ))
+                .unwrap(),
+                funds: vec![],
+            })]
+        );
+
+        // the TTL of a message is set when it is first routed, re-routing keeps the expiry
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&eth.gateway, &[]),
+            ExecuteMsg::RouteMessages(messages.clone()),
+        )
+        .unwrap();
+        assert_eq!(
+            res.messages,
+            vec![
+                SubMsg::new(WasmMsg::Execute {
+                    contract_addr: polygon.gateway.to_string(),
+                    msg: to_json_binary(&gateway_api::msg::ExecuteMsg::RouteMessages(
+                        messages[1..].to_vec()
+                    ))
+                    .unwrap(),
+                    funds: vec![],
+                }),
+                SubMsg::new(WasmMsg::Execute {
+                    contract_addr: polygon.gateway.to_string(),
+                    msg: to_json_binary(&gateway_api::msg::ExecuteMsg::RouteMessagesWithExpiry(
+                        vec![MessageWithExpiry {
+                            message: messages[0].clone(),
+                            expires_at: 110,
+                        }]
+                    ))
+                    .unwrap(),
+                    funds: vec![],
+                }),
+            ]
+        );
+
+        assert_eq!(
+            message_expiry(deps.as_ref(), 109, &cc_ids[0]),
+            Some(MessageExpiry {
+                expires_at: 110,
+                expired: false,
+            })
+        );
+        assert_eq!(
+            message_expiry(deps.as_ref(), 110, &cc_ids[0]),
+            Some(MessageExpiry {
+                expires_at: 110,
+                expired: true,
+            })
+        );
+        assert_eq!(message_expiry(deps.as_ref(), 110, &cc_ids[1]), None);
+
+        // messages can only be marked as expired after their expiry
+        env.block.height = 109;
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&MockApi::default().addr_make("anyone"), &[]),
+            ExecuteMsg::ExpireMessages(cc_ids[..1].to_vec()),
+        )
+        .unwrap_err();
+        assert!(err_contains!(
+            err.report,
+            Error,
+            Error::MessageNotExpired(_)
+        ));
+
+        env.block.height = 110;
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&MockApi::default().addr_make("anyone"), &[]),
+            ExecuteMsg::ExpireMessages(vec![cc_ids[1].clone(), cc_ids[0].clone()]),
+        )
+        .unwrap_err();
+        assert!(err_contains!(
+            err.report,
+            Error,
+            Error::MessageNotExpired(_)
+        ));
+
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&MockApi::default().addr_make("anyone"), &[]),
+            ExecuteMsg::ExpireMessages(cc_ids[..1].to_vec()),
+        )
+        .unwrap();
+        assert_eq!(
+            res.events,
+            vec![Event::new("message_expired")
+                .add_attribute("source_chain", cc_ids[0].source_chain.to_string())
+                .add_attribute("message_id", cc_ids[0].message_id.to_string())
+                .add_attribute("destination_chain", polygon.chain_name.to_string())
+                .add_attribute("expires_at", "110")]
+        );
+
+        // expiring again is a no-op
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&MockApi::default().addr_make("anyone"), &[]),
+            ExecuteMsg::ExpireMessages(cc_ids[..1].to_vec()),
+        )
+        .unwrap();
+        assert!(res.events.is_empty());
+
+        // expired messages are not routed again
+        let res = execute(
+            deps.as_mut(),
+            env,
+            message_info(&eth.gateway, &[]),
+            ExecuteMsg::RouteMessages(messages.clone()),
+        )
+        .unwrap();
+        assert_eq!(
+            res.messages,
+            vec![SubMsg::new(WasmMsg::Execute {
+                contract_addr: polygon.gateway.to_string(),
+                msg: to_json_binary(&gateway_api::msg::ExecuteMsg::RouteMessages(
+                    messages[1..].to_vec()
+                ))
+                .unwrap(),
+                funds: vec![],
+            })]
+        );
+
+        // marked messages keep their expiry until they are pruned after the retention window
+        assert_eq!(
+            message_expiry(deps.as_ref(), 110, &cc_ids[0]),
+            Some(MessageExpiry {
+                expires_at: 110,
+                expired: true,
+            })
+        );
+
+        let mut env = mock_env();
+        env.block.height = 100_111;
+        execute(
+            deps.as_mut(),
+            env,
+            message_info(&MockApi::default().addr_make("anyone"), &[]),
+            ExecuteMsg::ExpireMessages(vec![]),
+        )
+        .unwrap();
+        assert_eq!(message_expiry(deps.as_ref(), 100_111, &cc_ids[0]), None);
+    }
+
     #[test]
     fn chain_info_fails_on_unregistered_chain() {
         let deps = setup();
@@ -1819,4 +2708,92 @@ mod test {
         .unwrap_err();
         goldie::assert!(err.to_string());
     }
+
+    #[test]
+    fn only_coordinator_can_set_maintenance_windows() {
+        let mut deps = setup();
+        let api = deps.api;
+        let polygon = make_chain("polygon");
+        register_chain(deps.as_mut(), &polygon);
+
+        let msg = ExecuteMsg::SetMaintenanceWindows {
+            chain: polygon.chain_name.clone(),
+            windows: vec![MaintenanceWindow::new(10, 20).unwrap()],
+        };
+
+        for sender in [GOVERNANCE_ADDRESS, ADMIN_ADDRESS, UNAUTHORIZED_ADDRESS] {
+            assert!(execute(
+                deps.as_mut(),
+                mock_env(),
+                message_info(&api.addr_make(sender), &[]),
+                msg.clone(),
+            )
+            .is_err());
+        }
+
+        assert!(execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(COORDINATOR_ADDRESS), &[]),
+            msg,
+        )
+        .is_ok());
+    }
+
+    #[test]
+    #[allow(clippy::arithmetic_side_effects)]
+    fn messages_are_not_routed_to_chain_under_maintenance() {
+        let mut deps = setup();
+        let api = deps.api;
+        let eth = make_chain("ethereum");
+        let polygon = make_chain("polygon");
+        register_chain(deps.as_mut(), &eth);
+        register_chain(deps.as_mut(), &polygon);
+
+        let height = mock_env().block.height;
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(COORDINATOR_ADDRESS), &[]),
+            ExecuteMsg::SetMaintenanceWindows {
+                chain: polygon.chain_name.clone(),
+                windows: vec![MaintenanceWindow::new(height, height + 10).unwrap()],
+            },
+        )
+        .unwrap();
+
+        let nonce = &mut 0;
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&eth.gateway, &[]),
+            ExecuteMsg::RouteMessages(generate_messages(&eth, &polygon, nonce, 1)),
+        )
+        .unwrap_err();
+        assert_contract_err_string_contains(
+            err,
+            Error::ChainUnderMaintenance {
+                chain: polygon.chain_name.clone(),
+            },
+        );
+
+        // the chain can still send messages during its maintenance
+        assert!(execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&polygon.gateway, &[]),
+            ExecuteMsg::RouteMessages(generate_messages(&polygon, &eth, nonce, 1)),
+        )
+        .is_ok());
+
+        let mut env = mock_env();
+        env.block.height = height + 10;
+        assert!(execute(
+            deps.as_mut(),
+            env,
+            message_info(&eth.gateway, &[]),
+            ExecuteMsg::RouteMessages(generate_messages(&eth, &polygon, nonce, 1)),
+        )
+        .is_ok());
+    }
 }
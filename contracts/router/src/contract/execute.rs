@@ -1,22 +1,32 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::vec;
 
 use axelar_core_std::nexus;
+use axelar_wasm_std::address::AddressFormat;
 use axelar_wasm_std::flagset::FlagSet;
-use axelar_wasm_std::killswitch;
+use axelar_wasm_std::maintenance::MaintenanceWindow;
 use axelar_wasm_std::msg_id::{self, MessageIdFormat};
+use axelar_wasm_std::vec::VecExt;
+use axelar_wasm_std::{killswitch, nonempty};
 use cosmwasm_std::{
-    to_json_binary, Addr, Event, QuerierWrapper, Response, StdResult, Storage, WasmMsg,
+    to_json_binary, Addr, Event, QuerierWrapper, Response, StdResult, Storage, SubMsg, WasmMsg,
 };
 use error_stack::{bail, ensure, report, Report, ResultExt};
+use gateway_api::msg::MessageWithExpiry;
 use itertools::Itertools;
 use router_api::error::Error;
-use router_api::{ChainEndpoint, ChainName, Gateway, GatewayDirection, Message};
+use router_api::msg::TapExecuteMsg;
+use router_api::{
+    ChainEndpoint, ChainName, ChainNameRaw, CrossChainId, Gateway, GatewayDirection, Message, Tap,
+};
 
+use crate::contract::TAP_REPLY_ID;
 use crate::events::{
-    ChainFrozen, ChainRegistered, ChainUnfrozen, GatewayInfo, GatewayUpgraded, MessageRouted,
+    AddressFormatUpdated, ChainFrozen, ChainRegistered, ChainUnfrozen, GatewayInfo,
+    GatewayUpgraded, MessageExecutionRecorded, MessageExpired, MessageRecalled, MessageRouted,
+    TapDeregistered, TapRegistered,
 };
-use crate::state::{chain_endpoints, Config};
+use crate::state::{chain_endpoints, Config, ExpiredMessage, RoutedMessage};
 use crate::{events, state};
 
 pub fn register_chain(
@@ -25,6 +35,7 @@ pub fn register_chain(
     name: ChainName,
     gateway: Addr,
     msg_id_format: MessageIdFormat,
+    address_format: Option<AddressFormat>,
 ) -> Result<Response, Report<Error>> {
     if find_chain_for_gateway(storage, &gateway)
         .change_context(Error::StoreFailure)?
@@ -51,11 +62,31 @@ pub fn register_chain(
             },
             frozen_status: FlagSet::from(GatewayDirection::None),
             msg_id_format,
+            address_format,
         }),
     })?;
     Ok(Response::new().add_event(ChainRegistered { name, gateway }))
 }
 
+pub fn update_address_format(
+    storage: &mut dyn Storage,
+    chain: ChainName,
+    address_format: Option<AddressFormat>,
+) -> Result<Response, Error> {
+    chain_endpoints().update(storage, chain.clone(), |endpoint| match endpoint {
+        None => Err(Error::ChainNotFound),
+        Some(mut endpoint) => {
+            endpoint.address_format = address_format.clone();
+            Ok(endpoint)
+        }
+    })?;
+
+    Ok(Response::new().add_event(AddressFormatUpdated {
+        chain,
+        address_format,
+    }))
+}
+
 pub fn find_chain_for_gateway(
     storage: &dyn Storage,
     contract_address: &Addr,
@@ -162,6 +193,111 @@ pub fn enable_routing(storage: &mut dyn Storage) -> Result<Response, Error> {
     killswitch::disengage(storage, events::RoutingEnabled).map_err(|err| err.into())
 }
 
+/// Upper bound on the taps of a single chain pair, so taps cannot make routing arbitrarily expensive
+const MAX_TAPS_PER_CHAIN_PAIR: usize = 5;
+/// Upper bound on the gas limit of a single tap, so the taps of a chain pair can't claim most of a routing transaction's gas
+const MAX_TAP_GAS_LIMIT: u64 = 1_000_000;
+
+pub fn register_tap(
+    storage: &mut dyn Storage,
+    source_chain: ChainNameRaw,
+    destination_chain: ChainName,
+    tap: Tap,
+) -> error_stack::Result<Response, Error> {
+    ensure!(
+        tap.gas_limit <= MAX_TAP_GAS_LIMIT,
+        Error::TapGasLimitTooHigh {
+            gas_limit: tap.gas_limit,
+            max: MAX_TAP_GAS_LIMIT,
+        }
+    );
+    ensure!(
+        state::may_load_tap(storage, &source_chain, &destination_chain, &tap.address)?.is_none(),
+        Error::TapAlreadyRegistered {
+            source_chain,
+            destination_chain,
+            address: tap.address.to_string(),
+        }
+    );
+    ensure!(
+        state::load_taps(storage, &source_chain, &destination_chain)?.len()
+            < MAX_TAPS_PER_CHAIN_PAIR,
+        Error::TooManyTaps {
+            source_chain,
+            destination_chain,
+            max: MAX_TAPS_PER_CHAIN_PAIR,
+        }
+    );
+
+    state::save_tap(storage, &source_chain, &destination_chain, &tap)?;
+
+    Ok(Response::new().add_event(TapRegistered {
+        source_chain,
+        destination_chain,
+        address: tap.address,
+        gas_limit: tap.gas_limit,
+    }))
+}
+
+pub fn deregister_tap(
+    storage: &mut dyn Storage,
+    source_chain: ChainNameRaw,
+    destination_chain: ChainName,
+    address: Addr,
+) -> error_stack::Result<Response, Error> {
+    ensure!(
+        state::may_load_tap(storage, &source_chain, &destination_chain, &address)?.is_some(),
+        Error::TapNotFound {
+            source_chain,
+            destination_chain,
+            address: address.to_string(),
+        }
+    );
+
+    state::remove_tap(storage, &source_chain, &destination_chain, &address);
+
+    Ok(Response::new().add_event(TapDeregistered {
+        source_chain,
+        destination_chain,
+        address,
+    }))
+}
+
+// taps only get a copy of the routed messages, so their failures are caught in the reply
+// and the gas limit keeps them from running the routing transaction out of gas
+fn tap_msgs(storage: &dyn Storage, msgs: &[Message]) -> error_stack::Result<Vec<SubMsg>, Error> {
+    msgs.iter()
+        .chunk_by(|msg| {
+            (
+                msg.cc_id.source_chain.clone(),
+                msg.destination_chain.clone(),
+            )
+        })
+        .into_iter()
+        .map(|((source_chain, destination_chain), msgs)| {
+            let msgs: Vec<_> = msgs.cloned().collect();
+
+            Ok(
+                state::load_taps(storage, &source_chain, &destination_chain)?
+                    .into_iter()
+                    .map(move |tap| {
+                        SubMsg::reply_on_error(
+                            WasmMsg::Execute {
+                                contract_addr: tap.address.to_string(),
+                                msg: to_json_binary(&TapExecuteMsg::ObserveMessages(msgs.clone()))
+                                    .expect("must serialize message"),
+                                funds: vec![],
+                            },
+                            TAP_REPLY_ID,
+                        )
+                        .with_gas_limit(tap.gas_limit)
+                    }),
+            )
+        })
+        .flatten_ok()
+        .collect()
+}
+
 fn verify_msg_ids(
     msgs: &[Message],
     expected_format: &MessageIdFormat,
@@ -208,6 +344,7 @@ fn validate_msgs(
 pub fn route_messages(
     storage: &dyn Storage,
     querier: QuerierWrapper,
+    block_height: u64,
     sender: Addr,
     msgs: Vec<Message>,
 ) -> error_stack::Result<Response, Error> {
@@ -220,12 +357,21 @@ pub fn route_messages(
     let client: nexus::Client = client::CosmosClient::new(querier).into();
 
     let msgs = validate_msgs(storage, config.clone(), &sender, msgs)?;
+    let msgs = without_recalled(storage, msgs)?;
+    let msgs = without_expired(storage, block_height, msgs)?;
 
     let wasm_msgs = msgs
         .iter()
         .chunk_by(|msg| msg.destination_chain.to_owned())
         .into_iter()
         .map(|(destination_chain, msgs)| {
+            ensure!(
+                !state::is_under_maintenance(storage, &destination_chain, block_height)?,
+                Error::ChainUnderMaintenance {
+                    chain: destination_chain
+                }
+            );
+
             let gateway = match state::load_chain_by_chain_name(storage, &destination_chain)? {
                 Some(destination_chain) if destination_chain.outgoing_frozen() => {
                     return Err(report!(Error::ChainFrozen {
@@ -245,22 +391,309 @@ pub fn route_messages(
                 _ => return Err(report!(Error::ChainNotFound)),
             };
 
-            Ok(WasmMsg::Execute {
-                contract_addr: gateway.to_string(),
-                msg: to_json_binary(&gateway_api::msg::ExecuteMsg::RouteMessages(
-                    msgs.cloned().collect(),
-                ))
-                .expect("must serialize message"),
-                funds: vec![],
-            })
+            gateway_msgs(storage, gateway, msgs.cloned().collect())
         })
+        .flatten_ok()
         .collect::<Result<Vec<_>, _>>()?;
 
+    let tap_msgs = tap_msgs(storage, &msgs)?;
+
     Ok(Response::new()
         .add_messages(wasm_msgs)
+        .add_submessages(tap_msgs)
         .add_events(msgs.into_iter().map(|msg| MessageRouted { msg })))
 }
 
+/// Messages that were routed with a TTL are forwarded together with their expiry, so the gateway can refuse them once they expire
+fn gateway_msgs(
+    storage: &dyn Storage,
+    gateway: Addr,
+    msgs: Vec<Message>,
+) -> error_stack::Result<Vec<WasmMsg>, Error> {
+    let mut without_expiry = vec![];
+    let mut with_expiry = vec![];
+
+    for msg in msgs {
+        match state::may_load_routed_message(storage, &msg.cc_id)?
+            .and_then(|routed| routed.expires_at)
+        {
+            Some(expires_at) => with_expiry.push(MessageWithExpiry {
+                message: msg,
+                expires_at,
+            }),
+            None => without_expiry.push(msg),
+        }
+    }
+
+    Ok([
+        without_expiry
+            .to_none_if_empty()
+            .map(gateway_api::msg::ExecuteMsg::RouteMessages),
+        with_expiry
+            .to_none_if_empty()
+            .map(gateway_api::msg::ExecuteMsg::RouteMessagesWithExpiry),
+    ]
+    .into_iter()
+    .flatten()
+    .map(|msg| WasmMsg::Execute {
+        contract_addr: gateway.to_string(),
+        msg: to_json_binary(&msg).expect("must serialize message"),
+        funds: vec![],
+    })
+    .collect())
+}
+
+/// Recalled messages must never reach their destination again, so they are dropped silently if they are routed again
+fn without_recalled(
+    storage: &dyn Storage,
+    msgs: Vec<Message>,
+) -> error_stack::Result<Vec<Message>, Error> {
+    let mut routable = vec![];
+
+    for msg in msgs {
+        if !state::is_recalled(storage, &msg.cc_id)? {
+            routable.push(msg);
+        }
+    }
+
+    Ok(routable)
+}
+
+/// Expired messages must not be approved anymore, so they are dropped silently if they are routed again
+fn without_expired(
+    storage: &dyn Storage,
+    block_height: u64,
+    msgs: Vec<Message>,
+) -> error_stack::Result<Vec<Message>, Error> {
+    let mut routable = vec![];
+
+    for msg in msgs {
+        let expired = state::is_marked_expired(storage, &msg.cc_id)?
+            || state::may_load_routed_message(storage, &msg.cc_id)?
+                .is_some_and(|routed| routed.is_expired(block_height));
+
+        if !expired {
+            routable.push(msg);
+        }
+    }
+
+    Ok(routable)
+}
+
+/// Number of blocks the latencies of executed messages are kept for, which bounds the window of latency queries
+pub const LATENCY_RETENTION_BLOCKS: u64 = 100_000;
+/// Maximum number of block heights whose latencies are pruned per recorded execution, so the cost of a call stays bounded
+const LATENCY_PRUNE_LIMIT: usize = 10;
+/// Maximum number of executed messages that are pruned per recorded execution. Executed messages are kept as long as
+/// their latencies, afterwards routing them again tracks them as routed once more
+const EXECUTED_MESSAGES_PRUNE_LIMIT: usize = 10;
+
+/// Number of blocks expired messages are kept for after they were marked as expired.
+/// Afterwards, the destination gateway is the only one left to refuse them.
+const EXPIRY_RETENTION_BLOCKS: u64 = 100_000;
+/// Maximum number of expired messages that are pruned per ExpireMessages call, so the cost of a call stays bounded
+const EXPIRED_MESSAGES_PRUNE_LIMIT: usize = 100;
+
+pub fn record_routed_messages(
+    storage: &mut dyn Storage,
+    block_height: u64,
+    msgs: &[Message],
+    ttl: Option<nonempty::Uint64>,
+) -> error_stack::Result<(), Error> {
+    for msg in msgs {
+        // a message can be routed more than once, its latency and expiry are measured from the first time
+        if state::may_load_routed_message(storage, &msg.cc_id)?.is_none()
+            && !state::is_marked_expired(storage, &msg.cc_id)?
+            && !state::is_marked_executed(storage, &msg.cc_id)?
+        {
+            state::save_routed_message(
+                storage,
+                &msg.cc_id,
+                &RoutedMessage {
+                    destination_chain: msg.destination_chain.clone(),
+                    block_height,
+                    expires_at: ttl.map(|ttl| block_height.saturating_add(ttl.into())),
+                },
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+fn destination_gateway(
+    storage: &dyn Storage,
+    config: &Config,
+    destination_chain: &ChainName,
+) -> error_stack::Result<Addr, Error> {
+    Ok(state::load_chain_by_chain_name(storage, destination_chain)?
+        .map(|chain| chain.gateway.address)
+        .unwrap_or_else(|| config.axelarnet_gateway.clone()))
+}
+
+pub fn record_executions(
+    storage: &mut dyn Storage,
+    block_height: u64,
+    sender: Addr,
+    cc_ids: Vec<CrossChainId>,
+) -> error_stack::Result<Response, Error> {
+    let config = state::load_config(storage)?;
+    let mut events = vec![];
+
+    for cc_id in cc_ids {
+        // messages routed before latency tracking was introduced, or reported twice, have nothing to match
+        let Some(routed) = state::may_load_routed_message(storage, &cc_id)? else {
+            continue;
+        };
+
+        ensure!(
+            destination_gateway(storage, &config, &routed.destination_chain)? == sender,
+            Error::WrongDestinationGateway
+        );
+
+        let latency = block_height.saturating_sub(routed.block_height);
+
+        state::mark_executed(storage, &cc_id, block_height)?;
+        state::add_latency(
+            storage,
+            &cc_id.source_chain,
+            &routed.destination_chain,
+            block_height,
+            latency,
+        )?;
+        state::prune_latencies_before(
+            storage,
+            &cc_id.source_chain,
+            &routed.destination_chain,
+            block_height.saturating_sub(LATENCY_RETENTION_BLOCKS),
+            LATENCY_PRUNE_LIMIT,
+        )?;
+        state::prune_executed_messages_before(
+            storage,
+            block_height.saturating_sub(LATENCY_RETENTION_BLOCKS),
+            EXECUTED_MESSAGES_PRUNE_LIMIT,
+        )?;
+
+        events.push(MessageExecutionRecorded {
+            cc_id,
+            destination_chain: routed.destination_chain,
+            latency,
+        });
+    }
+
+    Ok(Response::new().add_events(events))
+}
+
+pub fn recall_messages(
+    storage: &mut dyn Storage,
+    block_height: u64,
+    cc_ids: Vec<CrossChainId>,
+) -> error_stack::Result<Response, Error> {
+    let config = state::load_config(storage)?;
+    let mut recalled_by_gateway: BTreeMap<Addr, Vec<CrossChainId>> = BTreeMap::new();
+    let mut events = vec![];
+
+    for cc_id in cc_ids {
+        // recalling a message twice is a no-op
+        if state::is_recalled(storage, &cc_id)? {
+            continue;
+        }
+
+        // only messages that are still waiting for execution are tracked as routed, destination gateways report
+        // executions, so executed messages are no longer recallable
+        let routed = state::may_load_routed_message(storage, &cc_id)?
+            .ok_or_else(|| report!(Error::MessageNotRecallable(cc_id.clone())))?;
+
+        // recalled messages are never executed, so they would otherwise stay tracked as routed forever
+        state::remove_routed_message(storage, &cc_id);
+        state::save_recalled_message(storage, &cc_id, block_height)?;
+
+        recalled_by_gateway
+            .entry(destination_gateway(
+                storage,
+                &config,
+                &routed.destination_chain,
+            )?)
+            .or_default()
+            .push(cc_id.clone());
+        events.push(MessageRecalled {
+            cc_id,
+            destination_chain: routed.destination_chain,
+        });
+    }
+
+    let wasm_msgs = recalled_by_gateway
+        .into_iter()
+        .map(|(gateway, cc_ids)| WasmMsg::Execute {
+            contract_addr: gateway.to_string(),
+            msg: to_json_binary(&gateway_api::msg::ExecuteMsg::RecallMessages(cc_ids))
+                .expect("must serialize message"),
+            funds: vec![],
+        });
+
+    Ok(Response::new().add_messages(wasm_msgs).add_events(events))
+}
+
+pub fn expire_messages(
+    storage: &mut dyn Storage,
+    block_height: u64,
+    cc_ids: Vec<CrossChainId>,
+) -> error_stack::Result<Response, Error> {
+    let mut events = vec![];
+
+    for cc_id in cc_ids {
+        // expiring a message twice is a no-op
+        if state::is_marked_expired(storage, &cc_id)? {
+            continue;
+        }
+
+        let Some((destination_chain, expires_at)) =
+            state::may_load_routed_message(storage, &cc_id)?
+                .filter(|routed| routed.is_expired(block_height))
+                .and_then(|routed| {
+                    routed
+                        .expires_at
+                        .map(|expires_at| (routed.destination_chain, expires_at))
+                })
+        else {
+            bail!(Error::MessageNotExpired(cc_id));
+        };
+
+        state::mark_expired(
+            storage,
+            &cc_id,
+            &ExpiredMessage {
+                expires_at,
+                marked_at: block_height,
+            },
+        )?;
+
+        events.push(MessageExpired {
+            cc_id,
+            destination_chain,
+            expires_at,
+        });
+    }
+
+    state::prune_expired_messages_before(
+        storage,
+        block_height.saturating_sub(EXPIRY_RETENTION_BLOCKS),
+        EXPIRED_MESSAGES_PRUNE_LIMIT,
+    )?;
+
+    Ok(Response::new().add_events(events))
+}
+
+pub fn set_maintenance_windows(
+    storage: &mut dyn Storage,
+    chain: ChainName,
+    windows: Vec<MaintenanceWindow>,
+) -> error_stack::Result<Response, Error> {
+    state::save_maintenance_windows(storage, chain, windows)?;
+
+    Ok(Response::new())
+}
+
 #[cfg(test)]
 mod test {
     use std::collections::HashMap;
@@ -330,6 +763,7 @@ mod test {
                 admin_address: api.addr_make("admin").to_string(),
                 governance_address: api.addr_make("governance").to_string(),
                 axelarnet_gateway: api.addr_make(AXELARNET_GATEWAY).to_string(),
+                coordinator_address: None,
             },
         )
         .unwrap();
@@ -337,6 +771,7 @@ mod test {
         assert!(route_messages(
             &deps.storage,
             QuerierWrapper::new(&deps.querier),
+            mock_env().block.height,
             sender,
             vec![rand_message(source_chain, destination_chain)]
         )
@@ -359,6 +794,7 @@ mod test {
                 admin_address: api.addr_make("admin").to_string(),
                 governance_address: api.addr_make("governance").to_string(),
                 axelarnet_gateway: api.addr_make(AXELARNET_GATEWAY).to_string(),
+                coordinator_address: None,
             },
         )
         .unwrap();
@@ -370,6 +806,7 @@ mod test {
             },
             frozen_status: FlagSet::from(GatewayDirection::Incoming),
             msg_id_format: axelar_wasm_std::msg_id::MessageIdFormat::HexTxHashAndEventIndex,
+            address_format: None,
         };
         chain_endpoints()
             .save(deps.as_mut().storage, source_chain.clone(), &chain_endpoint)
@@ -378,6 +815,7 @@ mod test {
         assert!(route_messages(
             &deps.storage,
             QuerierWrapper::new(&deps.querier),
+            mock_env().block.height,
             sender,
             vec![rand_message(source_chain.clone(), destination_chain)]
         )
@@ -402,6 +840,7 @@ mod test {
                 admin_address: api.addr_make("admin").to_string(),
                 governance_address: api.addr_make("governance").to_string(),
                 axelarnet_gateway: api.addr_make(AXELARNET_GATEWAY).to_string(),
+                coordinator_address: None,
             },
         )
         .unwrap();
@@ -413,6 +852,7 @@ mod test {
             },
             frozen_status: FlagSet::from(GatewayDirection::None),
             msg_id_format: axelar_wasm_std::msg_id::MessageIdFormat::HexTxHashAndEventIndex,
+            address_format: None,
         };
         chain_endpoints()
             .save(deps.as_mut().storage, source_chain.clone(), &chain_endpoint)
@@ -421,6 +861,7 @@ mod test {
         assert!(route_messages(
             &deps.storage,
             QuerierWrapper::new(&deps.querier),
+            mock_env().block.height,
             sender,
             vec![rand_message("polygon".parse().unwrap(), destination_chain)]
         )
@@ -443,6 +884,7 @@ mod test {
                 admin_address: api.addr_make("admin").to_string(),
                 governance_address: api.addr_make("governance").to_string(),
                 axelarnet_gateway: api.addr_make(AXELARNET_GATEWAY).to_string(),
+                coordinator_address: None,
             },
         )
         .unwrap();
@@ -453,6 +895,7 @@ mod test {
             },
             frozen_status: FlagSet::from(GatewayDirection::None),
             msg_id_format: axelar_wasm_std::msg_id::MessageIdFormat::HexTxHashAndEventIndex,
+            address_format: None,
         };
         chain_endpoints()
             .save(
@@ -468,6 +911,7 @@ mod test {
             },
             frozen_status: FlagSet::from(GatewayDirection::Bidirectional),
             msg_id_format: axelar_wasm_std::msg_id::MessageIdFormat::HexTxHashAndEventIndex,
+            address_format: None,
         };
         chain_endpoints()
             .save(
@@ -477,7 +921,7 @@ mod test {
             )
             .unwrap();
 
-        assert!(route_messages(&deps.storage, QuerierWrapper::new(&deps.querier), sender, vec![rand_message(source_chain, destination_chain.clone())])
+        assert!(route_messages(&deps.storage, QuerierWrapper::new(&deps.querier), mock_env().block.height, sender, vec![rand_message(source_chain, destination_chain.clone())])
             .is_err_and(move |err| {
                 matches!(err.current_context(), Error::ChainFrozen { chain } if *chain == destination_chain)
             }));
@@ -499,6 +943,7 @@ mod test {
                 admin_address: api.addr_make("admin").to_string(),
                 governance_address: api.addr_make("governance").to_string(),
                 axelarnet_gateway: api.addr_make(AXELARNET_GATEWAY).to_string(),
+                coordinator_address: None,
             },
         )
         .unwrap();
@@ -510,6 +955,7 @@ mod test {
             },
             frozen_status: FlagSet::from(GatewayDirection::None),
             msg_id_format: axelar_wasm_std::msg_id::MessageIdFormat::HexTxHashAndEventIndex,
+            address_format: None,
         };
         chain_endpoints()
             .save(
@@ -525,6 +971,7 @@ mod test {
             route_messages(
                 &deps.storage,
                 QuerierWrapper::new(&deps.querier),
+                mock_env().block.height,
                 sender,
                 vec![msg]
             ),
@@ -549,6 +996,7 @@ mod test {
                 admin_address: api.addr_make("admin").to_string(),
                 governance_address: api.addr_make("governance").to_string(),
                 axelarnet_gateway: api.addr_make(AXELARNET_GATEWAY).to_string(),
+                coordinator_address: None,
             },
         )
         .unwrap();
@@ -559,6 +1007,7 @@ mod test {
             route_messages(
                 &deps.storage,
                 QuerierWrapper::new(&deps.querier),
+                mock_env().block.height,
                 sender,
                 vec![msg]
             ),
@@ -583,6 +1032,7 @@ mod test {
                 admin_address: api.addr_make("admin").to_string(),
                 governance_address: api.addr_make("governance").to_string(),
                 axelarnet_gateway: api.addr_make(AXELARNET_GATEWAY).to_string(),
+                coordinator_address: None,
             },
         )
         .unwrap();
@@ -594,6 +1044,7 @@ mod test {
             },
             frozen_status: FlagSet::from(GatewayDirection::None),
             msg_id_format: axelar_wasm_std::msg_id::MessageIdFormat::Base58TxDigestAndEventIndex,
+            address_format: None,
         };
         chain_endpoints()
             .save(
@@ -619,6 +1070,7 @@ mod test {
             route_messages(
                 &deps.storage,
                 QuerierWrapper::new(&deps.querier),
+                mock_env().block.height,
                 sender,
                 vec![msg]
             ),
@@ -644,6 +1096,7 @@ mod test {
                 admin_address: api.addr_make("admin").to_string(),
                 governance_address: api.addr_make("governance").to_string(),
                 axelarnet_gateway: api.addr_make(AXELARNET_GATEWAY).to_string(),
+                coordinator_address: None,
             },
         )
         .unwrap();
@@ -655,6 +1108,7 @@ mod test {
             },
             frozen_status: FlagSet::from(GatewayDirection::None),
             msg_id_format: axelar_wasm_std::msg_id::MessageIdFormat::HexTxHashAndEventIndex,
+            address_format: None,
         };
         chain_endpoints()
             .save(
@@ -670,6 +1124,7 @@ mod test {
             },
             frozen_status: FlagSet::from(GatewayDirection::None),
             msg_id_format: axelar_wasm_std::msg_id::MessageIdFormat::HexTxHashAndEventIndex,
+            address_format: None,
         };
         chain_endpoints()
             .save(
@@ -685,6 +1140,7 @@ mod test {
             },
             frozen_status: FlagSet::from(GatewayDirection::None),
             msg_id_format: axelar_wasm_std::msg_id::MessageIdFormat::HexTxHashAndEventIndex,
+            address_format: None,
         };
         chain_endpoints()
             .save(
@@ -697,6 +1153,7 @@ mod test {
         assert!(route_messages(
             &deps.storage,
             QuerierWrapper::new(&deps.querier),
+            mock_env().block.height,
             sender,
             vec![
                 rand_message(source_chain.clone(), destination_chain_1.clone()),
@@ -725,6 +1182,7 @@ mod test {
                 admin_address: api.addr_make("admin").to_string(),
                 governance_address: api.addr_make("governance").to_string(),
                 axelarnet_gateway: api.addr_make(AXELARNET_GATEWAY).to_string(),
+                coordinator_address: None,
             },
         )
         .unwrap();
@@ -736,6 +1194,7 @@ mod test {
             },
             frozen_status: FlagSet::from(GatewayDirection::None),
             msg_id_format: axelar_wasm_std::msg_id::MessageIdFormat::HexTxHashAndEventIndex,
+            address_format: None,
         };
         chain_endpoints()
             .save(
@@ -751,6 +1210,7 @@ mod test {
             },
             frozen_status: FlagSet::from(GatewayDirection::None),
             msg_id_format: axelar_wasm_std::msg_id::MessageIdFormat::HexTxHashAndEventIndex,
+            address_format: None,
         };
         chain_endpoints()
             .save(
@@ -763,6 +1223,7 @@ mod test {
         assert!(route_messages(
             &deps.storage,
             QuerierWrapper::new(&deps.querier),
+            mock_env().block.height,
             sender,
             vec![
                 rand_message(source_chain.clone(), destination_chain_1.clone()),
@@ -794,6 +1255,7 @@ mod test {
                 admin_address: api.addr_make("admin").to_string(),
                 governance_address: api.addr_make("governance").to_string(),
                 axelarnet_gateway: api.addr_make(AXELARNET_GATEWAY).to_string(),
+                coordinator_address: None,
             },
         )
         .unwrap();
@@ -801,6 +1263,7 @@ mod test {
         assert!(route_messages(
             &deps.storage,
             QuerierWrapper::new(&deps.querier),
+            mock_env().block.height,
             sender,
             vec![rand_message(
                 source_chain.clone(),
@@ -829,6 +1292,7 @@ mod test {
                 admin_address: api.addr_make("admin").to_string(),
                 governance_address: api.addr_make("governance").to_string(),
                 axelarnet_gateway: api.addr_make(AXELARNET_GATEWAY).to_string(),
+                coordinator_address: None,
             },
         )
         .unwrap();
@@ -840,6 +1304,7 @@ mod test {
             },
             frozen_status: FlagSet::from(GatewayDirection::None),
             msg_id_format: axelar_wasm_std::msg_id::MessageIdFormat::HexTxHashAndEventIndex,
+            address_format: None,
         };
         chain_endpoints()
             .save(
@@ -852,6 +1317,7 @@ mod test {
         assert!(route_messages(
             &deps.storage,
             QuerierWrapper::new(&deps.querier),
+            mock_env().block.height,
             sender,
             vec![rand_message(
                 source_chain.clone(),
@@ -877,6 +1343,7 @@ mod test {
                     },
                     frozen_status: FlagSet::from(GatewayDirection::None),
                     msg_id_format: axelar_wasm_std::msg_id::MessageIdFormat::HexTxHashAndEventIndex,
+                    address_format: None,
                 },
             )
             .unwrap();
@@ -968,6 +1435,7 @@ mod test {
                     },
                     frozen_status: FlagSet::from(GatewayDirection::None),
                     msg_id_format: axelar_wasm_std::msg_id::MessageIdFormat::HexTxHashAndEventIndex,
+                    address_format: None,
                 },
             )
             .unwrap();
@@ -1,16 +1,38 @@
-use axelar_wasm_std::migrate_from_version;
+use axelar_wasm_std::{address, migrate_from_version};
+use cosmwasm_schema::cw_serde;
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
-use cosmwasm_std::{DepsMut, Empty, Env, Response};
+use cosmwasm_std::{DepsMut, Env, Response};
 
-pub type MigrateMsg = Empty;
+use crate::state::{Config, CONFIG};
+
+#[cw_serde]
+pub struct MigrateMsg {
+    /// The coordinator is allowed to set the maintenance windows of chains
+    #[serde(default)]
+    pub coordinator_address: Option<String>,
+}
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 #[migrate_from_version("1.2")]
 pub fn migrate(
     deps: DepsMut,
     _env: Env,
-    _msg: MigrateMsg,
+    msg: MigrateMsg,
 ) -> Result<Response, axelar_wasm_std::error::ContractError> {
+    let coordinator = msg
+        .coordinator_address
+        .map(|addr| address::validate_cosmwasm_address(deps.api, &addr))
+        .transpose()?;
+
+    let config = CONFIG.load(deps.storage)?;
+    CONFIG.save(
+        deps.storage,
+        &Config {
+            coordinator,
+            ..config
+        },
+    )?;
+
     Ok(Response::default())
 }
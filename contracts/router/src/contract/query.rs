@@ -2,8 +2,12 @@ use cosmwasm_std::{Order, Storage};
 use cw_storage_plus::Bound;
 use error_stack::{Result, ResultExt};
 use router_api::error::Error;
-use router_api::{ChainEndpoint, ChainName};
+use router_api::{
+    ChainEndpoint, ChainName, ChainNameRaw, CrossChainId, LatencyPercentiles, MessageExpiry, Tap,
+};
 
+use crate::contract::execute::LATENCY_RETENTION_BLOCKS;
+use crate::state;
 use crate::state::chain_endpoints;
 
 // Pagination limits
@@ -34,6 +38,89 @@ pub fn chains(
         .collect()
 }
 
+pub fn recalled_messages(
+    storage: &dyn Storage,
+    start_after: Option<CrossChainId>,
+    limit: Option<u32>,
+) -> Result<Vec<CrossChainId>, Error> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT) as usize;
+
+    state::load_recalled_messages(storage, start_after, limit)
+}
+
+pub fn message_expiry(
+    storage: &dyn Storage,
+    block_height: u64,
+    cc_id: CrossChainId,
+) -> Result<Option<MessageExpiry>, Error> {
+    // messages marked as expired no longer have a routing record
+    if let Some(expired) = state::may_load_expired_message(storage, &cc_id)? {
+        return Ok(Some(MessageExpiry {
+            expires_at: expired.expires_at,
+            expired: true,
+        }));
+    }
+
+    Ok(
+        state::may_load_routed_message(storage, &cc_id)?.and_then(|routed| {
+            routed.expires_at.map(|expires_at| MessageExpiry {
+                expires_at,
+                expired: routed.is_expired(block_height),
+            })
+        }),
+    )
+}
+
+pub fn taps(
+    storage: &dyn Storage,
+    source_chain: ChainNameRaw,
+    destination_chain: ChainName,
+) -> Result<Vec<Tap>, Error> {
+    state::load_taps(storage, &source_chain, &destination_chain)
+}
+
+/// Maximum number of the most recent latencies a latency query takes into account, so its cost stays bounded
+const MAX_LATENCY_SAMPLES: usize = 1_000;
+
+pub fn latency(
+    storage: &dyn Storage,
+    block_height: u64,
+    source_chain: ChainNameRaw,
+    destination_chain: ChainName,
+    window: u64,
+) -> Result<Option<LatencyPercentiles>, Error> {
+    let window = window.min(LATENCY_RETENTION_BLOCKS);
+    let since = block_height.saturating_sub(window).saturating_add(1);
+
+    let mut latencies = state::load_latencies_since(
+        storage,
+        &source_chain,
+        &destination_chain,
+        since,
+        MAX_LATENCY_SAMPLES,
+    )?;
+    if latencies.is_empty() {
+        return Ok(None);
+    }
+    latencies.sort_unstable();
+
+    Ok(Some(LatencyPercentiles {
+        sample_count: latencies.len() as u64,
+        p50: percentile(&latencies, 50),
+        p90: percentile(&latencies, 90),
+        p99: percentile(&latencies, 99),
+    }))
+}
+
+/// Nearest-rank percentile of a non-empty, sorted list
+fn percentile(sorted: &[u64], percentile: usize) -> u64 {
+    let rank = sorted.len().saturating_mul(percentile).div_ceil(100);
+
+    *sorted
+        .get(rank.saturating_sub(1))
+        .expect("rank must be within the list")
+}
+
 #[cfg(test)]
 mod test {
     use axelar_wasm_std::flagset::FlagSet;
@@ -41,7 +128,7 @@ mod test {
     use router_api::error::Error;
     use router_api::{ChainEndpoint, ChainName, Gateway, GatewayDirection};
 
-    use super::chain_info;
+    use super::{chain_info, percentile};
     use crate::state::chain_endpoints;
 
     #[test]
@@ -55,6 +142,7 @@ mod test {
             },
             frozen_status: FlagSet::from(GatewayDirection::None),
             msg_id_format: axelar_wasm_std::msg_id::MessageIdFormat::HexTxHashAndEventIndex,
+            address_format: None,
         };
 
         assert!(chain_endpoints()
@@ -93,6 +181,7 @@ mod test {
                 },
                 frozen_status: FlagSet::from(GatewayDirection::None),
                 msg_id_format: axelar_wasm_std::msg_id::MessageIdFormat::HexTxHashAndEventIndex,
+                address_format: None,
             })
             .collect();
 
@@ -141,4 +230,14 @@ mod test {
         .unwrap();
         assert_eq!(result.len(), 0);
     }
+
+    #[test]
+    fn percentile_uses_nearest_rank() {
+        let latencies: Vec<u64> = (1..=10).collect();
+
+        assert_eq!(percentile(&latencies, 50), 5);
+        assert_eq!(percentile(&latencies, 90), 9);
+        assert_eq!(percentile(&latencies, 99), 10);
+        assert_eq!(percentile(&[7], 50), 7);
+    }
 }
@@ -0,0 +1,11 @@
+use cosmwasm_std::{Reply, Response};
+use error_stack::Result;
+
+use crate::events::TapFailed;
+
+/// Routing must never be blocked by a tap, so a failed tap is only recorded in an event
+pub fn tap_failed_reply(reply: Reply) -> Result<Response, axelar_wasm_std::reply::Error> {
+    let reason = axelar_wasm_std::reply::parse_failure(reply)?;
+
+    Ok(Response::new().add_event(TapFailed { reason }))
+}
@@ -1,5 +1,7 @@
+use axelar_wasm_std::address::AddressFormat;
+use axelar_wasm_std::canonical_json;
 use cosmwasm_std::{Addr, Attribute, Event};
-use router_api::{ChainName, GatewayDirection, Message};
+use router_api::{ChainName, ChainNameRaw, CrossChainId, GatewayDirection, Message};
 
 pub struct RouterInstantiated {
     pub admin: Addr,
@@ -12,6 +14,11 @@ pub struct ChainRegistered {
     pub gateway: Addr,
 }
 
+pub struct AddressFormatUpdated {
+    pub chain: ChainName,
+    pub address_format: Option<AddressFormat>,
+}
+
 pub struct GatewayInfo {
     pub chain: ChainName,
     pub gateway_address: Addr,
@@ -38,6 +45,40 @@ pub struct MessageRouted {
 pub struct RoutingDisabled;
 pub struct RoutingEnabled;
 
+pub struct TapRegistered {
+    pub source_chain: ChainNameRaw,
+    pub destination_chain: ChainName,
+    pub address: Addr,
+    pub gas_limit: u64,
+}
+
+pub struct TapDeregistered {
+    pub source_chain: ChainNameRaw,
+    pub destination_chain: ChainName,
+    pub address: Addr,
+}
+
+pub struct MessageExecutionRecorded {
+    pub cc_id: CrossChainId,
+    pub destination_chain: ChainName,
+    pub latency: u64,
+}
+
+pub struct MessageRecalled {
+    pub cc_id: CrossChainId,
+    pub destination_chain: ChainName,
+}
+
+pub struct MessageExpired {
+    pub cc_id: CrossChainId,
+    pub destination_chain: ChainName,
+    pub expires_at: u64,
+}
+
+pub struct TapFailed {
+    pub reason: String,
+}
+
 impl From<RouterInstantiated> for Event {
     fn from(other: RouterInstantiated) -> Self {
         Event::new("router_instantiated")
@@ -54,6 +95,18 @@ impl From<ChainRegistered> for Event {
     }
 }
 
+impl From<AddressFormatUpdated> for Event {
+    fn from(other: AddressFormatUpdated) -> Self {
+        Event::new("address_format_updated")
+            .add_attribute("chain", other.chain)
+            .add_attribute(
+                "address_format",
+                canonical_json::to_string(&other.address_format)
+                    .expect("failed to serialize address format"),
+            )
+    }
+}
+
 impl From<GatewayInfo> for Vec<Attribute> {
     fn from(other: GatewayInfo) -> Self {
         vec![
@@ -88,7 +141,7 @@ impl From<ChainFrozen> for Event {
             .add_attribute("name", other.name)
             .add_attribute(
                 "direction",
-                serde_json::to_string(&other.direction).expect("failed to serialize direction"),
+                canonical_json::to_string(&other.direction).expect("failed to serialize direction"),
             )
     }
 }
@@ -99,7 +152,7 @@ impl From<ChainUnfrozen> for Event {
             .add_attribute("name", other.name)
             .add_attribute(
                 "direction",
-                serde_json::to_string(&other.direction).expect("failed to serialize direction"),
+                canonical_json::to_string(&other.direction).expect("failed to serialize direction"),
             )
     }
 }
@@ -111,3 +164,57 @@ impl From<MessageRouted> for Event {
         Event::new("message_routed").add_attributes(attrs)
     }
 }
+
+impl From<TapRegistered> for Event {
+    fn from(other: TapRegistered) -> Self {
+        Event::new("tap_registered")
+            .add_attribute("source_chain", other.source_chain)
+            .add_attribute("destination_chain", other.destination_chain)
+            .add_attribute("address", other.address)
+            .add_attribute("gas_limit", other.gas_limit.to_string())
+    }
+}
+
+impl From<TapDeregistered> for Event {
+    fn from(other: TapDeregistered) -> Self {
+        Event::new("tap_deregistered")
+            .add_attribute("source_chain", other.source_chain)
+            .add_attribute("destination_chain", other.destination_chain)
+            .add_attribute("address", other.address)
+    }
+}
+
+impl From<MessageExecutionRecorded> for Event {
+    fn from(other: MessageExecutionRecorded) -> Self {
+        Event::new("message_execution_recorded")
+            .add_attribute("source_chain", other.cc_id.source_chain)
+            .add_attribute("message_id", other.cc_id.message_id)
+            .add_attribute("destination_chain", other.destination_chain)
+            .add_attribute("latency", other.latency.to_string())
+    }
+}
+
+impl From<MessageRecalled> for Event {
+    fn from(other: MessageRecalled) -> Self {
+        Event::new("message_recalled")
+            .add_attribute("source_chain", other.cc_id.source_chain)
+            .add_attribute("message_id", other.cc_id.message_id)
+            .add_attribute("destination_chain", other.destination_chain)
+    }
+}
+
+impl From<MessageExpired> for Event {
+    fn from(other: MessageExpired) -> Self {
+        Event::new("message_expired")
+            .add_attribute("source_chain", other.cc_id.source_chain)
+            .add_attribute("message_id", other.cc_id.message_id)
+            .add_attribute("destination_chain", other.destination_chain)
+            .add_attribute("expires_at", other.expires_at.to_string())
+    }
+}
+
+impl From<TapFailed> for Event {
+    fn from(other: TapFailed) -> Self {
+        Event::new("tap_failed").add_attribute("reason", other.reason)
+    }
+}
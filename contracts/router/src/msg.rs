@@ -12,4 +12,7 @@ pub struct InstantiateMsg {
     pub governance_address: String,
     // the address of the axelarnet gateway
     pub axelarnet_gateway: String,
+    // the coordinator schedules maintenance windows of chains
+    #[serde(default)]
+    pub coordinator_address: Option<String>,
 }
@@ -1,9 +1,11 @@
+use axelar_wasm_std::maintenance::{self, MaintenanceWindow};
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Order, StdResult, Storage};
-use cw_storage_plus::{Index, IndexList, IndexedMap, Item, MultiIndex};
+use cw_storage_plus::{Bound, Index, IndexList, IndexedMap, Item, Map, MultiIndex, PrefixBound};
 use error_stack::{report, ResultExt};
+use itertools::Itertools;
 use router_api::error::Error;
-use router_api::{ChainEndpoint, ChainName};
+use router_api::{ChainEndpoint, ChainName, ChainNameRaw, CrossChainId, Tap};
 
 pub fn save_config(storage: &mut dyn Storage, config: &Config) -> error_stack::Result<(), Error> {
     CONFIG
@@ -38,10 +40,412 @@ pub fn load_chain_by_gateway(
 #[cw_serde]
 pub struct Config {
     pub axelarnet_gateway: Addr,
+    /// The coordinator pushes the maintenance windows of chains to the router
+    #[serde(default)]
+    pub coordinator: Option<Addr>,
 }
 
 pub const CONFIG: Item<Config> = Item::new("config");
 
+/// Taps keyed by (source chain, destination chain, tap address), the value is the gas limit of the forwarded copy
+const TAPS: Map<(ChainNameRaw, ChainName, Addr), u64> = Map::new("taps");
+
+pub fn may_load_tap(
+    storage: &dyn Storage,
+    source_chain: &ChainNameRaw,
+    destination_chain: &ChainName,
+    address: &Addr,
+) -> error_stack::Result<Option<Tap>, Error> {
+    TAPS.may_load(
+        storage,
+        (
+            source_chain.clone(),
+            destination_chain.clone(),
+            address.clone(),
+        ),
+    )
+    .change_context(Error::StoreFailure)
+    .map(|gas_limit| {
+        gas_limit.map(|gas_limit| Tap {
+            address: address.clone(),
+            gas_limit,
+        })
+    })
+}
+
+pub fn load_taps(
+    storage: &dyn Storage,
+    source_chain: &ChainNameRaw,
+    destination_chain: &ChainName,
+) -> error_stack::Result<Vec<Tap>, Error> {
+    TAPS.prefix((source_chain.clone(), destination_chain.clone()))
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(address, gas_limit)| Tap { address, gas_limit }))
+        .collect::<StdResult<Vec<_>>>()
+        .change_context(Error::StoreFailure)
+}
+
+pub fn save_tap(
+    storage: &mut dyn Storage,
+    source_chain: &ChainNameRaw,
+    destination_chain: &ChainName,
+    tap: &Tap,
+) -> error_stack::Result<(), Error> {
+    TAPS.save(
+        storage,
+        (
+            source_chain.clone(),
+            destination_chain.clone(),
+            tap.address.clone(),
+        ),
+        &tap.gas_limit,
+    )
+    .change_context(Error::StoreFailure)
+}
+
+pub fn remove_tap(
+    storage: &mut dyn Storage,
+    source_chain: &ChainNameRaw,
+    destination_chain: &ChainName,
+    address: &Addr,
+) {
+    TAPS.remove(
+        storage,
+        (
+            source_chain.clone(),
+            destination_chain.clone(),
+            address.clone(),
+        ),
+    )
+}
+
+/// A routed message whose execution on the destination chain has not been reported yet
+#[cw_serde]
+pub struct RoutedMessage {
+    pub destination_chain: ChainName,
+    /// block height at which the router received the message
+    pub block_height: u64,
+    /// block height from which on the message can no longer be approved, if it was routed with a TTL
+    #[serde(default)]
+    pub expires_at: Option<u64>,
+}
+
+impl RoutedMessage {
+    pub fn is_expired(&self, block_height: u64) -> bool {
+        self.expires_at
+            .is_some_and(|expires_at| block_height >= expires_at)
+    }
+}
+
+const ROUTED_MESSAGES: Map<&CrossChainId, RoutedMessage> = Map::new("routed_messages");
+
+/// Latencies (in blocks) of executed messages, keyed by (source chain, destination chain, execution block height)
+const LATENCIES: Map<(ChainNameRaw, ChainName, u64), Vec<u64>> = Map::new("latencies");
+
+pub fn may_load_routed_message(
+    storage: &dyn Storage,
+    cc_id: &CrossChainId,
+) -> error_stack::Result<Option<RoutedMessage>, Error> {
+    ROUTED_MESSAGES
+        .may_load(storage, cc_id)
+        .change_context(Error::StoreFailure)
+}
+
+pub fn save_routed_message(
+    storage: &mut dyn Storage,
+    cc_id: &CrossChainId,
+    msg: &RoutedMessage,
+) -> error_stack::Result<(), Error> {
+    ROUTED_MESSAGES
+        .save(storage, cc_id, msg)
+        .change_context(Error::StoreFailure)
+}
+
+pub fn remove_routed_message(storage: &mut dyn Storage, cc_id: &CrossChainId) {
+    ROUTED_MESSAGES.remove(storage, cc_id)
+}
+
+/// Messages that were recalled by governance, with the block height at which they were recalled
+const RECALLED_MESSAGES: Map<CrossChainId, u64> = Map::new("recalled_messages");
+
+pub fn is_recalled(
+    storage: &dyn Storage,
+    cc_id: &CrossChainId,
+) -> error_stack::Result<bool, Error> {
+    RECALLED_MESSAGES
+        .may_load(storage, cc_id.clone())
+        .map(|recalled| recalled.is_some())
+        .change_context(Error::StoreFailure)
+}
+
+pub fn save_recalled_message(
+    storage: &mut dyn Storage,
+    cc_id: &CrossChainId,
+    block_height: u64,
+) -> error_stack::Result<(), Error> {
+    RECALLED_MESSAGES
+        .save(storage, cc_id.clone(), &block_height)
+        .change_context(Error::StoreFailure)
+}
+
+/// Maintenance windows of chains as scheduled by the coordinator
+const MAINTENANCE_WINDOWS: Map<ChainName, Vec<MaintenanceWindow>> = Map::new("maintenance_windows");
+
+pub fn save_maintenance_windows(
+    storage: &mut dyn Storage,
+    chain: ChainName,
+    windows: Vec<MaintenanceWindow>,
+) -> error_stack::Result<(), Error> {
+    if windows.is_empty() {
+        MAINTENANCE_WINDOWS.remove(storage, chain);
+        return Ok(());
+    }
+
+    MAINTENANCE_WINDOWS
+        .save(storage, chain, &windows)
+        .change_context(Error::StoreFailure)
+}
+
+pub fn is_under_maintenance(
+    storage: &dyn Storage,
+    chain: &ChainName,
+    block_height: u64,
+) -> error_stack::Result<bool, Error> {
+    let windows = MAINTENANCE_WINDOWS
+        .may_load(storage, chain.clone())
+        .change_context(Error::StoreFailure)?
+        .unwrap_or_default();
+
+    Ok(maintenance::active_window(&windows, block_height).is_some())
+}
+
+/// A routed message that was marked as expired. It replaces the routed message, which is no longer needed.
+#[cw_serde]
+pub struct ExpiredMessage {
+    /// block height from which on the message could no longer be approved
+    pub expires_at: u64,
+    /// block height at which the message was marked as expired
+    pub marked_at: u64,
+}
+
+const EXPIRED_MESSAGES: Map<&CrossChainId, ExpiredMessage> = Map::new("expired_messages");
+const EXPIRED_MESSAGES_BY_HEIGHT: Map<(u64, &CrossChainId), ()> =
+    Map::new("expired_messages_by_height");
+
+pub fn may_load_expired_message(
+    storage: &dyn Storage,
+    cc_id: &CrossChainId,
+) -> error_stack::Result<Option<ExpiredMessage>, Error> {
+    EXPIRED_MESSAGES
+        .may_load(storage, cc_id)
+        .change_context(Error::StoreFailure)
+}
+
+pub fn is_marked_expired(
+    storage: &dyn Storage,
+    cc_id: &CrossChainId,
+) -> error_stack::Result<bool, Error> {
+    may_load_expired_message(storage, cc_id).map(|expired| expired.is_some())
+}
+
+/// Marks the routed message as expired, removing its routing record
+pub fn mark_expired(
+    storage: &mut dyn Storage,
+    cc_id: &CrossChainId,
+    expired: &ExpiredMessage,
+) -> error_stack::Result<(), Error> {
+    remove_routed_message(storage, cc_id);
+
+    EXPIRED_MESSAGES
+        .save(storage, cc_id, expired)
+        .change_context(Error::StoreFailure)?;
+    EXPIRED_MESSAGES_BY_HEIGHT
+        .save(storage, (expired.marked_at, cc_id), &())
+        .change_context(Error::StoreFailure)
+}
+
+/// Removes up to `limit` expired messages that were marked before the given block height, oldest first
+pub fn prune_expired_messages_before(
+    storage: &mut dyn Storage,
+    block_height: u64,
+    limit: usize,
+) -> error_stack::Result<(), Error> {
+    let prunable = EXPIRED_MESSAGES_BY_HEIGHT
+        .prefix_range(
+            storage,
+            None,
+            Some(PrefixBound::exclusive(block_height)),
+            Order::Ascending,
+        )
+        .take(limit)
+        .map_ok(|(key, _)| key)
+        .collect::<StdResult<Vec<_>>>()
+        .change_context(Error::StoreFailure)?;
+
+    for (marked_at, cc_id) in prunable {
+        EXPIRED_MESSAGES.remove(storage, &cc_id);
+        EXPIRED_MESSAGES_BY_HEIGHT.remove(storage, (marked_at, &cc_id));
+    }
+
+    Ok(())
+}
+
+/// Routed messages whose execution was recorded, by the block height of the execution. They replace the routed messages,
+/// so messages that are routed again after their execution are neither tracked nor recallable again
+const EXECUTED_MESSAGES: Map<&CrossChainId, u64> = Map::new("executed_messages");
+const EXECUTED_MESSAGES_BY_HEIGHT: Map<(u64, &CrossChainId), ()> =
+    Map::new("executed_messages_by_height");
+
+pub fn is_marked_executed(
+    storage: &dyn Storage,
+    cc_id: &CrossChainId,
+) -> error_stack::Result<bool, Error> {
+    EXECUTED_MESSAGES
+        .may_load(storage, cc_id)
+        .map(|executed_at| executed_at.is_some())
+        .change_context(Error::StoreFailure)
+}
+
+/// Marks the routed message as executed, removing its routing record
+pub fn mark_executed(
+    storage: &mut dyn Storage,
+    cc_id: &CrossChainId,
+    block_height: u64,
+) -> error_stack::Result<(), Error> {
+    remove_routed_message(storage, cc_id);
+
+    EXECUTED_MESSAGES
+        .save(storage, cc_id, &block_height)
+        .change_context(Error::StoreFailure)?;
+    EXECUTED_MESSAGES_BY_HEIGHT
+        .save(storage, (block_height, cc_id), &())
+        .change_context(Error::StoreFailure)
+}
+
+/// Removes up to `limit` executed messages that were executed before the given block height, oldest first
+pub fn prune_executed_messages_before(
+    storage: &mut dyn Storage,
+    block_height: u64,
+    limit: usize,
+) -> error_stack::Result<(), Error> {
+    let prunable = EXECUTED_MESSAGES_BY_HEIGHT
+        .prefix_range(
+            storage,
+            None,
+            Some(PrefixBound::exclusive(block_height)),
+            Order::Ascending,
+        )
+        .take(limit)
+        .map_ok(|(key, _)| key)
+        .collect::<StdResult<Vec<_>>>()
+        .change_context(Error::StoreFailure)?;
+
+    for (executed_at, cc_id) in prunable {
+        EXECUTED_MESSAGES.remove(storage, &cc_id);
+        EXECUTED_MESSAGES_BY_HEIGHT.remove(storage, (executed_at, &cc_id));
+    }
+
+    Ok(())
+}
+
+pub fn load_recalled_messages(
+    storage: &dyn Storage,
+    start_after: Option<CrossChainId>,
+    limit: usize,
+) -> error_stack::Result<Vec<CrossChainId>, Error> {
+    RECALLED_MESSAGES
+        .keys(
+            storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .collect::<StdResult<Vec<_>>>()
+        .change_context(Error::StoreFailure)
+}
+
+pub fn add_latency(
+    storage: &mut dyn Storage,
+    source_chain: &ChainNameRaw,
+    destination_chain: &ChainName,
+    block_height: u64,
+    latency: u64,
+) -> error_stack::Result<(), Error> {
+    LATENCIES
+        .update(
+            storage,
+            (
+                source_chain.clone(),
+                destination_chain.clone(),
+                block_height,
+            ),
+            |latencies| -> StdResult<_> {
+                let mut latencies = latencies.unwrap_or_default();
+                latencies.push(latency);
+                Ok(latencies)
+            },
+        )
+        .change_context(Error::StoreFailure)?;
+
+    Ok(())
+}
+
+/// Loads the latencies of up to `limit` of the most recently executed messages between the chain pair that were
+/// executed at or after the given block height
+pub fn load_latencies_since(
+    storage: &dyn Storage,
+    source_chain: &ChainNameRaw,
+    destination_chain: &ChainName,
+    block_height: u64,
+    limit: usize,
+) -> error_stack::Result<Vec<u64>, Error> {
+    LATENCIES
+        .prefix((source_chain.clone(), destination_chain.clone()))
+        .range(
+            storage,
+            Some(Bound::inclusive(block_height)),
+            None,
+            Order::Descending,
+        )
+        .map(|item| item.map(|(_, latencies)| latencies))
+        .flatten_ok()
+        .take(limit)
+        .collect::<StdResult<Vec<_>>>()
+        .change_context(Error::StoreFailure)
+}
+
+/// Removes the latencies of messages between the chain pair that were executed before the given block height,
+/// for up to `limit` block heights
+pub fn prune_latencies_before(
+    storage: &mut dyn Storage,
+    source_chain: &ChainNameRaw,
+    destination_chain: &ChainName,
+    block_height: u64,
+    limit: usize,
+) -> error_stack::Result<(), Error> {
+    let prefix = LATENCIES.prefix((source_chain.clone(), destination_chain.clone()));
+    let expired = prefix
+        .keys(
+            storage,
+            None,
+            Some(Bound::exclusive(block_height)),
+            Order::Ascending,
+        )
+        .take(limit)
+        .collect::<StdResult<Vec<_>>>()
+        .change_context(Error::StoreFailure)?;
+
+    for height in expired {
+        LATENCIES.remove(
+            storage,
+            (source_chain.clone(), destination_chain.clone(), height),
+        );
+    }
+
+    Ok(())
+}
+
 pub struct ChainEndpointIndexes<'a> {
     pub gateway: GatewayIndex<'a>,
 }
@@ -9,7 +9,7 @@ use service_registry_api::error::ContractError;
 use service_registry_api::{AuthorizationState, BondingState, Service};
 
 use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
-use crate::state::{SERVICES, VERIFIERS};
+use crate::state::{ATTESTERS, SERVICES, SLASHERS, VERIFIERS};
 
 mod execute;
 mod migrations;
@@ -42,7 +42,14 @@ pub fn execute(
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, axelar_wasm_std::error::ContractError> {
-    match msg.ensure_permissions(deps.storage, &info.sender, match_verifier(&info.sender))? {
+    match msg.ensure_permissions(
+        deps.storage,
+        &info.sender,
+        match_coordinator,
+        match_verifier(&info.sender),
+        match_slasher(&info.sender),
+        match_attester(&info.sender),
+    )? {
         ExecuteMsg::RegisterService {
             service_name,
             coordinator_contract,
@@ -52,6 +59,7 @@ pub fn execute(
             bond_denom,
             unbonding_period_days,
             description,
+            exit_handover_blocks,
         } => {
             let coordinator_contract = deps.api.addr_validate(&coordinator_contract)?;
             execute::register_service(
@@ -64,12 +72,13 @@ pub fn execute(
                 bond_denom,
                 unbonding_period_days,
                 description,
+                exit_handover_blocks,
             )
         }
         ExecuteMsg::UpdateService {
             service_name,
             updated_service_params,
-        } => execute::update_service(deps, service_name, updated_service_params),
+        } => execute::update_service(deps, env, service_name, updated_service_params),
         ExecuteMsg::AuthorizeVerifiers {
             verifiers,
             service_name,
@@ -80,6 +89,7 @@ pub fn execute(
                 .collect::<Result<Vec<_>, _>>()?;
             execute::update_verifier_authorization_status(
                 deps,
+                env,
                 verifiers,
                 service_name,
                 AuthorizationState::Authorized,
@@ -95,6 +105,7 @@ pub fn execute(
                 .collect::<Result<Vec<_>, _>>()?;
             execute::update_verifier_authorization_status(
                 deps,
+                env,
                 verifiers,
                 service_name,
                 AuthorizationState::NotAuthorized,
@@ -110,6 +121,7 @@ pub fn execute(
                 .collect::<Result<Vec<_>, _>>()?;
             execute::update_verifier_authorization_status(
                 deps,
+                env,
                 verifiers,
                 service_name,
                 AuthorizationState::Jailed,
@@ -118,24 +130,106 @@ pub fn execute(
         ExecuteMsg::RegisterChainSupport {
             service_name,
             chains,
-        } => execute::register_chains_support(deps, info, service_name, chains),
+        } => execute::register_chains_support(deps, env, info, service_name, chains),
         ExecuteMsg::DeregisterChainSupport {
             service_name,
             chains,
-        } => execute::deregister_chains_support(deps, info, service_name, chains),
+        } => execute::deregister_chains_support(deps, env, info, service_name, chains),
         ExecuteMsg::BondVerifier { service_name } => {
-            execute::bond_verifier(deps, info, service_name)
+            execute::bond_verifier(deps, env, info, service_name)
         }
         ExecuteMsg::UnbondVerifier { service_name } => {
             execute::unbond_verifier(deps, env, info, service_name)
         }
+        ExecuteMsg::UnbondPartialStake {
+            service_name,
+            amount,
+        } => execute::unbond_partial_stake(deps, env, info, service_name, amount),
         ExecuteMsg::ClaimStake { service_name } => {
             execute::claim_stake(deps, env, info, service_name)
         }
+        ExecuteMsg::RequestExit { service_name } => {
+            execute::request_exit(deps, env, info, service_name)
+        }
+        ExecuteMsg::ProcessExitQueue {
+            service_name,
+            limit,
+        } => execute::process_exit_queue(deps, env, service_name, limit),
+        ExecuteMsg::RegisterSlasher {
+            service_name,
+            slasher,
+            limits,
+        } => {
+            let slasher = address::validate_cosmwasm_address(deps.api, &slasher)?;
+            execute::register_slasher(deps, service_name, slasher, limits)
+        }
+        ExecuteMsg::DeregisterSlasher {
+            service_name,
+            slasher,
+        } => {
+            let slasher = address::validate_cosmwasm_address(deps.api, &slasher)?;
+            execute::deregister_slasher(deps, service_name, slasher)
+        }
+        ExecuteMsg::SlashVerifier {
+            service_name,
+            verifier,
+            amount,
+            destination,
+            reason,
+        } => {
+            let verifier = address::validate_cosmwasm_address(deps.api, &verifier)?;
+            let destination = destination
+                .map(|destination| address::validate_cosmwasm_address(deps.api, &destination))
+                .transpose()?;
+            execute::slash_verifier(
+                deps,
+                env,
+                info,
+                service_name,
+                verifier,
+                amount,
+                destination,
+                reason.into(),
+            )
+        }
+        ExecuteMsg::RegisterAttester {
+            service_name,
+            attester,
+        } => {
+            let attester = address::validate_cosmwasm_address(deps.api, &attester)?;
+            execute::register_attester(deps, service_name, attester)
+        }
+        ExecuteMsg::DeregisterAttester {
+            service_name,
+            attester,
+        } => {
+            let attester = address::validate_cosmwasm_address(deps.api, &attester)?;
+            execute::deregister_attester(deps, service_name, attester)
+        }
+        ExecuteMsg::SubmitAttestations {
+            service_name,
+            summaries,
+        } => execute::submit_attestations(deps, env, info, service_name, summaries),
     }?
     .then(Ok)
 }
 
+fn match_coordinator(
+    storage: &dyn Storage,
+    msg: &ExecuteMsg,
+) -> Result<Addr, Report<permission_control::Error>> {
+    let service_name = match msg {
+        ExecuteMsg::AuthorizeVerifiers { service_name, .. } => service_name,
+        _ => bail!(permission_control::Error::WrongVariant),
+    };
+
+    SERVICES
+        .load(storage, service_name)
+        .map(|service| service.coordinator_contract)
+        .change_context(ContractError::ServiceNotFound)
+        .change_context(permission_control::Error::Unauthorized)
+}
+
 fn match_verifier(
     sender: &Addr,
 ) -> impl FnOnce(&dyn Storage, &ExecuteMsg) -> Result<Addr, Report<permission_control::Error>> + '_
@@ -163,6 +257,46 @@ fn match_verifier(
     }
 }
 
+fn match_slasher(
+    sender: &Addr,
+) -> impl FnOnce(&dyn Storage, &ExecuteMsg) -> Result<Addr, Report<permission_control::Error>> + '_
+{
+    |storage: &dyn Storage, msg: &ExecuteMsg| {
+        let service_name = match msg {
+            ExecuteMsg::SlashVerifier { service_name, .. } => service_name,
+            _ => bail!(permission_control::Error::WrongVariant),
+        };
+
+        SLASHERS
+            .may_load(storage, (service_name, sender))
+            .change_context(ContractError::StorageError)
+            .change_context(permission_control::Error::Unauthorized)?
+            .ok_or(ContractError::SlasherNotFound)
+            .change_context(permission_control::Error::Unauthorized)
+            .map(|_| sender.clone())
+    }
+}
+
+fn match_attester(
+    sender: &Addr,
+) -> impl FnOnce(&dyn Storage, &ExecuteMsg) -> Result<Addr, Report<permission_control::Error>> + '_
+{
+    |storage: &dyn Storage, msg: &ExecuteMsg| {
+        let service_name = match msg {
+            ExecuteMsg::SubmitAttestations { service_name, .. } => service_name,
+            _ => bail!(permission_control::Error::WrongVariant),
+        };
+
+        ATTESTERS
+            .may_load(storage, (service_name, sender))
+            .change_context(ContractError::StorageError)
+            .change_context(permission_control::Error::Unauthorized)?
+            .ok_or(ContractError::AttesterNotFound)
+            .change_context(permission_control::Error::Unauthorized)
+            .map(|_| sender.clone())
+    }
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(
     deps: Deps,
@@ -175,6 +309,30 @@ pub fn query(
             chain_name,
         } => to_json_binary(&query::active_verifiers(deps, service_name, chain_name)?)
             .map_err(|err| err.into()),
+        QueryMsg::ActiveVerifiersAt {
+            service_name,
+            chain_name,
+            height,
+        } => to_json_binary(&query::active_verifiers_at(
+            deps,
+            service_name,
+            chain_name,
+            height,
+        )?)
+        .map_err(|err| err.into()),
+        QueryMsg::ActiveVerifiersPage {
+            service_name,
+            chain_name,
+            start_after,
+            limit,
+        } => to_json_binary(&query::active_verifiers_page(
+            deps,
+            service_name,
+            chain_name,
+            start_after,
+            limit,
+        )?)
+        .map_err(|err| err.into()),
         QueryMsg::Verifier {
             service_name,
             verifier,
@@ -183,6 +341,38 @@ pub fn query(
         QueryMsg::Service { service_name } => {
             to_json_binary(&query::service(deps, service_name)?).map_err(|err| err.into())
         }
+        QueryMsg::Verifiers {
+            service_name,
+            verifiers,
+        } => to_json_binary(&query::verifiers(deps, service_name, verifiers)?)
+            .map_err(|err| err.into()),
+        QueryMsg::PendingUnbonds {
+            service_name,
+            verifier,
+        } => to_json_binary(&query::pending_unbonds(deps, service_name, verifier)?)
+            .map_err(|err| err.into()),
+        QueryMsg::SlashHistory {
+            service_name,
+            verifier,
+        } => to_json_binary(&query::slash_history(deps, service_name, verifier)?)
+            .map_err(|err| err.into()),
+        QueryMsg::Attestations {
+            service_name,
+            start_after,
+            limit,
+        } => to_json_binary(&query::attestations(
+            deps,
+            service_name,
+            start_after,
+            limit,
+        )?)
+        .map_err(|err| err.into()),
+        QueryMsg::ExitQueue {
+            service_name,
+            start_after,
+            limit,
+        } => to_json_binary(&query::exit_queue(deps, service_name, start_after, limit)?)
+            .map_err(|err| err.into()),
     }
 }
 
@@ -201,15 +391,21 @@ mod test {
     };
     use router_api::ChainName;
     use service_registry_api::msg::{UpdatedServiceParams, VerifierDetails};
-    use service_registry_api::{Verifier, WeightedVerifier};
+    use service_registry_api::{
+        ExitRequest, ParticipationSummary, PendingUnbond, PerformanceAttestation, SlashRecord,
+        SlashingLimits, Verifier, VerifierSetSnapshot, WeightedVerifier,
+    };
 
     use super::*;
-    use crate::state::VERIFIER_WEIGHT;
+    use crate::msg::{ActiveVerifiersPageResponse, MAX_VERIFIERS_QUERY_LIMIT};
+    use crate::state::{MAX_SLASH_HISTORY_LENGTH, VERIFIER_WEIGHT};
 
     const GOVERNANCE_ADDRESS: &str = "governance";
     const UNAUTHORIZED_ADDRESS: &str = "unauthorized";
     const COORDINATOR_ADDRESS: &str = "coordinator";
     const VERIFIER_ADDRESS: &str = "verifier";
+    const SLASHER_ADDRESS: &str = "slasher";
+    const ATTESTER_ADDRESS: &str = "attester";
     const AXL_DENOMINATION: &str = "uaxl";
 
     fn setup() -> OwnedDeps<MockStorage, MockApi, MockQuerier, Empty> {
@@ -257,6 +453,7 @@ mod test {
                 bond_denom: AXL_DENOMINATION.into(),
                 unbonding_period_days: 10,
                 description: "Some service".into(),
+                exit_handover_blocks: 0,
             },
         );
         assert!(res.is_ok());
@@ -274,6 +471,7 @@ mod test {
                 bond_denom: AXL_DENOMINATION.into(),
                 unbonding_period_days: 10,
                 description: "Some service".into(),
+                exit_handover_blocks: 0,
             },
         )
         .unwrap_err();
@@ -296,6 +494,7 @@ mod test {
             bond_denom: AXL_DENOMINATION.into(),
             unbonding_period_days: 10,
             description: "amplifier service".into(),
+            exit_handover_blocks: 0,
         };
         let res = execute(
             deps,
@@ -310,6 +509,7 @@ mod test {
                 bond_denom: service.bond_denom.clone(),
                 unbonding_period_days: service.unbonding_period_days,
                 description: service.description.clone(),
+                exit_handover_blocks: 0,
             },
         );
         assert!(res.is_ok());
@@ -338,6 +538,7 @@ mod test {
                     .unwrap(),
             ),
             unbonding_period_days: Some(service.unbonding_period_days + 1),
+            exit_handover_blocks: Some(service.exit_handover_blocks + 1),
         };
 
         let res = execute(
@@ -368,6 +569,7 @@ mod test {
             min_num_verifiers: updated_params.min_num_verifiers.unwrap(),
             min_verifier_bond: updated_params.min_verifier_bond.unwrap(),
             unbonding_period_days: updated_params.unbonding_period_days.unwrap(),
+            exit_handover_blocks: updated_params.exit_handover_blocks.unwrap(),
             ..service
         };
         assert_eq!(res, expected_service);
@@ -400,6 +602,7 @@ mod test {
                     max_num_verifiers: None,
                     min_verifier_bond: Some(new_min_bond),
                     unbonding_period_days: None,
+                    exit_handover_blocks: None,
                 },
             },
         );
@@ -444,6 +647,7 @@ mod test {
                     max_num_verifiers: None,
                     min_verifier_bond: None,
                     unbonding_period_days: None,
+                    exit_handover_blocks: None,
                 },
             },
         );
@@ -476,6 +680,7 @@ mod test {
                 bond_denom: AXL_DENOMINATION.into(),
                 unbonding_period_days: 10,
                 description: "Some service".into(),
+                exit_handover_blocks: 0,
             },
         );
         assert!(res.is_ok());
@@ -506,6 +711,95 @@ mod test {
             permission_control::Error,
             permission_control::Error::PermissionDenied { .. }
         ));
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make("nowhere"), &[]),
+            ExecuteMsg::AuthorizeVerifiers {
+                verifiers: vec![MockApi::default().addr_make("other_verifier").into()],
+                service_name: service_name.into(),
+            },
+        );
+        assert!(res.is_ok());
+    }
+
+    #[test]
+    fn query_verifiers_omits_unknown_verifiers() {
+        let mut deps = setup();
+        let api = deps.api;
+
+        let service_name = "validators";
+        execute_register_service(deps.as_mut(), service_name.into());
+
+        let authorized = api.addr_make("authorized");
+        let jailed = api.addr_make("jailed");
+        let unknown = api.addr_make("unknown");
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(GOVERNANCE_ADDRESS), &[]),
+            ExecuteMsg::AuthorizeVerifiers {
+                verifiers: vec![authorized.to_string()],
+                service_name: service_name.into(),
+            },
+        );
+        assert!(res.is_ok());
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(GOVERNANCE_ADDRESS), &[]),
+            ExecuteMsg::JailVerifiers {
+                verifiers: vec![jailed.to_string()],
+                service_name: service_name.into(),
+            },
+        );
+        assert!(res.is_ok());
+
+        let verifiers: Vec<Verifier> = from_json(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::Verifiers {
+                    service_name: service_name.into(),
+                    verifiers: vec![
+                        authorized.to_string(),
+                        unknown.to_string(),
+                        jailed.to_string(),
+                    ],
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            verifiers
+                .into_iter()
+                .map(|verifier| (verifier.address, verifier.authorization_state))
+                .collect::<Vec<_>>(),
+            vec![
+                (authorized, AuthorizationState::Authorized),
+                (jailed, AuthorizationState::Jailed),
+            ]
+        );
+
+        let err = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Verifiers {
+                service_name: service_name.into(),
+                verifiers: vec![unknown.to_string(); MAX_VERIFIERS_QUERY_LIMIT + 1],
+            },
+        )
+        .unwrap_err();
+        assert!(err_contains!(
+            err.report,
+            ContractError,
+            ContractError::TooManyVerifiers { .. }
+        ));
     }
 
     #[test]
@@ -528,6 +822,7 @@ mod test {
                 bond_denom: AXL_DENOMINATION.into(),
                 unbonding_period_days: 10,
                 description: "Some service".into(),
+                exit_handover_blocks: 0,
             },
         );
         assert!(res.is_ok());
@@ -577,6 +872,7 @@ mod test {
                 bond_denom: AXL_DENOMINATION.into(),
                 unbonding_period_days: 10,
                 description: "Some service".into(),
+                exit_handover_blocks: 0,
             },
         );
         assert!(res.is_ok());
@@ -623,6 +919,7 @@ mod test {
                 bond_denom: AXL_DENOMINATION.into(),
                 unbonding_period_days: 10,
                 description: "Some service".into(),
+                exit_handover_blocks: 0,
             },
         );
         assert!(res.is_ok());
@@ -727,6 +1024,7 @@ mod test {
                 bond_denom: AXL_DENOMINATION.into(),
                 unbonding_period_days: 10,
                 description: "Some service".into(),
+                exit_handover_blocks: 0,
             },
         );
         assert!(res.is_ok());
@@ -815,6 +1113,7 @@ mod test {
                 bond_denom: AXL_DENOMINATION.into(),
                 unbonding_period_days: 10,
                 description: "Some service".into(),
+                exit_handover_blocks: 0,
             },
         );
         assert!(res.is_ok());
@@ -910,6 +1209,7 @@ mod test {
                 bond_denom: AXL_DENOMINATION.into(),
                 unbonding_period_days: 10,
                 description: "Some service".into(),
+                exit_handover_blocks: 0,
             },
         );
         assert!(res.is_ok());
@@ -1036,6 +1336,7 @@ mod test {
                 bond_denom: AXL_DENOMINATION.into(),
                 unbonding_period_days: 10,
                 description: "Some service".into(),
+                exit_handover_blocks: 0,
             },
         );
         assert!(res.is_ok());
@@ -1139,6 +1440,7 @@ mod test {
                 bond_denom: AXL_DENOMINATION.into(),
                 unbonding_period_days: 10,
                 description: "Some service".into(),
+                exit_handover_blocks: 0,
             },
         );
         assert!(res.is_ok());
@@ -1252,6 +1554,7 @@ mod test {
                 bond_denom: AXL_DENOMINATION.into(),
                 unbonding_period_days: 10,
                 description: "Some service".into(),
+                exit_handover_blocks: 0,
             },
         );
         assert!(res.is_ok());
@@ -1329,6 +1632,7 @@ mod test {
                 bond_denom: AXL_DENOMINATION.into(),
                 unbonding_period_days: 10,
                 description: "Some service".into(),
+                exit_handover_blocks: 0,
             },
         );
         assert!(res.is_ok());
@@ -1458,6 +1762,7 @@ mod test {
                 bond_denom: AXL_DENOMINATION.into(),
                 unbonding_period_days: 10,
                 description: "Some service".into(),
+                exit_handover_blocks: 0,
             },
         );
         assert!(res.is_ok());
@@ -1503,6 +1808,7 @@ mod test {
                 bond_denom: AXL_DENOMINATION.into(),
                 unbonding_period_days: 10,
                 description: "Some service".into(),
+                exit_handover_blocks: 0,
             },
         );
         assert!(res.is_ok());
@@ -1560,6 +1866,7 @@ mod test {
                 bond_denom: AXL_DENOMINATION.into(),
                 unbonding_period_days: 10,
                 description: "Some service".into(),
+                exit_handover_blocks: 0,
             },
         );
         assert!(res.is_ok());
@@ -1645,6 +1952,7 @@ mod test {
                 bond_denom: AXL_DENOMINATION.into(),
                 unbonding_period_days: 10,
                 description: "Some service".into(),
+                exit_handover_blocks: 0,
             },
         );
         assert!(res.is_ok());
@@ -1689,6 +1997,7 @@ mod test {
                 bond_denom: AXL_DENOMINATION.into(),
                 unbonding_period_days: 10,
                 description: "Some service".into(),
+                exit_handover_blocks: 0,
             },
         );
         assert!(res.is_ok());
@@ -1753,6 +2062,7 @@ mod test {
                 bond_denom: AXL_DENOMINATION.into(),
                 unbonding_period_days: 10,
                 description: "Some service".into(),
+                exit_handover_blocks: 0,
             },
         );
         assert!(res.is_ok());
@@ -1828,6 +2138,7 @@ mod test {
                 bond_denom: AXL_DENOMINATION.into(),
                 unbonding_period_days: 10,
                 description: "Some service".into(),
+                exit_handover_blocks: 0,
             },
         );
         assert!(res.is_ok());
@@ -1916,6 +2227,7 @@ mod test {
                 bond_denom: AXL_DENOMINATION.into(),
                 unbonding_period_days: 10,
                 description: "Some service".into(),
+                exit_handover_blocks: 0,
             },
         );
         assert!(res.is_ok());
@@ -2026,6 +2338,7 @@ mod test {
                 bond_denom: AXL_DENOMINATION.into(),
                 unbonding_period_days,
                 description: "Some service".into(),
+                exit_handover_blocks: 0,
             },
         );
         assert!(res.is_ok());
@@ -2077,8 +2390,12 @@ mod test {
                 service_name: service_name.into(),
             },
         );
-        assert!(res.is_ok());
-        assert_eq!(res.unwrap(), Response::new());
+        let res = res.unwrap();
+        assert!(res.messages.is_empty());
+        assert!(res
+            .events
+            .iter()
+            .any(|e| e.ty == "active_verifiers_snapshotted"));
 
         let res = execute(
             deps.as_mut(),
@@ -2126,304 +2443,206 @@ mod test {
     }
 
     #[test]
-    #[allow(clippy::cast_possible_truncation)]
-    fn active_verifiers_should_not_return_less_than_min() {
+    fn partial_unbonds_are_claimable_in_order_of_completion() {
         let mut deps = setup();
         let api = deps.api;
 
-        let verifiers = vec![
-            MockApi::default().addr_make("verifier1"),
-            MockApi::default().addr_make("verifier2"),
-        ];
-        let min_num_verifiers = verifiers.len() as u16;
-
-        let service_name = "validators";
         let min_verifier_bond: nonempty::Uint128 = Uint128::new(100).try_into().unwrap();
-        let _ = execute(
+        let service_name = "validators";
+        let unbonding_period_days = 1;
+
+        execute(
             deps.as_mut(),
             mock_env(),
             message_info(&api.addr_make(GOVERNANCE_ADDRESS), &[]),
             ExecuteMsg::RegisterService {
                 service_name: service_name.into(),
                 coordinator_contract: api.addr_make(COORDINATOR_ADDRESS).to_string(),
-                min_num_verifiers,
+                min_num_verifiers: 0,
                 max_num_verifiers: Some(100),
                 min_verifier_bond,
                 bond_denom: AXL_DENOMINATION.into(),
-                unbonding_period_days: 10,
+                unbonding_period_days,
                 description: "Some service".into(),
+                exit_handover_blocks: 0,
             },
         )
         .unwrap();
 
-        let _ = execute(
+        execute(
             deps.as_mut(),
             mock_env(),
             message_info(&api.addr_make(GOVERNANCE_ADDRESS), &[]),
             ExecuteMsg::AuthorizeVerifiers {
-                verifiers: verifiers.iter().map(|w| w.into()).collect(),
+                verifiers: vec![api.addr_make(VERIFIER_ADDRESS).to_string()],
                 service_name: service_name.into(),
             },
         )
         .unwrap();
 
-        let chain_name = ChainName::from_str("ethereum").unwrap();
-
-        for verifier in &verifiers {
-            // should return err until all verifiers are registered
-            let res = query(
-                deps.as_ref(),
-                mock_env(),
-                QueryMsg::ActiveVerifiers {
-                    service_name: service_name.into(),
-                    chain_name: chain_name.clone(),
-                },
-            );
-            assert!(res.is_err());
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(
+                &api.addr_make(VERIFIER_ADDRESS),
+                &coins(300, AXL_DENOMINATION),
+            ),
+            ExecuteMsg::BondVerifier {
+                service_name: service_name.into(),
+            },
+        )
+        .unwrap();
 
-            let _ = execute(
-                deps.as_mut(),
-                mock_env(),
-                message_info(
-                    verifier,
-                    &coins(min_verifier_bond.into_inner().u128(), AXL_DENOMINATION),
-                ),
-                ExecuteMsg::BondVerifier {
-                    service_name: service_name.into(),
-                },
-            )
-            .unwrap();
+        let first_unbond_env = mock_env();
+        let mut second_unbond_env = mock_env();
+        second_unbond_env.block.time = second_unbond_env.block.time.plus_days(1);
 
-            let _ = execute(
+        for env in [first_unbond_env.clone(), second_unbond_env.clone()] {
+            execute(
                 deps.as_mut(),
-                mock_env(),
-                message_info(verifier, &[]),
-                ExecuteMsg::RegisterChainSupport {
+                env,
+                message_info(&api.addr_make(VERIFIER_ADDRESS), &[]),
+                ExecuteMsg::UnbondPartialStake {
                     service_name: service_name.into(),
-                    chains: vec![chain_name.clone()],
+                    amount: Uint128::new(100).try_into().unwrap(),
                 },
             )
             .unwrap();
         }
 
-        // all verifiers registered, should not return err now
-        let res: StdResult<Vec<WeightedVerifier>> = from_json(
+        // the remaining bond cannot go below the minimum bond
+        let err = execute(
+            deps.as_mut(),
+            second_unbond_env.clone(),
+            message_info(&api.addr_make(VERIFIER_ADDRESS), &[]),
+            ExecuteMsg::UnbondPartialStake {
+                service_name: service_name.into(),
+                amount: Uint128::new(1).try_into().unwrap(),
+            },
+        )
+        .unwrap_err();
+        assert!(err_contains!(
+            err.report,
+            ContractError,
+            ContractError::RemainingBondBelowMinimum
+        ));
+
+        let pending_unbonds: Vec<PendingUnbond> = from_json(
             query(
                 deps.as_ref(),
                 mock_env(),
-                QueryMsg::ActiveVerifiers {
+                QueryMsg::PendingUnbonds {
                     service_name: service_name.into(),
-                    chain_name: chain_name.clone(),
+                    verifier: api.addr_make(VERIFIER_ADDRESS).to_string(),
                 },
             )
             .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            pending_unbonds,
+            vec![
+                PendingUnbond {
+                    amount: Uint128::new(100).try_into().unwrap(),
+                    requested_at: first_unbond_env.block.time,
+                    completes_at: first_unbond_env
+                        .block
+                        .time
+                        .plus_days(unbonding_period_days.into()),
+                },
+                PendingUnbond {
+                    amount: Uint128::new(100).try_into().unwrap(),
+                    requested_at: second_unbond_env.block.time,
+                    completes_at: second_unbond_env
+                        .block
+                        .time
+                        .plus_days(unbonding_period_days.into()),
+                },
+            ]
         );
-        assert!(res.is_ok());
 
-        // remove one, should return err again
-        let _ = execute(
+        // nothing has completed unbonding yet
+        let err = execute(
             deps.as_mut(),
-            mock_env(),
-            message_info(&verifiers[0], &[]),
-            ExecuteMsg::DeregisterChainSupport {
+            first_unbond_env,
+            message_info(&api.addr_make(VERIFIER_ADDRESS), &[]),
+            ExecuteMsg::ClaimStake {
                 service_name: service_name.into(),
-                chains: vec![chain_name.clone()],
             },
         )
-        .unwrap();
-        let res = query(
-            deps.as_ref(),
-            mock_env(),
-            QueryMsg::ActiveVerifiers {
-                service_name: service_name.into(),
-                chain_name: chain_name.clone(),
-            },
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            axelar_wasm_std::error::ContractError::from(ContractError::InvalidBondingState(
+                BondingState::Bonded {
+                    amount: min_verifier_bond
+                }
+            ))
+            .to_string()
         );
-        assert!(res.is_err());
-    }
-
-    #[test]
-    fn jail_verifier() {
-        let mut deps = setup();
-        let api = deps.api;
 
-        // register a service
-        let service_name = "validators";
-        let min_verifier_bond: nonempty::Uint128 = Uint128::new(100).try_into().unwrap();
-        let unbonding_period_days = 10;
+        // only the first partial unbond has completed
         let res = execute(
             deps.as_mut(),
-            mock_env(),
-            message_info(&api.addr_make(GOVERNANCE_ADDRESS), &[]),
-            ExecuteMsg::RegisterService {
-                service_name: service_name.into(),
-                coordinator_contract: api.addr_make(COORDINATOR_ADDRESS).to_string(),
-                min_num_verifiers: 0,
-                max_num_verifiers: Some(100),
-                min_verifier_bond,
-                bond_denom: AXL_DENOMINATION.into(),
-                unbonding_period_days,
-                description: "Some service".into(),
-            },
-        );
-        assert!(res.is_ok());
-
-        // given a bonded verifier
-        let verifier1 = MockApi::default().addr_make("verifier-1");
-        let res = execute(
-            deps.as_mut(),
-            mock_env(),
-            message_info(
-                &verifier1,
-                &coins(min_verifier_bond.into_inner().u128(), AXL_DENOMINATION),
-            ),
-            ExecuteMsg::BondVerifier {
-                service_name: service_name.into(),
-            },
-        );
-        assert!(res.is_ok());
-
-        // when verifier is jailed
-        let res = execute(
-            deps.as_mut(),
-            mock_env(),
-            message_info(&api.addr_make(GOVERNANCE_ADDRESS), &[]),
-            ExecuteMsg::JailVerifiers {
-                verifiers: vec![verifier1.clone().into()],
-                service_name: service_name.into(),
-            },
-        );
-        assert!(res.is_ok());
-
-        // verifier cannot unbond
-        let err = execute(
-            deps.as_mut(),
-            mock_env(),
-            message_info(&verifier1, &[]),
-            ExecuteMsg::UnbondVerifier {
+            second_unbond_env,
+            message_info(&api.addr_make(VERIFIER_ADDRESS), &[]),
+            ExecuteMsg::ClaimStake {
                 service_name: service_name.into(),
             },
         )
-        .unwrap_err();
-        assert!(err_contains!(
-            err.report,
-            ContractError,
-            ContractError::VerifierJailed
-        ));
-
-        // given a verifier passed unbonding period
-        let verifier2 = MockApi::default().addr_make("verifier-2");
-
-        // bond verifier
-        let res = execute(
-            deps.as_mut(),
-            mock_env(),
-            message_info(
-                &verifier2,
-                &coins(min_verifier_bond.into_inner().u128(), AXL_DENOMINATION),
-            ),
-            ExecuteMsg::BondVerifier {
-                service_name: service_name.into(),
-            },
+        .unwrap();
+        assert_eq!(res.messages.len(), 1);
+        assert_eq!(
+            res.messages[0].msg,
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: api.addr_make(VERIFIER_ADDRESS).to_string(),
+                amount: coins(100, AXL_DENOMINATION)
+            })
         );
-        assert!(res.is_ok());
 
-        let mut unbond_request_env = mock_env();
-        unbond_request_env.block.time = unbond_request_env.block.time.plus_days(1);
+        let pending_unbonds: Vec<PendingUnbond> = from_json(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::PendingUnbonds {
+                    service_name: service_name.into(),
+                    verifier: api.addr_make(VERIFIER_ADDRESS).to_string(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(pending_unbonds.len(), 1);
 
-        // unbond verifier
-        let res = execute(
-            deps.as_mut(),
-            unbond_request_env.clone(),
-            message_info(&verifier2, &[]),
-            ExecuteMsg::UnbondVerifier {
-                service_name: service_name.into(),
-            },
-        );
-        assert!(res.is_ok());
-        let verifier2_details: VerifierDetails = from_json(
+        let verifier_details: VerifierDetails = from_json(
             query(
                 deps.as_ref(),
                 mock_env(),
                 QueryMsg::Verifier {
                     service_name: service_name.into(),
-                    verifier: verifier2.to_string(),
+                    verifier: api.addr_make(VERIFIER_ADDRESS).to_string(),
                 },
             )
             .unwrap(),
         )
         .unwrap();
-        let verifier = verifier2_details.verifier;
-
         assert_eq!(
-            verifier.bonding_state,
-            BondingState::Unbonding {
-                amount: min_verifier_bond,
-                unbonded_at: unbond_request_env.block.time,
+            verifier_details.verifier.bonding_state,
+            BondingState::Bonded {
+                amount: min_verifier_bond
             }
         );
-
-        // when verifier is jailed
-        let res = execute(
-            deps.as_mut(),
-            mock_env(),
-            message_info(&api.addr_make(GOVERNANCE_ADDRESS), &[]),
-            ExecuteMsg::JailVerifiers {
-                verifiers: vec![verifier2.clone().into()],
-                service_name: service_name.into(),
-            },
-        );
-        assert!(res.is_ok());
-
-        // and unbonding period has passed
-        let mut after_unbond_period_env = mock_env();
-        after_unbond_period_env.block.time = unbond_request_env
-            .block
-            .time
-            .plus_days((unbonding_period_days + 1).into());
-
-        // verifier cannot claim stake
-        let err = execute(
-            deps.as_mut(),
-            after_unbond_period_env,
-            message_info(&verifier2, &[]),
-            ExecuteMsg::ClaimStake {
-                service_name: service_name.into(),
-            },
-        )
-        .unwrap_err();
-        assert!(err_contains!(
-            err.report,
-            ContractError,
-            ContractError::VerifierJailed
-        ));
     }
 
-    #[test]
-    fn get_single_verifier_details() {
-        let mut deps = setup();
+    fn setup_slashing(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier, Empty>,
+        service_name: &str,
+    ) {
         let api = deps.api;
 
-        let service_name = "validators";
-        let min_verifier_bond: nonempty::Uint128 = Uint128::new(100).try_into().unwrap();
-        let res = execute(
-            deps.as_mut(),
-            mock_env(),
-            message_info(&api.addr_make(GOVERNANCE_ADDRESS), &[]),
-            ExecuteMsg::RegisterService {
-                service_name: service_name.into(),
-                coordinator_contract: api.addr_make(COORDINATOR_ADDRESS).to_string(),
-                min_num_verifiers: 0,
-                max_num_verifiers: Some(100),
-                min_verifier_bond,
-                bond_denom: AXL_DENOMINATION.into(),
-                unbonding_period_days: 10,
-                description: "Some service".into(),
-            },
-        );
-        assert!(res.is_ok());
+        execute_register_service(deps.as_mut(), service_name.into());
 
-        let res = execute(
+        execute(
             deps.as_mut(),
             mock_env(),
             message_info(&api.addr_make(GOVERNANCE_ADDRESS), &[]),
@@ -2431,39 +2650,108 @@ mod test {
                 verifiers: vec![api.addr_make(VERIFIER_ADDRESS).to_string()],
                 service_name: service_name.into(),
             },
-        );
-        assert!(res.is_ok());
+        )
+        .unwrap();
 
-        let res = execute(
+        execute(
             deps.as_mut(),
             mock_env(),
             message_info(
                 &api.addr_make(VERIFIER_ADDRESS),
-                &coins(min_verifier_bond.into_inner().u128(), AXL_DENOMINATION),
+                &coins(300, AXL_DENOMINATION),
             ),
             ExecuteMsg::BondVerifier {
                 service_name: service_name.into(),
             },
-        );
-        assert!(res.is_ok());
+        )
+        .unwrap();
 
-        let chains = vec![
-            ChainName::from_str("ethereum").unwrap(),
-            ChainName::from_str("binance").unwrap(),
-            ChainName::from_str("avalanche").unwrap(),
-        ];
         let res = execute(
             deps.as_mut(),
             mock_env(),
-            message_info(&api.addr_make(VERIFIER_ADDRESS), &[]),
-            ExecuteMsg::RegisterChainSupport {
+            message_info(&api.addr_make(GOVERNANCE_ADDRESS), &[]),
+            ExecuteMsg::RegisterSlasher {
                 service_name: service_name.into(),
-                chains: chains.clone(),
+                slasher: api.addr_make(SLASHER_ADDRESS).to_string(),
+                limits: SlashingLimits {
+                    max_slash_amount: Uint128::new(100).try_into().unwrap(),
+                    max_slash_amount_per_epoch: Uint128::new(150).try_into().unwrap(),
+                    epoch_duration: 100u64.try_into().unwrap(),
+                },
+            },
+        )
+        .unwrap();
+        assert!(res.events.iter().any(|e| e.ty == "slasher_registered"));
+    }
+
+    fn execute_slash_verifier(
+        deps: DepsMut,
+        env: Env,
+        sender: &str,
+        amount: u128,
+        destination: Option<String>,
+    ) -> Result<Response, axelar_wasm_std::error::ContractError> {
+        let api = MockApi::default();
+
+        execute(
+            deps,
+            env,
+            message_info(&api.addr_make(sender), &[]),
+            ExecuteMsg::SlashVerifier {
+                service_name: "validators".into(),
+                verifier: api.addr_make(VERIFIER_ADDRESS).to_string(),
+                amount: Uint128::new(amount).try_into().unwrap(),
+                destination,
+                reason: "double signing".try_into().unwrap(),
             },
+        )
+    }
+
+    #[test]
+    fn slash_verifier_should_burn_or_redirect_bond_within_epoch_limits() {
+        let mut deps = setup();
+        let api = deps.api;
+        let service_name = "validators";
+        setup_slashing(&mut deps, service_name);
+
+        let res =
+            execute_slash_verifier(deps.as_mut(), mock_env(), SLASHER_ADDRESS, 100, None).unwrap();
+        assert_eq!(
+            res.messages[0].msg,
+            CosmosMsg::Bank(BankMsg::Burn {
+                amount: coins(100, AXL_DENOMINATION)
+            })
         );
-        assert!(res.is_ok());
+        assert!(res.events.iter().any(|e| e.ty == "verifier_slashed"));
 
-        let verifier_details: VerifierDetails = from_json(
+        // only 50 more can be slashed in the current epoch
+        let err = execute_slash_verifier(deps.as_mut(), mock_env(), SLASHER_ADDRESS, 60, None)
+            .unwrap_err();
+        assert!(err_contains!(
+            err.report,
+            ContractError,
+            ContractError::SlashExceedsEpochLimit { .. }
+        ));
+
+        let mut next_epoch = mock_env();
+        next_epoch.block.height += 100;
+        let res = execute_slash_verifier(
+            deps.as_mut(),
+            next_epoch.clone(),
+            SLASHER_ADDRESS,
+            100,
+            Some(api.addr_make("treasury").to_string()),
+        )
+        .unwrap();
+        assert_eq!(
+            res.messages[0].msg,
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: api.addr_make("treasury").to_string(),
+                amount: coins(100, AXL_DENOMINATION)
+            })
+        );
+
+        let verifier: VerifierDetails = from_json(
             query(
                 deps.as_ref(),
                 mock_env(),
@@ -2475,23 +2763,1371 @@ mod test {
             .unwrap(),
         )
         .unwrap();
+        assert_eq!(
+            verifier.verifier.bonding_state,
+            BondingState::Bonded {
+                amount: Uint128::new(100).try_into().unwrap()
+            }
+        );
 
+        let history: Vec<SlashRecord> = from_json(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::SlashHistory {
+                    service_name: service_name.into(),
+                    verifier: api.addr_make(VERIFIER_ADDRESS).to_string(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
         assert_eq!(
-            verifier_details.verifier,
-            Verifier {
-                address: MockApi::default().addr_make(VERIFIER_ADDRESS),
-                bonding_state: BondingState::Bonded {
-                    amount: min_verifier_bond
+            history,
+            vec![
+                SlashRecord {
+                    slasher: api.addr_make(SLASHER_ADDRESS),
+                    amount: Uint128::new(100),
+                    destination: None,
+                    reason: "double signing".try_into().unwrap(),
+                    slashed_at: mock_env().block.time,
+                    block_height: mock_env().block.height,
                 },
-                authorization_state: AuthorizationState::Authorized,
-                service_name: service_name.into()
-            }
+                SlashRecord {
+                    slasher: api.addr_make(SLASHER_ADDRESS),
+                    amount: Uint128::new(100),
+                    destination: Some(api.addr_make("treasury")),
+                    reason: "double signing".try_into().unwrap(),
+                    slashed_at: next_epoch.block.time,
+                    block_height: next_epoch.block.height,
+                },
+            ]
         );
-        assert_eq!(verifier_details.weight, VERIFIER_WEIGHT);
+    }
 
-        let expected_chains: HashSet<ChainName> = chains.into_iter().collect();
-        let actual_chains: HashSet<ChainName> =
-            verifier_details.supported_chains.into_iter().collect();
-        assert_eq!(expected_chains, actual_chains);
+    #[test]
+    fn slash_history_should_only_keep_the_most_recent_slashes() {
+        let mut deps = setup();
+        let api = deps.api;
+        let service_name = "validators";
+        setup_slashing(&mut deps, service_name);
+
+        let mut env = mock_env();
+        let first_height = env.block.height;
+        for _ in 0..=MAX_SLASH_HISTORY_LENGTH {
+            execute_slash_verifier(deps.as_mut(), env.clone(), SLASHER_ADDRESS, 1, None).unwrap();
+            env.block.height += 1;
+        }
+
+        let history: Vec<SlashRecord> = from_json(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::SlashHistory {
+                    service_name: service_name.into(),
+                    verifier: api.addr_make(VERIFIER_ADDRESS).to_string(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(history.len(), MAX_SLASH_HISTORY_LENGTH);
+        assert_eq!(history[0].block_height, first_height + 1);
+    }
+
+    #[test]
+    fn slash_verifier_should_respect_slasher_registration_and_limits() {
+        let mut deps = setup();
+        let api = deps.api;
+        let service_name = "validators";
+        setup_slashing(&mut deps, service_name);
+
+        let err = execute_slash_verifier(deps.as_mut(), mock_env(), SLASHER_ADDRESS, 101, None)
+            .unwrap_err();
+        assert!(err_contains!(
+            err.report,
+            ContractError,
+            ContractError::SlashExceedsLimit { .. }
+        ));
+
+        let err = execute_slash_verifier(deps.as_mut(), mock_env(), UNAUTHORIZED_ADDRESS, 1, None)
+            .unwrap_err();
+        assert!(err_contains!(
+            err.report,
+            permission_control::Error,
+            permission_control::Error::WhitelistNotFound { .. }
+        ));
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(SLASHER_ADDRESS), &[]),
+            ExecuteMsg::DeregisterSlasher {
+                service_name: service_name.into(),
+                slasher: api.addr_make(SLASHER_ADDRESS).to_string(),
+            },
+        )
+        .unwrap_err();
+        assert!(err_contains!(
+            err.report,
+            permission_control::Error,
+            permission_control::Error::PermissionDenied { .. }
+        ));
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(GOVERNANCE_ADDRESS), &[]),
+            ExecuteMsg::DeregisterSlasher {
+                service_name: service_name.into(),
+                slasher: api.addr_make(SLASHER_ADDRESS).to_string(),
+            },
+        )
+        .unwrap();
+
+        let err = execute_slash_verifier(deps.as_mut(), mock_env(), SLASHER_ADDRESS, 1, None)
+            .unwrap_err();
+        assert!(err_contains!(
+            err.report,
+            permission_control::Error,
+            permission_control::Error::WhitelistNotFound { .. }
+        ));
+    }
+
+    fn execute_submit_attestations(
+        deps: DepsMut,
+        sender: &str,
+        summaries: Vec<ParticipationSummary>,
+    ) -> Result<Response, axelar_wasm_std::error::ContractError> {
+        let api = MockApi::default();
+
+        let mut env = mock_env();
+        env.block.height = 42;
+
+        execute(
+            deps,
+            env,
+            message_info(&api.addr_make(sender), &[]),
+            ExecuteMsg::SubmitAttestations {
+                service_name: "validators".into(),
+                summaries,
+            },
+        )
+    }
+
+    fn participation_summary(
+        verifier: &str,
+        chain_name: &str,
+        events_participated: u64,
+        events_total: u64,
+    ) -> ParticipationSummary {
+        ParticipationSummary {
+            verifier: MockApi::default().addr_make(verifier).to_string(),
+            chain_name: chain_name.parse().unwrap(),
+            events_participated,
+            events_total,
+        }
+    }
+
+    #[test]
+    fn submit_attestations_should_only_be_allowed_for_registered_attesters() {
+        let mut deps = setup();
+        let api = deps.api;
+        let service_name = "validators";
+        setup_slashing(&mut deps, service_name);
+
+        let summaries = vec![participation_summary(VERIFIER_ADDRESS, "ethereum", 9, 10)];
+
+        let err = execute_submit_attestations(deps.as_mut(), ATTESTER_ADDRESS, summaries.clone())
+            .unwrap_err();
+        assert!(err_contains!(
+            err.report,
+            permission_control::Error,
+            permission_control::Error::WhitelistNotFound { .. }
+        ));
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(ATTESTER_ADDRESS), &[]),
+            ExecuteMsg::RegisterAttester {
+                service_name: service_name.into(),
+                attester: api.addr_make(ATTESTER_ADDRESS).to_string(),
+            },
+        )
+        .unwrap_err();
+        assert!(err_contains!(
+            err.report,
+            permission_control::Error,
+            permission_control::Error::PermissionDenied { .. }
+        ));
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(GOVERNANCE_ADDRESS), &[]),
+            ExecuteMsg::RegisterAttester {
+                service_name: service_name.into(),
+                attester: api.addr_make(ATTESTER_ADDRESS).to_string(),
+            },
+        )
+        .unwrap();
+        assert!(res.events.iter().any(|e| e.ty == "attester_registered"));
+
+        let res = execute_submit_attestations(deps.as_mut(), ATTESTER_ADDRESS, summaries.clone())
+            .unwrap();
+        assert!(res.events.iter().any(|e| e.ty == "attestations_submitted"));
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(GOVERNANCE_ADDRESS), &[]),
+            ExecuteMsg::DeregisterAttester {
+                service_name: service_name.into(),
+                attester: api.addr_make(ATTESTER_ADDRESS).to_string(),
+            },
+        )
+        .unwrap();
+
+        let err =
+            execute_submit_attestations(deps.as_mut(), ATTESTER_ADDRESS, summaries).unwrap_err();
+        assert!(err_contains!(
+            err.report,
+            permission_control::Error,
+            permission_control::Error::WhitelistNotFound { .. }
+        ));
+    }
+
+    #[test]
+    fn submitted_attestations_should_accumulate_per_verifier_and_chain() {
+        let mut deps = setup();
+        let api = deps.api;
+        let service_name = "validators";
+        setup_slashing(&mut deps, service_name);
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(GOVERNANCE_ADDRESS), &[]),
+            ExecuteMsg::RegisterAttester {
+                service_name: service_name.into(),
+                attester: api.addr_make(ATTESTER_ADDRESS).to_string(),
+            },
+        )
+        .unwrap();
+
+        let err = execute_submit_attestations(
+            deps.as_mut(),
+            ATTESTER_ADDRESS,
+            vec![participation_summary(VERIFIER_ADDRESS, "ethereum", 11, 10)],
+        )
+        .unwrap_err();
+        assert!(err_contains!(
+            err.report,
+            ContractError,
+            ContractError::InvalidParticipationSummary { .. }
+        ));
+
+        execute_submit_attestations(
+            deps.as_mut(),
+            ATTESTER_ADDRESS,
+            vec![
+                participation_summary(VERIFIER_ADDRESS, "ethereum", 9, 10),
+                participation_summary(VERIFIER_ADDRESS, "avalanche", 5, 5),
+                // unknown verifiers are ignored
+                participation_summary("unknown", "ethereum", 10, 10),
+            ],
+        )
+        .unwrap();
+        execute_submit_attestations(
+            deps.as_mut(),
+            ATTESTER_ADDRESS,
+            vec![participation_summary(VERIFIER_ADDRESS, "ethereum", 5, 10)],
+        )
+        .unwrap();
+
+        let attestation =
+            |chain_name: &str, events_participated, events_total| PerformanceAttestation {
+                verifier: api.addr_make(VERIFIER_ADDRESS),
+                chain_name: chain_name.parse().unwrap(),
+                events_participated,
+                events_total,
+                attester: api.addr_make(ATTESTER_ADDRESS),
+                updated_at: 42,
+            };
+
+        let attestations: Vec<PerformanceAttestation> = from_json(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::Attestations {
+                    service_name: service_name.into(),
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            attestations,
+            vec![
+                attestation("avalanche", 5, 5),
+                attestation("ethereum", 14, 20)
+            ]
+        );
+
+        let next_page: Vec<PerformanceAttestation> = from_json(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::Attestations {
+                    service_name: service_name.into(),
+                    start_after: Some((
+                        api.addr_make(VERIFIER_ADDRESS).to_string(),
+                        "avalanche".parse().unwrap(),
+                    )),
+                    limit: Some(1),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(next_page, vec![attestation("ethereum", 14, 20)]);
+
+        let verifier: VerifierDetails = from_json(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::Verifier {
+                    service_name: service_name.into(),
+                    verifier: api.addr_make(VERIFIER_ADDRESS).to_string(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(verifier.attestations, attestations);
+
+        // attestations are removed once the verifier claimed its full bond
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(VERIFIER_ADDRESS), &[]),
+            ExecuteMsg::UnbondVerifier {
+                service_name: service_name.into(),
+            },
+        )
+        .unwrap();
+        let mut after_unbond_period_env = mock_env();
+        after_unbond_period_env.block.time = after_unbond_period_env.block.time.plus_days(11);
+        execute(
+            deps.as_mut(),
+            after_unbond_period_env,
+            message_info(&api.addr_make(VERIFIER_ADDRESS), &[]),
+            ExecuteMsg::ClaimStake {
+                service_name: service_name.into(),
+            },
+        )
+        .unwrap();
+
+        let attestations: Vec<PerformanceAttestation> = from_json(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::Attestations {
+                    service_name: service_name.into(),
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert!(attestations.is_empty());
+    }
+
+    #[test]
+    fn active_verifiers_page_should_paginate_without_enforcing_min() {
+        let mut deps = setup();
+        let api = deps.api;
+
+        let service_name = "validators";
+        let min_verifier_bond: nonempty::Uint128 = Uint128::new(100).try_into().unwrap();
+        let _ = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(GOVERNANCE_ADDRESS), &[]),
+            ExecuteMsg::RegisterService {
+                service_name: service_name.into(),
+                coordinator_contract: api.addr_make(COORDINATOR_ADDRESS).to_string(),
+                min_num_verifiers: 10,
+                max_num_verifiers: Some(100),
+                min_verifier_bond,
+                bond_denom: AXL_DENOMINATION.into(),
+                unbonding_period_days: 10,
+                description: "Some service".into(),
+                exit_handover_blocks: 0,
+            },
+        )
+        .unwrap();
+
+        let mut verifiers = vec![
+            api.addr_make("verifier1"),
+            api.addr_make("verifier2"),
+            api.addr_make("verifier3"),
+        ];
+        let unbonded_verifier = api.addr_make("verifier4");
+        let _ = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(GOVERNANCE_ADDRESS), &[]),
+            ExecuteMsg::AuthorizeVerifiers {
+                verifiers: verifiers
+                    .iter()
+                    .chain([&unbonded_verifier])
+                    .map(|verifier| verifier.to_string())
+                    .collect(),
+                service_name: service_name.into(),
+            },
+        )
+        .unwrap();
+
+        let chain_name = ChainName::from_str("ethereum").unwrap();
+        for verifier in &verifiers {
+            let _ = execute(
+                deps.as_mut(),
+                mock_env(),
+                message_info(
+                    verifier,
+                    &coins(min_verifier_bond.into_inner().u128(), AXL_DENOMINATION),
+                ),
+                ExecuteMsg::BondVerifier {
+                    service_name: service_name.into(),
+                },
+            )
+            .unwrap();
+        }
+        for verifier in verifiers.iter().chain([&unbonded_verifier]) {
+            let _ = execute(
+                deps.as_mut(),
+                mock_env(),
+                message_info(verifier, &[]),
+                ExecuteMsg::RegisterChainSupport {
+                    service_name: service_name.into(),
+                    chains: vec![chain_name.clone()],
+                },
+            )
+            .unwrap();
+        }
+
+        // the complete set is below the minimum
+        assert!(query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::ActiveVerifiers {
+                service_name: service_name.into(),
+                chain_name: chain_name.clone(),
+            },
+        )
+        .is_err());
+
+        let query_page = |start_after: Option<String>, limit: u32| {
+            from_json::<ActiveVerifiersPageResponse>(
+                query(
+                    deps.as_ref(),
+                    mock_env(),
+                    QueryMsg::ActiveVerifiersPage {
+                        service_name: service_name.into(),
+                        chain_name: chain_name.clone(),
+                        start_after,
+                        limit: Some(limit),
+                    },
+                )
+                .unwrap(),
+            )
+            .unwrap()
+        };
+
+        // the unbonded verifier is scanned but not returned, so pages are followed until all verifiers are scanned
+        let mut pages = vec![query_page(None, 2)];
+        while let Some(start_after) = pages.last().unwrap().next_start_after.clone() {
+            pages.push(query_page(Some(start_after), 2));
+        }
+        assert_eq!(pages.len(), 3);
+        assert!(pages.iter().all(|page| page.verifiers.len() <= 2));
+
+        verifiers.sort();
+        let active: Vec<Addr> = pages
+            .into_iter()
+            .flat_map(|page| page.verifiers)
+            .map(|verifier| verifier.verifier_info.address)
+            .collect();
+        assert_eq!(active, verifiers);
+
+        // limits beyond the maximum are capped instead of rejected
+        let page = query_page(None, u32::MAX);
+        assert_eq!(page.verifiers.len(), verifiers.len());
+        assert_eq!(page.next_start_after, None);
+    }
+
+    #[test]
+    #[allow(clippy::cast_possible_truncation)]
+    fn active_verifiers_should_not_return_less_than_min() {
+        let mut deps = setup();
+        let api = deps.api;
+
+        let verifiers = vec![
+            MockApi::default().addr_make("verifier1"),
+            MockApi::default().addr_make("verifier2"),
+        ];
+        let min_num_verifiers = verifiers.len() as u16;
+
+        let service_name = "validators";
+        let min_verifier_bond: nonempty::Uint128 = Uint128::new(100).try_into().unwrap();
+        let _ = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(GOVERNANCE_ADDRESS), &[]),
+            ExecuteMsg::RegisterService {
+                service_name: service_name.into(),
+                coordinator_contract: api.addr_make(COORDINATOR_ADDRESS).to_string(),
+                min_num_verifiers,
+                max_num_verifiers: Some(100),
+                min_verifier_bond,
+                bond_denom: AXL_DENOMINATION.into(),
+                unbonding_period_days: 10,
+                description: "Some service".into(),
+                exit_handover_blocks: 0,
+            },
+        )
+        .unwrap();
+
+        let _ = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(GOVERNANCE_ADDRESS), &[]),
+            ExecuteMsg::AuthorizeVerifiers {
+                verifiers: verifiers.iter().map(|w| w.into()).collect(),
+                service_name: service_name.into(),
+            },
+        )
+        .unwrap();
+
+        let chain_name = ChainName::from_str("ethereum").unwrap();
+
+        for verifier in &verifiers {
+            // should return err until all verifiers are registered
+            let res = query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::ActiveVerifiers {
+                    service_name: service_name.into(),
+                    chain_name: chain_name.clone(),
+                },
+            );
+            assert!(res.is_err());
+
+            let _ = execute(
+                deps.as_mut(),
+                mock_env(),
+                message_info(
+                    verifier,
+                    &coins(min_verifier_bond.into_inner().u128(), AXL_DENOMINATION),
+                ),
+                ExecuteMsg::BondVerifier {
+                    service_name: service_name.into(),
+                },
+            )
+            .unwrap();
+
+            let _ = execute(
+                deps.as_mut(),
+                mock_env(),
+                message_info(verifier, &[]),
+                ExecuteMsg::RegisterChainSupport {
+                    service_name: service_name.into(),
+                    chains: vec![chain_name.clone()],
+                },
+            )
+            .unwrap();
+        }
+
+        // all verifiers registered, should not return err now
+        let res: StdResult<Vec<WeightedVerifier>> = from_json(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::ActiveVerifiers {
+                    service_name: service_name.into(),
+                    chain_name: chain_name.clone(),
+                },
+            )
+            .unwrap(),
+        );
+        assert!(res.is_ok());
+
+        // remove one, should return err again
+        let _ = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&verifiers[0], &[]),
+            ExecuteMsg::DeregisterChainSupport {
+                service_name: service_name.into(),
+                chains: vec![chain_name.clone()],
+            },
+        )
+        .unwrap();
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::ActiveVerifiers {
+                service_name: service_name.into(),
+                chain_name: chain_name.clone(),
+            },
+        );
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn jail_verifier() {
+        let mut deps = setup();
+        let api = deps.api;
+
+        // register a service
+        let service_name = "validators";
+        let min_verifier_bond: nonempty::Uint128 = Uint128::new(100).try_into().unwrap();
+        let unbonding_period_days = 10;
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(GOVERNANCE_ADDRESS), &[]),
+            ExecuteMsg::RegisterService {
+                service_name: service_name.into(),
+                coordinator_contract: api.addr_make(COORDINATOR_ADDRESS).to_string(),
+                min_num_verifiers: 0,
+                max_num_verifiers: Some(100),
+                min_verifier_bond,
+                bond_denom: AXL_DENOMINATION.into(),
+                unbonding_period_days,
+                description: "Some service".into(),
+                exit_handover_blocks: 0,
+            },
+        );
+        assert!(res.is_ok());
+
+        // given a bonded verifier
+        let verifier1 = MockApi::default().addr_make("verifier-1");
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(
+                &verifier1,
+                &coins(min_verifier_bond.into_inner().u128(), AXL_DENOMINATION),
+            ),
+            ExecuteMsg::BondVerifier {
+                service_name: service_name.into(),
+            },
+        );
+        assert!(res.is_ok());
+
+        // when verifier is jailed
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(GOVERNANCE_ADDRESS), &[]),
+            ExecuteMsg::JailVerifiers {
+                verifiers: vec![verifier1.clone().into()],
+                service_name: service_name.into(),
+            },
+        );
+        assert!(res.is_ok());
+
+        // verifier cannot unbond
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&verifier1, &[]),
+            ExecuteMsg::UnbondVerifier {
+                service_name: service_name.into(),
+            },
+        )
+        .unwrap_err();
+        assert!(err_contains!(
+            err.report,
+            ContractError,
+            ContractError::VerifierJailed
+        ));
+
+        // given a verifier passed unbonding period
+        let verifier2 = MockApi::default().addr_make("verifier-2");
+
+        // bond verifier
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(
+                &verifier2,
+                &coins(min_verifier_bond.into_inner().u128(), AXL_DENOMINATION),
+            ),
+            ExecuteMsg::BondVerifier {
+                service_name: service_name.into(),
+            },
+        );
+        assert!(res.is_ok());
+
+        let mut unbond_request_env = mock_env();
+        unbond_request_env.block.time = unbond_request_env.block.time.plus_days(1);
+
+        // unbond verifier
+        let res = execute(
+            deps.as_mut(),
+            unbond_request_env.clone(),
+            message_info(&verifier2, &[]),
+            ExecuteMsg::UnbondVerifier {
+                service_name: service_name.into(),
+            },
+        );
+        assert!(res.is_ok());
+        let verifier2_details: VerifierDetails = from_json(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::Verifier {
+                    service_name: service_name.into(),
+                    verifier: verifier2.to_string(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        let verifier = verifier2_details.verifier;
+
+        assert_eq!(
+            verifier.bonding_state,
+            BondingState::Unbonding {
+                amount: min_verifier_bond,
+                unbonded_at: unbond_request_env.block.time,
+            }
+        );
+
+        // when verifier is jailed
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(GOVERNANCE_ADDRESS), &[]),
+            ExecuteMsg::JailVerifiers {
+                verifiers: vec![verifier2.clone().into()],
+                service_name: service_name.into(),
+            },
+        );
+        assert!(res.is_ok());
+
+        // and unbonding period has passed
+        let mut after_unbond_period_env = mock_env();
+        after_unbond_period_env.block.time = unbond_request_env
+            .block
+            .time
+            .plus_days((unbonding_period_days + 1).into());
+
+        // verifier cannot claim stake
+        let err = execute(
+            deps.as_mut(),
+            after_unbond_period_env,
+            message_info(&verifier2, &[]),
+            ExecuteMsg::ClaimStake {
+                service_name: service_name.into(),
+            },
+        )
+        .unwrap_err();
+        assert!(err_contains!(
+            err.report,
+            ContractError,
+            ContractError::VerifierJailed
+        ));
+    }
+
+    #[test]
+    fn get_single_verifier_details() {
+        let mut deps = setup();
+        let api = deps.api;
+
+        let service_name = "validators";
+        let min_verifier_bond: nonempty::Uint128 = Uint128::new(100).try_into().unwrap();
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(GOVERNANCE_ADDRESS), &[]),
+            ExecuteMsg::RegisterService {
+                service_name: service_name.into(),
+                coordinator_contract: api.addr_make(COORDINATOR_ADDRESS).to_string(),
+                min_num_verifiers: 0,
+                max_num_verifiers: Some(100),
+                min_verifier_bond,
+                bond_denom: AXL_DENOMINATION.into(),
+                unbonding_period_days: 10,
+                description: "Some service".into(),
+                exit_handover_blocks: 0,
+            },
+        );
+        assert!(res.is_ok());
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(GOVERNANCE_ADDRESS), &[]),
+            ExecuteMsg::AuthorizeVerifiers {
+                verifiers: vec![api.addr_make(VERIFIER_ADDRESS).to_string()],
+                service_name: service_name.into(),
+            },
+        );
+        assert!(res.is_ok());
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(
+                &api.addr_make(VERIFIER_ADDRESS),
+                &coins(min_verifier_bond.into_inner().u128(), AXL_DENOMINATION),
+            ),
+            ExecuteMsg::BondVerifier {
+                service_name: service_name.into(),
+            },
+        );
+        assert!(res.is_ok());
+
+        let chains = vec![
+            ChainName::from_str("ethereum").unwrap(),
+            ChainName::from_str("binance").unwrap(),
+            ChainName::from_str("avalanche").unwrap(),
+        ];
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(VERIFIER_ADDRESS), &[]),
+            ExecuteMsg::RegisterChainSupport {
+                service_name: service_name.into(),
+                chains: chains.clone(),
+            },
+        );
+        assert!(res.is_ok());
+
+        let verifier_details: VerifierDetails = from_json(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::Verifier {
+                    service_name: service_name.into(),
+                    verifier: api.addr_make(VERIFIER_ADDRESS).to_string(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            verifier_details.verifier,
+            Verifier {
+                address: MockApi::default().addr_make(VERIFIER_ADDRESS),
+                bonding_state: BondingState::Bonded {
+                    amount: min_verifier_bond
+                },
+                authorization_state: AuthorizationState::Authorized,
+                service_name: service_name.into()
+            }
+        );
+        assert_eq!(verifier_details.weight, VERIFIER_WEIGHT);
+
+        let expected_chains: HashSet<ChainName> = chains.into_iter().collect();
+        let actual_chains: HashSet<ChainName> =
+            verifier_details.supported_chains.into_iter().collect();
+        assert_eq!(expected_chains, actual_chains);
+    }
+
+    const EXIT_HANDOVER_BLOCKS: u64 = 10;
+
+    fn setup_exit(deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier, Empty>) -> ChainName {
+        let api = deps.api;
+        let service_name = "validators";
+        let chain_name: ChainName = "ethereum".parse().unwrap();
+
+        execute_register_service(deps.as_mut(), service_name.into());
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(GOVERNANCE_ADDRESS), &[]),
+            ExecuteMsg::UpdateService {
+                service_name: service_name.into(),
+                updated_service_params: UpdatedServiceParams {
+                    min_num_verifiers: None,
+                    max_num_verifiers: None,
+                    min_verifier_bond: None,
+                    unbonding_period_days: None,
+                    exit_handover_blocks: Some(EXIT_HANDOVER_BLOCKS),
+                },
+            },
+        )
+        .unwrap();
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(GOVERNANCE_ADDRESS), &[]),
+            ExecuteMsg::AuthorizeVerifiers {
+                verifiers: vec![api.addr_make(VERIFIER_ADDRESS).to_string()],
+                service_name: service_name.into(),
+            },
+        )
+        .unwrap();
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(
+                &api.addr_make(VERIFIER_ADDRESS),
+                &coins(100, AXL_DENOMINATION),
+            ),
+            ExecuteMsg::BondVerifier {
+                service_name: service_name.into(),
+            },
+        )
+        .unwrap();
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(VERIFIER_ADDRESS), &[]),
+            ExecuteMsg::RegisterChainSupport {
+                service_name: service_name.into(),
+                chains: vec![chain_name.clone()],
+            },
+        )
+        .unwrap();
+
+        chain_name
+    }
+
+    fn execute_process_exit_queue(deps: DepsMut, env: Env) -> Response {
+        execute(
+            deps,
+            env,
+            message_info(&MockApi::default().addr_make(UNAUTHORIZED_ADDRESS), &[]),
+            ExecuteMsg::ProcessExitQueue {
+                service_name: "validators".into(),
+                limit: None,
+            },
+        )
+        .unwrap()
+    }
+
+    fn query_exit_queue(deps: Deps) -> Vec<ExitRequest> {
+        from_json(
+            query(
+                deps,
+                mock_env(),
+                QueryMsg::ExitQueue {
+                    service_name: "validators".into(),
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap()
+    }
+
+    fn query_verifier(deps: Deps) -> Verifier {
+        from_json::<VerifierDetails>(
+            query(
+                deps,
+                mock_env(),
+                QueryMsg::Verifier {
+                    service_name: "validators".into(),
+                    verifier: MockApi::default().addr_make(VERIFIER_ADDRESS).to_string(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap()
+        .verifier
+    }
+
+    #[test]
+    fn exiting_verifier_should_be_excluded_from_active_verifiers_and_unbond_after_handover() {
+        let mut deps = setup();
+        let api = deps.api;
+        let chain_name = setup_exit(&mut deps);
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(VERIFIER_ADDRESS), &[]),
+            ExecuteMsg::RequestExit {
+                service_name: "validators".into(),
+            },
+        )
+        .unwrap();
+        assert!(res.events.iter().any(|e| e.ty == "verifier_exit_requested"));
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(VERIFIER_ADDRESS), &[]),
+            ExecuteMsg::RequestExit {
+                service_name: "validators".into(),
+            },
+        )
+        .unwrap_err();
+        assert!(err_contains!(
+            err.report,
+            ContractError,
+            ContractError::ExitAlreadyRequested
+        ));
+
+        let active_verifiers: Vec<WeightedVerifier> = from_json(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::ActiveVerifiers {
+                    service_name: "validators".into(),
+                    chain_name,
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(active_verifiers, vec![]);
+
+        let requested_at = mock_env().block.height;
+        assert_eq!(
+            query_exit_queue(deps.as_ref()),
+            vec![ExitRequest {
+                verifier: api.addr_make(VERIFIER_ADDRESS),
+                requested_at,
+                duties_end_at: requested_at + EXIT_HANDOVER_BLOCKS,
+            }]
+        );
+
+        // the verifier stays bonded until the handover period has passed
+        let mut env = mock_env();
+        env.block.height = requested_at + EXIT_HANDOVER_BLOCKS - 1;
+        let res = execute_process_exit_queue(deps.as_mut(), env.clone());
+        assert!(res.events.is_empty());
+        assert_eq!(query_exit_queue(deps.as_ref()).len(), 1);
+        assert!(matches!(
+            query_verifier(deps.as_ref()).bonding_state,
+            BondingState::Bonded { .. }
+        ));
+
+        env.block.height = requested_at + EXIT_HANDOVER_BLOCKS;
+        let res = execute_process_exit_queue(deps.as_mut(), env.clone());
+        assert!(res.events.iter().any(|e| e.ty == "verifier_exit_completed"));
+        assert_eq!(query_exit_queue(deps.as_ref()), vec![]);
+        assert_eq!(
+            query_verifier(deps.as_ref()).bonding_state,
+            BondingState::Unbonding {
+                amount: Uint128::new(100).try_into().unwrap(),
+                unbonded_at: env.block.time,
+            }
+        );
+    }
+
+    #[test]
+    fn exit_should_be_delayed_while_verifier_is_not_ready_to_unbond() {
+        let mut deps = setup();
+        let api = deps.api;
+        setup_exit(&mut deps);
+
+        let coordinator_address = api.addr_make(COORDINATOR_ADDRESS);
+        deps.querier.update_wasm(move |wq| match wq {
+            WasmQuery::Smart { contract_addr, .. }
+                if contract_addr == coordinator_address.as_str() =>
+            {
+                Ok(to_json_binary(&false).into()).into()
+            }
+            _ => panic!("no mock for this query"),
+        });
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(VERIFIER_ADDRESS), &[]),
+            ExecuteMsg::RequestExit {
+                service_name: "validators".into(),
+            },
+        )
+        .unwrap();
+
+        let mut env = mock_env();
+        env.block.height += EXIT_HANDOVER_BLOCKS;
+        let res = execute_process_exit_queue(deps.as_mut(), env);
+        assert!(res.events.iter().any(|e| e.ty == "verifier_exit_delayed"));
+        assert_eq!(query_exit_queue(deps.as_ref()).len(), 1);
+        assert!(matches!(
+            query_verifier(deps.as_ref()).bonding_state,
+            BondingState::Bonded { .. }
+        ));
+    }
+
+    #[test]
+    fn unbonding_during_handover_should_go_through_exit_queue() {
+        let mut deps = setup();
+        let api = deps.api;
+        setup_exit(&mut deps);
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(VERIFIER_ADDRESS), &[]),
+            ExecuteMsg::UnbondVerifier {
+                service_name: "validators".into(),
+            },
+        )
+        .unwrap();
+        assert!(res.events.iter().any(|e| e.ty == "verifier_exit_requested"));
+
+        let requested_at = mock_env().block.height;
+        assert_eq!(
+            query_exit_queue(deps.as_ref()),
+            vec![ExitRequest {
+                verifier: api.addr_make(VERIFIER_ADDRESS),
+                requested_at,
+                duties_end_at: requested_at + EXIT_HANDOVER_BLOCKS,
+            }]
+        );
+        assert!(matches!(
+            query_verifier(deps.as_ref()).bonding_state,
+            BondingState::RequestedUnbonding { .. }
+        ));
+
+        let mut env = mock_env();
+        env.block.height = requested_at + EXIT_HANDOVER_BLOCKS;
+        let res = execute_process_exit_queue(deps.as_mut(), env.clone());
+        assert!(res.events.iter().any(|e| e.ty == "verifier_exit_completed"));
+        assert_eq!(query_exit_queue(deps.as_ref()), vec![]);
+        assert_eq!(
+            query_verifier(deps.as_ref()).bonding_state,
+            BondingState::Unbonding {
+                amount: Uint128::new(100).try_into().unwrap(),
+                unbonded_at: env.block.time,
+            }
+        );
+    }
+
+    #[test]
+    fn exit_of_jailed_verifier_should_be_delayed() {
+        let mut deps = setup();
+        let api = deps.api;
+        setup_exit(&mut deps);
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(VERIFIER_ADDRESS), &[]),
+            ExecuteMsg::RequestExit {
+                service_name: "validators".into(),
+            },
+        )
+        .unwrap();
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(GOVERNANCE_ADDRESS), &[]),
+            ExecuteMsg::JailVerifiers {
+                verifiers: vec![api.addr_make(VERIFIER_ADDRESS).to_string()],
+                service_name: "validators".into(),
+            },
+        )
+        .unwrap();
+
+        let mut env = mock_env();
+        env.block.height += EXIT_HANDOVER_BLOCKS;
+        let res = execute_process_exit_queue(deps.as_mut(), env);
+        let delayed = res
+            .events
+            .iter()
+            .find(|e| e.ty == "verifier_exit_delayed")
+            .unwrap();
+        assert!(delayed
+            .attributes
+            .iter()
+            .any(|attr| attr.key == "reason" && attr.value == "\"jailed\""));
+        assert_eq!(query_exit_queue(deps.as_ref()).len(), 1);
+    }
+
+    #[test]
+    fn jailed_verifiers_at_the_head_of_the_exit_queue_should_not_block_later_exits() {
+        let mut deps = setup();
+        let api = deps.api;
+        setup_exit(&mut deps);
+
+        let mut verifiers: Vec<_> = ["verifier0", "verifier1", "verifier2"]
+            .into_iter()
+            .map(|verifier| api.addr_make(verifier))
+            .collect();
+        verifiers.sort();
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(GOVERNANCE_ADDRESS), &[]),
+            ExecuteMsg::AuthorizeVerifiers {
+                verifiers: verifiers.iter().map(Addr::to_string).collect(),
+                service_name: "validators".into(),
+            },
+        )
+        .unwrap();
+
+        for verifier in &verifiers {
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                message_info(verifier, &coins(100, AXL_DENOMINATION)),
+                ExecuteMsg::BondVerifier {
+                    service_name: "validators".into(),
+                },
+            )
+            .unwrap();
+
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                message_info(verifier, &[]),
+                ExecuteMsg::RequestExit {
+                    service_name: "validators".into(),
+                },
+            )
+            .unwrap();
+        }
+
+        // the queue is ordered by address, so the first two verifiers are at its head
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(GOVERNANCE_ADDRESS), &[]),
+            ExecuteMsg::JailVerifiers {
+                verifiers: verifiers[..2].iter().map(Addr::to_string).collect(),
+                service_name: "validators".into(),
+            },
+        )
+        .unwrap();
+
+        let mut env = mock_env();
+        env.block.height += EXIT_HANDOVER_BLOCKS;
+        let res = execute(
+            deps.as_mut(),
+            env,
+            message_info(&api.addr_make(UNAUTHORIZED_ADDRESS), &[]),
+            ExecuteMsg::ProcessExitQueue {
+                service_name: "validators".into(),
+                limit: Some(1),
+            },
+        )
+        .unwrap();
+
+        let verifier_of = |ty: &str| -> Vec<String> {
+            res.events
+                .iter()
+                .filter(|e| e.ty == ty)
+                .flat_map(|e| e.attributes.iter().filter(|attr| attr.key == "verifier"))
+                .map(|attr| attr.value.clone())
+                .collect()
+        };
+        assert_eq!(verifier_of("verifier_exit_delayed").len(), 2);
+        assert_eq!(
+            verifier_of("verifier_exit_completed"),
+            vec![format!("\"{}\"", verifiers[2])]
+        );
+        assert_eq!(
+            query_exit_queue(deps.as_ref())
+                .into_iter()
+                .map(|request| request.verifier)
+                .collect::<Vec<_>>(),
+            verifiers[..2].to_vec()
+        );
+    }
+
+    #[test]
+    fn active_verifiers_should_be_snapshotted_whenever_they_change() {
+        let mut deps = setup();
+        let api = deps.api;
+        let chain_name = setup_exit(&mut deps);
+
+        let active_verifiers_at = |deps: Deps, height: u64| -> Option<VerifierSetSnapshot> {
+            from_json(
+                query(
+                    deps,
+                    mock_env(),
+                    QueryMsg::ActiveVerifiersAt {
+                        service_name: "validators".into(),
+                        chain_name: chain_name.clone(),
+                        height,
+                    },
+                )
+                .unwrap(),
+            )
+            .unwrap()
+        };
+
+        // registering chain support during setup made the verifier active
+        let registered_at = mock_env().block.height;
+        let verifier = WeightedVerifier {
+            verifier_info: query_verifier(deps.as_ref()),
+            weight: VERIFIER_WEIGHT,
+        };
+        assert_eq!(active_verifiers_at(deps.as_ref(), registered_at - 1), None);
+        assert_eq!(
+            active_verifiers_at(deps.as_ref(), registered_at + 5),
+            Some(VerifierSetSnapshot {
+                height: registered_at,
+                verifiers: vec![verifier],
+            })
+        );
+
+        let mut env = mock_env();
+        env.block.height = registered_at + 10;
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&api.addr_make(VERIFIER_ADDRESS), &[]),
+            ExecuteMsg::RequestExit {
+                service_name: "validators".into(),
+            },
+        )
+        .unwrap();
+        assert!(res
+            .events
+            .iter()
+            .any(|e| e.ty == "active_verifiers_snapshotted"));
+        assert_eq!(
+            active_verifiers_at(deps.as_ref(), registered_at + 10),
+            Some(VerifierSetSnapshot {
+                height: registered_at + 10,
+                verifiers: vec![],
+            })
+        );
+
+        // an unchanged set is not recorded again
+        env.block.height = registered_at + 20;
+        let res = execute(
+            deps.as_mut(),
+            env,
+            message_info(&api.addr_make(GOVERNANCE_ADDRESS), &[]),
+            ExecuteMsg::JailVerifiers {
+                verifiers: vec![api.addr_make(VERIFIER_ADDRESS).to_string()],
+                service_name: "validators".into(),
+            },
+        )
+        .unwrap();
+        assert!(res.events.is_empty());
+        assert_eq!(
+            active_verifiers_at(deps.as_ref(), registered_at + 20),
+            Some(VerifierSetSnapshot {
+                height: registered_at + 10,
+                verifiers: vec![],
+            })
+        );
     }
 }
@@ -1,10 +1,20 @@
+use std::collections::BTreeSet;
+
 use axelar_wasm_std::nonempty;
+use cosmwasm_std::{Order, Uint128};
 use error_stack::Result;
 use router_api::ChainName;
-use service_registry_api::{self, AuthorizationState, Verifier};
-use state::VERIFIERS;
+use service_registry_api::{
+    self, AuthorizationState, ExitRequest, ParticipationSummary, PendingUnbond,
+    PerformanceAttestation, SlashRecord, SlashingLimits, Verifier,
+};
+use state::{
+    ATTESTATIONS, ATTESTERS, EPOCH_SLASHES, EXIT_QUEUE, SLASHERS, SLASH_HISTORY, UNBONDING_QUEUE,
+    VERIFIERS, VERIFIERS_PER_CHAIN,
+};
 
 use super::*;
+use crate::events::{Event, ExitDelayReason};
 use crate::msg::UpdatedServiceParams;
 use crate::state::{self};
 
@@ -19,6 +29,7 @@ pub fn register_service(
     bond_denom: String,
     unbonding_period_days: u16,
     description: String,
+    exit_handover_blocks: u64,
 ) -> Result<Response, ContractError> {
     let key = &service_name.clone();
 
@@ -36,6 +47,7 @@ pub fn register_service(
                     bond_denom,
                     unbonding_period_days,
                     description,
+                    exit_handover_blocks,
                 }),
                 _ => Err(ContractError::ServiceAlreadyExists),
             }
@@ -47,7 +59,8 @@ pub fn register_service(
 }
 
 pub fn update_verifier_authorization_status(
-    deps: DepsMut,
+    mut deps: DepsMut,
+    env: Env,
     verifiers: Vec<Addr>,
     service_name: String,
     auth_state: AuthorizationState,
@@ -57,7 +70,10 @@ pub fn update_verifier_authorization_status(
         .change_context(ContractError::StorageError)?
         .ok_or(ContractError::ServiceNotFound)?;
 
+    let mut chains = vec![];
     for verifier in verifiers {
+        chains.extend(supported_chains(deps.storage, &service_name, &verifier)?);
+
         VERIFIERS.update(
             deps.storage,
             (&service_name, &verifier.clone()),
@@ -78,14 +94,19 @@ pub fn update_verifier_authorization_status(
         )?;
     }
 
-    Ok(Response::new())
+    let events = snapshot_active_verifiers(deps.branch(), &env, &service_name, chains)?;
+
+    Ok(Response::new().add_events(events))
 }
 
 pub fn update_service(
-    deps: DepsMut,
+    mut deps: DepsMut,
+    env: Env,
     service_name: String,
     updated_service_params: UpdatedServiceParams,
 ) -> Result<Response, ContractError> {
+    let min_verifier_bond_updated = updated_service_params.min_verifier_bond.is_some();
+
     SERVICES.update(deps.storage, &service_name, |service| match service {
         None => Err(ContractError::ServiceNotFound),
         Some(service) => Ok(Service {
@@ -101,14 +122,32 @@ pub fn update_service(
             unbonding_period_days: updated_service_params
                 .unbonding_period_days
                 .unwrap_or(service.unbonding_period_days),
+            exit_handover_blocks: updated_service_params
+                .exit_handover_blocks
+                .unwrap_or(service.exit_handover_blocks),
             ..service
         }),
     })?;
-    Ok(Response::new())
+
+    if !min_verifier_bond_updated {
+        return Ok(Response::new());
+    }
+
+    // the minimum bond decides which verifiers are active on every chain of the service
+    let chains: Vec<ChainName> = VERIFIERS_PER_CHAIN
+        .sub_prefix(service_name.clone())
+        .keys(deps.storage, None, None, Order::Ascending)
+        .map(|key| key.map(|(chain, _)| chain))
+        .collect::<std::result::Result<_, _>>()
+        .change_context(ContractError::StorageError)?;
+    let events = snapshot_active_verifiers(deps.branch(), &env, &service_name, chains)?;
+
+    Ok(Response::new().add_events(events))
 }
 
 pub fn bond_verifier(
-    deps: DepsMut,
+    mut deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     service_name: String,
 ) -> Result<Response, ContractError> {
@@ -138,22 +177,26 @@ pub fn bond_verifier(
             match sw {
                 Some(verifier) => Ok(state::bond_verifier(verifier, bond)?),
                 None => Ok(Verifier {
-                    address: info.sender,
+                    address: info.sender.clone(),
                     bonding_state: BondingState::Bonded {
                         amount: bond.ok_or(ContractError::NoFundsToBond)?,
                     },
                     authorization_state: AuthorizationState::NotAuthorized,
-                    service_name,
+                    service_name: service_name.clone(),
                 }),
             }
         },
     )?;
 
-    Ok(Response::new())
+    let chains = supported_chains(deps.storage, &service_name, &info.sender)?;
+    let events = snapshot_active_verifiers(deps.branch(), &env, &service_name, chains)?;
+
+    Ok(Response::new().add_events(events))
 }
 
 pub fn register_chains_support(
-    deps: DepsMut,
+    mut deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     service_name: String,
     chains: Vec<ChainName>,
@@ -170,11 +213,14 @@ pub fn register_chains_support(
         info.sender.clone(),
     )?;
 
-    Ok(Response::new())
+    let events = snapshot_active_verifiers(deps.branch(), &env, &service_name, chains)?;
+
+    Ok(Response::new().add_events(events))
 }
 
 pub fn deregister_chains_support(
-    deps: DepsMut,
+    mut deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     service_name: String,
     chains: Vec<ChainName>,
@@ -184,13 +230,20 @@ pub fn deregister_chains_support(
         .change_context(ContractError::StorageError)?
         .ok_or(ContractError::ServiceNotFound)?;
 
-    state::deregister_chains_support(deps.storage, service_name.clone(), chains, info.sender)?;
+    state::deregister_chains_support(
+        deps.storage,
+        service_name.clone(),
+        chains.clone(),
+        info.sender,
+    )?;
 
-    Ok(Response::new())
+    let events = snapshot_active_verifiers(deps.branch(), &env, &service_name, chains)?;
+
+    Ok(Response::new().add_events(events))
 }
 
 pub fn unbond_verifier(
-    deps: DepsMut,
+    mut deps: DepsMut,
     env: Env,
     info: MessageInfo,
     service_name: String,
@@ -205,12 +258,25 @@ pub fn unbond_verifier(
         .change_context(ContractError::StorageError)?
         .ok_or(ContractError::VerifierNotFound)?;
 
+    let exit_request = EXIT_QUEUE
+        .may_load(deps.storage, (&service_name, &info.sender))
+        .change_context(ContractError::StorageError)?;
+    let duties_end_at = exit_request.as_ref().map_or_else(
+        || {
+            env.block
+                .height
+                .saturating_add(service.exit_handover_blocks)
+        },
+        |request| request.duties_end_at,
+    );
+
     let coordinator: coordinator::Client =
         client::ContractClient::new(deps.querier, &service.coordinator_contract).into();
 
-    let ready_to_unbond = coordinator
-        .ready_to_unbond(verifier.address.to_string())
-        .change_context(ContractError::FailedToUnbondVerifier)?;
+    let ready_to_unbond = duties_end_at <= env.block.height
+        && coordinator
+            .ready_to_unbond(verifier.address.to_string())
+            .change_context(ContractError::FailedToUnbondVerifier)?;
 
     let verifier = state::unbond_verifier(verifier, ready_to_unbond, env.block.time)?;
 
@@ -218,6 +284,78 @@ pub fn unbond_verifier(
         .save(deps.storage, (&service_name, &info.sender), &verifier)
         .change_context(ContractError::StorageError)?;
 
+    let mut response = Response::new();
+
+    if ready_to_unbond {
+        EXIT_QUEUE.remove(deps.storage, (&service_name, &info.sender));
+    } else if exit_request.is_none() {
+        // verifiers that can't unbond yet go through the exit queue, so they finish their duties before ProcessExitQueue unbonds them
+        EXIT_QUEUE
+            .save(
+                deps.storage,
+                (&service_name, &info.sender),
+                &ExitRequest {
+                    verifier: info.sender.clone(),
+                    requested_at: env.block.height,
+                    duties_end_at,
+                },
+            )
+            .change_context(ContractError::StorageError)?;
+
+        response = response.add_event(Event::VerifierExitRequested {
+            service_name: service_name.clone(),
+            verifier: info.sender.clone(),
+            duties_end_at,
+        });
+    }
+
+    let chains = supported_chains(deps.storage, &service_name, &info.sender)?;
+    let events = snapshot_active_verifiers(deps.branch(), &env, &service_name, chains)?;
+
+    Ok(response.add_events(events))
+}
+
+pub fn unbond_partial_stake(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    service_name: String,
+    amount: nonempty::Uint128,
+) -> Result<Response, ContractError> {
+    let service = SERVICES
+        .may_load(deps.storage, &service_name)
+        .change_context(ContractError::StorageError)?
+        .ok_or(ContractError::ServiceNotFound)?;
+
+    let verifier = VERIFIERS
+        .may_load(deps.storage, (&service_name, &info.sender))
+        .change_context(ContractError::StorageError)?
+        .ok_or(ContractError::VerifierNotFound)?;
+
+    let verifier = state::unbond_partial_stake(verifier, amount, service.min_verifier_bond)?;
+
+    VERIFIERS
+        .save(deps.storage, (&service_name, &info.sender), &verifier)
+        .change_context(ContractError::StorageError)?;
+
+    let mut queue = UNBONDING_QUEUE
+        .may_load(deps.storage, (&service_name, &info.sender))
+        .change_context(ContractError::StorageError)?
+        .unwrap_or_default();
+
+    queue.push(PendingUnbond {
+        amount,
+        requested_at: env.block.time,
+        completes_at: env
+            .block
+            .time
+            .plus_days(service.unbonding_period_days as u64),
+    });
+
+    UNBONDING_QUEUE
+        .save(deps.storage, (&service_name, &info.sender), &queue)
+        .change_context(ContractError::StorageError)?;
+
     Ok(Response::new())
 }
 
@@ -237,22 +375,574 @@ pub fn claim_stake(
         .change_context(ContractError::StorageError)?
         .ok_or(ContractError::VerifierNotFound)?;
 
-    let (verifier, released_bond) = state::claim_verifier_stake(
-        verifier,
+    let queue = UNBONDING_QUEUE
+        .may_load(deps.storage, (&service_name, &info.sender))
+        .change_context(ContractError::StorageError)?
+        .unwrap_or_default();
+
+    let (queue, released_partial_bond) = state::release_completed_unbonds(queue, env.block.time)?;
+
+    let (verifier, released_bond) = match state::claim_verifier_stake(
+        verifier.clone(),
         env.block.time,
         service.unbonding_period_days as u64,
-    )?;
+    ) {
+        Ok((verifier, released_bond)) => (verifier, released_bond.into_inner()),
+        // nothing to claim from the full unbond, but matured partial unbonds can still be released
+        Err(ContractError::InvalidBondingState(_)) if !released_partial_bond.is_zero() => {
+            (verifier, Uint128::zero())
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    let released_bond = released_bond
+        .checked_add(released_partial_bond)
+        .map_err(ContractError::Overflow)?;
+
+    // a verifier that claimed its full bond left the service, so its attestations are no longer needed
+    if verifier.bonding_state == BondingState::Unbonded {
+        state::remove_attestations(deps.storage, &service_name, &info.sender)?;
+    }
 
     VERIFIERS
         .save(deps.storage, (&service_name, &info.sender), &verifier)
         .change_context(ContractError::StorageError)?;
 
+    if queue.is_empty() {
+        UNBONDING_QUEUE.remove(deps.storage, (&service_name, &info.sender));
+    } else {
+        UNBONDING_QUEUE
+            .save(deps.storage, (&service_name, &info.sender), &queue)
+            .change_context(ContractError::StorageError)?;
+    }
+
     Ok(Response::new().add_message(BankMsg::Send {
         to_address: info.sender.into(),
         amount: [Coin {
             denom: service.bond_denom,
-            amount: released_bond.into(),
+            amount: released_bond,
         }]
         .to_vec(),
     }))
 }
+
+pub fn request_exit(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    service_name: String,
+) -> Result<Response, ContractError> {
+    let service = SERVICES
+        .may_load(deps.storage, &service_name)
+        .change_context(ContractError::StorageError)?
+        .ok_or(ContractError::ServiceNotFound)?;
+
+    let verifier = VERIFIERS
+        .may_load(deps.storage, (&service_name, &info.sender))
+        .change_context(ContractError::StorageError)?
+        .ok_or(ContractError::VerifierNotFound)?;
+
+    if verifier.authorization_state == AuthorizationState::Jailed {
+        return Err(ContractError::VerifierJailed.into());
+    }
+
+    if !matches!(verifier.bonding_state, BondingState::Bonded { .. }) {
+        return Err(ContractError::InvalidBondingState(verifier.bonding_state).into());
+    }
+
+    if EXIT_QUEUE.has(deps.storage, (&service_name, &info.sender)) {
+        return Err(ContractError::ExitAlreadyRequested.into());
+    }
+
+    let duties_end_at = env
+        .block
+        .height
+        .saturating_add(service.exit_handover_blocks);
+
+    EXIT_QUEUE
+        .save(
+            deps.storage,
+            (&service_name, &info.sender),
+            &ExitRequest {
+                verifier: info.sender.clone(),
+                requested_at: env.block.height,
+                duties_end_at,
+            },
+        )
+        .change_context(ContractError::StorageError)?;
+
+    let chains = supported_chains(deps.storage, &service_name, &info.sender)?;
+    let events = snapshot_active_verifiers(deps.branch(), &env, &service_name, chains)?;
+
+    Ok(Response::new()
+        .add_event(Event::VerifierExitRequested {
+            service_name,
+            verifier: info.sender,
+            duties_end_at,
+        })
+        .add_events(events))
+}
+
+pub fn process_exit_queue(
+    deps: DepsMut,
+    env: Env,
+    service_name: String,
+    limit: Option<u32>,
+) -> Result<Response, ContractError> {
+    let service = SERVICES
+        .may_load(deps.storage, &service_name)
+        .change_context(ContractError::StorageError)?
+        .ok_or(ContractError::ServiceNotFound)?;
+
+    let due: Vec<_> = EXIT_QUEUE
+        .prefix(&service_name)
+        .range(deps.storage, None, None, Order::Ascending)
+        .filter(|entry| {
+            entry.as_ref().map_or(true, |(_, request)| {
+                request.duties_end_at <= env.block.height
+            })
+        })
+        .collect::<std::result::Result<_, _>>()
+        .change_context(ContractError::StorageError)?;
+
+    let coordinator: coordinator::Client =
+        client::ContractClient::new(deps.querier, &service.coordinator_contract).into();
+
+    // only completed exits count towards the limit, so delayed verifiers at the head of the queue can't block the ones behind them
+    let limit = limit.map_or(usize::MAX, |limit| limit as usize);
+    let mut completed = 0usize;
+    let mut response = Response::new();
+
+    for (verifier_addr, _) in due {
+        if completed >= limit {
+            break;
+        }
+
+        let Some(verifier) = VERIFIERS
+            .may_load(deps.storage, (&service_name, &verifier_addr))
+            .change_context(ContractError::StorageError)?
+        else {
+            EXIT_QUEUE.remove(deps.storage, (&service_name, &verifier_addr));
+            continue;
+        };
+
+        // jailed verifiers can't unbond, so they stay in the queue until they are unjailed
+        if verifier.authorization_state == AuthorizationState::Jailed {
+            response = response.add_event(Event::VerifierExitDelayed {
+                service_name: service_name.clone(),
+                verifier: verifier_addr,
+                reason: ExitDelayReason::Jailed,
+            });
+            continue;
+        }
+
+        // verifiers that still have open polls or signing sessions, or are part of an active verifier set, have to stay
+        let ready_to_unbond = coordinator
+            .ready_to_unbond(verifier_addr.to_string())
+            .change_context(ContractError::FailedToUnbondVerifier)?;
+        if !ready_to_unbond {
+            response = response.add_event(Event::VerifierExitDelayed {
+                service_name: service_name.clone(),
+                verifier: verifier_addr,
+                reason: ExitDelayReason::NotReadyToUnbond,
+            });
+            continue;
+        }
+
+        let verifier = match state::unbond_verifier(verifier.clone(), true, env.block.time) {
+            Ok(verifier) => verifier,
+            // the verifier already started unbonding by itself
+            Err(ContractError::InvalidBondingState(_)) => verifier,
+            Err(err) => return Err(err.into()),
+        };
+
+        VERIFIERS
+            .save(deps.storage, (&service_name, &verifier_addr), &verifier)
+            .change_context(ContractError::StorageError)?;
+        EXIT_QUEUE.remove(deps.storage, (&service_name, &verifier_addr));
+        completed = completed.saturating_add(1);
+
+        response = response.add_event(Event::VerifierExitCompleted {
+            service_name: service_name.clone(),
+            verifier: verifier_addr,
+            bonding_state: verifier.bonding_state,
+        });
+    }
+
+    Ok(response)
+}
+
+pub fn register_slasher(
+    deps: DepsMut,
+    service_name: String,
+    slasher: Addr,
+    limits: SlashingLimits,
+) -> Result<Response, ContractError> {
+    SERVICES
+        .may_load(deps.storage, &service_name)
+        .change_context(ContractError::StorageError)?
+        .ok_or(ContractError::ServiceNotFound)?;
+
+    SLASHERS
+        .save(deps.storage, (&service_name, &slasher), &limits)
+        .change_context(ContractError::StorageError)?;
+
+    Ok(Response::new().add_event(Event::SlasherRegistered {
+        service_name,
+        slasher,
+        limits,
+    }))
+}
+
+pub fn deregister_slasher(
+    deps: DepsMut,
+    service_name: String,
+    slasher: Addr,
+) -> Result<Response, ContractError> {
+    SLASHERS
+        .may_load(deps.storage, (&service_name, &slasher))
+        .change_context(ContractError::StorageError)?
+        .ok_or(ContractError::SlasherNotFound)?;
+
+    SLASHERS.remove(deps.storage, (&service_name, &slasher));
+    EPOCH_SLASHES.remove(deps.storage, (&service_name, &slasher));
+
+    Ok(Response::new().add_event(Event::SlasherDeregistered {
+        service_name,
+        slasher,
+    }))
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn slash_verifier(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    service_name: String,
+    verifier: Addr,
+    amount: nonempty::Uint128,
+    destination: Option<Addr>,
+    reason: nonempty::String,
+) -> Result<Response, ContractError> {
+    let service = SERVICES
+        .may_load(deps.storage, &service_name)
+        .change_context(ContractError::StorageError)?
+        .ok_or(ContractError::ServiceNotFound)?;
+
+    let limits = SLASHERS
+        .may_load(deps.storage, (&service_name, &info.sender))
+        .change_context(ContractError::StorageError)?
+        .ok_or(ContractError::SlasherNotFound)?;
+
+    if amount > limits.max_slash_amount {
+        return Err(ContractError::SlashExceedsLimit {
+            amount: amount.into(),
+            max: limits.max_slash_amount.into(),
+        }
+        .into());
+    }
+
+    let epoch = env
+        .block
+        .height
+        .checked_div(limits.epoch_duration.into())
+        .expect("epoch duration should not be zero");
+    let epoch_slashes = EPOCH_SLASHES
+        .may_load(deps.storage, (&service_name, &info.sender))
+        .change_context(ContractError::StorageError)?
+        .filter(|epoch_slashes| epoch_slashes.epoch == epoch)
+        .unwrap_or(state::EpochSlashes {
+            epoch,
+            amount: Uint128::zero(),
+        });
+
+    let remaining = limits
+        .max_slash_amount_per_epoch
+        .into_inner()
+        .saturating_sub(epoch_slashes.amount);
+    if amount.into_inner() > remaining {
+        return Err(ContractError::SlashExceedsEpochLimit {
+            amount: amount.into(),
+            remaining,
+        }
+        .into());
+    }
+
+    let verifier_info = VERIFIERS
+        .may_load(deps.storage, (&service_name, &verifier))
+        .change_context(ContractError::StorageError)?
+        .ok_or(ContractError::VerifierNotFound)?;
+
+    let (verifier_info, slashed_bond) = state::slash_bond(verifier_info, amount.into())?;
+
+    let queue = UNBONDING_QUEUE
+        .may_load(deps.storage, (&service_name, &verifier))
+        .change_context(ContractError::StorageError)?
+        .unwrap_or_default();
+
+    let (queue, slashed_unbonds) = state::slash_pending_unbonds(
+        queue,
+        amount
+            .into_inner()
+            .checked_sub(slashed_bond)
+            .map_err(ContractError::Overflow)?,
+    )?;
+
+    let slashed = slashed_bond
+        .checked_add(slashed_unbonds)
+        .map_err(ContractError::Overflow)?;
+    if slashed.is_zero() {
+        return Err(ContractError::NothingToSlash.into());
+    }
+
+    VERIFIERS
+        .save(deps.storage, (&service_name, &verifier), &verifier_info)
+        .change_context(ContractError::StorageError)?;
+
+    if queue.is_empty() {
+        UNBONDING_QUEUE.remove(deps.storage, (&service_name, &verifier));
+    } else {
+        UNBONDING_QUEUE
+            .save(deps.storage, (&service_name, &verifier), &queue)
+            .change_context(ContractError::StorageError)?;
+    }
+
+    EPOCH_SLASHES
+        .save(
+            deps.storage,
+            (&service_name, &info.sender),
+            &state::EpochSlashes {
+                epoch,
+                amount: epoch_slashes
+                    .amount
+                    .checked_add(slashed)
+                    .map_err(ContractError::Overflow)?,
+            },
+        )
+        .change_context(ContractError::StorageError)?;
+
+    let mut history = SLASH_HISTORY
+        .may_load(deps.storage, (&service_name, &verifier))
+        .change_context(ContractError::StorageError)?
+        .unwrap_or_default();
+    history.push(SlashRecord {
+        slasher: info.sender.clone(),
+        amount: slashed,
+        destination: destination.clone(),
+        reason: reason.clone(),
+        slashed_at: env.block.time,
+        block_height: env.block.height,
+    });
+    let excess = history
+        .len()
+        .saturating_sub(state::MAX_SLASH_HISTORY_LENGTH);
+    history.drain(..excess);
+    SLASH_HISTORY
+        .save(deps.storage, (&service_name, &verifier), &history)
+        .change_context(ContractError::StorageError)?;
+
+    let chains = supported_chains(deps.storage, &service_name, &verifier)?;
+    let events = snapshot_active_verifiers(deps.branch(), &env, &service_name, chains)?;
+
+    let funds = vec![Coin {
+        denom: service.bond_denom,
+        amount: slashed,
+    }];
+    let msg = match destination.clone() {
+        Some(destination) => BankMsg::Send {
+            to_address: destination.into(),
+            amount: funds,
+        },
+        None => BankMsg::Burn { amount: funds },
+    };
+
+    Ok(Response::new()
+        .add_message(msg)
+        .add_event(Event::VerifierSlashed {
+            service_name,
+            verifier,
+            slasher: info.sender,
+            amount: slashed,
+            destination,
+            reason,
+        })
+        .add_events(events))
+}
+
+pub fn register_attester(
+    deps: DepsMut,
+    service_name: String,
+    attester: Addr,
+) -> Result<Response, ContractError> {
+    SERVICES
+        .may_load(deps.storage, &service_name)
+        .change_context(ContractError::StorageError)?
+        .ok_or(ContractError::ServiceNotFound)?;
+
+    ATTESTERS
+        .save(deps.storage, (&service_name, &attester), &())
+        .change_context(ContractError::StorageError)?;
+
+    Ok(Response::new().add_event(Event::AttesterRegistered {
+        service_name,
+        attester,
+    }))
+}
+
+pub fn deregister_attester(
+    deps: DepsMut,
+    service_name: String,
+    attester: Addr,
+) -> Result<Response, ContractError> {
+    ATTESTERS
+        .may_load(deps.storage, (&service_name, &attester))
+        .change_context(ContractError::StorageError)?
+        .ok_or(ContractError::AttesterNotFound)?;
+
+    ATTESTERS.remove(deps.storage, (&service_name, &attester));
+
+    Ok(Response::new().add_event(Event::AttesterDeregistered {
+        service_name,
+        attester,
+    }))
+}
+
+pub fn submit_attestations(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    service_name: String,
+    summaries: Vec<ParticipationSummary>,
+) -> Result<Response, ContractError> {
+    let mut recorded = vec![];
+
+    for summary in summaries {
+        if summary.events_participated > summary.events_total {
+            return Err(ContractError::InvalidParticipationSummary {
+                verifier: summary.verifier,
+                events_participated: summary.events_participated,
+                events_total: summary.events_total,
+            }
+            .into());
+        }
+
+        let verifier = address::validate_cosmwasm_address(deps.api, &summary.verifier)
+            .change_context(ContractError::VerifierNotFound)?;
+
+        let is_known_verifier = VERIFIERS
+            .may_load(deps.storage, (&service_name, &verifier))
+            .change_context(ContractError::StorageError)?
+            .is_some();
+        if !is_known_verifier {
+            continue;
+        }
+
+        let key = (&service_name, &verifier, summary.chain_name.clone());
+        let attestation = ATTESTATIONS
+            .may_load(deps.storage, key.clone())
+            .change_context(ContractError::StorageError)?
+            .unwrap_or(PerformanceAttestation {
+                verifier: verifier.clone(),
+                chain_name: summary.chain_name.clone(),
+                events_participated: 0,
+                events_total: 0,
+                attester: info.sender.clone(),
+                updated_at: env.block.height,
+            });
+
+        ATTESTATIONS
+            .save(
+                deps.storage,
+                key,
+                &PerformanceAttestation {
+                    events_participated: attestation
+                        .events_participated
+                        .saturating_add(summary.events_participated),
+                    events_total: attestation
+                        .events_total
+                        .saturating_add(summary.events_total),
+                    attester: info.sender.clone(),
+                    updated_at: env.block.height,
+                    ..attestation
+                },
+            )
+            .change_context(ContractError::StorageError)?;
+
+        recorded.push(summary);
+    }
+
+    Ok(Response::new().add_event(Event::AttestationsSubmitted {
+        service_name,
+        attester: info.sender,
+        summaries: recorded,
+    }))
+}
+
+/// Returns the chains the verifier has registered support for
+fn supported_chains(
+    storage: &dyn Storage,
+    service_name: &String,
+    verifier: &Addr,
+) -> Result<Vec<ChainName>, ContractError> {
+    VERIFIERS_PER_CHAIN
+        .idx
+        .verifier_address
+        .prefix((service_name.clone(), verifier.clone()))
+        .keys(storage, None, None, Order::Ascending)
+        .map(|key| key.map(|(_, chain, _)| chain))
+        .collect::<std::result::Result<_, _>>()
+        .change_context(ContractError::StorageError)
+}
+
+/// Records the active verifiers of the service for each of the chains at the current block height, so that
+/// `ActiveVerifiersAt` reflects every change of an active set. Nothing is recorded for a chain if its set is the same
+/// as in the latest snapshot
+fn snapshot_active_verifiers(
+    deps: DepsMut,
+    env: &Env,
+    service_name: &String,
+    chains: impl IntoIterator<Item = ChainName>,
+) -> Result<Vec<Event>, ContractError> {
+    let service = SERVICES
+        .may_load(deps.storage, service_name)
+        .change_context(ContractError::StorageError)?
+        .ok_or(ContractError::ServiceNotFound)?;
+
+    let mut events = vec![];
+    for chain_name in chains.into_iter().collect::<BTreeSet<_>>() {
+        let verifiers: Vec<_> =
+            query::weighted_active_verifiers(deps.as_ref(), &service, &chain_name)
+                .collect::<std::result::Result<_, _>>()
+                .change_context(ContractError::StorageError)?;
+
+        let latest = state::verifier_set_snapshot_at(
+            deps.storage,
+            service_name,
+            chain_name.clone(),
+            env.block.height,
+        )?;
+        if latest.is_some_and(|snapshot| snapshot.verifiers == verifiers) {
+            continue;
+        }
+
+        events.push(Event::ActiveVerifiersSnapshotted {
+            service_name: service_name.clone(),
+            chain_name: chain_name.clone(),
+            height: env.block.height,
+            verifiers: verifiers
+                .iter()
+                .map(|verifier| verifier.verifier_info.address.clone())
+                .collect(),
+        });
+
+        state::save_verifier_set_snapshot(
+            deps.storage,
+            service_name,
+            chain_name,
+            env.block.height,
+            verifiers,
+        )?;
+    }
+
+    Ok(events)
+}
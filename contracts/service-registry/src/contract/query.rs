@@ -1,12 +1,22 @@
 use axelar_wasm_std::address;
-use cosmwasm_std::{Deps, Order};
+use cosmwasm_std::{Addr, Deps, Order, StdError};
+use cw_storage_plus::Bound;
 use itertools::Itertools;
 use router_api::ChainName;
 use service_registry_api::error::ContractError;
 use service_registry_api::*;
 
-use crate::msg::VerifierDetails;
-use crate::state::{SERVICES, VERIFIERS, VERIFIERS_PER_CHAIN, VERIFIER_WEIGHT};
+use crate::msg::{
+    ActiveVerifiersPageResponse, VerifierDetails, MAX_ACTIVE_VERIFIERS_PAGE_LIMIT,
+    MAX_VERIFIERS_QUERY_LIMIT,
+};
+use crate::state::{
+    self, ATTESTATIONS, EXIT_QUEUE, SERVICES, SLASH_HISTORY, UNBONDING_QUEUE, VERIFIERS,
+    VERIFIERS_PER_CHAIN, VERIFIER_WEIGHT,
+};
+
+const DEFAULT_ATTESTATIONS_LIMIT: u32 = 100;
+const DEFAULT_EXIT_QUEUE_LIMIT: u32 = 100;
 
 pub fn active_verifiers(
     deps: Deps,
@@ -17,33 +27,107 @@ pub fn active_verifiers(
         .may_load(deps.storage, &service_name)?
         .ok_or(ContractError::ServiceNotFound)?;
 
-    let verifiers: Vec<_> = VERIFIERS_PER_CHAIN
-        .prefix((service_name.clone(), chain_name.clone()))
+    let verifiers: Vec<_> = weighted_active_verifiers(deps, &service, &chain_name).try_collect()?;
+
+    if verifiers.len() < service.min_num_verifiers.into() {
+        Err(ContractError::NotEnoughVerifiers)
+    } else {
+        Ok(verifiers)
+    }
+}
+
+/// Scans at most `limit` verifiers of the chain, so a page can't run out of gas on a long stretch of inactive verifiers
+pub fn active_verifiers_page(
+    deps: Deps,
+    service_name: String,
+    chain_name: ChainName,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> Result<ActiveVerifiersPageResponse, axelar_wasm_std::error::ContractError> {
+    let service = SERVICES
+        .may_load(deps.storage, &service_name)?
+        .ok_or(ContractError::ServiceNotFound)?;
+
+    let start_after = start_after
+        .map(|verifier| address::validate_cosmwasm_address(deps.api, &verifier))
+        .transpose()?;
+    let limit = limit
+        .unwrap_or(MAX_ACTIVE_VERIFIERS_PAGE_LIMIT)
+        .min(MAX_ACTIVE_VERIFIERS_PAGE_LIMIT) as usize;
+
+    let scanned: Vec<Addr> = VERIFIERS_PER_CHAIN
+        .prefix((service_name.clone(), chain_name))
+        .keys(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .try_collect()?;
+
+    let next_start_after = scanned
+        .last()
+        .filter(|_| scanned.len() == limit)
+        .map(Addr::to_string);
+
+    Ok(ActiveVerifiersPageResponse {
+        verifiers: scanned
+            .iter()
+            .filter_map(|verifier_addr| active_verifier(deps, &service, verifier_addr))
+            .collect(),
+        next_start_after,
+    })
+}
+
+/// Iterates over the verifiers of the service that are active for the chain, ordered by address
+pub fn weighted_active_verifiers<'a>(
+    deps: Deps<'a>,
+    service: &'a Service,
+    chain_name: &ChainName,
+) -> impl Iterator<Item = Result<WeightedVerifier, StdError>> + 'a {
+    VERIFIERS_PER_CHAIN
+        .prefix((service.name.clone(), chain_name.clone()))
         .keys(deps.storage, None, None, Order::Ascending)
-        .filter_map_ok(|verifier_addr| {
-            VERIFIERS
-                .may_load(deps.storage, (&service_name, &verifier_addr))
-                .ok()
-                .flatten()
-        })
-        .filter_ok(|verifier| {
+        .filter_map_ok(move |verifier_addr| active_verifier(deps, service, &verifier_addr))
+}
+
+/// Returns the verifier with its weight if it is active for the service
+fn active_verifier(
+    deps: Deps,
+    service: &Service,
+    verifier_addr: &Addr,
+) -> Option<WeightedVerifier> {
+    VERIFIERS
+        .may_load(deps.storage, (&service.name, verifier_addr))
+        .ok()
+        .flatten()
+        .filter(|verifier| {
             matches!(
                 verifier.bonding_state,
                 BondingState::Bonded { amount } if amount >= service.min_verifier_bond
             )
         })
-        .filter_ok(|verifier| verifier.authorization_state == AuthorizationState::Authorized)
-        .map_ok(|verifier| WeightedVerifier {
+        .filter(|verifier| verifier.authorization_state == AuthorizationState::Authorized)
+        // exiting verifiers must not be selected for new polls and signing sessions
+        .filter(|verifier| !EXIT_QUEUE.has(deps.storage, (&service.name, &verifier.address)))
+        .map(|verifier| WeightedVerifier {
             verifier_info: verifier,
             weight: VERIFIER_WEIGHT, // all verifiers have an identical const weight for now
         })
-        .try_collect()?;
+}
 
-    if verifiers.len() < service.min_num_verifiers.into() {
-        Err(ContractError::NotEnoughVerifiers)
-    } else {
-        Ok(verifiers)
-    }
+pub fn active_verifiers_at(
+    deps: Deps,
+    service_name: String,
+    chain_name: ChainName,
+    height: u64,
+) -> Result<Option<VerifierSetSnapshot>, ContractError> {
+    SERVICES
+        .may_load(deps.storage, &service_name)?
+        .ok_or(ContractError::ServiceNotFound)?;
+
+    state::verifier_set_snapshot_at(deps.storage, &service_name, chain_name, height)
 }
 
 pub fn verifier(
@@ -60,18 +144,129 @@ pub fn verifier(
     let supported_chains = VERIFIERS_PER_CHAIN
         .idx
         .verifier_address
-        .prefix((service_name, verifier_addr.clone()))
+        .prefix((service_name.clone(), verifier_addr.clone()))
         .keys(deps.storage, None, None, Order::Ascending)
         .map_ok(|(_, chain, _)| chain)
         .try_collect()?;
 
+    let attestations = ATTESTATIONS
+        .prefix((&service_name, &verifier_addr))
+        .range(deps.storage, None, None, Order::Ascending)
+        .map_ok(|(_, attestation)| attestation)
+        .try_collect()?;
+
     Ok(VerifierDetails {
         verifier,
         weight: VERIFIER_WEIGHT,
         supported_chains,
+        attestations,
     })
 }
 
+pub fn verifiers(
+    deps: Deps,
+    service_name: String,
+    verifiers: Vec<String>,
+) -> Result<Vec<Verifier>, axelar_wasm_std::error::ContractError> {
+    SERVICES
+        .may_load(deps.storage, &service_name)?
+        .ok_or(ContractError::ServiceNotFound)?;
+
+    if verifiers.len() > MAX_VERIFIERS_QUERY_LIMIT {
+        return Err(ContractError::TooManyVerifiers {
+            count: verifiers.len(),
+            max: MAX_VERIFIERS_QUERY_LIMIT,
+        }
+        .into());
+    }
+
+    verifiers
+        .iter()
+        .map(
+            |verifier| -> Result<Option<Verifier>, axelar_wasm_std::error::ContractError> {
+                let verifier_addr = address::validate_cosmwasm_address(deps.api, verifier)?;
+                Ok(VERIFIERS.may_load(deps.storage, (&service_name, &verifier_addr))?)
+            },
+        )
+        .flatten_ok()
+        .collect()
+}
+
+pub fn pending_unbonds(
+    deps: Deps,
+    service_name: String,
+    verifier: String,
+) -> Result<Vec<PendingUnbond>, axelar_wasm_std::error::ContractError> {
+    let verifier_addr = address::validate_cosmwasm_address(deps.api, &verifier)?;
+
+    Ok(UNBONDING_QUEUE
+        .may_load(deps.storage, (&service_name, &verifier_addr))?
+        .unwrap_or_default())
+}
+
+pub fn slash_history(
+    deps: Deps,
+    service_name: String,
+    verifier: String,
+) -> Result<Vec<SlashRecord>, axelar_wasm_std::error::ContractError> {
+    let verifier_addr = address::validate_cosmwasm_address(deps.api, &verifier)?;
+
+    Ok(SLASH_HISTORY
+        .may_load(deps.storage, (&service_name, &verifier_addr))?
+        .unwrap_or_default())
+}
+
+pub fn attestations(
+    deps: Deps,
+    service_name: String,
+    start_after: Option<(String, ChainName)>,
+    limit: Option<u32>,
+) -> Result<Vec<PerformanceAttestation>, axelar_wasm_std::error::ContractError> {
+    let start_after = start_after
+        .map(|(verifier, chain_name)| {
+            address::validate_cosmwasm_address(deps.api, &verifier)
+                .map(|verifier| (verifier, chain_name))
+        })
+        .transpose()?;
+
+    Ok(ATTESTATIONS
+        .sub_prefix(&service_name)
+        .range(
+            deps.storage,
+            start_after
+                .as_ref()
+                .map(|(verifier, chain_name)| Bound::exclusive((verifier, chain_name.clone()))),
+            None,
+            Order::Ascending,
+        )
+        .take(limit.unwrap_or(DEFAULT_ATTESTATIONS_LIMIT) as usize)
+        .map_ok(|(_, attestation)| attestation)
+        .try_collect()?)
+}
+
+pub fn exit_queue(
+    deps: Deps,
+    service_name: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> Result<Vec<ExitRequest>, axelar_wasm_std::error::ContractError> {
+    let start_after = start_after
+        .map(|verifier| address::validate_cosmwasm_address(deps.api, &verifier))
+        .transpose()?;
+
+    Ok(EXIT_QUEUE
+        .prefix(&service_name)
+        .range(
+            deps.storage,
+            start_after.as_ref().map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit.unwrap_or(DEFAULT_EXIT_QUEUE_LIMIT) as usize)
+        .map_ok(|(_, request)| request)
+        .try_collect()?)
+}
+
 pub fn service(deps: Deps, service_name: String) -> Result<Service, ContractError> {
     SERVICES
         .may_load(deps.storage, &service_name)?
@@ -0,0 +1,73 @@
+use axelar_wasm_std::{nonempty, IntoEvent};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Uint128};
+use router_api::ChainName;
+use service_registry_api::{BondingState, ParticipationSummary, SlashingLimits};
+
+#[derive(IntoEvent)]
+pub enum Event {
+    SlasherRegistered {
+        service_name: String,
+        slasher: Addr,
+        limits: SlashingLimits,
+    },
+    SlasherDeregistered {
+        service_name: String,
+        slasher: Addr,
+    },
+    VerifierSlashed {
+        service_name: String,
+        verifier: Addr,
+        slasher: Addr,
+        amount: Uint128,
+        destination: Option<Addr>,
+        reason: nonempty::String,
+    },
+    AttesterRegistered {
+        service_name: String,
+        attester: Addr,
+    },
+    AttesterDeregistered {
+        service_name: String,
+        attester: Addr,
+    },
+    AttestationsSubmitted {
+        service_name: String,
+        attester: Addr,
+        /// Summaries that were added to the verifiers' attestations
+        summaries: Vec<ParticipationSummary>,
+    },
+    /// The verifier is excluded from new polls and signing sessions, but must keep participating
+    /// in the ones it is already part of at least until `duties_end_at`
+    VerifierExitRequested {
+        service_name: String,
+        verifier: Addr,
+        duties_end_at: u64,
+    },
+    /// The verifier's duties have ended and it left the exit queue
+    VerifierExitCompleted {
+        service_name: String,
+        verifier: Addr,
+        bonding_state: BondingState,
+    },
+    /// The verifier's handover has ended, but it can't unbond yet, so it stays in the exit queue
+    VerifierExitDelayed {
+        service_name: String,
+        verifier: Addr,
+        reason: ExitDelayReason,
+    },
+    ActiveVerifiersSnapshotted {
+        service_name: String,
+        chain_name: ChainName,
+        height: u64,
+        verifiers: Vec<Addr>,
+    },
+}
+
+#[cw_serde]
+pub enum ExitDelayReason {
+    /// The verifier still has open polls or signing sessions, or is part of an active verifier set
+    NotReadyToUnbond,
+    /// Jailed verifiers can't unbond until they are unjailed
+    Jailed,
+}
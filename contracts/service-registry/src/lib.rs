@@ -1,4 +1,5 @@
 pub mod contract;
+mod events;
 pub mod helpers;
 pub mod msg;
 mod state;
@@ -1,6 +1,9 @@
 use cosmwasm_schema::cw_serde;
 // these messages and structs are extracted into a separate package to avoid circular dependencies
-pub use service_registry_api::msg::{ExecuteMsg, QueryMsg, UpdatedServiceParams, VerifierDetails};
+pub use service_registry_api::msg::{
+    ActiveVerifiersPageResponse, ExecuteMsg, QueryMsg, UpdatedServiceParams, VerifierDetails,
+    MAX_ACTIVE_VERIFIERS_PAGE_LIMIT, MAX_VERIFIERS_QUERY_LIMIT,
+};
 
 pub use crate::contract::MigrateMsg;
 
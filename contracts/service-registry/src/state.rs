@@ -1,9 +1,13 @@
 use axelar_wasm_std::nonempty;
-use cosmwasm_std::{Addr, Storage, Timestamp, Uint128};
-use cw_storage_plus::{Index, IndexList, IndexedMap, KeyDeserialize, Map, MultiIndex};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Order, Storage, Timestamp, Uint128};
+use cw_storage_plus::{Bound, Index, IndexList, IndexedMap, KeyDeserialize, Map, MultiIndex};
 use router_api::ChainName;
 use service_registry_api::error::ContractError;
-use service_registry_api::{AuthorizationState, BondingState, Service, Verifier};
+use service_registry_api::{
+    AuthorizationState, BondingState, ExitRequest, PendingUnbond, PerformanceAttestation, Service,
+    SlashRecord, SlashingLimits, Verifier, VerifierSetSnapshot, WeightedVerifier,
+};
 
 type ServiceName = String;
 type VerifierAddress = Addr;
@@ -49,6 +53,124 @@ pub const VERIFIER_WEIGHT: nonempty::Uint128 = nonempty::Uint128::one();
 
 pub const SERVICES: Map<&ServiceName, Service> = Map::new("services");
 pub const VERIFIERS: Map<(&ServiceName, &VerifierAddress), Verifier> = Map::new("verifiers");
+/// Partial unbonds of each verifier, ordered from oldest to newest request
+pub const UNBONDING_QUEUE: Map<(&ServiceName, &VerifierAddress), Vec<PendingUnbond>> =
+    Map::new("unbonding_queue");
+/// Verifiers that requested to exit, but whose unbonding has not started yet
+pub const EXIT_QUEUE: Map<(&ServiceName, &VerifierAddress), ExitRequest> = Map::new("exit_queue");
+
+type SlasherAddress = Addr;
+
+/// Amount a slasher has slashed within its current epoch
+#[cw_serde]
+#[derive(Default)]
+pub struct EpochSlashes {
+    pub epoch: u64,
+    pub amount: Uint128,
+}
+
+pub const SLASHERS: Map<(&ServiceName, &SlasherAddress), SlashingLimits> = Map::new("slashers");
+pub const EPOCH_SLASHES: Map<(&ServiceName, &SlasherAddress), EpochSlashes> =
+    Map::new("epoch_slashes");
+/// Maximum number of slashes kept per verifier. Once the history is full, the oldest slash is dropped for every new one
+pub const MAX_SLASH_HISTORY_LENGTH: usize = 100;
+/// Most recent slashes of each verifier, ordered from oldest to newest
+pub const SLASH_HISTORY: Map<(&ServiceName, &VerifierAddress), Vec<SlashRecord>> =
+    Map::new("slash_history");
+
+type AttesterAddress = Addr;
+
+pub const ATTESTERS: Map<(&ServiceName, &AttesterAddress), ()> = Map::new("attesters");
+/// Accumulated performance of each verifier per chain
+pub const ATTESTATIONS: Map<(&ServiceName, &VerifierAddress, ChainName), PerformanceAttestation> =
+    Map::new("attestations");
+
+/// Removes the attestations of the verifier for all chains
+pub fn remove_attestations(
+    storage: &mut dyn Storage,
+    service_name: &ServiceName,
+    verifier: &VerifierAddress,
+) -> Result<(), ContractError> {
+    let chains = ATTESTATIONS
+        .prefix((service_name, verifier))
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    for chain in chains {
+        ATTESTATIONS.remove(storage, (service_name, verifier, chain));
+    }
+
+    Ok(())
+}
+
+type BlockHeight = u64;
+
+/// Number of blocks snapshots are kept for after they were superseded by a newer one
+pub const VERIFIER_SET_SNAPSHOT_RETENTION_BLOCKS: u64 = 1_000_000;
+
+/// Maximum number of superseded snapshots removed whenever a new snapshot is saved, so the cost of a single save stays bounded
+const MAX_PRUNED_SNAPSHOTS: usize = 10;
+
+/// Active verifiers of each service per chain, keyed by the block height the snapshot was taken at
+pub const VERIFIER_SET_SNAPSHOTS: Map<
+    (&ServiceName, ChainName, BlockHeight),
+    Vec<WeightedVerifier>,
+> = Map::new("verifier_set_snapshots");
+
+/// Returns the latest snapshot that was taken at or before the given height
+pub fn verifier_set_snapshot_at(
+    storage: &dyn Storage,
+    service_name: &ServiceName,
+    chain_name: ChainName,
+    height: BlockHeight,
+) -> Result<Option<VerifierSetSnapshot>, ContractError> {
+    Ok(VERIFIER_SET_SNAPSHOTS
+        .prefix((service_name, chain_name))
+        .range(
+            storage,
+            None,
+            Some(Bound::inclusive(height)),
+            Order::Descending,
+        )
+        .next()
+        .transpose()?
+        .map(|(height, verifiers)| VerifierSetSnapshot { height, verifiers }))
+}
+
+/// Saves the snapshot and prunes the snapshots that are older than the retention period. The latest snapshot before the
+/// retention period is kept, because it still describes the active verifiers at the start of the period
+pub fn save_verifier_set_snapshot(
+    storage: &mut dyn Storage,
+    service_name: &ServiceName,
+    chain_name: ChainName,
+    height: BlockHeight,
+    verifiers: Vec<WeightedVerifier>,
+) -> Result<(), ContractError> {
+    VERIFIER_SET_SNAPSHOTS.save(
+        storage,
+        (service_name, chain_name.clone(), height),
+        &verifiers,
+    )?;
+
+    let retained_from = height.saturating_sub(VERIFIER_SET_SNAPSHOT_RETENTION_BLOCKS);
+    let expired: Vec<BlockHeight> = VERIFIER_SET_SNAPSHOTS
+        .prefix((service_name, chain_name.clone()))
+        .keys(
+            storage,
+            None,
+            Some(Bound::inclusive(retained_from)),
+            Order::Descending,
+        )
+        .skip(1)
+        .take(MAX_PRUNED_SNAPSHOTS)
+        .collect::<Result<_, _>>()?;
+
+    for expired_height in expired {
+        VERIFIER_SET_SNAPSHOTS.remove(storage, (service_name, chain_name.clone(), expired_height));
+    }
+
+    Ok(())
+}
 
 pub fn bond_verifier(
     verifier: Verifier,
@@ -127,6 +249,122 @@ pub fn claim_verifier_stake(
     }
 }
 
+pub fn unbond_partial_stake(
+    verifier: Verifier,
+    to_unbond: nonempty::Uint128,
+    min_verifier_bond: nonempty::Uint128,
+) -> Result<Verifier, ContractError> {
+    if verifier.authorization_state == AuthorizationState::Jailed {
+        return Err(ContractError::VerifierJailed);
+    }
+
+    let remaining = match verifier.bonding_state {
+        BondingState::Bonded { amount } => amount
+            .into_inner()
+            .checked_sub(to_unbond.into_inner())
+            .map_err(ContractError::Overflow)?,
+        _ => return Err(ContractError::InvalidBondingState(verifier.bonding_state)),
+    };
+
+    if remaining < min_verifier_bond.into_inner() {
+        return Err(ContractError::RemainingBondBelowMinimum);
+    }
+
+    Ok(Verifier {
+        bonding_state: BondingState::Bonded {
+            amount: remaining.try_into()?,
+        },
+        ..verifier
+    })
+}
+
+/// Releases pending unbonds from the front of the queue until one is found that has not completed yet.
+/// Returns the remaining queue and the total released amount.
+pub fn release_completed_unbonds(
+    mut queue: Vec<PendingUnbond>,
+    time: Timestamp,
+) -> Result<(Vec<PendingUnbond>, Uint128), ContractError> {
+    let completed = queue
+        .iter()
+        .take_while(|pending| pending.completes_at <= time)
+        .count();
+
+    let released = queue
+        .drain(..completed)
+        .try_fold(Uint128::zero(), |total, pending| {
+            total.checked_add(pending.amount.into_inner())
+        })?;
+
+    Ok((queue, released))
+}
+
+/// Slashes up to the given amount from the verifier's bond. A bond that is slashed completely leaves the verifier unbonded.
+/// Returns the updated verifier and the slashed amount.
+pub fn slash_bond(
+    verifier: Verifier,
+    to_slash: Uint128,
+) -> Result<(Verifier, Uint128), ContractError> {
+    let amount = match verifier.bonding_state {
+        BondingState::Bonded { amount }
+        | BondingState::RequestedUnbonding { amount }
+        | BondingState::Unbonding { amount, .. } => amount.into_inner(),
+        BondingState::Unbonded => return Ok((verifier, Uint128::zero())),
+    };
+
+    let slashed = to_slash.min(amount);
+    let remaining = nonempty::Uint128::try_from(amount.checked_sub(slashed)?);
+
+    let bonding_state = match (verifier.bonding_state, remaining) {
+        (BondingState::Bonded { .. }, Ok(amount)) => BondingState::Bonded { amount },
+        (BondingState::RequestedUnbonding { .. }, Ok(amount)) => {
+            BondingState::RequestedUnbonding { amount }
+        }
+        (BondingState::Unbonding { unbonded_at, .. }, Ok(amount)) => BondingState::Unbonding {
+            amount,
+            unbonded_at,
+        },
+        (BondingState::Unbonded, _) | (_, Err(_)) => BondingState::Unbonded,
+    };
+
+    Ok((
+        Verifier {
+            bonding_state,
+            ..verifier
+        },
+        slashed,
+    ))
+}
+
+/// Slashes up to the given amount from the pending unbonds, starting with the most recent one.
+/// Returns the remaining queue and the slashed amount.
+pub fn slash_pending_unbonds(
+    mut queue: Vec<PendingUnbond>,
+    mut to_slash: Uint128,
+) -> Result<(Vec<PendingUnbond>, Uint128), ContractError> {
+    let mut slashed = Uint128::zero();
+
+    while let Some(pending) = queue.pop() {
+        let amount = pending.amount.into_inner().min(to_slash);
+        slashed = slashed.checked_add(amount)?;
+        to_slash = to_slash.checked_sub(amount)?;
+
+        if let Ok(remaining) =
+            nonempty::Uint128::try_from(pending.amount.into_inner().checked_sub(amount)?)
+        {
+            queue.push(PendingUnbond {
+                amount: remaining,
+                ..pending
+            });
+        }
+
+        if to_slash.is_zero() {
+            break;
+        }
+    }
+
+    Ok((queue, slashed))
+}
+
 pub fn register_chains_support(
     storage: &mut dyn Storage,
     service_name: String,
@@ -703,4 +941,222 @@ mod tests {
         assert!(res.is_err());
         assert_eq!(res.unwrap_err(), ContractError::VerifierJailed);
     }
+
+    #[test]
+    fn partial_unbond_keeps_remaining_stake_bonded() {
+        let verifier = Verifier {
+            address: MockApi::default().addr_make("verifier"),
+            bonding_state: BondingState::Bonded {
+                amount: Uint128::from(300u32).try_into().unwrap(),
+            },
+            authorization_state: AuthorizationState::Authorized,
+            service_name: "validators".to_string(),
+        };
+
+        let res = unbond_partial_stake(
+            verifier,
+            Uint128::from(200u32).try_into().unwrap(),
+            Uint128::from(100u32).try_into().unwrap(),
+        );
+        assert_eq!(
+            res.unwrap().bonding_state,
+            BondingState::Bonded {
+                amount: Uint128::from(100u32).try_into().unwrap()
+            }
+        );
+    }
+
+    #[test]
+    fn partial_unbond_below_min_bond_fails() {
+        let verifier = Verifier {
+            address: MockApi::default().addr_make("verifier"),
+            bonding_state: BondingState::Bonded {
+                amount: Uint128::from(300u32).try_into().unwrap(),
+            },
+            authorization_state: AuthorizationState::Authorized,
+            service_name: "validators".to_string(),
+        };
+
+        let res = unbond_partial_stake(
+            verifier,
+            Uint128::from(201u32).try_into().unwrap(),
+            Uint128::from(100u32).try_into().unwrap(),
+        );
+        assert_eq!(res.unwrap_err(), ContractError::RemainingBondBelowMinimum);
+    }
+
+    #[test]
+    fn partial_unbond_of_entire_stake_fails() {
+        let verifier = Verifier {
+            address: MockApi::default().addr_make("verifier"),
+            bonding_state: BondingState::Bonded {
+                amount: Uint128::from(300u32).try_into().unwrap(),
+            },
+            authorization_state: AuthorizationState::Authorized,
+            service_name: "validators".to_string(),
+        };
+
+        let res = unbond_partial_stake(
+            verifier,
+            Uint128::from(300u32).try_into().unwrap(),
+            Uint128::from(1u32).try_into().unwrap(),
+        );
+        assert_eq!(res.unwrap_err(), ContractError::RemainingBondBelowMinimum);
+    }
+
+    #[test]
+    fn partial_unbond_requires_bonded_state() {
+        let bonding_state = BondingState::RequestedUnbonding {
+            amount: Uint128::from(300u32).try_into().unwrap(),
+        };
+        let verifier = Verifier {
+            address: MockApi::default().addr_make("verifier"),
+            bonding_state: bonding_state.clone(),
+            authorization_state: AuthorizationState::Authorized,
+            service_name: "validators".to_string(),
+        };
+
+        let res = unbond_partial_stake(
+            verifier,
+            Uint128::from(100u32).try_into().unwrap(),
+            Uint128::from(100u32).try_into().unwrap(),
+        );
+        assert_eq!(
+            res.unwrap_err(),
+            ContractError::InvalidBondingState(bonding_state)
+        );
+    }
+
+    #[test]
+    fn jailed_verifier_cannot_partially_unbond() {
+        let verifier = Verifier {
+            address: MockApi::default().addr_make("verifier"),
+            bonding_state: BondingState::Bonded {
+                amount: Uint128::from(300u32).try_into().unwrap(),
+            },
+            authorization_state: AuthorizationState::Jailed,
+            service_name: "validators".to_string(),
+        };
+
+        let res = unbond_partial_stake(
+            verifier,
+            Uint128::from(100u32).try_into().unwrap(),
+            Uint128::from(100u32).try_into().unwrap(),
+        );
+        assert_eq!(res.unwrap_err(), ContractError::VerifierJailed);
+    }
+
+    #[test]
+    fn release_completed_unbonds_in_fifo_order() {
+        let pending_unbond = |amount: u32, completes_at: u64| PendingUnbond {
+            amount: Uint128::from(amount).try_into().unwrap(),
+            requested_at: Timestamp::from_seconds(0),
+            completes_at: Timestamp::from_seconds(completes_at),
+        };
+        let queue = vec![
+            pending_unbond(10, 1),
+            pending_unbond(20, 2),
+            pending_unbond(30, 4),
+            // completes early, but is queued behind an entry that is still unbonding
+            pending_unbond(40, 3),
+        ];
+
+        let (queue, released) =
+            release_completed_unbonds(queue, Timestamp::from_seconds(3)).unwrap();
+        assert_eq!(released, Uint128::from(30u32));
+        assert_eq!(queue, vec![pending_unbond(30, 4), pending_unbond(40, 3)]);
+
+        let (queue, released) =
+            release_completed_unbonds(queue, Timestamp::from_seconds(4)).unwrap();
+        assert_eq!(released, Uint128::from(70u32));
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn slash_bond_should_unbond_verifier_when_bond_is_depleted() {
+        let verifier = Verifier {
+            address: Addr::unchecked("verifier"),
+            bonding_state: BondingState::Unbonding {
+                amount: Uint128::new(100).try_into().unwrap(),
+                unbonded_at: Timestamp::from_seconds(10),
+            },
+            authorization_state: AuthorizationState::Authorized,
+            service_name: "validators".to_string(),
+        };
+
+        let (partially_slashed, slashed) = slash_bond(verifier.clone(), Uint128::new(40)).unwrap();
+        assert_eq!(slashed, Uint128::new(40));
+        assert_eq!(
+            partially_slashed.bonding_state,
+            BondingState::Unbonding {
+                amount: Uint128::new(60).try_into().unwrap(),
+                unbonded_at: Timestamp::from_seconds(10),
+            }
+        );
+
+        let (fully_slashed, slashed) = slash_bond(verifier, Uint128::new(500)).unwrap();
+        assert_eq!(slashed, Uint128::new(100));
+        assert_eq!(fully_slashed.bonding_state, BondingState::Unbonded);
+    }
+
+    #[test]
+    fn slash_pending_unbonds_should_start_with_most_recent_unbond() {
+        let pending_unbond = |amount: u128, requested_at: u64| PendingUnbond {
+            amount: Uint128::new(amount).try_into().unwrap(),
+            requested_at: Timestamp::from_seconds(requested_at),
+            completes_at: Timestamp::from_seconds(requested_at).plus_days(1),
+        };
+        let queue = vec![pending_unbond(100, 1), pending_unbond(50, 2)];
+
+        let (queue, slashed) = slash_pending_unbonds(queue, Uint128::new(70)).unwrap();
+        assert_eq!(slashed, Uint128::new(70));
+        assert_eq!(queue, vec![pending_unbond(80, 1)]);
+
+        let (queue, slashed) = slash_pending_unbonds(queue, Uint128::new(100)).unwrap();
+        assert_eq!(slashed, Uint128::new(80));
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn saving_verifier_set_snapshot_should_prune_snapshots_older_than_retention_period() {
+        let mut deps = mock_dependencies();
+        let service_name: ServiceName = "validators".into();
+        let chain_name = ChainName::from_str("ethereum").unwrap();
+        let snapshot_heights = |storage: &dyn Storage| -> Vec<BlockHeight> {
+            VERIFIER_SET_SNAPSHOTS
+                .prefix((&service_name, chain_name.clone()))
+                .keys(storage, None, None, Order::Ascending)
+                .collect::<Result<_, _>>()
+                .unwrap()
+        };
+
+        for height in [1, 2, 3] {
+            save_verifier_set_snapshot(
+                deps.as_mut().storage,
+                &service_name,
+                chain_name.clone(),
+                height,
+                vec![],
+            )
+            .unwrap();
+        }
+        assert_eq!(snapshot_heights(deps.as_ref().storage), vec![1, 2, 3]);
+
+        let height = VERIFIER_SET_SNAPSHOT_RETENTION_BLOCKS + 3;
+        save_verifier_set_snapshot(
+            deps.as_mut().storage,
+            &service_name,
+            chain_name.clone(),
+            height,
+            vec![],
+        )
+        .unwrap();
+        assert_eq!(snapshot_heights(deps.as_ref().storage), vec![3, height]);
+        assert_eq!(
+            verifier_set_snapshot_at(deps.as_ref().storage, &service_name, chain_name.clone(), 3)
+                .unwrap()
+                .map(|snapshot| snapshot.height),
+            Some(3)
+        );
+    }
 }
@@ -1,12 +1,16 @@
+use axelar_wasm_std::time::Duration;
 use axelar_wasm_std::vec::VecExt;
-use axelar_wasm_std::voting::{PollId, Vote};
-use axelar_wasm_std::{nonempty, MajorityThreshold, VerificationStatus};
-use cosmwasm_std::CosmosMsg;
+use axelar_wasm_std::voting::{PollId, Vote, VoteLatency};
+use axelar_wasm_std::{bounded, nonempty, MajorityThreshold, VerificationStatus};
+use cosmwasm_std::{Coin, CosmosMsg};
 use error_stack::ResultExt;
 use multisig::verifier_set::VerifierSet;
 use router_api::Message;
 
-use crate::msg::{ExecuteMsg, MessageStatus, PollResponse, QueryMsg};
+use crate::msg::{
+    DynamicVotingThreshold, ExecuteMsg, MessageStatus, ObservedBlock, PollResponse, PollRetry,
+    QueryMsg, SourceChainHalt, VerificationFee, VoteWeightDecay, MAX_REASON_LENGTH,
+};
 
 type Result<T> = error_stack::Result<T, Error>;
 
@@ -20,6 +24,35 @@ pub enum Error {
     MessagesStatus(Vec<Message>),
     #[error("failed to query voting verifier for poll. poll_id: {0}")]
     Poll(PollId),
+    #[error("failed to query voting verifier for messages not executed status. messages: {messages:?}, up_to_height: {up_to_height}")]
+    MessagesNotExecutedStatus {
+        messages: Vec<Message>,
+        up_to_height: u64,
+    },
+    #[error("failed to query voting verifier for verification fee")]
+    VerificationFee,
+    #[error("failed to query voting verifier for accrued fees")]
+    AccruedFees,
+    #[error("failed to query voting verifier for vote weight decay")]
+    VoteWeightDecay,
+    #[error("failed to query voting verifier for dynamic voting threshold")]
+    DynamicVotingThreshold,
+    #[error("failed to query voting verifier for poll retry")]
+    PollRetry,
+    #[error("failed to query voting verifier for poll time expiry")]
+    PollTimeExpiry,
+    #[error("failed to query voting verifier for late vote grace blocks")]
+    LateVoteGraceBlocks,
+    #[error("failed to query voting verifier for source chain halt")]
+    SourceChainHalt,
+    #[error("failed to query voting verifier for vote latencies. poll_id: {0}")]
+    VoteLatencies(PollId),
+    #[error("failed to query voting verifier for message retries. message: {0:?}")]
+    MessageRetries(Message),
+    #[error("failed to query voting verifier for missed polls. verifier_address: {0}")]
+    MissedPolls(String),
+    #[error("failed to query voting verifier for open polls. verifier_address: {0}")]
+    HasOpenPolls(String),
 }
 
 impl From<QueryMsg> for Error {
@@ -29,6 +62,25 @@ impl From<QueryMsg> for Error {
             QueryMsg::VerifierSetStatus(verifier_set) => Error::VerifierSetStatus(verifier_set),
             QueryMsg::Poll { poll_id } => Error::Poll(poll_id),
             QueryMsg::CurrentThreshold => Error::CurrentThreshold,
+            QueryMsg::MessagesNotExecutedStatus {
+                messages,
+                up_to_height,
+            } => Error::MessagesNotExecutedStatus {
+                messages,
+                up_to_height,
+            },
+            QueryMsg::VerificationFee => Error::VerificationFee,
+            QueryMsg::AccruedFees => Error::AccruedFees,
+            QueryMsg::VoteWeightDecay => Error::VoteWeightDecay,
+            QueryMsg::DynamicVotingThreshold => Error::DynamicVotingThreshold,
+            QueryMsg::PollRetry => Error::PollRetry,
+            QueryMsg::PollTimeExpiry => Error::PollTimeExpiry,
+            QueryMsg::LateVoteGraceBlocks => Error::LateVoteGraceBlocks,
+            QueryMsg::SourceChainHalt => Error::SourceChainHalt,
+            QueryMsg::VoteLatencies { poll_id } => Error::VoteLatencies(poll_id),
+            QueryMsg::MessageRetries(message) => Error::MessageRetries(message),
+            QueryMsg::MissedPolls { verifier_address } => Error::MissedPolls(verifier_address),
+            QueryMsg::HasOpenPolls { verifier_address } => Error::HasOpenPolls(verifier_address),
         }
     }
 }
@@ -50,6 +102,19 @@ impl Client<'_> {
             .map(|messages| self.client.execute(&ExecuteMsg::VerifyMessages(messages)))
     }
 
+    pub fn verify_messages_observed_at(
+        &self,
+        messages: Vec<Message>,
+        observed_at: ObservedBlock,
+    ) -> Option<CosmosMsg> {
+        messages.to_none_if_empty().map(|messages| {
+            self.client.execute(&ExecuteMsg::VerifyMessagesObservedAt {
+                messages,
+                observed_at,
+            })
+        })
+    }
+
     pub fn vote(&self, poll_id: PollId, votes: Vec<Vote>) -> CosmosMsg {
         self.client.execute(&ExecuteMsg::Vote { poll_id, votes })
     }
@@ -69,12 +134,86 @@ impl Client<'_> {
         })
     }
 
+    pub fn verify_messages_not_executed(
+        &self,
+        messages: Vec<Message>,
+        up_to_height: u64,
+    ) -> Option<CosmosMsg> {
+        messages.to_none_if_empty().map(|messages| {
+            self.client.execute(&ExecuteMsg::VerifyMessagesNotExecuted {
+                messages,
+                up_to_height,
+            })
+        })
+    }
+
     pub fn update_voting_threshold(&self, new_voting_threshold: MajorityThreshold) -> CosmosMsg {
         self.client.execute(&ExecuteMsg::UpdateVotingThreshold {
             new_voting_threshold,
         })
     }
 
+    pub fn cancel_poll(
+        &self,
+        poll_id: PollId,
+        reason: bounded::String<MAX_REASON_LENGTH>,
+    ) -> CosmosMsg {
+        self.client
+            .execute(&ExecuteMsg::CancelPoll { poll_id, reason })
+    }
+
+    pub fn update_verification_fee(&self, verification_fee: Option<VerificationFee>) -> CosmosMsg {
+        self.client
+            .execute(&ExecuteMsg::UpdateVerificationFee { verification_fee })
+    }
+
+    pub fn update_vote_weight_decay(
+        &self,
+        vote_weight_decay: Option<VoteWeightDecay>,
+    ) -> CosmosMsg {
+        self.client
+            .execute(&ExecuteMsg::UpdateVoteWeightDecay { vote_weight_decay })
+    }
+
+    pub fn update_dynamic_voting_threshold(
+        &self,
+        dynamic_voting_threshold: Option<DynamicVotingThreshold>,
+    ) -> CosmosMsg {
+        self.client
+            .execute(&ExecuteMsg::UpdateDynamicVotingThreshold {
+                dynamic_voting_threshold,
+            })
+    }
+
+    pub fn update_poll_retry(&self, poll_retry: Option<PollRetry>) -> CosmosMsg {
+        self.client
+            .execute(&ExecuteMsg::UpdatePollRetry { poll_retry })
+    }
+
+    pub fn update_poll_time_expiry(&self, poll_time_expiry: Option<Duration>) -> CosmosMsg {
+        self.client
+            .execute(&ExecuteMsg::UpdatePollTimeExpiry { poll_time_expiry })
+    }
+
+    pub fn update_late_vote_grace_blocks(&self, late_vote_grace_blocks: Option<u64>) -> CosmosMsg {
+        self.client.execute(&ExecuteMsg::UpdateLateVoteGraceBlocks {
+            late_vote_grace_blocks,
+        })
+    }
+
+    pub fn halt_source_chain(&self, blocks: nonempty::Uint64) -> CosmosMsg {
+        self.client.execute(&ExecuteMsg::HaltSourceChain { blocks })
+    }
+
+    pub fn extend_polls(&self, limit: u32) -> CosmosMsg {
+        self.client.execute(&ExecuteMsg::ExtendPolls { limit })
+    }
+
+    pub fn transfer_fees_to_rewards(&self, denom: nonempty::String) -> CosmosMsg {
+        self.client
+            .execute(&ExecuteMsg::TransferFeesToRewards { denom })
+    }
+
     pub fn poll(&self, poll_id: PollId) -> Result<PollResponse> {
         let msg = QueryMsg::Poll { poll_id };
         self.client.query(&msg).change_context_lazy(|| msg.into())
@@ -90,6 +229,23 @@ impl Client<'_> {
         }
     }
 
+    pub fn messages_not_executed_status(
+        &self,
+        messages: Vec<Message>,
+        up_to_height: u64,
+    ) -> Result<Vec<MessageStatus>> {
+        match messages.as_slice() {
+            [] => Ok(vec![]),
+            _ => {
+                let msg = QueryMsg::MessagesNotExecutedStatus {
+                    messages,
+                    up_to_height,
+                };
+                self.client.query(&msg).change_context_lazy(|| msg.into())
+            }
+        }
+    }
+
     pub fn verifier_set_status(&self, new_verifier_set: VerifierSet) -> Result<VerificationStatus> {
         let msg = QueryMsg::VerifierSetStatus(new_verifier_set);
         self.client.query(&msg).change_context_lazy(|| msg.into())
@@ -99,6 +255,66 @@ impl Client<'_> {
         let msg = QueryMsg::CurrentThreshold;
         self.client.query(&msg).change_context_lazy(|| msg.into())
     }
+
+    pub fn verification_fee(&self) -> Result<Option<VerificationFee>> {
+        let msg = QueryMsg::VerificationFee;
+        self.client.query(&msg).change_context_lazy(|| msg.into())
+    }
+
+    pub fn accrued_fees(&self) -> Result<Vec<Coin>> {
+        let msg = QueryMsg::AccruedFees;
+        self.client.query(&msg).change_context_lazy(|| msg.into())
+    }
+
+    pub fn vote_weight_decay(&self) -> Result<Option<VoteWeightDecay>> {
+        let msg = QueryMsg::VoteWeightDecay;
+        self.client.query(&msg).change_context_lazy(|| msg.into())
+    }
+
+    pub fn dynamic_voting_threshold(&self) -> Result<Option<DynamicVotingThreshold>> {
+        let msg = QueryMsg::DynamicVotingThreshold;
+        self.client.query(&msg).change_context_lazy(|| msg.into())
+    }
+
+    pub fn poll_retry(&self) -> Result<Option<PollRetry>> {
+        let msg = QueryMsg::PollRetry;
+        self.client.query(&msg).change_context_lazy(|| msg.into())
+    }
+
+    pub fn poll_time_expiry(&self) -> Result<Option<Duration>> {
+        let msg = QueryMsg::PollTimeExpiry;
+        self.client.query(&msg).change_context_lazy(|| msg.into())
+    }
+
+    pub fn source_chain_halt(&self) -> Result<Option<SourceChainHalt>> {
+        let msg = QueryMsg::SourceChainHalt;
+        self.client.query(&msg).change_context_lazy(|| msg.into())
+    }
+
+    pub fn late_vote_grace_blocks(&self) -> Result<Option<u64>> {
+        let msg = QueryMsg::LateVoteGraceBlocks;
+        self.client.query(&msg).change_context_lazy(|| msg.into())
+    }
+
+    pub fn vote_latencies(&self, poll_id: PollId) -> Result<Vec<VoteLatency>> {
+        let msg = QueryMsg::VoteLatencies { poll_id };
+        self.client.query(&msg).change_context_lazy(|| msg.into())
+    }
+
+    pub fn message_retries(&self, message: Message) -> Result<u64> {
+        let msg = QueryMsg::MessageRetries(message);
+        self.client.query(&msg).change_context_lazy(|| msg.into())
+    }
+
+    pub fn missed_polls(&self, verifier_address: String) -> Result<u64> {
+        let msg = QueryMsg::MissedPolls { verifier_address };
+        self.client.query(&msg).change_context_lazy(|| msg.into())
+    }
+
+    pub fn has_open_polls(&self, verifier_address: String) -> Result<bool> {
+        let msg = QueryMsg::HasOpenPolls { verifier_address };
+        self.client.query(&msg).change_context_lazy(|| msg.into())
+    }
 }
 
 #[cfg(test)]
@@ -115,7 +331,9 @@ mod test {
     use router_api::{CrossChainId, Message};
 
     use crate::contract::{instantiate, query};
-    use crate::msg::{InstantiateMsg, MessageStatus, QueryMsg};
+    use crate::msg::{
+        DynamicVotingThreshold, InstantiateMsg, MessageStatus, PollRetry, QueryMsg, VoteWeightDecay,
+    };
     use crate::Client;
 
     #[test]
@@ -180,7 +398,8 @@ mod test {
                 .verifier_set_status(VerifierSet {
                     signers: BTreeMap::new(),
                     threshold: Uint128::one(),
-                    created_at: 0
+                    created_at: 0,
+                    min_signers: None
                 })
                 .unwrap(),
             VerificationStatus::Unknown
@@ -199,6 +418,94 @@ mod test {
         );
     }
 
+    #[test]
+    fn query_verification_fee() {
+        let (querier, instantiate_msg, addr) = setup();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+
+        assert_eq!(
+            client.verification_fee().unwrap(),
+            instantiate_msg.verification_fee
+        );
+    }
+
+    #[test]
+    fn query_accrued_fees() {
+        let (querier, _, addr) = setup();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+
+        assert_eq!(client.accrued_fees().unwrap(), vec![]);
+    }
+
+    #[test]
+    fn query_vote_weight_decay() {
+        let (querier, instantiate_msg, addr) = setup();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+
+        assert_eq!(
+            client.vote_weight_decay().unwrap(),
+            instantiate_msg.vote_weight_decay
+        );
+    }
+
+    #[test]
+    fn query_dynamic_voting_threshold() {
+        let (querier, instantiate_msg, addr) = setup();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+
+        assert_eq!(
+            client.dynamic_voting_threshold().unwrap(),
+            instantiate_msg.dynamic_voting_threshold
+        );
+    }
+
+    #[test]
+    fn query_poll_retry() {
+        let (querier, instantiate_msg, addr) = setup();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+
+        assert_eq!(client.poll_retry().unwrap(), instantiate_msg.poll_retry);
+    }
+
+    #[test]
+    fn query_late_vote_grace_blocks() {
+        let (querier, _, addr) = setup();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+
+        assert_eq!(client.late_vote_grace_blocks().unwrap(), None);
+    }
+
+    #[test]
+    fn query_missed_polls() {
+        let (querier, _, addr) = setup();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+
+        assert_eq!(
+            client
+                .missed_polls(MockApi::default().addr_make("verifier").to_string())
+                .unwrap(),
+            0
+        );
+    }
+
+    #[test]
+    fn query_has_open_polls() {
+        let (querier, _, addr) = setup();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+
+        assert!(!client
+            .has_open_polls(MockApi::default().addr_make("verifier").to_string())
+            .unwrap());
+    }
+
     #[test]
     fn query_verifier_set_returns_error_when_query_fails() {
         let (querier, addr) = setup_queries_to_fail();
@@ -208,6 +515,7 @@ mod test {
             signers: BTreeMap::new(),
             threshold: Uint128::one(),
             created_at: 0,
+            min_signers: None,
         });
 
         assert!(res.is_err());
@@ -240,6 +548,35 @@ mod test {
         goldie::assert!(res.unwrap_err().to_string());
     }
 
+    #[test]
+    fn query_messages_not_executed_status_returns_error_when_query_fails() {
+        let (querier, addr) = setup_queries_to_fail();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+        let res = client.messages_not_executed_status(
+            vec![Message {
+                cc_id: CrossChainId::new(
+                    "eth",
+                    HexTxHashAndEventIndex {
+                        tx_hash: [0; 32],
+                        event_index: 0,
+                    }
+                    .to_string()
+                    .as_str(),
+                )
+                .unwrap(),
+                source_address: "0x1234".parse().unwrap(),
+                destination_address: "0x5678".parse().unwrap(),
+                destination_chain: "eth".parse().unwrap(),
+                payload_hash: [0; 32],
+            }],
+            100,
+        );
+
+        assert!(res.is_err());
+        goldie::assert!(res.unwrap_err().to_string());
+    }
+
     #[test]
     fn query_poll_returns_error_when_query_fails() {
         let (querier, addr) = setup_queries_to_fail();
@@ -262,6 +599,105 @@ mod test {
         goldie::assert!(res.unwrap_err().to_string());
     }
 
+    #[test]
+    fn query_verification_fee_returns_error_when_query_fails() {
+        let (querier, addr) = setup_queries_to_fail();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+        let res = client.verification_fee();
+
+        assert!(res.is_err());
+        goldie::assert!(res.unwrap_err().to_string());
+    }
+
+    #[test]
+    fn query_accrued_fees_returns_error_when_query_fails() {
+        let (querier, addr) = setup_queries_to_fail();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+        let res = client.accrued_fees();
+
+        assert!(res.is_err());
+        goldie::assert!(res.unwrap_err().to_string());
+    }
+
+    #[test]
+    fn query_vote_weight_decay_returns_error_when_query_fails() {
+        let (querier, addr) = setup_queries_to_fail();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+        let res = client.vote_weight_decay();
+
+        assert!(res.is_err());
+        goldie::assert!(res.unwrap_err().to_string());
+    }
+
+    #[test]
+    fn query_dynamic_voting_threshold_returns_error_when_query_fails() {
+        let (querier, addr) = setup_queries_to_fail();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+        let res = client.dynamic_voting_threshold();
+
+        assert!(res.is_err());
+        goldie::assert!(res.unwrap_err().to_string());
+    }
+
+    #[test]
+    fn query_poll_retry_returns_error_when_query_fails() {
+        let (querier, addr) = setup_queries_to_fail();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+        let res = client.poll_retry();
+
+        assert!(res.is_err());
+        goldie::assert!(res.unwrap_err().to_string());
+    }
+
+    #[test]
+    fn query_late_vote_grace_blocks_returns_error_when_query_fails() {
+        let (querier, addr) = setup_queries_to_fail();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+        let res = client.late_vote_grace_blocks();
+
+        assert!(res.is_err());
+        goldie::assert!(res.unwrap_err().to_string());
+    }
+
+    #[test]
+    fn query_vote_latencies_returns_error_when_query_fails() {
+        let (querier, addr) = setup_queries_to_fail();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+        let res = client.vote_latencies(1u64.into());
+
+        assert!(res.is_err());
+        goldie::assert!(res.unwrap_err().to_string());
+    }
+
+    #[test]
+    fn query_missed_polls_returns_error_when_query_fails() {
+        let (querier, addr) = setup_queries_to_fail();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+        let res = client.missed_polls("verifier".to_string());
+
+        assert!(res.is_err());
+        goldie::assert!(res.unwrap_err().to_string());
+    }
+
+    #[test]
+    fn query_has_open_polls_returns_error_when_query_fails() {
+        let (querier, addr) = setup_queries_to_fail();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+        let res = client.has_open_polls("verifier".to_string());
+
+        assert!(res.is_err());
+        goldie::assert!(res.unwrap_err().to_string());
+    }
+
     fn setup_queries_to_fail() -> (MockQuerier, Addr) {
         let deps = mock_dependencies();
         let api: MockApi = deps.api;
@@ -327,6 +763,19 @@ mod test {
             rewards_address: api.addr_make("rewards").to_string().try_into().unwrap(),
             msg_id_format: axelar_wasm_std::msg_id::MessageIdFormat::HexTxHashAndEventIndex,
             address_format: axelar_wasm_std::address::AddressFormat::Eip55,
+            verification_fee: None,
+            vote_weight_decay: Some(VoteWeightDecay {
+                missed_polls_threshold: 3u64.try_into().unwrap(),
+                decayed_weight: Threshold::try_from((1, 2)).unwrap(),
+            }),
+            dynamic_voting_threshold: Some(DynamicVotingThreshold {
+                missed_polls_threshold: 5u64.try_into().unwrap(),
+                floor: Threshold::try_from((3, 5)).unwrap().try_into().unwrap(),
+            }),
+            poll_retry: Some(PollRetry {
+                max_retries: 2u64.try_into().unwrap(),
+            }),
+            coordinator_address: api.addr_make("coordinator").to_string().try_into().unwrap(),
         };
 
         instantiate(deps, env, info.clone(), msg.clone()).unwrap();
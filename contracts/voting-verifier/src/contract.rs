@@ -3,12 +3,13 @@ use axelar_wasm_std::{address, permission_control, FnExt};
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    to_json_binary, Attribute, Binary, Deps, DepsMut, Env, Event, MessageInfo, Response,
+    to_json_binary, Addr, Api, Attribute, Binary, Deps, DepsMut, Env, Event, MessageInfo, Response,
+    Storage,
 };
 use error_stack::ResultExt;
 
 use crate::error::ContractError;
-use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
+use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg, VerificationFee};
 use crate::state::{Config, CONFIG};
 
 mod execute;
@@ -35,13 +36,15 @@ pub fn instantiate(
     validate_address(&msg.source_gateway_address, &msg.address_format)
         .change_context(ContractError::InvalidSourceGatewayAddress)?;
 
+    validate_verification_fee(deps.api, msg.verification_fee.as_ref())?;
+
     let config = Config {
         service_name: msg.service_name,
         service_registry_contract: address::validate_cosmwasm_address(
             deps.api,
             &msg.service_registry_address,
         )?,
-        source_gateway_address: msg.source_gateway_address,
+        source_gateway_address: msg.source_gateway_address.into(),
         voting_threshold: msg.voting_threshold,
         block_expiry: msg.block_expiry,
         confirmation_height: msg.confirmation_height,
@@ -49,6 +52,13 @@ pub fn instantiate(
         rewards_contract: address::validate_cosmwasm_address(deps.api, &msg.rewards_address)?,
         msg_id_format: msg.msg_id_format,
         address_format: msg.address_format,
+        verification_fee: msg.verification_fee,
+        vote_weight_decay: msg.vote_weight_decay,
+        dynamic_voting_threshold: msg.dynamic_voting_threshold,
+        poll_retry: msg.poll_retry,
+        poll_time_expiry: None,
+        late_vote_grace_blocks: None,
+        coordinator: address::validate_cosmwasm_address(deps.api, &msg.coordinator_address)?,
     };
     CONFIG.save(deps.storage, &config)?;
 
@@ -63,8 +73,20 @@ pub fn execute(
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, axelar_wasm_std::error::ContractError> {
-    match msg.ensure_permissions(deps.storage, &info.sender)? {
-        ExecuteMsg::VerifyMessages(messages) => Ok(execute::verify_messages(deps, env, messages)?),
+    match msg.ensure_permissions(deps.storage, &info.sender, match_coordinator)? {
+        ExecuteMsg::VerifyMessages(messages) => {
+            Ok(execute::verify_messages(deps, env, info, messages, None)?)
+        }
+        ExecuteMsg::VerifyMessagesObservedAt {
+            messages,
+            observed_at,
+        } => Ok(execute::verify_messages(
+            deps,
+            env,
+            info,
+            messages,
+            Some(observed_at),
+        )?),
         ExecuteMsg::Vote { poll_id, votes } => Ok(execute::vote(deps, env, info, poll_id, votes)?),
         ExecuteMsg::EndPoll { poll_id } => Ok(execute::end_poll(deps, env, poll_id)?),
         ExecuteMsg::VerifyVerifierSet {
@@ -76,15 +98,83 @@ pub fn execute(
             message_id,
             new_verifier_set,
         )?),
+        ExecuteMsg::VerifyMessagesNotExecuted {
+            messages,
+            up_to_height,
+        } => Ok(execute::verify_messages_not_executed(
+            deps,
+            env,
+            messages,
+            up_to_height,
+        )?),
         ExecuteMsg::UpdateVotingThreshold {
             new_voting_threshold,
         } => Ok(execute::update_voting_threshold(
             deps,
             new_voting_threshold,
         )?),
+        ExecuteMsg::CancelPoll { poll_id, reason } => {
+            Ok(execute::cancel_poll(deps, env, poll_id, reason.into())?)
+        }
+        ExecuteMsg::UpdateVerificationFee { verification_fee } => {
+            validate_verification_fee(deps.api, verification_fee.as_ref())?;
+            Ok(execute::update_verification_fee(deps, verification_fee)?)
+        }
+        ExecuteMsg::UpdateVoteWeightDecay { vote_weight_decay } => {
+            Ok(execute::update_vote_weight_decay(deps, vote_weight_decay)?)
+        }
+        ExecuteMsg::UpdateDynamicVotingThreshold {
+            dynamic_voting_threshold,
+        } => Ok(execute::update_dynamic_voting_threshold(
+            deps,
+            dynamic_voting_threshold,
+        )?),
+        ExecuteMsg::UpdatePollRetry { poll_retry } => {
+            Ok(execute::update_poll_retry(deps, poll_retry)?)
+        }
+        ExecuteMsg::UpdatePollTimeExpiry { poll_time_expiry } => {
+            Ok(execute::update_poll_time_expiry(deps, poll_time_expiry)?)
+        }
+        ExecuteMsg::UpdateLateVoteGraceBlocks {
+            late_vote_grace_blocks,
+        } => Ok(execute::update_late_vote_grace_blocks(
+            deps,
+            late_vote_grace_blocks,
+        )?),
+        ExecuteMsg::HaltSourceChain { blocks } => {
+            Ok(execute::halt_source_chain(deps, env, blocks)?)
+        }
+        ExecuteMsg::ExtendPolls { limit } => Ok(execute::extend_polls(deps, limit)?),
+        ExecuteMsg::TransferFeesToRewards { denom } => {
+            Ok(execute::transfer_fees_to_rewards(deps, env, denom)?)
+        }
+        ExecuteMsg::SetMaintenanceWindows { windows } => {
+            Ok(execute::set_maintenance_windows(deps, env, windows)?)
+        }
     }
 }
 
+/// Exempt callers are compared to the sender as is, so they must be valid addresses in their canonical form
+fn validate_verification_fee(
+    api: &dyn Api,
+    verification_fee: Option<&VerificationFee>,
+) -> error_stack::Result<(), address::Error> {
+    verification_fee
+        .into_iter()
+        .flat_map(|fee| fee.exempt_callers.iter())
+        .try_for_each(|caller| address::validate_cosmwasm_address(api, caller).map(|_| ()))
+}
+
+fn match_coordinator(
+    storage: &dyn Storage,
+    _: &ExecuteMsg,
+) -> error_stack::Result<Addr, permission_control::Error> {
+    Ok(CONFIG
+        .load(storage)
+        .change_context(permission_control::Error::Unauthorized)?
+        .coordinator)
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(
     deps: Deps,
@@ -93,15 +183,48 @@ pub fn query(
 ) -> Result<Binary, axelar_wasm_std::error::ContractError> {
     match msg {
         QueryMsg::Poll { poll_id } => {
-            to_json_binary(&query::poll_response(deps, env.block.height, poll_id)?)
+            to_json_binary(&query::poll_response(deps, &env.block, poll_id)?)
         }
         QueryMsg::MessagesStatus(messages) => {
-            to_json_binary(&query::messages_status(deps, &messages, env.block.height)?)
+            to_json_binary(&query::messages_status(deps, &messages, &env.block)?)
         }
         QueryMsg::VerifierSetStatus(new_verifier_set) => to_json_binary(
-            &query::verifier_set_status(deps, &new_verifier_set, env.block.height)?,
+            &query::verifier_set_status(deps, &new_verifier_set, &env.block)?,
         ),
+        QueryMsg::MessagesNotExecutedStatus {
+            messages,
+            up_to_height,
+        } => to_json_binary(&query::messages_not_executed_status(
+            deps,
+            &messages,
+            up_to_height,
+            &env.block,
+        )?),
         QueryMsg::CurrentThreshold => to_json_binary(&query::voting_threshold(deps)?),
+        QueryMsg::VerificationFee => to_json_binary(&query::verification_fee(deps)?),
+        QueryMsg::AccruedFees => to_json_binary(&query::accrued_fees(deps)?),
+        QueryMsg::VoteWeightDecay => to_json_binary(&query::vote_weight_decay(deps)?),
+        QueryMsg::DynamicVotingThreshold => to_json_binary(&query::dynamic_voting_threshold(deps)?),
+        QueryMsg::PollRetry => to_json_binary(&query::poll_retry(deps)?),
+        QueryMsg::PollTimeExpiry => to_json_binary(&query::poll_time_expiry(deps)?),
+        QueryMsg::LateVoteGraceBlocks => to_json_binary(&query::late_vote_grace_blocks(deps)?),
+        QueryMsg::SourceChainHalt => to_json_binary(&query::source_chain_halt(deps, &env.block)?),
+        QueryMsg::PollExtensionsPending => to_json_binary(&query::poll_extensions_pending(deps)?),
+        QueryMsg::VoteLatencies { poll_id } => {
+            to_json_binary(&query::vote_latencies(deps, poll_id)?)
+        }
+        QueryMsg::MessageRetries(message) => {
+            to_json_binary(&query::message_retries(deps, &message)?)
+        }
+        QueryMsg::MissedPolls { verifier_address } => to_json_binary(&query::missed_polls(
+            deps,
+            address::validate_cosmwasm_address(deps.api, &verifier_address)?,
+        )?),
+        QueryMsg::HasOpenPolls { verifier_address } => to_json_binary(&query::has_open_polls(
+            deps,
+            &env.block,
+            address::validate_cosmwasm_address(deps.api, &verifier_address)?,
+        )?),
     }?
     .then(Ok)
 }
@@ -110,33 +233,45 @@ pub fn query(
 mod test {
     use assert_ok::assert_ok;
     use axelar_wasm_std::address::AddressFormat;
+    use axelar_wasm_std::maintenance::MaintenanceWindow;
     use axelar_wasm_std::msg_id::{
         Base58SolanaTxSignatureAndEventIndex, Base58TxDigestAndEventIndex,
         FieldElementAndEventIndex, HexTxHash, HexTxHashAndEventIndex, MessageIdFormat,
     };
-    use axelar_wasm_std::voting::Vote;
+    use axelar_wasm_std::time::Duration;
+    use axelar_wasm_std::voting::{self, Vote, VoteLatency};
     use axelar_wasm_std::{
-        assert_err_contains, err_contains, nonempty, MajorityThreshold, Threshold,
+        assert_err_contains, canonical_json, err_contains, nonempty, MajorityThreshold, Threshold,
         VerificationStatus,
     };
     use bech32::{Bech32m, Hrp};
     use cosmwasm_std::testing::{
         message_info, mock_dependencies, mock_env, MockApi, MockQuerier, MockStorage,
     };
-    use cosmwasm_std::{from_json, Empty, Fraction, OwnedDeps, Uint128, Uint64, WasmQuery};
+    use cosmwasm_std::{
+        coin, coins, from_json, Addr, BankMsg, Coin, CosmosMsg, Empty, Fraction, HexBinary,
+        OwnedDeps, SystemError, SystemResult, Uint128, Uint64, WasmMsg, WasmQuery,
+    };
+    use itertools::Itertools;
     use multisig::key::KeyType;
     use multisig::test::common::{build_verifier_set, ecdsa_test_data};
     use router_api::{ChainName, CrossChainId, Message};
     use service_registry::{
         AuthorizationState, BondingState, Verifier, WeightedVerifier, VERIFIER_WEIGHT,
     };
+    use service_registry_api::msg::{
+        ActiveVerifiersPageResponse, QueryMsg as ServiceRegistryQueryMsg,
+    };
     use sha3::{Digest, Keccak256, Keccak512};
     use starknet_checked_felt::CheckedFelt;
 
     use super::*;
     use crate::error::ContractError;
     use crate::events::TxEventConfirmation;
-    use crate::msg::MessageStatus;
+    use crate::msg::{
+        DynamicVotingThreshold, MessageStatus, ObservedBlock, PollResponse, PollRetry,
+        SourceChainHalt, VerificationFee, VoteWeightDecay,
+    };
 
     const SENDER: &str = "sender";
     const SERVICE_REGISTRY_ADDRESS: &str = "service_registry_address";
@@ -144,6 +279,7 @@ mod test {
     const SERVICE_NAME: &str = "service_name";
     const POLL_BLOCK_EXPIRY: u64 = 100;
     const GOVERNANCE: &str = "governance";
+    const COORDINATOR: &str = "coordinator";
 
     fn source_chain() -> ChainName {
         "source-chain".parse().unwrap()
@@ -201,26 +337,52 @@ mod test {
                 rewards_address: api.addr_make(REWARDS_ADDRESS).as_str().parse().unwrap(),
                 msg_id_format: msg_id_format.clone(),
                 address_format: AddressFormat::Eip55,
+                verification_fee: None,
+                vote_weight_decay: None,
+                dynamic_voting_threshold: None,
+                poll_retry: None,
+                coordinator_address: api.addr_make(COORDINATOR).as_str().parse().unwrap(),
             },
         )
         .unwrap();
 
         deps.querier.update_wasm(move |wq| match wq {
-            WasmQuery::Smart { contract_addr, .. }
+            WasmQuery::Smart { contract_addr, msg }
                 if contract_addr == service_registry.as_str() =>
             {
-                Ok(to_json_binary(
-                    &verifiers
-                        .clone()
-                        .into_iter()
-                        .map(|v| WeightedVerifier {
-                            verifier_info: v,
-                            weight: VERIFIER_WEIGHT,
+                match from_json(msg).unwrap() {
+                    ServiceRegistryQueryMsg::Service { service_name } => {
+                        Ok(to_json_binary(&service_registry_api::Service {
+                            name: service_name,
+                            coordinator_contract: api.addr_make(COORDINATOR),
+                            min_num_verifiers: 1,
+                            max_num_verifiers: None,
+                            min_verifier_bond: Uint128::one().try_into().unwrap(),
+                            bond_denom: "uaxl".to_string(),
+                            unbonding_period_days: 1,
+                            description: "verifiers".to_string(),
+                            exit_handover_blocks: 0,
                         })
-                        .collect::<Vec<WeightedVerifier>>(),
-                )
-                .into())
-                .into()
+                        .into())
+                        .into()
+                    }
+                    ServiceRegistryQueryMsg::ActiveVerifiersPage { .. } => {
+                        Ok(to_json_binary(&ActiveVerifiersPageResponse {
+                            verifiers: verifiers
+                                .clone()
+                                .into_iter()
+                                .map(|v| WeightedVerifier {
+                                    verifier_info: v,
+                                    weight: VERIFIER_WEIGHT,
+                                })
+                                .collect(),
+                            next_start_after: None,
+                        })
+                        .into())
+                        .into()
+                    }
+                    msg => panic!("no mock for query {:?}", msg),
+                }
             }
             _ => panic!("no mock for this query"),
         });
@@ -441,6 +603,11 @@ mod test {
                     rewards_address: api.addr_make(REWARDS_ADDRESS).as_str().parse().unwrap(),
                     msg_id_format: MessageIdFormat::HexTxHashAndEventIndex,
                     address_format,
+                    verification_fee: None,
+                    vote_weight_decay: None,
+                    dynamic_voting_threshold: None,
+                    poll_retry: None,
+                    coordinator_address: api.addr_make(COORDINATOR).as_str().parse().unwrap(),
                 },
             );
 
@@ -650,6 +817,63 @@ mod test {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn poll_started_event_should_include_observed_block_if_supplied() {
+        let msg_id_format = MessageIdFormat::HexTxHashAndEventIndex;
+        let mut deps = setup(verifiers(2), &msg_id_format);
+        let api = deps.api;
+        let messages = messages(4, &msg_id_format);
+        let observed_at = ObservedBlock {
+            height: 1000,
+            hash: Some("0xabcd".parse().unwrap()),
+        };
+
+        let poll_started_attribute = |res: &Response, key: &str| {
+            res.events
+                .iter()
+                .find(|event| event.ty == "messages_poll_started")
+                .unwrap()
+                .attributes
+                .iter()
+                .find(|attribute| attribute.key == key)
+                .map(|attribute| attribute.value.clone())
+        };
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(SENDER), &[]),
+            ExecuteMsg::VerifyMessagesObservedAt {
+                messages: messages[0..2].to_vec(),
+                observed_at: observed_at.clone(),
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            poll_started_attribute(&res, "observed_at")
+                .map(|value| serde_json::from_str(&value).unwrap()),
+            Some(observed_at)
+        );
+        // the observed block reaches the configured confirmation height of 100 blocks at height 1100
+        assert_eq!(
+            poll_started_attribute(&res, "observed_at_confirmation_height"),
+            Some("1100".to_string())
+        );
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(SENDER), &[]),
+            ExecuteMsg::VerifyMessages(messages[2..4].to_vec()),
+        )
+        .unwrap();
+        assert_eq!(poll_started_attribute(&res, "observed_at"), None);
+        assert_eq!(
+            poll_started_attribute(&res, "observed_at_confirmation_height"),
+            None
+        );
+    }
+
     #[test]
     fn should_retry_if_message_not_verified() {
         let msg_id_format = MessageIdFormat::HexTxHashAndEventIndex;
@@ -1335,197 +1559,1855 @@ mod test {
     }
 
     #[test]
-    fn threshold_changes_should_not_affect_existing_polls() {
-        let verifiers = verifiers(10);
-        let initial_threshold = initial_voting_threshold();
-        let majority = (verifiers.len() as u64 * initial_threshold.numerator().u64())
-            .div_ceil(initial_threshold.denominator().u64());
-
+    fn poll_should_commit_to_its_content() {
         let msg_id_format = MessageIdFormat::HexTxHashAndEventIndex;
+        let verifiers = verifiers(2);
         let mut deps = setup(verifiers.clone(), &msg_id_format);
         let api = deps.api;
 
-        let messages = messages(1, &msg_id_format);
-
-        execute(
+        let res = execute(
             deps.as_mut(),
             mock_env(),
             message_info(&api.addr_make(SENDER), &[]),
-            ExecuteMsg::VerifyMessages(messages.clone()),
+            ExecuteMsg::VerifyMessages(messages(2, &msg_id_format)),
         )
         .unwrap();
 
-        // simulate a majority of verifiers voting for succeeded on chain
-        verifiers.iter().enumerate().for_each(|(i, verifier)| {
-            if i as u64 >= majority {
-                return;
-            }
-            let msg = ExecuteMsg::Vote {
-                poll_id: 1u64.into(),
-                votes: vec![Vote::SucceededOnChain],
-            };
+        let event = res
+            .events
+            .iter()
+            .find(|event| event.ty == "messages_poll_started")
+            .unwrap();
+        let emitted_hash = event
+            .attributes
+            .iter()
+            .find(|attribute| attribute.key == "content_hash")
+            .map(|attribute| attribute.value.clone())
+            .unwrap();
 
-            let res = execute(
-                deps.as_mut(),
+        // the commitment can be recomputed from the other attributes of the event
+        let attributes: Vec<_> = event
+            .attributes
+            .iter()
+            .filter(|attribute| attribute.key != "content_hash")
+            .map(|attribute| (attribute.key.clone(), attribute.value.clone()))
+            .collect();
+        let recomputed_hash = HexBinary::from(
+            Keccak256::digest(canonical_json::to_string(&(&event.ty, attributes)).unwrap())
+                .to_vec(),
+        );
+        assert_eq!(emitted_hash, recomputed_hash.to_hex());
+
+        let poll: PollResponse = from_json(
+            query(
+                deps.as_ref(),
                 mock_env(),
-                message_info(&verifier.address, &[]),
-                msg,
-            );
-            assert!(res.is_ok());
-        });
+                QueryMsg::Poll {
+                    poll_id: Uint64::one().into(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(poll.content_hash, Some(recomputed_hash));
+    }
 
-        // increase the threshold. Not enough verifiers voted to meet the new majority,
-        // but threshold changes should not affect existing polls
-        let new_voting_threshold: MajorityThreshold =
-            Threshold::try_from((majority + 1, verifiers.len() as u64))
-                .unwrap()
-                .try_into()
-                .unwrap();
+    fn verification_fee() -> VerificationFee {
+        VerificationFee {
+            denom: "uaxl".parse().unwrap(),
+            amount: Uint128::new(10).try_into().unwrap(),
+            exempt_callers: vec![],
+        }
+    }
+
+    fn setup_with_verification_fee(
+        msg_id_format: &MessageIdFormat,
+    ) -> OwnedDeps<MockStorage, MockApi, MockQuerier, Empty> {
+        let mut deps = setup(verifiers(2), msg_id_format);
+        let api = deps.api;
 
         execute(
             deps.as_mut(),
             mock_env(),
             message_info(&api.addr_make(GOVERNANCE), &[]),
-            ExecuteMsg::UpdateVotingThreshold {
-                new_voting_threshold,
+            ExecuteMsg::UpdateVerificationFee {
+                verification_fee: Some(verification_fee()),
             },
         )
         .unwrap();
 
-        execute(
-            deps.as_mut(),
-            mock_env_expired(),
-            message_info(&api.addr_make(SENDER), &[]),
-            ExecuteMsg::EndPoll {
-                poll_id: 1u64.into(),
-            },
-        )
-        .unwrap();
+        deps
+    }
 
-        let res: Vec<MessageStatus> = from_json(
+    fn poll_weights(deps: Deps, poll_id: u64, verifiers: &[Verifier]) -> Vec<Uint128> {
+        let res: PollResponse = from_json(
             query(
-                deps.as_ref(),
+                deps,
                 mock_env(),
-                QueryMsg::MessagesStatus(messages.clone()),
+                QueryMsg::Poll {
+                    poll_id: Uint64::from(poll_id).into(),
+                },
             )
             .unwrap(),
         )
         .unwrap();
-        assert_eq!(
-            res,
-            vec![MessageStatus::new(
-                messages[0].clone(),
-                VerificationStatus::SucceededOnSourceChain
-            )]
-        );
+
+        verifiers
+            .iter()
+            .map(|verifier| {
+                res.poll.participation[verifier.address.as_str()]
+                    .weight
+                    .into()
+            })
+            .collect()
     }
 
-    #[test]
-    fn threshold_changes_should_affect_new_polls() {
-        let verifiers = verifiers(10);
-        let initial_threshold = initial_voting_threshold();
-        let old_majority = (verifiers.len() as u64 * initial_threshold.numerator().u64())
-            .div_ceil(initial_threshold.denominator().u64());
+    fn has_open_polls(deps: Deps, env: Env, verifier: &Addr) -> bool {
+        from_json(
+            query(
+                deps,
+                env,
+                QueryMsg::HasOpenPolls {
+                    verifier_address: verifier.to_string(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap()
+    }
 
+    #[test]
+    fn verifiers_should_have_open_polls_until_they_voted_or_the_poll_expired() {
         let msg_id_format = MessageIdFormat::HexTxHashAndEventIndex;
+        let verifiers = verifiers(2);
         let mut deps = setup(verifiers.clone(), &msg_id_format);
         let api = deps.api;
 
-        // increase the threshold prior to starting a poll
-        let new_voting_threshold: MajorityThreshold =
-            Threshold::try_from((old_majority + 1, verifiers.len() as u64))
-                .unwrap()
-                .try_into()
-                .unwrap();
-
         execute(
             deps.as_mut(),
             mock_env(),
-            message_info(&api.addr_make(GOVERNANCE), &[]),
-            ExecuteMsg::UpdateVotingThreshold {
-                new_voting_threshold,
-            },
+            message_info(&api.addr_make(SENDER), &[]),
+            ExecuteMsg::VerifyMessages(messages(1, &msg_id_format)),
         )
         .unwrap();
 
-        let messages = messages(1, &msg_id_format);
+        assert!(has_open_polls(
+            deps.as_ref(),
+            mock_env(),
+            &verifiers[0].address
+        ));
+        assert!(!has_open_polls(
+            deps.as_ref(),
+            mock_env(),
+            &api.addr_make("not_a_participant")
+        ));
 
-        // start the poll, should just the new threshold
         execute(
             deps.as_mut(),
             mock_env(),
-            message_info(&api.addr_make(SENDER), &[]),
-            ExecuteMsg::VerifyMessages(messages.clone()),
-        )
-        .unwrap();
-
-        // simulate old_majority of verifiers voting succeeded on chain,
-        // which is one less than the updated majority. The messages
-        // should not receive enough votes to be considered verified
-        verifiers.iter().enumerate().for_each(|(i, verifier)| {
-            if i as u64 >= old_majority {
-                return;
-            }
-            let msg = ExecuteMsg::Vote {
-                poll_id: 1u64.into(),
+            message_info(&verifiers[0].address, &[]),
+            ExecuteMsg::Vote {
+                poll_id: Uint64::one().into(),
                 votes: vec![Vote::SucceededOnChain],
-            };
-
-            let res = execute(
-                deps.as_mut(),
-                mock_env(),
-                message_info(&verifier.address, &[]),
-                msg,
-            );
-            assert!(res.is_ok());
-        });
-
-        execute(
-            deps.as_mut(),
-            mock_env_expired(),
-            message_info(&api.addr_make(SENDER), &[]),
-            ExecuteMsg::EndPoll {
-                poll_id: 1u64.into(),
             },
         )
         .unwrap();
 
-        let res: Vec<MessageStatus> = from_json(
+        assert!(!has_open_polls(
+            deps.as_ref(),
+            mock_env(),
+            &verifiers[0].address
+        ));
+        assert!(has_open_polls(
+            deps.as_ref(),
+            mock_env(),
+            &verifiers[1].address
+        ));
+        assert!(!has_open_polls(
+            deps.as_ref(),
+            mock_env_expired(),
+            &verifiers[1].address
+        ));
+    }
+
+    fn missed_polls(deps: Deps, verifier: &Addr) -> u64 {
+        from_json(
             query(
-                deps.as_ref(),
-                mock_env_expired(),
-                QueryMsg::MessagesStatus(messages.clone()),
+                deps,
+                mock_env(),
+                QueryMsg::MissedPolls {
+                    verifier_address: verifier.to_string(),
+                },
             )
             .unwrap(),
         )
-        .unwrap();
-        assert_eq!(
-            res,
-            vec![MessageStatus::new(
-                messages[0].clone(),
-                VerificationStatus::FailedToVerify
-            )]
-        );
+        .unwrap()
     }
 
     #[test]
-    fn should_emit_event_when_verification_succeeds() {
+    fn weight_of_verifiers_that_missed_polls_should_decay_until_they_vote_again() {
         let msg_id_format = MessageIdFormat::HexTxHashAndEventIndex;
         let verifiers = verifiers(3);
         let mut deps = setup(verifiers.clone(), &msg_id_format);
         let api = deps.api;
-        let threshold = initial_voting_threshold();
-        // this test depends on the threshold being 2/3
-        assert_eq!(
-            threshold,
-            Threshold::try_from((2, 3)).unwrap().try_into().unwrap()
-        );
-
-        let messages = messages(3, &msg_id_format);
-
-        // 1. First verification
-
-        let msg_verify = ExecuteMsg::VerifyMessages(messages.clone());
+        let inactive_verifier = verifiers[2].address.clone();
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(GOVERNANCE), &[]),
+            ExecuteMsg::UpdateVoteWeightDecay {
+                vote_weight_decay: Some(VoteWeightDecay {
+                    missed_polls_threshold: 1u64.try_into().unwrap(),
+                    decayed_weight: Threshold::try_from((1, 10)).unwrap(),
+                }),
+            },
+        )
+        .unwrap();
+
+        let messages = messages(3, &msg_id_format);
+        for (poll_id, message) in (1u64..).zip(&messages[..2]) {
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                message_info(&api.addr_make(SENDER), &[]),
+                ExecuteMsg::VerifyMessages(vec![message.clone()]),
+            )
+            .unwrap();
+
+            for verifier in &verifiers[..2] {
+                execute(
+                    deps.as_mut(),
+                    mock_env(),
+                    message_info(&verifier.address, &[]),
+                    ExecuteMsg::Vote {
+                        poll_id: Uint64::from(poll_id).into(),
+                        votes: vec![Vote::SucceededOnChain],
+                    },
+                )
+                .unwrap();
+            }
+
+            execute(
+                deps.as_mut(),
+                mock_env_expired(),
+                message_info(&api.addr_make(SENDER), &[]),
+                ExecuteMsg::EndPoll {
+                    poll_id: Uint64::from(poll_id).into(),
+                },
+            )
+            .unwrap();
+        }
+
+        let full_weight = Uint128::from(VERIFIER_WEIGHT)
+            .checked_mul(10u128.into())
+            .unwrap();
+        let decayed_weight = Uint128::from(VERIFIER_WEIGHT);
+
+        // the first poll was started before the verifier missed any poll
+        assert_eq!(
+            poll_weights(deps.as_ref(), 1, &verifiers),
+            vec![full_weight; 3]
+        );
+        assert_eq!(
+            poll_weights(deps.as_ref(), 2, &verifiers),
+            vec![full_weight, full_weight, decayed_weight]
+        );
+        assert_eq!(missed_polls(deps.as_ref(), &inactive_verifier), 2);
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(SENDER), &[]),
+            ExecuteMsg::VerifyMessages(vec![messages[2].clone()]),
+        )
+        .unwrap();
+        assert_eq!(
+            poll_weights(deps.as_ref(), 3, &verifiers),
+            vec![full_weight, full_weight, decayed_weight]
+        );
+
+        // voting again restores the full weight in new polls
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&inactive_verifier, &[]),
+            ExecuteMsg::Vote {
+                poll_id: Uint64::from(3u64).into(),
+                votes: vec![Vote::SucceededOnChain],
+            },
+        )
+        .unwrap();
+        assert_eq!(missed_polls(deps.as_ref(), &inactive_verifier), 0);
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(SENDER), &[]),
+            ExecuteMsg::VerifyVerifierSet {
+                message_id: message_id("id", 0, &msg_id_format),
+                new_verifier_set: build_verifier_set(KeyType::Ecdsa, &ecdsa_test_data::signers()),
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            poll_weights(deps.as_ref(), 4, &verifiers),
+            vec![full_weight; 3]
+        );
+    }
+
+    #[test]
+    fn voting_threshold_should_scale_with_active_verifiers_down_to_floor() {
+        let msg_id_format = MessageIdFormat::HexTxHashAndEventIndex;
+        let verifiers = verifiers(5);
+        let mut deps = setup(verifiers.clone(), &msg_id_format);
+        let api = deps.api;
+        let floor: MajorityThreshold = Threshold::try_from((13, 25)).unwrap().try_into().unwrap();
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(GOVERNANCE), &[]),
+            ExecuteMsg::UpdateDynamicVotingThreshold {
+                dynamic_voting_threshold: Some(DynamicVotingThreshold {
+                    missed_polls_threshold: 1u64.try_into().unwrap(),
+                    floor,
+                }),
+            },
+        )
+        .unwrap();
+
+        // in each poll one more verifier stops voting
+        let messages = messages(3, &msg_id_format);
+        for (poll_id, message) in (1u64..).zip(&messages[..2]) {
+            let res = execute(
+                deps.as_mut(),
+                mock_env(),
+                message_info(&api.addr_make(SENDER), &[]),
+                ExecuteMsg::VerifyMessages(vec![message.clone()]),
+            )
+            .unwrap();
+            assert!(res.events[0]
+                .attributes
+                .iter()
+                .any(|attribute| attribute.key == "voting_threshold"));
+
+            for verifier in &verifiers[..5 - poll_id as usize] {
+                execute(
+                    deps.as_mut(),
+                    mock_env(),
+                    message_info(&verifier.address, &[]),
+                    ExecuteMsg::Vote {
+                        poll_id: Uint64::from(poll_id).into(),
+                        votes: vec![Vote::SucceededOnChain],
+                    },
+                )
+                .unwrap();
+            }
+
+            execute(
+                deps.as_mut(),
+                mock_env_expired(),
+                message_info(&api.addr_make(SENDER), &[]),
+                ExecuteMsg::EndPoll {
+                    poll_id: Uint64::from(poll_id).into(),
+                },
+            )
+            .unwrap();
+        }
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(SENDER), &[]),
+            ExecuteMsg::VerifyMessages(vec![messages[2].clone()]),
+        )
+        .unwrap();
+
+        let voting_threshold = |poll_id: u64| {
+            from_json::<PollResponse>(
+                query(
+                    deps.as_ref(),
+                    mock_env(),
+                    QueryMsg::Poll {
+                        poll_id: Uint64::from(poll_id).into(),
+                    },
+                )
+                .unwrap(),
+            )
+            .unwrap()
+            .voting_threshold
+        };
+
+        // all verifiers were active
+        assert_eq!(voting_threshold(1), Some(initial_voting_threshold()));
+        // 4 of 5 verifiers were active: 2/3 * 4/5
+        assert_eq!(
+            voting_threshold(2),
+            Some(Threshold::try_from((8, 15)).unwrap().try_into().unwrap())
+        );
+        // 3 of 5 verifiers were active: 2/3 * 3/5 is below the floor
+        assert_eq!(voting_threshold(3), Some(floor));
+    }
+
+    #[test]
+    fn poll_should_expire_once_time_expiry_is_reached() {
+        let msg_id_format = MessageIdFormat::HexTxHashAndEventIndex;
+        let verifiers = verifiers(2);
+        let mut deps = setup(verifiers.clone(), &msg_id_format);
+        let api = deps.api;
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(GOVERNANCE), &[]),
+            ExecuteMsg::UpdatePollTimeExpiry {
+                poll_time_expiry: Some(60u64.try_into().unwrap()),
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            from_json::<Option<Duration>>(
+                query(deps.as_ref(), mock_env(), QueryMsg::PollTimeExpiry).unwrap()
+            )
+            .unwrap(),
+            Some(60u64.try_into().unwrap())
+        );
+
+        let messages = messages(1, &msg_id_format);
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(SENDER), &[]),
+            ExecuteMsg::VerifyMessages(messages.clone()),
+        )
+        .unwrap();
+
+        // verifiers learn about the time expiry from the poll started event
+        let expires_at_time =
+            axelar_wasm_std::time::Timestamp::from(mock_env().block.time.plus_seconds(60));
+        assert!(res
+            .events
+            .iter()
+            .find(|event| event.ty == "messages_poll_started")
+            .unwrap()
+            .attributes
+            .contains(&cosmwasm_std::Attribute::new(
+                "expires_at_time",
+                axelar_wasm_std::canonical_json::to_string(&expires_at_time).unwrap(),
+            )));
+
+        // same block height, but the time expiry has been reached
+        let mut env_time_expired = mock_env();
+        env_time_expired.block.time = env_time_expired.block.time.plus_seconds(60);
+
+        let res = execute(
+            deps.as_mut(),
+            env_time_expired.clone(),
+            message_info(&verifiers[0].address, &[]),
+            ExecuteMsg::Vote {
+                poll_id: Uint64::one().into(),
+                votes: vec![Vote::SucceededOnChain],
+            },
+        );
+        assert_err_contains!(
+            res,
+            ContractError,
+            ContractError::VoteError(voting::Error::PollExpired)
+        );
+
+        assert_ok!(execute(
+            deps.as_mut(),
+            env_time_expired.clone(),
+            message_info(&api.addr_make(SENDER), &[]),
+            ExecuteMsg::EndPoll {
+                poll_id: Uint64::one().into(),
+            },
+        ));
+
+        let statuses: Vec<MessageStatus> = from_json(
+            query(
+                deps.as_ref(),
+                env_time_expired,
+                QueryMsg::MessagesStatus(messages.clone()),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            statuses,
+            vec![MessageStatus::new(
+                messages[0].clone(),
+                VerificationStatus::FailedToVerify
+            )]
+        );
+    }
+
+    #[test]
+    #[allow(clippy::arithmetic_side_effects)]
+    fn polls_should_expire_later_while_source_chain_is_halted() {
+        let msg_id_format = MessageIdFormat::HexTxHashAndEventIndex;
+        let verifiers = verifiers(2);
+        let mut deps = setup(verifiers.clone(), &msg_id_format);
+        let api = deps.api;
+        let messages = messages(2, &msg_id_format);
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(SENDER), &[]),
+            ExecuteMsg::VerifyMessages(vec![messages[0].clone()]),
+        )
+        .unwrap();
+
+        let mut env_halted = mock_env();
+        env_halted.block.height += 10;
+        let halt_msg = ExecuteMsg::HaltSourceChain {
+            blocks: 50u64.try_into().unwrap(),
+        };
+
+        assert!(execute(
+            deps.as_mut(),
+            env_halted.clone(),
+            message_info(&api.addr_make(SENDER), &[]),
+            halt_msg.clone(),
+        )
+        .is_err());
+
+        let res = execute(
+            deps.as_mut(),
+            env_halted.clone(),
+            message_info(&api.addr_make(GOVERNANCE), &[]),
+            halt_msg,
+        )
+        .unwrap();
+        assert!(res
+            .events
+            .iter()
+            .any(|event| event.ty == "source_chain_halted"));
+        let expiry_extension = res
+            .events
+            .iter()
+            .find(|event| event.ty == "poll_expiry_extended")
+            .unwrap();
+        assert_eq!(
+            expiry_extension.attributes,
+            vec![
+                Attribute::new("poll_id", "\"1\""),
+                Attribute::new(
+                    "expires_at",
+                    (mock_env().block.height + POLL_BLOCK_EXPIRY + 50).to_string()
+                ),
+            ]
+        );
+
+        let halt_start = env_halted.block.height;
+        assert_eq!(
+            from_json::<Option<SourceChainHalt>>(
+                query(deps.as_ref(), env_halted.clone(), QueryMsg::SourceChainHalt).unwrap()
+            )
+            .unwrap(),
+            Some(SourceChainHalt {
+                started_at: halt_start,
+                ends_at: halt_start + 50,
+            })
+        );
+
+        // polls started during the halt expire the rest of the halt later
+        let mut env_during_halt = mock_env();
+        env_during_halt.block.height += 20;
+        execute(
+            deps.as_mut(),
+            env_during_halt.clone(),
+            message_info(&api.addr_make(SENDER), &[]),
+            ExecuteMsg::VerifyMessages(vec![messages[1].clone()]),
+        )
+        .unwrap();
+
+        // the first poll would have expired without the halt
+        assert_ok!(execute(
+            deps.as_mut(),
+            mock_env_expired(),
+            message_info(&verifiers[0].address, &[]),
+            ExecuteMsg::Vote {
+                poll_id: Uint64::one().into(),
+                votes: vec![Vote::SucceededOnChain],
+            },
+        ));
+
+        let mut env_extension_over = mock_env_expired();
+        env_extension_over.block.height += 50;
+        let poll_status = |deps: &OwnedDeps<_, _, _>, poll_id: u64| {
+            from_json::<PollResponse>(
+                query(
+                    deps.as_ref(),
+                    env_extension_over.clone(),
+                    QueryMsg::Poll {
+                        poll_id: Uint64::from(poll_id).into(),
+                    },
+                )
+                .unwrap(),
+            )
+            .unwrap()
+            .status
+        };
+        assert_eq!(poll_status(&deps, 1), voting::PollStatus::Expired);
+        assert_eq!(poll_status(&deps, 2), voting::PollStatus::InProgress);
+
+        assert_eq!(
+            from_json::<Option<SourceChainHalt>>(
+                query(deps.as_ref(), env_extension_over, QueryMsg::SourceChainHalt).unwrap()
+            )
+            .unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    #[allow(clippy::arithmetic_side_effects)]
+    fn polls_in_progress_should_be_extended_in_pages_after_source_chain_halt() {
+        let msg_id_format = MessageIdFormat::HexTxHashAndEventIndex;
+        let verifiers = verifiers(2);
+        let mut deps = setup(verifiers.clone(), &msg_id_format);
+        let api = deps.api;
+        let messages = messages(102, &msg_id_format);
+
+        for message in messages {
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                message_info(&api.addr_make(SENDER), &[]),
+                ExecuteMsg::VerifyMessages(vec![message]),
+            )
+            .unwrap();
+        }
+
+        let mut env_halted = mock_env();
+        env_halted.block.height += 10;
+        let res = execute(
+            deps.as_mut(),
+            env_halted.clone(),
+            message_info(&api.addr_make(GOVERNANCE), &[]),
+            ExecuteMsg::HaltSourceChain {
+                blocks: 50u64.try_into().unwrap(),
+            },
+        )
+        .unwrap();
+        let extended_polls = |res: &Response| {
+            res.events
+                .iter()
+                .filter(|event| event.ty == "poll_expiry_extended")
+                .count()
+        };
+        assert_eq!(extended_polls(&res), 100);
+
+        let extensions_pending = |deps: &OwnedDeps<_, _, _>| {
+            from_json::<bool>(
+                query(
+                    deps.as_ref(),
+                    env_halted.clone(),
+                    QueryMsg::PollExtensionsPending,
+                )
+                .unwrap(),
+            )
+            .unwrap()
+        };
+        assert!(extensions_pending(&deps));
+
+        let res = execute(
+            deps.as_mut(),
+            env_halted.clone(),
+            message_info(&api.addr_make(SENDER), &[]),
+            ExecuteMsg::ExtendPolls { limit: 10 },
+        )
+        .unwrap();
+        assert_eq!(extended_polls(&res), 2);
+        assert!(!extensions_pending(&deps));
+
+        // the oldest polls have been extended as well
+        let res = execute(
+            deps.as_mut(),
+            mock_env_expired(),
+            message_info(&verifiers[0].address, &[]),
+            ExecuteMsg::Vote {
+                poll_id: Uint64::one().into(),
+                votes: vec![Vote::SucceededOnChain],
+            },
+        );
+        assert_ok!(res);
+    }
+
+    #[test]
+    #[allow(clippy::arithmetic_side_effects)]
+    fn polls_should_expire_later_during_maintenance_windows() {
+        let msg_id_format = MessageIdFormat::HexTxHashAndEventIndex;
+        let verifiers = verifiers(2);
+        let mut deps = setup(verifiers.clone(), &msg_id_format);
+        let api = deps.api;
+        let messages = messages(1, &msg_id_format);
+
+        let height = mock_env().block.height;
+        let msg = ExecuteMsg::SetMaintenanceWindows {
+            windows: vec![MaintenanceWindow::new(height + 10, height + 40).unwrap()],
+        };
+        assert!(execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(GOVERNANCE), &[]),
+            msg.clone(),
+        )
+        .is_err());
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(COORDINATOR), &[]),
+            msg,
+        )
+        .unwrap();
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(SENDER), &[]),
+            ExecuteMsg::VerifyMessages(messages),
+        )
+        .unwrap();
+
+        let poll_status = |deps: &OwnedDeps<_, _, _>, env: Env| {
+            from_json::<PollResponse>(
+                query(
+                    deps.as_ref(),
+                    env,
+                    QueryMsg::Poll {
+                        poll_id: Uint64::one().into(),
+                    },
+                )
+                .unwrap(),
+            )
+            .unwrap()
+            .status
+        };
+
+        // the poll expires the 30 blocks of the maintenance window later
+        let mut env = mock_env_expired();
+        env.block.height += 29;
+        assert_eq!(
+            poll_status(&deps, env.clone()),
+            voting::PollStatus::InProgress
+        );
+
+        env.block.height += 1;
+        assert_eq!(poll_status(&deps, env), voting::PollStatus::Expired);
+    }
+
+    #[test]
+    #[allow(clippy::arithmetic_side_effects)]
+    fn polls_in_progress_should_be_extended_when_maintenance_window_is_scheduled() {
+        let msg_id_format = MessageIdFormat::HexTxHashAndEventIndex;
+        let verifiers = verifiers(2);
+        let mut deps = setup(verifiers.clone(), &msg_id_format);
+        let api = deps.api;
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(SENDER), &[]),
+            ExecuteMsg::VerifyMessages(messages(1, &msg_id_format)),
+        )
+        .unwrap();
+
+        let height = mock_env().block.height;
+        let window = MaintenanceWindow::new(height + 10, height + 40).unwrap();
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(COORDINATOR), &[]),
+            ExecuteMsg::SetMaintenanceWindows {
+                windows: vec![window],
+            },
+        )
+        .unwrap();
+        assert!(res
+            .events
+            .iter()
+            .any(|event| event.ty == "poll_expiry_extended"));
+
+        // pushing the same window again doesn't extend the poll a second time
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(COORDINATOR), &[]),
+            ExecuteMsg::SetMaintenanceWindows {
+                windows: vec![window],
+            },
+        )
+        .unwrap();
+        assert!(res.events.is_empty());
+
+        let poll: PollResponse = from_json(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::Poll {
+                    poll_id: Uint64::one().into(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(poll.poll.expires_at(), height + POLL_BLOCK_EXPIRY + 30);
+    }
+
+    #[test]
+    fn abstain_votes_should_neither_count_toward_quorum_nor_be_rewarded_if_consensus_was_reached() {
+        let msg_id_format = MessageIdFormat::HexTxHashAndEventIndex;
+        let verifiers = verifiers(3);
+        let mut deps = setup(verifiers.clone(), &msg_id_format);
+        let api = deps.api;
+        let messages = messages(1, &msg_id_format);
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(SENDER), &[]),
+            ExecuteMsg::VerifyMessages(messages.clone()),
+        )
+        .unwrap();
+
+        let votes = [
+            Vote::Abstain,
+            Vote::SucceededOnChain,
+            Vote::SucceededOnChain,
+        ];
+        for (verifier, vote) in verifiers.iter().zip(votes) {
+            let status: Vec<MessageStatus> = from_json(
+                query(
+                    deps.as_ref(),
+                    mock_env(),
+                    QueryMsg::MessagesStatus(messages.clone()),
+                )
+                .unwrap(),
+            )
+            .unwrap();
+            // the abstain vote doesn't help to reach the quorum of two out of three verifiers
+            assert_eq!(
+                status,
+                msgs_statuses(messages.clone(), VerificationStatus::InProgress)
+            );
+
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                message_info(&verifier.address, &[]),
+                ExecuteMsg::Vote {
+                    poll_id: Uint64::one().into(),
+                    votes: vec![vote],
+                },
+            )
+            .unwrap();
+        }
+
+        let status: Vec<MessageStatus> = from_json(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::MessagesStatus(messages.clone()),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            status,
+            msgs_statuses(messages, VerificationStatus::SucceededOnSourceChain)
+        );
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env_expired(),
+            message_info(&api.addr_make(SENDER), &[]),
+            ExecuteMsg::EndPoll {
+                poll_id: Uint64::one().into(),
+            },
+        )
+        .unwrap();
+
+        let rewarded_verifiers: Vec<String> = res
+            .messages
+            .into_iter()
+            .map(|msg| match msg.msg {
+                CosmosMsg::Wasm(WasmMsg::Execute { msg, .. }) => match from_json(msg).unwrap() {
+                    rewards::msg::ExecuteMsg::RecordParticipation {
+                        verifier_address, ..
+                    } => verifier_address,
+                    _ => panic!("unexpected rewards message"),
+                },
+                _ => panic!("unexpected message"),
+            })
+            .sorted()
+            .collect();
+        // the abstaining verifier didn't help to reach the consensus, so only the other verifiers are rewarded
+        assert_eq!(
+            rewarded_verifiers,
+            verifiers[1..]
+                .iter()
+                .map(|verifier| verifier.address.to_string())
+                .sorted()
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    #[allow(clippy::arithmetic_side_effects)]
+    fn votes_after_quorum_grace_window_should_be_excluded_from_rewards_if_configured() {
+        let msg_id_format = MessageIdFormat::HexTxHashAndEventIndex;
+        let verifiers = verifiers(6);
+        let mut deps = setup(verifiers.clone(), &msg_id_format);
+        let api = deps.api;
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(GOVERNANCE), &[]),
+            ExecuteMsg::UpdateLateVoteGraceBlocks {
+                late_vote_grace_blocks: Some(2),
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            from_json::<Option<u64>>(
+                query(deps.as_ref(), mock_env(), QueryMsg::LateVoteGraceBlocks).unwrap()
+            )
+            .unwrap(),
+            Some(2)
+        );
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(SENDER), &[]),
+            ExecuteMsg::VerifyMessages(messages(1, &msg_id_format)),
+        )
+        .unwrap();
+
+        // four of six votes reach quorum at 4 blocks after the start, so the fifth vote is within the grace window
+        // and the sixth vote is not
+        let blocks_after_start = [1u64, 2, 3, 4, 5, 7];
+        for (blocks_after_start, verifier) in blocks_after_start.into_iter().zip(verifiers.iter()) {
+            let mut env = mock_env();
+            env.block.height += blocks_after_start;
+
+            execute(
+                deps.as_mut(),
+                env,
+                message_info(&verifier.address, &[]),
+                ExecuteMsg::Vote {
+                    poll_id: Uint64::one().into(),
+                    votes: vec![Vote::SucceededOnChain],
+                },
+            )
+            .unwrap();
+        }
+
+        let expected_latencies: Vec<VoteLatency> = verifiers
+            .iter()
+            .zip(blocks_after_start)
+            .map(|(verifier, blocks_after_start)| VoteLatency {
+                voter: verifier.address.to_string(),
+                voted_at: mock_env().block.height + blocks_after_start,
+                blocks_after_start: Some(blocks_after_start),
+                after_quorum: blocks_after_start > 4,
+                blocks_after_quorum: (blocks_after_start > 4).then(|| blocks_after_start - 4),
+            })
+            .sorted_by(|a, b| a.voter.cmp(&b.voter))
+            .collect();
+        assert_eq!(
+            from_json::<Vec<VoteLatency>>(
+                query(
+                    deps.as_ref(),
+                    mock_env(),
+                    QueryMsg::VoteLatencies {
+                        poll_id: Uint64::one().into()
+                    }
+                )
+                .unwrap()
+            )
+            .unwrap(),
+            expected_latencies
+        );
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env_expired(),
+            message_info(&api.addr_make(SENDER), &[]),
+            ExecuteMsg::EndPoll {
+                poll_id: Uint64::one().into(),
+            },
+        )
+        .unwrap();
+
+        let rewarded_verifiers: Vec<String> = res
+            .messages
+            .into_iter()
+            .map(|msg| match msg.msg {
+                CosmosMsg::Wasm(WasmMsg::Execute { msg, .. }) => match from_json(msg).unwrap() {
+                    rewards::msg::ExecuteMsg::RecordParticipation {
+                        verifier_address, ..
+                    } => verifier_address,
+                    _ => panic!("unexpected rewards message"),
+                },
+                _ => panic!("unexpected message"),
+            })
+            .sorted()
+            .collect();
+        assert_eq!(
+            rewarded_verifiers,
+            verifiers[..5]
+                .iter()
+                .map(|verifier| verifier.address.to_string())
+                .sorted()
+                .collect::<Vec<_>>()
+        );
+
+        let poll_ended = res
+            .events
+            .iter()
+            .find(|event| event.ty == "poll_ended")
+            .unwrap();
+        assert!(poll_ended
+            .attributes
+            .iter()
+            .any(|attribute| attribute.key == "vote_latencies"
+                && attribute.value == canonical_json::to_string(&expected_latencies).unwrap()));
+    }
+
+    #[test]
+    #[allow(clippy::arithmetic_side_effects)]
+    fn messages_of_expired_poll_should_be_retried_until_retries_are_used_up() {
+        let msg_id_format = MessageIdFormat::HexTxHashAndEventIndex;
+        let verifiers = verifiers(2);
+        let mut deps = setup(verifiers.clone(), &msg_id_format);
+        let api = deps.api;
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(GOVERNANCE), &[]),
+            ExecuteMsg::UpdatePollRetry {
+                poll_retry: Some(PollRetry {
+                    max_retries: 1u64.try_into().unwrap(),
+                }),
+            },
+        )
+        .unwrap();
+
+        let messages = messages(2, &msg_id_format);
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(SENDER), &[]),
+            ExecuteMsg::VerifyMessages(messages.clone()),
+        )
+        .unwrap();
+
+        // only the first message reaches quorum
+        for (verifier, votes) in verifiers.iter().zip([
+            vec![Vote::SucceededOnChain, Vote::SucceededOnChain],
+            vec![Vote::SucceededOnChain, Vote::NotFound],
+        ]) {
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                message_info(&verifier.address, &[]),
+                ExecuteMsg::Vote {
+                    poll_id: Uint64::one().into(),
+                    votes,
+                },
+            )
+            .unwrap();
+        }
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env_expired(),
+            message_info(&api.addr_make(SENDER), &[]),
+            ExecuteMsg::EndPoll {
+                poll_id: Uint64::one().into(),
+            },
+        )
+        .unwrap();
+
+        assert!(!res
+            .events
+            .iter()
+            .any(|event| event.ty == "message_verification_finalized"));
+        let poll_started = res
+            .events
+            .iter()
+            .find(|event| event.ty == "messages_poll_started")
+            .unwrap();
+        assert!(poll_started
+            .attributes
+            .iter()
+            .any(|attribute| attribute.key == "poll_id" && attribute.value == "\"2\""));
+        let poll_retried = res
+            .events
+            .iter()
+            .find(|event| event.ty == "poll_retried")
+            .unwrap();
+        assert!(poll_retried
+            .attributes
+            .iter()
+            .any(|attribute| attribute.key == "retry_poll_id" && attribute.value == "\"2\""));
+
+        let statuses: Vec<MessageStatus> = from_json(
+            query(
+                deps.as_ref(),
+                mock_env_expired(),
+                QueryMsg::MessagesStatus(messages.clone()),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            statuses,
+            vec![
+                MessageStatus::new(
+                    messages[0].clone(),
+                    VerificationStatus::SucceededOnSourceChain
+                ),
+                MessageStatus::new(messages[1].clone(), VerificationStatus::InProgress),
+            ]
+        );
+
+        let poll: PollResponse = from_json(
+            query(
+                deps.as_ref(),
+                mock_env_expired(),
+                QueryMsg::Poll {
+                    poll_id: Uint64::from(2u64).into(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(poll.retry_of, Some(Uint64::one().into()));
+        assert_eq!(
+            from_json::<u64>(
+                query(
+                    deps.as_ref(),
+                    mock_env(),
+                    QueryMsg::MessageRetries(messages[1].clone()),
+                )
+                .unwrap()
+            )
+            .unwrap(),
+            1
+        );
+
+        // the retry expires as well, but the message has no retries left
+        let mut env = mock_env_expired();
+        env.block.height += POLL_BLOCK_EXPIRY;
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&api.addr_make(SENDER), &[]),
+            ExecuteMsg::EndPoll {
+                poll_id: Uint64::from(2u64).into(),
+            },
+        )
+        .unwrap();
+
+        assert!(!res.events.iter().any(|event| event.ty == "poll_retried"));
+        assert!(res
+            .events
+            .iter()
+            .any(|event| event.ty == "message_verification_finalized"));
+
+        let statuses: Vec<MessageStatus> = from_json(
+            query(
+                deps.as_ref(),
+                env,
+                QueryMsg::MessagesStatus(vec![messages[1].clone()]),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            statuses,
+            vec![MessageStatus::new(
+                messages[1].clone(),
+                VerificationStatus::FailedToVerify
+            )]
+        );
+
+        // the retry count is no longer needed once the message has a final status
+        assert_eq!(
+            from_json::<u64>(
+                query(
+                    deps.as_ref(),
+                    mock_env(),
+                    QueryMsg::MessageRetries(messages[1].clone()),
+                )
+                .unwrap()
+            )
+            .unwrap(),
+            0
+        );
+    }
+
+    #[test]
+    fn failed_poll_retry_should_be_reported() {
+        let msg_id_format = MessageIdFormat::HexTxHashAndEventIndex;
+        let verifiers = verifiers(2);
+        let mut deps = setup(verifiers.clone(), &msg_id_format);
+        let api = deps.api;
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(GOVERNANCE), &[]),
+            ExecuteMsg::UpdatePollRetry {
+                poll_retry: Some(PollRetry {
+                    max_retries: 1u64.try_into().unwrap(),
+                }),
+            },
+        )
+        .unwrap();
+
+        let messages = messages(1, &msg_id_format);
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(SENDER), &[]),
+            ExecuteMsg::VerifyMessages(messages.clone()),
+        )
+        .unwrap();
+
+        // the snapshot for the retry can't be taken
+        deps.querier
+            .update_wasm(|_| SystemResult::Err(SystemError::Unknown {}));
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env_expired(),
+            message_info(&api.addr_make(SENDER), &[]),
+            ExecuteMsg::EndPoll {
+                poll_id: Uint64::one().into(),
+            },
+        )
+        .unwrap();
+
+        assert!(!res.events.iter().any(|event| event.ty == "poll_retried"));
+        assert!(res
+            .events
+            .iter()
+            .any(|event| event.ty == "poll_retry_failed"));
+        assert!(res
+            .events
+            .iter()
+            .any(|event| event.ty == "message_verification_finalized"));
+        assert_eq!(
+            from_json::<u64>(
+                query(
+                    deps.as_ref(),
+                    mock_env(),
+                    QueryMsg::MessageRetries(messages[0].clone()),
+                )
+                .unwrap()
+            )
+            .unwrap(),
+            0
+        );
+    }
+
+    #[test]
+    fn should_be_able_to_update_verification_fee_and_then_query_it() {
+        let msg_id_format = MessageIdFormat::HexTxHashAndEventIndex;
+        let mut deps = setup(verifiers(2), &msg_id_format);
+        let api = deps.api;
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(SENDER), &[]),
+            ExecuteMsg::UpdateVerificationFee {
+                verification_fee: Some(verification_fee()),
+            },
+        );
+        assert!(res.is_err());
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(GOVERNANCE), &[]),
+            ExecuteMsg::UpdateVerificationFee {
+                verification_fee: Some(verification_fee()),
+            },
+        )
+        .unwrap();
+
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::VerificationFee).unwrap();
+        let fee: Option<VerificationFee> = from_json(res).unwrap();
+        assert_eq!(fee, Some(verification_fee()));
+    }
+
+    #[test]
+    fn verify_messages_should_charge_fee_per_message_and_refund_excess() {
+        let msg_id_format = MessageIdFormat::HexTxHashAndEventIndex;
+        let mut deps = setup_with_verification_fee(&msg_id_format);
+        let api = deps.api;
+        let messages = messages(3, &msg_id_format);
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(SENDER), &coins(35, "uaxl")),
+            ExecuteMsg::VerifyMessages(messages.clone()),
+        )
+        .unwrap();
+
+        assert_eq!(
+            res.messages
+                .into_iter()
+                .map(|msg| msg.msg)
+                .collect::<Vec<_>>(),
+            vec![CosmosMsg::Bank(BankMsg::Send {
+                to_address: api.addr_make(SENDER).to_string(),
+                amount: coins(5, "uaxl"),
+            })]
+        );
+
+        // messages that are already being verified are not charged again
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(SENDER), &coins(10, "uaxl")),
+            ExecuteMsg::VerifyMessages(messages),
+        )
+        .unwrap();
+        assert_eq!(res.messages.len(), 1);
+
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::AccruedFees).unwrap();
+        let accrued: Vec<Coin> = from_json(res).unwrap();
+        assert_eq!(accrued, coins(30, "uaxl"));
+    }
+
+    #[test]
+    fn verify_messages_should_not_charge_exempt_callers() {
+        let msg_id_format = MessageIdFormat::HexTxHashAndEventIndex;
+        let mut deps = setup(verifiers(2), &msg_id_format);
+        let api = deps.api;
+        let gateway = api.addr_make("gateway");
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(GOVERNANCE), &[]),
+            ExecuteMsg::UpdateVerificationFee {
+                verification_fee: Some(VerificationFee {
+                    exempt_callers: vec![gateway.to_string().try_into().unwrap()],
+                    ..verification_fee()
+                }),
+            },
+        )
+        .unwrap();
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&gateway, &[]),
+            ExecuteMsg::VerifyMessages(messages(3, &msg_id_format)),
+        )
+        .unwrap();
+        assert!(res.messages.is_empty());
+
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::AccruedFees).unwrap();
+        let accrued: Vec<Coin> = from_json(res).unwrap();
+        assert!(accrued.is_empty());
+
+        // other callers still pay
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(SENDER), &[]),
+            ExecuteMsg::VerifyMessages(messages(4, &msg_id_format)[3..].to_vec()),
+        );
+        assert_contract_err_strings_equal(
+            res.unwrap_err(),
+            ContractError::InsufficientVerificationFee(coin(10, "uaxl")),
+        );
+    }
+
+    #[test]
+    fn update_verification_fee_should_fail_for_invalid_exempt_caller() {
+        let mut deps = setup(verifiers(2), &MessageIdFormat::HexTxHashAndEventIndex);
+        let api = deps.api;
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(GOVERNANCE), &[]),
+            ExecuteMsg::UpdateVerificationFee {
+                verification_fee: Some(VerificationFee {
+                    exempt_callers: vec!["invalid".try_into().unwrap()],
+                    ..verification_fee()
+                }),
+            },
+        );
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn verify_messages_should_fail_if_verification_fee_is_insufficient() {
+        let msg_id_format = MessageIdFormat::HexTxHashAndEventIndex;
+        let mut deps = setup_with_verification_fee(&msg_id_format);
+        let api = deps.api;
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(SENDER), &coins(29, "uaxl")),
+            ExecuteMsg::VerifyMessages(messages(3, &msg_id_format)),
+        );
+        assert_contract_err_strings_equal(
+            res.unwrap_err(),
+            ContractError::InsufficientVerificationFee(coin(30, "uaxl")),
+        );
+    }
+
+    #[test]
+    fn verify_messages_should_fail_if_verification_fee_is_paid_in_wrong_denom() {
+        let msg_id_format = MessageIdFormat::HexTxHashAndEventIndex;
+        let mut deps = setup_with_verification_fee(&msg_id_format);
+        let api = deps.api;
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(
+                &api.addr_make(SENDER),
+                &[coin(10, "uaxl"), coin(10, "uusdc")],
+            ),
+            ExecuteMsg::VerifyMessages(messages(1, &msg_id_format)),
+        );
+        assert_contract_err_strings_equal(
+            res.unwrap_err(),
+            ContractError::WrongFeeDenom("uaxl".to_string()),
+        );
+    }
+
+    #[test]
+    fn accrued_fees_should_be_transferred_to_rewards_pool() {
+        let msg_id_format = MessageIdFormat::HexTxHashAndEventIndex;
+        let mut deps = setup_with_verification_fee(&msg_id_format);
+        let api = deps.api;
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(SENDER), &coins(20, "uaxl")),
+            ExecuteMsg::VerifyMessages(messages(2, &msg_id_format)),
+        )
+        .unwrap();
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(SENDER), &[]),
+            ExecuteMsg::TransferFeesToRewards {
+                denom: "uaxl".parse().unwrap(),
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            res.messages
+                .into_iter()
+                .map(|msg| msg.msg)
+                .collect::<Vec<_>>(),
+            vec![CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: api.addr_make(REWARDS_ADDRESS).to_string(),
+                msg: to_json_binary(&rewards::msg::ExecuteMsg::AddRewards {
+                    pool_id: rewards::msg::PoolId {
+                        chain_name: source_chain(),
+                        contract: mock_env().contract.address.to_string(),
+                    },
+                })
+                .unwrap(),
+                funds: coins(20, "uaxl"),
+            })]
+        );
+
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::AccruedFees).unwrap();
+        let accrued: Vec<Coin> = from_json(res).unwrap();
+        assert!(accrued.is_empty());
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(SENDER), &[]),
+            ExecuteMsg::TransferFeesToRewards {
+                denom: "uaxl".parse().unwrap(),
+            },
+        );
+        assert_contract_err_strings_equal(
+            res.unwrap_err(),
+            ContractError::NoAccruedFees("uaxl".to_string()),
+        );
+    }
+
+    #[test]
+    fn cancelled_poll_should_make_messages_eligible_for_reverification() {
+        let msg_id_format = MessageIdFormat::HexTxHashAndEventIndex;
+        let verifiers = verifiers(2);
+        let mut deps = setup(verifiers.clone(), &msg_id_format);
+        let api = deps.api;
+
+        let messages = messages(2, &msg_id_format);
+        let poll_id = Uint64::one().into();
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(SENDER), &[]),
+            ExecuteMsg::VerifyMessages(messages.clone()),
+        )
+        .unwrap();
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&verifiers[0].address, &[]),
+            ExecuteMsg::Vote {
+                poll_id,
+                votes: vec![Vote::SucceededOnChain; messages.len()],
+            },
+        )
+        .unwrap();
+
+        let cancel_msg = ExecuteMsg::CancelPoll {
+            poll_id,
+            reason: "malformed content".try_into().unwrap(),
+        };
+
+        assert!(execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(SENDER), &[]),
+            cancel_msg.clone(),
+        )
+        .is_err());
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(GOVERNANCE), &[]),
+            cancel_msg.clone(),
+        )
+        .unwrap();
+        assert!(res.messages.is_empty());
+        assert!(res.events.iter().any(|event| event.ty == "poll_cancelled"
+            && event.attributes.iter().any(
+                |attribute| attribute.key == "reason" && attribute.value == "malformed content"
+            )));
+        assert!(!crate::state::POLL_STARTED_AT.has(deps.as_ref().storage, poll_id));
+        assert!(!crate::state::POLL_VOTING_THRESHOLDS.has(deps.as_ref().storage, poll_id));
+
+        let statuses: Vec<MessageStatus> = from_json(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::MessagesStatus(messages.clone()),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            statuses,
+            msgs_statuses(messages.clone(), VerificationStatus::Unknown)
+        );
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&verifiers[1].address, &[]),
+            ExecuteMsg::Vote {
+                poll_id,
+                votes: vec![Vote::SucceededOnChain; messages.len()],
+            },
+        );
+        assert_err_contains!(res, ContractError, ContractError::PollCancelled(..));
+
+        // no participation gets recorded for a cancelled poll
+        let res = execute(
+            deps.as_mut(),
+            mock_env_expired(),
+            message_info(&api.addr_make(SENDER), &[]),
+            ExecuteMsg::EndPoll { poll_id },
+        );
+        assert_err_contains!(res, ContractError, ContractError::PollCancelled(..));
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(GOVERNANCE), &[]),
+            cancel_msg,
+        );
+        assert_err_contains!(res, ContractError, ContractError::PollCancelled(..));
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(SENDER), &[]),
+            ExecuteMsg::VerifyMessages(messages.clone()),
+        )
+        .unwrap();
+
+        let statuses: Vec<MessageStatus> = from_json(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::MessagesStatus(messages.clone()),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            statuses,
+            msgs_statuses(messages, VerificationStatus::InProgress)
+        );
+    }
+
+    #[test]
+    fn ended_poll_cannot_be_cancelled() {
+        let msg_id_format = MessageIdFormat::HexTxHashAndEventIndex;
+        let verifiers = verifiers(2);
+        let mut deps = setup(verifiers.clone(), &msg_id_format);
+        let api = deps.api;
+        let poll_id = Uint64::one().into();
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(SENDER), &[]),
+            ExecuteMsg::VerifyMessages(messages(1, &msg_id_format)),
+        )
+        .unwrap();
+        assert!(crate::state::POLL_VOTING_THRESHOLDS.has(deps.as_ref().storage, poll_id));
+
+        execute(
+            deps.as_mut(),
+            mock_env_expired(),
+            message_info(&api.addr_make(SENDER), &[]),
+            ExecuteMsg::EndPoll { poll_id },
+        )
+        .unwrap();
+        assert!(!crate::state::POLL_VOTING_THRESHOLDS.has(deps.as_ref().storage, poll_id));
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env_expired(),
+            message_info(&api.addr_make(GOVERNANCE), &[]),
+            ExecuteMsg::CancelPoll {
+                poll_id,
+                reason: "malformed content".try_into().unwrap(),
+            },
+        );
+        assert_err_contains!(res, ContractError, ContractError::PollAlreadyEnded(..));
+    }
+
+    #[test]
+    fn threshold_changes_should_not_affect_existing_polls() {
+        let verifiers = verifiers(10);
+        let initial_threshold = initial_voting_threshold();
+        let majority = (verifiers.len() as u64 * initial_threshold.numerator().u64())
+            .div_ceil(initial_threshold.denominator().u64());
+
+        let msg_id_format = MessageIdFormat::HexTxHashAndEventIndex;
+        let mut deps = setup(verifiers.clone(), &msg_id_format);
+        let api = deps.api;
+
+        let messages = messages(1, &msg_id_format);
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(SENDER), &[]),
+            ExecuteMsg::VerifyMessages(messages.clone()),
+        )
+        .unwrap();
+
+        // simulate a majority of verifiers voting for succeeded on chain
+        verifiers.iter().enumerate().for_each(|(i, verifier)| {
+            if i as u64 >= majority {
+                return;
+            }
+            let msg = ExecuteMsg::Vote {
+                poll_id: 1u64.into(),
+                votes: vec![Vote::SucceededOnChain],
+            };
+
+            let res = execute(
+                deps.as_mut(),
+                mock_env(),
+                message_info(&verifier.address, &[]),
+                msg,
+            );
+            assert!(res.is_ok());
+        });
+
+        // increase the threshold. Not enough verifiers voted to meet the new majority,
+        // but threshold changes should not affect existing polls
+        let new_voting_threshold: MajorityThreshold =
+            Threshold::try_from((majority + 1, verifiers.len() as u64))
+                .unwrap()
+                .try_into()
+                .unwrap();
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(GOVERNANCE), &[]),
+            ExecuteMsg::UpdateVotingThreshold {
+                new_voting_threshold,
+            },
+        )
+        .unwrap();
+
+        execute(
+            deps.as_mut(),
+            mock_env_expired(),
+            message_info(&api.addr_make(SENDER), &[]),
+            ExecuteMsg::EndPoll {
+                poll_id: 1u64.into(),
+            },
+        )
+        .unwrap();
+
+        let res: Vec<MessageStatus> = from_json(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::MessagesStatus(messages.clone()),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            res,
+            vec![MessageStatus::new(
+                messages[0].clone(),
+                VerificationStatus::SucceededOnSourceChain
+            )]
+        );
+    }
+
+    #[test]
+    fn threshold_changes_should_affect_new_polls() {
+        let verifiers = verifiers(10);
+        let initial_threshold = initial_voting_threshold();
+        let old_majority = (verifiers.len() as u64 * initial_threshold.numerator().u64())
+            .div_ceil(initial_threshold.denominator().u64());
+
+        let msg_id_format = MessageIdFormat::HexTxHashAndEventIndex;
+        let mut deps = setup(verifiers.clone(), &msg_id_format);
+        let api = deps.api;
+
+        // increase the threshold prior to starting a poll
+        let new_voting_threshold: MajorityThreshold =
+            Threshold::try_from((old_majority + 1, verifiers.len() as u64))
+                .unwrap()
+                .try_into()
+                .unwrap();
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(GOVERNANCE), &[]),
+            ExecuteMsg::UpdateVotingThreshold {
+                new_voting_threshold,
+            },
+        )
+        .unwrap();
+
+        let messages = messages(1, &msg_id_format);
+
+        // start the poll, should just the new threshold
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(SENDER), &[]),
+            ExecuteMsg::VerifyMessages(messages.clone()),
+        )
+        .unwrap();
+
+        // simulate old_majority of verifiers voting succeeded on chain,
+        // which is one less than the updated majority. The messages
+        // should not receive enough votes to be considered verified
+        verifiers.iter().enumerate().for_each(|(i, verifier)| {
+            if i as u64 >= old_majority {
+                return;
+            }
+            let msg = ExecuteMsg::Vote {
+                poll_id: 1u64.into(),
+                votes: vec![Vote::SucceededOnChain],
+            };
+
+            let res = execute(
+                deps.as_mut(),
+                mock_env(),
+                message_info(&verifier.address, &[]),
+                msg,
+            );
+            assert!(res.is_ok());
+        });
+
+        execute(
+            deps.as_mut(),
+            mock_env_expired(),
+            message_info(&api.addr_make(SENDER), &[]),
+            ExecuteMsg::EndPoll {
+                poll_id: 1u64.into(),
+            },
+        )
+        .unwrap();
+
+        let res: Vec<MessageStatus> = from_json(
+            query(
+                deps.as_ref(),
+                mock_env_expired(),
+                QueryMsg::MessagesStatus(messages.clone()),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            res,
+            vec![MessageStatus::new(
+                messages[0].clone(),
+                VerificationStatus::FailedToVerify
+            )]
+        );
+    }
+
+    #[test]
+    fn should_emit_event_when_verification_succeeds() {
+        let msg_id_format = MessageIdFormat::HexTxHashAndEventIndex;
+        let verifiers = verifiers(3);
+        let mut deps = setup(verifiers.clone(), &msg_id_format);
+        let api = deps.api;
+        let threshold = initial_voting_threshold();
+        // this test depends on the threshold being 2/3
+        assert_eq!(
+            threshold,
+            Threshold::try_from((2, 3)).unwrap().try_into().unwrap()
+        );
+
+        let messages = messages(3, &msg_id_format);
+
+        // 1. First verification
+
+        let msg_verify = ExecuteMsg::VerifyMessages(messages.clone());
 
         let res = execute(
             deps.as_mut(),
@@ -1622,6 +3504,115 @@ mod test {
         });
     }
 
+    fn finalized_events(
+        res: &Response,
+    ) -> Vec<(Message, VerificationStatus, String, String, String)> {
+        res.events
+            .iter()
+            .filter(|event| event.ty == "message_verification_finalized")
+            .map(|event| {
+                let attr = |key: &str| {
+                    event
+                        .attributes
+                        .iter()
+                        .find(|attr| attr.key == key)
+                        .unwrap()
+                        .value
+                        .clone()
+                };
+
+                (
+                    serde_json::from_str(&attr("message")).unwrap(),
+                    serde_json::from_str(&attr("status")).unwrap(),
+                    attr("blocks_to_quorum"),
+                    attr("votes_received"),
+                    attr("poll_expired"),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    #[allow(clippy::arithmetic_side_effects)]
+    fn should_emit_verification_metrics_when_message_status_is_final() {
+        let msg_id_format = MessageIdFormat::HexTxHashAndEventIndex;
+        let verifiers = verifiers(3);
+        let mut deps = setup(verifiers.clone(), &msg_id_format);
+        let api = deps.api;
+
+        let messages = messages(2, &msg_id_format);
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(SENDER), &[]),
+            ExecuteMsg::VerifyMessages(messages.clone()),
+        )
+        .unwrap();
+
+        let mut env = mock_env();
+        env.block.height += 3;
+
+        // the first message reaches quorum with the second vote, the second message never does
+        let responses: Vec<Response> = verifiers
+            .iter()
+            .take(2)
+            .enumerate()
+            .map(|(i, verifier)| {
+                execute(
+                    deps.as_mut(),
+                    env.clone(),
+                    message_info(&verifier.address, &[]),
+                    ExecuteMsg::Vote {
+                        poll_id: 1u64.into(),
+                        votes: vec![
+                            Vote::SucceededOnChain,
+                            if i == 0 {
+                                Vote::NotFound
+                            } else {
+                                Vote::SucceededOnChain
+                            },
+                        ],
+                    },
+                )
+                .unwrap()
+            })
+            .collect();
+
+        assert!(finalized_events(&responses[0]).is_empty());
+        assert_eq!(
+            finalized_events(&responses[1]),
+            vec![(
+                messages[0].clone(),
+                VerificationStatus::SucceededOnSourceChain,
+                "3".to_string(),
+                "2".to_string(),
+                "false".to_string()
+            )]
+        );
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env_expired(),
+            message_info(&api.addr_make(SENDER), &[]),
+            ExecuteMsg::EndPoll {
+                poll_id: 1u64.into(),
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            finalized_events(&res),
+            vec![(
+                messages[1].clone(),
+                VerificationStatus::FailedToVerify,
+                "null".to_string(),
+                "2".to_string(),
+                "true".to_string()
+            )]
+        );
+    }
+
     #[test]
     fn should_fail_if_messages_have_invalid_source_address() {
         let msg_id_format = MessageIdFormat::HexTxHashAndEventIndex;
@@ -1677,4 +3668,247 @@ mod test {
         );
         assert!(res.is_ok());
     }
+
+    fn verify_not_executed_and_vote(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier, Empty>,
+        verifiers: &[Verifier],
+        messages: &[Message],
+        up_to_height: u64,
+        vote: Vote,
+    ) -> Response {
+        let api = deps.api;
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(SENDER), &[]),
+            ExecuteMsg::VerifyMessagesNotExecuted {
+                messages: messages.to_vec(),
+                up_to_height,
+            },
+        )
+        .unwrap();
+
+        let vote_msg = ExecuteMsg::Vote {
+            poll_id: Uint64::one().into(),
+            votes: vec![vote; messages.len()],
+        };
+        verifiers.iter().for_each(|verifier| {
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                message_info(&verifier.address, &[]),
+                vote_msg.clone(),
+            )
+            .unwrap();
+        });
+
+        execute(
+            deps.as_mut(),
+            mock_env_expired(),
+            message_info(&api.addr_make(SENDER), &[]),
+            ExecuteMsg::EndPoll {
+                poll_id: Uint64::one().into(),
+            },
+        )
+        .unwrap()
+    }
+
+    fn messages_not_executed_status(
+        deps: &OwnedDeps<MockStorage, MockApi, MockQuerier, Empty>,
+        messages: &[Message],
+        up_to_height: u64,
+    ) -> Vec<MessageStatus> {
+        from_json(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::MessagesNotExecutedStatus {
+                    messages: messages.to_vec(),
+                    up_to_height,
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn should_query_not_executed_status_according_to_vote() {
+        let test_cases = [
+            (
+                Vote::SucceededOnChain,
+                VerificationStatus::SucceededOnSourceChain,
+            ),
+            (Vote::FailedOnChain, VerificationStatus::FailedOnSourceChain),
+            (Vote::NotFound, VerificationStatus::NotFoundOnSourceChain),
+        ];
+
+        for (consensus_vote, expected_status) in test_cases {
+            let msg_id_format = MessageIdFormat::HexTxHashAndEventIndex;
+            let verifiers = verifiers(2);
+            let mut deps = setup(verifiers.clone(), &msg_id_format);
+            let messages = messages(3, &msg_id_format);
+
+            assert_eq!(
+                messages_not_executed_status(&deps, &messages, 1000),
+                msgs_statuses(messages.clone(), VerificationStatus::Unknown)
+            );
+
+            let res = verify_not_executed_and_vote(
+                &mut deps,
+                &verifiers,
+                &messages,
+                1000,
+                consensus_vote,
+            );
+
+            // participation is recorded for every verifier that voted with the consensus
+            assert_eq!(res.messages.len(), verifiers.len());
+
+            assert_eq!(
+                messages_not_executed_status(&deps, &messages, 1000),
+                msgs_statuses(messages.clone(), expected_status)
+            );
+
+            // claims are independent of the regular message verification
+            let statuses: Vec<MessageStatus> = from_json(
+                query(
+                    deps.as_ref(),
+                    mock_env(),
+                    QueryMsg::MessagesStatus(messages.clone()),
+                )
+                .unwrap(),
+            )
+            .unwrap();
+            assert_eq!(
+                statuses,
+                msgs_statuses(messages.clone(), VerificationStatus::Unknown)
+            );
+        }
+    }
+
+    #[test]
+    fn should_start_not_executed_poll_with_event() {
+        let msg_id_format = MessageIdFormat::HexTxHashAndEventIndex;
+        let mut deps = setup(verifiers(2), &msg_id_format);
+        let api = deps.api;
+        let messages = messages(2, &msg_id_format);
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(SENDER), &[]),
+            ExecuteMsg::VerifyMessagesNotExecuted {
+                messages: messages.clone(),
+                up_to_height: 1000,
+            },
+        )
+        .unwrap();
+
+        let event = res
+            .events
+            .iter()
+            .find(|event| event.ty == "messages_not_executed_poll_started")
+            .unwrap();
+        assert!(event
+            .attributes
+            .iter()
+            .any(|attr| attr.key == "up_to_height" && attr.value == "1000"));
+
+        let poll: crate::msg::PollResponse = from_json(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::Poll {
+                    poll_id: Uint64::one().into(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            poll.data,
+            crate::msg::PollData::MessagesNotExecuted {
+                messages: messages.clone(),
+                up_to_height: 1000,
+            }
+        );
+
+        assert_eq!(
+            messages_not_executed_status(&deps, &messages, 1000),
+            msgs_statuses(messages.clone(), VerificationStatus::InProgress)
+        );
+    }
+
+    #[test]
+    fn should_not_reverify_final_not_executed_claims() {
+        let msg_id_format = MessageIdFormat::HexTxHashAndEventIndex;
+        let verifiers = verifiers(2);
+        let mut deps = setup(verifiers.clone(), &msg_id_format);
+        let api = deps.api;
+        let messages = messages(2, &msg_id_format);
+
+        verify_not_executed_and_vote(&mut deps, &verifiers, &messages, 1000, Vote::NotFound);
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(SENDER), &[]),
+            ExecuteMsg::VerifyMessagesNotExecuted {
+                messages: messages.clone(),
+                up_to_height: 1000,
+            },
+        )
+        .unwrap();
+        assert!(res.events.is_empty());
+
+        // a claim for a later height is a different claim and needs its own poll
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(SENDER), &[]),
+            ExecuteMsg::VerifyMessagesNotExecuted {
+                messages: messages.clone(),
+                up_to_height: 2000,
+            },
+        )
+        .unwrap();
+        assert_eq!(res.events.len(), 1);
+        assert_eq!(
+            messages_not_executed_status(&deps, &messages, 2000),
+            msgs_statuses(messages.clone(), VerificationStatus::InProgress)
+        );
+    }
+
+    #[test]
+    fn should_retry_not_executed_claims_if_poll_failed_to_verify() {
+        let msg_id_format = MessageIdFormat::HexTxHashAndEventIndex;
+        let mut deps = setup(verifiers(2), &msg_id_format);
+        let api = deps.api;
+        let messages = messages(2, &msg_id_format);
+        let msg = ExecuteMsg::VerifyMessagesNotExecuted {
+            messages: messages.clone(),
+            up_to_height: 1000,
+        };
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&api.addr_make(SENDER), &[]),
+            msg.clone(),
+        )
+        .unwrap();
+
+        // nobody votes before the poll expires
+        let res = execute(
+            deps.as_mut(),
+            mock_env_expired(),
+            message_info(&api.addr_make(SENDER), &[]),
+            msg,
+        )
+        .unwrap();
+        assert_eq!(res.events.len(), 1);
+        assert_eq!(res.events[0].ty, "messages_not_executed_poll_started");
+    }
 }
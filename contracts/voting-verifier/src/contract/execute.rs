@@ -1,27 +1,39 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use axelar_wasm_std::address::{validate_address, AddressFormat};
+use axelar_wasm_std::maintenance::MaintenanceWindow;
+use axelar_wasm_std::time::{Duration, Timestamp};
 use axelar_wasm_std::utils::TryMapExt;
-use axelar_wasm_std::voting::{PollId, PollResults, Vote, WeightedPoll};
-use axelar_wasm_std::{nonempty, snapshot, MajorityThreshold, VerificationStatus};
+use axelar_wasm_std::voting::{PollId, PollResults, PollStatus, Vote, WeightedPoll};
+use axelar_wasm_std::{nonempty, snapshot, MajorityThreshold, Threshold, VerificationStatus};
 use cosmwasm_std::{
-    to_json_binary, Deps, DepsMut, Env, Event, MessageInfo, OverflowError, OverflowOperation,
-    Response, Storage, WasmMsg,
+    coin, coins, to_json_binary, BankMsg, BlockInfo, Deps, DepsMut, Env, Event, Fraction,
+    MessageInfo, Order, OverflowError, OverflowOperation, Response, Storage, Uint128, Uint64,
+    WasmMsg,
 };
-use error_stack::{report, Report, Result, ResultExt};
+use cw_storage_plus::Bound;
+use error_stack::{ensure, report, Report, Result, ResultExt};
 use itertools::Itertools;
 use multisig::verifier_set::VerifierSet;
 use router_api::{ChainName, Message};
-use service_registry::WeightedVerifier;
 
-use crate::contract::query::{message_status, verifier_set_status};
+use crate::contract::query::{message_status, not_executed_claim_status, verifier_set_status};
 use crate::error::ContractError;
 use crate::events::{
-    PollEnded, PollMetadata, PollStarted, QuorumReached, TxEventConfirmation,
-    VerifierSetConfirmation, Voted,
+    self, MessageVerificationFinalized, PollCancelled, PollEnded, PollExpiryExtended, PollMetadata,
+    PollRetried, PollRetryFailed, PollStarted, QuorumReached, SourceChainHalted,
+    TxEventConfirmation, VerifierSetConfirmation, Voted,
+};
+use crate::msg::{
+    DynamicVotingThreshold, ObservedBlock, PollRetry, SourceChainHalt, VerificationFee,
+    VoteWeightDecay,
 };
 use crate::state::{
-    self, poll_messages, poll_verifier_sets, Poll, PollContent, CONFIG, POLLS, POLL_ID, VOTES,
+    self, poll_messages, poll_not_executed_claims, poll_verifier_sets, Config, NotExecutedClaim,
+    Poll, PollContent, PollExtension, PollExtensionLength, ACCRUED_FEES, CANCELLED_POLLS, CONFIG,
+    MAINTENANCE_WINDOWS, MESSAGE_RETRIES, MISSED_POLLS, POLLS, POLL_CONTENT_HASHES,
+    POLL_EXTENSIONS, POLL_ID, POLL_STARTED_AT, POLL_VOTING_THRESHOLDS, RETRIED_POLLS,
+    SOURCE_CHAIN_HALT, VOTES,
 };
 
 pub fn update_voting_threshold(
@@ -40,24 +52,346 @@ pub fn update_voting_threshold(
     Ok(Response::new())
 }
 
+pub fn update_verification_fee(
+    deps: DepsMut,
+    verification_fee: Option<VerificationFee>,
+) -> Result<Response, ContractError> {
+    CONFIG
+        .update(
+            deps.storage,
+            |mut config| -> Result<_, cosmwasm_std::StdError> {
+                config.verification_fee = verification_fee;
+                Ok(config)
+            },
+        )
+        .change_context(ContractError::StorageError)?;
+    Ok(Response::new())
+}
+
+pub fn update_vote_weight_decay(
+    deps: DepsMut,
+    vote_weight_decay: Option<VoteWeightDecay>,
+) -> Result<Response, ContractError> {
+    CONFIG
+        .update(
+            deps.storage,
+            |mut config| -> Result<_, cosmwasm_std::StdError> {
+                config.vote_weight_decay = vote_weight_decay;
+                Ok(config)
+            },
+        )
+        .change_context(ContractError::StorageError)?;
+    Ok(Response::new())
+}
+
+pub fn update_dynamic_voting_threshold(
+    deps: DepsMut,
+    dynamic_voting_threshold: Option<DynamicVotingThreshold>,
+) -> Result<Response, ContractError> {
+    CONFIG
+        .update(
+            deps.storage,
+            |mut config| -> Result<_, cosmwasm_std::StdError> {
+                config.dynamic_voting_threshold = dynamic_voting_threshold;
+                Ok(config)
+            },
+        )
+        .change_context(ContractError::StorageError)?;
+    Ok(Response::new())
+}
+
+pub fn update_poll_retry(
+    deps: DepsMut,
+    poll_retry: Option<PollRetry>,
+) -> Result<Response, ContractError> {
+    CONFIG
+        .update(
+            deps.storage,
+            |mut config| -> Result<_, cosmwasm_std::StdError> {
+                config.poll_retry = poll_retry;
+                Ok(config)
+            },
+        )
+        .change_context(ContractError::StorageError)?;
+    Ok(Response::new())
+}
+
+pub fn update_poll_time_expiry(
+    deps: DepsMut,
+    poll_time_expiry: Option<Duration>,
+) -> Result<Response, ContractError> {
+    CONFIG
+        .update(
+            deps.storage,
+            |mut config| -> Result<_, cosmwasm_std::StdError> {
+                config.poll_time_expiry = poll_time_expiry;
+                Ok(config)
+            },
+        )
+        .change_context(ContractError::StorageError)?;
+    Ok(Response::new())
+}
+
+pub fn update_late_vote_grace_blocks(
+    deps: DepsMut,
+    late_vote_grace_blocks: Option<u64>,
+) -> Result<Response, ContractError> {
+    CONFIG
+        .update(
+            deps.storage,
+            |mut config| -> Result<_, cosmwasm_std::StdError> {
+                config.late_vote_grace_blocks = late_vote_grace_blocks;
+                Ok(config)
+            },
+        )
+        .change_context(ContractError::StorageError)?;
+    Ok(Response::new())
+}
+
+pub fn halt_source_chain(
+    deps: DepsMut,
+    env: Env,
+    blocks: nonempty::Uint64,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage).expect("failed to load config");
+    let ends_at = env.block.height.saturating_add(blocks.into());
+
+    let active_halt = state::active_source_chain_halt(deps.storage, env.block.height)
+        .change_context(ContractError::StorageError)?;
+    // polls in progress have already been extended until the end of an active halt
+    let extended_until = active_halt
+        .as_ref()
+        .map_or(env.block.height, |halt| halt.ends_at);
+    let halt = SourceChainHalt {
+        started_at: active_halt.map_or(env.block.height, |halt| halt.started_at),
+        ends_at: ends_at.max(extended_until),
+    };
+
+    schedule_poll_extension(
+        deps.storage,
+        &env.block,
+        PollExtensionLength::Blocks(ends_at.saturating_sub(extended_until)),
+    )?;
+    let extended_polls = apply_poll_extensions(deps.storage, MAX_POLL_EXTENSION_LIMIT)?;
+
+    SOURCE_CHAIN_HALT
+        .save(deps.storage, &halt)
+        .change_context(ContractError::StorageError)?;
+
+    Ok(Response::new()
+        .add_event(SourceChainHalted {
+            source_chain: config.source_chain,
+            started_at: halt.started_at,
+            ends_at: halt.ends_at,
+        })
+        .add_events(extended_polls))
+}
+
+pub fn extend_polls(deps: DepsMut, limit: u32) -> Result<Response, ContractError> {
+    Ok(Response::new().add_events(apply_poll_extensions(deps.storage, limit)?))
+}
+
+/// Maximum number of polls that are checked for pending extensions per call
+const MAX_POLL_EXTENSION_LIMIT: u32 = 100;
+
+/// Schedules the extension of all polls that are in progress at the given block.
+/// Polls can be started with different expiries, so all of them have to be checked, which is done in pages
+fn schedule_poll_extension(
+    storage: &mut dyn Storage,
+    block: &BlockInfo,
+    length: PollExtensionLength,
+) -> Result<(), ContractError> {
+    if length == PollExtensionLength::Blocks(0) {
+        return Ok(());
+    }
+
+    let Some(newest_poll_id) = POLLS
+        .keys(storage, None, None, Order::Descending)
+        .next()
+        .transpose()
+        .change_context(ContractError::StorageError)?
+    else {
+        return Ok(());
+    };
+
+    let mut extensions = POLL_EXTENSIONS
+        .may_load(storage)
+        .change_context(ContractError::StorageError)?
+        .unwrap_or_default();
+    extensions.push(PollExtension {
+        in_progress_at: block.clone(),
+        length,
+        newest_poll_id,
+        checked_until: None,
+    });
+
+    POLL_EXTENSIONS
+        .save(storage, &extensions)
+        .change_context(ContractError::StorageError)
+}
+
+/// Checks up to `limit` polls of the pending extensions, oldest extension first, and extends the polls that were
+/// in progress when the extension was scheduled
+fn apply_poll_extensions(
+    storage: &mut dyn Storage,
+    limit: u32,
+) -> Result<Vec<PollExpiryExtended>, ContractError> {
+    let mut extensions = POLL_EXTENSIONS
+        .may_load(storage)
+        .change_context(ContractError::StorageError)?
+        .unwrap_or_default();
+    let mut remaining =
+        usize::try_from(limit.min(MAX_POLL_EXTENSION_LIMIT)).expect("limit should fit into usize");
+    let mut extended = vec![];
+
+    while remaining > 0 {
+        let Some(extension) = extensions.first_mut() else {
+            break;
+        };
+
+        let max = match extension.checked_until {
+            Some(poll_id) => Bound::exclusive(poll_id),
+            None => Bound::inclusive(extension.newest_poll_id),
+        };
+        let polls: Vec<(PollId, Poll)> = POLLS
+            .range(storage, None, Some(max), Order::Descending)
+            .take(remaining)
+            .try_collect()
+            .change_context(ContractError::StorageError)?;
+        let exhausted = polls.len() < remaining;
+        remaining = remaining.saturating_sub(polls.len());
+
+        for (poll_id, poll) in polls {
+            extension.checked_until = Some(poll_id);
+
+            if poll.weighted_poll_ref().status(&extension.in_progress_at) != PollStatus::InProgress
+                || CANCELLED_POLLS.has(storage, poll_id)
+            {
+                continue;
+            }
+
+            let blocks = match &extension.length {
+                PollExtensionLength::Blocks(blocks) => *blocks,
+                PollExtensionLength::MaintenanceWindow(window) => window.blocks_within(
+                    extension.in_progress_at.height,
+                    poll.weighted_poll_ref().expires_at(),
+                ),
+            };
+            if blocks == 0 {
+                continue;
+            }
+
+            let poll = poll.try_map(|poll| Ok::<_, ContractError>(poll.extend_expiry(blocks)))?;
+            POLLS
+                .save(storage, poll_id, &poll)
+                .change_context(ContractError::StorageError)?;
+
+            extended.push(PollExpiryExtended {
+                poll_id,
+                expires_at: poll.weighted_poll_ref().expires_at(),
+            });
+        }
+
+        if exhausted {
+            extensions.remove(0);
+        }
+    }
+
+    POLL_EXTENSIONS
+        .save(storage, &extensions)
+        .change_context(ContractError::StorageError)?;
+
+    Ok(extended)
+}
+
+/// Polls started after a window is scheduled already expire later by the blocks of the window. Polls that are in
+/// progress at that point are extended in pages, the same way as for a source chain halt. Polls keep their extension
+/// if a window is cancelled
+pub fn set_maintenance_windows(
+    deps: DepsMut,
+    env: Env,
+    windows: Vec<MaintenanceWindow>,
+) -> Result<Response, ContractError> {
+    let scheduled = MAINTENANCE_WINDOWS
+        .may_load(deps.storage)
+        .change_context(ContractError::StorageError)?
+        .unwrap_or_default();
+
+    for window in windows
+        .iter()
+        .filter(|window| !scheduled.contains(window) && !window.has_ended(env.block.height))
+    {
+        schedule_poll_extension(
+            deps.storage,
+            &env.block,
+            PollExtensionLength::MaintenanceWindow(*window),
+        )?;
+    }
+    let extended_polls = apply_poll_extensions(deps.storage, MAX_POLL_EXTENSION_LIMIT)?;
+
+    MAINTENANCE_WINDOWS
+        .save(deps.storage, &windows)
+        .change_context(ContractError::StorageError)?;
+
+    Ok(Response::new().add_events(extended_polls))
+}
+
+pub fn transfer_fees_to_rewards(
+    deps: DepsMut,
+    env: Env,
+    denom: nonempty::String,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage).expect("failed to load config");
+
+    let accrued = ACCRUED_FEES
+        .may_load(deps.storage, denom.as_str())
+        .change_context(ContractError::StorageError)?
+        .unwrap_or_default();
+    ensure!(
+        !accrued.is_zero(),
+        ContractError::NoAccruedFees(denom.to_string())
+    );
+
+    ACCRUED_FEES.remove(deps.storage, denom.as_str());
+
+    Ok(Response::new().add_message(WasmMsg::Execute {
+        contract_addr: config.rewards_contract.to_string(),
+        msg: to_json_binary(&rewards::msg::ExecuteMsg::AddRewards {
+            pool_id: rewards::msg::PoolId {
+                chain_name: config.source_chain,
+                contract: env.contract.address.to_string(),
+            },
+        })
+        .expect("failed to serialize message for rewards contract"),
+        funds: coins(accrued.u128(), denom.as_str()),
+    }))
+}
+
 pub fn verify_verifier_set(
     deps: DepsMut,
     env: Env,
     message_id: nonempty::String,
     new_verifier_set: VerifierSet,
 ) -> Result<Response, ContractError> {
-    let status = verifier_set_status(deps.as_ref(), &new_verifier_set, env.block.height)?;
+    let status = verifier_set_status(deps.as_ref(), &new_verifier_set, &env.block)?;
     if status.is_confirmed() {
         return Ok(Response::new());
     }
 
     let config = CONFIG.load(deps.storage).expect("failed to load config");
 
-    let snapshot = take_snapshot(deps.as_ref(), &config.source_chain)?;
+    let (snapshot, voting_threshold) = take_snapshot(deps.as_ref(), &config.source_chain)?;
     let participants = snapshot.participants();
-    let expires_at = calculate_expiration(env.block.height, config.block_expiry.into())?;
+    let (expires_at, expires_at_time) = poll_expiration(deps.storage, &env, &config)?;
 
-    let poll_id = create_verifier_set_poll(deps.storage, expires_at, snapshot)?;
+    let poll_id = create_verifier_set_poll(
+        deps.storage,
+        env.block.height,
+        expires_at,
+        expires_at_time,
+        snapshot,
+        voting_threshold,
+    )?;
 
     poll_verifier_sets()
         .save(
@@ -67,7 +401,7 @@ pub fn verify_verifier_set(
         )
         .change_context(ContractError::StorageError)?;
 
-    Ok(Response::new().add_event(PollStarted::VerifierSet {
+    let poll_started = PollStarted::VerifierSet {
         verifier_set: VerifierSetConfirmation::new(
             message_id,
             config.msg_id_format,
@@ -79,15 +413,22 @@ pub fn verify_verifier_set(
             source_gateway_address: config.source_gateway_address,
             confirmation_height: config.confirmation_height,
             expires_at,
+            expires_at_time,
             participants,
+            voting_threshold,
+            observed_at: None,
         },
-    }))
+    };
+
+    Ok(Response::new().add_event(commit_poll_content(deps.storage, poll_started)?))
 }
 
 pub fn verify_messages(
     deps: DepsMut,
     env: Env,
+    info: MessageInfo,
     messages: Vec<Message>,
+    observed_at: Option<ObservedBlock>,
 ) -> Result<Response, ContractError> {
     if messages.is_empty() {
         return Err(report!(ContractError::EmptyMessages));
@@ -99,8 +440,7 @@ pub fn verify_messages(
         validate_source_chain(message, &config.source_chain)
             .and_then(|message| validate_source_address(message, &config.address_format))
             .and_then(|message| {
-                message_status(deps.as_ref(), &message, env.block.height)
-                    .map(|status| (status, message))
+                message_status(deps.as_ref(), &message, &env.block).map(|status| (status, message))
             })
     })?;
 
@@ -116,15 +456,34 @@ pub fn verify_messages(
         })
         .collect();
 
+    let refund = charge_verification_fee(
+        deps.storage,
+        config.verification_fee.as_ref(),
+        &info,
+        msgs_to_verify.len(),
+    )?;
+    let response = Response::new().add_messages(refund);
+
     if msgs_to_verify.is_empty() {
-        return Ok(Response::new());
+        return Ok(response);
     }
 
-    let snapshot = take_snapshot(deps.as_ref(), &config.source_chain)?;
+    let (snapshot, voting_threshold) = take_snapshot(deps.as_ref(), &config.source_chain)?;
     let participants = snapshot.participants();
-    let expires_at = calculate_expiration(env.block.height, config.block_expiry.into())?;
+    let (expires_at, expires_at_time) = poll_expiration(deps.storage, &env, &config)?;
 
-    let id = create_messages_poll(deps.storage, expires_at, snapshot, msgs_to_verify.len())?;
+    let id = create_messages_poll(
+        deps.storage,
+        env.block.height,
+        expires_at,
+        expires_at_time,
+        snapshot,
+        voting_threshold,
+        msgs_to_verify.len(),
+    )?;
+    POLL_STARTED_AT
+        .save(deps.storage, id, &env.block.height)
+        .change_context(ContractError::StorageError)?;
 
     for (idx, message) in msgs_to_verify.iter().enumerate() {
         poll_messages()
@@ -144,7 +503,7 @@ pub fn verify_messages(
         })
         .collect::<Result<Vec<TxEventConfirmation>, _>>()?;
 
-    Ok(Response::new().add_event(PollStarted::Messages {
+    let poll_started = PollStarted::Messages {
         messages,
         metadata: PollMetadata {
             poll_id: id,
@@ -152,15 +511,128 @@ pub fn verify_messages(
             source_gateway_address: config.source_gateway_address,
             confirmation_height: config.confirmation_height,
             expires_at,
+            expires_at_time,
             participants,
+            voting_threshold,
+            observed_at,
         },
-    }))
+    };
+
+    Ok(response.add_event(commit_poll_content(deps.storage, poll_started)?))
+}
+
+pub fn verify_messages_not_executed(
+    deps: DepsMut,
+    env: Env,
+    messages: Vec<Message>,
+    up_to_height: u64,
+) -> Result<Response, ContractError> {
+    if messages.is_empty() {
+        return Err(report!(ContractError::EmptyMessages));
+    }
+
+    let config = CONFIG.load(deps.storage).expect("failed to load config");
+
+    let claims = messages.try_map(|message| {
+        validate_source_chain(message, &config.source_chain)
+            .and_then(|message| validate_source_address(message, &config.address_format))
+            .map(|message| NotExecutedClaim {
+                message,
+                up_to_height,
+            })
+            .and_then(|claim| {
+                not_executed_claim_status(deps.as_ref(), &claim, &env.block)
+                    .map(|status| (status, claim))
+            })
+    })?;
+
+    // confirmed and refuted claims are final, so only claims without a conclusive poll get verified
+    let claims_to_verify: Vec<NotExecutedClaim> = claims
+        .into_iter()
+        .filter_map(|(status, claim)| match status {
+            VerificationStatus::FailedToVerify | VerificationStatus::Unknown => Some(claim),
+            VerificationStatus::InProgress
+            | VerificationStatus::NotFoundOnSourceChain
+            | VerificationStatus::SucceededOnSourceChain
+            | VerificationStatus::FailedOnSourceChain => None,
+        })
+        .collect();
+
+    if claims_to_verify.is_empty() {
+        return Ok(Response::new());
+    }
+
+    let (snapshot, voting_threshold) = take_snapshot(deps.as_ref(), &config.source_chain)?;
+    let participants = snapshot.participants();
+    let (expires_at, expires_at_time) = poll_expiration(deps.storage, &env, &config)?;
+
+    let id = create_messages_not_executed_poll(
+        deps.storage,
+        env.block.height,
+        expires_at,
+        expires_at_time,
+        snapshot,
+        voting_threshold,
+        claims_to_verify.len(),
+    )?;
+
+    for (idx, claim) in claims_to_verify.iter().enumerate() {
+        poll_not_executed_claims()
+            .save(
+                deps.storage,
+                &claim.hash(),
+                &PollContent::<NotExecutedClaim>::new(claim.clone(), id, idx),
+            )
+            .change_context(ContractError::StorageError)?;
+    }
+
+    let messages = claims_to_verify
+        .into_iter()
+        .map(|claim| {
+            TxEventConfirmation::try_from((claim.message, &config.msg_id_format))
+                .map_err(|err| report!(err))
+        })
+        .collect::<Result<Vec<TxEventConfirmation>, _>>()?;
+
+    let poll_started = PollStarted::MessagesNotExecuted {
+        messages,
+        up_to_height,
+        metadata: PollMetadata {
+            poll_id: id,
+            source_chain: config.source_chain,
+            source_gateway_address: config.source_gateway_address,
+            confirmation_height: config.confirmation_height,
+            expires_at,
+            expires_at_time,
+            participants,
+            voting_threshold,
+            observed_at: None,
+        },
+    };
+
+    Ok(Response::new().add_event(commit_poll_content(deps.storage, poll_started)?))
+}
+
+/// Stores the commitment to the content of the started poll and returns the poll started event that carries it
+fn commit_poll_content(
+    storage: &mut dyn Storage,
+    poll_started: PollStarted,
+) -> Result<Event, ContractError> {
+    let poll_id = poll_started.poll_id();
+    let event = Event::from(poll_started);
+
+    POLL_CONTENT_HASHES
+        .save(storage, poll_id, &events::poll_content_hash(&event))
+        .change_context(ContractError::StorageError)?;
+
+    Ok(event)
 }
 
 fn poll_results(poll: &Poll) -> PollResults {
     match poll {
         Poll::Messages(weighted_poll) => weighted_poll.results(),
         Poll::ConfirmVerifierSet(weighted_poll) => weighted_poll.results(),
+        Poll::MessagesNotExecuted(weighted_poll) => weighted_poll.results(),
     }
 }
 
@@ -171,11 +643,7 @@ fn make_quorum_event(
     poll: &Poll,
     deps: &DepsMut,
 ) -> Result<Option<Event>, ContractError> {
-    let status = vote.map(|vote| match vote {
-        Vote::SucceededOnChain => VerificationStatus::SucceededOnSourceChain,
-        Vote::FailedOnChain => VerificationStatus::FailedOnSourceChain,
-        Vote::NotFound => VerificationStatus::NotFoundOnSourceChain,
-    });
+    let status = vote.map(vote_to_status);
 
     match poll {
         Poll::Messages(_) => {
@@ -210,6 +678,22 @@ fn make_quorum_event(
                 .into()
             }))
         }
+        Poll::MessagesNotExecuted(_) => {
+            let claim = poll_not_executed_claims()
+                .idx
+                .load_claim(deps.storage, *poll_id, index_in_poll)
+                .change_context(ContractError::StorageError)
+                .expect("claim not found in poll");
+
+            Ok(status.map(|status| {
+                QuorumReached {
+                    content: claim,
+                    status,
+                    poll_id: *poll_id,
+                }
+                .into()
+            }))
+        }
     }
 }
 
@@ -220,15 +704,18 @@ pub fn vote(
     poll_id: PollId,
     votes: Vec<Vote>,
 ) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage).expect("failed to load config");
+
     let poll = POLLS
         .may_load(deps.storage, poll_id)
         .change_context(ContractError::StorageError)?
         .ok_or(ContractError::PollNotFound)?;
+    ensure_not_cancelled(deps.storage, poll_id)?;
 
     let results_before_voting = poll_results(&poll);
 
     let poll = poll.try_map(|poll| {
-        poll.cast_vote(env.block.height, &info.sender, votes.clone())
+        poll.cast_vote(&env.block, &info.sender, votes.clone())
             .map_err(ContractError::from)
     })?;
     POLLS
@@ -237,44 +724,82 @@ pub fn vote(
 
     let results_after_voting = poll_results(&poll);
 
-    let quorum_events = results_after_voting
+    let newly_decided = results_after_voting
         .difference(results_before_voting)
         .expect("failed to substract poll results")
-        .0
-        .into_iter()
+        .0;
+
+    let quorum_events = newly_decided
+        .iter()
         .enumerate()
         .map(|(index_in_poll, vote)| {
             let idx = u32::try_from(index_in_poll)
                 .expect("the amount of votes should never overflow u32");
-            make_quorum_event(vote, idx, &poll_id, &poll, &deps)
+            make_quorum_event(vote.clone(), idx, &poll_id, &poll, &deps)
         })
         .collect::<Result<Vec<Option<Event>>, _>>()?;
 
+    let finalized_events = match &poll {
+        Poll::Messages(weighted_poll) => {
+            let blocks_to_quorum = POLL_STARTED_AT
+                .may_load(deps.storage, poll_id)
+                .change_context(ContractError::StorageError)?
+                .map(|started_at| env.block.height.saturating_sub(started_at));
+
+            newly_decided
+                .into_iter()
+                .enumerate()
+                .filter_map(|(index_in_poll, vote)| vote.map(|vote| (index_in_poll, vote)))
+                .map(|(index_in_poll, vote)| {
+                    Ok(MessageVerificationFinalized {
+                        poll_id,
+                        source_chain: config.source_chain.clone(),
+                        message: load_poll_message(deps.storage, poll_id, index_in_poll)?,
+                        status: vote_to_status(vote),
+                        blocks_to_quorum,
+                        votes_received: votes_received(weighted_poll),
+                        poll_expired: false,
+                    }
+                    .into())
+                })
+                .collect::<Result<Vec<Event>, _>>()?
+        }
+        Poll::ConfirmVerifierSet(_) | Poll::MessagesNotExecuted(_) => vec![],
+    };
+
     VOTES
         .save(deps.storage, (poll_id, info.sender.to_string()), &votes)
         .change_context(ContractError::StorageError)?;
 
+    if config.tracks_missed_polls() {
+        MISSED_POLLS.remove(deps.storage, info.sender.as_str());
+    }
+
     Ok(Response::new()
         .add_event(Voted {
             poll_id,
             voter: info.sender,
             votes,
         })
-        .add_events(quorum_events.into_iter().flatten()))
+        .add_events(quorum_events.into_iter().flatten())
+        .add_events(finalized_events))
 }
 
-pub fn end_poll(deps: DepsMut, env: Env, poll_id: PollId) -> Result<Response, ContractError> {
+pub fn end_poll(mut deps: DepsMut, env: Env, poll_id: PollId) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage).expect("failed to load config");
 
+    ensure_not_cancelled(deps.storage, poll_id)?;
+
     let poll = POLLS
         .may_load(deps.storage, poll_id)
         .change_context(ContractError::StorageError)?
         .ok_or(ContractError::PollNotFound)?
-        .try_map(|poll| poll.finish(env.block.height).map_err(ContractError::from))?;
+        .try_map(|poll| poll.finish(&env.block).map_err(ContractError::from))?;
 
     POLLS
         .save(deps.storage, poll_id, &poll)
         .change_context(ContractError::StorageError)?;
+    POLL_VOTING_THRESHOLDS.remove(deps.storage, poll_id);
 
     let votes: Vec<(String, Vec<Vote>)> = VOTES
         .prefix(poll_id)
@@ -282,12 +807,100 @@ pub fn end_poll(deps: DepsMut, env: Env, poll_id: PollId) -> Result<Response, Co
         .try_collect()
         .change_context(ContractError::StorageError)?;
 
-    let poll_result = match &poll {
-        Poll::Messages(poll) | Poll::ConfirmVerifierSet(poll) => {
-            poll.state(HashMap::from_iter(votes))
+    if config.tracks_missed_polls() {
+        record_missed_polls(deps.storage, &poll, &votes)?;
+    }
+
+    let votes_received = votes.len() as u64;
+
+    let (poll_result, vote_latencies) = match &poll {
+        Poll::Messages(poll) | Poll::ConfirmVerifierSet(poll) | Poll::MessagesNotExecuted(poll) => {
+            let mut poll_result = poll.state(HashMap::from_iter(votes));
+            let vote_latencies = poll.vote_latencies();
+
+            // verifiers that only voted well after the poll was already decided don't participate in rewards.
+            // Votes of polls that reached quorum before the quorum height was recorded are never considered late
+            if let Some(grace_blocks) = config.late_vote_grace_blocks {
+                let late_voters: HashSet<&str> = vote_latencies
+                    .iter()
+                    .filter(|latency| {
+                        latency
+                            .blocks_after_quorum
+                            .is_some_and(|blocks_after_quorum| blocks_after_quorum > grace_blocks)
+                    })
+                    .map(|latency| latency.voter.as_str())
+                    .collect();
+
+                poll_result
+                    .consensus_participants
+                    .retain(|address| !late_voters.contains(address.as_str()));
+            }
+
+            (poll_result, vote_latencies)
         }
     };
 
+    let expired_messages = match &poll {
+        Poll::Messages(_) => {
+            POLL_STARTED_AT.remove(deps.storage, poll_id);
+
+            poll_result
+                .results
+                .0
+                .iter()
+                .enumerate()
+                .filter(|(_, vote)| vote.is_none())
+                .map(|(index_in_poll, _)| load_poll_message(deps.storage, poll_id, index_in_poll))
+                .collect::<Result<Vec<Message>, _>>()?
+        }
+        Poll::ConfirmVerifierSet(_) | Poll::MessagesNotExecuted(_) => vec![],
+    };
+
+    let (expired_messages, retry_events) = match &config.poll_retry {
+        Some(poll_retry) if !expired_messages.is_empty() => retry_expired_messages(
+            deps.branch(),
+            &env,
+            &config,
+            poll_retry,
+            poll_id,
+            expired_messages,
+        )?,
+        _ => (expired_messages, vec![]),
+    };
+
+    // retry counts are removed once a message gets a final status, so only the messages of retry polls can have one
+    if RETRIED_POLLS.has(deps.storage, poll_id) {
+        let decided_messages = poll_result
+            .results
+            .0
+            .iter()
+            .enumerate()
+            .filter(|(_, vote)| vote.is_some())
+            .map(|(index_in_poll, _)| load_poll_message(deps.storage, poll_id, index_in_poll))
+            .collect::<Result<Vec<Message>, _>>()?;
+
+        for message in decided_messages.iter().chain(expired_messages.iter()) {
+            MESSAGE_RETRIES.remove(deps.storage, &message.hash());
+        }
+    }
+
+    // messages that did not reach quorum before the poll expired and are not retried only get their final status now
+    let finalized_events = expired_messages
+        .into_iter()
+        .map(|message| {
+            MessageVerificationFinalized {
+                poll_id,
+                source_chain: config.source_chain.clone(),
+                message,
+                status: VerificationStatus::FailedToVerify,
+                blocks_to_quorum: None,
+                votes_received,
+                poll_expired: true,
+            }
+            .into()
+        })
+        .collect::<Vec<Event>>();
+
     // TODO: change rewards contract interface to accept a list of addresses to avoid creating multiple wasm messages
     let rewards_msgs = poll_result
         .consensus_participants
@@ -312,65 +925,549 @@ pub fn end_poll(deps: DepsMut, env: Env, poll_id: PollId) -> Result<Response, Co
             poll_id: poll_result.poll_id,
             results: poll_result.results.0.clone(),
             source_chain: config.source_chain,
-        }))
+            vote_latencies,
+        })
+        .add_events(finalized_events)
+        .add_events(retry_events))
 }
 
-fn take_snapshot(deps: Deps, chain: &ChainName) -> Result<snapshot::Snapshot, ContractError> {
+/// Starts a new poll for the messages of an expired poll that did not reach quorum and have retries left.
+/// Returns the messages that are not retried, together with the events of the new poll.
+/// If no new poll can be started, e.g. because there are no active verifiers, none of the messages are retried and a
+/// `poll_retry_failed` event is emitted instead, so ending the expired poll never fails because of the retry
+fn retry_expired_messages(
+    deps: DepsMut,
+    env: &Env,
+    config: &Config,
+    poll_retry: &PollRetry,
+    poll_id: PollId,
+    messages: Vec<Message>,
+) -> Result<(Vec<Message>, Vec<Event>), ContractError> {
+    let max_retries: u64 = poll_retry.max_retries.into();
+
+    let mut to_retry = vec![];
+    let mut not_retried = vec![];
+    for message in messages {
+        let retries = MESSAGE_RETRIES
+            .may_load(deps.storage, &message.hash())
+            .change_context(ContractError::StorageError)?
+            .unwrap_or_default();
+
+        if retries < max_retries {
+            to_retry.push((message, retries));
+        } else {
+            not_retried.push(message);
+        }
+    }
+
+    if to_retry.is_empty() {
+        return Ok((not_retried, vec![]));
+    }
+
+    let (snapshot, voting_threshold) = match take_snapshot(deps.as_ref(), &config.source_chain) {
+        Ok(snapshot) => snapshot,
+        Err(err) => {
+            let poll_retry_failed = PollRetryFailed {
+                poll_id,
+                source_chain: config.source_chain.clone(),
+                messages: to_retry
+                    .iter()
+                    .map(|(message, _)| message.cc_id.clone())
+                    .collect(),
+                reason: err.current_context().to_string(),
+            };
+            not_retried.extend(to_retry.into_iter().map(|(message, _)| message));
+            return Ok((not_retried, vec![poll_retry_failed.into()]));
+        }
+    };
+    let participants = snapshot.participants();
+    let (expires_at, expires_at_time) = poll_expiration(deps.storage, env, config)?;
+
+    let retry_poll_id = create_messages_poll(
+        deps.storage,
+        env.block.height,
+        expires_at,
+        expires_at_time,
+        snapshot,
+        voting_threshold,
+        to_retry.len(),
+    )?;
+    POLL_STARTED_AT
+        .save(deps.storage, retry_poll_id, &env.block.height)
+        .change_context(ContractError::StorageError)?;
+    RETRIED_POLLS
+        .save(deps.storage, retry_poll_id, &poll_id)
+        .change_context(ContractError::StorageError)?;
+
+    for (idx, (message, retries)) in to_retry.iter().enumerate() {
+        poll_messages()
+            .save(
+                deps.storage,
+                &message.hash(),
+                &state::PollContent::<Message>::new(message.clone(), retry_poll_id, idx),
+            )
+            .change_context(ContractError::StorageError)?;
+        MESSAGE_RETRIES
+            .save(deps.storage, &message.hash(), &retries.saturating_add(1))
+            .change_context(ContractError::StorageError)?;
+    }
+
+    let messages: Vec<Message> = to_retry.into_iter().map(|(message, _)| message).collect();
+
+    let poll_retried = PollRetried {
+        poll_id,
+        retry_poll_id,
+        source_chain: config.source_chain.clone(),
+        messages: messages
+            .iter()
+            .map(|message| message.cc_id.clone())
+            .collect(),
+    };
+
+    let messages = messages
+        .into_iter()
+        .map(|msg| {
+            TxEventConfirmation::try_from((msg, &config.msg_id_format)).map_err(|err| report!(err))
+        })
+        .collect::<Result<Vec<TxEventConfirmation>, _>>()?;
+
+    let poll_started = PollStarted::Messages {
+        messages,
+        metadata: PollMetadata {
+            poll_id: retry_poll_id,
+            source_chain: config.source_chain.clone(),
+            source_gateway_address: config.source_gateway_address.clone(),
+            confirmation_height: config.confirmation_height,
+            expires_at,
+            expires_at_time,
+            participants,
+            voting_threshold,
+            observed_at: None,
+        },
+    };
+
+    Ok((
+        not_retried,
+        vec![
+            commit_poll_content(deps.storage, poll_started)?,
+            poll_retried.into(),
+        ],
+    ))
+}
+
+pub fn cancel_poll(
+    deps: DepsMut,
+    env: Env,
+    poll_id: PollId,
+    reason: nonempty::String,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage).expect("failed to load config");
+
+    let poll = POLLS
+        .may_load(deps.storage, poll_id)
+        .change_context(ContractError::StorageError)?
+        .ok_or(ContractError::PollNotFound)?;
+    ensure_not_cancelled(deps.storage, poll_id)?;
+
+    // participation of ended polls has already been recorded for rewards, so they cannot be cancelled anymore
+    ensure!(
+        poll.weighted_poll().status(&env.block) != PollStatus::Finished,
+        ContractError::PollAlreadyEnded(poll_id)
+    );
+
+    CANCELLED_POLLS
+        .save(deps.storage, poll_id, &reason)
+        .change_context(ContractError::StorageError)?;
+    // cancelled polls can't be ended, so this per-poll data would never be cleaned up otherwise
+    POLL_STARTED_AT.remove(deps.storage, poll_id);
+    POLL_VOTING_THRESHOLDS.remove(deps.storage, poll_id);
+
+    Ok(Response::new().add_event(PollCancelled {
+        poll_id,
+        source_chain: config.source_chain,
+        reason,
+    }))
+}
+
+/// Charges the verification fee for `msg_count` messages from the attached funds and returns a refund of any excess to the sender.
+/// Funds are not touched if no verification fee is configured. Exempt callers are charged nothing and get attached fee funds refunded
+fn charge_verification_fee(
+    storage: &mut dyn Storage,
+    verification_fee: Option<&VerificationFee>,
+    info: &MessageInfo,
+    msg_count: usize,
+) -> Result<Option<BankMsg>, ContractError> {
+    let Some(fee) = verification_fee else {
+        return Ok(None);
+    };
+
+    let msg_count = if fee
+        .exempt_callers
+        .iter()
+        .any(|caller| caller.as_str() == info.sender.as_str())
+    {
+        0
+    } else {
+        msg_count
+    };
+
+    ensure!(
+        info.funds
+            .iter()
+            .all(|funds| funds.denom == fee.denom.as_str()),
+        ContractError::WrongFeeDenom(fee.denom.to_string())
+    );
+
+    let paid = info
+        .funds
+        .iter()
+        .find(|funds| funds.denom == fee.denom.as_str())
+        .map(|funds| funds.amount)
+        .unwrap_or_default();
+    let required = Uint128::from(fee.amount)
+        .checked_mul(Uint128::from(
+            u128::try_from(msg_count).expect("message count should fit into u128"),
+        ))
+        .map_err(ContractError::from)?;
+    let excess = paid.checked_sub(required).map_err(|_| {
+        ContractError::InsufficientVerificationFee(coin(required.u128(), fee.denom.as_str()))
+    })?;
+
+    if !required.is_zero() {
+        let accrued = ACCRUED_FEES
+            .may_load(storage, fee.denom.as_str())
+            .change_context(ContractError::StorageError)?
+            .unwrap_or_default()
+            .checked_add(required)
+            .map_err(ContractError::from)?;
+        ACCRUED_FEES
+            .save(storage, fee.denom.as_str(), &accrued)
+            .change_context(ContractError::StorageError)?;
+    }
+
+    Ok((!excess.is_zero()).then(|| BankMsg::Send {
+        to_address: info.sender.to_string(),
+        amount: coins(excess.u128(), fee.denom.as_str()),
+    }))
+}
+
+/// Increments the number of consecutive missed polls of every participant of the poll that did not vote
+fn record_missed_polls(
+    storage: &mut dyn Storage,
+    poll: &Poll,
+    votes: &[(String, Vec<Vote>)],
+) -> Result<(), ContractError> {
+    let voters: HashSet<&str> = votes.iter().map(|(voter, _)| voter.as_str()).collect();
+
+    let poll = match poll {
+        Poll::Messages(poll) | Poll::ConfirmVerifierSet(poll) | Poll::MessagesNotExecuted(poll) => {
+            poll
+        }
+    };
+
+    poll.participation
+        .keys()
+        .filter(|participant| !voters.contains(participant.as_str()))
+        .try_for_each(|participant| {
+            MISSED_POLLS
+                .update(storage, participant.as_str(), |missed| {
+                    Ok::<_, cosmwasm_std::StdError>(missed.unwrap_or_default().saturating_add(1))
+                })
+                .map(|_| ())
+        })
+        .change_context(ContractError::StorageError)
+}
+
+/// Scales the weights of the participants so that verifiers who missed too many polls in a row only keep the decayed fraction of
+/// their weight, while all other verifiers keep their full weight
+fn apply_vote_weight_decay(
+    storage: &dyn Storage,
+    decay: &VoteWeightDecay,
+    participants: Vec<snapshot::Participant>,
+) -> Result<Vec<snapshot::Participant>, ContractError> {
+    let missed_polls_threshold: u64 = decay.missed_polls_threshold.into();
+
+    participants
+        .into_iter()
+        .map(|participant| {
+            let missed_polls = MISSED_POLLS
+                .may_load(storage, participant.address.as_str())
+                .change_context(ContractError::StorageError)?
+                .unwrap_or_default();
+
+            let factor = if missed_polls >= missed_polls_threshold {
+                decay.decayed_weight.numerator()
+            } else {
+                decay.decayed_weight.denominator()
+            };
+            let factor = nonempty::Uint64::try_from(factor)
+                .expect("violated invariant: threshold numerator and denominator are non-zero");
+
+            Ok(participant
+                .scale_weight(factor)
+                .map_err(ContractError::from)?)
+        })
+        .collect()
+}
+
+/// Scales the voting threshold with the share of active participants, i.e. participants that did not miss too many polls in a row,
+/// so polls can still reach quorum while some verifiers are inactive. The scaled threshold never drops below the floor
+fn dynamic_voting_threshold(
+    storage: &dyn Storage,
+    voting_threshold: MajorityThreshold,
+    dynamic: &DynamicVotingThreshold,
+    participants: &[snapshot::Participant],
+) -> Result<MajorityThreshold, ContractError> {
+    let missed_polls_threshold: u64 = dynamic.missed_polls_threshold.into();
+
+    let active = participants
+        .iter()
+        .map(|participant| MISSED_POLLS.may_load(storage, participant.address.as_str()))
+        .filter_ok(|missed_polls| missed_polls.unwrap_or_default() < missed_polls_threshold)
+        .process_results(|active| active.count())
+        .change_context(ContractError::StorageError)?;
+
+    let scaled = Threshold::try_from((
+        voting_threshold
+            .numerator()
+            .checked_mul(Uint64::from(active as u64))
+            .map_err(ContractError::from)?,
+        voting_threshold
+            .denominator()
+            .checked_mul(Uint64::from(participants.len() as u64))
+            .map_err(ContractError::from)?,
+    ));
+
+    match scaled {
+        Ok(scaled) if scaled >= Threshold::from(dynamic.floor) => {
+            Ok(MajorityThreshold::try_from(scaled)
+                .expect("violated invariant: a threshold above the majority floor is a majority"))
+        }
+        _ => Ok(dynamic.floor),
+    }
+}
+
+fn vote_to_status(vote: Vote) -> VerificationStatus {
+    match vote {
+        Vote::SucceededOnChain => VerificationStatus::SucceededOnSourceChain,
+        Vote::FailedOnChain => VerificationStatus::FailedOnSourceChain,
+        Vote::NotFound => VerificationStatus::NotFoundOnSourceChain,
+        Vote::Abstain => unreachable!("violated invariant: abstain votes never reach quorum"),
+    }
+}
+
+fn votes_received(poll: &WeightedPoll) -> u64 {
+    poll.participation
+        .values()
+        .filter(|participation| participation.voted)
+        .count() as u64
+}
+
+fn load_poll_message(
+    storage: &dyn Storage,
+    poll_id: PollId,
+    index_in_poll: usize,
+) -> Result<Message, ContractError> {
+    let index_in_poll =
+        u32::try_from(index_in_poll).expect("the amount of votes should never overflow u32");
+
+    poll_messages()
+        .idx
+        .load_message(storage, poll_id, index_in_poll)
+        .change_context(ContractError::StorageError)?
+        .ok_or(report!(ContractError::StorageError))
+}
+
+fn ensure_not_cancelled(storage: &dyn Storage, poll_id: PollId) -> Result<(), ContractError> {
+    ensure!(
+        !CANCELLED_POLLS.has(storage, poll_id),
+        ContractError::PollCancelled(poll_id)
+    );
+
+    Ok(())
+}
+
+/// Returns the snapshot of the participants of a new poll, together with the voting threshold its quorum is based on
+fn take_snapshot(
+    deps: Deps,
+    chain: &ChainName,
+) -> Result<(snapshot::Snapshot, MajorityThreshold), ContractError> {
     let config = CONFIG.load(deps.storage).expect("failed to load config");
 
     let service_registry: service_registry_api::Client =
         client::ContractClient::new(deps.querier, &config.service_registry_contract).into();
 
-    let verifiers: Vec<WeightedVerifier> = service_registry
-        .active_verifiers(config.service_name.into(), chain.to_owned())
+    let mut participants = service_registry
+        .active_participants(config.service_name.into(), chain.to_owned())
         .change_context(ContractError::FailedToBuildSnapshot)?;
 
-    let participants = verifiers
-        .into_iter()
-        .map(WeightedVerifier::into)
-        .collect::<Vec<snapshot::Participant>>();
+    if let Some(decay) = &config.vote_weight_decay {
+        participants = apply_vote_weight_decay(deps.storage, decay, participants)?;
+    }
 
-    Ok(snapshot::Snapshot::new(
-        config.voting_threshold,
-        participants.try_into().map_err(ContractError::from)?,
+    let voting_threshold = match &config.dynamic_voting_threshold {
+        Some(dynamic) => dynamic_voting_threshold(
+            deps.storage,
+            config.voting_threshold,
+            dynamic,
+            &participants,
+        )?,
+        None => config.voting_threshold,
+    };
+
+    Ok((
+        snapshot::Snapshot::new(
+            voting_threshold,
+            participants.try_into().map_err(ContractError::from)?,
+        ),
+        voting_threshold,
     ))
 }
 
 fn create_verifier_set_poll(
     store: &mut dyn Storage,
+    started_at: u64,
     expires_at: u64,
+    expires_at_time: Option<Timestamp>,
     snapshot: snapshot::Snapshot,
+    voting_threshold: MajorityThreshold,
 ) -> Result<PollId, ContractError> {
     let id = POLL_ID
         .incr(store)
         .change_context(ContractError::StorageError)?;
 
-    let poll = WeightedPoll::new(id, snapshot, expires_at, 1);
+    let poll = new_poll(id, snapshot, started_at, expires_at, expires_at_time, 1);
     POLLS
         .save(store, id, &Poll::ConfirmVerifierSet(poll))
         .change_context(ContractError::StorageError)?;
+    POLL_VOTING_THRESHOLDS
+        .save(store, id, &voting_threshold)
+        .change_context(ContractError::StorageError)?;
 
     Ok(id)
 }
 
 fn create_messages_poll(
     store: &mut dyn Storage,
+    started_at: u64,
     expires_at: u64,
+    expires_at_time: Option<Timestamp>,
     snapshot: snapshot::Snapshot,
+    voting_threshold: MajorityThreshold,
     poll_size: usize,
 ) -> Result<PollId, ContractError> {
     let id = POLL_ID
         .incr(store)
         .change_context(ContractError::StorageError)?;
 
-    let poll = WeightedPoll::new(id, snapshot, expires_at, poll_size);
+    let poll = new_poll(
+        id,
+        snapshot,
+        started_at,
+        expires_at,
+        expires_at_time,
+        poll_size,
+    );
     POLLS
         .save(store, id, &Poll::Messages(poll))
         .change_context(ContractError::StorageError)?;
+    POLL_VOTING_THRESHOLDS
+        .save(store, id, &voting_threshold)
+        .change_context(ContractError::StorageError)?;
+
+    Ok(id)
+}
+
+fn create_messages_not_executed_poll(
+    store: &mut dyn Storage,
+    started_at: u64,
+    expires_at: u64,
+    expires_at_time: Option<Timestamp>,
+    snapshot: snapshot::Snapshot,
+    voting_threshold: MajorityThreshold,
+    poll_size: usize,
+) -> Result<PollId, ContractError> {
+    let id = POLL_ID
+        .incr(store)
+        .change_context(ContractError::StorageError)?;
+
+    let poll = new_poll(
+        id,
+        snapshot,
+        started_at,
+        expires_at,
+        expires_at_time,
+        poll_size,
+    );
+    POLLS
+        .save(store, id, &Poll::MessagesNotExecuted(poll))
+        .change_context(ContractError::StorageError)?;
+    POLL_VOTING_THRESHOLDS
+        .save(store, id, &voting_threshold)
+        .change_context(ContractError::StorageError)?;
 
     Ok(id)
 }
 
+fn new_poll(
+    id: PollId,
+    snapshot: snapshot::Snapshot,
+    started_at: u64,
+    expires_at: u64,
+    expires_at_time: Option<Timestamp>,
+    poll_size: usize,
+) -> WeightedPoll {
+    let poll = WeightedPoll::new(id, snapshot, expires_at, poll_size).with_start_height(started_at);
+
+    match expires_at_time {
+        Some(expires_at_time) => poll.with_time_expiry(expires_at_time),
+        None => poll,
+    }
+}
+
+/// Polls expire after the configured number of blocks. Polls started while the source chain is halted expire the rest
+/// of the halt later, and only by block height. The same applies to maintenance windows of the source chain that
+/// overlap with the poll
+fn poll_expiration(
+    storage: &dyn Storage,
+    env: &Env,
+    config: &Config,
+) -> Result<(u64, Option<Timestamp>), ContractError> {
+    let expires_at = calculate_expiration(env.block.height, config.block_expiry.into())?;
+
+    let (expires_at, expires_at_time) =
+        match state::active_source_chain_halt(storage, env.block.height)
+            .change_context(ContractError::StorageError)?
+        {
+            Some(halt) => (
+                expires_at.saturating_add(halt.ends_at.saturating_sub(env.block.height)),
+                None,
+            ),
+            None => (expires_at, calculate_time_expiration(env, config)),
+        };
+
+    let maintenance_blocks = MAINTENANCE_WINDOWS
+        .may_load(storage)
+        .change_context(ContractError::StorageError)?
+        .unwrap_or_default()
+        .iter()
+        .map(|window| window.blocks_within(env.block.height, expires_at))
+        .fold(0u64, u64::saturating_add);
+
+    if maintenance_blocks == 0 {
+        return Ok((expires_at, expires_at_time));
+    }
+
+    Ok((expires_at.saturating_add(maintenance_blocks), None))
+}
+
+/// Polls additionally expire after the configured wall-clock duration, whichever comes first
+fn calculate_time_expiration(env: &Env, config: &Config) -> Option<Timestamp> {
+    config
+        .poll_time_expiry
+        .map(|expiry| Timestamp::from(env.block.time).saturating_add(expiry))
+}
+
 fn calculate_expiration(block_height: u64, block_expiry: u64) -> Result<u64, ContractError> {
     block_height
         .checked_add(block_expiry)
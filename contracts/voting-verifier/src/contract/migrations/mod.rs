@@ -1,16 +1,42 @@
-use axelar_wasm_std::migrate_from_version;
+use axelar_wasm_std::{address, migrate_from_version};
+use cosmwasm_schema::cw_serde;
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
-use cosmwasm_std::{DepsMut, Empty, Env, Response};
+use cosmwasm_std::{Addr, DepsMut, Env, Response, StdError, Storage};
 
-pub type MigrateMsg = Empty;
+use crate::state::{Config, CONFIG};
+
+#[cw_serde]
+pub struct MigrateMsg {
+    /// The coordinator is allowed to set the maintenance windows of the source chain
+    pub coordinator_address: String,
+}
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 #[migrate_from_version("1.1")]
 pub fn migrate(
     deps: DepsMut,
     _env: Env,
-    _msg: MigrateMsg,
+    msg: MigrateMsg,
 ) -> Result<Response, axelar_wasm_std::error::ContractError> {
+    let coordinator = address::validate_cosmwasm_address(deps.api, &msg.coordinator_address)?;
+
+    add_coordinator_to_config(deps.storage, coordinator)?;
+
     Ok(Response::default())
 }
+
+/// Configs stored before the migration have no coordinator, so they can't be loaded as [Config] yet
+fn add_coordinator_to_config(storage: &mut dyn Storage, coordinator: Addr) -> Result<(), StdError> {
+    let mut config: serde_json::Value = storage
+        .get(CONFIG.as_slice())
+        .ok_or_else(|| StdError::not_found("config"))
+        .and_then(|raw| {
+            serde_json::from_slice(&raw).map_err(|err| StdError::parse_err("config", err))
+        })?;
+    config["coordinator"] = serde_json::Value::String(coordinator.into_string());
+
+    let config: Config =
+        serde_json::from_value(config).map_err(|err| StdError::parse_err("config", err))?;
+    CONFIG.save(storage, &config)
+}
@@ -1,13 +1,21 @@
-use axelar_wasm_std::voting::{PollId, PollStatus, Vote};
+use axelar_wasm_std::time::Duration;
+use axelar_wasm_std::voting::{PollId, PollStatus, Vote, VoteLatency};
 use axelar_wasm_std::{MajorityThreshold, VerificationStatus};
-use cosmwasm_std::Deps;
+use cosmwasm_std::{coin, Addr, BlockInfo, Coin, Deps, HexBinary, Order, StdResult};
 use error_stack::{Result, ResultExt};
 use multisig::verifier_set::VerifierSet;
 use router_api::Message;
 
 use crate::error::ContractError;
-use crate::msg::{MessageStatus, PollData, PollResponse};
-use crate::state::{poll_messages, poll_verifier_sets, Poll, PollContent, CONFIG, POLLS};
+use crate::msg::{
+    DynamicVotingThreshold, MessageStatus, PollData, PollResponse, PollRetry, SourceChainHalt,
+    VerificationFee, VoteWeightDecay,
+};
+use crate::state::{
+    self, poll_messages, poll_not_executed_claims, poll_verifier_sets, NotExecutedClaim, Poll,
+    PollContent, ACCRUED_FEES, CANCELLED_POLLS, CONFIG, MESSAGE_RETRIES, MISSED_POLLS, POLLS,
+    POLL_CONTENT_HASHES, POLL_EXTENSIONS, POLL_VOTING_THRESHOLDS, RETRIED_POLLS,
+};
 
 pub fn voting_threshold(deps: Deps) -> Result<MajorityThreshold, ContractError> {
     Ok(CONFIG
@@ -16,15 +24,133 @@ pub fn voting_threshold(deps: Deps) -> Result<MajorityThreshold, ContractError>
         .voting_threshold)
 }
 
+pub fn verification_fee(deps: Deps) -> Result<Option<VerificationFee>, ContractError> {
+    Ok(CONFIG
+        .load(deps.storage)
+        .change_context(ContractError::StorageError)?
+        .verification_fee)
+}
+
+pub fn accrued_fees(deps: Deps) -> Result<Vec<Coin>, ContractError> {
+    ACCRUED_FEES
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|entry| entry.map(|(denom, amount)| coin(amount.u128(), denom)))
+        .collect::<StdResult<_>>()
+        .change_context(ContractError::StorageError)
+}
+
+pub fn vote_weight_decay(deps: Deps) -> Result<Option<VoteWeightDecay>, ContractError> {
+    Ok(CONFIG
+        .load(deps.storage)
+        .change_context(ContractError::StorageError)?
+        .vote_weight_decay)
+}
+
+pub fn dynamic_voting_threshold(
+    deps: Deps,
+) -> Result<Option<DynamicVotingThreshold>, ContractError> {
+    Ok(CONFIG
+        .load(deps.storage)
+        .change_context(ContractError::StorageError)?
+        .dynamic_voting_threshold)
+}
+
+pub fn poll_retry(deps: Deps) -> Result<Option<PollRetry>, ContractError> {
+    Ok(CONFIG
+        .load(deps.storage)
+        .change_context(ContractError::StorageError)?
+        .poll_retry)
+}
+
+pub fn poll_time_expiry(deps: Deps) -> Result<Option<Duration>, ContractError> {
+    Ok(CONFIG
+        .load(deps.storage)
+        .change_context(ContractError::StorageError)?
+        .poll_time_expiry)
+}
+
+pub fn late_vote_grace_blocks(deps: Deps) -> Result<Option<u64>, ContractError> {
+    Ok(CONFIG
+        .load(deps.storage)
+        .change_context(ContractError::StorageError)?
+        .late_vote_grace_blocks)
+}
+
+pub fn source_chain_halt(
+    deps: Deps,
+    block: &BlockInfo,
+) -> Result<Option<SourceChainHalt>, ContractError> {
+    state::active_source_chain_halt(deps.storage, block.height)
+        .change_context(ContractError::StorageError)
+}
+
+pub fn poll_extensions_pending(deps: Deps) -> Result<bool, ContractError> {
+    Ok(!POLL_EXTENSIONS
+        .may_load(deps.storage)
+        .change_context(ContractError::StorageError)?
+        .unwrap_or_default()
+        .is_empty())
+}
+
+pub fn vote_latencies(deps: Deps, poll_id: PollId) -> Result<Vec<VoteLatency>, ContractError> {
+    Ok(POLLS
+        .load(deps.storage, poll_id)
+        .change_context(ContractError::PollNotFound)?
+        .weighted_poll()
+        .vote_latencies())
+}
+
+pub fn message_retries(deps: Deps, message: &Message) -> Result<u64, ContractError> {
+    Ok(MESSAGE_RETRIES
+        .may_load(deps.storage, &message.hash())
+        .change_context(ContractError::StorageError)?
+        .unwrap_or_default())
+}
+
+pub fn missed_polls(deps: Deps, verifier: Addr) -> Result<u64, ContractError> {
+    Ok(MISSED_POLLS
+        .may_load(deps.storage, verifier.as_str())
+        .change_context(ContractError::StorageError)?
+        .unwrap_or_default())
+}
+
+/// Polls expire in the order they were started, so the polls are checked from the most recent one backwards
+/// until the first expired one
+pub fn has_open_polls(
+    deps: Deps,
+    block: &BlockInfo,
+    verifier: Addr,
+) -> Result<bool, ContractError> {
+    for poll in POLLS.range(deps.storage, None, None, Order::Descending) {
+        let (_, poll) = poll.change_context(ContractError::StorageError)?;
+        let poll = poll.weighted_poll_ref();
+
+        if poll.is_expired(block) {
+            break;
+        }
+
+        if poll.status(block) == PollStatus::InProgress
+            && poll
+                .participation
+                .get(verifier.as_str())
+                .is_some_and(|participation| !participation.voted)
+        {
+            return Ok(true);
+        }
+    }
+
+    Ok(false)
+}
+
 pub fn messages_status(
     deps: Deps,
     messages: &[Message],
-    cur_block_height: u64,
+    block: &BlockInfo,
 ) -> Result<Vec<MessageStatus>, ContractError> {
     messages
         .iter()
         .map(|message| {
-            message_status(deps, message, cur_block_height)
+            message_status(deps, message, block)
                 .map(|status| MessageStatus::new(message.to_owned(), status))
         })
         .collect()
@@ -33,7 +159,7 @@ pub fn messages_status(
 pub fn message_status(
     deps: Deps,
     message: &Message,
-    cur_block_height: u64,
+    block: &BlockInfo,
 ) -> Result<VerificationStatus, ContractError> {
     let loaded_poll_content = poll_messages()
         .may_load(deps.storage, &message.hash())
@@ -43,13 +169,45 @@ pub fn message_status(
         deps,
         loaded_poll_content,
         message,
-        cur_block_height,
+        block,
     ))
 }
 
+pub fn messages_not_executed_status(
+    deps: Deps,
+    messages: &[Message],
+    up_to_height: u64,
+    block: &BlockInfo,
+) -> Result<Vec<MessageStatus>, ContractError> {
+    messages
+        .iter()
+        .map(|message| {
+            let claim = NotExecutedClaim {
+                message: message.to_owned(),
+                up_to_height,
+            };
+
+            not_executed_claim_status(deps, &claim, block)
+                .map(|status| MessageStatus::new(message.to_owned(), status))
+        })
+        .collect()
+}
+
+pub fn not_executed_claim_status(
+    deps: Deps,
+    claim: &NotExecutedClaim,
+    block: &BlockInfo,
+) -> Result<VerificationStatus, ContractError> {
+    let loaded_poll_content = poll_not_executed_claims()
+        .may_load(deps.storage, &claim.hash())
+        .change_context(ContractError::StorageError)?;
+
+    Ok(verification_status(deps, loaded_poll_content, claim, block))
+}
+
 pub fn poll_response(
     deps: Deps,
-    current_block_height: u64,
+    block: &BlockInfo,
     poll_id: PollId,
 ) -> Result<PollResponse, ContractError> {
     let poll = POLLS
@@ -68,7 +226,7 @@ pub fn poll_response(
                 poll.poll_id
             );
 
-            (PollData::Messages(msgs), poll.status(current_block_height))
+            (PollData::Messages(msgs), poll.status(block))
         }
         Poll::ConfirmVerifierSet(poll) => (
             PollData::VerifierSet(
@@ -78,21 +236,61 @@ pub fn poll_response(
                     .change_context(ContractError::StorageError)?
                     .expect("verifier set not found in poll"),
             ),
-            poll.status(current_block_height),
+            poll.status(block),
         ),
+        Poll::MessagesNotExecuted(poll) => {
+            let claims = poll_not_executed_claims()
+                .idx
+                .load_claims(deps.storage, poll_id)
+                .change_context(ContractError::StorageError)?;
+            assert_eq!(
+                poll.tallies.len(),
+                claims.len(),
+                "data inconsistency for number of claims in poll {}",
+                poll.poll_id
+            );
+
+            // all claims of a poll share the same height
+            let up_to_height = claims
+                .first()
+                .map(|claim| claim.up_to_height)
+                .expect("poll must contain at least one claim");
+
+            (
+                PollData::MessagesNotExecuted {
+                    messages: claims.into_iter().map(|claim| claim.message).collect(),
+                    up_to_height,
+                },
+                poll.status(block),
+            )
+        }
     };
 
+    let voting_threshold = POLL_VOTING_THRESHOLDS
+        .may_load(deps.storage, poll_id)
+        .change_context(ContractError::StorageError)?;
+    let retry_of = RETRIED_POLLS
+        .may_load(deps.storage, poll_id)
+        .change_context(ContractError::StorageError)?;
+    let content_hash = POLL_CONTENT_HASHES
+        .may_load(deps.storage, poll_id)
+        .change_context(ContractError::StorageError)?
+        .map(HexBinary::from);
+
     Ok(PollResponse {
         poll: poll.weighted_poll(),
         data,
         status,
+        voting_threshold,
+        retry_of,
+        content_hash,
     })
 }
 
 pub fn verifier_set_status(
     deps: Deps,
     verifier_set: &VerifierSet,
-    cur_block_height: u64,
+    block: &BlockInfo,
 ) -> Result<VerificationStatus, ContractError> {
     let loaded_poll_content = poll_verifier_sets()
         .may_load(
@@ -105,7 +303,7 @@ pub fn verifier_set_status(
         deps,
         loaded_poll_content,
         verifier_set,
-        cur_block_height,
+        block,
     ))
 }
 
@@ -113,7 +311,7 @@ fn verification_status<T: PartialEq + std::fmt::Debug>(
     deps: Deps,
     stored_poll_content: Option<PollContent<T>>,
     content: &T,
-    cur_block_height: u64,
+    block: &BlockInfo,
 ) -> VerificationStatus {
     match stored_poll_content {
         Some(stored) => {
@@ -122,12 +320,19 @@ fn verification_status<T: PartialEq + std::fmt::Debug>(
                 "invalid invariant: content mismatch with the stored one"
             );
 
+            // the content of a cancelled poll counts as unverified, so it can be verified again
+            if CANCELLED_POLLS.has(deps.storage, stored.poll_id) {
+                return VerificationStatus::Unknown;
+            }
+
             let poll = POLLS
                 .load(deps.storage, stored.poll_id)
                 .expect("invalid invariant: content's poll not found");
 
             let consensus = match &poll {
-                Poll::Messages(poll) | Poll::ConfirmVerifierSet(poll) => poll
+                Poll::Messages(poll)
+                | Poll::ConfirmVerifierSet(poll)
+                | Poll::MessagesNotExecuted(poll) => poll
                     .consensus(stored.index_in_poll)
                     .expect("invalid invariant: message not found in poll"),
             };
@@ -136,9 +341,10 @@ fn verification_status<T: PartialEq + std::fmt::Debug>(
                 Some(Vote::SucceededOnChain) => VerificationStatus::SucceededOnSourceChain,
                 Some(Vote::FailedOnChain) => VerificationStatus::FailedOnSourceChain,
                 Some(Vote::NotFound) => VerificationStatus::NotFoundOnSourceChain,
-                None if voting_completed(&poll, cur_block_height) => {
-                    VerificationStatus::FailedToVerify
+                Some(Vote::Abstain) => {
+                    unreachable!("violated invariant: abstain votes never reach quorum")
                 }
+                None if voting_completed(&poll, block) => VerificationStatus::FailedToVerify,
                 None => VerificationStatus::InProgress,
             }
         }
@@ -146,11 +352,11 @@ fn verification_status<T: PartialEq + std::fmt::Debug>(
     }
 }
 
-fn voting_completed(poll: &Poll, cur_block_height: u64) -> bool {
+fn voting_completed(poll: &Poll, block: &BlockInfo) -> bool {
     match poll {
-        Poll::Messages(poll) | Poll::ConfirmVerifierSet(poll) => {
+        Poll::Messages(poll) | Poll::ConfirmVerifierSet(poll) | Poll::MessagesNotExecuted(poll) => {
             matches!(
-                poll.status(cur_block_height),
+                poll.status(block),
                 PollStatus::Expired | PollStatus::Finished
             )
         }
@@ -199,7 +405,7 @@ mod tests {
                 msg.clone(),
                 VerificationStatus::InProgress
             )],
-            messages_status(deps.as_ref(), &[msg], cur_block_height).unwrap()
+            messages_status(deps.as_ref(), &[msg], &block(cur_block_height)).unwrap()
         );
     }
 
@@ -235,7 +441,7 @@ mod tests {
                 msg.clone(),
                 VerificationStatus::SucceededOnSourceChain
             )],
-            messages_status(deps.as_ref(), &[msg], cur_block_height).unwrap()
+            messages_status(deps.as_ref(), &[msg], &block(cur_block_height)).unwrap()
         );
     }
 
@@ -271,7 +477,7 @@ mod tests {
                 msg.clone(),
                 VerificationStatus::FailedToVerify
             )],
-            messages_status(deps.as_ref(), &[msg], expires_at).unwrap()
+            messages_status(deps.as_ref(), &[msg], &block(expires_at)).unwrap()
         );
     }
 
@@ -282,7 +488,7 @@ mod tests {
 
         assert_eq!(
             vec![MessageStatus::new(msg.clone(), VerificationStatus::Unknown)],
-            messages_status(deps.as_ref(), &[msg], 0).unwrap()
+            messages_status(deps.as_ref(), &[msg], &block(0)).unwrap()
         );
     }
 
@@ -315,9 +521,12 @@ mod tests {
             PollResponse {
                 poll: poll.clone(),
                 data: PollData::Messages(messages.collect_vec()),
-                status: PollStatus::Expired
+                status: PollStatus::Expired,
+                voting_threshold: None,
+                retry_of: None,
+                content_hash: None,
             },
-            super::poll_response(deps.as_ref(), mock_env().block.height, poll.poll_id).unwrap()
+            super::poll_response(deps.as_ref(), &mock_env().block, poll.poll_id).unwrap()
         );
     }
 
@@ -340,6 +549,13 @@ mod tests {
         }
     }
 
+    fn block(height: u64) -> BlockInfo {
+        BlockInfo {
+            height,
+            ..mock_env().block
+        }
+    }
+
     pub fn poll(expires_at: u64) -> WeightedPoll {
         let participants: nonempty::Vec<Participant> = vec!["addr1", "addr2", "addr3"]
             .into_iter()
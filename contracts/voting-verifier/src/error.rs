@@ -1,5 +1,6 @@
+use axelar_wasm_std::voting::PollId;
 use axelar_wasm_std::{nonempty, voting, IntoContractError};
-use cosmwasm_std::{OverflowError, StdError};
+use cosmwasm_std::{Coin, OverflowError, StdError};
 use router_api::ChainName;
 use thiserror::Error;
 
@@ -32,6 +33,12 @@ pub enum ContractError {
     #[error("poll not found")]
     PollNotFound,
 
+    #[error("poll {0} has been cancelled")]
+    PollCancelled(PollId),
+
+    #[error("poll {0} has already ended")]
+    PollAlreadyEnded(PollId),
+
     #[error(transparent)]
     VoteError(#[from] voting::Error),
 
@@ -47,6 +54,15 @@ pub enum ContractError {
     #[error("invalid source gateway address")]
     InvalidSourceGatewayAddress,
 
+    #[error("insufficient verification fee, {0} is required")]
+    InsufficientVerificationFee(Coin),
+
+    #[error("verification fee must be paid in {0}")]
+    WrongFeeDenom(String),
+
+    #[error("no accrued fees in {0}")]
+    NoAccruedFees(String),
+
     // Generic error to wrap cw_storage_plus errors
     // This should only be used for things that shouldn't happen, such as encountering
     // an error when loading data that should load successfully. For errors that can
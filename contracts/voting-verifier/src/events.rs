@@ -1,18 +1,22 @@
 use std::str::FromStr;
 use std::vec::Vec;
 
+use axelar_wasm_std::hash::Hash;
 use axelar_wasm_std::msg_id::{
     Base58SolanaTxSignatureAndEventIndex, Base58TxDigestAndEventIndex, Bech32mFormat,
     FieldElementAndEventIndex, HexTxHash, HexTxHashAndEventIndex, MessageIdFormat,
 };
-use axelar_wasm_std::voting::{PollId, Vote};
-use axelar_wasm_std::{nonempty, VerificationStatus};
+use axelar_wasm_std::time::Timestamp;
+use axelar_wasm_std::voting::{PollId, Vote, VoteLatency};
+use axelar_wasm_std::{canonical_json, nonempty, MajorityThreshold, VerificationStatus};
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Attribute, Event};
+use cosmwasm_std::{Addr, Attribute, Event, HexBinary};
 use multisig::verifier_set::VerifierSet;
-use router_api::{Address, ChainName, Message};
+use router_api::{Address, ChainName, CrossChainId, Message};
+use sha3::{Digest, Keccak256};
 
 use crate::error::ContractError;
+use crate::msg::ObservedBlock;
 use crate::state::Config;
 
 impl From<Config> for Vec<Attribute> {
@@ -29,6 +33,13 @@ impl From<Config> for Vec<Attribute> {
             rewards_contract,
             msg_id_format,
             address_format,
+            verification_fee,
+            vote_weight_decay,
+            dynamic_voting_threshold,
+            poll_retry,
+            poll_time_expiry,
+            late_vote_grace_blocks,
+            coordinator,
         } = other;
 
         vec![
@@ -40,7 +51,7 @@ impl From<Config> for Vec<Attribute> {
             ("source_gateway_address", source_gateway_address.to_string()),
             (
                 "voting_threshold",
-                serde_json::to_string(&voting_threshold)
+                canonical_json::to_string(&voting_threshold)
                     .expect("failed to serialize voting_threshold"),
             ),
             ("block_expiry", block_expiry.to_string()),
@@ -49,12 +60,44 @@ impl From<Config> for Vec<Attribute> {
             ("rewards_contract", rewards_contract.to_string()),
             (
                 "msg_id_format",
-                serde_json::to_string(&msg_id_format).expect("failed to serialize msg_id_format"),
+                canonical_json::to_string(&msg_id_format)
+                    .expect("failed to serialize msg_id_format"),
             ),
             (
                 "address_format",
-                serde_json::to_string(&address_format).expect("failed to serialize address_format"),
+                canonical_json::to_string(&address_format)
+                    .expect("failed to serialize address_format"),
             ),
+            (
+                "verification_fee",
+                canonical_json::to_string(&verification_fee)
+                    .expect("failed to serialize verification_fee"),
+            ),
+            (
+                "vote_weight_decay",
+                canonical_json::to_string(&vote_weight_decay)
+                    .expect("failed to serialize vote_weight_decay"),
+            ),
+            (
+                "dynamic_voting_threshold",
+                canonical_json::to_string(&dynamic_voting_threshold)
+                    .expect("failed to serialize dynamic_voting_threshold"),
+            ),
+            (
+                "poll_retry",
+                canonical_json::to_string(&poll_retry).expect("failed to serialize poll_retry"),
+            ),
+            (
+                "poll_time_expiry",
+                canonical_json::to_string(&poll_time_expiry)
+                    .expect("failed to serialize poll_time_expiry"),
+            ),
+            (
+                "late_vote_grace_blocks",
+                canonical_json::to_string(&late_vote_grace_blocks)
+                    .expect("failed to serialize late_vote_grace_blocks"),
+            ),
+            ("coordinator", coordinator.to_string()),
         ]
         .into_iter()
         .map(Attribute::from)
@@ -68,9 +111,18 @@ pub struct PollMetadata {
     pub source_gateway_address: nonempty::String,
     pub confirmation_height: u64,
     pub expires_at: u64,
+    /// Block time after which the poll also expires, if it has a wall-clock expiry
+    pub expires_at_time: Option<Timestamp>,
     pub participants: Vec<Addr>,
+    /// Voting threshold the poll was started with
+    pub voting_threshold: MajorityThreshold,
+    /// Source chain block at which the polled content was observed, if supplied with the verification request.
+    /// The event also carries the source chain height at which that block reaches the required confirmation height
+    pub observed_at: Option<ObservedBlock>,
 }
 
+const CONTENT_HASH_ATTRIBUTE: &str = "content_hash";
+
 pub enum PollStarted {
     Messages {
         messages: Vec<TxEventConfirmation>,
@@ -80,14 +132,44 @@ pub enum PollStarted {
         verifier_set: VerifierSetConfirmation,
         metadata: PollMetadata,
     },
+    MessagesNotExecuted {
+        messages: Vec<TxEventConfirmation>,
+        up_to_height: u64,
+        metadata: PollMetadata,
+    },
 }
 
 impl From<PollMetadata> for Vec<Attribute> {
     fn from(value: PollMetadata) -> Self {
+        let observed_at = value.observed_at.map(|observed_at| {
+            vec![
+                Attribute::new(
+                    "observed_at",
+                    canonical_json::to_string(&observed_at)
+                        .expect("failed to serialize observed_at"),
+                ),
+                Attribute::new(
+                    "observed_at_confirmation_height",
+                    observed_at
+                        .height
+                        .saturating_add(value.confirmation_height)
+                        .to_string(),
+                ),
+            ]
+        });
+
+        let expires_at_time = value.expires_at_time.map(|expires_at_time| {
+            Attribute::new(
+                "expires_at_time",
+                canonical_json::to_string(&expires_at_time)
+                    .expect("failed to serialize expires_at_time"),
+            )
+        });
+
         vec![
             (
                 "poll_id",
-                &serde_json::to_string(&value.poll_id).expect("failed to serialize poll_id"),
+                &canonical_json::to_string(&value.poll_id).expect("failed to serialize poll_id"),
             ),
             ("source_chain", &value.source_chain.to_string()),
             ("source_gateway_address", &value.source_gateway_address),
@@ -98,26 +180,63 @@ impl From<PollMetadata> for Vec<Attribute> {
             ("expires_at", &value.expires_at.to_string()),
             (
                 "participants",
-                &serde_json::to_string(&value.participants)
+                &canonical_json::to_string(&value.participants)
                     .expect("failed to serialize participants"),
             ),
+            (
+                "voting_threshold",
+                &canonical_json::to_string(&value.voting_threshold)
+                    .expect("failed to serialize voting_threshold"),
+            ),
         ]
         .into_iter()
         .map(Attribute::from)
+        .chain(expires_at_time)
+        .chain(observed_at.into_iter().flatten())
         .collect()
     }
 }
 
+impl PollStarted {
+    pub fn poll_id(&self) -> PollId {
+        match self {
+            PollStarted::Messages { metadata, .. }
+            | PollStarted::VerifierSet { metadata, .. }
+            | PollStarted::MessagesNotExecuted { metadata, .. } => metadata.poll_id,
+        }
+    }
+}
+
+/// Commitment to the full content of a poll started event: the keccak256 hash of the canonical JSON array
+/// [event type, [[key, value], ...]] of all its attributes in emission order, except the commitment itself.
+/// Voters can recompute it from the poll content and confirm they vote on what was submitted,
+/// even if an indexer truncated the event. The chain prefixes the type of emitted events with `wasm-` and adds a
+/// `_contract_address` attribute, both have to be removed from the on-chain event before recomputing the hash
+pub fn poll_content_hash(event: &Event) -> Hash {
+    let attributes: Vec<_> = event
+        .attributes
+        .iter()
+        .filter(|attribute| attribute.key != CONTENT_HASH_ATTRIBUTE)
+        .map(|attribute| (&attribute.key, &attribute.value))
+        .collect();
+
+    Keccak256::digest(
+        canonical_json::to_string(&(&event.ty, attributes))
+            .expect("failed to serialize poll content"),
+    )
+    .into()
+}
+
 impl From<PollStarted> for Event {
     fn from(other: PollStarted) -> Self {
-        match other {
+        let event = match other {
             PollStarted::Messages {
                 messages: data,
                 metadata,
             } => Event::new("messages_poll_started")
                 .add_attribute(
                     "messages",
-                    serde_json::to_string(&data).expect("failed to serialize messages"),
+                    canonical_json::to_string(&data).expect("failed to serialize messages"),
                 )
                 .add_attributes(Vec::<_>::from(metadata)),
             PollStarted::VerifierSet {
@@ -126,11 +245,28 @@ impl From<PollStarted> for Event {
             } => Event::new("verifier_set_poll_started")
                 .add_attribute(
                     "verifier_set",
-                    serde_json::to_string(&data)
+                    canonical_json::to_string(&data)
                         .expect("failed to serialize verifier set confirmation"),
                 )
                 .add_attributes(Vec::<_>::from(metadata)),
-        }
+            PollStarted::MessagesNotExecuted {
+                messages: data,
+                up_to_height,
+                metadata,
+            } => Event::new("messages_not_executed_poll_started")
+                .add_attribute(
+                    "messages",
+                    canonical_json::to_string(&data).expect("failed to serialize messages"),
+                )
+                .add_attribute("up_to_height", up_to_height.to_string())
+                .add_attributes(Vec::<_>::from(metadata)),
+        };
+
+        let content_hash = poll_content_hash(&event);
+        event.add_attribute(
+            CONTENT_HASH_ATTRIBUTE,
+            HexBinary::from(content_hash).to_hex(),
+        )
     }
 }
 
@@ -272,12 +408,12 @@ impl From<Voted> for Event {
         Event::new("voted")
             .add_attribute(
                 "poll_id",
-                serde_json::to_string(&other.poll_id).expect("failed to serialize poll_id"),
+                canonical_json::to_string(&other.poll_id).expect("failed to serialize poll_id"),
             )
             .add_attribute("voter", other.voter)
             .add_attribute(
                 "votes",
-                serde_json::to_string(&other.votes).expect("failed to serialize votes"),
+                canonical_json::to_string(&other.votes).expect("failed to serialize votes"),
             )
     }
 }
@@ -286,6 +422,8 @@ pub struct PollEnded {
     pub poll_id: PollId,
     pub source_chain: ChainName,
     pub results: Vec<Option<Vote>>,
+    /// when each verifier that voted cast its vote
+    pub vote_latencies: Vec<VoteLatency>,
 }
 
 impl From<PollEnded> for Event {
@@ -293,20 +431,143 @@ impl From<PollEnded> for Event {
         Event::new("poll_ended")
             .add_attribute(
                 "poll_id",
-                serde_json::to_string(&other.poll_id).expect("failed to serialize poll_id"),
+                canonical_json::to_string(&other.poll_id).expect("failed to serialize poll_id"),
             )
             .add_attribute(
                 "source_chain",
-                serde_json::to_string(&other.source_chain)
+                canonical_json::to_string(&other.source_chain)
                     .expect("failed to serialize source_chain"),
             )
             .add_attribute(
                 "results",
-                serde_json::to_string(&other.results).expect("failed to serialize results"),
+                canonical_json::to_string(&other.results).expect("failed to serialize results"),
+            )
+            .add_attribute(
+                "vote_latencies",
+                canonical_json::to_string(&other.vote_latencies)
+                    .expect("failed to serialize vote_latencies"),
+            )
+    }
+}
+
+pub struct PollCancelled {
+    pub poll_id: PollId,
+    pub source_chain: ChainName,
+    pub reason: nonempty::String,
+}
+
+impl From<PollCancelled> for Event {
+    fn from(other: PollCancelled) -> Self {
+        Event::new("poll_cancelled")
+            .add_attribute(
+                "poll_id",
+                canonical_json::to_string(&other.poll_id).expect("failed to serialize poll_id"),
+            )
+            .add_attribute(
+                "source_chain",
+                canonical_json::to_string(&other.source_chain)
+                    .expect("failed to serialize source_chain"),
+            )
+            .add_attribute("reason", other.reason)
+    }
+}
+
+/// Links an expired poll to the poll that was automatically started for its messages without quorum
+pub struct PollRetried {
+    pub poll_id: PollId,
+    pub retry_poll_id: PollId,
+    pub source_chain: ChainName,
+    pub messages: Vec<CrossChainId>,
+}
+
+/// Emitted when the messages of an expired poll could not be retried because no new poll could be started
+pub struct PollRetryFailed {
+    pub poll_id: PollId,
+    pub source_chain: ChainName,
+    pub messages: Vec<CrossChainId>,
+    pub reason: String,
+}
+
+impl From<PollRetryFailed> for Event {
+    fn from(other: PollRetryFailed) -> Self {
+        Event::new("poll_retry_failed")
+            .add_attribute(
+                "poll_id",
+                canonical_json::to_string(&other.poll_id).expect("failed to serialize poll_id"),
+            )
+            .add_attribute(
+                "source_chain",
+                canonical_json::to_string(&other.source_chain)
+                    .expect("failed to serialize source_chain"),
+            )
+            .add_attribute(
+                "messages",
+                canonical_json::to_string(&other.messages).expect("failed to serialize messages"),
+            )
+            .add_attribute("reason", other.reason)
+    }
+}
+
+impl From<PollRetried> for Event {
+    fn from(other: PollRetried) -> Self {
+        Event::new("poll_retried")
+            .add_attribute(
+                "poll_id",
+                canonical_json::to_string(&other.poll_id).expect("failed to serialize poll_id"),
+            )
+            .add_attribute(
+                "retry_poll_id",
+                canonical_json::to_string(&other.retry_poll_id)
+                    .expect("failed to serialize retry_poll_id"),
+            )
+            .add_attribute(
+                "source_chain",
+                canonical_json::to_string(&other.source_chain)
+                    .expect("failed to serialize source_chain"),
+            )
+            .add_attribute(
+                "messages",
+                canonical_json::to_string(&other.messages).expect("failed to serialize messages"),
             )
     }
 }
 
+/// Emitted when the source chain is marked as halted, with the polls whose expiry was postponed
+pub struct SourceChainHalted {
+    pub source_chain: ChainName,
+    pub started_at: u64,
+    pub ends_at: u64,
+}
+
+impl From<SourceChainHalted> for Event {
+    fn from(other: SourceChainHalted) -> Self {
+        Event::new("source_chain_halted")
+            .add_attribute(
+                "source_chain",
+                canonical_json::to_string(&other.source_chain)
+                    .expect("failed to serialize source_chain"),
+            )
+            .add_attribute("started_at", other.started_at.to_string())
+            .add_attribute("ends_at", other.ends_at.to_string())
+    }
+}
+
+pub struct PollExpiryExtended {
+    pub poll_id: PollId,
+    pub expires_at: u64,
+}
+
+impl From<PollExpiryExtended> for Event {
+    fn from(other: PollExpiryExtended) -> Self {
+        Event::new("poll_expiry_extended")
+            .add_attribute(
+                "poll_id",
+                canonical_json::to_string(&other.poll_id).expect("failed to serialize poll_id"),
+            )
+            .add_attribute("expires_at", other.expires_at.to_string())
+    }
+}
+
 pub struct QuorumReached<T> {
     pub content: T,
     pub status: VerificationStatus,
@@ -321,19 +582,63 @@ where
         Event::new("quorum_reached")
             .add_attribute(
                 "content",
-                serde_json::to_string(&value.content).expect("failed to serialize content"),
+                canonical_json::to_string(&value.content).expect("failed to serialize content"),
             )
             .add_attribute(
                 "status",
-                serde_json::to_string(&value.status).expect("failed to serialize status"),
+                canonical_json::to_string(&value.status).expect("failed to serialize status"),
             )
             .add_attribute(
                 "poll_id",
-                serde_json::to_string(&value.poll_id).expect("failed to serialize poll_id"),
+                canonical_json::to_string(&value.poll_id).expect("failed to serialize poll_id"),
             )
     }
 }
 
+/// Emitted when a message of a poll reaches its final verification status, either because the poll reached quorum on it
+/// or because the poll ended without quorum
+pub struct MessageVerificationFinalized {
+    pub poll_id: PollId,
+    pub source_chain: ChainName,
+    pub message: Message,
+    pub status: VerificationStatus,
+    /// Blocks between the start of the poll and the quorum, None if no quorum was reached or the poll's start is unknown
+    pub blocks_to_quorum: Option<u64>,
+    pub votes_received: u64,
+    /// True if the poll had to expire before the message's status was final
+    pub poll_expired: bool,
+}
+
+impl From<MessageVerificationFinalized> for Event {
+    fn from(other: MessageVerificationFinalized) -> Self {
+        Event::new("message_verification_finalized")
+            .add_attribute(
+                "poll_id",
+                canonical_json::to_string(&other.poll_id).expect("failed to serialize poll_id"),
+            )
+            .add_attribute(
+                "source_chain",
+                canonical_json::to_string(&other.source_chain)
+                    .expect("failed to serialize source_chain"),
+            )
+            .add_attribute(
+                "message",
+                canonical_json::to_string(&other.message).expect("failed to serialize message"),
+            )
+            .add_attribute(
+                "status",
+                canonical_json::to_string(&other.status).expect("failed to serialize status"),
+            )
+            .add_attribute(
+                "blocks_to_quorum",
+                canonical_json::to_string(&other.blocks_to_quorum)
+                    .expect("failed to serialize blocks_to_quorum"),
+            )
+            .add_attribute("votes_received", other.votes_received.to_string())
+            .add_attribute("poll_expired", other.poll_expired.to_string())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use std::collections::BTreeMap;
@@ -342,7 +647,7 @@ mod test {
     use axelar_wasm_std::msg_id::{
         Base58TxDigestAndEventIndex, HexTxHash, HexTxHashAndEventIndex, MessageIdFormat,
     };
-    use axelar_wasm_std::voting::Vote;
+    use axelar_wasm_std::voting::{Vote, VoteLatency};
     use axelar_wasm_std::{nonempty, Threshold, VerificationStatus};
     use cosmwasm_std::testing::MockApi;
     use cosmwasm_std::{Attribute, Uint128};
@@ -353,7 +658,10 @@ mod test {
     use serde_json::json;
 
     use super::{TxEventConfirmation, VerifierSetConfirmation};
-    use crate::events::{PollEnded, PollMetadata, PollStarted, QuorumReached, Voted};
+    use crate::events::{
+        PollCancelled, PollEnded, PollExpiryExtended, PollMetadata, PollRetried, PollRetryFailed,
+        PollStarted, QuorumReached, SourceChainHalted, Voted,
+    };
     use crate::state::Config;
 
     fn random_32_bytes() -> [u8; 32] {
@@ -462,6 +770,7 @@ mod test {
             signers: BTreeMap::new(),
             threshold: Uint128::one(),
             created_at: 1,
+            min_signers: None,
         };
         let event = VerifierSetConfirmation::new(
             msg_id.to_string().parse().unwrap(),
@@ -484,6 +793,7 @@ mod test {
             signers: BTreeMap::new(),
             threshold: Uint128::one(),
             created_at: 1,
+            min_signers: None,
         };
         let event = VerifierSetConfirmation::new(
             msg_id.to_string().parse().unwrap(),
@@ -503,6 +813,7 @@ mod test {
             signers: BTreeMap::new(),
             threshold: Uint128::one(),
             created_at: 1,
+            min_signers: None,
         };
 
         let event = VerifierSetConfirmation::new(
@@ -523,6 +834,7 @@ mod test {
             signers: BTreeMap::new(),
             threshold: Uint128::one(),
             created_at: 1,
+            min_signers: None,
         };
 
         let event = VerifierSetConfirmation::new(
@@ -549,6 +861,13 @@ mod test {
             rewards_contract: api.addr_make("rewardsContract"),
             msg_id_format: MessageIdFormat::HexTxHashAndEventIndex,
             address_format: AddressFormat::Eip55,
+            verification_fee: None,
+            vote_weight_decay: None,
+            dynamic_voting_threshold: None,
+            poll_retry: None,
+            poll_time_expiry: Some(600u64.try_into().unwrap()),
+            late_vote_grace_blocks: Some(1),
+            coordinator: api.addr_make("coordinator"),
         };
         let event_instantiated =
             cosmwasm_std::Event::new("instantiated").add_attributes(<Vec<Attribute>>::from(config));
@@ -580,11 +899,17 @@ mod test {
                 source_gateway_address: "sourceGatewayAddress".try_into().unwrap(),
                 confirmation_height: 1,
                 expires_at: 1,
+                expires_at_time: None,
                 participants: vec![
                     api.addr_make("participant1"),
                     api.addr_make("participant2"),
                     api.addr_make("participant3"),
                 ],
+                voting_threshold: Threshold::try_from((2, 3)).unwrap().try_into().unwrap(),
+                observed_at: Some(ObservedBlock {
+                    height: 100,
+                    hash: Some("blockHash".try_into().unwrap()),
+                }),
             },
         }
         .into();
@@ -602,15 +927,47 @@ mod test {
                 source_gateway_address: "sourceGatewayAddress".try_into().unwrap(),
                 confirmation_height: 1,
                 expires_at: 1,
+                expires_at_time: None,
                 participants: vec![
                     api.addr_make("participant4"),
                     api.addr_make("participant5"),
                     api.addr_make("participant6"),
                 ],
+                voting_threshold: Threshold::try_from((2, 3)).unwrap().try_into().unwrap(),
+                observed_at: None,
             },
         }
         .into();
 
+        let event_messages_not_executed_poll_started: cosmwasm_std::Event =
+            PollStarted::MessagesNotExecuted {
+                messages: vec![TxEventConfirmation {
+                    tx_id: "txId3".try_into().unwrap(),
+                    event_index: 3,
+                    message_id: "messageId".try_into().unwrap(),
+                    destination_address: "destinationAddress3".parse().unwrap(),
+                    destination_chain: "destinationChain".try_into().unwrap(),
+                    source_address: "sourceAddress3".parse().unwrap(),
+                    payload_hash: [2; 32],
+                }],
+                up_to_height: 100,
+                metadata: PollMetadata {
+                    poll_id: 3.into(),
+                    source_chain: "sourceChain".try_into().unwrap(),
+                    source_gateway_address: "sourceGatewayAddress".try_into().unwrap(),
+                    confirmation_height: 1,
+                    expires_at: 1,
+                    expires_at_time: None,
+                    participants: vec![
+                        api.addr_make("participant7"),
+                        api.addr_make("participant8"),
+                    ],
+                    voting_threshold: Threshold::try_from((2, 3)).unwrap().try_into().unwrap(),
+                    observed_at: None,
+                },
+            }
+            .into();
+
         let event_quorum_reached: cosmwasm_std::Event = QuorumReached {
             content: "content".to_string(),
             status: VerificationStatus::NotFoundOnSourceChain,
@@ -634,6 +991,58 @@ mod test {
                 Some(Vote::NotFound),
                 None,
             ],
+            vote_latencies: vec![
+                VoteLatency {
+                    voter: api.addr_make("voter1").to_string(),
+                    voted_at: 12,
+                    blocks_after_start: Some(2),
+                    after_quorum: false,
+                    blocks_after_quorum: None,
+                },
+                VoteLatency {
+                    voter: api.addr_make("voter2").to_string(),
+                    voted_at: 15,
+                    blocks_after_start: Some(5),
+                    after_quorum: true,
+                    blocks_after_quorum: Some(3),
+                },
+            ],
+        }
+        .into();
+
+        let event_poll_cancelled: cosmwasm_std::Event = PollCancelled {
+            poll_id: 1.into(),
+            source_chain: "sourceChain".try_into().unwrap(),
+            reason: "malformed content".try_into().unwrap(),
+        }
+        .into();
+
+        let event_poll_retried: cosmwasm_std::Event = PollRetried {
+            poll_id: 1.into(),
+            retry_poll_id: 4.into(),
+            source_chain: "sourceChain".try_into().unwrap(),
+            messages: vec![CrossChainId::new("sourceChain", "messageId").unwrap()],
+        }
+        .into();
+
+        let event_poll_retry_failed: cosmwasm_std::Event = PollRetryFailed {
+            poll_id: 1.into(),
+            source_chain: "sourceChain".try_into().unwrap(),
+            messages: vec![CrossChainId::new("sourceChain", "messageId").unwrap()],
+            reason: "failed to build snapshot".to_string(),
+        }
+        .into();
+
+        let event_source_chain_halted: cosmwasm_std::Event = SourceChainHalted {
+            source_chain: "sourceChain".try_into().unwrap(),
+            started_at: 100,
+            ends_at: 150,
+        }
+        .into();
+
+        let event_poll_expiry_extended: cosmwasm_std::Event = PollExpiryExtended {
+            poll_id: 2.into(),
+            expires_at: 160,
         }
         .into();
 
@@ -641,9 +1050,15 @@ mod test {
             "event_instantiated": event_instantiated,
             "event_messages_poll_started": event_messages_poll_started,
             "event_verifier_set_poll_started": event_verifier_set_poll_started,
+            "event_messages_not_executed_poll_started": event_messages_not_executed_poll_started,
             "event_quorum_reached": event_quorum_reached,
             "event_voted": event_voted,
             "event_poll_ended": event_poll_ended,
+            "event_poll_cancelled": event_poll_cancelled,
+            "event_poll_retried": event_poll_retried,
+            "event_poll_retry_failed": event_poll_retry_failed,
+            "event_source_chain_halted": event_source_chain_halted,
+            "event_poll_expiry_extended": event_poll_expiry_extended,
         }));
     }
 }
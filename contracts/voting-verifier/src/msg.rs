@@ -1,13 +1,22 @@
-use axelar_wasm_std::voting::{PollId, PollStatus, Vote, WeightedPoll};
-use axelar_wasm_std::{nonempty, MajorityThreshold, VerificationStatus};
+use axelar_wasm_std::maintenance::MaintenanceWindow;
+use axelar_wasm_std::time::Duration;
+use axelar_wasm_std::voting::{PollId, PollStatus, Vote, VoteLatency, WeightedPoll};
+use axelar_wasm_std::{bounded, nonempty, MajorityThreshold, VerificationStatus};
 use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Coin, HexBinary};
 use msgs_derive::EnsurePermissions;
 use multisig::verifier_set::VerifierSet;
 use router_api::Message;
-pub use voting_verifier_api::msg::InstantiateMsg;
+pub use voting_verifier_api::msg::{
+    DynamicVotingThreshold, InstantiateMsg, ObservedBlock, PollRetry, VerificationFee,
+    VoteWeightDecay,
+};
 
 pub use crate::contract::MigrateMsg;
 
+/// Maximum length in bytes of the reason a poll is cancelled for
+pub const MAX_REASON_LENGTH: usize = 256;
+
 #[cw_serde]
 #[derive(EnsurePermissions)]
 pub enum ExecuteMsg {
@@ -22,9 +31,19 @@ pub enum ExecuteMsg {
 
     // returns a vector of true/false values, indicating current verification status for each message
     // starts a poll for any not yet verified messages
+    // If a verification fee is configured, the fee for each message that is put up for verification must be attached.
+    // Any excess funds are returned to the sender
     #[permission(Any)]
     VerifyMessages(Vec<Message>),
 
+    // Same as VerifyMessages, but includes the source chain block at which the relayer observed the messages
+    // in the poll, so verifiers can all check the messages at the same block
+    #[permission(Any)]
+    VerifyMessagesObservedAt {
+        messages: Vec<Message>,
+        observed_at: ObservedBlock,
+    },
+
     // Starts a poll to confirm a verifier set update on the external gateway
     #[permission(Any)]
     VerifyVerifierSet {
@@ -32,23 +51,112 @@ pub enum ExecuteMsg {
         new_verifier_set: VerifierSet,
     },
 
+    // Starts a poll to confirm that the given messages were not executed on the source chain up to and including
+    // block height `up_to_height`. A claim is confirmed once a quorum of verifiers votes NotFound,
+    // and refuted once a quorum votes that the message was executed (successfully or not).
+    // Polls are only started for claims that have not been confirmed or refuted yet and have no poll in progress
+    #[permission(Any)]
+    VerifyMessagesNotExecuted {
+        messages: Vec<Message>,
+        up_to_height: u64,
+    },
+
     // Update the threshold used for new polls. Callable only by governance
     #[permission(Governance)]
     UpdateVotingThreshold {
         new_voting_threshold: MajorityThreshold,
     },
+
+    // Closes a poll that has not been ended yet without a result, e.g. because it was started with malformed content.
+    // The poll's content becomes eligible for verification again, and no participation is recorded for rewards.
+    // Callable only by governance
+    #[permission(Governance)]
+    CancelPoll {
+        poll_id: PollId,
+        reason: bounded::String<MAX_REASON_LENGTH>,
+    },
+
+    // Sets the fee charged per message with VerifyMessages, or removes it if None. Callable only by governance
+    #[permission(Governance)]
+    UpdateVerificationFee {
+        verification_fee: Option<VerificationFee>,
+    },
+
+    // Sets how the voting weight of verifiers that keep missing polls decays in new polls, or disables the decay if None.
+    // Callable only by governance
+    #[permission(Governance)]
+    UpdateVoteWeightDecay {
+        vote_weight_decay: Option<VoteWeightDecay>,
+    },
+
+    // Sets how the voting threshold of new polls scales with the share of active verifiers, or disables the scaling if None.
+    // Callable only by governance
+    #[permission(Governance)]
+    UpdateDynamicVotingThreshold {
+        dynamic_voting_threshold: Option<DynamicVotingThreshold>,
+    },
+
+    // Sets how often messages are automatically put up for verification again after their poll expired without quorum,
+    // or disables the retries if None. Callable only by governance
+    #[permission(Governance)]
+    UpdatePollRetry { poll_retry: Option<PollRetry> },
+
+    // Sets the wall-clock duration after which new polls expire, in addition to the block expiry,
+    // or removes it if None. Callable only by governance
+    #[permission(Governance)]
+    UpdatePollTimeExpiry { poll_time_expiry: Option<Duration> },
+
+    // Sets the number of blocks after every item of a poll has reached quorum within which votes still count
+    // towards rewards participation for that poll. Later votes are excluded. If None, no votes are excluded.
+    // Callable only by governance
+    #[permission(Governance)]
+    UpdateLateVoteGraceBlocks { late_vote_grace_blocks: Option<u64> },
+
+    // Marks the source chain as halted for the given number of blocks, starting now. Polls in progress and polls
+    // started during the halt expire that much later, and only by block height, so verifiers don't miss polls they
+    // can't vote in. Halting again while halted extends the halt if it ends later. Callable only by governance
+    #[permission(Governance)]
+    HaltSourceChain { blocks: nonempty::Uint64 },
+
+    // Extends the expiry of up to `limit` (at most 100) more polls that were in progress when the source chain was
+    // halted. Halting only extends the first page of polls in progress, so this continues until no extensions are pending
+    #[permission(Any)]
+    ExtendPolls { limit: u32 },
+
+    // Adds all accrued verification fees of the given denom to the rewards pool of the source chain
+    // and this contract. The denom must match the rewards denom of the rewards contract
+    #[permission(Any)]
+    TransferFeesToRewards { denom: nonempty::String },
+
+    // Replaces the scheduled maintenance windows of the source chain. Called by the coordinator whenever a window is
+    // scheduled or cancelled. Polls that overlap with a window expire the blocks of the window later, and only by block
+    // height, so verifiers don't miss polls they can't vote in. Polls already in progress when a window is scheduled are
+    // extended in pages like after a source chain halt, see ExtendPolls
+    #[permission(Specific(coordinator))]
+    SetMaintenanceWindows { windows: Vec<MaintenanceWindow> },
 }
 
 #[cw_serde]
 pub enum PollData {
     Messages(Vec<Message>),
     VerifierSet(VerifierSet),
+    MessagesNotExecuted {
+        messages: Vec<Message>,
+        up_to_height: u64,
+    },
 }
 #[cw_serde]
 pub struct PollResponse {
     pub poll: WeightedPoll,
     pub data: PollData,
     pub status: PollStatus,
+    /// Voting threshold the poll was started with. Only known while the poll is in progress, and not for polls that were
+    /// started before it was recorded
+    pub voting_threshold: Option<MajorityThreshold>,
+    /// Expired poll whose messages this poll was automatically started for, if it is a retry
+    pub retry_of: Option<PollId>,
+    /// Commitment to the full poll content, as emitted in the poll started event. Not known for polls that were started before it was recorded
+    pub content_hash: Option<HexBinary>,
 }
 
 #[cw_serde]
@@ -63,8 +171,71 @@ pub enum QueryMsg {
     #[returns(VerificationStatus)]
     VerifierSetStatus(VerifierSet),
 
+    /// Returns the status of the claims that the given messages were not executed up to the given block height.
+    /// NotFoundOnSourceChain means the claim is confirmed, SucceededOnSourceChain and FailedOnSourceChain mean it was refuted
+    #[returns(Vec<MessageStatus>)]
+    MessagesNotExecutedStatus {
+        messages: Vec<Message>,
+        up_to_height: u64,
+    },
+
     #[returns(MajorityThreshold)]
     CurrentThreshold,
+
+    #[returns(Option<VerificationFee>)]
+    VerificationFee,
+
+    /// Returns the verification fees that have been charged but not yet transferred to the rewards pool, per denom
+    #[returns(Vec<Coin>)]
+    AccruedFees,
+
+    #[returns(Option<VoteWeightDecay>)]
+    VoteWeightDecay,
+
+    #[returns(Option<DynamicVotingThreshold>)]
+    DynamicVotingThreshold,
+
+    #[returns(Option<PollRetry>)]
+    PollRetry,
+
+    #[returns(Option<Duration>)]
+    PollTimeExpiry,
+
+    #[returns(Option<u64>)]
+    LateVoteGraceBlocks,
+
+    /// Returns the halt of the source chain if it is currently halted
+    #[returns(Option<SourceChainHalt>)]
+    SourceChainHalt,
+
+    /// Returns true if some polls in progress at a source chain halt still have to be checked for an extension
+    #[returns(bool)]
+    PollExtensionsPending,
+
+    /// Returns the block height at which each verifier voted in the poll and how many blocks after the poll start that was
+    #[returns(Vec<VoteLatency>)]
+    VoteLatencies { poll_id: PollId },
+
+    /// Returns the number of times the message was automatically put up for verification again
+    #[returns(u64)]
+    MessageRetries(Message),
+
+    /// Returns the number of consecutive ended polls the verifier did not vote in.
+    /// Only tracked while a vote weight decay or a dynamic voting threshold is configured
+    #[returns(u64)]
+    MissedPolls { verifier_address: String },
+
+    /// Returns true if the verifier is a participant of a poll in progress that it hasn't voted in yet
+    #[returns(bool)]
+    HasOpenPolls { verifier_address: String },
+}
+
+#[cw_serde]
+pub struct SourceChainHalt {
+    /// block height at which the source chain was marked as halted
+    pub started_at: u64,
+    /// block height at which the halt ends
+    pub ends_at: u64,
 }
 
 #[cw_serde]
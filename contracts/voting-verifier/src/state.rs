@@ -1,15 +1,21 @@
 use axelar_wasm_std::address::AddressFormat;
 use axelar_wasm_std::hash::Hash;
+use axelar_wasm_std::maintenance::MaintenanceWindow;
 use axelar_wasm_std::msg_id::MessageIdFormat;
+use axelar_wasm_std::time::Duration;
 use axelar_wasm_std::voting::{PollId, Vote, WeightedPoll};
 use axelar_wasm_std::{counter, nonempty, MajorityThreshold};
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Order, StdResult, Storage};
+use cosmwasm_std::{Addr, BlockInfo, Order, StdResult, Storage, Uint128};
 use cw_storage_plus::{Index, IndexList, IndexedMap, Item, Map, MultiIndex};
 use multisig::verifier_set::VerifierSet;
 use router_api::{ChainName, Message};
+use sha3::{Digest, Keccak256};
 
 use crate::error::ContractError;
+use crate::msg::{
+    DynamicVotingThreshold, PollRetry, SourceChainHalt, VerificationFee, VoteWeightDecay,
+};
 
 #[cw_serde]
 pub struct Config {
@@ -23,12 +29,37 @@ pub struct Config {
     pub rewards_contract: Addr,
     pub msg_id_format: MessageIdFormat,
     pub address_format: AddressFormat,
+    #[serde(default)]
+    pub verification_fee: Option<VerificationFee>,
+    #[serde(default)]
+    pub vote_weight_decay: Option<VoteWeightDecay>,
+    #[serde(default)]
+    pub dynamic_voting_threshold: Option<DynamicVotingThreshold>,
+    #[serde(default)]
+    pub poll_retry: Option<PollRetry>,
+    /// wall-clock duration after which a poll expires, in addition to block_expiry
+    #[serde(default)]
+    pub poll_time_expiry: Option<Duration>,
+    /// if set, verifiers that vote more than this many blocks after every item of a poll has reached quorum
+    /// don't participate in rewards for it
+    #[serde(default)]
+    pub late_vote_grace_blocks: Option<u64>,
+    /// the coordinator pushes the maintenance windows of the source chain
+    pub coordinator: Addr,
+}
+
+impl Config {
+    /// Missed polls are only counted while a feature that depends on them is configured
+    pub fn tracks_missed_polls(&self) -> bool {
+        self.vote_weight_decay.is_some() || self.dynamic_voting_threshold.is_some()
+    }
 }
 
 #[cw_serde]
 pub enum Poll {
     Messages(WeightedPoll),
     ConfirmVerifierSet(WeightedPoll),
+    MessagesNotExecuted(WeightedPoll),
 }
 
 impl Poll {
@@ -40,6 +71,7 @@ impl Poll {
         match self {
             Poll::Messages(poll) => Ok(Poll::Messages(func(poll)?)),
             Poll::ConfirmVerifierSet(poll) => Ok(Poll::ConfirmVerifierSet(func(poll)?)),
+            Poll::MessagesNotExecuted(poll) => Ok(Poll::MessagesNotExecuted(func(poll)?)),
         }
     }
 
@@ -47,8 +79,33 @@ impl Poll {
         match self {
             Poll::Messages(poll) => poll,
             Poll::ConfirmVerifierSet(poll) => poll,
+            Poll::MessagesNotExecuted(poll) => poll,
         }
     }
+
+    pub fn weighted_poll_ref(&self) -> &WeightedPoll {
+        match self {
+            Poll::Messages(poll) => poll,
+            Poll::ConfirmVerifierSet(poll) => poll,
+            Poll::MessagesNotExecuted(poll) => poll,
+        }
+    }
+}
+
+/// A claim that a message was not executed on the source chain up to (and including) the given block height
+#[cw_serde]
+pub struct NotExecutedClaim {
+    pub message: Message,
+    pub up_to_height: u64,
+}
+
+impl NotExecutedClaim {
+    pub fn hash(&self) -> Hash {
+        let mut hasher = Keccak256::new();
+        hasher.update(self.message.hash());
+        hasher.update(self.up_to_height.to_be_bytes());
+        hasher.finalize().into()
+    }
 }
 
 #[cw_serde]
@@ -68,6 +125,16 @@ impl PollContent<Message> {
     }
 }
 
+impl PollContent<NotExecutedClaim> {
+    pub fn new(claim: NotExecutedClaim, poll_id: PollId, index_in_poll: usize) -> Self {
+        Self {
+            content: claim,
+            poll_id,
+            index_in_poll: index_in_poll.try_into().unwrap(),
+        }
+    }
+}
+
 impl PollContent<VerifierSet> {
     pub fn new(verifier_set: VerifierSet, poll_id: PollId) -> Self {
         Self {
@@ -82,11 +149,77 @@ pub const POLL_ID: counter::Counter<PollId> = counter::Counter::new("poll_id");
 
 pub const POLLS: Map<PollId, Poll> = Map::new("polls");
 
+/// Block height at which each messages poll was started, to measure how long the verification of its messages took
+pub const POLL_STARTED_AT: Map<PollId, u64> = Map::new("poll_started_at");
+
+/// Voting threshold each poll was started with, which differs from the configured one while the dynamic voting threshold is enabled
+pub const POLL_VOTING_THRESHOLDS: Map<PollId, MajorityThreshold> =
+    Map::new("poll_voting_thresholds");
+
+/// Commitment to the full content of each poll, as emitted in its poll started event
+pub const POLL_CONTENT_HASHES: Map<PollId, Hash> = Map::new("poll_content_hashes");
+
 type VerifierAddr = String;
 pub const VOTES: Map<(PollId, VerifierAddr), Vec<Vote>> = Map::new("votes");
 
+/// Number of consecutive ended polls each verifier did not vote in. Reset when the verifier votes
+pub const MISSED_POLLS: Map<&str, u64> = Map::new("missed_polls");
+
+/// Maps a denom to the verification fees that have been charged in it but not yet transferred to the rewards pool
+pub const ACCRUED_FEES: Map<&str, Uint128> = Map::new("accrued_fees");
+
+/// Maps polls that were cancelled by governance to the reason for their cancellation
+pub const CANCELLED_POLLS: Map<PollId, nonempty::String> = Map::new("cancelled_polls");
+
+/// Number of times each message was automatically put up for verification again after its poll expired without quorum
+pub const MESSAGE_RETRIES: Map<&Hash, u64> = Map::new("message_retries");
+
+/// Maps polls that were started automatically for the messages of an expired poll to the expired poll
+pub const RETRIED_POLLS: Map<PollId, PollId> = Map::new("retried_polls");
+
 pub const CONFIG: Item<Config> = Item::new("config");
 
+/// Most recent halt of the source chain, which keeps being stored after it ended
+pub const SOURCE_CHAIN_HALT: Item<SourceChainHalt> = Item::new("source_chain_halt");
+
+/// Returns the halt of the source chain if it hasn't ended at the given block height
+pub fn active_source_chain_halt(
+    storage: &dyn Storage,
+    block_height: u64,
+) -> StdResult<Option<SourceChainHalt>> {
+    Ok(SOURCE_CHAIN_HALT
+        .may_load(storage)?
+        .filter(|halt| halt.ends_at > block_height))
+}
+
+/// An extension of the expiry of all polls that were in progress at a given block. Extensions are applied in pages,
+/// from the most recent poll backwards, so the cost of a single call stays bounded
+#[cw_serde]
+pub struct PollExtension {
+    /// block at which the polls to extend had to be in progress
+    pub in_progress_at: BlockInfo,
+    /// by how much the polls expire later
+    pub length: PollExtensionLength,
+    /// most recent poll to extend, later polls were started with the extension already applied
+    pub newest_poll_id: PollId,
+    /// the last poll that was checked, the remaining polls are the ones before it
+    pub checked_until: Option<PollId>,
+}
+
+#[cw_serde]
+pub enum PollExtensionLength {
+    /// every poll expires the same number of blocks later
+    Blocks(u64),
+    /// every poll expires later by the blocks of the maintenance window that fall before its expiry
+    MaintenanceWindow(MaintenanceWindow),
+}
+
+/// Poll extensions that still have polls left to check, oldest first
+pub const POLL_EXTENSIONS: Item<Vec<PollExtension>> = Item::new("poll_extensions");
+
+/// Maintenance windows of the source chain as scheduled by the coordinator
+pub const MAINTENANCE_WINDOWS: Item<Vec<MaintenanceWindow>> = Item::new("maintenance_windows");
+
 /// A multi-index that indexes a message by (PollID, index in poll) pair. The primary key of the underlying
 /// map is the hash of the message (typed as Hash). This allows looking up a Message by it's hash,
 /// or by a (PollID, index in poll) pair. The PollID is stored as a String
@@ -208,3 +341,77 @@ impl IndexList<PollContent<VerifierSet>> for PollVerifierSetsIndex<'_> {
         Box::new(v.into_iter())
     }
 }
+
+/// A multi-index that indexes a not-executed claim by (PollID, index in poll) pair. The primary key of the underlying
+/// map is the hash of the claim (typed as Hash). This allows looking up a claim by its hash,
+/// or by a (PollID, index in poll) pair. The PollID is stored as a String
+pub struct PollNotExecutedClaimsIndex<'a>(
+    MultiIndex<'a, (String, u32), PollContent<NotExecutedClaim>, &'a Hash>,
+);
+
+impl<'a> PollNotExecutedClaimsIndex<'a> {
+    fn new(
+        idx_fn: fn(&[u8], &PollContent<NotExecutedClaim>) -> (String, u32),
+        pk_namespace: &'a str,
+        idx_namespace: &'static str,
+    ) -> Self {
+        PollNotExecutedClaimsIndex(MultiIndex::new(idx_fn, pk_namespace, idx_namespace))
+    }
+
+    pub fn load_claim(
+        &self,
+        storage: &dyn Storage,
+        poll_id: PollId,
+        index_in_poll: u32,
+    ) -> StdResult<Option<NotExecutedClaim>> {
+        match self
+            .0
+            .prefix((poll_id.to_string(), index_in_poll))
+            .range(storage, None, None, Order::Ascending)
+            .collect::<Result<Vec<([u8; 32], PollContent<NotExecutedClaim>)>, _>>()?
+            .as_slice()
+        {
+            [] => Ok(None),
+            [(_, content)] => Ok(Some(content.content.to_owned())),
+            _ => panic!("More than one claim for poll_id and index_in_poll"),
+        }
+    }
+
+    pub fn load_claims(
+        &self,
+        storage: &dyn Storage,
+        poll_id: PollId,
+    ) -> StdResult<Vec<NotExecutedClaim>> {
+        self.0
+            .sub_prefix(poll_id.to_string())
+            .range(storage, None, None, Order::Ascending)
+            .map(|item| item.map(|(_, poll_content)| poll_content.content))
+            .collect::<StdResult<Vec<_>>>()
+    }
+}
+
+const POLL_NOT_EXECUTED_CLAIMS_PKEY_NAMESPACE: &str = "poll_not_executed_claims";
+const POLL_NOT_EXECUTED_CLAIMS_IDX_NAMESPACE: &str = "poll_not_executed_claims_idx";
+
+pub fn poll_not_executed_claims<'a>(
+) -> IndexedMap<&'a Hash, PollContent<NotExecutedClaim>, PollNotExecutedClaimsIndex<'a>> {
+    IndexedMap::new(
+        POLL_NOT_EXECUTED_CLAIMS_PKEY_NAMESPACE,
+        PollNotExecutedClaimsIndex::new(
+            |_pk: &[u8], d: &PollContent<NotExecutedClaim>| {
+                (d.poll_id.to_string(), d.index_in_poll)
+            },
+            POLL_NOT_EXECUTED_CLAIMS_PKEY_NAMESPACE,
+            POLL_NOT_EXECUTED_CLAIMS_IDX_NAMESPACE,
+        ),
+    )
+}
+
+impl IndexList<PollContent<NotExecutedClaim>> for PollNotExecutedClaimsIndex<'_> {
+    fn get_indexes(
+        &'_ self,
+    ) -> Box<dyn Iterator<Item = &'_ dyn Index<PollContent<NotExecutedClaim>>> + '_> {
+        let v: Vec<&dyn Index<PollContent<NotExecutedClaim>>> = vec![&self.0];
+        Box::new(v.into_iter())
+    }
+}
@@ -25,8 +25,12 @@ impl GatewayContract {
                 code_id,
                 MockApi::default().addr_make("anyone"),
                 &gateway::msg::InstantiateMsg {
+                    governance_address: MockApi::default().addr_make("governance").to_string(),
                     router_address: router_address.to_string(),
                     verifier_address: verifier_address.to_string(),
+                    payload_hash_algorithm: None,
+                    batching_window: None,
+                    message_retention: None,
                 },
                 &[],
                 "gateway",
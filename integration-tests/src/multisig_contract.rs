@@ -32,6 +32,7 @@ impl MultisigContract {
                     governance_address: governance.to_string(),
                     admin_address: admin.to_string(),
                     block_expiry,
+                    coordinator_address: None,
                 },
                 &[],
                 "multisig",
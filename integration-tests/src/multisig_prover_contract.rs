@@ -51,6 +51,8 @@ impl MultisigProverContract {
                     encoder: Encoder::Abi,
                     key_type: KeyType::Ecdsa,
                     domain_separator: [0; 32],
+                    proof_validity: None,
+                    min_signers: None,
                 },
                 &[],
                 "multisig_prover",
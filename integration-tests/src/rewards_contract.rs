@@ -1,7 +1,7 @@
 use cosmwasm_std::testing::MockApi;
 use cosmwasm_std::Addr;
 use cw_multi_test::{ContractWrapper, Executor};
-use rewards::contract::{execute, instantiate, query};
+use rewards::contract::{execute, instantiate, query, reply};
 
 use crate::contract::Contract;
 use crate::protocol::AxelarApp;
@@ -17,7 +17,8 @@ impl RewardsContract {
         governance: Addr,
         rewards_denom: String,
     ) -> Self {
-        let code = ContractWrapper::new_with_empty(execute, instantiate, query);
+        let code =
+            ContractWrapper::new_with_empty(execute, instantiate, query).with_reply_empty(reply);
         let code_id = app.store_code(Box::new(code));
 
         let contract_addr = app
@@ -27,6 +28,10 @@ impl RewardsContract {
                 &rewards::msg::InstantiateMsg {
                     governance_address: governance.to_string(),
                     rewards_denom,
+                    epoch_retention: 10,
+                    track_event_attribution: false,
+                    reserve_epoch_rewards: false,
+                    attestation_target: None,
                 },
                 &[],
                 "rewards",
@@ -1,7 +1,7 @@
 use cosmwasm_std::testing::MockApi;
 use cosmwasm_std::Addr;
 use cw_multi_test::{ContractWrapper, Executor};
-use router::contract::{execute, instantiate, query};
+use router::contract::{execute, instantiate, query, reply};
 
 use crate::contract::Contract;
 use crate::protocol::AxelarApp;
@@ -18,7 +18,8 @@ impl RouterContract {
         governance: Addr,
         axelarnet: Addr,
     ) -> Self {
-        let code = ContractWrapper::new_with_empty(execute, instantiate, query);
+        let code =
+            ContractWrapper::new_with_empty(execute, instantiate, query).with_reply_empty(reply);
         let code_id = app.store_code(Box::new(code));
 
         let contract_addr = app
@@ -29,6 +30,7 @@ impl RouterContract {
                     admin_address: admin.to_string(),
                     governance_address: governance.to_string(),
                     axelarnet_gateway: axelarnet.to_string(),
+                    coordinator_address: None,
                 },
                 &[],
                 "router",
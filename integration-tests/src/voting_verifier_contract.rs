@@ -51,6 +51,16 @@ impl VotingVerifierContract {
                         .unwrap(),
                     msg_id_format: axelar_wasm_std::msg_id::MessageIdFormat::HexTxHashAndEventIndex,
                     address_format: axelar_wasm_std::address::AddressFormat::Eip55,
+                    verification_fee: None,
+                    vote_weight_decay: None,
+                    dynamic_voting_threshold: None,
+                    poll_retry: None,
+                    coordinator_address: protocol
+                        .coordinator
+                        .contract_addr
+                        .to_string()
+                        .try_into()
+                        .unwrap(),
                 },
                 &[],
                 "voting_verifier",
@@ -0,0 +1,164 @@
+use axelar_wasm_std::permission_control;
+use coordinator::msg::{
+    AuthorizationAction, ExecuteMsg as CoordinatorExecuteMsg, QueryMsg as CoordinatorQueryMsg,
+    VerifierAuthorizationAction, VerifierAuthorizationPreview,
+};
+use cosmwasm_std::testing::MockApi;
+use cosmwasm_std::{Addr, StdError};
+use integration_tests::contract::Contract;
+use integration_tests::protocol::Protocol;
+use router_api::ChainName;
+use service_registry_api::msg::{ExecuteMsg, QueryMsg, VerifierDetails};
+use service_registry_api::AuthorizationState;
+
+pub mod test_utils;
+
+#[test]
+fn coordinator_authorizes_verifier_cohort_atomically() {
+    let test_utils::TestCase {
+        mut protocol,
+        chain1,
+        verifiers,
+        ..
+    } = test_utils::setup_test_case();
+
+    let already_authorized = verifiers.first().unwrap().addr.clone();
+    let jailed = MockApi::default().addr_make("jailed_verifier");
+    let new_verifier = MockApi::default().addr_make("new_verifier");
+
+    let response = protocol.service_registry.execute(
+        &mut protocol.app,
+        protocol.governance_address.clone(),
+        &ExecuteMsg::JailVerifiers {
+            verifiers: vec![jailed.to_string()],
+            service_name: protocol.service_name.to_string(),
+        },
+    );
+    assert!(response.is_ok());
+
+    let cohort = vec![
+        new_verifier.to_string(),
+        already_authorized.to_string(),
+        jailed.to_string(),
+        new_verifier.to_string(),
+    ];
+
+    let preview: Result<VerifierAuthorizationPreview, StdError> = protocol.coordinator.query(
+        &protocol.app,
+        &CoordinatorQueryMsg::VerifierAuthorizationPreview {
+            service_name: protocol.service_name.to_string(),
+            chain_name: chain1.chain_name.clone(),
+            verifiers: cohort.clone(),
+        },
+    );
+    assert_eq!(
+        preview.unwrap(),
+        VerifierAuthorizationPreview {
+            service_name: protocol.service_name.to_string(),
+            chain_name: chain1.chain_name.clone(),
+            actions: vec![
+                VerifierAuthorizationAction {
+                    verifier: new_verifier.clone(),
+                    action: AuthorizationAction::Authorize,
+                },
+                VerifierAuthorizationAction {
+                    verifier: already_authorized.clone(),
+                    action: AuthorizationAction::AlreadyAuthorized,
+                },
+                VerifierAuthorizationAction {
+                    verifier: jailed.clone(),
+                    action: AuthorizationAction::BlockedByJail,
+                },
+            ],
+        }
+    );
+
+    let response = protocol.coordinator.execute(
+        &mut protocol.app,
+        protocol.governance_address.clone(),
+        &CoordinatorExecuteMsg::AuthorizeVerifiers {
+            service_name: protocol.service_name.to_string(),
+            chain_name: chain1.chain_name.clone(),
+            verifiers: cohort,
+        },
+    );
+    assert!(response.is_ok());
+
+    assert_eq!(
+        authorization_state(&protocol, &new_verifier),
+        AuthorizationState::Authorized
+    );
+    assert_eq!(
+        authorization_state(&protocol, &already_authorized),
+        AuthorizationState::Authorized
+    );
+    assert_eq!(
+        authorization_state(&protocol, &jailed),
+        AuthorizationState::Jailed
+    );
+}
+
+#[test]
+fn coordinator_verifier_authorization_requires_governance_and_registered_chain() {
+    let test_utils::TestCase {
+        mut protocol,
+        chain1,
+        ..
+    } = test_utils::setup_test_case();
+
+    let new_verifier = MockApi::default().addr_make("new_verifier");
+
+    let response = protocol.coordinator.execute(
+        &mut protocol.app,
+        MockApi::default().addr_make("random_address"),
+        &CoordinatorExecuteMsg::AuthorizeVerifiers {
+            service_name: protocol.service_name.to_string(),
+            chain_name: chain1.chain_name.clone(),
+            verifiers: vec![new_verifier.to_string()],
+        },
+    );
+    assert!(response.is_err());
+    assert!(response.unwrap_err().to_string().contains(
+        &permission_control::Error::PermissionDenied {
+            expected: permission_control::Permission::Governance.into(),
+            actual: permission_control::Permission::NoPrivilege.into(),
+        }
+        .to_string()
+    ));
+
+    let unregistered_chain: ChainName = "unregistered".parse().unwrap();
+    let response = protocol.coordinator.execute(
+        &mut protocol.app,
+        protocol.governance_address.clone(),
+        &CoordinatorExecuteMsg::AuthorizeVerifiers {
+            service_name: protocol.service_name.to_string(),
+            chain_name: unregistered_chain,
+            verifiers: vec![new_verifier.to_string()],
+        },
+    );
+    assert!(response.is_err());
+
+    let details: Result<VerifierDetails, StdError> = protocol.service_registry.query(
+        &protocol.app,
+        &QueryMsg::Verifier {
+            service_name: protocol.service_name.to_string(),
+            verifier: new_verifier.to_string(),
+        },
+    );
+    assert!(details.is_err());
+}
+
+fn authorization_state(protocol: &Protocol, verifier: &Addr) -> AuthorizationState {
+    let details: VerifierDetails = protocol
+        .service_registry
+        .query(
+            &protocol.app,
+            &QueryMsg::Verifier {
+                service_name: protocol.service_name.to_string(),
+                verifier: verifier.to_string(),
+            },
+        )
+        .unwrap();
+
+    details.verifier.authorization_state
+}
@@ -21,13 +21,12 @@ use integration_tests::router_contract::RouterContract;
 use integration_tests::service_registry_contract::ServiceRegistryContract;
 use integration_tests::voting_verifier_contract::VotingVerifierContract;
 use k256::ecdsa;
-use multisig::key::{KeyType, PublicKey};
+use multisig::key::{registration_challenge, KeyType, PublicKey};
 use multisig::verifier_set::VerifierSet;
 use multisig_prover::msg::VerifierSetResponse;
 use rewards::PoolId;
 use router_api::{Address, ChainName, CrossChainId, GatewayDirection, Message};
 use service_registry_api::msg::ExecuteMsg;
-use sha3::{Digest, Keccak256};
 use tofn::ecdsa::KeyPair;
 
 pub const AXL_DENOMINATION: &str = "uaxl";
@@ -268,6 +267,7 @@ pub fn register_service(
             bond_denom: AXL_DENOMINATION.into(),
             unbonding_period_days,
             description: "Some service".into(),
+            exit_handover_blocks: 0,
         },
     );
     assert!(response.is_ok());
@@ -436,6 +436,9 @@ pub fn setup_protocol(service_name: nonempty::String) -> Protocol {
         epoch_duration: nonempty::Uint64::try_from(10u64).unwrap(),
         rewards_per_epoch: Uint128::from(100u128).try_into().unwrap(),
         participation_threshold: (1, 2).try_into().unwrap(),
+        distribution_cooldown: None,
+        remainder_policy: None,
+        epoch_time: None,
     };
     let rewards = RewardsContract::instantiate_contract(
         &mut app,
@@ -780,6 +783,7 @@ pub fn setup_chain(protocol: &mut Protocol, chain_name: ChainName) -> Chain {
             chain: chain_name.clone(),
             gateway_address: gateway.contract_addr.to_string().try_into().unwrap(),
             msg_id_format: axelar_wasm_std::msg_id::MessageIdFormat::HexTxHashAndEventIndex,
+            address_format: None,
         },
     );
     assert!(response.is_ok());
@@ -788,6 +792,9 @@ pub fn setup_chain(protocol: &mut Protocol, chain_name: ChainName) -> Chain {
         epoch_duration: nonempty::Uint64::try_from(10u64).unwrap(),
         rewards_per_epoch: Uint128::from(100u128).try_into().unwrap(),
         participation_threshold: (1, 2).try_into().unwrap(),
+        distribution_cooldown: None,
+        remainder_policy: None,
+        epoch_time: None,
     };
 
     let response = protocol.rewards.execute(
@@ -799,6 +806,8 @@ pub fn setup_chain(protocol: &mut Protocol, chain_name: ChainName) -> Chain {
                 contract: voting_verifier.contract_addr.to_string(),
             },
             params: rewards_params.clone(),
+            rewards_asset: rewards::msg::RewardsAsset::Native,
+            align_epochs_with: None,
         },
     );
     assert!(response.is_ok());
@@ -812,6 +821,8 @@ pub fn setup_chain(protocol: &mut Protocol, chain_name: ChainName) -> Chain {
                 contract: protocol.multisig.contract_addr.to_string(),
             },
             params: rewards_params,
+            rewards_asset: rewards::msg::RewardsAsset::Native,
+            align_epochs_with: None,
         },
     );
     assert!(response.is_ok());
@@ -1008,13 +1019,13 @@ pub fn register_in_service_registry(
 
 pub fn submit_pubkeys(protocol: &mut Protocol, verifiers: &Vec<Verifier>) {
     for verifier in verifiers {
-        let address_hash = Keccak256::digest(verifier.addr.as_bytes());
+        let challenge = registration_challenge(
+            verifier.addr.as_str(),
+            protocol.app.block_info().chain_id.as_str(),
+            KeyType::Ecdsa,
+        );
 
-        let sig = tofn::ecdsa::sign(
-            verifier.key_pair.signing_key(),
-            &address_hash.as_slice().try_into().unwrap(),
-        )
-        .unwrap();
+        let sig = tofn::ecdsa::sign(verifier.key_pair.signing_key(), &challenge.into()).unwrap();
         let sig = ecdsa::Signature::from_der(&sig).unwrap();
 
         let response = protocol.multisig.execute(
@@ -1024,7 +1035,7 @@ pub fn submit_pubkeys(protocol: &mut Protocol, verifiers: &Vec<Verifier>) {
                 public_key: PublicKey::Ecdsa(HexBinary::from(
                     verifier.key_pair.encoded_verifying_key(),
                 )),
-                signed_sender_address: HexBinary::from(sig.to_vec()),
+                proof_of_possession: HexBinary::from(sig.to_vec()),
             },
         );
         assert!(response.is_ok());
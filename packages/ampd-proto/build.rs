@@ -1,11 +1,25 @@
+use std::env;
+use std::path::PathBuf;
+
 fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let out_dir = PathBuf::from(env::var("OUT_DIR")?);
+
     tonic_build::configure()
         .build_server(true)
         .build_client(true)
         .extern_path(".google.protobuf.Any", "::cosmrs::Any")
+        .file_descriptor_set_path(out_dir.join("ampd_descriptor.bin"))
         .compile_protos(
-            &["proto-files/ampd/v1/ampd.proto"],
-            &["proto-files/ampd/v1"],
+            &[
+                "proto-files/ampd/v1/ampd.proto",
+                "proto/ampd/info/v1/info.proto",
+                "proto/ampd/broadcast/v1/broadcast.proto",
+            ],
+            &[
+                "proto-files/ampd/v1",
+                "proto/ampd/info/v1",
+                "proto/ampd/broadcast/v1",
+            ],
         )?;
 
     Ok(())
@@ -4,4 +4,21 @@ mod proto {
     tonic::include_proto!("ampd.v1");
 }
 
+/// Service that reports the version and the enabled features of an ampd deployment
+pub mod info {
+    tonic::include_proto!("ampd.info.v1");
+}
+
+/// Broadcast RPCs of the blockchain service that are not part of `ampd.v1` yet
+pub mod broadcast {
+    tonic::include_proto!("ampd.broadcast.v1");
+}
+
+/// Proto packages of the services that ampd implements. Clients compare them with the packages they were built
+/// against to detect incompatible deployments
+pub const PROTO_VERSIONS: &[&str] = &["ampd.v1", "ampd.info.v1", "ampd.broadcast.v1"];
+
+/// Encoded descriptors of all services, used by the server reflection service
+pub const FILE_DESCRIPTOR_SET: &[u8] = tonic::include_file_descriptor_set!("ampd_descriptor");
+
 mod utils;
@@ -1,25 +1,29 @@
 use std::pin::Pin;
+use std::time::Duration;
 use std::vec;
 
 use ampd_proto;
 use ampd_proto::blockchain_service_client::BlockchainServiceClient;
 use ampd_proto::crypto_service_client::CryptoServiceClient;
+use ampd_proto::info::info_service_client::InfoServiceClient;
+use ampd_proto::info::{ServiceInfoRequest, ServiceInfoResponse};
 use ampd_proto::SubscribeRequest;
 use async_trait::async_trait;
-use error_stack::{report, Report, Result, ResultExt};
+use error_stack::{ensure, report, Report, Result, ResultExt};
 use events::{AbciEventTypeFilter, Event};
 use futures::StreamExt;
 use mockall::automock;
 use thiserror::Error;
 use tokio_stream::Stream;
 use tonic::transport;
+use tonic_types::{ErrorDetails, StatusExt};
 
 #[derive(Error, Debug)]
 pub enum Error {
     #[error("failed to connect to the grpc endpoint")]
     GrpcConnection(#[from] tonic::transport::Error),
 
-    #[error("failed to execute gRPC request")]
+    #[error("failed to execute gRPC request: {}", .0.message())]
     GrpcRequest(#[from] tonic::Status),
 
     #[error("failed to convert event")]
@@ -27,8 +31,35 @@ pub enum Error {
 
     #[error("missing event in response")]
     InvalidResponse,
+
+    #[error("ampd {ampd_version} doesn't support proto version {expected}, supported versions: {supported:?}")]
+    IncompatibleServer {
+        ampd_version: String,
+        expected: String,
+        supported: Vec<String>,
+    },
 }
 
+impl Error {
+    /// The google.rpc error details that ampd attached to a failed request, e.g. the subsystem
+    /// the error originated in and the offending request fields
+    pub fn details(&self) -> Option<ErrorDetails> {
+        match self {
+            Error::GrpcRequest(status) => Some(status.get_error_details()),
+            _ => None,
+        }
+    }
+
+    /// The delay after which the failed request can be retried. Returns None if ampd did not mark
+    /// the error as retryable, in which case retrying the same request is expected to fail again
+    pub fn retry_delay(&self) -> Option<Duration> {
+        self.details()?.retry_info()?.retry_delay
+    }
+}
+
+/// Proto package of the services this SDK was built against
+const PROTO_VERSION: &str = "ampd.v1";
+
 #[automock(type Stream = tokio_stream::Iter<vec::IntoIter<Result<Event, Error>>>;)]
 #[async_trait]
 #[allow(dead_code)]
@@ -47,6 +78,7 @@ pub trait Client {
 pub struct GrpcClient {
     pub blockchain: BlockchainServiceClient<transport::Channel>,
     pub crypto: CryptoServiceClient<transport::Channel>,
+    pub info: InfoServiceClient<transport::Channel>,
 }
 
 #[allow(dead_code)]
@@ -63,9 +95,39 @@ pub async fn new(url: &str) -> Result<GrpcClient, Error> {
         .map_err(Report::new)?;
 
     let blockchain = BlockchainServiceClient::new(conn.clone());
-    let crypto = CryptoServiceClient::new(conn);
+    let crypto = CryptoServiceClient::new(conn.clone());
+    let mut info = InfoServiceClient::new(conn);
 
-    Ok(GrpcClient { blockchain, crypto })
+    // fail fast instead of running into confusing errors on the first call to an incompatible server
+    let service_info = info
+        .service_info(ServiceInfoRequest {})
+        .await
+        .map_err(Error::GrpcRequest)
+        .map_err(Report::new)?
+        .into_inner();
+    check_compatibility(&service_info)?;
+
+    Ok(GrpcClient {
+        blockchain,
+        crypto,
+        info,
+    })
+}
+
+fn check_compatibility(service_info: &ServiceInfoResponse) -> Result<(), Error> {
+    ensure!(
+        service_info
+            .proto_versions
+            .iter()
+            .any(|version| version == PROTO_VERSION),
+        Error::IncompatibleServer {
+            ampd_version: service_info.ampd_version.clone(),
+            expected: PROTO_VERSION.to_string(),
+            supported: service_info.proto_versions.clone(),
+        }
+    );
+
+    Ok(())
 }
 
 #[async_trait]
@@ -116,4 +178,73 @@ mod tests {
         let mut _mock = MockClient::new();
         // This test just verifies the mock can be created
     }
+
+    #[test]
+    fn check_compatibility_should_fail_if_proto_version_is_not_supported() {
+        let service_info = ServiceInfoResponse {
+            ampd_version: "2.0.0".to_string(),
+            proto_versions: vec!["ampd.v2".to_string(), "ampd.info.v1".to_string()],
+            handlers: vec![],
+            auth_enabled: false,
+        };
+
+        assert!(matches!(
+            check_compatibility(&service_info)
+                .unwrap_err()
+                .current_context(),
+            Error::IncompatibleServer { .. }
+        ));
+    }
+
+    #[test]
+    fn retry_delay_should_be_read_from_status_details() {
+        let mut details = ErrorDetails::new();
+        details.set_retry_info(Some(Duration::from_secs(1)));
+        let err = Error::GrpcRequest(tonic::Status::with_error_details(
+            tonic::Code::Unavailable,
+            "blockchain service is temporarily unavailable",
+            details,
+        ));
+
+        assert_eq!(err.retry_delay(), Some(Duration::from_secs(1)));
+        assert_eq!(
+            err.to_string(),
+            "failed to execute gRPC request: blockchain service is temporarily unavailable"
+        );
+    }
+
+    #[test]
+    fn retry_delay_should_be_none_without_retry_info() {
+        let mut details = ErrorDetails::new();
+        details.add_bad_request_violation("filters", "filter must set an event type or a contract");
+        let err = Error::GrpcRequest(tonic::Status::with_error_details(
+            tonic::Code::InvalidArgument,
+            "empty filter provided",
+            details,
+        ));
+
+        assert_eq!(err.retry_delay(), None);
+        assert_eq!(
+            err.details()
+                .unwrap()
+                .bad_request()
+                .unwrap()
+                .field_violations[0]
+                .field,
+            "filters"
+        );
+        assert_eq!(Error::InvalidResponse.retry_delay(), None);
+    }
+
+    #[test]
+    fn check_compatibility_should_succeed_if_proto_version_is_supported() {
+        let service_info = ServiceInfoResponse {
+            ampd_version: "1.7.0".to_string(),
+            proto_versions: vec!["ampd.v1".to_string(), "ampd.info.v1".to_string()],
+            handlers: vec!["MultisigSigner".to_string()],
+            auth_enabled: false,
+        };
+
+        assert!(check_compatibility(&service_info).is_ok());
+    }
 }
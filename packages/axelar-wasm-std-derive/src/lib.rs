@@ -6,7 +6,7 @@ use proc_macro::TokenStream;
 use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
 use quote::quote;
 use syn::spanned::Spanned;
-use syn::{DeriveInput, FieldsNamed, Generics, ItemEnum, Variant};
+use syn::{DeriveInput, Fields, FieldsNamed, Generics, ItemEnum, Type, Variant};
 
 #[proc_macro_derive(IntoContractError)]
 pub fn into_contract_error_derive(input: TokenStream) -> TokenStream {
@@ -284,6 +284,249 @@ fn match_unit_variant(event_enum: &Ident, variant_name: &Ident) -> TokenStream2
     }
 }
 
+/// Derive macro to generate a typed query client for a contract's query message enum.
+///
+/// The client is named after the enum, with a trailing `Msg` replaced by `Client` (e.g. `QueryMsg` becomes `QueryClient`).
+/// It has one method per variant, named after the variant in snake case, which takes the variant's fields as parameters
+/// and returns the type given in the variant's `#[returns(...)]` attribute, the same attribute that `cosmwasm_schema::QueryResponses` uses.
+/// Query errors are reported with the error type given in the enum's `#[query_client(error = ...)]` attribute as context,
+/// which is created from the failed query message, so it must implement `From<QueryMsg>`.
+///
+/// # Examples
+///
+/// ```
+/// use cosmwasm_std::testing::{MockApi, MockQuerier};
+/// use cosmwasm_std::{to_json_binary, ContractResult, QuerierWrapper, SystemResult, WasmQuery};
+///
+/// use axelar_wasm_std_derive::QueryClient;
+///
+/// #[derive(serde::Serialize, QueryClient)]
+/// #[serde(rename_all = "snake_case")]
+/// #[query_client(error = Error)]
+/// enum QueryMsg {
+///     /// Returns the current epoch
+///     #[returns(u64)]
+///     CurrentEpoch,
+///     #[returns(Option<String>)]
+///     Verifier { address: String },
+///     #[returns(bool)]
+///     IsRegistered(String),
+/// }
+///
+/// #[derive(thiserror::Error, Debug)]
+/// #[error("query failed")]
+/// struct Error;
+///
+/// impl From<QueryMsg> for Error {
+///     fn from(_: QueryMsg) -> Self {
+///         Error
+///     }
+/// }
+///
+/// let mut querier = MockQuerier::default();
+/// querier.update_wasm(|query| match query {
+///     WasmQuery::Smart { .. } => SystemResult::Ok(ContractResult::Ok(to_json_binary(&42u64).unwrap())),
+///     _ => panic!("unexpected query"),
+/// });
+/// let address = MockApi::default().addr_make("contract");
+///
+/// let client = QueryClient::new(QuerierWrapper::new(&querier), &address);
+/// let epoch: error_stack::Result<u64, Error> = client.current_epoch();
+/// assert_eq!(epoch.unwrap(), 42);
+/// ```
+///
+/// ```compile_fail
+/// # use axelar_wasm_std_derive::QueryClient;
+/// # #[derive(Debug)]
+/// # struct Error;
+///
+/// # #[derive(serde::Serialize, QueryClient)] // should not compile because the variant has no return type
+/// # #[query_client(error = Error)]
+/// # enum QueryMsg {
+/// #     CurrentEpoch,
+/// # }
+/// ```
+///
+/// ```compile_fail
+/// # use axelar_wasm_std_derive::QueryClient;
+///
+/// # #[derive(serde::Serialize, QueryClient)] // should not compile because the error type is missing
+/// # enum QueryMsg {
+/// #     #[returns(u64)]
+/// #     CurrentEpoch,
+/// # }
+/// ```
+///
+/// ```compile_fail
+/// # use axelar_wasm_std_derive::QueryClient;
+///
+/// # #[derive(serde::Serialize, QueryClient)] // should not compile because the query message is not an enum
+/// # struct QueryMsg {
+/// #     epoch: u64,
+/// # }
+/// ```
+#[proc_macro_derive(QueryClient, attributes(returns, query_client))]
+pub fn derive_query_client(input: TokenStream) -> TokenStream {
+    let ItemEnum {
+        attrs,
+        variants,
+        ident: query_enum,
+        generics,
+        vis,
+        ..
+    } = syn::parse_macro_input!(input as syn::ItemEnum);
+
+    try_derive_query_client(vis, query_enum, &attrs, variants, generics)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn try_derive_query_client(
+    vis: syn::Visibility,
+    query_enum: Ident,
+    attrs: &[syn::Attribute],
+    variants: impl IntoIterator<Item = Variant>,
+    generics: Generics,
+) -> Result<TokenStream2, syn::Error> {
+    if !generics.params.is_empty() {
+        return Err(syn::Error::new(
+            generics.span(),
+            "generic query messages are not supported",
+        ));
+    }
+
+    let error = query_client_error(&query_enum, attrs)?;
+    let client = query_client_name(&query_enum);
+    let methods: Vec<_> = variants
+        .into_iter()
+        .map(|variant| query_method(&query_enum, &error, variant))
+        .try_collect()?;
+
+    let client_doc = format!("Typed client for the queries of [`{}`]", query_enum);
+
+    Ok(quote! {
+        #[doc = #client_doc]
+        #vis struct #client<'a> {
+            querier: cosmwasm_std::QuerierWrapper<'a>,
+            pub address: &'a cosmwasm_std::Addr,
+        }
+
+        impl<'a> #client<'a> {
+            pub fn new(querier: cosmwasm_std::QuerierWrapper<'a>, address: &'a cosmwasm_std::Addr) -> Self {
+                Self { querier, address }
+            }
+
+            #(#methods)*
+        }
+    })
+}
+
+fn query_client_name(query_enum: &Ident) -> Ident {
+    let name = query_enum.to_string();
+    let prefix = name.strip_suffix("Msg").unwrap_or(&name);
+
+    Ident::new(&format!("{}Client", prefix), query_enum.span())
+}
+
+fn query_client_error(
+    query_enum: &Ident,
+    attrs: &[syn::Attribute],
+) -> Result<syn::Path, syn::Error> {
+    let mut error = None;
+
+    for attr in attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("query_client"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("error") {
+                error = Some(meta.value()?.parse::<syn::Path>()?);
+                Ok(())
+            } else {
+                Err(meta.error("unsupported query_client attribute"))
+            }
+        })?;
+    }
+
+    error.ok_or_else(|| {
+        syn::Error::new(
+            query_enum.span(),
+            "missing #[query_client(error = ...)] attribute with the error type of the client",
+        )
+    })
+}
+
+fn query_method(
+    query_enum: &Ident,
+    error: &syn::Path,
+    variant: Variant,
+) -> Result<TokenStream2, syn::Error> {
+    let variant_name = &variant.ident;
+    let method_name = Ident::new(
+        &variant_name.to_string().to_snake_case(),
+        variant_name.span(),
+    );
+    let return_type = returns_type(&variant)?;
+    let docs = variant
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"));
+
+    let (params, msg) = match &variant.fields {
+        Fields::Named(fields) => {
+            let names = fields
+                .named
+                .iter()
+                .flat_map(|field| &field.ident)
+                .collect_vec();
+            let types = fields.named.iter().map(|field| &field.ty);
+
+            (
+                quote! { #(#names: #types),* },
+                quote! { #query_enum::#variant_name { #(#names),* } },
+            )
+        }
+        Fields::Unnamed(fields) => {
+            let names = (0..fields.unnamed.len())
+                .map(|i| Ident::new(&format!("field_{}", i), Span::call_site()))
+                .collect_vec();
+            let types = fields.unnamed.iter().map(|field| &field.ty);
+
+            (
+                quote! { #(#names: #types),* },
+                quote! { #query_enum::#variant_name(#(#names),*) },
+            )
+        }
+        Fields::Unit => (quote! {}, quote! { #query_enum::#variant_name }),
+    };
+
+    Ok(quote! {
+        #(#docs)*
+        pub fn #method_name(&self, #params) -> error_stack::Result<#return_type, #error> {
+            let msg = #msg;
+
+            match self.querier.query_wasm_smart(self.address, &msg) {
+                Ok(response) => Ok(response),
+                Err(err) => Err(error_stack::Report::new(err).change_context(<#error>::from(msg))),
+            }
+        }
+    })
+}
+
+fn returns_type(variant: &Variant) -> Result<Type, syn::Error> {
+    variant
+        .attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("returns"))
+        .ok_or_else(|| {
+            syn::Error::new(
+                variant.ident.span(),
+                "missing #[returns(...)] attribute with the query's response type",
+            )
+        })?
+        .parse_args::<Type>()
+}
+
 /// Attribute macro for handling contract version migrations. Must be applied to the `migrate` contract entry point.
 /// Checks if migrating from the current version is supported and sets the new version. The base version must be a valid semver without patch, pre, or build.
 ///
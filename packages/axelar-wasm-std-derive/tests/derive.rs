@@ -1,8 +1,12 @@
 use assert_ok::assert_ok;
 use axelar_wasm_std::error::ContractError;
-use axelar_wasm_std::{migrate_from_version, IntoContractError};
-use cosmwasm_std::testing::{mock_dependencies, mock_env};
-use cosmwasm_std::{DepsMut, Empty, Env, Response};
+use axelar_wasm_std::{migrate_from_version, IntoContractError, QueryClient};
+use cosmwasm_std::testing::{mock_dependencies, mock_env, MockApi, MockQuerier};
+use cosmwasm_std::{
+    from_json, to_json_binary, ContractResult, DepsMut, Empty, Env, QuerierWrapper, Response,
+    SystemError, SystemResult, WasmQuery,
+};
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 #[derive(Error, Debug, IntoContractError)]
@@ -16,6 +20,68 @@ fn can_convert_error() {
     _ = ContractError::from(TestError::Something);
 }
 
+#[derive(Serialize, Deserialize, Debug, PartialEq, QueryClient)]
+#[serde(rename_all = "snake_case")]
+#[query_client(error = TestQueryError)]
+enum TestQueryMsg {
+    #[returns(u64)]
+    Counter,
+    #[returns(Vec<String>)]
+    Names {
+        start_after: Option<String>,
+        limit: u32,
+    },
+    #[returns(Option<String>)]
+    Owner(String),
+}
+
+#[derive(Error, Debug, PartialEq)]
+#[error("failed to query {0:?}")]
+struct TestQueryError(TestQueryMsg);
+
+impl From<TestQueryMsg> for TestQueryError {
+    fn from(msg: TestQueryMsg) -> Self {
+        TestQueryError(msg)
+    }
+}
+
+#[test]
+fn query_client_should_send_typed_queries() {
+    let addr = MockApi::default().addr_make("contract");
+    let mut querier = MockQuerier::<Empty>::new(&[]);
+    querier.update_wasm(|query| {
+        let WasmQuery::Smart { msg, .. } = query else {
+            panic!("unexpected query");
+        };
+
+        let response = match from_json::<TestQueryMsg>(msg).unwrap() {
+            TestQueryMsg::Counter => to_json_binary(&42u64),
+            TestQueryMsg::Names { limit, .. } => {
+                to_json_binary(&vec!["name".to_string(); limit as usize])
+            }
+            TestQueryMsg::Owner(_) => {
+                return SystemResult::Err(SystemError::Unknown {});
+            }
+        };
+
+        SystemResult::Ok(ContractResult::Ok(response.unwrap()))
+    });
+
+    let client = TestQueryClient::new(QuerierWrapper::new(&querier), &addr);
+
+    assert_eq!(client.counter().unwrap(), 42);
+    assert_eq!(
+        client.names(Some("a".to_string()), 2).unwrap(),
+        vec!["name".to_string(), "name".to_string()]
+    );
+
+    let err = client.owner("name".to_string()).unwrap_err();
+    assert_eq!(
+        err.current_context(),
+        &TestQueryError(TestQueryMsg::Owner("name".to_string()))
+    );
+}
+
 #[migrate_from_version("999.1")]
 pub fn migrate(
     deps: DepsMut,
@@ -0,0 +1,126 @@
+use std::fmt::Display;
+use std::ops::Deref;
+
+use schemars::gen::SchemaGenerator;
+use schemars::schema::Schema;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::bounded::{string_schema, Error};
+use crate::nonempty;
+
+/// Non-empty bytes of at most `N` bytes, serialized as hex like [cosmwasm_std::HexBinary]. Use it for user-supplied
+/// binary data that ends up in state or events, so its size is checked when the message is deserialized
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[serde(try_from = "cosmwasm_std::HexBinary")]
+pub struct Bytes<const N: usize>(cosmwasm_std::HexBinary);
+
+impl<const N: usize> TryFrom<cosmwasm_std::HexBinary> for Bytes<N> {
+    type Error = Error;
+
+    fn try_from(value: cosmwasm_std::HexBinary) -> Result<Self, Self::Error> {
+        if value.is_empty() || value.len() > N {
+            Err(Error::InvalidLength {
+                length: value.len(),
+                max: N,
+            })
+        } else {
+            Ok(Bytes(value))
+        }
+    }
+}
+
+impl<const N: usize> TryFrom<std::vec::Vec<u8>> for Bytes<N> {
+    type Error = Error;
+
+    fn try_from(value: std::vec::Vec<u8>) -> Result<Self, Self::Error> {
+        cosmwasm_std::HexBinary::from(value).try_into()
+    }
+}
+
+impl<const N: usize> From<Bytes<N>> for cosmwasm_std::HexBinary {
+    fn from(value: Bytes<N>) -> Self {
+        value.0
+    }
+}
+
+impl<const N: usize> From<Bytes<N>> for std::vec::Vec<u8> {
+    fn from(value: Bytes<N>) -> Self {
+        value.0.into()
+    }
+}
+
+impl<const N: usize> From<Bytes<N>> for nonempty::HexBinary {
+    fn from(value: Bytes<N>) -> Self {
+        value.0.try_into().expect("cannot be empty")
+    }
+}
+
+impl<const N: usize> Deref for Bytes<N> {
+    type Target = cosmwasm_std::HexBinary;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<const N: usize> Display for Bytes<N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl<const N: usize> JsonSchema for Bytes<N> {
+    fn is_referenceable() -> bool {
+        false
+    }
+
+    fn schema_name() -> std::string::String {
+        format!("Bytes_max_{}", N)
+    }
+
+    // each byte is encoded as two hex characters
+    fn json_schema(_gen: &mut SchemaGenerator) -> Schema {
+        string_schema(2, N.saturating_mul(2))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::HexBinary;
+
+    use crate::bounded::{self, Error};
+    use crate::nonempty;
+
+    #[test]
+    fn bytes_length_should_be_bounded() {
+        assert_eq!(
+            bounded::Bytes::<2>::try_from(vec![]).unwrap_err(),
+            Error::InvalidLength { length: 0, max: 2 }
+        );
+        assert_eq!(
+            bounded::Bytes::<2>::try_from(vec![1, 2, 3]).unwrap_err(),
+            Error::InvalidLength { length: 3, max: 2 }
+        );
+        assert!(serde_json::from_str::<bounded::Bytes<2>>("\"\"").is_err());
+        assert!(serde_json::from_str::<bounded::Bytes<2>>("\"010203\"").is_err());
+
+        let bytes = serde_json::from_str::<bounded::Bytes<2>>("\"0102\"").unwrap();
+        assert_eq!(HexBinary::from(bytes), HexBinary::from(&[1, 2]));
+    }
+
+    #[test]
+    fn bytes_should_convert_to_nonempty_hexbinary() {
+        let bytes: nonempty::HexBinary = bounded::Bytes::<2>::try_from(vec![1, 2]).unwrap().into();
+        assert_eq!(bytes.as_slice(), &[1, 2]);
+    }
+
+    #[test]
+    fn bytes_schema_should_include_hex_length_bounds() {
+        let schema = serde_json::to_value(schemars::schema_for!(bounded::Bytes<2>)).unwrap();
+
+        assert_eq!(schema["type"], "string");
+        assert_eq!(schema["minLength"], 2);
+        assert_eq!(schema["maxLength"], 4);
+    }
+}
@@ -0,0 +1,7 @@
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum Error {
+    #[error("length must be between 1 and {max} but is {length}")]
+    InvalidLength { length: usize, max: usize },
+}
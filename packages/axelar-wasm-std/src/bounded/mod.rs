@@ -0,0 +1,23 @@
+mod bytes;
+mod error;
+mod string;
+
+pub use bytes::Bytes;
+pub use error::Error;
+pub use string::String;
+
+use schemars::schema::{InstanceType, Schema, SchemaObject, StringValidation};
+
+/// Schema of a string whose length is between `min_length` and `max_length`
+fn string_schema(min_length: usize, max_length: usize) -> Schema {
+    SchemaObject {
+        instance_type: Some(InstanceType::String.into()),
+        string: Some(Box::new(StringValidation {
+            min_length: Some(u32::try_from(min_length).unwrap_or(u32::MAX)),
+            max_length: Some(u32::try_from(max_length).unwrap_or(u32::MAX)),
+            pattern: None,
+        })),
+        ..Default::default()
+    }
+    .into()
+}
@@ -0,0 +1,137 @@
+use std::fmt::Display;
+use std::ops::Deref;
+use std::str::FromStr;
+
+use schemars::gen::SchemaGenerator;
+use schemars::schema::Schema;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::bounded::{string_schema, Error};
+use crate::nonempty;
+
+/// A non-empty string of at most `N` bytes. Use it for user-supplied strings that end up in state or events,
+/// so their size is checked when the message is deserialized
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[serde(try_from = "std::string::String")]
+pub struct String<const N: usize>(std::string::String);
+
+impl<const N: usize> TryFrom<std::string::String> for String<N> {
+    type Error = Error;
+
+    fn try_from(value: std::string::String) -> Result<Self, Self::Error> {
+        if value.is_empty() || value.len() > N {
+            Err(Error::InvalidLength {
+                length: value.len(),
+                max: N,
+            })
+        } else {
+            Ok(String(value))
+        }
+    }
+}
+
+impl<const N: usize> TryFrom<&str> for String<N> {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        String::try_from(value.to_string())
+    }
+}
+
+impl<const N: usize> FromStr for String<N> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.try_into()
+    }
+}
+
+impl<const N: usize> From<String<N>> for std::string::String {
+    fn from(value: String<N>) -> Self {
+        value.0
+    }
+}
+
+impl<const N: usize> From<String<N>> for nonempty::String {
+    fn from(value: String<N>) -> Self {
+        value.0.try_into().expect("cannot be empty")
+    }
+}
+
+impl<const N: usize> Deref for String<N> {
+    type Target = std::string::String;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<const N: usize> Display for String<N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl<const N: usize> JsonSchema for String<N> {
+    fn is_referenceable() -> bool {
+        false
+    }
+
+    fn schema_name() -> std::string::String {
+        format!("String_max_{}", N)
+    }
+
+    fn json_schema(_gen: &mut SchemaGenerator) -> Schema {
+        string_schema(1, N)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bounded::{self, Error};
+    use crate::nonempty;
+
+    #[test]
+    fn string_length_should_be_bounded() {
+        assert_eq!(
+            bounded::String::<3>::try_from("").unwrap_err(),
+            Error::InvalidLength { length: 0, max: 3 }
+        );
+        assert_eq!(
+            bounded::String::<3>::try_from("abcd").unwrap_err(),
+            Error::InvalidLength { length: 4, max: 3 }
+        );
+        assert!(serde_json::from_str::<bounded::String<3>>("\"\"").is_err());
+        assert!(serde_json::from_str::<bounded::String<3>>("\"abcd\"").is_err());
+
+        assert!(bounded::String::<3>::try_from("a").is_ok());
+        assert!(bounded::String::<3>::try_from("abc").is_ok());
+        assert_eq!(
+            serde_json::from_str::<bounded::String<3>>("\"abc\"").unwrap(),
+            "abc".parse().unwrap()
+        );
+    }
+
+    #[test]
+    fn string_length_should_count_bytes() {
+        // two characters of two bytes each
+        assert!(bounded::String::<3>::try_from("éé").is_err());
+        assert!(bounded::String::<4>::try_from("éé").is_ok());
+    }
+
+    #[test]
+    fn string_should_convert_to_nonempty_string() {
+        let string: nonempty::String = bounded::String::<3>::try_from("abc").unwrap().into();
+        assert_eq!(string, "abc");
+    }
+
+    #[test]
+    fn string_schema_should_include_length_bounds() {
+        let schema = serde_json::to_value(schemars::schema_for!(bounded::String<3>)).unwrap();
+
+        assert_eq!(schema["type"], "string");
+        assert_eq!(schema["minLength"], 1);
+        assert_eq!(schema["maxLength"], 3);
+    }
+}
@@ -0,0 +1,159 @@
+use error_stack::{Result, ResultExt};
+use serde::Serialize;
+use serde_json::{Number, Value};
+
+#[derive(thiserror::Error, Debug, PartialEq)]
+pub enum Error {
+    #[error("failed to serialize value to json")]
+    Serialize,
+}
+
+/// Serializes the value into canonical JSON, so the output only depends on the value itself and not on
+/// the serde_json version, enabled serde_json features, or the iteration order of maps:
+/// - object keys are sorted by their UTF-8 bytes
+/// - no insignificant whitespace is emitted
+/// - integers are written as plain decimals and floats without a fractional part are written as integers,
+///   all other floats use their shortest round-trip representation
+///
+/// Use this for all structured event attributes, because indexers and relayers compare them byte by byte.
+pub fn to_string<T>(value: &T) -> Result<String, Error>
+where
+    T: Serialize + ?Sized,
+{
+    let value = serde_json::to_value(value).change_context(Error::Serialize)?;
+
+    let mut out = String::new();
+    write_value(&value, &mut out);
+
+    Ok(out)
+}
+
+fn write_value(value: &Value, out: &mut String) {
+    match value {
+        Value::Null => out.push_str("null"),
+        Value::Bool(b) => out.push_str(if *b { "true" } else { "false" }),
+        Value::Number(n) => write_number(n, out),
+        Value::String(s) => write_string(s, out),
+        Value::Array(values) => {
+            out.push('[');
+            for (i, value) in values.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_value(value, out);
+            }
+            out.push(']');
+        }
+        Value::Object(map) => {
+            let mut entries: Vec<_> = map.iter().collect();
+            entries.sort_by(|(a, _), (b, _)| a.as_bytes().cmp(b.as_bytes()));
+
+            out.push('{');
+            for (i, (key, value)) in entries.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_string(key, out);
+                out.push(':');
+                write_value(value, out);
+            }
+            out.push('}');
+        }
+    }
+}
+
+fn write_number(n: &Number, out: &mut String) {
+    if let Some(n) = n.as_u64() {
+        out.push_str(&n.to_string());
+    } else if let Some(n) = n.as_i64() {
+        out.push_str(&n.to_string());
+    } else if let Some(f) = n.as_f64().filter(|f| f.fract() == 0.0) {
+        // avoids "-0" for negative zero
+        out.push_str(&format!("{:.0}", f + 0.0));
+    } else {
+        out.push_str(&n.to_string());
+    }
+}
+
+fn write_string(s: &str, out: &mut String) {
+    out.push_str(&serde_json::to_string(s).expect("strings should always be serializable"));
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeMap, HashMap};
+
+    use cosmwasm_std::{Addr, Uint128};
+    use serde::Serialize;
+
+    use super::*;
+
+    #[derive(Serialize)]
+    struct Nested {
+        zeta: u64,
+        alpha: Vec<Option<String>>,
+        map: HashMap<String, i64>,
+    }
+
+    #[derive(Serialize)]
+    enum Kind {
+        Unit,
+        Tuple(u8, bool),
+        Struct { b: f64, a: f64 },
+    }
+
+    #[derive(Serialize)]
+    struct Sample {
+        name: String,
+        address: Addr,
+        amount: Uint128,
+        kinds: Vec<Kind>,
+        nested: Nested,
+        floats: Vec<f64>,
+        escaped: String,
+    }
+
+    fn sample() -> Sample {
+        Sample {
+            name: "sample".to_string(),
+            address: Addr::unchecked("axelar1address"),
+            amount: Uint128::new(u128::MAX),
+            kinds: vec![
+                Kind::Unit,
+                Kind::Tuple(7, true),
+                Kind::Struct { b: 0.5, a: -2.0 },
+            ],
+            nested: Nested {
+                zeta: u64::MAX,
+                alpha: vec![Some("x".to_string()), None],
+                map: (0..20).map(|i| (format!("key{}", i), i)).collect(),
+            },
+            floats: vec![1.0, -0.0, 0.1, 1e21, 123.456],
+            escaped: "quote\" backslash\\ newline\n unicode ✓".to_string(),
+        }
+    }
+
+    #[test]
+    fn canonical_json_is_stable() {
+        goldie::assert!(to_string(&sample()).unwrap());
+    }
+
+    #[test]
+    fn canonical_json_does_not_depend_on_map_order() {
+        let hash_map: HashMap<_, _> = (0..100).map(|i| (format!("key{}", i), i)).collect();
+        let btree_map: BTreeMap<_, _> = hash_map.clone().into_iter().collect();
+
+        assert_eq!(
+            to_string(&hash_map).unwrap(),
+            to_string(&btree_map).unwrap()
+        );
+    }
+
+    #[test]
+    fn canonical_json_is_a_fixpoint() {
+        let json = to_string(&sample()).unwrap();
+        let value: Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(to_string(&value).unwrap(), json);
+    }
+}
@@ -4,6 +4,8 @@ pub use crate::threshold::{MajorityThreshold, Threshold};
 pub use crate::verification::VerificationStatus;
 
 pub mod address;
+pub mod bounded;
+pub mod canonical_json;
 pub mod counter;
 pub mod error;
 pub mod event;
@@ -12,12 +14,15 @@ mod fn_ext;
 pub mod hash;
 pub mod hex;
 pub mod killswitch;
+pub mod maintenance;
 pub mod msg_id;
 pub mod nonempty;
 pub mod permission_control;
+pub mod reply;
 pub mod response;
 pub mod snapshot;
 pub mod threshold;
+pub mod time;
 pub mod token;
 pub mod utils;
 pub mod vec;
@@ -0,0 +1,138 @@
+use cosmwasm_schema::cw_serde;
+
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+pub enum Error {
+    #[error("maintenance window must end after it starts, got start height {start_height} and end height {end_height}")]
+    EmptyWindow { start_height: u64, end_height: u64 },
+}
+
+/// Blocks during which a chain is under maintenance, from `start_height` up to but excluding `end_height`
+#[cw_serde]
+#[serde(try_from = "RawMaintenanceWindow")]
+#[derive(Copy, Eq, PartialOrd, Ord)]
+pub struct MaintenanceWindow {
+    start_height: u64,
+    end_height: u64,
+}
+
+#[cw_serde]
+struct RawMaintenanceWindow {
+    start_height: u64,
+    end_height: u64,
+}
+
+impl TryFrom<RawMaintenanceWindow> for MaintenanceWindow {
+    type Error = Error;
+
+    fn try_from(raw: RawMaintenanceWindow) -> Result<Self, Self::Error> {
+        MaintenanceWindow::new(raw.start_height, raw.end_height)
+    }
+}
+
+impl MaintenanceWindow {
+    pub fn new(start_height: u64, end_height: u64) -> Result<Self, Error> {
+        if start_height >= end_height {
+            return Err(Error::EmptyWindow {
+                start_height,
+                end_height,
+            });
+        }
+
+        Ok(MaintenanceWindow {
+            start_height,
+            end_height,
+        })
+    }
+
+    pub fn start_height(&self) -> u64 {
+        self.start_height
+    }
+
+    pub fn end_height(&self) -> u64 {
+        self.end_height
+    }
+
+    pub fn is_active(&self, block_height: u64) -> bool {
+        self.start_height <= block_height && block_height < self.end_height
+    }
+
+    pub fn has_ended(&self, block_height: u64) -> bool {
+        self.end_height <= block_height
+    }
+
+    pub fn overlaps(&self, other: &MaintenanceWindow) -> bool {
+        self.start_height < other.end_height && other.start_height < self.end_height
+    }
+
+    /// Returns the number of blocks from `from_height` up to but excluding `to_height` that fall into the window
+    pub fn blocks_within(&self, from_height: u64, to_height: u64) -> u64 {
+        to_height
+            .min(self.end_height)
+            .saturating_sub(from_height.max(self.start_height))
+    }
+}
+
+/// Returns the window that is active at the given block height, if any
+pub fn active_window(
+    windows: &[MaintenanceWindow],
+    block_height: u64,
+) -> Option<&MaintenanceWindow> {
+    windows.iter().find(|window| window.is_active(block_height))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn window_should_not_be_empty() {
+        assert_eq!(
+            MaintenanceWindow::new(10, 10).unwrap_err(),
+            Error::EmptyWindow {
+                start_height: 10,
+                end_height: 10
+            }
+        );
+        assert!(MaintenanceWindow::new(11, 10).is_err());
+        assert!(
+            serde_json::from_str::<MaintenanceWindow>(r#"{"start_height":10,"end_height":5}"#)
+                .is_err()
+        );
+
+        let window: MaintenanceWindow =
+            serde_json::from_str(r#"{"start_height":10,"end_height":20}"#).unwrap();
+        assert_eq!(window, MaintenanceWindow::new(10, 20).unwrap());
+    }
+
+    #[test]
+    fn window_should_be_active_from_start_until_before_end() {
+        let window = MaintenanceWindow::new(10, 20).unwrap();
+
+        assert!(!window.is_active(9));
+        assert!(window.is_active(10));
+        assert!(window.is_active(19));
+        assert!(!window.is_active(20));
+        assert!(!window.has_ended(19));
+        assert!(window.has_ended(20));
+
+        let windows = [MaintenanceWindow::new(30, 40).unwrap(), window];
+        assert_eq!(active_window(&windows, 15), Some(&window));
+        assert_eq!(active_window(&windows, 25), None);
+    }
+
+    #[test]
+    fn overlapping_blocks_should_be_counted() {
+        let window = MaintenanceWindow::new(10, 20).unwrap();
+
+        assert!(window.overlaps(&MaintenanceWindow::new(19, 25).unwrap()));
+        assert!(window.overlaps(&MaintenanceWindow::new(0, 11).unwrap()));
+        assert!(!window.overlaps(&MaintenanceWindow::new(20, 25).unwrap()));
+        assert!(!window.overlaps(&MaintenanceWindow::new(0, 10).unwrap()));
+
+        assert_eq!(window.blocks_within(0, 5), 0);
+        assert_eq!(window.blocks_within(5, 15), 5);
+        assert_eq!(window.blocks_within(12, 30), 8);
+        assert_eq!(window.blocks_within(0, 30), 10);
+        assert_eq!(window.blocks_within(25, 30), 0);
+    }
+}
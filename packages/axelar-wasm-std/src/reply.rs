@@ -0,0 +1,183 @@
+use cosmwasm_std::{from_json, Reply, SubMsgResponse};
+use cw_utils::{parse_execute_response_data, MsgExecuteContractResponse};
+use error_stack::{bail, report, Result, ResultExt};
+use serde::de::DeserializeOwned;
+
+#[derive(thiserror::Error, Debug, PartialEq)]
+pub enum Error {
+    #[error("unknown reply id {0}")]
+    UnknownReplyId(u64),
+    #[error("submessage with reply id {id} failed: {reason}")]
+    SubMsgFailure { id: u64, reason: String },
+    #[error("submessage with reply id {0} succeeded but was expected to fail")]
+    UnexpectedSuccess(u64),
+    #[error("submessage with reply id {0} returned no data")]
+    MissingData(u64),
+    #[error("failed to parse the data returned by submessage with reply id {0}")]
+    InvalidData(u64),
+}
+
+/// Maps the id of a reply to the submessage kind it was registered for.
+/// Unknown ids result in an error instead of a panic in the reply entry point.
+pub fn route<R>(reply: &Reply) -> Result<R, Error>
+where
+    R: TryFrom<u64>,
+{
+    R::try_from(reply.id).map_err(|_| report!(Error::UnknownReplyId(reply.id)))
+}
+
+/// Returns the response of a successful submessage, or an error containing the failure reason.
+pub fn parse_success(reply: Reply) -> Result<SubMsgResponse, Error> {
+    reply.result.into_result().map_err(|reason| {
+        report!(Error::SubMsgFailure {
+            id: reply.id,
+            reason,
+        })
+    })
+}
+
+/// Returns the failure reason of a submessage that was dispatched with `ReplyOn::Error`.
+pub fn parse_failure(reply: Reply) -> Result<String, Error> {
+    match reply.result.into_result() {
+        Ok(_) => bail!(Error::UnexpectedSuccess(reply.id)),
+        Err(reason) => Ok(reason),
+    }
+}
+
+/// Deserializes the data a `WasmMsg::Execute` submessage set on its response.
+/// The submessage must have been dispatched with `ReplyOn::Success` or `ReplyOn::Always`.
+pub fn parse_execute_data<T>(reply: Reply) -> Result<T, Error>
+where
+    T: DeserializeOwned,
+{
+    let id = reply.id;
+
+    #[allow(deprecated)]
+    // TODO: use `msg_responses` instead when the cosmwasm vm is updated to 2.x.x
+    let data = parse_success(reply)?.data.ok_or(Error::MissingData(id))?;
+
+    match parse_execute_response_data(data.as_slice()).change_context(Error::InvalidData(id))? {
+        MsgExecuteContractResponse { data: Some(data) } => {
+            from_json(data).change_context(Error::InvalidData(id))
+        }
+        MsgExecuteContractResponse { data: None } => bail!(Error::MissingData(id)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{to_json_binary, Binary, SubMsgResult, Uint64};
+
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    enum ReplyId {
+        First,
+    }
+
+    impl TryFrom<u64> for ReplyId {
+        type Error = ();
+
+        fn try_from(id: u64) -> std::result::Result<Self, Self::Error> {
+            match id {
+                1 => Ok(ReplyId::First),
+                _ => Err(()),
+            }
+        }
+    }
+
+    fn reply(id: u64, result: SubMsgResult) -> Reply {
+        Reply {
+            id,
+            result,
+            payload: Binary::default(),
+            gas_used: 0,
+        }
+    }
+
+    #[allow(deprecated)]
+    fn success(data: Option<Binary>) -> SubMsgResult {
+        SubMsgResult::Ok(SubMsgResponse {
+            events: vec![],
+            data,
+            msg_responses: vec![],
+        })
+    }
+
+    // the reply data of an execute message is a protobuf encoded MsgExecuteContractResponse
+    fn execute_response_data(data: &[u8]) -> Binary {
+        let mut encoded = vec![0x0a, u8::try_from(data.len()).unwrap()];
+        encoded.extend_from_slice(data);
+        encoded.into()
+    }
+
+    #[test]
+    fn route_should_map_known_ids() {
+        assert_eq!(
+            route::<ReplyId>(&reply(1, success(None))).unwrap(),
+            ReplyId::First
+        );
+        assert_eq!(
+            route::<ReplyId>(&reply(2, success(None)))
+                .unwrap_err()
+                .current_context(),
+            &Error::UnknownReplyId(2)
+        );
+    }
+
+    #[test]
+    fn parse_execute_data_should_deserialize_data() {
+        let data = to_json_binary(&Uint64::new(42)).unwrap();
+        let reply = reply(1, success(Some(execute_response_data(data.as_slice()))));
+
+        assert_eq!(
+            parse_execute_data::<Uint64>(reply).unwrap(),
+            Uint64::new(42)
+        );
+    }
+
+    #[test]
+    fn parse_execute_data_should_fail_on_missing_or_invalid_data() {
+        assert_eq!(
+            parse_execute_data::<Uint64>(reply(1, success(None)))
+                .unwrap_err()
+                .current_context(),
+            &Error::MissingData(1)
+        );
+        assert_eq!(
+            parse_execute_data::<Uint64>(reply(1, success(Some(execute_response_data(&[])))))
+                .unwrap_err()
+                .current_context(),
+            &Error::MissingData(1)
+        );
+        assert_eq!(
+            parse_execute_data::<Uint64>(reply(
+                1,
+                success(Some(execute_response_data(b"not json")))
+            ))
+            .unwrap_err()
+            .current_context(),
+            &Error::InvalidData(1)
+        );
+    }
+
+    #[test]
+    fn parse_should_distinguish_success_and_failure() {
+        let failure = || reply(1, SubMsgResult::Err("out of gas".to_string()));
+
+        assert_eq!(
+            parse_success(failure()).unwrap_err().current_context(),
+            &Error::SubMsgFailure {
+                id: 1,
+                reason: "out of gas".to_string()
+            }
+        );
+        assert_eq!(parse_failure(failure()).unwrap(), "out of gas");
+        assert_eq!(
+            parse_failure(reply(1, success(None)))
+                .unwrap_err()
+                .current_context(),
+            &Error::UnexpectedSuccess(1)
+        );
+    }
+}
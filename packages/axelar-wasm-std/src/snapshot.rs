@@ -1,17 +1,93 @@
 use std::collections::HashMap;
 
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Uint128};
+use cosmwasm_std::{Addr, OverflowError, Uint128};
+use itertools::Itertools;
 
 use crate::nonempty;
 use crate::threshold::MajorityThreshold;
 
+/// Anything that takes part in a weighted decision, like a verifier voting in a poll or a signer of a multisig
+pub trait WeightedParticipant {
+    fn address(&self) -> &Addr;
+    fn weight(&self) -> Uint128;
+}
+
+impl<P: WeightedParticipant> WeightedParticipant for &P {
+    fn address(&self) -> &Addr {
+        (*self).address()
+    }
+
+    fn weight(&self) -> Uint128 {
+        (*self).weight()
+    }
+}
+
 #[cw_serde]
 pub struct Participant {
     pub address: Addr,
     pub weight: nonempty::Uint128,
 }
 
+impl Participant {
+    /// Multiplies the participant's weight by the given factor, which can't make the weight zero
+    pub fn scale_weight(self, factor: nonempty::Uint64) -> Result<Self, OverflowError> {
+        let weight = Uint128::from(self.weight)
+            .checked_mul(Uint128::from(u64::from(factor)))?
+            .try_into()
+            .expect("violated invariant: scaled weight is zero");
+
+        Ok(Self { weight, ..self })
+    }
+}
+
+impl WeightedParticipant for Participant {
+    fn address(&self) -> &Addr {
+        &self.address
+    }
+
+    fn weight(&self) -> Uint128 {
+        self.weight.into()
+    }
+}
+
+/// Sum of the weights of the given participants, saturating at `Uint128::MAX`
+pub fn total_weight<P: WeightedParticipant>(participants: impl IntoIterator<Item = P>) -> Uint128 {
+    participants
+        .into_iter()
+        .map(|participant| participant.weight())
+        .fold(Uint128::zero(), Uint128::saturating_add)
+}
+
+/// Weight required to meet the threshold of the total weight, rounded up
+pub fn quorum(total_weight: Uint128, threshold: MajorityThreshold) -> Uint128 {
+    total_weight.mul_ceil(threshold)
+}
+
+pub fn is_quorum_met(weight: Uint128, quorum: Uint128) -> bool {
+    weight >= quorum
+}
+
+/// Returns the heaviest participants, sorted by weight, until their combined weight meets the quorum.
+/// If the quorum can't be met, all participants are returned.
+pub fn heaviest_until_quorum<P: WeightedParticipant>(
+    participants: impl IntoIterator<Item = P>,
+    quorum: Uint128,
+) -> Vec<P> {
+    participants
+        .into_iter()
+        .sorted_by(|a, b| b.weight().cmp(&a.weight()))
+        .scan(Uint128::zero(), |acc, participant| {
+            if is_quorum_met(*acc, quorum) {
+                None
+            } else {
+                *acc = acc.saturating_add(participant.weight());
+                Some(participant)
+            }
+        })
+        .collect()
+}
+
 #[cw_serde]
 pub struct Snapshot {
     pub quorum: nonempty::Uint128,
@@ -23,20 +99,16 @@ impl Snapshot {
         quorum_threshold: MajorityThreshold,
         participants: nonempty::Vec<Participant>,
     ) -> Self {
-        let mut total_weight = Uint128::zero();
-
         let participants: Vec<Participant> = participants.into();
+        let total_weight = total_weight(&participants);
+
         let participants: HashMap<String, Participant> = participants
             .into_iter()
-            .map(|participant| {
-                total_weight = total_weight.saturating_add(participant.weight.into());
-
-                (participant.address.to_string(), participant)
-            })
+            .map(|participant| (participant.address.to_string(), participant))
             .collect();
 
         // Shouldn't panic here since it's impossible to have zero values when using nonempty::Vec of Participants with NonZero weight
-        let quorum = nonempty::Uint128::try_from(total_weight.mul_ceil(quorum_threshold))
+        let quorum = nonempty::Uint128::try_from(quorum(total_weight, quorum_threshold))
             .expect("violated invariant: quorum is zero");
 
         Self {
@@ -61,7 +133,9 @@ impl Snapshot {
 #[cfg(test)]
 mod tests {
     use cosmwasm_std::testing::MockApi;
-    use cosmwasm_std::{from_json, to_json_binary, Uint64};
+    use cosmwasm_std::{from_json, to_json_binary, Fraction, Uint64};
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
 
     use super::*;
     use crate::Threshold;
@@ -224,4 +298,119 @@ mod tests {
 
         assert_eq!(Uint128::from(snapshot.quorum), Uint128::MAX);
     }
+
+    // fixed seed, so failing cases can be reproduced
+    const RNG_SEED: u64 = 42;
+
+    fn random_participants(rng: &mut impl Rng) -> nonempty::Vec<Participant> {
+        mock_participants(
+            (0..rng.gen_range(1..=20))
+                .map(|_| ("participant", non_zero_128(rng.gen_range(1u64..=1000))))
+                .collect(),
+        )
+    }
+
+    fn random_majority_threshold(rng: &mut impl Rng) -> MajorityThreshold {
+        let denominator = rng.gen_range(1u64..=100);
+        let numerator =
+            rng.gen_range(denominator.saturating_div(2).saturating_add(1)..=denominator);
+
+        Threshold::try_from((numerator, denominator))
+            .unwrap()
+            .try_into()
+            .unwrap()
+    }
+
+    #[test]
+    fn quorum_is_smallest_weight_meeting_threshold() {
+        let mut rng = StdRng::seed_from_u64(RNG_SEED);
+
+        for _ in 0..1000 {
+            let total_weight = Uint128::from(rng.gen_range(1u64..=u64::MAX));
+            let threshold = random_majority_threshold(&mut rng);
+            let numerator = Uint128::from(u64::from(threshold.numerator()));
+            let denominator = Uint128::from(u64::from(threshold.denominator()));
+
+            let quorum = quorum(total_weight, threshold);
+            let required = total_weight.checked_mul(numerator).unwrap();
+
+            assert!(quorum <= total_weight);
+            assert!(quorum.checked_mul(denominator).unwrap() >= required);
+            assert!(
+                quorum
+                    .checked_sub(Uint128::one())
+                    .unwrap()
+                    .checked_mul(denominator)
+                    .unwrap()
+                    < required
+            );
+        }
+    }
+
+    #[test]
+    fn heaviest_until_quorum_selects_minimal_heaviest_participants() {
+        let mut rng = StdRng::seed_from_u64(RNG_SEED);
+
+        for _ in 0..1000 {
+            let participants: Vec<Participant> = random_participants(&mut rng).into();
+            let quorum = quorum(
+                total_weight(&participants),
+                random_majority_threshold(&mut rng),
+            );
+
+            let selected = heaviest_until_quorum(&participants, quorum);
+
+            assert!(is_quorum_met(total_weight(&selected), quorum));
+            assert!(selected
+                .windows(2)
+                .all(|pair| pair[0].weight >= pair[1].weight));
+
+            // without the lightest selected participant the quorum is no longer met
+            let (_, heavier) = selected.split_last().unwrap();
+            assert!(!is_quorum_met(total_weight(heavier), quorum));
+
+            // no unselected participant is heavier than the lightest selected one
+            let lightest = selected.last().unwrap().weight;
+            assert_eq!(
+                participants
+                    .iter()
+                    .filter(|participant| participant.weight > lightest)
+                    .count(),
+                selected
+                    .iter()
+                    .filter(|participant| participant.weight > lightest)
+                    .count()
+            );
+        }
+    }
+
+    #[test]
+    fn heaviest_until_quorum_returns_all_participants_if_quorum_is_unreachable() {
+        let participants: Vec<Participant> = default_participants().into();
+        let quorum = total_weight(&participants)
+            .checked_add(Uint128::one())
+            .unwrap();
+
+        assert_eq!(
+            heaviest_until_quorum(&participants, quorum).len(),
+            participants.len()
+        );
+    }
+
+    #[test]
+    fn scale_weight_multiplies_weight() {
+        let participant = mock_participant("participant", non_zero_128(100u16));
+        let factor = nonempty::Uint64::try_from(3u64).unwrap();
+
+        assert_eq!(
+            participant.clone().scale_weight(factor).unwrap(),
+            Participant {
+                weight: non_zero_128(300u16),
+                ..participant
+            }
+        );
+
+        let participant = mock_participant("participant", non_zero_128(Uint128::MAX));
+        assert!(participant.scale_weight(factor).is_err());
+    }
 }
@@ -0,0 +1,198 @@
+use std::fmt;
+
+use cosmwasm_schema::cw_serde;
+use into_inner_derive::IntoInner;
+
+use crate::nonempty;
+
+const NANOS_PER_SECOND: u64 = 1_000_000_000;
+
+/// A non-zero span of wall-clock time with second precision.
+/// Use it for thresholds that must not depend on the block time of the chain, e.g. poll expiry.
+#[cw_serde]
+#[serde(try_from = "u64")]
+#[serde(into = "u64")]
+#[derive(Copy, Eq, PartialOrd, Ord, IntoInner)]
+pub struct Duration(u64);
+
+impl Duration {
+    pub fn from_secs(seconds: nonempty::Uint64) -> Self {
+        Duration(seconds.into())
+    }
+
+    pub fn as_secs(&self) -> u64 {
+        self.0
+    }
+
+    pub fn as_nanos(&self) -> u64 {
+        self.0.saturating_mul(NANOS_PER_SECOND)
+    }
+}
+
+impl TryFrom<u64> for Duration {
+    type Error = nonempty::Error;
+
+    fn try_from(seconds: u64) -> Result<Self, Self::Error> {
+        nonempty::Uint64::try_from(seconds).map(Duration::from_secs)
+    }
+}
+
+impl From<Duration> for u64 {
+    fn from(value: Duration) -> Self {
+        value.0
+    }
+}
+
+impl fmt::Display for Duration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}s", self.0)
+    }
+}
+
+/// A point in wall-clock time, usually taken from the block time. Unlike [cosmwasm_std::Timestamp],
+/// arithmetic with it saturates instead of panicking on overflow.
+#[cw_serde]
+#[derive(Copy, Eq, PartialOrd, Ord, IntoInner)]
+pub struct Timestamp(cosmwasm_std::Timestamp);
+
+impl Timestamp {
+    pub fn from_nanos(nanos: u64) -> Self {
+        Timestamp(cosmwasm_std::Timestamp::from_nanos(nanos))
+    }
+
+    pub fn from_seconds(seconds: u64) -> Self {
+        Self::from_nanos(seconds.saturating_mul(NANOS_PER_SECOND))
+    }
+
+    pub fn nanos(&self) -> u64 {
+        self.0.nanos()
+    }
+
+    pub fn seconds(&self) -> u64 {
+        self.0.seconds()
+    }
+
+    pub fn saturating_add(self, duration: Duration) -> Self {
+        Self::from_nanos(self.nanos().saturating_add(duration.as_nanos()))
+    }
+
+    pub fn saturating_sub(self, duration: Duration) -> Self {
+        Self::from_nanos(self.nanos().saturating_sub(duration.as_nanos()))
+    }
+
+    /// Whole seconds that have passed since `earlier`, or 0 if `earlier` is not earlier than this timestamp
+    pub fn seconds_since(&self, earlier: Timestamp) -> u64 {
+        self.nanos()
+            .saturating_sub(earlier.nanos())
+            .checked_div(NANOS_PER_SECOND)
+            .expect("divisor should not be zero")
+    }
+
+    /// Returns true if `now` is at or after this timestamp
+    pub fn is_reached(&self, now: Timestamp) -> bool {
+        now >= *self
+    }
+}
+
+impl From<cosmwasm_std::Timestamp> for Timestamp {
+    fn from(value: cosmwasm_std::Timestamp) -> Self {
+        Timestamp(value)
+    }
+}
+
+impl From<Timestamp> for cosmwasm_std::Timestamp {
+    fn from(value: Timestamp) -> Self {
+        value.0
+    }
+}
+
+impl From<nonempty::Timestamp> for Timestamp {
+    fn from(value: nonempty::Timestamp) -> Self {
+        Timestamp(value.into_inner())
+    }
+}
+
+impl TryFrom<Timestamp> for nonempty::Timestamp {
+    type Error = nonempty::Error;
+
+    fn try_from(value: Timestamp) -> Result<Self, Self::Error> {
+        value.0.try_into()
+    }
+}
+
+impl fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{from_json, to_json_string};
+
+    use super::*;
+
+    #[test]
+    fn zero_duration_should_be_rejected() {
+        assert_eq!(
+            Duration::try_from(0u64).unwrap_err(),
+            nonempty::Error::InvalidValue("0".into())
+        );
+        assert!(from_json::<Duration>("0").is_err());
+    }
+
+    #[test]
+    fn duration_should_serialize_as_seconds() {
+        let duration = Duration::try_from(600u64).unwrap();
+
+        assert_eq!(to_json_string(&duration).unwrap(), "600");
+        assert_eq!(from_json::<Duration>("600").unwrap(), duration);
+        assert_eq!(duration.to_string(), "600s");
+    }
+
+    #[test]
+    fn timestamp_arithmetic_should_saturate() {
+        let duration = Duration::try_from(10u64).unwrap();
+
+        assert_eq!(
+            Timestamp::from_seconds(5).saturating_add(duration),
+            Timestamp::from_seconds(15)
+        );
+        assert_eq!(
+            Timestamp::from_nanos(u64::MAX).saturating_add(duration),
+            Timestamp::from_nanos(u64::MAX)
+        );
+        assert_eq!(
+            Timestamp::from_seconds(5).saturating_sub(duration),
+            Timestamp::from_nanos(0)
+        );
+        assert_eq!(Duration::try_from(u64::MAX).unwrap().as_nanos(), u64::MAX);
+    }
+
+    #[test]
+    fn seconds_since_should_not_underflow() {
+        let earlier = Timestamp::from_seconds(10);
+        let later = Timestamp::from_nanos(25_000_000_001);
+
+        assert_eq!(later.seconds_since(earlier), 15);
+        assert_eq!(earlier.seconds_since(later), 0);
+    }
+
+    #[test]
+    fn timestamp_should_be_reached_at_and_after_it() {
+        let timestamp = Timestamp::from_seconds(10);
+
+        assert!(!timestamp.is_reached(Timestamp::from_seconds(9)));
+        assert!(timestamp.is_reached(Timestamp::from_seconds(10)));
+        assert!(timestamp.is_reached(Timestamp::from_seconds(11)));
+    }
+
+    #[test]
+    fn zero_timestamp_should_not_convert_to_nonempty() {
+        assert!(nonempty::Timestamp::try_from(Timestamp::from_nanos(0)).is_err());
+        assert_eq!(
+            Timestamp::from(nonempty::Timestamp::try_from(Timestamp::from_seconds(1)).unwrap()),
+            Timestamp::from_seconds(1)
+        );
+    }
+}
@@ -20,14 +20,16 @@ use std::ops::{Add, Mul};
 use std::str::FromStr;
 
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, StdError, StdResult, Uint128, Uint64};
+use cosmwasm_std::{Addr, BlockInfo, StdError, StdResult, Uint128, Uint64};
 use cw_storage_plus::{IntKey, Key, KeyDeserialize, Prefixer, PrimaryKey};
 use num_traits::{CheckedAdd, One};
 use strum::{AsRefStr, EnumIter, EnumString, IntoEnumIterator};
 use thiserror::Error;
 use valuable::Valuable;
 
-use crate::{nonempty, Snapshot};
+use crate::nonempty;
+use crate::snapshot::{self, Snapshot};
+use crate::time::Timestamp;
 
 #[derive(Error, Debug, PartialEq, Eq)]
 pub enum Error {
@@ -159,6 +161,15 @@ pub enum Vote {
     SucceededOnChain, // the txn was included on chain, and achieved the intended result
     FailedOnChain,    // the txn was included on chain, but failed to achieve the intended result
     NotFound,         // the txn could not be found on chain in any blocks at the time of voting
+    Abstain,          // the outcome could not be determined, e.g. because RPC endpoints disagree
+}
+
+impl Vote {
+    /// Abstain votes count as having voted, but never contribute to the quorum of any outcome. They are only rewarded
+    /// for messages that didn't reach consensus
+    pub fn counts_toward_quorum(&self) -> bool {
+        *self != Vote::Abstain
+    }
 }
 
 // Deserialization of enums as map keys is not supported by serde-json-wasm, we use String instead
@@ -169,6 +180,7 @@ impl Default for Tallies {
     fn default() -> Self {
         Self(
             Vote::iter()
+                .filter(Vote::counts_toward_quorum)
                 .map(|vote| (vote.as_ref().to_string(), Uint128::zero()))
                 .collect(),
         )
@@ -178,7 +190,7 @@ impl Default for Tallies {
 impl Tallies {
     pub fn consensus(&self, quorum: Uint128) -> Option<Vote> {
         self.0.iter().find_map(|(vote, tally)| {
-            if *tally >= quorum {
+            if snapshot::is_quorum_met(*tally, quorum) {
                 Some(vote.parse().expect("can't parse vote string back to enum"))
             } else {
                 None
@@ -187,6 +199,10 @@ impl Tallies {
     }
 
     pub fn tally(&mut self, vote: &Vote, weight: &Uint128) {
+        if !vote.counts_toward_quorum() {
+            return;
+        }
+
         let key = vote.as_ref();
 
         let tally = self
@@ -246,6 +262,27 @@ pub enum PollStatus {
 pub struct Participation {
     pub weight: nonempty::Uint128,
     pub voted: bool,
+    /// block height at which the vote was cast
+    #[serde(default)]
+    pub voted_at: Option<u64>,
+    /// true if every item of the poll had already reached quorum when the vote was cast,
+    /// i.e. the vote could not affect the outcome anymore
+    #[serde(default)]
+    pub after_quorum: bool,
+}
+
+/// When a participant voted, relative to the start of the poll and its outcome
+#[cw_serde]
+pub struct VoteLatency {
+    pub voter: String,
+    pub voted_at: u64,
+    /// number of blocks between the start of the poll and the vote. Not known for polls that were started before it was recorded
+    pub blocks_after_start: Option<u64>,
+    pub after_quorum: bool,
+    /// number of blocks between the poll reaching quorum and the vote. Only set for votes cast after quorum,
+    /// and not known for polls that reached quorum before it was recorded
+    #[serde(default)]
+    pub blocks_after_quorum: Option<u64>,
 }
 
 #[cw_serde]
@@ -253,6 +290,15 @@ pub struct WeightedPoll {
     pub poll_id: PollId,
     pub quorum: nonempty::Uint128,
     expires_at: u64,
+    /// optional wall-clock expiry, the poll expires once either the block height or the block time is reached
+    #[serde(default)]
+    expires_at_time: Option<Timestamp>,
+    /// block height at which the poll was started
+    #[serde(default)]
+    started_at: Option<u64>,
+    /// block height at which every item of the poll had reached quorum
+    #[serde(default)]
+    quorum_reached_at: Option<u64>,
     pub poll_size: u64,
     pub tallies: Vec<Tallies>, // running tally of weighted votes
     finished: bool,
@@ -272,6 +318,8 @@ impl WeightedPoll {
                     Participation {
                         weight: participant.weight,
                         voted: false,
+                        voted_at: None,
+                        after_quorum: false,
                     },
                 )
             })
@@ -281,6 +329,9 @@ impl WeightedPoll {
             poll_id,
             quorum: snapshot.quorum,
             expires_at: expiry,
+            expires_at_time: None,
+            started_at: None,
+            quorum_reached_at: None,
             poll_size: poll_size as u64,
             tallies: vec![Tallies::default(); poll_size],
             finished: false,
@@ -288,12 +339,67 @@ impl WeightedPoll {
         }
     }
 
-    pub fn finish(mut self, block_height: u64) -> Result<Self, Error> {
+    pub fn with_time_expiry(mut self, expires_at: Timestamp) -> Self {
+        self.expires_at_time = Some(expires_at);
+        self
+    }
+
+    pub fn expires_at_time(&self) -> Option<Timestamp> {
+        self.expires_at_time
+    }
+
+    pub fn expires_at(&self) -> u64 {
+        self.expires_at
+    }
+
+    /// Postpones the block expiry by the given number of blocks. The wall-clock expiry is removed,
+    /// because it can't be postponed by a number of blocks
+    pub fn extend_expiry(mut self, blocks: u64) -> Self {
+        self.expires_at = self.expires_at.saturating_add(blocks);
+        self.expires_at_time = None;
+        self
+    }
+
+    pub fn with_start_height(mut self, started_at: u64) -> Self {
+        self.started_at = Some(started_at);
+        self
+    }
+
+    pub fn started_at(&self) -> Option<u64> {
+        self.started_at
+    }
+
+    pub fn quorum_reached_at(&self) -> Option<u64> {
+        self.quorum_reached_at
+    }
+
+    /// Returns when each participant that voted cast its vote, in the order of the participants' addresses
+    pub fn vote_latencies(&self) -> Vec<VoteLatency> {
+        self.participation
+            .iter()
+            .filter_map(|(voter, participation)| {
+                participation.voted_at.map(|voted_at| VoteLatency {
+                    voter: voter.to_owned(),
+                    voted_at,
+                    blocks_after_start: self
+                        .started_at
+                        .map(|started_at| voted_at.saturating_sub(started_at)),
+                    after_quorum: participation.after_quorum,
+                    blocks_after_quorum: self
+                        .quorum_reached_at
+                        .filter(|_| participation.after_quorum)
+                        .map(|quorum_reached_at| voted_at.saturating_sub(quorum_reached_at)),
+                })
+            })
+            .collect()
+    }
+
+    pub fn finish(mut self, block: &BlockInfo) -> Result<Self, Error> {
         if self.finished {
             return Err(Error::PollNotInProgress);
         }
 
-        if block_height < self.expires_at {
+        if !self.is_expired(block) {
             return Err(Error::PollNotEnded);
         }
 
@@ -327,7 +433,8 @@ impl WeightedPoll {
                 voting_history.get(address).and_then(|votes| {
                     let voted_consensus = votes.iter().zip(results.iter()).all(|(vote, result)| {
                         result.is_none() || Some(vote) == result.as_ref()
-                        // if there was no consensus, we don't care about the vote
+                        // if there was no consensus, we don't care about the vote,
+                        // so abstaining is only rewarded where no outcome was reached
                     });
 
                     if voted_consensus {
@@ -356,16 +463,22 @@ impl WeightedPoll {
 
     pub fn cast_vote(
         mut self,
-        block_height: u64,
+        block: &BlockInfo,
         sender: &Addr,
         votes: Vec<Vote>,
     ) -> Result<Self, Error> {
+        let quorum: Uint128 = self.quorum.into();
+        let decided = self
+            .tallies
+            .iter()
+            .all(|tallies| tallies.consensus(quorum).is_some());
+
         let participation = self
             .participation
             .get_mut(sender.as_str())
             .ok_or(Error::NotParticipant)?;
 
-        if block_height >= self.expires_at {
+        if self.is_expired(block) {
             return Err(Error::PollExpired);
         }
 
@@ -385,22 +498,41 @@ impl WeightedPoll {
             });
 
         participation.voted = true;
+        participation.voted_at = Some(block.height);
+        participation.after_quorum = decided;
+
+        if !decided
+            && self
+                .tallies
+                .iter()
+                .all(|tallies| tallies.consensus(quorum).is_some())
+        {
+            self.quorum_reached_at = Some(block.height);
+        }
 
         Ok(self)
     }
 
-    pub fn status(&self, current_height: u64) -> PollStatus {
+    pub fn status(&self, block: &BlockInfo) -> PollStatus {
         match self.finished {
             true => PollStatus::Finished,
-            false if current_height >= self.expires_at => PollStatus::Expired,
+            false if self.is_expired(block) => PollStatus::Expired,
             _ => PollStatus::InProgress,
         }
     }
+
+    /// Returns true once the poll can't be voted in anymore, whether it finished or not
+    pub fn is_expired(&self, block: &BlockInfo) -> bool {
+        block.height >= self.expires_at
+            || self
+                .expires_at_time
+                .is_some_and(|expires_at| expires_at.is_reached(block.time.into()))
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use cosmwasm_std::testing::MockApi;
+    use cosmwasm_std::testing::{mock_env, MockApi};
     use cosmwasm_std::{Addr, Uint64};
     use rand::distributions::Alphanumeric;
     use rand::Rng;
@@ -426,12 +558,18 @@ mod tests {
                 .unwrap(),
             &Participation {
                 weight: nonempty::Uint128::try_from(Uint128::from(100u64)).unwrap(),
-                voted: false
+                voted: false,
+                voted_at: None,
+                after_quorum: false,
             }
         );
 
         let poll = poll
-            .cast_vote(1, &MockApi::default().addr_make("addr1"), votes.clone())
+            .cast_vote(
+                &block(1),
+                &MockApi::default().addr_make("addr1"),
+                votes.clone(),
+            )
             .unwrap();
 
         assert_eq!(
@@ -440,11 +578,72 @@ mod tests {
                 .unwrap(),
             &Participation {
                 weight: nonempty::Uint128::try_from(Uint128::from(100u64)).unwrap(),
-                voted: true
+                voted: true,
+                voted_at: Some(1),
+                after_quorum: false,
             }
         );
     }
 
+    #[test]
+    fn votes_after_quorum_should_be_marked_as_such() {
+        let poll = new_poll(10, 1, vec!["addr1", "addr2", "addr3", "addr4"]).with_start_height(2);
+        let votes = vec![Vote::SucceededOnChain];
+
+        let poll = ["addr1", "addr2", "addr3", "addr4"]
+            .into_iter()
+            .zip(3u64..)
+            .fold(poll, |poll, (voter, height)| {
+                poll.cast_vote(
+                    &block(height),
+                    &MockApi::default().addr_make(voter),
+                    votes.clone(),
+                )
+                .unwrap()
+            });
+
+        assert_eq!(poll.quorum_reached_at(), Some(5));
+
+        let latencies: HashMap<String, (u64, Option<u64>, bool, Option<u64>)> = poll
+            .vote_latencies()
+            .into_iter()
+            .map(|latency| {
+                (
+                    latency.voter,
+                    (
+                        latency.voted_at,
+                        latency.blocks_after_start,
+                        latency.after_quorum,
+                        latency.blocks_after_quorum,
+                    ),
+                )
+            })
+            .collect();
+
+        // three of four votes are needed for quorum, so only the last vote came after it
+        assert_eq!(
+            latencies,
+            HashMap::from([
+                (
+                    MockApi::default().addr_make("addr1").to_string(),
+                    (3, Some(1), false, None)
+                ),
+                (
+                    MockApi::default().addr_make("addr2").to_string(),
+                    (4, Some(2), false, None)
+                ),
+                (
+                    MockApi::default().addr_make("addr3").to_string(),
+                    (5, Some(3), false, None)
+                ),
+                (
+                    MockApi::default().addr_make("addr4").to_string(),
+                    (6, Some(4), true, Some(1))
+                ),
+            ])
+        );
+    }
+
     #[test]
     fn voter_not_a_participant() {
         let mut rng = rand::thread_rng();
@@ -462,7 +661,11 @@ mod tests {
             .collect();
 
         assert_eq!(
-            poll.cast_vote(1, &MockApi::default().addr_make(rand_addr.as_str()), votes),
+            poll.cast_vote(
+                &block(1),
+                &MockApi::default().addr_make(rand_addr.as_str()),
+                votes
+            ),
             Err(Error::NotParticipant)
         );
     }
@@ -476,7 +679,7 @@ mod tests {
         );
         let votes = vec![Vote::SucceededOnChain, Vote::SucceededOnChain];
         assert_eq!(
-            poll.cast_vote(2, &MockApi::default().addr_make("addr1"), votes),
+            poll.cast_vote(&block(2), &MockApi::default().addr_make("addr1"), votes),
             Err(Error::PollExpired)
         );
     }
@@ -486,7 +689,7 @@ mod tests {
         let poll = new_poll(2, 2, vec!["addr1", "addr2"]);
         let votes = vec![Vote::SucceededOnChain];
         assert_eq!(
-            poll.cast_vote(1, &MockApi::default().addr_make("addr1"), votes),
+            poll.cast_vote(&block(1), &MockApi::default().addr_make("addr1"), votes),
             Err(Error::InvalidVoteSize)
         );
     }
@@ -497,10 +700,14 @@ mod tests {
         let votes = vec![Vote::SucceededOnChain, Vote::SucceededOnChain];
 
         let poll = poll
-            .cast_vote(1, &MockApi::default().addr_make("addr1"), votes.clone())
+            .cast_vote(
+                &block(1),
+                &MockApi::default().addr_make("addr1"),
+                votes.clone(),
+            )
             .unwrap();
         assert_eq!(
-            poll.cast_vote(1, &MockApi::default().addr_make("addr1"), votes),
+            poll.cast_vote(&block(1), &MockApi::default().addr_make("addr1"), votes),
             Err(Error::AlreadyVoted)
         );
     }
@@ -508,14 +715,14 @@ mod tests {
     #[test]
     fn finish_before_poll_expiry() {
         let poll = new_poll(1, 2, vec!["addr1", "addr2"]);
-        assert_eq!(poll.finish(0), Err(Error::PollNotEnded));
+        assert_eq!(poll.finish(&block(0)), Err(Error::PollNotEnded));
     }
 
     #[test]
     fn finish_after_poll_conclude() {
         let mut poll = new_poll(2, 2, vec!["addr1", "addr2"]);
-        poll = poll.finish(2).unwrap();
-        assert_eq!(poll.finish(3), Err(Error::PollNotInProgress));
+        poll = poll.finish(&block(2)).unwrap();
+        assert_eq!(poll.finish(&block(3)), Err(Error::PollNotInProgress));
     }
 
     #[test]
@@ -528,13 +735,13 @@ mod tests {
         ];
 
         let poll = poll
-            .cast_vote(1, &voters[0], votes.clone())
+            .cast_vote(&block(1), &voters[0], votes.clone())
             .unwrap()
-            .cast_vote(1, &voters[1], votes.clone())
+            .cast_vote(&block(1), &voters[1], votes.clone())
             .unwrap();
 
-        let poll = poll.finish(2).unwrap();
-        assert_eq!(poll.status(2), PollStatus::Finished);
+        let poll = poll.finish(&block(2)).unwrap();
+        assert_eq!(poll.status(&block(2)), PollStatus::Finished);
 
         let result = poll.state(
             voters
@@ -581,14 +788,14 @@ mod tests {
             .collect();
 
         let poll = poll
-            .cast_vote(1, voting_history[0].0, voting_history[0].1.clone())
+            .cast_vote(&block(1), voting_history[0].0, voting_history[0].1.clone())
             .unwrap()
-            .cast_vote(1, voting_history[1].0, voting_history[1].1.clone())
+            .cast_vote(&block(1), voting_history[1].0, voting_history[1].1.clone())
             .unwrap()
-            .cast_vote(1, voting_history[2].0, voting_history[2].1.clone())
+            .cast_vote(&block(1), voting_history[2].0, voting_history[2].1.clone())
             .unwrap();
 
-        let result = poll.finish(2).unwrap().state(
+        let result = poll.finish(&block(2)).unwrap().state(
             voting_history
                 .into_iter()
                 .map(|(voter, votes)| (voter.to_string(), votes))
@@ -611,13 +818,108 @@ mod tests {
         );
     }
 
+    #[test]
+    fn abstain_votes_should_not_count_toward_quorum_or_be_rewarded_where_consensus_was_reached() {
+        let voters = [
+            MockApi::default().addr_make("addr1"),
+            MockApi::default().addr_make("addr2"),
+            MockApi::default().addr_make("addr3"),
+        ];
+        let voting_history = vec![
+            (&voters[0], vec![Vote::Abstain, Vote::Abstain]),
+            (&voters[1], vec![Vote::SucceededOnChain, Vote::Abstain]),
+            (&voters[2], vec![Vote::SucceededOnChain, Vote::NotFound]),
+        ];
+
+        let poll = voting_history.iter().fold(
+            new_poll(2, 2, vec!["addr1", "addr2", "addr3"]),
+            |poll, (voter, votes)| poll.cast_vote(&block(1), voter, votes.clone()).unwrap(),
+        );
+
+        assert!(poll
+            .tallies
+            .iter()
+            .all(|tallies| !tallies.0.contains_key(Vote::Abstain.as_ref())));
+        assert_eq!(
+            poll.results(),
+            PollResults(vec![Some(Vote::SucceededOnChain), None])
+        );
+
+        let result = poll.finish(&block(2)).unwrap().state(
+            voting_history
+                .into_iter()
+                .map(|(voter, votes)| (voter.to_string(), votes))
+                .collect(),
+        );
+
+        let mut consensus_participants = result.consensus_participants;
+        consensus_participants.sort();
+        let mut expected: Vec<_> = voters[1..].iter().map(Addr::to_string).collect();
+        expected.sort();
+        assert_eq!(consensus_participants, expected);
+    }
+
     #[test]
     fn status_should_return_current_status() {
         let mut poll = new_poll(2, 2, vec!["addr1", "addr2"]);
-        assert_eq!(poll.status(1), PollStatus::InProgress);
-        assert_eq!(poll.status(2), PollStatus::Expired);
-        poll = poll.finish(3).unwrap();
-        assert_eq!(poll.status(3), PollStatus::Finished);
+        assert_eq!(poll.status(&block(1)), PollStatus::InProgress);
+        assert_eq!(poll.status(&block(2)), PollStatus::Expired);
+        poll = poll.finish(&block(3)).unwrap();
+        assert_eq!(poll.status(&block(3)), PollStatus::Finished);
+    }
+
+    #[test]
+    fn poll_with_time_expiry_should_expire_at_whichever_comes_first() {
+        let expires_at_time = Timestamp::from(mock_env().block.time)
+            .saturating_add(crate::time::Duration::try_from(60u64).unwrap());
+        let poll = new_poll(10, 2, vec!["addr1", "addr2"]).with_time_expiry(expires_at_time);
+        let votes = vec![Vote::SucceededOnChain, Vote::SucceededOnChain];
+
+        let before_expiry = block(1);
+        let time_expired = BlockInfo {
+            time: expires_at_time.into(),
+            ..block(1)
+        };
+
+        assert_eq!(poll.status(&before_expiry), PollStatus::InProgress);
+        assert_eq!(poll.status(&time_expired), PollStatus::Expired);
+        assert_eq!(poll.status(&block(10)), PollStatus::Expired);
+        assert_eq!(
+            poll.clone()
+                .cast_vote(&time_expired, &MockApi::default().addr_make("addr1"), votes),
+            Err(Error::PollExpired)
+        );
+        assert_eq!(
+            poll.clone().finish(&before_expiry),
+            Err(Error::PollNotEnded)
+        );
+        assert!(poll.finish(&time_expired).is_ok());
+    }
+
+    fn block(height: u64) -> BlockInfo {
+        BlockInfo {
+            height,
+            ..mock_env().block
+        }
+    }
+
+    #[test]
+    fn extended_poll_should_expire_later_and_only_by_block_height() {
+        let expires_at_time = Timestamp::from(mock_env().block.time)
+            .saturating_add(crate::time::Duration::try_from(60u64).unwrap());
+        let poll = new_poll(2, 2, vec!["addr1", "addr2"])
+            .with_time_expiry(expires_at_time)
+            .extend_expiry(3);
+
+        assert_eq!(poll.expires_at(), 5);
+        assert_eq!(poll.expires_at_time(), None);
+
+        let after_time_expiry = BlockInfo {
+            time: expires_at_time.into(),
+            ..block(4)
+        };
+        assert_eq!(poll.status(&after_time_expiry), PollStatus::InProgress);
+        assert_eq!(poll.status(&block(5)), PollStatus::Expired);
     }
 
     fn new_poll(expires_at: u64, poll_size: usize, participants: Vec<&str>) -> WeightedPoll {
@@ -73,6 +73,10 @@ where
         }
     }
 
+    pub fn querier(&self) -> QuerierWrapper<'a> {
+        self.inner.querier
+    }
+
     pub fn execute(&self, msg: &M) -> CosmosMsg<T> {
         self.inner.execute(WasmMsg::Execute {
             contract_addr: self.address.to_string(),
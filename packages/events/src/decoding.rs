@@ -0,0 +1,368 @@
+use std::ops::Range;
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use cosmrs::AccountId;
+use error_stack::{Result, ResultExt};
+use serde::de::value::BorrowedStrDeserializer;
+use serde::Deserialize;
+use tendermint::abci;
+
+use crate::errors::DecodingError;
+use crate::event::KEY_CONTRACT_ADDRESS;
+use crate::{Error, Event};
+
+/// Decodes all ABCI events of a block into owned events, see [EventBatch]
+pub fn decode_batch(events: impl IntoIterator<Item = abci::Event>) -> Result<Vec<Event>, Error> {
+    Ok(EventBatch::decode(events)?
+        .events()
+        .map(|event| event.to_event())
+        .collect())
+}
+
+/// The decoded ABCI events of a block. The base64 encoded attributes of all events are decoded into a single buffer
+/// that is shared by the whole batch, so decoding allocates per block instead of per attribute.
+/// Events are read through borrowed views, and attribute values are only parsed as JSON when they are accessed
+#[derive(Debug, Default)]
+pub struct EventBatch {
+    buffer: String,
+    attributes: Vec<RawAttribute>,
+    events: Vec<RawEvent>,
+}
+
+#[derive(Debug)]
+struct RawAttribute {
+    key: Range<usize>,
+    value: Range<usize>,
+}
+
+#[derive(Debug)]
+struct RawEvent {
+    event_type: String,
+    attributes: Range<usize>,
+}
+
+impl EventBatch {
+    /// Fails if any attribute is not valid base64 or doesn't decode to valid UTF-8
+    pub fn decode(events: impl IntoIterator<Item = abci::Event>) -> Result<Self, Error> {
+        let mut batch = EventBatch::default();
+        let mut scratch = Vec::new();
+
+        for abci::Event {
+            kind: event_type,
+            attributes,
+        } in events
+        {
+            let first_attribute = batch.attributes.len();
+            for attribute in &attributes {
+                let key = batch
+                    .push_decoded(&attribute.key, &mut scratch)
+                    .change_context(Error::DecodingAttributesFailed)?;
+                let value = batch
+                    .push_decoded(&attribute.value, &mut scratch)
+                    .change_context(Error::DecodingAttributesFailed)?;
+
+                batch.attributes.push(RawAttribute { key, value });
+            }
+
+            batch.events.push(RawEvent {
+                event_type,
+                attributes: first_attribute..batch.attributes.len(),
+            });
+        }
+
+        Ok(batch)
+    }
+
+    pub fn events(&self) -> impl ExactSizeIterator<Item = EventView<'_>> {
+        self.events
+            .iter()
+            .map(|event| EventView { batch: self, event })
+    }
+
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Appends the decoded string to the buffer and returns its range. The scratch space is reused for every attribute,
+    /// so the buffer only ever holds valid UTF-8
+    fn push_decoded(
+        &mut self,
+        base64_str: &str,
+        scratch: &mut Vec<u8>,
+    ) -> std::result::Result<Range<usize>, DecodingError> {
+        scratch.clear();
+        STANDARD.decode_vec(base64_str, scratch)?;
+
+        let start = self.buffer.len();
+        self.buffer.push_str(std::str::from_utf8(scratch)?);
+
+        Ok(start..self.buffer.len())
+    }
+
+    fn str(&self, range: &Range<usize>) -> &str {
+        self.buffer
+            .get(range.clone())
+            .expect("attribute ranges must lie within the buffer")
+    }
+}
+
+/// An ABCI event of an [EventBatch], borrowed from the batch
+#[derive(Clone, Copy)]
+pub struct EventView<'a> {
+    batch: &'a EventBatch,
+    event: &'a RawEvent,
+}
+
+impl<'a> EventView<'a> {
+    pub fn event_type(&self) -> &'a str {
+        &self.event.event_type
+    }
+
+    pub fn attributes(&self) -> impl DoubleEndedIterator<Item = AttributeView<'a>> {
+        let batch = self.batch;
+
+        batch
+            .attributes
+            .get(self.event.attributes.clone())
+            .expect("event attributes must lie within the batch")
+            .iter()
+            .map(move |attribute| AttributeView {
+                key: batch.str(&attribute.key),
+                value: batch.str(&attribute.value),
+            })
+    }
+
+    /// Returns the attribute with the given key. If the key is duplicated, the last attribute wins, like in [Event::Abci]
+    pub fn attribute(&self, key: &str) -> Option<AttributeView<'a>> {
+        self.attributes()
+            .rev()
+            .find(|attribute| attribute.key == key)
+    }
+
+    pub fn contract_address(&self) -> Option<AccountId> {
+        self.attribute(KEY_CONTRACT_ADDRESS)
+            .and_then(|address| serde_json::from_value(address.value()).ok())
+    }
+
+    /// Parses all attribute values to convert the view into an owned event
+    pub fn to_event(&self) -> Event {
+        Event::Abci {
+            event_type: self.event_type().to_string(),
+            attributes: self
+                .attributes()
+                .map(|attribute| (attribute.key.to_string(), attribute.value()))
+                .collect(),
+        }
+    }
+}
+
+/// A decoded attribute of an [EventView], borrowed from the batch
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AttributeView<'a> {
+    key: &'a str,
+    value: &'a str,
+}
+
+impl<'a> AttributeView<'a> {
+    pub fn key(&self) -> &'a str {
+        self.key
+    }
+
+    /// Returns the decoded value without parsing it
+    pub fn raw_value(&self) -> &'a str {
+        self.value
+    }
+
+    /// Parses the value as JSON, falling back to a JSON string if it isn't valid JSON
+    pub fn value(&self) -> serde_json::Value {
+        parse_value(self.value)
+    }
+
+    /// Deserializes the value without going through [serde_json::Value], borrowing strings from the batch where possible.
+    /// Like [Self::value], values that aren't valid JSON are deserialized as strings
+    pub fn deserialize<T: Deserialize<'a>>(&self) -> std::result::Result<T, serde_json::Error> {
+        if might_be_json(self.value) {
+            if let Ok(deserialized) = serde_json::from_str(self.value) {
+                return Ok(deserialized);
+            }
+        }
+
+        T::deserialize(BorrowedStrDeserializer::new(self.value))
+    }
+}
+
+/// Parses the value as JSON, falling back to a JSON string if it isn't valid JSON.
+/// Most attribute values are plain strings like addresses or hashes, so parsing is skipped for values that can't be JSON
+fn parse_value(value: &str) -> serde_json::Value {
+    if might_be_json(value) {
+        if let Ok(parsed) = serde_json::from_str(value) {
+            return parsed;
+        }
+    }
+
+    value.into()
+}
+
+fn might_be_json(value: &str) -> bool {
+    value
+        .trim_start_matches([' ', '\t', '\n', '\r'])
+        .bytes()
+        .next()
+        .is_some_and(|first| {
+            matches!(
+                first,
+                b'{' | b'[' | b'"' | b'-' | b'0'..=b'9' | b't' | b'f' | b'n'
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn attribute(key: &str, value: &str) -> abci::EventAttribute {
+        abci::EventAttribute {
+            key: STANDARD.encode(key),
+            value: STANDARD.encode(value),
+            index: false,
+        }
+    }
+
+    #[test]
+    fn decode_batch_should_decode_all_events() {
+        let events = vec![
+            abci::Event::new(
+                "first",
+                vec![
+                    attribute("string", "axelar1zh9wrak6ke4n6fclj5e8yk397czv430ygs5jz7"),
+                    attribute("hash", "0x1234"),
+                    attribute("number", "42"),
+                    attribute("padded_number", " 42"),
+                    attribute("quoted", "\"quoted\""),
+                    attribute("object", "{\"a\":[1,true,null]}"),
+                    attribute("not_json", "{not json"),
+                    attribute("word", "native"),
+                    attribute("empty", ""),
+                ],
+            ),
+            abci::Event::new("second", vec![attribute("key", "value")]),
+            abci::Event::new("empty", Vec::<abci::EventAttribute>::new()),
+        ];
+
+        let expected = vec![
+            Event::Abci {
+                event_type: "first".to_string(),
+                attributes: json!({
+                    "string": "axelar1zh9wrak6ke4n6fclj5e8yk397czv430ygs5jz7",
+                    "hash": "0x1234",
+                    "number": 42,
+                    "padded_number": 42,
+                    "quoted": "quoted",
+                    "object": {"a": [1, true, null]},
+                    "not_json": "{not json",
+                    "word": "native",
+                    "empty": "",
+                })
+                .as_object()
+                .unwrap()
+                .clone(),
+            },
+            Event::Abci {
+                event_type: "second".to_string(),
+                attributes: json!({"key": "value"}).as_object().unwrap().clone(),
+            },
+            Event::Abci {
+                event_type: "empty".to_string(),
+                attributes: serde_json::Map::new(),
+            },
+        ];
+
+        assert_eq!(decode_batch(events).unwrap(), expected);
+    }
+
+    #[test]
+    fn decode_batch_should_fail_on_invalid_attributes() {
+        let invalid_base64 = abci::EventAttribute {
+            key: "not base64!".to_string(),
+            value: STANDARD.encode("value"),
+            index: false,
+        };
+        let invalid_utf8 = abci::EventAttribute {
+            key: STANDARD.encode("key"),
+            value: STANDARD.encode([0xff, 0xfe]),
+            index: false,
+        };
+
+        for invalid in [invalid_base64, invalid_utf8] {
+            let events = vec![
+                abci::Event::new("valid", vec![attribute("key", "value")]),
+                abci::Event::new("invalid", vec![invalid]),
+            ];
+
+            assert!(matches!(
+                decode_batch(events).unwrap_err().current_context(),
+                Error::DecodingAttributesFailed
+            ));
+        }
+    }
+
+    #[test]
+    fn event_views_should_borrow_attributes_from_the_batch() {
+        let contract = "axelar1zh9wrak6ke4n6fclj5e8yk397czv430ygs5jz7";
+        let batch = EventBatch::decode(vec![
+            abci::Event::new(
+                "wasm-first",
+                vec![
+                    attribute("_contract_address", contract),
+                    attribute("payload", "{\"amount\":\"100\",\"ids\":[1,2]}"),
+                    attribute("payload", "{\"amount\":\"200\",\"ids\":[]}"),
+                ],
+            ),
+            abci::Event::new("transfer", vec![attribute("amount", "100uaxl")]),
+        ])
+        .unwrap();
+
+        assert_eq!(batch.len(), 2);
+        let events: Vec<_> = batch.events().collect();
+
+        assert_eq!(events[0].event_type(), "wasm-first");
+        assert_eq!(
+            events[0].contract_address(),
+            Some(contract.parse().unwrap())
+        );
+        assert_eq!(events[0].attributes().count(), 3);
+        let address: &str = events[0]
+            .attribute("_contract_address")
+            .unwrap()
+            .deserialize()
+            .unwrap();
+        assert_eq!(address, contract);
+
+        #[derive(Deserialize)]
+        struct Payload<'a> {
+            amount: &'a str,
+            ids: Vec<u64>,
+        }
+
+        let payload = events[0].attribute("payload").unwrap();
+        assert_eq!(payload.raw_value(), "{\"amount\":\"200\",\"ids\":[]}");
+        let payload: Payload = payload.deserialize().unwrap();
+        assert_eq!(payload.amount, "200");
+        assert!(payload.ids.is_empty());
+
+        assert_eq!(events[1].event_type(), "transfer");
+        assert_eq!(events[1].contract_address(), None);
+        assert_eq!(
+            events[1].attribute("amount").unwrap().value(),
+            json!("100uaxl")
+        );
+        assert_eq!(events[1].attribute("missing"), None);
+    }
+}
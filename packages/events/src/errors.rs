@@ -19,5 +19,5 @@ pub enum DecodingError {
     #[error(transparent)]
     Base64(#[from] base64::DecodeError),
     #[error(transparent)]
-    UTF8(#[from] std::string::FromUtf8Error),
+    UTF8(#[from] std::str::Utf8Error),
 }
@@ -1,19 +1,15 @@
 use std::collections::BTreeMap;
 use std::fmt::Display;
 
-use axelar_wasm_std::FnExt;
-use base64::engine::general_purpose::STANDARD;
-use base64::Engine;
 use cosmrs::AccountId;
 use error_stack::{Report, Result, ResultExt};
 use serde::Serialize;
-use tendermint::abci::EventAttribute;
 use tendermint::{abci, block};
 
-use crate::errors::DecodingError;
+use crate::decoding::EventBatch;
 use crate::Error;
 
-const KEY_CONTRACT_ADDRESS: &str = "_contract_address";
+pub(crate) const KEY_CONTRACT_ADDRESS: &str = "_contract_address";
 
 pub struct AbciEventTypeFilter {
     pub event_type: String,
@@ -83,46 +79,16 @@ impl Event {
 impl TryFrom<abci::Event> for Event {
     type Error = Report<Error>;
 
+    /// Use [EventBatch] to decode all events of a block
     fn try_from(event: abci::Event) -> Result<Self, Error> {
-        let abci::Event {
-            kind: event_type,
-            attributes,
-        } = event;
-
-        let attributes = attributes
-            .iter()
-            .map(try_into_kv_pair)
-            .collect::<Result<_, _>>()?;
-
-        Ok(Self::Abci {
-            event_type,
-            attributes,
-        })
+        Ok(EventBatch::decode([event])?
+            .events()
+            .next()
+            .expect("batch must contain the decoded event")
+            .to_event())
     }
 }
 
-fn try_into_kv_pair(attr: &EventAttribute) -> Result<(String, serde_json::Value), Error> {
-    decode_event_attribute(attr)
-        .change_context(Error::DecodingAttributesFailed)
-        .map(|(key, value)| {
-            (
-                key,
-                serde_json::from_str(&value).unwrap_or_else(|_| value.into()),
-            )
-        })
-}
-
-fn decode_event_attribute(attribute: &EventAttribute) -> Result<(String, String), DecodingError> {
-    Ok((
-        base64_to_utf8(&attribute.key)?,
-        base64_to_utf8(&attribute.value)?,
-    ))
-}
-
-fn base64_to_utf8(base64_str: &str) -> std::result::Result<String, DecodingError> {
-    Ok(STANDARD.decode(base64_str)?.then(String::from_utf8)?)
-}
-
 impl From<Event> for ampd_proto::subscribe_response::Event {
     fn from(event: Event) -> Self {
         let contract = event.contract_address();
@@ -1,6 +1,8 @@
+pub use crate::decoding::{decode_batch, AttributeView, EventBatch, EventView};
 pub use crate::errors::Error;
 pub use crate::event::*;
 
+mod decoding;
 mod event;
 
 mod errors;
@@ -1,9 +1,13 @@
 use axelar_wasm_std::vec::VecExt;
-use cosmwasm_std::CosmosMsg;
+use cosmwasm_std::{Addr, CosmosMsg};
 use error_stack::ResultExt;
-use router_api::{CrossChainId, Message};
+use router_api::{ChainName, CrossChainId, Message};
+use voting_verifier_api::msg::ObservedBlock;
 
-use crate::msg::{ExecuteMsg, QueryMsg};
+use crate::msg::{
+    DuplicateSubmissions, ExecuteMsg, ProofBatch, PrunableMessage, QueryMsg, RelayerAllowlist,
+    RoutedMessage,
+};
 
 type Result<T> = error_stack::Result<T, Error>;
 
@@ -11,12 +15,54 @@ type Result<T> = error_stack::Result<T, Error>;
 pub enum Error {
     #[error("failed to query gateway for outgoing messages. message ids: {0:?}")]
     OutgoingMessages(Vec<CrossChainId>),
+    #[error("failed to query gateway for proof batches. start_height: {start_height:?}, limit: {limit:?}")]
+    ProofBatches {
+        start_height: Option<u64>,
+        limit: Option<u32>,
+    },
+    #[error("failed to query gateway for the message validator of source chain {0}")]
+    MessageValidator(ChainName),
+    #[error("failed to query gateway for routed messages. start_after: {start_after:?}, limit: {limit:?}")]
+    RoutedMessages {
+        start_after: Option<CrossChainId>,
+        limit: Option<u32>,
+    },
+    #[error("failed to query gateway for the relayer allowlist. start_after: {start_after:?}, limit: {limit:?}")]
+    RelayerAllowlist {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    #[error("failed to query gateway for duplicate submissions. start_after: {start_after:?}, limit: {limit:?}")]
+    DuplicateSubmissions {
+        start_after: Option<CrossChainId>,
+        limit: Option<u32>,
+    },
+    #[error("failed to query gateway for prunable messages. limit: {0:?}")]
+    PrunableMessages(Option<u32>),
 }
 
 impl From<QueryMsg> for Error {
     fn from(value: QueryMsg) -> Self {
         match value {
             QueryMsg::OutgoingMessages(message_ids) => Error::OutgoingMessages(message_ids),
+            QueryMsg::ProofBatches {
+                start_height,
+                limit,
+            } => Error::ProofBatches {
+                start_height,
+                limit,
+            },
+            QueryMsg::MessageValidator { source_chain } => Error::MessageValidator(source_chain),
+            QueryMsg::RoutedMessages { start_after, limit } => {
+                Error::RoutedMessages { start_after, limit }
+            }
+            QueryMsg::RelayerAllowlist { start_after, limit } => {
+                Error::RelayerAllowlist { start_after, limit }
+            }
+            QueryMsg::DuplicateSubmissions { start_after, limit } => {
+                Error::DuplicateSubmissions { start_after, limit }
+            }
+            QueryMsg::PrunableMessages { limit } => Error::PrunableMessages(limit),
         }
     }
 }
@@ -37,12 +83,74 @@ impl Client<'_> {
         self.client.query(&msg).change_context_lazy(|| msg.into())
     }
 
+    pub fn proof_batches(
+        &self,
+        start_height: Option<u64>,
+        limit: Option<u32>,
+    ) -> Result<Vec<ProofBatch>> {
+        let msg = QueryMsg::ProofBatches {
+            start_height,
+            limit,
+        };
+        self.client.query(&msg).change_context_lazy(|| msg.into())
+    }
+
+    pub fn message_validator(&self, source_chain: ChainName) -> Result<Option<Addr>> {
+        let msg = QueryMsg::MessageValidator { source_chain };
+        self.client.query(&msg).change_context_lazy(|| msg.into())
+    }
+
+    pub fn routed_messages(
+        &self,
+        start_after: Option<CrossChainId>,
+        limit: Option<u32>,
+    ) -> Result<Vec<RoutedMessage>> {
+        let msg = QueryMsg::RoutedMessages { start_after, limit };
+        self.client.query(&msg).change_context_lazy(|| msg.into())
+    }
+
+    pub fn relayer_allowlist(
+        &self,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> Result<RelayerAllowlist> {
+        let msg = QueryMsg::RelayerAllowlist { start_after, limit };
+        self.client.query(&msg).change_context_lazy(|| msg.into())
+    }
+
+    pub fn duplicate_submissions(
+        &self,
+        start_after: Option<CrossChainId>,
+        limit: Option<u32>,
+    ) -> Result<Vec<DuplicateSubmissions>> {
+        let msg = QueryMsg::DuplicateSubmissions { start_after, limit };
+        self.client.query(&msg).change_context_lazy(|| msg.into())
+    }
+
+    pub fn prunable_messages(&self, limit: Option<u32>) -> Result<Vec<PrunableMessage>> {
+        let msg = QueryMsg::PrunableMessages { limit };
+        self.client.query(&msg).change_context_lazy(|| msg.into())
+    }
+
     pub fn verify_messages(&self, messages: Vec<Message>) -> Option<CosmosMsg> {
         messages
             .to_none_if_empty()
             .map(|messages| self.client.execute(&ExecuteMsg::VerifyMessages(messages)))
     }
 
+    pub fn verify_messages_observed_at(
+        &self,
+        messages: Vec<Message>,
+        observed_at: ObservedBlock,
+    ) -> Option<CosmosMsg> {
+        messages.to_none_if_empty().map(|messages| {
+            self.client.execute(&ExecuteMsg::VerifyMessagesObservedAt {
+                messages,
+                observed_at,
+            })
+        })
+    }
+
     pub fn route_messages(&self, messages: Vec<Message>) -> Option<CosmosMsg> {
         messages
             .to_none_if_empty()
@@ -57,7 +165,10 @@ mod tests {
     use router_api::{CrossChainId, Message};
 
     use crate::client::Client;
-    use crate::msg::QueryMsg;
+    use crate::msg::{
+        DuplicateSubmissions, ProofBatch, PrunableMessage, QueryMsg, Relayer, RelayerAllowlist,
+        RoutedMessage,
+    };
 
     #[test]
     fn query_outgoing_messages_should_return_error_when_query_errors() {
@@ -93,6 +204,138 @@ mod tests {
         goldie::assert_json!(res.unwrap());
     }
 
+    #[test]
+    fn query_proof_batches_should_return_error_when_query_errors() {
+        let (querier, addr) = setup_queries_to_fail();
+
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+        let res = client.proof_batches(Some(100), Some(10));
+        assert!(res.is_err());
+        goldie::assert!(res.unwrap_err().to_string());
+    }
+
+    #[test]
+    fn query_proof_batches_should_return_proof_batches() {
+        let (querier, addr) = setup_queries_to_succeed();
+
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+        let res = client.proof_batches(Some(100), Some(10));
+        assert!(res.is_ok());
+        goldie::assert_json!(res.unwrap());
+    }
+
+    #[test]
+    fn query_message_validator_should_return_error_when_query_errors() {
+        let (querier, addr) = setup_queries_to_fail();
+
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+        let res = client.message_validator("ethereum".parse().unwrap());
+        assert!(res.is_err());
+        goldie::assert!(res.unwrap_err().to_string());
+    }
+
+    #[test]
+    fn query_message_validator_should_return_message_validator() {
+        let (querier, addr) = setup_queries_to_succeed();
+
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+        let res = client.message_validator("ethereum".parse().unwrap());
+        assert!(res.is_ok());
+        goldie::assert_json!(res.unwrap());
+    }
+
+    #[test]
+    fn query_routed_messages_should_return_error_when_query_errors() {
+        let (querier, addr) = setup_queries_to_fail();
+
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+        let res = client.routed_messages(None, Some(10));
+        assert!(res.is_err());
+        goldie::assert!(res.unwrap_err().to_string());
+    }
+
+    #[test]
+    fn query_routed_messages_should_return_routed_messages() {
+        let (querier, addr) = setup_queries_to_succeed();
+
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+        let res = client.routed_messages(None, Some(10));
+        assert!(res.is_ok());
+        goldie::assert_json!(res.unwrap());
+    }
+
+    #[test]
+    fn query_relayer_allowlist_should_return_error_when_query_errors() {
+        let (querier, addr) = setup_queries_to_fail();
+
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+        let res = client.relayer_allowlist(None, Some(10));
+        assert!(res.is_err());
+        goldie::assert!(res.unwrap_err().to_string());
+    }
+
+    #[test]
+    fn query_relayer_allowlist_should_return_relayer_allowlist() {
+        let (querier, addr) = setup_queries_to_succeed();
+
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+        let res = client.relayer_allowlist(None, Some(10));
+        assert!(res.is_ok());
+        goldie::assert_json!(res.unwrap());
+    }
+
+    #[test]
+    fn query_duplicate_submissions_should_return_error_when_query_errors() {
+        let (querier, addr) = setup_queries_to_fail();
+
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+        let res = client.duplicate_submissions(None, Some(10));
+        assert!(res.is_err());
+        goldie::assert!(res.unwrap_err().to_string());
+    }
+
+    #[test]
+    fn query_duplicate_submissions_should_return_duplicate_submissions() {
+        let (querier, addr) = setup_queries_to_succeed();
+
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+        let res = client.duplicate_submissions(None, Some(10));
+        assert!(res.is_ok());
+        goldie::assert_json!(res.unwrap());
+    }
+
+    #[test]
+    fn query_prunable_messages_should_return_error_when_query_errors() {
+        let (querier, addr) = setup_queries_to_fail();
+
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+        let res = client.prunable_messages(Some(10));
+        assert!(res.is_err());
+        goldie::assert!(res.unwrap_err().to_string());
+    }
+
+    #[test]
+    fn query_prunable_messages_should_return_prunable_messages() {
+        let (querier, addr) = setup_queries_to_succeed();
+
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+        let res = client.prunable_messages(Some(10));
+        assert!(res.is_ok());
+        goldie::assert_json!(res.unwrap());
+    }
+
     fn setup_queries_to_fail() -> (MockQuerier, Addr) {
         let addr = "gateway";
 
@@ -134,6 +377,81 @@ mod tests {
                     )
                     .into())
                     .into(),
+                    QueryMsg::ProofBatches { start_height, .. } => Ok(to_json_binary(&vec![
+                        ProofBatch {
+                            destination_chain: "ethereum".parse().unwrap(),
+                            window_start: start_height.unwrap_or_default(),
+                            index: 0,
+                            message_ids: vec![CrossChainId {
+                                source_chain: "avalanche".parse().unwrap(),
+                                message_id: "0x13548ac28fe95805ad2b8b824472d08e3b45cbc023a5a45a912f11ea98f81e97-0"
+                                    .parse()
+                                    .unwrap(),
+                            }],
+                        },
+                    ])
+                    .into())
+                    .into(),
+                    QueryMsg::MessageValidator { .. } => Ok(to_json_binary(&Some(
+                        MockApi::default().addr_make("validator"),
+                    ))
+                    .into())
+                    .into(),
+                    QueryMsg::RoutedMessages { .. } => Ok(to_json_binary(&vec![RoutedMessage {
+                        message: Message {
+                            cc_id: CrossChainId {
+                                source_chain: "avalanche".parse().unwrap(),
+                                message_id: "0x13548ac28fe95805ad2b8b824472d08e3b45cbc023a5a45a912f11ea98f81e97-0"
+                                    .parse()
+                                    .unwrap(),
+                            },
+                            source_address: "foobar".parse().unwrap(),
+                            destination_chain: "ethereum".parse().unwrap(),
+                            destination_address: "foobar".parse().unwrap(),
+                            payload_hash: [0u8; 32],
+                        },
+                        routed_at: 100,
+                    }])
+                    .into())
+                    .into(),
+                    QueryMsg::RelayerAllowlist { .. } => Ok(to_json_binary(&RelayerAllowlist {
+                        enabled: true,
+                        relayers: vec![Relayer {
+                            address: MockApi::default().addr_make("relayer"),
+                            submissions: 2,
+                            messages: 5,
+                        }],
+                    })
+                    .into())
+                    .into(),
+                    QueryMsg::DuplicateSubmissions { .. } => {
+                        Ok(to_json_binary(&vec![DuplicateSubmissions {
+                            cc_id: CrossChainId {
+                                source_chain: "avalanche".parse().unwrap(),
+                                message_id: "0x13548ac28fe95805ad2b8b824472d08e3b45cbc023a5a45a912f11ea98f81e97-0"
+                                    .parse()
+                                    .unwrap(),
+                            },
+                            count: 3,
+                        }])
+                        .into())
+                        .into()
+                    }
+                    QueryMsg::PrunableMessages { .. } => {
+                        Ok(to_json_binary(&vec![PrunableMessage {
+                            cc_id: CrossChainId {
+                                source_chain: "avalanche".parse().unwrap(),
+                                message_id: "0x13548ac28fe95805ad2b8b824472d08e3b45cbc023a5a45a912f11ea98f81e97-0"
+                                    .parse()
+                                    .unwrap(),
+                            },
+                            routed_at: Some(100),
+                            expires_at: Some(150),
+                            executed_at: None,
+                        }])
+                        .into())
+                        .into()
+                    }
                 }
             }
             _ => panic!("unexpected query: {:?}", msg),
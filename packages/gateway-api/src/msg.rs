@@ -1,6 +1,9 @@
+use axelar_wasm_std::nonempty;
 use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Addr, HexBinary};
 use msgs_derive::EnsurePermissions;
-use router_api::{CrossChainId, Message};
+use router_api::{ChainName, CrossChainId, Message};
+use voting_verifier_api::msg::ObservedBlock;
 
 #[cw_serde]
 #[derive(EnsurePermissions)]
@@ -10,16 +13,238 @@ pub enum ExecuteMsg {
     #[permission(Any)]
     VerifyMessages(Vec<Message>),
 
+    /// Like VerifyMessages, but also passes on the source chain block at which the messages were observed,
+    /// so verifiers check the messages at that block instead of whichever block is the latest for them.
+    #[permission(Any)]
+    VerifyMessagesObservedAt {
+        messages: Vec<Message>,
+        observed_at: ObservedBlock,
+    },
+
     /// Forward the given messages to the next step of the routing layer. If these messages are coming in from an external chain,
     /// they have to be verified first.
     #[permission(Any)]
     RouteMessages(Vec<Message>),
+
+    /// Forward the given incoming messages to the next step of the routing layer, like RouteMessages.
+    /// Each payload is checked against the payload hash of its message first, and the whole batch is rejected on any mismatch.
+    /// Only available if the gateway is configured with a payload hash algorithm.
+    #[permission(Any)]
+    RouteMessagesWithPayload(Vec<MessageWithPayload>),
+
+    /// Forward the given incoming messages to the router, like RouteMessages, but let them expire if they aren't executed
+    /// within `ttl` blocks after the router first received them. The destination gateway refuses expired messages.
+    #[permission(Any)]
+    RouteMessagesWithTtl {
+        messages: Vec<Message>,
+        ttl: nonempty::Uint64,
+    },
+
+    /// Registers the contract that validates incoming messages from the given source chain against chain-specific rules,
+    /// replacing any previously registered validator. Can only be called by governance.
+    #[permission(Governance)]
+    RegisterMessageValidator {
+        source_chain: ChainName,
+        validator: String,
+    },
+
+    /// Stops validating incoming messages from the given source chain. Can only be called by governance.
+    #[permission(Governance)]
+    DeregisterMessageValidator { source_chain: ChainName },
+
+    /// Refuses the given outgoing messages, because they were recalled after they had been routed to this gateway.
+    /// Recalled messages are left out when retrieving messages to construct proofs, and are ignored if they are routed again.
+    /// Can only be called by the router.
+    #[permission(Specific(router))]
+    RecallMessages(Vec<CrossChainId>),
+
+    /// Like RouteMessages for outgoing messages, but each message carries the block height at which it expires.
+    /// Expired messages can no longer be retrieved to construct proofs. Can only be called by the router.
+    #[permission(Specific(router))]
+    RouteMessagesWithExpiry(Vec<MessageWithExpiry>),
+
+    /// Restricts VerifyMessages, VerifyMessagesObservedAt, RouteMessagesWithPayload, RouteMessagesWithTtl and RouteMessages calls for incoming messages
+    /// to the relayers on the allowlist. Calls of other senders are rejected. Can only be called by governance.
+    #[permission(Governance)]
+    EnableRelayerAllowlist,
+
+    /// Allows anyone to submit incoming messages again. The allowlist is kept. Can only be called by governance.
+    #[permission(Governance)]
+    DisableRelayerAllowlist,
+
+    /// Adds the given relayers to the allowlist. Relayers that are already allowlisted keep their submission counters.
+    /// Can only be called by governance.
+    #[permission(Governance)]
+    AllowRelayers(Vec<String>),
+
+    /// Removes the given relayers and their submission counters from the allowlist. Can only be called by governance.
+    #[permission(Governance)]
+    DisallowRelayers(Vec<String>),
+
+    /// Records that the given outgoing messages were executed on the destination chain, so they can be pruned once the
+    /// retention window has passed. Unknown, recalled and already recorded messages are ignored.
+    /// Newly recorded executions are forwarded to the router.
+    /// Can only be called by allowlisted relayers, whether or not the allowlist is enforced for submissions.
+    #[permission(Any)]
+    RecordExecutions(Vec<CrossChainId>),
+
+    /// Deletes up to `limit` outgoing messages that are no longer needed to construct proofs, expired messages first,
+    /// followed by the messages whose execution was recorded more than the retention window ago, oldest first.
+    /// Proof batches with a window that started more than the retention window ago are deleted as well, up to `limit`,
+    /// and so are up to `limit` routing heights of messages routed more than 1,000,000 blocks ago.
+    /// The limit is capped at 100. Only the hash of a pruned message is kept, so routing it again has no effect.
+    /// The PrunableMessages query previews the messages a call with the same limit would delete.
+    /// Can only be called by allowlisted relayers, whether or not the allowlist is enforced for submissions.
+    #[permission(Any)]
+    PruneMessages { limit: u32 },
+
+    /// Sets the number of blocks over which outgoing messages are grouped into proof batches. Messages routed after the
+    /// update are batched according to the new window, existing batches are kept. If None, no new batches are created.
+    /// Can only be called by governance.
+    #[permission(Governance)]
+    UpdateBatchingWindow {
+        batching_window: Option<nonempty::Uint64>,
+    },
+}
+
+#[cw_serde]
+pub struct MessageWithPayload {
+    pub message: Message,
+    pub payload: HexBinary,
+}
+
+#[cw_serde]
+pub struct MessageWithExpiry {
+    pub message: Message,
+    /// Block height from which on the message can no longer be approved
+    pub expires_at: u64,
 }
 
 #[cw_serde]
 #[derive(QueryResponses)]
 pub enum QueryMsg {
-    // messages that can be relayed to the chain corresponding to this gateway
+    // messages that can be relayed to the chain corresponding to this gateway, expired and recalled messages are omitted
     #[returns(Vec<Message>)]
     OutgoingMessages(Vec<CrossChainId>),
+
+    /// Outgoing messages grouped by destination chain and by the batching window in which they were routed to this gateway,
+    /// starting with the oldest window that begins at or after `start_height`. Provers can use these batches to decide which
+    /// messages to prove together. A batch holds at most 100 messages, further messages of the same window and destination
+    /// chain go into the next batch. Always empty if the gateway never had a batching window configured.
+    #[returns(Vec<ProofBatch>)]
+    ProofBatches {
+        start_height: Option<u64>,
+        limit: Option<u32>,
+    },
+
+    /// Returns the contract that validates incoming messages from the given source chain, if any
+    #[returns(Option<Addr>)]
+    MessageValidator { source_chain: ChainName },
+
+    /// Outgoing messages ordered by ID, together with the block height at which they were first routed to this gateway.
+    /// The list is paginated by:
+    /// - start_after: the message ID after which the page starts
+    /// - limit: maximum number of messages returned, default is 100
+    ///
+    /// Recalled messages and messages that were routed before routing heights were tracked are not included,
+    /// and neither are messages whose routing height was pruned after 1,000,000 blocks.
+    #[returns(Vec<RoutedMessage>)]
+    RoutedMessages {
+        start_after: Option<CrossChainId>,
+        limit: Option<u32>,
+    },
+
+    /// Whether the relayer allowlist is enforced, and the allowlisted relayers ordered by address, together with the number
+    /// of submissions they made. The list of relayers is paginated by:
+    /// - start_after: the relayer address after which the page starts
+    /// - limit: maximum number of relayers returned, default is 100
+    #[returns(RelayerAllowlist)]
+    RelayerAllowlist {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
+    /// Incoming messages that were submitted with VerifyMessages or VerifyMessagesObservedAt during the current summary
+    /// period of 1000 blocks while they were already being verified or their verification was final, ordered by ID,
+    /// together with the number of such duplicate submissions. Counts of past periods are deleted by PruneMessages.
+    /// The list is paginated by:
+    /// - start_after: the message ID after which the page starts
+    /// - limit: maximum number of messages returned, defaults to and is capped at 100
+    #[returns(Vec<DuplicateSubmissions>)]
+    DuplicateSubmissions {
+        start_after: Option<CrossChainId>,
+        limit: Option<u32>,
+    },
+
+    /// Outgoing messages that a PruneMessages call with the same limit would delete at the current block height,
+    /// in the order they would be deleted. The limit defaults to and is capped at 100.
+    #[returns(Vec<PrunableMessage>)]
+    PrunableMessages { limit: Option<u32> },
+}
+
+#[cw_serde]
+pub struct ProofBatch {
+    pub destination_chain: ChainName,
+    /// Block height at which the batching window of this batch begins
+    pub window_start: u64,
+    /// Position of this batch among the batches of the same window and destination chain, starting at 0
+    pub index: u32,
+    /// IDs of the messages in the order they were routed to the gateway
+    pub message_ids: Vec<CrossChainId>,
+}
+
+#[cw_serde]
+pub struct RoutedMessage {
+    pub message: Message,
+    /// Block height at which the message was first routed to the gateway
+    pub routed_at: u64,
+}
+
+#[cw_serde]
+pub struct RelayerAllowlist {
+    pub enabled: bool,
+    pub relayers: Vec<Relayer>,
+}
+
+#[cw_serde]
+pub struct Relayer {
+    pub address: Addr,
+    /// Number of calls that submitted incoming messages for verification or routing since the relayer was allowlisted
+    pub submissions: u64,
+    /// Total number of incoming messages across all submissions
+    pub messages: u64,
+}
+
+#[cw_serde]
+pub struct DuplicateSubmissions {
+    pub cc_id: CrossChainId,
+    /// Number of submissions that didn't trigger a new verification of the message
+    pub count: u64,
+}
+
+#[cw_serde]
+pub struct PrunableMessage {
+    pub cc_id: CrossChainId,
+    /// Block height at which the message was first routed to the gateway, if it was routed at all
+    pub routed_at: Option<u64>,
+    /// Block height from which on the message can no longer be approved, if it was routed with an expiry
+    pub expires_at: Option<u64>,
+    /// Block height at which the execution of the message on the destination chain was recorded, if it was
+    pub executed_at: Option<u64>,
+}
+
+/// Query interface of message validator contracts. Before incoming messages from a source chain with a registered validator
+/// are verified or routed, the gateway queries the validator and rejects the whole batch if any message is invalid.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum MessageValidatorQueryMsg {
+    /// Returns the given messages that violate the rules of their source chain. An empty list means all messages are valid.
+    #[returns(Vec<InvalidMessage>)]
+    InvalidMessages(Vec<Message>),
+}
+
+#[cw_serde]
+pub struct InvalidMessage {
+    pub cc_id: CrossChainId,
+    pub reason: String,
 }
@@ -6,4 +6,9 @@ pub enum Encoder {
     Abi,
     Bcs,
     StellarXdr,
+    /// Proofs for the legacy Axelar gateway on EVM chains (operators, weights and threshold),
+    /// for chains that are still being migrated to the amplifier gateway
+    LegacyAbi {
+        chain_id: u64,
+    },
 }
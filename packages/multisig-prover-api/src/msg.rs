@@ -47,8 +47,19 @@ pub struct InstantiateMsg {
     /// parameter controls which registered public key to use for signing for each verifier registered to the destination chain.
     pub key_type: KeyType,
     /// An opaque value created to distinguish distinct chains that the external gateway should be initialized with.
+    /// This is the initial domain separator, governance can rotate it later with `RotateDomainSeparator`.
     /// Value must be a String in hex format without `0x`, e.g. "598ba04d225cec385d1ce3cf3c9a076af803aa5c614bc0e0d176f04ac8d28f55".
     #[serde(with = "axelar_wasm_std::hex")] // (de)serialization with hex module
     #[schemars(with = "String")] // necessary attribute in conjunction with #[serde(with ...)]
     pub domain_separator: Hash,
+    /// Number of blocks a constructed proof stays valid for after its signing session was started. Once this window has passed,
+    /// the proof is considered stale and a new signing session can be started for the same payload with `ResignProof`.
+    /// Useful for destination chains that reject proofs older than a certain age. Proofs never go stale if this is not set.
+    #[serde(default)]
+    pub proof_validity: Option<u64>,
+    /// Minimum number of distinct signers required to complete a signing session, in addition to the signing threshold.
+    /// Guards against a few verifiers with a large share of the weight completing sessions on their own.
+    /// Applies to verifier sets created from now on. No minimum if not set.
+    #[serde(default)]
+    pub min_signers: Option<u16>,
 }
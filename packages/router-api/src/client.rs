@@ -1,10 +1,63 @@
 use std::marker::PhantomData;
 
+use axelar_wasm_std::maintenance::MaintenanceWindow;
+use axelar_wasm_std::nonempty;
 use axelar_wasm_std::vec::VecExt;
-use cosmwasm_std::{to_json_binary, Addr, CosmosMsg, Empty, WasmMsg};
+use cosmwasm_std::{to_json_binary, Addr, CosmosMsg, Empty, QuerierWrapper, WasmMsg};
 
-use crate::msg::ExecuteMsg;
-use crate::Message;
+use crate::msg::{ExecuteMsg, QueryClient, QueryMsg};
+use crate::{ChainName, ChainNameRaw, CrossChainId, Message};
+
+#[derive(thiserror::Error, Debug, PartialEq)]
+pub enum Error {
+    #[error("failed to query router for chain info of chain {0}")]
+    ChainInfo(ChainName),
+    #[error("failed to query router for chains")]
+    Chains,
+    #[error("failed to query router for its status")]
+    IsEnabled,
+    #[error("failed to query router for taps from {source_chain} to {destination_chain}")]
+    Taps {
+        source_chain: ChainNameRaw,
+        destination_chain: ChainName,
+    },
+    #[error("failed to query router for latency from {source_chain} to {destination_chain}")]
+    Latency {
+        source_chain: ChainNameRaw,
+        destination_chain: ChainName,
+    },
+    #[error("failed to query router for recalled messages")]
+    RecalledMessages,
+    #[error("failed to query router for the expiry of message {0}")]
+    MessageExpiry(CrossChainId),
+}
+
+impl From<QueryMsg> for Error {
+    fn from(value: QueryMsg) -> Self {
+        match value {
+            QueryMsg::ChainInfo(chain) => Error::ChainInfo(chain),
+            QueryMsg::Chains { .. } => Error::Chains,
+            QueryMsg::IsEnabled => Error::IsEnabled,
+            QueryMsg::Taps {
+                source_chain,
+                destination_chain,
+            } => Error::Taps {
+                source_chain,
+                destination_chain,
+            },
+            QueryMsg::Latency {
+                source_chain,
+                destination_chain,
+                ..
+            } => Error::Latency {
+                source_chain,
+                destination_chain,
+            },
+            QueryMsg::RecalledMessages { .. } => Error::RecalledMessages,
+            QueryMsg::MessageExpiry(cc_id) => Error::MessageExpiry(cc_id),
+        }
+    }
+}
 
 pub struct Router<T = Empty> {
     pub address: Addr,
@@ -19,6 +72,11 @@ impl<T> Router<T> {
         }
     }
 
+    /// Typed client for the router's queries. Failed queries are reported with [`Error`] as context
+    pub fn query<'a>(&'a self, querier: QuerierWrapper<'a>) -> QueryClient<'a> {
+        QueryClient::new(querier, &self.address)
+    }
+
     fn execute(&self, msg: &ExecuteMsg) -> CosmosMsg<T> {
         WasmMsg::Execute {
             contract_addr: self.address.to_string(),
@@ -32,4 +90,63 @@ impl<T> Router<T> {
         msgs.to_none_if_empty()
             .map(|msgs| self.execute(&ExecuteMsg::RouteMessages(msgs)))
     }
+
+    pub fn route_with_ttl(
+        &self,
+        msgs: Vec<Message>,
+        ttl: nonempty::Uint64,
+    ) -> Option<CosmosMsg<T>> {
+        msgs.to_none_if_empty()
+            .map(|messages| self.execute(&ExecuteMsg::RouteMessagesWithTtl { messages, ttl }))
+    }
+
+    pub fn record_executions(&self, cc_ids: Vec<CrossChainId>) -> Option<CosmosMsg<T>> {
+        cc_ids
+            .to_none_if_empty()
+            .map(|cc_ids| self.execute(&ExecuteMsg::RecordExecutions(cc_ids)))
+    }
+
+    pub fn set_maintenance_windows(
+        &self,
+        chain: ChainName,
+        windows: Vec<MaintenanceWindow>,
+    ) -> CosmosMsg<T> {
+        self.execute(&ExecuteMsg::SetMaintenanceWindows { chain, windows })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::{MockApi, MockQuerier};
+    use cosmwasm_std::{
+        from_json, to_json_binary, ContractResult, QuerierWrapper, SystemError, SystemResult,
+        WasmQuery,
+    };
+
+    use super::*;
+
+    #[test]
+    fn query_should_return_typed_responses_and_errors() {
+        let router = Router::<Empty>::new(MockApi::default().addr_make("router"));
+        let mut querier = MockQuerier::<Empty>::new(&[]);
+        querier.update_wasm(|query| {
+            let WasmQuery::Smart { msg, .. } = query else {
+                panic!("unexpected query");
+            };
+
+            match from_json::<QueryMsg>(msg).unwrap() {
+                QueryMsg::IsEnabled => {
+                    SystemResult::Ok(ContractResult::Ok(to_json_binary(&true).unwrap()))
+                }
+                _ => SystemResult::Err(SystemError::Unknown {}),
+            }
+        });
+        let querier = QuerierWrapper::new(&querier);
+
+        assert!(router.query(querier).is_enabled().unwrap());
+
+        let chain: ChainName = "ethereum".parse().unwrap();
+        let err = router.query(querier).chain_info(chain.clone()).unwrap_err();
+        assert_eq!(err.current_context(), &Error::ChainInfo(chain));
+    }
 }
@@ -2,7 +2,7 @@ use axelar_wasm_std::IntoContractError;
 use cosmwasm_std::StdError;
 use thiserror::Error;
 
-use crate::ChainName;
+use crate::{ChainName, ChainNameRaw, CrossChainId};
 
 /// A chain name must adhere to the following rules:
 /// 1. it can optionally start with an uppercase letter, followed by one or more lowercase letters
@@ -38,15 +38,57 @@ pub enum Error {
     #[error("chain is frozen")]
     ChainFrozen { chain: ChainName },
 
+    #[error("chain is under maintenance")]
+    ChainUnderMaintenance { chain: ChainName },
+
     #[error("address is invalid")]
     InvalidAddress,
 
     #[error("source chain does not match registered gateway")]
     WrongSourceChain,
 
+    #[error("sender is not the gateway of the message's destination chain")]
+    WrongDestinationGateway,
+
+    #[error("message {0} has not been routed or has already been executed")]
+    MessageNotRecallable(CrossChainId),
+
+    #[error("message {0} was not routed with a TTL or has not expired yet")]
+    MessageNotExpired(CrossChainId),
+
     #[error("store failed saving/loading data")]
     StoreFailure,
 
     #[error("failed to query the nexus module")]
     Nexus,
+
+    #[error(
+        "tap {address} is already registered for chain pair ({source_chain}, {destination_chain})"
+    )]
+    TapAlreadyRegistered {
+        source_chain: ChainNameRaw,
+        destination_chain: ChainName,
+        address: String,
+    },
+
+    #[error(
+        "tap {address} is not registered for chain pair ({source_chain}, {destination_chain})"
+    )]
+    TapNotFound {
+        source_chain: ChainNameRaw,
+        destination_chain: ChainName,
+        address: String,
+    },
+
+    #[error(
+        "chain pair ({source_chain}, {destination_chain}) already has the maximum of {max} taps"
+    )]
+    TooManyTaps {
+        source_chain: ChainNameRaw,
+        destination_chain: ChainName,
+        max: usize,
+    },
+
+    #[error("tap gas limit {gas_limit} exceeds the maximum of {max}")]
+    TapGasLimitTooHigh { gas_limit: u64, max: u64 },
 }
@@ -1,6 +1,9 @@
 use std::collections::HashMap;
 
+use axelar_wasm_std::address::AddressFormat;
+use axelar_wasm_std::maintenance::MaintenanceWindow;
 use axelar_wasm_std::msg_id::MessageIdFormat;
+use axelar_wasm_std::{nonempty, QueryClient};
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use msgs_derive::EnsurePermissions;
 
@@ -9,12 +12,21 @@ use crate::primitives::*;
 #[cw_serde]
 #[derive(EnsurePermissions)]
 pub enum ExecuteMsg {
-    /// Registers a new chain with the router
+    /// Registers a new chain with the router, together with the formats of its message ids and addresses.
+    /// Other contracts and ampd can query them with `ChainInfo` instead of configuring them separately
     #[permission(Governance)]
     RegisterChain {
         chain: ChainName,
         gateway_address: Address,
         msg_id_format: MessageIdFormat,
+        #[serde(default)]
+        address_format: Option<AddressFormat>,
+    },
+    /// Sets the address format of a registered chain, e.g. for chains that were registered without one
+    #[permission(Governance)]
+    UpdateAddressFormat {
+        chain: ChainName,
+        address_format: Option<AddressFormat>,
     },
     /// Changes the gateway address associated with a particular chain
     #[permission(Governance)]
@@ -45,10 +57,73 @@ pub enum ExecuteMsg {
     /// Called by an incoming gateway
     #[permission(Specific(gateway))]
     RouteMessages(Vec<Message>),
+
+    /// Like RouteMessages, but the messages expire `ttl` blocks after they were first routed.
+    /// Destination gateways leave expired messages out when handing out messages for approval (the axelarnet gateway
+    /// refuses to execute them), and the router stops routing them, so applications can refund expired messages
+    /// without racing their execution. Gateways call this for RouteMessagesWithTtl of incoming messages.
+    /// The TTL is ignored for messages that have been routed before.
+    #[permission(Specific(gateway))]
+    RouteMessagesWithTtl {
+        messages: Vec<Message>,
+        ttl: nonempty::Uint64,
+    },
+
+    /// Marks the given messages as expired and emits an event for each of them.
+    /// Fails if any of the messages was not routed with a TTL or has not expired yet. Expiry takes effect at the expiry
+    /// height regardless of this call, it only makes the expiry observable as an event and releases the routing record.
+    /// Marked messages are pruned by later calls once they are older than the retention window of 100000 blocks.
+    #[permission(Any)]
+    ExpireMessages(Vec<CrossChainId>),
+
+    /// Registers a read-only contract that receives a copy of every message routed from `source_chain` to `destination_chain`.
+    /// The copy is sent with the given gas limit of at most 1000000, and a failing tap never blocks routing.
+    #[permission(Governance)]
+    RegisterTap {
+        source_chain: ChainNameRaw,
+        destination_chain: ChainName,
+        contract_address: Address,
+        gas_limit: u64,
+    },
+
+    /// Stops forwarding routed messages to a previously registered tap.
+    #[permission(Governance)]
+    DeregisterTap {
+        source_chain: ChainNameRaw,
+        destination_chain: ChainName,
+        contract_address: Address,
+    },
+
+    /// Receipts for messages that were routed by the router and have since been executed on their destination chain.
+    /// Called by the gateway of the destination chain, the receipts are used to track cross-chain latency.
+    #[permission(Specific(gateway))]
+    RecordExecutions(Vec<CrossChainId>),
+
+    /// Emergency command to recall messages that have been routed, but not executed on their destination chain yet,
+    /// e.g. because an exploit was detected. The destination gateways are instructed to refuse the recalled messages,
+    /// and the router does not route them again.
+    #[permission(Governance)]
+    RecallMessages(Vec<CrossChainId>),
+
+    /// Replaces the scheduled maintenance windows of a chain. Called by the coordinator whenever a window is
+    /// scheduled or cancelled. No messages are routed to the chain while one of its windows is active
+    #[permission(Specific(coordinator))]
+    SetMaintenanceWindows {
+        chain: ChainName,
+        windows: Vec<MaintenanceWindow>,
+    },
 }
 
+/// Message the router sends to registered tap contracts
 #[cw_serde]
-#[derive(QueryResponses)]
+pub enum TapExecuteMsg {
+    /// Copy of messages that were routed between the chain pair the tap is registered for
+    ObserveMessages(Vec<Message>),
+}
+
+#[cw_serde]
+#[derive(QueryResponses, QueryClient)]
+#[query_client(error = crate::client::Error)]
 pub enum QueryMsg {
     #[returns(ChainEndpoint)]
     ChainInfo(ChainName),
@@ -64,4 +139,37 @@ pub enum QueryMsg {
     },
     #[returns(bool)]
     IsEnabled,
+
+    // Returns the taps registered for messages routed from source_chain to destination_chain
+    #[returns(Vec<Tap>)]
+    Taps {
+        source_chain: ChainNameRaw,
+        destination_chain: ChainName,
+    },
+
+    // Returns the latency percentiles (in blocks) of messages routed from source_chain to destination_chain
+    // whose execution was recorded within the last `window` blocks, or None if there are no such messages.
+    // Only the 1000 most recently executed messages within the window are taken into account
+    #[returns(Option<LatencyPercentiles>)]
+    Latency {
+        source_chain: ChainNameRaw,
+        destination_chain: ChainName,
+        window: u64,
+    },
+
+    // Returns the IDs of all recalled messages
+    // The list is paginated by:
+    // - start_after: the message ID to start after, which the next page of results should start.
+    // - limit: limit the number of message IDs returned, default is u32::MAX.
+    #[returns(Vec<CrossChainId>)]
+    RecalledMessages {
+        start_after: Option<CrossChainId>,
+        limit: Option<u32>,
+    },
+
+    // Returns the expiry of a message that was routed with a TTL, or None if the message was routed without a TTL,
+    // has never been routed, or has already been executed. Messages that were marked as expired with ExpireMessages
+    // are only kept for a limited number of blocks
+    #[returns(Option<MessageExpiry>)]
+    MessageExpiry(CrossChainId),
 }
@@ -4,6 +4,7 @@ use std::fmt::Display;
 use std::ops::Deref;
 use std::str::FromStr;
 
+use axelar_wasm_std::address::AddressFormat;
 use axelar_wasm_std::flagset::FlagSet;
 use axelar_wasm_std::hash::Hash;
 use axelar_wasm_std::msg_id::MessageIdFormat;
@@ -462,6 +463,9 @@ pub struct ChainEndpoint {
     pub gateway: Gateway,
     pub frozen_status: FlagSet<GatewayDirection>,
     pub msg_id_format: MessageIdFormat,
+    /// Format of addresses on the chain. Not set for chains whose address format is unknown to the router
+    #[serde(default)]
+    pub address_format: Option<AddressFormat>,
 }
 
 impl ChainEndpoint {
@@ -474,6 +478,32 @@ impl ChainEndpoint {
     }
 }
 
+/// A read-only contract that receives a copy of the messages routed between a pair of chains
+#[cw_serde]
+pub struct Tap {
+    pub address: Addr,
+    /// gas limit of the submessage that forwards the copy, so a misbehaving tap cannot exhaust the routing transaction's gas
+    pub gas_limit: u64,
+}
+
+/// Latency between a message being routed and its execution on the destination chain, in blocks
+#[cw_serde]
+pub struct LatencyPercentiles {
+    /// number of executed messages the percentiles are computed from
+    pub sample_count: u64,
+    pub p50: u64,
+    pub p90: u64,
+    pub p99: u64,
+}
+
+/// Expiry of a message that was routed with a TTL
+#[cw_serde]
+pub struct MessageExpiry {
+    /// block height from which on the message can no longer be approved
+    pub expires_at: u64,
+    pub expired: bool,
+}
+
 #[cfg(test)]
 mod tests {
     use cosmwasm_std::to_json_vec;
@@ -1,8 +1,13 @@
-use error_stack::ResultExt;
+use std::ops::Deref;
+
+use axelar_wasm_std::snapshot::Participant;
+use axelar_wasm_std::vec::VecExt;
+use cosmwasm_std::CosmosMsg;
+use error_stack::bail;
 use router_api::ChainName;
 
-use crate::msg::{ExecuteMsg, QueryMsg, VerifierDetails};
-use crate::{Service, WeightedVerifier};
+use crate::msg::{ExecuteMsg, QueryClient, QueryMsg};
+use crate::ParticipationSummary;
 
 type Result<T> = error_stack::Result<T, Error>;
 
@@ -14,6 +19,19 @@ pub enum Error {
         chain_name: ChainName,
     },
 
+    #[error("failed to query service registry for active verifiers for service {service_name} and chain {chain_name} at height {height}")]
+    ActiveVerifiersAt {
+        service_name: String,
+        chain_name: ChainName,
+        height: u64,
+    },
+
+    #[error("failed to query service registry for a page of active verifiers for service {service_name} and chain {chain_name}")]
+    ActiveVerifiersPage {
+        service_name: String,
+        chain_name: ChainName,
+    },
+
     #[error("failed to query service registry for service {0}")]
     Service(String),
 
@@ -22,6 +40,35 @@ pub enum Error {
         service_name: String,
         verifier: String,
     },
+
+    #[error("failed to query service registry for verifiers of service {0}")]
+    Verifiers(String),
+
+    #[error("failed to query service registry for pending unbonds of verifier {verifier} of service {service_name}")]
+    PendingUnbonds {
+        service_name: String,
+        verifier: String,
+    },
+
+    #[error("failed to query service registry for slash history of verifier {verifier} of service {service_name}")]
+    SlashHistory {
+        service_name: String,
+        verifier: String,
+    },
+
+    #[error("failed to query service registry for performance attestations of service {0}")]
+    Attestations(String),
+
+    #[error("failed to query service registry for exit queue of service {0}")]
+    ExitQueue(String),
+
+    #[error(
+        "service {service_name} has fewer active verifiers for chain {chain_name} than its minimum"
+    )]
+    NotEnoughActiveVerifiers {
+        service_name: String,
+        chain_name: ChainName,
+    },
 }
 
 impl From<QueryMsg> for Error {
@@ -34,6 +81,23 @@ impl From<QueryMsg> for Error {
                 service_name,
                 chain_name,
             },
+            QueryMsg::ActiveVerifiersAt {
+                service_name,
+                chain_name,
+                height,
+            } => Error::ActiveVerifiersAt {
+                service_name,
+                chain_name,
+                height,
+            },
+            QueryMsg::ActiveVerifiersPage {
+                service_name,
+                chain_name,
+                ..
+            } => Error::ActiveVerifiersPage {
+                service_name,
+                chain_name,
+            },
             QueryMsg::Service { service_name } => Error::Service(service_name),
             QueryMsg::Verifier {
                 service_name,
@@ -42,46 +106,111 @@ impl From<QueryMsg> for Error {
                 service_name,
                 verifier,
             },
+            QueryMsg::Verifiers { service_name, .. } => Error::Verifiers(service_name),
+            QueryMsg::PendingUnbonds {
+                service_name,
+                verifier,
+            } => Error::PendingUnbonds {
+                service_name,
+                verifier,
+            },
+            QueryMsg::SlashHistory {
+                service_name,
+                verifier,
+            } => Error::SlashHistory {
+                service_name,
+                verifier,
+            },
+            QueryMsg::Attestations { service_name, .. } => Error::Attestations(service_name),
+            QueryMsg::ExitQueue { service_name, .. } => Error::ExitQueue(service_name),
         }
     }
 }
 
 impl<'a> From<client::ContractClient<'a, ExecuteMsg, QueryMsg>> for Client<'a> {
     fn from(client: client::ContractClient<'a, ExecuteMsg, QueryMsg>) -> Self {
-        Client { client }
+        Client {
+            queries: QueryClient::new(client.querier(), client.address),
+            client,
+        }
     }
 }
 
+/// Builds the service registry's execute messages. Queries are available through the generated [QueryClient]
 pub struct Client<'a> {
     client: client::ContractClient<'a, ExecuteMsg, QueryMsg>,
+    queries: QueryClient<'a>,
 }
 
-impl Client<'_> {
-    // TODO: add execute methods
+impl<'a> Deref for Client<'a> {
+    type Target = QueryClient<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.queries
+    }
+}
 
-    pub fn active_verifiers(
+impl Client<'_> {
+    pub fn authorize_verifiers(
         &self,
+        verifiers: Vec<String>,
         service_name: String,
-        chain_name: ChainName,
-    ) -> Result<Vec<WeightedVerifier>> {
-        let msg = QueryMsg::ActiveVerifiers {
-            service_name,
-            chain_name,
-        };
-        self.client.query(&msg).change_context_lazy(|| msg.into())
+    ) -> Option<CosmosMsg> {
+        verifiers.to_none_if_empty().map(|verifiers| {
+            self.client.execute(&ExecuteMsg::AuthorizeVerifiers {
+                verifiers,
+                service_name,
+            })
+        })
     }
 
-    pub fn service(&self, service_name: String) -> Result<Service> {
-        let msg = QueryMsg::Service { service_name };
-        self.client.query(&msg).change_context_lazy(|| msg.into())
+    pub fn submit_attestations(
+        &self,
+        service_name: String,
+        summaries: Vec<ParticipationSummary>,
+    ) -> Option<CosmosMsg> {
+        summaries.to_none_if_empty().map(|summaries| {
+            self.client.execute(&ExecuteMsg::SubmitAttestations {
+                service_name,
+                summaries,
+            })
+        })
     }
 
-    pub fn verifier(&self, service_name: String, verifier: String) -> Result<VerifierDetails> {
-        let msg = QueryMsg::Verifier {
-            service_name,
-            verifier,
-        };
-        self.client.query(&msg).change_context_lazy(|| msg.into())
+    /// Returns the active verifiers as the weighted participants of a snapshot. They are read page by page, so no single
+    /// query has to load the complete set. Fails if the service has fewer active verifiers for the chain than its minimum
+    pub fn active_participants(
+        &self,
+        service_name: String,
+        chain_name: ChainName,
+    ) -> Result<Vec<Participant>> {
+        let min_num_verifiers = self.service(service_name.clone())?.min_num_verifiers;
+
+        let mut participants = vec![];
+        let mut start_after = None;
+        loop {
+            let page = self.active_verifiers_page(
+                service_name.clone(),
+                chain_name.clone(),
+                start_after,
+                None,
+            )?;
+            participants.extend(page.verifiers.into_iter().map(Participant::from));
+
+            match page.next_start_after {
+                Some(next_start_after) => start_after = Some(next_start_after),
+                None => break,
+            }
+        }
+
+        if participants.len() < usize::from(min_num_verifiers) {
+            bail!(Error::NotEnoughActiveVerifiers {
+                service_name,
+                chain_name,
+            });
+        }
+
+        Ok(participants)
     }
 }
 
@@ -89,13 +218,19 @@ impl Client<'_> {
 mod test {
 
     use axelar_wasm_std::nonempty::Uint128;
+    use axelar_wasm_std::snapshot::Participant;
     use cosmwasm_std::testing::{MockApi, MockQuerier};
-    use cosmwasm_std::{from_json, to_json_binary, Addr, QuerierWrapper, SystemError, WasmQuery};
+    use cosmwasm_std::{
+        from_json, to_json_binary, Addr, QuerierWrapper, SystemError, Timestamp, WasmQuery,
+    };
     use router_api::ChainName;
 
     use crate::client::Client;
-    use crate::msg::{QueryMsg, VerifierDetails};
-    use crate::{Service, Verifier, WeightedVerifier};
+    use crate::msg::{ActiveVerifiersPageResponse, QueryMsg, VerifierDetails};
+    use crate::{
+        ExitRequest, PendingUnbond, PerformanceAttestation, Service, SlashRecord, Verifier,
+        VerifierSetSnapshot, WeightedVerifier,
+    };
 
     #[test]
     fn query_active_verifiers_returns_error_when_query_fails() {
@@ -123,6 +258,80 @@ mod test {
         goldie::assert_json!(res.unwrap());
     }
 
+    #[test]
+    fn query_active_verifiers_at_returns_error_when_query_fails() {
+        let (querier, addr) = setup_queries_to_fail();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+        let chain_name: ChainName = "ethereum".try_into().unwrap();
+        let res = client.active_verifiers_at("verifiers".to_string(), chain_name, 100);
+
+        assert!(res.is_err());
+        goldie::assert!(res.unwrap_err().to_string());
+    }
+
+    #[test]
+    fn query_active_verifiers_at_returns_snapshot() {
+        let (querier, addr) = setup_queries_to_succeed();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+        let chain_name: ChainName = "ethereum".try_into().unwrap();
+        let res = client.active_verifiers_at("verifiers".to_string(), chain_name, 100);
+
+        assert!(res.is_ok());
+        goldie::assert_json!(res.unwrap());
+    }
+
+    #[test]
+    fn query_active_verifiers_page_returns_error_when_query_fails() {
+        let (querier, addr) = setup_queries_to_fail();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+        let chain_name: ChainName = "ethereum".try_into().unwrap();
+        let res = client.active_verifiers_page("verifiers".to_string(), chain_name, None, None);
+
+        assert!(res.is_err());
+        goldie::assert!(res.unwrap_err().to_string());
+    }
+
+    #[test]
+    fn query_active_verifiers_page_returns_active_verifiers() {
+        let (querier, addr) = setup_queries_to_succeed();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+        let chain_name: ChainName = "ethereum".try_into().unwrap();
+        let start_after = MockApi::default().addr_make("previous").to_string();
+        let res = client.active_verifiers_page(
+            "verifiers".to_string(),
+            chain_name,
+            Some(start_after),
+            Some(10),
+        );
+
+        assert!(res.is_ok());
+        goldie::assert_json!(res.unwrap());
+    }
+
+    #[test]
+    fn query_active_participants_returns_weighted_participants() {
+        let (querier, addr) = setup_queries_to_succeed();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+        let chain_name: ChainName = "ethereum".try_into().unwrap();
+        let res = client.active_participants("verifiers".to_string(), chain_name);
+
+        assert_eq!(
+            res.unwrap(),
+            ["verifier", "other"]
+                .into_iter()
+                .map(|verifier| Participant {
+                    address: MockApi::default().addr_make(verifier),
+                    weight: Uint128::one(),
+                })
+                .collect::<Vec<_>>()
+        );
+    }
+
     #[test]
     fn query_verifier_returns_error_when_query_fails() {
         let (querier, addr) = setup_queries_to_fail();
@@ -173,6 +382,128 @@ mod test {
         goldie::assert_json!(res.unwrap());
     }
 
+    #[test]
+    fn query_verifiers_returns_error_when_query_fails() {
+        let (querier, addr) = setup_queries_to_fail();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+        let service_name = "verifiers".to_string();
+        let verifiers = vec![MockApi::default().addr_make("verifier").to_string()];
+        let res = client.verifiers(service_name.clone(), verifiers);
+
+        assert!(res.is_err());
+        goldie::assert!(res.unwrap_err().to_string());
+    }
+
+    #[test]
+    fn query_verifiers_returns_verifiers() {
+        let (querier, addr) = setup_queries_to_succeed();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+        let service_name = "verifiers".to_string();
+        let verifiers = vec![MockApi::default().addr_make("verifier").to_string()];
+        let res = client.verifiers(service_name.clone(), verifiers);
+
+        assert!(res.is_ok());
+        goldie::assert_json!(res.unwrap());
+    }
+
+    #[test]
+    fn query_pending_unbonds_returns_error_when_query_fails() {
+        let (querier, addr) = setup_queries_to_fail();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+        let service_name = "verifiers".to_string();
+        let verifier = MockApi::default().addr_make("verifier").to_string();
+        let res = client.pending_unbonds(service_name.clone(), verifier.clone());
+
+        assert!(res.is_err());
+        goldie::assert!(res.unwrap_err().to_string());
+    }
+
+    #[test]
+    fn query_pending_unbonds_returns_pending_unbonds() {
+        let (querier, addr) = setup_queries_to_succeed();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+        let service_name = "verifiers".to_string();
+        let verifier = MockApi::default().addr_make("verifier").to_string();
+        let res = client.pending_unbonds(service_name.clone(), verifier.clone());
+
+        assert!(res.is_ok());
+        goldie::assert_json!(res.unwrap());
+    }
+
+    #[test]
+    fn query_slash_history_returns_error_when_query_fails() {
+        let (querier, addr) = setup_queries_to_fail();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+        let service_name = "verifiers".to_string();
+        let verifier = MockApi::default().addr_make("verifier").to_string();
+        let res = client.slash_history(service_name.clone(), verifier.clone());
+
+        assert!(res.is_err());
+        goldie::assert!(res.unwrap_err().to_string());
+    }
+
+    #[test]
+    fn query_slash_history_returns_slash_history() {
+        let (querier, addr) = setup_queries_to_succeed();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+        let service_name = "verifiers".to_string();
+        let verifier = MockApi::default().addr_make("verifier").to_string();
+        let res = client.slash_history(service_name.clone(), verifier.clone());
+
+        assert!(res.is_ok());
+        goldie::assert_json!(res.unwrap());
+    }
+
+    #[test]
+    fn query_attestations_returns_error_when_query_fails() {
+        let (querier, addr) = setup_queries_to_fail();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+        let res = client.attestations("verifiers".to_string(), None, None);
+
+        assert!(res.is_err());
+        goldie::assert!(res.unwrap_err().to_string());
+    }
+
+    #[test]
+    fn query_attestations_returns_attestations() {
+        let (querier, addr) = setup_queries_to_succeed();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+        let res = client.attestations("verifiers".to_string(), None, Some(10));
+
+        assert!(res.is_ok());
+        goldie::assert_json!(res.unwrap());
+    }
+
+    #[test]
+    fn query_exit_queue_returns_error_when_query_fails() {
+        let (querier, addr) = setup_queries_to_fail();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+        let res = client.exit_queue("verifiers".to_string(), None, None);
+
+        assert!(res.is_err());
+        goldie::assert!(res.unwrap_err().to_string());
+    }
+
+    #[test]
+    fn query_exit_queue_returns_exit_requests() {
+        let (querier, addr) = setup_queries_to_succeed();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+        let res = client.exit_queue("verifiers".to_string(), None, Some(10));
+
+        assert!(res.is_ok());
+        goldie::assert_json!(res.unwrap());
+    }
+
     fn setup_queries_to_fail() -> (MockQuerier, Addr) {
         let api = MockApi::default();
         let addr = api.addr_make("service-registry");
@@ -218,6 +549,57 @@ mod test {
                     }])
                     .into())
                     .into(),
+                    QueryMsg::ActiveVerifiersAt {
+                        service_name,
+                        chain_name: _,
+                        height,
+                    } => Ok(to_json_binary(&Some(VerifierSetSnapshot {
+                        height: height - 10,
+                        verifiers: vec![WeightedVerifier {
+                            verifier_info: Verifier {
+                                address: api.addr_make("verifier"),
+                                bonding_state: crate::BondingState::Bonded {
+                                    amount: Uint128::one(),
+                                },
+                                authorization_state: crate::AuthorizationState::Authorized,
+                                service_name,
+                            },
+                            weight: Uint128::one(),
+                        }],
+                    }))
+                    .into())
+                    .into(),
+                    QueryMsg::ActiveVerifiersPage {
+                        service_name,
+                        start_after,
+                        limit,
+                        ..
+                    } => Ok(to_json_binary(&ActiveVerifiersPageResponse {
+                        verifiers: ["verifier", "other"]
+                            .into_iter()
+                            .map(|verifier| WeightedVerifier {
+                                verifier_info: Verifier {
+                                    address: api.addr_make(verifier),
+                                    bonding_state: crate::BondingState::Bonded {
+                                        amount: Uint128::one(),
+                                    },
+                                    authorization_state: crate::AuthorizationState::Authorized,
+                                    service_name: service_name.clone(),
+                                },
+                                weight: Uint128::one(),
+                            })
+                            .filter(|verifier| {
+                                start_after.as_ref().map_or(true, |start_after| {
+                                    verifier.verifier_info.address.as_str() > start_after.as_str()
+                                })
+                            })
+                            .take(limit.unwrap_or(100) as usize)
+                            .collect::<Vec<_>>(),
+                        // the mock has fewer verifiers than the smallest page
+                        next_start_after: None,
+                    })
+                    .into())
+                    .into(),
                     QueryMsg::Service { service_name } => Ok(to_json_binary(&Service {
                         name: service_name,
                         coordinator_contract: api.addr_make("coordinator"),
@@ -227,6 +609,7 @@ mod test {
                         bond_denom: "uaxl".into(),
                         unbonding_period_days: 10,
                         description: "some service".into(),
+                        exit_handover_blocks: 100,
                     })
                     .into())
                     .into(),
@@ -244,9 +627,64 @@ mod test {
                         },
                         weight: Uint128::one(),
                         supported_chains: vec![],
+                        attestations: vec![],
                     })
                     .into())
                     .into(),
+                    QueryMsg::Verifiers {
+                        service_name,
+                        verifiers,
+                    } => Ok(to_json_binary(
+                        &verifiers
+                            .into_iter()
+                            .map(|verifier| Verifier {
+                                address: Addr::unchecked(verifier),
+                                bonding_state: crate::BondingState::Bonded {
+                                    amount: Uint128::one(),
+                                },
+                                authorization_state: crate::AuthorizationState::Authorized,
+                                service_name: service_name.clone(),
+                            })
+                            .collect::<Vec<_>>(),
+                    )
+                    .into())
+                    .into(),
+                    QueryMsg::PendingUnbonds { .. } => Ok(to_json_binary(&vec![PendingUnbond {
+                        amount: Uint128::one(),
+                        requested_at: Timestamp::from_seconds(100),
+                        completes_at: Timestamp::from_seconds(100).plus_days(10),
+                    }])
+                    .into())
+                    .into(),
+                    QueryMsg::SlashHistory { .. } => Ok(to_json_binary(&vec![SlashRecord {
+                        slasher: api.addr_make("slasher"),
+                        amount: Uint128::one().into(),
+                        destination: None,
+                        reason: "double signing".try_into().unwrap(),
+                        slashed_at: Timestamp::from_seconds(100),
+                        block_height: 10,
+                    }])
+                    .into())
+                    .into(),
+                    QueryMsg::Attestations { .. } => {
+                        Ok(to_json_binary(&vec![PerformanceAttestation {
+                            verifier: api.addr_make("verifier"),
+                            chain_name: "ethereum".parse().unwrap(),
+                            events_participated: 9,
+                            events_total: 10,
+                            attester: api.addr_make("rewards"),
+                            updated_at: 10,
+                        }])
+                        .into())
+                        .into()
+                    }
+                    QueryMsg::ExitQueue { .. } => Ok(to_json_binary(&vec![ExitRequest {
+                        verifier: api.addr_make("verifier"),
+                        requested_at: 10,
+                        duties_end_at: 110,
+                    }])
+                    .into())
+                    .into(),
                 }
             }
             _ => panic!("unexpected query: {:?}", msg),
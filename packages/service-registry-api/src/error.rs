@@ -1,5 +1,5 @@
 use axelar_wasm_std::{nonempty, IntoContractError};
-use cosmwasm_std::{OverflowError, StdError};
+use cosmwasm_std::{OverflowError, StdError, Uint128};
 use thiserror::Error;
 
 use crate::primitives::BondingState;
@@ -37,6 +37,30 @@ pub enum ContractError {
     VerifierJailed,
     #[error("failed to unbond verifier")]
     FailedToUnbondVerifier,
+    #[error("remaining bond would fall below the minimum verifier bond")]
+    RemainingBondBelowMinimum,
+    #[error("slasher not found")]
+    SlasherNotFound,
+    #[error("slash amount {amount} exceeds the maximum of {max} per slash")]
+    SlashExceedsLimit { amount: Uint128, max: Uint128 },
+    #[error(
+        "slash amount {amount} exceeds the remaining {remaining} that can be slashed in this epoch"
+    )]
+    SlashExceedsEpochLimit { amount: Uint128, remaining: Uint128 },
+    #[error("verifier has no stake to slash")]
+    NothingToSlash,
+    #[error("attester not found")]
+    AttesterNotFound,
+    #[error("verifier already requested to exit")]
+    ExitAlreadyRequested,
+    #[error("cannot look up {count} verifiers at once, the maximum is {max}")]
+    TooManyVerifiers { count: usize, max: usize },
+    #[error("verifier {verifier} participated in {events_participated} events, but only {events_total} were expected")]
+    InvalidParticipationSummary {
+        verifier: String,
+        events_participated: u64,
+        events_total: u64,
+    },
 
     // Generic error to wrap cw_storage_plus errors
     // This should only be used for things that shouldn't happen, such as encountering
@@ -1,4 +1,4 @@
-use axelar_wasm_std::nonempty;
+use axelar_wasm_std::{bounded, nonempty, QueryClient};
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use msgs_derive::EnsurePermissions;
 use router_api::ChainName;
@@ -7,6 +7,13 @@ use serde::{Deserialize, Serialize};
 
 use crate::primitives::*;
 
+/// Maximum length in bytes of the reason a verifier is slashed for
+pub const MAX_SLASHING_REASON_LENGTH: usize = 256;
+/// Maximum number of verifiers an ActiveVerifiersPage query scans, which is also its default limit
+pub const MAX_ACTIVE_VERIFIERS_PAGE_LIMIT: u32 = 100;
+/// Maximum number of verifiers that can be looked up with a single Verifiers query
+pub const MAX_VERIFIERS_QUERY_LIMIT: usize = 100;
+
 #[cw_serde]
 #[derive(EnsurePermissions)]
 pub enum ExecuteMsg {
@@ -21,6 +28,10 @@ pub enum ExecuteMsg {
         bond_denom: String,
         unbonding_period_days: u16, // number of days to wait after starting unbonding before allowed to claim stake
         description: String,
+        /// Minimum number of blocks an exiting verifier must keep participating in the polls and signing sessions it is
+        /// already part of. Set it to cover the longest poll or signing session of the service
+        #[serde(default)]
+        exit_handover_blocks: u64,
     },
     /// Updates modifiable fields of the service. Note, not all fields are modifiable.
     #[permission(Governance)]
@@ -28,8 +39,8 @@ pub enum ExecuteMsg {
         service_name: String,
         updated_service_params: UpdatedServiceParams,
     },
-    /// Authorizes verifiers to join a service. Can only be called by governance account. Verifiers must still bond sufficient stake to participate.
-    #[permission(Governance)]
+    /// Authorizes verifiers to join a service. Can only be called by governance account or the service's coordinator. Verifiers must still bond sufficient stake to participate.
+    #[permission(Governance, Specific(coordinator))]
     AuthorizeVerifiers {
         verifiers: Vec<String>,
         service_name: String,
@@ -66,13 +77,80 @@ pub enum ExecuteMsg {
     /// Initiates unbonding of staked funds for the sender.
     #[permission(Any)]
     UnbondVerifier { service_name: String },
-    /// Claim previously staked funds that have finished unbonding for the sender.
+    /// Initiates unbonding of part of the sender's stake. The remaining bond must not fall below the service's minimum bond.
+    /// Each partial unbond is queued and becomes claimable once its own unbonding period has passed.
+    #[permission(Any)]
+    UnbondPartialStake {
+        service_name: String,
+        amount: nonempty::Uint128,
+    },
+    /// Claim previously staked funds that have finished unbonding for the sender, including any matured partial unbonds.
     #[permission(Any)]
     ClaimStake { service_name: String },
+
+    /// Adds the sender to the service's exit queue. The verifier is immediately excluded from the active verifiers,
+    /// so it is not selected for new polls and signing sessions, but stays bonded until the service's exit handover period has passed.
+    #[permission(Any)]
+    RequestExit { service_name: String },
+    /// Starts unbonding for up to `limit` queued verifiers whose exit handover period has passed,
+    /// in the order of their addresses. Verifiers that are still part of an active verifier set stay in the queue.
+    /// Only completed exits count towards `limit`, delayed verifiers are skipped.
+    /// Can be called by anyone.
+    #[permission(Any)]
+    ProcessExitQueue {
+        service_name: String,
+        limit: Option<u32>,
+    },
+    /// Allows the slasher contract to slash the bonds of the service's verifiers within the given limits.
+    /// Registering an already registered slasher replaces its limits. Can only be called by governance account.
+    #[permission(Governance)]
+    RegisterSlasher {
+        service_name: String,
+        slasher: String,
+        limits: SlashingLimits,
+    },
+    /// Revokes the slasher's permission to slash. Can only be called by governance account.
+    #[permission(Governance)]
+    DeregisterSlasher {
+        service_name: String,
+        slasher: String,
+    },
+    /// Slashes the given amount from the verifier's stake, taking it from the bond first and then from pending partial unbonds.
+    /// The slashed funds are sent to the destination if set, otherwise they are burned. Called by a registered slasher.
+    #[permission(Specific(slasher))]
+    SlashVerifier {
+        service_name: String,
+        verifier: String,
+        amount: nonempty::Uint128,
+        destination: Option<String>,
+        reason: bounded::String<MAX_SLASHING_REASON_LENGTH>,
+    },
+
+    /// Allows the attester to submit performance attestations for the service's verifiers. Can only be called by governance account.
+    #[permission(Governance)]
+    RegisterAttester {
+        service_name: String,
+        attester: String,
+    },
+    /// Revokes the attester's permission to submit attestations. Previously submitted attestations are kept.
+    /// Can only be called by governance account.
+    #[permission(Governance)]
+    DeregisterAttester {
+        service_name: String,
+        attester: String,
+    },
+    /// Adds the participation summaries to the verifiers' performance attestations. Summaries of verifiers that are unknown
+    /// to the service are ignored. Called by a registered attester, e.g. the rewards contract.
+    #[permission(Specific(attester))]
+    SubmitAttestations {
+        service_name: String,
+        summaries: Vec<ParticipationSummary>,
+    },
 }
 
 #[cw_serde]
-#[derive(QueryResponses)]
+#[derive(QueryResponses, QueryClient)]
+#[query_client(error = crate::client::Error)]
 pub enum QueryMsg {
     #[returns(Vec<WeightedVerifier>)]
     ActiveVerifiers {
@@ -80,6 +158,29 @@ pub enum QueryMsg {
         chain_name: ChainName,
     },
 
+    /// Returns the latest snapshot of the service's active verifiers for the chain that was taken at or before the given height,
+    /// or None if there is no such snapshot
+    #[returns(Option<VerifierSetSnapshot>)]
+    ActiveVerifiersAt {
+        service_name: String,
+        chain_name: ChainName,
+        height: u64,
+    },
+
+    /// Returns the service's active verifiers for the chain with their weights, ordered by address.
+    /// Unlike ActiveVerifiers, the minimum number of verifiers of the service is not enforced, because a single page can't tell.
+    /// The list is paginated by:
+    /// - start_after: the verifier address to start after, which the next page of results should start.
+    /// - limit: limit the number of verifiers of the chain that are scanned, default and maximum is 100.
+    ///   Inactive verifiers are skipped, so a page can hold fewer verifiers even if more follow.
+    #[returns(ActiveVerifiersPageResponse)]
+    ActiveVerifiersPage {
+        service_name: String,
+        chain_name: ChainName,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
     #[returns(Service)]
     Service { service_name: String },
 
@@ -88,6 +189,57 @@ pub enum QueryMsg {
         service_name: String,
         verifier: String,
     },
+
+    /// Returns the records of the given verifiers. Addresses that are unknown to the service are omitted.
+    /// At most 100 verifiers can be looked up at once.
+    #[returns(Vec<Verifier>)]
+    Verifiers {
+        service_name: String,
+        verifiers: Vec<String>,
+    },
+
+    /// Returns the verifier's partial unbonds that have not been claimed yet, in the order they were requested
+    #[returns(Vec<PendingUnbond>)]
+    PendingUnbonds {
+        service_name: String,
+        verifier: String,
+    },
+
+    /// Returns the 100 most recent slashes of the verifier, in the order they were executed
+    #[returns(Vec<SlashRecord>)]
+    SlashHistory {
+        service_name: String,
+        verifier: String,
+    },
+
+    /// Returns the performance attestations of the service's verifiers, ordered by verifier address and chain name.
+    /// The list is paginated by:
+    /// - start_after: the (verifier, chain) pair to start after, which the next page of results should start.
+    /// - limit: limit the number of attestations returned, default is 100.
+    #[returns(Vec<PerformanceAttestation>)]
+    Attestations {
+        service_name: String,
+        start_after: Option<(String, ChainName)>,
+        limit: Option<u32>,
+    },
+
+    /// Returns the verifiers in the service's exit queue, ordered by address.
+    /// The list is paginated by:
+    /// - start_after: the verifier address to start after, which the next page of results should start.
+    /// - limit: limit the number of exit requests returned, default is 100.
+    #[returns(Vec<ExitRequest>)]
+    ExitQueue {
+        service_name: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+}
+
+#[cw_serde]
+pub struct ActiveVerifiersPageResponse {
+    pub verifiers: Vec<WeightedVerifier>,
+    /// Verifier to start the next page after, or None if all verifiers of the chain have been scanned
+    pub next_start_after: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
@@ -95,6 +247,9 @@ pub struct VerifierDetails {
     pub verifier: Verifier,
     pub weight: nonempty::Uint128,
     pub supported_chains: Vec<ChainName>,
+    /// Performance attestations of the verifier, one per chain it was attested for
+    #[serde(default)]
+    pub attestations: Vec<PerformanceAttestation>,
 }
 
 // Represents any modifiable fields of the Service struct
@@ -105,4 +260,6 @@ pub struct UpdatedServiceParams {
     pub max_num_verifiers: Option<Option<u16>>,
     pub min_verifier_bond: Option<nonempty::Uint128>,
     pub unbonding_period_days: Option<u16>,
+    #[serde(default)]
+    pub exit_handover_blocks: Option<u64>,
 }
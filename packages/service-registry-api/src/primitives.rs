@@ -1,6 +1,7 @@
 use axelar_wasm_std::{nonempty, Participant};
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Timestamp};
+use cosmwasm_std::{Addr, Timestamp, Uint128};
+use router_api::ChainName;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -16,6 +17,10 @@ pub struct Service {
     // otherwise a verifier could bail before they get penalized
     pub unbonding_period_days: u16,
     pub description: String,
+    /// Number of blocks an exiting verifier stays accountable for the polls and signing sessions it is already part of.
+    /// Should be at least as long as the longest poll or signing session of the service
+    #[serde(default)]
+    pub exit_handover_blocks: u64,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
@@ -56,6 +61,79 @@ pub enum BondingState {
     Unbonded,
 }
 
+/// A portion of a verifier's bond that is being unbonded while the rest stays bonded
+#[cw_serde]
+pub struct PendingUnbond {
+    pub amount: nonempty::Uint128,
+    pub requested_at: Timestamp,
+    pub completes_at: Timestamp,
+}
+
+/// Limits on how much of the verifiers' stake a slasher can slash
+#[cw_serde]
+pub struct SlashingLimits {
+    /// Maximum amount that can be slashed in a single slash
+    pub max_slash_amount: nonempty::Uint128,
+    /// Maximum amount that can be slashed in total across all verifiers of the service within one epoch
+    pub max_slash_amount_per_epoch: nonempty::Uint128,
+    /// Length of an epoch in blocks
+    pub epoch_duration: nonempty::Uint64,
+}
+
+#[cw_serde]
+pub struct SlashRecord {
+    pub slasher: Addr,
+    /// Amount that was actually slashed, which can be less than the requested amount if the verifier's stake was smaller
+    pub amount: Uint128,
+    /// Recipient of the slashed funds, None if they were burned
+    pub destination: Option<Addr>,
+    pub reason: nonempty::String,
+    pub slashed_at: Timestamp,
+    pub block_height: u64,
+}
+
+/// Participation of a verifier in the events of a chain over some period, as reported by an attester
+#[cw_serde]
+pub struct ParticipationSummary {
+    pub verifier: String,
+    pub chain_name: ChainName,
+    /// Number of events the verifier participated in
+    pub events_participated: u64,
+    /// Number of events the verifier was expected to participate in
+    pub events_total: u64,
+}
+
+/// Participation of a verifier in the events of a chain, accumulated over all attested summaries
+#[cw_serde]
+pub struct PerformanceAttestation {
+    pub verifier: Addr,
+    pub chain_name: ChainName,
+    pub events_participated: u64,
+    pub events_total: u64,
+    /// Attester of the most recent summary
+    pub attester: Addr,
+    /// Block height of the most recent summary
+    pub updated_at: u64,
+}
+
+/// A verifier that is leaving the service. It is not selected for new polls and signing sessions anymore,
+/// but must keep participating in the ones it is already part of until its duties end
+#[cw_serde]
+pub struct ExitRequest {
+    pub verifier: Addr,
+    /// Block height at which the exit was requested
+    pub requested_at: u64,
+    /// Block height from which on unbonding can start
+    pub duties_end_at: u64,
+}
+
+/// The active verifiers of a service for a chain as of the block height the snapshot was taken at
+#[cw_serde]
+pub struct VerifierSetSnapshot {
+    pub height: u64,
+    pub verifiers: Vec<WeightedVerifier>,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 pub enum AuthorizationState {
     NotAuthorized,
@@ -1,9 +1,12 @@
 use axelar_wasm_std::address::AddressFormat;
 use axelar_wasm_std::msg_id::MessageIdFormat;
-use axelar_wasm_std::{nonempty, MajorityThreshold};
+use axelar_wasm_std::{bounded, nonempty, MajorityThreshold, Threshold};
 use cosmwasm_schema::cw_serde;
 use router_api::ChainName;
 
+/// Maximum length in bytes of the source gateway address
+pub const MAX_SOURCE_GATEWAY_ADDRESS_LENGTH: usize = 128;
+
 #[cw_serde]
 pub struct InstantiateMsg {
     /// Address that can call all messages of unrestricted governance permission level, like UpdateVotingThreshold.
@@ -15,7 +18,7 @@ pub struct InstantiateMsg {
     /// Name of service in the service registry for which verifiers are registered.
     pub service_name: nonempty::String,
     /// Axelar's gateway contract address on the source chain
-    pub source_gateway_address: nonempty::String,
+    pub source_gateway_address: bounded::String<MAX_SOURCE_GATEWAY_ADDRESS_LENGTH>,
     /// Threshold of weighted votes required for voting to be considered complete for a particular message
     pub voting_threshold: MajorityThreshold,
     /// The number of blocks after which a poll expires
@@ -29,4 +32,61 @@ pub struct InstantiateMsg {
     /// Format that incoming messages should use for the id field of CrossChainId
     pub msg_id_format: MessageIdFormat,
     pub address_format: AddressFormat,
+    /// Fee charged per message that is put up for verification with VerifyMessages. If not set, verification is free
+    #[serde(default)]
+    pub verification_fee: Option<VerificationFee>,
+    /// Reduces the voting weight of verifiers in new polls while they keep missing polls. If not set, all verifiers vote with full weight
+    #[serde(default)]
+    pub vote_weight_decay: Option<VoteWeightDecay>,
+    /// Scales the voting threshold of new polls with the share of active verifiers. If not set, all polls use the voting threshold
+    #[serde(default)]
+    pub dynamic_voting_threshold: Option<DynamicVotingThreshold>,
+    /// Automatically starts a new poll for messages whose poll expired without quorum. If not set, messages have to be resubmitted
+    #[serde(default)]
+    pub poll_retry: Option<PollRetry>,
+    /// Address of the coordinator, which schedules maintenance windows of the source chain
+    pub coordinator_address: nonempty::String,
+}
+
+#[cw_serde]
+pub struct VerificationFee {
+    pub denom: nonempty::String,
+    /// Amount of `denom` charged per message
+    pub amount: nonempty::Uint128,
+    /// Callers that put messages up for verification without paying, e.g. the chain's gateway, which doesn't forward funds
+    #[serde(default)]
+    pub exempt_callers: Vec<nonempty::String>,
+}
+
+#[cw_serde]
+pub struct VoteWeightDecay {
+    /// Number of consecutive ended polls a verifier can miss before their voting weight in new polls decays.
+    /// The full weight is restored as soon as the verifier votes again
+    pub missed_polls_threshold: nonempty::Uint64,
+    /// Fraction of the full voting weight that a verifier keeps while their weight is decayed
+    pub decayed_weight: Threshold,
+}
+
+#[cw_serde]
+pub struct DynamicVotingThreshold {
+    /// Number of consecutive ended polls a verifier can miss before they no longer count as active.
+    /// Verifiers count as active again as soon as they vote
+    pub missed_polls_threshold: nonempty::Uint64,
+    /// Lowest threshold a poll is started with, no matter how few verifiers are active
+    pub floor: MajorityThreshold,
+}
+
+#[cw_serde]
+pub struct PollRetry {
+    /// Number of times a message is put up for verification again after its poll expired without quorum.
+    /// Once used up, the message has to be resubmitted to be verified
+    pub max_retries: nonempty::Uint64,
+}
+
+/// Block of the source chain at which the messages of a verification request were observed
+#[cw_serde]
+pub struct ObservedBlock {
+    pub height: u64,
+    /// Hash of the block in the source chain's native format, if known
+    pub hash: Option<nonempty::String>,
 }